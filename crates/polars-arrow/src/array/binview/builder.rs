@@ -105,6 +105,15 @@ impl<V: ViewType + ?Sized> BinaryViewArrayGenericBuilder<V> {
         }
     }
 
+    #[inline]
+    pub fn push(&mut self, value: Option<&V>) {
+        match value {
+            Some(value) => self.push_value_ignore_validity(value),
+            None => self.views.push(View::default()),
+        }
+        self.validity.push(value.is_some());
+    }
+
     /// # Safety
     /// The view must be inline.
     pub unsafe fn push_inline_view_ignore_validity(&mut self, view: View) {
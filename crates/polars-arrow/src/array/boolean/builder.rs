@@ -19,6 +19,12 @@ impl BooleanArrayBuilder {
             validity: OptBitmapBuilder::default(),
         }
     }
+
+    #[inline]
+    pub fn push(&mut self, value: Option<bool>) {
+        self.values.push(value.unwrap_or(false));
+        self.validity.push(value.is_some());
+    }
 }
 
 impl StaticArrayBuilder for BooleanArrayBuilder {
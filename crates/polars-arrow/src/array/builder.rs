@@ -1,3 +1,5 @@
+use std::any::Any;
+
 use polars_utils::IdxSize;
 
 use crate::array::binary::BinaryArrayBuilder;
@@ -95,7 +97,17 @@ pub trait StaticArrayBuilder: Send {
     fn opt_gather_extend(&mut self, other: &Self::Array, idxs: &[IdxSize], share: ShareStrategy);
 }
 
-impl<T: StaticArrayBuilder> ArrayBuilder for T {
+impl<T: StaticArrayBuilder + 'static> ArrayBuilder for T {
+    #[inline(always)]
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[inline(always)]
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
     #[inline(always)]
     fn dtype(&self) -> &ArrowDataType {
         StaticArrayBuilder::dtype(self)
@@ -181,6 +193,10 @@ impl<T: StaticArrayBuilder> ArrayBuilder for T {
 
 #[allow(private_bounds)]
 pub trait ArrayBuilder: ArrayBuilderBoxedHelper + Send {
+    /// Downcast to the concrete builder, e.g. to push single values.
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
     fn dtype(&self) -> &ArrowDataType;
     fn reserve(&mut self, additional: usize);
 
@@ -260,6 +276,16 @@ impl<T: ArrayBuilder> ArrayBuilderBoxedHelper for T {
 }
 
 impl ArrayBuilder for Box<dyn ArrayBuilder> {
+    #[inline(always)]
+    fn as_any(&self) -> &dyn Any {
+        (**self).as_any()
+    }
+
+    #[inline(always)]
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        (**self).as_any_mut()
+    }
+
     #[inline(always)]
     fn dtype(&self) -> &ArrowDataType {
         (**self).dtype()
@@ -22,6 +22,12 @@ impl<T: NativeType> PrimitiveArrayBuilder<T> {
             validity: OptBitmapBuilder::default(),
         }
     }
+
+    #[inline]
+    pub fn push(&mut self, value: Option<T>) {
+        self.values.push(value.unwrap_or_default());
+        self.validity.push(value.is_some());
+    }
 }
 
 impl<T: NativeType> StaticArrayBuilder for PrimitiveArrayBuilder<T> {
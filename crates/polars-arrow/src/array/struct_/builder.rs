@@ -21,6 +21,27 @@ impl StructArrayBuilder {
             validity: OptBitmapBuilder::default(),
         }
     }
+
+    pub fn inner_builders(&self) -> &[Box<dyn ArrayBuilder>] {
+        &self.inner_builders
+    }
+
+    /// The builders of the fields. After pushing a single value to every field, finish the
+    /// struct value with [`push_valid`](Self::push_valid).
+    pub fn inner_builders_mut(&mut self) -> &mut [Box<dyn ArrayBuilder>] {
+        &mut self.inner_builders
+    }
+
+    /// Finish a valid struct value whose fields were pushed to the inner builders.
+    pub fn push_valid(&mut self) {
+        debug_assert!(
+            self.inner_builders
+                .iter()
+                .all(|b| b.len() == self.length + 1)
+        );
+        self.validity.push(true);
+        self.length += 1;
+    }
 }
 
 impl StaticArrayBuilder for StructArrayBuilder {
@@ -532,6 +532,18 @@ impl OptBitmapBuilder {
         }
     }
 
+    #[inline]
+    pub fn push(&mut self, value: bool) {
+        match self {
+            Self::AllTrue { bit_len, bit_cap } if value => {
+                *bit_len += 1;
+                *bit_cap = usize::max(*bit_cap, *bit_len);
+            },
+            Self::AllTrue { .. } => self.get_builder().push(false),
+            Self::MayHaveFalse(inner) => inner.push(value),
+        }
+    }
+
     pub fn extend_constant(&mut self, length: usize, value: bool) {
         match self {
             Self::AllTrue { bit_len, bit_cap } => {
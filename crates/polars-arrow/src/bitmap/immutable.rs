@@ -350,6 +350,11 @@ impl Bitmap {
         self.storage.deref().as_ptr()
     }
 
+    /// Returns the number of bytes in the storage underlying this [`Bitmap`].
+    pub(crate) fn storage_len(&self) -> usize {
+        self.storage.len()
+    }
+
     /// Returns a pointer to the start of this [`Bitmap`] (ignores `offsets`)
     /// This pointer is allocated iff `self.len() > 0`.
     pub(crate) fn offset(&self) -> usize {
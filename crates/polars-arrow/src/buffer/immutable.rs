@@ -214,6 +214,12 @@ impl<T> Buffer<T> {
         self.storage.as_ptr()
     }
 
+    /// Returns the number of elements in the storage underlying this buffer.
+    #[inline]
+    pub(crate) fn storage_len(&self) -> usize {
+        self.storage.len()
+    }
+
     /// Returns the start offset of this buffer within the underlying storage.
     #[inline]
     pub fn offset(&self) -> usize {
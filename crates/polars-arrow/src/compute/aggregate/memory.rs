@@ -1,5 +1,6 @@
 use crate::array::*;
 use crate::bitmap::Bitmap;
+use crate::buffer::Buffer;
 use crate::datatypes::PhysicalType;
 pub use crate::types::PrimitiveType;
 use crate::{match_integer_type, with_match_primitive_type_full};
@@ -129,3 +130,205 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
         },
     }
 }
+
+/// The role of a buffer within an array, see [`visit_buffers`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BufferKind {
+    Values,
+    Validity,
+    Offsets,
+    /// The views of a binary view array. The data they point to are [`BufferKind::Values`].
+    Views,
+    /// Any buffer of the values of a dictionary array.
+    Dictionary,
+    /// The type ids of a union array.
+    Types,
+}
+
+/// A buffer of an array, see [`visit_buffers`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BufferInfo {
+    pub kind: BufferKind,
+    /// Address of the underlying allocation. Buffers with the same address share memory.
+    pub storage_address: usize,
+    /// Size of the underlying allocation in bytes.
+    pub storage_bytes: usize,
+    /// Number of bytes visible to the array. This is smaller than `storage_bytes` if the
+    /// buffer is sliced.
+    pub bytes: usize,
+}
+
+fn buffer_info<T>(kind: BufferKind, buffer: &Buffer<T>) -> BufferInfo {
+    BufferInfo {
+        kind,
+        storage_address: buffer.storage_ptr() as usize,
+        storage_bytes: buffer.storage_len() * size_of::<T>(),
+        bytes: buffer.len() * size_of::<T>(),
+    }
+}
+
+fn bitmap_info(kind: BufferKind, bitmap: &Bitmap) -> BufferInfo {
+    BufferInfo {
+        kind,
+        storage_address: bitmap.as_ptr() as usize,
+        storage_bytes: bitmap.storage_len(),
+        bytes: bitmap.as_slice().0.len(),
+    }
+}
+
+/// Calls `f` for every buffer of the array, including the buffers of nested arrays.
+///
+/// In contrast to [`estimated_bytes_size`], this reports the underlying allocations, so that
+/// callers can detect buffers that are sliced or shared between arrays.
+pub fn visit_buffers(array: &dyn Array, f: &mut dyn FnMut(BufferInfo)) {
+    visit_buffers_impl(array, None, f)
+}
+
+fn visit_buffers_impl(
+    array: &dyn Array,
+    kind_override: Option<BufferKind>,
+    f: &mut dyn FnMut(BufferInfo),
+) {
+    use BufferKind as K;
+    use PhysicalType::*;
+    let kind = |kind: BufferKind| kind_override.unwrap_or(kind);
+
+    if let Some(validity) = array.validity() {
+        f(bitmap_info(kind(K::Validity), validity));
+    }
+    match array.dtype().to_physical_type() {
+        Null => {},
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            f(bitmap_info(kind(K::Values), array.values()));
+        },
+        Primitive(PrimitiveType::DaysMs) => {
+            let array = array.as_any().downcast_ref::<DaysMsArray>().unwrap();
+            f(buffer_info(kind(K::Values), array.values()));
+        },
+        Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<$T>>()
+                .unwrap();
+            f(buffer_info(kind(K::Values), array.values()));
+        }),
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            f(buffer_info(kind(K::Offsets), array.offsets().buffer()));
+            f(buffer_info(kind(K::Values), array.values()));
+        },
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            f(buffer_info(kind(K::Offsets), array.offsets().buffer()));
+            f(buffer_info(kind(K::Values), array.values()));
+        },
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            f(buffer_info(kind(K::Offsets), array.offsets().buffer()));
+            f(buffer_info(kind(K::Values), array.values()));
+        },
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            f(buffer_info(kind(K::Offsets), array.offsets().buffer()));
+            f(buffer_info(kind(K::Values), array.values()));
+        },
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            f(buffer_info(kind(K::Values), array.values()));
+        },
+        List => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            f(buffer_info(kind(K::Offsets), array.offsets().buffer()));
+            visit_buffers_impl(array.values().as_ref(), kind_override, f);
+        },
+        LargeList => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            f(buffer_info(kind(K::Offsets), array.offsets().buffer()));
+            visit_buffers_impl(array.values().as_ref(), kind_override, f);
+        },
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            visit_buffers_impl(array.values().as_ref(), kind_override, f);
+        },
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            for field in array.values() {
+                visit_buffers_impl(field.as_ref(), kind_override, f);
+            }
+        },
+        Union => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            f(buffer_info(kind(K::Types), array.types()));
+            if let Some(offsets) = array.offsets() {
+                f(buffer_info(kind(K::Offsets), offsets));
+            }
+            for field in array.fields() {
+                visit_buffers_impl(field.as_ref(), kind_override, f);
+            }
+        },
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            f(buffer_info(kind(K::Offsets), array.offsets().buffer()));
+            visit_buffers_impl(array.field().as_ref(), kind_override, f);
+        },
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<DictionaryArray<$T>>()
+                .unwrap();
+            // The validity of the keys is the validity of the array, which we already visited.
+            f(buffer_info(kind(K::Values), array.keys().values()));
+            visit_buffers_impl(array.values().as_ref(), Some(K::Dictionary), f);
+        }),
+        Utf8View => {
+            let array = array.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
+            f(buffer_info(kind(K::Views), array.views()));
+            for buffer in array.data_buffers().iter() {
+                f(buffer_info(kind(K::Values), buffer));
+            }
+        },
+        BinaryView => {
+            let array = array.as_any().downcast_ref::<BinaryViewArray>().unwrap();
+            f(buffer_info(kind(K::Views), array.views()));
+            for buffer in array.data_buffers().iter() {
+                f(buffer_info(kind(K::Values), buffer));
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_visit_buffers() {
+        let array = Utf8Array::<i32>::from([Some("a"), None, Some("bc")]);
+        let mut buffers = vec![];
+        visit_buffers(&array, &mut |info| buffers.push(info));
+        let kinds = buffers.iter().map(|b| b.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            &[
+                BufferKind::Validity,
+                BufferKind::Offsets,
+                BufferKind::Values
+            ]
+        );
+        assert_eq!(buffers[1].bytes, 4 * size_of::<i32>());
+
+        let sliced = array.clone().sliced(1, 2);
+        let mut sliced_buffers = vec![];
+        visit_buffers(&sliced, &mut |info| sliced_buffers.push(info));
+        assert_eq!(
+            sliced_buffers[1].storage_address,
+            buffers[1].storage_address
+        );
+        assert_eq!(sliced_buffers[1].storage_bytes, buffers[1].storage_bytes);
+        assert_eq!(sliced_buffers[1].bytes, 3 * size_of::<i32>());
+    }
+}
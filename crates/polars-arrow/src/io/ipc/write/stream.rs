@@ -116,6 +116,28 @@ impl<W: Write> StreamWriter<W> {
         Ok(())
     }
 
+    /// Writes an already encoded record batch and the dictionaries it needs to the stream
+    pub fn write_encoded(
+        &mut self,
+        encoded_dictionaries: &[EncodedData],
+        encoded_message: &EncodedData,
+    ) -> PolarsResult<()> {
+        if self.finished {
+            let io_err = std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "Cannot write to a finished stream".to_string(),
+            );
+            return Err(PolarsError::from(io_err));
+        }
+
+        for encoded_dictionary in encoded_dictionaries {
+            write_message(&mut self.writer, encoded_dictionary)?;
+        }
+
+        write_message(&mut self.writer, encoded_message)?;
+        Ok(())
+    }
+
     /// Write continuation bytes, and mark the stream as done
     pub fn finish(&mut self) -> PolarsResult<()> {
         write_continuation(&mut self.writer, 0)?;
@@ -129,4 +151,9 @@ impl<W: Write> StreamWriter<W> {
     pub fn into_inner(self) -> W {
         self.writer
     }
+
+    /// Returns a mutable reference to the inner writer
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
 }
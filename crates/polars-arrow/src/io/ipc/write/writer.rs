@@ -95,6 +95,11 @@ impl<W: Write> FileWriter<W> {
         self.writer
     }
 
+    /// Returns a mutable reference to the inner writer
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
     /// Get the inner memory scratches so they can be reused in a new writer.
     /// This can be utilized to save memory allocations for performance reasons.
     pub fn get_scratches(&mut self) -> EncodedData {
@@ -253,4 +258,9 @@ impl<W: Write> FileWriter<W> {
     pub fn set_custom_schema_metadata(&mut self, custom_metadata: Arc<Metadata>) {
         self.custom_schema_metadata = Some(custom_metadata);
     }
+
+    /// Returns a mutable reference to the schema. Must be called before `start` is called
+    pub fn schema_mut(&mut self) -> &mut ArrowSchema {
+        Arc::make_mut(&mut self.schema)
+    }
 }
@@ -7,6 +7,86 @@ use crate::legacy::utils::CustomIterTools;
 use crate::trusted_len::TrustedLen;
 use crate::types::NativeType;
 
+/// Resumable state of an exponentially-weighted moving average.
+///
+/// Feeding values through [`EwmMeanState::update`] one by one gives the same
+/// results as [`ewm_mean`] over all of those values, so the computation can be
+/// carried across chunks, morsels or groups.
+#[derive(Debug, Clone, Copy)]
+pub struct EwmMeanState<T> {
+    alpha: T,
+    adjust: bool,
+    min_periods: usize,
+    ignore_nulls: bool,
+    old_wt: T,
+    weighted_avg: Option<T>,
+    non_null_cnt: usize,
+}
+
+impl<T> EwmMeanState<T>
+where
+    T: Float + AddAssign + MulAssign,
+{
+    pub fn new(alpha: T, adjust: bool, min_periods: usize, ignore_nulls: bool) -> Self {
+        Self {
+            alpha,
+            adjust,
+            min_periods,
+            ignore_nulls,
+            old_wt: T::one(),
+            weighted_avg: None,
+            non_null_cnt: 0,
+        }
+    }
+
+    /// Adds the next value and returns the moving average at that position.
+    #[inline]
+    pub fn update(&mut self, opt_x: Option<T>) -> Option<T> {
+        let new_wt = if self.adjust { T::one() } else { self.alpha };
+        let old_wt_factor = T::one() - self.alpha;
+
+        if opt_x.is_some() {
+            self.non_null_cnt += 1;
+        }
+        match self.weighted_avg {
+            None => self.weighted_avg = opt_x,
+            Some(w_avg) => {
+                if opt_x.is_some() || !self.ignore_nulls {
+                    self.old_wt *= old_wt_factor;
+                    if let Some(x) = opt_x {
+                        if w_avg != x {
+                            self.weighted_avg =
+                                Some((self.old_wt * w_avg + new_wt * x) / (self.old_wt + new_wt));
+                        }
+                        self.old_wt = if self.adjust {
+                            self.old_wt + new_wt
+                        } else {
+                            T::one()
+                        };
+                    }
+                }
+            },
+        }
+        self.current(opt_x.is_some())
+    }
+
+    /// The moving average after the last update, `None` if fewer than
+    /// `min_periods` non-null values were seen.
+    #[inline]
+    pub fn last(&self) -> Option<T> {
+        self.current(true)
+    }
+
+    #[inline]
+    fn current(&self, is_valid: bool) -> Option<T> {
+        if !is_valid || self.non_null_cnt < self.min_periods {
+            None
+        } else {
+            self.weighted_avg
+        }
+    }
+}
+
 pub fn ewm_mean<I, T>(
     xs: I,
     alpha: T,
@@ -19,39 +99,9 @@ where
     I::IntoIter: TrustedLen,
     T: Float + NativeType + AddAssign + MulAssign,
 {
-    let new_wt = if adjust { T::one() } else { alpha };
-    let old_wt_factor = T::one() - alpha;
-    let mut old_wt = T::one();
-    let mut weighted_avg = None;
-    let mut non_null_cnt = 0usize;
-
+    let mut state = EwmMeanState::new(alpha, adjust, min_periods, ignore_nulls);
     xs.into_iter()
-        .enumerate()
-        .map(|(i, opt_x)| {
-            if opt_x.is_some() {
-                non_null_cnt += 1;
-            }
-            match (i, weighted_avg) {
-                (0, _) | (_, None) => weighted_avg = opt_x,
-                (_, Some(w_avg)) => {
-                    if opt_x.is_some() || !ignore_nulls {
-                        old_wt *= old_wt_factor;
-                        if let Some(x) = opt_x {
-                            if w_avg != x {
-                                weighted_avg =
-                                    Some((old_wt * w_avg + new_wt * x) / (old_wt + new_wt));
-                            }
-                            old_wt = if adjust { old_wt + new_wt } else { T::one() };
-                        }
-                    }
-                },
-            }
-            match (non_null_cnt < min_periods, opt_x.is_some()) {
-                (_, false) => None,
-                (true, true) => None,
-                (false, true) => weighted_avg,
-            }
-        })
+        .map(|opt_x| state.update(opt_x))
         .collect_trusted()
 }
 
@@ -162,4 +212,20 @@ mod test {
             EPS
         );
     }
+
+    #[test]
+    fn test_ewm_mean_state_resumes() {
+        let xs = vec![None, Some(5.0f64), Some(7.0), None, Some(2.0), Some(1.0)];
+        for adjust in [false, true] {
+            for ignore_nulls in [false, true] {
+                let expected = ewm_mean(xs.clone(), ALPHA, adjust, 0, ignore_nulls);
+                let mut state = EwmMeanState::new(ALPHA, adjust, 0, ignore_nulls);
+                let (head, tail) = xs.split_at(3);
+                let mut out: Vec<Option<f64>> = head.iter().map(|x| state.update(*x)).collect();
+                out.extend(tail.iter().map(|x| state.update(*x)));
+                assert_allclose!(PrimitiveArray::from(out), expected, EPS);
+                assert_eq!(state.last(), Some(expected.value(xs.len() - 1)));
+            }
+        }
+    }
 }
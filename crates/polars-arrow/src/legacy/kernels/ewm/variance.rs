@@ -7,6 +7,143 @@ use crate::legacy::utils::CustomIterTools;
 use crate::trusted_len::TrustedLen;
 use crate::types::NativeType;
 
+/// Resumable state of an exponentially-weighted moving covariance.
+///
+/// Feeding pairs of values through [`EwmCovState::update`] one by one gives the
+/// same results as [`ewm_cov`] over all of those values, so the computation can
+/// be carried across chunks, morsels or groups.
+#[derive(Debug, Clone, Copy)]
+pub struct EwmCovState<T> {
+    alpha: T,
+    adjust: bool,
+    bias: bool,
+    min_periods: usize,
+    ignore_nulls: bool,
+    sum_wt: T,
+    sum_wt2: T,
+    old_wt: T,
+    opt_mean_x: Option<T>,
+    opt_mean_y: Option<T>,
+    cov: T,
+    non_na_cnt: usize,
+}
+
+impl<T> EwmCovState<T>
+where
+    T: Float + AddAssign + MulAssign + DivAssign,
+{
+    pub fn new(alpha: T, adjust: bool, bias: bool, min_periods: usize, ignore_nulls: bool) -> Self {
+        Self {
+            alpha,
+            adjust,
+            bias,
+            min_periods: if min_periods == 0 { 1 } else { min_periods },
+            ignore_nulls,
+            sum_wt: T::one(),
+            sum_wt2: T::one(),
+            old_wt: T::one(),
+            opt_mean_x: None,
+            opt_mean_y: None,
+            cov: T::zero(),
+            non_na_cnt: 0,
+        }
+    }
+
+    /// Adds the next pair of values and returns the moving covariance at that
+    /// position.
+    pub fn update(&mut self, opt_x: Option<T>, opt_y: Option<T>) -> Option<T> {
+        let old_wt_factor = T::one() - self.alpha;
+        let new_wt = if self.adjust { T::one() } else { self.alpha };
+
+        let is_observation = opt_x.is_some() && opt_y.is_some();
+        if is_observation {
+            self.non_na_cnt += 1;
+        }
+        match (self.opt_mean_x, self.opt_mean_y) {
+            (Some(mean_x), Some(mean_y)) => {
+                if is_observation || !self.ignore_nulls {
+                    self.sum_wt *= old_wt_factor;
+                    self.sum_wt2 *= old_wt_factor * old_wt_factor;
+                    self.old_wt *= old_wt_factor;
+                    if is_observation {
+                        let x = opt_x.unwrap();
+                        let y = opt_y.unwrap();
+                        let old_wt = self.old_wt;
+                        let old_mean_x = mean_x;
+                        let old_mean_y = mean_y;
+
+                        // avoid numerical errors on constant series
+                        if mean_x != x {
+                            self.opt_mean_x =
+                                Some((old_wt * old_mean_x + new_wt * x) / (old_wt + new_wt));
+                        }
+
+                        // avoid numerical errors on constant series
+                        if mean_y != y {
+                            self.opt_mean_y =
+                                Some((old_wt * old_mean_y + new_wt * y) / (old_wt + new_wt));
+                        }
+
+                        let mean_x = self.opt_mean_x.unwrap();
+                        let mean_y = self.opt_mean_y.unwrap();
+                        self.cov = ((old_wt
+                            * (self.cov + ((old_mean_x - mean_x) * (old_mean_y - mean_y))))
+                            + (new_wt * ((x - mean_x) * (y - mean_y))))
+                            / (old_wt + new_wt);
+
+                        self.sum_wt += new_wt;
+                        self.sum_wt2 += new_wt * new_wt;
+                        self.old_wt += new_wt;
+                        if !self.adjust {
+                            self.sum_wt /= self.old_wt;
+                            self.sum_wt2 /= self.old_wt * self.old_wt;
+                            self.old_wt = T::one();
+                        }
+                    }
+                }
+            },
+            _ => {
+                if is_observation {
+                    self.opt_mean_x = opt_x;
+                    self.opt_mean_y = opt_y;
+                }
+            },
+        }
+        self.current(is_observation)
+    }
+
+    /// The moving covariance after the last update, `None` if fewer than
+    /// `min_periods` observations were seen.
+    pub fn last(&self) -> Option<T> {
+        self.current(true)
+    }
+
+    fn current(&self, is_observation: bool) -> Option<T> {
+        match (
+            self.non_na_cnt >= self.min_periods,
+            self.bias,
+            is_observation,
+        ) {
+            (_, _, false) => None,
+            (false, _, true) => None,
+            (true, false, true) => {
+                if self.non_na_cnt == 1 {
+                    Some(self.cov)
+                } else {
+                    let numerator = self.sum_wt * self.sum_wt;
+                    let denominator = numerator - self.sum_wt2;
+                    if denominator > T::zero() {
+                        Some((numerator / denominator) * self.cov)
+                    } else {
+                        None
+                    }
+                }
+            },
+            (true, true, true) => Some(self.cov),
+        }
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn ewm_cov_internal<I, T>(
     xs: I,
@@ -23,102 +160,11 @@ where
     I::IntoIter: TrustedLen,
     T: Float + NativeType + AddAssign + MulAssign + DivAssign,
 {
-    let old_wt_factor = T::one() - alpha;
-    let new_wt = if adjust { T::one() } else { alpha };
-    let mut sum_wt = T::one();
-    let mut sum_wt2 = T::one();
-    let mut old_wt = T::one();
-
-    let mut opt_mean_x = None;
-    let mut opt_mean_y = None;
-    let mut cov = T::zero();
-    let mut non_na_cnt = 0usize;
-    let min_periods_fixed = if min_periods == 0 { 1 } else { min_periods };
-
+    let mut state = EwmCovState::new(alpha, adjust, bias, min_periods, ignore_nulls);
     let res = xs
         .into_iter()
         .zip(ys)
-        .enumerate()
-        .map(|(i, (opt_x, opt_y))| {
-            let is_observation = opt_x.is_some() && opt_y.is_some();
-            if is_observation {
-                non_na_cnt += 1;
-            }
-            match (i, opt_mean_x, opt_mean_y) {
-                (0, _, _) => {
-                    if is_observation {
-                        opt_mean_x = opt_x;
-                        opt_mean_y = opt_y;
-                    }
-                },
-                (_, Some(mean_x), Some(mean_y)) => {
-                    if is_observation || !ignore_nulls {
-                        sum_wt *= old_wt_factor;
-                        sum_wt2 *= old_wt_factor * old_wt_factor;
-                        old_wt *= old_wt_factor;
-                        if is_observation {
-                            let x = opt_x.unwrap();
-                            let y = opt_y.unwrap();
-                            let old_mean_x = mean_x;
-                            let old_mean_y = mean_y;
-
-                            // avoid numerical errors on constant series
-                            if mean_x != x {
-                                opt_mean_x =
-                                    Some((old_wt * old_mean_x + new_wt * x) / (old_wt + new_wt));
-                            }
-
-                            // avoid numerical errors on constant series
-                            if mean_y != y {
-                                opt_mean_y =
-                                    Some((old_wt * old_mean_y + new_wt * y) / (old_wt + new_wt));
-                            }
-
-                            cov = ((old_wt
-                                * (cov
-                                    + ((old_mean_x - opt_mean_x.unwrap())
-                                        * (old_mean_y - opt_mean_y.unwrap()))))
-                                + (new_wt
-                                    * ((x - opt_mean_x.unwrap()) * (y - opt_mean_y.unwrap()))))
-                                / (old_wt + new_wt);
-
-                            sum_wt += new_wt;
-                            sum_wt2 += new_wt * new_wt;
-                            old_wt += new_wt;
-                            if !adjust {
-                                sum_wt /= old_wt;
-                                sum_wt2 /= old_wt * old_wt;
-                                old_wt = T::one();
-                            }
-                        }
-                    }
-                },
-                _ => {
-                    if is_observation {
-                        opt_mean_x = opt_x;
-                        opt_mean_y = opt_y;
-                    }
-                },
-            }
-            match (non_na_cnt >= min_periods_fixed, bias, is_observation) {
-                (_, _, false) => None,
-                (false, _, true) => None,
-                (true, false, true) => {
-                    if non_na_cnt == 1 {
-                        Some(cov)
-                    } else {
-                        let numerator = sum_wt * sum_wt;
-                        let denominator = numerator - sum_wt2;
-                        if denominator > T::zero() {
-                            Some((numerator / denominator) * cov)
-                        } else {
-                            None
-                        }
-                    }
-                },
-                (true, true, true) => Some(cov),
-            }
-        });
+        .map(move |(opt_x, opt_y)| state.update(opt_x, opt_y));
 
     if do_sqrt {
         res.map(|opt_x| opt_x.map(|x| x.sqrt())).collect_trusted()
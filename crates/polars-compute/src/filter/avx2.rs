@@ -0,0 +1,140 @@
+#![allow(unsafe_op_in_unsafe_fn)]
+use core::arch::x86_64::*;
+
+use bytemuck::Pod;
+
+/// Builds the `_mm256_permutevar8x32_epi32` indices that move the selected
+/// lanes of a 32-byte vector with `LANES` lanes to the front, indexed by the
+/// lane mask. The positions after the selected lanes are don't-cares.
+const fn compress_table<const LANES: usize, const N: usize>() -> [[u32; 8]; N] {
+    let width = 8 / LANES;
+    let mut table = [[0; 8]; N];
+    let mut m = 0;
+    while m < N {
+        let mut written = 0;
+        let mut lane = 0;
+        while lane < LANES {
+            if m & (1 << lane) != 0 {
+                let mut w = 0;
+                while w < width {
+                    table[m][written * width + w] = (lane * width + w) as u32;
+                    w += 1;
+                }
+                written += 1;
+            }
+            lane += 1;
+        }
+        m += 1;
+    }
+    table
+}
+
+static COMPRESS_U32: [[u32; 8]; 256] = compress_table::<8, 256>();
+static COMPRESS_U64: [[u32; 8]; 16] = compress_table::<4, 16>();
+
+/// # Safety
+/// out must be valid for LANES + bitslice(mask_bytes, 0..values.len()).count_ones() writes.
+/// size_of::<T>() * LANES must be 32 and `table` must have 2^LANES entries.
+/// AVX2 must be enabled.
+#[target_feature(enable = "avx2")]
+unsafe fn avx2_filter<'a, T: Pod, const LANES: usize>(
+    mut values: &'a [T],
+    mut mask_bytes: &'a [u8],
+    mut out: *mut T,
+    table: &[[u32; 8]],
+) -> (&'a [T], &'a [u8], *mut T) {
+    let lane_mask = (1u64 << LANES) - 1;
+
+    let chunks = values.chunks_exact(64);
+    values = chunks.remainder();
+    for chunk in chunks {
+        let mask_chunk = mask_bytes.get_unchecked(..8);
+        mask_bytes = mask_bytes.get_unchecked(8..);
+        let m64 = u64::from_le_bytes(mask_chunk.try_into().unwrap());
+
+        // Fast-path: skip entire 64-element chunk.
+        if m64 == 0 {
+            continue;
+        }
+
+        // Fast-path: copy entire 64-element chunk.
+        if m64 == u64::MAX {
+            core::ptr::copy_nonoverlapping(chunk.as_ptr(), out, 64);
+            out = out.add(64);
+            continue;
+        }
+
+        for (i, vchunk) in chunk.chunks_exact(LANES).enumerate() {
+            let m = ((m64 >> (i * LANES)) & lane_mask) as usize;
+            // We are allowed to overshoot, so always store the full vector.
+            let v = _mm256_loadu_si256(vchunk.as_ptr().cast());
+            let idx = _mm256_loadu_si256(table.get_unchecked(m).as_ptr().cast());
+            _mm256_storeu_si256(out.cast(), _mm256_permutevar8x32_epi32(v, idx));
+            out = out.add(m.count_ones() as usize);
+        }
+    }
+
+    (values, mask_bytes, out)
+}
+
+/// # Safety
+/// out must be valid for 8 + bitslice(mask_bytes, 0..values.len()).count_ones() writes.
+/// AVX2 must be enabled.
+#[target_feature(enable = "avx2")]
+pub unsafe fn filter_u32_avx2<'a>(
+    values: &'a [u32],
+    mask_bytes: &'a [u8],
+    out: *mut u32,
+) -> (&'a [u32], &'a [u8], *mut u32) {
+    avx2_filter::<u32, 8>(values, mask_bytes, out, &COMPRESS_U32)
+}
+
+/// # Safety
+/// out must be valid for 4 + bitslice(mask_bytes, 0..values.len()).count_ones() writes.
+/// AVX2 must be enabled.
+#[target_feature(enable = "avx2")]
+pub unsafe fn filter_u64_avx2<'a>(
+    values: &'a [u64],
+    mask_bytes: &'a [u8],
+    out: *mut u64,
+) -> (&'a [u64], &'a [u8], *mut u64) {
+    avx2_filter::<u64, 4>(values, mask_bytes, out, &COMPRESS_U64)
+}
+
+/// # Safety
+/// out must be valid for idx.len() writes, every index must be in bounds of
+/// `values` and `values.len()` must be at most `i32::MAX`. AVX2 must be enabled.
+#[target_feature(enable = "avx2")]
+pub unsafe fn gather_u32_avx2(values: &[u32], idx: &[u32], mut out: *mut u32) {
+    let chunks = idx.chunks_exact(8);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        let offsets = _mm256_loadu_si256(chunk.as_ptr().cast());
+        let v = _mm256_i32gather_epi32::<4>(values.as_ptr().cast(), offsets);
+        _mm256_storeu_si256(out.cast(), v);
+        out = out.add(8);
+    }
+    for &i in remainder {
+        out.write(*values.get_unchecked(i as usize));
+        out = out.add(1);
+    }
+}
+
+/// # Safety
+/// out must be valid for idx.len() writes, every index must be in bounds of
+/// `values` and `values.len()` must be at most `i32::MAX`. AVX2 must be enabled.
+#[target_feature(enable = "avx2")]
+pub unsafe fn gather_u64_avx2(values: &[u64], idx: &[u32], mut out: *mut u64) {
+    let chunks = idx.chunks_exact(4);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        let offsets = _mm_loadu_si128(chunk.as_ptr().cast());
+        let v = _mm256_i32gather_epi64::<8>(values.as_ptr().cast(), offsets);
+        _mm256_storeu_si256(out.cast(), v);
+        out = out.add(4);
+    }
+    for &i in remainder {
+        out.write(*values.get_unchecked(i as usize));
+        out = out.add(1);
+    }
+}
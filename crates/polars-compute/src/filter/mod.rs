@@ -3,8 +3,12 @@ mod boolean;
 mod primitive;
 mod scalar;
 
+#[cfg(all(target_arch = "x86_64", feature = "simd"))]
+mod avx2;
 #[cfg(all(target_arch = "x86_64", feature = "simd"))]
 mod avx512;
+#[cfg(all(target_arch = "aarch64", feature = "simd"))]
+mod neon;
 
 use arrow::array::builder::{ArrayBuilder, ShareStrategy, make_builder};
 use arrow::array::{
@@ -0,0 +1,96 @@
+#![allow(unsafe_op_in_unsafe_fn)]
+use core::arch::aarch64::*;
+
+use bytemuck::Pod;
+
+/// Builds the byte shuffles that move the selected lanes of a 16-byte vector
+/// with `LANES` lanes to the front, indexed by the lane mask. Unused positions
+/// are out of range so the table lookup zeroes them.
+const fn compress_table<const LANES: usize, const N: usize>() -> [[u8; 16]; N] {
+    let width = 16 / LANES;
+    let mut table = [[0xff; 16]; N];
+    let mut m = 0;
+    while m < N {
+        let mut written = 0;
+        let mut lane = 0;
+        while lane < LANES {
+            if m & (1 << lane) != 0 {
+                let mut b = 0;
+                while b < width {
+                    table[m][written * width + b] = (lane * width + b) as u8;
+                    b += 1;
+                }
+                written += 1;
+            }
+            lane += 1;
+        }
+        m += 1;
+    }
+    table
+}
+
+static COMPRESS_U32: [[u8; 16]; 16] = compress_table::<4, 16>();
+static COMPRESS_U64: [[u8; 16]; 4] = compress_table::<2, 4>();
+
+/// # Safety
+/// out must be valid for LANES + bitslice(mask_bytes, 0..values.len()).count_ones() writes.
+/// size_of::<T>() * LANES must be 16 and `table` must have 2^LANES entries.
+unsafe fn neon_filter<'a, T: Pod, const LANES: usize>(
+    mut values: &'a [T],
+    mut mask_bytes: &'a [u8],
+    mut out: *mut T,
+    table: &[[u8; 16]],
+) -> (&'a [T], &'a [u8], *mut T) {
+    let lane_mask = (1u64 << LANES) - 1;
+
+    let chunks = values.chunks_exact(64);
+    values = chunks.remainder();
+    for chunk in chunks {
+        let mask_chunk = mask_bytes.get_unchecked(..8);
+        mask_bytes = mask_bytes.get_unchecked(8..);
+        let m64 = u64::from_le_bytes(mask_chunk.try_into().unwrap());
+
+        // Fast-path: skip entire 64-element chunk.
+        if m64 == 0 {
+            continue;
+        }
+
+        // Fast-path: copy entire 64-element chunk.
+        if m64 == u64::MAX {
+            core::ptr::copy_nonoverlapping(chunk.as_ptr(), out, 64);
+            out = out.add(64);
+            continue;
+        }
+
+        for (i, vchunk) in chunk.chunks_exact(LANES).enumerate() {
+            let m = ((m64 >> (i * LANES)) & lane_mask) as usize;
+            // We are allowed to overshoot, so always store the full vector.
+            let v = vld1q_u8(vchunk.as_ptr().cast());
+            let shuffle = vld1q_u8(table.get_unchecked(m).as_ptr());
+            vst1q_u8(out.cast(), vqtbl1q_u8(v, shuffle));
+            out = out.add(m.count_ones() as usize);
+        }
+    }
+
+    (values, mask_bytes, out)
+}
+
+/// # Safety
+/// out must be valid for 4 + bitslice(mask_bytes, 0..values.len()).count_ones() writes.
+pub unsafe fn filter_u32_neon<'a>(
+    values: &'a [u32],
+    mask_bytes: &'a [u8],
+    out: *mut u32,
+) -> (&'a [u32], &'a [u8], *mut u32) {
+    neon_filter::<u32, 4>(values, mask_bytes, out, &COMPRESS_U32)
+}
+
+/// # Safety
+/// out must be valid for 2 + bitslice(mask_bytes, 0..values.len()).count_ones() writes.
+pub unsafe fn filter_u64_neon<'a>(
+    values: &'a [u64],
+    mask_bytes: &'a [u8],
+    out: *mut u64,
+) -> (&'a [u64], &'a [u8], *mut u64) {
+    neon_filter::<u64, 2>(values, mask_bytes, out, &COMPRESS_U64)
+}
@@ -1,15 +1,51 @@
+use std::sync::OnceLock;
+
 use arrow::bitmap::Bitmap;
 use bytemuck::{Pod, cast_slice, cast_vec};
 #[cfg(all(target_arch = "x86_64", feature = "simd"))]
 use polars_utils::cpuid::is_avx512_enabled;
 
+#[cfg(all(target_arch = "x86_64", feature = "simd"))]
+use super::avx2;
 #[cfg(all(target_arch = "x86_64", feature = "simd"))]
 use super::avx512;
 use super::boolean::filter_boolean_kernel;
+#[cfg(all(target_arch = "aarch64", feature = "simd"))]
+use super::neon;
 use super::scalar::{scalar_filter, scalar_filter_offset};
 
 type FilterFn<T> = for<'a> unsafe fn(&'a [T], &'a [u8], *mut T) -> (&'a [T], &'a [u8], *mut T);
 
+/// Writes `values[idx[i]]` to `out[i]` for every `i`.
+type GatherFn<T> = unsafe fn(&[T], &[u32], *mut T);
+
+/// If fewer than one in this many values is selected, a SIMD filter gathers
+/// the selected values by index instead of compressing every block of values.
+///
+/// The compress kernels are bound by the memory bandwidth of reading all values,
+/// the gather by the number of selected values. On AVX2 and AVX-512 the break-even
+/// point is at about one in 32 values for 4-byte values and one in 10 for 8-byte
+/// values, so by default we gather below one selected value per 128 bytes. This
+/// can be tuned with `POLARS_FILTER_SPARSE_SELECTIVITY`.
+fn sparse_selectivity<T>() -> usize {
+    static SELECTIVITY: OnceLock<Option<usize>> = OnceLock::new();
+    SELECTIVITY
+        .get_or_init(|| {
+            std::env::var("POLARS_FILTER_SPARSE_SELECTIVITY")
+                .ok()
+                .and_then(|v| v.parse().ok())
+        })
+        .unwrap_or(128 / size_of::<T>())
+}
+
+/// # Safety
+/// out must be valid for idx.len() writes and every index must be in bounds of `values`.
+unsafe fn scalar_gather<T: Pod>(values: &[T], idx: &[u32], out: *mut T) {
+    for (i, &j) in idx.iter().enumerate() {
+        unsafe { out.add(i).write(*values.get_unchecked(j as usize)) };
+    }
+}
+
 fn nop_filter<'a, T: Pod>(
     values: &'a [T],
     mask: &'a [u8],
@@ -24,54 +60,108 @@ pub fn filter_values<T: Pod>(values: &[T], mask: &Bitmap) -> Vec<T> {
         (2, 2) => cast_vec(filter_values_u16(cast_slice(values), mask)),
         (4, 4) => cast_vec(filter_values_u32(cast_slice(values), mask)),
         (8, 8) => cast_vec(filter_values_u64(cast_slice(values), mask)),
-        _ => filter_values_generic(values, mask, 1, nop_filter),
+        _ => filter_values_generic(values, mask, 1, nop_filter, None),
     }
 }
 
 fn filter_values_u8(values: &[u8], mask: &Bitmap) -> Vec<u8> {
     #[cfg(all(target_arch = "x86_64", feature = "simd"))]
     if is_avx512_enabled() && std::arch::is_x86_feature_detected!("avx512vbmi2") {
-        return filter_values_generic(values, mask, 64, avx512::filter_u8_avx512vbmi2);
+        let sparse = Some(scalar_gather as GatherFn<u8>);
+        return filter_values_generic(values, mask, 64, avx512::filter_u8_avx512vbmi2, sparse);
     }
 
-    filter_values_generic(values, mask, 1, nop_filter)
+    filter_values_generic(values, mask, 1, nop_filter, None)
 }
 
 fn filter_values_u16(values: &[u16], mask: &Bitmap) -> Vec<u16> {
     #[cfg(all(target_arch = "x86_64", feature = "simd"))]
     if is_avx512_enabled() && std::arch::is_x86_feature_detected!("avx512vbmi2") {
-        return filter_values_generic(values, mask, 32, avx512::filter_u16_avx512vbmi2);
+        let sparse = Some(scalar_gather as GatherFn<u16>);
+        return filter_values_generic(values, mask, 32, avx512::filter_u16_avx512vbmi2, sparse);
     }
 
-    filter_values_generic(values, mask, 1, nop_filter)
+    filter_values_generic(values, mask, 1, nop_filter, None)
 }
 
 fn filter_values_u32(values: &[u32], mask: &Bitmap) -> Vec<u32> {
     #[cfg(all(target_arch = "x86_64", feature = "simd"))]
-    if is_avx512_enabled() {
-        return filter_values_generic(values, mask, 16, avx512::filter_u32_avx512f);
+    if std::arch::is_x86_feature_detected!("avx2") {
+        let gather = if values.len() <= i32::MAX as usize {
+            avx2::gather_u32_avx2 as GatherFn<u32>
+        } else {
+            scalar_gather
+        };
+        if is_avx512_enabled() {
+            return filter_values_generic(
+                values,
+                mask,
+                16,
+                avx512::filter_u32_avx512f,
+                Some(gather),
+            );
+        }
+        return filter_values_generic(values, mask, 8, avx2::filter_u32_avx2, Some(gather));
     }
 
-    filter_values_generic(values, mask, 1, nop_filter)
+    #[cfg(all(target_arch = "aarch64", feature = "simd"))]
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        let sparse = Some(scalar_gather as GatherFn<u32>);
+        return filter_values_generic(values, mask, 4, neon::filter_u32_neon, sparse);
+    }
+
+    filter_values_generic(values, mask, 1, nop_filter, None)
 }
 
 fn filter_values_u64(values: &[u64], mask: &Bitmap) -> Vec<u64> {
     #[cfg(all(target_arch = "x86_64", feature = "simd"))]
-    if is_avx512_enabled() {
-        return filter_values_generic(values, mask, 8, avx512::filter_u64_avx512f);
+    if std::arch::is_x86_feature_detected!("avx2") {
+        let gather = if values.len() <= i32::MAX as usize {
+            avx2::gather_u64_avx2 as GatherFn<u64>
+        } else {
+            scalar_gather
+        };
+        if is_avx512_enabled() {
+            return filter_values_generic(
+                values,
+                mask,
+                8,
+                avx512::filter_u64_avx512f,
+                Some(gather),
+            );
+        }
+        return filter_values_generic(values, mask, 4, avx2::filter_u64_avx2, Some(gather));
+    }
+
+    #[cfg(all(target_arch = "aarch64", feature = "simd"))]
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        let sparse = Some(scalar_gather as GatherFn<u64>);
+        return filter_values_generic(values, mask, 2, neon::filter_u64_neon, sparse);
     }
 
-    filter_values_generic(values, mask, 1, nop_filter)
+    filter_values_generic(values, mask, 1, nop_filter, None)
 }
 
+/// Filters with `bulk_filter`, or with `gather` if the mask is sparse. The
+/// scalar filter already skips over sparse words of the mask, so it passes no
+/// `gather`.
 fn filter_values_generic<T: Pod>(
     values: &[T],
     mask: &Bitmap,
     pad: usize,
     bulk_filter: FilterFn<T>,
+    gather: Option<GatherFn<T>>,
 ) -> Vec<T> {
     assert_eq!(values.len(), mask.len());
     let mask_bits_set = mask.set_bits();
+    if let Some(gather) = gather {
+        if mask_bits_set * sparse_selectivity::<T>() < values.len()
+            && values.len() <= u32::MAX as usize
+        {
+            return filter_values_sparse(values, mask, mask_bits_set, gather);
+        }
+    }
+
     let mut out = Vec::with_capacity(mask_bits_set + pad);
     unsafe {
         let (values, mask_bytes, out_ptr) = scalar_filter_offset(values, mask, out.as_mut_ptr());
@@ -82,6 +172,40 @@ fn filter_values_generic<T: Pod>(
     out
 }
 
+/// Gathers the selected values by index, which only touches the words of the
+/// mask and the values that are selected.
+fn filter_values_sparse<T: Pod>(
+    values: &[T],
+    mask: &Bitmap,
+    mask_bits_set: usize,
+    gather: GatherFn<T>,
+) -> Vec<T> {
+    const BATCH: usize = 64;
+
+    let mut out = Vec::<T>::with_capacity(mask_bits_set);
+    let mut idx = [0u32; BATCH];
+    let mut true_idx = mask.true_idx_iter();
+    let mut written = 0;
+    loop {
+        let mut n = 0;
+        for i in true_idx.by_ref().take(BATCH) {
+            idx[n] = i as u32;
+            n += 1;
+        }
+        if n == 0 {
+            break;
+        }
+        assert!(written + n <= mask_bits_set);
+        // SAFETY: the mask has the same length as the values, which fit in a
+        // u32, and we checked that there is capacity for n more values.
+        unsafe { gather(values, &idx[..n], out.as_mut_ptr().add(written)) };
+        written += n;
+    }
+    // SAFETY: we wrote exactly written values.
+    unsafe { out.set_len(written) };
+    out
+}
+
 pub fn filter_values_and_validity<T: Pod>(
     values: &[T],
     validity: Option<&Bitmap>,
@@ -92,3 +216,61 @@ pub fn filter_values_and_validity<T: Pod>(
         validity.map(|v| filter_boolean_kernel(v, mask)),
     )
 }
+
+#[cfg(test)]
+mod test {
+    use rand::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_filter_values_selectivity() {
+        // Verify every strategy against a naive filter, from very sparse to
+        // completely dense masks and with an offset into the mask.
+        let mut rng = StdRng::seed_from_u64(0xdeadbeef);
+        let values: Vec<u32> = (0..1000).collect();
+        let wide: Vec<u64> = (0..1000).collect();
+        for p in [0.001, 0.01, 0.1, 0.5, 0.9, 1.0] {
+            let bits: Vec<bool> = (0..1003).map(|_| rng.r#gen::<f64>() < p).collect();
+            let mask = Bitmap::from_iter(bits.iter().copied()).sliced(3, 1000);
+            let expected: Vec<u32> = values
+                .iter()
+                .zip(&bits[3..])
+                .filter_map(|(v, m)| m.then_some(*v))
+                .collect();
+
+            assert_eq!(filter_values(&values, &mask), expected);
+            let expected: Vec<u64> = expected.iter().map(|v| *v as u64).collect();
+            assert_eq!(filter_values(&wide, &mask), expected);
+        }
+    }
+
+    #[test]
+    fn test_gather_kernels() {
+        // Index counts that are not a multiple of the vector width exercise the
+        // scalar tail of the SIMD gathers.
+        let values: Vec<u32> = (0..1000).map(|i| i * 7).collect();
+        let wide: Vec<u64> = values.iter().map(|v| *v as u64 * 3).collect();
+        let mut rng = StdRng::seed_from_u64(0xdeadbeef);
+        for n in [0, 1, 3, 4, 7, 8, 13, 64] {
+            let idx: Vec<u32> = (0..n).map(|_| rng.gen_range(0..1000)).collect();
+            let expected: Vec<u32> = idx.iter().map(|i| values[*i as usize]).collect();
+            let expected_wide: Vec<u64> = idx.iter().map(|i| wide[*i as usize]).collect();
+
+            let mut gathers: Vec<(GatherFn<u32>, GatherFn<u64>)> =
+                vec![(scalar_gather, scalar_gather)];
+            #[cfg(all(target_arch = "x86_64", feature = "simd"))]
+            if std::arch::is_x86_feature_detected!("avx2") {
+                gathers.push((avx2::gather_u32_avx2, avx2::gather_u64_avx2));
+            }
+            for (gather, gather_wide) in gathers {
+                let mut out = vec![0u32; n];
+                unsafe { gather(&values, &idx, out.as_mut_ptr()) };
+                assert_eq!(out, expected);
+                let mut out = vec![0u64; n];
+                unsafe { gather_wide(&wide, &idx, out.as_mut_ptr()) };
+                assert_eq!(out, expected_wide);
+            }
+        }
+    }
+}
@@ -377,7 +377,10 @@ impl BinaryChunked {
             .downcast_iter()
             .map(|arr| unsafe { arr.to_utf8view_unchecked() }.boxed())
             .collect();
-        let field = Arc::new(Field::new(self.name().clone(), DataType::String));
+        let field = Arc::new(
+            Field::new(self.name().clone(), DataType::String)
+                .with_metadata(self.metadata().cloned()),
+        );
 
         let mut ca = StringChunked::new_with_compute_len(field, chunks);
 
@@ -393,7 +396,10 @@ impl StringChunked {
             .downcast_iter()
             .map(|arr| arr.to_binview().boxed())
             .collect();
-        let field = Arc::new(Field::new(self.name().clone(), DataType::Binary));
+        let field = Arc::new(
+            Field::new(self.name().clone(), DataType::Binary)
+                .with_metadata(self.metadata().cloned()),
+        );
 
         let mut ca = BinaryChunked::new_with_compute_len(field, chunks);
 
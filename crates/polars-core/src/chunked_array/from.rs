@@ -136,9 +136,8 @@ where
             .into_iter()
             .map(|x| Box::new(x) as Box<dyn Array>)
             .collect();
-        unsafe {
-            Self::from_chunks_and_dtype_unchecked(ca.name().clone(), chunks, ca.dtype().clone())
-        }
+        // Keep the field, so that the custom metadata is kept.
+        unsafe { ChunkedArray::new_with_compute_len(ca.field.clone(), chunks) }
     }
 
     pub fn try_from_chunk_iter<I, A, E>(name: PlSmallStr, iter: I) -> Result<Self, E>
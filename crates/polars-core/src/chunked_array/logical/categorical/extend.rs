@@ -0,0 +1,206 @@
+use super::*;
+
+/// What happens when values of an `Enum` are appended to an `Enum` with other categories.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EnumAppendPolicy {
+    /// Raise an error if the categories differ.
+    #[default]
+    Strict,
+    /// Extend the categories of the left-hand side with the missing categories of the
+    /// right-hand side and remap the appended values.
+    Extend,
+}
+
+/// Append the categories that `categories` lacks, in order and without duplicates.
+///
+/// Returns the new categories and, for every given category, its index in them.
+fn extend_categories<'a>(
+    categories: &Utf8ViewArray,
+    new: impl IntoIterator<Item = &'a str>,
+) -> (Utf8ViewArray, Vec<u32>) {
+    let mut positions = PlHashMap::from_iter(
+        categories
+            .values_iter()
+            .enumerate()
+            .map(|(i, v)| (v, i as u32)),
+    );
+    let mut extended = None::<MutablePlString>;
+    let mut mapping = Vec::new();
+    for cat in new {
+        let idx = *positions.entry(cat).or_insert_with(|| {
+            let extended = extended.get_or_insert_with(|| categories.clone().make_mut());
+            extended.push(Some(cat));
+            (extended.len() - 1) as u32
+        });
+        mapping.push(idx);
+    }
+    let categories = extended.map_or_else(|| categories.clone(), Into::into);
+    (categories, mapping)
+}
+
+fn enum_categories(dtype: &DataType) -> PolarsResult<(&Arc<RevMapping>, CategoricalOrdering)> {
+    match dtype {
+        DataType::Enum(Some(rev_map), ordering) => Ok((rev_map, *ordering)),
+        dt => polars_bail!(InvalidOperation: "expected an Enum with known categories, got {}", dt),
+    }
+}
+
+/// Add `categories` that are not yet part of the `Enum` `dtype` after its existing
+/// categories. The physical encoding of the existing categories doesn't change.
+pub fn extend_enum_dtype<'a>(
+    dtype: &DataType,
+    categories: impl IntoIterator<Item = &'a str>,
+) -> PolarsResult<DataType> {
+    let (rev_map, ordering) = enum_categories(dtype)?;
+    let (categories, _) = extend_categories(rev_map.get_categories(), categories);
+    if categories.len() == rev_map.len() {
+        return Ok(dtype.clone());
+    }
+    Ok(DataType::Enum(
+        Some(Arc::new(RevMapping::build_local(categories))),
+        ordering,
+    ))
+}
+
+/// Merge two `Enum` dtypes into the `Enum` holding the categories of `left` followed by the
+/// categories of `right` that `left` lacks.
+pub fn merge_enum_dtypes(left: &DataType, right: &DataType) -> PolarsResult<DataType> {
+    let (rev_map, _) = enum_categories(right)?;
+    extend_enum_dtype(left, rev_map.get_categories().values_iter())
+}
+
+/// Extend the categories of the `Enum` `left` with those of `right` and encode the values of
+/// `right` in the extended categories.
+fn remap_enum_into(
+    left: &CategoricalChunked,
+    right: &CategoricalChunked,
+) -> (Arc<RevMapping>, UInt32Chunked) {
+    let rev_map = left.get_rev_map();
+    let (categories, mapping) = extend_categories(
+        rev_map.get_categories(),
+        right.get_rev_map().get_categories().values_iter(),
+    );
+    let rev_map = if categories.len() == rev_map.len() {
+        rev_map.clone()
+    } else {
+        Arc::new(RevMapping::build_local(categories))
+    };
+    // The values under null slots aren't necessarily valid category indices.
+    let mapping = &mapping;
+    let physical = right.physical().apply(|v| v.map(|v| mapping[v as usize]));
+    (rev_map, physical)
+}
+
+impl CategoricalChunked {
+    /// Add `categories` to an `Enum` column, see [`extend_enum_dtype`].
+    ///
+    /// Only the categories change, the physical values are reused as is.
+    pub fn extend_categories<'a>(
+        &self,
+        categories: impl IntoIterator<Item = &'a str>,
+    ) -> PolarsResult<Self> {
+        let DataType::Enum(Some(rev_map), ordering) = extend_enum_dtype(self.dtype(), categories)?
+        else {
+            unreachable!()
+        };
+        // SAFETY: the existing categories keep their index.
+        Ok(unsafe {
+            Self::from_cats_and_rev_map_unchecked(self.physical().clone(), rev_map, true, ordering)
+        })
+    }
+
+    /// Append `other` in place, extending the categories of an `Enum` according to `policy`.
+    pub fn append_with_policy(
+        &mut self,
+        other: &Self,
+        policy: EnumAppendPolicy,
+    ) -> PolarsResult<()> {
+        if policy == EnumAppendPolicy::Strict
+            || !self.is_enum()
+            || !other.is_enum()
+            || self.dtype() == other.dtype()
+        {
+            return self.append(other);
+        }
+
+        let (rev_map, other_physical) = remap_enum_into(self, other);
+        let mut physical = self.physical().clone();
+        physical.append_owned(other_physical)?;
+        if self.uses_lexical_ordering() {
+            physical.set_sorted_flag(IsSorted::Not)
+        }
+        // SAFETY: the values of `other` are remapped to the extended categories.
+        *self = unsafe {
+            Self::from_cats_and_rev_map_unchecked(physical, rev_map, true, self.get_ordering())
+        };
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::array::PrimitiveArray;
+    use arrow::bitmap::Bitmap;
+    use arrow::datatypes::ArrowDataType;
+
+    use super::*;
+
+    #[test]
+    fn test_enum_append_with_policy() -> PolarsResult<()> {
+        let enum_ = |cats: &[&str]| create_enum_dtype(Utf8ViewArray::from_slice_values(cats));
+        let left = Series::new("a".into(), ["x", "y"]).cast(&enum_(&["x", "y"]))?;
+        let right = Series::new("a".into(), ["z", "y"]).cast(&enum_(&["z", "y"]))?;
+
+        assert!(left.clone().append(&right).is_err());
+        let mut out = left.clone();
+        out.append_with_policy(&right, EnumAppendPolicy::Extend)?;
+        assert_eq!(out.dtype(), &enum_(&["x", "y", "z"]));
+        assert_eq!(
+            Vec::from(out.categorical()?.physical()),
+            [Some(0), Some(1), Some(2), Some(1)]
+        );
+        assert_eq!(
+            &merge_enum_dtypes(left.dtype(), right.dtype())?,
+            out.dtype()
+        );
+
+        let extended = left.categorical()?.extend_categories(["y", "w"])?;
+        assert_eq!(extended.dtype(), &enum_(&["x", "y", "w"]));
+        assert_eq!(
+            Vec::from(extended.physical()),
+            Vec::from(left.categorical()?.physical())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_enum_append_with_policy_invalid_null_slot() -> PolarsResult<()> {
+        let enum_ = |cats: &[&str]| create_enum_dtype(Utf8ViewArray::from_slice_values(cats));
+        let right = Series::new("a".into(), ["z"]).cast(&enum_(&["z", "y"]))?;
+        let right = right.categorical()?;
+        let arr = PrimitiveArray::new(
+            ArrowDataType::UInt32,
+            vec![0u32, 100].into(),
+            Some(Bitmap::from_iter([true, false])),
+        );
+        let physical = UInt32Chunked::with_chunk("a".into(), arr);
+        // SAFETY: the only valid value is a valid category index.
+        let right = unsafe {
+            CategoricalChunked::from_cats_and_rev_map_unchecked(
+                physical,
+                right.get_rev_map().clone(),
+                true,
+                right.get_ordering(),
+            )
+        };
+
+        let mut out = Series::new("a".into(), ["x"])
+            .cast(&enum_(&["x"]))?
+            .categorical()?
+            .clone();
+        out.append_with_policy(&right, EnumAppendPolicy::Extend)?;
+        assert_eq!(Vec::from(out.physical()), [Some(0), Some(1), None]);
+        Ok(())
+    }
+}
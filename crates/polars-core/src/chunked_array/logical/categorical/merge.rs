@@ -200,6 +200,33 @@ impl CategoricalMergeOperation for DoNothing {
     }
 }
 
+/// Whether the physical codes of two categorical dtypes refer to the same strings, such that
+/// they can be compared, hashed and joined on directly.
+///
+/// This holds for categoricals from the same string cache and for `Enum`s with the same
+/// categories.
+pub fn categoricals_compatible(left: &DataType, right: &DataType) -> bool {
+    match (left, right) {
+        (DataType::Categorical(Some(l), _), DataType::Categorical(Some(r), _))
+        | (DataType::Enum(Some(l), _), DataType::Enum(Some(r), _)) => l.same_src(r),
+        _ => false,
+    }
+}
+
+/// Encode two categorical columns with the same rev-map, see [`categoricals_compatible`].
+///
+/// The categories of `left` are extended with those of `right`, so the physical codes of
+/// `left` don't change and only `right` is re-encoded.
+pub fn make_categoricals_compatible(
+    left: &CategoricalChunked,
+    right: &CategoricalChunked,
+) -> PolarsResult<(CategoricalChunked, CategoricalChunked)> {
+    if categoricals_compatible(left.dtype(), right.dtype()) {
+        return Ok((left.clone(), right.clone()));
+    }
+    make_rhs_categoricals_compatible(left, right)
+}
+
 // Make the right categorical compatible with the left
 pub fn make_rhs_categoricals_compatible(
     ca_left: &CategoricalChunked,
@@ -258,3 +285,24 @@ pub fn make_rhs_list_categoricals_compatible(
     list_ca_right.set_inner_dtype(cat_right.dtype().clone());
     Ok((list_ca_left, list_ca_right))
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_make_categoricals_compatible() -> PolarsResult<()> {
+        let dtype = DataType::Categorical(None, Default::default());
+        let left = Series::new("a".into(), ["x", "y"]).cast(&dtype)?;
+        let right = Series::new("b".into(), ["z", "x"]).cast(&dtype)?;
+        assert!(categoricals_compatible(left.dtype(), left.dtype()));
+        assert!(!categoricals_compatible(left.dtype(), right.dtype()));
+
+        let (l, r) = make_categoricals_compatible(left.categorical()?, right.categorical()?)?;
+        assert!(categoricals_compatible(l.dtype(), r.dtype()));
+        assert_eq!(Vec::from(l.physical()), [Some(0), Some(1)]);
+        assert_eq!(Vec::from(r.physical()), [Some(2), Some(0)]);
+        assert_eq!(r.name().as_str(), "b");
+        Ok(())
+    }
+}
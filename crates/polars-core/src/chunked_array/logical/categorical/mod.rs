@@ -1,4 +1,5 @@
 mod builder;
+mod extend;
 mod from;
 mod merge;
 mod ops;
@@ -7,6 +8,7 @@ pub mod string_cache;
 
 use bitflags::bitflags;
 pub use builder::*;
+pub use extend::*;
 pub use merge::*;
 use polars_utils::itertools::Itertools;
 use polars_utils::sync::SyncPtr;
@@ -36,8 +38,8 @@ pub struct CategoricalChunked {
 
 impl CategoricalChunked {
     pub(crate) fn field(&self) -> Field {
-        let name = self.physical().name();
-        Field::new(name.clone(), self.dtype().clone())
+        let field = self.physical().ref_field();
+        Field::new(field.name().clone(), self.dtype().clone()).with_metadata(field.metadata.clone())
     }
 
     pub fn is_empty(&self) -> bool {
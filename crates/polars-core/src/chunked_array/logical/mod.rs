@@ -112,7 +112,8 @@ where
         &self.phys
     }
     pub fn field(&self) -> Field {
-        let name = self.phys.ref_field().name();
-        Field::new(name.clone(), LogicalType::dtype(self).clone())
+        let field = self.phys.ref_field();
+        Field::new(field.name().clone(), LogicalType::dtype(self).clone())
+            .with_metadata(field.metadata.clone())
     }
 }
@@ -6,6 +6,7 @@ use std::sync::Arc;
 use arrow::array::*;
 use arrow::bitmap::Bitmap;
 use arrow::compute::concatenate::concatenate_unchecked;
+use arrow::datatypes::Metadata;
 use polars_compute::filter::filter_with_bitmap;
 
 use crate::prelude::*;
@@ -490,9 +491,24 @@ impl<T: PolarsDataType> ChunkedArray<T> {
         &self.field
     }
 
-    /// Rename this [`ChunkedArray`].
+    /// Rename this [`ChunkedArray`]. The custom metadata of the field is kept.
     pub fn rename(&mut self, name: PlSmallStr) {
-        self.field = Arc::new(Field::new(name, self.field.dtype().clone()));
+        self.field = Arc::new(
+            Field::new(name, self.field.dtype().clone()).with_metadata(self.field.metadata.clone()),
+        );
+    }
+
+    /// Get the custom metadata of the field.
+    pub fn metadata(&self) -> Option<&Arc<Metadata>> {
+        self.field.metadata()
+    }
+
+    /// Set the custom metadata of the field.
+    pub fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        if self.field.metadata.is_none() && metadata.as_ref().is_none_or(|md| md.is_empty()) {
+            return;
+        }
+        self.field = Arc::new(self.field.as_ref().clone().with_metadata(metadata));
     }
 
     /// Return this [`ChunkedArray`] with a new name.
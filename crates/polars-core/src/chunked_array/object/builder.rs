@@ -180,6 +180,14 @@ pub(crate) fn object_series_to_arrow_array(s: &Series) -> ArrayRef {
 }
 
 impl<T: PolarsObject> ArrayBuilder for ObjectChunkedBuilder<T> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
     fn dtype(&self) -> &ArrowDataType {
         &ArrowDataType::FixedSizeBinary(size_of::<T>())
     }
@@ -260,6 +260,26 @@ pub(crate) fn offsets_to_indexes(offsets: &[i64], capacity: usize) -> Vec<IdxSiz
     idx
 }
 
+/// Convert Arrow array offsets to the position of every value in its original list. The null
+/// that replaces an empty list has a null position.
+pub(crate) fn offsets_to_positions(offsets: &[i64], capacity: usize) -> IdxCa {
+    let mut positions = Vec::with_capacity(capacity);
+    for (offset_start, offset_end) in offsets.iter().zip(offsets.iter().skip(1)) {
+        if positions.len() >= capacity {
+            break;
+        }
+
+        if offset_start == offset_end {
+            positions.push(None);
+        } else {
+            let width = (offset_end - offset_start) as IdxSize;
+            positions.extend((0..width).map(Some));
+        }
+    }
+    positions.resize(capacity, None);
+    IdxCa::from_iter_options(PlSmallStr::EMPTY, positions.into_iter())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
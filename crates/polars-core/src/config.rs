@@ -1,3 +1,5 @@
+use std::cell::Cell;
+
 use crate::POOL;
 
 // Formatting environment variables (typically referenced/set from the python-side Config object)
@@ -29,6 +31,28 @@ pub(crate) const FMT_TABLE_INLINE_COLUMN_DATA_TYPE: &str =
 pub(crate) const FMT_TABLE_ROUNDED_CORNERS: &str = "POLARS_FMT_TABLE_ROUNDED_CORNERS";
 pub(crate) const FMT_TABLE_CELL_LIST_LEN: &str = "POLARS_FMT_TABLE_CELL_LIST_LEN";
 
+thread_local! {
+    static QUERY_MEMORY_BUDGET: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Run `f` with `budget` as the memory budget of the query that executes on this thread. Nodes
+/// that can spill to disk use it if their own budget isn't configured.
+pub fn with_query_memory_budget<R>(budget: Option<usize>, f: impl FnOnce() -> R) -> R {
+    struct Reset(Option<usize>);
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            QUERY_MEMORY_BUDGET.set(self.0);
+        }
+    }
+    let _reset = Reset(QUERY_MEMORY_BUDGET.replace(budget));
+    f()
+}
+
+/// The memory budget set by [`with_query_memory_budget`] for the query on this thread.
+pub fn query_memory_budget() -> Option<usize> {
+    QUERY_MEMORY_BUDGET.get()
+}
+
 pub fn verbose() -> bool {
     std::env::var("POLARS_VERBOSE").as_deref().unwrap_or("") == "1"
 }
@@ -67,6 +91,14 @@ pub fn get_rg_prefetch_size() -> usize {
         .unwrap_or_else(|_| std::cmp::max(get_file_prefetch_size(), 128))
 }
 
+/// Fraction of distinct keys in a sample of the group-by keys above which the
+/// partitioned radix group-by is used. Values above 1 disable it.
+pub fn get_group_by_radix_threshold() -> f64 {
+    std::env::var("POLARS_GROUP_BY_RADIX_THRESHOLD")
+        .map(|s| s.parse::<f64>().expect("float"))
+        .unwrap_or(0.5)
+}
+
 pub fn force_async() -> bool {
     std::env::var("POLARS_FORCE_ASYNC")
         .map(|value| value == "1")
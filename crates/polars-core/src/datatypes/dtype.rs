@@ -36,6 +36,22 @@ pub trait MetaDataExt: IntoMetadata {
         let metadata = self.into_metadata_ref();
         metadata.get(PL_KEY).map(|s| s.as_str()) == Some(MAINTAIN_PL_TYPE)
     }
+
+    /// The custom metadata, without the keys used to encode data types.
+    fn user_metadata(&self) -> Option<Arc<Metadata>> {
+        let metadata = self.into_metadata_ref();
+        let out: Metadata = metadata
+            .iter()
+            .filter(|(k, _)| {
+                !(k.as_str() == PL_KEY
+                    || k.as_str() == DTYPE_ENUM_VALUES
+                    || k.as_str() == DTYPE_CATEGORICAL
+                    || k.starts_with("ARROW:"))
+            })
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        (!out.is_empty()).then(|| Arc::new(out))
+    }
 }
 
 impl MetaDataExt for Metadata {}
@@ -0,0 +1,305 @@
+//! Logical extension types registered by the user, see [`register_extension_type`].
+//!
+//! An extension type is layered over a physical storage type, e.g. a "currency" over `Decimal`
+//! or a "probability" over `Float64`. A Series of an extension type is a Series of the storage
+//! type that has the name of the extension type in its field metadata, so it keeps its type
+//! through the operations that keep the metadata and is written to and read from Parquet and
+//! IPC files.
+//!
+//! Operations that compute new values, such as arithmetic and aggregations, give values of the
+//! storage type. They warn when they drop a registered extension type, so that the values can
+//! be converted back with [`Series::to_extension`] if that is correct for the operation.
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+
+use arrow::datatypes::Metadata;
+
+use crate::prelude::*;
+
+/// The key of the field metadata with the name of the extension type.
+pub const EXTENSION_TYPE_NAME: &str = "polars.extension.name";
+/// The key of the field metadata with the parameters of the extension type, e.g. a currency
+/// code.
+pub const EXTENSION_TYPE_METADATA: &str = "polars.extension.metadata";
+
+/// The definition of a logical extension type.
+///
+/// All methods get the storage values, without the extension type, and the parameters of the
+/// extension type, if any.
+pub trait ExtensionTypeDefinition: Send + Sync {
+    /// The physical data type the values are stored as.
+    fn storage_dtype(&self) -> DataType;
+
+    /// Parse a `String` Series into values of the storage type.
+    fn parse(&self, s: &Series, metadata: Option<&str>) -> PolarsResult<Series> {
+        let _ = metadata;
+        s.strict_cast(&self.storage_dtype())
+    }
+
+    /// Format the values as a `String` Series. This is used when casting to `String`.
+    fn format(&self, s: &Series, metadata: Option<&str>) -> PolarsResult<Series> {
+        let _ = metadata;
+        s.cast(&DataType::String)
+    }
+
+    /// Check that the values are valid for this type, e.g. that probabilities are in `[0, 1]`.
+    fn validate(&self, s: &Series, metadata: Option<&str>) -> PolarsResult<()> {
+        let _ = (s, metadata);
+        Ok(())
+    }
+
+    /// Whether the values may be cast to `dtype`. Casting to the storage type always succeeds
+    /// and keeps the extension type; other casts give values of the plain `dtype`.
+    fn can_cast_to(&self, dtype: &DataType) -> bool {
+        let _ = dtype;
+        true
+    }
+}
+
+static EXTENSION_TYPES: RwLock<Vec<(PlSmallStr, Arc<dyn ExtensionTypeDefinition>)>> =
+    RwLock::new(Vec::new());
+/// The number of registered extension types, so that the registry isn't locked by the casts
+/// and operations of processes that don't use them.
+static N_EXTENSION_TYPES: AtomicUsize = AtomicUsize::new(0);
+
+/// Register the extension type with the given `name`, or remove it with `None`.
+pub fn register_extension_type(name: &str, definition: Option<Arc<dyn ExtensionTypeDefinition>>) {
+    let mut types = EXTENSION_TYPES.write().unwrap();
+    let removed = types
+        .iter()
+        .position(|(n, _)| n == name)
+        .map(|i| types.remove(i));
+    if let Some(definition) = definition {
+        types.push((name.into(), definition));
+    }
+    N_EXTENSION_TYPES.store(types.len(), Ordering::Release);
+    // Dropping a definition may run user code, e.g. release a Python object, which must not
+    // happen while the registry is locked.
+    drop(types);
+    drop(removed);
+}
+
+/// Get the definition of the extension type registered as `name`.
+pub fn get_extension_type(name: &str) -> Option<Arc<dyn ExtensionTypeDefinition>> {
+    if N_EXTENSION_TYPES.load(Ordering::Acquire) == 0 {
+        return None;
+    }
+    let types = EXTENSION_TYPES.read().unwrap();
+    types
+        .iter()
+        .find(|(n, _)| n == name)
+        .map(|(_, definition)| definition.clone())
+}
+
+impl Series {
+    /// The name and the parameters of the extension type of this Series, if any.
+    pub fn extension_type(&self) -> Option<(PlSmallStr, Option<PlSmallStr>)> {
+        let md = self.metadata()?;
+        let name = md.get(EXTENSION_TYPE_NAME)?.clone();
+        Some((name, md.get(EXTENSION_TYPE_METADATA).cloned()))
+    }
+
+    /// The name of the extension type of this Series, if it is registered in this process.
+    fn registered_extension_type(&self) -> Option<PlSmallStr> {
+        if N_EXTENSION_TYPES.load(Ordering::Acquire) == 0 {
+            return None;
+        }
+        let name = self.metadata()?.get(EXTENSION_TYPE_NAME)?.clone();
+        get_extension_type(&name).map(|_| name)
+    }
+
+    /// Convert to the extension type registered as `name`, with the parameters `metadata`.
+    ///
+    /// A `String` Series is parsed by the extension type, other Series are strictly cast to the
+    /// storage type. The values are then validated by the extension type.
+    pub fn to_extension(&self, name: &str, metadata: Option<&str>) -> PolarsResult<Series> {
+        let definition = get_extension_type(name).ok_or_else(
+            || polars_err!(ComputeError: "no extension type registered as '{}'", name),
+        )?;
+        let storage = self.to_storage();
+        let storage_dtype = definition.storage_dtype();
+        let out = if storage.dtype().is_string() && !storage_dtype.is_string() {
+            definition.parse(&storage, metadata)?
+        } else {
+            storage.strict_cast(&storage_dtype)?
+        };
+        polars_ensure!(
+            out.dtype() == &storage_dtype,
+            SchemaMismatch: "extension type '{}' is stored as {}, got {}",
+            name, storage_dtype, out.dtype()
+        );
+        definition.validate(&out, metadata)?;
+
+        let mut md: Metadata = storage
+            .metadata()
+            .map(|md| md.as_ref().clone())
+            .unwrap_or_default();
+        md.insert(EXTENSION_TYPE_NAME.into(), name.into());
+        if let Some(metadata) = metadata {
+            md.insert(EXTENSION_TYPE_METADATA.into(), metadata.into());
+        }
+        Ok(out
+            .with_name(self.name().clone())
+            .with_metadata(Some(Arc::new(md))))
+    }
+
+    /// The values of this Series without their extension type. Other metadata is kept.
+    pub fn to_storage(&self) -> Series {
+        let Some(md) = self.metadata() else {
+            return self.clone();
+        };
+        if !md.contains_key(EXTENSION_TYPE_NAME) {
+            return self.clone();
+        }
+        let md: Metadata = md
+            .iter()
+            .filter(|(k, _)| {
+                k.as_str() != EXTENSION_TYPE_NAME && k.as_str() != EXTENSION_TYPE_METADATA
+            })
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        self.clone().with_metadata(Some(Arc::new(md)))
+    }
+
+    /// Apply the cast rules of the extension type of this Series. Returns `None` if the cast
+    /// isn't handled by an extension type.
+    ///
+    /// Extension types that aren't registered in this process, e.g. of data read from a file,
+    /// are cast like their storage type.
+    pub(crate) fn cast_extension_type(
+        &self,
+        dtype: &DataType,
+        options: CastOptions,
+    ) -> PolarsResult<Option<Series>> {
+        if N_EXTENSION_TYPES.load(Ordering::Acquire) == 0 {
+            return Ok(None);
+        }
+        let Some((name, metadata)) = self.extension_type() else {
+            return Ok(None);
+        };
+        let Some(definition) = get_extension_type(&name) else {
+            return Ok(None);
+        };
+        if dtype == self.dtype() {
+            return Ok(Some(self.clone()));
+        }
+        polars_ensure!(
+            definition.can_cast_to(dtype),
+            InvalidOperation: "cannot cast extension type '{}' to {}", name, dtype
+        );
+        let storage = self.to_storage();
+        let out = if dtype.is_string() {
+            definition.format(&storage, metadata.as_deref())?
+        } else {
+            storage.cast_with_options(dtype, options)?
+        };
+        Ok(Some(out))
+    }
+}
+
+/// Warn if one of the `inputs` of `op` has a registered extension type, as the result of `op`
+/// only has the storage type.
+pub(crate) fn warn_on_dropped_extension_type(op: &str, inputs: &[&Series]) {
+    if let Some(name) = inputs.iter().find_map(|s| s.registered_extension_type()) {
+        polars_warn!(
+            UserWarning,
+            "{} drops the extension type '{}', the result has the storage type; use \
+            `to_extension` to convert it back",
+            op,
+            name
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Amounts of money, stored as cents.
+    struct Currency;
+
+    impl ExtensionTypeDefinition for Currency {
+        fn storage_dtype(&self) -> DataType {
+            DataType::Int64
+        }
+
+        fn parse(&self, s: &Series, metadata: Option<&str>) -> PolarsResult<Series> {
+            let symbol = metadata.unwrap_or("$");
+            let out: Int64Chunked = s
+                .str()?
+                .iter()
+                .map(|v| {
+                    let (units, cents) = v?.strip_prefix(symbol)?.split_once('.')?;
+                    Some(units.parse::<i64>().ok()? * 100 + cents.parse::<i64>().ok()?)
+                })
+                .collect();
+            Ok(out.into_series())
+        }
+
+        fn format(&self, s: &Series, metadata: Option<&str>) -> PolarsResult<Series> {
+            let symbol = metadata.unwrap_or("$");
+            let out: StringChunked = s
+                .i64()?
+                .iter()
+                .map(|v| v.map(|v| format!("{symbol}{}.{:02}", v / 100, v % 100)))
+                .collect();
+            Ok(out.with_name(s.name().clone()).into_series())
+        }
+
+        fn can_cast_to(&self, dtype: &DataType) -> bool {
+            // Cents can't be cast to floats without losing precision.
+            !dtype.is_float()
+        }
+    }
+
+    struct Probability;
+
+    impl ExtensionTypeDefinition for Probability {
+        fn storage_dtype(&self) -> DataType {
+            DataType::Float64
+        }
+
+        fn validate(&self, s: &Series, _metadata: Option<&str>) -> PolarsResult<()> {
+            polars_ensure!(
+                s.f64()?.into_no_null_iter().all(|p| (0.0..=1.0).contains(&p)),
+                ComputeError: "probabilities should be between 0 and 1"
+            );
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_extension_type() -> PolarsResult<()> {
+        register_extension_type("test_currency", Some(Arc::new(Currency)));
+        register_extension_type("test_probability", Some(Arc::new(Probability)));
+
+        let s = Series::new("price".into(), ["€1.50", "€20.05"]);
+        let prices = s.to_extension("test_currency", Some("€"))?;
+        assert_eq!(prices.dtype(), &DataType::Int64);
+        assert_eq!(
+            prices.extension_type(),
+            Some(("test_currency".into(), Some("€".into())))
+        );
+
+        // The type survives operations that keep the values.
+        let sorted = prices.sort(SortOptions::default().with_order_descending(true))?;
+        assert_eq!(sorted.extension_type(), prices.extension_type());
+        assert!(sorted.cast(&DataType::Int64)?.extension_type().is_some());
+
+        let formatted = sorted.cast(&DataType::String)?;
+        assert_eq!(Vec::from(formatted.str()?), [Some("€20.05"), Some("€1.50")]);
+        assert!(prices.cast(&DataType::Float64).is_err());
+        assert_eq!(prices.to_storage().extension_type(), None);
+
+        let p = Series::new("p".into(), [0.5f64, 1.0]);
+        assert!(p.to_extension("test_probability", None).is_ok());
+        let p = Series::new("p".into(), [0.5f64, 1.5]);
+        assert!(p.to_extension("test_probability", None).is_err());
+
+        register_extension_type("test_currency", None);
+        assert!(s.to_extension("test_currency", None).is_err());
+        // Unregistered types are cast like their storage type.
+        assert!(prices.cast(&DataType::Float64).is_ok());
+        Ok(())
+    }
+}
@@ -5,7 +5,10 @@ use super::*;
 pub static EXTENSION_NAME: &str = "POLARS_EXTENSION_TYPE";
 
 /// Characterizes the name and the [`DataType`] of a column.
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+///
+/// A field can carry custom key/value metadata, e.g. units, descriptions or PII flags. The
+/// metadata is not part of the type: it is ignored when comparing and hashing fields.
+#[derive(Clone, Debug)]
 #[cfg_attr(
     any(feature = "serde", feature = "serde-lazy"),
     derive(Serialize, Deserialize)
@@ -13,6 +16,29 @@ pub static EXTENSION_NAME: &str = "POLARS_EXTENSION_TYPE";
 pub struct Field {
     pub name: PlSmallStr,
     pub dtype: DataType,
+    #[cfg_attr(
+        any(feature = "serde", feature = "serde-lazy"),
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub metadata: Option<Arc<Metadata>>,
+}
+
+// Fields compare and hash by name and dtype only. Schemas are compared to check that frames
+// can be concatenated, unioned or joined and to cache plans, and columns with different units
+// or descriptions are still of the same type.
+impl PartialEq for Field {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.dtype == other.dtype
+    }
+}
+
+impl Eq for Field {}
+
+impl Hash for Field {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.dtype.hash(state);
+    }
 }
 
 impl From<Field> for (PlSmallStr, DataType) {
@@ -36,7 +62,11 @@ impl Field {
     /// ```
     #[inline]
     pub fn new(name: PlSmallStr, dtype: DataType) -> Self {
-        Field { name, dtype }
+        Field {
+            name,
+            dtype,
+            metadata: None,
+        }
     }
 
     /// Returns a reference to the `Field` name.
@@ -105,6 +135,30 @@ impl Field {
         self
     }
 
+    /// Returns a reference to the custom metadata of the `Field`.
+    #[inline]
+    pub fn metadata(&self) -> Option<&Arc<Metadata>> {
+        self.metadata.as_ref()
+    }
+
+    /// Returns this `Field` with the given custom metadata.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// # use std::collections::BTreeMap;
+    /// # use std::sync::Arc;
+    /// let md = BTreeMap::from([("unit".into(), "m/s".into())]);
+    /// let f = Field::new("Speed".into(), DataType::Float64).with_metadata(Some(Arc::new(md)));
+    ///
+    /// assert_eq!(f.metadata().unwrap()["unit"], "m/s");
+    /// ```
+    pub fn with_metadata(mut self, metadata: Option<Arc<Metadata>>) -> Self {
+        self.metadata = metadata.filter(|md| !md.is_empty());
+        self
+    }
+
     /// Converts the `Field` to an `arrow::datatypes::Field`.
     ///
     /// # Example
@@ -117,7 +171,15 @@ impl Field {
     /// assert_eq!(f.to_arrow(CompatLevel::newest()), af);
     /// ```
     pub fn to_arrow(&self, compat_level: CompatLevel) -> ArrowField {
-        self.dtype.to_arrow_field(self.name.clone(), compat_level)
+        let field = self.dtype.to_arrow_field(self.name.clone(), compat_level);
+        match &self.metadata {
+            None => field,
+            Some(md) => {
+                let mut merged = field.metadata.as_deref().cloned().unwrap_or_default();
+                merged.extend(md.iter().map(|(k, v)| (k.clone(), v.clone())));
+                field.with_metadata(merged)
+            },
+        }
     }
 }
 
@@ -261,5 +323,6 @@ impl DataType {
 impl From<&ArrowField> for Field {
     fn from(f: &ArrowField) -> Self {
         Field::new(f.name.clone(), DataType::from_arrow_field(f))
+            .with_metadata(f.metadata.as_deref().and_then(|md| md.user_metadata()))
     }
 }
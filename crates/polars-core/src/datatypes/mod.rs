@@ -11,6 +11,7 @@ mod _serde;
 mod aliases;
 mod any_value;
 mod dtype;
+mod extension;
 mod field;
 mod into_scalar;
 #[cfg(feature = "object")]
@@ -33,6 +34,7 @@ pub use arrow::datatypes::{ArrowDataType, TimeUnit as ArrowTimeUnit};
 use arrow::types::NativeType;
 use bytemuck::Zeroable;
 pub use dtype::*;
+pub use extension::*;
 pub use field::*;
 pub use into_scalar::*;
 use num_traits::{AsPrimitive, Bounded, FromPrimitive, Num, NumCast, One, Zero};
@@ -1,3 +1,6 @@
+#[cfg(feature = "timezones")]
+use std::sync::RwLock;
+
 use polars_error::PolarsResult;
 use polars_utils::pl_str::PlSmallStr;
 
@@ -56,7 +59,7 @@ impl TimeZone {
         #[cfg(feature = "timezones")]
         if let Some(tz) = canonical_tz.as_mut() {
             if Self::validate_time_zone(tz).is_err() {
-                match parse_fixed_offset(tz) {
+                match resolve_time_zone(tz).map_or_else(|| parse_fixed_offset(tz), Ok) {
                     Ok(v) => *tz = v,
                     Err(err) => {
                         // This can be used if there are externally created arrow buffers / dtypes
@@ -157,12 +160,45 @@ polars_utils::regex_cache::cached_regex! {
     static FIXED_OFFSET_RE = FIXED_OFFSET_PATTERN;
 }
 
+/// Maps a time zone name that is unknown to the time zone database to a name
+/// that is known to it, e.g. a Windows zone name to an IANA name.
+#[cfg(feature = "timezones")]
+pub type TimeZoneResolver = fn(&str) -> Option<PlSmallStr>;
+
+#[cfg(feature = "timezones")]
+static TIME_ZONE_RESOLVER: RwLock<Option<TimeZoneResolver>> = RwLock::new(None);
+
+/// Set the resolver that is consulted for time zone names which are not in the
+/// time zone database, or `None` to unset it.
+///
+/// The IANA time zone database is compiled into Polars, so time zone aware
+/// operations never depend on the time zone database of the system. The
+/// resolver allows mapping names from other sources, e.g. Windows zone names or
+/// site-specific aliases, on top of it. Resolved names are stored in the
+/// data type, so the resolver must be set before the time zone is first used.
+#[cfg(feature = "timezones")]
+pub fn set_time_zone_resolver(resolver: Option<TimeZoneResolver>) {
+    *TIME_ZONE_RESOLVER.write().unwrap() = resolver;
+}
+
+/// Resolve a time zone name with the resolver set by [`set_time_zone_resolver`].
+///
+/// Only returns names that are in the time zone database.
+#[cfg(feature = "timezones")]
+fn resolve_time_zone(tz: &str) -> Option<PlSmallStr> {
+    let resolver = (*TIME_ZONE_RESOLVER.read().unwrap())?;
+    resolver(tz).filter(|resolved| resolved.parse::<chrono_tz::Tz>().is_ok())
+}
+
 /// Parse a time zone string to [`chrono_tz::Tz`]
 #[cfg(feature = "timezones")]
 pub fn parse_time_zone(tz: &str) -> PolarsResult<chrono_tz::Tz> {
     match tz.parse::<chrono_tz::Tz>() {
         Ok(tz) => Ok(tz),
-        Err(_) => unable_to_parse_err(tz),
+        Err(_) => match resolve_time_zone(tz) {
+            Some(resolved) => Ok(resolved.parse::<chrono_tz::Tz>().unwrap()),
+            None => unable_to_parse_err(tz),
+        },
     }
 }
 
@@ -205,3 +241,43 @@ fn unable_to_parse_err<T>(tz: &str) -> PolarsResult<T> {
         tz
     )
 }
+
+#[cfg(all(test, feature = "timezones"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_time_zone_resolver() {
+        fn resolver(tz: &str) -> Option<PlSmallStr> {
+            match tz {
+                "W. Europe Standard Time" => Some("Europe/Berlin".into()),
+                "Bogus" => Some("Not/AZone".into()),
+                _ => None,
+            }
+        }
+
+        // Unset the resolver again even if an assertion fails, so that it
+        // doesn't leak into other tests.
+        struct ResetResolver;
+        impl Drop for ResetResolver {
+            fn drop(&mut self) {
+                set_time_zone_resolver(None);
+            }
+        }
+
+        assert!(parse_time_zone("W. Europe Standard Time").is_err());
+        let reset = ResetResolver;
+        set_time_zone_resolver(Some(resolver));
+        let tz = TimeZone::opt_try_new(Some("W. Europe Standard Time"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(tz.as_str(), "Europe/Berlin");
+        assert_eq!(
+            parse_time_zone("W. Europe Standard Time").unwrap(),
+            chrono_tz::Europe::Berlin
+        );
+        assert!(parse_time_zone("Bogus").is_err());
+        drop(reset);
+        assert!(parse_time_zone("W. Europe Standard Time").is_err());
+    }
+}
@@ -1,12 +1,12 @@
 use std::sync::Arc;
 
-use arrow::array::builder::ShareStrategy;
+use arrow::array::builder::{ArrayBuilder, ShareStrategy};
 use polars_utils::IdxSize;
 
 use crate::frame::DataFrame;
 use crate::prelude::*;
 use crate::schema::Schema;
-use crate::series::builder::SeriesBuilder;
+use crate::series::builder::{PushValue, SeriesBuilder};
 
 pub struct DataFrameBuilder {
     schema: Arc<Schema>,
@@ -27,12 +27,33 @@ impl DataFrameBuilder {
         }
     }
 
+    pub fn with_capacity(schema: Arc<Schema>, capacity: usize) -> Self {
+        let mut builder = Self::new(schema);
+        builder.reserve(capacity);
+        builder
+    }
+
     pub fn reserve(&mut self, additional: usize) {
         for builder in &mut self.builders {
             builder.reserve(additional);
         }
     }
 
+    /// Push a row of typed values, e.g. `(1i64, Some("a"), StructValue((2.5f64, true)))`,
+    /// without going through [`AnyValue`].
+    ///
+    /// The values must match the physical types of the schema, see [`SeriesBuilder::push`].
+    pub fn push_row<R: PushRow>(&mut self, row: R) -> PolarsResult<()> {
+        polars_ensure!(
+            self.builders.iter().all(|b| b.supports_push()) && R::accepts(&self.builders[..]),
+            SchemaMismatch: "cannot push a row of type '{}' to a builder of schema {:?}",
+            std::any::type_name::<R>(), self.schema,
+        );
+        row.push_to(&mut self.builders[..]);
+        self.height += 1;
+        Ok(())
+    }
+
     pub fn freeze(self) -> DataFrame {
         let columns = self
             .schema
@@ -264,3 +285,132 @@ impl DataFrameBuilder {
         self.height += idxs.len();
     }
 }
+
+/// The column builders a [`PushRow`] pushes its values to.
+pub trait RowBuilders {
+    fn width(&self) -> usize;
+    fn builder(&self, i: usize) -> &dyn ArrayBuilder;
+    fn builder_mut(&mut self, i: usize) -> &mut dyn ArrayBuilder;
+}
+
+impl RowBuilders for [Box<dyn ArrayBuilder>] {
+    fn width(&self) -> usize {
+        self.len()
+    }
+
+    fn builder(&self, i: usize) -> &dyn ArrayBuilder {
+        &*self[i]
+    }
+
+    fn builder_mut(&mut self, i: usize) -> &mut dyn ArrayBuilder {
+        &mut *self[i]
+    }
+}
+
+impl RowBuilders for [SeriesBuilder] {
+    fn width(&self) -> usize {
+        self.len()
+    }
+
+    fn builder(&self, i: usize) -> &dyn ArrayBuilder {
+        self[i].array_builder()
+    }
+
+    fn builder_mut(&mut self, i: usize) -> &mut dyn ArrayBuilder {
+        self[i].array_builder_mut()
+    }
+}
+
+/// A row of values that can be pushed to a [`DataFrameBuilder`] or as the fields of a struct.
+/// Implemented for tuples of up to 12 [`PushValue`]s.
+pub trait PushRow {
+    /// Whether `builders` can hold rows of this type.
+    fn accepts<B: RowBuilders + ?Sized>(builders: &B) -> bool;
+
+    /// Push this row to `builders`. Panics if `builders` does not [accept](Self::accepts) it.
+    fn push_to<B: RowBuilders + ?Sized>(self, builders: &mut B);
+}
+
+macro_rules! impl_push_row {
+    ($n:expr; $($T:ident $idx:tt),+) => {
+        impl<$($T: PushValue),+> PushRow for ($($T,)+) {
+            #[inline]
+            fn accepts<B: RowBuilders + ?Sized>(builders: &B) -> bool {
+                builders.width() == $n $(&& $T::accepts(builders.builder($idx)))+
+            }
+
+            #[inline]
+            fn push_to<B: RowBuilders + ?Sized>(self, builders: &mut B) {
+                $(self.$idx.push_to(builders.builder_mut($idx));)+
+            }
+        }
+    };
+}
+
+impl_push_row!(1; A 0);
+impl_push_row!(2; A 0, B 1);
+impl_push_row!(3; A 0, B 1, C 2);
+impl_push_row!(4; A 0, B 1, C 2, D 3);
+impl_push_row!(5; A 0, B 1, C 2, D 3, E 4);
+impl_push_row!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+impl_push_row!(7; A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_push_row!(8; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_push_row!(9; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_push_row!(10; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_push_row!(11; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_push_row!(12; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_push_row() -> PolarsResult<()> {
+        let schema = Schema::from_iter([
+            Field::new("id".into(), DataType::Int64),
+            Field::new("name".into(), DataType::String),
+            Field::new("score".into(), DataType::Float64),
+        ]);
+        let mut builder = DataFrameBuilder::with_capacity(Arc::new(schema), 3);
+        builder.push_row((1i64, "a", Some(1.5f64)))?;
+        builder.push_row((2i64, None::<&str>, None::<f64>))?;
+        builder.push_row((3i64, "c".to_string(), 2.5f64))?;
+
+        // Mismatching types and widths are rejected without pushing anything.
+        assert!(builder.push_row((4i32, "d", 1.0f64)).is_err());
+        assert!(builder.push_row((4i64, "d")).is_err());
+
+        let df = builder.freeze();
+        let expected = df!(
+            "id" => [1i64, 2, 3],
+            "name" => [Some("a"), None, Some("c")],
+            "score" => [Some(1.5f64), None, Some(2.5)],
+        )?;
+        assert!(df.equals_missing(&expected));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_push_struct() -> PolarsResult<()> {
+        use crate::series::builder::StructValue;
+
+        let dtype = DataType::Struct(vec![
+            Field::new("x".into(), DataType::Float64),
+            Field::new("label".into(), DataType::String),
+        ]);
+        let mut builder = SeriesBuilder::new(dtype);
+        builder.push_struct((1.5f64, "p"))?;
+        builder.push(None::<StructValue<(f64, &str)>>)?;
+        builder.push_struct((None::<f64>, "q"))?;
+        assert!(builder.push_struct((1.5f64,)).is_err());
+
+        let s = builder.freeze("point".into());
+        assert_eq!(s.len(), 3);
+        assert_eq!(s.null_count(), 1);
+        let x = s.struct_()?.field_by_name("x")?;
+        assert_eq!(x.f64()?.get(0), Some(1.5));
+        assert_eq!(x.f64()?.get(2), None);
+        Ok(())
+    }
+}
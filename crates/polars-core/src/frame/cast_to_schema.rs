@@ -0,0 +1,190 @@
+//! Conform a [`DataFrame`] to a target [`Schema`], see [`DataFrame::cast_to_schema`].
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+/// How a column whose dtype differs from the target dtype is handled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CastPolicy {
+    /// Raise an error if the dtype differs.
+    NoCast,
+    /// Cast and raise an error if a value can't be cast.
+    #[default]
+    Strict,
+    /// Cast and set values that can't be cast to null.
+    Lossy,
+}
+
+/// How columns of the target schema that are not in the [`DataFrame`] are handled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MissingColumnPolicy {
+    #[default]
+    Raise,
+    /// Add the column with only null values.
+    Insert,
+}
+
+/// How columns of the [`DataFrame`] that are not in the target schema are handled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ExtraColumnPolicy {
+    #[default]
+    Raise,
+    Drop,
+    /// Keep the columns after the columns of the target schema.
+    Keep,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CastToSchemaOptions {
+    pub cast: CastPolicy,
+    /// Overrides `cast` for single columns.
+    pub column_casts: PlHashMap<PlSmallStr, CastPolicy>,
+    pub missing_columns: MissingColumnPolicy,
+    pub extra_columns: ExtraColumnPolicy,
+}
+
+impl CastToSchemaOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_cast(mut self, policy: CastPolicy) -> Self {
+        self.cast = policy;
+        self
+    }
+
+    pub fn with_column_cast(mut self, column: impl Into<PlSmallStr>, policy: CastPolicy) -> Self {
+        self.column_casts.insert(column.into(), policy);
+        self
+    }
+
+    pub fn with_missing_columns(mut self, policy: MissingColumnPolicy) -> Self {
+        self.missing_columns = policy;
+        self
+    }
+
+    pub fn with_extra_columns(mut self, policy: ExtraColumnPolicy) -> Self {
+        self.extra_columns = policy;
+        self
+    }
+}
+
+impl DataFrame {
+    /// Cast and align the columns to `schema`.
+    ///
+    /// The columns of the output are in the order of `schema`. Columns are cast according to
+    /// the [`CastPolicy`] of the column, missing and extra columns are handled according to
+    /// `options.missing_columns` and `options.extra_columns`. All problems with the columns are
+    /// reported in a single error.
+    pub fn cast_to_schema(
+        &self,
+        schema: &Schema,
+        options: &CastToSchemaOptions,
+    ) -> PolarsResult<DataFrame> {
+        let mut columns = Vec::with_capacity(schema.len());
+        let mut missing = vec![];
+        let mut mismatched = vec![];
+        for (name, dtype) in schema.iter() {
+            let Some(column) = self.column(name).ok() else {
+                match options.missing_columns {
+                    MissingColumnPolicy::Raise => missing.push(name.as_str()),
+                    MissingColumnPolicy::Insert => {
+                        columns.push(Column::full_null(name.clone(), self.height(), dtype))
+                    },
+                }
+                continue;
+            };
+            if column.dtype() == dtype {
+                columns.push(column.clone());
+                continue;
+            }
+
+            let policy = options
+                .column_casts
+                .get(name)
+                .copied()
+                .unwrap_or(options.cast);
+            let casted = match policy {
+                CastPolicy::NoCast => {
+                    mismatched.push(format!("'{name}': {} != {dtype}", column.dtype()));
+                    continue;
+                },
+                CastPolicy::Strict => column.strict_cast(dtype),
+                CastPolicy::Lossy => column.cast_with_options(dtype, CastOptions::NonStrict),
+            };
+            match casted {
+                Ok(casted) => columns.push(casted),
+                Err(err) => mismatched.push(format!("'{name}': {err}")),
+            }
+        }
+
+        let extra = self
+            .columns
+            .iter()
+            .filter(|c| !schema.contains(c.name()))
+            .collect::<Vec<_>>();
+        match options.extra_columns {
+            ExtraColumnPolicy::Raise if !extra.is_empty() => polars_bail!(
+                SchemaMismatch: "columns {:?} are not in the schema",
+                extra.iter().map(|c| c.name().as_str()).collect::<Vec<_>>()
+            ),
+            ExtraColumnPolicy::Keep => columns.extend(extra.into_iter().cloned()),
+            _ => {},
+        }
+        polars_ensure!(
+            missing.is_empty(),
+            ColumnNotFound: "columns {:?} of the schema are missing", missing
+        );
+        polars_ensure!(
+            mismatched.is_empty(),
+            SchemaMismatch: "cannot cast to the schema:\n{}", mismatched.join("\n")
+        );
+
+        // SAFETY: all columns have the height of `self` and the names are unique.
+        Ok(unsafe { DataFrame::new_no_checks(self.height(), columns) })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cast_to_schema() -> PolarsResult<()> {
+        let df = df![
+            "b" => ["1", "x"],
+            "a" => [1i32, 2],
+            "extra" => [true, false],
+        ]?;
+        let schema = Schema::from_iter([
+            Field::new("a".into(), DataType::Int64),
+            Field::new("b".into(), DataType::Int64),
+            Field::new("c".into(), DataType::String),
+        ]);
+
+        let err = df
+            .cast_to_schema(&schema, &CastToSchemaOptions::default())
+            .unwrap_err();
+        assert!(err.to_string().contains("extra"));
+
+        let options = CastToSchemaOptions::new()
+            .with_extra_columns(ExtraColumnPolicy::Drop)
+            .with_missing_columns(MissingColumnPolicy::Insert);
+        assert!(df.cast_to_schema(&schema, &options).is_err());
+
+        let options = options.with_column_cast("b", CastPolicy::Lossy);
+        let out = df.cast_to_schema(&schema, &options)?;
+        let expected = df![
+            "a" => [1i64, 2],
+            "b" => [Some(1i64), None],
+            "c" => [None::<&str>, None],
+        ]?;
+        assert!(out.equals_missing(&expected));
+
+        let options = options
+            .with_cast(CastPolicy::NoCast)
+            .with_extra_columns(ExtraColumnPolicy::Keep);
+        let err = df.cast_to_schema(&schema, &options).unwrap_err();
+        assert!(err.to_string().contains("'a': i32 != i64"));
+        Ok(())
+    }
+}
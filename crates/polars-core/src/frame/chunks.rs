@@ -67,6 +67,50 @@ impl DataFrame {
         })
     }
 
+    /// Split and merge the chunks of all columns so that every chunk, except possibly
+    /// the last one, has `chunk_size` rows. Afterwards the chunks of all columns are aligned.
+    ///
+    /// Chunks that are larger than `chunk_size` are sliced without copying, only runs of
+    /// smaller chunks are copied into a new chunk. This makes it cheap to consolidate the
+    /// result of appending many small batches.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is zero.
+    pub fn align_chunks_to(&mut self, chunk_size: usize) -> &mut Self {
+        assert!(chunk_size > 0, "chunk size must be positive");
+        let height = self.height();
+        let n_full = height / chunk_size;
+        let remainder = height % chunk_size;
+        let expected =
+            || std::iter::repeat_n(chunk_size, n_full).chain((remainder > 0).then_some(remainder));
+
+        let is_aligned = self.columns.iter().all(|c| match c {
+            Column::Series(s) => s.chunk_lengths().eq(expected()),
+            Column::Partitioned(_) | Column::Scalar(_) => true,
+        });
+        if is_aligned {
+            return self;
+        }
+
+        self.columns = self._apply_columns_par(&|c| match c {
+            Column::Series(s) if !s.chunk_lengths().eq(expected()) => {
+                let mut out = Series::new_empty(s.name().clone(), s.dtype());
+                let mut offset = 0;
+                for len in expected() {
+                    let mut chunk = s.slice(offset as i64, len);
+                    if chunk.n_chunks() > 1 {
+                        chunk = chunk.rechunk();
+                    }
+                    out.append_owned(chunk).unwrap();
+                    offset += len;
+                }
+                out.into_column()
+            },
+            _ => c.clone(),
+        });
+        self
+    }
+
     pub fn split_chunks_by_n(self, n: usize, parallel: bool) -> Vec<DataFrame> {
         let split = _split_offsets(self.height(), n);
 
@@ -746,8 +746,10 @@ impl Column {
     /// Does no bounds checks, groups must be correct.
     #[cfg(feature = "algorithm_group_by")]
     pub unsafe fn agg_sum(&self, groups: &GroupsType) -> Self {
+        let s = self.as_materialized_series();
+        warn_on_dropped_extension_type("sum", &[s]);
         // @scalar-opt
-        unsafe { self.as_materialized_series().agg_sum(groups) }.into()
+        unsafe { s.agg_sum(groups) }.into()
     }
 
     /// # Safety
@@ -5,7 +5,7 @@ use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::POOL;
-use crate::chunked_array::ops::explode::offsets_to_indexes;
+use crate::chunked_array::ops::explode::{offsets_to_indexes, offsets_to_positions};
 use crate::prelude::*;
 use crate::series::IsSorted;
 
@@ -29,14 +29,20 @@ pub struct UnpivotArgsIR {
 }
 
 impl DataFrame {
-    pub fn explode_impl(&self, mut columns: Vec<Column>) -> PolarsResult<DataFrame> {
+    pub fn explode_impl(&self, columns: Vec<Column>) -> PolarsResult<DataFrame> {
+        self.explode_impl_with_position(columns, None)
+    }
+
+    /// Explode `columns` and, if `position` is given, insert a column with that name and the
+    /// position of every value in its list before the first exploded column.
+    pub fn explode_impl_with_position(
+        &self,
+        mut columns: Vec<Column>,
+        position: Option<PlSmallStr>,
+    ) -> PolarsResult<DataFrame> {
         polars_ensure!(!columns.is_empty(), InvalidOperation: "no columns provided in explode");
-        let mut df = self.clone();
-        if self.is_empty() {
-            for s in &columns {
-                df.with_column(s.as_materialized_series().explode(false)?)?;
-            }
-            return Ok(df);
+        if let Some(position) = &position {
+            self.check_already_present(position.as_str())?;
         }
         columns.sort_by(|sa, sb| {
             self.check_name_to_idx(sa.name().as_str())
@@ -49,6 +55,18 @@ impl DataFrame {
                 .expect("cmp usize -> Ordering")
         });
 
+        let mut df = self.clone();
+        if self.is_empty() {
+            for s in &columns {
+                df.with_column(s.as_materialized_series().explode(false)?)?;
+            }
+            if let Some(position) = position {
+                let idx = self.check_name_to_idx(columns[0].name().as_str())?;
+                df.insert_column(idx, IdxCa::full_null(position, df.height()))?;
+            }
+            return Ok(df);
+        }
+
         // first remove all the exploded columns
         for s in &columns {
             df = df.drop(s.name().as_str())?;
@@ -117,9 +135,20 @@ impl DataFrame {
         let mut df = df?;
         result?;
 
+        let positions = position.map(|position| {
+            let (exploded, offsets) = &exploded_columns[0];
+            let idx = self
+                .check_name_to_idx(exploded.name().as_str())
+                .expect("checked above");
+            let positions = offsets_to_positions(offsets.as_slice(), exploded.len());
+            (idx, positions.with_name(position))
+        });
         for (exploded, _) in exploded_columns.into_iter().skip(1) {
             process_column(self, &mut df, exploded)?
         }
+        if let Some((idx, positions)) = positions {
+            df.insert_column(idx, positions)?;
+        }
 
         Ok(df)
     }
@@ -192,6 +221,31 @@ impl DataFrame {
         let columns = self.select_columns(columns)?;
         self.explode_impl(columns)
     }
+
+    /// Explode `columns` like [`DataFrame::explode`], and add a column named `position` with the
+    /// position of every value in its original list.
+    ///
+    /// The position column is inserted before the first exploded column. Empty and null lists
+    /// explode to a single null value, which has a null position. If `row_index` is given, a
+    /// column with that name and the index of the original row is added as the first column.
+    pub fn explode_with_position<I, S>(
+        &self,
+        columns: I,
+        position: PlSmallStr,
+        row_index: Option<PlSmallStr>,
+    ) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        let columns = self.select_columns(columns)?;
+        match row_index {
+            Some(name) => self
+                .with_row_index(name, None)?
+                .explode_impl_with_position(columns, Some(position)),
+            None => self.explode_impl_with_position(columns, Some(position)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -305,4 +359,24 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_explode_with_position() -> PolarsResult<()> {
+        let s0 = Series::new(PlSmallStr::from_static("a"), &[1i32, 2, 3]);
+        let s1 = Series::new(PlSmallStr::from_static("b"), Vec::<i32>::new());
+        let list = Column::new(PlSmallStr::from_static("foo"), &[s0.clone(), s1, s0]);
+        let df = DataFrame::new(vec![Column::new("B".into(), [1, 2, 3]), list])?;
+
+        let out = df.explode_with_position(["foo"], "pos".into(), Some("row".into()))?;
+        let expected = df![
+            "row" => [0 as IdxSize, 0, 0, 1, 2, 2, 2],
+            "B" => [1, 1, 1, 2, 3, 3, 3],
+            "pos" => [Some(0 as IdxSize), Some(1), Some(2), None, Some(0), Some(1), Some(2)],
+            "foo" => [Some(1), Some(2), Some(3), None, Some(1), Some(2), Some(3)],
+        ]?;
+        assert!(out.equals_missing(&expected));
+        assert!(df.explode_with_position(["foo"], "B".into(), None).is_err());
+        Ok(())
+    }
 }
@@ -123,6 +123,7 @@ impl Series {
 
     #[doc(hidden)]
     pub unsafe fn agg_mean(&self, groups: &GroupsType) -> Series {
+        warn_on_dropped_extension_type("mean", &[self]);
         // Prevent a rechunk for every individual group.
         let s = if groups.len() > 1 {
             self.rechunk()
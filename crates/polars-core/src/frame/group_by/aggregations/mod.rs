@@ -49,6 +49,11 @@ fn idx2usize(idx: &[IdxSize]) -> impl ExactSizeIterator<Item = usize> + '_ {
 // if the windows don't overlap, we should not use these kernels as they are single threaded, so
 // we miss out on easy parallelization.
 pub fn _use_rolling_kernels(groups: &GroupsSlice, chunks: &[ArrayRef]) -> bool {
+    _groups_overlap(groups) && chunks.len() == 1
+}
+
+/// Whether the slices are the overlapping windows of a rolling group-by.
+pub fn _groups_overlap(groups: &GroupsSlice) -> bool {
     match groups.len() {
         0 | 1 => false,
         _ => {
@@ -58,7 +63,6 @@ pub fn _use_rolling_kernels(groups: &GroupsSlice, chunks: &[ArrayRef]) -> bool {
             second_offset >= first_offset // Prevent false positive from regular group-by that has out of order slices.
                                           // Rolling group-by is expected to have monotonically increasing slices.
                 && second_offset < (first_offset + first_len)
-                && chunks.len() == 1
         },
     }
 }
@@ -296,7 +300,11 @@ where
             })
         },
         GroupsType::Slice { groups, .. } => {
-            if _use_rolling_kernels(groups, ca.chunks()) {
+            // The quantile of every window on its own is linear in the window size, so for
+            // overlapping windows rechunking to use the rolling kernel, which keeps the window
+            // in an indexable skiplist, is cheaper than selecting every window.
+            if _groups_overlap(groups) {
+                let ca = ca.rechunk();
                 // this cast is a no-op for floats
                 let s = ca
                     .cast_with_options(&K::get_static_dtype(), CastOptions::Overflowing)
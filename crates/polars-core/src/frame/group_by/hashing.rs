@@ -8,6 +8,7 @@ use polars_utils::unitvec;
 use rayon::prelude::*;
 
 use crate::POOL;
+use crate::config::get_group_by_radix_threshold;
 use crate::hashing::*;
 use crate::prelude::*;
 use crate::utils::flatten;
@@ -110,6 +111,36 @@ where
     GroupsType::Idx(GroupsIdx::new(first, groups, sorted))
 }
 
+const CARDINALITY_SAMPLE_SIZE: usize = 1024;
+
+/// Estimate the fraction of distinct keys from an evenly spaced sample of the keys.
+fn sample_distinct_fraction<T, IntoSlice>(keys: &[IntoSlice]) -> f64
+where
+    T: ToTotalOrd,
+    IntoSlice: AsRef<[T]>,
+{
+    let len: usize = keys.iter().map(|k| k.as_ref().len()).sum();
+    if len == 0 {
+        return 0.0;
+    }
+    let step = (len / CARDINALITY_SAMPLE_SIZE).max(1);
+
+    let mut seen = PlHashSet::with_capacity(CARDINALITY_SAMPLE_SIZE.min(len));
+    let mut n_sampled = 0usize;
+    let mut next = 0;
+    let mut offset = 0;
+    for keys in keys {
+        let keys = keys.as_ref();
+        while next < offset + keys.len() {
+            seen.insert(keys[next - offset].to_total_ord());
+            n_sampled += 1;
+            next += step;
+        }
+        offset += keys.len();
+    }
+    seen.len() as f64 / n_sampled as f64
+}
+
 // giving the slice info to the compiler is much
 // faster than the using an iterator, that's why we
 // have the code duplication
@@ -119,10 +150,14 @@ pub(crate) fn group_by_threaded_slice<T, IntoSlice>(
     sorted: bool,
 ) -> GroupsType
 where
-    T: ToTotalOrd,
+    T: ToTotalOrd + Sync,
     <T as ToTotalOrd>::TotalOrdItem: Send + Sync + Copy + DirtyHash,
     IntoSlice: AsRef<[T]> + Send + Sync,
 {
+    if n_partitions > 1 && sample_distinct_fraction(&keys) > get_group_by_radix_threshold() {
+        return group_by_radix_slice(keys, n_partitions, sorted);
+    }
+
     let init_size = get_init_size();
 
     // We will create a hashtable in every thread.
@@ -166,6 +201,82 @@ where
     finish_group_order(out, sorted)
 }
 
+/// Two-phase group-by for keys with a high cardinality.
+///
+/// First every thread scatters the keys of a contiguous morsel into a local buffer
+/// per partition. Then every thread builds the hash table of a single partition
+/// from the buffers of all morsels. Compared to [`group_by_threaded_slice`], every
+/// key is read and hashed only once and the hash tables can be allocated up front,
+/// as the number of keys in a partition is known.
+pub(crate) fn group_by_radix_slice<T, IntoSlice>(
+    keys: Vec<IntoSlice>,
+    n_partitions: usize,
+    sorted: bool,
+) -> GroupsType
+where
+    T: ToTotalOrd + Sync,
+    <T as ToTotalOrd>::TotalOrdItem: Send + Sync + Copy + DirtyHash,
+    IntoSlice: AsRef<[T]> + Send + Sync,
+{
+    let len: usize = keys.iter().map(|k| k.as_ref().len()).sum();
+    let morsel_size = len.div_ceil(n_partitions).max(1);
+
+    let mut morsels = Vec::with_capacity(n_partitions + keys.len());
+    let mut offset = 0 as IdxSize;
+    for keys in &keys {
+        for morsel in keys.as_ref().chunks(morsel_size) {
+            morsels.push((offset, morsel));
+            offset += morsel.len() as IdxSize;
+        }
+    }
+
+    let partitioned = POOL.install(|| {
+        morsels
+            .into_par_iter()
+            .map(|(offset, keys)| {
+                let mut partitions = (0..n_partitions)
+                    .map(|_| Vec::with_capacity(keys.len() / n_partitions + 1))
+                    .collect::<Vec<_>>();
+                for (key_idx, k) in keys.iter().enumerate_idx() {
+                    let k = k.to_total_ord();
+                    let partition = hash_to_partition(k.dirty_hash(), n_partitions);
+                    unsafe { partitions.get_unchecked_mut(partition) }.push((key_idx + offset, k));
+                }
+                partitions
+            })
+            .collect::<Vec<_>>()
+    });
+
+    // The morsels are in order, so within a partition the indices are ascending
+    // and the first index we see of a key is the first index of its group.
+    let out = POOL.install(|| {
+        (0..n_partitions)
+            .into_par_iter()
+            .map(|partition| {
+                let size = partitioned.iter().map(|p| p[partition].len()).sum();
+                let mut hash_tbl = PlHashMap::with_capacity(size);
+                for partitions in &partitioned {
+                    for &(idx, k) in &partitions[partition] {
+                        match hash_tbl.entry(k) {
+                            Entry::Vacant(entry) => {
+                                entry.insert((idx, unitvec![idx]));
+                            },
+                            Entry::Occupied(mut entry) => {
+                                entry.get_mut().1.push(idx);
+                            },
+                        }
+                    }
+                }
+                hash_tbl
+                    .into_iter()
+                    .map(|(_k, v)| v)
+                    .collect_trusted::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    });
+    finish_group_order(out, sorted)
+}
+
 pub(crate) fn group_by_threaded_iter<T, I>(
     keys: &[I],
     n_partitions: usize,
@@ -229,3 +340,34 @@ where
     });
     finish_group_order(out, sorted)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn to_sorted(groups: GroupsType) -> Vec<(IdxSize, Vec<IdxSize>)> {
+        let mut out = groups
+            .unwrap_idx()
+            .iter()
+            .map(|(first, all)| (first, all.to_vec()))
+            .collect::<Vec<_>>();
+        out.sort_unstable();
+        out
+    }
+
+    #[test]
+    fn test_group_by_radix_slice() {
+        let a: Vec<u64> = (0..3000).map(|i| (i * 7919) % 1000).collect();
+        let b: Vec<u64> = (0..1234).map(|i| i % 1500).collect();
+        let expected = to_sorted(group_by(a.iter().chain(b.iter()), false));
+
+        for sorted in [false, true] {
+            let keys = vec![a.as_slice(), b.as_slice()];
+            let out = group_by_radix_slice(keys, 4, sorted);
+            if sorted {
+                assert!(out.unwrap_idx().first().is_sorted());
+            }
+            assert_eq!(to_sorted(out), expected);
+        }
+    }
+}
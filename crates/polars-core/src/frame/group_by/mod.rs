@@ -26,6 +26,36 @@ use crate::chunked_array::ops::row_encode::{
     encode_rows_unordered, encode_rows_vertical_par_unordered,
 };
 
+/// The `[first, len]` slices of the runs of equal values in `by`.
+pub(crate) fn sorted_key_runs(by: &[Column]) -> PolarsResult<GroupsSlice> {
+    let n = by[0].len();
+    if n == 0 {
+        return Ok(vec![]);
+    }
+
+    let mut boundaries: Option<BooleanChunked> = None;
+    for s in by {
+        let s = s.as_materialized_series();
+        let changed = s.slice(1, n - 1).not_equal_missing(&s.slice(0, n - 1))?;
+        boundaries = Some(match boundaries {
+            Some(b) => &b | &changed,
+            None => changed,
+        });
+    }
+
+    let mut groups = vec![];
+    let mut start = 0 as IdxSize;
+    for (i, changed) in boundaries.unwrap().iter().enumerate() {
+        if changed.unwrap_or(false) {
+            let end = i as IdxSize + 1;
+            groups.push([start, end - start]);
+            start = end;
+        }
+    }
+    groups.push([start, n as IdxSize - start]);
+    Ok(groups)
+}
+
 impl DataFrame {
     pub fn group_by_with_series(
         &self,
@@ -104,6 +134,31 @@ impl DataFrame {
         Ok(GroupBy::new(self, by, groups?.into_sliceable(), None))
     }
 
+    /// Group by keys of which equal values are contiguous, e.g. because the [`DataFrame`] is
+    /// sorted by them. The groups are the runs of equal keys, in order of appearance.
+    ///
+    /// # Safety
+    /// Equal keys must be contiguous. This is not checked: keys that are not contiguous give a
+    /// group per run, so a key can have multiple groups.
+    pub unsafe fn group_by_sorted_keys(&self, by: Vec<Column>) -> PolarsResult<GroupBy> {
+        polars_ensure!(
+            !by.is_empty(),
+            ComputeError: "at least one key is required in a group_by operation"
+        );
+        if by.iter().any(|s| s.dtype().is_object()) {
+            return self.group_by_with_series(by, true, true);
+        }
+        polars_ensure!(
+            self.width() == 0 || by.iter().all(|s| s.len() == self.height()),
+            ShapeMismatch: "series used as keys should have the same length as the DataFrame"
+        );
+        let groups = GroupsType::Slice {
+            groups: sorted_key_runs(&by)?,
+            rolling: false,
+        };
+        Ok(GroupBy::new(self, by, groups.into_sliceable(), None))
+    }
+
     /// Group DataFrame using a Series column.
     ///
     /// # Example
@@ -874,6 +929,9 @@ pub enum GroupByMethod {
     First,
     Last,
     Sum,
+    Product,
+    GeoMean,
+    HarmonicMean,
     Groups,
     NUnique,
     Quantile(f64, QuantileMethod),
@@ -896,6 +954,9 @@ impl Display for GroupByMethod {
             First => "first",
             Last => "last",
             Sum => "sum",
+            Product => "product",
+            GeoMean => "geo_mean",
+            HarmonicMean => "harmonic_mean",
             Groups => "groups",
             NUnique => "n_unique",
             Quantile(_, _) => "quantile",
@@ -921,6 +982,9 @@ pub fn fmt_group_by_column(name: &str, method: GroupByMethod) -> PlSmallStr {
         First => format_pl_smallstr!("{name}_first"),
         Last => format_pl_smallstr!("{name}_last"),
         Sum => format_pl_smallstr!("{name}_sum"),
+        Product => format_pl_smallstr!("{name}_product"),
+        GeoMean => format_pl_smallstr!("{name}_geo_mean"),
+        HarmonicMean => format_pl_smallstr!("{name}_harmonic_mean"),
         Groups => PlSmallStr::from_static("groups"),
         NUnique => format_pl_smallstr!("{name}_n_unique"),
         Count { .. } => format_pl_smallstr!("{name}_count"),
@@ -1207,4 +1271,41 @@ mod test {
         let _ = df.group_by(["g"])?.sum()?;
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_group_by_sorted_keys() -> PolarsResult<()> {
+        let df = df![
+            "a" => [Some(1), Some(1), Some(1), Some(2), None, None],
+            "b" => ["x", "x", "y", "y", "y", "y"],
+            "c" => [1, 2, 3, 4, 5, 6],
+        ]?;
+
+        let keys = df.select_columns(["a", "b"])?;
+        let gb = unsafe { df.group_by_sorted_keys(keys)? };
+        assert_eq!(gb.get_groups().len(), 4);
+        // Use of deprecated `first()` for testing purposes
+        #[allow(deprecated)]
+        let out = gb.select(["c"]).first()?;
+        let expected = df![
+            "a" => [Some(1), Some(1), Some(2), None],
+            "b" => ["x", "y", "y", "y"],
+            "c_first" => [1, 3, 4, 5],
+        ]?;
+        assert!(out.equals_missing(&expected));
+
+        let subset = Some(vec!["a".into()]);
+        let out = unsafe { df.unique_sorted_keys(subset, UniqueKeepStrategy::Last, None)? };
+        assert_eq!(
+            Vec::from(out.column("c")?.i32()?),
+            &[Some(3), Some(4), Some(6)]
+        );
+        let subset = Some(vec!["a".into(), "b".into()]);
+        let out = unsafe { df.unique_sorted_keys(subset.clone(), UniqueKeepStrategy::None, None)? };
+        assert_eq!(Vec::from(out.column("c")?.i32()?), &[Some(3), Some(4)]);
+        let out =
+            unsafe { df.unique_sorted_keys(subset, UniqueKeepStrategy::First, Some((1, 2)))? };
+        assert_eq!(Vec::from(out.column("c")?.i32()?), &[Some(3), Some(4)]);
+        Ok(())
+    }
 }
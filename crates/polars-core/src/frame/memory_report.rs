@@ -0,0 +1,228 @@
+//! Buffer level memory usage of a [`DataFrame`], see [`DataFrame::memory_report`].
+use std::fmt::{Display, Formatter};
+
+use arrow::compute::aggregate::{BufferInfo, BufferKind, visit_buffers};
+
+use crate::prelude::*;
+
+/// Size in bytes of the buffers of a single chunk.
+///
+/// Sizes are those of the underlying allocations, so a sliced chunk reports the full size of
+/// the buffers it keeps alive.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChunkMemory {
+    pub values: usize,
+    pub validity: usize,
+    /// Offsets of (large) binary, string and list arrays, and union offsets and type ids.
+    pub offsets: usize,
+    /// Views of binary view and string arrays.
+    pub views: usize,
+    /// Values of dictionary arrays.
+    pub dictionary: usize,
+    /// Bytes of the buffers above that are also referenced by other chunks or columns.
+    pub shared: usize,
+}
+
+impl ChunkMemory {
+    pub fn total_bytes(&self) -> usize {
+        self.values + self.validity + self.offsets + self.views + self.dictionary
+    }
+
+    fn add(&mut self, info: &BufferInfo, shared: bool) {
+        let bytes = info.storage_bytes;
+        match info.kind {
+            BufferKind::Values => self.values += bytes,
+            BufferKind::Validity => self.validity += bytes,
+            BufferKind::Offsets | BufferKind::Types => self.offsets += bytes,
+            BufferKind::Views => self.views += bytes,
+            BufferKind::Dictionary => self.dictionary += bytes,
+        }
+        if shared {
+            self.shared += bytes;
+        }
+    }
+}
+
+/// Memory usage of a single column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnMemory {
+    pub name: PlSmallStr,
+    pub dtype: DataType,
+    pub chunks: Vec<ChunkMemory>,
+    /// Bytes of the categories of a categorical or enum column. These are shared by all
+    /// chunks and often by other columns.
+    pub categories: usize,
+}
+
+impl ColumnMemory {
+    pub fn total_bytes(&self) -> usize {
+        self.chunks
+            .iter()
+            .map(ChunkMemory::total_bytes)
+            .sum::<usize>()
+            + self.categories
+    }
+
+    pub fn shared_bytes(&self) -> usize {
+        self.chunks.iter().map(|c| c.shared).sum()
+    }
+}
+
+/// Memory usage of a [`DataFrame`] per column and per chunk.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MemoryReport {
+    pub columns: Vec<ColumnMemory>,
+    /// Sum of the sizes of all buffers. Buffers referenced more than once are counted every time.
+    pub total_bytes: usize,
+    /// Sum of the sizes of the distinct allocations referenced by the [`DataFrame`].
+    pub unique_bytes: usize,
+}
+
+impl Display for MemoryReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name_width = self
+            .columns
+            .iter()
+            .map(|c| c.name.len())
+            .max()
+            .unwrap_or(0)
+            .max("column".len());
+        writeln!(
+            f,
+            "{:<name_width$}  {:>8}  {:>14}  {:>14}",
+            "column", "chunks", "bytes", "shared bytes"
+        )?;
+        for column in &self.columns {
+            writeln!(
+                f,
+                "{:<name_width$}  {:>8}  {:>14}  {:>14}",
+                column.name,
+                column.chunks.len(),
+                column.total_bytes(),
+                column.shared_bytes()
+            )?;
+        }
+        write!(
+            f,
+            "total bytes: {}, unique bytes: {}",
+            self.total_bytes, self.unique_bytes
+        )
+    }
+}
+
+fn column_buffers(column: &Column) -> (Vec<Vec<BufferInfo>>, Vec<BufferInfo>) {
+    let series = match column {
+        Column::Series(s) => Series::clone(s),
+        Column::Partitioned(s) => s
+            .lazy_as_materialized_series()
+            .cloned()
+            .unwrap_or_else(|| s.partitions().clone()),
+        Column::Scalar(s) => s
+            .lazy_as_materialized_series()
+            .cloned()
+            .unwrap_or_else(|| s.as_single_value_series()),
+    };
+
+    let chunks = series
+        .chunks()
+        .iter()
+        .map(|arr| {
+            let mut buffers = vec![];
+            visit_buffers(arr.as_ref(), &mut |info| buffers.push(info));
+            buffers
+        })
+        .collect();
+
+    #[allow(unused_mut)]
+    let mut categories = vec![];
+    #[cfg(feature = "dtype-categorical")]
+    if let Ok(ca) = series.categorical() {
+        visit_buffers(ca.get_rev_map().get_categories(), &mut |info| {
+            categories.push(BufferInfo {
+                kind: BufferKind::Dictionary,
+                ..info
+            })
+        });
+    }
+    (chunks, categories)
+}
+
+impl DataFrame {
+    /// Get the size of the buffers of every chunk of every column.
+    ///
+    /// Unlike [`DataFrame::estimated_size`], this reports the allocations that are kept alive
+    /// by the [`DataFrame`], and detects buffers that are shared between chunks and columns,
+    /// e.g. after slicing or selecting a column twice.
+    ///
+    /// Columns that are not materialized are reported by the data they actually hold, e.g. a
+    /// single value for a scalar column.
+    pub fn memory_report(&self) -> MemoryReport {
+        let buffers = self.columns.iter().map(column_buffers).collect::<Vec<_>>();
+
+        // Count the references to every allocation.
+        let mut refs = PlHashMap::<usize, (usize, usize)>::new();
+        let all_buffers = buffers
+            .iter()
+            .flat_map(|(chunks, categories)| chunks.iter().flatten().chain(categories));
+        for info in all_buffers.filter(|info| info.storage_bytes > 0) {
+            refs.entry(info.storage_address)
+                .or_insert((0, info.storage_bytes))
+                .0 += 1;
+        }
+        let is_shared =
+            |info: &BufferInfo| refs.get(&info.storage_address).is_some_and(|r| r.0 > 1);
+
+        let columns = self
+            .columns
+            .iter()
+            .zip(&buffers)
+            .map(|(column, (chunks, categories))| ColumnMemory {
+                name: column.name().clone(),
+                dtype: column.dtype().clone(),
+                chunks: chunks
+                    .iter()
+                    .map(|buffers| {
+                        let mut chunk = ChunkMemory::default();
+                        for info in buffers {
+                            chunk.add(info, is_shared(info));
+                        }
+                        chunk
+                    })
+                    .collect(),
+                categories: categories.iter().map(|info| info.storage_bytes).sum(),
+            })
+            .collect::<Vec<_>>();
+
+        MemoryReport {
+            total_bytes: columns.iter().map(ColumnMemory::total_bytes).sum(),
+            unique_bytes: refs.values().map(|(_, bytes)| bytes).sum(),
+            columns,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_memory_report() -> PolarsResult<()> {
+        let a = Column::new("a".into(), (0..100i64).collect::<Vec<_>>());
+        let b = Column::new("b".into(), [Some("x"), None, Some("a longer string value")]);
+        let df = DataFrame::new(vec![a.clone(), a.with_name("a2".into())])?;
+
+        let report = df.memory_report();
+        assert_eq!(report.columns.len(), 2);
+        assert_eq!(report.columns[0].chunks[0].values, 100 * size_of::<i64>());
+        assert_eq!(report.columns[0].shared_bytes(), 100 * size_of::<i64>());
+        assert_eq!(report.total_bytes, 2 * 100 * size_of::<i64>());
+        assert_eq!(report.unique_bytes, 100 * size_of::<i64>());
+
+        let report = DataFrame::new(vec![b])?.memory_report();
+        let chunk = &report.columns[0].chunks[0];
+        assert!(chunk.validity > 0 && chunk.views > 0 && chunk.values > 0);
+        assert_eq!(chunk.shared, 0);
+        assert_eq!(report.total_bytes, report.unique_bytes);
+        Ok(())
+    }
+}
@@ -21,6 +21,7 @@ use crate::{HEAD_DEFAULT_LENGTH, TAIL_DEFAULT_LENGTH};
 #[cfg(feature = "dataframe_arithmetic")]
 mod arithmetic;
 pub mod builder;
+pub mod cast_to_schema;
 mod chunks;
 pub use chunks::chunk_df_for_writing;
 pub mod column;
@@ -29,6 +30,9 @@ mod from;
 #[cfg(feature = "algorithm_group_by")]
 pub mod group_by;
 pub(crate) mod horizontal;
+pub mod memory_report;
+#[cfg(feature = "proptest")]
+pub mod proptest;
 #[cfg(any(feature = "rows", feature = "object"))]
 pub mod row;
 mod top_k;
@@ -1173,6 +1177,43 @@ impl DataFrame {
         Ok(self)
     }
 
+    /// Concatenate a [`DataFrame`] to this [`DataFrame`] like [`vstack_mut`](Self::vstack_mut),
+    /// extending the categories of `Enum` columns according to `policy`.
+    ///
+    /// With [`EnumAppendPolicy::Extend`] a long-lived frame can absorb rows with new categories
+    /// without recasting the rows it already holds.
+    #[cfg(feature = "dtype-categorical")]
+    pub fn vstack_mut_with_policy(
+        &mut self,
+        other: &DataFrame,
+        policy: EnumAppendPolicy,
+    ) -> PolarsResult<&mut Self> {
+        if self.width() != other.width() || policy == EnumAppendPolicy::Strict {
+            return self.vstack_mut(other);
+        }
+
+        self.columns
+            .iter_mut()
+            .zip(other.columns.iter())
+            .try_for_each::<_, PolarsResult<_>>(|(left, right)| {
+                ensure_can_extend(&*left, right)?;
+                let context = |e: PolarsError| {
+                    e.context(format!("failed to vstack column '{}'", right.name()).into())
+                };
+                if matches!(left.dtype(), DataType::Enum(_, _)) {
+                    let mut s = left.as_materialized_series().clone();
+                    s.append_with_policy(right.as_materialized_series(), policy)
+                        .map_err(context)?;
+                    *left = s.into_column();
+                } else {
+                    left.append(right).map_err(context)?;
+                }
+                Ok(())
+            })?;
+        self.height += other.height;
+        Ok(self)
+    }
+
     pub fn vstack_mut_owned(&mut self, other: DataFrame) -> PolarsResult<&mut Self> {
         if self.width() != other.width() {
             polars_ensure!(
@@ -1237,6 +1278,26 @@ impl DataFrame {
         self.height += other.height;
     }
 
+    /// Concatenate a [`DataFrame`] to this [`DataFrame`] and merge the trailing chunks of every
+    /// column according to `policy`.
+    ///
+    /// Prefer this over [`vstack_mut`](Self::vstack_mut) when appending small frames at a high
+    /// frequency, e.g. when accumulating events, and finish with
+    /// [`as_single_chunk_par`](Self::as_single_chunk_par) before running heavy queries.
+    /// See [`CompactionPolicy`].
+    pub fn vstack_mut_compacting(
+        &mut self,
+        other: &DataFrame,
+        policy: CompactionPolicy,
+    ) -> PolarsResult<&mut Self> {
+        self.vstack_mut(other)?;
+        // SAFETY: compacting changes neither the length, the names nor the dtypes.
+        for c in unsafe { self.get_columns_mut() } {
+            c.into_materialized_series().compact_with(policy);
+        }
+        Ok(self)
+    }
+
     /// Extend the memory backed by this [`DataFrame`] with the values from `other`.
     ///
     /// Different from [`vstack`](Self::vstack) which adds the chunks from `other` to the chunks of this [`DataFrame`]
@@ -2334,6 +2395,50 @@ impl DataFrame {
         Ok(df)
     }
 
+    /// Sort the rows within every run of equal values in `partition_by`, keeping the runs in
+    /// place.
+    ///
+    /// This is cheaper than a full sort when the consumer of the data only needs the rows to be
+    /// ordered per partition, e.g. when writing a file per partition. At least one partition
+    /// column is required, use [`DataFrame::sort`] for a full sort.
+    #[cfg(feature = "algorithm_group_by")]
+    pub fn sort_within_partitions(
+        &self,
+        partition_by: impl IntoVec<PlSmallStr>,
+        by: impl IntoVec<PlSmallStr>,
+        sort_options: SortMultipleOptions,
+    ) -> PolarsResult<Self> {
+        let partition_by = self.select_columns(partition_by)?;
+        polars_ensure!(
+            !partition_by.is_empty(),
+            InvalidOperation: "sort_within_partitions needs a column to partition by"
+        );
+        let by: Vec<PlSmallStr> = by.into_vec();
+        let partitions = group_by::sorted_key_runs(&partition_by)?
+            .into_iter()
+            .map(|[first, len]| self.slice(first as i64, len as usize))
+            .collect::<Vec<_>>();
+        if partitions.len() <= 1 {
+            return self.sort(by, sort_options);
+        }
+
+        let sort_partition = |df: &DataFrame| df.sort(by.clone(), sort_options.clone());
+        let dfs = if sort_options.multithreaded {
+            POOL.install(|| {
+                partitions
+                    .par_iter()
+                    .map(sort_partition)
+                    .collect::<PolarsResult<Vec<_>>>()
+            })?
+        } else {
+            partitions
+                .iter()
+                .map(sort_partition)
+                .collect::<PolarsResult<Vec<_>>>()?
+        };
+        Ok(crate::utils::accumulate_dataframes_vertical_unchecked(dfs))
+    }
+
     /// Replace a column with a [`Series`].
     ///
     /// # Example
@@ -3070,6 +3175,46 @@ impl DataFrame {
         Ok(unsafe { DataFrame::new_no_checks(height, columns) })
     }
 
+    /// Drop duplicate rows like [`DataFrame::unique_stable`], for a `subset` of which equal
+    /// values are contiguous, e.g. because the [`DataFrame`] is sorted by it. This compares
+    /// neighbouring rows instead of hashing, and maintains the order.
+    ///
+    /// # Safety
+    /// Equal values of `subset` must be contiguous. This is not checked: duplicates that are not
+    /// contiguous are not removed.
+    #[cfg(feature = "algorithm_group_by")]
+    pub unsafe fn unique_sorted_keys(
+        &self,
+        subset: Option<Vec<PlSmallStr>>,
+        keep: UniqueKeepStrategy,
+        slice: Option<(i64, usize)>,
+    ) -> PolarsResult<Self> {
+        let names = subset.unwrap_or_else(|| self.get_column_names_owned());
+        let mut df = self.clone();
+        df.as_single_chunk_par();
+        if df.is_empty() {
+            return Ok(df);
+        }
+
+        let mut runs = group_by::sorted_key_runs(&df.select_columns(names)?)?;
+        if keep == UniqueKeepStrategy::None {
+            runs.retain(|[_, len]| *len == 1);
+        }
+        let groups = GroupsType::Slice {
+            groups: runs,
+            rolling: false,
+        }
+        .into_sliceable();
+        let (offset, len) = slice.unwrap_or((0, groups.len()));
+        let groups = groups.slice(offset, len);
+        let columns = match keep {
+            UniqueKeepStrategy::Last => df._apply_columns_par(&|s| unsafe { s.agg_last(&groups) }),
+            _ => df._apply_columns_par(&|s| unsafe { s.agg_first(&groups) }),
+        };
+        let height = Self::infer_height(&columns);
+        Ok(unsafe { DataFrame::new_no_checks(height, columns) })
+    }
+
     /// Get a mask of all the unique rows in the [`DataFrame`].
     ///
     /// # Example
@@ -3558,6 +3703,23 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_align_chunks_to() -> PolarsResult<()> {
+        let mut df = df!("a" => [1, 2, 3], "b" => ["a", "b", "c"])?;
+        for i in 0..4 {
+            df.vstack_mut(&df!("a" => [i], "b" => ["x"])?)?;
+        }
+        df.vstack_mut(&df!("a" => 0..10, "b" => ["y"; 10])?)?;
+        let expected = df.clone();
+
+        df.align_chunks_to(4);
+        assert!(df.equals(&expected));
+        assert!(!df.should_rechunk());
+        let lengths = df.column("a")?.as_materialized_series().chunk_lengths();
+        assert_eq!(lengths.collect::<Vec<_>>(), &[4, 4, 4, 4, 1]);
+        Ok(())
+    }
+
     #[test]
     fn test_duplicate_column() {
         let mut df = df! {
@@ -3661,4 +3823,36 @@ mod test {
         .unwrap();
         assert!(out.equals(&expected));
     }
+
+    #[test]
+    #[cfg(feature = "algorithm_group_by")]
+    fn test_sort_within_partitions() -> PolarsResult<()> {
+        let df = df! {
+            "p" => ["a", "a", "a", "b", "b", "a"],
+            "x" => [3, 1, 2, 5, 4, 0],
+        }?;
+        let out = df.sort_within_partitions(["p"], ["x"], Default::default())?;
+        let expected = df! {
+            "p" => ["a", "a", "a", "b", "b", "a"],
+            "x" => [1, 2, 3, 4, 5, 0],
+        }?;
+        assert!(out.equals(&expected));
+
+        let out = df.sort_within_partitions(
+            ["p"],
+            ["x"],
+            SortMultipleOptions::new().with_order_descending(true),
+        )?;
+        assert_eq!(
+            Vec::from(out.column("x")?.i32()?),
+            &[Some(3), Some(2), Some(1), Some(5), Some(4), Some(0)]
+        );
+
+        // Without partition columns the result would depend on the chunking of the input.
+        assert!(
+            df.sort_within_partitions(Vec::<PlSmallStr>::new(), ["x"], Default::default())
+                .is_err()
+        );
+        Ok(())
+    }
 }
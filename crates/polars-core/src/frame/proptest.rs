@@ -0,0 +1,71 @@
+//! [`proptest`] strategies that generate [`DataFrame`]s.
+use std::ops::RangeInclusive;
+
+use arrow::array::proptest::arrow_data_type;
+use polars_utils::format_pl_smallstr;
+use proptest::prelude::*;
+
+use crate::prelude::*;
+use crate::series::proptest::{SeriesArbitraryOptions, series_with_dtype};
+
+#[derive(Clone, Debug)]
+pub struct DataFrameArbitraryOptions {
+    pub num_columns_range: RangeInclusive<usize>,
+    /// Options for every column. The height of the frame is taken from `size_range`.
+    pub series: SeriesArbitraryOptions,
+}
+
+impl Default for DataFrameArbitraryOptions {
+    fn default() -> Self {
+        Self {
+            num_columns_range: 1..=5,
+            series: Default::default(),
+        }
+    }
+}
+
+/// Generate a [`DataFrame`] with the columns `col_0`, `col_1`, ...
+pub fn dataframe(options: DataFrameArbitraryOptions) -> impl Strategy<Value = DataFrame> {
+    let dtypes = proptest::collection::vec(
+        arrow_data_type(options.series.dtype.clone()),
+        options.num_columns_range.clone(),
+    );
+    (dtypes, options.series.size_range.clone()).prop_flat_map(move |(dtypes, height)| {
+        let series_options = SeriesArbitraryOptions {
+            size_range: height..=height,
+            ..options.series.clone()
+        };
+        dtypes
+            .into_iter()
+            .enumerate()
+            .map(|(i, dtype)| {
+                series_with_dtype(format_pl_smallstr!("col_{i}"), dtype, &series_options)
+            })
+            .collect::<Vec<_>>()
+            .prop_map(|columns| {
+                DataFrame::new(columns.into_iter().map(Column::from).collect()).unwrap()
+            })
+    })
+}
+
+impl Arbitrary for DataFrame {
+    type Parameters = DataFrameArbitraryOptions;
+    type Strategy = BoxedStrategy<DataFrame>;
+
+    fn arbitrary_with(options: Self::Parameters) -> Self::Strategy {
+        dataframe(options).boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn test_arbitrary_dataframe(df in any::<DataFrame>()) {
+            prop_assert!((1..=5).contains(&df.width()));
+            prop_assert!(df.get_columns().iter().all(|c| c.len() == df.height()));
+        }
+    }
+}
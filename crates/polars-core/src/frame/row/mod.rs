@@ -1,6 +1,7 @@
 mod av_buffer;
 mod dataframe;
 mod transpose;
+mod typed;
 
 use std::borrow::Borrow;
 use std::fmt::Debug;
@@ -13,6 +14,7 @@ use polars_utils::format_pl_smallstr;
 #[cfg(feature = "object")]
 use polars_utils::total_ord::TotalHash;
 use rayon::prelude::*;
+pub use typed::{FromColumnValue, FromRow, TypedRows, ValueCursor};
 
 use crate::POOL;
 use crate::prelude::*;
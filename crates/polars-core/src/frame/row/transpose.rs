@@ -165,6 +165,171 @@ impl DataFrame {
         }
         df.transpose_from_dtype(&dtype, keep_names_as.map(PlSmallStr::from_str), &names_out)
     }
+
+    /// Transpose a DataFrame without casting the values to a common supertype.
+    ///
+    /// Every row becomes a struct column with a field per distinct dtype of the columns, named
+    /// after the dtype. Every column becomes a row in which only the field of its own dtype is
+    /// set. The names of the columns are stored in `names_column` and the names of the fields
+    /// that hold their values in `dtype_column`, so that
+    /// [`DataFrame::transpose_typed_inverse`] restores the original DataFrame.
+    ///
+    /// If `header` is given, the output columns are named after the values of that column,
+    /// otherwise they are named `column_0`, `column_1`, ...
+    #[cfg(feature = "dtype-struct")]
+    pub fn transpose_typed(
+        &self,
+        header: Option<&str>,
+        names_column: &str,
+        dtype_column: &str,
+    ) -> PolarsResult<DataFrame> {
+        let (df, names_out) = match header {
+            None => (
+                Cow::Borrowed(self),
+                (0..self.height())
+                    .map(|i| format_pl_smallstr!("column_{i}"))
+                    .collect::<Vec<_>>(),
+            ),
+            Some(header) => {
+                let names = self.column(header)?.cast(&DataType::String)?;
+                let names = names.str()?;
+                polars_ensure!(names.null_count() == 0, ComputeError: "column with new names can't have null values");
+                let names = names
+                    .into_no_null_iter()
+                    .map(PlSmallStr::from_str)
+                    .collect();
+                (Cow::Owned(self.drop(header)?), names)
+            },
+        };
+        polars_ensure!(
+            df.height() != 0 && df.width() != 0,
+            NoData: "unable to transpose an empty DataFrame"
+        );
+        polars_ensure!(
+            !names_out.iter().any(|n| n == names_column || n == dtype_column),
+            Duplicate: "'{}' or '{}' is already in output column names", names_column, dtype_column
+        );
+
+        // Transpose the columns of every dtype separately, and move their rows to the positions
+        // of the columns.
+        let mut dtypes: Vec<DataType> = vec![];
+        for c in df.get_columns() {
+            if !dtypes.contains(c.dtype()) {
+                dtypes.push(c.dtype().clone());
+            }
+        }
+        let dtype_names = dtypes
+            .iter()
+            .map(|dtype| PlSmallStr::from_string(dtype.to_string()))
+            .collect::<Vec<_>>();
+        let mut fields = vec![Vec::with_capacity(dtypes.len()); names_out.len()];
+        for (dtype, dtype_name) in dtypes.iter().zip(&dtype_names) {
+            let group = df
+                .get_columns()
+                .iter()
+                .filter(|c| c.dtype() == dtype)
+                .cloned()
+                .collect::<Vec<_>>();
+            let mut positions = vec![None; df.width()];
+            let mut n = 0;
+            for (position, c) in positions.iter_mut().zip(df.get_columns()) {
+                if c.dtype() == dtype {
+                    *position = Some(n as IdxSize);
+                    n += 1;
+                }
+            }
+            let positions = IdxCa::from_iter_options(PlSmallStr::EMPTY, positions.into_iter());
+
+            let mut group = DataFrame::new(group)?;
+            let transposed = group.transpose_impl(None, Some(Either::Right(names_out.clone())))?;
+            for (field, c) in fields.iter_mut().zip(transposed.take_columns()) {
+                let mut s = c.take_materialized_series().take(&positions)?;
+                s.rename(dtype_name.clone());
+                field.push(s);
+            }
+        }
+
+        let mut columns = Vec::with_capacity(names_out.len() + 2);
+        columns.push(Column::new(
+            names_column.into(),
+            df.get_column_names()
+                .into_iter()
+                .map(|n| n.as_str())
+                .collect::<Vec<_>>(),
+        ));
+        columns.push(Column::new(
+            dtype_column.into(),
+            df.get_columns()
+                .iter()
+                .map(|c| dtype_names[dtypes.iter().position(|d| d == c.dtype()).unwrap()].as_str())
+                .collect::<Vec<_>>(),
+        ));
+        for (name, fields) in names_out.into_iter().zip(fields) {
+            let ca = StructChunked::from_series(name, df.width(), fields.iter())?;
+            columns.push(ca.into_column());
+        }
+        DataFrame::new(columns)
+    }
+
+    /// Restore a DataFrame that was transposed with [`DataFrame::transpose_typed`].
+    ///
+    /// If `header` is given, a column with that name is added with the names of the transposed
+    /// columns.
+    #[cfg(feature = "dtype-struct")]
+    pub fn transpose_typed_inverse(
+        &self,
+        names_column: &str,
+        dtype_column: &str,
+        header: Option<&str>,
+    ) -> PolarsResult<DataFrame> {
+        let names = self.column(names_column)?.str()?;
+        let dtype_names = self.column(dtype_column)?.str()?;
+        let structs = self
+            .get_columns()
+            .iter()
+            .filter(|c| c.name() != names_column && c.name() != dtype_column)
+            .map(|c| c.struct_())
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut columns = Vec::with_capacity(self.height() + 1);
+        if let Some(header) = header {
+            let values = structs
+                .iter()
+                .map(|ca| ca.name().as_str())
+                .collect::<Vec<_>>();
+            columns.push(Column::new(header.into(), values));
+        }
+        // The fields of all structs for a dtype, one after the other. Row `i` of the input
+        // becomes the column with the values at `i`, `i + height`, `i + 2 * height`, ...
+        let height = self.height() as IdxSize;
+        let mut stacked: PlHashMap<&str, Series> = PlHashMap::new();
+        for (i, (name, dtype_name)) in names.iter().zip(dtype_names.iter()).enumerate() {
+            let (Some(name), Some(dtype_name)) = (name, dtype_name) else {
+                polars_bail!(
+                    ComputeError: "'{}' and '{}' can't have null values", names_column, dtype_column
+                );
+            };
+            if !stacked.contains_key(dtype_name) {
+                let (first, rest) = structs
+                    .split_first()
+                    .ok_or_else(|| polars_err!(NoData: "no transposed columns to restore"))?;
+                let mut values = first.field_by_name(dtype_name)?;
+                for ca in rest {
+                    values.append(&ca.field_by_name(dtype_name)?)?;
+                }
+                stacked.insert(dtype_name, values.rechunk());
+            }
+            let values = &stacked[dtype_name];
+            let idx = (0..structs.len() as IdxSize)
+                .map(|j| j * height + i as IdxSize)
+                .collect::<Vec<_>>();
+            // SAFETY: every field has `height` values.
+            let mut values = unsafe { values.take_slice_unchecked(&idx) };
+            values.rename(name.into());
+            columns.push(values.into_column());
+        }
+        DataFrame::new(columns)
+    }
 }
 
 #[inline]
@@ -331,4 +496,27 @@ mod test {
         assert!(out.equals_missing(&expected));
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_transpose_typed() -> PolarsResult<()> {
+        let df = df![
+            "key" => ["x", "y"],
+            "a" => [1i64, 2],
+            "b" => [Some("u"), None],
+            "c" => [3i64, 4],
+        ]?;
+
+        let out = df.transpose_typed(Some("key"), "column", "dtype")?;
+        assert_eq!(out.get_column_names(), &["column", "dtype", "x", "y"]);
+        let x = out.column("x")?.struct_()?;
+        let ints = x.field_by_name("i64")?;
+        assert_eq!(Vec::from(ints.i64()?), &[Some(1), None, Some(3)]);
+        let strs = x.field_by_name("str")?;
+        assert_eq!(Vec::from(strs.str()?), &[None, Some("u"), None]);
+
+        let restored = out.transpose_typed_inverse("column", "dtype", Some("key"))?;
+        assert!(restored.equals_missing(&df));
+        Ok(())
+    }
 }
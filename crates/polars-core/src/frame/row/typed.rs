@@ -0,0 +1,254 @@
+use arrow::array::StaticArray;
+
+use crate::prelude::*;
+
+/// Walks the values of a [`ChunkedArray`] in order.
+pub struct ValueCursor<'a, T: PolarsDataType> {
+    ca: &'a ChunkedArray<T>,
+    chunk: Option<&'a T::Array>,
+    chunk_idx: usize,
+    offset: usize,
+}
+
+impl<'a, T: PolarsDataType> ValueCursor<'a, T> {
+    fn new(ca: &'a ChunkedArray<T>) -> Self {
+        Self {
+            ca,
+            chunk: ca.downcast_get(0),
+            chunk_idx: 0,
+            offset: 0,
+        }
+    }
+
+    /// Return the array and offset of the next value.
+    ///
+    /// # Safety
+    /// Must not be called more often than the length of the array.
+    #[inline]
+    unsafe fn advance(&mut self) -> (&'a T::Array, usize) {
+        loop {
+            let chunk = unsafe { self.chunk.unwrap_unchecked() };
+            if self.offset < chunk.len() {
+                self.offset += 1;
+                return (chunk, self.offset - 1);
+            }
+            self.chunk_idx += 1;
+            self.offset = 0;
+            self.chunk = Some(unsafe { self.ca.downcast_get_unchecked(self.chunk_idx) });
+        }
+    }
+}
+
+/// A type that can be read from the values of a column, e.g. `i64`, `&str` or `Option<f64>`.
+///
+/// Only `Option` types can be read from columns that contain nulls.
+pub trait FromColumnValue<'a>: Sized {
+    type DataType: PolarsDataType<IsLogical = FalseT>;
+    const NULLABLE: bool;
+
+    /// # Safety
+    /// `offset` must be in bounds.
+    unsafe fn read(array: &'a <Self::DataType as PolarsDataType>::Array, offset: usize) -> Self;
+}
+
+macro_rules! impl_from_column_value {
+    ($($T:ty => $dt:ty),*) => {$(
+        impl<'a> FromColumnValue<'a> for $T {
+            type DataType = $dt;
+            const NULLABLE: bool = false;
+
+            #[inline]
+            unsafe fn read(array: &'a <$dt as PolarsDataType>::Array, offset: usize) -> Self {
+                unsafe { array.value_unchecked(offset) }
+            }
+        }
+
+        impl<'a> FromColumnValue<'a> for Option<$T> {
+            type DataType = $dt;
+            const NULLABLE: bool = true;
+
+            #[inline]
+            unsafe fn read(array: &'a <$dt as PolarsDataType>::Array, offset: usize) -> Self {
+                unsafe { array.get_unchecked(offset) }
+            }
+        }
+    )*};
+}
+
+impl_from_column_value!(
+    i8 => Int8Type,
+    i16 => Int16Type,
+    i32 => Int32Type,
+    i64 => Int64Type,
+    u8 => UInt8Type,
+    u16 => UInt16Type,
+    u32 => UInt32Type,
+    u64 => UInt64Type,
+    f32 => Float32Type,
+    f64 => Float64Type,
+    bool => BooleanType,
+    &'a str => StringType,
+    &'a [u8] => BinaryType
+);
+
+/// Allocates per value; prefer `&str` unless the row must not borrow from the [`DataFrame`].
+impl<'a> FromColumnValue<'a> for String {
+    type DataType = StringType;
+    const NULLABLE: bool = false;
+
+    #[inline]
+    unsafe fn read(array: &'a Utf8ViewArray, offset: usize) -> Self {
+        unsafe { array.value_unchecked(offset).to_string() }
+    }
+}
+
+impl<'a> FromColumnValue<'a> for Option<String> {
+    type DataType = StringType;
+    const NULLABLE: bool = true;
+
+    #[inline]
+    unsafe fn read(array: &'a Utf8ViewArray, offset: usize) -> Self {
+        unsafe { array.get_unchecked(offset).map(str::to_string) }
+    }
+}
+
+/// A row that can be read from a [`DataFrame`] without going through [`AnyValue`].
+/// Implemented for tuples of up to 12 [`FromColumnValue`]s.
+pub trait FromRow<'a>: Sized {
+    type Cursors;
+
+    fn cursors(df: &'a DataFrame) -> PolarsResult<Self::Cursors>;
+
+    /// # Safety
+    /// Must not be called more often than the height of the [`DataFrame`].
+    unsafe fn read(cursors: &mut Self::Cursors) -> Self;
+}
+
+fn cursor<'a, V: FromColumnValue<'a>>(
+    column: &'a Column,
+) -> PolarsResult<ValueCursor<'a, V::DataType>> {
+    let ca = column
+        .as_materialized_series()
+        .unpack::<V::DataType>()
+        .map_err(|e| e.context(format!("cannot read column '{}'", column.name()).into()))?;
+    polars_ensure!(
+        V::NULLABLE || !ca.has_nulls(),
+        ComputeError: "column '{}' contains nulls, read it as an `Option`", column.name()
+    );
+    Ok(ValueCursor::new(ca))
+}
+
+macro_rules! impl_from_row {
+    ($n:expr; $($T:ident $idx:tt),+) => {
+        impl<'a, $($T: FromColumnValue<'a>),+> FromRow<'a> for ($($T,)+) {
+            type Cursors = ($(ValueCursor<'a, $T::DataType>,)+);
+
+            fn cursors(df: &'a DataFrame) -> PolarsResult<Self::Cursors> {
+                polars_ensure!(
+                    df.width() == $n,
+                    ShapeMismatch: "cannot read rows of {} values from a DataFrame of width {}",
+                    $n, df.width()
+                );
+                let columns = df.get_columns();
+                Ok(($(cursor::<$T>(&columns[$idx])?,)+))
+            }
+
+            #[inline]
+            unsafe fn read(cursors: &mut Self::Cursors) -> Self {
+                ($({
+                    let (array, offset) = unsafe { cursors.$idx.advance() };
+                    unsafe { $T::read(array, offset) }
+                },)+)
+            }
+        }
+    };
+}
+
+impl_from_row!(1; A 0);
+impl_from_row!(2; A 0, B 1);
+impl_from_row!(3; A 0, B 1, C 2);
+impl_from_row!(4; A 0, B 1, C 2, D 3);
+impl_from_row!(5; A 0, B 1, C 2, D 3, E 4);
+impl_from_row!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+impl_from_row!(7; A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_from_row!(8; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_from_row!(9; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_from_row!(10; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_from_row!(11; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_from_row!(12; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+/// Iterator over the rows of a [`DataFrame`] as typed tuples, see [`DataFrame::rows_typed`].
+pub struct TypedRows<'a, R: FromRow<'a>> {
+    cursors: R::Cursors,
+    remaining: usize,
+}
+
+impl<'a, R: FromRow<'a>> Iterator for TypedRows<'a, R> {
+    type Item = R;
+
+    #[inline]
+    fn next(&mut self) -> Option<R> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        // SAFETY: every column has `height` values and we read at most `height` rows.
+        Some(unsafe { R::read(&mut self.cursors) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, R: FromRow<'a>> ExactSizeIterator for TypedRows<'a, R> {}
+
+impl DataFrame {
+    /// Iterate over the rows as tuples of native values, e.g.
+    /// `df.rows_typed::<(i64, &str, Option<f64>)>()`.
+    ///
+    /// Unlike [`get_row`](Self::get_row), this does not allocate or dispatch per value. The
+    /// tuple must have a type for every column that matches its dtype exactly, and columns
+    /// with nulls must be read as `Option`. Logical types such as dates can be read after
+    /// casting them to their physical type.
+    pub fn rows_typed<'a, R: FromRow<'a>>(&'a self) -> PolarsResult<TypedRows<'a, R>> {
+        Ok(TypedRows {
+            cursors: R::cursors(self)?,
+            remaining: self.height(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rows_typed() -> PolarsResult<()> {
+        let mut df = df!(
+            "id" => [1i64, 2],
+            "name" => ["a", "b"],
+            "score" => [Some(1.5f64), None],
+        )?;
+        df.vstack_mut(&df.clone())?;
+
+        let rows = df
+            .rows_typed::<(i64, &str, Option<f64>)>()?
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            [
+                (1, "a", Some(1.5)),
+                (2, "b", None),
+                (1, "a", Some(1.5)),
+                (2, "b", None)
+            ]
+        );
+
+        // Nulls, mismatching dtypes and widths are rejected.
+        assert!(df.rows_typed::<(i64, &str, f64)>().is_err());
+        assert!(df.rows_typed::<(i32, &str, Option<f64>)>().is_err());
+        assert!(df.rows_typed::<(i64, &str)>().is_err());
+        Ok(())
+    }
+}
@@ -27,7 +27,7 @@ pub mod testing;
 #[cfg(test)]
 mod tests;
 
-use std::sync::{LazyLock, Mutex};
+use std::sync::{LazyLock, Mutex, OnceLock};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 pub use datatypes::SchemaExtPl;
@@ -44,25 +44,46 @@ pub static PROCESS_ID: LazyLock<u128> = LazyLock::new(|| {
         .as_nanos()
 });
 
+/// Number of threads of the [`POOL`] if set before it is first used, see [`set_pool_size`].
+#[cfg(not(target_family = "wasm"))]
+static POOL_SIZE: OnceLock<usize> = OnceLock::new();
+
 // this is re-exported in utils for polars child crates
 #[cfg(not(target_family = "wasm"))] // only use this on non wasm targets
 pub static POOL: LazyLock<ThreadPool> = LazyLock::new(|| {
     let thread_name = std::env::var("POLARS_THREAD_NAME").unwrap_or_else(|_| "polars".to_string());
     ThreadPoolBuilder::new()
-        .num_threads(
+        .num_threads(POOL_SIZE.get().copied().unwrap_or_else(|| {
             std::env::var("POLARS_MAX_THREADS")
                 .map(|s| s.parse::<usize>().expect("integer"))
                 .unwrap_or_else(|_| {
                     std::thread::available_parallelism()
                         .unwrap_or(std::num::NonZeroUsize::new(1).unwrap())
                         .get()
-                }),
-        )
+                })
+        }))
         .thread_name(move |i| format!("{}-{}", thread_name, i))
         .build()
         .expect("could not spawn threads")
 });
 
+/// Set the number of threads of the [`POOL`], taking precedence over `POLARS_MAX_THREADS`. The
+/// pool can't be resized once it runs, so this must happen before it is first used.
+#[cfg(not(target_family = "wasm"))]
+pub fn set_pool_size(num_threads: usize) -> polars_error::PolarsResult<()> {
+    polars_error::polars_ensure!(
+        num_threads > 0,
+        InvalidOperation: "the number of threads must be positive"
+    );
+    _ = POOL_SIZE.set(num_threads);
+    polars_error::polars_ensure!(
+        POOL.current_num_threads() == num_threads,
+        InvalidOperation: "the thread pool already runs with {} threads",
+        POOL.current_num_threads()
+    );
+    Ok(())
+}
+
 #[cfg(all(target_os = "emscripten", target_family = "wasm"))] // Use 1 rayon thread on emscripten
 pub static POOL: LazyLock<ThreadPool> = LazyLock::new(|| {
     ThreadPoolBuilder::new()
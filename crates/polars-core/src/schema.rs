@@ -8,6 +8,7 @@ use crate::utils::try_get_supertype;
 
 pub type SchemaRef = Arc<Schema>;
 pub type Schema = polars_schema::Schema<DataType>;
+pub type SchemaDiff = polars_schema::SchemaDiff<DataType>;
 
 pub trait SchemaExt {
     fn from_arrow_schema(value: &ArrowSchema) -> Self;
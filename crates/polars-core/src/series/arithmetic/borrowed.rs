@@ -486,6 +486,7 @@ impl Add for &Series {
 
     fn add(self, rhs: Self) -> Self::Output {
         check_lengths(self, rhs)?;
+        warn_on_dropped_extension_type("arithmetic", &[self, rhs]);
         match (self.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-struct")]
             (DataType::Struct(_), DataType::Struct(_)) => {
@@ -511,6 +512,7 @@ impl Sub for &Series {
 
     fn sub(self, rhs: Self) -> Self::Output {
         check_lengths(self, rhs)?;
+        warn_on_dropped_extension_type("arithmetic", &[self, rhs]);
         match (self.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-struct")]
             (DataType::Struct(_), DataType::Struct(_)) => {
@@ -541,6 +543,7 @@ impl Mul for &Series {
     /// ```
     fn mul(self, rhs: Self) -> Self::Output {
         check_lengths(self, rhs)?;
+        warn_on_dropped_extension_type("arithmetic", &[self, rhs]);
 
         use DataType::*;
         match (self.dtype(), rhs.dtype()) {
@@ -582,6 +585,7 @@ impl Div for &Series {
     /// ```
     fn div(self, rhs: Self) -> Self::Output {
         check_lengths(self, rhs)?;
+        warn_on_dropped_extension_type("arithmetic", &[self, rhs]);
         use DataType::*;
         match (self.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-struct")]
@@ -619,6 +623,7 @@ impl Rem for &Series {
     /// ```
     fn rem(self, rhs: Self) -> Self::Output {
         check_lengths(self, rhs)?;
+        warn_on_dropped_extension_type("arithmetic", &[self, rhs]);
         match (self.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-struct")]
             (DataType::Struct(_), DataType::Struct(_)) => {
@@ -1,8 +1,12 @@
 use arrow::array::builder::{ArrayBuilder, ShareStrategy, make_builder};
+use arrow::array::{
+    BinaryViewArrayGenericBuilder, BooleanArrayBuilder, PrimitiveArrayBuilder, StructArrayBuilder,
+};
 use polars_utils::IdxSize;
 
 #[cfg(feature = "object")]
 use crate::chunked_array::object::registry::get_object_builder;
+use crate::frame::builder::PushRow;
 use crate::prelude::*;
 use crate::utils::Container;
 
@@ -92,6 +96,41 @@ impl SeriesBuilder {
         self.builder.len()
     }
 
+    /// Push a single value, e.g. an `i64`, an `Option<&str>` or a [`StructValue`].
+    ///
+    /// The value must match the physical type of the builder, e.g. a `Date` builder takes the
+    /// days since the epoch as `i32`. Categoricals and enums don't support pushing values.
+    pub fn push<V: PushValue>(&mut self, value: V) -> PolarsResult<()> {
+        polars_ensure!(
+            self.supports_push() && V::accepts(&*self.builder),
+            SchemaMismatch: "cannot push a value of type '{}' to a builder of dtype {}",
+            std::any::type_name::<V>(), self.dtype,
+        );
+        value.push_to(&mut *self.builder);
+        Ok(())
+    }
+
+    /// Push the fields of a single struct value to a `Struct` builder.
+    pub fn push_struct<R: PushRow>(&mut self, fields: R) -> PolarsResult<()> {
+        self.push(StructValue(fields))
+    }
+
+    pub fn push_null(&mut self) {
+        self.builder.extend_nulls(1);
+    }
+
+    pub(crate) fn supports_push(&self) -> bool {
+        !(self.dtype.is_categorical() || self.dtype.is_enum())
+    }
+
+    pub(crate) fn array_builder(&self) -> &dyn ArrayBuilder {
+        &*self.builder
+    }
+
+    pub(crate) fn array_builder_mut(&mut self) -> &mut dyn ArrayBuilder {
+        &mut *self.builder
+    }
+
     pub fn is_empty(&self) -> bool {
         self.builder.len() == 0
     }
@@ -239,3 +278,116 @@ impl SeriesBuilder {
         self.builder.opt_gather_extend(&*chunks[0], idxs, share);
     }
 }
+
+/// A value that can be pushed to an [`ArrayBuilder`] without going through [`AnyValue`].
+pub trait PushValue {
+    /// Whether `builder` can hold values of this type.
+    fn accepts(builder: &dyn ArrayBuilder) -> bool;
+
+    /// Push this value to `builder`. Panics if `builder` does not [accept](Self::accepts) it.
+    fn push_to(self, builder: &mut dyn ArrayBuilder);
+}
+
+macro_rules! impl_push_value {
+    ($builder:ty => $($T:ty),*) => {$(
+        impl PushValue for $T {
+            #[inline]
+            fn accepts(builder: &dyn ArrayBuilder) -> bool {
+                builder.as_any().is::<$builder>()
+            }
+
+            #[inline]
+            fn push_to(self, builder: &mut dyn ArrayBuilder) {
+                Some(self).push_to(builder)
+            }
+        }
+
+        impl PushValue for Option<$T> {
+            #[inline]
+            fn accepts(builder: &dyn ArrayBuilder) -> bool {
+                <$T>::accepts(builder)
+            }
+
+            #[inline]
+            fn push_to(self, builder: &mut dyn ArrayBuilder) {
+                let builder = builder.as_any_mut().downcast_mut::<$builder>().unwrap();
+                builder.push(self);
+            }
+        }
+    )*};
+}
+
+impl_push_value!(PrimitiveArrayBuilder<i8> => i8);
+impl_push_value!(PrimitiveArrayBuilder<i16> => i16);
+impl_push_value!(PrimitiveArrayBuilder<i32> => i32);
+impl_push_value!(PrimitiveArrayBuilder<i64> => i64);
+impl_push_value!(PrimitiveArrayBuilder<i128> => i128);
+impl_push_value!(PrimitiveArrayBuilder<u8> => u8);
+impl_push_value!(PrimitiveArrayBuilder<u16> => u16);
+impl_push_value!(PrimitiveArrayBuilder<u32> => u32);
+impl_push_value!(PrimitiveArrayBuilder<u64> => u64);
+impl_push_value!(PrimitiveArrayBuilder<f32> => f32);
+impl_push_value!(PrimitiveArrayBuilder<f64> => f64);
+impl_push_value!(BooleanArrayBuilder => bool);
+impl_push_value!(BinaryViewArrayGenericBuilder<str> => &str);
+impl_push_value!(BinaryViewArrayGenericBuilder<[u8]> => &[u8]);
+
+impl PushValue for String {
+    #[inline]
+    fn accepts(builder: &dyn ArrayBuilder) -> bool {
+        <&str>::accepts(builder)
+    }
+
+    #[inline]
+    fn push_to(self, builder: &mut dyn ArrayBuilder) {
+        self.as_str().push_to(builder)
+    }
+}
+
+impl PushValue for Option<String> {
+    #[inline]
+    fn accepts(builder: &dyn ArrayBuilder) -> bool {
+        <&str>::accepts(builder)
+    }
+
+    #[inline]
+    fn push_to(self, builder: &mut dyn ArrayBuilder) {
+        self.as_deref().push_to(builder)
+    }
+}
+
+/// A struct value with the fields of a [`PushRow`], e.g. a tuple.
+pub struct StructValue<R>(pub R);
+
+impl<R: PushRow> PushValue for StructValue<R> {
+    fn accepts(builder: &dyn ArrayBuilder) -> bool {
+        builder
+            .as_any()
+            .downcast_ref::<StructArrayBuilder>()
+            .is_some_and(|b| R::accepts(b.inner_builders()))
+    }
+
+    fn push_to(self, builder: &mut dyn ArrayBuilder) {
+        Some(self).push_to(builder)
+    }
+}
+
+impl<R: PushRow> PushValue for Option<StructValue<R>> {
+    fn accepts(builder: &dyn ArrayBuilder) -> bool {
+        StructValue::<R>::accepts(builder)
+    }
+
+    fn push_to(self, builder: &mut dyn ArrayBuilder) {
+        let builder = builder
+            .as_any_mut()
+            .downcast_mut::<StructArrayBuilder>()
+            .unwrap();
+        match self {
+            Some(StructValue(fields)) => {
+                fields.push_to(builder.inner_builders_mut());
+                builder.push_valid();
+            },
+            None => ArrayBuilder::extend_nulls(builder, 1),
+        }
+    }
+}
@@ -163,6 +163,8 @@ impl Series {
 
     /// Create a new Series without checking if the inner dtype of the chunks is correct
     ///
+    /// Custom keys in the field metadata `md` are stored as the metadata of the Series.
+    ///
     /// # Safety
     /// The caller must ensure that the given `dtype` matches all the `ArrayRef` dtypes.
     pub unsafe fn _try_from_arrow_unchecked_with_md(
@@ -170,6 +172,21 @@ impl Series {
         chunks: Vec<ArrayRef>,
         dtype: &ArrowDataType,
         md: Option<&Metadata>,
+    ) -> PolarsResult<Self> {
+        let mut out = unsafe { Self::try_from_arrow_unchecked_dtype_md(name, chunks, dtype, md)? };
+        if let Some(user_md) = md.and_then(|md| md.user_metadata()) {
+            out.set_metadata(Some(user_md));
+        }
+        Ok(out)
+    }
+
+    /// # Safety
+    /// The caller must ensure that the given `dtype` matches all the `ArrayRef` dtypes.
+    unsafe fn try_from_arrow_unchecked_dtype_md(
+        name: PlSmallStr,
+        chunks: Vec<ArrayRef>,
+        dtype: &ArrowDataType,
+        md: Option<&Metadata>,
     ) -> PolarsResult<Self> {
         match dtype {
             ArrowDataType::Utf8View => Ok(StringChunked::from_chunks(name, chunks).into_series()),
@@ -136,6 +136,10 @@ impl SeriesTrait for SeriesWrap<CategoricalChunked> {
         self.0.physical_mut().rename(name);
     }
 
+    fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        self.0.physical_mut().set_metadata(metadata);
+    }
+
     fn chunk_lengths(&self) -> ChunkLenIter {
         self.0.physical().chunk_lengths()
     }
@@ -22,6 +22,7 @@ pub struct NullChunked {
     // we still need chunks as many series consumers expect
     // chunks to be there
     chunks: Vec<ArrayRef>,
+    metadata: Option<Arc<Metadata>>,
 }
 
 impl NullChunked {
@@ -33,6 +34,15 @@ impl NullChunked {
                 ArrowDataType::Null,
                 len,
             ))],
+            metadata: None,
+        }
+    }
+
+    /// A null column of length `len` with the same name and metadata.
+    fn with_len(&self, len: usize) -> Self {
+        Self {
+            metadata: self.metadata.clone(),
+            ..Self::new(self.name.clone(), len)
         }
     }
 
@@ -61,7 +71,9 @@ impl PrivateSeries for NullChunked {
         self.length = IdxSize::try_from(inner(&self.chunks)).expect(LENGTH_LIMIT_MSG);
     }
     fn _field(&self) -> Cow<Field> {
-        Cow::Owned(Field::new(self.name().clone(), DataType::Null))
+        Cow::Owned(
+            Field::new(self.name().clone(), DataType::Null).with_metadata(self.metadata.clone()),
+        )
     }
 
     #[allow(unused)]
@@ -169,6 +181,10 @@ impl SeriesTrait for NullChunked {
         self.name = name
     }
 
+    fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        self.metadata = metadata;
+    }
+
     fn chunks(&self) -> &Vec<ArrayRef> {
         &self.chunks
     }
@@ -181,19 +197,19 @@ impl SeriesTrait for NullChunked {
     }
 
     fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
-        Ok(NullChunked::new(self.name.clone(), indices.len()).into_series())
+        Ok(self.with_len(indices.len()).into_series())
     }
 
     unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
-        NullChunked::new(self.name.clone(), indices.len()).into_series()
+        self.with_len(indices.len()).into_series()
     }
 
     fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
-        Ok(NullChunked::new(self.name.clone(), indices.len()).into_series())
+        Ok(self.with_len(indices.len()).into_series())
     }
 
     unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
-        NullChunked::new(self.name.clone(), indices.len()).into_series()
+        self.with_len(indices.len()).into_series()
     }
 
     fn len(&self) -> usize {
@@ -205,11 +221,11 @@ impl SeriesTrait for NullChunked {
     }
 
     fn rechunk(&self) -> Series {
-        NullChunked::new(self.name.clone(), self.len()).into_series()
+        self.with_len(self.len()).into_series()
     }
 
     fn drop_nulls(&self) -> Series {
-        NullChunked::new(self.name.clone(), 0).into_series()
+        self.with_len(0).into_series()
     }
 
     fn cast(&self, dtype: &DataType, _cast_options: CastOptions) -> PolarsResult<Series> {
@@ -222,7 +238,7 @@ impl SeriesTrait for NullChunked {
 
     #[cfg(feature = "algorithm_group_by")]
     fn unique(&self) -> PolarsResult<Series> {
-        let ca = NullChunked::new(self.name.clone(), self.n_unique().unwrap());
+        let ca = self.with_len(self.n_unique().unwrap());
         Ok(ca.into_series())
     }
 
@@ -239,7 +255,7 @@ impl SeriesTrait for NullChunked {
     }
 
     fn new_from_index(&self, _index: usize, length: usize) -> Series {
-        NullChunked::new(self.name.clone(), length).into_series()
+        self.with_len(length).into_series()
     }
 
     unsafe fn get_unchecked(&self, _index: usize) -> AnyValue {
@@ -252,6 +268,7 @@ impl SeriesTrait for NullChunked {
             name: self.name.clone(),
             length: len as IdxSize,
             chunks,
+            metadata: self.metadata.clone(),
         }
         .into_series()
     }
@@ -263,12 +280,14 @@ impl SeriesTrait for NullChunked {
                 name: self.name.clone(),
                 length: l.iter().map(|arr| arr.len() as IdxSize).sum(),
                 chunks: l,
+                metadata: self.metadata.clone(),
             }
             .into_series(),
             NullChunked {
                 name: self.name.clone(),
                 length: r.iter().map(|arr| arr.len() as IdxSize).sum(),
                 chunks: r,
+                metadata: self.metadata.clone(),
             }
             .into_series(),
         )
@@ -302,13 +321,13 @@ impl SeriesTrait for NullChunked {
         } else if filter.len() == 1 {
             return match filter.get(0) {
                 Some(true) => Ok(self.clone().into_series()),
-                None | Some(false) => Ok(NullChunked::new(self.name.clone(), 0).into_series()),
+                None | Some(false) => Ok(self.with_len(0).into_series()),
             };
         } else {
             polars_ensure!(filter.len() == self.len(), ShapeMismatch: "filter's length: {} differs from that of the series: {}", filter.len(), self.len());
             filter.sum().unwrap_or(0) as usize
         };
-        Ok(NullChunked::new(self.name.clone(), len).into_series())
+        Ok(self.with_len(len).into_series())
     }
 
     fn shift(&self, _periods: i64) -> Series {
@@ -332,7 +351,7 @@ impl SeriesTrait for NullChunked {
     }
 
     fn extend(&mut self, other: &Series) -> PolarsResult<()> {
-        *self = NullChunked::new(self.name.clone(), self.len() + other.len());
+        *self = self.with_len(self.len() + other.len());
         Ok(())
     }
 
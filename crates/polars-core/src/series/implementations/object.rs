@@ -95,6 +95,10 @@ where
         ObjectChunked::rename(&mut self.0, name)
     }
 
+    fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        ObjectChunked::set_metadata(&mut self.0, metadata)
+    }
+
     fn chunk_lengths(&self) -> ChunkLenIter {
         ObjectChunked::chunk_lengths(&self.0)
     }
@@ -25,6 +25,8 @@ pub mod implementations;
 mod into;
 pub(crate) mod iterator;
 pub mod ops;
+#[cfg(feature = "proptest")]
+pub mod proptest;
 mod series_trait;
 
 use std::borrow::Cow;
@@ -32,6 +34,7 @@ use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 
 use arrow::compute::aggregate::estimated_bytes_size;
+use arrow::datatypes::Metadata;
 use arrow::offset::Offsets;
 pub use from::*;
 pub use iterator::{SeriesIter, SeriesPhysIter};
@@ -289,6 +292,27 @@ impl Series {
         self
     }
 
+    /// Get the custom key/value metadata of the field of this Series.
+    pub fn metadata(&self) -> Option<Arc<Metadata>> {
+        self.field().metadata.clone()
+    }
+
+    /// Set the custom key/value metadata of the field of this Series, e.g. units or
+    /// descriptions. The metadata is written to the field metadata of Parquet and IPC files.
+    ///
+    /// Operations that rename, select or reorder values, like slicing, filtering, gathering,
+    /// sorting and joins, keep the metadata. Operations that compute new values drop it.
+    pub fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) -> &mut Series {
+        self._get_inner_mut().set_metadata(metadata);
+        self
+    }
+
+    /// Return this Series with the given custom metadata.
+    pub fn with_metadata(mut self, metadata: Option<Arc<Metadata>>) -> Series {
+        self.set_metadata(metadata);
+        self
+    }
+
     pub fn from_arrow_chunks(name: PlSmallStr, arrays: Vec<ArrayRef>) -> PolarsResult<Series> {
         Self::try_from((name, arrays))
     }
@@ -330,6 +354,26 @@ impl Series {
         Ok(self)
     }
 
+    /// Append in place like [`append`](Self::append), extending the categories of an `Enum`
+    /// with the categories of `other` that it lacks if `policy` allows it.
+    #[cfg(feature = "dtype-categorical")]
+    pub fn append_with_policy(
+        &mut self,
+        other: &Series,
+        policy: EnumAppendPolicy,
+    ) -> PolarsResult<&mut Self> {
+        if policy == EnumAppendPolicy::Extend
+            && matches!(self.dtype(), DataType::Enum(_, _))
+            && matches!(other.dtype(), DataType::Enum(_, _))
+        {
+            let mut ca = self.categorical()?.clone();
+            ca.append_with_policy(other.categorical()?, policy)?;
+            *self = ca.into_series();
+            return Ok(self);
+        }
+        self.append(other)
+    }
+
     /// Redo a length and null_count compute
     pub fn compute_len(&mut self) {
         self._get_inner_mut().compute_len()
@@ -405,6 +449,9 @@ impl Series {
         if do_clone {
             return Ok(slf.into_owned());
         }
+        if let Some(out) = slf.cast_extension_type(dtype, options)? {
+            return Ok(out);
+        }
 
         pub fn cast_dtype(dtype: &DataType) -> Option<DataType> {
             match dtype {
@@ -427,6 +474,7 @@ impl Series {
                             new_fields.push(Field {
                                 name: field.name.clone(),
                                 dtype,
+                                metadata: field.metadata.clone(),
                             });
                             break new_fields;
                         }
@@ -437,6 +485,7 @@ impl Series {
                         Field {
                             name: field.name.clone(),
                             dtype,
+                            metadata: field.metadata,
                         }
                     }));
 
@@ -777,6 +826,7 @@ impl Series {
     /// If the [`DataType`] is one of `{Int8, UInt8, Int16, UInt16}` the `Series` is
     /// first cast to `Int64` to prevent overflow issues.
     pub fn sum_reduce(&self) -> PolarsResult<Scalar> {
+        warn_on_dropped_extension_type("sum", &[self]);
         use DataType::*;
         match self.dtype() {
             Int8 | UInt8 | Int16 | UInt16 => self.cast(&Int64).unwrap().sum_reduce(),
@@ -946,6 +996,7 @@ impl Series {
     }
 
     pub fn mean_reduce(&self) -> Scalar {
+        warn_on_dropped_extension_type("mean", &[self]);
         crate::scalar::reduce::mean_reduce(self.mean(), self.dtype().clone())
     }
 
@@ -1099,6 +1150,9 @@ where
 
 #[cfg(test)]
 mod test {
+    use std::collections::BTreeMap;
+    use std::hash::BuildHasher;
+
     use crate::prelude::*;
     use crate::series::*;
 
@@ -1113,6 +1167,36 @@ mod test {
         assert!(s2.f32().is_ok());
     }
 
+    #[test]
+    fn test_metadata_propagation() -> PolarsResult<()> {
+        let md = Arc::new(BTreeMap::from([("unit".into(), "m".into())]));
+        let s = Series::new("a".into(), &[3, 1, 2]).with_metadata(Some(md.clone()));
+
+        let renamed = s.clone().with_name("b".into());
+        assert_eq!(renamed.metadata(), Some(md.clone()));
+        assert_eq!(s.slice(1, 2).metadata(), Some(md.clone()));
+        assert_eq!(s.sort(Default::default())?.metadata(), Some(md.clone()));
+        let mask = BooleanChunked::new("".into(), &[true, false, true]);
+        assert_eq!(s.filter(&mask)?.metadata(), Some(md.clone()));
+        assert_eq!((&s + 1).metadata(), None);
+
+        // Metadata is not part of the type.
+        let plain = Series::new("a".into(), &[1]);
+        assert_eq!(s.field(), plain.field());
+        let hash = |f: &Field| PlFixedStateQuality::default().hash_one(f);
+        assert_eq!(hash(&s.field()), hash(&plain.field()));
+
+        let nulls = Series::new_null("n".into(), 3).with_metadata(Some(md.clone()));
+        assert_eq!(nulls.field().metadata(), Some(&md));
+        assert_eq!(nulls.slice(1, 1).metadata(), Some(md.clone()));
+        assert_eq!(nulls.filter(&mask)?.metadata(), Some(md.clone()));
+        assert_eq!(nulls.cast(&DataType::Int32)?.metadata(), None);
+
+        let arrow_field = s.field().to_arrow(CompatLevel::newest());
+        assert_eq!(Field::from(&arrow_field).metadata(), Some(&md));
+        Ok(())
+    }
+
     #[test]
     fn new_series() {
         let _ = Series::new("boolean series".into(), &vec![true, false, true]);
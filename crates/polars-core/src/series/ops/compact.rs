@@ -0,0 +1,139 @@
+use arrow::compute::concatenate::concatenate_unchecked;
+
+use crate::prelude::*;
+
+/// When [`Series::append_compacting`] merges the chunks of a [`Series`].
+///
+/// [`Series::append`] adds a chunk per call, so many small appends leave a long tail of tiny
+/// chunks that slow down every later operation, while [`Series::extend`] may copy all existing
+/// data on every call. This policy sits in between: after an append the trailing chunks are
+/// merged for as long as a chunk is at most `growth_factor` times as long as the chunks after
+/// it. Chunk lengths thus decrease geometrically, a [`Series`] of `n` rows has `O(log n)`
+/// chunks and every row is copied `O(log n)` times.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CompactionPolicy {
+    /// Merge a chunk with the chunks after it if it is at most this many times as long.
+    pub growth_factor: usize,
+    /// Merge all chunks into one once there are more than this many.
+    pub max_chunks: usize,
+}
+
+impl Default for CompactionPolicy {
+    fn default() -> Self {
+        Self {
+            growth_factor: 4,
+            max_chunks: 64,
+        }
+    }
+}
+
+impl Series {
+    /// Append in place and merge the trailing chunks according to `policy`.
+    ///
+    /// Use this instead of [`append`](Self::append) when appending small batches at a high
+    /// frequency, e.g. when accumulating events.
+    pub fn append_compacting(
+        &mut self,
+        other: &Series,
+        policy: CompactionPolicy,
+    ) -> PolarsResult<&mut Self> {
+        self.append(other)?;
+        Ok(self.compact_with(policy))
+    }
+
+    /// Append a stream of Arrow arrays, merging the trailing chunks according to `policy` after
+    /// every array.
+    pub fn extend_from_arrow_stream<I>(
+        &mut self,
+        arrays: I,
+        policy: CompactionPolicy,
+    ) -> PolarsResult<&mut Self>
+    where
+        I: IntoIterator<Item = ArrayRef>,
+    {
+        for array in arrays {
+            let other = Series::from_arrow(self.name().clone(), array)?;
+            self.append_compacting(&other, policy)?;
+        }
+        Ok(self)
+    }
+
+    /// Merge the trailing chunks according to `policy`.
+    ///
+    /// Chunks of `Object` columns are never merged.
+    pub fn compact_with(&mut self, policy: CompactionPolicy) -> &mut Self {
+        let chunks = self.chunks();
+        let n_chunks = chunks.len();
+        if n_chunks < 2 || self.dtype().is_object() {
+            return self;
+        }
+
+        let start = if n_chunks > policy.max_chunks {
+            0
+        } else {
+            let mut start = n_chunks - 1;
+            let mut tail_len = chunks[start].len();
+            while start > 0
+                && chunks[start - 1].len() <= policy.growth_factor.saturating_mul(tail_len)
+            {
+                start -= 1;
+                tail_len += chunks[start].len();
+            }
+            start
+        };
+
+        if start + 1 < n_chunks {
+            // SAFETY: merging chunks changes neither the length, the dtype nor the null count.
+            let chunks = unsafe { self.chunks_mut() };
+            let merged = concatenate_unchecked(&chunks[start..]).unwrap();
+            chunks.truncate(start);
+            chunks.push(merged);
+        }
+        self
+    }
+
+    /// Merge all chunks into a single chunk in place, e.g. after a sequence of
+    /// [`append_compacting`](Self::append_compacting) calls and before running queries.
+    pub fn compact(&mut self) -> &mut Self {
+        if self.n_chunks() > 1 {
+            *self = self.rechunk();
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_append_compacting() -> PolarsResult<()> {
+        let policy = CompactionPolicy {
+            growth_factor: 2,
+            max_chunks: 64,
+        };
+        let mut s = Series::new("a".into(), [0i32]);
+        for i in 1..1000 {
+            s.append_compacting(&Series::new("a".into(), [i]), policy)?;
+            let lens = s.chunk_lengths().collect::<Vec<_>>();
+            assert!(lens.windows(2).all(|w| w[0] > 2 * w[1]), "{lens:?}");
+        }
+        assert_eq!(s.len(), 1000);
+        assert!(s.n_chunks() <= 10);
+        assert!(s.equals(&Series::new("a".into(), (0..1000).collect::<Vec<i32>>())));
+
+        let few_chunks = CompactionPolicy {
+            growth_factor: 0,
+            max_chunks: 3,
+        };
+        let arrays =
+            (0..10).map(|i| Series::new("a".into(), [i]).to_arrow(0, CompatLevel::newest()));
+        s.extend_from_arrow_stream(arrays, few_chunks)?;
+        assert_eq!(s.len(), 1010);
+        assert!(s.n_chunks() <= 3);
+
+        s.compact();
+        assert_eq!(s.n_chunks(), 1);
+        Ok(())
+    }
+}
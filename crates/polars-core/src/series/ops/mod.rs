@@ -1,3 +1,4 @@
+mod compact;
 mod downcast;
 mod extend;
 mod null;
@@ -5,6 +6,8 @@ mod null;
 use serde::{Deserialize, Serialize};
 mod reshape;
 
+pub use compact::CompactionPolicy;
+
 #[derive(Copy, Clone, Hash, Eq, PartialEq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NullBehavior {
@@ -0,0 +1,164 @@
+//! [`proptest`] strategies that generate [`Series`].
+use std::ops::RangeInclusive;
+
+use arrow::array::proptest::{
+    ArrowDataTypeArbitraryOptions, ArrowDataTypeArbitrarySelection, array_with_dtype,
+    arrow_data_type,
+};
+use arrow::bitmap::Bitmap;
+use proptest::prelude::*;
+
+use crate::prelude::*;
+
+/// Whether generated [`Series`] are sorted, and get the matching sortedness flag.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortednessArbitrary {
+    /// Leave the values in the generated order.
+    #[default]
+    Unsorted,
+    Ascending,
+    Descending,
+    /// Pick one of the above for every [`Series`].
+    Any,
+}
+
+#[derive(Clone, Debug)]
+pub struct SeriesArbitraryOptions {
+    pub dtype: ArrowDataTypeArbitraryOptions,
+    pub size_range: RangeInclusive<usize>,
+    /// Fraction of (outer) values that are null. `None` keeps the validities of the generated
+    /// arrays, which are either absent or random.
+    pub null_density: Option<f64>,
+    /// Sortedness of series with a non-nested data type. Nested series are never sorted.
+    pub sortedness: SortednessArbitrary,
+}
+
+/// The data types that Polars supports with the enabled features.
+pub fn supported_dtypes() -> ArrowDataTypeArbitrarySelection {
+    use ArrowDataTypeArbitrarySelection as S;
+    #[allow(unused_mut)]
+    let mut dtypes = S::all();
+    #[cfg(not(feature = "dtype-i8"))]
+    dtypes.remove(S::INT8);
+    #[cfg(not(feature = "dtype-i16"))]
+    dtypes.remove(S::INT16);
+    #[cfg(not(feature = "dtype-i128"))]
+    dtypes.remove(S::INT128);
+    #[cfg(not(feature = "dtype-u8"))]
+    dtypes.remove(S::UINT8);
+    #[cfg(not(feature = "dtype-u16"))]
+    dtypes.remove(S::UINT16);
+    #[cfg(not(feature = "dtype-array"))]
+    dtypes.remove(S::FIXED_SIZE_LIST);
+    #[cfg(not(feature = "dtype-struct"))]
+    dtypes.remove(S::STRUCT);
+    dtypes
+}
+
+impl Default for SeriesArbitraryOptions {
+    fn default() -> Self {
+        Self {
+            dtype: ArrowDataTypeArbitraryOptions {
+                allowed_dtypes: supported_dtypes(),
+                array_width_range: 1..=4,
+                struct_num_fields_range: 1..=4,
+                max_nesting_level: 3,
+            },
+            size_range: 0..=100,
+            null_density: None,
+            sortedness: SortednessArbitrary::Unsorted,
+        }
+    }
+}
+
+fn finish_series(
+    name: PlSmallStr,
+    array: ArrayRef,
+    validity: Option<Vec<bool>>,
+    descending: Option<bool>,
+) -> Series {
+    let array = match validity {
+        Some(validity) => array.with_validity(Some(Bitmap::from_iter(validity))),
+        None => array,
+    };
+    let s = Series::from_arrow(name, array).unwrap();
+    match descending {
+        Some(descending) if !s.dtype().is_nested() && !s.dtype().is_null() => s
+            .sort(SortOptions::default().with_order_descending(descending))
+            .unwrap(),
+        _ => s,
+    }
+}
+
+/// Generate a [`Series`] named `name` of the given data type.
+pub fn series_with_dtype(
+    name: PlSmallStr,
+    dtype: ArrowDataType,
+    options: &SeriesArbitraryOptions,
+) -> impl Strategy<Value = Series> + use<> {
+    let null_density = options.null_density;
+    let sortedness = options.sortedness;
+    (
+        array_with_dtype(dtype, options.size_range.clone()),
+        any::<bool>(),
+    )
+        .prop_flat_map(move |(array, descending)| {
+            let validity = match null_density {
+                Some(density) => {
+                    proptest::collection::vec(proptest::bool::weighted(1.0 - density), array.len())
+                        .prop_map(Some)
+                        .boxed()
+                },
+                None => Just(None).boxed(),
+            };
+            let descending = match sortedness {
+                SortednessArbitrary::Unsorted => None,
+                SortednessArbitrary::Ascending => Some(false),
+                SortednessArbitrary::Descending => Some(true),
+                SortednessArbitrary::Any => Some(descending),
+            };
+            let name = name.clone();
+            validity.prop_map(move |validity| {
+                finish_series(name.clone(), array.clone(), validity, descending)
+            })
+        })
+}
+
+/// Generate a [`Series`] named `name` of an arbitrary data type.
+pub fn series(name: PlSmallStr, options: SeriesArbitraryOptions) -> impl Strategy<Value = Series> {
+    arrow_data_type(options.dtype.clone())
+        .prop_flat_map(move |dtype| series_with_dtype(name.clone(), dtype, &options))
+}
+
+impl Arbitrary for Series {
+    type Parameters = SeriesArbitraryOptions;
+    type Strategy = BoxedStrategy<Series>;
+
+    fn arbitrary_with(options: Self::Parameters) -> Self::Strategy {
+        series(PlSmallStr::from_static("s"), options).boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn test_arbitrary_series(
+            s in series(
+                "a".into(),
+                SeriesArbitraryOptions {
+                    null_density: Some(0.5),
+                    sortedness: SortednessArbitrary::Ascending,
+                    ..Default::default()
+                },
+            )
+        ) {
+            prop_assert!(s.len() <= 100);
+            if s.dtype().is_primitive_numeric() {
+                prop_assert_eq!(s.is_sorted_flag(), IsSorted::Ascending);
+            }
+        }
+    }
+}
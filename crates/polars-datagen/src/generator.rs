@@ -0,0 +1,591 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use rayon::prelude::*;
+
+/// How the values of a generated column are distributed.
+///
+/// Every value is a pure function of the seed, the column and the row index, so any slice
+/// of a table can be generated independently and always yields the same values.
+#[derive(Clone, Debug)]
+pub enum Distribution {
+    /// `start + row / repeat`, e.g. a primary key (`repeat == 1`) or a key that is shared by
+    /// `repeat` consecutive rows.
+    Sequence { start: i64, repeat: u64 },
+    /// `start + row % modulus`, e.g. the position of a row within its group of `modulus` rows.
+    Modulo { start: i64, modulus: u64 },
+    /// Integers drawn uniformly from `low..=high`.
+    UniformInt { low: i64, high: i64 },
+    /// Floats drawn uniformly from `low..high`.
+    UniformFloat { low: f64, high: f64 },
+    /// Normally distributed floats.
+    Normal { mean: f64, std_dev: f64 },
+    /// Exponentially distributed floats with rate `lambda`.
+    Exponential { lambda: f64 },
+    /// Booleans that are `true` with probability `p`.
+    Bool { p: f64 },
+    /// Consecutive dates (days since the epoch) starting at `start`.
+    DateSequence { start: i32 },
+    /// Dates (days since the epoch) drawn uniformly from `start..=end`.
+    Date { start: i32, end: i32 },
+    /// `prefix` followed by the zero-padded `start + row`, e.g. `Customer#000000001`.
+    Label {
+        prefix: PlSmallStr,
+        start: i64,
+        width: usize,
+    },
+    /// `prefix` followed by one of `cardinality` uniformly drawn ids.
+    Strings {
+        prefix: PlSmallStr,
+        cardinality: u64,
+    },
+    /// Values drawn uniformly from `values`.
+    Choice(Arc<[PlSmallStr]>),
+    /// `values[row % values.len()]`.
+    Cycle(Arc<[PlSmallStr]>),
+}
+
+impl Distribution {
+    pub fn dtype(&self) -> DataType {
+        use Distribution::*;
+        match self {
+            Sequence { .. } | Modulo { .. } | UniformInt { .. } => DataType::Int64,
+            UniformFloat { .. } | Normal { .. } | Exponential { .. } => DataType::Float64,
+            Bool { .. } => DataType::Boolean,
+            DateSequence { .. } | Date { .. } => DataType::Date,
+            Label { .. } | Strings { .. } | Choice(_) | Cycle(_) => DataType::String,
+        }
+    }
+
+    /// Check the parameters, and that no value of the first `n_rows` rows overflows.
+    fn validate(&self, n_rows: u64) -> PolarsResult<()> {
+        use Distribution::*;
+        let last_row = n_rows.saturating_sub(1);
+        let fits = |start: i64, offset: u64| {
+            i64::try_from(offset)
+                .ok()
+                .and_then(|offset| start.checked_add(offset))
+                .is_some()
+        };
+        match self {
+            Sequence { start, repeat } => {
+                polars_ensure!(*repeat > 0, InvalidOperation: "`repeat` must be positive");
+                polars_ensure!(
+                    fits(*start, last_row / repeat),
+                    InvalidOperation: "sequence starting at {} overflows", start
+                );
+            },
+            Modulo { start, modulus } => {
+                polars_ensure!(*modulus > 0, InvalidOperation: "`modulus` must be positive");
+                polars_ensure!(
+                    fits(*start, last_row.min(modulus - 1)),
+                    InvalidOperation: "sequence starting at {} overflows", start
+                );
+            },
+            UniformInt { low, high } => {
+                polars_ensure!(low <= high, InvalidOperation: "`low` must not exceed `high`")
+            },
+            UniformFloat { low, high } => {
+                polars_ensure!(low <= high, InvalidOperation: "`low` must not exceed `high`")
+            },
+            Normal { std_dev, .. } => {
+                polars_ensure!(*std_dev >= 0.0, InvalidOperation: "`std_dev` must not be negative")
+            },
+            Exponential { lambda } => {
+                polars_ensure!(*lambda > 0.0, InvalidOperation: "`lambda` must be positive")
+            },
+            Bool { p } => {
+                polars_ensure!((0.0..=1.0).contains(p), InvalidOperation: "`p` must be in [0, 1]")
+            },
+            Date { start, end } => {
+                polars_ensure!(start <= end, InvalidOperation: "`start` must not exceed `end`")
+            },
+            Strings { cardinality, .. } => {
+                polars_ensure!(*cardinality > 0, InvalidOperation: "`cardinality` must be positive")
+            },
+            Choice(values) | Cycle(values) => {
+                polars_ensure!(!values.is_empty(), InvalidOperation: "no values to choose from")
+            },
+            DateSequence { start } => {
+                let fits = i32::try_from(last_row)
+                    .ok()
+                    .and_then(|offset| start.checked_add(offset))
+                    .is_some();
+                polars_ensure!(
+                    fits,
+                    InvalidOperation: "date sequence starting at {} overflows", start
+                );
+            },
+            Label { start, .. } => {
+                polars_ensure!(
+                    fits(*start, last_row),
+                    InvalidOperation: "labels starting at {} overflow", start
+                );
+            },
+        }
+        Ok(())
+    }
+}
+
+/// A generated column.
+#[derive(Clone, Debug)]
+pub struct ColumnSpec {
+    pub name: PlSmallStr,
+    pub distribution: Distribution,
+    /// Fraction of the rows that are null.
+    pub null_ratio: f64,
+}
+
+impl ColumnSpec {
+    pub fn new(name: impl Into<PlSmallStr>, distribution: Distribution) -> Self {
+        Self {
+            name: name.into(),
+            distribution,
+            null_ratio: 0.0,
+        }
+    }
+
+    pub fn with_null_ratio(mut self, null_ratio: f64) -> Self {
+        self.null_ratio = null_ratio;
+        self
+    }
+
+    /// Generate the values of `rows`. `stream` identifies the column within its table.
+    ///
+    /// The distribution must be validated for the rows, so that the values don't overflow.
+    fn generate(&self, seed: u64, stream: u64, rows: Range<u64>) -> Column {
+        use Distribution::*;
+        let name = self.name.clone();
+        let null_ratio = self.null_ratio;
+        let valid = move |row: u64| {
+            null_ratio <= 0.0 || unit(hash(seed, 2 * stream + 1, row)) >= null_ratio
+        };
+        let draw = move |row: u64| hash(seed, 2 * stream, row);
+
+        match &self.distribution {
+            Sequence { start, repeat } => Int64Chunked::from_iter_options(
+                name,
+                rows.map(|row| valid(row).then(|| start + (row / repeat) as i64)),
+            )
+            .into_column(),
+            Modulo { start, modulus } => Int64Chunked::from_iter_options(
+                name,
+                rows.map(|row| valid(row).then(|| start + (row % modulus) as i64)),
+            )
+            .into_column(),
+            UniformInt { low, high } => {
+                let span = (*high as i128 - *low as i128 + 1) as u128;
+                Int64Chunked::from_iter_options(
+                    name,
+                    rows.map(|row| {
+                        valid(row)
+                            .then(|| (*low as i128 + (draw(row) as u128 % span) as i128) as i64)
+                    }),
+                )
+                .into_column()
+            },
+            UniformFloat { low, high } => Float64Chunked::from_iter_options(
+                name,
+                rows.map(|row| valid(row).then(|| low + unit(draw(row)) * (high - low))),
+            )
+            .into_column(),
+            Normal { mean, std_dev } => Float64Chunked::from_iter_options(
+                name,
+                rows.map(|row| {
+                    valid(row).then(|| {
+                        // Box-Muller transform.
+                        let u1 = 1.0 - unit(draw(row));
+                        let u2 = unit(hash(!seed, 2 * stream, row));
+                        let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+                        mean + std_dev * z
+                    })
+                }),
+            )
+            .into_column(),
+            Exponential { lambda } => Float64Chunked::from_iter_options(
+                name,
+                rows.map(|row| valid(row).then(|| -(1.0 - unit(draw(row))).ln() / lambda)),
+            )
+            .into_column(),
+            Bool { p } => BooleanChunked::from_iter_options(
+                name,
+                rows.map(|row| valid(row).then(|| unit(draw(row)) < *p)),
+            )
+            .into_column(),
+            DateSequence { start } => Int32Chunked::from_iter_options(
+                name,
+                rows.map(|row| valid(row).then(|| start + row as i32)),
+            )
+            .into_date()
+            .into_column(),
+            Date { start, end } => {
+                let span = (*end as i64 - *start as i64 + 1) as u64;
+                Int32Chunked::from_iter_options(
+                    name,
+                    rows.map(|row| valid(row).then(|| start + (draw(row) % span) as i32)),
+                )
+                .into_date()
+                .into_column()
+            },
+            Label {
+                prefix,
+                start,
+                width,
+            } => StringChunked::from_iter_options(
+                name,
+                rows.map(|row| {
+                    valid(row).then(|| format!("{prefix}{:0width$}", start + row as i64))
+                }),
+            )
+            .into_column(),
+            Strings {
+                prefix,
+                cardinality,
+            } => StringChunked::from_iter_options(
+                name,
+                rows.map(|row| valid(row).then(|| format!("{prefix}{}", draw(row) % cardinality))),
+            )
+            .into_column(),
+            Choice(values) => StringChunked::from_iter_options(
+                name,
+                rows.map(|row| {
+                    valid(row).then(|| values[(draw(row) % values.len() as u64) as usize].as_str())
+                }),
+            )
+            .into_column(),
+            Cycle(values) => StringChunked::from_iter_options(
+                name,
+                rows.map(|row| {
+                    valid(row).then(|| values[(row % values.len() as u64) as usize].as_str())
+                }),
+            )
+            .into_column(),
+        }
+    }
+}
+
+/// A synthetic table of `n_rows` rows.
+///
+/// # Example
+/// ```rust,ignore
+/// let lf = TableGenerator::new(1_000_000)
+///     .with_column(ColumnSpec::new("id", Distribution::Sequence { start: 0, repeat: 1 }))
+///     .with_column(
+///         ColumnSpec::new("score", Distribution::Normal { mean: 0.0, std_dev: 1.0 })
+///             .with_null_ratio(0.1),
+///     )
+///     .lazy()?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct TableGenerator {
+    columns: Vec<ColumnSpec>,
+    n_rows: usize,
+    seed: u64,
+}
+
+impl TableGenerator {
+    pub fn new(n_rows: usize) -> Self {
+        Self {
+            columns: vec![],
+            n_rows,
+            seed: 0,
+        }
+    }
+
+    /// Set the seed; the same seed always generates the same table.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn with_column(mut self, column: ColumnSpec) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    pub fn n_rows(&self) -> usize {
+        self.n_rows
+    }
+
+    pub fn schema(&self) -> Schema {
+        self.columns
+            .iter()
+            .map(|c| Field::new(c.name.clone(), c.distribution.dtype()))
+            .collect()
+    }
+
+    fn validate(&self) -> PolarsResult<()> {
+        let mut names = PlHashSet::with_capacity(self.columns.len());
+        for c in &self.columns {
+            polars_ensure!(names.insert(c.name.as_str()), Duplicate: "column '{}' is generated more than once", c.name);
+            polars_ensure!(
+                (0.0..=1.0).contains(&c.null_ratio),
+                InvalidOperation: "`null_ratio` of column '{}' must be in [0, 1]", c.name
+            );
+            c.distribution.validate(self.n_rows as u64)?;
+        }
+        Ok(())
+    }
+
+    /// Generate the given rows of the columns at `projection` (all columns if `None`).
+    pub fn generate(
+        &self,
+        rows: Range<usize>,
+        projection: Option<&[PlSmallStr]>,
+    ) -> PolarsResult<DataFrame> {
+        self.validate()?;
+        self.generate_validated(rows, projection)
+    }
+
+    fn generate_validated(
+        &self,
+        rows: Range<usize>,
+        projection: Option<&[PlSmallStr]>,
+    ) -> PolarsResult<DataFrame> {
+        let rows = rows.start.min(self.n_rows) as u64..rows.end.min(self.n_rows) as u64;
+        let columns = match projection {
+            None => (0..self.columns.len()).collect::<Vec<_>>(),
+            Some(names) => names
+                .iter()
+                .map(|name| {
+                    self.columns
+                        .iter()
+                        .position(|c| &c.name == name)
+                        .ok_or_else(|| polars_err!(col_not_found = name))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?,
+        };
+
+        let columns = POOL.install(|| {
+            columns
+                .into_par_iter()
+                .map(|idx| self.columns[idx].generate(self.seed, idx as u64, rows.clone()))
+                .collect::<Vec<_>>()
+        });
+        DataFrame::new(columns)
+    }
+
+    /// A [`LazyFrame`] that generates this table. Slices and projections are pushed into the
+    /// generator and the streaming engine generates the table in morsels.
+    pub fn lazy(self) -> PolarsResult<LazyFrame> {
+        self.validate()?;
+        let args = ScanArgsAnonymous {
+            schema: Some(Arc::new(self.schema())),
+            name: "DATAGEN",
+            ..Default::default()
+        };
+        LazyFrame::anonymous_scan(Arc::new(self), args)
+    }
+}
+
+impl AnonymousScan for TableGenerator {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let start = scan_opts.offset;
+        let end = match scan_opts.n_rows {
+            Some(n_rows) => start.saturating_add(n_rows),
+            None => self.n_rows,
+        };
+        self.generate(start..end, scan_opts.with_columns.as_deref())
+    }
+
+    fn scan_batches(
+        &self,
+        scan_opts: AnonymousScanArgs,
+        batch_size: usize,
+    ) -> PolarsResult<AnonymousScanBatches> {
+        let start = scan_opts.offset.min(self.n_rows);
+        let end = match scan_opts.n_rows {
+            Some(n_rows) => start.saturating_add(n_rows).min(self.n_rows),
+            None => self.n_rows,
+        };
+        let projection = scan_opts.with_columns;
+        let batch_size = batch_size.max(1);
+        // Validate once rather than for every batch.
+        self.validate()?;
+        let this = self.clone();
+        Ok(Box::new((start..end).step_by(batch_size).map(
+            move |offset| {
+                this.generate_validated(
+                    offset..(offset + batch_size).min(end),
+                    projection.as_deref(),
+                )
+            },
+        )))
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(Arc::new(TableGenerator::schema(self)))
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_offset_pushdown(&self) -> bool {
+        true
+    }
+}
+
+// Shorthands for the benchmark table definitions.
+
+pub(crate) fn key(name: &str, repeat: u64) -> ColumnSpec {
+    ColumnSpec::new(name, Distribution::Sequence { start: 1, repeat })
+}
+
+pub(crate) fn int(name: &str, low: i64, high: i64) -> ColumnSpec {
+    ColumnSpec::new(name, Distribution::UniformInt { low, high })
+}
+
+pub(crate) fn float(name: &str, low: f64, high: f64) -> ColumnSpec {
+    ColumnSpec::new(name, Distribution::UniformFloat { low, high })
+}
+
+pub(crate) fn date(name: &str, start: i32, end: i32) -> ColumnSpec {
+    ColumnSpec::new(name, Distribution::Date { start, end })
+}
+
+pub(crate) fn label(name: &str, prefix: &str, width: usize) -> ColumnSpec {
+    let prefix = prefix.into();
+    ColumnSpec::new(
+        name,
+        Distribution::Label {
+            prefix,
+            start: 1,
+            width,
+        },
+    )
+}
+
+pub(crate) fn text(name: &str, prefix: &str, cardinality: u64) -> ColumnSpec {
+    let prefix = prefix.into();
+    ColumnSpec::new(
+        name,
+        Distribution::Strings {
+            prefix,
+            cardinality,
+        },
+    )
+}
+
+pub(crate) fn choice(name: &str, values: &[&str]) -> ColumnSpec {
+    let values = values.iter().map(|v| PlSmallStr::from(*v)).collect();
+    ColumnSpec::new(name, Distribution::Choice(values))
+}
+
+pub(crate) fn cycle(name: &str, values: &[&str]) -> ColumnSpec {
+    let values = values.iter().map(|v| PlSmallStr::from(*v)).collect();
+    ColumnSpec::new(name, Distribution::Cycle(values))
+}
+
+/// `base` rows scaled by `scale_factor`, at least one.
+pub(crate) fn scaled(base: usize, scale_factor: f64) -> usize {
+    ((base as f64 * scale_factor).round() as usize).max(1)
+}
+
+/// SplitMix64 finalizer over the combined key.
+#[inline]
+fn hash(seed: u64, stream: u64, row: u64) -> u64 {
+    let mut z = seed
+        ^ stream.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ row.wrapping_add(1).wrapping_mul(0xD1B5_4A32_D192_ED03);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Uniform float in `[0, 1)`.
+#[inline]
+fn unit(h: u64) -> f64 {
+    (h >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn generator() -> TableGenerator {
+        TableGenerator::new(1000)
+            .with_seed(7)
+            .with_column(ColumnSpec::new(
+                "id",
+                Distribution::Sequence {
+                    start: 1,
+                    repeat: 1,
+                },
+            ))
+            .with_column(
+                ColumnSpec::new("x", Distribution::UniformInt { low: -5, high: 5 })
+                    .with_null_ratio(0.2),
+            )
+            .with_column(ColumnSpec::new(
+                "s",
+                Distribution::Strings {
+                    prefix: "s".into(),
+                    cardinality: 10,
+                },
+            ))
+    }
+
+    #[test]
+    fn test_generate_deterministic() -> PolarsResult<()> {
+        let g = generator();
+        let full = g.generate(0..1000, None)?;
+        assert_eq!(full.shape(), (1000, 3));
+        assert!(full.equals_missing(&g.generate(0..1000, None)?));
+
+        // Any slice generates the same values.
+        assert!(
+            full.slice(100, 50)
+                .equals_missing(&g.generate(100..150, None)?)
+        );
+
+        let x = full.column("x")?.i64()?;
+        assert!(x.null_count() > 100 && x.null_count() < 300);
+        assert!(x.into_iter().flatten().all(|v| (-5..=5).contains(&v)));
+        assert!(full.column("s")?.n_unique()? <= 10);
+
+        let projected = g.generate(0..1000, Some(&["s".into()]))?;
+        assert!(projected.column("s")?.equals(full.column("s")?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_lazy() -> PolarsResult<()> {
+        let g = generator();
+        let out = g
+            .clone()
+            .lazy()?
+            .slice(10, 5)
+            .select([col("x")])
+            .collect()?;
+        assert!(out.equals_missing(&g.generate(10..15, Some(&["x".into()]))?));
+
+        let bad = TableGenerator::new(10)
+            .with_column(ColumnSpec::new("c", Distribution::Choice(Arc::from([]))));
+        assert!(bad.lazy().is_err());
+
+        // The values of the last row must not overflow.
+        let dates = |n_rows| {
+            TableGenerator::new(n_rows).with_column(ColumnSpec::new(
+                "d",
+                Distribution::DateSequence {
+                    start: i32::MAX - 9,
+                },
+            ))
+        };
+        assert!(dates(10).lazy().is_ok());
+        assert!(dates(11).lazy().is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+//! Synthetic tables for benchmarks and tests.
+//!
+//! A [`TableGenerator`] describes a table as a list of [`ColumnSpec`]s, each with a value
+//! [`Distribution`] and a null ratio. [`TableGenerator::lazy`] turns it into a
+//! [`LazyFrame`](polars_lazy::frame::LazyFrame) source that generates only the projected
+//! columns and sliced rows, in morsels when run on the streaming engine.
+//!
+//! Values are a pure function of the seed and the row index, so a table is reproducible
+//! regardless of how it is sliced or batched. [`TpchTable`] and [`TpcdsTable`] provide
+//! generators with the schemas and scaling of the TPC-H and TPC-DS benchmarks.
+mod generator;
+mod tpcds;
+mod tpch;
+
+pub use generator::{ColumnSpec, Distribution, TableGenerator};
+pub use tpcds::{TpcdsTable, tpcds_table};
+pub use tpch::{TpchTable, tpch_table};
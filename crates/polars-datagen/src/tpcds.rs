@@ -0,0 +1,162 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+
+use crate::generator::*;
+
+/// Surrogate keys of the first and last sales date (1998-01-02 and 2003-01-02).
+const FIRST_SALES_DATE_SK: i64 = 2_450_816;
+const LAST_SALES_DATE_SK: i64 = 2_452_642;
+/// Surrogate key of 1900-01-02, the first row of `date_dim`.
+const FIRST_DATE_SK: i64 = 2_415_022;
+/// 1900-01-02 in days since the epoch.
+const FIRST_DATE: i32 = -25_566;
+
+const CATEGORIES: &[&str] = &[
+    "Books",
+    "Children",
+    "Electronics",
+    "Home",
+    "Jewelry",
+    "Men",
+    "Music",
+    "Shoes",
+    "Sports",
+    "Women",
+];
+const STATES: &[&str] = &["AL", "CA", "GA", "IL", "MI", "NY", "OH", "TN", "TX", "WA"];
+
+/// The subset of the TPC-DS tables that make up the `store_sales` star schema.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TpcdsTable {
+    DateDim,
+    Item,
+    Customer,
+    Store,
+    StoreSales,
+}
+
+impl TpcdsTable {
+    pub const ALL: [TpcdsTable; 5] = [
+        TpcdsTable::DateDim,
+        TpcdsTable::Item,
+        TpcdsTable::Customer,
+        TpcdsTable::Store,
+        TpcdsTable::StoreSales,
+    ];
+
+    pub fn name(self) -> &'static str {
+        use TpcdsTable::*;
+        match self {
+            DateDim => "date_dim",
+            Item => "item",
+            Customer => "customer",
+            Store => "store",
+            StoreSales => "store_sales",
+        }
+    }
+
+    /// Number of rows at the given scale factor. Unlike the official generator, all
+    /// dimension tables except `date_dim` scale linearly.
+    pub fn n_rows(self, scale_factor: f64) -> usize {
+        use TpcdsTable::*;
+        match self {
+            DateDim => 73_049,
+            Item => scaled(18_000, scale_factor),
+            Customer => scaled(100_000, scale_factor),
+            Store => scaled(12, scale_factor),
+            StoreSales => scaled(2_880_404, scale_factor),
+        }
+    }
+
+    /// The generator of this table at the given scale factor.
+    ///
+    /// Only the most commonly queried columns of each table are generated. Foreign keys of
+    /// `store_sales` are null with the frequencies of the official data set.
+    pub fn generator(self, scale_factor: f64) -> TableGenerator {
+        use TpcdsTable::*;
+        let n_rows = self.n_rows(scale_factor);
+        let n = |table: TpcdsTable| table.n_rows(scale_factor) as i64;
+        let columns = match self {
+            DateDim => vec![
+                ColumnSpec::new(
+                    "d_date_sk",
+                    Distribution::Sequence {
+                        start: FIRST_DATE_SK,
+                        repeat: 1,
+                    },
+                ),
+                label("d_date_id", "AAAAAAAA", 8),
+                ColumnSpec::new("d_date", Distribution::DateSequence { start: FIRST_DATE }),
+            ],
+            Item => vec![
+                key("i_item_sk", 1),
+                label("i_item_id", "AAAAAAAA", 8),
+                float("i_current_price", 0.09, 99.99).with_null_ratio(0.0025),
+                text("i_brand", "brand #", 1000),
+                choice("i_category", CATEGORIES),
+                int("i_manufact_id", 1, 1000),
+            ],
+            Customer => vec![
+                key("c_customer_sk", 1),
+                label("c_customer_id", "AAAAAAAA", 8),
+                text("c_first_name", "first name ", 5000).with_null_ratio(0.035),
+                text("c_last_name", "last name ", 5000).with_null_ratio(0.035),
+                int("c_birth_year", 1924, 1992).with_null_ratio(0.035),
+                date("c_first_sales_date", 10_592, 12_418).with_null_ratio(0.035),
+            ],
+            Store => vec![
+                key("s_store_sk", 1),
+                label("s_store_id", "AAAAAAAA", 8),
+                text("s_store_name", "store ", 20),
+                int("s_number_employees", 200, 300),
+                choice("s_state", STATES),
+            ],
+            StoreSales => vec![
+                int("ss_sold_date_sk", FIRST_SALES_DATE_SK, LAST_SALES_DATE_SK)
+                    .with_null_ratio(0.045),
+                int("ss_item_sk", 1, n(Item)),
+                int("ss_customer_sk", 1, n(Customer)).with_null_ratio(0.045),
+                int("ss_store_sk", 1, n(Store)).with_null_ratio(0.045),
+                key("ss_ticket_number", 12),
+                int("ss_quantity", 1, 100).with_null_ratio(0.045),
+                float("ss_wholesale_cost", 1.0, 100.0).with_null_ratio(0.045),
+                float("ss_list_price", 1.0, 200.0).with_null_ratio(0.045),
+                float("ss_sales_price", 0.0, 200.0).with_null_ratio(0.045),
+                float("ss_net_profit", -10_000.0, 10_000.0).with_null_ratio(0.045),
+            ],
+        };
+        columns
+            .into_iter()
+            .fold(TableGenerator::new(n_rows), |g, c| g.with_column(c))
+    }
+}
+
+/// A [`LazyFrame`] generating the given TPC-DS table at `scale_factor`.
+pub fn tpcds_table(table: TpcdsTable, scale_factor: f64) -> PolarsResult<LazyFrame> {
+    polars_ensure!(
+        scale_factor > 0.0 && scale_factor.is_finite(),
+        InvalidOperation: "scale factor must be positive, got {}", scale_factor
+    );
+    table.generator(scale_factor).lazy()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tpcds_tables() -> PolarsResult<()> {
+        for table in TpcdsTable::ALL {
+            let g = table.generator(0.01);
+            let df = g.generate(0..1000, None)?;
+            assert_eq!(df.height(), table.n_rows(0.01).min(1000));
+        }
+
+        let store_sales = TpcdsTable::StoreSales.generator(0.01);
+        let df = store_sales.generate(0..10_000, Some(&["ss_store_sk".into()]))?;
+        let store_sk = df.column("ss_store_sk")?.i64()?;
+        assert!(store_sk.null_count() > 0);
+        assert!(store_sk.into_iter().flatten().all(|sk| sk == 1));
+        Ok(())
+    }
+}
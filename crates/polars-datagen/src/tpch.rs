@@ -0,0 +1,294 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+
+use crate::generator::*;
+
+/// First and last order date of the TPC-H data set (1992-01-01 and 1998-08-02).
+const START_DATE: i32 = 8035;
+const END_DATE: i32 = 10440;
+
+const REGIONS: &[&str] = &["AFRICA", "AMERICA", "ASIA", "EUROPE", "MIDDLE EAST"];
+/// Nations ordered by region, so that `n_regionkey == n_nationkey / 5`.
+const NATIONS: &[&str] = &[
+    "ALGERIA",
+    "ETHIOPIA",
+    "KENYA",
+    "MOROCCO",
+    "MOZAMBIQUE",
+    "ARGENTINA",
+    "BRAZIL",
+    "CANADA",
+    "PERU",
+    "UNITED STATES",
+    "CHINA",
+    "INDIA",
+    "INDONESIA",
+    "JAPAN",
+    "VIETNAM",
+    "FRANCE",
+    "GERMANY",
+    "ROMANIA",
+    "RUSSIA",
+    "UNITED KINGDOM",
+    "EGYPT",
+    "IRAN",
+    "IRAQ",
+    "JORDAN",
+    "SAUDI ARABIA",
+];
+const SEGMENTS: &[&str] = &[
+    "AUTOMOBILE",
+    "BUILDING",
+    "FURNITURE",
+    "HOUSEHOLD",
+    "MACHINERY",
+];
+const PRIORITIES: &[&str] = &["1-URGENT", "2-HIGH", "3-MEDIUM", "4-NOT SPECIFIED", "5-LOW"];
+const SHIP_INSTRUCTIONS: &[&str] = &[
+    "DELIVER IN PERSON",
+    "COLLECT COD",
+    "NONE",
+    "TAKE BACK RETURN",
+];
+const SHIP_MODES: &[&str] = &["REG AIR", "AIR", "RAIL", "SHIP", "TRUCK", "MAIL", "FOB"];
+const CONTAINERS: &[&str] = &[
+    "SM CASE",
+    "SM BOX",
+    "SM PACK",
+    "SM PKG",
+    "MED BAG",
+    "MED BOX",
+    "MED PKG",
+    "MED PACK",
+    "LG CASE",
+    "LG BOX",
+    "LG PACK",
+    "LG PKG",
+    "JUMBO BAG",
+    "JUMBO BOX",
+    "JUMBO PKG",
+    "WRAP CASE",
+];
+const TYPES: &[&str] = &[
+    "STANDARD ANODIZED TIN",
+    "SMALL PLATED COPPER",
+    "MEDIUM BURNISHED NICKEL",
+    "LARGE BRUSHED BRASS",
+    "ECONOMY POLISHED STEEL",
+    "PROMO PLATED TIN",
+];
+
+/// The tables of the TPC-H benchmark.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TpchTable {
+    Region,
+    Nation,
+    Supplier,
+    Customer,
+    Part,
+    PartSupp,
+    Orders,
+    LineItem,
+}
+
+impl TpchTable {
+    pub const ALL: [TpchTable; 8] = [
+        TpchTable::Region,
+        TpchTable::Nation,
+        TpchTable::Supplier,
+        TpchTable::Customer,
+        TpchTable::Part,
+        TpchTable::PartSupp,
+        TpchTable::Orders,
+        TpchTable::LineItem,
+    ];
+
+    pub fn name(self) -> &'static str {
+        use TpchTable::*;
+        match self {
+            Region => "region",
+            Nation => "nation",
+            Supplier => "supplier",
+            Customer => "customer",
+            Part => "part",
+            PartSupp => "partsupp",
+            Orders => "orders",
+            LineItem => "lineitem",
+        }
+    }
+
+    /// Number of rows at the given scale factor.
+    pub fn n_rows(self, scale_factor: f64) -> usize {
+        use TpchTable::*;
+        match self {
+            Region => REGIONS.len(),
+            Nation => NATIONS.len(),
+            Supplier => scaled(10_000, scale_factor),
+            Customer => scaled(150_000, scale_factor),
+            Part => scaled(200_000, scale_factor),
+            PartSupp => 4 * Part.n_rows(scale_factor),
+            Orders => scaled(1_500_000, scale_factor),
+            LineItem => 4 * Orders.n_rows(scale_factor),
+        }
+    }
+
+    /// The generator of this table at the given scale factor.
+    ///
+    /// The schemas follow the TPC-H specification and foreign keys always refer to existing
+    /// rows, but the values are drawn from simplified distributions. Every order has four
+    /// line items and every part four suppliers.
+    pub fn generator(self, scale_factor: f64) -> TableGenerator {
+        use TpchTable::*;
+        let n_rows = self.n_rows(scale_factor);
+        let n = |table: TpchTable| table.n_rows(scale_factor) as i64;
+        let columns = match self {
+            Region => vec![
+                ColumnSpec::new(
+                    "r_regionkey",
+                    Distribution::Sequence {
+                        start: 0,
+                        repeat: 1,
+                    },
+                ),
+                cycle("r_name", REGIONS),
+                text("r_comment", "region comment ", 1000),
+            ],
+            Nation => vec![
+                ColumnSpec::new(
+                    "n_nationkey",
+                    Distribution::Sequence {
+                        start: 0,
+                        repeat: 1,
+                    },
+                ),
+                cycle("n_name", NATIONS),
+                ColumnSpec::new(
+                    "n_regionkey",
+                    Distribution::Sequence {
+                        start: 0,
+                        repeat: 5,
+                    },
+                ),
+                text("n_comment", "nation comment ", 1000),
+            ],
+            Supplier => vec![
+                key("s_suppkey", 1),
+                label("s_name", "Supplier#", 9),
+                text("s_address", "address ", n(Supplier) as u64),
+                int("s_nationkey", 0, 24),
+                text("s_phone", "phone ", n(Supplier) as u64),
+                float("s_acctbal", -999.99, 9999.99),
+                text("s_comment", "supplier comment ", 10_000),
+            ],
+            Customer => vec![
+                key("c_custkey", 1),
+                label("c_name", "Customer#", 9),
+                text("c_address", "address ", n(Customer) as u64),
+                int("c_nationkey", 0, 24),
+                text("c_phone", "phone ", n(Customer) as u64),
+                float("c_acctbal", -999.99, 9999.99),
+                choice("c_mktsegment", SEGMENTS),
+                text("c_comment", "customer comment ", 10_000),
+            ],
+            Part => vec![
+                key("p_partkey", 1),
+                text("p_name", "part ", n(Part) as u64),
+                choice(
+                    "p_mfgr",
+                    &[
+                        "Manufacturer#1",
+                        "Manufacturer#2",
+                        "Manufacturer#3",
+                        "Manufacturer#4",
+                        "Manufacturer#5",
+                    ],
+                ),
+                text("p_brand", "Brand#", 25),
+                choice("p_type", TYPES),
+                int("p_size", 1, 50),
+                choice("p_container", CONTAINERS),
+                float("p_retailprice", 900.0, 2100.0),
+                text("p_comment", "part comment ", 10_000),
+            ],
+            PartSupp => vec![
+                key("ps_partkey", 4),
+                int("ps_suppkey", 1, n(Supplier)),
+                int("ps_availqty", 1, 9999),
+                float("ps_supplycost", 1.0, 1000.0),
+                text("ps_comment", "partsupp comment ", 10_000),
+            ],
+            Orders => vec![
+                key("o_orderkey", 1),
+                int("o_custkey", 1, n(Customer)),
+                choice("o_orderstatus", &["F", "O", "P"]),
+                float("o_totalprice", 800.0, 600_000.0),
+                date("o_orderdate", START_DATE, END_DATE),
+                choice("o_orderpriority", PRIORITIES),
+                text("o_clerk", "Clerk#", scaled(1000, scale_factor) as u64),
+                int("o_shippriority", 0, 0),
+                text("o_comment", "order comment ", 10_000),
+            ],
+            LineItem => vec![
+                key("l_orderkey", 4),
+                int("l_partkey", 1, n(Part)),
+                int("l_suppkey", 1, n(Supplier)),
+                ColumnSpec::new(
+                    "l_linenumber",
+                    Distribution::Modulo {
+                        start: 1,
+                        modulus: 4,
+                    },
+                ),
+                int("l_quantity", 1, 50),
+                float("l_extendedprice", 900.0, 105_000.0),
+                float("l_discount", 0.0, 0.1),
+                float("l_tax", 0.0, 0.08),
+                choice("l_returnflag", &["A", "N", "R"]),
+                choice("l_linestatus", &["F", "O"]),
+                date("l_shipdate", START_DATE + 1, END_DATE + 121),
+                date("l_commitdate", START_DATE + 30, END_DATE + 90),
+                date("l_receiptdate", START_DATE + 2, END_DATE + 151),
+                choice("l_shipinstruct", SHIP_INSTRUCTIONS),
+                choice("l_shipmode", SHIP_MODES),
+                text("l_comment", "lineitem comment ", 10_000),
+            ],
+        };
+        columns
+            .into_iter()
+            .fold(TableGenerator::new(n_rows), |g, c| g.with_column(c))
+    }
+}
+
+/// A [`LazyFrame`] generating the given TPC-H table at `scale_factor`.
+pub fn tpch_table(table: TpchTable, scale_factor: f64) -> PolarsResult<LazyFrame> {
+    polars_ensure!(
+        scale_factor > 0.0 && scale_factor.is_finite(),
+        InvalidOperation: "scale factor must be positive, got {}", scale_factor
+    );
+    table.generator(scale_factor).lazy()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tpch_tables() -> PolarsResult<()> {
+        for table in TpchTable::ALL {
+            let g = table.generator(0.01);
+            let df = g.generate(0..g.n_rows(), None)?;
+            assert_eq!(df.height(), table.n_rows(0.01));
+            assert_eq!(df.schema().as_ref(), &g.schema());
+        }
+
+        let lineitem = TpchTable::LineItem.generator(0.01);
+        let df = lineitem.generate(0..8, None)?;
+        let orderkey = df.column("l_orderkey")?.i64()?;
+        assert_eq!(
+            orderkey.into_no_null_iter().collect::<Vec<_>>(),
+            [1, 1, 1, 1, 2, 2, 2, 2]
+        );
+        assert!(tpch_table(TpchTable::Orders, 0.0).is_err());
+        Ok(())
+    }
+}
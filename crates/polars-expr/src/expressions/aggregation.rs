@@ -18,6 +18,7 @@ use crate::expressions::AggState::AggregatedScalar;
 use crate::expressions::{
     AggState, AggregationContext, PartitionedAggregation, PhysicalExpr, UpdateGroups,
 };
+use crate::reduce::{GroupedReduction, new_geo_harmonic_mean_reduction, new_product_reduction};
 
 #[derive(Debug, Clone, Copy)]
 pub struct AggregationType {
@@ -43,6 +44,17 @@ impl AggregationExpr {
             field,
         }
     }
+
+    /// The aggregations that have no kernel on [`Column`] are computed with the
+    /// [`GroupedReduction`] of the streaming engine.
+    fn new_reduction(&self, dtype: &DataType) -> PolarsResult<Box<dyn GroupedReduction>> {
+        match self.agg_type.groupby {
+            GroupByMethod::Product => new_product_reduction(dtype.clone()),
+            GroupByMethod::GeoMean => new_geo_harmonic_mean_reduction(dtype.clone(), false),
+            GroupByMethod::HarmonicMean => new_geo_harmonic_mean_reduction(dtype.clone(), true),
+            _ => unreachable!(),
+        }
+    }
 }
 
 impl PhysicalExpr for AggregationExpr {
@@ -135,6 +147,15 @@ impl PhysicalExpr for AggregationExpr {
                 s,
                 allow_threading,
             ),
+            GroupByMethod::Product | GroupByMethod::GeoMean | GroupByMethod::HarmonicMean => {
+                let mut reduction = self.new_reduction(s.dtype())?;
+                reduction.resize(1);
+                reduction.update_group(&s, 0, 0)?;
+                Ok(reduction
+                    .finalize()?
+                    .with_name(s.name().clone())
+                    .into_column())
+            },
             GroupByMethod::Groups => unreachable!(),
             GroupByMethod::NUnique => s.n_unique().map(|count| {
                 IdxCa::from_slice(s.name().clone(), &[count as IdxSize]).into_column()
@@ -206,6 +227,12 @@ impl PhysicalExpr for AggregationExpr {
                     let agg_c = c.agg_sum(&groups);
                     AggregatedScalar(agg_c.with_name(keep_name))
                 },
+                GroupByMethod::Product | GroupByMethod::GeoMean | GroupByMethod::HarmonicMean => {
+                    let (c, groups) = ac.get_final_aggregation();
+                    let reduction = self.new_reduction(c.dtype())?;
+                    let agg_s = reduce_groups(reduction, &c, &groups)?;
+                    AggregatedScalar(agg_s.with_name(keep_name).into_column())
+                },
                 GroupByMethod::Count { include_nulls } => {
                     if include_nulls || ac.get_values().null_count() == 0 {
                         // a few fast paths that prevent materializing new groups
@@ -754,6 +781,106 @@ impl PhysicalExpr for AggQuantileExpr {
     }
 }
 
+pub struct AggUdfExpr {
+    pub(crate) input: Arc<dyn PhysicalExpr>,
+    pub(crate) function: AggregationUdfFn,
+}
+
+impl AggUdfExpr {
+    pub fn new(input: Arc<dyn PhysicalExpr>, function: AggregationUdfFn) -> Self {
+        Self { input, function }
+    }
+
+    fn aggregate(&self, values: &Series) -> PolarsResult<AnyValue<'static>> {
+        let mut state = self.function.init();
+        self.function.update(state.as_mut(), values)?;
+        self.function.finalize(state.as_ref())
+    }
+}
+
+impl PhysicalExpr for AggUdfExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        None
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Column> {
+        let input = self.input.evaluate(df, state)?;
+        let dtype = self.function.output_dtype(input.dtype())?;
+        let value = self.aggregate(input.as_materialized_series())?;
+        Ok(Scalar::new(dtype, value).into_column(input.name().clone()))
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupPositions,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let mut ac = self.input.evaluate_on_groups(df, groups, state)?;
+        // don't change names by aggregations as is done in polars-core
+        let keep_name = ac.get_values().name().clone();
+        let dtype = self.function.output_dtype(ac.get_values().dtype())?;
+
+        let values = ac.flat_naive().into_owned().take_materialized_series();
+        let aggregated = POOL.install(|| {
+            ac.groups()
+                .par_iter()
+                .map(|indicator| {
+                    let group = match indicator {
+                        GroupsIndicator::Idx((_, idx)) => {
+                            // SAFETY: group tuples are always in bounds.
+                            unsafe { values.take_slice_unchecked(idx) }
+                        },
+                        GroupsIndicator::Slice([first, len]) => {
+                            values.slice(first as i64, len as usize)
+                        },
+                    };
+                    self.aggregate(&group)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        let agg = Series::from_any_values_and_dtype(keep_name, &aggregated, &dtype, true)?;
+
+        Ok(AggregationContext::from_agg_state(
+            AggregatedScalar(agg.into_column()),
+            Cow::Borrowed(groups),
+        ))
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        let field = self.input.to_field(input_schema)?;
+        let dtype = self.function.output_dtype(field.dtype())?;
+        Ok(Field::new(field.name, dtype))
+    }
+
+    fn is_scalar(&self) -> bool {
+        true
+    }
+}
+
+/// Reduces every group of `values` in a single pass over the groups.
+fn reduce_groups(
+    mut reduction: Box<dyn GroupedReduction>,
+    values: &Column,
+    groups: &GroupsType,
+) -> PolarsResult<Series> {
+    let values = values.rechunk();
+    let mut subset = Vec::with_capacity(values.len());
+    let mut group_idxs = Vec::with_capacity(values.len());
+    for (group_idx, indicator) in groups.iter().enumerate() {
+        match indicator {
+            GroupsIndicator::Idx((_, idx)) => subset.extend_from_slice(idx),
+            GroupsIndicator::Slice([first, len]) => subset.extend(first..first + len),
+        }
+        group_idxs.resize(subset.len(), group_idx as IdxSize);
+    }
+    reduction.resize(groups.len() as IdxSize);
+    // SAFETY: group tuples are always in bounds.
+    unsafe { reduction.update_groups_subset(&values, &subset, &group_idxs, 0)? };
+    reduction.finalize()
+}
+
 /// Simple wrapper to parallelize functions that can be divided over threads aggregated and
 /// finally aggregated in the main thread. This can be done for sum, min, max, etc.
 fn parallel_op_columns<F>(f: F, s: Column, allow_threading: bool) -> PolarsResult<Column>
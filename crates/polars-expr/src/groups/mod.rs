@@ -85,7 +85,9 @@ pub fn new_hash_grouper(key_schema: Arc<Schema>) -> Box<dyn Grouper> {
                 Box::new(single_key::SingleKeyHashGrouper::<Int128Type>::new())
             },
             #[cfg(feature = "dtype-categorical")]
-            DataType::Enum(_, _) => Box::new(single_key::SingleKeyHashGrouper::<UInt32Type>::new()),
+            DataType::Enum(_, _) | DataType::Categorical(_, _) => {
+                Box::new(single_key::SingleKeyHashGrouper::<UInt32Type>::new())
+            },
 
             DataType::String | DataType::Binary => Box::new(binview::BinviewHashGrouper::new()),
 
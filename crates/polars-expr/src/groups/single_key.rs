@@ -68,6 +68,17 @@ where
         unsafe {
             let s =
                 Series::from_chunks_and_dtype_unchecked(name.clone(), vec![Box::new(keys)], dtype);
+            // The string cache may have grown since the schema was resolved, so build the
+            // rev-map of categorical keys from the cache instead.
+            #[cfg(feature = "dtype-categorical")]
+            let s = match dtype {
+                DataType::Categorical(_, ordering) => {
+                    let physical = s.to_physical_repr().u32().unwrap().clone();
+                    CategoricalChunked::from_global_indices_unchecked(physical, *ordering)
+                        .into_series()
+                },
+                _ => s,
+            };
             DataFrame::new(vec![Column::from(s)]).unwrap()
         }
     }
@@ -29,8 +29,9 @@ pub fn hash_keys_variant_for_dtype(dt: &DataType) -> HashKeysVariant {
 
         #[cfg(feature = "dtype-decimal")]
         DataType::Decimal(_, _) => HashKeysVariant::Single,
+        // Categoricals must come from the same string cache, so their codes can be compared.
         #[cfg(feature = "dtype-categorical")]
-        DataType::Enum(_, _) => HashKeysVariant::Single,
+        DataType::Enum(_, _) | DataType::Categorical(_, _) => HashKeysVariant::Single,
 
         DataType::String | DataType::Binary => HashKeysVariant::Binview,
 
@@ -77,7 +78,7 @@ macro_rules! downcast_single_key_ca {
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(..) => { let $ca = $self.decimal().unwrap(); $($body)* },
             #[cfg(feature = "dtype-categorical")]
-            DataType::Enum(..) => { let $ca = $self.categorical().unwrap().physical(); $($body)* },
+            DataType::Enum(..) | DataType::Categorical(..) => { let $ca = $self.categorical().unwrap().physical(); $($body)* },
 
             _ => unreachable!(),
         }
@@ -85,6 +86,19 @@ macro_rules! downcast_single_key_ca {
 }
 pub(crate) use downcast_single_key_ca;
 
+/// The physical codes of categorical keys are only comparable across morsels if they come
+/// from the active string cache.
+#[cfg(feature = "dtype-categorical")]
+fn assert_global_categorical(key: &Column) {
+    if let DataType::Categorical(Some(rev_map), _) = key.dtype() {
+        assert!(
+            rev_map.is_active_global(),
+            "{}",
+            polars_err!(string_cache_mismatch)
+        );
+    }
+}
+
 /// Represents a DataFrame plus a hash per row, intended for keys in grouping
 /// or joining. The hashes may or may not actually be physically pre-computed,
 /// this depends per type.
@@ -107,40 +121,7 @@ impl HashKeys {
             || df.width() > 1
             || first_col_variant == HashKeysVariant::RowEncoded;
         if use_row_encoding {
-            let keys = df.get_columns();
-            #[cfg(feature = "dtype-categorical")]
-            for key in keys {
-                if let DataType::Categorical(Some(rev_map), _) = key.dtype() {
-                    assert!(
-                        rev_map.is_active_global(),
-                        "{}",
-                        polars_err!(string_cache_mismatch)
-                    );
-                }
-            }
-            let mut keys_encoded = _get_rows_encoded_unordered(keys).unwrap().into_array();
-
-            if !null_is_valid {
-                let validities = keys
-                    .iter()
-                    .map(|c| c.as_materialized_series().rechunk_validity())
-                    .collect_vec();
-                let combined = combine_validities_and_many(&validities);
-                keys_encoded.set_validity(combined);
-            }
-
-            // TODO: use vechash? Not supported yet for lists.
-            // let mut hashes = Vec::with_capacity(df.height());
-            // columns_to_hashes(df.get_columns(), Some(random_state), &mut hashes).unwrap();
-
-            let hashes = keys_encoded
-                .values_iter()
-                .map(|k| random_state.hash_one(k))
-                .collect();
-            Self::RowEncoded(RowEncodedKeys {
-                hashes: PrimitiveArray::from_vec(hashes),
-                keys: keys_encoded,
-            })
+            Self::row_encoded(df, random_state, &vec![null_is_valid; df.width()])
         } else if first_col_variant == HashKeysVariant::Binview {
             let keys = if let Ok(ca_str) = df[0].str() {
                 ca_str.as_binary()
@@ -165,6 +146,8 @@ impl HashKeys {
                 null_is_valid,
             })
         } else {
+            #[cfg(feature = "dtype-categorical")]
+            assert_global_categorical(&df[0]);
             Self::Single(SingleKeys {
                 random_state,
                 keys: df[0].as_materialized_series().rechunk(),
@@ -173,6 +156,53 @@ impl HashKeys {
         }
     }
 
+    /// Like [`HashKeys::from_df`], but with a separate `null_is_valid` for every key column.
+    pub fn from_df_per_key(
+        df: &DataFrame,
+        random_state: PlRandomState,
+        null_is_valid: &[bool],
+        force_row_encoding: bool,
+    ) -> Self {
+        assert_eq!(null_is_valid.len(), df.width());
+        if null_is_valid.iter().all_equal() {
+            let null_is_valid = null_is_valid.first().copied().unwrap_or(false);
+            return Self::from_df(df, random_state, null_is_valid, force_row_encoding);
+        }
+        Self::row_encoded(df, random_state, null_is_valid)
+    }
+
+    /// Row-encode the keys, a row is null if it is null in a key of which nulls aren't valid.
+    fn row_encoded(df: &DataFrame, random_state: PlRandomState, null_is_valid: &[bool]) -> Self {
+        let keys = df.get_columns();
+        #[cfg(feature = "dtype-categorical")]
+        keys.iter().for_each(assert_global_categorical);
+        let mut keys_encoded = _get_rows_encoded_unordered(keys).unwrap().into_array();
+
+        if !null_is_valid.iter().all(|&valid| valid) {
+            let validities = keys
+                .iter()
+                .zip(null_is_valid)
+                .filter(|(_, valid)| !**valid)
+                .map(|(c, _)| c.as_materialized_series().rechunk_validity())
+                .collect_vec();
+            let combined = combine_validities_and_many(&validities);
+            keys_encoded.set_validity(combined);
+        }
+
+        // TODO: use vechash? Not supported yet for lists.
+        // let mut hashes = Vec::with_capacity(df.height());
+        // columns_to_hashes(df.get_columns(), Some(random_state), &mut hashes).unwrap();
+
+        let hashes = keys_encoded
+            .values_iter()
+            .map(|k| random_state.hash_one(k))
+            .collect();
+        Self::RowEncoded(RowEncodedKeys {
+            hashes: PrimitiveArray::from_vec(hashes),
+            keys: keys_encoded,
+        })
+    }
+
     pub fn len(&self) -> usize {
         match self {
             HashKeys::RowEncoded(s) => s.keys.len(),
@@ -82,7 +82,9 @@ pub fn new_hash_hot_grouper(key_schema: Arc<Schema>, num_groups: usize) -> Box<d
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(_, _) => Box::new(SK::<Int128Type>::new(dt, ng)),
             #[cfg(feature = "dtype-categorical")]
-            DataType::Enum(_, _) => Box::new(SK::<UInt32Type>::new(dt, ng)),
+            DataType::Enum(_, _) | DataType::Categorical(_, _) => {
+                Box::new(SK::<UInt32Type>::new(dt, ng))
+            },
 
             DataType::String | DataType::Binary => {
                 Box::new(binview::BinviewHashHotGrouper::new(ng))
@@ -342,6 +342,10 @@ fn create_physical_expr_inner(
             state.local.has_implode |= matches!(agg, IRAggExpr::Implode(_));
             let allow_threading = state.allow_threading;
 
+            if let IRAggExpr::Udf { function, .. } = agg {
+                return Ok(Arc::new(AggUdfExpr::new(input, function.clone())));
+            }
+
             match ctxt {
                 Context::Default if !matches!(agg, IRAggExpr::Quantile { .. }) => {
                     use {GroupByMethod as GBM, IRAggExpr as I};
@@ -359,6 +363,9 @@ fn create_physical_expr_inner(
                         I::Implode(_) => GBM::Implode,
                         I::Quantile { .. } => unreachable!(),
                         I::Sum(_) => GBM::Sum,
+                        I::Product(_) => GBM::Product,
+                        I::GeoMean(_) => GBM::GeoMean,
+                        I::HarmonicMean(_) => GBM::HarmonicMean,
                         I::Count(_, include_nulls) => GBM::Count {
                             include_nulls: *include_nulls,
                         },
@@ -367,6 +374,7 @@ fn create_physical_expr_inner(
                         I::AggGroups(_) => {
                             polars_bail!(InvalidOperation: "agg groups expression only supported in aggregation context")
                         },
+                        I::Udf { .. } => unreachable!(),
                     };
 
                     let agg_type = AggregationType {
@@ -6,9 +6,11 @@ use super::*;
 use crate::reduce::count::CountReduce;
 use crate::reduce::first_last::{new_first_reduction, new_last_reduction};
 use crate::reduce::len::LenReduce;
-use crate::reduce::mean::new_mean_reduction;
+use crate::reduce::mean::{new_geo_harmonic_mean_reduction, new_mean_reduction};
 use crate::reduce::min_max::{new_max_reduction, new_min_reduction};
+use crate::reduce::product::new_product_reduction;
 use crate::reduce::sum::new_sum_reduction;
+use crate::reduce::udf::UdfReduce;
 use crate::reduce::var_std::new_var_std_reduction;
 
 /// Converts a node into a reduction + its associated selector expression.
@@ -27,6 +29,15 @@ pub fn into_reduction(
         AExpr::Agg(agg) => match agg {
             IRAggExpr::Sum(input) => (new_sum_reduction(get_dt(*input)?), *input),
             IRAggExpr::Mean(input) => (new_mean_reduction(get_dt(*input)?), *input),
+            IRAggExpr::Product(input) => (new_product_reduction(get_dt(*input)?)?, *input),
+            IRAggExpr::GeoMean(input) => (
+                new_geo_harmonic_mean_reduction(get_dt(*input)?, false)?,
+                *input,
+            ),
+            IRAggExpr::HarmonicMean(input) => (
+                new_geo_harmonic_mean_reduction(get_dt(*input)?, true)?,
+                *input,
+            ),
             IRAggExpr::Min {
                 propagate_nans,
                 input,
@@ -47,6 +58,10 @@ pub fn into_reduction(
                 let count = Box::new(CountReduce::new(*include_nulls)) as Box<_>;
                 (count, *input)
             },
+            IRAggExpr::Udf { input, function } => {
+                let udf = Box::new(UdfReduce::new(function.clone(), &get_dt(*input)?)?) as Box<_>;
+                (udf, *input)
+            },
             IRAggExpr::Quantile { .. } => todo!(),
             IRAggExpr::Median(_) => todo!(),
             IRAggExpr::NUnique(_) => todo!(),
@@ -25,6 +25,32 @@ pub fn new_mean_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
     }
 }
 
+/// Creates the reduction for `geo_mean` (`harmonic = false`) or `harmonic_mean`, which both
+/// sum a transform of the values and transform the mean back when finishing.
+pub fn new_geo_harmonic_mean_reduction(
+    dtype: DataType,
+    harmonic: bool,
+) -> PolarsResult<Box<dyn GroupedReduction>> {
+    let name = if harmonic {
+        "harmonic_mean"
+    } else {
+        "geo_mean"
+    };
+    polars_ensure!(
+        dtype.is_primitive_numeric(),
+        InvalidOperation: "expected numerical input for '{name}'"
+    );
+    Ok(
+        with_match_physical_numeric_polars_type!(dtype.to_physical(), |$T| {
+            let reducer = GeoHarmonicMeanReducer::<$T> {
+                harmonic,
+                _phantom: PhantomData,
+            };
+            Box::new(VecGroupedReduction::new(dtype, reducer))
+        }),
+    )
+}
+
 fn finish_output(values: Vec<(f64, usize)>, dtype: &DataType) -> Series {
     match dtype {
         DataType::Float32 => {
@@ -122,6 +148,92 @@ where
     }
 }
 
+struct GeoHarmonicMeanReducer<T> {
+    harmonic: bool,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> Clone for GeoHarmonicMeanReducer<T> {
+    fn clone(&self) -> Self {
+        Self {
+            harmonic: self.harmonic,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> GeoHarmonicMeanReducer<T> {
+    /// The geometric mean is `exp(mean(ln(x)))` so that it doesn't overflow, the harmonic
+    /// mean is `1 / mean(1 / x)`.
+    #[inline(always)]
+    fn transform(&self, v: f64) -> f64 {
+        if self.harmonic { 1.0 / v } else { v.ln() }
+    }
+}
+
+impl<T> Reducer for GeoHarmonicMeanReducer<T>
+where
+    T: PolarsNumericType,
+{
+    type Dtype = T;
+    type Value = (f64, usize);
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        (0.0, 0)
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.0 += b.0;
+        a.1 += b.1;
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<T::Native>, _seq_id: u64) {
+        if let Some(b) = b {
+            a.0 += self.transform(b.as_());
+            a.1 += 1;
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        for arr in ca.downcast_iter() {
+            for x in arr.iter().flatten() {
+                v.0 += self.transform(x.as_());
+            }
+        }
+        v.1 += ca.len() - ca.null_count();
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let means = v.into_iter().map(|(s, c)| {
+            (c != 0).then(|| {
+                if self.harmonic {
+                    c as f64 / s
+                } else {
+                    (s / c as f64).exp()
+                }
+            })
+        });
+        Ok(if dtype == &DataType::Float32 {
+            let ca: Float32Chunked = means
+                .map(|m| m.map(|m| m as f32))
+                .collect_ca(PlSmallStr::EMPTY);
+            ca.into_series()
+        } else {
+            let ca: Float64Chunked = means.collect_ca(PlSmallStr::EMPTY);
+            ca.into_series()
+        })
+    }
+}
+
 #[derive(Clone)]
 struct BoolMeanReducer;
 
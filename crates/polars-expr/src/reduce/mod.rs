@@ -5,7 +5,9 @@ mod first_last;
 mod len;
 mod mean;
 mod min_max;
+mod product;
 mod sum;
+mod udf;
 mod var_std;
 
 use std::any::Any;
@@ -15,7 +17,9 @@ use std::marker::PhantomData;
 use arrow::array::{Array, PrimitiveArray, StaticArray};
 use arrow::bitmap::{Bitmap, BitmapBuilder, MutableBitmap};
 pub use convert::into_reduction;
+pub(crate) use mean::new_geo_harmonic_mean_reduction;
 use polars_core::prelude::*;
+pub(crate) use product::new_product_reduction;
 
 use crate::EvictIdx;
 
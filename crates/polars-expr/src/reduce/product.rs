@@ -0,0 +1,88 @@
+use std::borrow::Cow;
+
+use arrow::array::PrimitiveArray;
+use num_traits::One;
+
+use super::*;
+
+pub fn new_product_reduction(dtype: DataType) -> PolarsResult<Box<dyn GroupedReduction>> {
+    use DataType::*;
+    use VecGroupedReduction as VGR;
+    // Small integers are multiplied as Int64 to prevent overflow, like `Series::product`.
+    Ok(match dtype {
+        Float32 => Box::new(VGR::new(dtype, ProductReducer::<Float32Type>(PhantomData))),
+        Float64 => Box::new(VGR::new(dtype, ProductReducer::<Float64Type>(PhantomData))),
+        UInt64 => Box::new(VGR::new(dtype, ProductReducer::<UInt64Type>(PhantomData))),
+        #[cfg(feature = "dtype-i128")]
+        Int128 => Box::new(VGR::new(dtype, ProductReducer::<Int128Type>(PhantomData))),
+        Boolean | Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 | Int64 => {
+            Box::new(VGR::new(dtype, ProductReducer::<Int64Type>(PhantomData)))
+        },
+        dt => polars_bail!(InvalidOperation: "`product` operation not supported for dtype `{dt}`"),
+    })
+}
+
+struct ProductReducer<T>(PhantomData<T>);
+impl<T> Clone for ProductReducer<T> {
+    fn clone(&self) -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Reducer for ProductReducer<T>
+where
+    T: PolarsNumericType,
+{
+    type Dtype = T;
+    type Value = T::Native;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        One::one()
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        if s.dtype() == &T::get_static_dtype() {
+            Cow::Borrowed(s)
+        } else {
+            Cow::Owned(s.cast(&T::get_static_dtype()).unwrap())
+        }
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        *a = *a * *b;
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<T::Native>, _seq_id: u64) {
+        if let Some(b) = b {
+            *a = *a * b;
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        for arr in ca.downcast_iter() {
+            for x in arr.iter().flatten() {
+                *v = *v * *x;
+            }
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        _dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let arr = Box::new(PrimitiveArray::from_vec(v));
+        Ok(unsafe {
+            Series::from_chunks_and_dtype_unchecked(
+                PlSmallStr::EMPTY,
+                vec![arr],
+                &T::get_static_dtype(),
+            )
+        })
+    }
+}
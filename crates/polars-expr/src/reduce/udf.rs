@@ -0,0 +1,141 @@
+#![allow(unsafe_op_in_unsafe_fn)]
+use polars_plan::dsl::{AggregationUdfFn, AggregationUdfState};
+
+use super::*;
+
+/// Adapts a user-defined aggregation to a [`GroupedReduction`].
+pub struct UdfReduce {
+    function: AggregationUdfFn,
+    out_dtype: DataType,
+    states: Vec<Box<AggregationUdfState>>,
+    evicted_states: Vec<Box<AggregationUdfState>>,
+}
+
+impl UdfReduce {
+    pub fn new(function: AggregationUdfFn, in_dtype: &DataType) -> PolarsResult<Self> {
+        let out_dtype = function.output_dtype(in_dtype)?;
+        Ok(Self {
+            function,
+            out_dtype,
+            states: Vec::new(),
+            evicted_states: Vec::new(),
+        })
+    }
+
+    fn empty_like(&self) -> Self {
+        Self {
+            function: self.function.clone(),
+            out_dtype: self.out_dtype.clone(),
+            states: Vec::new(),
+            evicted_states: Vec::new(),
+        }
+    }
+
+    /// Aggregate the rows of `values` at `idx` into the state of `group_idx`.
+    ///
+    /// # Safety
+    /// The indices are in-bounds.
+    unsafe fn update_rows(
+        &mut self,
+        values: &Series,
+        idx: &[IdxSize],
+        group_idx: usize,
+    ) -> PolarsResult<()> {
+        let rows = values.take_slice_unchecked(idx);
+        let state = self.states.get_unchecked_mut(group_idx);
+        self.function.update(state.as_mut(), &rows)
+    }
+}
+
+impl GroupedReduction for UdfReduce {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::new(self.empty_like())
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.states.reserve(additional);
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        let function = &self.function;
+        self.states
+            .resize_with(num_groups as usize, || function.init());
+    }
+
+    fn update_group(
+        &mut self,
+        values: &Column,
+        group_idx: IdxSize,
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        let state = &mut self.states[group_idx as usize];
+        self.function
+            .update(state.as_mut(), values.as_materialized_series())
+    }
+
+    unsafe fn update_groups_while_evicting(
+        &mut self,
+        values: &Column,
+        subset: &[IdxSize],
+        group_idxs: &[EvictIdx],
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(subset.len() == group_idxs.len());
+        let values = values.as_materialized_series(); // @scalar-opt
+
+        // Updating the state once per row is expensive for most aggregations, so the rows are
+        // gathered per group and only aggregated when the group is evicted or at the end.
+        let mut pending: PlHashMap<usize, Vec<IdxSize>> = PlHashMap::new();
+        for (i, g) in subset.iter().zip(group_idxs) {
+            if g.should_evict() {
+                if let Some(idx) = pending.remove(&g.idx()) {
+                    self.update_rows(values, &idx, g.idx())?;
+                }
+                let state = self.states.get_unchecked_mut(g.idx());
+                let evicted = std::mem::replace(state, self.function.init());
+                self.evicted_states.push(evicted);
+            }
+            pending.entry(g.idx()).or_default().push(*i);
+        }
+        for (group_idx, idx) in pending {
+            self.update_rows(values, &idx, group_idx)?;
+        }
+        Ok(())
+    }
+
+    unsafe fn combine_subset(
+        &mut self,
+        other: &dyn GroupedReduction,
+        subset: &[IdxSize],
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        assert!(subset.len() == group_idxs.len());
+        // SAFETY: indices are in-bounds guaranteed by trait.
+        for (i, g) in subset.iter().zip(group_idxs) {
+            let state = self.states.get_unchecked_mut(*g as usize);
+            let other_state = other.states.get_unchecked(*i as usize);
+            self.function.merge(state.as_mut(), other_state.as_ref())?;
+        }
+        Ok(())
+    }
+
+    fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
+        let mut out = self.empty_like();
+        out.states = core::mem::take(&mut self.evicted_states);
+        Box::new(out)
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let values = self
+            .states
+            .drain(..)
+            .map(|state| self.function.finalize(state.as_ref()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Series::from_any_values_and_dtype(PlSmallStr::EMPTY, &values, &self.out_dtype, true)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -8,7 +8,7 @@ use polars_core::config::verbose;
 use polars_core::prelude::*;
 use polars_ops::prelude::ChunkJoinOptIds;
 
-use super::NodeTimer;
+use super::{NodeStats, NodeTimer, NodeUsage};
 
 pub type JoinTuplesCache = Arc<Mutex<PlHashMap<String, ChunkJoinOptIds>>>;
 
@@ -155,10 +155,19 @@ impl ExecutionState {
                 Duration::from_nanos(start),
                 Duration::from_nanos(end),
                 name.to_string(),
+                NodeStats::default(),
             );
         }
     }
 
+    /// Record a node that was executed outside of this state, e.g. by the streaming engine.
+    /// The `start` and `end` are durations since the query start.
+    pub fn record_node(&self, start: Duration, end: Duration, name: String, stats: NodeStats) {
+        if let Some(timer) = &self.node_timer {
+            timer.store_duration(start, end, name, stats);
+        }
+    }
+
     // This is wrong when the U64 overflows which will never happen.
     pub fn should_stop(&self) -> PolarsResult<()> {
         try_raise_keyboard_interrupt();
@@ -174,11 +183,30 @@ impl ExecutionState {
         match &self.node_timer {
             None => func(),
             Some(timer) => {
-                let start = std::time::Instant::now();
+                let usage = NodeUsage::start();
+                let out = func();
+                let (start, end, stats) = usage.finish(None);
+
+                timer.store(start, end, name.as_ref().to_string(), stats);
+                out
+            },
+        }
+    }
+
+    /// Like [`ExecutionState::record`], but also records the rows of the produced [`DataFrame`].
+    pub fn record_frame<F: FnOnce() -> PolarsResult<DataFrame>>(
+        &self,
+        func: F,
+        name: Cow<'static, str>,
+    ) -> PolarsResult<DataFrame> {
+        match &self.node_timer {
+            None => func(),
+            Some(timer) => {
+                let usage = NodeUsage::start();
                 let out = func();
-                let end = std::time::Instant::now();
+                let (start, end, stats) = usage.finish(out.as_ref().ok());
 
-                timer.store(start, end, name.as_ref().to_string());
+                timer.store(start, end, name.as_ref().to_string(), stats);
                 out
             },
         }
@@ -9,35 +9,98 @@ type EndInstant = Instant;
 
 type Nodes = Vec<String>;
 type Ticks = Vec<(Duration, Duration)>;
+type Stats = Vec<NodeStats>;
+
+/// Resource statistics of a single profiled node.
+///
+/// Statistics that could not be measured for a node are `None`.
+#[derive(Clone, Debug, Default)]
+pub struct NodeStats {
+    /// Number of rows produced by the node.
+    pub rows: Option<u64>,
+    /// Largest estimated size in bytes of the data the node held at once.
+    pub peak_memory: Option<u64>,
+    /// Number of bytes the node spilled to disk, `None` for nodes that cannot spill.
+    pub spilled_bytes: Option<u64>,
+    /// Fraction of the node's threads that were busy while the node ran, in `[0, 1]`.
+    pub thread_utilization: Option<f64>,
+}
+
+/// Resource usage snapshot taken when a node of the in-memory engine starts.
+pub(super) struct NodeUsage {
+    start: Instant,
+}
+
+impl NodeUsage {
+    pub(super) fn start() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+
+    /// Compute the statistics of a node that produced `out`.
+    ///
+    /// Nodes of the in-memory engine can't spill, and their parallel work runs on the shared
+    /// thread pool where it can't be attributed to a single node, so both are left `None`.
+    pub(super) fn finish(self, out: Option<&DataFrame>) -> (StartInstant, EndInstant, NodeStats) {
+        let end = Instant::now();
+        let stats = NodeStats {
+            rows: out.map(|df| df.height() as u64),
+            peak_memory: out.map(|df| df.estimated_size() as u64),
+            spilled_bytes: None,
+            thread_utilization: None,
+        };
+        (self.start, end, stats)
+    }
+}
 
 #[derive(Clone)]
 pub(super) struct NodeTimer {
     query_start: Instant,
-    data: Arc<Mutex<(Nodes, Ticks)>>,
+    data: Arc<Mutex<(Nodes, Ticks, Stats)>>,
 }
 
 impl NodeTimer {
     pub(super) fn new(query_start: Instant) -> Self {
         Self {
             query_start,
-            data: Arc::new(Mutex::new((Vec::with_capacity(16), Vec::with_capacity(16)))),
+            data: Arc::new(Mutex::new((
+                Vec::with_capacity(16),
+                Vec::with_capacity(16),
+                Vec::with_capacity(16),
+            ))),
         }
     }
 
-    pub(super) fn store(&self, start: StartInstant, end: EndInstant, name: String) {
+    pub(super) fn store(
+        &self,
+        start: StartInstant,
+        end: EndInstant,
+        name: String,
+        stats: NodeStats,
+    ) {
         self.store_duration(
             start.duration_since(self.query_start),
             end.duration_since(self.query_start),
             name,
+            stats,
         )
     }
 
-    pub(super) fn store_duration(&self, start: Duration, end: Duration, name: String) {
+    pub(super) fn store_duration(
+        &self,
+        start: Duration,
+        end: Duration,
+        name: String,
+        stats: NodeStats,
+    ) {
         let mut data = self.data.lock().unwrap();
         let nodes = &mut data.0;
         nodes.push(name);
         let ticks = &mut data.1;
-        ticks.push((start, end))
+        ticks.push((start, end));
+        let node_stats = &mut data.2;
+        node_stats.push(stats);
     }
 
     pub(super) fn finish(self) -> PolarsResult<DataFrame> {
@@ -50,6 +113,8 @@ impl NodeTimer {
         polars_ensure!(!ticks.is_empty(), ComputeError: "no data to time");
         let start = ticks[0].0;
         ticks.push((Duration::from_nanos(0), start));
+        let mut stats = std::mem::take(&mut data.2);
+        stats.push(NodeStats::default());
         let nodes_s = Column::new(PlSmallStr::from_static("node"), nodes);
         let start: NoNull<UInt64Chunked> = ticks
             .iter()
@@ -65,8 +130,33 @@ impl NodeTimer {
         let mut end = end.into_inner();
         end.rename(PlSmallStr::from_static("end"));
 
+        let rows = UInt64Chunked::from_iter_options(
+            PlSmallStr::from_static("rows"),
+            stats.iter().map(|s| s.rows),
+        );
+        let peak_memory = UInt64Chunked::from_iter_options(
+            PlSmallStr::from_static("peak_memory"),
+            stats.iter().map(|s| s.peak_memory),
+        );
+        let spilled_bytes = UInt64Chunked::from_iter_options(
+            PlSmallStr::from_static("spilled_bytes"),
+            stats.iter().map(|s| s.spilled_bytes),
+        );
+        let thread_utilization = Float64Chunked::from_iter_options(
+            PlSmallStr::from_static("thread_utilization"),
+            stats.iter().map(|s| s.thread_utilization),
+        );
+
         let height = nodes_s.len();
-        let columns = vec![nodes_s, start.into_column(), end.into_column()];
+        let columns = vec![
+            nodes_s,
+            start.into_column(),
+            end.into_column(),
+            rows.into_column(),
+            peak_memory.into_column(),
+            spilled_bytes.into_column(),
+            thread_utilization.into_column(),
+        ];
         let df = unsafe { DataFrame::new_no_checks(height, columns) };
         df.sort(vec!["start"], SortMultipleOptions::default())
     }
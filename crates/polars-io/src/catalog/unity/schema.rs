@@ -321,7 +321,7 @@ fn dtype_to_type_text(dtype: &DataType) -> PolarsResult<PlSmallStr> {
             // affect us as we parse using `type_json` rather than this field.
             let mut out = std::string::String::from("struct<");
 
-            for Field { name, dtype } in fields {
+            for Field { name, dtype, .. } in fields {
                 out.push_str(name);
                 out.push(':');
                 out.push_str(&dtype_to_type_text(dtype)?);
@@ -471,7 +471,7 @@ fn dtype_to_type_json(dtype: &DataType) -> PolarsResult<ColumnTypeJsonType> {
                 fields: Some(
                     fields
                         .iter()
-                        .map(|Field { name, dtype }| field_to_type_json(name.clone(), dtype))
+                        .map(|Field { name, dtype, .. }| field_to_type_json(name.clone(), dtype))
                         .collect::<PolarsResult<_>>()?,
                 ),
 
@@ -80,6 +80,8 @@ pub enum ObjectStoreCredential {
     Azure(Arc<object_store::azure::AzureCredential>),
     #[cfg(feature = "gcp")]
     Gcp(Arc<object_store::gcp::GcpCredential>),
+    #[cfg(feature = "http")]
+    Http(Arc<HttpCredential>),
     /// For testing purposes
     None,
 }
@@ -93,6 +95,8 @@ impl ObjectStoreCredential {
             Self::Azure(_) => "Azure",
             #[cfg(feature = "gcp")]
             Self::Gcp(_) => "Gcp",
+            #[cfg(feature = "http")]
+            Self::Http(_) => "Http",
             Self::None => "None",
         }
     }
@@ -131,8 +135,28 @@ impl ObjectStoreCredential {
         };
         v
     }
+
+    #[cfg(feature = "http")]
+    fn unwrap_http(self) -> Arc<HttpCredential> {
+        let Self::Http(v) = self else {
+            self.panic_type_mismatch("http");
+            unreachable!()
+        };
+        v
+    }
 }
 
+/// Credential for plain HTTP(S) endpoints, sent as an `Authorization: Bearer` header.
+#[cfg(feature = "http")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpCredential {
+    pub bearer_token: String,
+}
+
+#[cfg(feature = "http")]
+pub type HttpCredentialProvider =
+    Arc<dyn object_store::CredentialProvider<Credential = HttpCredential>>;
+
 pub trait IntoCredentialProvider: Sized {
     #[cfg(feature = "aws")]
     fn into_aws_provider(self) -> object_store::aws::AwsCredentialProvider {
@@ -148,6 +172,11 @@ pub trait IntoCredentialProvider: Sized {
     fn into_gcp_provider(self) -> object_store::gcp::GcpCredentialProvider {
         unimplemented!()
     }
+
+    #[cfg(feature = "http")]
+    fn into_http_provider(self) -> HttpCredentialProvider {
+        unimplemented!()
+    }
 }
 
 impl IntoCredentialProvider for PlCredentialProvider {
@@ -177,6 +206,15 @@ impl IntoCredentialProvider for PlCredentialProvider {
             Self::Python(v) => v.into_gcp_provider(),
         }
     }
+
+    #[cfg(feature = "http")]
+    fn into_http_provider(self) -> HttpCredentialProvider {
+        match self {
+            Self::Function(v) => v.into_http_provider(),
+            #[cfg(feature = "python")]
+            Self::Python(v) => v.into_http_provider(),
+        }
+    }
 }
 
 type CredentialProviderFunctionImpl = Arc<
@@ -298,6 +336,37 @@ impl IntoCredentialProvider for CredentialProviderFunction {
             })),
         ))
     }
+
+    #[cfg(feature = "http")]
+    fn into_http_provider(self) -> HttpCredentialProvider {
+        #[derive(Debug)]
+        struct S(
+            CredentialProviderFunction,
+            FetchedCredentialsCache<Arc<HttpCredential>>,
+        );
+
+        #[async_trait]
+        impl object_store::CredentialProvider for S {
+            type Credential = HttpCredential;
+
+            async fn get_credential(&self) -> object_store::Result<Arc<Self::Credential>> {
+                self.1
+                    .get_maybe_update(async {
+                        let (creds, expiry) = self.0.0().await?;
+                        PolarsResult::Ok((creds.unwrap_http(), expiry))
+                    })
+                    .await
+                    .map_err(build_to_object_store_err!("credential-provider-http"))
+            }
+        }
+
+        Arc::new(S(
+            self,
+            FetchedCredentialsCache::new(Arc::new(HttpCredential {
+                bearer_token: String::new(),
+            })),
+        ))
+    }
 }
 
 impl Debug for CredentialProviderFunction {
@@ -735,6 +804,59 @@ mod python_impl {
             }))
             .into_gcp_provider()
         }
+
+        #[cfg(feature = "http")]
+        fn into_http_provider(self) -> super::HttpCredentialProvider {
+            use polars_error::PolarsResult;
+
+            use crate::cloud::credential_provider::{
+                CredentialProviderFunction, HttpCredential, ObjectStoreCredential,
+            };
+
+            let func = self.unwrap_as_provider();
+
+            CredentialProviderFunction(Arc::new(move || {
+                let func = func.clone();
+                Box::pin(async move {
+                    let mut credentials = HttpCredential {
+                        bearer_token: String::new(),
+                    };
+
+                    let expiry = Python::with_gil(|py| {
+                        let v = func.0.call0(py)?.into_bound(py);
+                        let (storage_options, expiry) =
+                            v.extract::<(pyo3::Bound<'_, PyDict>, Option<u64>)>()?;
+
+                        for (k, v) in storage_options.iter() {
+                            let k = k.extract::<PyBackedStr>()?;
+                            let v = v.extract::<String>()?;
+
+                            match k.as_ref() {
+                                "bearer_token" => credentials.bearer_token = v,
+                                v => {
+                                    return pyo3::PyResult::Err(PyValueError::new_err(format!(
+                                        "unknown configuration key for http: {}, \
+                                    valid configuration keys are: {}",
+                                        v, "bearer_token",
+                                    )));
+                                },
+                            }
+                        }
+
+                        pyo3::PyResult::Ok(expiry.unwrap_or(u64::MAX))
+                    })?;
+
+                    if credentials.bearer_token.is_empty() {
+                        return Err(PolarsError::ComputeError(
+                            "bearer_token was empty or not given".into(),
+                        ));
+                    }
+
+                    PolarsResult::Ok((ObjectStoreCredential::Http(Arc::new(credentials)), expiry))
+                })
+            }))
+            .into_http_provider()
+        }
     }
 
     // Note: We don't consider `is_builder` for hash/eq - we don't expect the same Arc<PythonObject>
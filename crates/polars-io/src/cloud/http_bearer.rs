@@ -0,0 +1,154 @@
+//! HTTP(S) store that authenticates every request with the current bearer token of a credential
+//! provider.
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use object_store::http::{HttpBuilder, HttpStore};
+use object_store::path::Path;
+use object_store::{
+    ClientOptions, GetOptions, GetResult, ListResult, MultipartUpload, ObjectMeta, ObjectStore,
+    PutMultipartOpts, PutOptions, PutPayload, PutResult, RetryConfig,
+};
+use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderValue};
+
+use super::credential_provider::{HttpCredential, HttpCredentialProvider};
+
+/// Wraps a [`HttpStore`] whose `Authorization` header is built from the credential provider.
+///
+/// The credential is requested before every request; the provider caches it until it expires, so
+/// this is cheap. The inner store is only rebuilt when the provider hands out a new token.
+#[derive(Debug)]
+pub(super) struct BearerTokenHttpStore {
+    url: String,
+    headers: HeaderMap,
+    client_options: ClientOptions,
+    retry_config: RetryConfig,
+    provider: HttpCredentialProvider,
+    current: Mutex<(Arc<HttpCredential>, Arc<HttpStore>)>,
+}
+
+impl BearerTokenHttpStore {
+    pub(super) async fn try_new(
+        url: &str,
+        headers: HeaderMap,
+        client_options: ClientOptions,
+        retry_config: RetryConfig,
+        provider: HttpCredentialProvider,
+    ) -> object_store::Result<Self> {
+        let credential = provider.get_credential().await?;
+        let store = build_store(url, &headers, &client_options, &retry_config, &credential)?;
+        Ok(Self {
+            url: url.to_string(),
+            headers,
+            client_options,
+            retry_config,
+            provider,
+            current: Mutex::new((credential, store)),
+        })
+    }
+
+    /// The store for the current token, fetching a new token if the cached one has expired.
+    async fn store(&self) -> object_store::Result<Arc<HttpStore>> {
+        let credential = self.provider.get_credential().await?;
+        let mut current = self.current.lock().unwrap();
+
+        if current.0.bearer_token != credential.bearer_token {
+            let store = build_store(
+                &self.url,
+                &self.headers,
+                &self.client_options,
+                &self.retry_config,
+                &credential,
+            )?;
+            *current = (credential, store);
+        }
+
+        Ok(current.1.clone())
+    }
+}
+
+fn build_store(
+    url: &str,
+    headers: &HeaderMap,
+    client_options: &ClientOptions,
+    retry_config: &RetryConfig,
+    credential: &HttpCredential,
+) -> object_store::Result<Arc<HttpStore>> {
+    let mut value =
+        HeaderValue::from_str(&format!("Bearer {}", credential.bearer_token)).map_err(|e| {
+            object_store::Error::Generic {
+                store: "HTTP",
+                source: Box::new(e),
+            }
+        })?;
+    value.set_sensitive(true);
+
+    let mut headers = headers.clone();
+    headers.insert(AUTHORIZATION, value);
+
+    let store = HttpBuilder::new()
+        .with_url(url)
+        .with_client_options(client_options.clone().with_default_headers(headers))
+        .with_retry(retry_config.clone())
+        .build()?;
+
+    Ok(Arc::new(store))
+}
+
+impl Display for BearerTokenHttpStore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BearerTokenHttpStore({})", self.url)
+    }
+}
+
+#[async_trait]
+impl ObjectStore for BearerTokenHttpStore {
+    async fn put_opts(
+        &self,
+        location: &Path,
+        payload: PutPayload,
+        opts: PutOptions,
+    ) -> object_store::Result<PutResult> {
+        self.store().await?.put_opts(location, payload, opts).await
+    }
+
+    async fn put_multipart_opts(
+        &self,
+        location: &Path,
+        opts: PutMultipartOpts,
+    ) -> object_store::Result<Box<dyn MultipartUpload>> {
+        self.store().await?.put_multipart_opts(location, opts).await
+    }
+
+    async fn get_opts(
+        &self,
+        location: &Path,
+        options: GetOptions,
+    ) -> object_store::Result<GetResult> {
+        self.store().await?.get_opts(location, options).await
+    }
+
+    async fn delete(&self, location: &Path) -> object_store::Result<()> {
+        self.store().await?.delete(location).await
+    }
+
+    /// Listing is synchronous, so it uses the most recently fetched token.
+    fn list(&self, prefix: Option<&Path>) -> BoxStream<'static, object_store::Result<ObjectMeta>> {
+        let store = self.current.lock().unwrap().1.clone();
+        store.list(prefix)
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> object_store::Result<ListResult> {
+        self.store().await?.list_with_delimiter(prefix).await
+    }
+
+    async fn copy(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        self.store().await?.copy(from, to).await
+    }
+
+    async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        self.store().await?.copy_if_not_exists(from, to).await
+    }
+}
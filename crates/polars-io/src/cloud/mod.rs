@@ -4,6 +4,8 @@
 mod adaptors;
 #[cfg(feature = "cloud")]
 mod glob;
+#[cfg(feature = "http")]
+mod http_bearer;
 #[cfg(feature = "cloud")]
 mod object_store_setup;
 pub mod options;
@@ -22,3 +24,5 @@ pub use polars_object_store::*;
 
 #[cfg(feature = "cloud")]
 pub mod credential_provider;
+#[cfg(feature = "cloud")]
+pub mod offload;
@@ -145,12 +145,11 @@ impl PolarsObjectStoreBuilder {
                 {
                     #[cfg(feature = "http")]
                     {
-                        let store = options.build_http(&self.url)?;
-                        PolarsResult::Ok(Arc::new(store) as Arc<dyn ObjectStore>)
+                        options.build_http(&self.url).await
                     }
                 }
                 #[cfg(not(feature = "http"))]
-                return err_missing_feature("http", &cloud_location.scheme);
+                return err_missing_feature("http", &self.scheme);
             },
             CloudType::Hf => panic!("impl error: unresolved hf:// path"),
         }?;
@@ -212,6 +211,13 @@ impl PolarsObjectStoreBuilder {
     pub(crate) fn is_azure(&self) -> bool {
         matches!(&self.cloud_type, CloudType::Azure)
     }
+
+    pub(crate) fn max_retries(&self) -> usize {
+        self.options
+            .as_ref()
+            .unwrap_or_else(|| CloudOptions::default_static_ref())
+            .max_retries
+    }
 }
 
 /// Build an [`ObjectStore`] based on the URL and passed in url. Return the cloud location and an implementation of the object store.
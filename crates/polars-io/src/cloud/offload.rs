@@ -0,0 +1,138 @@
+//! Offloading of scans of cloud objects to server-side facilities of the object store, such
+//! as S3 Select.
+use std::fmt::Write;
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+use polars_core::prelude::*;
+
+use super::CloudOptions;
+
+/// A scan of a single object that can be evaluated by the object store.
+#[derive(Clone, Debug)]
+pub struct OffloadRequest {
+    /// URL of the object.
+    pub url: PlSmallStr,
+    /// Name of the format of the object, e.g. `parquet`, `csv` or `ndjson`.
+    pub format: PlSmallStr,
+    pub cloud_options: Option<Arc<CloudOptions>>,
+    /// The columns the scan needs.
+    pub columns: Vec<PlSmallStr>,
+    /// Only the rows where the columns are equal to these values are needed. These are implied by
+    /// the predicate of the scan, which is applied to the returned rows again.
+    pub equalities: Vec<(PlSmallStr, Scalar)>,
+}
+
+impl OffloadRequest {
+    /// The request as an S3 Select SQL expression.
+    ///
+    /// Equalities with values that have no SQL literal are left out, which only makes the
+    /// result larger.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::from("SELECT ");
+        for (i, column) in self.columns.iter().enumerate() {
+            if i > 0 {
+                sql.push_str(", ");
+            }
+            write_identifier(&mut sql, column);
+        }
+        if self.columns.is_empty() {
+            sql.push('*');
+        }
+        sql.push_str(" FROM S3Object s");
+
+        let mut conjunction = " WHERE ";
+        for (column, value) in &self.equalities {
+            let Some(literal) = sql_literal(value.value()) else {
+                continue;
+            };
+            sql.push_str(conjunction);
+            write_identifier(&mut sql, column);
+            write!(sql, " = {literal}").unwrap();
+            conjunction = " AND ";
+        }
+        sql
+    }
+}
+
+fn write_identifier(sql: &mut String, name: &str) {
+    write!(sql, "s.\"{}\"", name.replace('"', "\"\"")).unwrap();
+}
+
+fn sql_literal(value: &AnyValue) -> Option<String> {
+    if value.is_integer() || value.is_boolean() {
+        return Some(value.to_string());
+    }
+    match value {
+        AnyValue::Float32(_) | AnyValue::Float64(_) => value
+            .extract::<f64>()
+            .filter(|v| v.is_finite())
+            .map(|_| value.to_string()),
+        AnyValue::String(v) => Some(format!("'{}'", v.replace('\'', "''"))),
+        AnyValue::StringOwned(v) => Some(format!("'{}'", v.replace('\'', "''"))),
+        _ => None,
+    }
+}
+
+/// Evaluates scans of cloud objects server side, so that only the needed columns and rows are
+/// transferred.
+///
+/// Offloads are registered per URL scheme with [`register_scan_offload`]. Scans offer every
+/// object they read without a row index or slice to the offload of its scheme, and read the
+/// object as usual if it declines.
+#[async_trait]
+pub trait ScanOffload: Send + Sync {
+    /// Name of the offload, used in verbose output.
+    fn name(&self) -> &str;
+
+    /// Evaluate the request, or return `None` to read the object as usual.
+    ///
+    /// The result must have the requested columns, and contain the rows of the object that
+    /// satisfy the equalities in their original order. It may contain other rows as well.
+    async fn offload(&self, request: OffloadRequest) -> PolarsResult<Option<DataFrame>>;
+}
+
+static SCAN_OFFLOADS: RwLock<Vec<(PlSmallStr, Arc<dyn ScanOffload>)>> = RwLock::new(Vec::new());
+
+/// Register the offload for URLs with the `scheme`, e.g. `s3`, or remove it with `None`.
+pub fn register_scan_offload(scheme: &str, offload: Option<Arc<dyn ScanOffload>>) {
+    let mut offloads = SCAN_OFFLOADS.write().unwrap();
+    offloads.retain(|(s, _)| s != scheme);
+    if let Some(offload) = offload {
+        offloads.push((scheme.into(), offload));
+    }
+}
+
+/// The offload registered for the scheme of `url`.
+pub fn get_scan_offload(url: &str) -> Option<Arc<dyn ScanOffload>> {
+    let (scheme, _) = url.split_once("://")?;
+    let offloads = SCAN_OFFLOADS.read().unwrap();
+    offloads
+        .iter()
+        .find(|(s, _)| s == scheme)
+        .map(|(_, offload)| offload.clone())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_offload_request_to_sql() {
+        let request = OffloadRequest {
+            url: "s3://bucket/data.csv".into(),
+            format: "csv".into(),
+            cloud_options: None,
+            columns: vec!["a".into(), "b\"".into()],
+            equalities: vec![
+                ("a".into(), Scalar::from(1i32)),
+                ("c".into(), Scalar::from(PlSmallStr::from_static("it's"))),
+                ("d".into(), Scalar::null(DataType::Int32)),
+            ],
+        };
+        assert_eq!(
+            request.to_sql(),
+            "SELECT s.\"a\", s.\"b\"\"\" FROM S3Object s WHERE s.\"a\" = 1 AND s.\"c\" = 'it''s'"
+        );
+    }
+}
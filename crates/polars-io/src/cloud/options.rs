@@ -19,7 +19,7 @@ use object_store::azure::MicrosoftAzureBuilder;
 use object_store::gcp::GoogleCloudStorageBuilder;
 #[cfg(feature = "gcp")]
 pub use object_store::gcp::GoogleConfigKey;
-#[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
+#[cfg(any(feature = "aws", feature = "gcp", feature = "azure", feature = "http"))]
 use object_store::{BackoffConfig, RetryConfig};
 use polars_error::*;
 #[cfg(feature = "aws")]
@@ -198,7 +198,7 @@ impl FromStr for CloudType {
         polars_bail!(ComputeError: "at least one of the cloud features must be enabled");
     }
 }
-#[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
+#[cfg(any(feature = "aws", feature = "gcp", feature = "azure", feature = "http"))]
 fn get_retry_config(max_retries: usize) -> RetryConfig {
     RetryConfig {
         backoff: BackoffConfig::default(),
@@ -496,22 +496,45 @@ impl CloudOptions {
         Ok(out)
     }
 
+    /// Build the [`object_store::ObjectStore`] implementation for plain HTTP(S) endpoints.
+    ///
+    /// With a credential provider, its bearer token is sent as the `Authorization` header. The
+    /// token is requested before every request, so an expired token is replaced by a new one.
     #[cfg(feature = "http")]
-    pub fn build_http(&self, url: &str) -> PolarsResult<impl object_store::ObjectStore> {
+    pub async fn build_http(
+        &self,
+        url: &str,
+    ) -> PolarsResult<std::sync::Arc<dyn object_store::ObjectStore>> {
+        use super::credential_provider::IntoCredentialProvider;
+
+        let headers = match &self.config {
+            Some(CloudConfig::Http { headers }) => {
+                try_build_http_header_map_from_items_slice(headers.as_slice())?
+            },
+            _ => HeaderMap::new(),
+        };
+        let client_options = super::get_client_options();
+        let retry_config = get_retry_config(self.max_retries);
+
+        if let Some(v) = self.initialized_credential_provider()? {
+            let store = super::http_bearer::BearerTokenHttpStore::try_new(
+                url,
+                headers,
+                client_options,
+                retry_config,
+                v.into_http_provider(),
+            )
+            .await?;
+            return Ok(std::sync::Arc::new(store));
+        }
+
         let out = object_store::http::HttpBuilder::new()
             .with_url(url)
-            .with_client_options({
-                let mut opts = super::get_client_options();
-                if let Some(CloudConfig::Http { headers }) = &self.config {
-                    opts = opts.with_default_headers(try_build_http_header_map_from_items_slice(
-                        headers.as_slice(),
-                    )?);
-                }
-                opts
-            })
+            .with_client_options(client_options.with_default_headers(headers))
+            .with_retry(retry_config)
             .build()?;
 
-        Ok(out)
+        Ok(std::sync::Arc::new(out))
     }
 
     /// Parse a configuration from a Hashmap. This is the interface from Python.
@@ -3,9 +3,10 @@ use std::ops::Range;
 use bytes::Bytes;
 use futures::{StreamExt, TryStreamExt};
 use object_store::path::Path;
-use object_store::{ObjectMeta, ObjectStore};
+use object_store::{GetOptions, ObjectMeta, ObjectStore};
+use polars_core::config;
 use polars_core::prelude::{InitHashMaps, PlHashMap};
-use polars_error::{PolarsError, PolarsResult};
+use polars_error::{PolarsError, PolarsResult, polars_ensure};
 use tokio::io::{AsyncSeekExt, AsyncWriteExt};
 
 use crate::pl_async::{
@@ -95,6 +96,11 @@ mod inner {
             Ok((*current_store).clone())
         }
 
+        /// Maximum number of times a request is retried, and a range download resumed.
+        pub(crate) fn max_retries(&self) -> usize {
+            self.inner.builder.max_retries()
+        }
+
         pub async fn try_exec_rebuild_on_err<Fn, Fut, O>(&self, mut func: Fn) -> PolarsResult<O>
         where
             Fn: FnMut(&Arc<dyn ObjectStore>) -> Fut,
@@ -154,20 +160,20 @@ impl PolarsObjectStore {
         store: &'a dyn ObjectStore,
         path: &'a Path,
         ranges: T,
+        max_resumes: usize,
     ) -> impl StreamExt<Item = PolarsResult<Bytes>>
     + TryStreamExt<Ok = Bytes, Error = PolarsError, Item = PolarsResult<Bytes>>
     + use<'a, T> {
-        futures::stream::iter(ranges.map(move |range| async move {
-            let out = store
-                .get_range(path, range.start as u64..range.end as u64)
-                .await?;
-            Ok(out)
-        }))
+        futures::stream::iter(
+            ranges.map(move |range| get_range_resumable(store, path, range, max_resumes)),
+        )
         // Add a limit locally as this gets run inside a single `tune_with_concurrency_budget`.
         .buffered(get_concurrency_limit() as usize)
     }
 
     pub async fn get_range(&self, path: &Path, range: Range<usize>) -> PolarsResult<Bytes> {
+        let max_resumes = self.max_retries();
+
         self.try_exec_rebuild_on_err(move |store| {
             let range = range.clone();
             let st = store.clone();
@@ -178,9 +184,7 @@ impl PolarsObjectStore {
 
                 if parts.len() == 1 {
                     let out = tune_with_concurrency_budget(1, move || async move {
-                        store
-                            .get_range(path, range.start as u64..range.end as u64)
-                            .await
+                        get_range_resumable(&store, path, range, max_resumes).await
                     })
                     .await?;
 
@@ -189,7 +193,7 @@ impl PolarsObjectStore {
                     let parts = tune_with_concurrency_budget(
                         parts.len().clamp(0, MAX_BUDGET_PER_REQUEST) as u32,
                         || {
-                            Self::get_buffered_ranges_stream(&store, path, parts)
+                            Self::get_buffered_ranges_stream(&store, path, parts, max_resumes)
                                 .try_collect::<Vec<Bytes>>()
                         },
                     )
@@ -231,6 +235,7 @@ impl PolarsObjectStore {
 
         let ranges_len = ranges.len();
         let (merged_ranges, merged_ends): (Vec<_>, Vec<_>) = merge_ranges(ranges).unzip();
+        let max_resumes = self.max_retries();
 
         self.try_exec_rebuild_on_err(|store| {
             let st = store.clone();
@@ -239,8 +244,12 @@ impl PolarsObjectStore {
                 let store = st;
                 let mut out = PlHashMap::with_capacity(ranges_len);
 
-                let mut stream =
-                    Self::get_buffered_ranges_stream(&store, path, merged_ranges.iter().cloned());
+                let mut stream = Self::get_buffered_ranges_stream(
+                    &store,
+                    path,
+                    merged_ranges.iter().cloned(),
+                    max_resumes,
+                );
 
                 tune_with_concurrency_budget(
                     merged_ranges.len().clamp(0, MAX_BUDGET_PER_REQUEST) as u32,
@@ -313,6 +322,7 @@ impl PolarsObjectStore {
         let opt_size = self.head(path).await.ok().map(|x| x.size);
 
         let initial_pos = file.stream_position().await?;
+        let max_resumes = self.max_retries();
 
         self.try_exec_rebuild_on_err(|store| {
             let st = store.clone();
@@ -332,7 +342,8 @@ impl PolarsObjectStore {
                     tune_with_concurrency_budget(
                         parts.len().clamp(0, MAX_BUDGET_PER_REQUEST) as u32,
                         || async {
-                            let mut stream = Self::get_buffered_ranges_stream(&store, path, parts);
+                            let mut stream =
+                                Self::get_buffered_ranges_stream(&store, path, parts, max_resumes);
                             let mut len = 0;
                             while let Some(bytes) = stream.try_next().await? {
                                 len += bytes.len();
@@ -409,6 +420,81 @@ impl PolarsObjectStore {
     }
 }
 
+/// Fetch a range of the object. If the connection breaks while the body is downloaded, the
+/// remainder of the range is requested again, at most `max_resumes` times. The object must not
+/// change in between.
+async fn get_range_resumable(
+    store: &dyn ObjectStore,
+    path: &Path,
+    range: Range<usize>,
+    max_resumes: usize,
+) -> PolarsResult<Bytes> {
+    if range.is_empty() {
+        return Ok(Bytes::new());
+    }
+
+    let mut parts = vec![];
+    let mut offset = range.start;
+    let mut e_tag = None;
+    let mut n_resumes = 0;
+
+    loop {
+        let result = store
+            .get_opts(
+                path,
+                GetOptions {
+                    range: Some((offset as u64..range.end as u64).into()),
+                    if_match: e_tag.clone(),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        if n_resumes == 0 {
+            e_tag = result.meta.e_tag.clone();
+        }
+
+        let mut stream = result.into_stream();
+        let err = loop {
+            match stream.try_next().await {
+                Ok(Some(bytes)) => {
+                    offset += bytes.len();
+                    parts.push(bytes);
+                },
+                Ok(None) => break None,
+                Err(e) => break Some(e),
+            }
+        };
+
+        match err {
+            None => break,
+            Some(e) if n_resumes < max_resumes && offset < range.end => {
+                n_resumes += 1;
+                if config::verbose() {
+                    eprintln!(
+                        "[PolarsObjectStore]: resuming download of {} at byte {} after error: {}",
+                        path, offset, e
+                    );
+                }
+            },
+            Some(e) => return Err(e.into()),
+        }
+    }
+
+    polars_ensure!(
+        offset == range.end,
+        ComputeError: "expected {} bytes from {}, got {}", range.len(), path, offset - range.start
+    );
+
+    if parts.len() == 1 {
+        return Ok(parts.pop().unwrap());
+    }
+    let mut combined = Vec::with_capacity(range.len());
+    for part in parts {
+        combined.extend_from_slice(&part);
+    }
+    Ok(Bytes::from(combined))
+}
+
 /// Splits a single range into multiple smaller ranges, which can be downloaded concurrently for
 /// much higher throughput.
 fn split_range(range: Range<usize>) -> impl ExactSizeIterator<Item = Range<usize>> {
@@ -0,0 +1,439 @@
+//! Encryption at rest of IPC files with AES-256-GCM.
+//!
+//! An encrypted file starts with a header that contains the id of the key it was encrypted with,
+//! followed by the IPC file split in segments that are encrypted on their own:
+//!
+//! ```text
+//! header:  magic (8 bytes) | version (u8) | key id length (u16) | key id
+//! segment: flags (u8) | nonce (12 bytes) | ciphertext length (u32) | ciphertext with tag
+//! ```
+//!
+//! The index of a segment and whether it is the last one are authenticated with the ciphertext,
+//! so that reordered, truncated or extended files are rejected.
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::io::{Read, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+
+use polars_error::{PolarsResult, polars_ensure, polars_err};
+use polars_utils::pl_str::PlSmallStr;
+
+const MAGIC: &[u8; 8] = b"PLENCIPC";
+const VERSION: u8 = 1;
+const SEGMENT_SIZE: usize = 1 << 20;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+const LAST_SEGMENT: u8 = 1;
+
+/// Supplies the keys that IPC files are encrypted and decrypted with.
+pub trait EncryptionKeyProvider: Send + Sync {
+    /// The id of the key that new files are encrypted with. The id is stored unencrypted in the
+    /// header of the file, so that the key can be found again when the file is read.
+    fn current_key_id(&self) -> PolarsResult<PlSmallStr>;
+
+    /// The 256-bit key with the given id.
+    fn key(&self, key_id: &str) -> PolarsResult<[u8; 32]>;
+}
+
+/// A provider of a single key.
+pub struct StaticKeyProvider {
+    key_id: PlSmallStr,
+    key: [u8; 32],
+}
+
+impl StaticKeyProvider {
+    pub fn new(key_id: PlSmallStr, key: [u8; 32]) -> Self {
+        Self { key_id, key }
+    }
+}
+
+impl EncryptionKeyProvider for StaticKeyProvider {
+    fn current_key_id(&self) -> PolarsResult<PlSmallStr> {
+        Ok(self.key_id.clone())
+    }
+
+    fn key(&self, key_id: &str) -> PolarsResult<[u8; 32]> {
+        polars_ensure!(
+            key_id == self.key_id,
+            ComputeError: "unknown encryption key id '{}'", key_id
+        );
+        Ok(self.key)
+    }
+}
+
+/// Encryption of IPC files with the keys of an [`EncryptionKeyProvider`].
+///
+/// An encryption is identified by the key id that the provider returned when it was created:
+/// encryptions compare and hash equal if they have the same key id. Only that key id is
+/// serialized, a deserialized encryption has no provider and fails when it is used to encrypt
+/// or decrypt a file.
+#[derive(Clone)]
+pub struct IpcEncryption {
+    key_id: PlSmallStr,
+    provider: Option<Arc<dyn EncryptionKeyProvider>>,
+}
+
+impl IpcEncryption {
+    pub fn new(provider: Arc<dyn EncryptionKeyProvider>) -> PolarsResult<Self> {
+        Ok(Self {
+            key_id: provider.current_key_id()?,
+            provider: Some(provider),
+        })
+    }
+
+    /// Encryption with a random key that only lives in memory. Files encrypted with it can only
+    /// be read by this process, which is what we want for temporary files.
+    pub fn ephemeral() -> PolarsResult<Self> {
+        static NUM_EPHEMERAL: AtomicU64 = AtomicU64::new(0);
+
+        let key = Cipher::random_key()?;
+        let key_id = format!(
+            "ephemeral-{}-{}",
+            std::process::id(),
+            NUM_EPHEMERAL.fetch_add(1, Ordering::Relaxed)
+        );
+        Self::new(Arc::new(StaticKeyProvider::new(key_id.into(), key)))
+    }
+
+    /// The key id that identifies this encryption.
+    pub fn key_id(&self) -> &PlSmallStr {
+        &self.key_id
+    }
+
+    pub fn provider(&self) -> PolarsResult<&Arc<dyn EncryptionKeyProvider>> {
+        self.provider.as_ref().ok_or_else(|| {
+            polars_err!(
+                ComputeError: "the key provider of IPC encryption '{}' is not serialized, \
+                set the encryption again after deserializing",
+                self.key_id
+            )
+        })
+    }
+}
+
+impl Debug for IpcEncryption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IpcEncryption({:?})", self.key_id)
+    }
+}
+
+impl PartialEq for IpcEncryption {
+    fn eq(&self, other: &Self) -> bool {
+        self.key_id == other.key_id
+    }
+}
+
+impl Eq for IpcEncryption {}
+
+impl Hash for IpcEncryption {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key_id.hash(state)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for IpcEncryption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.key_id)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for IpcEncryption {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(Self {
+            key_id: <String as serde::Deserialize>::deserialize(deserializer)?.into(),
+            provider: None,
+        })
+    }
+}
+
+static SPILL_ENCRYPTION: RwLock<Option<IpcEncryption>> = RwLock::new(None);
+
+/// Set the encryption of the files that operations spill to disk, `None` disables it. Use
+/// [`IpcEncryption::ephemeral`] to encrypt them with a key that only lives in memory.
+pub fn set_spill_encryption(encryption: Option<IpcEncryption>) {
+    *SPILL_ENCRYPTION.write().unwrap() = encryption;
+}
+
+/// The encryption of the files that operations spill to disk, see [`set_spill_encryption`].
+pub fn spill_encryption() -> Option<IpcEncryption> {
+    SPILL_ENCRYPTION.read().unwrap().clone()
+}
+
+struct Cipher {
+    key: ring::aead::LessSafeKey,
+    rng: ring::rand::SystemRandom,
+}
+
+impl Cipher {
+    fn try_new(key: &[u8; 32]) -> PolarsResult<Self> {
+        use ring::aead::{AES_256_GCM, LessSafeKey, UnboundKey};
+        let key = UnboundKey::new(&AES_256_GCM, key)
+            .map_err(|_| polars_err!(ComputeError: "invalid AES-256-GCM key"))?;
+        Ok(Self {
+            key: LessSafeKey::new(key),
+            rng: ring::rand::SystemRandom::new(),
+        })
+    }
+
+    fn random_key() -> PolarsResult<[u8; 32]> {
+        use ring::rand::SecureRandom;
+        let mut key = [0u8; 32];
+        ring::rand::SystemRandom::new()
+            .fill(&mut key)
+            .map_err(|_| polars_err!(ComputeError: "failed to generate an encryption key"))?;
+        Ok(key)
+    }
+
+    /// Encrypts `data` in place and returns the nonce it was encrypted with.
+    fn seal(&self, aad: &[u8], data: &mut Vec<u8>) -> PolarsResult<[u8; NONCE_LEN]> {
+        use ring::aead::{Aad, Nonce};
+        use ring::rand::SecureRandom;
+
+        let mut nonce = [0u8; NONCE_LEN];
+        self.rng
+            .fill(&mut nonce)
+            .map_err(|_| polars_err!(ComputeError: "failed to generate a nonce"))?;
+        self.key
+            .seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::from(aad), data)
+            .map_err(|_| polars_err!(ComputeError: "failed to encrypt IPC segment"))?;
+        Ok(nonce)
+    }
+
+    /// Decrypts `data` in place and truncates it to the plaintext.
+    fn open(&self, aad: &[u8], nonce: [u8; NONCE_LEN], data: &mut Vec<u8>) -> PolarsResult<()> {
+        use ring::aead::{Aad, Nonce};
+
+        let len = self
+            .key
+            .open_in_place(Nonce::assume_unique_for_key(nonce), Aad::from(aad), data)
+            .map_err(|_| {
+                polars_err!(ComputeError: "failed to decrypt IPC file: wrong key or corrupted data")
+            })?
+            .len();
+        data.truncate(len);
+        Ok(())
+    }
+}
+
+fn segment_aad(segment_idx: u64, flags: u8) -> [u8; 9] {
+    let mut aad = [0u8; 9];
+    aad[..8].copy_from_slice(&segment_idx.to_le_bytes());
+    aad[8] = flags;
+    aad
+}
+
+/// A writer that encrypts everything written to it. [`EncryptedWriter::finish`] must be called
+/// after the last write, otherwise the file can't be read.
+pub struct EncryptedWriter<W: Write> {
+    inner: W,
+    cipher: Cipher,
+    buf: Vec<u8>,
+    segment_idx: u64,
+    finished: bool,
+}
+
+impl<W: Write> EncryptedWriter<W> {
+    pub fn try_new(mut inner: W, encryption: &IpcEncryption) -> PolarsResult<Self> {
+        let provider = encryption.provider()?;
+        let key_id = provider.current_key_id()?;
+        let cipher = Cipher::try_new(&provider.key(&key_id)?)?;
+        let key_id_len = u16::try_from(key_id.len())
+            .map_err(|_| polars_err!(ComputeError: "encryption key id is too long"))?;
+
+        inner.write_all(MAGIC)?;
+        inner.write_all(&[VERSION])?;
+        inner.write_all(&key_id_len.to_le_bytes())?;
+        inner.write_all(key_id.as_bytes())?;
+        Ok(Self {
+            inner,
+            cipher,
+            buf: Vec::with_capacity(SEGMENT_SIZE + TAG_LEN),
+            segment_idx: 0,
+            finished: false,
+        })
+    }
+
+    fn write_segment(&mut self, flags: u8) -> std::io::Result<()> {
+        let aad = segment_aad(self.segment_idx, flags);
+        let nonce = self
+            .cipher
+            .seal(&aad, &mut self.buf)
+            .map_err(std::io::Error::other)?;
+        self.inner.write_all(&[flags])?;
+        self.inner.write_all(&nonce)?;
+        self.inner
+            .write_all(&(self.buf.len() as u32).to_le_bytes())?;
+        self.inner.write_all(&self.buf)?;
+        self.buf.clear();
+        self.segment_idx += 1;
+        Ok(())
+    }
+
+    /// Writes the last segment. Further writes are an error.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        if !self.finished {
+            self.write_segment(LAST_SEGMENT)?;
+            self.inner.flush()?;
+            self.finished = true;
+        }
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for EncryptedWriter<W> {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        if self.finished {
+            return Err(std::io::Error::other("write to a finished EncryptedWriter"));
+        }
+        // Only write a full segment once we know there is more data, as the last segment has to
+        // be marked.
+        if self.buf.len() == SEGMENT_SIZE && !data.is_empty() {
+            self.write_segment(0)?;
+        }
+        let n = data.len().min(SEGMENT_SIZE - self.buf.len());
+        self.buf.extend_from_slice(&data[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Whether `bytes` start like an encrypted IPC file.
+pub fn is_encrypted(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+/// Reads and decrypts a whole encrypted IPC file.
+pub fn decrypt<R: Read>(mut reader: R, encryption: &IpcEncryption) -> PolarsResult<Vec<u8>> {
+    let mut magic = [0u8; 8];
+    reader.read_exact(&mut magic)?;
+    polars_ensure!(&magic == MAGIC, ComputeError: "not an encrypted IPC file");
+
+    let mut version = [0u8; 1];
+    reader.read_exact(&mut version)?;
+    polars_ensure!(
+        version[0] == VERSION,
+        ComputeError: "unsupported version {} of encrypted IPC file", version[0]
+    );
+
+    let mut key_id_len = [0u8; 2];
+    reader.read_exact(&mut key_id_len)?;
+    let mut key_id = vec![0u8; u16::from_le_bytes(key_id_len) as usize];
+    reader.read_exact(&mut key_id)?;
+    let key_id = std::str::from_utf8(&key_id)
+        .map_err(|_| polars_err!(ComputeError: "invalid key id in encrypted IPC file"))?;
+    let cipher = Cipher::try_new(&encryption.provider()?.key(key_id)?)?;
+
+    let mut out = Vec::new();
+    let mut segment = Vec::new();
+    for segment_idx in 0.. {
+        let mut flags = [0u8; 1];
+        reader
+            .read_exact(&mut flags)
+            .map_err(|_| polars_err!(ComputeError: "encrypted IPC file is truncated"))?;
+        let mut nonce = [0u8; NONCE_LEN];
+        reader.read_exact(&mut nonce)?;
+        let mut len = [0u8; 4];
+        reader.read_exact(&mut len)?;
+        let len = u32::from_le_bytes(len) as usize;
+        polars_ensure!(
+            len <= SEGMENT_SIZE + TAG_LEN,
+            ComputeError: "corrupted encrypted IPC file"
+        );
+
+        segment.resize(len, 0);
+        reader.read_exact(&mut segment)?;
+        cipher.open(&segment_aad(segment_idx, flags[0]), nonce, &mut segment)?;
+        out.extend_from_slice(&segment);
+
+        if flags[0] & LAST_SEGMENT != 0 {
+            break;
+        }
+    }
+
+    polars_ensure!(
+        reader.read(&mut [0u8; 1])? == 0,
+        ComputeError: "encrypted IPC file has trailing data"
+    );
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn encryption(key_id: &str, key: u8) -> IpcEncryption {
+        IpcEncryption::new(Arc::new(StaticKeyProvider::new(key_id.into(), [key; 32]))).unwrap()
+    }
+
+    fn encrypt(data: &[u8], encryption: &IpcEncryption) -> Vec<u8> {
+        let mut writer = EncryptedWriter::try_new(Vec::new(), encryption).unwrap();
+        writer.write_all(data).unwrap();
+        writer.finish().unwrap();
+        writer.into_inner()
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let encryption = encryption("a", 1);
+        for len in [0, 10, SEGMENT_SIZE, SEGMENT_SIZE + 1, 3 * SEGMENT_SIZE / 2] {
+            let data = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+            let encrypted = encrypt(&data, &encryption);
+            assert!(is_encrypted(&encrypted));
+            assert_eq!(decrypt(encrypted.as_slice(), &encryption).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn test_tampering() {
+        let data = vec![7u8; SEGMENT_SIZE + 10];
+        let encrypted = encrypt(&data, &encryption("a", 1));
+
+        // Wrong key.
+        assert!(decrypt(encrypted.as_slice(), &encryption("a", 2)).is_err());
+        // Unknown key id.
+        assert!(decrypt(encrypted.as_slice(), &encryption("b", 1)).is_err());
+        // Truncated to the first segment.
+        let first_segment = MAGIC.len() + 3 + 1 + 1 + NONCE_LEN + 4 + SEGMENT_SIZE + TAG_LEN;
+        assert!(decrypt(&encrypted[..first_segment], &encryption("a", 1)).is_err());
+        // Flipped bit in the ciphertext.
+        let mut flipped = encrypted.clone();
+        *flipped.last_mut().unwrap() ^= 1;
+        assert!(decrypt(flipped.as_slice(), &encryption("a", 1)).is_err());
+    }
+
+    #[test]
+    fn test_identity() {
+        assert_eq!(encryption("a", 1), encryption("a", 1));
+        assert_ne!(encryption("a", 1), encryption("b", 1));
+        assert_ne!(
+            IpcEncryption::ephemeral().unwrap(),
+            IpcEncryption::ephemeral().unwrap()
+        );
+
+        // A deserialized encryption only has the key id.
+        let encrypted = encrypt(b"data", &encryption("a", 1));
+        let deserialized = IpcEncryption {
+            key_id: "a".into(),
+            provider: None,
+        };
+        assert_eq!(deserialized, encryption("a", 1));
+        assert!(decrypt(encrypted.as_slice(), &deserialized).is_err());
+        assert!(EncryptedWriter::try_new(Vec::new(), &deserialized).is_err());
+    }
+}
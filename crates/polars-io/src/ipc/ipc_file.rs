@@ -208,6 +208,21 @@ impl<R: MmapBytesReader> IpcReader<R> {
     }
 }
 
+#[cfg(feature = "ipc_encryption")]
+impl IpcReader<std::io::Cursor<Vec<u8>>> {
+    /// Create a reader of a file that was encrypted with [`IpcWriter::with_encryption`].
+    ///
+    /// The whole file is decrypted into memory, as the segments of an encrypted file can't be
+    /// decrypted in isolation.
+    pub fn new_encrypted<R: Read>(
+        reader: R,
+        encryption: &super::IpcEncryption,
+    ) -> PolarsResult<Self> {
+        let bytes = super::decrypt(reader, encryption)?;
+        Ok(Self::new(std::io::Cursor::new(bytes)))
+    }
+}
+
 impl<R: MmapBytesReader> ArrowReader for read::FileReader<R>
 where
     R: Read + Seek,
@@ -1,3 +1,5 @@
+#[cfg(feature = "ipc_encryption")]
+mod encryption;
 #[cfg(feature = "ipc")]
 mod ipc_file;
 #[cfg(feature = "cloud")]
@@ -6,11 +8,20 @@ mod ipc_reader_async;
 mod ipc_stream;
 #[cfg(feature = "ipc")]
 mod mmap;
+#[cfg(feature = "ipc")]
+mod shared_memory;
 mod write;
+#[cfg(feature = "ipc_encryption")]
+pub use encryption::{
+    EncryptedWriter, EncryptionKeyProvider, IpcEncryption, StaticKeyProvider, decrypt,
+    is_encrypted, set_spill_encryption, spill_encryption,
+};
 #[cfg(feature = "ipc")]
 pub use ipc_file::{IpcReader, IpcScanOptions};
 #[cfg(feature = "cloud")]
 pub use ipc_reader_async::*;
 #[cfg(feature = "ipc_streaming")]
 pub use ipc_stream::*;
+#[cfg(feature = "ipc")]
+pub use shared_memory::{remove_shared_memory, shared_memory_path, write_shared_memory};
 pub use write::{BatchedWriter, IpcCompression, IpcWriter, IpcWriterOptions};
@@ -0,0 +1,90 @@
+//! Frames shared between processes through named shared memory objects.
+//!
+//! A producer writes a frame as an uncompressed Arrow IPC file into a shared memory object, and
+//! consumers memory map it, e.g. with `scan_ipc` on [`shared_memory_path`]. The consumers read
+//! the pages the producer wrote, without the frame touching a disk.
+use std::fs::File;
+use std::path::PathBuf;
+
+use polars_core::prelude::*;
+
+use super::IpcWriter;
+use crate::prelude::SerWriter;
+
+fn check_name(name: &str) -> PolarsResult<()> {
+    polars_ensure!(
+        !name.is_empty() && !name.starts_with('.') && !name.contains(['/', '\0']),
+        InvalidOperation: "invalid shared memory name '{}': it must be non-empty, must not start \
+        with '.' and must not contain '/'", name
+    );
+    Ok(())
+}
+
+/// The path of the shared memory object with the given `name`, which can be memory mapped by
+/// any process.
+pub fn shared_memory_path(name: &str) -> PolarsResult<PathBuf> {
+    check_name(name)?;
+    if cfg!(target_os = "linux") {
+        Ok(PathBuf::from("/dev/shm").join(name))
+    } else {
+        polars_bail!(InvalidOperation: "shared memory frames are only supported on Linux")
+    }
+}
+
+/// Write `df` into the shared memory object with the given `name` and return its path.
+///
+/// An existing object with that name is replaced atomically: consumers see either the old or
+/// the new frame, and consumers that still map the old frame can keep reading it. The object
+/// lives until it is removed with [`remove_shared_memory`].
+pub fn write_shared_memory(df: &mut DataFrame, name: &str) -> PolarsResult<PathBuf> {
+    let path = shared_memory_path(name)?;
+    let tmp_path = path.with_file_name(format!(".{}.{}.tmp", name, std::process::id()));
+
+    let result = File::create(&tmp_path)
+        .map_err(PolarsError::from)
+        .and_then(|file| {
+            // Compression would prevent consumers from reading the mapped buffers as they are.
+            IpcWriter::new(file).with_compression(None).finish(df)
+        })
+        .and_then(|_| Ok(std::fs::rename(&tmp_path, &path)?));
+    if result.is_err() {
+        _ = std::fs::remove_file(&tmp_path);
+    }
+    result.map(|_| path)
+}
+
+/// Remove the shared memory object with the given `name`. Its memory is freed once no process
+/// maps it anymore.
+pub fn remove_shared_memory(name: &str) -> PolarsResult<()> {
+    Ok(std::fs::remove_file(shared_memory_path(name)?)?)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod test {
+    use super::*;
+    use crate::ipc::IpcReader;
+    use crate::prelude::SerReader;
+
+    #[test]
+    fn test_shared_memory_roundtrip() -> PolarsResult<()> {
+        let name = format!("polars-test-{}", std::process::id());
+        let mut df = df!("a" => [1i64, 2, 3], "b" => ["x", "y", "z"])?;
+
+        let path = write_shared_memory(&mut df, &name)?;
+        let out = IpcReader::new(File::open(&path)?)
+            .memory_mapped(Some(path.clone()))
+            .finish()?;
+        assert!(out.equals(&df));
+
+        let mut df = df.head(Some(1));
+        write_shared_memory(&mut df, &name)?;
+        let out = IpcReader::new(File::open(&path)?).finish()?;
+        assert!(out.equals(&df));
+
+        remove_shared_memory(&name)?;
+        assert!(!path.exists());
+        assert!(shared_memory_path("a/b").is_err());
+        assert!(shared_memory_path(".a").is_err());
+        Ok(())
+    }
+}
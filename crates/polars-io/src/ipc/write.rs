@@ -6,10 +6,14 @@ use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "ipc_encryption")]
+use super::encryption::{EncryptedWriter, IpcEncryption};
 use crate::prelude::*;
-use crate::shared::schema_to_arrow_checked;
+use crate::shared::{
+    add_column_metadata, add_field_metadata, schema_to_arrow_checked, schema_with_column_metadata,
+};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IpcWriterOptions {
     /// Data page compression
@@ -18,6 +22,18 @@ pub struct IpcWriterOptions {
     pub compat_level: CompatLevel,
     /// Size of each written chunk.
     pub chunk_size: IdxSize,
+    /// Encrypt the written file.
+    #[cfg(feature = "ipc_encryption")]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub encryption: Option<IpcEncryption>,
+    /// Write the Arrow IPC streaming format instead of the file format. The stream can be
+    /// read while it is written, as it has no footer.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub stream: bool,
+    /// Flush the writer after every record batch, so that readers on the other end of e.g. a
+    /// socket receive each batch as soon as it is written.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub flush_each_batch: bool,
 }
 
 impl Default for IpcWriterOptions {
@@ -26,13 +42,20 @@ impl Default for IpcWriterOptions {
             compression: None,
             compat_level: CompatLevel::newest(),
             chunk_size: 1 << 18,
+            #[cfg(feature = "ipc_encryption")]
+            encryption: None,
+            stream: false,
+            flush_each_batch: false,
         }
     }
 }
 
 impl IpcWriterOptions {
     pub fn to_writer<W: Write>(&self, writer: W) -> IpcWriter<W> {
-        IpcWriter::new(writer).with_compression(self.compression)
+        let writer = IpcWriter::new(writer).with_compression(self.compression);
+        #[cfg(feature = "ipc_encryption")]
+        let writer = writer.with_encryption(self.encryption.clone());
+        writer
     }
 }
 
@@ -70,6 +93,8 @@ pub struct IpcWriter<W> {
     pub(super) compat_level: CompatLevel,
     pub(super) parallel: bool,
     pub(super) custom_schema_metadata: Option<Arc<Metadata>>,
+    #[cfg(feature = "ipc_encryption")]
+    pub(super) encryption: Option<IpcEncryption>,
 }
 
 impl<W: Write> IpcWriter<W> {
@@ -89,24 +114,60 @@ impl<W: Write> IpcWriter<W> {
         self
     }
 
+    /// Encrypt the written file. Defaults to None.
+    #[cfg(feature = "ipc_encryption")]
+    pub fn with_encryption(mut self, encryption: Option<IpcEncryption>) -> Self {
+        self.encryption = encryption;
+        self
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
         let schema = schema_to_arrow_checked(schema, self.compat_level, "ipc")?;
-        let mut writer = write::FileWriter::new(
-            self.writer,
-            Arc::new(schema),
+        let (compat_level, write_options) = (self.compat_level, self.write_options());
+        let writer = write::FileWriter::new(
+            self.maybe_encrypted_writer()?,
+            Arc::new(schema.clone()),
             None,
-            WriteOptions {
-                compression: self.compression.map(|c| c.into()),
-            },
+            write_options,
         );
-        writer.start()?;
 
         Ok(BatchedWriter {
-            writer,
-            compat_level: self.compat_level,
+            writer: BatchedFormatWriter::File(writer),
+            compat_level,
+            pending_schema: Some(schema),
+        })
+    }
+
+    /// Like [`IpcWriter::batched`], but writes the Arrow IPC streaming format.
+    pub fn batched_stream(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        let schema = schema_to_arrow_checked(schema, self.compat_level, "ipc")?;
+        let (compat_level, write_options) = (self.compat_level, self.write_options());
+        let writer = write::StreamWriter::new(self.maybe_encrypted_writer()?, write_options);
+
+        Ok(BatchedWriter {
+            writer: BatchedFormatWriter::Stream(writer),
+            compat_level,
+            pending_schema: Some(schema),
         })
     }
 
+    fn write_options(&self) -> WriteOptions {
+        WriteOptions {
+            compression: self.compression.map(|c| c.into()),
+        }
+    }
+
+    fn maybe_encrypted_writer(self) -> PolarsResult<MaybeEncryptedWriter<W>> {
+        #[cfg(feature = "ipc_encryption")]
+        if let Some(encryption) = &self.encryption {
+            return Ok(MaybeEncryptedWriter::Encrypted(EncryptedWriter::try_new(
+                self.writer,
+                encryption,
+            )?));
+        }
+        Ok(MaybeEncryptedWriter::Plain(self.writer))
+    }
+
     /// Sets custom schema metadata. Must be called before `start` is called
     pub fn set_custom_schema_metadata(&mut self, custom_metadata: Arc<Metadata>) {
         self.custom_schema_metadata = Some(custom_metadata);
@@ -124,13 +185,27 @@ where
             compat_level: CompatLevel::newest(),
             parallel: true,
             custom_schema_metadata: None,
+            #[cfg(feature = "ipc_encryption")]
+            encryption: None,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        #[cfg(feature = "ipc_encryption")]
+        let writer = match &self.encryption {
+            Some(encryption) => MaybeEncryptedWriter::Encrypted(EncryptedWriter::try_new(
+                &mut self.writer,
+                encryption,
+            )?),
+            None => MaybeEncryptedWriter::Plain(&mut self.writer),
+        };
+        #[cfg(not(feature = "ipc_encryption"))]
+        let writer = MaybeEncryptedWriter::Plain(&mut self.writer);
+
         let schema = schema_to_arrow_checked(df.schema(), self.compat_level, "ipc")?;
+        let schema = schema_with_column_metadata(schema, df);
         let mut ipc_writer = write::FileWriter::try_new(
-            &mut self.writer,
+            writer,
             Arc::new(schema),
             None,
             WriteOptions {
@@ -152,13 +227,58 @@ where
             ipc_writer.write(&batch, None)?
         }
         ipc_writer.finish()?;
+        ipc_writer.into_inner().finish()?;
+        Ok(())
+    }
+}
+
+/// Writes to the inner writer, encrypting the data if encryption is enabled.
+pub(super) enum MaybeEncryptedWriter<W: Write> {
+    Plain(W),
+    #[cfg(feature = "ipc_encryption")]
+    Encrypted(EncryptedWriter<W>),
+}
+
+impl<W: Write> MaybeEncryptedWriter<W> {
+    fn finish(&mut self) -> PolarsResult<()> {
+        match self {
+            Self::Plain(w) => w.flush()?,
+            #[cfg(feature = "ipc_encryption")]
+            Self::Encrypted(w) => w.finish()?,
+        }
         Ok(())
     }
 }
 
+impl<W: Write> Write for MaybeEncryptedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Plain(w) => w.write(buf),
+            #[cfg(feature = "ipc_encryption")]
+            Self::Encrypted(w) => w.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Plain(w) => w.flush(),
+            #[cfg(feature = "ipc_encryption")]
+            Self::Encrypted(w) => w.flush(),
+        }
+    }
+}
+
+enum BatchedFormatWriter<W: Write> {
+    File(write::FileWriter<W>),
+    Stream(write::StreamWriter<W>),
+}
+
 pub struct BatchedWriter<W: Write> {
-    writer: write::FileWriter<W>,
+    writer: BatchedFormatWriter<MaybeEncryptedWriter<W>>,
     compat_level: CompatLevel,
+    /// The schema is written in the header, so we only start writing once the column metadata
+    /// of the first batch is known.
+    pending_schema: Option<ArrowSchema>,
 }
 
 impl<W: Write> BatchedWriter<W> {
@@ -167,9 +287,16 @@ impl<W: Write> BatchedWriter<W> {
     /// # Panics
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        if let Some(schema) = &mut self.pending_schema {
+            add_column_metadata(schema, df.get_columns());
+        }
+        self.start()?;
         let iter = df.iter_chunks(self.compat_level, true);
         for batch in iter {
-            self.writer.write(&batch, None)?
+            match &mut self.writer {
+                BatchedFormatWriter::File(w) => w.write(&batch, None)?,
+                BatchedFormatWriter::Stream(w) => w.write(&batch, None)?,
+            }
         }
         Ok(())
     }
@@ -183,15 +310,58 @@ impl<W: Write> BatchedWriter<W> {
         dictionaries: &[EncodedData],
         message: &EncodedData,
     ) -> PolarsResult<()> {
-        self.writer.write_encoded(dictionaries, message)?;
+        self.start()?;
+        match &mut self.writer {
+            BatchedFormatWriter::File(w) => w.write_encoded(dictionaries, message)?,
+            BatchedFormatWriter::Stream(w) => w.write_encoded(dictionaries, message)?,
+        }
         Ok(())
     }
 
-    /// Writes the footer of the IPC file.
+    /// Flush what was written so far to the inner writer.
+    pub fn flush(&mut self) -> PolarsResult<()> {
+        self.inner_mut().flush()?;
+        Ok(())
+    }
+
+    /// Add custom metadata to the field `name` of the written schema. This has no effect once
+    /// the first batch has been written.
+    pub fn add_field_metadata(&mut self, name: &str, metadata: &Metadata) {
+        if let Some(schema) = &mut self.pending_schema {
+            add_field_metadata(schema, name, metadata);
+        }
+    }
+
+    /// Writes the footer of the IPC file, or the end of the IPC stream.
     pub fn finish(&mut self) -> PolarsResult<()> {
-        self.writer.finish()?;
+        self.start()?;
+        match &mut self.writer {
+            BatchedFormatWriter::File(w) => w.finish()?,
+            BatchedFormatWriter::Stream(w) => w.finish()?,
+        }
+        self.inner_mut().finish()?;
         Ok(())
     }
+
+    fn start(&mut self) -> PolarsResult<()> {
+        let Some(schema) = self.pending_schema.take() else {
+            return Ok(());
+        };
+        match &mut self.writer {
+            BatchedFormatWriter::File(w) => {
+                *w.schema_mut() = schema;
+                w.start()
+            },
+            BatchedFormatWriter::Stream(w) => w.start(&schema, None),
+        }
+    }
+
+    fn inner_mut(&mut self) -> &mut MaybeEncryptedWriter<W> {
+        match &mut self.writer {
+            BatchedFormatWriter::File(w) => w.get_mut(),
+            BatchedFormatWriter::Stream(w) => w.get_mut(),
+        }
+    }
 }
 
 /// Compression codec
@@ -0,0 +1,123 @@
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use arrow::datatypes::ArrowSchema;
+use polars_core::prelude::*;
+use polars_parquet::read::FileMetadata;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Maps the top-level columns of Parquet files to the columns of a scan, so that files whose
+/// columns were renamed between generations can be scanned as one table.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ParquetColumnMapping {
+    /// Give the columns with these Parquet field IDs these names. Columns with other field IDs
+    /// keep their names.
+    FieldIds(Vec<(PlSmallStr, i32)>),
+    /// Rename every column with a function of its name in the file.
+    Rename(ColumnRenameFn),
+}
+
+/// Maps the name of a column in a file to its name in the scan.
+#[derive(Clone)]
+pub struct ColumnRenameFn(pub Arc<dyn Fn(&str) -> PolarsResult<PlSmallStr> + Send + Sync>);
+
+impl Debug for ColumnRenameFn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ColumnRenameFn")
+    }
+}
+
+impl PartialEq for ColumnRenameFn {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for ColumnRenameFn {}
+
+impl Hash for ColumnRenameFn {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (Arc::as_ptr(&self.0) as *const () as usize).hash(state)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for ColumnRenameFn {
+    fn serialize<S: Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::Error;
+        Err(S::Error::custom(
+            "serialization not supported for column renaming functions",
+        ))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ColumnRenameFn {
+    fn deserialize<D: Deserializer<'de>>(_deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+        Err(D::Error::custom(
+            "deserialization not supported for column renaming functions",
+        ))
+    }
+}
+
+impl ParquetColumnMapping {
+    /// The new names of the top-level columns of a file that are renamed.
+    fn renames(&self, metadata: &FileMetadata) -> PolarsResult<PlHashMap<PlSmallStr, PlSmallStr>> {
+        let fields = metadata.schema().fields();
+        let mut renames = PlHashMap::with_capacity(fields.len());
+        match self {
+            Self::FieldIds(field_ids) => {
+                let names = field_ids
+                    .iter()
+                    .map(|(name, id)| (*id, name))
+                    .collect::<PlHashMap<_, _>>();
+                for field in fields {
+                    let info = field.get_field_info();
+                    if let Some(&name) = info.id.and_then(|id| names.get(&id)) {
+                        renames.insert(info.name.clone(), name.clone());
+                    }
+                }
+            },
+            Self::Rename(function) => {
+                for field in fields {
+                    renames.insert(field.name().into(), (function.0)(field.name())?);
+                }
+            },
+        }
+        renames.retain(|old, new| old != new);
+        Ok(renames)
+    }
+
+    /// Rename the top-level columns of the `schema` of a file with the given `metadata`.
+    ///
+    /// Returns the renamed columns, which can be passed to [`FileMetadata::rename_root_columns`]
+    /// to read them by their new names.
+    pub fn apply_to_schema(
+        &self,
+        schema: &mut ArrowSchema,
+        metadata: &FileMetadata,
+    ) -> PolarsResult<PlHashMap<PlSmallStr, PlSmallStr>> {
+        let renames = self.renames(metadata)?;
+        if renames.is_empty() {
+            return Ok(renames);
+        }
+
+        let mut renamed = ArrowSchema::with_capacity(schema.len());
+        for (name, mut field) in std::mem::take(schema) {
+            if let Some(new_name) = renames.get(&name) {
+                field.name = new_name.clone();
+            }
+            polars_ensure!(
+                !renamed.contains(&field.name),
+                Duplicate: "column mapping maps multiple columns of the file to '{}'", field.name
+            );
+            renamed.insert(field.name.clone(), field);
+        }
+        *schema = renamed;
+        Ok(renames)
+    }
+}
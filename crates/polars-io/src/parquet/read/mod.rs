@@ -16,6 +16,7 @@
 
 #[cfg(feature = "cloud")]
 mod async_impl;
+mod column_mapping;
 mod mmap;
 mod options;
 mod predicates;
@@ -32,6 +33,7 @@ or set 'streaming'",
 
 #[cfg(feature = "cloud")]
 pub use async_impl::ParquetObjectStore;
+pub use column_mapping::{ColumnRenameFn, ParquetColumnMapping};
 pub use options::{ParallelStrategy, ParquetOptions};
 use polars_error::{ErrString, PolarsError};
 pub use polars_parquet::arrow::read::infer_schema;
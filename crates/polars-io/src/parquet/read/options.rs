@@ -2,6 +2,8 @@ use polars_core::schema::SchemaRef;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use super::ParquetColumnMapping;
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParquetOptions {
@@ -9,6 +11,13 @@ pub struct ParquetOptions {
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
+    /// Read string columns as `Categorical`, using the dictionaries of the file as categories
+    /// where the columns are dictionary encoded.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub strings_as_categorical: bool,
+    /// Map the columns of every file to the columns of the scan, see [`ParquetColumnMapping`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub column_mapping: Option<ParquetColumnMapping>,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Default, Hash)]
@@ -1,6 +1,7 @@
 use std::io::Write;
 use std::sync::Mutex;
 
+use arrow::datatypes::Metadata;
 use arrow::record_batch::RecordBatch;
 use polars_core::POOL;
 use polars_core::prelude::*;
@@ -13,6 +14,7 @@ use polars_parquet::write::{
 use rayon::prelude::*;
 
 use super::{KeyValueMetadata, ParquetMetadataContext};
+use crate::shared::{add_column_metadata, add_field_metadata};
 
 pub struct BatchedWriter<W: Write> {
     // A mutex so that streaming engine can get concurrent read access to
@@ -50,6 +52,10 @@ impl<W: Write> BatchedWriter<W> {
         &'a self,
         df: &'a DataFrame,
     ) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+        let columns = df.get_columns();
+        if columns.iter().any(|c| c.field().metadata().is_some()) {
+            add_column_metadata(self.writer.lock().unwrap().schema_mut(), columns);
+        }
         let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
         rb_iter.filter_map(move |batch| match batch.len() {
             0 => None,
@@ -80,6 +86,7 @@ impl<W: Write> BatchedWriter<W> {
         );
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
+        add_column_metadata(writer.schema_mut(), df.get_columns());
         for group in row_group_iter {
             writer.write(group?)?;
         }
@@ -102,6 +109,12 @@ impl<W: Write> BatchedWriter<W> {
         Ok(())
     }
 
+    /// Add custom metadata to the field `name` of the arrow schema written in the footer.
+    pub fn add_field_metadata(&mut self, name: &str, metadata: &Metadata) {
+        let writer = self.writer.get_mut().unwrap();
+        add_field_metadata(writer.schema_mut(), name, metadata);
+    }
+
     pub fn get_writer(&self) -> &Mutex<FileWriter<W>> {
         &self.writer
     }
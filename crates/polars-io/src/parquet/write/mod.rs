@@ -11,5 +11,5 @@ pub use options::{
     BrotliLevel, ChildFieldOverwrites, GzipLevel, MetadataKeyValue, ParquetCompression,
     ParquetFieldOverwrites, ParquetWriteOptions, ZstdLevel,
 };
-pub use polars_parquet::write::{RowGroupIterColumns, StatisticsOptions};
+pub use polars_parquet::write::{KeyValue, RowGroupIterColumns, StatisticsOptions};
 pub use writer::{ParquetWriter, get_column_write_options};
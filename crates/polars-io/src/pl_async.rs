@@ -6,6 +6,7 @@ use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU64, Ordering};
 
 use polars_core::POOL;
 use polars_core::config::{self, verbose};
+use polars_error::{PolarsResult, polars_ensure};
 use tokio::runtime::{Builder, Runtime};
 use tokio::sync::Semaphore;
 
@@ -198,6 +199,21 @@ pub(crate) fn get_concurrency_limit() -> u32 {
     get_semaphore().1
 }
 
+/// Set the maximum number of simultaneous IO requests of the process. This disables tuning of
+/// the budget and must happen before the first request, as the budget can't change afterwards.
+pub fn set_concurrency_budget(permits: u32) -> PolarsResult<()> {
+    polars_ensure!(permits > 0, InvalidOperation: "concurrency budget must be positive");
+    let (_, budget) = CONCURRENCY_BUDGET.get_or_init(|| {
+        FINISHED_TUNING.store(true, Ordering::Relaxed);
+        (Semaphore::new(permits as usize), permits)
+    });
+    polars_ensure!(
+        *budget == permits,
+        InvalidOperation: "concurrency budget is already set to {}", budget
+    );
+    Ok(())
+}
+
 pub async fn tune_with_concurrency_budget<F, Fut>(requested_budget: u32, callable: F) -> Fut::Output
 where
     F: FnOnce() -> Fut,
@@ -206,8 +222,8 @@ where
 {
     let (semaphore, initial_budget) = get_semaphore();
 
-    // This would never finish otherwise.
-    assert!(requested_budget <= *initial_budget);
+    // This would never finish otherwise, the budget may be set below the budget of a request.
+    let requested_budget = requested_budget.min(*initial_budget);
 
     // Keep permit around.
     // On drop it is returned to the semaphore.
@@ -261,8 +277,8 @@ where
 {
     let (semaphore, initial_budget) = get_semaphore();
 
-    // This would never finish otherwise.
-    assert!(requested_budget <= *initial_budget);
+    // This would never finish otherwise, the budget may be set below the budget of a request.
+    let requested_budget = requested_budget.min(*initial_budget);
 
     // Keep permit around.
     // On drop it is returned to the semaphore.
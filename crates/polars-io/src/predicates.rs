@@ -1,4 +1,6 @@
 use std::fmt;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use arrow::array::Array;
 use arrow::bitmap::{Bitmap, BitmapBuilder};
@@ -508,3 +510,335 @@ impl fmt::Debug for ScanIOPredicate {
         f.write_str("scan_io_predicate")
     }
 }
+
+const BLOOM_BITS_PER_KEY: usize = 10;
+const BLOOM_NUM_PROBES: u64 = 3;
+/// Above this number of keys the bloom filter is no longer worth its memory and only the
+/// minimum and maximum are used.
+const BLOOM_MAX_KEYS: usize = 1 << 24;
+
+fn bloom_probes(hash: u64, mask: u64) -> impl Iterator<Item = u64> {
+    let step = hash.rotate_left(32) | 1;
+    (0..BLOOM_NUM_PROBES).map(move |i| hash.wrapping_add(i.wrapping_mul(step)) & mask)
+}
+
+/// The values that the join key of the probe side of an inner or semi-join can take to find a
+/// match, derived from the keys of the build side once that is collected. It is pushed into the
+/// scan of the probe side to skip batches by their statistics and rows by a bloom filter.
+pub struct RuntimeFilter {
+    column: PlSmallStr,
+    dtype: DataType,
+    /// Minimum and maximum of the keys, null if the build side has no non-null keys.
+    min: Scalar,
+    max: Scalar,
+    /// Bloom filter of the hashes of the keys, with a power of two number of bits.
+    bloom: Option<Vec<u64>>,
+}
+
+impl RuntimeFilter {
+    pub fn column(&self) -> &PlSmallStr {
+        &self.column
+    }
+
+    fn bloom_contains(bloom: &[u64], hash: u64) -> bool {
+        let mask = (bloom.len() * 64 - 1) as u64;
+        bloom_probes(hash, mask).all(|bit| bloom[(bit / 64) as usize] & (1 << (bit % 64)) != 0)
+    }
+
+    /// Whether the rows of `df` can find a match, nulls never do.
+    fn evaluate(&self, df: &DataFrame) -> PolarsResult<BooleanChunked> {
+        let keys = df
+            .column(&self.column)?
+            .as_materialized_series()
+            .cast(&self.dtype)?;
+        if self.min.is_null() {
+            return Ok(BooleanChunked::full(self.column.clone(), false, keys.len()));
+        }
+
+        let min = self.min.clone().into_series(PlSmallStr::EMPTY);
+        let max = self.max.clone().into_series(PlSmallStr::EMPTY);
+        let mut mask = &keys.gt_eq(&min)? & &keys.lt_eq(&max)?;
+        if let Some(bloom) = &self.bloom {
+            let mut hashes = Vec::new();
+            keys.vec_hash(PlSeedableRandomStateQuality::fixed(), &mut hashes)?;
+            let in_bloom: BooleanChunked = hashes
+                .into_iter()
+                .map(|h| Self::bloom_contains(bloom, h))
+                .collect();
+            mask = &mask & &in_bloom;
+        }
+        mask.fill_null_with_values(false)
+    }
+
+    /// Which batches can be skipped given a DataFrame of their statistics.
+    fn skip_batches(&self, df: &DataFrame) -> PolarsResult<Bitmap> {
+        if self.min.is_null() {
+            return Ok(Bitmap::new_with_value(true, df.height()));
+        }
+
+        let min = self.min.clone().into_series(PlSmallStr::EMPTY);
+        let max = self.max.clone().into_series(PlSmallStr::EMPTY);
+        let stat = |suffix: &str| {
+            df.column(&format_pl_smallstr!("{}_{suffix}", self.column))?
+                .as_materialized_series()
+                .cast(&self.dtype)
+        };
+        let skip = &stat("max")?.lt(&min)? | &stat("min")?.gt(&max)?;
+        Ok(skip.into_iter().map(|v| v == Some(true)).collect())
+    }
+}
+
+/// Collects the keys of the build side of a join into a [`RuntimeFilter`].
+#[derive(Default)]
+pub struct RuntimeFilterBuilder {
+    /// Minima and maxima of the batches of keys seen so far.
+    bounds: Option<Series>,
+    hashes: Vec<u64>,
+    too_many_keys: bool,
+}
+
+impl RuntimeFilterBuilder {
+    pub fn update(&mut self, keys: &Series) -> PolarsResult<()> {
+        let keys = keys.drop_nulls();
+        if keys.is_empty() {
+            return Ok(());
+        }
+
+        let mut bounds = keys.min_reduce()?.into_series(PlSmallStr::EMPTY);
+        bounds.append_owned(keys.max_reduce()?.into_series(PlSmallStr::EMPTY))?;
+        self.append_bounds(bounds)?;
+
+        if self.hashes.len() + keys.len() > BLOOM_MAX_KEYS {
+            self.too_many_keys = true;
+            self.hashes = Vec::new();
+        }
+        if !self.too_many_keys {
+            let mut hashes = Vec::new();
+            keys.vec_hash(PlSeedableRandomStateQuality::fixed(), &mut hashes)?;
+            self.hashes.extend(hashes);
+        }
+        Ok(())
+    }
+
+    fn append_bounds(&mut self, bounds: Series) -> PolarsResult<()> {
+        match &mut self.bounds {
+            Some(b) => {
+                b.append_owned(bounds)?;
+            },
+            None => self.bounds = Some(bounds),
+        }
+        Ok(())
+    }
+
+    pub fn merge(&mut self, other: Self) -> PolarsResult<()> {
+        if let Some(bounds) = other.bounds {
+            self.append_bounds(bounds)?;
+        }
+        self.too_many_keys |=
+            other.too_many_keys || self.hashes.len() + other.hashes.len() > BLOOM_MAX_KEYS;
+        if self.too_many_keys {
+            self.hashes = Vec::new();
+        } else {
+            self.hashes.extend(other.hashes);
+        }
+        Ok(())
+    }
+
+    pub fn finish(self, column: PlSmallStr, dtype: DataType) -> PolarsResult<RuntimeFilter> {
+        let (min, max) = match &self.bounds {
+            Some(bounds) => (bounds.min_reduce()?, bounds.max_reduce()?),
+            None => (Scalar::null(dtype.clone()), Scalar::null(dtype.clone())),
+        };
+
+        let bloom = (!self.too_many_keys && !self.hashes.is_empty()).then(|| {
+            let num_bits = (self.hashes.len() * BLOOM_BITS_PER_KEY)
+                .next_power_of_two()
+                .max(64);
+            let mut bloom = vec![0u64; num_bits / 64];
+            for h in self.hashes {
+                for bit in bloom_probes(h, (num_bits - 1) as u64) {
+                    bloom[(bit / 64) as usize] |= 1 << (bit % 64);
+                }
+            }
+            bloom
+        });
+
+        Ok(RuntimeFilter {
+            column,
+            dtype,
+            min,
+            max,
+            bloom,
+        })
+    }
+}
+
+/// A [`RuntimeFilter`] on `column` that is set by a join once its build side is collected.
+pub struct RuntimeFilterSlot {
+    column: PlSmallStr,
+    dtype: DataType,
+    filter: OnceLock<Arc<RuntimeFilter>>,
+    /// Number of rows that were skipped or filtered out by the filter.
+    pruned_rows: AtomicU64,
+}
+
+impl RuntimeFilterSlot {
+    pub fn new(column: PlSmallStr, dtype: DataType) -> Self {
+        Self {
+            column,
+            dtype,
+            filter: OnceLock::new(),
+            pruned_rows: AtomicU64::new(0),
+        }
+    }
+
+    pub fn column(&self) -> &PlSmallStr {
+        &self.column
+    }
+
+    pub fn set(&self, builder: RuntimeFilterBuilder) -> PolarsResult<()> {
+        let filter = builder.finish(self.column.clone(), self.dtype.clone())?;
+        _ = self.filter.set(Arc::new(filter));
+        Ok(())
+    }
+
+    /// The filter, if the build side was collected.
+    pub fn get(&self) -> Option<&Arc<RuntimeFilter>> {
+        self.filter.get()
+    }
+
+    pub fn pruned_rows(&self) -> u64 {
+        self.pruned_rows.load(Ordering::Relaxed)
+    }
+
+    fn record_pruned(&self, n: usize) {
+        self.pruned_rows.fetch_add(n as u64, Ordering::Relaxed);
+    }
+
+    /// Count the rows of the batches in `skipped`, given the statistics of the batches.
+    fn record_skipped(&self, stat_df: &DataFrame, skipped: &Bitmap) -> PolarsResult<()> {
+        if skipped.set_bits() == 0 {
+            return Ok(());
+        }
+        let Ok(len) = stat_df.column("len") else {
+            return Ok(());
+        };
+        let len = len.cast(&IDX_DTYPE)?;
+        let len = len.idx()?;
+        let n = skipped
+            .true_idx_iter()
+            .map(|i| len.get(i).unwrap_or(0) as usize)
+            .sum();
+        self.record_pruned(n);
+        Ok(())
+    }
+
+    /// Combine the filter with the predicate of a scan, `file_schema` being the schema of the
+    /// files that it reads. The filter is looked up on every evaluation, so that it applies to
+    /// the rest of the scan once it is set, and lets all rows through until then.
+    pub fn apply_to_scan_predicate(
+        self: &Arc<Self>,
+        predicate: Option<ScanIOPredicate>,
+        file_schema: &SchemaRef,
+    ) -> ScanIOPredicate {
+        let (child, mut live_columns, child_skip_batch_predicate, mut column_predicates) =
+            match predicate {
+                Some(p) => (
+                    Some(p.predicate),
+                    p.live_columns.as_ref().clone(),
+                    p.skip_batch_predicate,
+                    p.column_predicates.as_ref().clone(),
+                ),
+                None => (
+                    None,
+                    PlIndexSet::default(),
+                    None,
+                    ColumnPredicates::default(),
+                ),
+            };
+        live_columns.insert(self.column.clone());
+        // The column predicates don't include the filter.
+        column_predicates.is_sumwise_complete = false;
+
+        ScanIOPredicate {
+            predicate: Arc::new(RuntimeFilterExpr {
+                slot: self.clone(),
+                child,
+            }),
+            live_columns: Arc::new(live_columns),
+            skip_batch_predicate: Some(Arc::new(RuntimeFilterSkipBatchPredicate {
+                slot: self.clone(),
+                schema: child_skip_batch_predicate
+                    .as_ref()
+                    .map_or_else(|| file_schema.clone(), |p| p.schema().clone()),
+                child: child_skip_batch_predicate,
+            })),
+            column_predicates: Arc::new(column_predicates),
+        }
+    }
+}
+
+struct RuntimeFilterExpr {
+    slot: Arc<RuntimeFilterSlot>,
+    child: Option<Arc<dyn PhysicalIoExpr>>,
+}
+
+impl PhysicalIoExpr for RuntimeFilterExpr {
+    fn evaluate_io(&self, df: &DataFrame) -> PolarsResult<Series> {
+        let child = match &self.child {
+            Some(child) => Some(child.evaluate_io(df)?.bool()?.clone()),
+            None => None,
+        };
+        let Some(filter) = self.slot.get() else {
+            return Ok(child
+                .unwrap_or_else(|| BooleanChunked::full(PlSmallStr::EMPTY, true, df.height()))
+                .into_series());
+        };
+
+        let mut mask = filter.evaluate(df)?;
+        let kept_before = match &child {
+            Some(child) => child.sum().unwrap_or(0) as usize,
+            None => mask.len(),
+        };
+        if let Some(child) = child {
+            mask = &mask & &child;
+        }
+        // Only count the rows that the filter removes on top of the predicate.
+        self.slot
+            .record_pruned(kept_before - mask.sum().unwrap_or(0) as usize);
+        Ok(mask.into_series())
+    }
+}
+
+struct RuntimeFilterSkipBatchPredicate {
+    slot: Arc<RuntimeFilterSlot>,
+    child: Option<Arc<dyn SkipBatchPredicate>>,
+    schema: SchemaRef,
+}
+
+impl SkipBatchPredicate for RuntimeFilterSkipBatchPredicate {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn evaluate_with_stat_df(&self, df: &DataFrame) -> PolarsResult<Bitmap> {
+        let child_skip = match &self.child {
+            Some(child) => Some(child.evaluate_with_stat_df(df)?),
+            None => None,
+        };
+        let Some(filter) = self.slot.get() else {
+            return Ok(child_skip.unwrap_or_else(|| Bitmap::new_zeroed(df.height())));
+        };
+
+        let mut skip = filter.skip_batches(df)?;
+        if let Some(child_skip) = child_skip {
+            skip = &skip | &child_skip;
+            // Only count the batches that the filter skips on top of the predicate.
+            let pruned_by_filter = &skip & &!&child_skip;
+            self.slot.record_skipped(df, &pruned_by_filter)?;
+        } else {
+            self.slot.record_skipped(df, &skip)?;
+        }
+        Ok(skip)
+    }
+}
@@ -2,6 +2,7 @@ use std::io::{Read, Write};
 use std::sync::Arc;
 
 use arrow::array::new_empty_array;
+use arrow::datatypes::Metadata;
 use arrow::record_batch::RecordBatch;
 use polars_core::prelude::*;
 
@@ -149,3 +150,28 @@ pub fn schema_to_arrow_checked(
         })
         .collect::<PolarsResult<ArrowSchema>>()
 }
+
+/// Add the custom metadata of the columns of `df` to the fields of the arrow `schema`.
+pub fn schema_with_column_metadata(mut schema: ArrowSchema, df: &DataFrame) -> ArrowSchema {
+    add_column_metadata(&mut schema, df.get_columns());
+    schema
+}
+
+/// Add the custom metadata of `columns` to the fields of the arrow `schema`.
+pub fn add_column_metadata(schema: &mut ArrowSchema, columns: &[Column]) {
+    for column in columns {
+        if let Some(md) = column.field().metadata() {
+            add_field_metadata(schema, column.name(), md);
+        }
+    }
+}
+
+/// Add custom `metadata` to the field `name` of the arrow `schema`.
+pub fn add_field_metadata(schema: &mut ArrowSchema, name: &str, metadata: &Metadata) {
+    let Some(arrow_field) = schema.get_mut(name) else {
+        return;
+    };
+    let mut merged = arrow_field.metadata.as_deref().cloned().unwrap_or_default();
+    merged.extend(metadata.iter().map(|(k, v)| (k.clone(), v.clone())));
+    arrow_field.metadata = Some(Arc::new(merged));
+}
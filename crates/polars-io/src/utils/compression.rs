@@ -32,25 +32,17 @@ impl SupportedCompression {
 
 /// Decompress `bytes` if compression is detected, otherwise simply return it.
 /// An `out` vec must be given for ownership of the decompressed data.
+///
+/// Files that consist of many independently compressed frames, such as zstd files in the
+/// seekable format or BGZF files, are decompressed in parallel.
 pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> PolarsResult<&'a [u8]> {
     assert!(out.is_empty());
 
     if let Some(algo) = SupportedCompression::check(bytes) {
         feature_gated!("decompress", {
-            match algo {
-                SupportedCompression::GZIP => {
-                    flate2::read::MultiGzDecoder::new(bytes)
-                        .read_to_end(out)
-                        .map_err(to_compute_err)?;
-                },
-                SupportedCompression::ZLIB => {
-                    flate2::read::ZlibDecoder::new(bytes)
-                        .read_to_end(out)
-                        .map_err(to_compute_err)?;
-                },
-                SupportedCompression::ZSTD => {
-                    zstd::Decoder::with_buffer(bytes)?.read_to_end(out)?;
-                },
+            match frame_offsets(&algo, bytes) {
+                Some(offsets) => decompress_parallel(&algo, bytes, &offsets, out)?,
+                None => decompress(&algo, bytes, out)?,
             }
 
             Ok(out)
@@ -59,3 +51,169 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
         Ok(bytes)
     }
 }
+
+#[cfg(feature = "decompress")]
+fn decompress(algo: &SupportedCompression, bytes: &[u8], out: &mut Vec<u8>) -> PolarsResult<()> {
+    match algo {
+        SupportedCompression::GZIP => {
+            flate2::read::MultiGzDecoder::new(bytes)
+                .read_to_end(out)
+                .map_err(to_compute_err)?;
+        },
+        SupportedCompression::ZLIB => {
+            flate2::read::ZlibDecoder::new(bytes)
+                .read_to_end(out)
+                .map_err(to_compute_err)?;
+        },
+        SupportedCompression::ZSTD => {
+            zstd::Decoder::with_buffer(bytes)?.read_to_end(out)?;
+        },
+    }
+    Ok(())
+}
+
+/// Decompress the frames starting at `offsets` in groups of consecutive frames, one group per
+/// thread.
+#[cfg(feature = "decompress")]
+fn decompress_parallel(
+    algo: &SupportedCompression,
+    bytes: &[u8],
+    offsets: &[usize],
+    out: &mut Vec<u8>,
+) -> PolarsResult<()> {
+    use polars_core::POOL;
+    use rayon::prelude::*;
+
+    let n_groups = POOL.current_num_threads() * 2;
+    let group_size = bytes.len().div_ceil(n_groups);
+    let mut groups = vec![];
+    let mut group_start = 0;
+    for &offset in offsets {
+        if offset >= group_start + group_size {
+            groups.push(group_start..offset);
+            group_start = offset;
+        }
+    }
+    groups.push(group_start..bytes.len());
+
+    let parts = POOL.install(|| {
+        groups
+            .into_par_iter()
+            .map(|range| {
+                let mut part = vec![];
+                decompress(algo, &bytes[range], &mut part)?;
+                Ok(part)
+            })
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+
+    out.reserve(parts.iter().map(|p| p.len()).sum());
+    for part in parts {
+        out.extend_from_slice(&part);
+    }
+    Ok(())
+}
+
+/// The offsets of the frames of `bytes` if it consists of more than one frame that can be
+/// decompressed independently and whose compressed size is known without decompressing it.
+///
+/// These are the frames of zstd, which the seekable format uses, and the blocks of BGZF, gzip
+/// members that store their size in the header.
+#[cfg(feature = "decompress")]
+fn frame_offsets(algo: &SupportedCompression, bytes: &[u8]) -> Option<Vec<usize>> {
+    let frame_size: fn(&[u8]) -> Option<usize> = match algo {
+        SupportedCompression::GZIP => bgzf_block_size,
+        SupportedCompression::ZLIB => return None,
+        SupportedCompression::ZSTD => {
+            |bytes: &[u8]| zstd::zstd_safe::find_frame_compressed_size(bytes).ok()
+        },
+    };
+
+    let mut offsets = vec![];
+    let mut offset = 0;
+    while offset < bytes.len() {
+        let size = frame_size(&bytes[offset..]).filter(|&size| size > 0)?;
+        offsets.push(offset);
+        offset += size;
+    }
+    (offsets.len() > 1 && offset == bytes.len()).then_some(offsets)
+}
+
+/// The size of the BGZF block at the start of `bytes`, from the `BC` subfield of its gzip
+/// header.
+#[cfg(feature = "decompress")]
+fn bgzf_block_size(bytes: &[u8]) -> Option<usize> {
+    const FEXTRA: u8 = 1 << 2;
+
+    if bytes.len() < 12 || bytes[..3] != [31, 139, 8] || bytes[3] & FEXTRA == 0 {
+        return None;
+    }
+    let xlen = u16::from_le_bytes([bytes[10], bytes[11]]) as usize;
+    let mut extra = bytes.get(12..12 + xlen)?;
+    while extra.len() >= 4 {
+        let slen = u16::from_le_bytes([extra[2], extra[3]]) as usize;
+        let data = extra.get(4..4 + slen)?;
+        if extra[..2] == *b"BC" && slen == 2 {
+            return Some(u16::from_le_bytes([data[0], data[1]]) as usize + 1);
+        }
+        extra = &extra[4 + slen..];
+    }
+    None
+}
+
+#[cfg(all(test, feature = "decompress"))]
+mod test {
+    use std::io::Write;
+
+    use super::*;
+
+    fn bgzf_block(data: &[u8]) -> Vec<u8> {
+        let mut encoder = flate2::GzBuilder::new()
+            .extra(vec![b'B', b'C', 2, 0, 0, 0])
+            .write(vec![], flate2::Compression::default());
+        encoder.write_all(data).unwrap();
+        let mut block = encoder.finish().unwrap();
+        let bsize = (block.len() - 1) as u16;
+        block[16..18].copy_from_slice(&bsize.to_le_bytes());
+        block
+    }
+
+    #[test]
+    fn test_decompress_frames() -> PolarsResult<()> {
+        let lines = (0..100)
+            .map(|i| format!("{i},value_{i}\n").into_bytes())
+            .collect::<Vec<_>>();
+        let expected = lines.concat();
+
+        // zstd frames, followed by a skippable frame such as the seek table of the seekable
+        // format.
+        let mut zstd_bytes = lines
+            .iter()
+            .map(|line| zstd::encode_all(line.as_slice(), 0).unwrap())
+            .collect::<Vec<_>>()
+            .concat();
+        zstd_bytes.extend_from_slice(&[0x5E, 0x2A, 0x4D, 0x18, 2, 0, 0, 0, 0, 0]);
+        let bgzf_bytes = lines
+            .iter()
+            .map(|line| bgzf_block(line))
+            .chain([bgzf_block(b"")])
+            .collect::<Vec<_>>()
+            .concat();
+
+        for (algo, bytes) in [
+            (SupportedCompression::ZSTD, zstd_bytes),
+            (SupportedCompression::GZIP, bgzf_bytes),
+        ] {
+            assert_eq!(frame_offsets(&algo, &bytes).map(|o| o.len()), Some(101));
+            let mut out = vec![];
+            assert_eq!(maybe_decompress_bytes(&bytes, &mut out)?, expected);
+        }
+
+        // A plain gzip file has no known member sizes.
+        let mut encoder = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+        encoder.write_all(&expected).unwrap();
+        let gzip_bytes = encoder.finish().unwrap();
+        assert!(frame_offsets(&SupportedCompression::GZIP, &gzip_bytes).is_none());
+        Ok(())
+    }
+}
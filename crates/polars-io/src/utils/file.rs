@@ -2,6 +2,8 @@ use std::io;
 use std::ops::{Deref, DerefMut};
 use std::path::Path;
 
+#[cfg(feature = "async")]
+pub use async_write_adapter::AsyncWriteAdapter;
 #[cfg(feature = "cloud")]
 pub use async_writeable::AsyncWriteable;
 use polars_core::config;
@@ -209,6 +211,58 @@ pub fn try_get_writeable(
     })
 }
 
+#[cfg(feature = "async")]
+mod async_write_adapter {
+    use std::io;
+
+    use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+    use super::DynWriteable;
+    use crate::pl_async;
+    use crate::utils::sync_on_close::SyncOnCloseType;
+
+    /// Turn an abstract tokio::io::AsyncWrite, such as a network socket, into a
+    /// [`DynWriteable`], so that sinks can write into it.
+    ///
+    /// Writes block on the async runtime of polars.
+    pub struct AsyncWriteAdapter<W>(W);
+
+    impl<W: AsyncWrite + Send + Unpin + 'static> AsyncWriteAdapter<W> {
+        pub fn new(writer: W) -> Self {
+            Self(writer)
+        }
+
+        pub fn into_inner(self) -> W {
+            self.0
+        }
+    }
+
+    impl<W: AsyncWrite + Send + Unpin + 'static> io::Write for AsyncWriteAdapter<W> {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            pl_async::get_runtime().block_in_place_on(self.0.write(buf))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            pl_async::get_runtime().block_in_place_on(self.0.flush())
+        }
+    }
+
+    impl<W: AsyncWrite + Send + Unpin + 'static> DynWriteable for AsyncWriteAdapter<W> {
+        fn as_dyn_write(&self) -> &(dyn io::Write + Send + 'static) {
+            self as _
+        }
+        fn as_mut_dyn_write(&mut self) -> &mut (dyn io::Write + Send + 'static) {
+            self as _
+        }
+        fn close(mut self: Box<Self>) -> io::Result<()> {
+            pl_async::get_runtime().block_in_place_on(self.0.shutdown())
+        }
+        fn sync_on_close(&mut self, _sync_on_close: SyncOnCloseType) -> io::Result<()> {
+            io::Write::flush(self)
+        }
+    }
+}
+
 #[cfg(feature = "cloud")]
 mod async_writeable {
     use std::io;
@@ -14,6 +14,18 @@ impl LazyFrame {
         Ok(lp.display_dot().to_string())
     }
 
+    /// Get a [Mermaid](https://mermaid.js.org) flowchart of the LogicalPlan. Nodes have the
+    /// same IDs as in [`LazyFrame::describe_plan_json`].
+    pub fn to_mermaid(&self, optimized: bool) -> PolarsResult<String> {
+        let lp = if optimized {
+            self.clone().to_alp_optimized()
+        } else {
+            self.clone().to_alp()
+        }?;
+
+        Ok(lp.describe_mermaid())
+    }
+
     /// Get a dot language representation of the streaming physical plan.
     #[cfg(feature = "new_streaming")]
     pub fn to_dot_streaming_phys(&self, optimized: bool) -> PolarsResult<String> {
@@ -1,9 +1,12 @@
 use polars_core::POOL;
 use polars_core::prelude::*;
 use polars_expr::{ExpressionConversionState, create_physical_expr};
+use polars_utils::idx_vec::UnitVec;
+use polars_utils::unitvec;
 use rayon::prelude::*;
 
 use super::*;
+use crate::physical_plan::exotic::prepare_expression_for_context;
 use crate::prelude::*;
 
 pub(crate) fn eval_field_to_dtype(f: &Field, expr: &Expr, list: bool) -> Field {
@@ -37,12 +40,90 @@ pub(crate) fn eval_field_to_dtype(f: &Field, expr: &Expr, list: bool) -> Field {
     }
 }
 
+/// Whether `expr` can be evaluated on all prefixes at once: it aggregates every prefix to a
+/// single value and only elementwise expressions run around the (non-nested) aggregations.
+fn aggregates_prefixes(node: Node, arena: &Arena<AExpr>) -> bool {
+    if !is_scalar_ae(node, arena) {
+        return false;
+    }
+
+    let mut has_agg = false;
+    let mut stack = unitvec![(node, false)];
+    let mut inputs: UnitVec<Node> = unitvec![];
+    while let Some((node, in_agg)) = stack.pop() {
+        let ae = arena.get(node);
+        let in_agg = match ae {
+            AExpr::Agg(_) | AExpr::Len => {
+                if in_agg {
+                    return false;
+                }
+                has_agg = true;
+                ae.inputs_rev(&mut inputs);
+                true
+            },
+            _ => {
+                if !is_elementwise(&mut inputs, ae, arena) {
+                    return false;
+                }
+                in_agg
+            },
+        };
+        stack.extend(inputs.iter().map(|node| (*node, in_agg)));
+        inputs.clear();
+    }
+    has_agg
+}
+
+/// Evaluate `expr` on the prefixes `[0, 1), [0, 2), ..` of `c` as (overlapping) groups. The
+/// aggregations then visit every element once, e.g. with the rolling kernels, instead of once
+/// per prefix.
+fn eval_on_prefixes(c: &Column, expr: &Expr, min_periods: usize) -> PolarsResult<Column> {
+    let len = c.len();
+    let groups = GroupsType::Slice {
+        groups: (1..=len as IdxSize).map(|len| [0, len]).collect(),
+        rolling: true,
+    }
+    .into_sliceable();
+
+    let phys_expr =
+        prepare_expression_for_context(c.name().clone(), expr, c.dtype(), Context::Aggregation)?;
+    let state = ExecutionState::new();
+    let df = c.clone().into_frame();
+    let mut ac = phys_expr.evaluate_on_groups(&df, &groups, &state)?;
+    let out = ac.finalize();
+    polars_ensure!(
+        out.len() == len,
+        ComputeError: "expected single value per prefix, got a result with length {}", out.len(),
+    );
+
+    // Prefixes with fewer than `min_periods` valid values are null.
+    if min_periods <= 1 && !c.has_nulls() {
+        return Ok(out);
+    }
+    let mut n_valid = 0;
+    let mask: BooleanChunked = c
+        .is_not_null()
+        .into_no_null_iter()
+        .map(|valid| {
+            n_valid += valid as usize;
+            n_valid >= min_periods
+        })
+        .collect();
+    out.zip_with(
+        &mask,
+        &Column::full_null(out.name().clone(), len, out.dtype()),
+    )
+}
+
 pub trait ExprEvalExtension: IntoExpr + Sized {
     /// Run an expression over a sliding window that increases `1` slot every iteration.
     ///
+    /// Expressions that aggregate every window to a single value, e.g. `sum`, `mean`, `min` or
+    /// `max` combined with elementwise expressions, are evaluated on all windows in a single
+    /// pass.
+    ///
     /// # Warning
-    /// This can be really slow as it can have `O(n^2)` complexity. Don't use this for operations
-    /// that visit all elements.
+    /// Other expressions are evaluated once per window, which has `O(n^2)` complexity.
     fn cumulative_eval(self, expr: Expr, min_periods: usize, parallel: bool) -> Expr {
         let this = self.into_expr();
         let expr2 = expr.clone();
@@ -54,9 +135,18 @@ pub trait ExprEvalExtension: IntoExpr + Sized {
             let output_field = eval_field_to_dtype(c.field().as_ref(), &expr, false);
             let schema = Arc::new(Schema::from_iter(std::iter::once(output_field.clone())));
 
-            let expr = expr.clone();
             let mut arena = Arena::with_capacity(10);
-            let aexpr = to_expr_ir(expr, &mut arena)?;
+            let aexpr = to_expr_ir(expr.clone(), &mut arena)?;
+            if aggregates_prefixes(aexpr.node(), &arena) {
+                let mut c = eval_on_prefixes(&c, &expr, min_periods)?;
+                c.rename(name);
+                return if c.dtype() != output_field.dtype() {
+                    c.cast(output_field.dtype()).map(Some)
+                } else {
+                    Ok(Some(c))
+                };
+            }
+
             let phys_expr = create_physical_expr(
                 &aexpr,
                 Context::Default,
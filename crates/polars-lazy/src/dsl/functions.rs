@@ -4,8 +4,10 @@
 //!
 
 use polars_core::prelude::*;
+use polars_ops::frame::MaintainOrderJoin;
 pub use polars_plan::dsl::functions::*;
 use polars_plan::prelude::UnionArgs;
+use polars_utils::format_pl_smallstr;
 use rayon::prelude::*;
 
 use crate::prelude::*;
@@ -76,6 +78,123 @@ pub fn concat<L: AsRef<[LazyFrame]>>(inputs: L, args: UnionArgs) -> PolarsResult
     concat_impl(inputs, args)
 }
 
+/// Align `frames` on the shared, sorted values of the `on` columns: every returned frame has a
+/// row for each key, with nulls in the other columns where a frame doesn't have the key.
+///
+/// The keys are computed once from all frames, the union for [`JoinType::Full`], the keys of
+/// the first frame for [`JoinType::Left`] and the keys all frames have for [`JoinType::Inner`],
+/// and every frame is joined to them, so the frames are merged in a single pass instead of
+/// joined to each other one after another. The keys must be unique within every frame; nulls
+/// are keys like any other value. The frames keep their columns in their own order.
+pub fn align_frames(
+    frames: &[LazyFrame],
+    on: &[PlSmallStr],
+    how: JoinType,
+    descending: Vec<bool>,
+) -> PolarsResult<Vec<LazyFrame>> {
+    polars_ensure!(!on.is_empty(), InvalidOperation: "'align_frames' requires at least one key column");
+    let Some(first) = frames.first() else {
+        return Ok(vec![]);
+    };
+    let key_cols = on.iter().cloned().map(col).collect::<Vec<_>>();
+    let unique_keys = |lf: &LazyFrame| {
+        lf.clone()
+            .select(key_cols.clone())
+            .unique_stable(None, UniqueKeepStrategy::Any)
+    };
+
+    let keys = match how {
+        JoinType::Full => concat(
+            frames.iter().map(unique_keys).collect::<Vec<_>>(),
+            UnionArgs::default(),
+        )?
+        .unique(None, UniqueKeepStrategy::Any),
+        JoinType::Left => unique_keys(first),
+        // The keys that occur in every frame.
+        JoinType::Inner => {
+            const COUNT: &str = "__POLARS_ALIGN_COUNT";
+            concat(
+                frames.iter().map(unique_keys).collect::<Vec<_>>(),
+                UnionArgs::default(),
+            )?
+            .group_by(key_cols.clone())
+            .agg([len().alias(COUNT)])
+            .filter(col(COUNT).eq(lit(frames.len() as IdxSize)))
+            .drop([col(COUNT)])
+        },
+        how => polars_bail!(InvalidOperation: "'align_frames' does not support a {} join", how),
+    };
+    let keys = keys
+        .sort(
+            on.to_vec(),
+            SortMultipleOptions::new().with_order_descending_multi(descending),
+        )
+        .cache();
+
+    frames
+        .iter()
+        .map(|lf| {
+            let columns = lf
+                .clone()
+                .collect_schema()?
+                .iter_names_cloned()
+                .map(col)
+                .collect::<Vec<_>>();
+            Ok(keys
+                .clone()
+                .join_builder()
+                .with(lf.clone())
+                .on(&key_cols)
+                .how(JoinType::Left)
+                .validate(JoinValidation::OneToOne)
+                .join_nulls(true)
+                .maintain_order(MaintainOrderJoin::Left)
+                .finish()
+                .select(columns))
+        })
+        .collect()
+}
+
+/// A [`LazyFrame`] of a small inline table, of which every row is given as a list of (literal)
+/// expressions. Every column gets the supertype of its values and is named `column_{i}` if no
+/// `names` are given.
+///
+/// The expressions are evaluated once here, so that the table is a `DataFrameScan` that the
+/// optimizer and the engines treat like any other in-memory frame.
+pub fn values<R: AsRef<[Expr]>>(
+    rows: &[R],
+    names: Option<&[PlSmallStr]>,
+) -> PolarsResult<LazyFrame> {
+    let width = rows.first().map_or(0, |row| row.as_ref().len());
+    polars_ensure!(width > 0, InvalidOperation: "'values' requires at least one row and column");
+    polars_ensure!(
+        rows.iter().all(|row| row.as_ref().len() == width),
+        ShapeMismatch: "all rows of 'values' must have the same number of values"
+    );
+    if let Some(names) = names {
+        polars_ensure!(
+            names.len() == width,
+            ShapeMismatch: "got {} names for {} columns", names.len(), width
+        );
+    }
+
+    let columns = (0..width)
+        .map(|i| {
+            let values = rows
+                .iter()
+                .map(|row| row.as_ref()[i].clone())
+                .collect::<Vec<_>>();
+            let name = match names {
+                Some(names) => names[i].clone(),
+                None => format_pl_smallstr!("column_{i}"),
+            };
+            Ok(concat_expr(values, false)?.alias(name))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let df = DataFrame::empty().lazy().select(columns).collect()?;
+    Ok(df.lazy())
+}
+
 /// Collect all [`LazyFrame`] computations.
 pub fn collect_all<I>(lfs: I) -> PolarsResult<Vec<DataFrame>>
 where
@@ -88,8 +207,6 @@ where
 
 #[cfg(test)]
 mod test {
-    // used only if feature="diagonal_concat"
-    #[allow(unused_imports)]
     use super::*;
 
     #[test]
@@ -132,4 +249,38 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_align_frames() -> PolarsResult<()> {
+        let a = df!["k" => [3, 1], "x" => [30, 10]]?;
+        let b = df!["y" => ["b", "c"], "k" => [2, 3]]?;
+        let on = [PlSmallStr::from_static("k")];
+
+        let out = align_frames(
+            &[a.clone().lazy(), b.clone().lazy()],
+            &on,
+            JoinType::Full,
+            vec![false],
+        )?;
+        let out = collect_all(out)?;
+        assert!(out[0].equals_missing(&df!["k" => [1, 2, 3], "x" => [Some(10), None, Some(30)]]?));
+        assert!(
+            out[1].equals_missing(&df!["y" => [None, Some("b"), Some("c")], "k" => [1, 2, 3]]?)
+        );
+
+        let out = align_frames(
+            &[a.clone().lazy(), b.lazy()],
+            &on,
+            JoinType::Inner,
+            vec![true],
+        )?;
+        let out = collect_all(out)?;
+        assert!(out[0].equals(&df!["k" => [3], "x" => [30]]?));
+        assert!(out[1].equals(&df!["y" => ["c"], "k" => [3]]?));
+
+        let dup = df!["k" => [1, 1], "z" => [0, 0]]?;
+        let out = align_frames(&[a.lazy(), dup.lazy()], &on, JoinType::Left, vec![false])?;
+        assert!(collect_all(out).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,181 @@
+//! [`proptest`] strategies that generate [`LazyFrame`] plans.
+use std::ops::RangeInclusive;
+
+use polars_core::frame::proptest::{DataFrameArbitraryOptions, dataframe};
+use polars_plan::dsl::arbitrary::{ExprArbitraryOptions, expr};
+use proptest::prelude::*;
+
+use crate::prelude::*;
+
+/// A single operation of a generated plan.
+///
+/// Operations keep the column names of the input frame, so that every operation can refer to
+/// the columns of the original [`DataFrame`].
+#[derive(Clone, Debug)]
+pub enum LazyOp {
+    /// Select all columns in the given order.
+    Select(Vec<PlSmallStr>),
+    /// Replace columns by expressions aliased to their name.
+    WithColumns(Vec<Expr>),
+    Filter(Expr),
+    Sort {
+        by: PlSmallStr,
+        descending: bool,
+        nulls_last: bool,
+    },
+    Slice {
+        offset: i64,
+        len: IdxSize,
+    },
+    Unique {
+        maintain_order: bool,
+    },
+}
+
+impl LazyOp {
+    fn apply(self, lf: LazyFrame) -> LazyFrame {
+        match self {
+            LazyOp::Select(columns) => lf.select(columns.into_iter().map(col).collect::<Vec<_>>()),
+            LazyOp::WithColumns(exprs) => lf.with_columns(exprs),
+            LazyOp::Filter(predicate) => lf.filter(predicate),
+            LazyOp::Sort {
+                by,
+                descending,
+                nulls_last,
+            } => lf.sort(
+                [by],
+                SortMultipleOptions::default()
+                    .with_order_descending(descending)
+                    .with_nulls_last(nulls_last),
+            ),
+            LazyOp::Slice { offset, len } => lf.slice(offset, len),
+            LazyOp::Unique { maintain_order } => {
+                if maintain_order {
+                    lf.unique_stable(None, UniqueKeepStrategy::First)
+                } else {
+                    lf.unique(None, UniqueKeepStrategy::Any)
+                }
+            },
+        }
+    }
+}
+
+/// A [`DataFrame`] and the operations of a plan on top of it.
+#[derive(Clone, Debug)]
+pub struct LazyPlanArbitrary {
+    pub df: DataFrame,
+    pub ops: Vec<LazyOp>,
+}
+
+impl LazyPlanArbitrary {
+    /// Build the plan.
+    pub fn lazy(&self) -> LazyFrame {
+        self.ops
+            .iter()
+            .cloned()
+            .fold(self.df.clone().lazy(), |lf, op| op.apply(lf))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LazyPlanArbitraryOptions {
+    pub frame: DataFrameArbitraryOptions,
+    pub num_ops_range: RangeInclusive<usize>,
+    /// Options for the expressions of `with_columns` and `filter`. The columns are taken from
+    /// the generated frame.
+    pub expr: ExprArbitraryOptions,
+}
+
+impl Default for LazyPlanArbitraryOptions {
+    fn default() -> Self {
+        Self {
+            frame: Default::default(),
+            num_ops_range: 0..=5,
+            expr: ExprArbitraryOptions {
+                max_depth: 3,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+fn lazy_op(
+    columns: Vec<PlSmallStr>,
+    options: ExprArbitraryOptions,
+) -> impl Strategy<Value = LazyOp> {
+    let exprs = proptest::sample::subsequence(columns.clone(), 1..=columns.len()).prop_flat_map(
+        move |names| {
+            names
+                .into_iter()
+                .map(|name| expr(options.clone()).prop_map(move |e| e.alias(name.clone())))
+                .collect::<Vec<_>>()
+        },
+    );
+    let predicate = expr(ExprArbitraryOptions {
+        allow_aggregations: false,
+        ..options.clone()
+    });
+    prop_oneof![
+        Just(columns.clone())
+            .prop_shuffle()
+            .prop_map(LazyOp::Select),
+        exprs.prop_map(LazyOp::WithColumns),
+        predicate.prop_map(LazyOp::Filter),
+        (
+            proptest::sample::select(columns),
+            any::<bool>(),
+            any::<bool>()
+        )
+            .prop_map(|(by, descending, nulls_last)| LazyOp::Sort {
+                by,
+                descending,
+                nulls_last
+            }),
+        (-10i64..10, 0..10 as IdxSize).prop_map(|(offset, len)| LazyOp::Slice { offset, len }),
+        any::<bool>().prop_map(|maintain_order| LazyOp::Unique { maintain_order }),
+    ]
+}
+
+/// Generate a [`DataFrame`] together with a random plan over its columns.
+///
+/// Generated plans are not guaranteed to be valid, e.g. a filter predicate might not be a
+/// boolean. Collecting them should return an error instead of panicking, and the result of a
+/// valid plan should not depend on the optimizations or the engine.
+pub fn lazy_plan(options: LazyPlanArbitraryOptions) -> impl Strategy<Value = LazyPlanArbitrary> {
+    dataframe(options.frame.clone()).prop_flat_map(move |df| {
+        let columns = df.get_column_names_owned();
+        let expr_options = ExprArbitraryOptions {
+            columns: columns.clone(),
+            ..options.expr.clone()
+        };
+        let ops = proptest::collection::vec(
+            lazy_op(columns, expr_options),
+            options.num_ops_range.clone(),
+        );
+        (Just(df), ops).prop_map(|(df, ops)| LazyPlanArbitrary { df, ops })
+    })
+}
+
+impl Arbitrary for LazyPlanArbitrary {
+    type Parameters = LazyPlanArbitraryOptions;
+    type Strategy = BoxedStrategy<LazyPlanArbitrary>;
+
+    fn arbitrary_with(options: Self::Parameters) -> Self::Strategy {
+        lazy_plan(options).boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+        #[test]
+        fn test_arbitrary_lazy_plan(plan in any::<LazyPlanArbitrary>()) {
+            prop_assert!(plan.ops.len() <= 5);
+            // Plans may be invalid, but resolving them should not panic.
+            let _ = plan.lazy().describe_plan();
+        }
+    }
+}
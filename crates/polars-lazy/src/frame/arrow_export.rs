@@ -0,0 +1,184 @@
+//! Export of a query result as Arrow record batches while the query runs, see
+//! [`LazyFrame::export_arrow_batches`].
+use std::thread::JoinHandle;
+
+use arrow::datatypes::ArrowSchema;
+use arrow::record_batch::RecordBatchT;
+use tokio::sync::mpsc;
+
+use super::*;
+
+type ArrowRecordBatch = RecordBatchT<Box<dyn arrow::array::Array>>;
+
+impl LazyFrame {
+    /// Run the query on the streaming engine and export its result as Arrow record batches while
+    /// it runs, e.g. to serve it from an Arrow Flight server without collecting it first.
+    ///
+    /// With `chunk_size` all batches but the last one have exactly that many rows. The query
+    /// pauses as long as `capacity` batches are waiting to be taken, so a slow consumer doesn't
+    /// cause the result to pile up in memory. Dropping the exporter stops the query and waits for
+    /// it to finish.
+    pub fn export_arrow_batches(
+        mut self,
+        chunk_size: Option<NonZeroUsize>,
+        capacity: usize,
+    ) -> PolarsResult<ArrowBatchExporter> {
+        polars_ensure!(capacity > 0, InvalidOperation: "capacity of the exporter must be positive");
+        let schema = self.collect_schema()?;
+        let (tx, rx) = mpsc::channel(capacity);
+
+        // The sink stops the query when the receiver is gone.
+        let batch_tx = tx.clone();
+        let lf = self.sink_batches(
+            move |df| Ok(batch_tx.blocking_send(Ok(df)).is_err()),
+            true,
+            chunk_size,
+        )?;
+        let handle = std::thread::spawn(move || {
+            if let Err(e) = lf.collect_with_engine(Engine::Streaming) {
+                _ = tx.blocking_send(Err(e));
+            }
+        });
+
+        Ok(ArrowBatchExporter {
+            schema,
+            rx,
+            handle: Some(handle),
+        })
+    }
+}
+
+/// The Arrow record batches of a running query, see [`LazyFrame::export_arrow_batches`].
+///
+/// Every batch is converted with the [`CompatLevel`] that the consumer asks for, so a server
+/// can give older clients the Arrow types they understand.
+pub struct ArrowBatchExporter {
+    schema: SchemaRef,
+    rx: mpsc::Receiver<PolarsResult<DataFrame>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl ArrowBatchExporter {
+    /// The schema of the result.
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// The Arrow schema of the batches that are exported with `compat_level`.
+    pub fn arrow_schema(&self, compat_level: CompatLevel) -> ArrowSchema {
+        self.schema.to_arrow(compat_level)
+    }
+
+    /// Wait for the next batch. Returns `None` when the query is done.
+    pub async fn next_batch(
+        &mut self,
+        compat_level: CompatLevel,
+    ) -> PolarsResult<Option<ArrowRecordBatch>> {
+        let next = self.rx.recv().await;
+        self.convert_next(next, compat_level)
+    }
+
+    /// Block until the next batch is available. Returns `None` when the query is done.
+    ///
+    /// This must not be called from an async context, use [`ArrowBatchExporter::next_batch`]
+    /// there.
+    pub fn blocking_next_batch(
+        &mut self,
+        compat_level: CompatLevel,
+    ) -> PolarsResult<Option<ArrowRecordBatch>> {
+        let next = self.rx.blocking_recv();
+        self.convert_next(next, compat_level)
+    }
+
+    fn convert_next(
+        &mut self,
+        next: Option<PolarsResult<DataFrame>>,
+        compat_level: CompatLevel,
+    ) -> PolarsResult<Option<ArrowRecordBatch>> {
+        match next {
+            Some(df) => Ok(Some(df?.rechunk_to_record_batch(compat_level))),
+            None => {
+                // The query is done once all senders are dropped; propagate a panic of it.
+                if let Some(handle) = self.handle.take() {
+                    if let Err(payload) = handle.join() {
+                        std::panic::resume_unwind(payload);
+                    }
+                }
+                Ok(None)
+            },
+        }
+    }
+}
+
+impl Drop for ArrowBatchExporter {
+    fn drop(&mut self) {
+        // Closing the channel wakes a sink that waits for capacity, and makes it stop the query
+        // at its next batch. Wait for that, so that the query doesn't keep using memory and
+        // threads after the exporter is gone. A panic of the query is ignored here, as we may
+        // already be unwinding.
+        self.rx.close();
+        if let Some(handle) = self.handle.take() {
+            _ = handle.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::datatypes::ArrowDataType;
+
+    use super::*;
+
+    #[test]
+    fn test_export_arrow_batches() -> PolarsResult<()> {
+        let df = df!(
+            "a" => (0..1000).collect::<Vec<i32>>(),
+            "b" => (0..1000).map(|i| i.to_string()).collect::<Vec<_>>(),
+        )?;
+        let mut exporter = df
+            .lazy()
+            .filter(col("a").gt_eq(lit(10)))
+            .export_arrow_batches(NonZeroUsize::new(100), 2)?;
+        assert_eq!(
+            exporter
+                .arrow_schema(CompatLevel::oldest())
+                .get("b")
+                .unwrap()
+                .dtype(),
+            &ArrowDataType::LargeUtf8
+        );
+
+        // The compat level may differ per batch.
+        let mut heights = vec![];
+        loop {
+            let (compat_level, expected) = if heights.len() % 2 == 0 {
+                (CompatLevel::newest(), ArrowDataType::Utf8View)
+            } else {
+                (CompatLevel::oldest(), ArrowDataType::LargeUtf8)
+            };
+            let Some(batch) = exporter.blocking_next_batch(compat_level)? else {
+                break;
+            };
+            assert_eq!(batch.schema().get("b").unwrap().dtype(), &expected);
+            heights.push(batch.height());
+        }
+        assert_eq!(heights, [100, 100, 100, 100, 100, 100, 100, 100, 100, 90]);
+
+        // Dropping the exporter stops the query.
+        let df = df!("a" => (0..100_000).collect::<Vec<i32>>())?;
+        let mut exporter = df.lazy().export_arrow_batches(NonZeroUsize::new(10), 1)?;
+        assert!(
+            exporter
+                .blocking_next_batch(CompatLevel::newest())?
+                .is_some()
+        );
+        drop(exporter);
+
+        let mut exporter = df!("a" => ["1", "x"])?
+            .lazy()
+            .select([col("a").strict_cast(DataType::Int32)])
+            .export_arrow_batches(None, 1)?;
+        assert!(exporter.blocking_next_batch(CompatLevel::newest()).is_err());
+        Ok(())
+    }
+}
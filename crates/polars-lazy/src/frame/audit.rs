@@ -0,0 +1,83 @@
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use polars_core::prelude::*;
+use polars_plan::plans::{ArenaLpIter, IR, IRPlan};
+use polars_plan::prelude::Engine;
+
+/// Information about an executed query that is passed to the hook set by
+/// [`set_query_audit_hook`].
+#[derive(Clone, Debug)]
+pub struct QueryAuditEvent {
+    /// The optimized plan in the format of [`LazyFrame::explain`](crate::frame::LazyFrame::explain).
+    pub plan: String,
+    /// The paths of the scanned sources, or `open-file`/`in-mem` for sources that are not paths.
+    pub sources: Vec<PlSmallStr>,
+    pub engine: Engine,
+    /// Wall time of optimizing and executing the query.
+    pub duration: Duration,
+    /// `(height, width)` of every result, or the error the query failed with.
+    pub result: Result<Vec<(usize, usize)>, String>,
+}
+
+pub type QueryAuditHook = Arc<dyn Fn(&QueryAuditEvent) + Send + Sync>;
+
+static QUERY_AUDIT_HOOK: RwLock<Option<QueryAuditHook>> = RwLock::new(None);
+
+/// Set a hook that is called after every query run with `collect`, `collect_all` or a
+/// `sink_*` method, or `None` to unset it.
+///
+/// The hook is called on the thread that ran the query, so it should be cheap or hand the
+/// event off to a logger.
+pub fn set_query_audit_hook(hook: Option<QueryAuditHook>) {
+    *QUERY_AUDIT_HOOK.write().unwrap() = hook;
+}
+
+/// A query that is audited once it is done.
+pub(crate) struct QueryAudit {
+    hook: QueryAuditHook,
+    plan: String,
+    sources: Vec<PlSmallStr>,
+    engine: Engine,
+    start: Instant,
+}
+
+impl QueryAudit {
+    /// Returns `None` if no hook is set, so unaudited queries don't format their plan.
+    pub(crate) fn new(plan: &IRPlan, engine: Engine, start: Instant) -> Option<Self> {
+        let hook = QUERY_AUDIT_HOOK.read().unwrap().clone()?;
+
+        let mut sources = vec![];
+        for (_, ir) in (&plan.lp_arena).iter(plan.lp_top) {
+            if let IR::Scan { sources: s, .. } = ir {
+                sources.extend(
+                    s.iter()
+                        .map(|s| PlSmallStr::from_str(s.to_include_path_name())),
+                );
+            }
+        }
+        Some(Self {
+            hook,
+            plan: plan.describe(),
+            sources,
+            engine,
+            start,
+        })
+    }
+
+    pub(crate) fn finish<'a>(
+        self,
+        result: Result<impl IntoIterator<Item = &'a DataFrame>, &PolarsError>,
+    ) {
+        let event = QueryAuditEvent {
+            plan: self.plan,
+            sources: self.sources,
+            engine: self.engine,
+            duration: self.start.elapsed(),
+            result: result
+                .map(|dfs| dfs.into_iter().map(DataFrame::shape).collect())
+                .map_err(|e| e.to_string()),
+        };
+        (self.hook)(&event)
+    }
+}
@@ -0,0 +1,177 @@
+//! Summary statistics of a [`LazyFrame`], see [`LazyFrame::describe`].
+use polars_compute::rolling::QuantileMethod;
+use polars_core::prelude::*;
+use polars_utils::format_pl_smallstr;
+
+use crate::prelude::*;
+
+#[derive(Clone, Debug)]
+pub struct DescribeOptions {
+    /// The percentiles to compute for numeric and temporal columns, between 0 and 1.
+    ///
+    /// The streaming engine computes these exactly with a node that spills to disk if
+    /// `POLARS_QUANTILE_MEMORY_BUDGET` is set, and otherwise buffers the columns in memory.
+    pub percentiles: Vec<f64>,
+    pub interpolation: QuantileMethod,
+    /// Count the distinct values of every column. This keeps the distinct values in memory, so
+    /// it is off by default.
+    pub n_unique: bool,
+}
+
+impl Default for DescribeOptions {
+    fn default() -> Self {
+        Self {
+            percentiles: vec![0.25, 0.5, 0.75],
+            interpolation: QuantileMethod::Nearest,
+            n_unique: false,
+        }
+    }
+}
+
+fn percentile_name(p: f64) -> PlSmallStr {
+    format_pl_smallstr!("{}%", p * 100.0)
+}
+
+/// The statistics of a column, in the order of the rows of the output.
+fn column_statistics(
+    c: &PlSmallStr,
+    dtype: &DataType,
+    options: &DescribeOptions,
+) -> Vec<(PlSmallStr, Expr)> {
+    let e = || col(c.clone());
+    let mut stats = vec![
+        ("count".into(), e().count()),
+        ("null_count".into(), e().null_count()),
+        (
+            "null_ratio".into(),
+            e().null_count().cast(DataType::Float64) / len().cast(DataType::Float64),
+        ),
+    ];
+    if options.n_unique {
+        stats.push(("n_unique".into(), e().n_unique()));
+    }
+
+    let numeric = dtype.is_primitive_numeric() || dtype.is_bool();
+    if dtype.is_primitive_numeric() {
+        stats.push(("mean".into(), e().mean()));
+        stats.push(("std".into(), e().std(1)));
+    } else if dtype.is_bool() {
+        stats.push(("mean".into(), e().mean()));
+    }
+    if numeric || dtype.is_temporal() || dtype.is_string() {
+        stats.push(("min".into(), e().min()));
+    }
+    if dtype.is_primitive_numeric() || dtype.is_temporal() {
+        for p in &options.percentiles {
+            // Temporal percentiles are taken of the physical integers, so that the
+            // streaming engine can compute all percentiles the same way.
+            let quantile = if dtype.is_temporal() {
+                e().to_physical()
+                    .quantile(lit(*p), options.interpolation)
+                    .cast(DataType::Int64)
+                    .cast(dtype.clone())
+            } else {
+                e().quantile(lit(*p), options.interpolation)
+            };
+            stats.push((percentile_name(*p), quantile));
+        }
+    }
+    if numeric || dtype.is_temporal() || dtype.is_string() {
+        stats.push(("max".into(), e().max()));
+    }
+    #[cfg(feature = "strings")]
+    if dtype.is_string() {
+        stats.push(("min_len".into(), e().str().len_chars().min()));
+        stats.push(("max_len".into(), e().str().len_chars().max()));
+    }
+    stats
+}
+
+impl LazyFrame {
+    /// Compute summary statistics of every column.
+    ///
+    /// All statistics are computed in a single aggregation over the input with the streaming
+    /// engine, so this also works on scans that are larger than memory. See
+    /// [`DescribeOptions`] for the statistics that buffer values in memory.
+    ///
+    /// The result has a `statistic` column with the name of the statistics and a column per
+    /// input column. Every column gets the count, null count, null ratio and, if enabled, the
+    /// number of distinct values. Numeric columns add the mean, standard deviation, minimum,
+    /// percentiles and maximum, temporal columns the minimum, percentiles and maximum and string
+    /// columns the minimum and maximum value and length. Numeric and boolean statistics are
+    /// `Float64`, the statistics of other columns are formatted as strings. Statistics that don't
+    /// apply to a column are null.
+    pub fn describe(mut self, options: DescribeOptions) -> PolarsResult<DataFrame> {
+        for p in &options.percentiles {
+            polars_ensure!(
+                (0.0..=1.0).contains(p),
+                InvalidOperation: "percentiles must be between 0 and 1, got {}", p
+            );
+        }
+        let schema = self.collect_schema()?;
+        polars_ensure!(
+            !schema.is_empty(),
+            InvalidOperation: "cannot describe a LazyFrame without columns"
+        );
+
+        let mut computed = PlHashSet::new();
+        let mut exprs = vec![];
+        let columns = schema
+            .iter()
+            .enumerate()
+            .map(|(i, (name, dtype))| {
+                let target = if dtype.is_primitive_numeric() || dtype.is_bool() {
+                    DataType::Float64
+                } else {
+                    DataType::String
+                };
+                for (stat, expr) in column_statistics(name, dtype, &options) {
+                    exprs.push(
+                        expr.cast(target.clone())
+                            .alias(format_pl_smallstr!("{i}:{stat}")),
+                    );
+                    computed.insert(stat);
+                }
+                (name.clone(), target)
+            })
+            .collect::<Vec<_>>();
+
+        let statistics = [
+            "count",
+            "null_count",
+            "null_ratio",
+            "n_unique",
+            "mean",
+            "std",
+            "min",
+        ]
+        .into_iter()
+        .map(PlSmallStr::from_static)
+        .chain(options.percentiles.iter().copied().map(percentile_name))
+        .chain(["max", "min_len", "max_len"].map(PlSmallStr::from_static))
+        .filter(|stat| computed.contains(stat))
+        .collect::<Vec<_>>();
+
+        #[cfg(feature = "new_streaming")]
+        let engine = Engine::Streaming;
+        #[cfg(not(feature = "new_streaming"))]
+        let engine = Engine::Auto;
+        let row = self.select(exprs).collect_with_engine(engine)?;
+
+        let mut out = Vec::with_capacity(columns.len() + 1);
+        let names = statistics.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        out.push(Column::new("statistic".into(), names));
+        for (i, (name, target)) in columns.into_iter().enumerate() {
+            let values = statistics
+                .iter()
+                .map(|stat| {
+                    row.column(&format_pl_smallstr!("{i}:{stat}"))
+                        .map_or(AnyValue::Null, |c| c.get(0).unwrap().into_static())
+                })
+                .collect::<Vec<_>>();
+            let s = Series::from_any_values_and_dtype(name, &values, &target, true)?;
+            out.push(s.into_column());
+        }
+        DataFrame::new(out)
+    }
+}
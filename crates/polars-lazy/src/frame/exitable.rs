@@ -17,10 +17,7 @@ impl LazyFrame {
             tx.send(result).unwrap();
         });
 
-        Ok(InProcessQuery {
-            rx: Arc::new(Mutex::new(rx)),
-            token,
-        })
+        Ok(InProcessQuery::new(rx, token))
     }
 }
 
@@ -31,6 +28,13 @@ pub struct InProcessQuery {
 }
 
 impl InProcessQuery {
+    pub(super) fn new(rx: Receiver<PolarsResult<DataFrame>>, token: Arc<AtomicBool>) -> Self {
+        Self {
+            rx: Arc::new(Mutex::new(rx)),
+            token,
+        }
+    }
+
     /// Cancel the query at earliest convenience.
     pub fn cancel(&self) {
         self.token.store(true, Ordering::Relaxed)
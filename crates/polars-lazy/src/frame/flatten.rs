@@ -0,0 +1,150 @@
+//! Flatten nested `List` and `Struct` columns to a long table, see [`LazyFrame::flatten`].
+use polars_core::prelude::*;
+use polars_utils::format_pl_smallstr;
+
+use crate::prelude::*;
+
+struct Flattener<'a> {
+    schema: &'a Schema,
+    max_depth: usize,
+    lf: LazyFrame,
+    /// The generated columns, in the order of the output.
+    output: Vec<PlSmallStr>,
+}
+
+impl Flattener<'_> {
+    fn ensure_new(&self, name: &str) -> PolarsResult<()> {
+        polars_ensure!(
+            !self.schema.contains(name) && !self.output.iter().any(|n| n == name),
+            Duplicate: "flattened column name '{}' already exists", name
+        );
+        Ok(())
+    }
+
+    fn visit(&mut self, name: PlSmallStr, dtype: &DataType, depth: usize) -> PolarsResult<()> {
+        match dtype {
+            DataType::List(inner) if depth < self.max_depth => {
+                let index = format_pl_smallstr!("{name}_index");
+                self.ensure_new(&index)?;
+                let lf = std::mem::take(&mut self.lf);
+                self.lf = lf.explode_with_position([name.clone()], index.clone(), None);
+                self.output.push(index);
+                self.visit(name, inner, depth + 1)
+            },
+            DataType::Struct(fields) if depth < self.max_depth => {
+                let children = fields
+                    .iter()
+                    .map(|f| (format_pl_smallstr!("{name}.{}", f.name()), f.dtype()))
+                    .collect::<Vec<_>>();
+                for (child, _) in &children {
+                    self.ensure_new(child)?;
+                }
+                let lf = std::mem::take(&mut self.lf);
+                self.lf = lf
+                    .with_columns(
+                        fields
+                            .iter()
+                            .zip(&children)
+                            .map(|(f, (child, _))| {
+                                col(name.clone())
+                                    .struct_()
+                                    .field_by_name(f.name())
+                                    .alias(child.clone())
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                    .drop([name]);
+                for (child, dtype) in children {
+                    self.visit(child, dtype, depth + 1)?;
+                }
+                Ok(())
+            },
+            _ => {
+                self.output.push(name);
+                Ok(())
+            },
+        }
+    }
+}
+
+impl LazyFrame {
+    /// Flatten a nested `List` and `Struct` column to a long table in one operation.
+    ///
+    /// Every `List` is exploded, and a column `{name}_index` with the position of the value
+    /// in its list is added before the values. Every `Struct` is replaced by a column
+    /// `{name}.{field}` per field. This repeats for the values and fields up to `max_depth`
+    /// levels deep, or until no nested columns are left if `max_depth` is `None`. Deeper
+    /// columns are kept as they are.
+    ///
+    /// The generated columns take the place of `column`. Lists that are fields of the same
+    /// struct are exploded one after the other, so the rows are their cartesian product.
+    /// Empty and null lists give a single row with null values and a null index.
+    pub fn flatten(self, column: &str, max_depth: Option<usize>) -> PolarsResult<LazyFrame> {
+        let mut lf = self;
+        let schema = lf.collect_schema()?;
+        let dtype = schema.try_get(column)?.clone();
+
+        let mut flattener = Flattener {
+            schema: &schema,
+            max_depth: max_depth.unwrap_or(usize::MAX),
+            lf,
+            output: vec![],
+        };
+        flattener.visit(column.into(), &dtype, 0)?;
+
+        let columns = schema
+            .iter_names()
+            .flat_map(|name| {
+                if name == column {
+                    flattener.output.clone()
+                } else {
+                    vec![name.clone()]
+                }
+            })
+            .map(col)
+            .collect::<Vec<_>>();
+        Ok(flattener.lf.select(columns))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_flatten() -> PolarsResult<()> {
+        // `orders: list[struct[items: list[i32], order: i32]]`
+        let items = Series::new(
+            "items".into(),
+            [
+                Series::new("".into(), [1, 2]),
+                Series::new("".into(), [3]),
+                Series::new("".into(), Vec::<i32>::new()),
+            ],
+        );
+        let order = Series::new("order".into(), [10, 11, 12]);
+        let orders = StructChunked::from_series("".into(), 3, [items, order].iter())?.into_series();
+        let df = df![
+            "id" => [1, 2],
+            "orders" => [orders.slice(0, 2), orders.slice(2, 1)],
+        ]?;
+
+        let out = df.clone().lazy().flatten("orders", None)?.collect()?;
+        let expected = df![
+            "id" => [1, 1, 1, 2],
+            "orders_index" => [0 as IdxSize, 0, 1, 0],
+            "orders.items_index" => [Some(0 as IdxSize), Some(1), Some(0), None],
+            "orders.items" => [Some(1), Some(2), Some(3), None],
+            "orders.order" => [10, 10, 11, 12],
+        ]?;
+        assert!(out.equals_missing(&expected));
+
+        let out = df.lazy().flatten("orders", Some(2))?.collect()?;
+        assert_eq!(
+            out.get_column_names(),
+            &["id", "orders_index", "orders.items", "orders.order"]
+        );
+        assert!(out.column("orders.items")?.dtype().is_list());
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+//! Migration of a [`LazyFrame`] to a new schema, see [`MigrationPlan`].
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// A step of a [`MigrationPlan`].
+#[derive(Clone, Debug)]
+pub enum MigrationStep {
+    /// Add a column that is filled with `fill`, cast to `dtype`.
+    Add {
+        name: PlSmallStr,
+        dtype: DataType,
+        fill: Expr,
+    },
+    /// Cast a column to a new type.
+    Cast {
+        name: PlSmallStr,
+        from: DataType,
+        to: DataType,
+    },
+    /// Drop a column.
+    Drop { name: PlSmallStr },
+}
+
+/// The steps that migrate data from one schema to another, planned from their
+/// [`diff`](polars_schema::Schema::diff).
+///
+/// Columns that are only in the target schema are added, columns with a different type are cast
+/// and columns that aren't in the target schema are dropped. After applying the plan the columns
+/// are in the order of the target schema.
+#[derive(Clone, Debug)]
+pub struct MigrationPlan {
+    target: SchemaRef,
+    steps: Vec<MigrationStep>,
+    strict: bool,
+}
+
+impl MigrationPlan {
+    /// Plan the migration from `from` to `target`. Added columns are filled with nulls and casts
+    /// are strict, so that values that can't be cast raise an error instead of becoming null.
+    pub fn new(from: &Schema, target: SchemaRef) -> Self {
+        let diff = from.diff(&target);
+        let added = diff
+            .added
+            .into_iter()
+            .map(|(name, dtype)| MigrationStep::Add {
+                name,
+                dtype,
+                fill: Expr::Literal(LiteralValue::untyped_null()),
+            });
+        let cast = diff
+            .retyped
+            .into_iter()
+            .map(|(name, from, to)| MigrationStep::Cast { name, from, to });
+        let dropped = diff
+            .removed
+            .into_iter()
+            .map(|(name, _)| MigrationStep::Drop { name });
+        Self {
+            target,
+            steps: added.chain(cast).chain(dropped).collect(),
+            strict: true,
+        }
+    }
+
+    /// Fill the added column `name` with `fill` instead of nulls, e.g. a literal default or an
+    /// expression of the existing columns.
+    pub fn with_fill(mut self, name: &str, fill: Expr) -> PolarsResult<Self> {
+        let Some(MigrationStep::Add { fill: f, .. }) = self
+            .steps
+            .iter_mut()
+            .find(|step| matches!(step, MigrationStep::Add { name: n, .. } if n == name))
+        else {
+            polars_bail!(InvalidOperation: "column '{}' is not added by the migration", name);
+        };
+        *f = fill;
+        Ok(self)
+    }
+
+    /// Whether values that can't be cast raise an error (the default) or become null.
+    pub fn with_strict_casts(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    pub fn steps(&self) -> &[MigrationStep] {
+        &self.steps
+    }
+
+    /// Whether the migration doesn't change anything but possibly the order of the columns.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Migrate `lf`, which should have the schema the plan was made from.
+    pub fn apply(&self, lf: LazyFrame) -> LazyFrame {
+        let cast = |e: Expr, dtype: &DataType| {
+            if self.strict {
+                e.strict_cast(dtype.clone())
+            } else {
+                e.cast(dtype.clone())
+            }
+        };
+        let exprs = self
+            .steps
+            .iter()
+            .filter_map(|step| match step {
+                MigrationStep::Add { name, dtype, fill } => {
+                    Some(cast(fill.clone(), dtype).alias(name.clone()))
+                },
+                MigrationStep::Cast { name, to, .. } => Some(cast(col(name.clone()), to)),
+                MigrationStep::Drop { .. } => None,
+            })
+            .collect::<Vec<_>>();
+        // Added literals are broadcast to the height of the frame by `with_columns`, the columns
+        // that aren't in the target are dropped by the `select`.
+        lf.with_columns(exprs)
+            .select(self.target.iter_names_cloned().map(col).collect::<Vec<_>>())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_migration_plan() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [1i32, 2],
+            "b" => ["x", "y"],
+            "c" => ["3", "4"],
+        )?;
+        let target = Arc::new(Schema::from_iter([
+            Field::new("c".into(), DataType::Int64),
+            Field::new("a".into(), DataType::Int32),
+            Field::new("d".into(), DataType::Float64),
+            Field::new("e".into(), DataType::String),
+        ]));
+
+        let diff = df.schema().diff(&target);
+        assert_eq!(
+            diff.added,
+            [
+                (PlSmallStr::from_static("d"), DataType::Float64),
+                (PlSmallStr::from_static("e"), DataType::String)
+            ]
+        );
+        assert_eq!(
+            diff.removed,
+            [(PlSmallStr::from_static("b"), DataType::String)]
+        );
+        assert_eq!(
+            diff.retyped,
+            [(
+                PlSmallStr::from_static("c"),
+                DataType::String,
+                DataType::Int64
+            )]
+        );
+
+        let plan = MigrationPlan::new(df.schema(), target.clone()).with_fill("d", lit(0.5))?;
+        assert_eq!(plan.steps().len(), 4);
+        assert!(plan.clone().with_fill("a", lit(1)).is_err());
+
+        let out = plan.apply(df.clone().lazy()).collect()?;
+        assert_eq!(out.schema(), &target);
+        assert_eq!(Vec::from(out.column("c")?.i64()?), [Some(3), Some(4)]);
+        assert_eq!(Vec::from(out.column("d")?.f64()?), [Some(0.5), Some(0.5)]);
+        assert_eq!(out.column("e")?.null_count(), 2);
+
+        let df = df!("a" => [1i32, 2], "b" => ["x", "y"], "c" => ["3", "four"])?;
+        assert!(plan.apply(df.clone().lazy()).collect().is_err());
+        let out = plan.with_strict_casts(false).apply(df.lazy()).collect()?;
+        assert_eq!(Vec::from(out.column("c")?.i64()?), [Some(3), None]);
+        Ok(())
+    }
+}
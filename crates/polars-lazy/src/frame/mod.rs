@@ -2,28 +2,58 @@
 #[cfg(feature = "python")]
 mod python;
 
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
+#[cfg(feature = "new_streaming")]
+mod arrow_export;
+mod audit;
 mod cached_arenas;
+#[cfg(feature = "describe")]
+mod describe;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+#[cfg(feature = "dtype-struct")]
+mod flatten;
+mod migration;
+mod partition;
 #[cfg(feature = "pivot")]
 pub mod pivot;
-
-use std::path::PathBuf;
+mod scan_rows;
+#[cfg(not(target_arch = "wasm32"))]
+mod scheduler;
+#[cfg(feature = "semi_anti_join")]
+mod set_ops;
+#[cfg(feature = "dtype-struct")]
+mod stats;
+#[cfg(feature = "upsample")]
+mod upsample;
+
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
 pub use anonymous_scan::*;
+#[cfg(feature = "new_streaming")]
+pub use arrow_export::ArrowBatchExporter;
+pub use audit::{QueryAuditEvent, QueryAuditHook, set_query_audit_hook};
 #[cfg(feature = "csv")]
 pub use csv::*;
+#[cfg(feature = "describe")]
+pub use describe::DescribeOptions;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
+pub use migration::{MigrationPlan, MigrationStep};
 #[cfg(feature = "json")]
 pub use ndjson::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
+pub use partition::PartitionIter;
+#[cfg(feature = "search_sorted")]
+pub use partition::RangePartitions;
 use polars_compute::rolling::QuantileMethod;
 use polars_core::POOL;
 #[cfg(feature = "new_streaming")]
@@ -32,15 +62,22 @@ use polars_core::error::feature_gated;
 use polars_core::prelude::*;
 use polars_expr::{ExpressionConversionState, create_physical_expr};
 use polars_io::RowIndex;
-use polars_mem_engine::{Executor, create_multiple_physical_plans, create_physical_plan};
+use polars_mem_engine::{
+    Executor, create_multiple_physical_plans, create_physical_plan,
+    create_physical_plan_with_backend,
+};
 use polars_ops::frame::{JoinCoalesce, MaintainOrderJoin};
 #[cfg(feature = "is_between")]
 use polars_ops::prelude::ClosedInterval;
 pub use polars_plan::frame::{AllowedOptimizations, OptFlags};
 use polars_plan::global::FETCH_ROWS;
 use polars_utils::pl_str::PlSmallStr;
+use polars_utils::slice_enum::Slice;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+#[cfg(not(target_arch = "wasm32"))]
+pub use scheduler::{QueryScheduler, SchedulerOptions};
 
+use crate::frame::audit::QueryAudit;
 use crate::frame::cached_arenas::CachedArena;
 #[cfg(feature = "streaming")]
 use crate::physical_plan::streaming::insert_streaming_nodes;
@@ -153,6 +190,31 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle semi-join reduction of aggregations over star-schema joins, this is off by
+    /// default.
+    pub fn with_semi_join_reduction(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::SEMI_JOIN_REDUCTION, toggle);
+        self
+    }
+
+    /// Force deterministic results: group-by, distinct and union maintain the order of their
+    /// input, and distinct keeps the first row instead of an arbitrary one. This trades
+    /// performance for reproducibility.
+    pub fn with_deterministic(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::DETERMINISTIC, toggle);
+        self
+    }
+
+    /// Treat floating point `NaN` in the `min`, `max`, `mean`, `sum` and `count` aggregations of
+    /// this query according to `policy`, instead of the default where `min` and `max` skip `NaN`
+    /// and `mean` and `sum` propagate it. With [`NanPolicy::Ignore`] or [`NanPolicy::AsNull`] a
+    /// group of only `NaN` aggregates to null, and only [`NanPolicy::AsNull`] counts `NaN` as
+    /// null, e.g. in `len`.
+    pub fn with_nan_policy(mut self, policy: Option<NanPolicy>) -> Self {
+        self.opt_state.set_nan_policy(policy);
+        self
+    }
+
     /// Check if operations are order dependent and unset maintaining_order if
     /// the order would not be observed.
     pub fn with_check_order(mut self, toggle: bool) -> Self {
@@ -265,6 +327,73 @@ impl LazyFrame {
             .describe_tree_format())
     }
 
+    /// Return a String describing the optimized logical plan that can be compared against a
+    /// snapshot stored in a file.
+    ///
+    /// Unlike [`LazyFrame::describe_optimized_plan`], the output does not contain the IDs of
+    /// scans and caches, and paths of scanned files are relative to `root` (if given), so that it
+    /// only changes if the optimized plan changes.
+    pub fn describe_optimized_plan_snapshot(&self, root: Option<&Path>) -> PolarsResult<String> {
+        Ok(self
+            .clone()
+            ._describe_to_alp_optimized()?
+            .describe_snapshot(root))
+    }
+
+    /// Return a JSON array that describes every node of the logical plan.
+    ///
+    /// Every node has a stable `id` (see [`IRPlan::nodes`]), the `ids` of its `inputs`, its
+    /// output `schema` and, for scans, the columns, predicate and slice that were pushed down.
+    /// If `optimized` is `true`, describes the optimized plan.
+    pub fn describe_plan_json(&self, optimized: bool) -> PolarsResult<String> {
+        let plan = if optimized {
+            self.clone()._describe_to_alp_optimized()
+        } else {
+            self.clone().to_alp()
+        }?;
+
+        let nodes = plan
+            .nodes()
+            .into_iter()
+            .map(|node| {
+                let schema = node
+                    .schema
+                    .iter()
+                    .map(|(name, dtype)| {
+                        serde_json::json!({ "name": name.as_str(), "dtype": dtype.to_string() })
+                    })
+                    .collect::<Vec<_>>();
+                let pushdown = node.pushdown.map(|pushdown| {
+                    let slice = pushdown.slice.map(|slice| match slice {
+                        Slice::Positive { offset, len } => {
+                            serde_json::json!({ "offset": offset as i64, "len": len })
+                        },
+                        Slice::Negative {
+                            offset_from_end,
+                            len,
+                        } => serde_json::json!({ "offset": -(offset_from_end as i64), "len": len }),
+                    });
+                    serde_json::json!({
+                        "projection": pushdown
+                            .projection
+                            .map(|columns| columns.iter().map(|c| c.to_string()).collect::<Vec<_>>()),
+                        "predicate": pushdown.predicate,
+                        "slice": slice,
+                    })
+                });
+                serde_json::json!({
+                    "id": node.id,
+                    "name": node.name,
+                    "description": node.description,
+                    "inputs": node.inputs,
+                    "schema": schema,
+                    "pushdown": pushdown,
+                })
+            })
+            .collect::<Vec<_>>();
+        serde_json::to_string_pretty(&nodes).map_err(polars_core::error::to_compute_err)
+    }
+
     /// Return a String describing the logical plan.
     ///
     /// If `optimized` is `true`, explains the optimized plan. If `optimized` is `false`,
@@ -325,6 +454,39 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Declare that the data is sorted by the columns `by`, lexicographically, without sorting
+    /// it. `descending` has a flag per column, or a single flag for all columns.
+    ///
+    /// The sortedness is kept through filters, slices and projections that keep the columns,
+    /// and is used to:
+    /// - group by runs of equal keys instead of hashing, if the keys of a group-by are a prefix
+    ///   of `by`. The streaming engine still groups by hashing.
+    /// - drop duplicates by comparing neighbouring rows, if the subset of a unique is a prefix
+    ///   of `by`. The streaming engine does so without keeping any state if the first or any
+    ///   row is kept.
+    /// - skip the sortedness check of asof joins, if both sides are sorted ascending by the
+    ///   `on` column within the groups of their `by` columns, that is, if the `by` columns are
+    ///   a prefix of `by` and the `on` column follows them, or if the `on` column comes first.
+    /// - set the sorted flag of the first column, so that operations that exploit the
+    ///   sortedness of a single column, like merges, don't have to check it.
+    ///
+    /// The data is not checked. If it is not sorted, the results are incorrect.
+    pub fn set_sorted_by(
+        self,
+        by: impl IntoVec<PlSmallStr>,
+        descending: impl IntoIterator<Item = bool>,
+    ) -> Self {
+        let columns = by.into_vec();
+        let mut descending = descending.into_iter().collect::<Vec<_>>();
+        if descending.len() == 1 {
+            descending = vec![descending[0]; columns.len()];
+        }
+        self.map_private(DslFunction::SetSortedBy {
+            columns,
+            descending,
+        })
+    }
+
     /// Add a sort operation to the logical plan.
     ///
     /// Sorts the LazyFrame by the provided list of expressions, which will be turned into
@@ -359,6 +521,26 @@ impl LazyFrame {
         }
     }
 
+    /// Sort the rows by the columns `by` within every run of equal values in `partition_by`,
+    /// keeping the runs in place. At least one partition column is required, use
+    /// [`LazyFrame::sort`] for a full sort.
+    ///
+    /// This skips the global merge of a full sort, which makes it much cheaper when the consumer
+    /// only needs the rows to be ordered per partition, e.g. a partitioned write of data that is
+    /// already grouped by the partition keys.
+    pub fn sort_within_partitions(
+        self,
+        partition_by: impl IntoVec<PlSmallStr>,
+        by: impl IntoVec<PlSmallStr>,
+        sort_options: SortMultipleOptions,
+    ) -> Self {
+        self.map_private(DslFunction::SortWithinPartitions {
+            partition_by: partition_by.into_vec(),
+            by: by.into_vec(),
+            sort_options,
+        })
+    }
+
     pub fn top_k<E: AsRef<[Expr]>>(
         self,
         k: IdxSize,
@@ -698,7 +880,9 @@ impl LazyFrame {
             !matches!(
                 lp_arena.get(lp_top),
                 IR::Sink {
-                    payload: SinkTypeIR::File { .. } | SinkTypeIR::Partition { .. },
+                    payload: SinkTypeIR::File { .. }
+                        | SinkTypeIR::Partition { .. }
+                        | SinkTypeIR::Callback { .. },
                     ..
                 }
             )
@@ -731,6 +915,21 @@ impl LazyFrame {
         physical_plan.execute(&mut state)
     }
 
+    /// Execute the query, offloading the parts of the optimized plan that `backend` supports
+    /// to it. The remaining parts run on the in-memory engine.
+    pub fn collect_with_backend(self, backend: Arc<dyn EngineBackend>) -> PolarsResult<DataFrame> {
+        let mut alp_plan = self.to_alp_optimized()?;
+        let mut physical_plan = create_physical_plan_with_backend(
+            alp_plan.lp_top,
+            &mut alp_plan.lp_arena,
+            &mut alp_plan.expr_arena,
+            BUILD_STREAMING_EXECUTOR,
+            Some(backend),
+        )?;
+        let mut state = ExecutionState::new();
+        physical_plan.execute(&mut state)
+    }
+
     #[allow(unused_mut)]
     fn prepare_collect(
         self,
@@ -745,6 +944,7 @@ impl LazyFrame {
     ///
     /// The query is optimized prior to execution.
     pub fn collect_with_engine(mut self, mut engine: Engine) -> PolarsResult<DataFrame> {
+        let query_start = std::time::Instant::now();
         let payload = if let DslPlan::Sink { payload, .. } = &self.logical_plan {
             payload.clone()
         } else {
@@ -759,7 +959,9 @@ impl LazyFrame {
         if engine == Engine::Auto {
             engine = match payload {
                 #[cfg(feature = "new_streaming")]
-                SinkType::File { .. } | SinkType::Partition { .. } => Engine::Streaming,
+                SinkType::File { .. } | SinkType::Partition { .. } | SinkType::Callback { .. } => {
+                    Engine::Streaming
+                },
                 _ => Engine::InMemory,
             };
         }
@@ -784,8 +986,9 @@ impl LazyFrame {
             _ => {},
         }
         let mut alp_plan = self.clone().to_alp_optimized()?;
+        let audit = QueryAudit::new(&alp_plan, engine, query_start);
 
-        match engine {
+        let result = match engine {
             Engine::Auto | Engine::Streaming => feature_gated!("new_streaming", {
                 let string_cache_hold = StringCacheHolder::hold();
                 let result = polars_stream::run_query(
@@ -823,7 +1026,11 @@ impl LazyFrame {
                 );
                 physical_plan.execute(&mut state)
             },
+        };
+        if let Some(audit) = audit {
+            audit.finish(result.as_ref().map(std::iter::once));
         }
+        result
     }
 
     pub fn explain_all(plans: Vec<DslPlan>, opt_state: OptFlags) -> PolarsResult<String> {
@@ -843,6 +1050,7 @@ impl LazyFrame {
         if plans.is_empty() {
             return Ok(Vec::new());
         }
+        let query_start = std::time::Instant::now();
 
         // Default engine for collect_all is InMemory
         if engine == Engine::Auto {
@@ -881,7 +1089,15 @@ impl LazyFrame {
             _ => {},
         }
         let mut alp_plan = sink_multiple.to_alp_optimized()?;
+        let audit = QueryAudit::new(&alp_plan, engine, query_start);
+        let result = Self::execute_all(&mut alp_plan, engine);
+        if let Some(audit) = audit {
+            audit.finish(result.as_deref());
+        }
+        result
+    }
 
+    fn execute_all(alp_plan: &mut IRPlan, engine: Engine) -> PolarsResult<Vec<DataFrame>> {
         if engine == Engine::Streaming {
             feature_gated!("new_streaming", {
                 let string_cache_hold = StringCacheHolder::hold();
@@ -966,6 +1182,48 @@ impl LazyFrame {
         self.collect_with_engine(Engine::InMemory)
     }
 
+    /// Execute all the lazy operations and collect them into a [`DataFrame`] whose chunks all
+    /// have `n_rows` rows, except for the last one.
+    ///
+    /// The query's result is sunk with `n_rows` as the [`SinkOptions::batch_size`], so the
+    /// chunks are cut by the sink while the query runs. This is useful for consumers that require
+    /// bounded batch sizes, e.g. Arrow Flight, as every chunk becomes one record batch when the
+    /// result is exported.
+    pub fn collect_chunked(self, n_rows: usize) -> PolarsResult<DataFrame> {
+        self.collect_chunked_with_engine(n_rows, Engine::Auto)
+    }
+
+    /// [`LazyFrame::collect_chunked`] with the given [`Engine`].
+    pub fn collect_chunked_with_engine(
+        mut self,
+        n_rows: usize,
+        engine: Engine,
+    ) -> PolarsResult<DataFrame> {
+        let batch_size = NonZeroUsize::new(n_rows).ok_or_else(
+            || polars_err!(InvalidOperation: "number of rows per chunk must be positive"),
+        )?;
+        let schema = self.collect_schema()?;
+        let batches = Arc::new(Mutex::new(Vec::new()));
+        let sink_batches = batches.clone();
+        self.sink_batches(
+            move |mut df| {
+                // A batch can still span several chunks of the query's output.
+                df.rechunk_mut();
+                sink_batches.lock().unwrap().push(df);
+                Ok(false)
+            },
+            true,
+            Some(batch_size),
+        )?
+        .collect_with_engine(engine)?;
+
+        let batches = std::mem::take(&mut *batches.lock().unwrap());
+        Ok(
+            polars_core::utils::accumulate_dataframes_vertical_unchecked_optional(batches)
+                .unwrap_or_else(|| DataFrame::empty_with_schema(&schema)),
+        )
+    }
+
     // post_opt: A function that is called after optimization. This can be used to modify the IR jit.
     // This version does profiling of the node execution.
     pub fn _profile_post_opt<P>(self, post_opt: P) -> PolarsResult<(DataFrame, DataFrame)>
@@ -978,6 +1236,10 @@ impl LazyFrame {
         ) -> PolarsResult<()>,
     {
         let query_start = std::time::Instant::now();
+        #[cfg(feature = "new_streaming")]
+        if self.opt_state.contains(OptFlags::NEW_STREAMING) {
+            return self.profile_streaming(query_start, post_opt);
+        }
         let (mut state, mut physical_plan, _) =
             self.prepare_collect_post_opt(false, Some(query_start), post_opt)?;
         state.time_nodes(query_start);
@@ -986,13 +1248,73 @@ impl LazyFrame {
         Ok((out, timer_df))
     }
 
+    #[cfg(feature = "new_streaming")]
+    fn profile_streaming<P>(
+        mut self,
+        query_start: std::time::Instant,
+        post_opt: P,
+    ) -> PolarsResult<(DataFrame, DataFrame)>
+    where
+        P: FnOnce(
+            Node,
+            &mut Arena<IR>,
+            &mut Arena<AExpr>,
+            Option<std::time::Duration>,
+        ) -> PolarsResult<()>,
+    {
+        self.logical_plan = DslPlan::Sink {
+            input: Arc::new(self.logical_plan),
+            payload: SinkType::Memory,
+        };
+        let (mut lp_arena, mut expr_arena) = self.get_arenas();
+        let mut scratch = vec![];
+        let lp_top =
+            self.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut scratch, false)?;
+        post_opt(
+            lp_top,
+            &mut lp_arena,
+            &mut expr_arena,
+            Some(query_start.elapsed()),
+        )?;
+
+        let string_cache_hold = StringCacheHolder::hold();
+        let (out, profile) =
+            polars_stream::StreamingQuery::build(lp_top, &mut lp_arena, &mut expr_arena)?
+                .execute_profiled()?;
+        drop(string_cache_hold);
+
+        let mut state = ExecutionState::new();
+        state.time_nodes(query_start);
+        for node in profile {
+            let stats = polars_expr::state::NodeStats {
+                rows: Some(node.rows),
+                peak_memory: Some(node.peak_memory),
+                spilled_bytes: Some(node.spilled_bytes),
+                thread_utilization: node.thread_utilization,
+            };
+            state.record_node(
+                node.start.duration_since(query_start),
+                node.end.duration_since(query_start),
+                node.name,
+                stats,
+            );
+        }
+        Ok((out.unwrap_single(), state.finish_timer()?))
+    }
+
     /// Profile a LazyFrame.
     ///
     /// This will run the query and return a tuple
     /// containing the materialized DataFrame and a DataFrame that contains profiling information
     /// of each node that is executed.
     ///
-    /// The units of the timings are microseconds.
+    /// The units of the timings are microseconds. Besides the timings, every node reports the
+    /// `rows` it produced, the estimated `peak_memory` of its output, the `spilled_bytes` it wrote
+    /// to disk, and the fraction of its threads it kept busy as `thread_utilization`. Statistics
+    /// that can't be measured by the engine, or don't apply to the node, are null.
+    ///
+    /// The query runs on the streaming engine if it was enabled with
+    /// [`LazyFrame::with_new_streaming`], and on the in-memory engine otherwise.
     pub fn profile(self) -> PolarsResult<(DataFrame, DataFrame)> {
         self._profile_post_opt(|_, _, _, _| Ok(()))
     }
@@ -1035,6 +1357,47 @@ impl LazyFrame {
         }))
     }
 
+    /// Stream a query result in the Arrow IPC streaming format into `target`, which may be any
+    /// writer, see [`SinkTarget::from_writeable`]. This allows piping the result into another
+    /// service, e.g. over a socket, while the query runs.
+    #[cfg(feature = "ipc")]
+    pub fn sink_ipc_stream(
+        self,
+        target: SinkTarget,
+        options: IpcWriterOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+        sink_options: SinkOptions,
+    ) -> PolarsResult<Self> {
+        let options = IpcWriterOptions {
+            stream: true,
+            ..options
+        };
+        self.sink_ipc(target, options, cloud_options, sink_options)
+    }
+
+    /// Stream a query result into `function`, which is called with the batches of the result
+    /// while the query runs and returns whether the query should stop.
+    ///
+    /// The function is called from a blocking thread and may block to apply back-pressure to the
+    /// query. With `chunk_size` all batches but the last one have exactly that many rows.
+    pub fn sink_batches(
+        self,
+        function: impl Fn(DataFrame) -> PolarsResult<bool> + Send + Sync + 'static,
+        maintain_order: bool,
+        chunk_size: Option<NonZeroUsize>,
+    ) -> PolarsResult<Self> {
+        let function: Arc<dyn Fn(DataFrame) -> PolarsResult<bool> + Send + Sync> =
+            Arc::new(function);
+        self.sink(SinkType::Callback(CallbackSinkType {
+            function: SpecialEq::new(function),
+            sink_options: SinkOptions {
+                maintain_order,
+                batch_size: chunk_size.map(NonZeroUsize::get),
+                ..Default::default()
+            },
+        }))
+    }
+
     /// Stream a query result into an csv file. This is useful if the final result doesn't fit
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
@@ -1096,6 +1459,53 @@ impl LazyFrame {
         }))
     }
 
+    /// Stream a query result into `num_buckets` parquet files by a stable hash of the key
+    /// columns `by`, see [`bucket_id`].
+    ///
+    /// The files of bucket `i` are written to `{base_path}/bucket={i}/`, without the bucket
+    /// column, and the bucketing scheme is recorded in the `polars.bucketing` file-level
+    /// metadata of every file.
+    #[cfg(all(feature = "parquet", feature = "row_hash", feature = "dtype-struct"))]
+    pub fn sink_parquet_bucketed(
+        self,
+        base_path: Arc<PathBuf>,
+        by: Vec<PlSmallStr>,
+        num_buckets: u32,
+        mut options: ParquetWriteOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+        sink_options: SinkOptions,
+    ) -> PolarsResult<Self> {
+        use polars_io::parquet::write::{KeyValue, KeyValueMetadata};
+
+        let bucket = bucket_id(by.iter().cloned().map(col).collect::<Vec<_>>(), num_buckets)?;
+        let scheme = KeyValue {
+            key: BUCKETING_METADATA_KEY.to_string(),
+            value: Some(bucketing_metadata(&by, num_buckets)),
+        };
+        options.key_value_metadata = match options.key_value_metadata {
+            None => Some(KeyValueMetadata::Static(vec![scheme])),
+            Some(KeyValueMetadata::Static(mut kv)) => {
+                kv.push(scheme);
+                Some(KeyValueMetadata::Static(kv))
+            },
+            Some(_) => polars_bail!(
+                InvalidOperation: "cannot combine bucketing with dynamic key value metadata"
+            ),
+        };
+
+        self.sink_parquet_partitioned(
+            base_path,
+            None,
+            PartitionVariant::ByKey {
+                key_exprs: vec![bucket],
+                include_key: false,
+            },
+            options,
+            cloud_options,
+            sink_options,
+        )
+    }
+
     /// Stream a query result into an ipc/arrow file in a partitioned manner. This is useful if the
     /// final result doesn't fit into memory. This methods will return an error if the query cannot
     /// be completely done in a streaming fashion.
@@ -1223,6 +1633,16 @@ impl LazyFrame {
             !matches!(self.logical_plan, DslPlan::Sink { .. }),
             InvalidOperation: "cannot create a sink on top of another sink"
         );
+        let batch_size = match &payload {
+            SinkType::Memory => None,
+            SinkType::File(f) => f.sink_options.batch_size,
+            SinkType::Partition(p) => p.sink_options.batch_size,
+            SinkType::Callback(c) => c.sink_options.batch_size,
+        };
+        polars_ensure!(
+            batch_size != Some(0),
+            InvalidOperation: "sink batch size must be positive"
+        );
         self.logical_plan = DslPlan::Sink {
             input: Arc::new(self.logical_plan),
             payload: payload.clone(),
@@ -1679,6 +2099,7 @@ impl LazyFrame {
             suffix,
             slice,
             nulls_equal,
+            nulls_equal_per_key,
             coalesce,
             maintain_order,
         } = args;
@@ -1695,6 +2116,7 @@ impl LazyFrame {
             .how(how)
             .validate(validation)
             .join_nulls(nulls_equal)
+            .join_nulls_per_key(nulls_equal_per_key)
             .coalesce(coalesce)
             .maintain_order(maintain_order);
 
@@ -1706,6 +2128,26 @@ impl LazyFrame {
         builder.finish()
     }
 
+    /// Use this [`LazyFrame`] of a single column as a subquery in an expression, e.g. in
+    /// `col("id").is_in(lookup.into_subquery()?, false)`.
+    ///
+    /// A filter on `is_in` against a subquery is executed as a semi-join with the subquery, or as
+    /// an anti-join if the `is_in` is negated, so the values don't have to be collected up front.
+    /// Subqueries are only allowed in the top-level conjunction of a filter.
+    #[cfg(all(feature = "is_in", feature = "semi_anti_join"))]
+    pub fn into_subquery(mut self) -> PolarsResult<Expr> {
+        let schema = self.collect_schema()?;
+        polars_ensure!(
+            schema.len() == 1,
+            InvalidOperation: "a subquery must have exactly one column, got {}", schema.len()
+        );
+        let name = schema.get_at_index(0).unwrap().0.to_string();
+        Ok(Expr::SubPlan(
+            SpecialEq::new(Arc::new(self.logical_plan)),
+            vec![name],
+        ))
+    }
+
     /// Consume `self` and return a [`JoinBuilder`] to customize a join on this LazyFrame.
     ///
     /// After the `JoinBuilder` has been created and set up, calling
@@ -1887,7 +2329,23 @@ impl LazyFrame {
 
     /// Apply explode operation. [See eager explode](polars_core::frame::DataFrame::explode).
     pub fn explode<E: AsRef<[IE]>, IE: Into<Selector> + Clone>(self, columns: E) -> LazyFrame {
-        self.explode_impl(columns, false)
+        self.explode_impl(columns, false, None)
+    }
+
+    /// Explode `columns` and add a column `position` with the position of every value in its
+    /// list, and optionally a column `row_index` with the index of the original row.
+    /// [See eager explode_with_position](polars_core::frame::DataFrame::explode_with_position).
+    pub fn explode_with_position<E: AsRef<[IE]>, IE: Into<Selector> + Clone>(
+        self,
+        columns: E,
+        position: PlSmallStr,
+        row_index: Option<PlSmallStr>,
+    ) -> LazyFrame {
+        let lf = match row_index {
+            Some(name) => self.with_row_index(name, None),
+            None => self,
+        };
+        lf.explode_impl(columns, false, Some(position))
     }
 
     /// Apply explode operation. [See eager explode](polars_core::frame::DataFrame::explode).
@@ -1895,6 +2353,7 @@ impl LazyFrame {
         self,
         columns: E,
         allow_empty: bool,
+        position: Option<PlSmallStr>,
     ) -> LazyFrame {
         let columns = columns
             .as_ref()
@@ -1904,7 +2363,7 @@ impl LazyFrame {
         let opt_state = self.get_opt_state();
         let lp = self
             .get_plan_builder()
-            .explode(columns, allow_empty)
+            .explode(columns, allow_empty, position)
             .build();
         Self::from_logical_plan(lp, opt_state)
     }
@@ -1914,6 +2373,39 @@ impl LazyFrame {
         self.select(vec![col(PlSmallStr::from_static("*")).null_count()])
     }
 
+    /// Count the occurrences of the unique combinations of the `subset` columns, or of all
+    /// columns if `None`.
+    ///
+    /// The counts are in a column `name`, which defaults to `"count"`, or to `"proportion"` if
+    /// `normalize` is set to divide them by the number of rows. With `sort` the result is sorted
+    /// by the counts in descending order, otherwise its order is random. This is a plain
+    /// group-by, so it runs in the streaming engine.
+    pub fn value_counts(
+        self,
+        subset: Option<Vec<Expr>>,
+        sort: bool,
+        normalize: bool,
+        name: Option<PlSmallStr>,
+    ) -> LazyFrame {
+        let name = name.unwrap_or_else(|| {
+            PlSmallStr::from_static(if normalize { "proportion" } else { "count" })
+        });
+        let keys = subset.unwrap_or_else(|| vec![all()]);
+        let mut lf = self.group_by(keys).agg([len().alias(name.clone())]);
+        if normalize {
+            lf = lf.with_column((col(name.clone()) / col(name.clone()).sum()).alias(name.clone()));
+        }
+        if sort {
+            lf = lf.sort(
+                [name],
+                SortMultipleOptions::default()
+                    .with_order_descending(true)
+                    .with_maintain_order(true),
+            );
+        }
+        lf
+    }
+
     /// Drop non-unique rows and maintain the order of kept rows.
     ///
     /// `subset` is an optional `Vec` of column names to consider for uniqueness; if
@@ -2008,6 +2500,26 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Keep only the rows of which the values occur more than once, with the number of
+    /// occurrences in a column named `name`.
+    ///
+    /// `subset` is an optional `Vec` of expressions to compare the rows by; if None, all columns
+    /// are compared. The query fails if a column named `name` already exists.
+    #[cfg(feature = "is_unique")]
+    pub fn duplicates(self, subset: Option<Vec<Expr>>, name: PlSmallStr) -> LazyFrame {
+        let keys = match subset {
+            Some(subset) if !subset.is_empty() => subset,
+            _ => vec![all()],
+        };
+        // The counts are computed in a single hashed pass over the keys, so this doesn't need
+        // to join the group sizes back onto the rows.
+        let count = Expr::n_ary(FunctionExpr::OccurrenceCountBy, keys).alias(name.clone());
+        // Unlike `with_column`, this raises a duplicate error instead of replacing a column
+        // named `name`.
+        self.select([all(), count])
+            .filter(col(name).gt(lit(1 as IdxSize)))
+    }
+
     /// Slice the DataFrame using an offset (starting row) and a length.
     ///
     /// If `offset` is negative, it is counted from the end of the DataFrame. For
@@ -2120,6 +2632,16 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Fix the seed of all random expressions (`shuffle`, `sample`, ...) in this query that
+    /// were not given an explicit seed.
+    ///
+    /// Each expression gets its own seed derived from `seed`, so the query produces the same
+    /// output on every run, independent of the number of threads or the morsel sizes used.
+    #[cfg(feature = "random")]
+    pub fn with_random_seed(self, seed: u64) -> LazyFrame {
+        self.map_private(DslFunction::RandomSeed(seed))
+    }
+
     /// Add a new column at index 0 that counts the rows.
     ///
     /// `name` is the name of the new column. `offset` is where to start counting from; if
@@ -2132,8 +2654,31 @@ impl LazyFrame {
     where
         S: Into<PlSmallStr>,
     {
-        let name = name.into();
+        self.with_row_index_impl(name.into(), offset, false)
+    }
 
+    /// Add a new column at index 0 that counts the rows of every file it is scanned from,
+    /// restarting at `offset` for every file.
+    ///
+    /// Unlike [`LazyFrame::with_row_index`], the index of a row doesn't depend on the number of
+    /// rows in the files before it, so the files can be read in parallel without any
+    /// synchronization between them. Together with a column of file paths (see
+    /// `include_file_paths` of the scans) this identifies every row.
+    ///
+    /// If this frame isn't a scan of files this is the same as [`LazyFrame::with_row_index`].
+    pub fn with_row_index_per_file<S>(self, name: S, offset: Option<IdxSize>) -> LazyFrame
+    where
+        S: Into<PlSmallStr>,
+    {
+        self.with_row_index_impl(name.into(), offset, true)
+    }
+
+    fn with_row_index_impl(
+        self,
+        name: PlSmallStr,
+        offset: Option<IdxSize>,
+        per_file: bool,
+    ) -> LazyFrame {
         match &self.logical_plan {
             v @ DslPlan::Scan { scan_type, .. }
                 if !matches!(&**scan_type, FileScan::Anonymous { .. }) =>
@@ -2153,6 +2698,7 @@ impl LazyFrame {
                     name,
                     offset: offset.unwrap_or(0),
                 });
+                unified_scan_args.row_index_per_file = per_file;
 
                 DslPlan::Scan {
                     sources,
@@ -2167,6 +2713,23 @@ impl LazyFrame {
         }
     }
 
+    /// Add a new column `name` with a 64-bit hash of the values of `by` in every row.
+    ///
+    /// Unlike a row index, the id of a row only depends on its values, so it can be computed
+    /// without processing the rows in order and is the same no matter in which file or partition
+    /// the row is read. Rows with equal values of `by` get equal ids. The hash depends on `seed`
+    /// and is not guaranteed to be stable across Polars versions.
+    #[cfg(all(feature = "row_hash", feature = "dtype-struct"))]
+    pub fn with_row_hash<S, E>(self, name: S, by: E, seed: u64) -> PolarsResult<LazyFrame>
+    where
+        S: Into<PlSmallStr>,
+        E: AsRef<[Expr]>,
+    {
+        let by = by.as_ref().to_vec();
+        polars_ensure!(!by.is_empty(), InvalidOperation: "cannot hash rows by zero columns");
+        Ok(self.with_column(as_struct(by).hash(seed, seed, seed, seed).alias(name)))
+    }
+
     /// Return the number of non-null elements for each column.
     pub fn count(self) -> LazyFrame {
         self.select(vec![col(PlSmallStr::from_static("*")).count()])
@@ -2283,6 +2846,7 @@ impl LazyGroupBy {
             .explode_impl(
                 [col(PlSmallStr::from_static("*")).exclude(keys.iter().cloned())],
                 true,
+                None,
             )
     }
 
@@ -2300,6 +2864,7 @@ impl LazyGroupBy {
             .explode_impl(
                 [col(PlSmallStr::from_static("*")).exclude(keys.iter().cloned())],
                 true,
+                None,
             )
     }
 
@@ -2316,10 +2881,14 @@ impl LazyGroupBy {
             dynamic: self.dynamic_options,
             rolling: self.rolling_options,
             slice: None,
+            keys_sorted: false,
         };
 
         #[cfg(not(feature = "dynamic_group_by"))]
-        let options = GroupbyOptions { slice: None };
+        let options = GroupbyOptions {
+            slice: None,
+            keys_sorted: false,
+        };
 
         let lp = DslPlan::GroupBy {
             input: Arc::new(self.logical_plan),
@@ -2345,6 +2914,7 @@ pub struct JoinBuilder {
     suffix: Option<PlSmallStr>,
     validation: JoinValidation,
     nulls_equal: bool,
+    nulls_equal_per_key: Option<Vec<bool>>,
     coalesce: JoinCoalesce,
     maintain_order: MaintainOrderJoin,
 }
@@ -2362,6 +2932,7 @@ impl JoinBuilder {
             suffix: None,
             validation: Default::default(),
             nulls_equal: false,
+            nulls_equal_per_key: None,
             coalesce: Default::default(),
             maintain_order: Default::default(),
         }
@@ -2428,6 +2999,13 @@ impl JoinBuilder {
         self
     }
 
+    /// Join on null values per key, overriding [`JoinBuilder::join_nulls`]. This takes one
+    /// value for every key, in the order of the keys.
+    pub fn join_nulls_per_key(mut self, nulls_equal: Option<Vec<bool>>) -> Self {
+        self.nulls_equal_per_key = nulls_equal;
+        self
+    }
+
     /// Suffix to add duplicate column names in join.
     /// Defaults to `"_right"` if this method is never called.
     pub fn suffix<S>(mut self, suffix: S) -> Self
@@ -2461,6 +3039,7 @@ impl JoinBuilder {
             suffix: self.suffix,
             slice: None,
             nulls_equal: self.nulls_equal,
+            nulls_equal_per_key: self.nulls_equal_per_key,
             coalesce: self.coalesce,
             maintain_order: self.maintain_order,
         };
@@ -2553,6 +3132,7 @@ impl JoinBuilder {
             suffix: self.suffix,
             slice: None,
             nulls_equal: self.nulls_equal,
+            nulls_equal_per_key: self.nulls_equal_per_key,
             coalesce: self.coalesce,
             maintain_order: self.maintain_order,
         };
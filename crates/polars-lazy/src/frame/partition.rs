@@ -0,0 +1,307 @@
+//! Partition a [`LazyFrame`] by key values or into ranges of keys.
+#[cfg(feature = "search_sorted")]
+use std::sync::Mutex;
+
+use polars_core::prelude::*;
+#[cfg(feature = "search_sorted")]
+use polars_core::utils::{
+    accumulate_dataframes_vertical_unchecked, accumulate_dataframes_vertical_unchecked_optional,
+};
+#[cfg(feature = "search_sorted")]
+use polars_ops::prelude::SearchSortedSide;
+
+use crate::prelude::*;
+
+/// Number of keys sampled per partition to find the boundaries of range partitions.
+#[cfg(feature = "search_sorted")]
+const SAMPLES_PER_PARTITION: usize = 128;
+
+/// Iterator over the partitions of a [`LazyFrame`], see [`LazyFrame::partition_by_iter`].
+pub struct PartitionIter {
+    partitions: std::vec::IntoIter<(Vec<AnyValue<'static>>, LazyFrame)>,
+}
+
+impl Iterator for PartitionIter {
+    type Item = PolarsResult<(Vec<AnyValue<'static>>, DataFrame)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, lf) = self.partitions.next()?;
+        Some(lf.collect().map(|df| (key, df)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.partitions.size_hint()
+    }
+}
+
+impl ExactSizeIterator for PartitionIter {}
+
+/// A systematic sample of a stream of rows: every `step`-th row, where the step doubles whenever
+/// the sample grows beyond twice its target size. Once enough rows are seen the sample holds
+/// between `target` and `2 * target` rows, no matter how many rows there are.
+#[cfg(feature = "search_sorted")]
+struct RowSampler {
+    target: usize,
+    step: usize,
+    /// The number of rows seen so far.
+    n_rows: usize,
+    sample: Vec<DataFrame>,
+    sample_len: usize,
+}
+
+#[cfg(feature = "search_sorted")]
+impl RowSampler {
+    fn new(target: usize) -> Self {
+        Self {
+            target,
+            step: 1,
+            n_rows: 0,
+            sample: vec![],
+            sample_len: 0,
+        }
+    }
+
+    /// Sample the next rows, which must be pushed in order.
+    fn push(&mut self, df: DataFrame) -> PolarsResult<()> {
+        // The sample holds the rows whose index is a multiple of the step.
+        let first = (self.step - self.n_rows % self.step) % self.step;
+        let idx = (first..df.height())
+            .step_by(self.step)
+            .map(|i| i as IdxSize)
+            .collect::<Vec<_>>();
+        self.n_rows += df.height();
+        if !idx.is_empty() {
+            self.sample_len += idx.len();
+            self.sample
+                .push(df.take(&IdxCa::from_vec(PlSmallStr::EMPTY, idx))?);
+        }
+
+        while self.sample_len > 2 * self.target {
+            // Every other sampled row is a multiple of the doubled step.
+            let sample = accumulate_dataframes_vertical_unchecked(self.sample.drain(..));
+            let idx = (0..sample.height() as IdxSize).step_by(2).collect();
+            let sample = sample.take(&IdxCa::from_vec(PlSmallStr::EMPTY, idx))?;
+            self.sample_len = sample.height();
+            self.sample = vec![sample];
+            self.step *= 2;
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Option<DataFrame> {
+        accumulate_dataframes_vertical_unchecked_optional(self.sample.drain(..))
+    }
+}
+
+/// Range partitioning of a [`LazyFrame`], see [`LazyFrame::partition_ranges`].
+#[cfg(feature = "search_sorted")]
+pub struct RangePartitions {
+    /// The smallest keys of every partition but the first, in ascending order.
+    pub boundaries: DataFrame,
+    /// Evaluates to the partition of every row, in `0..=boundaries.height()`.
+    pub partition_id: Expr,
+}
+
+impl LazyFrame {
+    /// Split into a [`LazyFrame`] per unique combination of the values of `keys`.
+    ///
+    /// This is eager: it collects the whole query into memory and groups the result by `keys`,
+    /// in the order in which the keys first occur. The returned frames are gathers of that
+    /// collected result, so they don't run the query again, but the result stays in memory for
+    /// as long as any of them is alive.
+    ///
+    /// The keys are returned in the order of `keys`. Nulls are a key of their own.
+    pub fn partition_by_lazy<I, S>(
+        self,
+        keys: I,
+        include_key: bool,
+    ) -> PolarsResult<Vec<(Vec<AnyValue<'static>>, LazyFrame)>>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        let keys = keys.into_iter().map(Into::into).collect::<Vec<_>>();
+        polars_ensure!(!keys.is_empty(), InvalidOperation: "cannot partition by zero keys");
+
+        let mut df = self.collect()?;
+        let (unique, groups) = {
+            let groups = df.group_by_stable(keys.iter().cloned())?;
+            (groups.keys(), (**groups.get_groups()).clone().into_idx())
+        };
+        if !include_key {
+            df = df.drop_many(keys.iter().cloned());
+        }
+
+        groups
+            .iter()
+            .enumerate()
+            .map(|(i, (_, idx))| {
+                let key = unique
+                    .iter()
+                    .map(|c| Ok(c.get(i)?.into_static()))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let idx = IdxCa::from_vec(PlSmallStr::EMPTY, idx.to_vec());
+                let lf = df
+                    .clone()
+                    .lazy()
+                    .select([all().gather(lit(idx.into_series()))]);
+                Ok((key, lf))
+            })
+            .collect()
+    }
+
+    /// Split into a [`DataFrame`] per unique combination of the values of `keys`, that are
+    /// gathered one at a time while iterating.
+    ///
+    /// Like [`LazyFrame::partition_by_lazy`] this collects the whole query up front, so the
+    /// input must fit in memory. Unlike [`DataFrame::partition_by`] the partitions aren't all
+    /// materialized at once: only the collected input and the current partition are in memory.
+    pub fn partition_by_iter<I, S>(self, keys: I, include_key: bool) -> PolarsResult<PartitionIter>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        let partitions = self.partition_by_lazy(keys, include_key)?;
+        Ok(PartitionIter {
+            partitions: partitions.into_iter(),
+        })
+    }
+
+    /// Split the rows into `num_partitions` ranges of the keys `by` of about the same size.
+    ///
+    /// The boundaries are the quantiles of a systematic sample of the keys that is taken in a
+    /// single pass over the query, so this doesn't collect the whole key column. Rows with equal
+    /// keys are always in the same partition, which means that there can be fewer partitions if
+    /// the keys have many duplicates.
+    ///
+    /// The keys are ordered by their row encoding, see [`encode_rows`], with nulls first.
+    #[cfg(feature = "search_sorted")]
+    pub fn partition_ranges<E: AsRef<[Expr]>>(
+        self,
+        by: E,
+        num_partitions: usize,
+    ) -> PolarsResult<RangePartitions> {
+        const KEY: &str = "__POLARS_RANGE_KEY";
+
+        let by = by.as_ref().to_vec();
+        polars_ensure!(!by.is_empty(), InvalidOperation: "cannot partition by zero keys");
+        polars_ensure!(num_partitions > 0, InvalidOperation: "cannot partition into zero partitions");
+
+        let key = encode_rows(&by, vec![false], vec![false])?;
+        let sampler = Arc::new(Mutex::new(RowSampler::new(
+            num_partitions * SAMPLES_PER_PARTITION,
+        )));
+        let sink_sampler = sampler.clone();
+        let mut keys = self.select(
+            by.into_iter()
+                .chain([key.clone().alias(KEY)])
+                .collect::<Vec<_>>(),
+        );
+        let schema = keys.collect_schema()?;
+        keys.sink_batches(
+            move |df| {
+                sink_sampler.lock().unwrap().push(df)?;
+                Ok(false)
+            },
+            true,
+            None,
+        )?
+        .collect()?;
+        let sample = sampler
+            .lock()
+            .unwrap()
+            .finish()
+            .unwrap_or_else(|| DataFrame::empty_with_schema(&schema))
+            .sort([KEY], Default::default())?;
+
+        let sample_keys = sample.column(KEY)?.binary()?;
+        let mut positions: Vec<IdxSize> = Vec::with_capacity(num_partitions - 1);
+        if sample.height() > 0 {
+            for i in 1..num_partitions {
+                let pos = i * sample.height() / num_partitions;
+                // The boundaries must be strictly increasing.
+                if positions
+                    .last()
+                    .is_none_or(|&last| sample_keys.get(last as usize) != sample_keys.get(pos))
+                {
+                    positions.push(pos as IdxSize);
+                }
+            }
+        }
+
+        let boundaries = sample.take(&IdxCa::from_vec(PlSmallStr::EMPTY, positions))?;
+        let boundary_keys = boundaries.column(KEY)?.as_materialized_series().clone();
+        let partition_id = lit(boundary_keys)
+            .search_sorted(key, SearchSortedSide::Right)
+            .alias("partition_id");
+        Ok(RangePartitions {
+            boundaries: boundaries.drop(KEY)?,
+            partition_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_partition_by_iter() -> PolarsResult<()> {
+        let df = df![
+            "k" => [Some("b"), Some("a"), None, Some("b")],
+            "v" => [1, 2, 3, 4],
+        ]?;
+
+        let partitions = df
+            .lazy()
+            .partition_by_iter(["k"], false)?
+            .collect::<PolarsResult<Vec<_>>>()?;
+        assert_eq!(partitions.len(), 3);
+        let (key, part) = &partitions[0];
+        assert_eq!(key, &[AnyValue::String("b")]);
+        assert!(part.equals(&df!["v" => [1, 4]]?));
+        assert_eq!(partitions[2].0, [AnyValue::Null]);
+        assert!(partitions[2].1.equals(&df!["v" => [3]]?));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "search_sorted")]
+    fn test_partition_ranges() -> PolarsResult<()> {
+        let df = df![
+            "k" => (0..1000).rev().collect::<Vec<i32>>(),
+        ]?;
+
+        let partitions = df.clone().lazy().partition_ranges([col("k")], 4)?;
+        assert!(partitions.boundaries.equals(&df!["k" => [250, 500, 750]]?));
+
+        let sizes = df
+            .lazy()
+            .group_by([partitions.partition_id])
+            .agg([len()])
+            .sort(["partition_id"], Default::default())
+            .collect()?;
+        let expected = df![
+            "partition_id" => [0 as IdxSize, 1, 2, 3],
+            "len" => [250 as IdxSize; 4],
+        ]?;
+        assert!(sizes.equals(&expected));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "search_sorted")]
+    fn test_row_sampler() -> PolarsResult<()> {
+        let mut sampler = RowSampler::new(10);
+        let mut offset = 0;
+        for len in [7, 0, 13, 50, 1, 29] {
+            sampler.push(df!["i" => (offset..offset + len).collect::<Vec<i32>>()]?)?;
+            offset += len;
+        }
+        // 100 rows with a target of 10 rows are sampled with a step of 8.
+        let sample = sampler.finish().unwrap();
+        let expected = (0..100).step_by(8).collect::<Vec<i32>>();
+        assert!(sample.equals(&df!["i" => expected]?));
+        Ok(())
+    }
+}
@@ -1,8 +1,9 @@
 //! Module containing implementation of the pivot operation.
 //!
-//! Polars lazy does not implement a pivot because it is impossible to know the schema without
-//! materializing the whole dataset. This makes a pivot quite a terrible operation for performant
-//! workflows. An optimization can never be pushed down passed a pivot.
+//! Polars lazy does not implement a general pivot because it is impossible to know the schema
+//! without materializing the whole dataset. This makes a pivot quite a terrible operation for
+//! performant workflows. An optimization can never be pushed down passed a pivot. If the output
+//! columns are known up front, [`LazyFrame::pivot`] can be used instead.
 //!
 //! We can do a pivot on an eager `DataFrame` as that is already materialized. The code for the
 //! pivot is here, because we want to be able to pass expressions to the pivot operation.
@@ -10,7 +11,9 @@
 
 use polars_core::frame::group_by::expr::PhysicalAggExpr;
 use polars_core::prelude::*;
+use polars_ops::frame::MaintainOrderJoin;
 use polars_ops::pivot::PivotAgg;
+use polars_utils::format_pl_smallstr;
 
 use crate::physical_plan::exotic::{prepare_eval_expr, prepare_expression_for_context};
 use crate::prelude::*;
@@ -88,3 +91,340 @@ where
     });
     polars_ops::pivot::pivot_stable(df, on, index, values, sort_columns, agg_expr, separator)
 }
+
+/// Pivot several `values` columns with several aggregation expressions in a single pass, see
+/// [`polars_ops::pivot::pivot_multiple`].
+pub fn pivot_multiple<I0, I1, I2, S0, S1, S2>(
+    df: &DataFrame,
+    on: I0,
+    index: Option<I1>,
+    values: Option<I2>,
+    aggs: &[(PlSmallStr, Expr)],
+    sort_columns: bool,
+    template: Option<&str>,
+) -> PolarsResult<DataFrame>
+where
+    I0: IntoIterator<Item = S0>,
+    I1: IntoIterator<Item = S1>,
+    I2: IntoIterator<Item = S2>,
+    S0: Into<PlSmallStr>,
+    S1: Into<PlSmallStr>,
+    S2: Into<PlSmallStr>,
+{
+    let aggs = aggs
+        .iter()
+        .map(|(name, expr)| {
+            let expr = prepare_eval_expr(expr.clone());
+            (name.clone(), PivotAgg::Expr(Arc::new(PivotExpr(expr))))
+        })
+        .collect::<Vec<_>>();
+    polars_ops::pivot::pivot_multiple(df, on, index, values, &aggs, sort_columns, template)
+}
+
+impl LazyFrame {
+    /// Pivot the unique values of the `on` column that are given in `on_columns` to columns.
+    ///
+    /// Unlike [`pivot`], the output columns are declared up front, so the schema is known
+    /// without running the query. This makes the pivot an ordinary group-by on `index` with an
+    /// aggregation per output column, that can be optimized and run on the streaming engine.
+    /// Values of `on` that are not in `on_columns` are ignored.
+    ///
+    /// Like [`pivot`], every column in `agg` refers to the column that is aggregated, e.g.
+    /// `col("").sum()`, and `len()` counts the rows of an output column. The column is null in
+    /// the rows of the other values of `on`, so the aggregations stay elementwise over the input
+    /// and can be streamed. If no aggregation is given, the first non-null value is taken. If
+    /// `values` is `None` all columns that are not in `index` or `on` are aggregated. With more
+    /// than one values column the output columns are named `{value}{separator}{on_value}`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn pivot<I1, I2, S1, S2>(
+        mut self,
+        on: impl Into<PlSmallStr>,
+        on_columns: &Series,
+        index: I1,
+        values: Option<I2>,
+        agg: Option<Expr>,
+        maintain_order: bool,
+        separator: Option<&str>,
+    ) -> PolarsResult<LazyFrame>
+    where
+        I1: IntoIterator<Item = S1>,
+        I2: IntoIterator<Item = S2>,
+        S1: Into<PlSmallStr>,
+        S2: Into<PlSmallStr>,
+    {
+        let on = on.into();
+        let index = index
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<PlSmallStr>>();
+        let values = match values {
+            Some(values) => values
+                .into_iter()
+                .map(Into::into)
+                .collect::<Vec<PlSmallStr>>(),
+            None => self
+                .collect_schema()?
+                .iter_names()
+                .filter(|c| **c != on && !index.contains(c))
+                .cloned()
+                .collect(),
+        };
+        let agg = agg.unwrap_or_else(|| col(PlSmallStr::EMPTY).drop_nulls().first());
+        let separator = separator.unwrap_or("_");
+
+        let on_columns = on_columns.unique_stable()?;
+        let on_dtype = on_columns.dtype().clone();
+        let headers = on_columns.cast(&DataType::String)?;
+        let headers = headers.str()?;
+
+        let mut aggs = Vec::with_capacity(values.len() * on_columns.len());
+        for value in &values {
+            for (i, header) in headers.iter().enumerate() {
+                let on_value = Scalar::new(on_dtype.clone(), on_columns.get(i)?.into_static());
+                let predicate = col(on.clone()).eq_missing(lit(on_value));
+                let agg = agg.clone().map_expr(|e| match e {
+                    Expr::Column(_) | Expr::Nth(_) => when(predicate.clone())
+                        .then(col(value.clone()))
+                        .otherwise(lit(NULL)),
+                    Expr::Len => predicate.clone().cast(IDX_DTYPE).sum(),
+                    e => e,
+                });
+                // Like in the eager pivot, combinations that don't occur are null.
+                let agg = when(predicate.clone().max()).then(agg).otherwise(lit(NULL));
+                let header = header.unwrap_or("null");
+                let name = if values.len() > 1 {
+                    format_pl_smallstr!("{value}{separator}{header}")
+                } else {
+                    PlSmallStr::from_str(header)
+                };
+                aggs.push(agg.alias(name));
+            }
+        }
+
+        let keys = index.into_iter().map(col).collect::<Vec<_>>();
+        Ok(if keys.is_empty() {
+            self.select(aggs)
+        } else if maintain_order {
+            self.group_by_stable(keys).agg(aggs)
+        } else {
+            self.group_by(keys).agg(aggs)
+        })
+    }
+}
+
+/// How the cells of a [`crosstab`] are normalized.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CrosstabNormalize {
+    #[default]
+    None,
+    /// Divide by the sum of all cells.
+    All,
+    /// Divide by the sum of the cells in the same row.
+    Rows,
+    /// Divide by the sum of the cells in the same column.
+    Columns,
+}
+
+#[derive(Clone, Debug)]
+pub struct CrosstabOptions {
+    /// The aggregation that computes a cell from the rows of the group, e.g.
+    /// `col("price").mean()`. Defaults to counting the rows.
+    pub agg: Option<Expr>,
+    pub normalize: CrosstabNormalize,
+    /// Add a column with the aggregation per row and a row with the aggregation per column.
+    pub margins: bool,
+    /// The name of the margin row and column.
+    pub margins_name: PlSmallStr,
+}
+
+impl Default for CrosstabOptions {
+    fn default() -> Self {
+        Self {
+            agg: None,
+            normalize: CrosstabNormalize::None,
+            margins: false,
+            margins_name: PlSmallStr::from_static("All"),
+        }
+    }
+}
+
+const CROSSTAB_VALUE: PlSmallStr = PlSmallStr::from_static("__POLARS_CROSSTAB_VALUE");
+
+fn cols(names: &[PlSmallStr]) -> Vec<Expr> {
+    names.iter().cloned().map(col).collect()
+}
+
+/// Compute a contingency table with a row for every unique value of `index` and a column for
+/// every unique value of `on`.
+///
+/// By default the cells count the rows with these values, `options.agg` can compute any other
+/// aggregation. Combinations that don't occur are 0 when counting and null otherwise.
+///
+/// If the cells are normalized, the margins are the sums of the normalized cells. Otherwise
+/// the margins are computed with the aggregation over the rows of the whole row or column, so
+/// that e.g. a mean is the mean of all values rather than the sum of the means. Adding margins
+/// casts the `index` columns to strings, the margin row has `options.margins_name` in the
+/// first `index` column.
+pub fn crosstab<I0, I1, S0, S1>(
+    df: &DataFrame,
+    index: I0,
+    on: I1,
+    options: CrosstabOptions,
+) -> PolarsResult<DataFrame>
+where
+    I0: IntoIterator<Item = S0>,
+    I1: IntoIterator<Item = S1>,
+    S0: Into<PlSmallStr>,
+    S1: Into<PlSmallStr>,
+{
+    let index = index.into_iter().map(Into::into).collect::<Vec<_>>();
+    let on = on.into_iter().map(Into::into).collect::<Vec<_>>();
+    polars_ensure!(
+        !index.is_empty() && !on.is_empty(),
+        InvalidOperation: "crosstab needs at least one `index` and one `on` column"
+    );
+
+    let is_count = options.agg.is_none();
+    let agg = options.agg.clone().unwrap_or_else(len);
+    let fill = |e: Expr| {
+        if is_count {
+            e.fill_null(lit(0).cast(IDX_DTYPE))
+        } else {
+            e
+        }
+    };
+    let pivot_cells = |df: &DataFrame, index: &[PlSmallStr]| {
+        polars_ops::pivot::pivot_stable(
+            df,
+            on.clone(),
+            Some(index.to_vec()),
+            Some([CROSSTAB_VALUE]),
+            true,
+            Some(PivotAgg::First),
+            None,
+        )
+    };
+
+    let keys = index
+        .iter()
+        .chain(&on)
+        .cloned()
+        .map(col)
+        .collect::<Vec<_>>();
+    let cells = df
+        .clone()
+        .lazy()
+        .group_by_stable(keys)
+        .agg([agg.clone().alias(CROSSTAB_VALUE)])
+        .collect()?;
+    let table = pivot_cells(&cells, &index)?;
+    let value_columns = table
+        .get_column_names()
+        .into_iter()
+        .filter(|name| !index.contains(name))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let cell = |name: &PlSmallStr| fill(col(name.clone()));
+    let normalized = |name: &PlSmallStr| cell(name).cast(DataType::Float64);
+    let values = match options.normalize {
+        CrosstabNormalize::None => value_columns.iter().map(cell).collect::<Vec<_>>(),
+        CrosstabNormalize::All => {
+            let total = sum_horizontal(
+                value_columns
+                    .iter()
+                    .map(|c| cell(c).sum())
+                    .collect::<Vec<_>>(),
+                true,
+            )?;
+            value_columns
+                .iter()
+                .map(|c| normalized(c) / total.clone())
+                .collect()
+        },
+        CrosstabNormalize::Rows => {
+            let total = sum_horizontal(value_columns.iter().map(cell).collect::<Vec<_>>(), true)?;
+            value_columns
+                .iter()
+                .map(|c| normalized(c) / total.clone())
+                .collect()
+        },
+        CrosstabNormalize::Columns => value_columns
+            .iter()
+            .map(|c| normalized(c) / cell(c).sum())
+            .collect(),
+    };
+    let table = table.lazy().with_columns(values);
+    if !options.margins {
+        return table.collect();
+    }
+
+    let name = options.margins_name;
+    let (table, margin_row) = if options.normalize == CrosstabNormalize::None {
+        let row_margin = df
+            .clone()
+            .lazy()
+            .group_by_stable(cols(&index))
+            .agg([agg.clone().alias(name.clone())]);
+        let table = table.join(
+            row_margin,
+            cols(&index),
+            cols(&index),
+            JoinArgs {
+                nulls_equal: true,
+                maintain_order: MaintainOrderJoin::Left,
+                ..JoinArgs::new(JoinType::Left)
+            },
+        );
+
+        let column_margin = df
+            .clone()
+            .lazy()
+            .group_by_stable(cols(&on))
+            .agg([agg.clone().alias(CROSSTAB_VALUE)])
+            .with_column(lit(name.as_str()).alias(index[0].clone()))
+            .collect()?;
+        let total = df.clone().lazy().select([agg.alias(name.clone())]);
+        let margin_row = concat_lf_horizontal(
+            [pivot_cells(&column_margin, &index[..1])?.lazy(), total],
+            Default::default(),
+        )?;
+        (table, margin_row)
+    } else {
+        let table =
+            table.with_column(sum_horizontal(cols(&value_columns), true)?.alias(name.clone()));
+        let margin_row = table.clone().select(
+            value_columns
+                .iter()
+                .chain([&name])
+                .map(|c| col(c.clone()).sum())
+                .chain([lit(name.as_str()).alias(index[0].clone())])
+                .collect::<Vec<_>>(),
+        );
+        (table, margin_row)
+    };
+
+    let table = table.with_columns(
+        index
+            .iter()
+            .map(|c| col(c.clone()).cast(DataType::String))
+            .collect::<Vec<_>>(),
+    );
+    let out = concat(
+        [table, margin_row],
+        UnionArgs {
+            rechunk: true,
+            to_supertypes: true,
+            diagonal: true,
+            ..Default::default()
+        },
+    )?;
+    out.with_columns(
+        value_columns
+            .iter()
+            .chain([&name])
+            .map(cell)
+            .collect::<Vec<_>>(),
+    )
+    .collect()
+}
@@ -0,0 +1,122 @@
+//! Stateful computations over the rows of a [`LazyFrame`], see [`LazyFrame::scan_rows`].
+use polars_core::frame::group_by::GroupsIndicator;
+use polars_core::frame::row::FromRow;
+use polars_core::prelude::*;
+use polars_core::series::builder::{PushValue, SeriesBuilder};
+
+use crate::prelude::*;
+
+/// The group of every row and the number of groups.
+fn group_ids(df: &DataFrame, by: &[PlSmallStr]) -> PolarsResult<(Vec<IdxSize>, usize)> {
+    let mut ids = vec![0; df.height()];
+    if by.is_empty() {
+        return Ok((ids, 1));
+    }
+
+    let gb = df.group_by(by.iter().cloned())?;
+    let groups = gb.get_groups();
+    for (g, group) in groups.iter().enumerate() {
+        let g = g as IdxSize;
+        match group {
+            GroupsIndicator::Idx((_, idx)) => idx.iter().for_each(|&i| ids[i as usize] = g),
+            GroupsIndicator::Slice([first, len]) => {
+                ids[first as usize..(first + len) as usize].fill(g)
+            },
+        }
+    }
+    Ok((ids, groups.len()))
+}
+
+fn scan_rows<R, S, V, F>(
+    mut df: DataFrame,
+    by: &[PlSmallStr],
+    columns: &[PlSmallStr],
+    init: &S,
+    function: &F,
+    output: &Field,
+) -> PolarsResult<DataFrame>
+where
+    R: for<'a> FromRow<'a>,
+    S: Clone,
+    V: PushValue,
+    F: Fn(&mut S, R) -> V,
+{
+    let (ids, n_groups) = group_ids(&df, by)?;
+    let mut states = vec![init.clone(); n_groups];
+    let inputs = df.select(columns.iter().cloned())?;
+
+    let mut builder = SeriesBuilder::new(output.dtype.clone());
+    builder.reserve(df.height());
+    for (row, g) in inputs.rows_typed::<R>()?.zip(ids) {
+        builder.push(function(&mut states[g as usize], row))?;
+    }
+    df.with_column(builder.freeze(output.name.clone()))?;
+    Ok(df)
+}
+
+impl LazyFrame {
+    /// Run a stateful function over the rows of `columns`, in order, and add its outputs as
+    /// the column `output`.
+    ///
+    /// Every group of rows with equal values in `by` starts from its own copy of `init`, and
+    /// without `by` all rows share a single state. The rows are passed as tuples of native
+    /// values in a single pass (see [`DataFrame::rows_typed`]), which makes this much faster
+    /// than `map_groups` for order-dependent logic such as running balances or sessions.
+    /// Read string columns as `String`, as the rows can't borrow from the frame.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// # fn example(lf: LazyFrame) -> LazyFrame {
+    /// // Running balance per account that never goes negative.
+    /// lf.scan_rows(
+    ///     ["account"],
+    ///     ["amount"],
+    ///     0i64,
+    ///     |balance: &mut i64, (amount,): (i64,)| {
+    ///         *balance = (*balance + amount).max(0);
+    ///         *balance
+    ///     },
+    ///     Field::new("balance".into(), DataType::Int64),
+    /// )
+    /// # }
+    /// ```
+    pub fn scan_rows<R, S, V, F>(
+        self,
+        by: impl IntoVec<PlSmallStr>,
+        columns: impl IntoVec<PlSmallStr>,
+        init: S,
+        function: F,
+        output: Field,
+    ) -> LazyFrame
+    where
+        R: for<'a> FromRow<'a> + 'static,
+        S: Clone + Send + Sync + 'static,
+        V: PushValue + 'static,
+        F: Fn(&mut S, R) -> V + Send + Sync + 'static,
+    {
+        let by = by.into_vec();
+        let columns = columns.into_vec();
+        let output_field = output.clone();
+        let schema = move |input_schema: &Schema| {
+            let mut schema = input_schema.clone();
+            schema.with_column(output_field.name.clone(), output_field.dtype.clone());
+            Ok(Arc::new(schema))
+        };
+
+        // The function depends on all rows and their order, so nothing may be pushed past it.
+        let mut optimizations = AllowedOptimizations::default();
+        optimizations.set(OptFlags::PREDICATE_PUSHDOWN, false);
+        optimizations.set(OptFlags::PROJECTION_PUSHDOWN, false);
+        optimizations.set(OptFlags::SLICE_PUSHDOWN, false);
+        optimizations.set(OptFlags::STREAMING, false);
+
+        self.map(
+            move |df| scan_rows(df, &by, &columns, &init, &function, &output),
+            optimizations,
+            Some(Arc::new(schema)),
+            Some("SCAN ROWS"),
+        )
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::sync::mpsc::channel;
+
+use polars_core::POOL;
+use polars_core::config::with_query_memory_budget;
+use polars_plan::plans::{ArenaLpIter, IR};
+
+use super::*;
+
+/// Limits on the resources that the queries of a [`QueryScheduler`] share.
+#[derive(Clone, Debug)]
+pub struct SchedulerOptions {
+    /// Maximum number of queries that execute at the same time. Every query fans out over the
+    /// global thread pool, so this bounds how many queries compete for its threads.
+    pub max_concurrent_queries: usize,
+    /// Total number of threads that execute queries. The queries run on the global thread
+    /// pool, which is created with this many threads, so this must be set before the pool is
+    /// first used.
+    pub max_threads: Option<usize>,
+    /// Total memory in bytes that the executing queries may reserve. A query that doesn't fit
+    /// waits until others finish, unless it is the only one left. The memory that is left when
+    /// a query starts is its budget, which the nodes that can spill to disk stay within.
+    pub memory_budget: Option<usize>,
+    /// Maximum number of simultaneous IO requests of the whole process.
+    pub max_io_requests: Option<u32>,
+}
+
+impl Default for SchedulerOptions {
+    fn default() -> Self {
+        Self {
+            max_concurrent_queries: 1,
+            max_threads: None,
+            memory_budget: None,
+            max_io_requests: None,
+        }
+    }
+}
+
+type Job = Box<dyn FnOnce(Permit) + Send>;
+
+struct Pending {
+    memory: usize,
+    job: Job,
+}
+
+#[derive(Default)]
+struct SchedulerState {
+    running: usize,
+    reserved_memory: usize,
+    queue: VecDeque<Pending>,
+}
+
+struct SchedulerShared {
+    options: SchedulerOptions,
+    state: Mutex<SchedulerState>,
+}
+
+impl SchedulerShared {
+    /// Starts the queued queries that fit, in submission order.
+    fn dispatch(self: &Arc<Self>) {
+        let mut admitted = Vec::new();
+        {
+            let mut state = self.state.lock().unwrap();
+            while let Some(pending) = state.queue.front() {
+                let fits_memory = self.options.memory_budget.is_none_or(|budget| {
+                    state.running == 0 || state.reserved_memory + pending.memory <= budget
+                });
+                if state.running >= self.options.max_concurrent_queries || !fits_memory {
+                    break;
+                }
+                let pending = state.queue.pop_front().unwrap();
+                let budget = self
+                    .options
+                    .memory_budget
+                    .map(|budget| budget.saturating_sub(state.reserved_memory));
+                state.running += 1;
+                state.reserved_memory += pending.memory;
+                admitted.push((pending, budget));
+            }
+        }
+
+        for (pending, budget) in admitted {
+            let permit = Permit {
+                shared: self.clone(),
+                memory: pending.memory,
+                budget,
+            };
+            POOL.spawn_fifo(move || (pending.job)(permit));
+        }
+    }
+}
+
+/// Held by an executing query, releases its resources on drop.
+struct Permit {
+    shared: Arc<SchedulerShared>,
+    memory: usize,
+    /// The memory budget of the query.
+    budget: Option<usize>,
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        {
+            let mut state = self.shared.state.lock().unwrap();
+            state.running -= 1;
+            state.reserved_memory -= self.memory;
+        }
+        self.shared.dispatch();
+    }
+}
+
+/// Runs queries concurrently within shared resource limits, instead of every query greedily
+/// using the global thread pool. Queries that don't fit wait in submission order.
+#[derive(Clone)]
+pub struct QueryScheduler {
+    shared: Arc<SchedulerShared>,
+}
+
+impl QueryScheduler {
+    pub fn new(options: SchedulerOptions) -> PolarsResult<Self> {
+        polars_ensure!(
+            options.max_concurrent_queries > 0,
+            InvalidOperation: "'max_concurrent_queries' must be positive"
+        );
+        if let Some(max_threads) = options.max_threads {
+            #[cfg(not(target_family = "wasm"))]
+            polars_core::set_pool_size(max_threads)?;
+            #[cfg(target_family = "wasm")]
+            polars_ensure!(max_threads == 1, InvalidOperation: "wasm runs on a single thread");
+        }
+        if let Some(max_io_requests) = options.max_io_requests {
+            #[cfg(feature = "async")]
+            polars_io::pl_async::set_concurrency_budget(max_io_requests)?;
+            #[cfg(not(feature = "async"))]
+            {
+                _ = max_io_requests;
+                polars_bail!(InvalidOperation: "'max_io_requests' requires the 'async' feature");
+            }
+        }
+        Ok(Self {
+            shared: Arc::new(SchedulerShared {
+                options,
+                state: Default::default(),
+            }),
+        })
+    }
+
+    /// Submit a query that reserves `memory` bytes of the memory budget while it executes.
+    pub fn submit(&self, lf: LazyFrame, memory: usize) -> PolarsResult<InProcessQuery> {
+        let (mut state, mut physical_plan, _) = lf.prepare_collect(false, None)?;
+
+        let (tx, rx) = channel();
+        let token = state.cancel_token();
+        let job = Box::new(move |permit: Permit| {
+            let result =
+                with_query_memory_budget(permit.budget, || physical_plan.execute(&mut state));
+            drop(permit);
+            _ = tx.send(result);
+        });
+        self.shared
+            .state
+            .lock()
+            .unwrap()
+            .queue
+            .push_back(Pending { memory, job });
+        self.shared.dispatch();
+
+        Ok(InProcessQuery::new(rx, token))
+    }
+
+    /// Collect all queries within the limits of the scheduler. The memory of every query is
+    /// estimated with [`QueryScheduler::estimate_memory`].
+    pub fn collect_all(&self, lfs: Vec<LazyFrame>) -> PolarsResult<Vec<DataFrame>> {
+        let queries = lfs
+            .into_iter()
+            .map(|lf| {
+                let memory = Self::estimate_memory(&lf)?;
+                self.submit(lf, memory)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        queries.iter().map(|q| q.fetch_blocking()).collect()
+    }
+
+    /// Estimate the memory that a query reserves by the size of its in-memory inputs and of
+    /// the local files it scans. Intermediate results aren't accounted for, so this is a lower
+    /// bound for most queries.
+    pub fn estimate_memory(lf: &LazyFrame) -> PolarsResult<usize> {
+        let plan = lf.clone().to_alp()?;
+        let mut size = 0;
+        for (_, ir) in (&plan.lp_arena).iter(plan.lp_top) {
+            match ir {
+                IR::DataFrameScan { df, .. } => size += df.estimated_size(),
+                IR::Scan { sources, .. } => {
+                    if let Some(paths) = sources.as_paths() {
+                        size += paths
+                            .iter()
+                            .filter_map(|p| std::fs::metadata(p).ok())
+                            .map(|m| m.len() as usize)
+                            .sum::<usize>();
+                    }
+                },
+                _ => {},
+            }
+        }
+        Ok(size)
+    }
+
+    /// Number of queries that are executing.
+    pub fn num_running(&self) -> usize {
+        self.shared.state.lock().unwrap().running
+    }
+
+    /// Number of queries that wait for resources.
+    pub fn num_queued(&self) -> usize {
+        self.shared.state.lock().unwrap().queue.len()
+    }
+}
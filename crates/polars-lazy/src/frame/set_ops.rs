@@ -0,0 +1,182 @@
+//! Set operations on the rows of two [`LazyFrame`]s, see [`LazyFrame::except`] and
+//! [`LazyFrame::intersect`].
+use polars_core::prelude::*;
+use polars_ops::frame::MaintainOrderJoin;
+
+use crate::prelude::*;
+
+/// Numbers the occurrences of every distinct row, so that the `ALL` variants can match the n-th
+/// occurrence of a row in one frame with the n-th occurrence in the other.
+#[cfg(feature = "range")]
+const OCCURRENCE: &str = "__POLARS_SET_OP_OCCURRENCE";
+
+impl LazyFrame {
+    /// The distinct rows of this frame that are not in `other`.
+    ///
+    /// Rows are compared by the columns in `subset`, or by all columns of this frame if `None`;
+    /// `other` must have these columns. Nulls compare equal. Of every distinct row the first one
+    /// is kept, and the kept rows are in the order of this frame.
+    pub fn except(
+        self,
+        other: LazyFrame,
+        subset: Option<Vec<PlSmallStr>>,
+    ) -> PolarsResult<LazyFrame> {
+        self.set_operation(other, subset, JoinType::Anti, false)
+    }
+
+    /// The rows of this frame that are not in `other`, keeping duplicates: a row that occurs `m`
+    /// times in this frame and `n` times in `other` is kept `m - n` times.
+    ///
+    /// Rows are compared as in [`LazyFrame::except`]. To match duplicates, the occurrences of
+    /// every row are numbered in a window over the compared columns, which groups both frames by
+    /// them in memory. That is more expensive than [`LazyFrame::except`], in particular for wide
+    /// frames; pass a `subset` if fewer columns identify a row.
+    #[cfg(feature = "range")]
+    pub fn except_all(
+        self,
+        other: LazyFrame,
+        subset: Option<Vec<PlSmallStr>>,
+    ) -> PolarsResult<LazyFrame> {
+        self.set_operation(other, subset, JoinType::Anti, true)
+    }
+
+    /// The distinct rows of this frame that are also in `other`.
+    ///
+    /// Rows are compared as in [`LazyFrame::except`].
+    pub fn intersect(
+        self,
+        other: LazyFrame,
+        subset: Option<Vec<PlSmallStr>>,
+    ) -> PolarsResult<LazyFrame> {
+        self.set_operation(other, subset, JoinType::Semi, false)
+    }
+
+    /// The rows of this frame that are also in `other`, keeping duplicates: a row that occurs `m`
+    /// times in this frame and `n` times in `other` is kept `min(m, n)` times.
+    ///
+    /// Rows are compared as in [`LazyFrame::except`], and duplicates are matched as in
+    /// [`LazyFrame::except_all`], with the same cost.
+    #[cfg(feature = "range")]
+    pub fn intersect_all(
+        self,
+        other: LazyFrame,
+        subset: Option<Vec<PlSmallStr>>,
+    ) -> PolarsResult<LazyFrame> {
+        self.set_operation(other, subset, JoinType::Semi, true)
+    }
+
+    fn set_operation(
+        mut self,
+        other: LazyFrame,
+        subset: Option<Vec<PlSmallStr>>,
+        how: JoinType,
+        keep_duplicates: bool,
+    ) -> PolarsResult<LazyFrame> {
+        let subset = match subset {
+            Some(subset) => subset,
+            None => self.collect_schema()?.iter_names_cloned().collect(),
+        };
+        polars_ensure!(
+            !subset.is_empty(),
+            InvalidOperation: "cannot compare rows by zero columns"
+        );
+        let on: Vec<Expr> = subset.iter().cloned().map(col).collect();
+
+        // Semi and anti joins filter this frame by the hashed keys of `other`, so only the keys
+        // of `other` are needed.
+        let join = |left: LazyFrame, right: LazyFrame, on: Vec<Expr>| {
+            left.join_builder()
+                .with(right.select(on.clone()))
+                .how(how)
+                .on(on)
+                .join_nulls(true)
+                .maintain_order(MaintainOrderJoin::Left)
+                .finish()
+        };
+
+        if !keep_duplicates {
+            return Ok(join(self, other, on).unique_stable(Some(subset), UniqueKeepStrategy::First));
+        }
+
+        #[cfg(feature = "range")]
+        {
+            let occurrence = || {
+                int_range(lit(0 as IdxSize), len(), 1, IDX_DTYPE)
+                    .over(on.clone())
+                    .alias(OCCURRENCE)
+            };
+            let left = self.with_column(occurrence());
+            let right = other.with_column(occurrence());
+            let mut on = on;
+            on.push(col(OCCURRENCE));
+            Ok(join(left, right, on).drop([col(OCCURRENCE)]))
+        }
+        #[cfg(not(feature = "range"))]
+        {
+            polars_bail!(
+                InvalidOperation: "keeping duplicate rows in set operations requires the 'range' feature"
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn frames() -> PolarsResult<(LazyFrame, LazyFrame)> {
+        let left = df!(
+            "a" => [Some(1), Some(1), Some(1), Some(2), None, None, Some(3)],
+            "b" => ["x", "x", "x", "y", "z", "z", "w"],
+        )?;
+        let right = df!(
+            "b" => ["x", "z", "v", "x"],
+            "a" => [Some(1), None, Some(4), Some(1)],
+        )?;
+        Ok((left.lazy(), right.lazy()))
+    }
+
+    fn column_a(lf: LazyFrame) -> PolarsResult<Vec<Option<i32>>> {
+        Ok(lf.collect()?.column("a")?.i32()?.into_iter().collect())
+    }
+
+    #[test]
+    fn test_except_intersect() -> PolarsResult<()> {
+        let (left, right) = frames()?;
+        assert_eq!(
+            column_a(left.clone().except(right.clone(), None)?)?,
+            [Some(2), Some(3)]
+        );
+        assert_eq!(
+            column_a(left.clone().intersect(right.clone(), None)?)?,
+            [Some(1), None]
+        );
+
+        let out = left
+            .clone()
+            .except(right.clone(), Some(vec!["a".into()]))?
+            .collect()?;
+        assert_eq!(out.shape(), (2, 2));
+        assert!(left.except(right, Some(vec![])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "range")]
+    fn test_except_intersect_all() -> PolarsResult<()> {
+        let (left, right) = frames()?;
+        assert_eq!(
+            column_a(left.clone().except_all(right.clone(), None)?)?,
+            [Some(1), Some(2), None, Some(3)]
+        );
+        assert_eq!(
+            column_a(left.clone().intersect_all(right.clone(), None)?)?,
+            [Some(1), Some(1), None]
+        );
+        assert_eq!(
+            column_a(left.intersect_all(right, Some(vec!["b".into()]))?)?,
+            [Some(1), Some(1), None]
+        );
+        Ok(())
+    }
+}
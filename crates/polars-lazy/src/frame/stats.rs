@@ -0,0 +1,324 @@
+//! Row counts, null counts and extrema of a [`LazyFrame`], see [`LazyFrame::stats`].
+use polars_core::prelude::*;
+use polars_utils::format_pl_smallstr;
+
+use crate::prelude::*;
+
+/// Whether the minimum and maximum of a column are computed.
+fn has_extrema(dtype: &DataType) -> bool {
+    dtype.is_primitive_numeric() || dtype.is_bool() || dtype.is_temporal() || dtype.is_string()
+}
+
+/// The statistics of a column, `None` where they are not known yet.
+#[derive(Default)]
+struct ColumnStats {
+    null_count: Option<IdxSize>,
+    min: Option<Scalar>,
+    max: Option<Scalar>,
+}
+
+struct FrameStats {
+    row_count: Option<IdxSize>,
+    columns: Vec<ColumnStats>,
+}
+
+impl FrameStats {
+    fn unknown(width: usize) -> Self {
+        Self {
+            row_count: None,
+            columns: (0..width).map(|_| ColumnStats::default()).collect(),
+        }
+    }
+}
+
+/// The statistics of a column, combined over the row groups of all files. The minimum and
+/// maximum collect those of the row groups.
+#[cfg(feature = "parquet")]
+struct MetadataStats {
+    null_count: Option<IdxSize>,
+    min: Option<Series>,
+    max: Option<Series>,
+}
+
+#[cfg(feature = "parquet")]
+impl MetadataStats {
+    fn new(dtype: &DataType) -> Self {
+        let extrema = has_extrema(dtype).then(|| Series::new_empty(PlSmallStr::EMPTY, dtype));
+        Self {
+            null_count: Some(0),
+            min: extrema.clone(),
+            max: extrema,
+        }
+    }
+
+    fn unknown() -> Self {
+        Self {
+            null_count: None,
+            min: None,
+            max: None,
+        }
+    }
+}
+
+#[cfg(feature = "parquet")]
+fn parquet_stats(sources: &ScanSources, schema: &Schema) -> PolarsResult<FrameStats> {
+    use arrow::array::{Array, StaticArray};
+    use polars_io::prelude::_internal::collect_statistics_with_live_columns;
+    use polars_io::prelude::ParquetReader;
+
+    let mut row_count: IdxSize = 0;
+    let mut columns = schema
+        .iter_values()
+        .map(MetadataStats::new)
+        .collect::<Vec<_>>();
+
+    for source in sources.iter() {
+        let mut reader = ParquetReader::new(std::io::Cursor::new(source.to_memslice()?));
+        let arrow_schema = reader.schema()?;
+        let metadata = reader.get_metadata()?.clone();
+        row_count += IdxSize::try_from(metadata.num_rows).map_err(
+            |_| polars_err!(ComputeError: "row count exceeds the maximum of {}", IdxSize::MAX),
+        )?;
+
+        // Columns that are missing from the file have no statistics.
+        let live_columns = schema
+            .iter_names()
+            .filter(|name| arrow_schema.contains(name.as_str()))
+            .cloned()
+            .collect::<PlIndexSet<_>>();
+        let file_stats = collect_statistics_with_live_columns(
+            &metadata.row_groups,
+            &arrow_schema,
+            &live_columns,
+            None,
+        )?;
+        let mut file_stats = live_columns
+            .iter()
+            .zip(file_stats)
+            .collect::<PlHashMap<_, _>>();
+
+        for ((name, dtype), column) in schema.iter().zip(columns.iter_mut()) {
+            let Some(stats) = file_stats.remove(name).flatten() else {
+                *column = MetadataStats::unknown();
+                continue;
+            };
+
+            let null_counts = &stats.null_count;
+            column.null_count = match column.null_count {
+                Some(n) if null_counts.null_count() == 0 => {
+                    Some(n + null_counts.values_iter().sum::<IdxSize>())
+                },
+                _ => None,
+            };
+
+            // Row groups have no minimum or maximum if all their values are null.
+            let extrema_known = metadata.row_groups.iter().enumerate().all(|(i, rg)| {
+                (stats.min_value.is_valid(i) && stats.max_value.is_valid(i))
+                    || null_counts.get(i) == Some(rg.num_rows() as IdxSize)
+            });
+            let field = arrow_schema.get(name).unwrap();
+            for (acc, values) in [
+                (&mut column.min, stats.min_value),
+                (&mut column.max, stats.max_value),
+            ] {
+                match acc {
+                    Some(acc) if extrema_known => {
+                        acc.append(&Series::try_from((field, values))?.cast(dtype)?)?;
+                    },
+                    _ => *acc = None,
+                }
+            }
+        }
+    }
+
+    let columns = columns
+        .into_iter()
+        .map(|c| {
+            PolarsResult::Ok(ColumnStats {
+                null_count: c.null_count,
+                min: c.min.map(|s| s.min_reduce()).transpose()?,
+                max: c.max.map(|s| s.max_reduce()).transpose()?,
+            })
+        })
+        .collect::<PolarsResult<_>>()?;
+    Ok(FrameStats {
+        row_count: Some(row_count),
+        columns,
+    })
+}
+
+#[cfg(feature = "ipc")]
+fn ipc_stats(sources: &ScanSources, width: usize) -> PolarsResult<FrameStats> {
+    let mut row_count: IdxSize = 0;
+    for source in sources.iter() {
+        let n_rows =
+            arrow::io::ipc::read::get_row_count(&mut std::io::Cursor::new(source.to_memslice()?))?;
+        row_count += IdxSize::try_from(n_rows).map_err(
+            |_| polars_err!(ComputeError: "row count exceeds the maximum of {}", IdxSize::MAX),
+        )?;
+    }
+    Ok(FrameStats {
+        row_count: Some(row_count),
+        ..FrameStats::unknown(width)
+    })
+}
+
+/// The statistics that are known from the metadata of the files that are scanned.
+///
+/// Only plans that read local Parquet or IPC files as they are, without filters, slices or
+/// added columns, have statistics in their metadata.
+#[allow(unused_variables)]
+fn metadata_stats(lf: LazyFrame, schema: &Schema) -> PolarsResult<FrameStats> {
+    let unknown = || Ok(FrameStats::unknown(schema.len()));
+    let plan = lf.to_alp_optimized()?;
+    let IR::Scan {
+        sources,
+        hive_parts: None,
+        predicate: None,
+        scan_type,
+        unified_scan_args,
+        ..
+    } = plan.lp_arena.get(plan.lp_top)
+    else {
+        return unknown();
+    };
+    if sources.is_cloud_url()
+        || unified_scan_args.row_index.is_some()
+        || unified_scan_args.pre_slice.is_some()
+        || unified_scan_args.include_file_paths.is_some()
+    {
+        return unknown();
+    }
+
+    match scan_type.as_ref() {
+        // The row count of an encrypted file is only known after decrypting all of it.
+        #[cfg(feature = "ipc_encryption")]
+        FileScan::Ipc {
+            encryption: Some(_),
+            ..
+        } => unknown(),
+        #[cfg(feature = "parquet")]
+        FileScan::Parquet { .. } => parquet_stats(sources, schema),
+        #[cfg(feature = "ipc")]
+        FileScan::Ipc { .. } => ipc_stats(sources, schema.len()),
+        _ => unknown(),
+    }
+}
+
+impl LazyFrame {
+    /// Get the number of rows and, for every column, the null count, minimum and maximum.
+    ///
+    /// The statistics are read from the metadata of the scanned files where possible, without
+    /// reading any data. This works for local Parquet files that have statistics, whose row
+    /// count is also known, and for the row count of local IPC files, as long as the plan only
+    /// scans and projects them. The statistics that aren't known from the metadata are computed
+    /// in a single aggregation over the input.
+    ///
+    /// The result has a single row with a `row_count` column and a struct column per input
+    /// column with the fields `null_count`, `min` and `max`. The minimum and maximum are only
+    /// computed for numeric, boolean, temporal and string columns and are null otherwise.
+    pub fn stats(mut self) -> PolarsResult<DataFrame> {
+        let schema = self.collect_schema()?;
+        let mut stats = metadata_stats(self.clone(), &schema)?;
+
+        let mut exprs = vec![];
+        if stats.row_count.is_none() {
+            exprs.push(len().alias("row_count"));
+        }
+        for (i, ((name, dtype), column)) in schema.iter().zip(&stats.columns).enumerate() {
+            let e = || col(name.clone());
+            if column.null_count.is_none() {
+                exprs.push(
+                    e().null_count()
+                        .alias(format_pl_smallstr!("{i}:null_count")),
+                );
+            }
+            if has_extrema(dtype) {
+                if column.min.is_none() {
+                    exprs.push(e().min().alias(format_pl_smallstr!("{i}:min")));
+                }
+                if column.max.is_none() {
+                    exprs.push(e().max().alias(format_pl_smallstr!("{i}:max")));
+                }
+            }
+        }
+
+        if !exprs.is_empty() {
+            let row = self.select(exprs).collect()?;
+            let get = |name: &str| -> PolarsResult<Scalar> {
+                let c = row.column(name)?;
+                Ok(Scalar::new(c.dtype().clone(), c.get(0)?.into_static()))
+            };
+            if stats.row_count.is_none() {
+                stats.row_count = get("row_count")?.value().extract::<IdxSize>();
+            }
+            for (i, column) in stats.columns.iter_mut().enumerate() {
+                if column.null_count.is_none() {
+                    column.null_count = get(&format!("{i}:null_count"))?.value().extract();
+                }
+                if row.schema().contains(&format!("{i}:min")) {
+                    column.min = Some(get(&format!("{i}:min"))?);
+                }
+                if row.schema().contains(&format!("{i}:max")) {
+                    column.max = Some(get(&format!("{i}:max"))?);
+                }
+            }
+        }
+
+        let mut out = Vec::with_capacity(schema.len() + 1);
+        out.push(Column::new("row_count".into(), [stats.row_count.unwrap()]));
+        for ((name, dtype), column) in schema.iter().zip(stats.columns) {
+            let extremum = |name: &str, value: Option<Scalar>| match value {
+                Some(value) => value.into_series(name.into()),
+                None => Series::full_null(name.into(), 1, dtype),
+            };
+            let fields = [
+                Series::new("null_count".into(), [column.null_count.unwrap()]),
+                extremum("min", column.min),
+                extremum("max", column.max),
+            ];
+            out.push(
+                StructChunked::from_series(name.clone(), 1, fields.iter())?
+                    .into_series()
+                    .into(),
+            );
+        }
+        DataFrame::new(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stats() -> PolarsResult<()> {
+        let list = Series::new(
+            "c".into(),
+            [
+                Series::new("".into(), [1i32]),
+                Series::new("".into(), [2i32, 3]),
+                Series::new("".into(), [4i32]),
+            ],
+        );
+        let mut df = df!(
+            "a" => [Some(3i32), None, Some(1)],
+            "b" => ["x", "z", "y"],
+        )?;
+        df.with_column(list)?;
+        let out = df.lazy().stats()?;
+        assert_eq!(out.get_column_names(), ["row_count", "a", "b", "c"]);
+        assert_eq!(out.column("row_count")?.idx()?.get(0), Some(3));
+
+        let a = out.column("a")?.struct_()?.fields_as_series();
+        assert_eq!(a[0].idx()?.get(0), Some(1));
+        assert_eq!(a[1].i32()?.get(0), Some(1));
+        assert_eq!(a[2].i32()?.get(0), Some(3));
+        let b = out.column("b")?.struct_()?.fields_as_series();
+        assert_eq!(b[1].str()?.get(0), Some("x"));
+        assert_eq!(b[2].str()?.get(0), Some("z"));
+        let c = out.column("c")?.struct_()?.fields_as_series();
+        assert_eq!(c[1].null_count(), 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+//! Upsampling of a [`LazyFrame`] at a regular frequency, see [`LazyFrame::upsample`].
+use polars_core::prelude::*;
+use polars_time::PolarsUpsample;
+use polars_time::prelude::{Duration, ensure_duration_matches_dtype};
+
+use crate::prelude::*;
+
+/// Upsample one group, whose time column holds no nulls, and give the new rows its keys.
+fn upsample_group(
+    group: DataFrame,
+    by: &[PlSmallStr],
+    time_column: &PlSmallStr,
+    every: Duration,
+    schema: &Schema,
+) -> PolarsResult<DataFrame> {
+    let group = group.sort(
+        [time_column.clone()],
+        SortMultipleOptions::default().with_maintain_order(true),
+    )?;
+    let mut out = group.upsample(Vec::<PlSmallStr>::new(), time_column, every)?;
+    let height = out.height();
+    for name in by {
+        let c = group.column(name)?;
+        let value = Scalar::new(c.dtype().clone(), c.get(0)?.into_static());
+        out.with_column(Column::new_scalar(name.clone(), value, height))?;
+    }
+    out.select(schema.iter_names_cloned())
+}
+
+impl LazyFrame {
+    /// Upsample at a regular frequency: for every group of the `by` columns, add a row for each
+    /// timestamp from the earliest to the latest value of `time_column` in steps of `every`.
+    ///
+    /// The new rows have the keys of their group and nulls in the other columns, so that they
+    /// can be filled with e.g. [`Expr::fill_null_with_strategy`] or `Expr::interpolate`. The
+    /// time column doesn't have to be sorted. Within every group the result is sorted by the
+    /// time column, and the groups are in the order of their first occurrence if
+    /// `maintain_order` is set.
+    ///
+    /// This doesn't stream: the input is collected and upsampled in memory with the eager
+    /// [`upsample`](polars_time::PolarsUpsample::upsample), one group at a time.
+    pub fn upsample(
+        mut self,
+        by: Vec<PlSmallStr>,
+        time_column: &str,
+        every: Duration,
+        maintain_order: bool,
+    ) -> PolarsResult<LazyFrame> {
+        let schema = self.collect_schema()?;
+        let dtype = schema.try_get(time_column)?;
+        ensure_duration_matches_dtype(every, dtype, "every")?;
+        polars_ensure!(
+            matches!(
+                dtype,
+                DataType::Datetime(_, _)
+                    | DataType::Date
+                    | DataType::Int32
+                    | DataType::Int64
+                    | DataType::UInt32
+                    | DataType::UInt64
+            ),
+            ComputeError: "upsample not allowed for index column of dtype {}", dtype,
+        );
+        polars_ensure!(
+            !by.iter().any(|name| name == time_column),
+            InvalidOperation: "cannot upsample by the time column '{}'", time_column,
+        );
+
+        let time_column = PlSmallStr::from_str(time_column);
+        let rows = self.filter(col(time_column.clone()).is_not_null());
+
+        // The groups need all of their rows, so the map must see the whole input at once.
+        let mut optimizations = AllowedOptimizations::default();
+        optimizations.set(OptFlags::PREDICATE_PUSHDOWN, false);
+        optimizations.set(OptFlags::PROJECTION_PUSHDOWN, false);
+        optimizations.set(OptFlags::SLICE_PUSHDOWN, false);
+
+        let output_schema = schema.clone();
+        Ok(rows.map(
+            move |df| {
+                if df.is_empty() {
+                    return Ok(DataFrame::empty_with_schema(&schema));
+                }
+                if by.is_empty() {
+                    return upsample_group(df, &by, &time_column, every, &schema);
+                }
+                let groups = if maintain_order {
+                    df.group_by_stable(by.iter().cloned())?
+                } else {
+                    df.group_by(by.iter().cloned())?
+                };
+                groups.apply(|group| upsample_group(group, &by, &time_column, every, &schema))
+            },
+            optimizations,
+            Some(Arc::new(move |_: &Schema| Ok(output_schema.clone()))),
+            Some("UPSAMPLE"),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lazy_upsample() -> PolarsResult<()> {
+        let df = df!(
+            "g" => ["a", "b", "a", "a"],
+            "t" => [3i64, 1, 0, 2],
+            "v" => [3i32, 1, 0, 2],
+        )?;
+        let out = df
+            .lazy()
+            .upsample(vec!["g".into()], "t", Duration::parse("1i"), true)?
+            .collect()?;
+        assert_eq!(out.get_column_names(), ["g", "t", "v"]);
+        assert_eq!(
+            Vec::from(out.column("g")?.str()?),
+            [Some("a"), Some("a"), Some("a"), Some("a"), Some("b")]
+        );
+        assert_eq!(
+            Vec::from(out.column("t")?.i64()?),
+            [Some(0), Some(1), Some(2), Some(3), Some(1)]
+        );
+        assert_eq!(
+            Vec::from(out.column("v")?.i32()?),
+            [Some(0), None, Some(2), Some(3), Some(1)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_upsample_without_by() -> PolarsResult<()> {
+        let df = df!(
+            "t" => [Some(4i64), None, Some(0), Some(2), Some(2)],
+            "v" => [4i32, 9, 0, 2, 3],
+        )?;
+        let out = df
+            .lazy()
+            .upsample(vec![], "t", Duration::parse("1i"), false)?
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("t")?.i64()?),
+            [Some(0), Some(1), Some(2), Some(2), Some(3), Some(4)]
+        );
+        assert_eq!(
+            Vec::from(out.column("v")?.i32()?),
+            [Some(0), None, Some(2), Some(3), None, Some(4)]
+        );
+        Ok(())
+    }
+}
@@ -39,5 +39,6 @@ pub(super) fn streamable_join(args: &JoinArgs) -> bool {
         JoinType::Full => true,
         _ => false,
     };
-    supported && !args.validation.needs_checks()
+    // Null equality per key is not supported by the join sinks.
+    supported && !args.validation.needs_checks() && args.nulls_equal_per_key.is_none()
 }
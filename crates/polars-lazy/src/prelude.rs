@@ -7,13 +7,17 @@ pub use polars_io::ipc::IpcWriterOptions;
 pub use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
 pub use polars_io::parquet::write::ParquetWriteOptions;
+pub use polars_mem_engine::{EngineBackend, Executor, is_offloadable_ir};
 pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
 #[cfg(feature = "polars_cloud")]
 pub use polars_plan::client::prepare_cloud_plan;
 pub use polars_plan::dsl::AnonymousScanOptions;
-pub use polars_plan::plans::{AnonymousScan, AnonymousScanArgs, Literal, LiteralValue, NULL, Null};
+pub use polars_plan::plans::{
+    AnonymousScan, AnonymousScanArgs, AnonymousScanBatches, ColumnMask, Literal, LiteralValue,
+    NULL, Null, ScanPolicy, clear_scan_policies, register_scan_policy,
+};
 pub use polars_plan::prelude::UnionArgs;
 pub(crate) use polars_plan::prelude::*;
 #[cfg(feature = "rolling_window_by")]
@@ -51,6 +51,7 @@ impl LazyFrame {
                 glob: false,
                 projection: None,
                 row_index: None,
+                row_index_per_file: false,
                 pre_slice: args.n_rows.map(|len| Slice::Positive { offset: 0, len }),
                 cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
                 missing_columns_policy: MissingColumnsPolicy::Raise,
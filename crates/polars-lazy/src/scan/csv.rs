@@ -338,6 +338,7 @@ impl LazyFileListReader for LazyCsvReader {
                 glob: self.glob,
                 projection: None,
                 row_index,
+                row_index_per_file: false,
                 pre_slice,
                 cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
                 missing_columns_policy: MissingColumnsPolicy::Raise,
@@ -17,6 +17,9 @@ pub struct ScanArgsIpc {
     pub cloud_options: Option<CloudOptions>,
     pub hive_options: HiveOptions,
     pub include_file_paths: Option<PlSmallStr>,
+    /// Decrypt files that were written with this encryption.
+    #[cfg(feature = "ipc_encryption")]
+    pub encryption: Option<polars_io::ipc::IpcEncryption>,
 }
 
 impl Default for ScanArgsIpc {
@@ -29,6 +32,8 @@ impl Default for ScanArgsIpc {
             cloud_options: Default::default(),
             hive_options: Default::default(),
             include_file_paths: None,
+            #[cfg(feature = "ipc_encryption")]
+            encryption: None,
         }
     }
 }
@@ -65,6 +70,8 @@ impl LazyFileListReader for LazyIpcReader {
         let lf: LazyFrame = DslBuilder::scan_ipc(
             self.sources,
             options,
+            #[cfg(feature = "ipc_encryption")]
+            args.encryption,
             UnifiedScanArgs {
                 schema: None,
                 cloud_options,
@@ -74,6 +81,7 @@ impl LazyFileListReader for LazyIpcReader {
                 glob: true,
                 projection: None,
                 row_index,
+                row_index_per_file: false,
                 pre_slice,
                 cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
                 missing_columns_policy: MissingColumnsPolicy::Raise,
@@ -148,4 +156,10 @@ impl LazyFrame {
     pub fn scan_ipc_sources(sources: ScanSources, args: ScanArgsIpc) -> PolarsResult<Self> {
         LazyIpcReader::new(args).with_sources(sources).finish()
     }
+
+    /// Create a LazyFrame from the frame that a process wrote into shared memory with
+    /// [`write_shared_memory`](polars_io::ipc::write_shared_memory).
+    pub fn scan_shared_memory(name: &str, args: ScanArgsIpc) -> PolarsResult<Self> {
+        Self::scan_ipc(polars_io::ipc::shared_memory_path(name)?, args)
+    }
 }
@@ -8,6 +8,8 @@ pub(super) mod ipc;
 pub(super) mod ndjson;
 #[cfg(feature = "parquet")]
 pub(super) mod parquet;
+#[cfg(feature = "range")]
+pub(super) mod range;
 
 #[cfg(feature = "catalog")]
 mod catalog;
@@ -132,6 +132,7 @@ impl LazyFileListReader for LazyJsonLineReader {
             glob: true,
             projection: None,
             row_index: self.row_index,
+            row_index_per_file: false,
             pre_slice: self.n_rows.map(|len| Slice::Positive { offset: 0, len }),
             cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
             missing_columns_policy: MissingColumnsPolicy::Raise,
@@ -3,7 +3,7 @@ use std::path::{Path, PathBuf};
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::read::ParallelStrategy;
-use polars_io::prelude::ParquetOptions;
+use polars_io::prelude::{ParquetColumnMapping, ParquetOptions};
 use polars_io::{HiveOptions, RowIndex};
 use polars_utils::slice_enum::Slice;
 
@@ -25,6 +25,10 @@ pub struct ScanArgsParquet {
     pub glob: bool,
     pub include_file_paths: Option<PlSmallStr>,
     pub allow_missing_columns: bool,
+    /// Read string columns as `Categorical`, see [`ParquetOptions::strings_as_categorical`].
+    pub strings_as_categorical: bool,
+    /// Map the columns of every file to the columns of the scan, see [`ParquetColumnMapping`].
+    pub column_mapping: Option<ParquetColumnMapping>,
 }
 
 impl Default for ScanArgsParquet {
@@ -43,6 +47,8 @@ impl Default for ScanArgsParquet {
             glob: true,
             include_file_paths: None,
             allow_missing_columns: false,
+            strings_as_categorical: false,
+            column_mapping: None,
         }
     }
 }
@@ -70,6 +76,8 @@ impl LazyFileListReader for LazyParquetReader {
             parallel: self.args.parallel,
             low_memory: self.args.low_memory,
             use_statistics: self.args.use_statistics,
+            strings_as_categorical: self.args.strings_as_categorical,
+            column_mapping: self.args.column_mapping,
         };
 
         let unified_scan_args = UnifiedScanArgs {
@@ -82,6 +90,7 @@ impl LazyFileListReader for LazyParquetReader {
             projection: None,
             // Note: We call `with_row_index()` on the LazyFrame below
             row_index: None,
+            row_index_per_file: false,
             pre_slice: self
                 .args
                 .n_rows
@@ -0,0 +1,392 @@
+//! Range generators as lazy sources.
+//!
+//! Unlike the `int_range`/`date_range` expressions, these sources support slice and predicate
+//! pushdown and produce their output in batches in the streaming engine, so a filtered or
+//! limited range never materializes in full.
+use std::ops::Range;
+
+#[cfg(feature = "dtype-date")]
+use arrow::temporal_conversions::MILLISECONDS_IN_DAY;
+use polars_ops::series::new_int_range;
+#[cfg(feature = "dtype-date")]
+use polars_time::{ClosedWindow, Duration};
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+enum RangeKind {
+    Int {
+        start: i64,
+        end: i64,
+        step: i64,
+    },
+    #[cfg(feature = "dtype-date")]
+    Date {
+        /// Start in milliseconds since the epoch.
+        start: i64,
+        interval: Duration,
+        /// Index of the first value, `1` if the start is excluded.
+        first: i64,
+    },
+}
+
+/// An [`AnonymousScan`] producing a single monotonic range column.
+#[derive(Clone)]
+struct RangeScan {
+    name: PlSmallStr,
+    dtype: DataType,
+    kind: RangeKind,
+    len: usize,
+}
+
+impl RangeScan {
+    fn ascending(&self) -> bool {
+        match &self.kind {
+            RangeKind::Int { step, .. } => *step > 0,
+            #[cfg(feature = "dtype-date")]
+            RangeKind::Date { .. } => true,
+        }
+    }
+
+    /// Physical value at index `i`.
+    fn value(&self, i: usize) -> PolarsResult<i64> {
+        match &self.kind {
+            RangeKind::Int { start, step, .. } => Ok(start + i as i64 * step),
+            #[cfg(feature = "dtype-date")]
+            RangeKind::Date {
+                start,
+                interval,
+                first,
+            } => {
+                let ms = (*interval * (first + i as i64)).add_ms(*start, None)?;
+                Ok(ms.div_euclid(MILLISECONDS_IN_DAY))
+            },
+        }
+    }
+
+    /// Index range of the values that can satisfy the bounds of `predicate`.
+    fn prune(&self, predicate: &Expr, rows: Range<usize>) -> PolarsResult<Range<usize>> {
+        let mut lower = None;
+        let mut upper = None;
+        collect_bounds(predicate, &self.name, &mut lower, &mut upper);
+
+        let (start, end) = if self.ascending() {
+            let start = match lower {
+                Some(lo) => partition_point(rows.clone(), |i| Ok(self.value(i)? < lo))?,
+                None => rows.start,
+            };
+            let end = match upper {
+                Some(hi) => partition_point(rows.clone(), |i| Ok(self.value(i)? <= hi))?,
+                None => rows.end,
+            };
+            (start, end)
+        } else {
+            let start = match upper {
+                Some(hi) => partition_point(rows.clone(), |i| Ok(self.value(i)? > hi))?,
+                None => rows.start,
+            };
+            let end = match lower {
+                Some(lo) => partition_point(rows.clone(), |i| Ok(self.value(i)? >= lo))?,
+                None => rows.end,
+            };
+            (start, end)
+        };
+        Ok(start..end.max(start))
+    }
+
+    /// Rows to produce after applying the slice and the predicate bounds.
+    fn rows(&self, scan_opts: &AnonymousScanArgs) -> PolarsResult<Range<usize>> {
+        let start = scan_opts.offset.min(self.len);
+        let end = match scan_opts.n_rows {
+            Some(n_rows) => start.saturating_add(n_rows).min(self.len),
+            None => self.len,
+        };
+        match &scan_opts.predicate {
+            Some(predicate) => self.prune(predicate, start..end),
+            None => Ok(start..end),
+        }
+    }
+
+    fn materialize(&self, rows: Range<usize>) -> PolarsResult<DataFrame> {
+        let s = match &self.kind {
+            RangeKind::Int { .. } if rows.is_empty() => {
+                Series::new_empty(self.name.clone(), &self.dtype)
+            },
+            RangeKind::Int { end, step, .. } => {
+                let start_v = self.value(rows.start)?;
+                // The end of the full range is used for the last rows, as the value past the
+                // last row may not fit in the dtype.
+                let end_v = if rows.end == self.len {
+                    *end
+                } else {
+                    self.value(rows.end)?
+                };
+                // All values fit in an `i64` as `start` and `end` do.
+                new_int_range::<Int64Type>(start_v, end_v, *step, self.name.clone())?
+                    .strict_cast(&self.dtype)?
+            },
+            #[cfg(feature = "dtype-date")]
+            RangeKind::Date { .. } => {
+                let values = rows
+                    .map(|i| self.value(i).map(|v| v as i32))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let mut ca = Int32Chunked::from_vec(self.name.clone(), values);
+                ca.set_sorted_flag(IsSorted::Ascending);
+                ca.into_date().into_series()
+            },
+        };
+        DataFrame::new(vec![s.into_column()])
+    }
+}
+
+impl AnonymousScan for RangeScan {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let df = self.materialize(self.rows(&scan_opts)?)?;
+        match scan_opts.predicate {
+            Some(predicate) => df.lazy().filter(predicate).collect(),
+            None => Ok(df),
+        }
+    }
+
+    fn scan_batches(
+        &self,
+        scan_opts: AnonymousScanArgs,
+        batch_size: usize,
+    ) -> PolarsResult<AnonymousScanBatches> {
+        let rows = self.rows(&scan_opts)?;
+        let batch_size = batch_size.max(1);
+        let this = self.clone();
+        Ok(Box::new(rows.clone().step_by(batch_size).map(
+            move |start| this.materialize(start..(start + batch_size).min(rows.end)),
+        )))
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(Arc::new(Schema::from_iter([Field::new(
+            self.name.clone(),
+            self.dtype.clone(),
+        )])))
+    }
+
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_offset_pushdown(&self) -> bool {
+        true
+    }
+}
+
+/// First index in `range` for which `pred` is false, assuming `pred` is monotonic.
+fn partition_point(
+    mut range: Range<usize>,
+    pred: impl Fn(usize) -> PolarsResult<bool>,
+) -> PolarsResult<usize> {
+    while range.start < range.end {
+        let mid = range.start + (range.end - range.start) / 2;
+        if pred(mid)? {
+            range.start = mid + 1;
+        } else {
+            range.end = mid;
+        }
+    }
+    Ok(range.start)
+}
+
+/// Narrow the inclusive physical bounds on `name` implied by the conjunction `predicate`.
+fn collect_bounds(
+    predicate: &Expr,
+    name: &PlSmallStr,
+    lower: &mut Option<i64>,
+    upper: &mut Option<i64>,
+) {
+    match predicate {
+        Expr::BinaryExpr { left, op, right } => {
+            if matches!(op, Operator::And | Operator::LogicalAnd) {
+                collect_bounds(left, name, lower, upper);
+                collect_bounds(right, name, lower, upper);
+                return;
+            }
+            match (left.as_ref(), right.as_ref()) {
+                (Expr::Column(c), value) if c == name => narrow(*op, value, lower, upper),
+                (value, Expr::Column(c)) if c == name => {
+                    narrow(op.swap_operands(), value, lower, upper)
+                },
+                _ => {},
+            }
+        },
+        #[cfg(feature = "is_between")]
+        Expr::Function {
+            input,
+            function: FunctionExpr::Boolean(BooleanFunction::IsBetween { closed }),
+            ..
+        } => {
+            use polars_ops::series::ClosedInterval;
+            let [Expr::Column(c), lo, hi] = input.as_slice() else {
+                return;
+            };
+            if c != name {
+                return;
+            }
+            let (lo_op, hi_op) = match closed {
+                ClosedInterval::Both => (Operator::GtEq, Operator::LtEq),
+                ClosedInterval::Left => (Operator::GtEq, Operator::Lt),
+                ClosedInterval::Right => (Operator::Gt, Operator::LtEq),
+                ClosedInterval::None => (Operator::Gt, Operator::Lt),
+            };
+            narrow(lo_op, lo, lower, upper);
+            narrow(hi_op, hi, lower, upper);
+        },
+        _ => {},
+    }
+}
+
+/// Narrow the bounds by `column <op> value` if `value` is an integer or date literal.
+fn narrow(op: Operator, value: &Expr, lower: &mut Option<i64>, upper: &mut Option<i64>) {
+    let Expr::Literal(lv) = value else {
+        return;
+    };
+    let Some(av) = lv.to_any_value() else {
+        return;
+    };
+    let dtype = av.dtype();
+    if !(dtype.is_integer() || dtype.is_date()) {
+        return;
+    }
+    let Some(v) = av.extract::<i64>() else {
+        return;
+    };
+
+    let (lo, hi) = match op {
+        Operator::Eq => (Some(v), Some(v)),
+        Operator::Gt => (v.checked_add(1), None),
+        Operator::GtEq => (Some(v), None),
+        Operator::Lt => (None, v.checked_sub(1)),
+        Operator::LtEq => (None, Some(v)),
+        _ => return,
+    };
+    if let Some(lo) = lo {
+        *lower = Some(lower.map_or(lo, |l| l.max(lo)));
+    }
+    if let Some(hi) = hi {
+        *upper = Some(upper.map_or(hi, |u| u.min(hi)));
+    }
+}
+
+fn range_scan(
+    name: PlSmallStr,
+    dtype: DataType,
+    kind: RangeKind,
+    len: usize,
+) -> PolarsResult<LazyFrame> {
+    let function = Arc::new(RangeScan {
+        name: name.clone(),
+        dtype: dtype.clone(),
+        kind,
+        len,
+    });
+    let args = ScanArgsAnonymous {
+        schema: Some(Arc::new(Schema::from_iter([Field::new(name, dtype)]))),
+        name: "RANGE SCAN",
+        ..Default::default()
+    };
+    LazyFrame::anonymous_scan(function, args)
+}
+
+impl LazyFrame {
+    /// Create a [`LazyFrame`] with a single integer column ranging from `start` (inclusive) to
+    /// `end` (exclusive) in steps of `step`.
+    ///
+    /// This is a lazy source: slices and filters on the column limit what is generated and the
+    /// streaming engine produces the range in morsels.
+    pub fn scan_int_range(
+        name: PlSmallStr,
+        start: i64,
+        end: i64,
+        step: i64,
+        dtype: DataType,
+    ) -> PolarsResult<LazyFrame> {
+        polars_ensure!(step != 0, InvalidOperation: "step must not be zero");
+        polars_ensure!(
+            dtype.is_integer(),
+            ComputeError: "non-integer `dtype` passed to `int_range`: {:?}", dtype
+        );
+        let (min, max) = (
+            dtype.min()?.value().extract::<i128>().unwrap(),
+            dtype.max()?.value().extract::<i128>().unwrap(),
+        );
+        polars_ensure!(
+            (min..=max).contains(&(start as i128)) && (min..=max).contains(&(end as i128)),
+            InvalidOperation: "`start` and `end` of `int_range` must fit in {:?}", dtype
+        );
+
+        let (start_w, end_w, step_w) = (start as i128, end as i128, step as i128);
+        let len = if step > 0 && end_w > start_w {
+            (end_w - start_w + step_w - 1) / step_w
+        } else if step < 0 && start_w > end_w {
+            (start_w - end_w - step_w - 1) / -step_w
+        } else {
+            0
+        };
+        let len = usize::try_from(len)
+            .map_err(|_| polars_err!(ComputeError: "`int_range` of length {len} is too long"))?;
+
+        range_scan(name, dtype, RangeKind::Int { start, end, step }, len)
+    }
+
+    /// Create a [`LazyFrame`] with a single date column ranging from `start` to `end` (days
+    /// since the epoch) with the given `interval`.
+    ///
+    /// This is a lazy source: slices and filters on the column limit what is generated and the
+    /// streaming engine produces the range in morsels.
+    #[cfg(feature = "dtype-date")]
+    pub fn scan_date_range(
+        name: PlSmallStr,
+        start: i32,
+        end: i32,
+        interval: Duration,
+        closed: ClosedWindow,
+    ) -> PolarsResult<LazyFrame> {
+        polars_ensure!(
+            interval.is_full_days(),
+            ComputeError: "`interval` input for `date_range` must consist of full days, got: {interval}"
+        );
+        polars_ensure!(
+            !interval.negative() && !interval.is_zero(),
+            ComputeError: "`interval` must be positive"
+        );
+
+        let first = match closed {
+            ClosedWindow::Both | ClosedWindow::Left => 0,
+            ClosedWindow::Right | ClosedWindow::None => 1,
+        };
+        let include_end = matches!(closed, ClosedWindow::Both | ClosedWindow::Right);
+        let scan = RangeScan {
+            name,
+            dtype: DataType::Date,
+            kind: RangeKind::Date {
+                start: start as i64 * MILLISECONDS_IN_DAY,
+                interval,
+                first,
+            },
+            len: 0,
+        };
+
+        // Every step adds at least a day, so this bounds the length.
+        let max_len = (end as i64 - start as i64 + 1).max(0) as usize;
+        let end = end as i64;
+        let len = partition_point(0..max_len, |i| {
+            let v = scan.value(i)?;
+            Ok(if include_end { v <= end } else { v < end })
+        })?;
+        range_scan(scan.name, scan.dtype, scan.kind, len)
+    }
+}
@@ -424,6 +424,8 @@ fn test_ipc_globbing() -> PolarsResult<()> {
             cloud_options: None,
             hive_options: Default::default(),
             include_file_paths: None,
+            #[cfg(feature = "ipc_encryption")]
+            encryption: None,
         },
     )?
     .collect()?;
@@ -638,6 +640,56 @@ fn test_row_index_on_files() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_row_index_per_file() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let indices = |df: DataFrame| -> PolarsResult<Vec<IdxSize>> {
+        Ok(df
+            .column("index")?
+            .idx()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>())
+    };
+
+    // 5 files of 27 rows.
+    for offset in [0 as IdxSize, 10] {
+        let lf = LazyCsvReader::new(GLOB_CSV)
+            .finish()?
+            .with_row_index_per_file("index", Some(offset));
+        assert!(row_index_at_scan(lf.clone()));
+        assert_eq!(
+            indices(lf.clone().collect()?)?,
+            (0..5).flat_map(|_| offset..27 + offset).collect::<Vec<_>>()
+        );
+
+        let df = lf
+            .clone()
+            .filter(col("index").lt(lit(offset + 2)))
+            .collect()?;
+        assert_eq!(
+            indices(df)?,
+            (0..5)
+                .flat_map(|_| [offset, offset + 1])
+                .collect::<Vec<_>>()
+        );
+
+        let df = lf.clone().slice(25, 4).collect()?;
+        assert_eq!(indices(df)?, [offset + 25, offset + 26, offset, offset + 1]);
+
+        let df = lf.slice(-2, 2).collect()?;
+        assert_eq!(indices(df)?, [offset + 25, offset + 26]);
+    }
+
+    // Without a scan this is a regular row index.
+    let df = df!("a" => [1, 2, 3])?
+        .lazy()
+        .with_row_index_per_file("index", None)
+        .collect()?;
+    assert_eq!(indices(df)?, [0, 1, 2]);
+
+    Ok(())
+}
+
 #[test]
 fn scan_predicate_on_set_null_values() -> PolarsResult<()> {
     let df = LazyCsvReader::new(FOODS_CSV)
@@ -763,3 +815,78 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_scan_policy() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join(format!("polars_scan_policy_{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join("users.parquet");
+    let mut df = df![
+        "region" => ["eu", "us", "eu"],
+        "email" => [Some("a@x.com"), Some("b@x.com"), None],
+        "salary" => [1, 2, 3],
+    ]?;
+    ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+
+    // The prefix is resolved, so the policy applies to the scanned path below.
+    register_scan_policy(
+        dir.join("sub").join(".."),
+        ScanPolicy::new()
+            .with_row_filter(col("region").eq(lit("eu")))
+            .with_mask("email", ColumnMask::Redact("***".into()))
+            .with_mask("salary", ColumnMask::Nullify)
+            .with_mask("not_a_column", ColumnMask::Nullify),
+    );
+    let out = LazyFrame::scan_parquet(&path, Default::default())?
+        .filter(col("salary").is_null().or(col("region").eq(lit("us"))))
+        .select([col("email"), col("salary")])
+        .collect();
+    clear_scan_policies();
+    std::fs::remove_dir_all(&dir)?;
+
+    let expected = df![
+        "email" => [Some("***"), None],
+        "salary" => [None::<i32>, None],
+    ]?;
+    assert!(out?.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ipc_encryption")]
+fn test_ipc_encryption_roundtrip() -> PolarsResult<()> {
+    use polars_io::ipc::{IpcEncryption, StaticKeyProvider, is_encrypted};
+
+    let provider = Arc::new(StaticKeyProvider::new("key-1".into(), [7; 32]));
+    let encryption = IpcEncryption::new(provider)?;
+    let dir = std::env::temp_dir().join(format!("polars_ipc_encryption_{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join("encrypted.ipc");
+    let mut df = df![
+        "a" => [1, 2, 3],
+        "b" => ["x", "y", "z"],
+    ]?;
+    IpcWriter::new(std::fs::File::create(&path)?)
+        .with_encryption(Some(encryption.clone()))
+        .finish(&mut df)?;
+
+    let bytes = std::fs::read(&path)?;
+    assert!(is_encrypted(&bytes));
+    assert!(IpcReader::new(Cursor::new(bytes.clone())).finish().is_err());
+    let read = IpcReader::new_encrypted(bytes.as_slice(), &encryption)?.finish()?;
+    assert!(read.equals(&df));
+
+    let args = ScanArgsIpc {
+        encryption: Some(encryption),
+        ..Default::default()
+    };
+    let scanned = LazyFrame::scan_ipc(&path, args.clone())?.collect();
+    let count = LazyFrame::scan_ipc(&path, args)?.select([len()]).collect();
+    let without_key = LazyFrame::scan_ipc(&path, Default::default())?.collect();
+    std::fs::remove_dir_all(&dir)?;
+
+    assert!(scanned?.equals(&df));
+    assert_eq!(count?.column("len")?.idx()?.get(0), Some(3));
+    assert!(without_key.is_err());
+    Ok(())
+}
@@ -14,6 +14,8 @@ mod queries;
 mod schema;
 #[cfg(feature = "streaming")]
 mod streaming;
+#[cfg(feature = "tracing")]
+mod tracing_spans;
 
 fn get_arenas() -> (Arena<AExpr>, Arena<IR>) {
     let expr_arena = Arena::with_capacity(16);
@@ -652,3 +652,120 @@ fn test_cluster_with_columns_chain() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_set_sorted_by_group_by_and_unique() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 1, 1, 2, 2, 3],
+        "b" => ["x", "x", "y", "y", "y", "z"],
+        "c" => [1, 2, 3, 4, 5, 6],
+    ]?;
+    let sorted = df.lazy().set_sorted_by(["a", "b"], [false]);
+
+    let keys_sorted = |q: LazyFrame| {
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.optimize(&mut lp_arena, &mut expr_arena).unwrap();
+        (&lp_arena).iter(lp).any(|(_, lp)| match lp {
+            IR::GroupBy { options, .. } => options.keys_sorted,
+            IR::Distinct { options, .. } => options.keys_sorted,
+            _ => false,
+        })
+    };
+
+    // The sortedness is kept through the filter and the projection.
+    let q = sorted
+        .clone()
+        .filter(col("c").neq(lit(2)))
+        .select([col("b"), col("a"), col("c")])
+        .group_by([col("b"), col("a")])
+        .agg([col("c").sum()]);
+    assert!(keys_sorted(q.clone()));
+    let expected = df![
+        "b" => ["x", "y", "y", "z"],
+        "a" => [1, 1, 2, 3],
+        "c" => [1, 3, 9, 6],
+    ]?;
+    assert!(q.collect()?.equals(&expected));
+
+    // `b` alone is not sorted.
+    let q = sorted.clone().group_by([col("b")]).agg([col("c").sum()]);
+    assert!(!keys_sorted(q));
+    let q = sorted
+        .clone()
+        .with_column(col("c").alias("a"))
+        .group_by([col("a")])
+        .agg([col("c").sum()]);
+    assert!(!keys_sorted(q));
+
+    let q = sorted.unique_stable(Some(vec!["a".into()]), UniqueKeepStrategy::Last);
+    assert!(keys_sorted(q.clone()));
+    assert_eq!(
+        Vec::from(q.collect()?.column("c")?.i32()?),
+        &[Some(3), Some(5), Some(6)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "asof_join")]
+fn test_set_sorted_by_asof_join_by() -> PolarsResult<()> {
+    let left = df![
+        "g" => [1, 1, 2, 2],
+        "t" => [1, 5, 2, 3],
+    ]?
+    .lazy();
+    let right = df![
+        "g" => [1, 2, 2],
+        "t" => [0, 1, 3],
+        "v" => [10, 20, 30],
+    ]?
+    .lazy();
+
+    let asof_join = |left: LazyFrame, right: LazyFrame| {
+        left.join_builder()
+            .with(right)
+            .left_on([col("t")])
+            .right_on([col("t")])
+            .how(JoinType::AsOf(AsOfOptions {
+                left_by: Some(vec!["g".into()]),
+                right_by: Some(vec!["g".into()]),
+                check_sortedness: true,
+                ..Default::default()
+            }))
+            .finish()
+    };
+    let check_sortedness = |q: LazyFrame| {
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.optimize(&mut lp_arena, &mut expr_arena).unwrap();
+        (&lp_arena).iter(lp).any(|(_, lp)| match lp {
+            IR::Join { options, .. } => {
+                matches!(&options.args.how, JoinType::AsOf(options) if options.check_sortedness)
+            },
+            _ => false,
+        })
+    };
+
+    // `t` is sorted within the groups of `g` on both sides.
+    let q = asof_join(
+        left.clone().set_sorted_by(["g", "t"], [false]),
+        right.clone().set_sorted_by(["g", "t"], [false]),
+    );
+    assert!(!check_sortedness(q.clone()));
+    assert_eq!(
+        Vec::from(q.collect()?.column("v")?.i32()?),
+        &[Some(10), Some(10), Some(20), Some(30)]
+    );
+
+    // `t` doesn't directly follow the `by` columns, or is descending.
+    let q = asof_join(
+        left.clone().set_sorted_by(["g"], [false]),
+        right.clone().set_sorted_by(["g", "t"], [false]),
+    );
+    assert!(check_sortedness(q));
+    let q = asof_join(
+        left.set_sorted_by(["g", "t"], [false, true]),
+        right.set_sorted_by(["g", "t"], [false]),
+    );
+    assert!(check_sortedness(q));
+    Ok(())
+}
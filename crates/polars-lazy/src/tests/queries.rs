@@ -83,6 +83,120 @@ fn test_lazy_drop_nulls() {
     assert!(new.equals(&out));
 }
 
+#[test]
+#[cfg(feature = "is_unique")]
+fn test_lazy_duplicates() -> PolarsResult<()> {
+    let df = df! {
+        "a" => &[1, 2, 1, 3, 1, 2],
+        "b" => &["x", "y", "z", "x", "x", "y"]
+    }?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .duplicates(None, "count".into())
+        .collect()?;
+    let expected = df! {
+        "a" => &[1, 2, 1, 2],
+        "b" => &["x", "y", "x", "y"],
+        "count" => &[2 as IdxSize, 2, 2, 2]
+    }?;
+    assert!(out.equals(&expected));
+
+    // An existing column isn't silently replaced by the counts.
+    let out = df
+        .clone()
+        .lazy()
+        .duplicates(Some(vec![col("a")]), "b".into())
+        .collect();
+    assert!(matches!(out, Err(PolarsError::Duplicate(_))));
+
+    let out = df
+        .clone()
+        .lazy()
+        .duplicates(Some(vec![col("c")]), "count".into())
+        .collect();
+    assert!(matches!(out, Err(PolarsError::ColumnNotFound(_))));
+
+    let out = df
+        .lazy()
+        .select([
+            is_duplicated_by([col("a")], UniqueKeepStrategy::First)?.alias("first"),
+            is_duplicated_by([col("a")], UniqueKeepStrategy::Last)?.alias("last"),
+            is_duplicated_by([col("a"), col("b")], UniqueKeepStrategy::None)?.alias("none"),
+        ])
+        .collect()?;
+    let expected = df! {
+        "first" => &[false, false, true, false, true, true],
+        "last" => &[true, true, true, false, false, false],
+        "none" => &[true, true, false, false, true, true]
+    }?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_lazy_encode_rows() -> PolarsResult<()> {
+    let df = df! {
+        "a" => &[Some(2), None, Some(1), Some(2)],
+        "b" => &["x", "y", "z", "w"]
+    }?;
+
+    let out = df
+        .lazy()
+        .with_column(encode_rows([col("a"), col("b")], vec![true, false], vec![true])?.alias("key"))
+        .sort(["key"], Default::default())
+        .select([col("a"), col("b")])
+        .collect()?;
+    let expected = df! {
+        "a" => &[Some(2), Some(2), Some(1), None],
+        "b" => &["w", "x", "z", "y"]
+    }?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "row_hash", feature = "dtype-struct"))]
+fn test_lazy_with_row_hash() -> PolarsResult<()> {
+    let df = df! {
+        "a" => &[Some(2), None, Some(2), Some(2)],
+        "b" => &["x", "y", "x", "w"]
+    }?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .with_row_hash("id", [col("a"), col("b")], 0)?
+        .collect()?;
+    let id = out.column("id")?.u64()?;
+    assert_eq!(id.get(0), id.get(2));
+    assert_ne!(id.get(0), id.get(1));
+    assert_ne!(id.get(0), id.get(3));
+
+    // The ids don't depend on how the rows are split up.
+    let tail = df
+        .slice(2, 2)
+        .lazy()
+        .with_row_hash("id", [col("a"), col("b")], 0)?
+        .collect()?;
+    assert!(tail.equals(&out.slice(2, 2)));
+
+    let other_seed = out
+        .lazy()
+        .with_row_hash("id", [col("a"), col("b")], 1)?
+        .collect()?;
+    assert_ne!(other_seed.column("id")?.u64()?.get(0), id.get(0));
+
+    assert!(
+        df! {"a" => [1]}?
+            .lazy()
+            .with_row_hash("id", Vec::<Expr>::new(), 0)
+            .is_err()
+    );
+    Ok(())
+}
+
 #[test]
 fn test_lazy_udf() {
     let df = get_df();
@@ -1109,6 +1223,188 @@ fn test_multiple_explode() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_explode_with_position() -> PolarsResult<()> {
+    let df = df![
+        "a" => [0, 1, 2, 0, 2],
+        "b" => [5, 4, 3, 2, 1],
+    ]?;
+
+    // The position and row index are not pushed down into the projection or the filter.
+    let out = df
+        .lazy()
+        .group_by_stable([col("a")])
+        .agg([col("b")])
+        .explode_with_position([col("b")], "pos".into(), Some("row".into()))
+        .filter(col("pos").gt(lit(0)))
+        .select([col("row"), col("pos"), col("b")])
+        .collect()?;
+    let expected = df![
+        "row" => [0 as IdxSize, 2],
+        "pos" => [1 as IdxSize, 1],
+        "b" => [2, 1],
+    ]?;
+    assert!(out.equals(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn test_sort_within_partitions() -> PolarsResult<()> {
+    let df = df![
+        "p" => ["a", "a", "b", "b", "b", "a"],
+        "x" => [2, 1, 3, 5, 4, 0],
+        "y" => [1, 2, 3, 4, 5, 6],
+    ]?;
+
+    // The filter is not pushed down, as it would merge the last run with the first.
+    let out = df
+        .lazy()
+        .sort_within_partitions(["p"], ["x"], SortMultipleOptions::new())
+        .filter(col("p").neq(lit("b")))
+        .select([col("y")])
+        .collect()?;
+    let expected = df![
+        "y" => [2, 1, 6],
+    ]?;
+    assert!(out.equals(&expected));
+
+    let out = df
+        .lazy()
+        .sort_within_partitions(Vec::<PlSmallStr>::new(), ["x"], SortMultipleOptions::new())
+        .collect();
+    assert!(matches!(out, Err(PolarsError::InvalidOperation(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_collect_with_backend() -> PolarsResult<()> {
+    use std::sync::Mutex;
+
+    use polars_expr::state::ExecutionState;
+
+    struct ConstExec(DataFrame);
+    impl Executor for ConstExec {
+        fn execute(&mut self, _state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+            Ok(self.0.clone())
+        }
+    }
+
+    // Only supports filters on in-memory frames and returns a fixed result for them.
+    struct FilterBackend {
+        accept: bool,
+        offloaded: Mutex<Vec<&'static str>>,
+    }
+    impl EngineBackend for FilterBackend {
+        fn name(&self) -> &str {
+            "test"
+        }
+
+        fn supports(&self, node: Node, lp_arena: &Arena<IR>, _: &Arena<AExpr>) -> bool {
+            matches!(
+                lp_arena.get(node),
+                IR::Filter { .. } | IR::DataFrameScan { .. }
+            )
+        }
+
+        fn create_executor(
+            &self,
+            root: Node,
+            lp_arena: &Arena<IR>,
+            _: &Arena<AExpr>,
+        ) -> PolarsResult<Option<Box<dyn Executor>>> {
+            self.offloaded
+                .lock()
+                .unwrap()
+                .push(lp_arena.get(root).name());
+            Ok(self
+                .accept
+                .then(|| Box::new(ConstExec(df!["a" => [30, 20]].unwrap())) as _))
+        }
+    }
+
+    let df = df!["a" => [1, 3, 2]]?;
+    let lf = df
+        .lazy()
+        .filter(col("a").gt(lit(1)))
+        .sort(["a"], Default::default());
+
+    let backend = Arc::new(FilterBackend {
+        accept: true,
+        offloaded: Default::default(),
+    });
+    let out = lf.clone().collect_with_backend(backend.clone())?;
+    assert!(out.equals(&df!["a" => [20, 30]]?));
+    assert_eq!(*backend.offloaded.lock().unwrap(), ["selection"]);
+
+    // Declining the filter offers the scan, declining that runs everything on the cpu.
+    let backend = Arc::new(FilterBackend {
+        accept: false,
+        offloaded: Default::default(),
+    });
+    let out = lf.collect_with_backend(backend.clone())?;
+    assert!(out.equals(&df!["a" => [2, 3]]?));
+    assert_eq!(*backend.offloaded.lock().unwrap(), ["selection", "df"]);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(not(target_arch = "wasm32"))]
+fn test_query_scheduler() -> PolarsResult<()> {
+    let scheduler = QueryScheduler::new(SchedulerOptions {
+        max_concurrent_queries: 2,
+        memory_budget: Some(100),
+        ..Default::default()
+    })?;
+
+    let lfs = (0..8)
+        .map(|i| {
+            df!["a" => [i, i + 1]]
+                .unwrap()
+                .lazy()
+                .select([col("a").sum()])
+        })
+        .collect::<Vec<_>>();
+    assert!(QueryScheduler::estimate_memory(&lfs[0])? > 0);
+    let out = scheduler.collect_all(lfs)?;
+    for (i, df) in out.iter().enumerate() {
+        assert!(df.equals(&df!["a" => [2 * i as i32 + 1]]?));
+    }
+
+    // A query that exceeds the budget on its own still runs.
+    let query = scheduler.submit(get_df().lazy(), 1000)?;
+    assert_eq!(query.fetch_blocking()?.shape(), get_df().shape());
+    assert_eq!(scheduler.num_running(), 0);
+    assert_eq!(scheduler.num_queued(), 0);
+
+    assert!(
+        QueryScheduler::new(SchedulerOptions {
+            max_concurrent_queries: 0,
+            ..Default::default()
+        })
+        .is_err()
+    );
+    // The thread pool is already running.
+    let num_threads = polars_core::POOL.current_num_threads();
+    assert!(
+        QueryScheduler::new(SchedulerOptions {
+            max_threads: Some(num_threads + 1),
+            ..Default::default()
+        })
+        .is_err()
+    );
+    assert!(
+        QueryScheduler::new(SchedulerOptions {
+            max_threads: Some(num_threads),
+            ..Default::default()
+        })
+        .is_ok()
+    );
+    Ok(())
+}
+
 #[test]
 fn test_filter_and_alias() -> PolarsResult<()> {
     let df = df![
@@ -1978,3 +2274,435 @@ fn test_over_with_options_empty_join() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_query_audit_hook() -> PolarsResult<()> {
+    use std::sync::Mutex;
+
+    let events = Arc::new(Mutex::new(vec![]));
+    let events_hook = events.clone();
+    set_query_audit_hook(Some(Arc::new(move |event: &QueryAuditEvent| {
+        // Other tests may run queries concurrently.
+        if event.plan.contains("audit_col") {
+            events_hook.lock().unwrap().push(event.clone());
+        }
+    })));
+
+    let df = df!["audit_col" => [1, 2, 3]]?;
+    df.clone()
+        .lazy()
+        .filter(col("audit_col").gt(lit(1)))
+        .collect()?;
+    let lf = df.lazy();
+    LazyFrame::collect_all_with_engine(
+        vec![
+            lf.logical_plan.clone(),
+            lf.clone().head(Some(1)).logical_plan,
+        ],
+        Engine::InMemory,
+        lf.opt_state,
+    )?;
+    set_query_audit_hook(None);
+
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 2);
+    assert!(events[0].plan.contains("FILTER"));
+    assert_eq!(events[0].engine, Engine::InMemory);
+    assert_eq!(events[0].result.as_ref().unwrap(), &[(2, 1)]);
+    assert_eq!(events[1].result.as_ref().unwrap(), &[(3, 1), (1, 1)]);
+    Ok(())
+}
+
+#[test]
+fn test_describe_plan_json() -> PolarsResult<()> {
+    let lf = df!["a" => [1, 2, 3], "b" => [1, 2, 3]]?
+        .lazy()
+        .filter(col("a").gt(lit(1)))
+        .select([col("a")]);
+
+    let json = lf.describe_plan_json(true)?;
+    let nodes: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let nodes = nodes.as_array().unwrap();
+    assert_eq!(nodes[0]["id"], 0);
+    let scan = nodes.last().unwrap();
+    assert_eq!(scan["name"], "df");
+    assert_eq!(scan["inputs"].as_array().unwrap().len(), 0);
+    assert_eq!(scan["pushdown"]["projection"], serde_json::json!(["a"]));
+    assert_eq!(
+        scan["schema"],
+        serde_json::json!([{"name": "a", "dtype": "i32"}])
+    );
+
+    // Node IDs don't change between runs.
+    assert_eq!(json, lf.describe_plan_json(true)?);
+    Ok(())
+}
+
+#[test]
+fn test_to_mermaid() -> PolarsResult<()> {
+    let query = || -> PolarsResult<LazyFrame> {
+        let lf = df!["a" => [1, 2, 3]]?
+            .lazy()
+            .filter(col("a").gt(lit(1)))
+            .cache();
+        concat([lf.clone(), lf], Default::default())
+    };
+
+    let mermaid = query()?.to_mermaid(false)?;
+    assert!(mermaid.starts_with("flowchart BT\n"));
+    // The cache is shown once, with its stable node ID instead of its memory address.
+    assert_eq!(mermaid.matches("CACHE[id: 1, cache_hits: ").count(), 1);
+    assert!(mermaid.contains("  n1 --> n0\n"));
+    // A query that is built anew gets the same flowchart.
+    assert_eq!(mermaid, query()?.to_mermaid(false)?);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "pivot")]
+fn test_crosstab() -> PolarsResult<()> {
+    use crate::frame::pivot::{CrosstabNormalize, CrosstabOptions, crosstab};
+
+    let df = df![
+        "a" => ["x", "x", "y", "y", "y"],
+        "b" => [1, 2, 1, 1, 2],
+        "v" => [1.0, 2.0, 3.0, 4.0, 5.0],
+    ]?;
+
+    let options = CrosstabOptions {
+        margins: true,
+        ..Default::default()
+    };
+    let out = crosstab(&df, ["a"], ["b"], options)?;
+    let expected = df![
+        "a" => ["x", "y", "All"],
+        "1" => [1 as IdxSize, 2, 3],
+        "2" => [1 as IdxSize, 1, 2],
+        "All" => [2 as IdxSize, 3, 5],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let options = CrosstabOptions {
+        normalize: CrosstabNormalize::All,
+        ..Default::default()
+    };
+    let out = crosstab(&df, ["a"], ["b"], options)?;
+    let expected = df![
+        "a" => ["x", "y"],
+        "1" => [0.2, 0.4],
+        "2" => [0.2, 0.2],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let options = CrosstabOptions {
+        agg: Some(col("v").mean()),
+        margins: true,
+        ..Default::default()
+    };
+    let out = crosstab(&df, ["a"], ["b"], options)?;
+    let expected = df![
+        "a" => ["x", "y", "All"],
+        "1" => [1.0, 3.5, 8.0 / 3.0],
+        "2" => [2.0, 5.0, 3.5],
+        "All" => [1.5, 4.0, 3.0],
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "describe", feature = "strings"))]
+fn test_describe() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1), Some(2), Some(3), None],
+        "b" => [Some("x"), Some("yy"), None, None],
+    ]?;
+    let options = DescribeOptions {
+        percentiles: vec![0.5],
+        n_unique: true,
+        ..Default::default()
+    };
+    let out = df.lazy().describe(options)?;
+
+    let expected = df![
+        "statistic" => [
+            "count", "null_count", "null_ratio", "n_unique", "mean", "std", "min", "50%", "max",
+            "min_len", "max_len",
+        ],
+        "a" => [
+            Some(3.0), Some(1.0), Some(0.25), Some(4.0), Some(2.0), Some(1.0), Some(1.0),
+            Some(2.0), Some(3.0), None, None,
+        ],
+        "b" => [
+            Some("2"), Some("2"), Some("0.5"), Some("3"), None, None, Some("x"), None, Some("yy"),
+            Some("1"), Some("2"),
+        ],
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "pivot")]
+fn test_lazy_pivot_declared_columns() -> PolarsResult<()> {
+    let df = df![
+        "k" => ["a", "a", "b", "b", "b"],
+        "on" => ["x", "y", "x", "x", "z"],
+        "v" => [1, 2, 3, 4, 5],
+    ]?;
+    let on_columns = Series::new("on".into(), ["x", "y"]);
+
+    let lf = df.clone().lazy().pivot(
+        "on",
+        &on_columns,
+        ["k"],
+        Some(["v"]),
+        Some(col("").sum()),
+        true,
+        None,
+    )?;
+    // The schema is known without running the query.
+    let schema = lf.clone().collect_schema()?;
+    assert_eq!(
+        schema.iter_names().cloned().collect::<Vec<_>>(),
+        ["k", "x", "y"]
+    );
+
+    let expected = df![
+        "k" => ["a", "b"],
+        "x" => [1, 7],
+        "y" => [Some(2), None],
+    ]?;
+    assert!(lf.collect()?.equals_missing(&expected));
+
+    // `len()` only counts the rows of the output column.
+    let lf = df.lazy().pivot(
+        "on",
+        &on_columns,
+        ["k"],
+        Some(["v"]),
+        Some(len()),
+        true,
+        None,
+    )?;
+    let expected = df![
+        "k" => ["a", "b"],
+        "x" => [1 as IdxSize, 2],
+        "y" => [Some(1 as IdxSize), None],
+    ]?;
+    assert!(lf.collect()?.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_lazy_values() -> PolarsResult<()> {
+    let lookup = values(
+        &[
+            [lit("a"), lit(1.5)],
+            [lit("b"), lit(NULL)],
+            [lit("c"), lit(1) + lit(1)],
+        ],
+        Some(&["key".into(), "weight".into()]),
+    )?;
+    assert!(matches!(lookup.logical_plan, DslPlan::DataFrameScan { .. }));
+    let df = df![
+        "key" => ["c", "a", "d"],
+    ]?;
+    let out = df
+        .lazy()
+        .left_join(lookup, col("key"), col("key"))
+        .sort(["key"], Default::default())
+        .collect()?;
+
+    let expected = df![
+        "key" => ["a", "c", "d"],
+        "weight" => [Some(1.5), Some(2.0), None],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    assert!(values(&[vec![lit(1)], vec![lit(1), lit(2)]], None).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "is_in", feature = "semi_anti_join"))]
+fn test_is_in_subquery() -> PolarsResult<()> {
+    let df = df![
+        "id" => [Some(1), Some(2), None, Some(4), Some(5)],
+        "v" => [10, 20, 30, 40, 50],
+    ]?;
+    let lookup = df![
+        "key" => [1, 4, 5, 6],
+        "flag" => [true, true, false, true],
+    ]?
+    .lazy()
+    .filter(col("flag"))
+    .select([col("key")]);
+
+    let lf = df
+        .clone()
+        .lazy()
+        .filter(
+            col("id")
+                .is_in(lookup.clone().into_subquery()?, false)
+                .and(col("v").gt(lit(10))),
+        )
+        .sort(["id"], Default::default());
+    assert!(lf.describe_optimized_plan()?.contains("SEMI JOIN"));
+    let out = lf.collect()?;
+    assert_eq!(out.column("v")?.i32()?.to_vec(), [Some(40)]);
+
+    // Nulls are filtered, as `is_in` is null for them.
+    let out = df
+        .lazy()
+        .filter(
+            col("id")
+                .is_in(lookup.clone().into_subquery()?, false)
+                .not(),
+        )
+        .sort(["id"], Default::default())
+        .collect()?;
+    assert_eq!(out.column("v")?.i32()?.to_vec(), [Some(20), Some(50)]);
+
+    assert!(lookup.select([col("key"), lit(1)]).into_subquery().is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "range")]
+fn test_scan_int_range_pushdown() -> PolarsResult<()> {
+    // Materializing this range would not fit in memory.
+    let lf = LazyFrame::scan_int_range("i".into(), 0, i64::MAX, 1, DataType::Int64)?;
+
+    let out = lf
+        .clone()
+        .filter(col("i").gt_eq(lit(10)).and(col("i").lt(lit(15))))
+        .collect()?;
+    assert_eq!(
+        out.column("i")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [10, 11, 12, 13, 14]
+    );
+
+    let out = lf.clone().slice(1_000_000_000_000, 2).collect()?;
+    assert_eq!(
+        out.column("i")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [1_000_000_000_000, 1_000_000_000_001]
+    );
+
+    let out = lf.filter(col("i").eq(lit(1i64 << 40))).collect()?;
+    assert_eq!(
+        out.column("i")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [1i64 << 40]
+    );
+
+    let out = LazyFrame::scan_int_range("i".into(), 10, 0, -3, DataType::Int8)?
+        .filter(col("i").lt(lit(8)))
+        .collect()?;
+    assert_eq!(
+        out.column("i")?
+            .i8()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [7, 4, 1]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_scan_rows() -> PolarsResult<()> {
+    let df = df![
+        "account" => ["a", "b", "a", "b", "a"],
+        "amount" => [10i64, 5, -20, 3, 7],
+        "memo" => ["x", "y", "z", "y", "x"],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .scan_rows(
+            ["account"],
+            ["amount", "memo"],
+            0i64,
+            |balance: &mut i64, (amount, memo): (i64, String)| {
+                *balance = (*balance + amount).max(0);
+                (memo != "z").then_some(*balance)
+            },
+            Field::new("balance".into(), DataType::Int64),
+        )
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("balance")?.i64()?),
+        [Some(10), Some(5), None, Some(8), Some(7)]
+    );
+
+    let out = df
+        .lazy()
+        .scan_rows(
+            Vec::<PlSmallStr>::new(),
+            ["amount"],
+            0i64,
+            |total: &mut i64, (amount,): (i64,)| {
+                *total += amount;
+                *total
+            },
+            Field::new("total".into(), DataType::Int64),
+        )
+        .collect()?;
+    assert_eq!(
+        out.column("total")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [10, 15, -5, -2, 5]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_lazy_value_counts() -> PolarsResult<()> {
+    let df = df![
+        "a" => ["x", "y", "x", "x", "y", "z"],
+        "b" => [1, 1, 1, 2, 1, 1],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .value_counts(Some(vec![col("a")]), true, false, None)
+        .collect()?;
+    assert_eq!(out.get_column_names(), ["a", "count"]);
+    assert_eq!(
+        Vec::from(out.column("a")?.str()?),
+        [Some("x"), Some("y"), Some("z")]
+    );
+    assert_eq!(
+        Vec::from(out.column("count")?.idx()?),
+        [Some(3), Some(2), Some(1)]
+    );
+
+    let out = df
+        .lazy()
+        .value_counts(None, true, true, None)
+        .sort(["a", "b"], Default::default())
+        .collect()?;
+    assert_eq!(out.get_column_names(), ["a", "b", "proportion"]);
+    assert_eq!(
+        Vec::from(out.column("proportion")?.f64()?),
+        [
+            Some(2.0 / 6.0),
+            Some(1.0 / 6.0),
+            Some(2.0 / 6.0),
+            Some(1.0 / 6.0)
+        ]
+    );
+    Ok(())
+}
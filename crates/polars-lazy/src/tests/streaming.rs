@@ -417,3 +417,136 @@ fn test_streaming_full_outer_join() -> PolarsResult<()> {
 
     Ok(())
 }
+
+struct SumOfSquares;
+
+impl AggregationUdf for SumOfSquares {
+    type State = f64;
+
+    fn name(&self) -> &str {
+        "sum_of_squares"
+    }
+
+    fn output_dtype(&self, _input_dtype: &DataType) -> PolarsResult<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn init(&self) -> f64 {
+        0.0
+    }
+
+    fn update(&self, state: &mut f64, values: &Series) -> PolarsResult<()> {
+        let values = values.cast(&DataType::Float64)?;
+        *state += values
+            .f64()?
+            .into_no_null_iter()
+            .map(|v| v * v)
+            .sum::<f64>();
+        Ok(())
+    }
+
+    fn merge(&self, state: &mut f64, other: &f64) -> PolarsResult<()> {
+        *state += other;
+        Ok(())
+    }
+
+    fn finalize(&self, state: &f64) -> PolarsResult<AnyValue<'static>> {
+        Ok(AnyValue::Float64(*state))
+    }
+}
+
+#[test]
+fn test_streaming_agg_udf() -> PolarsResult<()> {
+    let q = get_csv_file()
+        .group_by([col("category")])
+        .agg([col("calories").agg_udf(Arc::new(SumOfSquares))])
+        .sort(["category"], Default::default());
+
+    let expected = get_csv_file()
+        .group_by([col("category")])
+        .agg([(col("calories") * col("calories"))
+            .cast(DataType::Float64)
+            .sum()])
+        .sort(["category"], Default::default())
+        .collect()?;
+
+    assert_eq!(q.clone().with_new_streaming(true).collect()?, expected);
+    assert_eq!(q.with_new_streaming(false).collect()?, expected);
+    Ok(())
+}
+
+#[test]
+fn test_streaming_product_geo_harmonic_mean() -> PolarsResult<()> {
+    let q = df![
+        "g" => [1, 1, 1, 2, 2],
+        "a" => [1, 4, 16, 2, 3],
+        "b" => [Some(1.0), Some(4.0), Some(4.0), Some(2.0), None],
+    ]?
+    .lazy()
+    .group_by([col("g")])
+    .agg([
+        col("a").product().alias("product"),
+        col("a").geo_mean().alias("geo_mean"),
+        col("b").harmonic_mean().alias("harmonic_mean"),
+    ])
+    .sort(["g"], Default::default());
+
+    for new_streaming in [true, false] {
+        let out = q.clone().with_new_streaming(new_streaming).collect()?;
+        let product = out.column("product")?.i64()?;
+        assert_eq!(Vec::from(product), [Some(64), Some(6)]);
+        let geo_mean = out.column("geo_mean")?.f64()?;
+        assert!((geo_mean.get(0).unwrap() - 4.0).abs() < 1e-10);
+        assert!((geo_mean.get(1).unwrap() - 6f64.sqrt()).abs() < 1e-10);
+        let harmonic_mean = out.column("harmonic_mean")?.f64()?;
+        assert!((harmonic_mean.get(0).unwrap() - 2.0).abs() < 1e-10);
+        assert_eq!(harmonic_mean.get(1), Some(2.0));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "fill_null_by")]
+fn test_streaming_forward_fill_by_over() -> PolarsResult<()> {
+    // Enough rows to span multiple morsels, so the last valid value of every
+    // group is carried from one morsel to the next.
+    let n = 250_000;
+    let df = df![
+        "g" => (0..n).map(|i| i % 3).collect::<Vec<i32>>(),
+        "t" => (0..n).map(|i| (i + i / 1000) as i64).collect::<Vec<_>>(),
+        "a" => (0..n).map(|i| (i % 13 == 0).then_some(i)).collect::<Vec<_>>(),
+    ]?;
+    let limit = Duration::parse("20i");
+    let q = df.lazy().select([
+        col("a").fill_null_by(col("t"), true, limit).alias("filled"),
+        col("a")
+            .fill_null_by(col("t"), true, limit)
+            .over([col("g")])
+            .alias("filled_over"),
+    ]);
+
+    assert_streaming_with_default(q, false);
+    Ok(())
+}
+
+#[test]
+fn test_streaming_unique_sorted_keys() -> PolarsResult<()> {
+    // Enough rows to span multiple morsels, so that runs of equal keys span
+    // morsel boundaries.
+    let n = 250_000;
+    let df = df![
+        "a" => (0..n).map(|i| i / 1000).collect::<Vec<i32>>(),
+        "b" => (0..n).map(|i| (i / 7) % 3).collect::<Vec<i32>>(),
+        "c" => (0..n).collect::<Vec<i32>>(),
+    ]?;
+    let q = df
+        .lazy()
+        .sort(["a", "b"], Default::default())
+        .unique_stable(
+            Some(vec!["a".into(), "b".into()]),
+            UniqueKeepStrategy::First,
+        );
+
+    assert_streaming_with_default(q, false);
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use std::sync::{Arc, Mutex};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+use super::*;
+
+type SpanFields = PlHashMap<&'static str, String>;
+
+/// Records the name and fields of every span.
+#[derive(Clone, Default)]
+struct SpanRecorder {
+    spans: Arc<Mutex<Vec<(&'static str, SpanFields)>>>,
+}
+
+impl SpanRecorder {
+    /// The fields of the spans called `name` of which `node` is `node`.
+    fn find(&self, name: &str, node: &str) -> Vec<SpanFields> {
+        let node = format!("{node:?}");
+        self.spans
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(span, fields)| *span == name && fields.get("node") == Some(&node))
+            .map(|(_, fields)| fields.clone())
+            .collect()
+    }
+}
+
+struct FieldVisitor<'a>(&'a mut SpanFields);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.insert(field.name(), format!("{value:?}"));
+    }
+}
+
+impl Subscriber for SpanRecorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = SpanFields::default();
+        span.record(&mut FieldVisitor(&mut fields));
+        let mut spans = self.spans.lock().unwrap();
+        spans.push((span.metadata().name(), fields));
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        let mut spans = self.spans.lock().unwrap();
+        values.record(&mut FieldVisitor(
+            &mut spans[span.into_u64() as usize - 1].1,
+        ));
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[test]
+fn test_tracing_spans_in_memory() -> PolarsResult<()> {
+    let recorder = SpanRecorder::default();
+    let q = load_df()
+        .lazy()
+        .with_predicate_pushdown(false)
+        .filter(col("a").gt(lit(1)));
+    let out = tracing::subscriber::with_default(recorder.clone(), || q.collect())?;
+    assert_eq!(out.height(), 4);
+
+    let scan = recorder.find("polars.execute", "df");
+    assert_eq!(scan.len(), 1);
+    assert_eq!(scan[0]["estimated_rows"], "5");
+    assert_eq!(scan[0]["rows"], "5");
+    assert!(scan[0].contains_key("duration_us"));
+
+    let filter = recorder.find("polars.execute", "selection");
+    assert_eq!(filter.len(), 1);
+    assert!(!filter[0].contains_key("estimated_rows"));
+    assert_eq!(filter[0]["rows"], "4");
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_tracing_spans_streaming() -> PolarsResult<()> {
+    let recorder = SpanRecorder::default();
+    let q = load_df()
+        .lazy()
+        .with_new_streaming(true)
+        .with_predicate_pushdown(false)
+        .filter(col("a").gt(lit(1)));
+    let out = tracing::subscriber::with_default(recorder.clone(), || q.collect())?;
+    assert_eq!(out.height(), 4);
+
+    let source = recorder.find("polars.stream.execute", "in-memory-source");
+    assert_eq!(source.len(), 1);
+    assert_eq!(source[0]["estimated_rows"], "5");
+    assert_eq!(source[0]["rows"], "5");
+    assert!(source[0].contains_key("busy_us"));
+    assert!(source[0].contains_key("duration_us"));
+
+    let filter = recorder.find("polars.stream.execute", "filter");
+    assert_eq!(filter.len(), 1);
+    assert_eq!(filter[0]["rows"], "4");
+    Ok(())
+}
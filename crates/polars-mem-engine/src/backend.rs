@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use polars_core::config::verbose;
+use polars_core::prelude::*;
+use polars_plan::prelude::*;
+
+use crate::executors::Executor;
+
+/// An alternative executor for parts of a query, e.g. on an accelerator.
+///
+/// While the physical plan is built, every subtree of the optimized IR that consists only of
+/// scans, filters, projections and group-bys the backend supports is offered to it. The largest
+/// such subtrees are offloaded, everything else keeps running on the CPU with the result of the
+/// offloaded subtrees as input.
+pub trait EngineBackend: Send + Sync {
+    /// Name of the backend, used in verbose output.
+    fn name(&self) -> &str;
+
+    /// Whether the backend supports the node itself. Its inputs are checked separately.
+    fn supports(&self, node: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> bool;
+
+    /// Create the executor for the subtree with root `node`, of which all nodes are supported.
+    ///
+    /// Returning `None` falls back to the CPU for `root`, its inputs are offered again.
+    fn create_executor(
+        &self,
+        root: Node,
+        lp_arena: &Arena<IR>,
+        expr_arena: &Arena<AExpr>,
+    ) -> PolarsResult<Option<Box<dyn Executor>>>;
+}
+
+/// Whether the IR node is of a kind that can be offloaded to an [`EngineBackend`].
+pub fn is_offloadable_ir(ir: &IR) -> bool {
+    matches!(
+        ir,
+        IR::Scan { .. }
+            | IR::DataFrameScan { .. }
+            | IR::Filter { .. }
+            | IR::Select { .. }
+            | IR::SimpleProjection { .. }
+            | IR::HStack { .. }
+            | IR::GroupBy { .. }
+    )
+}
+
+fn supports_subtree(
+    backend: &dyn EngineBackend,
+    node: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    let ir = lp_arena.get(node);
+    is_offloadable_ir(ir)
+        && backend.supports(node, lp_arena, expr_arena)
+        && ir
+            .get_inputs()
+            .iter()
+            .all(|&input| supports_subtree(backend, input, lp_arena, expr_arena))
+}
+
+/// Offload the subtree with root `node` to the backend if it supports all of it.
+pub(crate) fn try_offload(
+    backend: &Arc<dyn EngineBackend>,
+    node: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<Option<Box<dyn Executor>>> {
+    if !supports_subtree(backend.as_ref(), node, lp_arena, expr_arena) {
+        return Ok(None);
+    }
+    let executor = backend.create_executor(node, lp_arena, expr_arena)?;
+    if verbose() {
+        let ir = lp_arena.get(node).name();
+        if executor.is_some() {
+            eprintln!("offloading {ir} to the '{}' backend", backend.name());
+        } else {
+            eprintln!("'{}' backend declined {ir}, running on cpu", backend.name());
+        }
+    }
+    Ok(executor)
+}
@@ -36,7 +36,7 @@ impl Executor for SinkExecutor {
             Cow::Borrowed("")
         };
 
-        state.clone().record(
+        state.clone().record_frame(
             || (self.f)(df, state).map(|df| df.unwrap_or_else(DataFrame::empty)),
             profile_name,
         )
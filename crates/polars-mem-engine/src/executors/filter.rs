@@ -107,7 +107,7 @@ impl Executor for FilterExec {
             Cow::Borrowed("")
         };
 
-        state.clone().record(
+        state.clone().record_frame(
             || {
                 let df = self.execute_impl(df, state);
                 if state.verbose() {
@@ -28,6 +28,8 @@ pub struct GroupByExec {
     maintain_order: bool,
     input_schema: SchemaRef,
     slice: Option<(i64, usize)>,
+    /// Equal keys are contiguous in the input.
+    keys_sorted: bool,
 }
 
 impl GroupByExec {
@@ -40,6 +42,7 @@ impl GroupByExec {
         maintain_order: bool,
         input_schema: SchemaRef,
         slice: Option<(i64, usize)>,
+        keys_sorted: bool,
     ) -> Self {
         Self {
             input,
@@ -49,6 +52,7 @@ impl GroupByExec {
             maintain_order,
             input_schema,
             slice,
+            keys_sorted,
         }
     }
 }
@@ -62,9 +66,15 @@ pub(super) fn group_by_helper(
     state: &ExecutionState,
     maintain_order: bool,
     slice: Option<(i64, usize)>,
+    keys_sorted: bool,
 ) -> PolarsResult<DataFrame> {
     df.as_single_chunk_par();
-    let gb = df.group_by_with_series(keys, true, maintain_order)?;
+    let gb = if keys_sorted {
+        // SAFETY: the optimizer only sets keys_sorted if the input is sorted by the keys.
+        unsafe { df.group_by_sorted_keys(keys)? }
+    } else {
+        df.group_by_with_series(keys, true, maintain_order)?
+    };
 
     if let Some(f) = apply {
         return gb.sliced(slice).apply(move |df| f.call_udf(df));
@@ -108,6 +118,7 @@ impl GroupByExec {
             state,
             self.maintain_order,
             self.slice,
+            self.keys_sorted,
         )
     }
 }
@@ -122,7 +133,11 @@ impl Executor for GroupByExec {
             }
         }
         if state.verbose() {
-            eprintln!("keys/aggregates are not partitionable: running default HASH AGGREGATION")
+            if self.keys_sorted {
+                eprintln!("keys are sorted: running SORTED KEYS AGGREGATION")
+            } else {
+                eprintln!("keys/aggregates are not partitionable: running default HASH AGGREGATION")
+            }
         }
         let df = self.input.execute(state)?;
 
@@ -140,7 +155,7 @@ impl Executor for GroupByExec {
 
         if state.has_node_timer() {
             let new_state = state.clone();
-            new_state.record(|| self.execute_impl(state, df), profile_name)
+            new_state.record_frame(|| self.execute_impl(state, df), profile_name)
         } else {
             self.execute_impl(state, df)
         }
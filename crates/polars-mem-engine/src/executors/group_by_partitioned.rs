@@ -275,6 +275,7 @@ impl PartitionGroupByExec {
                     state,
                     self.maintain_order,
                     self.slice,
+                    false,
                 );
             }
 
@@ -373,7 +374,7 @@ impl Executor for PartitionGroupByExec {
         };
         if state.has_node_timer() {
             let new_state = state.clone();
-            new_state.record(|| self.execute_impl(state, original_df), profile_name)
+            new_state.record_frame(|| self.execute_impl(state, original_df), profile_name)
         } else {
             self.execute_impl(state, original_df)
         }
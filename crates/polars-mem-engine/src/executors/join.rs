@@ -86,7 +86,7 @@ impl Executor for JoinExec {
             Cow::Borrowed("")
         };
 
-        state.record(|| {
+        state.record_frame(|| {
 
             let left_on_series = self
                 .left_on
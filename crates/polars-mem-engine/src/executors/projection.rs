@@ -95,7 +95,7 @@ impl Executor for ProjectionExec {
 
         if state.has_node_timer() {
             let new_state = state.clone();
-            new_state.record(|| self.execute_impl(state, df), profile_name)
+            new_state.record_frame(|| self.execute_impl(state, df), profile_name)
         } else {
             self.execute_impl(state, df)
         }
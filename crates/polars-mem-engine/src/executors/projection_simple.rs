@@ -26,7 +26,7 @@ impl Executor for ProjectionSimple {
         let df = self.input.execute(state)?;
 
         if state.has_node_timer() {
-            state.record(|| self.execute_impl(df, columns.as_slice()), profile_name)
+            state.record_frame(|| self.execute_impl(df, columns.as_slice()), profile_name)
         } else {
             self.execute_impl(df, columns.as_slice())
         }
@@ -47,12 +47,14 @@ pub(crate) struct AnonymousScanExec {
 
 impl Executor for AnonymousScanExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let (offset, n_rows) = match self.unified_scan_args.pre_slice.clone() {
+            Some(Slice::Positive { offset, len }) => (offset, Some(len)),
+            Some(Slice::Negative { .. }) => unreachable!(),
+            None => (0, None),
+        };
         let mut args = AnonymousScanArgs {
-            n_rows: self.unified_scan_args.pre_slice.clone().map(|x| {
-                assert!(matches!(x, Slice::Positive { offset: 0, .. }));
-
-                x.len()
-            }),
+            offset,
+            n_rows,
             with_columns: self.unified_scan_args.projection.clone(),
             schema: self.file_info.schema.clone(),
             output_schema: self.output_schema.clone(),
@@ -63,14 +65,15 @@ impl Executor for AnonymousScanExec {
         }
 
         match (self.function.allows_predicate_pushdown(), &self.predicate) {
-            (true, Some(predicate)) => state.record(
-                || {
-                    args.predicate = predicate.predicate.as_expression().cloned();
-                    self.function.scan(args)
-                },
-                "anonymous_scan".into(),
-            ),
-            (false, Some(predicate)) => state.record(
+            (true, Some(predicate)) if predicate.predicate.as_expression().is_some() => state
+                .record_frame(
+                    || {
+                        args.predicate = predicate.predicate.as_expression().cloned();
+                        self.function.scan(args)
+                    },
+                    "anonymous_scan".into(),
+                ),
+            (_, Some(predicate)) => state.record_frame(
                 || {
                     let mut df = self.function.scan(args)?;
                     let s = predicate.predicate.evaluate(&df, state)?;
@@ -86,7 +89,7 @@ impl Executor for AnonymousScanExec {
                 },
                 "anonymous_scan".into(),
             ),
-            _ => state.record(|| self.function.scan(args), "anonymous_scan".into()),
+            _ => state.record_frame(|| self.function.scan(args), "anonymous_scan".into()),
         }
     }
 }
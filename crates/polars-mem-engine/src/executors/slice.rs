@@ -16,7 +16,7 @@ impl Executor for SliceExec {
         }
         let df = self.input.execute(state)?;
 
-        state.record(
+        state.record_frame(
             || Ok(df.slice(self.offset, self.len as usize)),
             "slice".into(),
         )
@@ -1,3 +1,4 @@
+use polars_core::prelude::sort::_broadcast_bools;
 use polars_utils::format_pl_smallstr;
 
 use super::*;
@@ -19,31 +20,48 @@ impl SortExec {
         df.as_single_chunk_par();
 
         let height = df.height();
+        let mut sort_options = self.sort_options.clone();
+        _broadcast_bools(self.by_column.len(), &mut sort_options.descending);
+        _broadcast_bools(self.by_column.len(), &mut sort_options.nulls_last);
 
-        let by_columns = self
-            .by_column
-            .iter()
-            .enumerate()
-            .map(|(i, e)| {
-                let mut s = e.evaluate(&df, state)?.into_column();
-                // Polars core will try to set the sorted columns as sorted.
-                // This should only be done with simple col("foo") expressions,
-                // therefore we rename more complex expressions so that
-                // polars core does not match these.
-                if !matches!(e.as_expression(), Some(&Expr::Column(_))) {
-                    s.rename(format_pl_smallstr!("_POLARS_SORT_BY_{i}"));
-                }
-                polars_ensure!(
-                    s.len() == height,
-                    ShapeMismatch: "sort expressions must have same \
-                    length as DataFrame, got DataFrame height: {} and Series length: {}",
-                    height, s.len()
-                );
-                Ok(s)
-            })
-            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut by_columns = Vec::with_capacity(self.by_column.len());
+        let mut descending = Vec::with_capacity(self.by_column.len());
+        let mut nulls_last = Vec::with_capacity(self.by_column.len());
+        for (i, e) in self.by_column.iter().enumerate() {
+            let mut s = e.evaluate(&df, state)?.into_column();
+            // Polars core will try to set the sorted columns as sorted.
+            // This should only be done with simple col("foo") expressions,
+            // therefore we rename more complex expressions so that
+            // polars core does not match these.
+            if !matches!(e.as_expression(), Some(&Expr::Column(_))) {
+                s.rename(format_pl_smallstr!("_POLARS_SORT_BY_{i}"));
+            }
+            // A key of a single value, e.g. an aggregation, is equal for all
+            // rows and doesn't change the order.
+            if s.len() == 1 && height != 1 {
+                continue;
+            }
+            polars_ensure!(
+                s.len() == height,
+                ShapeMismatch: "sort expressions must have same \
+                length as DataFrame, got DataFrame height: {} and Series length: {}",
+                height, s.len()
+            );
+            by_columns.push(s);
+            descending.push(sort_options.descending[i]);
+            nulls_last.push(sort_options.nulls_last[i]);
+        }
+        if by_columns.is_empty() {
+            // All keys are constant, which keeps the rows in their order.
+            return Ok(match self.slice {
+                Some((offset, len)) => df.slice(offset, len),
+                None => df,
+            });
+        }
+        sort_options.descending = descending;
+        sort_options.nulls_last = nulls_last;
 
-        df.sort_impl(by_columns, self.sort_options.clone(), self.slice)
+        df.sort_impl(by_columns, sort_options, self.slice)
     }
 }
 
@@ -71,7 +89,7 @@ impl Executor for SortExec {
 
         if state.has_node_timer() {
             let new_state = state.clone();
-            new_state.record(|| self.execute_impl(state, df), profile_name)
+            new_state.record_frame(|| self.execute_impl(state, df), profile_name)
         } else {
             self.execute_impl(state, df)
         }
@@ -0,0 +1,55 @@
+use std::time::Instant;
+
+use tracing::field::Empty;
+
+use super::*;
+
+/// Wraps the executor of an IR node in a `tracing` span that records the
+/// estimated and actual number of rows and the time spent in the node,
+/// including its inputs.
+pub struct TracedExec {
+    pub input: Box<dyn Executor>,
+    pub node: &'static str,
+    pub estimated_rows: Option<usize>,
+}
+
+impl TracedExec {
+    /// Estimate the number of output rows of an IR node from what is known
+    /// before execution.
+    pub fn estimate_rows(lp: &IR) -> Option<usize> {
+        match lp {
+            IR::Scan { file_info, .. } => match file_info.row_estimation {
+                (Some(known), _) => Some(known),
+                (None, usize::MAX) => None,
+                (None, estimated) => Some(estimated),
+            },
+            IR::DataFrameScan { df, .. } => Some(df.height()),
+            IR::Union { options, .. } => options.rows.0.or(Some(options.rows.1)),
+            IR::Slice { len, .. } => Some(*len as usize),
+            _ => None,
+        }
+    }
+}
+
+impl Executor for TracedExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let span = tracing::info_span!(
+            "polars.execute",
+            node = self.node,
+            estimated_rows = self.estimated_rows,
+            rows = Empty,
+            duration_us = Empty,
+        );
+        let _guard = span.enter();
+        let start = Instant::now();
+        let out = self.input.execute(state);
+        span.record("duration_us", start.elapsed().as_micros() as u64);
+        match &out {
+            Ok(df) => {
+                span.record("rows", df.height() as u64);
+            },
+            Err(err) => tracing::error!(parent: &span, error = %err, "node failed"),
+        }
+        out
+    }
+}
@@ -21,6 +21,6 @@ impl Executor for UdfExec {
         } else {
             Cow::Borrowed("")
         };
-        state.record(|| self.function.evaluate(df), profile_name)
+        state.record_frame(|| self.function.evaluate(df), profile_name)
     }
 }
@@ -115,6 +115,8 @@ impl Executor for UnionExec {
         .map(|mut df| {
             if self.options.rechunk {
                 df.as_single_chunk_par();
+            } else if let Some(chunk_size) = self.options.chunk_size {
+                df.align_chunks_to(chunk_size.get());
             }
             df
         })
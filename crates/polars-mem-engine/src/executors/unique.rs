@@ -22,12 +22,17 @@ impl Executor for UniqueExec {
             .map(|v| v.iter().cloned().collect::<Vec<_>>());
         let keep = self.options.keep_strategy;
 
-        state.record(
+        state.record_frame(
             || {
                 if df.is_empty() {
                     return Ok(df);
                 }
 
+                if self.options.keys_sorted {
+                    // SAFETY: the optimizer only sets keys_sorted if the input is sorted by
+                    // the keys.
+                    return unsafe { df.unique_sorted_keys(subset, keep, self.options.slice) };
+                }
                 df.unique_impl(
                     self.options.maintain_order,
                     subset,
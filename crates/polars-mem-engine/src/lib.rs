@@ -1,13 +1,15 @@
+mod backend;
 mod executors;
 mod planner;
 mod predicate;
 mod prelude;
 
+pub use backend::{EngineBackend, is_offloadable_ir};
 pub use executors::Executor;
 #[cfg(feature = "python")]
 pub use planner::python_scan_predicate;
 pub use planner::{
     StreamingExecutorBuilder, create_multiple_physical_plans, create_physical_plan,
-    create_scan_predicate,
+    create_physical_plan_with_backend, create_scan_predicate,
 };
 pub use predicate::ScanPredicate;
@@ -13,6 +13,7 @@ use self::python_dsl::PythonScanSource;
 use super::super::executors::{self, Executor};
 use super::*;
 use crate::ScanPredicate;
+use crate::backend::{EngineBackend, try_offload};
 use crate::executors::{CachePrefiller, SinkExecutor};
 use crate::predicate::PhysicalColumnPredicates;
 
@@ -56,6 +57,7 @@ fn partitionable_gb(
 struct ConversionState {
     has_cache_child: bool,
     has_cache_parent: bool,
+    backend: Option<Arc<dyn EngineBackend>>,
 }
 
 impl ConversionState {
@@ -63,6 +65,7 @@ impl ConversionState {
         Ok(ConversionState {
             has_cache_child: false,
             has_cache_parent: false,
+            backend: None,
         })
     }
 
@@ -80,8 +83,20 @@ pub fn create_physical_plan(
     lp_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
     build_streaming_executor: Option<StreamingExecutorBuilder>,
+) -> PolarsResult<Box<dyn Executor>> {
+    create_physical_plan_with_backend(root, lp_arena, expr_arena, build_streaming_executor, None)
+}
+
+/// Create the physical plan, offloading the parts of the plan that `backend` supports to it.
+pub fn create_physical_plan_with_backend(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    build_streaming_executor: Option<StreamingExecutorBuilder>,
+    backend: Option<Arc<dyn EngineBackend>>,
 ) -> PolarsResult<Box<dyn Executor>> {
     let mut state = ConversionState::new()?;
+    state.backend = backend;
     let mut cache_nodes = Default::default();
     let plan = create_physical_plan_impl(
         root,
@@ -201,8 +216,41 @@ pub fn python_scan_predicate(
     Ok((predicate, predicate_serialized))
 }
 
-#[recursive]
 fn create_physical_plan_impl(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    state: &mut ConversionState,
+    cache_nodes: &mut PlIndexMap<usize, Box<executors::CacheExec>>,
+    build_streaming_executor: Option<StreamingExecutorBuilder>,
+) -> PolarsResult<Box<dyn Executor>> {
+    // Read what we need for the span before the node is taken out of the arena.
+    #[cfg(feature = "tracing")]
+    let (node, estimated_rows) = {
+        let lp = lp_arena.get(root);
+        (lp.name(), executors::TracedExec::estimate_rows(lp))
+    };
+
+    let exec = create_physical_plan_node(
+        root,
+        lp_arena,
+        expr_arena,
+        state,
+        cache_nodes,
+        build_streaming_executor,
+    )?;
+
+    #[cfg(feature = "tracing")]
+    let exec = Box::new(executors::TracedExec {
+        input: exec,
+        node,
+        estimated_rows,
+    });
+    Ok(exec)
+}
+
+#[recursive]
+fn create_physical_plan_node(
     root: Node,
     lp_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
@@ -226,6 +274,12 @@ fn create_physical_plan_impl(
         };
     }
 
+    if let Some(backend) = &state.backend {
+        if let Some(exec) = try_offload(backend, root, lp_arena, expr_arena)? {
+            return Ok(exec);
+        }
+    }
+
     let logical_plan = if state.has_cache_parent || matches!(lp_arena.get(root), IR::Scan { .. }) {
         lp_arena.get(root).clone()
     } else {
@@ -287,7 +341,9 @@ fn create_physical_plan_impl(
                                     ParquetWriter::new(BufWriter::new(writer))
                                         .with_compression(options.compression)
                                         .with_statistics(options.statistics)
-                                        .with_row_group_size(options.row_group_size)
+                                        .with_row_group_size(
+                                            sink_options.batch_size.or(options.row_group_size),
+                                        )
                                         .with_data_page_size(options.data_page_size)
                                         .with_key_value_metadata(options.key_value_metadata.clone())
                                         .finish(&mut df)?;
@@ -295,11 +351,20 @@ fn create_physical_plan_impl(
                                 #[cfg(feature = "ipc")]
                                 FileType::Ipc(options) => {
                                     use polars_io::SerWriter;
-                                    use polars_io::ipc::IpcWriter;
-                                    IpcWriter::new(BufWriter::new(writer))
-                                        .with_compression(options.compression)
-                                        .with_compat_level(options.compat_level)
-                                        .finish(&mut df)?;
+                                    // Every chunk is written as a record batch.
+                                    if let Some(batch_size) = sink_options.batch_size {
+                                        df.align_chunks_to(batch_size);
+                                    }
+                                    let ipc_writer = options
+                                        .to_writer(BufWriter::new(writer))
+                                        .with_compat_level(options.compat_level);
+                                    if options.stream {
+                                        let mut batched = ipc_writer.batched_stream(df.schema())?;
+                                        batched.write_batch(&df)?;
+                                        batched.finish()?;
+                                    } else {
+                                        ipc_writer.finish(&mut df)?;
+                                    }
                                 },
                                 #[cfg(feature = "csv")]
                                 FileType::Csv(options) => {
@@ -359,6 +424,29 @@ fn create_physical_plan_impl(
                         "partition sinks not yet supported in standard engine."
                     )
                 },
+                SinkTypeIR::Callback(CallbackSinkType {
+                    function,
+                    sink_options,
+                }) => {
+                    let function = function.clone();
+                    let chunk_size = sink_options.batch_size;
+                    Ok(Box::new(SinkExecutor {
+                        input,
+                        name: "callback".to_string(),
+                        f: Box::new(move |df, _state| {
+                            let chunk_size = chunk_size.unwrap_or(df.height());
+                            let mut offset = 0;
+                            while offset < df.height() {
+                                let batch = df.slice(offset as i64, chunk_size);
+                                offset += batch.height();
+                                if function(batch)? {
+                                    break;
+                                }
+                            }
+                            Ok(None)
+                        }),
+                    }))
+                },
             }
         },
         SinkMultiple { .. } => {
@@ -691,7 +779,8 @@ fn create_physical_plan_impl(
             }
 
             // We first check if we can partition the group_by on the latest moment.
-            let partitionable = partitionable_gb(&keys, &aggs, &input_schema, expr_arena, &apply);
+            let partitionable = !options.keys_sorted
+                && partitionable_gb(&keys, &aggs, &input_schema, expr_arena, &apply);
             if partitionable {
                 let from_partitioned_ds = (&*lp_arena).iter(input).any(|(_, lp)| {
                     if let Union { options, .. } = lp {
@@ -731,6 +820,7 @@ fn create_physical_plan_impl(
                     maintain_order,
                     input_schema,
                     options.slice,
+                    options.keys_sorted,
                 )))
             }
         },
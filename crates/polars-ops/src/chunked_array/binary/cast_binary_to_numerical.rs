@@ -1,4 +1,10 @@
+#[cfg(all(feature = "binary_encoding", feature = "dtype-array"))]
+use arrow::array::FixedSizeListArray;
+#[cfg(feature = "binary_encoding")]
+use arrow::array::MutableBinaryViewArray;
 use arrow::array::{Array, BinaryViewArray, PrimitiveArray};
+#[cfg(all(feature = "binary_encoding", feature = "dtype-array"))]
+use arrow::bitmap::MutableBitmap;
 use arrow::datatypes::ArrowDataType;
 use arrow::types::NativeType;
 use polars_error::PolarsResult;
@@ -78,3 +84,106 @@ where
         is_little_endian,
     )))
 }
+
+/// Casts a [`BinaryArray`] to a [`FixedSizeListArray`] of `width` primitive values, making
+/// any value that is not exactly `width` values long a Null.
+#[cfg(all(feature = "binary_encoding", feature = "dtype-array"))]
+pub(super) fn cast_binview_to_fixed_size_list_dyn<T>(
+    from: &dyn Array,
+    to: &ArrowDataType,
+    is_little_endian: bool,
+) -> PolarsResult<Box<dyn Array>>
+where
+    T: Cast + NativeType,
+{
+    let from: &BinaryViewArray = from.as_any().downcast_ref().unwrap();
+    let ArrowDataType::FixedSizeList(field, width) = to else {
+        unreachable!()
+    };
+    let width = *width;
+    let item_size = size_of::<T>();
+
+    let mut values = Vec::with_capacity(from.len() * width);
+    let mut validity = MutableBitmap::with_capacity(from.len());
+    for opt_bytes in from.iter() {
+        match opt_bytes {
+            Some(bytes) if bytes.len() == width * item_size => {
+                values.extend(bytes.chunks_exact(item_size).map(|b| {
+                    let v = if is_little_endian {
+                        T::cast_le(b)
+                    } else {
+                        T::cast_be(b)
+                    };
+                    v.unwrap()
+                }));
+                validity.push(true);
+            },
+            _ => {
+                values.extend(std::iter::repeat_n(T::default(), width));
+                validity.push(false);
+            },
+        }
+    }
+
+    let values = PrimitiveArray::<T>::from_vec(values).to(field.dtype().clone());
+    Ok(Box::new(FixedSizeListArray::try_new(
+        to.clone(),
+        from.len(),
+        values.boxed(),
+        validity.into(),
+    )?))
+}
+
+/// Writes every value of a [`PrimitiveArray`] as its bytes to a [`BinaryViewArray`].
+#[cfg(feature = "binary_encoding")]
+pub(super) fn cast_primitive_to_binview_dyn<T: NativeType>(
+    from: &dyn Array,
+    is_little_endian: bool,
+) -> BinaryViewArray {
+    let from: &PrimitiveArray<T> = from.as_any().downcast_ref().unwrap();
+    let mut out = MutableBinaryViewArray::<[u8]>::with_capacity(from.len());
+    for opt_v in from.iter() {
+        out.push(opt_v.map(|v| {
+            if is_little_endian {
+                v.to_le_bytes()
+            } else {
+                v.to_be_bytes()
+            }
+        }));
+    }
+    out.freeze()
+}
+
+/// Writes every list of a [`FixedSizeListArray`] of primitive values as the concatenation
+/// of their bytes to a [`BinaryViewArray`]. Lists that contain a Null become Null.
+#[cfg(all(feature = "binary_encoding", feature = "dtype-array"))]
+pub(super) fn cast_fixed_size_list_to_binview_dyn<T: NativeType>(
+    from: &dyn Array,
+    is_little_endian: bool,
+) -> BinaryViewArray {
+    let from: &FixedSizeListArray = from.as_any().downcast_ref().unwrap();
+    let values: &PrimitiveArray<T> = from.values().as_any().downcast_ref().unwrap();
+    let width = from.size();
+
+    let mut out = MutableBinaryViewArray::<[u8]>::with_capacity(from.len());
+    let mut buf = Vec::with_capacity(width * size_of::<T>());
+    for i in 0..from.len() {
+        let start = i * width;
+        if !from.is_valid(i)
+            || values.has_nulls() && (start..start + width).any(|j| values.is_null(j))
+        {
+            out.push_null();
+            continue;
+        }
+        buf.clear();
+        for v in &values.values()[start..start + width] {
+            if is_little_endian {
+                buf.extend_from_slice(v.to_le_bytes().as_ref());
+            } else {
+                buf.extend_from_slice(v.to_be_bytes().as_ref());
+            }
+        }
+        out.push_value(&buf);
+    }
+    out.freeze()
+}
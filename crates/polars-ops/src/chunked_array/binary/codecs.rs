@@ -0,0 +1,225 @@
+//! Binary-to-text encodings and per-value compression codecs that are not
+//! provided by an external crate.
+#[cfg(feature = "binary_compression")]
+use std::io::{Read, Write};
+
+#[cfg(feature = "binary_compression")]
+use polars_core::prelude::*;
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const BASE85_ALPHABET: &[u8; 85] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz!#$%&()*+-;<=>?@^_`{|}~";
+
+/// Encode with the standard base32 alphabet of RFC 4648, including padding.
+pub fn base32_encode(input: &[u8]) -> String {
+    let mut out = String::with_capacity(input.len().div_ceil(5) * 8);
+    for chunk in input.chunks(5) {
+        let mut buf = [0u8; 5];
+        buf[..chunk.len()].copy_from_slice(chunk);
+        let bits = buf.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64);
+        let n_chars = (chunk.len() * 8).div_ceil(5);
+        for i in 0..8 {
+            if i < n_chars {
+                let idx = (bits >> (35 - i * 5)) & 0x1f;
+                out.push(BASE32_ALPHABET[idx as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// Decode base32 (RFC 4648). Padding is optional. Returns `None` if the input
+/// is not valid base32.
+pub fn base32_decode(input: &[u8]) -> Option<Vec<u8>> {
+    let end = input
+        .iter()
+        .rposition(|c| *c != b'=')
+        .map_or(0, |pos| pos + 1);
+    let (data, padding) = input.split_at(end);
+    if !padding.is_empty() && input.len() % 8 != 0 {
+        return None;
+    }
+    let mut out = Vec::with_capacity(data.len() * 5 / 8);
+    for chunk in data.chunks(8) {
+        let n_bytes = match chunk.len() {
+            8 => 5,
+            7 => 4,
+            5 => 3,
+            4 => 2,
+            2 => 1,
+            _ => return None,
+        };
+        let mut bits = 0u64;
+        for i in 0..8 {
+            let v = match chunk.get(i) {
+                Some(c) => BASE32_ALPHABET.iter().position(|a| a == c)? as u64,
+                None => 0,
+            };
+            bits = (bits << 5) | v;
+        }
+        out.extend_from_slice(&bits.to_be_bytes()[3..3 + n_bytes]);
+    }
+    Some(out)
+}
+
+/// Encode with the base85 alphabet of RFC 1924 (as used by git and Python's
+/// `base64.b85encode`), without padding.
+pub fn base85_encode(input: &[u8]) -> String {
+    let mut out = String::with_capacity(input.len().div_ceil(4) * 5);
+    for chunk in input.chunks(4) {
+        let mut buf = [0u8; 4];
+        buf[..chunk.len()].copy_from_slice(chunk);
+        let mut value = u32::from_be_bytes(buf);
+        let mut digits = [0u8; 5];
+        for d in digits.iter_mut().rev() {
+            *d = BASE85_ALPHABET[(value % 85) as usize];
+            value /= 85;
+        }
+        out.extend(digits[..chunk.len() + 1].iter().map(|d| *d as char));
+    }
+    out
+}
+
+/// Decode base85 (RFC 1924). Returns `None` if the input is not valid base85.
+pub fn base85_decode(input: &[u8]) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(input.len() * 4 / 5);
+    for chunk in input.chunks(5) {
+        if chunk.len() == 1 {
+            return None;
+        }
+        let mut value = 0u32;
+        for i in 0..5 {
+            let digit = match chunk.get(i) {
+                Some(c) => BASE85_ALPHABET.iter().position(|a| a == c)? as u32,
+                None => 84,
+            };
+            value = value.checked_mul(85)?.checked_add(digit)?;
+        }
+        out.extend_from_slice(&value.to_be_bytes()[..chunk.len() - 1]);
+    }
+    Some(out)
+}
+
+/// Compression algorithm of [`compress`] and [`decompress`].
+#[cfg(feature = "binary_compression")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BinaryCompression {
+    /// A zstd frame.
+    Zstd,
+    /// A gzip member (RFC 1952).
+    Gzip,
+    /// An LZ4 frame.
+    Lz4,
+}
+
+#[cfg(feature = "binary_compression")]
+fn io_err(algo: BinaryCompression, e: std::io::Error) -> PolarsError {
+    polars_err!(ComputeError: "{:?} (de)compression failed: {}", algo, e)
+}
+
+/// Compress a single value with the given algorithm and optional level.
+#[cfg(feature = "binary_compression")]
+pub fn compress(
+    input: &[u8],
+    algo: BinaryCompression,
+    level: Option<i32>,
+) -> PolarsResult<Vec<u8>> {
+    match algo {
+        BinaryCompression::Zstd => {
+            zstd::stream::encode_all(input, level.unwrap_or(zstd::DEFAULT_COMPRESSION_LEVEL))
+                .map_err(|e| io_err(algo, e))
+        },
+        BinaryCompression::Gzip => {
+            let level = match level {
+                None => flate2::Compression::default(),
+                Some(level @ 0..=9) => flate2::Compression::new(level as u32),
+                Some(level) => {
+                    polars_bail!(InvalidOperation: "gzip compression level must be in 0..=9, got {}", level)
+                },
+            };
+            let mut encoder = flate2::write::GzEncoder::new(Vec::new(), level);
+            encoder.write_all(input).map_err(|e| io_err(algo, e))?;
+            encoder.finish().map_err(|e| io_err(algo, e))
+        },
+        BinaryCompression::Lz4 => {
+            polars_ensure!(level.is_none(), InvalidOperation: "lz4 compression does not support a level");
+            let mut encoder = lz4_flex::frame::FrameEncoder::new(Vec::new());
+            encoder.write_all(input).map_err(|e| io_err(algo, e))?;
+            encoder
+                .finish()
+                .map_err(|e| io_err(algo, std::io::Error::other(e)))
+        },
+    }
+}
+
+/// Decompress a single value that was compressed with the given algorithm.
+#[cfg(feature = "binary_compression")]
+pub fn decompress(input: &[u8], algo: BinaryCompression) -> PolarsResult<Vec<u8>> {
+    let mut out = Vec::new();
+    match algo {
+        BinaryCompression::Zstd => {
+            zstd::stream::copy_decode(input, &mut out).map_err(|e| io_err(algo, e))?;
+        },
+        BinaryCompression::Gzip => {
+            flate2::read::MultiGzDecoder::new(input)
+                .read_to_end(&mut out)
+                .map_err(|e| io_err(algo, e))?;
+        },
+        BinaryCompression::Lz4 => {
+            lz4_flex::frame::FrameDecoder::new(input)
+                .read_to_end(&mut out)
+                .map_err(|e| io_err(algo, e))?;
+        },
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_base32_base85() {
+        for (input, b32) in [
+            ("", ""),
+            ("f", "MY======"),
+            ("fo", "MZXQ===="),
+            ("foo", "MZXW6==="),
+            ("foob", "MZXW6YQ="),
+            ("fooba", "MZXW6YTB"),
+            ("foobar", "MZXW6YTBOI======"),
+        ] {
+            assert_eq!(base32_encode(input.as_bytes()), b32);
+            assert_eq!(base32_decode(b32.as_bytes()).unwrap(), input.as_bytes());
+        }
+        assert_eq!(base32_decode(b"MZXW6YQ").unwrap(), b"foob");
+        assert!(base32_decode(b"MZXW6YQ1").is_none());
+
+        assert_eq!(base85_encode(b"hello world"), "Xk~0{Zy<MXa%^M");
+        for input in ["", "a", "ab", "abc", "abcd", "hello world"] {
+            let encoded = base85_encode(input.as_bytes());
+            assert_eq!(base85_decode(encoded.as_bytes()).unwrap(), input.as_bytes());
+        }
+        assert!(base85_decode(b"Xk~0{Z").is_none());
+        assert!(base85_decode(b"\"\"\"\"\"").is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "binary_compression")]
+    fn test_compression_roundtrip() -> PolarsResult<()> {
+        let input = b"polars polars polars polars polars";
+        for algo in [
+            BinaryCompression::Zstd,
+            BinaryCompression::Gzip,
+            BinaryCompression::Lz4,
+        ] {
+            let compressed = compress(input, algo, None)?;
+            assert_eq!(decompress(&compressed, algo)?, input);
+            assert!(decompress(input, algo).is_err());
+        }
+        Ok(())
+    }
+}
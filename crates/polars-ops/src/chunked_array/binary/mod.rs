@@ -1,9 +1,22 @@
 mod cast_binary_to_numerical;
+#[cfg(any(
+    feature = "binary_encoding",
+    feature = "string_encoding",
+    feature = "binary_compression"
+))]
+pub mod codecs;
 mod namespace;
+#[cfg(feature = "uuid")]
+mod uuid;
 
+#[cfg(feature = "binary_compression")]
+pub use codecs::BinaryCompression;
 pub use namespace::*;
 use polars_core::prelude::*;
 
+#[cfg(feature = "uuid")]
+pub use self::uuid::*;
+
 pub trait AsBinary {
     fn as_binary(&self) -> &BinaryChunked;
 }
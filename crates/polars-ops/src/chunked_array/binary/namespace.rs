@@ -1,4 +1,4 @@
-#[cfg(feature = "binary_encoding")]
+#[cfg(any(feature = "binary_encoding", feature = "binary_compression"))]
 use std::borrow::Cow;
 
 use arrow::with_match_primitive_type;
@@ -11,6 +11,14 @@ use polars_compute::size::binary_size_bytes;
 use polars_core::prelude::arity::{broadcast_binary_elementwise_values, unary_elementwise_values};
 
 use super::cast_binary_to_numerical::cast_binview_to_primitive_dyn;
+#[cfg(feature = "binary_encoding")]
+use super::cast_binary_to_numerical::cast_primitive_to_binview_dyn;
+#[cfg(all(feature = "binary_encoding", feature = "dtype-array"))]
+use super::cast_binary_to_numerical::{
+    cast_binview_to_fixed_size_list_dyn, cast_fixed_size_list_to_binview_dyn,
+};
+#[cfg(any(feature = "binary_encoding", feature = "binary_compression"))]
+use super::codecs;
 use super::*;
 
 pub trait BinaryNameSpaceImpl: AsBinary {
@@ -154,12 +162,94 @@ pub trait BinaryNameSpaceImpl: AsBinary {
         }
     }
 
+    #[cfg(feature = "binary_encoding")]
+    fn base32_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
+        decode_with(self.as_binary(), codecs::base32_decode, "base32", strict)
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn base32_encode(&self) -> Series {
+        let ca = self.as_binary();
+        unsafe {
+            ca.apply_values(|s| codecs::base32_encode(s).into_bytes().into())
+                .cast_unchecked(&DataType::String)
+                .unwrap()
+        }
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn base85_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
+        decode_with(self.as_binary(), codecs::base85_decode, "base85", strict)
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn base85_encode(&self) -> Series {
+        let ca = self.as_binary();
+        unsafe {
+            ca.apply_values(|s| codecs::base85_encode(s).into_bytes().into())
+                .cast_unchecked(&DataType::String)
+                .unwrap()
+        }
+    }
+
+    /// Compress every value on its own with the given algorithm.
+    #[cfg(feature = "binary_compression")]
+    fn compress(&self, algo: BinaryCompression, level: Option<i32>) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_binary();
+        ca.try_apply_nonnull_values_generic(|s| codecs::compress(s, algo, level))
+    }
+
+    /// Decompress every value on its own with the given algorithm. If `strict`
+    /// is `false`, values that fail to decompress become null.
+    #[cfg(feature = "binary_compression")]
+    fn decompress(&self, algo: BinaryCompression, strict: bool) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_binary();
+        if strict {
+            ca.try_apply_nonnull_values_generic(|s| codecs::decompress(s, algo))
+        } else {
+            Ok(ca.apply(|opt_s| {
+                opt_s.and_then(|s| codecs::decompress(s, algo).ok().map(Cow::Owned))
+            }))
+        }
+    }
+
+    /// Reinterpret the bytes of every value as a numeric value, or as a fixed-size array
+    /// of numeric values if `dtype` is an `Array`. Values whose length doesn't match the
+    /// size of `dtype` become null.
     #[cfg(feature = "binary_encoding")]
     #[allow(clippy::wrong_self_convention)]
     fn from_buffer(&self, dtype: &DataType, is_little_endian: bool) -> PolarsResult<Series> {
         let ca = self.as_binary();
         let arrow_type = dtype.to_arrow(CompatLevel::newest());
 
+        #[cfg(feature = "dtype-array")]
+        if let DataType::Array(inner, _) = dtype {
+            polars_ensure!(
+                inner.is_primitive_numeric(),
+                InvalidOperation: "unsupported data type {} in from_buffer. Only arrays of numerical types are allowed.", dtype
+            );
+            let arrow_inner = inner.to_arrow(CompatLevel::newest());
+            let arrow::datatypes::PhysicalType::Primitive(ty) = arrow_inner.to_physical_type()
+            else {
+                unreachable!()
+            };
+            return with_match_primitive_type!(ty, |$T| {
+                unsafe {
+                    Ok(Series::from_chunks_and_dtype_unchecked(
+                        ca.name().clone(),
+                        ca.chunks().iter().map(|chunk| {
+                            cast_binview_to_fixed_size_list_dyn::<$T>(
+                                &**chunk,
+                                &arrow_type,
+                                is_little_endian,
+                            )
+                        }).collect::<PolarsResult<Vec<_>>>()?,
+                        dtype
+                    ))
+                }
+            });
+        }
+
         match arrow_type.to_physical_type() {
             arrow::datatypes::PhysicalType::Primitive(ty) => {
                 with_match_primitive_type!(ty, |$T| {
@@ -185,4 +275,104 @@ pub trait BinaryNameSpaceImpl: AsBinary {
     }
 }
 
+/// Write the bytes of every numeric value, or of every fixed-size array of numeric
+/// values, to a binary value. This is the inverse of
+/// [`BinaryNameSpaceImpl::from_buffer`]. Arrays that contain a null become null.
+#[cfg(feature = "binary_encoding")]
+pub fn to_buffer(s: &Series, is_little_endian: bool) -> PolarsResult<BinaryChunked> {
+    let chunks: Vec<ArrayRef> = match s.dtype() {
+        dt if dt.is_primitive_numeric() => {
+            let arrow_type = dt.to_arrow(CompatLevel::newest());
+            let arrow::datatypes::PhysicalType::Primitive(ty) = arrow_type.to_physical_type()
+            else {
+                unreachable!()
+            };
+            with_match_primitive_type!(ty, |$T| {
+                s.chunks()
+                    .iter()
+                    .map(|chunk| cast_primitive_to_binview_dyn::<$T>(&**chunk, is_little_endian).boxed())
+                    .collect()
+            })
+        },
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(inner, _) if inner.is_primitive_numeric() => {
+            let arrow_inner = inner.to_arrow(CompatLevel::newest());
+            let arrow::datatypes::PhysicalType::Primitive(ty) = arrow_inner.to_physical_type()
+            else {
+                unreachable!()
+            };
+            with_match_primitive_type!(ty, |$T| {
+                s.chunks()
+                    .iter()
+                    .map(|chunk| cast_fixed_size_list_to_binview_dyn::<$T>(&**chunk, is_little_endian).boxed())
+                    .collect()
+            })
+        },
+        dt => polars_bail!(
+            InvalidOperation: "unsupported data type {} in to_buffer. Only numerical types and arrays of numerical types are allowed.", dt
+        ),
+    };
+    Ok(unsafe { BinaryChunked::from_chunks(s.name().clone(), chunks) })
+}
+
+#[cfg(feature = "binary_encoding")]
+fn decode_with(
+    ca: &BinaryChunked,
+    decode: fn(&[u8]) -> Option<Vec<u8>>,
+    name: &str,
+    strict: bool,
+) -> PolarsResult<BinaryChunked> {
+    if strict {
+        ca.try_apply_nonnull_values_generic(|s| {
+            decode(s).ok_or_else(|| {
+                polars_err!(
+                    ComputeError:
+                    "invalid `{}` encoding found; try setting `strict=false` to ignore", name
+                )
+            })
+        })
+    } else {
+        Ok(ca.apply(|opt_s| opt_s.and_then(|s| decode(s).map(Cow::Owned))))
+    }
+}
+
 impl BinaryNameSpaceImpl for BinaryChunked {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(all(feature = "binary_encoding", feature = "dtype-array"))]
+    fn test_buffer_roundtrip() -> PolarsResult<()> {
+        let bytes: Vec<u8> = [1.0f32, 2.5, -3.0, 0.25]
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+        let ca = BinaryChunked::new(
+            "a".into(),
+            &[
+                Some(&bytes[..8]),
+                Some(&bytes[8..]),
+                Some(&bytes[..4]),
+                None,
+            ],
+        );
+
+        let dtype = DataType::Array(Box::new(DataType::Float32), 2);
+        let out = ca.from_buffer(&dtype, true)?;
+        assert_eq!(out.dtype(), &dtype);
+        assert_eq!(out.null_count(), 2);
+        let first = out.array()?.get_as_series(1).unwrap();
+        assert_eq!(Vec::from(first.f32()?), &[Some(-3.0), Some(0.25)]);
+
+        let back = to_buffer(&out, true)?;
+        assert_eq!(back.get(0), Some(&bytes[..8]));
+        assert_eq!(back.get(1), Some(&bytes[8..]));
+        assert_eq!(back.null_count(), 2);
+
+        let be = to_buffer(&first, false)?;
+        assert_eq!(be.get(1), Some(&0.25f32.to_be_bytes()[..]));
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+use arrow::array::{MutableBinaryViewArray, MutablePlString};
+use polars_core::prelude::*;
+use uuid::Uuid;
+
+/// Width in bytes of the binary representation of a UUID.
+pub const UUID_WIDTH: usize = 16;
+
+/// The version of newly generated UUIDs.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UuidVersion {
+    /// Random UUIDs.
+    V4,
+    /// UUIDs that start with a millisecond timestamp, so that they sort by
+    /// creation time. UUIDs generated within the same millisecond are still
+    /// increasing.
+    V7,
+}
+
+/// Generate `n` new UUIDs as lowercase hyphenated strings.
+///
+/// Use [`str_to_uuid`] for their 16-byte binary representation.
+pub fn uuid_generate(name: PlSmallStr, n: usize, version: UuidVersion) -> StringChunked {
+    let mut out = MutablePlString::with_capacity(n);
+    let mut buf = Uuid::encode_buffer();
+    for _ in 0..n {
+        let uuid = match version {
+            UuidVersion::V4 => Uuid::new_v4(),
+            UuidVersion::V7 => Uuid::now_v7(),
+        };
+        out.push_value(&*uuid.hyphenated().encode_lower(&mut buf));
+    }
+    StringChunked::with_chunk(name, out.freeze())
+}
+
+/// Parse UUID strings to their 16-byte binary representation.
+///
+/// The hyphenated, simple, braced and URN formats are accepted. If `strict` is
+/// `false` invalid UUIDs become null.
+pub fn str_to_uuid(ca: &StringChunked, strict: bool) -> PolarsResult<BinaryChunked> {
+    let mut out = MutableBinaryViewArray::<[u8]>::with_capacity(ca.len());
+    for opt_s in ca.iter() {
+        let Some(s) = opt_s else {
+            out.push_null();
+            continue;
+        };
+        match Uuid::try_parse(s) {
+            Ok(uuid) => out.push_value(uuid.as_bytes()),
+            Err(_) if !strict => out.push_null(),
+            Err(e) => {
+                polars_bail!(ComputeError: "invalid UUID '{}': {}; try setting `strict=false` to ignore", s, e)
+            },
+        }
+    }
+    Ok(BinaryChunked::with_chunk(ca.name().clone(), out.freeze()))
+}
+
+/// Format 16-byte binary UUIDs as lowercase hyphenated strings.
+///
+/// If `strict` is `false` values that are not 16 bytes long become null.
+pub fn uuid_to_string(ca: &BinaryChunked, strict: bool) -> PolarsResult<StringChunked> {
+    let mut out = MutablePlString::with_capacity(ca.len());
+    let mut buf = Uuid::encode_buffer();
+    for opt_b in ca.iter() {
+        let Some(b) = opt_b else {
+            out.push_null();
+            continue;
+        };
+        match Uuid::from_slice(b) {
+            Ok(uuid) => out.push_value(&*uuid.hyphenated().encode_lower(&mut buf)),
+            Err(_) if !strict => out.push_null(),
+            Err(_) => polars_bail!(
+                ComputeError: "invalid UUID of {} bytes, expected {}; try setting `strict=false` to ignore", b.len(), UUID_WIDTH
+            ),
+        }
+    }
+    Ok(StringChunked::with_chunk(ca.name().clone(), out.freeze()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_uuid() -> PolarsResult<()> {
+        let ca = StringChunked::new(
+            "a".into(),
+            &[
+                Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+                Some("{67E55044-10B1-426F-9247-BB680E5FE0C8}"),
+                Some("not a uuid"),
+                None,
+            ],
+        );
+        assert!(str_to_uuid(&ca, true).is_err());
+
+        let bin = str_to_uuid(&ca, false)?;
+        assert_eq!(bin.get(0).unwrap().len(), UUID_WIDTH);
+        assert_eq!(bin.get(0), bin.get(1));
+        assert_eq!(bin.null_count(), 2);
+
+        let out = uuid_to_string(&bin, true)?;
+        assert_eq!(out.get(1), Some("67e55044-10b1-426f-9247-bb680e5fe0c8"));
+        assert_eq!(out.null_count(), 2);
+
+        let short = BinaryChunked::new("a".into(), &[&b"abc"[..]]);
+        assert!(uuid_to_string(&short, true).is_err());
+        assert_eq!(uuid_to_string(&short, false)?.null_count(), 1);
+
+        let v7 = uuid_generate("a".into(), 100, UuidVersion::V7);
+        assert_eq!(v7.len(), 100);
+        assert!(v7.iter().is_sorted());
+        assert_eq!(str_to_uuid(&v7, true)?.null_count(), 0);
+        assert_eq!(v7.get(0).unwrap().len(), 36);
+        let v4 = uuid_generate("a".into(), 2, UuidVersion::V4);
+        assert_ne!(v4.get(0), v4.get(1));
+        Ok(())
+    }
+}
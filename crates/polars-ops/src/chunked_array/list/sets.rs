@@ -11,6 +11,7 @@ use arrow::offset::OffsetsBuffer;
 use arrow::types::NativeType;
 use polars_core::prelude::*;
 use polars_core::with_match_physical_numeric_type;
+use polars_utils::nulls::IsNull;
 use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash, TotalOrdWrap};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -47,21 +48,155 @@ impl<'a> MaterializeValues<Option<&'a [u8]>> for MutablePlBinary {
     }
 }
 
+/// Buffers of [`ordered_set_operation`] that are reused between rows.
+struct Scratch<K> {
+    a: Vec<K>,
+    b: Vec<K>,
+    out: Vec<K>,
+    counts: PlHashMap<K, usize>,
+    seen: PlHashSet<K>,
+}
+
+impl<K> Default for Scratch<K> {
+    fn default() -> Self {
+        Self {
+            a: Vec::new(),
+            b: Vec::new(),
+            out: Vec::new(),
+            counts: Default::default(),
+            seen: Default::default(),
+        }
+    }
+}
+
+/// Set operation that walks the left and then the right values, so the output keeps their
+/// order. Supports multisets and all [`SetNullBehavior`]s.
+fn ordered_set_operation<K, I, J, R>(
+    scratch: &mut Scratch<K>,
+    a: I,
+    b: J,
+    out: &mut R,
+    set_op: SetOperation,
+    options: SetOperationOptions,
+) -> usize
+where
+    K: Eq + Hash + Copy + IsNull,
+    I: IntoIterator<Item = K>,
+    J: IntoIterator<Item = K>,
+    R: MaterializeValues<K>,
+{
+    let ignore_nulls = options.nulls == SetNullBehavior::Ignore;
+    let distinct_nulls = options.nulls == SetNullBehavior::Distinct;
+    let Scratch {
+        a: values_a,
+        b: values_b,
+        out: buf,
+        counts,
+        seen,
+    } = scratch;
+    values_a.clear();
+    values_a.extend(a.into_iter().filter(|v| !(ignore_nulls && v.is_null())));
+    values_b.clear();
+    values_b.extend(b.into_iter().filter(|v| !(ignore_nulls && v.is_null())));
+    seen.clear();
+
+    // Distinct nulls never match anything, so they are neither counted nor deduplicated.
+    let matchable = |v: &K| !(distinct_nulls && v.is_null());
+    let count = |counts: &mut PlHashMap<K, usize>, values: &[K]| {
+        counts.clear();
+        for v in values.iter().filter(|v| matchable(v)) {
+            *counts.entry(*v).or_insert(0) += 1;
+        }
+    };
+    // For multisets every match consumes an occurrence.
+    let contains = |counts: &mut PlHashMap<K, usize>, v: &K| match counts.get_mut(v) {
+        Some(n) if *n > 0 => {
+            if options.multiset {
+                *n -= 1;
+            }
+            true
+        },
+        _ => false,
+    };
+    let emit = |buf: &mut Vec<K>, seen: &mut PlHashSet<K>, v: K| {
+        if options.multiset || !matchable(&v) || seen.insert(v) {
+            buf.push(v)
+        }
+    };
+
+    match set_op {
+        SetOperation::Intersection => {
+            count(counts, values_b);
+            for &v in values_a.iter() {
+                if contains(counts, &v) {
+                    emit(buf, seen, v)
+                }
+            }
+        },
+        SetOperation::Union => {
+            count(counts, values_a);
+            for &v in values_a.iter() {
+                emit(buf, seen, v)
+            }
+            for &v in values_b.iter() {
+                if !contains(counts, &v) {
+                    emit(buf, seen, v)
+                }
+            }
+        },
+        SetOperation::Difference => {
+            count(counts, values_b);
+            for &v in values_a.iter() {
+                if !contains(counts, &v) {
+                    emit(buf, seen, v)
+                }
+            }
+        },
+        SetOperation::SymmetricDifference => {
+            count(counts, values_b);
+            for &v in values_a.iter() {
+                if !contains(counts, &v) {
+                    emit(buf, seen, v)
+                }
+            }
+            count(counts, values_a);
+            for &v in values_b.iter() {
+                if !contains(counts, &v) {
+                    emit(buf, seen, v)
+                }
+            }
+        },
+    }
+    out.extend_buf(buf.drain(..))
+}
+
+#[allow(clippy::too_many_arguments)]
 fn set_operation<K, I, J, R>(
     set: &mut PlIndexSet<K>,
     set2: &mut PlIndexSet<K>,
+    scratch: &mut Scratch<K>,
     a: I,
     b: J,
     out: &mut R,
     set_op: SetOperation,
+    options: SetOperationOptions,
     broadcast_rhs: bool,
 ) -> usize
 where
-    K: Eq + Hash + Copy,
+    K: Eq + Hash + Copy + IsNull,
     I: IntoIterator<Item = K>,
     J: IntoIterator<Item = K>,
     R: MaterializeValues<K>,
 {
+    // The hash set operations below keep the order of the left values, except for the
+    // difference.
+    if options.multiset
+        || options.nulls != SetNullBehavior::Equal
+        || (options.maintain_order && set_op == SetOperation::Difference)
+    {
+        return ordered_set_operation(scratch, a, b, out, set_op, options);
+    }
+
     set.clear();
     let a = a.into_iter();
     let b = b.into_iter();
@@ -117,6 +252,38 @@ pub enum SetOperation {
     SymmetricDifference,
 }
 
+/// How a list set operation treats null elements.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SetNullBehavior {
+    /// Null elements are equal to each other.
+    #[default]
+    Equal,
+    /// Null elements are never equal to each other, so an intersection drops them and every
+    /// other operation keeps all of them.
+    Distinct,
+    /// Null elements are removed from both lists before the operation.
+    Ignore,
+}
+
+/// Options of a list set operation.
+///
+/// By default the lists are treated as sets: duplicates are removed and null elements are
+/// equal to each other.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SetOperationOptions {
+    /// Keep duplicates, where an element that occurs `m` times on the left and `n` times on
+    /// the right occurs `max(m, n)` times in the union, `min(m, n)` times in the
+    /// intersection, `m - n` times in the difference and `|m - n|` times in the symmetric
+    /// difference. The output is ordered as with `maintain_order`.
+    pub multiset: bool,
+    /// Return the left values in their original order, followed by the right values.
+    /// Otherwise only the order of the difference is unspecified.
+    pub maintain_order: bool,
+    pub nulls: SetNullBehavior,
+}
+
 impl Display for SetOperation {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let s = match self {
@@ -135,17 +302,19 @@ fn primitive<T>(
     offsets_a: &[i64],
     offsets_b: &[i64],
     set_op: SetOperation,
+    options: SetOperationOptions,
     validity: Option<Bitmap>,
 ) -> PolarsResult<ListArray<i64>>
 where
     T: NativeType + TotalHash + TotalEq + Copy + ToTotalOrd,
-    <Option<T> as ToTotalOrd>::TotalOrdItem: Hash + Eq + Copy,
+    <Option<T> as ToTotalOrd>::TotalOrdItem: Hash + Eq + Copy + IsNull,
 {
     let broadcast_lhs = offsets_a.len() == 2;
     let broadcast_rhs = offsets_b.len() == 2;
 
     let mut set = Default::default();
     let mut set2: PlIndexSet<<Option<T> as ToTotalOrd>::TotalOrdItem> = Default::default();
+    let mut scratch = Scratch::default();
 
     let mut values_out = MutablePrimitiveArray::with_capacity(std::cmp::max(
         *offsets_a.last().unwrap(),
@@ -196,10 +365,12 @@ where
             set_operation(
                 &mut set,
                 &mut set2,
+                &mut scratch,
                 a_iter,
                 b_iter,
                 &mut values_out,
                 set_op,
+                options,
                 true,
             )
         } else if broadcast_lhs {
@@ -218,10 +389,12 @@ where
             set_operation(
                 &mut set,
                 &mut set2,
+                &mut scratch,
                 a_iter,
                 b_iter,
                 &mut values_out,
                 set_op,
+                options,
                 false,
             )
         } else {
@@ -240,10 +413,12 @@ where
             set_operation(
                 &mut set,
                 &mut set2,
+                &mut scratch,
                 a_iter,
                 b_iter,
                 &mut values_out,
                 set_op,
+                options,
                 false,
             )
         };
@@ -257,12 +432,14 @@ where
     Ok(ListArray::new(dtype, offsets, values.boxed(), validity))
 }
 
+#[allow(clippy::too_many_arguments)]
 fn binary(
     a: &BinaryViewArray,
     b: &BinaryViewArray,
     offsets_a: &[i64],
     offsets_b: &[i64],
     set_op: SetOperation,
+    options: SetOperationOptions,
     validity: Option<Bitmap>,
     as_utf8: bool,
 ) -> PolarsResult<ListArray<i64>> {
@@ -270,6 +447,7 @@ fn binary(
     let broadcast_rhs = offsets_b.len() == 2;
     let mut set = Default::default();
     let mut set2: PlIndexSet<Option<&[u8]>> = Default::default();
+    let mut scratch = Scratch::default();
 
     let mut values_out = MutablePlBinary::with_capacity(std::cmp::max(
         *offsets_a.last().unwrap(),
@@ -310,10 +488,12 @@ fn binary(
             set_operation(
                 &mut set,
                 &mut set2,
+                &mut scratch,
                 a_iter,
                 b_iter,
                 &mut values_out,
                 set_op,
+                options,
                 true,
             )
         } else if broadcast_lhs {
@@ -325,10 +505,12 @@ fn binary(
             set_operation(
                 &mut set,
                 &mut set2,
+                &mut scratch,
                 a_iter,
                 b_iter,
                 &mut values_out,
                 set_op,
+                options,
                 false,
             )
         } else {
@@ -338,10 +520,12 @@ fn binary(
             set_operation(
                 &mut set,
                 &mut set2,
+                &mut scratch,
                 a_iter,
                 b_iter,
                 &mut values_out,
                 set_op,
+                options,
                 false,
             )
         };
@@ -364,6 +548,7 @@ fn array_set_operation(
     a: &ListArray<i64>,
     b: &ListArray<i64>,
     set_op: SetOperation,
+    options: SetOperationOptions,
 ) -> PolarsResult<ListArray<i64>> {
     let offsets_a = a.offsets().as_slice();
     let offsets_b = b.offsets().as_slice();
@@ -388,12 +573,14 @@ fn array_set_operation(
                 .unwrap()
                 .to_binview();
 
-            binary(&a, &b, offsets_a, offsets_b, set_op, validity, true)
+            binary(
+                &a, &b, offsets_a, offsets_b, set_op, options, validity, true,
+            )
         },
         ArrowDataType::BinaryView => {
             let a = values_a.as_any().downcast_ref::<BinaryViewArray>().unwrap();
             let b = values_b.as_any().downcast_ref::<BinaryViewArray>().unwrap();
-            binary(a, b, offsets_a, offsets_b, set_op, validity, false)
+            binary(a, b, offsets_a, offsets_b, set_op, options, validity, false)
         },
         ArrowDataType::Boolean => {
             polars_bail!(InvalidOperation: "boolean type not yet supported in list 'set' operations")
@@ -403,7 +590,7 @@ fn array_set_operation(
                 let a = values_a.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
                 let b = values_b.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
 
-                primitive(&a, &b, offsets_a, offsets_b, set_op, validity)
+                primitive(&a, &b, offsets_a, offsets_b, set_op, options, validity)
             })
         },
     }
@@ -413,6 +600,7 @@ pub fn list_set_operation(
     a: &ListChunked,
     b: &ListChunked,
     set_op: SetOperation,
+    options: SetOperationOptions,
 ) -> PolarsResult<ListChunked> {
     polars_ensure!(a.len() == b.len() || b.len() == 1 || a.len() == 1, ShapeMismatch: "column lengths don't match");
     polars_ensure!(a.dtype() == b.dtype(), InvalidOperation: "cannot do 'set' operation on dtypes: {} and {}", a.dtype(), b.dtype());
@@ -440,7 +628,7 @@ pub fn list_set_operation(
         arity::try_binary_unchecked_same_type(
             &a,
             &b,
-            |a, b| array_set_operation(a, b, set_op).map(|arr| arr.boxed()),
+            |a, b| array_set_operation(a, b, set_op, options).map(|arr| arr.boxed()),
             false,
             false,
         )
@@ -1,3 +1,4 @@
+use arrow::array::Array;
 use polars_core::POOL;
 use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
@@ -22,6 +23,10 @@ pub enum ListToStructArgs {
 pub enum ListToStructWidthStrategy {
     FirstNonNull,
     MaxWidth,
+    /// The maximum length of the first `n` sublists.
+    MaxWidthSample(usize),
+    /// A fixed number of fields, so the output schema is known without looking at the data.
+    Fixed(usize),
 }
 
 impl ListToStructArgs {
@@ -42,25 +47,52 @@ impl ListToStructArgs {
                     .map(|x| Field::new(x.clone(), inner_dtype.clone()))
                     .collect::<Vec<_>>(),
             )),
-            Self::InferWidth {
-                get_index_name,
-                max_fields,
-                ..
-            } if *max_fields > 0 => {
+            Self::InferWidth { get_index_name, .. } => {
+                let Some(n_fields) = self.known_n_fields() else {
+                    return Ok(DataType::Unknown(UnknownKind::Any));
+                };
                 let get_index_name_func = get_index_name.as_ref().map_or(
                     &_default_struct_name_gen as &dyn Fn(usize) -> PlSmallStr,
                     |x| x.0.as_ref(),
                 );
                 Ok(DataType::Struct(
-                    (0..*max_fields)
+                    (0..n_fields)
                         .map(|i| Field::new(get_index_name_func(i), inner_dtype.clone()))
                         .collect::<Vec<_>>(),
                 ))
             },
-            Self::InferWidth { .. } => Ok(DataType::Unknown(UnknownKind::Any)),
         }
     }
 
+    /// The number of fields of the output schema, if it doesn't depend on the data.
+    fn known_n_fields(&self) -> Option<usize> {
+        match self {
+            Self::FixedWidth(names) => Some(names.len()),
+            Self::InferWidth {
+                infer_field_strategy: ListToStructWidthStrategy::Fixed(n),
+                max_fields,
+                ..
+            } if *max_fields > 0 => Some((*n).min(*max_fields)),
+            Self::InferWidth {
+                infer_field_strategy: ListToStructWidthStrategy::Fixed(n),
+                ..
+            } => Some(*n),
+            Self::InferWidth { max_fields, .. } => (*max_fields > 0).then_some(*max_fields),
+        }
+    }
+
+    /// Whether the number of fields is determined without looking at the data.
+    pub fn is_fixed_width(&self) -> bool {
+        matches!(
+            self,
+            Self::FixedWidth(_)
+                | Self::InferWidth {
+                    infer_field_strategy: ListToStructWidthStrategy::Fixed(_),
+                    ..
+                }
+        )
+    }
+
     fn det_n_fields(&self, ca: &ListChunked) -> usize {
         match self {
             Self::FixedWidth(v) => v.len(),
@@ -70,20 +102,9 @@ impl ListToStructArgs {
                 ..
             } => {
                 let inferred = match infer_field_strategy {
-                    ListToStructWidthStrategy::MaxWidth => {
-                        let mut max = 0;
-
-                        ca.downcast_iter().for_each(|arr| {
-                            let offsets = arr.offsets().as_slice();
-                            let mut last = offsets[0];
-                            for o in &offsets[1..] {
-                                let len = (*o - last) as usize;
-                                max = std::cmp::max(max, len);
-                                last = *o;
-                            }
-                        });
-                        max
-                    },
+                    ListToStructWidthStrategy::MaxWidth => max_width(ca, usize::MAX),
+                    ListToStructWidthStrategy::MaxWidthSample(n) => max_width(ca, *n),
+                    ListToStructWidthStrategy::Fixed(n) => *n,
                     ListToStructWidthStrategy::FirstNonNull => {
                         let mut len = 0;
                         for arr in ca.downcast_iter() {
@@ -136,6 +157,15 @@ impl ListToStructArgs {
     }
 }
 
+/// The maximum length of the first `n` sublists.
+fn max_width(ca: &ListChunked, n: usize) -> usize {
+    ca.downcast_iter()
+        .flat_map(|arr| arr.offsets().lengths())
+        .take(n)
+        .max()
+        .unwrap_or(0)
+}
+
 #[derive(Clone)]
 pub struct NameGenerator(pub Arc<dyn Fn(usize) -> PlSmallStr + Send + Sync>);
 
@@ -193,6 +223,84 @@ pub trait ToStruct: AsList {
 
 impl ToStruct for ListChunked {}
 
+/// Convert lists of values to structs, where the field of every value is the key at the same
+/// position in `keys`, e.g. to turn JSON arrays with a header into columns.
+///
+/// `keys` has a list of keys for every row, or a single list for all rows. The fields are the
+/// keys in the order in which they first occur, or `fields` if given, in which case all other
+/// keys are ignored. Missing and null keys give null values, and for duplicate keys the last
+/// value wins.
+pub fn list_to_struct_with_keys(
+    values: &ListChunked,
+    keys: &ListChunked,
+    fields: Option<&[PlSmallStr]>,
+) -> PolarsResult<StructChunked> {
+    let len = values.len();
+    polars_ensure!(
+        keys.len() == len || keys.len() == 1,
+        length_mismatch = "list.to_struct",
+        keys.len(),
+        len
+    );
+    let broadcast_keys = keys.len() == 1;
+    let keys = keys.cast(&DataType::List(Box::new(DataType::String)))?;
+    let keys = keys.list()?.rechunk();
+    let keys_arr = keys.downcast_as_array();
+    let keys_inner = keys.get_inner();
+    let key_values = keys_inner.str()?.downcast_as_array();
+    let values = values.rechunk();
+    let values_arr = values.downcast_as_array();
+
+    let mut field_idx: PlIndexMap<&str, usize> = PlIndexMap::default();
+    if let Some(fields) = fields {
+        field_idx.extend(fields.iter().enumerate().map(|(i, f)| (f.as_str(), i)));
+    }
+    let mut gather_idx = vec![vec![None; len]; field_idx.len()];
+
+    for row in 0..len {
+        let key_row = if broadcast_keys { 0 } else { row };
+        if !values_arr.is_valid(row) || !keys_arr.is_valid(key_row) {
+            continue;
+        }
+        let (start, end) = values_arr.offsets().start_end(row);
+        let (key_start, key_end) = keys_arr.offsets().start_end(key_row);
+        polars_ensure!(
+            end - start == key_end - key_start,
+            ShapeMismatch: "row {} of `list.to_struct` has {} values but {} keys",
+            row, end - start, key_end - key_start
+        );
+
+        for (k, v) in (key_start..key_end).zip(start..end) {
+            let Some(key) = key_values.get(k) else {
+                continue;
+            };
+            let field = match field_idx.get(key) {
+                Some(&field) => field,
+                None if fields.is_some() => continue,
+                None => {
+                    field_idx.insert(key, gather_idx.len());
+                    gather_idx.push(vec![None; len]);
+                    gather_idx.len() - 1
+                },
+            };
+            gather_idx[field][row] = Some(v as IdxSize);
+        }
+    }
+
+    let inner = values.get_inner();
+    let fields = field_idx
+        .keys()
+        .zip(gather_idx)
+        .map(|(name, idx)| {
+            let idx = IdxCa::from_iter_options(PlSmallStr::EMPTY, idx.into_iter());
+            // SAFETY: the indices are offsets into the inner values.
+            let field = unsafe { inner.take_unchecked(&idx) };
+            field.with_name(PlSmallStr::from_str(name))
+        })
+        .collect::<Vec<_>>();
+    StructChunked::from_series(values.name().clone(), len, fields.iter())
+}
+
 #[cfg(feature = "serde")]
 mod _serde_impl {
     use super::*;
@@ -35,6 +35,8 @@ pub use gather::*;
 #[cfg(feature = "hist")]
 pub use hist::*;
 pub use list::*;
+#[cfg(feature = "mode")]
+pub use mode::ModeTiePolicy;
 #[allow(unused_imports)]
 use polars_core::prelude::*;
 #[cfg(feature = "repeat_by")]
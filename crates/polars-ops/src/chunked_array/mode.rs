@@ -1,5 +1,25 @@
 use polars_core::POOL;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum_macros::IntoStaticStr;
+
+/// Which of the tied most occurring values [`mode_with_ties`] returns.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[strum(serialize_all = "snake_case")]
+#[derive(Default)]
+pub enum ModeTiePolicy {
+    /// All tied values, in no particular order.
+    #[default]
+    All,
+    /// The smallest of the tied values.
+    Smallest,
+    /// The tied value that occurs first.
+    First,
+    /// All tied values in ascending order, as a single list.
+    AllAsList,
+}
 
 fn mode_indices(groups: GroupsType) -> Vec<IdxSize> {
     match groups {
@@ -26,12 +46,121 @@ fn mode_indices(groups: GroupsType) -> Vec<IdxSize> {
     }
 }
 
-pub fn mode(s: &Series) -> PolarsResult<Series> {
+fn group_tuples(s: &Series, sorted: bool) -> PolarsResult<GroupsType> {
     let parallel = !POOL.current_thread_has_pending_tasks().unwrap_or(false);
-    let groups = s.group_tuples(parallel, false).unwrap();
+    s.group_tuples(parallel, sorted)
+}
+
+pub fn mode(s: &Series) -> PolarsResult<Series> {
+    let groups = group_tuples(s, false)?;
     let idx = mode_indices(groups);
     let idx = IdxCa::from_vec("".into(), idx);
     // SAFETY:
     // group indices are in bounds
     Ok(unsafe { s.take_unchecked(&idx) })
 }
+
+/// The most occurring value(s), where ties are resolved by `ties`.
+pub fn mode_with_ties(s: &Series, ties: ModeTiePolicy) -> PolarsResult<Series> {
+    let modes = match ties {
+        ModeTiePolicy::All => return mode(s),
+        ModeTiePolicy::Smallest => mode(s)?
+            .sort(SortOptions::default().with_nulls_last(true))?
+            .head(Some(1)),
+        ModeTiePolicy::First => {
+            // The first index of a group is the first occurrence of its value.
+            let first = mode_indices(group_tuples(s, false)?).into_iter().min();
+            let idx = IdxCa::from_vec("".into(), first.into_iter().collect());
+            // SAFETY:
+            // group indices are in bounds
+            unsafe { s.take_unchecked(&idx) }
+        },
+        ModeTiePolicy::AllAsList => {
+            let modes = mode(s)?.sort(SortOptions::default())?;
+            return Ok(modes.implode()?.into_series());
+        },
+    };
+    if modes.is_empty() {
+        Ok(Series::full_null(s.name().clone(), 1, s.dtype()))
+    } else {
+        Ok(modes)
+    }
+}
+
+/// The `k` most occurring values with their counts, as a single list of structs with the
+/// fields `<name>` and `count`. Values that occur equally often are ordered by their first
+/// occurrence.
+#[cfg(feature = "dtype-struct")]
+pub fn top_k_frequent(s: &Series, k: usize) -> PolarsResult<Series> {
+    const COUNT: &str = "count";
+    polars_ensure!(
+        s.name() != COUNT,
+        Duplicate: "using `top_k_frequent` on a column/series named '{}' would lead to duplicate \
+        field names", COUNT,
+    );
+    // Sorted groups are ordered by their first occurrence.
+    let groups = group_tuples(s, true)?;
+    let counts = groups.group_count();
+    let counts = counts.cont_slice().unwrap();
+    let firsts = match &groups {
+        GroupsType::Idx(groups) => groups.first().to_vec(),
+        GroupsType::Slice { groups, .. } => groups.iter().map(|g| g[0]).collect(),
+    };
+
+    // The sort is stable, so ties keep the order of their first occurrence.
+    let mut order = (0..counts.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&i| std::cmp::Reverse(counts[i]));
+    order.truncate(k);
+
+    let idx = IdxCa::from_vec("".into(), order.iter().map(|&i| firsts[i]).collect());
+    // SAFETY:
+    // group indices are in bounds
+    let values = unsafe { s.take_unchecked(&idx) };
+    let counts = IdxCa::from_vec(COUNT.into(), order.iter().map(|&i| counts[i]).collect());
+    let out = StructChunked::from_series(
+        s.name().clone(),
+        values.len(),
+        [values, counts.into_series()].iter(),
+    )?;
+    Ok(out.into_series().implode()?.into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mode_with_ties() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            &[Some(3), Some(1), None, Some(3), Some(1), None],
+        );
+
+        let out = mode_with_ties(&s, ModeTiePolicy::Smallest)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(1)]);
+        let out = mode_with_ties(&s, ModeTiePolicy::First)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(3)]);
+        let out = mode_with_ties(&s, ModeTiePolicy::AllAsList)?;
+        let out = out.list()?.get_as_series(0).unwrap();
+        assert_eq!(Vec::from(out.i32()?), &[None, Some(1), Some(3)]);
+
+        let empty = Series::new_empty("a".into(), &DataType::Int32);
+        let out = mode_with_ties(&empty, ModeTiePolicy::Smallest)?;
+        assert_eq!(Vec::from(out.i32()?), &[None]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_top_k_frequent() -> PolarsResult<()> {
+        let s = Series::new("a".into(), &["x", "y", "z", "y", "z", "y", "w"]);
+        let out = top_k_frequent(&s, 2)?;
+        let out = out.list()?.get_as_series(0).unwrap();
+        let out = out.struct_()?;
+        let values = out.field_by_name("a")?;
+        let counts = out.field_by_name("count")?;
+        assert_eq!(Vec::from(values.str()?), &[Some("y"), Some("z")]);
+        assert_eq!(Vec::from(counts.idx()?), &[Some(3), Some(2)]);
+        Ok(())
+    }
+}
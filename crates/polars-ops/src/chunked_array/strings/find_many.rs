@@ -1,8 +1,11 @@
+use std::fmt::Write;
+
 use aho_corasick::{AhoCorasick, AhoCorasickBuilder};
 use arrow::array::Utf8ViewArray;
 use polars_core::prelude::arity::unary_elementwise;
 use polars_core::prelude::*;
 use polars_core::utils::align_chunks_binary;
+use regex::{Captures, Regex};
 
 fn build_ac(patterns: &StringChunked, ascii_case_insensitive: bool) -> PolarsResult<AhoCorasick> {
     AhoCorasickBuilder::new()
@@ -291,3 +294,198 @@ pub fn find_many(
         (a, b) => polars_bail!(length_mismatch = "str.find_many", a, b),
     }
 }
+
+/// A set of regular expressions compiled into a single alternation, so that
+/// all patterns are matched in one pass over each string.
+///
+/// Every pattern is wrapped in an outer capture group; `offsets[i]` is the
+/// index of that group and is followed by the pattern's own capture groups.
+struct RegexAlternation {
+    re: Regex,
+    offsets: Vec<usize>,
+    group_counts: Vec<usize>,
+}
+
+impl RegexAlternation {
+    fn new(patterns: &StringChunked) -> PolarsResult<Self> {
+        polars_ensure!(patterns.null_count() == 0, InvalidOperation: "'patterns' should not have nulls");
+        let mut combined = String::new();
+        let mut offsets = Vec::with_capacity(patterns.len());
+        let mut group_counts = Vec::with_capacity(patterns.len());
+        let mut next_group = 1;
+        for pat in patterns.into_no_null_iter() {
+            // Validate each pattern on its own so the error points at the culprit.
+            let groups = Regex::new(pat)
+                .map_err(|e| polars_err!(ComputeError: "invalid regex {:?}: {}", pat, e))?
+                .captures_len()
+                - 1;
+            if !combined.is_empty() {
+                combined.push('|');
+            }
+            write!(combined, "({pat})").unwrap();
+            offsets.push(next_group);
+            group_counts.push(groups);
+            next_group += groups + 1;
+        }
+        let re = Regex::new(&combined).map_err(|e| polars_err!(ComputeError: "{}", e))?;
+        Ok(Self {
+            re,
+            offsets,
+            group_counts,
+        })
+    }
+
+    /// Index of the pattern that produced `caps`.
+    fn pattern_idx(&self, caps: &Captures) -> usize {
+        self.offsets
+            .iter()
+            .position(|&g| caps.get(g).is_some())
+            .unwrap()
+    }
+}
+
+/// Rewrite the numbered group references (`$1`, `${1}`) in `replacement` so
+/// they refer to the groups of the pattern whose outer group is `offset`.
+fn shift_group_refs(replacement: &str, offset: usize) -> String {
+    let mut out = String::with_capacity(replacement.len());
+    let mut rest = replacement;
+    while let Some(pos) = rest.find('$') {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos + 1..];
+        if let Some(stripped) = rest.strip_prefix('$') {
+            out.push_str("$$");
+            rest = stripped;
+            continue;
+        }
+        let (braced, body) = match rest.strip_prefix('{') {
+            Some(body) => (true, body),
+            None => (false, rest),
+        };
+        let digits = body.bytes().take_while(u8::is_ascii_digit).count();
+        let closed = !braced || body[digits..].starts_with('}');
+        if digits > 0 && closed {
+            let group: usize = body[..digits].parse().unwrap();
+            write!(out, "${{{}}}", group + offset).unwrap();
+            rest = &body[digits + braced as usize..];
+        } else {
+            out.push('$');
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Replace every match of any of the regular expressions in `patterns` with
+/// the corresponding entry of `replace_with`.
+///
+/// All patterns are compiled into a single automaton, so each string is
+/// scanned once regardless of the number of patterns. At each position the
+/// first pattern (in order) that matches wins. Group references such as `$1`
+/// in a replacement refer to the capture groups of its own pattern.
+pub fn replace_all_regex(
+    ca: &StringChunked,
+    patterns: &ListChunked,
+    replace_with: &ListChunked,
+) -> PolarsResult<StringChunked> {
+    polars_ensure!(
+        patterns.len() == 1 && replace_with.len() == 1,
+        nyi = "`str.replace_many_regex` with a pattern per row"
+    );
+
+    if patterns.has_nulls() || replace_with.has_nulls() {
+        return Ok(StringChunked::full_null(ca.name().clone(), ca.len()));
+    }
+
+    let patterns = patterns.explode(true)?;
+    let patterns = patterns.str()?;
+    let replace_with = replace_with.explode(true)?;
+    let replace_with = replace_with.str()?;
+
+    let replace_with = if replace_with.len() == 1 && patterns.len() > 1 {
+        replace_with.new_from_index(0, patterns.len())
+    } else {
+        replace_with.clone()
+    };
+    polars_ensure!(patterns.len() == replace_with.len(), InvalidOperation: "expected the same amount of patterns as replacement strings");
+    polars_ensure!(replace_with.null_count() == 0, InvalidOperation: "'replace_with' should not have nulls");
+
+    let alternation = RegexAlternation::new(patterns)?;
+    let replace_with = replace_with
+        .into_no_null_iter()
+        .zip(&alternation.offsets)
+        .map(|(r, offset)| shift_group_refs(r, *offset))
+        .collect::<Vec<_>>();
+
+    Ok(ca.apply_values(|val| {
+        alternation.re.replace_all(val, |caps: &Captures| {
+            let mut dst = String::new();
+            caps.expand(&replace_with[alternation.pattern_idx(caps)], &mut dst);
+            dst
+        })
+    }))
+}
+
+/// Find the leftmost match of any of the regular expressions in `patterns`.
+///
+/// Returns a struct with the index of the `pattern` that matched, the
+/// `match`ed text and the `captures` of that pattern's groups. All fields are
+/// null if nothing matched.
+#[cfg(feature = "dtype-struct")]
+pub fn extract_first_match(ca: &StringChunked, patterns: &ListChunked) -> PolarsResult<Series> {
+    polars_ensure!(
+        patterns.len() == 1,
+        nyi = "`str.extract_first_match` with a pattern per row"
+    );
+    polars_ensure!(!patterns.has_nulls(), InvalidOperation: "'patterns' should not be null");
+
+    let patterns = patterns.explode(true)?;
+    let alternation = RegexAlternation::new(patterns.str()?)?;
+
+    let mut pattern_idx = Vec::with_capacity(ca.len());
+    let mut matched = StringChunkedBuilder::new(PlSmallStr::from_static("match"), ca.len());
+    let mut captures =
+        ListStringChunkedBuilder::new(PlSmallStr::from_static("captures"), ca.len(), ca.len());
+    for opt_val in ca.iter() {
+        match opt_val.and_then(|val| alternation.re.captures(val)) {
+            Some(caps) => {
+                let idx = alternation.pattern_idx(&caps);
+                let offset = alternation.offsets[idx];
+                pattern_idx.push(Some(idx as IdxSize));
+                matched.append_value(caps.get(offset).unwrap().as_str());
+                captures.append_trusted_len_iter(
+                    (offset + 1..=offset + alternation.group_counts[idx])
+                        .map(|g| caps.get(g).map(|m| m.as_str())),
+                );
+            },
+            None => {
+                pattern_idx.push(None);
+                matched.append_null();
+                captures.append_null();
+            },
+        }
+    }
+
+    let fields = [
+        IdxCa::from_slice_options(PlSmallStr::from_static("pattern"), &pattern_idx).into_series(),
+        matched.finish().into_series(),
+        captures.finish().into_series(),
+    ];
+    Ok(StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())?.into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_replace_all_regex() -> PolarsResult<()> {
+        assert_eq!(shift_group_refs("$1-${2}$$ $name", 3), "${4}-${5}$$ $name");
+
+        let ca = StringChunked::new("a".into(), &[Some("2024-01-31 abc"), None]);
+        let patterns = Series::new("p".into(), &[r"(\d+)-(\d+)-(\d+)", "[a-c]+"]).implode()?;
+        let replace_with = Series::new("r".into(), &["$3/$2/$1", "<$0>"]).implode()?;
+        let out = replace_all_regex(&ca, &patterns, &replace_with)?;
+        assert_eq!(Vec::from(&out), &[Some("31/01/2024 <abc>"), None]);
+        Ok(())
+    }
+}
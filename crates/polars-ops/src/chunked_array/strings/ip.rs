@@ -0,0 +1,256 @@
+use std::net::IpAddr;
+
+use arrow::array::MutableBinaryViewArray;
+use polars_core::prelude::arity::{unary_elementwise, unary_elementwise_values};
+use polars_core::prelude::*;
+
+/// Width in bytes of the binary representation of an IP address.
+pub const IP_ADDRESS_WIDTH: usize = 16;
+
+fn parse_ip(s: &str) -> Option<IpAddr> {
+    s.parse().ok()
+}
+
+/// The address as a 128-bit integer. IPv4 addresses are mapped into the
+/// `::ffff:0:0/96` range, so that every address has a single representation.
+fn ip_to_u128(ip: IpAddr) -> u128 {
+    match ip {
+        IpAddr::V4(v4) => u128::from(v4.to_ipv6_mapped()),
+        IpAddr::V6(v6) => u128::from(v6),
+    }
+}
+
+/// A block of addresses given in CIDR notation, e.g. `10.0.0.0/8` or `2001:db8::/32`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Subnet {
+    network: u128,
+    mask: u128,
+}
+
+impl Subnet {
+    /// Parse a subnet in CIDR notation. An address without a prefix length is a
+    /// subnet that contains only that address. Host bits in the address are
+    /// ignored, so `10.1.2.3/8` is the same subnet as `10.0.0.0/8`.
+    pub fn parse(cidr: &str) -> PolarsResult<Self> {
+        Self::try_parse(cidr)
+            .ok_or_else(|| polars_err!(ComputeError: "invalid subnet in CIDR notation: '{}'", cidr))
+    }
+
+    fn try_parse(cidr: &str) -> Option<Self> {
+        let (addr, prefix) = match cidr.split_once('/') {
+            Some((addr, prefix)) => (addr, Some(prefix.parse::<u32>().ok()?)),
+            None => (cidr, None),
+        };
+        let addr = parse_ip(addr)?;
+        let prefix = match (addr, prefix) {
+            (IpAddr::V4(_), Some(prefix @ 0..=32)) => prefix + 96,
+            (IpAddr::V6(_), Some(prefix @ 0..=128)) => prefix,
+            (_, Some(_)) => return None,
+            (_, None) => 128,
+        };
+        let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+        Some(Self {
+            network: ip_to_u128(addr) & mask,
+            mask,
+        })
+    }
+
+    fn contains(&self, ip: IpAddr) -> bool {
+        ip_to_u128(ip) & self.mask == self.network
+    }
+
+    fn first(&self) -> u128 {
+        self.network
+    }
+
+    fn last(&self) -> u128 {
+        self.network | !self.mask
+    }
+}
+
+fn binary_from_u128_iter<I>(name: PlSmallStr, iter: I) -> BinaryChunked
+where
+    I: Iterator<Item = Option<u128>>,
+{
+    let mut out = MutableBinaryViewArray::<[u8]>::with_capacity(iter.size_hint().0);
+    for opt_v in iter {
+        out.push(opt_v.map(u128::to_be_bytes));
+    }
+    BinaryChunked::with_chunk(name, out.freeze())
+}
+
+/// Parse every string to the 16-byte binary representation of its IP address.
+///
+/// IPv4 addresses are stored as IPv4-mapped IPv6 addresses and all addresses
+/// are stored in network byte order, so comparing the binary values compares
+/// the addresses. If `strict` is `false` invalid addresses become null.
+pub fn ip_parse(ca: &StringChunked, strict: bool) -> PolarsResult<BinaryChunked> {
+    let out = binary_from_u128_iter(
+        ca.name().clone(),
+        ca.iter()
+            .map(|opt_s| opt_s.and_then(parse_ip).map(ip_to_u128)),
+    );
+    if strict && out.null_count() != ca.null_count() {
+        let invalid = ca.iter().flatten().find(|s| parse_ip(s).is_none()).unwrap();
+        polars_bail!(ComputeError: "invalid IP address: '{}'; try setting `strict=false` to ignore", invalid);
+    }
+    Ok(out)
+}
+
+/// Check if the strings are valid IPv4 addresses in dotted-decimal notation.
+pub fn ip_is_ipv4(ca: &StringChunked) -> BooleanChunked {
+    unary_elementwise_values(ca, |s| matches!(parse_ip(s), Some(IpAddr::V4(_))))
+}
+
+/// Check if the strings are valid IPv6 addresses.
+pub fn ip_is_ipv6(ca: &StringChunked) -> BooleanChunked {
+    unary_elementwise_values(ca, |s| matches!(parse_ip(s), Some(IpAddr::V6(_))))
+}
+
+/// Convert IPv4 addresses, including IPv4-mapped IPv6 addresses, to their
+/// integer value. Other addresses and invalid strings become null.
+pub fn ip_to_int(ca: &StringChunked) -> UInt32Chunked {
+    unary_elementwise(ca, |opt_s| match parse_ip(opt_s?)? {
+        IpAddr::V4(v4) => Some(u32::from(v4)),
+        IpAddr::V6(v6) => v6.to_ipv4_mapped().map(u32::from),
+    })
+}
+
+/// Check if the IP addresses lie in the subnet given in CIDR notation. Invalid
+/// addresses become null.
+pub fn ip_is_in_subnet(ca: &StringChunked, cidr: &str) -> PolarsResult<BooleanChunked> {
+    let subnet = Subnet::parse(cidr)?;
+    Ok(unary_elementwise(ca, |opt_s| {
+        Some(subnet.contains(parse_ip(opt_s?)?))
+    }))
+}
+
+/// The first and last address of every subnet in CIDR notation, in the binary
+/// representation of [`ip_parse`].
+///
+/// Because the binary representation orders like the addresses, a join on
+/// `start <= ip_parse(ip) <= end` is a join on subnet containment.
+#[cfg(feature = "dtype-struct")]
+pub fn ip_subnet_range(ca: &StringChunked, strict: bool) -> PolarsResult<StructChunked> {
+    let subnets: Vec<Option<Subnet>> = ca
+        .iter()
+        .map(|opt_s| {
+            opt_s
+                .map(|s| match Subnet::try_parse(s) {
+                    Some(subnet) => Ok(Some(subnet)),
+                    None if strict => Subnet::parse(s).map(Some),
+                    None => Ok(None),
+                })
+                .transpose()
+                .map(Option::flatten)
+        })
+        .collect::<PolarsResult<_>>()?;
+
+    let start = binary_from_u128_iter(
+        PlSmallStr::from_static("start"),
+        subnets.iter().map(|opt| opt.map(|s| s.first())),
+    );
+    let end = binary_from_u128_iter(
+        PlSmallStr::from_static("end"),
+        subnets.iter().map(|opt| opt.map(|s| s.last())),
+    );
+    let out = StructChunked::from_series(
+        ca.name().clone(),
+        ca.len(),
+        [start.into_series(), end.into_series()].iter(),
+    )?;
+    Ok(out.with_outer_validity(ca.rechunk_validity()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ip() -> PolarsResult<()> {
+        let ca = StringChunked::new(
+            "a".into(),
+            &[
+                Some("10.1.2.3"),
+                Some("192.168.0.1"),
+                Some("::ffff:10.0.0.1"),
+                Some("2001:db8::1"),
+                Some("not an ip"),
+                None,
+            ],
+        );
+
+        let v4 = ip_is_ipv4(&ca);
+        assert_eq!(
+            Vec::from(&v4),
+            &[
+                Some(true),
+                Some(true),
+                Some(false),
+                Some(false),
+                Some(false),
+                None
+            ]
+        );
+        let out = ip_to_int(&ca);
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                Some(0x0a010203),
+                Some(0xc0a80001),
+                Some(0x0a000001),
+                None,
+                None,
+                None
+            ]
+        );
+        let out = ip_is_in_subnet(&ca, "10.0.0.0/8")?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(true), Some(false), Some(true), Some(false), None, None]
+        );
+        let out = ip_is_in_subnet(&ca, "2001:db8::/32")?;
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(true),
+                None,
+                None
+            ]
+        );
+        assert!(ip_is_in_subnet(&ca, "10.0.0.0/33").is_err());
+
+        assert!(ip_parse(&ca, true).is_err());
+        let parsed = ip_parse(&ca, false)?;
+        assert_eq!(parsed.get(0).unwrap().len(), IP_ADDRESS_WIDTH);
+        assert_eq!(
+            parsed.get(2),
+            Some(&[0u8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 10, 0, 0, 1][..])
+        );
+        assert!(parsed.get(0) < parsed.get(1));
+        assert_eq!(parsed.null_count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_ip_subnet_range() -> PolarsResult<()> {
+        let cidr = StringChunked::new("a".into(), &[Some("10.1.2.3/8"), Some("bogus"), None]);
+        assert!(ip_subnet_range(&cidr, true).is_err());
+
+        let out = ip_subnet_range(&cidr, false)?;
+        let fields = out.fields_as_series();
+        let start = fields[0].binary()?;
+        let end = fields[1].binary()?;
+        let ip = StringChunked::new("a".into(), &["10.0.0.0", "10.255.255.255"]);
+        let ip = ip_parse(&ip, true)?;
+        assert_eq!(start.get(0), ip.get(0));
+        assert_eq!(end.get(0), ip.get(1));
+        assert_eq!(start.null_count(), 2);
+        assert_eq!(out.null_count(), 1);
+        Ok(())
+    }
+}
@@ -8,6 +8,8 @@ mod escape_regex;
 mod extract;
 #[cfg(feature = "find_many")]
 mod find_many;
+#[cfg(feature = "ip")]
+mod ip;
 #[cfg(feature = "extract_jsonpath")]
 mod json_path;
 #[cfg(feature = "strings")]
@@ -16,14 +18,20 @@ mod namespace;
 mod normalize;
 #[cfg(feature = "string_pad")]
 mod pad;
+#[cfg(feature = "regex_lite")]
+mod regex_engine;
 #[cfg(feature = "string_reverse")]
 mod reverse;
+#[cfg(feature = "string_similarity")]
+mod similarity;
 #[cfg(feature = "strings")]
 mod split;
 #[cfg(feature = "strings")]
 mod strip;
 #[cfg(feature = "strings")]
 mod substring;
+#[cfg(feature = "string_tokenize")]
+mod tokenize;
 #[cfg(all(not(feature = "nightly"), feature = "strings"))]
 mod unicode_internals;
 
@@ -33,6 +41,8 @@ pub use concat::*;
 pub use escape_regex::*;
 #[cfg(feature = "find_many")]
 pub use find_many::*;
+#[cfg(feature = "ip")]
+pub use ip::*;
 #[cfg(feature = "extract_jsonpath")]
 pub use json_path::*;
 #[cfg(feature = "strings")]
@@ -40,12 +50,16 @@ pub use namespace::*;
 #[cfg(feature = "string_normalize")]
 pub use normalize::*;
 use polars_core::prelude::*;
+#[cfg(feature = "regex_lite")]
+pub use regex_engine::*;
 #[cfg(feature = "strings")]
 pub use split::*;
 #[cfg(feature = "strings")]
 pub use strip::*;
 #[cfg(feature = "strings")]
 pub use substring::{substring_ternary_offsets_value, update_view};
+#[cfg(feature = "string_tokenize")]
+pub use tokenize::Tokenizer;
 
 pub trait AsString {
     fn as_string(&self) -> &StringChunked;
@@ -61,6 +61,32 @@ pub trait StringNameSpaceImpl: AsString {
         ca.apply_values(|s| general_purpose::STANDARD.encode(s).into())
     }
 
+    #[cfg(feature = "binary_encoding")]
+    fn base32_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_string();
+        ca.as_binary().base32_decode(strict)
+    }
+
+    #[must_use]
+    #[cfg(feature = "string_encoding")]
+    fn base32_encode(&self) -> StringChunked {
+        let ca = self.as_string();
+        ca.apply_values(|s| crate::chunked_array::codecs::base32_encode(s.as_bytes()).into())
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn base85_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_string();
+        ca.as_binary().base85_decode(strict)
+    }
+
+    #[must_use]
+    #[cfg(feature = "string_encoding")]
+    fn base85_encode(&self) -> StringChunked {
+        let ca = self.as_string();
+        ca.apply_values(|s| crate::chunked_array::codecs::base85_encode(s.as_bytes()).into())
+    }
+
     #[cfg(feature = "string_to_integer")]
     // Parse a string number with base _radix_ into a decimal (i64)
     fn to_integer(&self, base: &UInt32Chunked, strict: bool) -> PolarsResult<Int64Chunked> {
@@ -505,6 +531,22 @@ pub trait StringNameSpaceImpl: AsString {
         split_helper(ca, by, str::split_inclusive)
     }
 
+    /// Split by a literal substring, making at most `maxsplit` splits.
+    fn split_max(&self, by: &StringChunked, maxsplit: usize) -> PolarsResult<ListChunked> {
+        let ca = self.as_string();
+        split::split_max(ca, by, maxsplit)
+    }
+
+    /// Split by the matches of a regular expression, making at most `maxsplit` splits if given.
+    fn split_regex(
+        &self,
+        pat: &StringChunked,
+        maxsplit: Option<usize>,
+    ) -> PolarsResult<ListChunked> {
+        let ca = self.as_string();
+        split::split_regex(ca, pat, maxsplit)
+    }
+
     /// Extract each successive non-overlapping regex match in an individual string as an array.
     fn extract_all_many(&self, pat: &StringChunked) -> PolarsResult<ListChunked> {
         let ca = self.as_string();
@@ -624,6 +666,37 @@ pub trait StringNameSpaceImpl: AsString {
         normalize::normalize(ca, form)
     }
 
+    /// Folds the case of the string values for caseless comparison.
+    #[must_use]
+    #[cfg(feature = "string_normalize")]
+    fn str_casefold(&self) -> StringChunked {
+        let ca = self.as_string();
+        normalize::casefold(ca)
+    }
+
+    /// Computes a binary key that sorts the string values by letters, then
+    /// accents and then case. This is not a locale-aware collation.
+    #[must_use]
+    #[cfg(feature = "string_normalize")]
+    fn str_collation_key(&self) -> BinaryChunked {
+        let ca = self.as_string();
+        normalize::collation_key(ca)
+    }
+
+    /// Converts the string values to lowercase using the casing rules of `locale`.
+    #[cfg(feature = "string_normalize")]
+    fn str_to_lowercase_locale(&self, locale: &str) -> PolarsResult<StringChunked> {
+        let ca = self.as_string();
+        normalize::to_lowercase_locale(ca, locale)
+    }
+
+    /// Converts the string values to uppercase using the casing rules of `locale`.
+    #[cfg(feature = "string_normalize")]
+    fn str_to_uppercase_locale(&self, locale: &str) -> PolarsResult<StringChunked> {
+        let ca = self.as_string();
+        normalize::to_uppercase_locale(ca, locale)
+    }
+
     /// Reverses the string values
     #[must_use]
     #[cfg(feature = "string_reverse")]
@@ -632,6 +705,54 @@ pub trait StringNameSpaceImpl: AsString {
         reverse::reverse(ca)
     }
 
+    /// Levenshtein edit distance between the string values and `other`.
+    #[must_use]
+    #[cfg(feature = "string_similarity")]
+    fn str_levenshtein(&self, other: &StringChunked) -> UInt32Chunked {
+        let ca = self.as_string();
+        similarity::levenshtein(ca, other)
+    }
+
+    /// Jaro-Winkler similarity between the string values and `other`.
+    #[must_use]
+    #[cfg(feature = "string_similarity")]
+    fn str_jaro_winkler(&self, other: &StringChunked) -> Float64Chunked {
+        let ca = self.as_string();
+        similarity::jaro_winkler(ca, other)
+    }
+
+    /// Similarity of the character `n`-grams of the string values and `other`.
+    #[cfg(feature = "string_similarity")]
+    fn str_ngram_similarity(
+        &self,
+        other: &StringChunked,
+        n: usize,
+    ) -> PolarsResult<Float64Chunked> {
+        let ca = self.as_string();
+        similarity::ngram_similarity(ca, other, n)
+    }
+
+    /// Splits the string values into lists of tokens.
+    #[cfg(feature = "string_tokenize")]
+    fn str_tokenize(&self, tokenizer: &Tokenizer) -> PolarsResult<ListChunked> {
+        let ca = self.as_string();
+        tokenize::tokenize(ca, tokenizer)
+    }
+
+    /// Lists the character `n`-grams of the string values.
+    #[cfg(feature = "string_tokenize")]
+    fn str_ngrams(&self, n: usize) -> PolarsResult<ListChunked> {
+        let ca = self.as_string();
+        tokenize::ngrams(ca, n)
+    }
+
+    /// Lists the shingles of `k` consecutive words of the string values.
+    #[cfg(feature = "string_tokenize")]
+    fn str_shingle(&self, k: usize) -> PolarsResult<ListChunked> {
+        let ca = self.as_string();
+        tokenize::shingle(ca, k)
+    }
+
     /// Slice the string values.
     ///
     /// Determines a substring starting from `offset` and with length `length` of each of the elements in `array`.
@@ -1,5 +1,7 @@
-use polars_core::prelude::{StringChunked, StringChunkedBuilder};
+use polars_core::prelude::{BinaryChunked, DataType, StringChunked, StringChunkedBuilder};
+use polars_error::{PolarsResult, polars_bail};
 use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -36,3 +38,169 @@ pub fn normalize(ca: &StringChunked, form: UnicodeForm) -> StringChunked {
         UnicodeForm::NFKD => normalize_with(ca, |s, b| b.extend(s.nfkd())),
     }
 }
+
+/// The casing rules of a locale.
+///
+/// Unicode only tailors the default casing for Turkish and Azerbaijani (the dotted and dotless
+/// `i`) and for Lithuanian (the dot above `i` and `j` with accents). The latter isn't
+/// implemented, so Lithuanian is rejected instead of being cased incorrectly.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Casing {
+    Default,
+    Turkic,
+}
+
+impl Casing {
+    /// Get the casing rules of the language of a BCP 47 language tag, e.g. `"tr-TR"`.
+    fn from_locale(locale: &str) -> PolarsResult<Self> {
+        let language = locale.split(['-', '_']).next().unwrap_or_default();
+        if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic())
+        {
+            polars_bail!(
+                InvalidOperation: "invalid locale '{}', expected a BCP 47 language tag", locale
+            );
+        }
+        Ok(match language.to_ascii_lowercase().as_str() {
+            "tr" | "az" => Self::Turkic,
+            "lt" => polars_bail!(
+                InvalidOperation: "the casing rules of locale '{}' are not supported", locale
+            ),
+            _ => Self::Default,
+        })
+    }
+}
+
+/// Convert to lowercase using the casing rules of `locale`.
+///
+/// Only Turkish and Azerbaijani tailor the default Unicode casing, Lithuanian is rejected.
+pub fn to_lowercase_locale(ca: &StringChunked, locale: &str) -> PolarsResult<StringChunked> {
+    if Casing::from_locale(locale)? == Casing::Default {
+        return Ok(normalize_with(ca, |s, b| b.push_str(&s.to_lowercase())));
+    }
+    Ok(normalize_with(ca, |s, b| {
+        let mut mapped = String::with_capacity(s.len());
+        let mut chars = s.chars().peekable();
+        while let Some(c) = chars.next() {
+            mapped.push(match c {
+                // `I` followed by a combining dot above is a dotted `i`.
+                'I' if chars.next_if_eq(&'\u{307}').is_some() => 'i',
+                'I' => 'ı',
+                'İ' => 'i',
+                c => c,
+            });
+        }
+        b.push_str(&mapped.to_lowercase())
+    }))
+}
+
+/// Convert to uppercase using the casing rules of `locale`.
+///
+/// Only Turkish and Azerbaijani tailor the default Unicode casing, Lithuanian is rejected.
+pub fn to_uppercase_locale(ca: &StringChunked, locale: &str) -> PolarsResult<StringChunked> {
+    if Casing::from_locale(locale)? == Casing::Default {
+        return Ok(normalize_with(ca, |s, b| b.push_str(&s.to_uppercase())));
+    }
+    Ok(normalize_with(ca, |s, b| {
+        let mapped: String = s.chars().map(|c| if c == 'i' { 'İ' } else { c }).collect();
+        b.push_str(&mapped.to_uppercase())
+    }))
+}
+
+fn casefold_into(s: &str, buf: &mut String) {
+    let mut folded = String::with_capacity(s.len());
+    for c in s.nfkd() {
+        match c {
+            'ß' | 'ẞ' => folded.push_str("ss"),
+            'ς' => folded.push('σ'),
+            c => folded.extend(c.to_lowercase()),
+        }
+    }
+    buf.extend(folded.nfc());
+}
+
+/// Fold case for caseless matching.
+///
+/// The string is decomposed (NFKD), lowercased with the default Unicode
+/// mapping, with `ß` folded to `ss` and the final sigma to `σ`, and composed
+/// again (NFC). This covers the case folding of the Latin, Greek and Cyrillic
+/// scripts, so compatibility equivalent strings that only differ in case there
+/// fold to the same string and the result can be used as a join or group key.
+/// It is not the full Unicode `NFKC_Casefold` mapping: e.g. Cherokee, whose
+/// case folds to uppercase, isn't folded.
+pub fn casefold(ca: &StringChunked) -> StringChunked {
+    normalize_with(ca, casefold_into)
+}
+
+/// Compute a binary sort key that orders strings by their base letters first,
+/// then by accents and finally by case and the exact code points.
+///
+/// The levels are separated by a zero byte. Sorting by this key puts e.g.
+/// `"resume"`, `"Résumé"` and `"resumes"` next to each other, where a plain
+/// code point sort does not.
+///
+/// This is not the Unicode Collation Algorithm and isn't locale-aware: within
+/// every level the characters are compared by code point, so e.g. Swedish `ö`
+/// sorts with `o` rather than after `z`, and non-Latin scripts sort in code
+/// point order.
+pub fn collation_key(ca: &StringChunked) -> BinaryChunked {
+    let mut folded = String::new();
+    let mut key = Vec::new();
+    ca.apply_nonnull_values_generic(DataType::Binary, |s| {
+        folded.clear();
+        key.clear();
+        casefold_into(s, &mut folded);
+
+        // Primary: base letters without case.
+        key.extend(
+            folded
+                .nfd()
+                .filter(|c| !is_combining_mark(*c))
+                .collect::<String>()
+                .bytes(),
+        );
+        key.push(0);
+        // Secondary: accents.
+        key.extend(folded.nfd().collect::<String>().bytes());
+        key.push(0);
+        // Tertiary: case and exact representation.
+        key.extend(s.nfd().collect::<String>().bytes());
+        key.clone()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_casefold_and_locale_casing() {
+        let ca = StringChunked::new("a".into(), &[Some("Straße"), Some("STRASSE"), None]);
+        let out = casefold(&ca);
+        assert_eq!(out.get(0), Some("strasse"));
+        assert_eq!(out.get(0), out.get(1));
+        assert_eq!(out.get(2), None);
+
+        let ca = StringChunked::new("a".into(), &["Iıİi", "I\u{307}"]);
+        let lower = to_lowercase_locale(&ca, "tr-TR").unwrap();
+        assert_eq!(lower.get(0), Some("ııii"));
+        assert_eq!(lower.get(1), Some("i"));
+        assert_eq!(to_uppercase_locale(&ca, "tr").unwrap().get(0), Some("IIİİ"));
+        assert_eq!(to_uppercase_locale(&ca, "en").unwrap().get(0), Some("IIİI"));
+        assert!(to_lowercase_locale(&ca, "lt").is_err());
+        assert!(to_lowercase_locale(&ca, "").is_err());
+    }
+
+    #[test]
+    fn test_collation_key() {
+        let ca = StringChunked::new("a".into(), &["resumes", "Résumé", "resume", "rz"]);
+        let mut keys = collation_key(&ca)
+            .into_no_null_iter()
+            .zip(ca.into_no_null_iter())
+            .collect::<Vec<_>>();
+        keys.sort();
+        let sorted = keys.into_iter().map(|(_, s)| s).collect::<Vec<_>>();
+        assert_eq!(sorted, ["resume", "Résumé", "resumes", "rz"]);
+    }
+}
@@ -0,0 +1,99 @@
+use std::cell::RefCell;
+
+use polars_core::prelude::arity::{broadcast_binary_elementwise, broadcast_try_binary_elementwise};
+use polars_core::prelude::*;
+use polars_utils::cache::LruCache;
+
+use super::StringNameSpaceImpl;
+
+/// The regular expression engine used to evaluate a pattern.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RegexEngine {
+    /// The default engine. Matching is fast and Unicode-aware, but compiling a
+    /// pattern is relatively expensive.
+    #[default]
+    Regex,
+    /// A lightweight engine that compiles patterns much faster at the cost of
+    /// slower matching and ASCII-only character classes. Preferable when there
+    /// are many distinct patterns that are each used only a few times, e.g.
+    /// a pattern per row.
+    Lite,
+}
+
+thread_local! {
+    static LITE_REGEX_CACHE: RefCell<LruCache<String, regex_lite::Regex>> =
+        RefCell::new(LruCache::with_capacity(64));
+}
+
+fn compile_lite<'a>(
+    cache: &'a mut LruCache<String, regex_lite::Regex>,
+    pat: &str,
+) -> PolarsResult<&'a regex_lite::Regex> {
+    cache
+        .try_get_or_insert_with(pat, regex_lite::Regex::new)
+        .map(|re| &*re)
+        .map_err(|e| polars_err!(ComputeError: "invalid regular expression: {}", e))
+}
+
+/// Check if the strings match the regular expressions in `pat`, using the given
+/// `engine`.
+///
+/// If `strict` is `false` an invalid pattern evaluates to null instead of
+/// raising an error.
+pub fn contains_with_engine(
+    ca: &StringChunked,
+    pat: &StringChunked,
+    engine: RegexEngine,
+    strict: bool,
+) -> PolarsResult<BooleanChunked> {
+    if engine == RegexEngine::Regex {
+        return ca.contains_chunked(pat, false, strict);
+    }
+
+    LITE_REGEX_CACHE.with_borrow_mut(|cache| {
+        if strict {
+            broadcast_try_binary_elementwise(
+                ca,
+                pat,
+                |src: Option<&str>, pat: Option<&str>| match (src, pat) {
+                    (Some(src), Some(pat)) => Ok(Some(compile_lite(cache, pat)?.is_match(src))),
+                    _ => Ok(None),
+                },
+            )
+        } else {
+            Ok(broadcast_binary_elementwise(
+                ca,
+                pat,
+                |src: Option<&str>, pat: Option<&str>| {
+                    Some(compile_lite(cache, pat?).ok()?.is_match(src?))
+                },
+            ))
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_contains_with_engine() -> PolarsResult<()> {
+        let ca = StringChunked::new(
+            "a".into(),
+            &[Some("foo123"), Some("bar"), None, Some("baz")],
+        );
+        let pat = StringChunked::new(
+            "p".into(),
+            &[Some(r"\d+"), Some("^b"), Some("x"), Some("(")],
+        );
+
+        let out = contains_with_engine(&ca, &pat, RegexEngine::Lite, false)?;
+        assert_eq!(Vec::from(&out), &[Some(true), Some(true), None, None]);
+        assert!(contains_with_engine(&ca, &pat, RegexEngine::Lite, true).is_err());
+
+        let out = contains_with_engine(&ca.slice(0, 3), &pat.slice(0, 3), RegexEngine::Lite, true);
+        assert_eq!(Vec::from(&out?), &[Some(true), Some(true), None]);
+        Ok(())
+    }
+}
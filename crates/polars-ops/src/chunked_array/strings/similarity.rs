@@ -0,0 +1,153 @@
+use polars_core::prelude::arity::broadcast_binary_elementwise_values;
+use polars_core::prelude::*;
+use polars_utils::aliases::PlHashMap;
+
+/// Number of single character insertions, deletions and substitutions needed
+/// to turn `a` into `b`.
+fn levenshtein_str(a: &str, b: &str) -> u32 {
+    if a == b {
+        return 0;
+    }
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<u32> = (0..=b.len() as u32).collect();
+    let mut cur = vec![0u32; b.len() + 1];
+    for (i, ca) in a.chars().enumerate() {
+        cur[0] = i as u32 + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + (ca != *cb) as u32;
+            cur[j + 1] = substitution.min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[b.len()]
+}
+
+fn jaro_str(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+    let mut matches = 0usize;
+    for (i, ca) in a.iter().enumerate() {
+        let lo = i.saturating_sub(window);
+        let hi = (i + window + 1).min(b.len());
+        for j in lo..hi {
+            if !b_matched[j] && b[j] == *ca {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let mut transpositions = 0usize;
+    let mut b_iter = b.iter().zip(&b_matched).filter(|(_, m)| **m);
+    for (ca, _) in a.iter().zip(&a_matched).filter(|(_, m)| **m) {
+        let (cb, _) = b_iter.next().unwrap();
+        transpositions += (ca != cb) as usize;
+    }
+
+    let m = matches as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - (transpositions / 2) as f64) / m) / 3.0
+}
+
+/// Jaro similarity boosted by the length of the common prefix (up to 4
+/// characters) with the standard scaling factor of 0.1.
+fn jaro_winkler_str(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let jaro = jaro_str(&a, &b);
+    let prefix = a
+        .iter()
+        .zip(&b)
+        .take(4)
+        .take_while(|(ca, cb)| ca == cb)
+        .count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+fn ngrams(s: &str, n: usize) -> PlHashMap<&str, u32> {
+    let mut out = PlHashMap::new();
+    let bounds: Vec<usize> = s
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(s.len()))
+        .collect();
+    for w in bounds.windows(n + 1) {
+        *out.entry(&s[w[0]..w[n]]).or_insert(0) += 1;
+    }
+    out
+}
+
+/// Sørensen-Dice coefficient of the character n-gram multisets of `a` and `b`.
+fn ngram_similarity_str(a: &str, b: &str, n: usize) -> f64 {
+    if a == b {
+        return 1.0;
+    }
+    let a = ngrams(a, n);
+    let b = ngrams(b, n);
+    let total: u32 = a.values().chain(b.values()).sum();
+    if total == 0 {
+        return 0.0;
+    }
+    let shared: u32 = a
+        .iter()
+        .map(|(gram, count)| b.get(gram).map_or(0, |other| *count.min(other)))
+        .sum();
+    2.0 * shared as f64 / total as f64
+}
+
+/// Levenshtein edit distance in characters between each pair of strings.
+pub fn levenshtein(ca: &StringChunked, other: &StringChunked) -> UInt32Chunked {
+    broadcast_binary_elementwise_values(ca, other, levenshtein_str)
+}
+
+/// Jaro-Winkler similarity in `[0, 1]` between each pair of strings.
+pub fn jaro_winkler(ca: &StringChunked, other: &StringChunked) -> Float64Chunked {
+    broadcast_binary_elementwise_values(ca, other, jaro_winkler_str)
+}
+
+/// Character n-gram similarity in `[0, 1]` between each pair of strings.
+pub fn ngram_similarity(
+    ca: &StringChunked,
+    other: &StringChunked,
+    n: usize,
+) -> PolarsResult<Float64Chunked> {
+    polars_ensure!(n > 0, InvalidOperation: "n-gram size must be positive");
+    Ok(broadcast_binary_elementwise_values(ca, other, |a, b| {
+        ngram_similarity_str(a, b, n)
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_string_similarity() {
+        assert_eq!(levenshtein_str("kitten", "sitting"), 3);
+        assert_eq!(levenshtein_str("", "abc"), 3);
+        assert_eq!(levenshtein_str("ñandú", "nandu"), 2);
+
+        assert!((jaro_winkler_str("MARTHA", "MARHTA") - 0.961).abs() < 1e-3);
+        assert!((jaro_winkler_str("DIXON", "DICKSONX") - 0.813).abs() < 1e-3);
+        assert_eq!(jaro_winkler_str("abc", "xyz"), 0.0);
+
+        assert_eq!(ngram_similarity_str("night", "nacht", 2), 0.25);
+        assert_eq!(ngram_similarity_str("a", "b", 2), 0.0);
+
+        let a = StringChunked::new("a".into(), &[Some("kitten"), None, Some("flaw")]);
+        let b = StringChunked::new("b".into(), &["sitting"]);
+        let out = levenshtein(&a, &b);
+        assert_eq!(Vec::from(&out), &[Some(3), None, Some(7)]);
+    }
+}
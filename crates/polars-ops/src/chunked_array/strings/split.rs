@@ -223,3 +223,73 @@ where
         _ => polars_bail!(length_mismatch = "str.split", ca.len(), by.len()),
     })
 }
+
+/// Splits by a literal substring, making at most `maxsplit` splits. The last
+/// element of every list holds the unsplit remainder.
+pub fn split_max(
+    ca: &StringChunked,
+    by: &StringChunked,
+    maxsplit: usize,
+) -> PolarsResult<ListChunked> {
+    split_helper(ca, by, |s, by| s.splitn(maxsplit.saturating_add(1), by))
+}
+
+/// Splits by the matches of a regular expression, making at most `maxsplit`
+/// splits if given. The last element of every list holds the unsplit remainder.
+pub fn split_regex(
+    ca: &StringChunked,
+    pat: &StringChunked,
+    maxsplit: Option<usize>,
+) -> PolarsResult<ListChunked> {
+    fn push(
+        builder: &mut ListStringChunkedBuilder,
+        re: &regex::Regex,
+        s: &str,
+        maxsplit: Option<usize>,
+    ) {
+        match maxsplit {
+            Some(n) => builder.append_values_iter(re.splitn(s, n.saturating_add(1))),
+            None => builder.append_values_iter(re.split(s)),
+        }
+    }
+
+    let len = match (ca.len(), pat.len()) {
+        (a, b) if a == b => a,
+        (1, b) => b,
+        (a, 1) => a,
+        (a, b) => polars_bail!(length_mismatch = "str.split_regex", a, b),
+    };
+    let mut builder = ListStringChunkedBuilder::new(ca.name().clone(), len, ca.get_values_size());
+
+    if pat.len() == 1 {
+        let Some(pat) = pat.get(0) else {
+            return Ok(ListChunked::full_null_with_dtype(
+                ca.name().clone(),
+                len,
+                &DataType::String,
+            ));
+        };
+        let re = polars_utils::regex_cache::compile_regex(pat)?;
+        ca.for_each(|opt_s| match opt_s {
+            Some(s) => push(&mut builder, &re, s, maxsplit),
+            None => builder.append_null(),
+        });
+        return Ok(builder.finish());
+    }
+
+    let values: Box<dyn Iterator<Item = Option<&str>>> = if ca.len() == len {
+        Box::new(ca.iter())
+    } else {
+        Box::new(std::iter::repeat_n(ca.get(0), len))
+    };
+    polars_utils::regex_cache::with_regex_cache(|cache| {
+        for (opt_s, opt_pat) in values.zip(pat.iter()) {
+            match (opt_s, opt_pat) {
+                (Some(s), Some(pat)) => push(&mut builder, cache.compile(pat)?, s, maxsplit),
+                _ => builder.append_null(),
+            }
+        }
+        PolarsResult::Ok(())
+    })?;
+    Ok(builder.finish())
+}
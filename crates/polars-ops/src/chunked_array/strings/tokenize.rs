@@ -0,0 +1,136 @@
+use polars_core::prelude::*;
+use polars_utils::regex_cache::compile_regex;
+use regex::Regex;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// How [`tokenize`] splits a string into tokens.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Tokenizer {
+    /// Words according to the Unicode word boundary rules (UAX #29).
+    /// Punctuation and whitespace are dropped.
+    Words,
+    /// Runs of non-whitespace characters.
+    Whitespace,
+    /// Every non-overlapping match of a regular expression.
+    Pattern(PlSmallStr),
+}
+
+fn build_list<'a, F, I>(ca: &'a StringChunked, mut f: F) -> ListChunked
+where
+    F: FnMut(&'a str) -> I,
+    I: Iterator<Item = &'a str>,
+{
+    let mut builder = ListStringChunkedBuilder::new(ca.name().clone(), ca.len(), ca.len() * 4);
+    for opt_s in ca.iter() {
+        match opt_s {
+            Some(s) => builder.append_values_iter(f(s)),
+            None => builder.append_null(),
+        }
+    }
+    builder.finish()
+}
+
+/// Split every string into a list of tokens.
+pub fn tokenize(ca: &StringChunked, tokenizer: &Tokenizer) -> PolarsResult<ListChunked> {
+    Ok(match tokenizer {
+        Tokenizer::Words => build_list(ca, |s| s.unicode_words()),
+        Tokenizer::Whitespace => build_list(ca, |s| s.split_whitespace()),
+        Tokenizer::Pattern(pat) => {
+            let re: Regex = compile_regex(pat)?;
+            build_list(ca, |s| re.find_iter(s).map(|m| m.as_str()))
+        },
+    })
+}
+
+/// The overlapping substrings of `n` consecutive characters of every string.
+///
+/// Characters are extended grapheme clusters, so combining marks stay attached
+/// to their base character. Strings shorter than `n` yield an empty list.
+pub fn ngrams(ca: &StringChunked, n: usize) -> PolarsResult<ListChunked> {
+    polars_ensure!(n > 0, InvalidOperation: "n-gram size must be positive");
+    let mut bounds = Vec::new();
+    Ok(build_list(ca, |s| {
+        bounds.clear();
+        bounds.extend(s.grapheme_indices(true).map(|(i, _)| i));
+        bounds.push(s.len());
+        let grams: Vec<&str> = bounds.windows(n + 1).map(|w| &s[w[0]..w[n]]).collect();
+        grams.into_iter()
+    }))
+}
+
+/// The overlapping sequences of `k` consecutive words of every string, joined
+/// by a single space.
+///
+/// Words are determined by the Unicode word boundary rules, so the shingles
+/// are insensitive to punctuation and repeated whitespace.
+pub fn shingle(ca: &StringChunked, k: usize) -> PolarsResult<ListChunked> {
+    polars_ensure!(k > 0, InvalidOperation: "shingle size must be positive");
+    let mut builder = ListStringChunkedBuilder::new(ca.name().clone(), ca.len(), ca.len() * 4);
+    let mut words = Vec::new();
+    for opt_s in ca.iter() {
+        match opt_s {
+            Some(s) => {
+                words.clear();
+                words.extend(s.unicode_words());
+                let shingles: Vec<String> = words.windows(k).map(|w| w.join(" ")).collect();
+                builder.append_values_iter(shingles.iter().map(|s| s.as_str()));
+            },
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn to_vecs(ca: &ListChunked) -> Vec<Option<Vec<String>>> {
+        ca.into_iter()
+            .map(|opt_s| {
+                opt_s.map(|s| {
+                    s.str()
+                        .unwrap()
+                        .into_no_null_iter()
+                        .map(|s| s.to_string())
+                        .collect()
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_tokenize() -> PolarsResult<()> {
+        let ca = StringChunked::new(
+            "a".into(),
+            &[Some("The quick (\"brown\") fox can't jump"), None],
+        );
+
+        let out = tokenize(&ca, &Tokenizer::Words)?;
+        assert_eq!(
+            to_vecs(&out)[0].as_deref().unwrap(),
+            ["The", "quick", "brown", "fox", "can't", "jump"]
+        );
+        assert_eq!(to_vecs(&out)[1], None);
+
+        let out = tokenize(&ca, &Tokenizer::Pattern("[a-z]+".into()))?;
+        assert_eq!(
+            to_vecs(&out)[0].as_deref().unwrap(),
+            ["he", "quick", "brown", "fox", "can", "t", "jump"]
+        );
+
+        let ca = StringChunked::new("a".into(), &["to be, or not  to be"]);
+        let out = shingle(&ca, 2)?;
+        assert_eq!(
+            to_vecs(&out)[0].as_deref().unwrap(),
+            ["to be", "be or", "or not", "not to", "to be"]
+        );
+
+        let ca = StringChunked::new("a".into(), &["abcd", "a"]);
+        let out = ngrams(&ca, 3)?;
+        assert_eq!(to_vecs(&out)[0].as_deref().unwrap(), ["abc", "bcd"]);
+        assert!(to_vecs(&out)[1].as_deref().unwrap().is_empty());
+        Ok(())
+    }
+}
@@ -27,6 +27,9 @@ pub struct JoinArgs {
     pub suffix: Option<PlSmallStr>,
     pub slice: Option<(i64, usize)>,
     pub nulls_equal: bool,
+    /// Overrides `nulls_equal` for every key separately.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub nulls_equal_per_key: Option<Vec<bool>>,
     pub coalesce: JoinCoalesce,
     pub maintain_order: MaintainOrderJoin,
 }
@@ -35,6 +38,21 @@ impl JoinArgs {
     pub fn should_coalesce(&self) -> bool {
         self.coalesce.coalesce(&self.how)
     }
+
+    /// Whether nulls are equal, for each of the `n_keys` keys.
+    pub fn nulls_equal_for_keys(&self, n_keys: usize) -> PolarsResult<Vec<bool>> {
+        match &self.nulls_equal_per_key {
+            Some(nulls_equal) => {
+                polars_ensure!(
+                    nulls_equal.len() == n_keys,
+                    InvalidOperation: "got {} 'nulls_equal' values for {} join keys",
+                    nulls_equal.len(), n_keys
+                );
+                Ok(nulls_equal.clone())
+            },
+            None => Ok(vec![self.nulls_equal; n_keys]),
+        }
+    }
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, Default, IntoStaticStr)]
@@ -121,6 +139,7 @@ impl JoinArgs {
             suffix: None,
             slice: None,
             nulls_equal: false,
+            nulls_equal_per_key: None,
             coalesce: Default::default(),
             maintain_order: Default::default(),
         }
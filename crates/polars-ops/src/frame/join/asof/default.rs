@@ -8,6 +8,13 @@ use super::{
     AsofJoinBackwardState, AsofJoinForwardState, AsofJoinNearestState, AsofJoinState, AsofStrategy,
 };
 
+/// The right rows matching the left rows of an asof join.
+pub(crate) enum AsofMatches {
+    Single(IdxCa),
+    /// The backward and the forward matches of a [`AsofStrategy::Bracket`] join.
+    Bracket(IdxCa, IdxCa),
+}
+
 fn join_asof_impl<'a, T, S, F>(
     left: &'a T::Array,
     right: &'a T::Array,
@@ -67,6 +74,59 @@ where
     IdxCa::from_vec_validity(PlSmallStr::EMPTY, out, Some(bitmap))
 }
 
+/// Find both the backward and the forward match of every left value in a single pass.
+fn join_asof_bracket<'a, T, F>(
+    left: &'a T::Array,
+    right: &'a T::Array,
+    mut filter: F,
+    allow_eq: bool,
+) -> AsofMatches
+where
+    T: PolarsDataType,
+    T::Physical<'a>: PartialOrd,
+    F: FnMut(T::Physical<'a>, T::Physical<'a>) -> bool,
+{
+    if left.len() == left.null_count() || right.len() == right.null_count() {
+        let out = IdxCa::full_null(PlSmallStr::EMPTY, left.len());
+        return AsofMatches::Bracket(out.clone(), out);
+    }
+
+    let n_right = right.len() as IdxSize;
+    let mut backward_state = AsofJoinBackwardState::new(allow_eq);
+    let mut forward_state = AsofJoinForwardState::new(allow_eq);
+    let mut backward = Vec::with_capacity(left.len());
+    let mut forward = Vec::with_capacity(left.len());
+
+    for opt_val_l in left.iter() {
+        let Some(val_l) = opt_val_l else {
+            backward.push(None);
+            forward.push(None);
+            continue;
+        };
+        // SAFETY: next() only calls with indices < right.len().
+        let get_right = |j: IdxSize| unsafe { right.get_unchecked(j as usize) };
+        for (state_idx, out) in [
+            AsofJoinState::next(&mut backward_state, &val_l, get_right, n_right),
+            AsofJoinState::next(&mut forward_state, &val_l, get_right, n_right),
+        ]
+        .into_iter()
+        .zip([&mut backward, &mut forward])
+        {
+            // SAFETY: the matched index is non-null and valid.
+            out.push(state_idx.filter(|&r_idx| {
+                filter(val_l.clone(), unsafe {
+                    right.value_unchecked(r_idx as usize)
+                })
+            }));
+        }
+    }
+
+    AsofMatches::Bracket(
+        IdxCa::from_iter_options(PlSmallStr::EMPTY, backward.into_iter()),
+        IdxCa::from_iter_options(PlSmallStr::EMPTY, forward.into_iter()),
+    )
+}
+
 fn join_asof_forward<'a, T, F>(
     left: &'a T::Array,
     right: &'a T::Array,
@@ -115,7 +175,7 @@ pub(crate) fn join_asof_numeric<T: PolarsNumericType>(
     strategy: AsofStrategy,
     tolerance: Option<AnyValue<'static>>,
     allow_eq: bool,
-) -> PolarsResult<IdxCa> {
+) -> PolarsResult<AsofMatches> {
     let other = input_ca.unpack_series_matching_type(other)?;
 
     let ca = input_ca.rechunk();
@@ -131,6 +191,9 @@ pub(crate) fn join_asof_numeric<T: PolarsNumericType>(
             AsofStrategy::Forward => join_asof_forward::<T, _>(left, right, filter, allow_eq),
             AsofStrategy::Backward => join_asof_backward::<T, _>(left, right, filter, allow_eq),
             AsofStrategy::Nearest => join_asof_nearest::<T, _>(left, right, filter, allow_eq),
+            AsofStrategy::Bracket => {
+                return Ok(join_asof_bracket::<T, _>(left, right, filter, allow_eq));
+            },
         }
     } else {
         let filter = |_l: T::Native, _r: T::Native| true;
@@ -138,9 +201,12 @@ pub(crate) fn join_asof_numeric<T: PolarsNumericType>(
             AsofStrategy::Forward => join_asof_forward::<T, _>(left, right, filter, allow_eq),
             AsofStrategy::Backward => join_asof_backward::<T, _>(left, right, filter, allow_eq),
             AsofStrategy::Nearest => join_asof_nearest::<T, _>(left, right, filter, allow_eq),
+            AsofStrategy::Bracket => {
+                return Ok(join_asof_bracket::<T, _>(left, right, filter, allow_eq));
+            },
         }
     };
-    Ok(out)
+    Ok(AsofMatches::Single(out))
 }
 
 pub(crate) fn join_asof<T>(
@@ -148,7 +214,7 @@ pub(crate) fn join_asof<T>(
     other: &Series,
     strategy: AsofStrategy,
     allow_eq: bool,
-) -> PolarsResult<IdxCa>
+) -> PolarsResult<AsofMatches>
 where
     T: PolarsDataType,
     for<'a> T::Physical<'a>: PartialOrd,
@@ -162,13 +228,14 @@ where
 
     let filter = |_l: T::Physical<'_>, _r: T::Physical<'_>| true;
     Ok(match strategy {
-        AsofStrategy::Forward => {
-            join_asof_impl::<T, AsofJoinForwardState, _>(left, right, filter, allow_eq)
-        },
-        AsofStrategy::Backward => {
-            join_asof_impl::<T, AsofJoinBackwardState, _>(left, right, filter, allow_eq)
-        },
+        AsofStrategy::Forward => AsofMatches::Single(join_asof_impl::<T, AsofJoinForwardState, _>(
+            left, right, filter, allow_eq,
+        )),
+        AsofStrategy::Backward => AsofMatches::Single(
+            join_asof_impl::<T, AsofJoinBackwardState, _>(left, right, filter, allow_eq),
+        ),
         AsofStrategy::Nearest => unimplemented!(),
+        AsofStrategy::Bracket => join_asof_bracket::<T, _>(left, right, filter, allow_eq),
     })
 }
 
@@ -225,6 +292,40 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_asof_bracket() {
+        let a = PrimitiveArray::from_slice([-1, 1, 2, 3, 4, 6]);
+        let b = PrimitiveArray::from_slice([1, 2, 2, 5]);
+
+        let AsofMatches::Bracket(backward, forward) =
+            join_asof_bracket::<Int32Type, _>(&a, &b, |_, _| true, true)
+        else {
+            unreachable!()
+        };
+        assert_eq!(
+            backward.to_vec(),
+            &[None, Some(0), Some(2), Some(2), Some(2), Some(3)]
+        );
+        assert_eq!(
+            forward.to_vec(),
+            &[Some(0), Some(0), Some(1), Some(3), Some(3), None]
+        );
+
+        let AsofMatches::Bracket(backward, forward) =
+            join_asof_bracket::<Int32Type, _>(&a, &b, |l, r| l.abs_diff(r) <= 1u32, false)
+        else {
+            unreachable!()
+        };
+        assert_eq!(
+            backward.to_vec(),
+            &[None, None, Some(0), Some(2), None, Some(3)]
+        );
+        assert_eq!(
+            forward.to_vec(),
+            &[None, Some(1), None, None, Some(3), None]
+        );
+    }
+
     #[test]
     fn test_asof_forward() {
         let a = PrimitiveArray::from_slice([-1, 1, 2, 4, 6]);
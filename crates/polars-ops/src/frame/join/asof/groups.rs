@@ -35,6 +35,140 @@ fn materialize_nullable(idx: Option<IdxSize>) -> NullableIdxSize {
     }
 }
 
+/// The matches of a left row in an asof join by groups.
+trait AsofGroupMatches {
+    /// The matched right rows of a left row.
+    type Matches: Copy + Send + Sync;
+
+    fn no_matches() -> Self::Matches;
+
+    fn finish(bufs: Vec<Vec<Self::Matches>>) -> AsofMatches;
+}
+
+/// The state of an asof join within a group of the `by` keys.
+trait AsofGroupState<T>: AsofGroupMatches + Sized {
+    fn new(allow_eq: bool) -> Self;
+
+    /// Find the matches of `left_val` in the group, `matched` maps an index into the group to the
+    /// index of the right row if it passes the filter.
+    fn next_matches<F, M>(
+        &mut self,
+        left_val: &T,
+        right: F,
+        n_right: IdxSize,
+        matched: M,
+    ) -> Self::Matches
+    where
+        F: FnMut(IdxSize) -> Option<T>,
+        M: FnMut(IdxSize) -> Option<IdxSize>;
+}
+
+macro_rules! impl_single_group_matches {
+    ($($state:ty),*) => {
+        $(
+            impl AsofGroupMatches for $state {
+                type Matches = NullableIdxSize;
+
+                fn no_matches() -> Self::Matches {
+                    NullableIdxSize::null()
+                }
+
+                fn finish(bufs: Vec<Vec<Self::Matches>>) -> AsofMatches {
+                    AsofMatches::Single(IdxCa::with_chunk(
+                        PlSmallStr::EMPTY,
+                        flatten_nullable(&bufs),
+                    ))
+                }
+            }
+        )*
+    };
+}
+
+impl_single_group_matches!(
+    AsofJoinBackwardState,
+    AsofJoinForwardState,
+    AsofJoinNearestState
+);
+
+impl<T, A> AsofGroupState<T> for A
+where
+    A: AsofJoinState<T> + AsofGroupMatches<Matches = NullableIdxSize>,
+{
+    fn new(allow_eq: bool) -> Self {
+        <A as AsofJoinState<T>>::new(allow_eq)
+    }
+
+    #[inline]
+    fn next_matches<F, M>(
+        &mut self,
+        left_val: &T,
+        right: F,
+        n_right: IdxSize,
+        matched: M,
+    ) -> Self::Matches
+    where
+        F: FnMut(IdxSize) -> Option<T>,
+        M: FnMut(IdxSize) -> Option<IdxSize>,
+    {
+        materialize_nullable(self.next(left_val, right, n_right).and_then(matched))
+    }
+}
+
+/// Tracks the backward and the forward match together, so that a bracket join needs a single
+/// pass over every group.
+struct AsofJoinBracketState {
+    backward: AsofJoinBackwardState,
+    forward: AsofJoinForwardState,
+}
+
+impl AsofGroupMatches for AsofJoinBracketState {
+    type Matches = [NullableIdxSize; 2];
+
+    fn no_matches() -> Self::Matches {
+        [NullableIdxSize::null(); 2]
+    }
+
+    fn finish(bufs: Vec<Vec<Self::Matches>>) -> AsofMatches {
+        let side = |i: usize| {
+            let bufs = bufs
+                .iter()
+                .map(|buf| buf.iter().map(|m| m[i]).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            IdxCa::with_chunk(PlSmallStr::EMPTY, flatten_nullable(&bufs))
+        };
+        AsofMatches::Bracket(side(0), side(1))
+    }
+}
+
+impl<T: PartialOrd> AsofGroupState<T> for AsofJoinBracketState {
+    fn new(allow_eq: bool) -> Self {
+        AsofJoinBracketState {
+            backward: AsofJoinState::<T>::new(allow_eq),
+            forward: AsofJoinState::<T>::new(allow_eq),
+        }
+    }
+
+    #[inline]
+    fn next_matches<F, M>(
+        &mut self,
+        left_val: &T,
+        mut right: F,
+        n_right: IdxSize,
+        mut matched: M,
+    ) -> Self::Matches
+    where
+        F: FnMut(IdxSize) -> Option<T>,
+        M: FnMut(IdxSize) -> Option<IdxSize>,
+    {
+        let backward = self.backward.next(left_val, &mut right, n_right);
+        let forward = self.forward.next(left_val, &mut right, n_right);
+        [
+            materialize_nullable(backward.and_then(&mut matched)),
+            materialize_nullable(forward.and_then(&mut matched)),
+        ]
+    }
+}
+
 fn asof_in_group<'a, T, A, F>(
     left_val: T::Physical<'a>,
     right_val_arr: &'a T::Array,
@@ -42,34 +176,39 @@ fn asof_in_group<'a, T, A, F>(
     group_states: &mut PlHashMap<IdxSize, A>,
     filter: F,
     allow_eq: bool,
-) -> Option<IdxSize>
+) -> A::Matches
 where
     T: PolarsDataType,
-    A: AsofJoinState<T::Physical<'a>>,
+    A: AsofGroupState<T::Physical<'a>>,
     F: Fn(T::Physical<'a>, T::Physical<'a>) -> bool,
 {
     // We use the index of the first element in a group as an identifier to
     // associate with the group state.
-    let id = right_grp_idxs.first()?;
+    let Some(id) = right_grp_idxs.first() else {
+        return A::no_matches();
+    };
     let grp_state = group_states.entry(*id).or_insert_with(|| A::new(allow_eq));
 
-    unsafe {
-        let r_grp_idx = grp_state.next(
-            &left_val,
-            |i| {
-                // SAFETY: the group indices are valid, and next() only calls with
-                // i < right_grp_idxs.len().
+    grp_state.next_matches(
+        &left_val,
+        |i| {
+            // SAFETY: the group indices are valid, and next() only calls with
+            // i < right_grp_idxs.len().
+            unsafe {
                 right_val_arr.get_unchecked(*right_grp_idxs.get_unchecked(i as usize) as usize)
-            },
-            right_grp_idxs.len() as IdxSize,
-        )?;
-
-        // SAFETY: r_grp_idx is valid, as is r_idx (which must be non-null) if
-        // we get here.
-        let r_idx = *right_grp_idxs.get_unchecked(r_grp_idx as usize);
-        let right_val = right_val_arr.value_unchecked(r_idx as usize);
-        filter(left_val, right_val).then_some(r_idx)
-    }
+            }
+        },
+        right_grp_idxs.len() as IdxSize,
+        |r_grp_idx| {
+            // SAFETY: r_grp_idx is valid, as is r_idx (which must be non-null) if
+            // we get here.
+            let (r_idx, right_val) = unsafe {
+                let r_idx = *right_grp_idxs.get_unchecked(r_grp_idx as usize);
+                (r_idx, right_val_arr.value_unchecked(r_idx as usize))
+            };
+            filter(left_val.clone(), right_val).then_some(r_idx)
+        },
+    )
 }
 
 fn asof_join_by_numeric<T, S, A, F>(
@@ -79,13 +218,13 @@ fn asof_join_by_numeric<T, S, A, F>(
     right_asof: &ChunkedArray<T>,
     filter: F,
     allow_eq: bool,
-) -> PolarsResult<IdxArr>
+) -> PolarsResult<AsofMatches>
 where
     T: PolarsDataType,
     S: PolarsNumericType,
     S::Native: TotalHash + TotalEq + DirtyHash + ToTotalOrd,
     <S::Native as ToTotalOrd>::TotalOrdItem: Send + Sync + Copy + Hash + Eq + DirtyHash + IsNull,
-    A: for<'a> AsofJoinState<T::Physical<'a>>,
+    A: AsofGroupMatches + for<'a> AsofGroupState<T::Physical<'a>>,
     F: Sync + for<'a> Fn(T::Physical<'a>, T::Physical<'a>) -> bool,
 {
     let (left_asof, right_asof) = POOL.join(|| left_asof.rechunk(), || right_asof.rechunk());
@@ -123,13 +262,13 @@ where
             let by_left_chunk = by_left.downcast_iter().next().unwrap();
             for (rel_idx_left, opt_by_left_k) in by_left_chunk.iter().enumerate() {
                 let Some(by_left_k) = opt_by_left_k else {
-                    results.push(NullableIdxSize::null());
+                    results.push(A::no_matches());
                     continue;
                 };
                 let by_left_k = by_left_k.to_total_ord();
                 let idx_left = (rel_idx_left + offset) as IdxSize;
                 let Some(left_val) = left_val_arr.get(idx_left as usize) else {
-                    results.push(NullableIdxSize::null());
+                    results.push(A::no_matches());
                     continue;
                 };
 
@@ -137,24 +276,23 @@ where
                     hash_tbls.get_unchecked(hash_to_partition(by_left_k.dirty_hash(), n_tables))
                 };
                 let Some(right_grp_idxs) = group_probe_table.get(&by_left_k) else {
-                    results.push(NullableIdxSize::null());
+                    results.push(A::no_matches());
                     continue;
                 };
-                let id = asof_in_group::<T, A, &F>(
+                results.push(asof_in_group::<T, A, &F>(
                     left_val,
                     right_val_arr,
                     right_grp_idxs.as_slice(),
                     &mut group_states,
                     &filter,
                     allow_eq,
-                );
-                results.push(materialize_nullable(id));
+                ));
             }
             results
         });
 
     let bufs = POOL.install(|| out.collect::<Vec<_>>());
-    Ok(flatten_nullable(&bufs))
+    Ok(A::finish(bufs))
 }
 
 fn asof_join_by_binary<B, T, A, F>(
@@ -164,12 +302,12 @@ fn asof_join_by_binary<B, T, A, F>(
     right_asof: &ChunkedArray<T>,
     filter: F,
     allow_eq: bool,
-) -> IdxArr
+) -> AsofMatches
 where
     B: PolarsDataType,
     for<'b> <B::Array as StaticArray>::ValueT<'b>: AsRef<[u8]>,
     T: PolarsDataType,
-    A: for<'a> AsofJoinState<T::Physical<'a>>,
+    A: AsofGroupMatches + for<'a> AsofGroupState<T::Physical<'a>>,
     F: Sync + for<'a> Fn(T::Physical<'a>, T::Physical<'a>) -> bool,
 {
     let (left_asof, right_asof) = POOL.join(|| left_asof.rechunk(), || right_asof.rechunk());
@@ -192,7 +330,7 @@ where
             for (rel_idx_left, by_left_k) in by_left.iter().enumerate() {
                 let idx_left = (rel_idx_left + offset) as IdxSize;
                 let Some(left_val) = left_val_arr.get(idx_left as usize) else {
-                    results.push(NullableIdxSize::null());
+                    results.push(A::no_matches());
                     continue;
                 };
 
@@ -200,24 +338,22 @@ where
                     hash_tbls.get_unchecked(hash_to_partition(by_left_k.dirty_hash(), n_tables))
                 };
                 let Some(right_grp_idxs) = group_probe_table.get(by_left_k) else {
-                    results.push(NullableIdxSize::null());
+                    results.push(A::no_matches());
                     continue;
                 };
-                let id = asof_in_group::<T, A, &F>(
+                results.push(asof_in_group::<T, A, &F>(
                     left_val,
                     right_val_arr,
                     right_grp_idxs.as_slice(),
                     &mut group_states,
                     &filter,
                     allow_eq,
-                );
-
-                results.push(materialize_nullable(id));
+                ));
             }
             results
         });
     let bufs = POOL.install(|| iter.collect::<Vec<_>>());
-    flatten_nullable(&bufs)
+    A::finish(bufs)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -228,10 +364,10 @@ fn dispatch_join_by_type<T, A, F>(
     right_by: &mut DataFrame,
     filter: F,
     allow_eq: bool,
-) -> PolarsResult<IdxArr>
+) -> PolarsResult<AsofMatches>
 where
     T: PolarsDataType,
-    A: for<'a> AsofJoinState<T::Physical<'a>>,
+    A: AsofGroupMatches + for<'a> AsofGroupState<T::Physical<'a>>,
     F: Sync + for<'a> Fn(T::Physical<'a>, T::Physical<'a>) -> bool,
 {
     let out = if left_by.width() == 1 {
@@ -320,7 +456,7 @@ fn dispatch_join_strategy<T: PolarsDataType>(
     right_by: &mut DataFrame,
     strategy: AsofStrategy,
     allow_eq: bool,
-) -> PolarsResult<IdxArr>
+) -> PolarsResult<AsofMatches>
 where
     for<'a> T::Physical<'a>: PartialOrd,
 {
@@ -335,6 +471,9 @@ where
             left_asof, right_asof, left_by, right_by, filter, allow_eq,
         ),
         AsofStrategy::Nearest => unimplemented!(),
+        AsofStrategy::Bracket => dispatch_join_by_type::<T, AsofJoinBracketState, _>(
+            left_asof, right_asof, left_by, right_by, filter, allow_eq,
+        ),
     }
 }
 
@@ -347,7 +486,7 @@ fn dispatch_join_strategy_numeric<T: PolarsNumericType>(
     strategy: AsofStrategy,
     tolerance: Option<AnyValue<'static>>,
     allow_eq: bool,
-) -> PolarsResult<IdxArr> {
+) -> PolarsResult<AsofMatches> {
     let right_ca = left_asof.unpack_series_matching_type(right_asof)?;
 
     if let Some(tol) = tolerance {
@@ -364,6 +503,9 @@ fn dispatch_join_strategy_numeric<T: PolarsNumericType>(
             AsofStrategy::Nearest => dispatch_join_by_type::<T, AsofJoinNearestState, _>(
                 left_asof, right_ca, left_by, right_by, filter, allow_eq,
             ),
+            AsofStrategy::Bracket => dispatch_join_by_type::<T, AsofJoinBracketState, _>(
+                left_asof, right_ca, left_by, right_by, filter, allow_eq,
+            ),
         }
     } else {
         let filter = |_a: T::Physical<'_>, _b: T::Physical<'_>| true;
@@ -377,6 +519,9 @@ fn dispatch_join_strategy_numeric<T: PolarsNumericType>(
             AsofStrategy::Nearest => dispatch_join_by_type::<T, AsofJoinNearestState, _>(
                 left_asof, right_ca, left_by, right_by, filter, allow_eq,
             ),
+            AsofStrategy::Bracket => dispatch_join_by_type::<T, AsofJoinBracketState, _>(
+                left_asof, right_ca, left_by, right_by, filter, allow_eq,
+            ),
         }
     }
 }
@@ -390,7 +535,7 @@ fn dispatch_join_type(
     strategy: AsofStrategy,
     tolerance: Option<AnyValue<'static>>,
     allow_eq: bool,
-) -> PolarsResult<IdxArr> {
+) -> PolarsResult<AsofMatches> {
     match left_asof.dtype() {
         DataType::Int64 => {
             let ca = left_asof.i64().unwrap();
@@ -533,7 +678,7 @@ pub trait AsofJoinBy: IntoDf {
             }
         }
 
-        let right_join_tuples = dispatch_join_type(
+        let matches = dispatch_join_type(
             &left_asof,
             &right_asof,
             &mut left_by,
@@ -559,8 +704,11 @@ pub trait AsofJoinBy: IntoDf {
         let left = self_df.clone();
 
         // SAFETY: join tuples are in bounds.
-        let right_df = unsafe {
-            proj_other_df.take_unchecked(&IdxCa::with_chunk(PlSmallStr::EMPTY, right_join_tuples))
+        let right_df = match matches {
+            AsofMatches::Single(take_idx) => unsafe { proj_other_df.take_unchecked(&take_idx) },
+            AsofMatches::Bracket(backward, forward) => unsafe {
+                take_bracket(&proj_other_df, &backward, &forward)?
+            },
         };
 
         _finish_join(left, right_df, suffix)
@@ -250,6 +250,46 @@ pub enum AsofStrategy {
     Forward,
     /// selects the right in the right DataFrame whose 'on' key is nearest to the left's key.
     Nearest,
+    /// selects both the `Backward` and the `Forward` match. Every column of the right DataFrame
+    /// becomes a struct with the fields `backward` and `forward`.
+    Bracket,
+}
+
+/// The dtype of a right column in an [`AsofStrategy::Bracket`] join.
+pub fn asof_bracket_dtype(dtype: &DataType) -> DataType {
+    DataType::Struct(vec![
+        Field::new(PlSmallStr::from_static("backward"), dtype.clone()),
+        Field::new(PlSmallStr::from_static("forward"), dtype.clone()),
+    ])
+}
+
+/// Take the `backward` and `forward` matches of every column of `other` into a struct column.
+///
+/// # Safety
+/// The indices must be in bounds.
+unsafe fn take_bracket(
+    other: &DataFrame,
+    backward: &IdxCa,
+    forward: &IdxCa,
+) -> PolarsResult<DataFrame> {
+    let backward_df = unsafe { other.take_unchecked(backward) };
+    let forward_df = unsafe { other.take_unchecked(forward) };
+    let columns = backward_df
+        .get_columns()
+        .iter()
+        .zip(forward_df.get_columns())
+        .map(|(b, f)| {
+            let fields = [
+                b.clone().with_name(PlSmallStr::from_static("backward")),
+                f.clone().with_name(PlSmallStr::from_static("forward")),
+            ];
+            Ok(
+                StructChunked::from_columns(b.name().clone(), backward.len(), &fields)?
+                    .into_column(),
+            )
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(unsafe { DataFrame::new_no_checks(backward.len(), columns) })
 }
 
 pub trait AsofJoin: IntoDf {
@@ -280,7 +320,7 @@ pub trait AsofJoin: IntoDf {
         let left_key = left_key.to_physical_repr();
         let right_key = right_key.to_physical_repr();
 
-        let mut take_idx = match left_key.dtype() {
+        let matches = match left_key.dtype() {
             DataType::Int64 => {
                 let ca = left_key.i64().unwrap();
                 join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
@@ -340,13 +380,21 @@ pub trait AsofJoin: IntoDf {
         };
 
         let mut left = self_df.clone();
+        let slice_idx = |idx: IdxCa| match slice {
+            Some((offset, len)) => idx.slice(offset, len),
+            None => idx,
+        };
         if let Some((offset, len)) = slice {
             left = left.slice(offset, len);
-            take_idx = take_idx.slice(offset, len);
         }
 
         // SAFETY: join tuples are in bounds.
-        let right_df = unsafe { other.take_unchecked(&take_idx) };
+        let right_df = match matches {
+            AsofMatches::Single(take_idx) => unsafe { other.take_unchecked(&slice_idx(take_idx)) },
+            AsofMatches::Bracket(backward, forward) => unsafe {
+                take_bracket(&other, &slice_idx(backward), &slice_idx(forward))?
+            },
+        };
 
         _finish_join(left, right_df, suffix)
     }
@@ -17,9 +17,10 @@ use std::fmt::{Debug, Display, Formatter};
 use std::hash::Hash;
 
 pub use args::*;
+use arrow::compute::utils::combine_validities_and_many;
 use arrow::trusted_len::TrustedLen;
 #[cfg(feature = "asof_join")]
-pub use asof::{AsOfOptions, AsofJoin, AsofJoinBy, AsofStrategy};
+pub use asof::{AsOfOptions, AsofJoin, AsofJoinBy, AsofStrategy, asof_bracket_dtype};
 #[cfg(feature = "dtype-categorical")]
 pub(crate) use checks::*;
 pub use cross_join::CrossJoin;
@@ -229,7 +230,7 @@ pub trait DataFrameJoinOps: IntoDf {
                 Ok(_) => {},
                 Err(_) => {
                     let (ca_left, ca_right) =
-                        make_rhs_categoricals_compatible(l.categorical()?, r.categorical()?)?;
+                        make_categoricals_compatible(l.categorical()?, r.categorical()?)?;
                     *l = ca_left.into_series().with_name(l.name().clone());
                     *r = ca_right.into_series().with_name(r.name().clone());
                 },
@@ -258,6 +259,13 @@ pub trait DataFrameJoinOps: IntoDf {
             );
         }
 
+        // Resolve the null equality of every key, after this only `nulls_equal` is used.
+        let nulls_equal = args.nulls_equal_for_keys(selected_left.len())?;
+        args.nulls_equal_per_key = None;
+        // With mixed null equality the keys are row encoded, and a row is only null if it is null
+        // in a key of which nulls aren't equal.
+        args.nulls_equal = nulls_equal.iter().all(|&eq| eq);
+
         // Single keys.
         if selected_left.len() == 1 {
             let s_left = &selected_left[0];
@@ -352,8 +360,8 @@ pub trait DataFrameJoinOps: IntoDf {
             } else {
                 // Row encode the keys.
                 (
-                    prepare_keys_multiple(&selected_left, args.nulls_equal)?.into_series(),
-                    prepare_keys_multiple(&selected_right, args.nulls_equal)?.into_series(),
+                    prepare_keys_multiple(&selected_left, &nulls_equal)?.into_series(),
+                    prepare_keys_multiple(&selected_right, &nulls_equal)?.into_series(),
                 )
             };
 
@@ -635,7 +643,7 @@ trait DataFrameJoinOpsPrivate: IntoDf {
 impl DataFrameJoinOps for DataFrame {}
 impl DataFrameJoinOpsPrivate for DataFrame {}
 
-fn prepare_keys_multiple(s: &[Series], nulls_equal: bool) -> PolarsResult<BinaryOffsetChunked> {
+fn prepare_keys_multiple(s: &[Series], nulls_equal: &[bool]) -> PolarsResult<BinaryOffsetChunked> {
     let keys = s
         .iter()
         .map(|s| {
@@ -648,10 +656,23 @@ fn prepare_keys_multiple(s: &[Series], nulls_equal: bool) -> PolarsResult<Binary
         })
         .collect::<Vec<_>>();
 
-    if nulls_equal {
+    if nulls_equal.iter().all(|&eq| eq) {
         encode_rows_vertical_par_unordered(&keys)
-    } else {
+    } else if nulls_equal.iter().all(|&eq| !eq) {
         encode_rows_vertical_par_unordered_broadcast_nulls(&keys)
+    } else {
+        // Only a null in a key of which nulls aren't equal makes the encoded row null.
+        let rows = encode_rows_vertical_par_unordered(&keys)?;
+        let rows = rows.rechunk();
+        let validities = keys
+            .iter()
+            .zip(nulls_equal)
+            .filter(|(_, eq)| !**eq)
+            .map(|(k, _)| k.as_materialized_series().rechunk_validity())
+            .collect::<Vec<_>>();
+        let validity = combine_validities_and_many(&validities);
+        let arr = rows.downcast_as_array().clone().with_validity(validity);
+        Ok(BinaryOffsetChunked::with_chunk(rows.name().clone(), arr))
     }
 }
 pub fn private_left_join_multiple_keys(
@@ -671,7 +692,8 @@ pub fn private_left_join_multiple_keys(
         .map(|c| c.as_materialized_series().clone())
         .collect::<Vec<_>>();
 
-    let a = prepare_keys_multiple(&a_cols, nulls_equal)?.into_series();
-    let b = prepare_keys_multiple(&b_cols, nulls_equal)?.into_series();
+    let nulls_equal_per_key = vec![nulls_equal; a_cols.len()];
+    let a = prepare_keys_multiple(&a_cols, &nulls_equal_per_key)?.into_series();
+    let b = prepare_keys_multiple(&b_cols, &nulls_equal_per_key)?.into_series();
     sort_or_hash_left(&a, &b, false, JoinValidation::ManyToMany, nulls_equal)
 }
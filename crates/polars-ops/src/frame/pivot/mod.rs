@@ -118,10 +118,10 @@ where
         &on,
         &index,
         &values,
-        agg_fn,
+        &[(PlSmallStr::EMPTY, agg_fn)],
         sort_columns,
         false,
-        separator,
+        ColumnNaming::Separator(separator.unwrap_or("_")),
     )
 }
 
@@ -154,13 +154,78 @@ where
         on.as_slice(),
         index.as_slice(),
         values.as_slice(),
-        agg_fn,
+        &[(PlSmallStr::EMPTY, agg_fn)],
         sort_columns,
         true,
-        separator,
+        ColumnNaming::Separator(separator.unwrap_or("_")),
     )
 }
 
+/// Pivot several `values` columns with several aggregations, computing the groups only once.
+///
+/// Every aggregation comes with a name that is used in the `template` of the output column
+/// names. The template replaces `{value}` by the name of the values column, `{agg}` by the name
+/// of the aggregation and `{on}` by the value of the `on` column, and defaults to
+/// `{value}_{agg}_{on}`. The columns are ordered by values column and then by aggregation.
+pub fn pivot_multiple<I0, I1, I2, S0, S1, S2>(
+    pivot_df: &DataFrame,
+    on: I0,
+    index: Option<I1>,
+    values: Option<I2>,
+    aggs: &[(PlSmallStr, PivotAgg)],
+    sort_columns: bool,
+    template: Option<&str>,
+) -> PolarsResult<DataFrame>
+where
+    I0: IntoIterator<Item = S0>,
+    I1: IntoIterator<Item = S1>,
+    I2: IntoIterator<Item = S2>,
+    S0: Into<PlSmallStr>,
+    S1: Into<PlSmallStr>,
+    S2: Into<PlSmallStr>,
+{
+    polars_ensure!(!aggs.is_empty(), InvalidOperation: "`pivot` needs at least one aggregation");
+    let on = on.into_iter().map(Into::into).collect::<Vec<_>>();
+    let (index, values) = assign_remaining_columns(pivot_df, &on, index, values)?;
+    let aggs = aggs
+        .iter()
+        .map(|(name, agg)| (name.clone(), Some(agg.clone())))
+        .collect::<Vec<_>>();
+    pivot_impl(
+        pivot_df,
+        &on,
+        &index,
+        &values,
+        &aggs,
+        sort_columns,
+        true,
+        ColumnNaming::Template(template.unwrap_or("{value}_{agg}_{on}")),
+    )
+}
+
+/// How the names of the output columns are created from the values column, the name of the
+/// aggregation and the value of the `on` column.
+#[derive(Clone, Copy)]
+enum ColumnNaming<'a> {
+    /// Only the value of `on`, prefixed with the values column and the separator if there is
+    /// more than one values column.
+    Separator(&'a str),
+    Template(&'a str),
+}
+
+impl ColumnNaming<'_> {
+    fn name(&self, value: &str, agg: &str, on: &str, n_values: usize) -> String {
+        match self {
+            ColumnNaming::Separator(sep) if n_values > 1 => format!("{value}{sep}{on}"),
+            ColumnNaming::Separator(_) => on.to_string(),
+            ColumnNaming::Template(template) => template
+                .replace("{value}", value)
+                .replace("{agg}", agg)
+                .replace("{on}", on),
+        }
+    }
+}
+
 /// Ensure both `index` and `values` are populated with `Vec<String>`.
 ///
 /// - If `index` is None, assign columns not in `on` and `values` to it.
@@ -220,12 +285,11 @@ fn pivot_impl(
     // these columns will be used for a nested group_by
     // the rows of this nested group_by will be pivoted as header column values
     values: &[PlSmallStr],
-    // aggregation function
-    agg_fn: Option<PivotAgg>,
+    // aggregation functions and their names
+    aggs: &[(PlSmallStr, Option<PivotAgg>)],
     sort_columns: bool,
     stable: bool,
-    // used as separator/delimiter in generated column names.
-    separator: Option<&str>,
+    naming: ColumnNaming,
 ) -> PolarsResult<DataFrame> {
     polars_ensure!(!index.is_empty(), ComputeError: "index cannot be zero length");
     polars_ensure!(!on.is_empty(), ComputeError: "`on` cannot be zero length");
@@ -247,24 +311,16 @@ fn pivot_impl(
             .into_column();
         let mut binding = pivot_df.clone();
         let pivot_df = unsafe { binding.with_column_unchecked(columns_struct) };
-        pivot_impl_single_column(
-            pivot_df,
-            index,
-            &column,
-            values,
-            agg_fn,
-            sort_columns,
-            separator,
-        )
+        pivot_impl_single_column(pivot_df, index, &column, values, aggs, sort_columns, naming)
     } else {
         pivot_impl_single_column(
             pivot_df,
             index,
             unsafe { on.get_unchecked(0) },
             values,
-            agg_fn,
+            aggs,
             sort_columns,
-            separator,
+            naming,
         )
     }
 }
@@ -274,11 +330,10 @@ fn pivot_impl_single_column(
     index: &[PlSmallStr],
     column: &PlSmallStr,
     values: &[PlSmallStr],
-    agg_fn: Option<PivotAgg>,
+    aggs: &[(PlSmallStr, Option<PivotAgg>)],
     sort_columns: bool,
-    separator: Option<&str>,
+    naming: ColumnNaming,
 ) -> PolarsResult<DataFrame> {
-    let sep = separator.unwrap_or("_");
     let mut final_cols = vec![];
     let mut count = 0;
     let out: PolarsResult<()> = POOL.install(|| {
@@ -296,93 +351,93 @@ fn pivot_impl_single_column(
 
         for value_col_name in values {
             let value_col = pivot_df.column(value_col_name)?;
-
-            use PivotAgg::*;
-            let value_agg = unsafe {
-                match &agg_fn {
-                    None => match value_col.len() > groups.len() {
-                        true => polars_bail!(
-                            ComputeError:
-                            "found multiple elements in the same group, \
-                            please specify an aggregation function"
-                        ),
-                        false => value_col.agg_first(&groups),
-                    },
-                    Some(agg_fn) => match agg_fn {
-                        Sum => value_col.agg_sum(&groups),
-                        Min => value_col.agg_min(&groups),
-                        Max => value_col.agg_max(&groups),
-                        Last => value_col.agg_last(&groups),
-                        First => value_col.agg_first(&groups),
-                        Mean => value_col.agg_mean(&groups),
-                        Median => value_col.agg_median(&groups),
-                        Count => groups.group_count().into_column(),
-                        Expr(expr) => {
-                            let name = expr.root_name()?.clone();
-                            let mut value_col = value_col.clone();
-                            value_col.rename(name);
-                            let tmp_df = value_col.into_frame();
-                            let mut aggregated = Column::from(expr.evaluate(&tmp_df, &groups)?);
-                            aggregated.rename(value_col_name.clone());
-                            aggregated
+            for (agg_name, agg_fn) in aggs {
+                use PivotAgg::*;
+                let value_agg = unsafe {
+                    match agg_fn {
+                        None => match value_col.len() > groups.len() {
+                            true => polars_bail!(
+                                ComputeError:
+                                "found multiple elements in the same group, \
+                                please specify an aggregation function"
+                            ),
+                            false => value_col.agg_first(&groups),
                         },
-                    },
-                }
-            };
+                        Some(agg_fn) => match agg_fn {
+                            Sum => value_col.agg_sum(&groups),
+                            Min => value_col.agg_min(&groups),
+                            Max => value_col.agg_max(&groups),
+                            Last => value_col.agg_last(&groups),
+                            First => value_col.agg_first(&groups),
+                            Mean => value_col.agg_mean(&groups),
+                            Median => value_col.agg_median(&groups),
+                            Count => groups.group_count().into_column(),
+                            Expr(expr) => {
+                                let name = expr.root_name()?.clone();
+                                let mut value_col = value_col.clone();
+                                value_col.rename(name);
+                                let tmp_df = value_col.into_frame();
+                                let mut aggregated = Column::from(expr.evaluate(&tmp_df, &groups)?);
+                                aggregated.rename(value_col_name.clone());
+                                aggregated
+                            },
+                        },
+                    }
+                };
 
-            let headers = column_agg.unique_stable()?.cast(&DataType::String)?;
-            let mut headers = headers.str().unwrap().clone();
-            if values.len() > 1 {
-                headers = headers.apply_values(|v| Cow::from(format!("{value_col_name}{sep}{v}")))
-            }
+                let headers = column_agg.unique_stable()?.cast(&DataType::String)?;
+                let headers = headers.str().unwrap().apply_values(|v| {
+                    Cow::from(naming.name(value_col_name, agg_name, v, values.len()))
+                });
 
-            let n_cols = headers.len();
-            let value_agg_phys = value_agg.to_physical_repr();
-            let logical_type = value_agg.dtype();
+                let n_cols = headers.len();
+                let value_agg_phys = value_agg.to_physical_repr();
+                let logical_type = value_agg.dtype();
 
-            debug_assert_eq!(row_locations.len(), col_locations.len());
-            debug_assert_eq!(value_agg_phys.len(), row_locations.len());
+                debug_assert_eq!(row_locations.len(), col_locations.len());
+                debug_assert_eq!(value_agg_phys.len(), row_locations.len());
 
-            let mut cols = if value_agg_phys.dtype().is_primitive_numeric() {
-                macro_rules! dispatch {
-                    ($ca:expr) => {{
-                        positioning::position_aggregates_numeric(
-                            n_rows,
-                            n_cols,
-                            &row_locations,
-                            &col_locations,
-                            $ca,
-                            logical_type,
-                            &headers,
-                        )
-                    }};
+                let mut cols = if value_agg_phys.dtype().is_primitive_numeric() {
+                    macro_rules! dispatch {
+                        ($ca:expr) => {{
+                            positioning::position_aggregates_numeric(
+                                n_rows,
+                                n_cols,
+                                &row_locations,
+                                &col_locations,
+                                $ca,
+                                logical_type,
+                                &headers,
+                            )
+                        }};
+                    }
+                    downcast_as_macro_arg_physical!(value_agg_phys, dispatch)
+                } else {
+                    positioning::position_aggregates(
+                        n_rows,
+                        n_cols,
+                        &row_locations,
+                        &col_locations,
+                        value_agg_phys.as_materialized_series(),
+                        logical_type,
+                        &headers,
+                    )
+                };
+
+                if sort_columns {
+                    cols.sort_unstable_by(|a, b| a.name().partial_cmp(b.name()).unwrap());
                 }
-                downcast_as_macro_arg_physical!(value_agg_phys, dispatch)
-            } else {
-                positioning::position_aggregates(
-                    n_rows,
-                    n_cols,
-                    &row_locations,
-                    &col_locations,
-                    value_agg_phys.as_materialized_series(),
-                    logical_type,
-                    &headers,
-                )
-            };
 
-            if sort_columns {
-                cols.sort_unstable_by(|a, b| a.name().partial_cmp(b.name()).unwrap());
+                let cols = if count == 0 {
+                    let mut final_cols = row_index.take().unwrap();
+                    final_cols.extend(cols);
+                    final_cols
+                } else {
+                    cols
+                };
+                count += 1;
+                final_cols.extend_from_slice(&cols);
             }
-
-            let cols = if count == 0 {
-                let mut final_cols = row_index.take().unwrap();
-                final_cols.extend(cols);
-                final_cols
-            } else {
-                cols
-            };
-            count += 1;
-            final_cols.extend_from_slice(&cols);
         }
         Ok(())
     });
@@ -1,6 +1,7 @@
 pub use arrow::legacy::kernels::ewm::EWMOptions;
 use arrow::legacy::kernels::ewm::{
-    ewm_mean as kernel_ewm_mean, ewm_std as kernel_ewm_std, ewm_var as kernel_ewm_var,
+    EwmCovState, EwmMeanState, ewm_mean as kernel_ewm_mean, ewm_std as kernel_ewm_std,
+    ewm_var as kernel_ewm_var,
 };
 use polars_core::prelude::*;
 
@@ -99,3 +100,110 @@ pub fn ewm_var(s: &Series, options: EWMOptions) -> PolarsResult<Series> {
         _ => ewm_var(&s.cast(&DataType::Float64)?, options),
     }
 }
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EwmMethod {
+    Mean,
+    Std,
+    Var,
+}
+
+#[derive(Clone, Debug)]
+enum EwmStateInner {
+    MeanF32(EwmMeanState<f32>),
+    MeanF64(EwmMeanState<f64>),
+    CovF32(EwmCovState<f32>),
+    CovF64(EwmCovState<f64>),
+}
+
+/// Exponentially-weighted moving statistic whose state is carried over
+/// consecutive inputs.
+///
+/// Calling [`EwmState::update`] on the consecutive slices of a column gives the
+/// same result as computing [`ewm_mean`], [`ewm_std`] or [`ewm_var`] on the
+/// whole column. This is what allows the streaming engine to process the column
+/// morsel by morsel.
+#[derive(Clone, Debug)]
+pub struct EwmState {
+    method: EwmMethod,
+    inner: EwmStateInner,
+}
+
+impl EwmState {
+    pub fn new(method: EwmMethod, options: EWMOptions, dtype: &DataType) -> PolarsResult<Self> {
+        check_alpha(options.alpha)?;
+        let is_f32 = matches!(dtype, DataType::Float32);
+        let inner = match (method, is_f32) {
+            (EwmMethod::Mean, true) => EwmStateInner::MeanF32(EwmMeanState::new(
+                options.alpha as f32,
+                options.adjust,
+                options.min_periods,
+                options.ignore_nulls,
+            )),
+            (EwmMethod::Mean, false) => EwmStateInner::MeanF64(EwmMeanState::new(
+                options.alpha,
+                options.adjust,
+                options.min_periods,
+                options.ignore_nulls,
+            )),
+            (EwmMethod::Std | EwmMethod::Var, true) => EwmStateInner::CovF32(EwmCovState::new(
+                options.alpha as f32,
+                options.adjust,
+                options.bias,
+                options.min_periods,
+                options.ignore_nulls,
+            )),
+            (EwmMethod::Std | EwmMethod::Var, false) => EwmStateInner::CovF64(EwmCovState::new(
+                options.alpha,
+                options.adjust,
+                options.bias,
+                options.min_periods,
+                options.ignore_nulls,
+            )),
+        };
+        Ok(Self { method, inner })
+    }
+
+    /// Computes the statistic for the next values of the column.
+    pub fn update(&mut self, s: &Series) -> PolarsResult<Series> {
+        let do_sqrt = self.method == EwmMethod::Std;
+        let out = match &mut self.inner {
+            EwmStateInner::MeanF32(state) => {
+                let s = s.cast(&DataType::Float32)?;
+                let ca: Float32Chunked = s.f32()?.iter().map(|x| state.update(x)).collect_trusted();
+                ca.into_series()
+            },
+            EwmStateInner::MeanF64(state) => {
+                let s = s.cast(&DataType::Float64)?;
+                let ca: Float64Chunked = s.f64()?.iter().map(|x| state.update(x)).collect_trusted();
+                ca.into_series()
+            },
+            EwmStateInner::CovF32(state) => {
+                let s = s.cast(&DataType::Float32)?;
+                let ca: Float32Chunked = s
+                    .f32()?
+                    .iter()
+                    .map(|x| {
+                        let v = state.update(x, x);
+                        if do_sqrt { v.map(f32::sqrt) } else { v }
+                    })
+                    .collect_trusted();
+                ca.into_series()
+            },
+            EwmStateInner::CovF64(state) => {
+                let s = s.cast(&DataType::Float64)?;
+                let ca: Float64Chunked = s
+                    .f64()?
+                    .iter()
+                    .map(|x| {
+                        let v = state.update(x, x);
+                        if do_sqrt { v.map(f64::sqrt) } else { v }
+                    })
+                    .collect_trusted();
+                ca.into_series()
+            },
+        };
+        Ok(out.with_name(s.name().clone()))
+    }
+}
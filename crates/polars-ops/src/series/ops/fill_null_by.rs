@@ -0,0 +1,105 @@
+use polars_core::prelude::*;
+
+/// Fill null values with the previous (`forward`) or next non-null value, but
+/// only if that value lies at most `limit` away as measured by `by`.
+///
+/// `limit` is expressed in nanoseconds for `Date`/`Datetime` columns and in
+/// index units for integer columns. `by` must be sorted ascending. Rows where
+/// `by` is null are never filled.
+pub fn fill_null_by(s: &Series, by: &Series, forward: bool, limit: i64) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.len() == by.len(),
+        length_mismatch = "fill_null_by",
+        s.len(),
+        by.len()
+    );
+    polars_ensure!(limit >= 0, InvalidOperation: "fill limit cannot be negative");
+
+    match by.dtype() {
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(time_unit, _) => {
+            let limit = match time_unit {
+                TimeUnit::Milliseconds => limit / 1_000_000,
+                TimeUnit::Microseconds => limit / 1_000,
+                TimeUnit::Nanoseconds => limit,
+            };
+            fill_null_by(s, &by.cast(&DataType::Int64)?, forward, limit)
+        },
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => fill_null_by(
+            s,
+            &by.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?,
+            forward,
+            limit,
+        ),
+        DataType::Int64 => {
+            if s.null_count() == 0 {
+                return Ok(s.clone());
+            }
+            let idx = fill_idx(s, by.i64().unwrap(), forward, limit);
+            // SAFETY: the indices are in bounds by construction.
+            Ok(unsafe { s.take_unchecked(&idx) })
+        },
+        DataType::UInt64 | DataType::UInt32 | DataType::Int32 => {
+            fill_null_by(s, &by.cast(&DataType::Int64)?, forward, limit)
+        },
+        dt => {
+            polars_bail!(InvalidOperation: "expected `by` to be Date, Datetime, Int64, Int32, UInt64, or UInt32, got {}", dt)
+        },
+    }
+}
+
+/// Compute for every row the index of the value it takes after filling.
+fn fill_idx(s: &Series, by: &Int64Chunked, forward: bool, limit: i64) -> IdxCa {
+    let len = s.len();
+    let is_valid = s.is_not_null();
+    let mut is_valid = is_valid.into_no_null_iter();
+    let by = by.rechunk();
+    let by = by.downcast_as_array();
+
+    let mut idx: Vec<Option<IdxSize>> = vec![None; len];
+    let mut last_valid: Option<usize> = None;
+    let mut visit = |i: usize, valid: bool| {
+        if valid {
+            last_valid = Some(i);
+            idx[i] = Some(i as IdxSize);
+        } else if let (Some(j), Some(t)) = (last_valid, by.get(i)) {
+            if by
+                .get(j)
+                .is_some_and(|t_valid| (t - t_valid).abs() <= limit)
+            {
+                idx[i] = Some(j as IdxSize);
+            }
+        }
+    };
+    if forward {
+        for i in 0..len {
+            visit(i, is_valid.next().unwrap());
+        }
+    } else {
+        let is_valid = is_valid.collect::<Vec<_>>();
+        for i in (0..len).rev() {
+            visit(i, is_valid[i]);
+        }
+    }
+    IdxCa::from_slice_options(PlSmallStr::EMPTY, &idx)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fill_null_by() {
+        let s = Series::new("a".into(), [Some(1), None, None, Some(4), None, None]);
+        let by = Series::new("t".into(), [0i64, 1, 5, 6, 7, 20]);
+
+        let out = fill_null_by(&s, &by, true, 2).unwrap();
+        let expected = Series::new("a".into(), [Some(1), Some(1), None, Some(4), Some(4), None]);
+        assert!(out.equals_missing(&expected));
+
+        let out = fill_null_by(&s, &by, false, 2).unwrap();
+        let expected = Series::new("a".into(), [Some(1), None, Some(4), Some(4), None, None]);
+        assert!(out.equals_missing(&expected));
+    }
+}
@@ -97,3 +97,90 @@ pub fn is_unique(s: &Series) -> PolarsResult<BooleanChunked> {
 pub fn is_duplicated(s: &Series) -> PolarsResult<BooleanChunked> {
     dispatcher(s, true)
 }
+
+/// Groups of the rows with equal keys, found in a single hashed pass.
+fn key_groups(keys: &[Column]) -> PolarsResult<GroupPositions> {
+    let height = keys
+        .iter()
+        .map(|c| c.len())
+        .max()
+        .ok_or_else(|| polars_err!(ColumnNotFound: "no key columns to find duplicates by"))?;
+    // Null keys don't distinguish rows, if all keys are null all rows are equal.
+    if keys.iter().all(|c| c.dtype().is_null()) {
+        let groups = if height == 0 {
+            vec![]
+        } else {
+            vec![[0, height as IdxSize]]
+        };
+        return Ok(GroupsType::Slice {
+            groups,
+            rolling: false,
+        }
+        .into_sliceable());
+    }
+    let gb =
+        DataFrame::empty_with_height(height).group_by_with_series(keys.to_vec(), true, false)?;
+    Ok(gb.take_groups())
+}
+
+/// Mask of the rows of which the `keys` occur more than once. With `keep` set to
+/// [`UniqueKeepStrategy::First`] (or `Any`) the first occurrence of every key isn't marked, with
+/// [`UniqueKeepStrategy::Last`] the last one isn't, and with [`UniqueKeepStrategy::None`] all
+/// occurrences are marked.
+pub fn is_duplicated_by(keys: &[Column], keep: UniqueKeepStrategy) -> PolarsResult<BooleanChunked> {
+    let len = keys.iter().map(|c| c.len()).max().unwrap_or(0);
+    let groups = key_groups(keys)?;
+
+    let mut values = MutableBitmap::with_capacity(len);
+    values.extend_constant(len, false);
+    let mut mark = |group: &mut dyn Iterator<Item = IdxSize>, group_len: usize| {
+        if group_len < 2 {
+            return;
+        }
+        let (skip, take) = match keep {
+            UniqueKeepStrategy::First | UniqueKeepStrategy::Any => (1, group_len - 1),
+            UniqueKeepStrategy::Last => (0, group_len - 1),
+            UniqueKeepStrategy::None => (0, group_len),
+        };
+        for idx in group.skip(skip).take(take) {
+            values.set(idx as usize, true);
+        }
+    };
+    match &*groups {
+        GroupsType::Idx(groups) => {
+            for (_, group) in groups.iter() {
+                mark(&mut group.iter().copied(), group.len());
+            }
+        },
+        GroupsType::Slice { groups, .. } => {
+            for &[first, group_len] in groups {
+                mark(&mut (first..first + group_len), group_len as usize);
+            }
+        },
+    }
+    let arr = BooleanArray::from_data_default(values.into(), None);
+    Ok(BooleanChunked::with_chunk(keys[0].name().clone(), arr))
+}
+
+/// The number of rows with the same `keys` as the row, for every row.
+pub fn occurrence_count_by(keys: &[Column]) -> PolarsResult<IdxCa> {
+    let len = keys.iter().map(|c| c.len()).max().unwrap_or(0);
+    let groups = key_groups(keys)?;
+
+    let mut counts = vec![0 as IdxSize; len];
+    match &*groups {
+        GroupsType::Idx(groups) => {
+            for (_, group) in groups.iter() {
+                for &idx in group.iter() {
+                    counts[idx as usize] = group.len() as IdxSize;
+                }
+            }
+        },
+        GroupsType::Slice { groups, .. } => {
+            for &[first, group_len] in groups {
+                counts[first as usize..(first + group_len) as usize].fill(group_len);
+            }
+        },
+    }
+    Ok(IdxCa::from_vec(keys[0].name().clone(), counts))
+}
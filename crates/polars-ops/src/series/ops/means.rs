@@ -0,0 +1,226 @@
+use num_traits::ToPrimitive;
+use polars_core::prelude::*;
+
+use crate::prelude::SeriesSealed;
+
+pub trait MeanSeries: SeriesSealed {
+    /// Compute the geometric mean of the non-null values.
+    ///
+    /// This is computed as `exp(mean(ln(x)))`, so unlike the `n`-th root of the product it
+    /// doesn't overflow. Negative values result in `NaN`.
+    fn geo_mean(&self) -> PolarsResult<Option<f64>> {
+        let s = self.as_series();
+        polars_ensure!(s.dtype().is_primitive_numeric(), InvalidOperation: "expected numerical input for 'geo_mean'");
+        let s = s.cast(&DataType::Float64)?;
+        let ca = s.f64().unwrap();
+
+        let n = ca.len() - ca.null_count();
+        if n == 0 {
+            return Ok(None);
+        }
+        let log_sum = ca.apply_values(f64::ln).sum().unwrap();
+        Ok(Some((log_sum / n as f64).exp()))
+    }
+
+    /// Compute the harmonic mean of the non-null values.
+    ///
+    /// This is computed as `n / sum(1 / x)`, a value of zero results in zero.
+    fn harmonic_mean(&self) -> PolarsResult<Option<f64>> {
+        let s = self.as_series();
+        polars_ensure!(s.dtype().is_primitive_numeric(), InvalidOperation: "expected numerical input for 'harmonic_mean'");
+        let s = s.cast(&DataType::Float64)?;
+        let ca = s.f64().unwrap();
+
+        let n = ca.len() - ca.null_count();
+        if n == 0 {
+            return Ok(None);
+        }
+        let reciprocal_sum = ca.apply_values(|v| 1.0 / v).sum().unwrap();
+        Ok(Some(n as f64 / reciprocal_sum))
+    }
+
+    /// Compute the time-weighted average (TWAP), where every value is weighted by the time
+    /// until the next observation in `time`.
+    ///
+    /// Observations where the value or the time is null are ignored. The last observation has
+    /// no next observation, so its weight is zero, unless the observations span no time at all,
+    /// in which case the value of the last one is returned.
+    ///
+    /// If a window is given, the average is over the time from `start` to `end`, which are
+    /// scalars of which a null leaves the window open on that side. The last observation at or
+    /// before `start` holds its value from `start` on, earlier observations are ignored, and the
+    /// last observation before `end` holds its value until `end`.
+    fn twap(&self, time: &Series, window: Option<(&Series, &Series)>) -> PolarsResult<Option<f64>> {
+        let s = self.as_series();
+        polars_ensure!(
+            s.dtype().is_primitive_numeric(),
+            InvalidOperation: "expected numerical input for 'twap', got {}", s.dtype()
+        );
+        polars_ensure!(
+            time.dtype().is_primitive_numeric() || time.dtype().is_temporal(),
+            InvalidOperation: "expected a numerical or temporal time column for 'twap', got {}",
+            time.dtype()
+        );
+        polars_ensure!(
+            s.len() == time.len(),
+            ShapeMismatch: "'twap' got values of length {} and times of length {}",
+            s.len(), time.len()
+        );
+        let s = s.cast(&DataType::Float64)?;
+        let values = s.f64().unwrap();
+
+        let bound = |b: &Series| -> PolarsResult<Series> {
+            polars_ensure!(
+                b.len() == 1,
+                ShapeMismatch: "the window bounds of 'twap' must be scalars, got length {}",
+                b.len()
+            );
+            Ok(b.cast(time.dtype())?.to_physical_repr().into_owned())
+        };
+        let (start, end) = match window {
+            Some((start, end)) => (Some(bound(start)?), Some(bound(end)?)),
+            None => (None, None),
+        };
+
+        // Take the differences of integer times before converting to floats, so that large
+        // timestamps don't lose precision.
+        let time = time.to_physical_repr();
+        if time.dtype().is_float() {
+            let time = time.cast(&DataType::Float64)?;
+            let bound = |b: Option<Series>| -> PolarsResult<_> {
+                Ok(b.map(|b| b.cast(&DataType::Float64))
+                    .transpose()?
+                    .and_then(|b| b.f64().unwrap().get(0)))
+            };
+            twap_impl(values, time.f64().unwrap(), bound(start)?, bound(end)?)
+        } else {
+            let time = time.cast(&DataType::Int64)?;
+            let bound = |b: Option<Series>| -> PolarsResult<_> {
+                Ok(b.map(|b| b.cast(&DataType::Int64))
+                    .transpose()?
+                    .and_then(|b| b.i64().unwrap().get(0)))
+            };
+            twap_impl(values, time.i64().unwrap(), bound(start)?, bound(end)?)
+        }
+    }
+}
+
+fn twap_impl<T>(
+    values: &Float64Chunked,
+    time: &ChunkedArray<T>,
+    start: Option<T::Native>,
+    end: Option<T::Native>,
+) -> PolarsResult<Option<f64>>
+where
+    T: PolarsNumericType,
+{
+    if let (Some(start), Some(end)) = (start, end) {
+        polars_ensure!(
+            start <= end,
+            InvalidOperation: "the window of 'twap' must not end before it starts"
+        );
+    }
+    // Observations outside of the window are moved to its bounds, so that they don't add any
+    // time, except for the last observation before the window, which holds until the next one.
+    let clamp = |t: T::Native| match (start, end) {
+        (Some(start), _) if t < start => start,
+        (_, Some(end)) if t > end => end,
+        _ => t,
+    };
+
+    let mut weighted_sum = 0.0;
+    let mut total_time = 0.0;
+    // The time, the time moved into the window and the value of the previous observation.
+    let mut prev = None;
+    for (value, t) in values.iter().zip(time.iter()) {
+        let (Some(value), Some(t)) = (value, t) else {
+            continue;
+        };
+        let clamped_t = clamp(t);
+        if let Some((prev_t, prev_clamped_t, prev_value)) = prev {
+            polars_ensure!(
+                t >= prev_t,
+                InvalidOperation: "the time column of 'twap' must be sorted in ascending order"
+            );
+            let dt = (clamped_t - prev_clamped_t).to_f64().unwrap();
+            weighted_sum += prev_value * dt;
+            total_time += dt;
+        }
+        prev = Some((t, clamped_t, value));
+    }
+    // The last observation holds until the end of the window.
+    if let (Some(end), Some((_, prev_clamped_t, prev_value))) = (end, prev) {
+        let dt = (end - prev_clamped_t).to_f64().unwrap();
+        weighted_sum += prev_value * dt;
+        total_time += dt;
+    }
+    Ok(prev.map(|(_, _, last)| {
+        if total_time > 0.0 {
+            weighted_sum / total_time
+        } else {
+            last
+        }
+    }))
+}
+
+impl MeanSeries for Series {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_geo_mean() -> PolarsResult<()> {
+        let s = Series::new(PlSmallStr::EMPTY, &[Some(1), Some(4), None, Some(16)]);
+        assert!((s.geo_mean()?.unwrap() - 4.0).abs() < 1e-10);
+
+        // The product of these values overflows a f64.
+        let s = Series::new(PlSmallStr::EMPTY, &[1e200, 1e200, 1e200]);
+        assert!((s.geo_mean()?.unwrap() / 1e200 - 1.0).abs() < 1e-10);
+
+        let s = Series::new(PlSmallStr::EMPTY, &[None::<i32>, None]);
+        assert_eq!(s.geo_mean()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_harmonic_mean() -> PolarsResult<()> {
+        let s = Series::new(PlSmallStr::EMPTY, &[Some(1.0), None, Some(4.0), Some(4.0)]);
+        assert!((s.harmonic_mean()?.unwrap() - 2.0).abs() < 1e-10);
+
+        let s = Series::new(PlSmallStr::EMPTY, &[1.0, 0.0]);
+        assert_eq!(s.harmonic_mean()?, Some(0.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_twap() -> PolarsResult<()> {
+        let s = Series::new(PlSmallStr::EMPTY, &[Some(1.0), Some(2.0), None, Some(4.0)]);
+        let time = Series::new(PlSmallStr::EMPTY, &[0i64, 3, 4, 4]);
+        // 1.0 for 3 units, 2.0 for 1 unit.
+        assert_eq!(s.twap(&time, None)?, Some(1.25));
+
+        // 1.0 from 2 until 3, 2.0 until 4 and 4.0 until 6.
+        let bound = |t: Option<i64>| Series::new(PlSmallStr::EMPTY, &[t]);
+        let (start, end) = (bound(Some(2)), bound(Some(6)));
+        assert_eq!(s.twap(&time, Some((&start, &end)))?, Some(2.75));
+        // 1.0 is carried into the window, 2.0 holds until the end of the window.
+        let (start, end) = (bound(Some(1)), bound(Some(3)));
+        let time_after = Series::new(PlSmallStr::EMPTY, &[0i64, 2, 4, 5]);
+        assert_eq!(s.twap(&time_after, Some((&start, &end)))?, Some(1.5));
+        // An open start is the same as no start.
+        let (start, end) = (bound(None), bound(Some(5)));
+        assert_eq!(s.twap(&time, Some((&start, &end)))?, Some(1.8));
+        let (start, end) = (bound(Some(6)), bound(Some(2)));
+        assert!(s.twap(&time, Some((&start, &end))).is_err());
+
+        let time = Series::new(PlSmallStr::EMPTY, &[5i64, 5, 5, 5]);
+        assert_eq!(s.twap(&time, None)?, Some(4.0));
+        let time = Series::new(PlSmallStr::EMPTY, &[5i64, 1, 5, 5]);
+        assert!(s.twap(&time, None).is_err());
+
+        let s = Series::new(PlSmallStr::EMPTY, &[None::<f64>]);
+        assert_eq!(s.twap(&time.head(Some(1)), None)?, None);
+        Ok(())
+    }
+}
@@ -15,6 +15,8 @@ mod diff;
 mod ewm;
 #[cfg(feature = "ewma_by")]
 mod ewm_by;
+#[cfg(feature = "fill_null_by")]
+mod fill_null_by;
 #[cfg(feature = "round_series")]
 mod floor_divide;
 #[cfg(feature = "fused")]
@@ -39,6 +41,7 @@ mod is_unique;
 mod linear_space;
 #[cfg(feature = "log")]
 mod log;
+mod means;
 #[cfg(feature = "moment")]
 mod moment;
 mod negate;
@@ -56,6 +59,7 @@ mod rle;
 mod rolling;
 #[cfg(feature = "round_series")]
 pub mod round;
+mod scatter;
 #[cfg(feature = "search_sorted")]
 mod search_sorted;
 #[cfg(feature = "to_dummies")]
@@ -81,6 +85,8 @@ pub use diff::*;
 pub use ewm::*;
 #[cfg(feature = "ewma_by")]
 pub use ewm_by::*;
+#[cfg(feature = "fill_null_by")]
+pub use fill_null_by::*;
 #[cfg(feature = "round_series")]
 pub use floor_divide::*;
 #[cfg(feature = "fused")]
@@ -109,6 +115,7 @@ pub use is_unique::*;
 pub use linear_space::*;
 #[cfg(feature = "log")]
 pub use log::*;
+pub use means::*;
 #[cfg(feature = "moment")]
 pub use moment::*;
 pub use negate::*;
@@ -128,6 +135,7 @@ pub use rle::*;
 pub use rolling::*;
 #[cfg(feature = "round_series")]
 pub use round::*;
+pub use scatter::*;
 #[cfg(feature = "search_sorted")]
 pub use search_sorted::*;
 #[cfg(feature = "to_dummies")]
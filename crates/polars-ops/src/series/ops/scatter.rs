@@ -0,0 +1,111 @@
+use polars_core::prelude::*;
+
+use super::convert_to_unsigned_index;
+
+/// Replace the values of `s` at the given positions by `values`.
+///
+/// `indices` is either a boolean mask of the same length as `s`, or integers where negative
+/// integers count from the end. `values` is broadcast if it has a single value. Given a mask,
+/// `values` may also have the length of `s`, in which case the values at the masked positions
+/// are used, as in `when(mask).then(values).otherwise(s)`. If an index occurs more than once,
+/// the last value wins.
+pub fn scatter(s: &Series, indices: &Series, values: &Series) -> PolarsResult<Series> {
+    let len = s.len();
+    let values = values.strict_cast(s.dtype())?;
+
+    // For every output position, where to gather it from in `s` followed by `values`.
+    let mut gather = (0..len as IdxSize).collect::<Vec<_>>();
+    if let Ok(mask) = indices.bool() {
+        polars_ensure!(
+            mask.len() == len,
+            length_mismatch = "scatter",
+            mask.len(),
+            len,
+            argument = "indices",
+            argument_idx = 1
+        );
+        let positions = mask
+            .iter()
+            .enumerate()
+            .filter_map(|(i, m)| m.unwrap_or(false).then_some(i));
+        let n_set = mask.num_trues();
+        if values.len() == len {
+            positions.for_each(|i| gather[i] = (len + i) as IdxSize);
+        } else {
+            ensure_values_len(values.len(), n_set)?;
+            let broadcast = values.len() == 1;
+            for (j, i) in positions.enumerate() {
+                gather[i] = (len + if broadcast { 0 } else { j }) as IdxSize;
+            }
+        }
+    } else {
+        let idx = convert_to_unsigned_index(indices, len)?;
+        polars_ensure!(
+            !idx.has_nulls(),
+            ComputeError: "index values of `scatter` should not be null"
+        );
+        ensure_values_len(values.len(), idx.len())?;
+        let broadcast = values.len() == 1;
+        for (j, i) in idx.into_no_null_iter().enumerate() {
+            polars_ensure!(
+                (i as usize) < len,
+                OutOfBounds: "scatter index {} is out of bounds for a column of length {}", i, len
+            );
+            gather[i as usize] = (len + if broadcast { 0 } else { j }) as IdxSize;
+        }
+    }
+
+    let mut combined = s.clone();
+    combined.append(&values)?;
+    // SAFETY: all positions are smaller than `len + values.len()`.
+    Ok(unsafe { combined.take_slice_unchecked(&gather) })
+}
+
+fn ensure_values_len(n_values: usize, n_set: usize) -> PolarsResult<()> {
+    polars_ensure!(
+        n_values == n_set || n_values == 1,
+        ShapeMismatch: "`scatter` got {} values for {} positions", n_values, n_set
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scatter() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [1i32, 2, 3, 4]);
+
+        let idx = Series::new("idx".into(), [-1i64, 0, 0]);
+        let values = Series::new("v".into(), [10i64, 20, 30]);
+        let out = scatter(&s, &idx, &values)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            [Some(30), Some(2), Some(3), Some(10)]
+        );
+        assert_eq!(out.name().as_str(), "a");
+
+        let mask = Series::new("m".into(), [Some(true), None, Some(false), Some(true)]);
+        let out = scatter(&s, &mask, &Series::new_null("v".into(), 1))?;
+        assert_eq!(Vec::from(out.i32()?), [None, Some(2), Some(3), None]);
+
+        let aligned = Series::new("v".into(), [-1i32, -2, -3, -4]);
+        let out = scatter(&s, &mask, &aligned)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            [Some(-1), Some(2), Some(3), Some(-4)]
+        );
+
+        assert!(
+            scatter(
+                &s,
+                &Series::new("idx".into(), [4u32]),
+                &aligned.head(Some(1))
+            )
+            .is_err()
+        );
+        assert!(scatter(&s, &Series::new("idx".into(), [0u32, 1]), &values).is_err());
+        Ok(())
+    }
+}
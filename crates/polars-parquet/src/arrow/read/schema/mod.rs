@@ -1,5 +1,7 @@
 //! APIs to handle Parquet <-> Arrow schemas.
-use arrow::datatypes::{ArrowSchema, TimeUnit};
+use std::sync::Arc;
+
+use arrow::datatypes::{ArrowDataType, ArrowSchema, DTYPE_CATEGORICAL, IntegerType, TimeUnit};
 
 mod convert;
 mod metadata;
@@ -8,6 +10,7 @@ pub(crate) use convert::*;
 pub use convert::{parquet_to_arrow_schema, parquet_to_arrow_schema_with_options};
 pub use metadata::{read_custom_key_value_metadata, read_schema_from_metadata};
 use polars_error::PolarsResult;
+use polars_utils::pl_str::PlSmallStr;
 
 use self::metadata::parse_key_value_metadata;
 pub use crate::parquet::metadata::{FileMetadata, KeyValue, SchemaDescriptor};
@@ -45,6 +48,37 @@ pub fn infer_schema(file_metadata: &FileMetadata) -> PolarsResult<ArrowSchema> {
     infer_schema_with_options(file_metadata, &None)
 }
 
+/// Read the top-level string columns of `schema` as dictionaries, which Polars loads as
+/// `Categorical`s.
+///
+/// Columns that are dictionary encoded in all row groups of `file_metadata` are marked to be
+/// decoded with the dictionaries of the file as categories, which avoids materializing the
+/// strings. The other string columns are decoded as strings and categorized afterwards.
+pub fn strings_as_categorical(schema: &mut ArrowSchema, file_metadata: &FileMetadata) {
+    for field in schema.iter_values_mut() {
+        if field.dtype != ArrowDataType::Utf8View {
+            continue;
+        }
+
+        field.dtype = ArrowDataType::Dictionary(
+            IntegerType::UInt32,
+            Box::new(ArrowDataType::Utf8View),
+            false,
+        );
+
+        let is_dictionary_encoded = file_metadata.row_groups.iter().all(|rg| {
+            rg.columns_under_root_iter(&field.name)
+                .is_some_and(|mut columns| columns.all(|c| c.is_fully_dictionary_encoded()))
+        });
+        if is_dictionary_encoded {
+            Arc::make_mut(field.metadata.get_or_insert_default()).insert(
+                PlSmallStr::from_static(DTYPE_CATEGORICAL),
+                PlSmallStr::from_static("physical"),
+            );
+        }
+    }
+}
+
 /// Like [`infer_schema`] but with configurable options which affects the behavior of inference
 pub fn infer_schema_with_options(
     file_metadata: &FileMetadata,
@@ -31,6 +31,12 @@ impl<W: Write> FileWriter<W> {
     pub fn schema(&self) -> &ArrowSchema {
         &self.schema
     }
+
+    /// A mutable reference to the [`ArrowSchema`] assigned to this file, it is only written
+    /// in the footer
+    pub fn schema_mut(&mut self) -> &mut ArrowSchema {
+        &mut self.schema
+    }
 }
 
 impl<W: Write> FileWriter<W> {
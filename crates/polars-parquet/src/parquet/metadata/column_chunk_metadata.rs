@@ -1,4 +1,4 @@
-use polars_parquet_format::{ColumnChunk, ColumnMetaData, Encoding};
+use polars_parquet_format::{ColumnChunk, ColumnMetaData, Encoding, PageType};
 
 use super::column_descriptor::ColumnDescriptor;
 use crate::parquet::compression::Compression;
@@ -169,6 +169,37 @@ impl ColumnChunkMetadata {
         &self.metadata().encodings
     }
 
+    /// Returns whether all data pages of this column chunk are dictionary encoded.
+    ///
+    /// This is exact if the chunk has page encoding statistics. Without them, a dictionary page
+    /// and data pages encoded as `PLAIN` can't be told apart, so only chunks that use
+    /// `PLAIN_DICTIONARY` and no other value encoding are considered fully dictionary encoded.
+    pub fn is_fully_dictionary_encoded(&self) -> bool {
+        let is_dictionary = |encoding: &Encoding| {
+            matches!(
+                *encoding,
+                Encoding::PLAIN_DICTIONARY | Encoding::RLE_DICTIONARY
+            )
+        };
+
+        let metadata = self.metadata();
+        if let Some(stats) = &metadata.encoding_stats {
+            return stats
+                .iter()
+                .any(|s| s.page_type == PageType::DICTIONARY_PAGE)
+                && stats
+                    .iter()
+                    .filter(|s| s.page_type != PageType::DICTIONARY_PAGE)
+                    .all(|s| is_dictionary(&s.encoding));
+        }
+
+        metadata.encodings.contains(&Encoding::PLAIN_DICTIONARY)
+            && metadata.encodings.iter().all(|e| {
+                // Levels are encoded with `RLE` or `BIT_PACKED`.
+                is_dictionary(e) || matches!(*e, Encoding::RLE | Encoding::BIT_PACKED)
+            })
+    }
+
     /// Returns the offset and length in bytes of the column chunk within the file
     pub fn byte_range(&self) -> core::ops::Range<u64> {
         // this has been validated in [`try_from_thrift`]
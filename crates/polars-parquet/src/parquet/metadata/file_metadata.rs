@@ -1,4 +1,6 @@
 use polars_parquet_format::ColumnOrder as TColumnOrder;
+use polars_utils::aliases::PlHashMap;
+use polars_utils::pl_str::PlSmallStr;
 
 use super::RowGroupMetadata;
 use super::column_order::ColumnOrder;
@@ -63,6 +65,14 @@ impl FileMetadata {
             .unwrap_or(ColumnOrder::Undefined)
     }
 
+    /// Look up the columns under the root names in `renames` by their new names in all row
+    /// groups. The Parquet schema keeps the names of the file.
+    pub fn rename_root_columns(&mut self, renames: &PlHashMap<PlSmallStr, PlSmallStr>) {
+        for rg in self.row_groups.iter_mut() {
+            rg.rename_root_columns(renames);
+        }
+    }
+
     /// Deserializes [`crate::parquet::thrift_format::FileMetadata`] into this struct
     pub fn try_from_thrift(
         metadata: polars_parquet_format::FileMetaData,
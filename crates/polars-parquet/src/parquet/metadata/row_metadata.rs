@@ -102,6 +102,14 @@ impl RowGroupMetadata {
         self.sorting_columns.as_deref()
     }
 
+    /// Look up the columns under the root names in `renames` by their new names.
+    pub fn rename_root_columns(&mut self, renames: &PlHashMap<PlSmallStr, PlSmallStr>) {
+        self.column_lookup = std::mem::take(&mut self.column_lookup)
+            .into_iter()
+            .map(|(name, idxs)| (renames.get(&name).cloned().unwrap_or(name), idxs))
+            .collect();
+    }
+
     /// Method to convert from Thrift.
     pub(crate) fn try_from_thrift(
         schema_descr: &SchemaDescriptor,
@@ -5,8 +5,8 @@ use futures::AsyncWrite;
 use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
 #[cfg(feature = "async")]
 use polars_parquet_format::thrift::protocol::TCompactOutputStreamProtocol;
-use polars_parquet_format::{ColumnChunk, ColumnMetaData, Type};
-use polars_utils::aliases::PlHashSet;
+use polars_parquet_format::{ColumnChunk, ColumnMetaData, PageEncodingStats, Type};
+use polars_utils::aliases::{PlHashMap, PlHashSet};
 
 use super::DynStreamingIterator;
 #[cfg(feature = "async")]
@@ -170,6 +170,35 @@ fn build_column_chunk(
     // Sort the encodings to have deterministic metadata
     encodings.sort();
 
+    // The number of pages per page type and encoding, which tells readers whether all data
+    // pages are dictionary encoded.
+    let mut page_counts = PlHashMap::default();
+    for spec in specs {
+        let encoding = match spec.header.type_.try_into().unwrap() {
+            PageType::DataPage => spec.header.data_page_header.as_ref().unwrap().encoding,
+            PageType::DataPageV2 => spec.header.data_page_header_v2.as_ref().unwrap().encoding,
+            PageType::DictionaryPage => {
+                spec.header
+                    .dictionary_page_header
+                    .as_ref()
+                    .unwrap()
+                    .encoding
+            },
+        };
+        *page_counts
+            .entry((spec.header.type_, encoding))
+            .or_insert(0) += 1;
+    }
+    let mut encoding_stats = page_counts
+        .into_iter()
+        .map(|((page_type, encoding), count)| PageEncodingStats {
+            page_type,
+            encoding,
+            count,
+        })
+        .collect::<Vec<_>>();
+    encoding_stats.sort_by_key(|s| (s.page_type, s.encoding));
+
     let statistics = specs.iter().map(|x| &x.statistics).collect::<Vec<_>>();
     let statistics = reduce(&statistics)?;
     let statistics = statistics.map(|x| x.serialize());
@@ -193,7 +222,7 @@ fn build_column_chunk(
         index_page_offset: None,
         dictionary_page_offset: None,
         statistics,
-        encoding_stats: None,
+        encoding_stats: Some(encoding_stats),
         bloom_filter_offset: None,
         bloom_filter_length: None,
         size_statistics: None,
@@ -19,8 +19,8 @@ impl IpcSink {
         schema: &Schema,
         cloud_options: Option<&CloudOptions>,
     ) -> PolarsResult<FilesSink> {
-        let writer = IpcWriter::new(try_get_writeable(path.to_str().unwrap(), cloud_options)?)
-            .with_compression(options.compression)
+        let writer = options
+            .to_writer(try_get_writeable(path.to_str().unwrap(), cloud_options)?)
             .batched(schema)?;
 
         let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
@@ -157,7 +157,7 @@ where
                         #[cfg(feature = "ipc")]
                         FileType::Ipc(options) => Box::new(IpcSink::new(
                             path,
-                            *options,
+                            options.clone(),
                             input_schema.as_ref(),
                             cloud_options.as_ref(),
                         )?) as Box<dyn SinkTrait>,
@@ -183,6 +183,9 @@ where
                 SinkTypeIR::Partition { .. } => {
                     polars_bail!(InvalidOperation: "partitioning sink not supported in old streaming engine")
                 },
+                SinkTypeIR::Callback { .. } => {
+                    polars_bail!(InvalidOperation: "callback sink not supported in old streaming engine")
+                },
             }
         },
         Join {
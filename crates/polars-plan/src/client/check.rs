@@ -58,6 +58,9 @@ pub(super) fn assert_cloud_eligible(dsl: &DslPlan) -> PolarsResult<()> {
                     SinkType::Partition(_) => {
                         return ineligible_error("contains partition sink");
                     },
+                    SinkType::Callback(_) => {
+                        return ineligible_error("contains callback sink");
+                    },
                 }
             },
             DslPlan::SinkMultiple { .. } => {
@@ -0,0 +1,132 @@
+use std::any::Any;
+use std::fmt::Formatter;
+use std::hash::{Hash, Hasher};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::*;
+
+/// A user-defined aggregation that reduces the values of every group to a single value.
+///
+/// The values of a group can arrive in multiple batches and on multiple threads, every batch is
+/// aggregated into its own state with [`AggregationUdf::update`] and these states are combined
+/// with [`AggregationUdf::merge`]. The order in which this happens is not defined.
+pub trait AggregationUdf: Send + Sync + 'static {
+    type State: Send + Sync + 'static;
+
+    /// Name of the aggregation, used to display the expression.
+    fn name(&self) -> &str;
+
+    /// The dtype of the aggregated values given the dtype of the input.
+    fn output_dtype(&self, input_dtype: &DataType) -> PolarsResult<DataType>;
+
+    /// The state of a group without values.
+    fn init(&self) -> Self::State;
+
+    /// Aggregate a batch of values into the state.
+    fn update(&self, state: &mut Self::State, values: &Series) -> PolarsResult<()>;
+
+    /// Combine the state of another batch of the same group into the state.
+    fn merge(&self, state: &mut Self::State, other: &Self::State) -> PolarsResult<()>;
+
+    /// The aggregated value of a state.
+    fn finalize(&self, state: &Self::State) -> PolarsResult<AnyValue<'static>>;
+}
+
+pub type AggregationUdfState = dyn Any + Send + Sync;
+
+/// Object safe version of [`AggregationUdf`] with type-erased states.
+pub trait DynAggregationUdf: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn output_dtype(&self, input_dtype: &DataType) -> PolarsResult<DataType>;
+
+    fn init(&self) -> Box<AggregationUdfState>;
+
+    fn update(&self, state: &mut AggregationUdfState, values: &Series) -> PolarsResult<()>;
+
+    fn merge(
+        &self,
+        state: &mut AggregationUdfState,
+        other: &AggregationUdfState,
+    ) -> PolarsResult<()>;
+
+    fn finalize(&self, state: &AggregationUdfState) -> PolarsResult<AnyValue<'static>>;
+}
+
+impl<T: AggregationUdf> DynAggregationUdf for T {
+    fn name(&self) -> &str {
+        AggregationUdf::name(self)
+    }
+
+    fn output_dtype(&self, input_dtype: &DataType) -> PolarsResult<DataType> {
+        AggregationUdf::output_dtype(self, input_dtype)
+    }
+
+    fn init(&self) -> Box<AggregationUdfState> {
+        Box::new(AggregationUdf::init(self))
+    }
+
+    fn update(&self, state: &mut AggregationUdfState, values: &Series) -> PolarsResult<()> {
+        AggregationUdf::update(self, state.downcast_mut().unwrap(), values)
+    }
+
+    fn merge(
+        &self,
+        state: &mut AggregationUdfState,
+        other: &AggregationUdfState,
+    ) -> PolarsResult<()> {
+        AggregationUdf::merge(
+            self,
+            state.downcast_mut().unwrap(),
+            other.downcast_ref().unwrap(),
+        )
+    }
+
+    fn finalize(&self, state: &AggregationUdfState) -> PolarsResult<AnyValue<'static>> {
+        AggregationUdf::finalize(self, state.downcast_ref().unwrap())
+    }
+}
+
+impl Debug for dyn DynAggregationUdf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+pub type AggregationUdfFn = SpecialEq<Arc<dyn DynAggregationUdf>>;
+
+impl Hash for AggregationUdfFn {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let function: &Arc<dyn DynAggregationUdf> = self;
+        (Arc::as_ptr(function) as *const () as usize).hash(state)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for AggregationUdfFn {
+    fn serialize<S>(&self, _serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::Error;
+        Err(S::Error::custom(format!(
+            "serialization not supported for aggregation '{}'",
+            self.name()
+        )))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> Deserialize<'a> for AggregationUdfFn {
+    fn deserialize<D>(_deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        use serde::de::Error;
+        Err(D::Error::custom(
+            "deserialization not supported for user-defined aggregations",
+        ))
+    }
+}
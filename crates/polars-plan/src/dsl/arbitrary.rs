@@ -0,0 +1,128 @@
+//! [`proptest`] strategies that generate expression trees.
+use proptest::prelude::*;
+
+use super::*;
+
+#[derive(Clone, Debug)]
+pub struct ExprArbitraryOptions {
+    /// The columns that expressions may refer to. If empty, expressions only contain literals.
+    pub columns: Vec<PlSmallStr>,
+    /// Maximum depth of the expression tree.
+    pub max_depth: u32,
+    /// Whether to generate aggregations such as `sum` and `first`.
+    pub allow_aggregations: bool,
+}
+
+impl Default for ExprArbitraryOptions {
+    fn default() -> Self {
+        Self {
+            columns: vec![],
+            max_depth: 4,
+            allow_aggregations: true,
+        }
+    }
+}
+
+fn literal() -> impl Strategy<Value = Expr> {
+    prop_oneof![
+        any::<i64>().prop_map(lit),
+        any::<f64>().prop_map(lit),
+        any::<bool>().prop_map(lit),
+        proptest::sample::select(vec!["", "a", "b", "ab"]).prop_map(lit),
+        Just(lit(NULL)),
+    ]
+}
+
+fn binary_operator() -> impl Strategy<Value = Operator> {
+    use Operator::*;
+    proptest::sample::select(vec![
+        Eq,
+        EqValidity,
+        NotEq,
+        Lt,
+        LtEq,
+        Gt,
+        GtEq,
+        Plus,
+        Minus,
+        Multiply,
+        TrueDivide,
+        FloorDivide,
+        Modulus,
+        And,
+        Or,
+        Xor,
+    ])
+}
+
+fn cast_dtype() -> impl Strategy<Value = DataType> {
+    proptest::sample::select(vec![
+        DataType::Boolean,
+        DataType::Int32,
+        DataType::Int64,
+        DataType::UInt32,
+        DataType::Float64,
+        DataType::String,
+    ])
+}
+
+/// Generate a random expression tree over `options.columns`.
+///
+/// Expressions are not type checked, so resolving them against a schema may fail. They are
+/// meant to check that operations return errors rather than panic, and that invariants hold
+/// whenever they succeed.
+pub fn expr(options: ExprArbitraryOptions) -> impl Strategy<Value = Expr> {
+    let leaf = if options.columns.is_empty() {
+        literal().boxed()
+    } else {
+        prop_oneof![
+            3 => proptest::sample::select(options.columns.clone()).prop_map(col),
+            1 => literal(),
+        ]
+        .boxed()
+    };
+
+    let allow_aggregations = options.allow_aggregations;
+    leaf.prop_recursive(options.max_depth, 64, 3, move |inner| {
+        let unary = (inner.clone(), 0..6u8).prop_map(|(e, op)| match op {
+            0 => e.not(),
+            1 => e.is_null(),
+            2 => e.is_not_null(),
+            3 => e.reverse(),
+            4 => e.shift(lit(1)),
+            _ => e.alias("alias"),
+        });
+        let agg = (inner.clone(), 0..6u8).prop_map(|(e, op)| match op {
+            0 => e.sum(),
+            1 => e.min(),
+            2 => e.max(),
+            3 => e.mean(),
+            4 => e.first(),
+            _ => e.count(),
+        });
+        let strategies = prop_oneof![
+            (inner.clone(), binary_operator(), inner.clone())
+                .prop_map(|(l, op, r)| binary_expr(l, op, r)),
+            unary,
+            (inner.clone(), cast_dtype()).prop_map(|(e, dtype)| e.cast(dtype)),
+            (inner.clone(), inner.clone()).prop_map(|(e, fill)| e.fill_null(fill)),
+            (inner.clone(), inner.clone(), inner.clone())
+                .prop_map(|(p, t, o)| when(p).then(t).otherwise(o)),
+            (inner.clone(), inner.clone()).prop_map(|(e, p)| e.filter(p)),
+        ];
+        if allow_aggregations {
+            prop_oneof![4 => strategies, 1 => agg].boxed()
+        } else {
+            strategies.boxed()
+        }
+    })
+}
+
+impl Arbitrary for Expr {
+    type Parameters = ExprArbitraryOptions;
+    type Strategy = BoxedStrategy<Expr>;
+
+    fn arbitrary_with(options: Self::Parameters) -> Self::Strategy {
+        expr(options).boxed()
+    }
+}
@@ -146,6 +146,7 @@ impl ArrayNameSpace {
 
     #[cfg(feature = "array_to_struct")]
     pub fn to_struct(self, name_generator: Option<ArrToStructNameGenerator>) -> PolarsResult<Expr> {
+        let schema_name_generator = name_generator.clone();
         Ok(self
             .0
             .map(
@@ -159,9 +160,12 @@ impl ArrayNameSpace {
                         polars_bail!(InvalidOperation: "expected Array type, got: {}", dt)
                     };
 
+                    let name_generator = schema_name_generator
+                        .as_deref()
+                        .unwrap_or(&arr_default_struct_name_gen);
                     let fields = (0..*width)
                         .map(|i| {
-                            let name = arr_default_struct_name_gen(i);
+                            let name = name_generator(i);
                             Field::new(name, inner.as_ref().clone())
                         })
                         .collect();
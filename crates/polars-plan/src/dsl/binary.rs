@@ -53,6 +53,65 @@ impl BinaryNameSpace {
             .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Base64Encode))
     }
 
+    #[cfg(feature = "binary_encoding")]
+    pub fn base32_decode(self, strict: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Base32Decode(
+                strict,
+            )))
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    pub fn base32_encode(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Base32Encode))
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    pub fn base85_decode(self, strict: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Base85Decode(
+                strict,
+            )))
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    pub fn base85_encode(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Base85Encode))
+    }
+
+    /// Compress each value on its own, e.g. to store large payloads compactly.
+    ///
+    /// `level` is the algorithm specific compression level; `None` uses its default.
+    #[cfg(feature = "binary_compression")]
+    pub fn compress(self, algo: BinaryCompression, level: Option<i32>) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Compress(
+                algo, level,
+            )))
+    }
+
+    /// Decompress each value that was compressed with `algo`. If `strict` is `false`, values
+    /// that fail to decompress become null instead of raising an error.
+    #[cfg(feature = "binary_compression")]
+    pub fn decompress(self, algo: BinaryCompression, strict: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Decompress(
+                algo, strict,
+            )))
+    }
+
+    /// Format 16-byte binary UUIDs as lowercase hyphenated strings. If `strict` is `false`
+    /// values that are not 16 bytes long become null.
+    #[cfg(feature = "uuid")]
+    pub fn uuid_to_string(self, strict: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::UuidToString(
+                strict,
+            )))
+    }
+
     #[cfg(feature = "binary_encoding")]
     pub fn from_buffer(self, to_type: DataType, is_little_endian: bool) -> Expr {
         self.0
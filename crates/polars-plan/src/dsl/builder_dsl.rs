@@ -75,6 +75,7 @@ impl DslBuilder {
     pub fn scan_ipc(
         sources: ScanSources,
         options: IpcScanOptions,
+        #[cfg(feature = "ipc_encryption")] encryption: Option<polars_io::ipc::IpcEncryption>,
         unified_scan_args: UnifiedScanArgs,
     ) -> PolarsResult<Self> {
         Ok(DslPlan::Scan {
@@ -83,6 +84,8 @@ impl DslBuilder {
             unified_scan_args: Box::new(unified_scan_args),
             scan_type: Box::new(FileScan::Ipc {
                 options,
+                #[cfg(feature = "ipc_encryption")]
+                encryption,
                 metadata: None,
             }),
             cached_ir: Default::default(),
@@ -233,6 +236,7 @@ impl DslBuilder {
             #[cfg(feature = "dynamic_group_by")]
             rolling: rolling_options,
             slice: None,
+            keys_sorted: false,
         };
 
         DslPlan::GroupBy {
@@ -269,12 +273,18 @@ impl DslBuilder {
         .into()
     }
 
-    pub fn explode(self, columns: Vec<Selector>, allow_empty: bool) -> Self {
+    pub fn explode(
+        self,
+        columns: Vec<Selector>,
+        allow_empty: bool,
+        position: Option<PlSmallStr>,
+    ) -> Self {
         DslPlan::MapFunction {
             input: Arc::new(self.0),
             function: DslFunction::Explode {
                 columns,
                 allow_empty,
+                position,
             },
         }
         .into()
@@ -8,6 +8,18 @@ impl CategoricalNameSpace {
         self.0.map_unary(CategoricalFunction::GetCategories)
     }
 
+    /// Add categories to an `Enum` after its existing categories. Categories that are already
+    /// part of the `Enum` are ignored and the values are left untouched.
+    pub fn extend_categories<I, S>(self, categories: I) -> Expr
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        let categories = categories.into_iter().map(Into::into).collect();
+        self.0
+            .map_unary(CategoricalFunction::ExtendCategories(categories))
+    }
+
     #[cfg(feature = "strings")]
     pub fn len_bytes(self) -> Expr {
         self.0.map_unary(CategoricalFunction::LenBytes)
@@ -218,6 +218,29 @@ impl DateLikeNameSpace {
         )
     }
 
+    /// Get the timestamps that are missing on the grid that starts at the earliest value and steps
+    /// by `every` up to the latest value, in ascending order.
+    ///
+    /// Nulls are ignored and the values don't have to be sorted. In a group-by context this
+    /// returns the missing timestamps of every group.
+    #[cfg(feature = "temporal_gaps")]
+    pub fn missing_periods(self, every: polars_time::Duration) -> Expr {
+        self.0.map_unary(FunctionExpr::TemporalExpr(
+            TemporalFunction::MissingPeriods(every),
+        ))
+    }
+
+    /// Get the gaps between consecutive values that are further apart than `interval`, as a
+    /// struct with the fields `start` and `end`, the values before and after every gap.
+    ///
+    /// Nulls are ignored and the values don't have to be sorted. In a group-by context this
+    /// returns the gaps of every group.
+    #[cfg(feature = "temporal_gaps")]
+    pub fn gaps(self, interval: polars_time::Duration) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::Gaps(interval)))
+    }
+
     /// Roll backward to the first day of the month.
     #[cfg(feature = "month_start")]
     pub fn month_start(self) -> Expr {
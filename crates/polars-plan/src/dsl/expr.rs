@@ -37,9 +37,16 @@ pub enum AggExpr {
         method: QuantileMethod,
     },
     Sum(Arc<Expr>),
+    Product(Arc<Expr>),
+    GeoMean(Arc<Expr>),
+    HarmonicMean(Arc<Expr>),
     AggGroups(Arc<Expr>),
     Std(Arc<Expr>, u8),
     Var(Arc<Expr>, u8),
+    Udf {
+        input: Arc<Expr>,
+        function: AggregationUdfFn,
+    },
 }
 
 impl AsRef<Expr> for AggExpr {
@@ -57,9 +64,13 @@ impl AsRef<Expr> for AggExpr {
             Count(e, _) => e,
             Quantile { expr, .. } => expr,
             Sum(e) => e,
+            Product(e) => e,
+            GeoMean(e) => e,
+            HarmonicMean(e) => e,
             AggGroups(e) => e,
             Std(e, _) => e,
             Var(e, _) => e,
+            Udf { input, .. } => input,
         }
     }
 }
@@ -50,6 +50,10 @@ pub enum FileScan {
     #[cfg(feature = "ipc")]
     Ipc {
         options: IpcScanOptions,
+        /// Decrypt the scanned files, see [`polars_io::ipc::IpcEncryption`].
+        #[cfg(feature = "ipc_encryption")]
+        #[cfg_attr(feature = "serde", serde(default))]
+        encryption: Option<polars_io::ipc::IpcEncryption>,
         #[cfg_attr(feature = "serde", serde(skip))]
         metadata: Option<Arc<arrow::io::ipc::read::FileMetadata>>,
     },
@@ -190,6 +194,9 @@ pub struct UnifiedScanArgs {
 
     pub projection: Option<Arc<[PlSmallStr]>>,
     pub row_index: Option<RowIndex>,
+    /// Restart the row index at its offset for every file instead of counting across files.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub row_index_per_file: bool,
     /// Slice applied before predicates
     pub pre_slice: Option<Slice>,
 
@@ -244,6 +251,8 @@ mod _file_scan_eq_hash {
         #[cfg(feature = "ipc")]
         Ipc {
             options: &'a polars_io::prelude::IpcScanOptions,
+            #[cfg(feature = "ipc_encryption")]
+            encryption: &'a Option<polars_io::ipc::IpcEncryption>,
             metadata: Option<usize>,
         },
 
@@ -279,8 +288,15 @@ mod _file_scan_eq_hash {
                 },
 
                 #[cfg(feature = "ipc")]
-                FileScan::Ipc { options, metadata } => FileScanEqHashWrap::Ipc {
+                FileScan::Ipc {
                     options,
+                    #[cfg(feature = "ipc_encryption")]
+                    encryption,
+                    metadata,
+                } => FileScanEqHashWrap::Ipc {
+                    options,
+                    #[cfg(feature = "ipc_encryption")]
+                    encryption,
                     metadata: metadata.as_ref().map(arc_as_ptr),
                 },
 
@@ -115,11 +115,15 @@ impl fmt::Debug for Expr {
                     Implode(expr) => write!(f, "{expr:?}.list()"),
                     NUnique(expr) => write!(f, "{expr:?}.n_unique()"),
                     Sum(expr) => write!(f, "{expr:?}.sum()"),
+                    Product(expr) => write!(f, "{expr:?}.product()"),
+                    GeoMean(expr) => write!(f, "{expr:?}.geo_mean()"),
+                    HarmonicMean(expr) => write!(f, "{expr:?}.harmonic_mean()"),
                     AggGroups(expr) => write!(f, "{expr:?}.groups()"),
                     Count(expr, _) => write!(f, "{expr:?}.count()"),
                     Var(expr, _) => write!(f, "{expr:?}.var()"),
                     Std(expr, _) => write!(f, "{expr:?}.std()"),
                     Quantile { expr, .. } => write!(f, "{expr:?}.quantile()"),
+                    Udf { input, function } => write!(f, "{input:?}.{}()", function.name()),
                 }
             },
             Cast {
@@ -18,9 +18,25 @@ pub enum BinaryFunction {
     Base64Decode(bool),
     #[cfg(feature = "binary_encoding")]
     Base64Encode,
+    #[cfg(feature = "binary_encoding")]
+    Base32Decode(bool),
+    #[cfg(feature = "binary_encoding")]
+    Base32Encode,
+    #[cfg(feature = "binary_encoding")]
+    Base85Decode(bool),
+    #[cfg(feature = "binary_encoding")]
+    Base85Encode,
+    #[cfg(feature = "binary_compression")]
+    Compress(BinaryCompression, Option<i32>),
+    #[cfg(feature = "binary_compression")]
+    Decompress(BinaryCompression, bool),
     Size,
     #[cfg(feature = "binary_encoding")]
     FromBuffer(DataType, bool),
+    #[cfg(feature = "binary_encoding")]
+    ToBuffer(bool),
+    #[cfg(feature = "uuid")]
+    UuidToString(bool),
 }
 
 impl BinaryFunction {
@@ -30,12 +46,22 @@ impl BinaryFunction {
             Contains => mapper.with_dtype(DataType::Boolean),
             EndsWith | StartsWith => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "binary_encoding")]
-            HexDecode(_) | Base64Decode(_) => mapper.with_same_dtype(),
+            HexDecode(_) | Base64Decode(_) | Base32Decode(_) | Base85Decode(_) => {
+                mapper.with_same_dtype()
+            },
             #[cfg(feature = "binary_encoding")]
-            HexEncode | Base64Encode => mapper.with_dtype(DataType::String),
+            HexEncode | Base64Encode | Base32Encode | Base85Encode => {
+                mapper.with_dtype(DataType::String)
+            },
+            #[cfg(feature = "binary_compression")]
+            Compress(_, _) | Decompress(_, _) => mapper.with_same_dtype(),
             Size => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "binary_encoding")]
             FromBuffer(dtype, _) => mapper.with_dtype(dtype.clone()),
+            #[cfg(feature = "binary_encoding")]
+            ToBuffer(_) => mapper.with_dtype(DataType::Binary),
+            #[cfg(feature = "uuid")]
+            UuidToString(_) => mapper.with_dtype(DataType::String),
         }
     }
 
@@ -51,7 +77,16 @@ impl BinaryFunction {
             | B::HexEncode
             | B::Base64Decode(_)
             | B::Base64Encode
-            | B::FromBuffer(_, _) => FunctionOptions::elementwise(),
+            | B::Base32Decode(_)
+            | B::Base32Encode
+            | B::Base85Decode(_)
+            | B::Base85Encode
+            | B::FromBuffer(_, _)
+            | B::ToBuffer(_) => FunctionOptions::elementwise(),
+            #[cfg(feature = "binary_compression")]
+            B::Compress(_, _) | B::Decompress(_, _) => FunctionOptions::elementwise(),
+            #[cfg(feature = "uuid")]
+            B::UuidToString(_) => FunctionOptions::elementwise(),
         }
     }
 }
@@ -71,9 +106,25 @@ impl Display for BinaryFunction {
             Base64Decode(_) => "base64_decode",
             #[cfg(feature = "binary_encoding")]
             Base64Encode => "base64_encode",
+            #[cfg(feature = "binary_encoding")]
+            Base32Decode(_) => "base32_decode",
+            #[cfg(feature = "binary_encoding")]
+            Base32Encode => "base32_encode",
+            #[cfg(feature = "binary_encoding")]
+            Base85Decode(_) => "base85_decode",
+            #[cfg(feature = "binary_encoding")]
+            Base85Encode => "base85_encode",
+            #[cfg(feature = "binary_compression")]
+            Compress(_, _) => "compress",
+            #[cfg(feature = "binary_compression")]
+            Decompress(_, _) => "decompress",
             Size => "size_bytes",
             #[cfg(feature = "binary_encoding")]
             FromBuffer(_, _) => "from_buffer",
+            #[cfg(feature = "binary_encoding")]
+            ToBuffer(_) => "to_buffer",
+            #[cfg(feature = "uuid")]
+            UuidToString(_) => "uuid_to_string",
         };
         write!(f, "bin.{s}")
     }
@@ -100,9 +151,25 @@ impl From<BinaryFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Base64Decode(strict) => map!(base64_decode, strict),
             #[cfg(feature = "binary_encoding")]
             Base64Encode => map!(base64_encode),
+            #[cfg(feature = "binary_encoding")]
+            Base32Decode(strict) => map!(base32_decode, strict),
+            #[cfg(feature = "binary_encoding")]
+            Base32Encode => map!(base32_encode),
+            #[cfg(feature = "binary_encoding")]
+            Base85Decode(strict) => map!(base85_decode, strict),
+            #[cfg(feature = "binary_encoding")]
+            Base85Encode => map!(base85_encode),
+            #[cfg(feature = "binary_compression")]
+            Compress(algo, level) => map!(compress, algo, level),
+            #[cfg(feature = "binary_compression")]
+            Decompress(algo, strict) => map!(decompress, algo, strict),
             Size => map!(size_bytes),
             #[cfg(feature = "binary_encoding")]
             FromBuffer(dtype, is_little_endian) => map!(from_buffer, &dtype, is_little_endian),
+            #[cfg(feature = "binary_encoding")]
+            ToBuffer(is_little_endian) => map!(to_buffer, is_little_endian),
+            #[cfg(feature = "uuid")]
+            UuidToString(strict) => map!(uuid_to_string, strict),
         }
     }
 }
@@ -165,6 +232,50 @@ pub(super) fn base64_encode(s: &Column) -> PolarsResult<Column> {
     Ok(ca.base64_encode().into())
 }
 
+#[cfg(feature = "binary_encoding")]
+pub(super) fn base32_decode(s: &Column, strict: bool) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    ca.base32_decode(strict).map(|ok| ok.into_column())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn base32_encode(s: &Column) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    Ok(ca.base32_encode().into())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn base85_decode(s: &Column, strict: bool) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    ca.base85_decode(strict).map(|ok| ok.into_column())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn base85_encode(s: &Column) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    Ok(ca.base85_encode().into())
+}
+
+#[cfg(feature = "binary_compression")]
+pub(super) fn compress(
+    s: &Column,
+    algo: BinaryCompression,
+    level: Option<i32>,
+) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    ca.compress(algo, level).map(|ok| ok.into_column())
+}
+
+#[cfg(feature = "binary_compression")]
+pub(super) fn decompress(
+    s: &Column,
+    algo: BinaryCompression,
+    strict: bool,
+) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    ca.decompress(algo, strict).map(|ok| ok.into_column())
+}
+
 #[cfg(feature = "binary_encoding")]
 pub(super) fn from_buffer(
     s: &Column,
@@ -176,6 +287,18 @@ pub(super) fn from_buffer(
         .map(|val| val.into())
 }
 
+#[cfg(feature = "binary_encoding")]
+pub(super) fn to_buffer(s: &Column, is_little_endian: bool) -> PolarsResult<Column> {
+    polars_ops::chunked_array::to_buffer(s.as_materialized_series(), is_little_endian)
+        .map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "uuid")]
+pub(super) fn uuid_to_string(s: &Column, strict: bool) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    polars_ops::chunked_array::uuid_to_string(ca, strict).map(|ok| ok.into_column())
+}
+
 impl From<BinaryFunction> for FunctionExpr {
     fn from(b: BinaryFunction) -> Self {
         FunctionExpr::BinaryExpr(b)
@@ -31,6 +31,10 @@ pub enum BooleanFunction {
     IsUnique,
     #[cfg(feature = "is_unique")]
     IsDuplicated,
+    #[cfg(feature = "is_unique")]
+    IsDuplicatedBy {
+        keep: UniqueKeepStrategy,
+    },
     #[cfg(feature = "is_between")]
     IsBetween {
         closed: ClosedInterval,
@@ -77,6 +81,9 @@ impl BooleanFunction {
             B::IsUnique => FunctionOptions::length_preserving(),
             #[cfg(feature = "is_unique")]
             B::IsDuplicated => FunctionOptions::length_preserving(),
+            #[cfg(feature = "is_unique")]
+            B::IsDuplicatedBy { .. } => FunctionOptions::length_preserving()
+                .with_flags(|f| f | FunctionFlags::INPUT_WILDCARD_EXPANSION),
             #[cfg(feature = "is_between")]
             B::IsBetween { .. } => FunctionOptions::elementwise().with_supertyping(
                 (SuperTypeFlags::default() & !SuperTypeFlags::ALLOW_PRIMITIVE_TO_STRING).into(),
@@ -111,6 +118,8 @@ impl Display for BooleanFunction {
             IsUnique => "is_unique",
             #[cfg(feature = "is_unique")]
             IsDuplicated => "is_duplicated",
+            #[cfg(feature = "is_unique")]
+            IsDuplicatedBy { .. } => "is_duplicated_by",
             #[cfg(feature = "is_between")]
             IsBetween { .. } => "is_between",
             #[cfg(feature = "is_in")]
@@ -143,6 +152,8 @@ impl From<BooleanFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             IsUnique => map!(is_unique),
             #[cfg(feature = "is_unique")]
             IsDuplicated => map!(is_duplicated),
+            #[cfg(feature = "is_unique")]
+            IsDuplicatedBy { keep } => map_as_slice!(is_duplicated_by, keep),
             #[cfg(feature = "is_between")]
             IsBetween { closed } => map_as_slice!(is_between, closed),
             #[cfg(feature = "is_in")]
@@ -222,6 +233,11 @@ fn is_duplicated(s: &Column) -> PolarsResult<Column> {
     polars_ops::prelude::is_duplicated(s.as_materialized_series()).map(|ca| ca.into_column())
 }
 
+#[cfg(feature = "is_unique")]
+fn is_duplicated_by(s: &[Column], keep: UniqueKeepStrategy) -> PolarsResult<Column> {
+    polars_ops::prelude::is_duplicated_by(s, keep).map(|ca| ca.into_column())
+}
+
 #[cfg(feature = "is_between")]
 fn is_between(s: &[Column], closed: ClosedInterval) -> PolarsResult<Column> {
     let ser = &s[0];
@@ -0,0 +1,92 @@
+use polars_utils::hashing::murmur3_32;
+
+use super::*;
+
+/// The bucket, in `0..num_buckets`, of every row of `columns`.
+///
+/// Every value is hashed with [`murmur3_32`] as in the Iceberg specification: integers and
+/// temporal values as their 8-byte little-endian physical value, floats as the bits of the
+/// 8-byte float with `-0.0` and `NaN` normalized, and strings and binaries as their bytes. The
+/// hashes `h_i` of the keys are combined as `31 * h + h_i` as Hive does, with `0` for nulls, and
+/// the bucket is the non-negative part of the combined hash modulo `num_buckets`.
+///
+/// The buckets of existing datasets depend on this, so it must never change; a change needs a new
+/// [`BUCKET_HASH_VERSION`](crate::dsl::functions::BUCKET_HASH_VERSION).
+pub(crate) fn bucket_id(columns: &mut [Column], num_buckets: u32) -> PolarsResult<Column> {
+    polars_ensure!(num_buckets > 0, InvalidOperation: "`num_buckets` must be positive");
+    let len = columns.iter().map(|c| c.len()).max().unwrap_or(0);
+
+    let mut hashes = vec![0u32; len];
+    for c in columns.iter() {
+        let c = if c.len() == len {
+            c.clone()
+        } else {
+            polars_ensure!(c.len() == 1, ShapeMismatch: "bucket keys must have the same length");
+            c.new_from_index(0, len)
+        };
+        combine_hashes(c.as_materialized_series(), &mut hashes)?;
+    }
+
+    let out: UInt32Chunked = hashes
+        .into_iter()
+        .map(|h| (h & i32::MAX as u32) % num_buckets)
+        .collect_ca(PlSmallStr::from_static("bucket"));
+    Ok(out.into_column())
+}
+
+fn combine(hashes: &mut [u32], values: impl Iterator<Item = Option<u32>>) {
+    for (h, v) in hashes.iter_mut().zip(values) {
+        *h = h.wrapping_mul(31).wrapping_add(v.unwrap_or(0));
+    }
+}
+
+fn hash_i64(v: i64) -> u32 {
+    murmur3_32(&v.to_le_bytes(), 0)
+}
+
+fn hash_f64(v: f64) -> u32 {
+    let v = if v == 0.0 {
+        0.0
+    } else if v.is_nan() {
+        f64::NAN
+    } else {
+        v
+    };
+    murmur3_32(&v.to_bits().to_le_bytes(), 0)
+}
+
+fn combine_hashes(s: &Series, hashes: &mut [u32]) -> PolarsResult<()> {
+    use DataType::*;
+    match s.dtype() {
+        Boolean => combine(
+            hashes,
+            s.bool()?.iter().map(|v| v.map(|v| hash_i64(v as i64))),
+        ),
+        UInt64 => combine(
+            hashes,
+            s.u64()?.iter().map(|v| v.map(|v| hash_i64(v as i64))),
+        ),
+        dt if dt.is_integer() => {
+            let s = s.strict_cast(&Int64)?;
+            combine(hashes, s.i64()?.iter().map(|v| v.map(hash_i64)))
+        },
+        Float32 | Float64 => {
+            let s = s.cast(&Float64)?;
+            combine(hashes, s.f64()?.iter().map(|v| v.map(hash_f64)))
+        },
+        String => combine(
+            hashes,
+            s.str()?
+                .iter()
+                .map(|v| v.map(|v| murmur3_32(v.as_bytes(), 0))),
+        ),
+        Binary => combine(
+            hashes,
+            s.binary()?.iter().map(|v| v.map(|v| murmur3_32(v, 0))),
+        ),
+        dt if dt.is_categorical() || dt.is_enum() => combine_hashes(&s.cast(&String)?, hashes)?,
+        dt if dt.is_temporal() => combine_hashes(&s.to_physical_repr(), hashes)?,
+        dt => polars_bail!(InvalidOperation: "cannot bucket by a column of type {dt}"),
+    }
+    Ok(())
+}
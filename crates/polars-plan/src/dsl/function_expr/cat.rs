@@ -5,6 +5,7 @@ use crate::map;
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub enum CategoricalFunction {
     GetCategories,
+    ExtendCategories(Arc<[PlSmallStr]>),
     #[cfg(feature = "strings")]
     LenBytes,
     #[cfg(feature = "strings")]
@@ -22,6 +23,9 @@ impl CategoricalFunction {
         use CategoricalFunction::*;
         match self {
             GetCategories => mapper.with_dtype(DataType::String),
+            ExtendCategories(categories) => mapper.try_map_dtype(|dt| {
+                extend_enum_dtype(dt, categories.iter().map(PlSmallStr::as_str))
+            }),
             #[cfg(feature = "strings")]
             LenBytes => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "strings")]
@@ -39,6 +43,7 @@ impl CategoricalFunction {
         use CategoricalFunction as C;
         match self {
             C::GetCategories => FunctionOptions::groupwise(),
+            C::ExtendCategories(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "strings")]
             C::LenBytes | C::LenChars | C::StartsWith(_) | C::EndsWith(_) | C::Slice(_, _) => {
                 FunctionOptions::elementwise()
@@ -52,6 +57,7 @@ impl Display for CategoricalFunction {
         use CategoricalFunction::*;
         let s = match self {
             GetCategories => "get_categories",
+            ExtendCategories(_) => "extend_categories",
             #[cfg(feature = "strings")]
             LenBytes => "len_bytes",
             #[cfg(feature = "strings")]
@@ -72,6 +78,7 @@ impl From<CategoricalFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
         use CategoricalFunction::*;
         match func {
             GetCategories => map!(get_categories),
+            ExtendCategories(categories) => map!(extend_categories, &categories),
             #[cfg(feature = "strings")]
             LenBytes => map!(len_bytes),
             #[cfg(feature = "strings")]
@@ -100,6 +107,12 @@ fn get_categories(s: &Column) -> PolarsResult<Column> {
     Series::try_from((ca.name().clone(), arr)).map(Column::from)
 }
 
+fn extend_categories(s: &Column, categories: &[PlSmallStr]) -> PolarsResult<Column> {
+    let ca = s.categorical()?;
+    ca.extend_categories(categories.iter().map(PlSmallStr::as_str))
+        .map(|ca| ca.into_series().into())
+}
+
 // Determine mapping between categories and underlying physical. For local, this is just 0..n.
 // For global, this is the global indexes.
 fn _get_cat_phys_map(ca: &CategoricalChunked) -> (StringChunked, Series) {
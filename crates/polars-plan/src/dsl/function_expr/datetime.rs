@@ -8,6 +8,8 @@ use polars_time::dst_offset as dst_offset_fn;
 use polars_time::impl_offset_by;
 #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
 use polars_time::replace::{replace_date, replace_datetime};
+#[cfg(feature = "temporal_gaps")]
+use polars_time::{impl_gaps, impl_missing_periods};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -53,6 +55,10 @@ pub enum TemporalFunction {
     Truncate,
     #[cfg(feature = "offset_by")]
     OffsetBy,
+    #[cfg(feature = "temporal_gaps")]
+    MissingPeriods(polars_time::Duration),
+    #[cfg(feature = "temporal_gaps")]
+    Gaps(polars_time::Duration),
     #[cfg(feature = "month_start")]
     MonthStart,
     #[cfg(feature = "month_end")]
@@ -109,6 +115,15 @@ impl TemporalFunction {
             Truncate => mapper.with_same_dtype(),
             #[cfg(feature = "offset_by")]
             OffsetBy => mapper.with_same_dtype(),
+            #[cfg(feature = "temporal_gaps")]
+            MissingPeriods(_) => mapper.with_same_dtype(),
+            #[cfg(feature = "temporal_gaps")]
+            Gaps(_) => mapper.map_dtype(|dt| {
+                DataType::Struct(vec![
+                    Field::new(PlSmallStr::from_static("start"), dt.clone()),
+                    Field::new(PlSmallStr::from_static("end"), dt.clone()),
+                ])
+            }),
             #[cfg(feature = "month_start")]
             MonthStart => mapper.with_same_dtype(),
             #[cfg(feature = "month_end")]
@@ -183,6 +198,8 @@ impl TemporalFunction {
             T::Truncate => FunctionOptions::elementwise(),
             #[cfg(feature = "offset_by")]
             T::OffsetBy => FunctionOptions::elementwise(),
+            #[cfg(feature = "temporal_gaps")]
+            T::MissingPeriods(_) | T::Gaps(_) => FunctionOptions::groupwise(),
             T::Round => FunctionOptions::elementwise(),
             T::Replace => FunctionOptions::elementwise(),
             T::Duration(_) => FunctionOptions::elementwise(),
@@ -237,6 +254,10 @@ impl Display for TemporalFunction {
             Truncate => "truncate",
             #[cfg(feature = "offset_by")]
             OffsetBy => "offset_by",
+            #[cfg(feature = "temporal_gaps")]
+            MissingPeriods(_) => "missing_periods",
+            #[cfg(feature = "temporal_gaps")]
+            Gaps(_) => "gaps",
             #[cfg(feature = "month_start")]
             MonthStart => "month_start",
             #[cfg(feature = "month_end")]
@@ -520,6 +541,16 @@ pub(super) fn offset_by(s: &[Column]) -> PolarsResult<Column> {
     impl_offset_by(s[0].as_materialized_series(), s[1].as_materialized_series()).map(Column::from)
 }
 
+#[cfg(feature = "temporal_gaps")]
+pub(super) fn missing_periods(s: &Column, every: &polars_time::Duration) -> PolarsResult<Column> {
+    impl_missing_periods(s.as_materialized_series(), every).map(Column::from)
+}
+
+#[cfg(feature = "temporal_gaps")]
+pub(super) fn gaps(s: &Column, interval: &polars_time::Duration) -> PolarsResult<Column> {
+    impl_gaps(s.as_materialized_series(), interval).map(Column::from)
+}
+
 #[cfg(feature = "month_start")]
 pub(super) fn month_start(s: &Column) -> PolarsResult<Column> {
     Ok(match s.dtype() {
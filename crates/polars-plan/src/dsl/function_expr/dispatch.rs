@@ -1,3 +1,4 @@
+use polars_core::chunked_array::ops::row_encode::_get_rows_encoded_ca;
 use polars_ops::series::NullStrategy;
 
 use super::*;
@@ -56,6 +57,10 @@ pub(super) fn set_sorted_flag(s: &Column, sorted: IsSorted) -> PolarsResult<Colu
     Ok(s)
 }
 
+pub(super) fn cache(s: &Column) -> PolarsResult<Column> {
+    Ok(s.clone())
+}
+
 #[cfg(feature = "timezones")]
 pub(super) fn replace_time_zone(
     s: &[Column],
@@ -86,6 +91,50 @@ pub(super) fn unique_counts(s: &Column) -> PolarsResult<Column> {
     polars_ops::prelude::unique_counts(s.as_materialized_series()).map(Column::from)
 }
 
+#[cfg(feature = "is_unique")]
+pub(super) fn occurrence_count_by(s: &[Column]) -> PolarsResult<Column> {
+    polars_ops::prelude::occurrence_count_by(s).map(Column::from)
+}
+
+pub(super) fn row_encode(
+    s: &[Column],
+    descending: &[bool],
+    nulls_last: &[bool],
+) -> PolarsResult<Column> {
+    let broadcast = |flags: &[bool], name: &str| -> PolarsResult<Vec<bool>> {
+        match flags {
+            [flag] => Ok(vec![*flag; s.len()]),
+            _ => {
+                polars_ensure!(
+                    flags.len() == s.len(),
+                    ComputeError: "the length of `{}` ({}) does not match the number of inputs ({})",
+                    name, flags.len(), s.len()
+                );
+                Ok(flags.to_vec())
+            },
+        }
+    };
+    let descending = broadcast(descending, "descending")?;
+    let nulls_last = broadcast(nulls_last, "nulls_last")?;
+
+    let len = s.iter().map(|c| c.len()).max().unwrap_or(0);
+    let columns = s
+        .iter()
+        .map(|c| {
+            if c.len() == len {
+                Ok(c.clone())
+            } else {
+                polars_ensure!(c.len() == 1, length_mismatch = "encode_rows", len, c.len());
+                Ok(c.new_from_index(0, len))
+            }
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let name = s[0].name().clone();
+    let ca = _get_rows_encoded_ca(name, &columns, &descending, &nulls_last)?;
+    ca.into_series().cast(&DataType::Binary).map(Column::from)
+}
+
 #[cfg(feature = "dtype-array")]
 pub(super) fn reshape(c: &Column, dimensions: &[ReshapeDimension]) -> PolarsResult<Column> {
     c.reshape_array(dimensions)
@@ -129,13 +178,27 @@ pub(super) fn mean_horizontal(
     polars_ops::prelude::mean_horizontal(s, null_strategy)
 }
 
+pub(super) fn scatter(s: &[Column]) -> PolarsResult<Column> {
+    polars_ops::series::scatter(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        s[2].as_materialized_series(),
+    )
+    .map(Column::from)
+}
+
 pub(super) fn drop_nulls(s: &Column) -> PolarsResult<Column> {
     Ok(s.drop_nulls())
 }
 
 #[cfg(feature = "mode")]
-pub(super) fn mode(s: &Column) -> PolarsResult<Column> {
-    mode::mode(s.as_materialized_series()).map(Column::from)
+pub(super) fn mode(s: &Column, ties: ModeTiePolicy) -> PolarsResult<Column> {
+    mode::mode_with_ties(s.as_materialized_series(), ties).map(Column::from)
+}
+
+#[cfg(all(feature = "mode", feature = "dtype-struct"))]
+pub(super) fn top_k_frequent(s: &Column, k: usize) -> PolarsResult<Column> {
+    mode::top_k_frequent(s.as_materialized_series(), k).map(Column::from)
 }
 
 #[cfg(feature = "moment")]
@@ -154,6 +217,27 @@ pub(super) fn kurtosis(s: &Column, fisher: bool, bias: bool) -> PolarsResult<Col
         .map(|opt_v| Column::new(s.name().clone(), &[opt_v]))
 }
 
+fn float_mean_to_column(s: &Column, mean: Option<f64>) -> Column {
+    if matches!(s.dtype(), DataType::Float32) {
+        Column::new(s.name().clone(), &[mean.map(|v| v as f32)])
+    } else {
+        Column::new(s.name().clone(), &[mean])
+    }
+}
+
+pub(super) fn twap(s: &[Column]) -> PolarsResult<Column> {
+    // @scalar-opt
+    // The window bounds are only given by `Expr::twap_in_window`.
+    let window = match s {
+        [_, _, start, end] => Some((start.as_materialized_series(), end.as_materialized_series())),
+        _ => None,
+    };
+    let mean = s[0]
+        .as_materialized_series()
+        .twap(s[1].as_materialized_series(), window)?;
+    Ok(float_mean_to_column(&s[0], mean))
+}
+
 pub(super) fn arg_unique(s: &Column) -> PolarsResult<Column> {
     // @scalar-opt
     s.as_materialized_series()
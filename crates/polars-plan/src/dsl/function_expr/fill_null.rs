@@ -59,6 +59,31 @@ pub(super) fn fill_null(s: &[Column]) -> PolarsResult<Column> {
     }
 }
 
+#[cfg(feature = "fill_null_by")]
+pub(super) fn fill_null_by(s: &[Column], forward: bool, limit: Duration) -> PolarsResult<Column> {
+    polars_ops::prelude::fill_null_by(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        forward,
+        fill_null_by_limit(limit, s[1].dtype())?,
+    )
+    .map(Column::from)
+}
+
+/// Validate the `limit` of `fill_null_by` for a `by` column of `by_dtype` and
+/// return it in nanoseconds.
+#[cfg(feature = "fill_null_by")]
+pub fn fill_null_by_limit(limit: Duration, by_dtype: &DataType) -> PolarsResult<i64> {
+    let time_zone = match by_dtype {
+        DataType::Datetime(_, Some(time_zone)) => Some(time_zone),
+        _ => None,
+    };
+    polars_ensure!(!limit.negative(), InvalidOperation: "fill limit cannot be negative");
+    ensure_is_constant_duration(limit, time_zone, "limit")?;
+    // `limit` is a constant duration so we can safely use `duration_ns()`.
+    Ok(limit.duration_ns())
+}
+
 pub(super) fn coalesce(s: &mut [Column]) -> PolarsResult<Column> {
     coalesce_columns(s)
 }
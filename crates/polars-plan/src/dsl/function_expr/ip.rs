@@ -0,0 +1,105 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+use crate::map;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum IpFunction {
+    Parse(bool),
+    IsIpv4,
+    IsIpv6,
+    ToInt,
+    IsInSubnet(PlSmallStr),
+    #[cfg(feature = "dtype-struct")]
+    SubnetRange(bool),
+}
+
+impl IpFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IpFunction::*;
+        match self {
+            Parse(_) => mapper.with_dtype(DataType::Binary),
+            IsIpv4 | IsIpv6 | IsInSubnet(_) => mapper.with_dtype(DataType::Boolean),
+            ToInt => mapper.with_dtype(DataType::UInt32),
+            #[cfg(feature = "dtype-struct")]
+            SubnetRange(_) => mapper.with_dtype(DataType::Struct(vec![
+                Field::new(PlSmallStr::from_static("start"), DataType::Binary),
+                Field::new(PlSmallStr::from_static("end"), DataType::Binary),
+            ])),
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        FunctionOptions::elementwise()
+    }
+}
+
+impl Display for IpFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IpFunction::*;
+        let s = match self {
+            Parse(_) => "parse",
+            IsIpv4 => "is_ipv4",
+            IsIpv6 => "is_ipv6",
+            ToInt => "to_int",
+            IsInSubnet(_) => "is_in_subnet",
+            #[cfg(feature = "dtype-struct")]
+            SubnetRange(_) => "subnet_range",
+        };
+        write!(f, "ip.{s}")
+    }
+}
+
+impl From<IpFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: IpFunction) -> Self {
+        use IpFunction::*;
+        match func {
+            Parse(strict) => map!(parse, strict),
+            IsIpv4 => map!(is_ipv4),
+            IsIpv6 => map!(is_ipv6),
+            ToInt => map!(to_int),
+            IsInSubnet(cidr) => map!(is_in_subnet, &cidr),
+            #[cfg(feature = "dtype-struct")]
+            SubnetRange(strict) => map!(subnet_range, strict),
+        }
+    }
+}
+
+fn parse(s: &Column, strict: bool) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    polars_ops::chunked_array::strings::ip_parse(ca, strict).map(|ca| ca.into_column())
+}
+
+fn is_ipv4(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(polars_ops::chunked_array::strings::ip_is_ipv4(ca).into_column())
+}
+
+fn is_ipv6(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(polars_ops::chunked_array::strings::ip_is_ipv6(ca).into_column())
+}
+
+fn to_int(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(polars_ops::chunked_array::strings::ip_to_int(ca).into_column())
+}
+
+fn is_in_subnet(s: &Column, cidr: &str) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    polars_ops::chunked_array::strings::ip_is_in_subnet(ca, cidr).map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "dtype-struct")]
+fn subnet_range(s: &Column, strict: bool) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    polars_ops::chunked_array::strings::ip_subnet_range(ca, strict).map(|ca| ca.into_column())
+}
+
+impl From<IpFunction> for FunctionExpr {
+    fn from(func: IpFunction) -> Self {
+        FunctionExpr::IpExpr(func)
+    }
+}
@@ -48,7 +48,7 @@ pub enum ListFunction {
     Unique(bool),
     NUnique,
     #[cfg(feature = "list_sets")]
-    SetOperation(SetOperation),
+    SetOperation(SetOperation, SetOperationOptions),
     #[cfg(feature = "list_any_all")]
     Any,
     #[cfg(feature = "list_any_all")]
@@ -58,6 +58,8 @@ pub enum ListFunction {
     ToArray(usize),
     #[cfg(feature = "list_to_struct")]
     ToStruct(ListToStructArgs),
+    #[cfg(feature = "list_to_struct")]
+    ToStructWithKeys(Option<Arc<[PlSmallStr]>>),
 }
 
 impl ListFunction {
@@ -110,7 +112,7 @@ impl ListFunction {
             Unique(_) => mapper.with_same_dtype(),
             Length => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "list_sets")]
-            SetOperation(_) => mapper.with_same_dtype(),
+            SetOperation(..) => mapper.with_same_dtype(),
             #[cfg(feature = "list_any_all")]
             Any => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "list_any_all")]
@@ -121,6 +123,21 @@ impl ListFunction {
             NUnique => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "list_to_struct")]
             ToStruct(args) => mapper.try_map_dtype(|x| args.get_output_dtype(x)),
+            #[cfg(feature = "list_to_struct")]
+            ToStructWithKeys(fields) => mapper.try_map_dtype(|dt| {
+                let inner = dt.inner_dtype().ok_or_else(
+                    || polars_err!(InvalidOperation: "attempted list to_struct on non-list dtype: {}", dt),
+                )?;
+                Ok(match fields {
+                    Some(fields) => DataType::Struct(
+                        fields
+                            .iter()
+                            .map(|name| Field::new(name.clone(), inner.clone()))
+                            .collect(),
+                    ),
+                    None => DataType::Unknown(UnknownKind::Any),
+                })
+            }),
         }
     }
 
@@ -137,7 +154,7 @@ impl ListFunction {
             #[cfg(feature = "list_gather")]
             L::GatherEvery => FunctionOptions::elementwise(),
             #[cfg(feature = "list_sets")]
-            L::SetOperation(_) => FunctionOptions::elementwise()
+            L::SetOperation(..) => FunctionOptions::elementwise()
                 .with_casting_rules(CastingRules::Supertype(SuperTypeOptions {
                     flags: SuperTypeFlags::default() | SuperTypeFlags::ALLOW_IMPLODE_LIST,
                 }))
@@ -171,9 +188,13 @@ impl ListFunction {
             #[cfg(feature = "dtype-array")]
             L::ToArray(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "list_to_struct")]
-            L::ToStruct(ListToStructArgs::FixedWidth(_)) => FunctionOptions::elementwise(),
+            L::ToStruct(args) if args.is_fixed_width() => FunctionOptions::elementwise(),
             #[cfg(feature = "list_to_struct")]
-            L::ToStruct(ListToStructArgs::InferWidth { .. }) => FunctionOptions::groupwise(),
+            L::ToStruct(_) => FunctionOptions::groupwise(),
+            #[cfg(feature = "list_to_struct")]
+            L::ToStructWithKeys(Some(_)) => FunctionOptions::elementwise(),
+            #[cfg(feature = "list_to_struct")]
+            L::ToStructWithKeys(None) => FunctionOptions::groupwise(),
         }
     }
 }
@@ -237,7 +258,7 @@ impl Display for ListFunction {
             },
             NUnique => "n_unique",
             #[cfg(feature = "list_sets")]
-            SetOperation(s) => return write!(f, "list.{s}"),
+            SetOperation(s, _) => return write!(f, "list.{s}"),
             #[cfg(feature = "list_any_all")]
             Any => "any",
             #[cfg(feature = "list_any_all")]
@@ -247,6 +268,8 @@ impl Display for ListFunction {
             ToArray(_) => "to_array",
             #[cfg(feature = "list_to_struct")]
             ToStruct(_) => "to_struct",
+            #[cfg(feature = "list_to_struct")]
+            ToStructWithKeys(_) => "to_struct_with_keys",
         };
         write!(f, "list.{name}")
     }
@@ -299,7 +322,7 @@ impl From<ListFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Reverse => map!(reverse),
             Unique(is_stable) => map!(unique, is_stable),
             #[cfg(feature = "list_sets")]
-            SetOperation(s) => map_as_slice!(set_operation, s),
+            SetOperation(s, options) => map_as_slice!(set_operation, s, options),
             #[cfg(feature = "list_any_all")]
             Any => map!(lst_any),
             #[cfg(feature = "list_any_all")]
@@ -310,6 +333,8 @@ impl From<ListFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             NUnique => map!(n_unique),
             #[cfg(feature = "list_to_struct")]
             ToStruct(args) => map!(to_struct, &args),
+            #[cfg(feature = "list_to_struct")]
+            ToStructWithKeys(fields) => map_as_slice!(to_struct_with_keys, fields.as_deref()),
         }
     }
 }
@@ -722,7 +747,11 @@ pub(super) fn unique(s: &Column, is_stable: bool) -> PolarsResult<Column> {
 }
 
 #[cfg(feature = "list_sets")]
-pub(super) fn set_operation(s: &[Column], set_type: SetOperation) -> PolarsResult<Column> {
+pub(super) fn set_operation(
+    s: &[Column],
+    set_type: SetOperation,
+    options: SetOperationOptions,
+) -> PolarsResult<Column> {
     let s0 = &s[0];
     let s1 = &s[1];
 
@@ -746,7 +775,7 @@ pub(super) fn set_operation(s: &[Column], set_type: SetOperation) -> PolarsResul
         };
     }
 
-    list_set_operation(s0.list()?, s1.list()?, set_type).map(|ca| ca.into_column())
+    list_set_operation(s0.list()?, s1.list()?, set_type, options).map(|ca| ca.into_column())
 }
 
 #[cfg(feature = "list_any_all")]
@@ -776,6 +805,15 @@ pub(super) fn to_struct(s: &Column, args: &ListToStructArgs) -> PolarsResult<Col
     Ok(s.list()?.to_struct(args)?.into_series().into())
 }
 
+#[cfg(feature = "list_to_struct")]
+pub(super) fn to_struct_with_keys(
+    s: &[Column],
+    fields: Option<&[PlSmallStr]>,
+) -> PolarsResult<Column> {
+    let ca = list_to_struct_with_keys(s[0].list()?, s[1].list()?, fields)?;
+    Ok(ca.into_series().into())
+}
+
 pub(super) fn n_unique(s: &Column) -> PolarsResult<Column> {
     Ok(s.list()?.lst_n_unique()?.into_column())
 }
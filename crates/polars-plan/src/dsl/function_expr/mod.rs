@@ -9,6 +9,8 @@ mod binary;
 mod bitwise;
 mod boolean;
 mod bounds;
+#[cfg(feature = "row_hash")]
+pub(crate) mod bucket;
 #[cfg(feature = "business")]
 mod business;
 #[cfg(feature = "dtype-categorical")]
@@ -36,6 +38,8 @@ mod fill_null;
 mod fused;
 #[cfg(feature = "index_of")]
 mod index_of;
+#[cfg(feature = "ip")]
+mod ip;
 mod list;
 #[cfg(feature = "log")]
 mod log;
@@ -57,7 +61,7 @@ pub mod rolling_by;
 #[cfg(feature = "round_series")]
 mod round;
 #[cfg(feature = "row_hash")]
-mod row_hash;
+pub(crate) mod row_hash;
 pub(super) mod schema;
 #[cfg(feature = "search_sorted")]
 mod search_sorted;
@@ -74,6 +78,8 @@ mod temporal;
 #[cfg(feature = "trigonometry")]
 pub mod trigonometry;
 mod unique;
+#[cfg(feature = "uuid")]
+mod uuid;
 
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
@@ -82,6 +88,8 @@ use std::hash::{Hash, Hasher};
 pub use array::ArrayFunction;
 #[cfg(feature = "cov")]
 pub use correlation::CorrelationMethod;
+#[cfg(feature = "fill_null_by")]
+pub use fill_null::fill_null_by_limit;
 #[cfg(feature = "fused")]
 pub use fused::FusedOperator;
 pub use list::ListFunction;
@@ -103,6 +111,8 @@ pub use self::business::BusinessFunction;
 pub use self::cat::CategoricalFunction;
 #[cfg(feature = "temporal")]
 pub use self::datetime::TemporalFunction;
+#[cfg(feature = "ip")]
+pub use self::ip::IpFunction;
 pub use self::pow::PowFunction;
 #[cfg(feature = "range")]
 pub(super) use self::range::RangeFunction;
@@ -127,6 +137,8 @@ pub enum FunctionExpr {
     BinaryExpr(BinaryFunction),
     #[cfg(feature = "dtype-categorical")]
     Categorical(CategoricalFunction),
+    #[cfg(feature = "ip")]
+    IpExpr(IpFunction),
     ListExpr(ListFunction),
     #[cfg(feature = "strings")]
     StringExpr(StringFunction),
@@ -154,6 +166,8 @@ pub enum FunctionExpr {
     Pow(PowFunction),
     #[cfg(feature = "row_hash")]
     Hash(u64, u64, u64, u64),
+    #[cfg(feature = "row_hash")]
+    BucketId(u32),
     #[cfg(feature = "arg_where")]
     ArgWhere,
     #[cfg(feature = "index_of")]
@@ -170,20 +184,30 @@ pub enum FunctionExpr {
     Sign,
     FillNull,
     FillNullWithStrategy(FillNullStrategy),
+    #[cfg(feature = "fill_null_by")]
+    FillNullBy {
+        forward: bool,
+        limit: Duration,
+    },
     #[cfg(feature = "rolling_window")]
     RollingExpr(RollingFunction),
     #[cfg(feature = "rolling_window_by")]
     RollingExprBy(RollingFunctionBy),
     ShiftAndFill,
     Shift,
+    #[cfg(feature = "shift_by_time")]
+    ShiftByTime(polars_time::Duration),
     DropNans,
     DropNulls,
     #[cfg(feature = "mode")]
-    Mode,
+    Mode(ModeTiePolicy),
+    #[cfg(all(feature = "mode", feature = "dtype-struct"))]
+    TopKFrequent(usize),
     #[cfg(feature = "moment")]
     Skew(bool),
     #[cfg(feature = "moment")]
     Kurtosis(bool, bool),
+    Twap,
     #[cfg(feature = "dtype-array")]
     Reshape(Vec<ReshapeDimension>),
     #[cfg(feature = "repeat_by")]
@@ -195,6 +219,9 @@ pub enum FunctionExpr {
         seed: Option<u64>,
     },
     Repeat,
+    Scatter,
+    #[cfg(feature = "uuid")]
+    Uuid(UuidVersion),
     #[cfg(feature = "round_series")]
     Clip {
         has_min: bool,
@@ -240,9 +267,16 @@ pub enum FunctionExpr {
     },
     #[cfg(feature = "unique_counts")]
     UniqueCounts,
+    #[cfg(feature = "is_unique")]
+    OccurrenceCountBy,
     #[cfg(feature = "approx_unique")]
     ApproxNUnique,
     Coalesce,
+    /// Order-preserving binary encoding of the rows of the inputs.
+    RowEncode {
+        descending: Vec<bool>,
+        nulls_last: Vec<bool>,
+    },
     ShrinkType,
     #[cfg(feature = "diff")]
     Diff(NullBehavior),
@@ -318,6 +352,9 @@ pub enum FunctionExpr {
         seed: Option<u64>,
     },
     SetSortedFlag(IsSorted),
+    /// Marks its input to be computed once per frame and shared by all its occurrences in a
+    /// context, see [`Expr::cache`]. Evaluating it directly is a no-op.
+    Cache,
     #[cfg(feature = "ffi_plugin")]
     /// Creating this node is unsafe
     /// This will lead to calls over FFI.
@@ -380,6 +417,8 @@ impl Hash for FunctionExpr {
             BinaryExpr(f) => f.hash(state),
             #[cfg(feature = "dtype-categorical")]
             Categorical(f) => f.hash(state),
+            #[cfg(feature = "ip")]
+            IpExpr(f) => f.hash(state),
             ListExpr(f) => f.hash(state),
             #[cfg(feature = "strings")]
             StringExpr(f) => f.hash(state),
@@ -415,6 +454,8 @@ impl Hash for FunctionExpr {
             Interpolate(f) => f.hash(state),
             #[cfg(feature = "interpolate_by")]
             InterpolateBy => {},
+            #[cfg(feature = "shift_by_time")]
+            ShiftByTime(period) => period.hash(state),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
                 flags: _,
@@ -437,7 +478,9 @@ impl Hash for FunctionExpr {
             | Shift
             | ShiftAndFill => {},
             #[cfg(feature = "mode")]
-            Mode => {},
+            Mode(ties) => ties.hash(state),
+            #[cfg(all(feature = "mode", feature = "dtype-struct"))]
+            TopKFrequent(k) => k.hash(state),
             #[cfg(feature = "abs")]
             Abs => {},
             Negate => {},
@@ -452,6 +495,8 @@ impl Hash for FunctionExpr {
             Sign => {},
             #[cfg(feature = "row_hash")]
             Hash(a, b, c, d) => (a, b, c, d).hash(state),
+            #[cfg(feature = "row_hash")]
+            BucketId(num_buckets) => num_buckets.hash(state),
             FillNull => {},
             #[cfg(feature = "rolling_window")]
             RollingExpr(f) => {
@@ -468,7 +513,11 @@ impl Hash for FunctionExpr {
                 a.hash(state);
                 b.hash(state);
             },
+            Twap => {},
             Repeat => {},
+            Scatter => {},
+            #[cfg(feature = "uuid")]
+            Uuid(version) => version.hash(state),
             #[cfg(feature = "rank")]
             Rank { options, seed } => {
                 options.hash(state);
@@ -505,9 +554,18 @@ impl Hash for FunctionExpr {
             },
             #[cfg(feature = "unique_counts")]
             UniqueCounts => {},
+            #[cfg(feature = "is_unique")]
+            OccurrenceCountBy => {},
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => {},
             Coalesce => {},
+            RowEncode {
+                descending,
+                nulls_last,
+            } => {
+                descending.hash(state);
+                nulls_last.hash(state);
+            },
             ShrinkType => {},
             #[cfg(feature = "pct_change")]
             PctChange => {},
@@ -579,6 +637,7 @@ impl Hash for FunctionExpr {
             RLEID => {},
             ToPhysical => {},
             SetSortedFlag(is_sorted) => is_sorted.hash(state),
+            Cache => {},
             #[cfg(feature = "ewma")]
             EwmMean { options } => options.hash(state),
             #[cfg(feature = "ewma_by")]
@@ -602,6 +661,11 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "replace")]
             ReplaceStrict { return_dtype } => return_dtype.hash(state),
             FillNullWithStrategy(strategy) => strategy.hash(state),
+            #[cfg(feature = "fill_null_by")]
+            FillNullBy { forward, limit } => {
+                forward.hash(state);
+                limit.hash(state);
+            },
             GatherEvery { n, offset } => (n, offset).hash(state),
             #[cfg(feature = "reinterpret")]
             Reinterpret(signed) => signed.hash(state),
@@ -622,6 +686,8 @@ impl Display for FunctionExpr {
             BinaryExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => return write!(f, "{func}"),
+            #[cfg(feature = "ip")]
+            IpExpr(func) => return write!(f, "{func}"),
             ListExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "strings")]
             StringExpr(func) => return write!(f, "{func}"),
@@ -643,6 +709,8 @@ impl Display for FunctionExpr {
             Pow(func) => return write!(f, "{func}"),
             #[cfg(feature = "row_hash")]
             Hash(_, _, _, _) => "hash",
+            #[cfg(feature = "row_hash")]
+            BucketId(_) => "bucket_id",
             #[cfg(feature = "arg_where")]
             ArgWhere => "arg_where",
             #[cfg(feature = "index_of")]
@@ -666,13 +734,21 @@ impl Display for FunctionExpr {
             DropNans => "drop_nans",
             DropNulls => "drop_nulls",
             #[cfg(feature = "mode")]
-            Mode => "mode",
+            Mode(_) => "mode",
+            #[cfg(all(feature = "mode", feature = "dtype-struct"))]
+            TopKFrequent(_) => "top_k_frequent",
             #[cfg(feature = "moment")]
             Skew(_) => "skew",
             #[cfg(feature = "moment")]
             Kurtosis(..) => "kurtosis",
+            Twap => "twap",
             ArgUnique => "arg_unique",
             Repeat => "repeat",
+            Scatter => "scatter",
+            #[cfg(feature = "uuid")]
+            Uuid(UuidVersion::V4) => "uuid_v4",
+            #[cfg(feature = "uuid")]
+            Uuid(UuidVersion::V7) => "uuid_v7",
             #[cfg(feature = "rank")]
             Rank { .. } => "rank",
             #[cfg(feature = "round_series")]
@@ -695,6 +771,8 @@ impl Display for FunctionExpr {
             #[cfg(feature = "top_k")]
             TopKBy { .. } => "top_k_by",
             Shift => "shift",
+            #[cfg(feature = "shift_by_time")]
+            ShiftByTime(_) => "shift_by_time",
             #[cfg(feature = "cum_agg")]
             CumCount { .. } => "cum_count",
             #[cfg(feature = "cum_agg")]
@@ -709,10 +787,13 @@ impl Display for FunctionExpr {
             ValueCounts { .. } => "value_counts",
             #[cfg(feature = "unique_counts")]
             UniqueCounts => "unique_counts",
+            #[cfg(feature = "is_unique")]
+            OccurrenceCountBy => "occurrence_count_by",
             Reverse => "reverse",
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => "approx_n_unique",
             Coalesce => "coalesce",
+            RowEncode { .. } => "encode_rows",
             ShrinkType => "shrink_dtype",
             #[cfg(feature = "diff")]
             Diff(_) => "diff",
@@ -772,6 +853,7 @@ impl Display for FunctionExpr {
             #[cfg(feature = "random")]
             Random { method, .. } => method.into(),
             SetSortedFlag(_) => "set_sorted",
+            Cache => "cache",
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin { lib, symbol, .. } => return write!(f, "{lib}:{symbol}"),
             MaxHorizontal => "max_horizontal",
@@ -793,6 +875,8 @@ impl Display for FunctionExpr {
             #[cfg(feature = "replace")]
             ReplaceStrict { .. } => "replace_strict",
             FillNullWithStrategy(_) => "fill_null_with_strategy",
+            #[cfg(feature = "fill_null_by")]
+            FillNullBy { .. } => "fill_null_by",
             GatherEvery { .. } => "gather_every",
             #[cfg(feature = "reinterpret")]
             Reinterpret(_) => "reinterpret",
@@ -894,6 +978,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             BinaryExpr(func) => func.into(),
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.into(),
+            #[cfg(feature = "ip")]
+            IpExpr(func) => func.into(),
             ListExpr(func) => func.into(),
             #[cfg(feature = "strings")]
             StringExpr(func) => func.into(),
@@ -930,6 +1016,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Hash(k0, k1, k2, k3) => {
                 map!(row_hash::row_hash, k0, k1, k2, k3)
             },
+            #[cfg(feature = "row_hash")]
+            BucketId(num_buckets) => wrap!(bucket::bucket_id, num_buckets),
             #[cfg(feature = "arg_where")]
             ArgWhere => {
                 wrap!(arg_where::arg_where)
@@ -972,6 +1060,9 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                     Quantile(options) => map!(rolling::rolling_quantile, options.clone()),
                     Var(options) => map!(rolling::rolling_var, options.clone()),
                     Std(options) => map!(rolling::rolling_std, options.clone()),
+                    Registered(name, options) => {
+                        map!(rolling::rolling_map_registered, &name, &options)
+                    },
                     #[cfg(feature = "moment")]
                     Skew(options) => map!(rolling::rolling_skew, options.clone()),
                     #[cfg(feature = "moment")]
@@ -1029,13 +1120,19 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                 map_as_slice!(clip::clip, has_min, has_max)
             },
             #[cfg(feature = "mode")]
-            Mode => map!(dispatch::mode),
+            Mode(ties) => map!(dispatch::mode, ties),
+            #[cfg(all(feature = "mode", feature = "dtype-struct"))]
+            TopKFrequent(k) => map!(dispatch::top_k_frequent, k),
             #[cfg(feature = "moment")]
             Skew(bias) => map!(dispatch::skew, bias),
             #[cfg(feature = "moment")]
             Kurtosis(fisher, bias) => map!(dispatch::kurtosis, fisher, bias),
+            Twap => map_as_slice!(dispatch::twap),
             ArgUnique => map!(dispatch::arg_unique),
             Repeat => map_as_slice!(repeat::repeat),
+            Scatter => map_as_slice!(dispatch::scatter),
+            #[cfg(feature = "uuid")]
+            Uuid(version) => map_as_slice!(uuid::uuid, version),
             #[cfg(feature = "rank")]
             Rank { options, seed } => map!(dispatch::rank, options, seed),
             #[cfg(feature = "dtype-struct")]
@@ -1049,6 +1146,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             #[cfg(feature = "top_k")]
             TopKBy { descending } => map_as_slice!(top_k_by, descending.clone()),
             Shift => map_as_slice!(shift_and_fill::shift),
+            #[cfg(feature = "shift_by_time")]
+            ShiftByTime(period) => map_as_slice!(shift_and_fill::shift_by_time, &period),
             #[cfg(feature = "cum_agg")]
             CumCount { reverse } => map!(cum::cum_count, reverse),
             #[cfg(feature = "cum_agg")]
@@ -1074,10 +1173,16 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             ),
             #[cfg(feature = "unique_counts")]
             UniqueCounts => map!(dispatch::unique_counts),
+            #[cfg(feature = "is_unique")]
+            OccurrenceCountBy => map_as_slice!(dispatch::occurrence_count_by),
             Reverse => map!(dispatch::reverse),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => map!(dispatch::approx_n_unique),
             Coalesce => map_as_slice!(fill_null::coalesce),
+            RowEncode {
+                descending,
+                nulls_last,
+            } => map_as_slice!(dispatch::row_encode, &descending, &nulls_last),
             ShrinkType => map_owned!(shrink_type::shrink),
             #[cfg(feature = "diff")]
             Diff(null_behavior) => map_as_slice!(dispatch::diff, null_behavior),
@@ -1175,6 +1280,7 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                 }
             },
             SetSortedFlag(sorted) => map!(dispatch::set_sorted_flag, sorted),
+            Cache => map!(dispatch::cache),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
                 flags: _,
@@ -1211,6 +1317,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             },
 
             FillNullWithStrategy(strategy) => map!(dispatch::fill_null_with_strategy, strategy),
+            #[cfg(feature = "fill_null_by")]
+            FillNullBy { forward, limit } => {
+                map_as_slice!(fill_null::fill_null_by, forward, limit)
+            },
             GatherEvery { n, offset } => map!(dispatch::gather_every, n, offset),
             #[cfg(feature = "reinterpret")]
             Reinterpret(signed) => map!(dispatch::reinterpret, signed),
@@ -1228,6 +1338,8 @@ impl FunctionExpr {
             F::BinaryExpr(e) => e.function_options(),
             #[cfg(feature = "dtype-categorical")]
             F::Categorical(e) => e.function_options(),
+            #[cfg(feature = "ip")]
+            F::IpExpr(e) => e.function_options(),
             F::ListExpr(e) => e.function_options(),
             #[cfg(feature = "strings")]
             F::StringExpr(e) => e.function_options(),
@@ -1251,6 +1363,8 @@ impl FunctionExpr {
             F::NullCount => FunctionOptions::aggregation(),
             #[cfg(feature = "row_hash")]
             F::Hash(_, _, _, _) => FunctionOptions::elementwise(),
+            #[cfg(feature = "row_hash")]
+            F::BucketId(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "arg_where")]
             F::ArgWhere => FunctionOptions::groupwise(),
             #[cfg(feature = "index_of")]
@@ -1272,21 +1386,32 @@ impl FunctionExpr {
                 FunctionOptions::elementwise()
             },
             F::FillNullWithStrategy(_) => FunctionOptions::groupwise(),
+            #[cfg(feature = "fill_null_by")]
+            F::FillNullBy { .. } => FunctionOptions::length_preserving(),
             #[cfg(feature = "rolling_window")]
             F::RollingExpr(_) => FunctionOptions::length_preserving(),
             #[cfg(feature = "rolling_window_by")]
             F::RollingExprBy(_) => FunctionOptions::length_preserving(),
             F::ShiftAndFill => FunctionOptions::length_preserving(),
             F::Shift => FunctionOptions::length_preserving(),
+            #[cfg(feature = "shift_by_time")]
+            F::ShiftByTime(_) => FunctionOptions::length_preserving(),
             F::DropNans => FunctionOptions::row_separable(),
             F::DropNulls => FunctionOptions::row_separable()
                 .with_flags(|f| f | FunctionFlags::ALLOW_EMPTY_INPUTS),
             #[cfg(feature = "mode")]
-            F::Mode => FunctionOptions::groupwise(),
+            F::Mode(ModeTiePolicy::All) => FunctionOptions::groupwise(),
+            #[cfg(feature = "mode")]
+            F::Mode(_) => FunctionOptions::aggregation(),
+            #[cfg(all(feature = "mode", feature = "dtype-struct"))]
+            F::TopKFrequent(_) => {
+                FunctionOptions::aggregation().with_flags(|f| f | FunctionFlags::PASS_NAME_TO_APPLY)
+            },
             #[cfg(feature = "moment")]
             F::Skew(_) => FunctionOptions::aggregation(),
             #[cfg(feature = "moment")]
             F::Kurtosis(_, _) => FunctionOptions::aggregation(),
+            F::Twap => FunctionOptions::aggregation(),
             #[cfg(feature = "dtype-array")]
             F::Reshape(_) => FunctionOptions::groupwise(),
             #[cfg(feature = "repeat_by")]
@@ -1297,6 +1422,11 @@ impl FunctionExpr {
             F::Repeat => {
                 FunctionOptions::groupwise().with_flags(|f| f | FunctionFlags::ALLOW_RENAME)
             },
+            F::Scatter => FunctionOptions::groupwise(),
+            #[cfg(feature = "uuid")]
+            F::Uuid(_) => {
+                FunctionOptions::groupwise().with_flags(|f| f | FunctionFlags::ALLOW_RENAME)
+            },
             #[cfg(feature = "round_series")]
             F::Clip { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "dtype-struct")]
@@ -1320,11 +1450,16 @@ impl FunctionExpr {
             },
             #[cfg(feature = "unique_counts")]
             F::UniqueCounts => FunctionOptions::groupwise(),
+            #[cfg(feature = "is_unique")]
+            F::OccurrenceCountBy => FunctionOptions::length_preserving()
+                .with_flags(|f| f | FunctionFlags::INPUT_WILDCARD_EXPANSION),
             #[cfg(feature = "approx_unique")]
             F::ApproxNUnique => FunctionOptions::aggregation(),
             F::Coalesce => FunctionOptions::elementwise()
                 .with_flags(|f| f | FunctionFlags::INPUT_WILDCARD_EXPANSION)
                 .with_supertyping(Default::default()),
+            F::RowEncode { .. } => FunctionOptions::elementwise()
+                .with_flags(|f| f | FunctionFlags::INPUT_WILDCARD_EXPANSION),
             F::ShrinkType => FunctionOptions::length_preserving(),
             #[cfg(feature = "diff")]
             F::Diff(NullBehavior::Drop) => FunctionOptions::groupwise(),
@@ -1375,7 +1510,7 @@ impl FunctionExpr {
                 method: RandomMethod::Shuffle,
                 ..
             } => FunctionOptions::length_preserving(),
-            F::SetSortedFlag(_) => FunctionOptions::elementwise(),
+            F::SetSortedFlag(_) | F::Cache => FunctionOptions::elementwise(),
             #[cfg(feature = "ffi_plugin")]
             F::FfiPlugin { flags, .. } => *flags,
             F::MaxHorizontal | F::MinHorizontal => FunctionOptions::elementwise().with_flags(|f| {
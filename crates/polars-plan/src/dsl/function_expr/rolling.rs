@@ -18,6 +18,8 @@ pub enum RollingFunction {
     Quantile(RollingOptionsFixedWindow),
     Var(RollingOptionsFixedWindow),
     Std(RollingOptionsFixedWindow),
+    /// A rolling aggregator registered with [`register_rolling_aggregator`] under the name.
+    Registered(PlSmallStr, RollingOptionsFixedWindow),
     #[cfg(feature = "moment")]
     Skew(RollingOptionsFixedWindow),
     #[cfg(feature = "moment")]
@@ -43,6 +45,7 @@ impl Display for RollingFunction {
             Quantile(_) => "quantile",
             Var(_) => "var",
             Std(_) => "std",
+            Registered(..) => "map_registered",
             #[cfg(feature = "moment")]
             Skew(..) => "skew",
             #[cfg(feature = "moment")]
@@ -120,6 +123,16 @@ pub(super) fn rolling_skew(s: &Column, options: RollingOptionsFixedWindow) -> Po
     polars_ops::series::rolling_skew(s, options).map(Column::from)
 }
 
+pub(super) fn rolling_map_registered(
+    s: &Column,
+    name: &str,
+    options: &RollingOptionsFixedWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    polars_time::chunkedarray::rolling_map_registered(s.as_materialized_series(), name, options)
+        .map(Column::from)
+}
+
 #[cfg(feature = "moment")]
 pub(super) fn rolling_kurtosis(
     s: &Column,
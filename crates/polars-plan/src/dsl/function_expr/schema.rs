@@ -19,6 +19,8 @@ impl FunctionExpr {
             BinaryExpr(s) => s.get_field(mapper),
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.get_field(mapper),
+            #[cfg(feature = "ip")]
+            IpExpr(func) => func.get_field(mapper),
             ListExpr(func) => func.get_field(mapper),
             #[cfg(feature = "strings")]
             StringExpr(s) => s.get_field(mapper),
@@ -42,8 +44,11 @@ impl FunctionExpr {
                 _ => mapper.map_to_float_dtype(),
             },
             Coalesce => mapper.map_to_supertype(),
+            RowEncode { .. } => mapper.with_dtype(DataType::Binary),
             #[cfg(feature = "row_hash")]
             Hash(..) => mapper.with_dtype(DataType::UInt64),
+            #[cfg(feature = "row_hash")]
+            BucketId(_) => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "arg_where")]
             ArgWhere => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "index_of")]
@@ -66,6 +71,7 @@ impl FunctionExpr {
                     Min(_) | Max(_) => mapper.with_same_dtype(),
                     Mean(_) | Quantile(_) | Var(_) | Std(_) => mapper.map_to_float_dtype(),
                     Sum(_) => mapper.sum_dtype(),
+                    Registered(..) => mapper.with_dtype(DataType::Float64),
                     #[cfg(feature = "cov")]
                     CorrCov {..} => mapper.map_to_float_dtype(),
                     #[cfg(feature = "moment")]
@@ -87,13 +93,26 @@ impl FunctionExpr {
             #[cfg(feature = "round_series")]
             Clip { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "mode")]
-            Mode => mapper.with_same_dtype(),
+            Mode(ModeTiePolicy::AllAsList) => mapper.map_dtype(|dt| DataType::List(Box::new(dt.clone()))),
+            #[cfg(feature = "mode")]
+            Mode(_) => mapper.with_same_dtype(),
+            #[cfg(all(feature = "mode", feature = "dtype-struct"))]
+            TopKFrequent(_) => mapper.map_dtype(|dt| {
+                DataType::List(Box::new(DataType::Struct(vec![
+                    Field::new(fields[0].name().clone(), dt.clone()),
+                    Field::new(PlSmallStr::from_static("count"), IDX_DTYPE),
+                ])))
+            }),
             #[cfg(feature = "moment")]
             Skew(_) => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "moment")]
             Kurtosis(..) => mapper.with_dtype(DataType::Float64),
+            Twap => mapper.map_to_float_dtype(),
             ArgUnique => mapper.with_dtype(IDX_DTYPE),
             Repeat => mapper.with_same_dtype(),
+            Scatter => mapper.with_same_dtype(),
+            #[cfg(feature = "uuid")]
+            Uuid(_) => mapper.with_dtype(DataType::String),
             #[cfg(feature = "rank")]
             Rank { options, .. } => mapper.with_dtype(match options.method {
                 RankMethod::Average => DataType::Float64,
@@ -127,7 +146,11 @@ impl FunctionExpr {
             }),
             #[cfg(feature = "unique_counts")]
             UniqueCounts => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "is_unique")]
+            OccurrenceCountBy => mapper.with_dtype(IDX_DTYPE),
             Shift | Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "shift_by_time")]
+            ShiftByTime(_) => mapper.with_same_dtype(),
             #[cfg(feature = "cum_agg")]
             CumCount { .. } => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "cum_agg")]
@@ -319,7 +342,7 @@ impl FunctionExpr {
             ToPhysical => mapper.to_physical_type(),
             #[cfg(feature = "random")]
             Random { .. } => mapper.with_same_dtype(),
-            SetSortedFlag(_) => mapper.with_same_dtype(),
+            SetSortedFlag(_) | Cache => mapper.with_same_dtype(),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
                 flags: _,
@@ -359,6 +382,8 @@ impl FunctionExpr {
             #[cfg(feature = "replace")]
             ReplaceStrict { return_dtype } => mapper.replace_dtype(return_dtype.clone()),
             FillNullWithStrategy(_) => mapper.with_same_dtype(),
+            #[cfg(feature = "fill_null_by")]
+            FillNullBy { .. } => mapper.with_same_dtype(),
             GatherEvery { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "reinterpret")]
             Reinterpret(signed) => {
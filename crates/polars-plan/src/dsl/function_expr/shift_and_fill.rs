@@ -121,3 +121,16 @@ pub fn shift(args: &[Column]) -> PolarsResult<Column> {
         None => Ok(Column::full_null(s.name().clone(), s.len(), s.dtype())),
     }
 }
+
+#[cfg(feature = "shift_by_time")]
+pub(super) fn shift_by_time(
+    args: &[Column],
+    period: &polars_time::Duration,
+) -> PolarsResult<Column> {
+    polars_time::impl_shift_by_time(
+        args[0].as_materialized_series(),
+        args[1].as_materialized_series(),
+        period,
+    )
+    .map(Column::from)
+}
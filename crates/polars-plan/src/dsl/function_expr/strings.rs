@@ -35,6 +35,11 @@ pub enum StringFunction {
         literal: bool,
         strict: bool,
     },
+    #[cfg(feature = "regex_lite")]
+    ContainsWithEngine {
+        engine: RegexEngine,
+        strict: bool,
+    },
     CountMatches(bool),
     EndsWith,
     Extract(usize),
@@ -72,8 +77,30 @@ pub enum StringFunction {
     Normalize {
         form: UnicodeForm,
     },
+    #[cfg(feature = "string_normalize")]
+    Casefold,
+    #[cfg(feature = "string_normalize")]
+    CollationKey,
+    #[cfg(feature = "string_normalize")]
+    LowercaseLocale(PlSmallStr),
+    #[cfg(feature = "string_normalize")]
+    UppercaseLocale(PlSmallStr),
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_similarity")]
+    Levenshtein,
+    #[cfg(feature = "string_similarity")]
+    JaroWinkler,
+    #[cfg(feature = "string_similarity")]
+    NgramSimilarity(usize),
+    #[cfg(feature = "string_tokenize")]
+    Tokenize(Tokenizer),
+    #[cfg(feature = "string_tokenize")]
+    Ngrams(usize),
+    #[cfg(feature = "string_tokenize")]
+    Shingle(usize),
+    #[cfg(feature = "uuid")]
+    ToUuid(bool),
     #[cfg(feature = "string_pad")]
     PadStart {
         length: usize,
@@ -95,6 +122,14 @@ pub enum StringFunction {
     Base64Encode,
     #[cfg(feature = "binary_encoding")]
     Base64Decode(bool),
+    #[cfg(feature = "string_encoding")]
+    Base32Encode,
+    #[cfg(feature = "binary_encoding")]
+    Base32Decode(bool),
+    #[cfg(feature = "string_encoding")]
+    Base85Encode,
+    #[cfg(feature = "binary_encoding")]
+    Base85Decode(bool),
     StartsWith,
     StripChars,
     StripCharsStart,
@@ -111,6 +146,9 @@ pub enum StringFunction {
     #[cfg(feature = "temporal")]
     Strptime(DataType, StrptimeOptions),
     Split(bool),
+    SplitMax(usize),
+    #[cfg(feature = "regex")]
+    SplitRegex(Option<usize>),
     #[cfg(feature = "dtype-decimal")]
     ToDecimal(usize),
     #[cfg(feature = "nightly")]
@@ -136,6 +174,10 @@ pub enum StringFunction {
         ascii_case_insensitive: bool,
         overlapping: bool,
     },
+    #[cfg(feature = "find_many")]
+    ReplaceManyRegex,
+    #[cfg(all(feature = "find_many", feature = "dtype-struct"))]
+    ExtractFirstMatch,
     #[cfg(feature = "regex")]
     EscapeRegex,
 }
@@ -148,6 +190,8 @@ impl StringFunction {
             ConcatVertical { .. } | ConcatHorizontal { .. } => mapper.with_dtype(DataType::String),
             #[cfg(feature = "regex")]
             Contains { .. } => mapper.with_dtype(DataType::Boolean),
+            #[cfg(feature = "regex_lite")]
+            ContainsWithEngine { .. } => mapper.with_dtype(DataType::Boolean),
             CountMatches(_) => mapper.with_dtype(DataType::UInt32),
             EndsWith | StartsWith => mapper.with_dtype(DataType::Boolean),
             Extract(_) => mapper.with_same_dtype(),
@@ -168,11 +212,27 @@ impl StringFunction {
             Replace { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "string_normalize")]
             Normalize { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "string_normalize")]
+            Casefold | LowercaseLocale(_) | UppercaseLocale(_) => mapper.with_same_dtype(),
+            #[cfg(feature = "string_normalize")]
+            CollationKey => mapper.with_dtype(DataType::Binary),
             #[cfg(feature = "string_reverse")]
             Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "string_similarity")]
+            Levenshtein => mapper.with_dtype(DataType::UInt32),
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler | NgramSimilarity(_) => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "string_tokenize")]
+            Tokenize(_) | Ngrams(_) | Shingle(_) => {
+                mapper.with_dtype(DataType::List(Box::new(DataType::String)))
+            },
+            #[cfg(feature = "uuid")]
+            ToUuid(_) => mapper.with_dtype(DataType::Binary),
             #[cfg(feature = "temporal")]
             Strptime(dtype, _) => mapper.with_dtype(dtype.clone()),
-            Split(_) => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
+            Split(_) | SplitMax(_) => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
+            #[cfg(feature = "regex")]
+            SplitRegex(_) => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
             #[cfg(feature = "nightly")]
             Titlecase => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-decimal")]
@@ -185,6 +245,10 @@ impl StringFunction {
             Base64Encode => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
             Base64Decode(_) => mapper.with_dtype(DataType::Binary),
+            #[cfg(feature = "string_encoding")]
+            Base32Encode | Base85Encode => mapper.with_same_dtype(),
+            #[cfg(feature = "binary_encoding")]
+            Base32Decode(_) | Base85Decode(_) => mapper.with_dtype(DataType::Binary),
             Uppercase | Lowercase | StripChars | StripCharsStart | StripCharsEnd | StripPrefix
             | StripSuffix | Slice | Head | Tail => mapper.with_same_dtype(),
             #[cfg(feature = "string_pad")]
@@ -209,6 +273,17 @@ impl StringFunction {
             ExtractMany { .. } => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
             #[cfg(feature = "find_many")]
             FindMany { .. } => mapper.with_dtype(DataType::List(Box::new(DataType::UInt32))),
+            #[cfg(feature = "find_many")]
+            ReplaceManyRegex => mapper.with_same_dtype(),
+            #[cfg(all(feature = "find_many", feature = "dtype-struct"))]
+            ExtractFirstMatch => mapper.with_dtype(DataType::Struct(vec![
+                Field::new(PlSmallStr::from_static("pattern"), IDX_DTYPE),
+                Field::new(PlSmallStr::from_static("match"), DataType::String),
+                Field::new(
+                    PlSmallStr::from_static("captures"),
+                    DataType::List(Box::new(DataType::String)),
+                ),
+            ])),
             #[cfg(feature = "regex")]
             EscapeRegex => mapper.with_same_dtype(),
         }
@@ -226,6 +301,10 @@ impl StringFunction {
             S::Contains { .. } => {
                 FunctionOptions::elementwise().with_supertyping(Default::default())
             },
+            #[cfg(feature = "regex_lite")]
+            S::ContainsWithEngine { .. } => {
+                FunctionOptions::elementwise().with_supertyping(Default::default())
+            },
             S::CountMatches(_) => FunctionOptions::elementwise(),
             S::EndsWith | S::StartsWith | S::Extract(_) => {
                 FunctionOptions::elementwise().with_supertyping(Default::default())
@@ -251,20 +330,38 @@ impl StringFunction {
             },
             #[cfg(feature = "string_normalize")]
             S::Normalize { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "string_normalize")]
+            S::Casefold | S::CollationKey | S::LowercaseLocale(_) | S::UppercaseLocale(_) => {
+                FunctionOptions::elementwise()
+            },
             #[cfg(feature = "string_reverse")]
             S::Reverse => FunctionOptions::elementwise(),
+            #[cfg(feature = "string_similarity")]
+            S::Levenshtein | S::JaroWinkler | S::NgramSimilarity(_) => {
+                FunctionOptions::elementwise()
+            },
+            #[cfg(feature = "string_tokenize")]
+            S::Tokenize(_) | S::Ngrams(_) | S::Shingle(_) => FunctionOptions::elementwise(),
+            #[cfg(feature = "uuid")]
+            S::ToUuid(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "temporal")]
             S::Strptime(_, options) if options.format.is_some() => FunctionOptions::elementwise(),
             S::Strptime(_, _) => FunctionOptions::elementwise_with_infer(),
-            S::Split(_) => FunctionOptions::elementwise(),
+            S::Split(_) | S::SplitMax(_) => FunctionOptions::elementwise(),
+            #[cfg(feature = "regex")]
+            S::SplitRegex(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "nightly")]
             S::Titlecase => FunctionOptions::elementwise(),
             #[cfg(feature = "dtype-decimal")]
             S::ToDecimal(_) => FunctionOptions::elementwise_with_infer(),
             #[cfg(feature = "string_encoding")]
-            S::HexEncode | S::Base64Encode => FunctionOptions::elementwise(),
+            S::HexEncode | S::Base64Encode | S::Base32Encode | S::Base85Encode => {
+                FunctionOptions::elementwise()
+            },
             #[cfg(feature = "binary_encoding")]
-            S::HexDecode(_) | S::Base64Decode(_) => FunctionOptions::elementwise(),
+            S::HexDecode(_) | S::Base64Decode(_) | S::Base32Decode(_) | S::Base85Decode(_) => {
+                FunctionOptions::elementwise()
+            },
             S::Uppercase | S::Lowercase => FunctionOptions::elementwise(),
             S::StripChars
             | S::StripCharsStart
@@ -288,6 +385,10 @@ impl StringFunction {
             S::ExtractMany { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "find_many")]
             S::FindMany { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "find_many")]
+            S::ReplaceManyRegex => FunctionOptions::elementwise(),
+            #[cfg(all(feature = "find_many", feature = "dtype-struct"))]
+            S::ExtractFirstMatch => FunctionOptions::elementwise(),
             #[cfg(feature = "regex")]
             S::EscapeRegex => FunctionOptions::elementwise(),
         }
@@ -300,6 +401,8 @@ impl Display for StringFunction {
         let s = match self {
             #[cfg(feature = "regex")]
             Contains { .. } => "contains",
+            #[cfg(feature = "regex_lite")]
+            ContainsWithEngine { .. } => "contains",
             CountMatches(_) => "count_matches",
             EndsWith => "ends_with",
             Extract(_) => "extract",
@@ -331,8 +434,30 @@ impl Display for StringFunction {
             Replace { .. } => "replace",
             #[cfg(feature = "string_normalize")]
             Normalize { .. } => "normalize",
+            #[cfg(feature = "string_normalize")]
+            Casefold => "casefold",
+            #[cfg(feature = "string_normalize")]
+            CollationKey => "collation_key",
+            #[cfg(feature = "string_normalize")]
+            LowercaseLocale(_) => "to_lowercase_locale",
+            #[cfg(feature = "string_normalize")]
+            UppercaseLocale(_) => "to_uppercase_locale",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_similarity")]
+            Levenshtein => "levenshtein",
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => "jaro_winkler",
+            #[cfg(feature = "string_similarity")]
+            NgramSimilarity(_) => "ngram_similarity",
+            #[cfg(feature = "string_tokenize")]
+            Tokenize(_) => "tokenize",
+            #[cfg(feature = "string_tokenize")]
+            Ngrams(_) => "ngrams",
+            #[cfg(feature = "string_tokenize")]
+            Shingle(_) => "shingle",
+            #[cfg(feature = "uuid")]
+            ToUuid(_) => "to_uuid",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
@@ -341,6 +466,14 @@ impl Display for StringFunction {
             Base64Encode => "base64_encode",
             #[cfg(feature = "binary_encoding")]
             Base64Decode(_) => "base64_decode",
+            #[cfg(feature = "string_encoding")]
+            Base32Encode => "base32_encode",
+            #[cfg(feature = "binary_encoding")]
+            Base32Decode(_) => "base32_decode",
+            #[cfg(feature = "string_encoding")]
+            Base85Encode => "base85_encode",
+            #[cfg(feature = "binary_encoding")]
+            Base85Decode(_) => "base85_decode",
             Slice => "slice",
             StartsWith => "starts_with",
             StripChars => "strip_chars",
@@ -367,6 +500,9 @@ impl Display for StringFunction {
                     "split"
                 }
             },
+            SplitMax(_) => "split",
+            #[cfg(feature = "regex")]
+            SplitRegex(_) => "split_regex",
             #[cfg(feature = "nightly")]
             Titlecase => "titlecase",
             #[cfg(feature = "dtype-decimal")]
@@ -382,6 +518,10 @@ impl Display for StringFunction {
             ExtractMany { .. } => "extract_many",
             #[cfg(feature = "find_many")]
             FindMany { .. } => "extract_many",
+            #[cfg(feature = "find_many")]
+            ReplaceManyRegex => "replace_many_regex",
+            #[cfg(all(feature = "find_many", feature = "dtype-struct"))]
+            ExtractFirstMatch => "extract_first_match",
             #[cfg(feature = "regex")]
             EscapeRegex => "escape_regex",
         };
@@ -395,6 +535,10 @@ impl From<StringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
         match func {
             #[cfg(feature = "regex")]
             Contains { literal, strict } => map_as_slice!(strings::contains, literal, strict),
+            #[cfg(feature = "regex_lite")]
+            ContainsWithEngine { engine, strict } => {
+                map_as_slice!(strings::contains_with_engine, engine, strict)
+            },
             CountMatches(literal) => {
                 map_as_slice!(strings::count_matches, literal)
             },
@@ -431,6 +575,9 @@ impl From<StringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Split(inclusive) => {
                 map_as_slice!(strings::split, inclusive)
             },
+            SplitMax(maxsplit) => map_as_slice!(strings::split_max, maxsplit),
+            #[cfg(feature = "regex")]
+            SplitRegex(maxsplit) => map_as_slice!(strings::split_regex, maxsplit),
             #[cfg(feature = "dtype-struct")]
             SplitExact { n, inclusive } => map_as_slice!(strings::split_exact, n, inclusive),
             #[cfg(feature = "dtype-struct")]
@@ -449,8 +596,30 @@ impl From<StringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Replace { n, literal } => map_as_slice!(strings::replace, literal, n),
             #[cfg(feature = "string_normalize")]
             Normalize { form } => map!(strings::normalize, form.clone()),
+            #[cfg(feature = "string_normalize")]
+            Casefold => map!(strings::casefold),
+            #[cfg(feature = "string_normalize")]
+            CollationKey => map!(strings::collation_key),
+            #[cfg(feature = "string_normalize")]
+            LowercaseLocale(locale) => map!(strings::to_lowercase_locale, locale.as_str()),
+            #[cfg(feature = "string_normalize")]
+            UppercaseLocale(locale) => map!(strings::to_uppercase_locale, locale.as_str()),
             #[cfg(feature = "string_reverse")]
             Reverse => map!(strings::reverse),
+            #[cfg(feature = "string_similarity")]
+            Levenshtein => map_as_slice!(strings::levenshtein),
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => map_as_slice!(strings::jaro_winkler),
+            #[cfg(feature = "string_similarity")]
+            NgramSimilarity(n) => map_as_slice!(strings::ngram_similarity, n),
+            #[cfg(feature = "string_tokenize")]
+            Tokenize(tokenizer) => map!(strings::tokenize, &tokenizer),
+            #[cfg(feature = "string_tokenize")]
+            Ngrams(n) => map!(strings::ngrams, n),
+            #[cfg(feature = "string_tokenize")]
+            Shingle(k) => map!(strings::shingle, k),
+            #[cfg(feature = "uuid")]
+            ToUuid(strict) => map!(strings::to_uuid, strict),
             Uppercase => map!(uppercase),
             Lowercase => map!(lowercase),
             #[cfg(feature = "nightly")]
@@ -473,6 +642,14 @@ impl From<StringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Base64Encode => map!(strings::base64_encode),
             #[cfg(feature = "binary_encoding")]
             Base64Decode(strict) => map!(strings::base64_decode, strict),
+            #[cfg(feature = "string_encoding")]
+            Base32Encode => map!(strings::base32_encode),
+            #[cfg(feature = "binary_encoding")]
+            Base32Decode(strict) => map!(strings::base32_decode, strict),
+            #[cfg(feature = "string_encoding")]
+            Base85Encode => map!(strings::base85_encode),
+            #[cfg(feature = "binary_encoding")]
+            Base85Decode(strict) => map!(strings::base85_decode, strict),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(infer_len) => map!(strings::to_decimal, infer_len),
             #[cfg(feature = "extract_jsonpath")]
@@ -508,6 +685,10 @@ impl From<StringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             } => {
                 map_as_slice!(find_many, ascii_case_insensitive, overlapping)
             },
+            #[cfg(feature = "find_many")]
+            ReplaceManyRegex => map_as_slice!(replace_many_regex),
+            #[cfg(all(feature = "find_many", feature = "dtype-struct"))]
+            ExtractFirstMatch => map_as_slice!(extract_first_match),
             #[cfg(feature = "regex")]
             EscapeRegex => map!(escape_regex),
         }
@@ -567,6 +748,23 @@ fn find_many(
         .map(|out| out.into_column())
 }
 
+#[cfg(feature = "find_many")]
+fn replace_many_regex(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].str()?;
+    let patterns = s[1].list()?;
+    let replace_with = s[2].list()?;
+    polars_ops::chunked_array::strings::replace_all_regex(ca, patterns, replace_with)
+        .map(|out| out.into_column())
+}
+
+#[cfg(all(feature = "find_many", feature = "dtype-struct"))]
+fn extract_first_match(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].str()?;
+    let patterns = s[1].list()?;
+    polars_ops::chunked_array::strings::extract_first_match(ca, patterns)
+        .map(|out| out.into_column())
+}
+
 fn uppercase(s: &Column) -> PolarsResult<Column> {
     let ca = s.str()?;
     Ok(ca.to_uppercase().into_column())
@@ -602,6 +800,19 @@ pub(super) fn contains(s: &[Column], literal: bool, strict: bool) -> PolarsResul
         .map(|ok| ok.into_column())
 }
 
+#[cfg(feature = "regex_lite")]
+pub(super) fn contains_with_engine(
+    s: &[Column],
+    engine: RegexEngine,
+    strict: bool,
+) -> PolarsResult<Column> {
+    _check_same_length(s, "contains")?;
+    let ca = s[0].str()?;
+    let pat = s[1].str()?;
+    polars_ops::chunked_array::strings::contains_with_engine(ca, pat, engine, strict)
+        .map(|ok| ok.into_column())
+}
+
 #[cfg(feature = "regex")]
 pub(super) fn find(s: &[Column], literal: bool, strict: bool) -> PolarsResult<Column> {
     _check_same_length(s, "find")?;
@@ -790,6 +1001,19 @@ pub(super) fn split(s: &[Column], inclusive: bool) -> PolarsResult<Column> {
     }
 }
 
+pub(super) fn split_max(s: &[Column], maxsplit: usize) -> PolarsResult<Column> {
+    let ca = s[0].str()?;
+    let by = s[1].str()?;
+    Ok(ca.split_max(by, maxsplit)?.into_column())
+}
+
+#[cfg(feature = "regex")]
+pub(super) fn split_regex(s: &[Column], maxsplit: Option<usize>) -> PolarsResult<Column> {
+    let ca = s[0].str()?;
+    let pat = s[1].str()?;
+    Ok(ca.split_regex(pat, maxsplit)?.into_column())
+}
+
 #[cfg(feature = "dtype-date")]
 fn to_date(s: &Column, options: &StrptimeOptions) -> PolarsResult<Column> {
     let ca = s.str()?;
@@ -1085,12 +1309,84 @@ pub(super) fn normalize(s: &Column, form: UnicodeForm) -> PolarsResult<Column> {
     Ok(ca.str_normalize(form).into_column())
 }
 
+#[cfg(feature = "string_normalize")]
+pub(super) fn casefold(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_casefold().into_column())
+}
+
+#[cfg(feature = "string_normalize")]
+pub(super) fn collation_key(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_collation_key().into_column())
+}
+
+#[cfg(feature = "string_normalize")]
+pub(super) fn to_lowercase_locale(s: &Column, locale: &str) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_to_lowercase_locale(locale)?.into_column())
+}
+
+#[cfg(feature = "string_normalize")]
+pub(super) fn to_uppercase_locale(s: &Column, locale: &str) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_to_uppercase_locale(locale)?.into_column())
+}
+
 #[cfg(feature = "string_reverse")]
 pub(super) fn reverse(s: &Column) -> PolarsResult<Column> {
     let ca = s.str()?;
     Ok(ca.str_reverse().into_column())
 }
 
+#[cfg(feature = "string_similarity")]
+pub(super) fn levenshtein(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "levenshtein")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.str_levenshtein(other).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn jaro_winkler(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "jaro_winkler")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.str_jaro_winkler(other).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn ngram_similarity(s: &[Column], n: usize) -> PolarsResult<Column> {
+    _check_same_length(s, "ngram_similarity")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    ca.str_ngram_similarity(other, n).map(|ok| ok.into_column())
+}
+
+#[cfg(feature = "string_tokenize")]
+pub(super) fn tokenize(s: &Column, tokenizer: &Tokenizer) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    ca.str_tokenize(tokenizer).map(|ok| ok.into_column())
+}
+
+#[cfg(feature = "string_tokenize")]
+pub(super) fn ngrams(s: &Column, n: usize) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    ca.str_ngrams(n).map(|ok| ok.into_column())
+}
+
+#[cfg(feature = "string_tokenize")]
+pub(super) fn shingle(s: &Column, k: usize) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    ca.str_shingle(k).map(|ok| ok.into_column())
+}
+
+#[cfg(feature = "uuid")]
+pub(super) fn to_uuid(s: &Column, strict: bool) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    polars_ops::chunked_array::str_to_uuid(ca, strict).map(|ok| ok.into_column())
+}
+
 #[cfg(feature = "string_to_integer")]
 pub(super) fn to_integer(s: &[Column], strict: bool) -> PolarsResult<Column> {
     let ca = s[0].str()?;
@@ -1162,6 +1458,26 @@ pub(super) fn base64_decode(s: &Column, strict: bool) -> PolarsResult<Column> {
     s.str()?.base64_decode(strict).map(|ca| ca.into_column())
 }
 
+#[cfg(feature = "string_encoding")]
+pub(super) fn base32_encode(s: &Column) -> PolarsResult<Column> {
+    Ok(s.str()?.base32_encode().into_column())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn base32_decode(s: &Column, strict: bool) -> PolarsResult<Column> {
+    s.str()?.base32_decode(strict).map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "string_encoding")]
+pub(super) fn base85_encode(s: &Column) -> PolarsResult<Column> {
+    Ok(s.str()?.base85_encode().into_column())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn base85_decode(s: &Column, strict: bool) -> PolarsResult<Column> {
+    s.str()?.base85_decode(strict).map(|ca| ca.into_column())
+}
+
 #[cfg(feature = "dtype-decimal")]
 pub(super) fn to_decimal(s: &Column, infer_len: usize) -> PolarsResult<Column> {
     let ca = s.str()?;
@@ -16,6 +16,8 @@ pub enum StructFunction {
     JsonEncode,
     WithFields,
     MultipleFields(Arc<[PlSmallStr]>),
+    #[cfg(feature = "dtype-array")]
+    ToArray,
 }
 
 impl StructFunction {
@@ -121,6 +123,20 @@ impl StructFunction {
                 }
             },
             MultipleFields(_) => panic!("should be expanded"),
+            #[cfg(feature = "dtype-array")]
+            ToArray => mapper.try_map_dtype(|dt| match dt {
+                DataType::Struct(fields) => {
+                    let inner = fields.first().map_or(DataType::Null, |fld| fld.dtype().clone());
+                    if let Some(fld) = fields.iter().find(|fld| fld.dtype() != &inner) {
+                        polars_bail!(
+                            InvalidOperation: "`struct.to_array` requires all fields to have the same dtype, \
+                            got field '{}' of type {} and type {}", fld.name(), fld.dtype(), inner
+                        )
+                    }
+                    Ok(DataType::Array(Box::new(inner), fields.len()))
+                },
+                _ => polars_bail!(op = "to_array", got = dt, expected = "Struct"),
+            }),
         }
     }
 
@@ -141,6 +157,8 @@ impl StructFunction {
             S::MultipleFields(_) => {
                 FunctionOptions::elementwise().with_flags(|f| f | FunctionFlags::ALLOW_RENAME)
             },
+            #[cfg(feature = "dtype-array")]
+            S::ToArray => FunctionOptions::elementwise(),
         }
     }
 }
@@ -158,6 +176,8 @@ impl Display for StructFunction {
             JsonEncode => write!(f, "struct.to_json"),
             WithFields => write!(f, "with_fields"),
             MultipleFields(_) => write!(f, "multiple_fields"),
+            #[cfg(feature = "dtype-array")]
+            ToArray => write!(f, "struct.to_array"),
         }
     }
 }
@@ -175,6 +195,8 @@ impl From<StructFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             JsonEncode => map!(to_json),
             WithFields => map_as_slice!(with_fields),
             MultipleFields(_) => unimplemented!(),
+            #[cfg(feature = "dtype-array")]
+            ToArray => map!(to_array),
         }
     }
 }
@@ -269,3 +291,35 @@ pub(super) fn with_fields(args: &[Column]) -> PolarsResult<Column> {
     out.zip_outer_validity(ca);
     Ok(out.into_column())
 }
+
+#[cfg(feature = "dtype-array")]
+pub(super) fn to_array(s: &Column) -> PolarsResult<Column> {
+    let ca = s.struct_()?.rechunk();
+    let fields = ca
+        .fields_as_series()
+        .into_iter()
+        .map(Column::from)
+        .collect::<Vec<_>>();
+    polars_ensure!(
+        !fields.is_empty(),
+        InvalidOperation: "`struct.to_array` requires at least one field"
+    );
+    let inner = fields[0].dtype().clone();
+    polars_ensure!(
+        fields.iter().all(|fld| fld.dtype() == &inner),
+        InvalidOperation: "`struct.to_array` requires all fields to have the same dtype"
+    );
+    let dtype = DataType::Array(Box::new(inner), fields.len());
+    let out = polars_ops::series::concat_arr::concat_arr(&fields, &dtype)?;
+    if !ca.has_nulls() {
+        return Ok(out.with_name(ca.name().clone()));
+    }
+
+    // A null struct becomes a null array.
+    let arr = out.array()?.rechunk().downcast_as_array().clone();
+    let arr = arr.with_validity(ca.rechunk_validity()).boxed();
+    // SAFETY: the dtype of the array is unchanged.
+    let out =
+        unsafe { Series::from_chunks_and_dtype_unchecked(ca.name().clone(), vec![arr], &dtype) };
+    Ok(out.into_column())
+}
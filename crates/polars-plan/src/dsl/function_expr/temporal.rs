@@ -46,6 +46,10 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             OffsetBy => {
                 map_as_slice!(datetime::offset_by)
             },
+            #[cfg(feature = "temporal_gaps")]
+            MissingPeriods(every) => map!(datetime::missing_periods, &every),
+            #[cfg(feature = "temporal_gaps")]
+            Gaps(interval) => map!(datetime::gaps, &interval),
             #[cfg(feature = "month_start")]
             MonthStart => map!(datetime::month_start),
             #[cfg(feature = "month_end")]
@@ -0,0 +1,20 @@
+use polars_core::prelude::{
+    Column, IntoColumn, PlSmallStr, PolarsResult, polars_ensure, polars_err,
+};
+use polars_ops::chunked_array::{UuidVersion, uuid_generate};
+
+pub fn uuid(args: &[Column], version: UuidVersion) -> PolarsResult<Column> {
+    let n = &args[0];
+
+    polars_ensure!(
+        n.dtype().is_integer(),
+        SchemaMismatch: "expected expression of dtype 'integer', got '{}'", n.dtype()
+    );
+
+    let first_value = n.get(0)?;
+    let n = first_value.extract::<usize>().ok_or_else(
+        || polars_err!(ComputeError: "could not parse value '{}' as a size.", first_value),
+    )?;
+
+    Ok(uuid_generate(PlSmallStr::from_static("uuid"), n, version).into_column())
+}
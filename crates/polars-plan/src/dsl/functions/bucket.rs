@@ -0,0 +1,88 @@
+use super::*;
+
+/// Key of the file-level metadata in which `sink_parquet_bucketed` records the bucketing scheme.
+pub const BUCKETING_METADATA_KEY: &str = "polars.bucketing";
+
+/// Name of the hash that assigns rows to buckets, recorded in the bucketing metadata.
+pub const BUCKET_HASH: &str = "murmur3_x86_32";
+
+/// Version of the way [`bucket_id`] combines the hashes of the keys, recorded in the bucketing
+/// metadata. Datasets written with another version cannot be pruned by bucket.
+pub const BUCKET_HASH_VERSION: u32 = 1;
+
+/// The bucket, in `0..num_buckets`, of every row by a stable hash of the values of `by`.
+///
+/// Rows with equal values of `by` are always in the same bucket, so datasets that are bucketed
+/// by the same keys into the same number of buckets can be joined bucket by bucket. The hash is
+/// the 32-bit MurmurHash3 that Hive and Iceberg use, so the buckets do not depend on the Polars
+/// version or platform.
+pub fn bucket_id<E: AsRef<[Expr]>>(by: E, num_buckets: u32) -> PolarsResult<Expr> {
+    let by = by.as_ref().to_vec();
+    polars_ensure!(!by.is_empty(), InvalidOperation: "cannot bucket by zero keys");
+    polars_ensure!(num_buckets > 0, InvalidOperation: "`num_buckets` must be positive");
+
+    Ok(Expr::n_ary(FunctionExpr::BucketId(num_buckets), by).alias("bucket"))
+}
+
+/// The value of the [`BUCKETING_METADATA_KEY`] metadata of a dataset bucketed by `by` into
+/// `num_buckets` buckets.
+#[cfg(feature = "serde_json")]
+pub fn bucketing_metadata(by: &[PlSmallStr], num_buckets: u32) -> String {
+    serde_json::json!({
+        "by": by,
+        "num_buckets": num_buckets,
+        "hash": BUCKET_HASH,
+        "hash_version": BUCKET_HASH_VERSION,
+    })
+    .to_string()
+}
+
+/// The bucket of a single row with the values `keys`, as computed by [`bucket_id`].
+pub(crate) fn bucket_of_row(keys: &[Column], num_buckets: u32) -> PolarsResult<u32> {
+    let mut keys = keys.to_vec();
+    let bucket = crate::dsl::function_expr::bucket::bucket_id(&mut keys, num_buckets)?;
+    Ok(bucket.u32()?.get(0).unwrap())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bucket_of_row_is_stable() -> PolarsResult<()> {
+        let key = |name: &str, value: AnyValue<'static>| {
+            Scalar::new(value.dtype(), value).into_column(name.into())
+        };
+
+        // Single keys are bucketed as Iceberg does.
+        assert_eq!(
+            bucket_of_row(&[key("a", AnyValue::Int64(34))], u32::MAX)?,
+            2017239379
+        );
+        assert_eq!(
+            bucket_of_row(
+                &[key("a", AnyValue::StringOwned("iceberg".into()))],
+                u32::MAX
+            )?,
+            1210000089
+        );
+        // All integer types hash as 8-byte integers.
+        assert_eq!(
+            bucket_of_row(&[key("a", AnyValue::Int8(34))], 16)?,
+            bucket_of_row(&[key("a", AnyValue::Int64(34))], 16)?
+        );
+        // -0.0 and 0.0 are in the same bucket.
+        assert_eq!(
+            bucket_of_row(&[key("a", AnyValue::Float64(-0.0))], 16)?,
+            bucket_of_row(&[key("a", AnyValue::Float32(0.0))], 16)?
+        );
+
+        let keys = [
+            key("a", AnyValue::Int64(34)),
+            key("b", AnyValue::StringOwned("iceberg".into())),
+        ];
+        let expected = 2017239379u32.wrapping_mul(31).wrapping_add(1210000089) & i32::MAX as u32;
+        assert_eq!(bucket_of_row(&keys, 1000)?, expected % 1000);
+        Ok(())
+    }
+}
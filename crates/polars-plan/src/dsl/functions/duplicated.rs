@@ -0,0 +1,25 @@
+use super::*;
+
+/// Mark the rows of which the values of `exprs` occur more than once.
+///
+/// With `keep` set to [`UniqueKeepStrategy::First`] the first occurrence of every duplicated row
+/// is not marked, with [`UniqueKeepStrategy::Last`] the last one, and with
+/// [`UniqueKeepStrategy::None`] all occurrences are marked.
+pub fn is_duplicated_by<E: AsRef<[Expr]>>(
+    exprs: E,
+    keep: UniqueKeepStrategy,
+) -> PolarsResult<Expr> {
+    let exprs = exprs.as_ref().to_vec();
+    polars_ensure!(!exprs.is_empty(), ComputeError: "'is_duplicated_by' needs at least one expression");
+    Ok(Expr::n_ary(
+        FunctionExpr::Boolean(BooleanFunction::IsDuplicatedBy { keep }),
+        exprs,
+    ))
+}
+
+/// Count for every row how many rows have the same values of `exprs`.
+pub fn occurrence_count_by<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
+    let exprs = exprs.as_ref().to_vec();
+    polars_ensure!(!exprs.is_empty(), ComputeError: "'occurrence_count_by' needs at least one expression");
+    Ok(Expr::n_ary(FunctionExpr::OccurrenceCountBy, exprs))
+}
@@ -2,6 +2,8 @@
 //!
 //! Functions on expressions that might be useful.
 mod arity;
+#[cfg(all(feature = "row_hash", feature = "dtype-struct"))]
+mod bucket;
 #[cfg(feature = "business")]
 mod business;
 #[cfg(feature = "dtype-struct")]
@@ -9,18 +11,25 @@ mod coerce;
 mod concat;
 #[cfg(feature = "cov")]
 mod correlation;
+#[cfg(feature = "is_unique")]
+mod duplicated;
 pub(crate) mod horizontal;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 mod index;
 #[cfg(feature = "range")]
 mod range;
 mod repeat;
+mod row_encode;
 mod selectors;
 mod syntactic_sugar;
 #[cfg(feature = "temporal")]
 mod temporal;
+#[cfg(feature = "uuid")]
+mod uuid;
 
 pub use arity::*;
+#[cfg(all(feature = "row_hash", feature = "dtype-struct"))]
+pub use bucket::*;
 #[cfg(all(feature = "business", feature = "dtype-date"))]
 pub use business::*;
 #[cfg(feature = "dtype-struct")]
@@ -28,6 +37,8 @@ pub use coerce::*;
 pub use concat::*;
 #[cfg(feature = "cov")]
 pub use correlation::*;
+#[cfg(feature = "is_unique")]
+pub use duplicated::*;
 pub use horizontal::*;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 pub use index::*;
@@ -40,10 +51,13 @@ pub use range::time_range; // This shouldn't be necessary, but clippy complains
 #[cfg(feature = "range")]
 pub use range::*;
 pub use repeat::*;
+pub use row_encode::*;
 pub use selectors::*;
 pub use syntactic_sugar::*;
 #[cfg(feature = "temporal")]
 pub use temporal::*;
+#[cfg(feature = "uuid")]
+pub use uuid::*;
 
 #[cfg(feature = "arg_where")]
 use crate::dsl::function_expr::FunctionExpr;
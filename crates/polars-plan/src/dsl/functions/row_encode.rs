@@ -0,0 +1,26 @@
+use super::*;
+
+/// Encode the rows of `exprs` into a single binary column of which the byte-wise order is the
+/// order of the rows when sorted by `exprs`.
+///
+/// This is the encoding the engine uses for multi-key sorts. `descending` and `nulls_last`
+/// either have a single value for all expressions or a value per expression.
+pub fn encode_rows<E: AsRef<[Expr]>>(
+    exprs: E,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+) -> PolarsResult<Expr> {
+    let exprs = exprs.as_ref().to_vec();
+    polars_ensure!(!exprs.is_empty(), ComputeError: "'encode_rows' needs at least one expression");
+    polars_ensure!(
+        !descending.is_empty() && !nulls_last.is_empty(),
+        ComputeError: "'descending' and 'nulls_last' of 'encode_rows' cannot be empty"
+    );
+    Ok(Expr::n_ary(
+        FunctionExpr::RowEncode {
+            descending,
+            nulls_last,
+        },
+        exprs,
+    ))
+}
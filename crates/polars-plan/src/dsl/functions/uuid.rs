@@ -0,0 +1,17 @@
+use super::*;
+
+/// Generate a column of `n` random (version 4) UUIDs as lowercase hyphenated strings.
+///
+/// Pass `len()` as `n` to generate a UUID for every row, and use `str().to_uuid()` for their
+/// 16-byte binary representation.
+pub fn uuid_v4(n: Expr) -> Expr {
+    Expr::n_ary(FunctionExpr::Uuid(UuidVersion::V4), vec![n])
+}
+
+/// Generate a column of `n` time-ordered (version 7) UUIDs as lowercase hyphenated strings.
+///
+/// Pass `len()` as `n` to generate a UUID for every row, and use `str().to_uuid()` for their
+/// 16-byte binary representation.
+pub fn uuid_v7(n: Expr) -> Expr {
+    Expr::n_ary(FunctionExpr::Uuid(UuidVersion::V7), vec![n])
+}
@@ -0,0 +1,48 @@
+use super::*;
+
+/// Specialized expressions for [`Series`] of [`DataType::String`] that hold IP addresses.
+pub struct IpNameSpace(pub(crate) Expr);
+
+impl IpNameSpace {
+    /// Parse IPv4 and IPv6 addresses to a 16-byte binary representation in
+    /// network byte order, with IPv4 addresses mapped into `::ffff:0:0/96`.
+    ///
+    /// Comparing the binary values compares the addresses. If `strict` is
+    /// `false` invalid addresses become null.
+    pub fn parse(self, strict: bool) -> Expr {
+        self.0.map_unary(IpFunction::Parse(strict))
+    }
+
+    /// Check if the values are IPv4 addresses.
+    pub fn is_ipv4(self) -> Expr {
+        self.0.map_unary(IpFunction::IsIpv4)
+    }
+
+    /// Check if the values are IPv6 addresses.
+    pub fn is_ipv6(self) -> Expr {
+        self.0.map_unary(IpFunction::IsIpv6)
+    }
+
+    /// Convert IPv4 addresses to their integer value. IPv6 addresses that are
+    /// not IPv4-mapped become null.
+    pub fn to_int(self) -> Expr {
+        self.0.map_unary(IpFunction::ToInt)
+    }
+
+    /// Check if the addresses lie in a subnet given in CIDR notation,
+    /// e.g. `10.0.0.0/8`.
+    pub fn is_in_subnet(self, cidr: &str) -> Expr {
+        self.0.map_unary(IpFunction::IsInSubnet(cidr.into()))
+    }
+
+    /// Get the first and last address of subnets in CIDR notation as a struct
+    /// with the fields `start` and `end`, in the representation of
+    /// [`IpNameSpace::parse`].
+    ///
+    /// Joining with `join_where` on `start <= ip && ip <= end` joins addresses to the
+    /// subnets that contain them.
+    #[cfg(feature = "dtype-struct")]
+    pub fn subnet_range(self, strict: bool) -> Expr {
+        self.0.map_unary(IpFunction::SubnetRange(strict))
+    }
+}
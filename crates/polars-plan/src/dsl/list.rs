@@ -248,6 +248,28 @@ impl ListNameSpace {
             .map_unary(FunctionExpr::ListExpr(ListFunction::ToStruct(args)))
     }
 
+    #[cfg(feature = "list_to_struct")]
+    #[allow(clippy::wrong_self_convention)]
+    /// Convert this `List` to a `Series` of type `Struct`, where the field of every value is the
+    /// key at the same position in the lists of `keys`. `keys` can also be a single list that is
+    /// used for every row.
+    ///
+    /// # Schema
+    ///
+    /// Without `fields` the fields are the keys in order of their first occurrence, so the schema
+    /// is unknown until the data is seen. Given `fields`, the output has exactly these fields and
+    /// all other keys are ignored.
+    pub fn to_struct_with_keys<E: Into<Expr>>(
+        self,
+        keys: E,
+        fields: Option<Arc<[PlSmallStr]>>,
+    ) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::ListExpr(ListFunction::ToStructWithKeys(fields)),
+            keys.into(),
+        )
+    }
+
     #[cfg(feature = "is_in")]
     /// Check if the list array contain an element
     pub fn contains<E: Into<Expr>>(self, other: E) -> Expr {
@@ -264,14 +286,26 @@ impl ListNameSpace {
         )
     }
 
+    /// Run a set operation between both list arrays with the given options, e.g. with
+    /// multiset semantics or nulls that don't match each other.
     #[cfg(feature = "list_sets")]
-    fn set_operation(self, other: Expr, set_operation: SetOperation) -> Expr {
+    pub fn set_operation_with_options<E: Into<Expr>>(
+        self,
+        other: E,
+        set_operation: SetOperation,
+        options: SetOperationOptions,
+    ) -> Expr {
         self.0.map_binary(
-            FunctionExpr::ListExpr(ListFunction::SetOperation(set_operation)),
-            other,
+            FunctionExpr::ListExpr(ListFunction::SetOperation(set_operation, options)),
+            other.into(),
         )
     }
 
+    #[cfg(feature = "list_sets")]
+    fn set_operation(self, other: Expr, set_operation: SetOperation) -> Expr {
+        self.set_operation_with_options(other, set_operation, SetOperationOptions::default())
+    }
+
     /// Return the SET UNION between both list arrays.
     #[cfg(feature = "list_sets")]
     pub fn union<E: Into<Expr>>(self, other: E) -> Expr {
@@ -8,6 +8,9 @@ pub use cat::*;
 #[cfg(feature = "rolling_window_by")]
 pub(crate) use polars_time::prelude::*;
 
+mod agg_udf;
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
 mod arithmetic;
 mod arity;
 #[cfg(feature = "dtype-array")]
@@ -25,6 +28,8 @@ mod format;
 mod from;
 pub mod function_expr;
 pub mod functions;
+#[cfg(feature = "ip")]
+pub mod ip;
 mod list;
 #[cfg(feature = "meta")]
 mod meta;
@@ -47,6 +52,7 @@ use std::fmt::Debug;
 use std::sync::Arc;
 
 mod plan;
+pub use agg_udf::*;
 pub use arity::*;
 #[cfg(feature = "dtype-array")]
 pub use array::*;
@@ -241,6 +247,15 @@ impl Expr {
         AggExpr::AggGroups(Arc::new(self)).into()
     }
 
+    /// Aggregate with a user-defined aggregation.
+    pub fn agg_udf(self, function: Arc<dyn DynAggregationUdf>) -> Self {
+        AggExpr::Udf {
+            input: Arc::new(self),
+            function: SpecialEq::new(function),
+        }
+        .into()
+    }
+
     /// Alias for `explode`.
     pub fn flatten(self) -> Self {
         self.explode()
@@ -422,6 +437,16 @@ impl Expr {
         }
     }
 
+    /// Replace the values at `indices` by `values`, the inverse of [`gather`](Self::gather).
+    ///
+    /// `indices` are integers, where negative integers count from the end, or a boolean mask.
+    /// `values` is either a single value or has a value for every index. Given a mask, it may
+    /// also have the length of this expression, in which case the values at the masked
+    /// positions are used. In a group-by context the indices are relative to the group.
+    pub fn scatter<I: Into<Expr>, V: Into<Expr>>(self, indices: I, values: V) -> Self {
+        self.map_ternary(FunctionExpr::Scatter, indices.into(), values.into())
+    }
+
     /// Sort with given options.
     ///
     /// # Example
@@ -648,6 +673,16 @@ impl Expr {
         self.map_binary(FunctionExpr::Shift, n)
     }
 
+    /// Shift the values by a period of time according to the Date or Datetime column `by`:
+    /// every row gets the value of the row whose `by` value is exactly `period` earlier, or
+    /// null if there is no such row.
+    ///
+    /// The values of `by` must be unique, use [`Expr::over`] to shift within groups.
+    #[cfg(feature = "shift_by_time")]
+    pub fn shift_by_time(self, by: Expr, period: polars_time::Duration) -> Self {
+        self.map_binary(FunctionExpr::ShiftByTime(period), by)
+    }
+
     /// Shift the values in the array by some period and fill the resulting empty values.
     pub fn shift_and_fill<E: Into<Expr>, IE: Into<Expr>>(self, n: E, fill_value: IE) -> Self {
         self.map_ternary(FunctionExpr::ShiftAndFill, n.into(), fill_value.into())
@@ -685,28 +720,32 @@ impl Expr {
 
     /// Get the product aggregation of an expression.
     pub fn product(self) -> Self {
-        let options = FunctionOptions::aggregation().with_fmt_str("product");
-        self.function_with_options(
-            move |c: Column| {
-                Some(
-                    c.product()
-                        .map(|sc| sc.into_series(c.name().clone()).into_column()),
-                )
-                .transpose()
-            },
-            GetOutput::map_dtype(|dt| {
-                use DataType as T;
-                Ok(match dt {
-                    T::Float32 => T::Float32,
-                    T::Float64 => T::Float64,
-                    T::UInt64 => T::UInt64,
-                    #[cfg(feature = "dtype-i128")]
-                    T::Int128 => T::Int128,
-                    _ => T::Int64,
-                })
-            }),
-            options,
-        )
+        AggExpr::Product(Arc::new(self)).into()
+    }
+
+    /// Get the geometric mean of an expression, computed from the mean of the logarithms so
+    /// that it doesn't overflow.
+    pub fn geo_mean(self) -> Self {
+        AggExpr::GeoMean(Arc::new(self)).into()
+    }
+
+    /// Get the harmonic mean of an expression.
+    pub fn harmonic_mean(self) -> Self {
+        AggExpr::HarmonicMean(Arc::new(self)).into()
+    }
+
+    /// Get the time-weighted average of an expression, where every value is weighted by the
+    /// time until the next value in the sorted `time` column.
+    pub fn twap(self, time: Expr) -> Self {
+        self.map_binary(FunctionExpr::Twap, time)
+    }
+
+    /// Get the time-weighted average of an expression over the window from `start` to `end`.
+    ///
+    /// The last value at or before `start` is carried into the window and the last value
+    /// before `end` holds until `end`. A null bound leaves the window open on that side.
+    pub fn twap_in_window(self, time: Expr, start: Expr, end: Expr) -> Self {
+        self.map_n_ary(FunctionExpr::Twap, [time, start, end])
     }
 
     /// Round underlying floating point array to given decimal numbers.
@@ -901,6 +940,14 @@ impl Expr {
         self.map_unary(FunctionExpr::FillNullWithStrategy(strategy))
     }
 
+    /// Fill null values with the previous (`forward`) or next non-null value,
+    /// but only if that value lies at most `limit` away in the sorted `by`
+    /// column, e.g. don't fill gaps longer than 5 minutes.
+    #[cfg(feature = "fill_null_by")]
+    pub fn fill_null_by(self, by: Expr, forward: bool, limit: Duration) -> Self {
+        self.map_binary(FunctionExpr::FillNullBy { forward, limit }, by)
+    }
+
     /// Replace the floating point `NaN` values by a value.
     pub fn fill_nan<E: Into<Expr>>(self, fill_value: E) -> Self {
         // we take the not branch so that self is truthy value of `when -> then -> otherwise`
@@ -1077,7 +1124,21 @@ impl Expr {
     #[cfg(feature = "mode")]
     /// Compute the mode(s) of this column. This is the most occurring value.
     pub fn mode(self) -> Expr {
-        self.map_unary(FunctionExpr::Mode)
+        self.map_unary(FunctionExpr::Mode(ModeTiePolicy::All))
+    }
+
+    #[cfg(feature = "mode")]
+    /// Compute the mode of this column, resolving ties between equally occurring values with
+    /// `ties`. Every policy except [`ModeTiePolicy::All`] returns a single value.
+    pub fn mode_with_ties(self, ties: ModeTiePolicy) -> Expr {
+        self.map_unary(FunctionExpr::Mode(ties))
+    }
+
+    #[cfg(all(feature = "mode", feature = "dtype-struct"))]
+    /// Get the `k` most occurring values with their counts, as a list of structs with the fields
+    /// `<name>` and `count`.
+    pub fn top_k_frequent(self, k: usize) -> Expr {
+        self.map_unary(FunctionExpr::TopKFrequent(k))
     }
 
     /// Exclude a column from a wildcard/regex selection.
@@ -1282,6 +1343,22 @@ impl Expr {
         self.finish_rolling(options, RollingFunction::Kurtosis)
     }
 
+    /// Apply the rolling aggregator registered as `name` with
+    /// [`register_rolling_aggregator`](polars_time::chunkedarray::register_rolling_aggregator).
+    ///
+    /// The result is always `Float64`.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_map_registered(
+        self,
+        name: impl Into<PlSmallStr>,
+        options: RollingOptionsFixedWindow,
+    ) -> Expr {
+        self.map_unary(FunctionExpr::RollingExpr(RollingFunction::Registered(
+            name.into(),
+            options,
+        )))
+    }
+
     #[cfg(feature = "rolling_window")]
     /// Apply a custom function over a rolling/ moving window of the array.
     /// This has quite some dynamic dispatch, so prefer rolling_min, max, mean, sum over this.
@@ -1621,6 +1698,18 @@ impl Expr {
         self.map_unary(FunctionExpr::SetSortedFlag(sorted))
     }
 
+    /// Compute this expression once per frame and share the result among all its occurrences
+    /// in the same context, e.g. a `select`, `with_columns`, `filter`, `sort` or the
+    /// aggregations of a `group_by`.
+    ///
+    /// Unlike common subexpression elimination this also applies in the branches of
+    /// `when/then/otherwise` and to elementwise expressions in window functions and
+    /// aggregations, where the result is computed before grouping. A cached expression that
+    /// isn't elementwise is only shared where it is evaluated on the whole frame.
+    pub fn cache(self) -> Expr {
+        self.map_unary(FunctionExpr::Cache)
+    }
+
     #[cfg(feature = "row_hash")]
     /// Compute the hash of every element.
     pub fn hash(self, k0: u64, k1: u64, k2: u64, k3: u64) -> Expr {
@@ -1640,6 +1729,16 @@ impl Expr {
         self.map_unary(FunctionExpr::Reinterpret(signed))
     }
 
+    /// Write the bytes of every numeric value, or of every fixed-size array of numeric
+    /// values, to a binary value. This is the inverse of
+    /// [`BinaryNameSpace::from_buffer`](binary::BinaryNameSpace::from_buffer).
+    #[cfg(feature = "binary_encoding")]
+    pub fn to_buffer(self, is_little_endian: bool) -> Expr {
+        self.map_unary(FunctionExpr::BinaryExpr(BinaryFunction::ToBuffer(
+            is_little_endian,
+        )))
+    }
+
     pub fn extend_constant(self, value: Expr, n: Expr) -> Expr {
         self.map_ternary(FunctionExpr::ExtendConstant, value, n)
     }
@@ -1661,6 +1760,12 @@ impl Expr {
         dt::DateLikeNameSpace(self)
     }
 
+    #[cfg(feature = "ip")]
+    /// Get the [`ip::IpNameSpace`]
+    pub fn ip(self) -> ip::IpNameSpace {
+        ip::IpNameSpace(self)
+    }
+
     /// Get the [`list::ListNameSpace`]
     pub fn list(self) -> list::ListNameSpace {
         list::ListNameSpace(self)
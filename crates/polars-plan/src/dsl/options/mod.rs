@@ -34,6 +34,21 @@ use strum_macros::IntoStaticStr;
 use super::ExprIR;
 use crate::dsl::Selector;
 
+/// How floating point `NaN` values participate in operations.
+#[derive(Copy, Clone, PartialEq, Debug, Eq, Hash, Default, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[strum(serialize_all = "snake_case")]
+pub enum NanPolicy {
+    /// `NaN` is skipped by aggregations, as if it were missing: a group of only `NaN` aggregates
+    /// to null.
+    Ignore,
+    /// `NaN` is a value: any `NaN` in the input makes an aggregation `NaN`.
+    #[default]
+    Propagate,
+    /// `NaN` is turned into null before the operation.
+    AsNull,
+}
+
 #[derive(Copy, Clone, PartialEq, Debug, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RollingCovOptions {
@@ -238,6 +253,7 @@ pub struct UnionOptions {
     pub from_partitioned_ds: bool,
     pub flattened_by_opt: bool,
     pub rechunk: bool,
+    pub chunk_size: Option<NonZeroUsize>,
     pub maintain_order: bool,
 }
 
@@ -256,6 +272,10 @@ pub struct GroupbyOptions {
     pub rolling: Option<RollingGroupOptions>,
     /// Take only a slice of the result
     pub slice: Option<(i64, usize)>,
+    /// Equal keys are contiguous in the input, so the groups are runs of equal keys. This is
+    /// set by the optimizer.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub keys_sorted: bool,
 }
 
 impl GroupbyOptions {
@@ -350,7 +370,12 @@ impl FileType {
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct UnionArgs {
     pub parallel: bool,
+    /// Rechunk the result to a single chunk.
     pub rechunk: bool,
+    /// If `rechunk` is not set, split and merge the chunks of the result so that they have
+    /// this number of rows. Only runs of small chunks are copied, so this is cheap when
+    /// concatenating many small frames. If neither is set the chunks are kept as they are.
+    pub chunk_size: Option<NonZeroUsize>,
     pub to_supertypes: bool,
     pub diagonal: bool,
     // If it is a union from a scan over multiple files.
@@ -363,6 +388,7 @@ impl Default for UnionArgs {
         Self {
             parallel: true,
             rechunk: false,
+            chunk_size: None,
             to_supertypes: false,
             diagonal: false,
             from_partitioned_ds: false,
@@ -380,6 +406,7 @@ impl From<UnionArgs> for UnionOptions {
             from_partitioned_ds: args.from_partitioned_ds,
             flattened_by_opt: false,
             rechunk: args.rechunk,
+            chunk_size: args.chunk_size,
             maintain_order: args.maintain_order,
         }
     }
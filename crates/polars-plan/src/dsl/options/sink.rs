@@ -4,6 +4,7 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use polars_core::error::PolarsResult;
+use polars_core::frame::DataFrame;
 use polars_core::prelude::DataType;
 use polars_core::scalar::Scalar;
 use polars_io::cloud::CloudOptions;
@@ -28,6 +29,12 @@ pub struct SinkOptions {
 
     /// Recursively create all the directories in the path.
     pub mkdir: bool,
+
+    /// The number of rows of every record batch or row group that is written, except for the
+    /// last one. This overrides the size configured for the format and is ignored by formats
+    /// without batches, such as CSV and NDJSON.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub batch_size: Option<usize>,
 }
 
 impl Default for SinkOptions {
@@ -36,6 +43,7 @@ impl Default for SinkOptions {
             sync_on_close: Default::default(),
             maintain_order: true,
             mkdir: false,
+            batch_size: None,
         }
     }
 }
@@ -49,6 +57,14 @@ pub enum SinkTarget {
 }
 
 impl SinkTarget {
+    /// Sink into an abstract writer, e.g. a socket wrapped in
+    /// [`AsyncWriteAdapter`](polars_io::utils::file::AsyncWriteAdapter).
+    pub fn from_writeable(writer: Box<dyn DynWriteable>) -> Self {
+        Self::Dyn(SpecialEq::new(Arc::new(std::sync::Mutex::new(Some(
+            writer,
+        )))))
+    }
+
     pub fn open_into_writeable(
         &self,
         sink_options: &SinkOptions,
@@ -154,12 +170,51 @@ pub struct FileSinkType {
     pub cloud_options: Option<polars_io::cloud::CloudOptions>,
 }
 
+/// A function that is called with the batches of the result of a query. It returns whether the
+/// query should stop, e.g. because the consumer went away.
+pub type SinkCallback = SpecialEq<Arc<dyn Fn(DataFrame) -> PolarsResult<bool> + Send + Sync>>;
+
+/// Sink the result of a query into a function, batch by batch, while the query runs.
+///
+/// The function is called on a blocking thread, so it may block to apply back-pressure to the
+/// query, e.g. by sending into a bounded channel.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallbackSinkType {
+    pub function: SinkCallback,
+    /// Only `maintain_order` and `batch_size` apply. Without a `batch_size`, the batches are
+    /// passed as they are produced by the query.
+    pub sink_options: SinkOptions,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CallbackSinkType {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::Error;
+        Err(S::Error::custom("cannot serialize a callback sink"))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CallbackSinkType {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+        Err(D::Error::custom("cannot deserialize a callback sink"))
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 pub enum SinkTypeIR {
     Memory,
     File(FileSinkType),
     Partition(PartitionSinkTypeIR),
+    Callback(CallbackSinkType),
 }
 
 #[cfg_attr(feature = "python", pyo3::pyclass)]
@@ -327,6 +382,7 @@ pub enum SinkType {
     Memory,
     File(FileSinkType),
     Partition(PartitionSinkType),
+    Callback(CallbackSinkType),
 }
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -370,6 +426,11 @@ impl SinkTypeIR {
                 f.variant.traverse_and_hash(expr_arena, state);
                 f.cloud_options.hash(state);
             },
+            Self::Callback(f) => {
+                (Arc::as_ptr(&f.function) as *const () as usize).hash(state);
+                f.maintain_order.hash(state);
+                f.chunk_size.hash(state);
+            },
         }
     }
 }
@@ -324,6 +324,31 @@ impl ScanSourceRef<'_> {
         self.to_memslice_possibly_async(false, None, 0)
     }
 
+    /// Read an IPC file that was written with an [`IpcEncryption`] and decrypt it into memory.
+    ///
+    /// [`IpcEncryption`]: polars_io::ipc::IpcEncryption
+    #[cfg(feature = "ipc_encryption")]
+    pub fn to_decrypted_ipc_memslice(
+        &self,
+        cloud_options: Option<&CloudOptions>,
+        encryption: &polars_io::ipc::IpcEncryption,
+    ) -> PolarsResult<MemSlice> {
+        let run_async = self.run_async();
+        #[cfg(feature = "cloud")]
+        if let (true, ScanSourceRef::Path(path)) = (run_async, self) {
+            polars_io::file_cache::init_entries_from_uri_list(
+                &[Arc::from(path.to_str().unwrap())],
+                cloud_options,
+            )?;
+        }
+        #[cfg(not(feature = "cloud"))]
+        let _ = cloud_options;
+
+        let memslice = self.to_memslice_async_check_latest(run_async)?;
+        let bytes = polars_io::ipc::decrypt(memslice.as_ref(), encryption)?;
+        Ok(MemSlice::from_vec(bytes))
+    }
+
     #[allow(clippy::wrong_self_convention)]
     #[cfg(feature = "cloud")]
     fn to_memslice_async<F: Fn(Arc<FileCacheEntry>) -> PolarsResult<std::fs::File>>(
@@ -52,6 +52,57 @@ impl Expr {
         AggExpr::Mean(Arc::new(self)).into()
     }
 
+    /// Apply a [`NanPolicy`] to the floating point `NaN` values of this
+    /// expression.
+    ///
+    /// With [`NanPolicy::AsNull`] `NaN` becomes null, so that it is skipped by
+    /// aggregations, sorted together with the nulls and grouped under the null
+    /// key. With [`NanPolicy::Ignore`] `NaN` is dropped, so that it is skipped
+    /// without being counted as null, e.g. by `len` or `null_count`. The
+    /// expression must be a float for this policy. [`NanPolicy::Propagate`]
+    /// leaves the values as is.
+    pub fn with_nan_policy(self, policy: NanPolicy) -> Self {
+        match policy {
+            NanPolicy::AsNull => self.fill_nan(Expr::Literal(LiteralValue::untyped_null())),
+            NanPolicy::Ignore => self
+                .clone()
+                .filter(self.clone().is_not_nan().or(self.is_null())),
+            NanPolicy::Propagate => self,
+        }
+    }
+
+    /// Reduce groups to the minimal value, treating `NaN` according to `policy`.
+    pub fn min_with_nan_policy(self, policy: NanPolicy) -> Self {
+        match policy {
+            NanPolicy::Propagate => self.nan_min(),
+            NanPolicy::Ignore | NanPolicy::AsNull => self.with_nan_policy(policy).min(),
+        }
+    }
+
+    /// Reduce groups to the maximum value, treating `NaN` according to `policy`.
+    pub fn max_with_nan_policy(self, policy: NanPolicy) -> Self {
+        match policy {
+            NanPolicy::Propagate => self.nan_max(),
+            NanPolicy::Ignore | NanPolicy::AsNull => self.with_nan_policy(policy).max(),
+        }
+    }
+
+    /// Reduce groups to the mean value, treating `NaN` according to `policy`.
+    pub fn mean_with_nan_policy(self, policy: NanPolicy) -> Self {
+        match policy {
+            NanPolicy::Propagate => self.mean(),
+            NanPolicy::Ignore | NanPolicy::AsNull => self.with_nan_policy(policy).mean(),
+        }
+    }
+
+    /// Reduce groups to the sum of the values, treating `NaN` according to `policy`.
+    pub fn sum_with_nan_policy(self, policy: NanPolicy) -> Self {
+        match policy {
+            NanPolicy::Propagate => self.sum(),
+            NanPolicy::Ignore | NanPolicy::AsNull => self.with_nan_policy(policy).sum(),
+        }
+    }
+
     /// Reduce groups to the median value.
     pub fn median(self) -> Self {
         AggExpr::Median(Arc::new(self)).into()
@@ -28,6 +28,16 @@ impl StringNameSpace {
         )
     }
 
+    /// Check if this column of strings contains a Regex, evaluated with the given `engine`.
+    ///
+    /// [`RegexEngine::Lite`] compiles patterns much faster, which pays off when `pat` is a
+    /// column with many distinct patterns. See [`contains`](Self::contains) for `strict`.
+    #[cfg(feature = "regex_lite")]
+    pub fn contains_with_engine(self, pat: Expr, engine: RegexEngine, strict: bool) -> Expr {
+        self.0
+            .map_binary(StringFunction::ContainsWithEngine { engine, strict }, pat)
+    }
+
     /// Uses aho-corasick to find many patterns.
     ///
     /// # Arguments
@@ -114,6 +124,35 @@ impl StringNameSpace {
         )
     }
 
+    /// Replace the matches of many regular expressions in a single pass.
+    ///
+    /// All patterns are compiled into one automaton. At each position the first pattern that
+    /// matches wins, and `$1`-style references in a replacement refer to the capture groups of
+    /// its own pattern.
+    ///
+    /// # Arguments
+    /// - `patterns`: an expression that evaluates to a `List(String)` of regular expressions
+    /// - `replace_with`: an expression that evaluates to a `List(String)` of replacements, one
+    ///   per pattern or a single one for all of them
+    #[cfg(feature = "find_many")]
+    pub fn replace_many_regex(self, patterns: Expr, replace_with: Expr) -> Expr {
+        self.0
+            .map_ternary(StringFunction::ReplaceManyRegex, patterns, replace_with)
+    }
+
+    /// Find the leftmost match of any of the given regular expressions.
+    ///
+    /// Returns a struct with the index of the matching `pattern`, the `match`ed text and the
+    /// `captures` of that pattern's capture groups.
+    ///
+    /// # Arguments
+    /// - `patterns`: an expression that evaluates to a `List(String)` of regular expressions
+    #[cfg(all(feature = "find_many", feature = "dtype-struct"))]
+    pub fn extract_first_match(self, patterns: Expr) -> Expr {
+        self.0
+            .map_binary(StringFunction::ExtractFirstMatch, patterns)
+    }
+
     /// Check if a string value ends with the `sub` string.
     pub fn ends_with(self, sub: Expr) -> Expr {
         self.0.map_binary(StringFunction::EndsWith, sub)
@@ -144,6 +183,26 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::Base64Decode(strict))
     }
 
+    #[cfg(feature = "string_encoding")]
+    pub fn base32_encode(self) -> Expr {
+        self.0.map_unary(StringFunction::Base32Encode)
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    pub fn base32_decode(self, strict: bool) -> Expr {
+        self.0.map_unary(StringFunction::Base32Decode(strict))
+    }
+
+    #[cfg(feature = "string_encoding")]
+    pub fn base85_encode(self) -> Expr {
+        self.0.map_unary(StringFunction::Base85Encode)
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    pub fn base85_decode(self, strict: bool) -> Expr {
+        self.0.map_unary(StringFunction::Base85Decode(strict))
+    }
+
     /// Extract a regex pattern from the a string value. If `group_index` is out of bounds, null is returned.
     pub fn extract(self, pat: Expr, group_index: usize) -> Expr {
         self.0.map_binary(StringFunction::Extract(group_index), pat)
@@ -333,6 +392,20 @@ impl StringNameSpace {
         self.0.map_binary(StringFunction::Split(true), by)
     }
 
+    /// Split the string by a substring, making at most `maxsplit` splits. The last element holds
+    /// the remainder of the string. The resulting dtype is `List<String>`.
+    pub fn split_max(self, by: Expr, maxsplit: usize) -> Expr {
+        self.0.map_binary(StringFunction::SplitMax(maxsplit), by)
+    }
+
+    /// Split the string by the matches of a regular expression, making at most `maxsplit`
+    /// splits if given. The last element holds the remainder of the string. The resulting dtype
+    /// is `List<String>`.
+    #[cfg(feature = "regex")]
+    pub fn split_regex(self, pat: Expr, maxsplit: Option<usize>) -> Expr {
+        self.0.map_binary(StringFunction::SplitRegex(maxsplit), pat)
+    }
+
     #[cfg(feature = "dtype-struct")]
     /// Split exactly `n` times by a given substring. The resulting dtype is [`DataType::Struct`].
     pub fn split_exact(self, by: Expr, n: usize) -> Expr {
@@ -387,12 +460,98 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::Normalize { form })
     }
 
+    #[cfg(feature = "string_normalize")]
+    /// Fold the case of each string for caseless comparison.
+    ///
+    /// Unlike [`to_lowercase`](Self::to_lowercase) this also unifies characters such as
+    /// `ß` and `ss`, so `"Straße"` and `"STRASSE"` fold to the same value.
+    pub fn casefold(self) -> Expr {
+        self.0.map_unary(StringFunction::Casefold)
+    }
+
+    #[cfg(feature = "string_normalize")]
+    /// Compute a binary sort key per string that orders by base letters, then accents,
+    /// then case.
+    ///
+    /// This is not the Unicode Collation Algorithm: it isn't locale-aware and compares
+    /// characters by code point within every level.
+    pub fn collation_key(self) -> Expr {
+        self.0.map_unary(StringFunction::CollationKey)
+    }
+
+    #[cfg(feature = "string_normalize")]
+    /// Convert all characters to lowercase using the casing rules of a BCP 47 `locale`.
+    ///
+    /// Only the Turkish and Azerbaijani tailorings of the default Unicode casing are
+    /// implemented, other locales use the default casing and Lithuanian is rejected.
+    pub fn to_lowercase_locale(self, locale: PlSmallStr) -> Expr {
+        self.0.map_unary(StringFunction::LowercaseLocale(locale))
+    }
+
+    #[cfg(feature = "string_normalize")]
+    /// Convert all characters to uppercase using the casing rules of a BCP 47 `locale`.
+    ///
+    /// Only the Turkish and Azerbaijani tailorings of the default Unicode casing are
+    /// implemented, other locales use the default casing and Lithuanian is rejected.
+    pub fn to_uppercase_locale(self, locale: PlSmallStr) -> Expr {
+        self.0.map_unary(StringFunction::UppercaseLocale(locale))
+    }
+
     #[cfg(feature = "string_reverse")]
     /// Reverse each string
     pub fn reverse(self) -> Expr {
         self.0.map_unary(StringFunction::Reverse)
     }
 
+    #[cfg(feature = "string_similarity")]
+    /// Compute the Levenshtein edit distance, in characters, between each string and `other`.
+    pub fn levenshtein(self, other: Expr) -> Expr {
+        self.0.map_binary(StringFunction::Levenshtein, other)
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Compute the Jaro-Winkler similarity between each string and `other`.
+    ///
+    /// The result lies in `[0, 1]`, where `1` means the strings are equal.
+    pub fn jaro_winkler(self, other: Expr) -> Expr {
+        self.0.map_binary(StringFunction::JaroWinkler, other)
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Compute the Sørensen-Dice coefficient of the character `n`-grams of each string
+    /// and `other`.
+    ///
+    /// The result lies in `[0, 1]`, where `1` means both strings have the same `n`-grams.
+    pub fn ngram_similarity(self, other: Expr, n: usize) -> Expr {
+        self.0.map_binary(StringFunction::NgramSimilarity(n), other)
+    }
+
+    #[cfg(feature = "string_tokenize")]
+    /// Split each string into a list of tokens.
+    pub fn tokenize(self, tokenizer: Tokenizer) -> Expr {
+        self.0.map_unary(StringFunction::Tokenize(tokenizer))
+    }
+
+    #[cfg(feature = "string_tokenize")]
+    /// List the overlapping substrings of `n` consecutive characters of each string.
+    pub fn ngrams(self, n: usize) -> Expr {
+        self.0.map_unary(StringFunction::Ngrams(n))
+    }
+
+    #[cfg(feature = "string_tokenize")]
+    /// List the overlapping sequences of `k` consecutive words of each string, joined by a
+    /// single space.
+    pub fn shingle(self, k: usize) -> Expr {
+        self.0.map_unary(StringFunction::Shingle(k))
+    }
+
+    #[cfg(feature = "uuid")]
+    /// Parse UUID strings to their 16-byte binary representation. If `strict` is `false`
+    /// invalid UUIDs become null.
+    pub fn to_uuid(self, strict: bool) -> Expr {
+        self.0.map_unary(StringFunction::ToUuid(strict))
+    }
+
     /// Remove leading and trailing characters, or whitespace if matches is None.
     pub fn strip_chars(self, matches: Expr) -> Expr {
         self.0.map_binary(StringFunction::StripChars, matches)
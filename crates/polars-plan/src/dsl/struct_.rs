@@ -57,6 +57,14 @@ impl StructNameSpace {
             )))
     }
 
+    /// Convert a struct whose fields all have the same dtype to an `Array` with one element
+    /// per field, in field order. The inverse of `arr.to_struct`.
+    #[cfg(feature = "dtype-array")]
+    pub fn to_array(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::StructExpr(StructFunction::ToArray))
+    }
+
     #[cfg(feature = "json")]
     pub fn json_encode(self) -> Expr {
         self.0
@@ -1,5 +1,7 @@
 use bitflags::bitflags;
 
+use crate::dsl::NanPolicy;
+
 bitflags! {
 #[derive(Copy, Clone, Debug)]
     /// Allowed optimizations.
@@ -38,6 +40,19 @@ bitflags! {
         /// Check if operations are order dependent and unset maintaining_order if
         /// the order would not be observed.
         const CHECK_ORDER_OBSERVE = 1 << 16;
+        /// Semi-join the fact table of an aggregation over a star-schema join with the
+        /// filtered dimensions before it is joined with the other dimensions. This is off by
+        /// default, as the extra join only pays off if the dimension filters are selective.
+        const SEMI_JOIN_REDUCTION = 1 << 17;
+        /// Force deterministic results where the engine is free to vary them between runs,
+        /// e.g. the order of group-by, distinct and union outputs.
+        const DETERMINISTIC = 1 << 18;
+        /// Skip `NaN` in float aggregations, see [`NanPolicy::Ignore`].
+        const NAN_IGNORE = 1 << 19;
+        /// Let `NaN` propagate through float aggregations, see [`NanPolicy::Propagate`].
+        const NAN_PROPAGATE = 1 << 20;
+        /// Aggregate `NaN` in float aggregations as null, see [`NanPolicy::AsNull`].
+        const NAN_AS_NULL = 1 << 21;
     }
 }
 
@@ -58,6 +73,37 @@ impl OptFlags {
         self.contains(OptFlags::COLLAPSE_JOINS)
     }
 
+    pub fn semi_join_reduction(&self) -> bool {
+        self.contains(OptFlags::SEMI_JOIN_REDUCTION)
+    }
+
+    pub fn deterministic(&self) -> bool {
+        self.contains(OptFlags::DETERMINISTIC)
+    }
+
+    /// The [`NanPolicy`] of the aggregations of the query, if one was set.
+    pub fn nan_policy(&self) -> Option<NanPolicy> {
+        if self.contains(OptFlags::NAN_IGNORE) {
+            Some(NanPolicy::Ignore)
+        } else if self.contains(OptFlags::NAN_PROPAGATE) {
+            Some(NanPolicy::Propagate)
+        } else if self.contains(OptFlags::NAN_AS_NULL) {
+            Some(NanPolicy::AsNull)
+        } else {
+            None
+        }
+    }
+
+    pub fn set_nan_policy(&mut self, policy: Option<NanPolicy>) {
+        self.remove(OptFlags::NAN_IGNORE | OptFlags::NAN_PROPAGATE | OptFlags::NAN_AS_NULL);
+        match policy {
+            Some(NanPolicy::Ignore) => self.insert(OptFlags::NAN_IGNORE),
+            Some(NanPolicy::Propagate) => self.insert(OptFlags::NAN_PROPAGATE),
+            Some(NanPolicy::AsNull) => self.insert(OptFlags::NAN_AS_NULL),
+            None => {},
+        }
+    }
+
     pub fn predicate_pushdown(&self) -> bool {
         self.contains(OptFlags::PREDICATE_PUSHDOWN)
     }
@@ -84,7 +130,15 @@ impl OptFlags {
 
 impl Default for OptFlags {
     fn default() -> Self {
-        Self::from_bits_truncate(u32::MAX) & !Self::NEW_STREAMING & !Self::STREAMING & !Self::EAGER
+        Self::from_bits_truncate(u32::MAX)
+            & !Self::NEW_STREAMING
+            & !Self::STREAMING
+            & !Self::EAGER
+            & !Self::SEMI_JOIN_REDUCTION
+            & !Self::DETERMINISTIC
+            & !Self::NAN_IGNORE
+            & !Self::NAN_PROPAGATE
+            & !Self::NAN_AS_NULL
     }
 }
 
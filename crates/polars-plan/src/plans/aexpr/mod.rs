@@ -52,11 +52,18 @@ pub enum IRAggExpr {
         method: QuantileMethod,
     },
     Sum(Node),
+    Product(Node),
+    GeoMean(Node),
+    HarmonicMean(Node),
     // include_nulls
     Count(Node, bool),
     Std(Node, u8),
     Var(Node, u8),
     AggGroups(Node),
+    Udf {
+        input: Node,
+        function: AggregationUdfFn,
+    },
 }
 
 impl Hash for IRAggExpr {
@@ -70,6 +77,7 @@ impl Hash for IRAggExpr {
                 method: interpol, ..
             } => interpol.hash(state),
             Self::Std(_, v) | Self::Var(_, v) => v.hash(state),
+            Self::Udf { function, .. } => function.hash(state),
             _ => {},
         }
     }
@@ -99,6 +107,7 @@ impl IRAggExpr {
             (Quantile { method: l, .. }, Quantile { method: r, .. }) => l == r,
             (Std(_, l), Std(_, r)) => l == r,
             (Var(_, l), Var(_, r)) => l == r,
+            (Udf { function: l, .. }, Udf { function: r, .. }) => l == r,
             _ => std::mem::discriminant(self) == std::mem::discriminant(other),
         }
     }
@@ -129,11 +138,14 @@ impl From<IRAggExpr> for GroupByMethod {
             Mean(_) => GroupByMethod::Mean,
             Implode(_) => GroupByMethod::Implode,
             Sum(_) => GroupByMethod::Sum,
+            Product(_) => GroupByMethod::Product,
+            GeoMean(_) => GroupByMethod::GeoMean,
+            HarmonicMean(_) => GroupByMethod::HarmonicMean,
             Count(_, include_nulls) => GroupByMethod::Count { include_nulls },
             Std(_, ddof) => GroupByMethod::Std(ddof),
             Var(_, ddof) => GroupByMethod::Var(ddof),
             AggGroups(_) => GroupByMethod::Groups,
-            Quantile { .. } => unreachable!(),
+            Quantile { .. } | Udf { .. } => unreachable!(),
         }
     }
 }
@@ -246,6 +246,26 @@ impl AExpr {
                         }
                         Ok(field)
                     },
+                    Product(expr) => {
+                        *agg_list = false;
+                        let mut field = ctx.arena.get(*expr).to_field_impl(ctx, &mut false)?;
+                        let dt = match field.dtype() {
+                            Float32 | Float64 | UInt64 => None,
+                            #[cfg(feature = "dtype-i128")]
+                            Int128 => None,
+                            _ => Some(Int64),
+                        };
+                        if let Some(dt) = dt {
+                            field.coerce(dt);
+                        }
+                        Ok(field)
+                    },
+                    GeoMean(expr) | HarmonicMean(expr) => {
+                        *agg_list = false;
+                        let mut field = ctx.arena.get(*expr).to_field_impl(ctx, &mut false)?;
+                        float_type(&mut field);
+                        Ok(field)
+                    },
                     Median(expr) => {
                         *agg_list = false;
                         let mut field = ctx.arena.get(*expr).to_field_impl(ctx, &mut false)?;
@@ -306,6 +326,12 @@ impl AExpr {
                         float_type(&mut field);
                         Ok(field)
                     },
+                    Udf { input, function } => {
+                        *agg_list = false;
+                        let mut field = ctx.arena.get(*input).to_field_impl(ctx, &mut false)?;
+                        field.coerce(function.output_dtype(field.dtype())?);
+                        Ok(field)
+                    },
                 }
             },
             Cast { expr, dtype, .. } => {
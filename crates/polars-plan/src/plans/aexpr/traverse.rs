@@ -169,10 +169,14 @@ impl IRAggExpr {
             Implode(input) => Single(*input),
             Quantile { expr, quantile, .. } => Many(vec![*expr, *quantile]),
             Sum(input) => Single(*input),
+            Product(input) => Single(*input),
+            GeoMean(input) => Single(*input),
+            HarmonicMean(input) => Single(*input),
             Count(input, _) => Single(*input),
             Std(input, _) => Single(*input),
             Var(input, _) => Single(*input),
             AggGroups(input) => Single(*input),
+            Udf { input, .. } => Single(*input),
         }
     }
     pub fn set_input(&mut self, input: Node) {
@@ -188,10 +192,14 @@ impl IRAggExpr {
             Implode(input) => input,
             Quantile { expr, .. } => expr,
             Sum(input) => input,
+            Product(input) => input,
+            GeoMean(input) => input,
+            HarmonicMean(input) => input,
             Count(input, _) => input,
             Std(input, _) => input,
             Var(input, _) => input,
             AggGroups(input) => input,
+            Udf { input, .. } => input,
         };
         *node = input;
     }
@@ -6,6 +6,9 @@ use polars_core::prelude::*;
 use crate::dsl::Expr;
 
 pub struct AnonymousScanArgs {
+    /// Number of rows to skip. Always zero unless
+    /// [`allows_offset_pushdown`](AnonymousScan::allows_offset_pushdown) is `true`.
+    pub offset: usize,
     pub n_rows: Option<usize>,
     pub with_columns: Option<Arc<[PlSmallStr]>>,
     pub schema: SchemaRef,
@@ -13,6 +16,8 @@ pub struct AnonymousScanArgs {
     pub predicate: Option<Expr>,
 }
 
+pub type AnonymousScanBatches = Box<dyn Iterator<Item = PolarsResult<DataFrame>> + Send>;
+
 pub trait AnonymousScan: Send + Sync {
     fn as_any(&self) -> &dyn Any;
     /// Creates a DataFrame from the supplied function & scan options.
@@ -24,6 +29,20 @@ pub trait AnonymousScan: Send + Sync {
         self.scan(scan_opts).map(Some)
     }
 
+    /// Produce the output as batches of roughly `batch_size` rows. Implement this method to
+    /// get proper streaming support in the new streaming engine.
+    ///
+    /// The streaming engine applies the predicate again on the produced batches, so sources
+    /// are free to only use it to skip data.
+    fn scan_batches(
+        &self,
+        scan_opts: AnonymousScanArgs,
+        _batch_size: usize,
+    ) -> PolarsResult<AnonymousScanBatches> {
+        let df = self.scan(scan_opts)?;
+        Ok(Box::new(std::iter::once(Ok(df))))
+    }
+
     /// function to supply the schema.
     /// Allows for an optional infer schema argument for data sources with dynamic schemas
     fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
@@ -47,6 +66,13 @@ pub trait AnonymousScan: Send + Sync {
     fn allows_slice_pushdown(&self) -> bool {
         false
     }
+    /// Specify if the scan provider handles the `offset` of [`AnonymousScanArgs`]. Otherwise only
+    /// slices that start at the first row are pushed down into the scan.
+    ///
+    /// Defaults to `false`
+    fn allows_offset_pushdown(&self) -> bool {
+        false
+    }
 }
 
 impl Debug for dyn AnonymousScan {
@@ -218,6 +218,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                                 &sources,
                                 unified_scan_args.row_index.as_ref(),
                                 cloud_options,
+                                options,
                             )
                             .map_err(|e| e.context(failed_here!(parquet scan)))?;
 
@@ -226,11 +227,18 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                         }
                     },
                     #[cfg(feature = "ipc")]
-                    FileScan::Ipc { metadata, .. } => {
+                    FileScan::Ipc {
+                        #[cfg(feature = "ipc_encryption")]
+                        encryption,
+                        metadata,
+                        ..
+                    } => {
                         let (file_info, md) = scans::ipc_file_info(
                             &sources,
                             unified_scan_args.row_index.as_ref(),
                             cloud_options,
+                            #[cfg(feature = "ipc_encryption")]
+                            encryption.as_ref(),
                         )
                         .map_err(|e| e.context(failed_here!(ipc scan)))?;
                         *metadata = Some(Arc::new(md));
@@ -381,7 +389,9 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                 cached_ir.replace(ir);
             }
 
-            cached_ir.clone().unwrap()
+            let node = ctxt.lp_arena.add(cached_ir.clone().unwrap());
+            drop(cached_ir);
+            return apply_scan_policies(node, ctxt);
         },
         #[cfg(feature = "python")]
         DslPlan::PythonScan { mut options } => {
@@ -448,6 +458,10 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             }
         },
         DslPlan::Filter { input, predicate } => {
+            #[cfg(all(feature = "is_in", feature = "semi_anti_join"))]
+            if let Some(lp) = subquery::rewrite_is_in_subqueries(&input, &predicate) {
+                return to_alp_impl(lp, ctxt);
+            }
             let mut input =
                 to_alp_impl(owned(input), ctxt).map_err(|e| e.context(failed_here!(filter)))?;
             let predicate = expand_filter(predicate, input, ctxt.lp_arena, ctxt.opt_flags)
@@ -763,11 +777,14 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                 maintain_order: options.maintain_order,
                 keep_strategy: options.keep_strategy,
                 slice: None,
+                keys_sorted: false,
             };
 
             IR::Distinct { input, options }
         },
         DslPlan::MapFunction { input, function } => {
+            #[cfg(feature = "random")]
+            let expr_start = ctxt.expr_arena.len();
             let input = to_alp_impl(owned(input), ctxt)
                 .map_err(|e| e.context(failed_here!(format!("{}", function).to_lowercase())))?;
             let input_schema = ctxt.lp_arena.get(input).schema(ctxt.lp_arena);
@@ -776,6 +793,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                 DslFunction::Explode {
                     columns,
                     allow_empty,
+                    position,
                 } => {
                     let columns = expand_selectors(columns, &input_schema, &[])?;
                     validate_columns_in_input(columns.as_ref(), &input_schema, "explode")?;
@@ -785,11 +803,17 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     }
                     let function = FunctionIR::Explode {
                         columns,
+                        position,
                         schema: Default::default(),
                     };
                     let ir = IR::MapFunction { input, function };
                     return Ok(ctxt.lp_arena.add(ir));
                 },
+                #[cfg(feature = "random")]
+                DslFunction::RandomSeed(seed) => {
+                    seed_random_exprs(seed, expr_start, ctxt.expr_arena);
+                    return Ok(input);
+                },
                 DslFunction::FillNan(fill_value) => {
                     let exprs = input_schema
                         .iter()
@@ -970,6 +994,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             let payload = match payload {
                 SinkType::Memory => SinkTypeIR::Memory,
                 SinkType::File(f) => SinkTypeIR::File(f),
+                SinkType::Callback(f) => SinkTypeIR::Callback(f),
                 SinkType::Partition(f) => SinkTypeIR::Partition(PartitionSinkTypeIR {
                     base_path: f.base_path,
                     file_path_cb: f.file_path_cb,
@@ -1052,7 +1077,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
     Ok(ctxt.lp_arena.add(v))
 }
 
-fn expand_filter(
+pub(super) fn expand_filter(
     predicate: Expr,
     input: Node,
     lp_arena: &Arena<IR>,
@@ -1121,7 +1146,7 @@ fn expand_filter(
     Ok(predicate)
 }
 
-fn resolve_with_columns(
+pub(super) fn resolve_with_columns(
     exprs: Vec<Expr>,
     input: Node,
     lp_arena: &Arena<IR>,
@@ -1223,6 +1248,30 @@ fn resolve_group_by(
 
     Ok((keys, aggs, Arc::new(schema)))
 }
+/// Give every unseeded random expression converted after `expr_start` a seed derived from
+/// `seed` and its position in the arena, so that repeated runs draw the same values
+/// regardless of thread count or evaluation order.
+#[cfg(feature = "random")]
+fn seed_random_exprs(seed: u64, expr_start: usize, expr_arena: &mut Arena<AExpr>) {
+    use polars_utils::hashing::folded_multiply;
+    const MULTIPLE: u64 = 6364136223846793005;
+
+    let mut i = 0u64;
+    for idx in expr_start..expr_arena.len() {
+        if let AExpr::Function {
+            function: FunctionExpr::Random { seed: s @ None, .. },
+            ..
+        } = expr_arena.get_mut(Node(idx))
+        {
+            i += 1;
+            *s = Some(folded_multiply(
+                seed ^ folded_multiply(i, MULTIPLE),
+                MULTIPLE,
+            ));
+        }
+    }
+}
+
 fn stats_helper<F, E>(condition: F, expr: E, schema: &Schema) -> Vec<Expr>
 where
     F: Fn(&DataType) -> bool,
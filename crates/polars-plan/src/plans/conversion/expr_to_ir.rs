@@ -239,6 +239,15 @@ pub(super) fn to_aexpr_impl(
                 AggExpr::Sum(expr) => {
                     IRAggExpr::Sum(to_aexpr_impl_materialized_lit(owned(expr), arena, state)?)
                 },
+                AggExpr::Product(expr) => {
+                    IRAggExpr::Product(to_aexpr_impl_materialized_lit(owned(expr), arena, state)?)
+                },
+                AggExpr::GeoMean(expr) => {
+                    IRAggExpr::GeoMean(to_aexpr_impl_materialized_lit(owned(expr), arena, state)?)
+                },
+                AggExpr::HarmonicMean(expr) => IRAggExpr::HarmonicMean(
+                    to_aexpr_impl_materialized_lit(owned(expr), arena, state)?,
+                ),
                 AggExpr::Std(expr, ddof) => IRAggExpr::Std(
                     to_aexpr_impl_materialized_lit(owned(expr), arena, state)?,
                     ddof,
@@ -250,6 +259,10 @@ pub(super) fn to_aexpr_impl(
                 AggExpr::AggGroups(expr) => {
                     IRAggExpr::AggGroups(to_aexpr_impl_materialized_lit(owned(expr), arena, state)?)
                 },
+                AggExpr::Udf { input, function } => IRAggExpr::Udf {
+                    input: to_aexpr_impl_materialized_lit(owned(input), arena, state)?,
+                    function,
+                },
             };
             AExpr::Agg(a_agg)
         },
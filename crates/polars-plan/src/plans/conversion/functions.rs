@@ -62,6 +62,10 @@ pub(super) fn convert_functions(
             polars_ensure!(&e[0].is_scalar(arena), ComputeError: "'value' must be scalar value");
             polars_ensure!(&e[1].is_scalar(arena), ComputeError: "'n' must be scalar value");
         },
+        #[cfg(feature = "uuid")]
+        F::Uuid(_) => {
+            polars_ensure!(&e[0].is_scalar(arena), ComputeError: "'n' must be scalar value");
+        },
         #[cfg(feature = "replace")]
         F::Replace | F::ReplaceStrict { .. } => {
             let old = &e[1];
@@ -147,6 +147,18 @@ pub fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
                 let exp = node_to_expr(expr, expr_arena);
                 AggExpr::Sum(Arc::new(exp)).into()
             },
+            IRAggExpr::Product(expr) => {
+                let exp = node_to_expr(expr, expr_arena);
+                AggExpr::Product(Arc::new(exp)).into()
+            },
+            IRAggExpr::GeoMean(expr) => {
+                let exp = node_to_expr(expr, expr_arena);
+                AggExpr::GeoMean(Arc::new(exp)).into()
+            },
+            IRAggExpr::HarmonicMean(expr) => {
+                let exp = node_to_expr(expr, expr_arena);
+                AggExpr::HarmonicMean(Arc::new(exp)).into()
+            },
             IRAggExpr::Std(expr, ddof) => {
                 let exp = node_to_expr(expr, expr_arena);
                 AggExpr::Std(Arc::new(exp), ddof).into()
@@ -163,6 +175,14 @@ pub fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
                 let expr = node_to_expr(expr, expr_arena);
                 AggExpr::Count(Arc::new(expr), include_nulls).into()
             },
+            IRAggExpr::Udf { input, function } => {
+                let input = node_to_expr(input, expr_arena);
+                AggExpr::Udf {
+                    input: Arc::new(input),
+                    function,
+                }
+                .into()
+            },
         },
         AExpr::Ternary {
             predicate,
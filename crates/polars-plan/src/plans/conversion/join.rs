@@ -110,6 +110,7 @@ pub fn resolve_join(
 
     let mut left_on = to_expr_irs_ignore_alias(left_on, ctxt.expr_arena)?;
     let mut right_on = to_expr_irs_ignore_alias(right_on, ctxt.expr_arena)?;
+    options.args.nulls_equal_for_keys(left_on.len())?;
     let mut joined_on = PlHashSet::new();
 
     #[cfg(feature = "iejoin")]
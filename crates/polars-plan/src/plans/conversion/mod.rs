@@ -3,6 +3,7 @@ mod dsl_to_ir;
 mod expr_expansion;
 mod expr_to_ir;
 mod ir_to_dsl;
+mod scan_policy;
 #[cfg(any(
     feature = "ipc",
     feature = "parquet",
@@ -11,6 +12,8 @@ mod ir_to_dsl;
 ))]
 mod scans;
 mod stack_opt;
+#[cfg(all(feature = "is_in", feature = "semi_anti_join"))]
+mod subquery;
 
 use std::borrow::Cow;
 use std::sync::{Arc, Mutex};
@@ -23,6 +26,8 @@ use polars_utils::idx_vec::UnitVec;
 use polars_utils::unitvec;
 use polars_utils::vec::ConvertVec;
 use recursive::recursive;
+use scan_policy::apply_scan_policies;
+pub use scan_policy::{ColumnMask, ScanPolicy, clear_scan_policies, register_scan_policy};
 #[cfg(any(
     feature = "ipc",
     feature = "parquet",
@@ -277,6 +282,7 @@ impl IR {
                 let payload = match payload {
                     SinkTypeIR::Memory => SinkType::Memory,
                     SinkTypeIR::File(f) => SinkType::File(f),
+                    SinkTypeIR::Callback(f) => SinkType::Callback(f),
                     SinkTypeIR::Partition(f) => SinkType::Partition(PartitionSinkType {
                         base_path: f.base_path,
                         file_path_cb: f.file_path_cb,
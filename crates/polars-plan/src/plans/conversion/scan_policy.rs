@@ -0,0 +1,167 @@
+//! Row filters and column masks that are enforced on every scan of a path.
+use std::path::{Component, Path, PathBuf};
+use std::sync::RwLock;
+
+use super::*;
+
+/// How the values of a column are masked.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ColumnMask {
+    /// Replace the values with their hash, keyed with `key`. The column becomes
+    /// [`DataType::UInt64`]. The key must be kept secret, otherwise the values can be recovered
+    /// by hashing candidate values.
+    #[cfg(feature = "row_hash")]
+    Hash { key: [u64; 4] },
+    /// Replace the non-null values with a fixed string. The column becomes [`DataType::String`].
+    Redact(PlSmallStr),
+    /// Replace all values with null, keeping the data type of the column.
+    Nullify,
+}
+
+/// Row filter and column masks that are applied to every scan of the paths a policy is
+/// registered for.
+#[derive(Clone, Debug, Default)]
+pub struct ScanPolicy {
+    row_filter: Option<Expr>,
+    masks: Vec<(PlSmallStr, ColumnMask)>,
+}
+
+impl ScanPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only let rows for which `predicate` is true through. Multiple filters are combined
+    /// with `and`.
+    pub fn with_row_filter(mut self, predicate: Expr) -> Self {
+        self.row_filter = Some(match self.row_filter {
+            Some(filter) => filter.and(predicate),
+            None => predicate,
+        });
+        self
+    }
+
+    /// Mask the values of `column`. Columns that are not in the scanned schema are ignored.
+    pub fn with_mask(mut self, column: impl Into<PlSmallStr>, mask: ColumnMask) -> Self {
+        self.masks.push((column.into(), mask));
+        self
+    }
+}
+
+static SCAN_POLICIES: RwLock<Vec<(PathBuf, ScanPolicy)>> = RwLock::new(Vec::new());
+
+/// Register a policy for all paths under `prefix`, which is a file, a directory or a cloud
+/// location such as `s3://bucket/table`.
+///
+/// The policy is injected right above the scan when a plan is converted to the IR, so it is
+/// applied before any user predicate and no optimization can bypass it. If several policies
+/// match a scan, all of them are applied.
+pub fn register_scan_policy(prefix: impl Into<PathBuf>, policy: ScanPolicy) {
+    let prefix = normalize_path(&prefix.into());
+    SCAN_POLICIES.write().unwrap().push((prefix, policy));
+}
+
+/// Remove all policies registered with [`register_scan_policy`].
+pub fn clear_scan_policies() {
+    SCAN_POLICIES.write().unwrap().clear();
+}
+
+/// Resolve `path` to the form in which paths are compared with the registered prefixes, so that
+/// a policy can't be bypassed by scanning a file through a relative path, `..` components or a
+/// symlink.
+fn normalize_path(path: &Path) -> PathBuf {
+    if polars_io::path_utils::is_cloud_url(path) {
+        return path.to_path_buf();
+    }
+    if let Ok(path) = std::fs::canonicalize(path) {
+        return path;
+    }
+    // The path doesn't exist (yet), e.g. a prefix of files that are written later.
+    let path = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {},
+            Component::ParentDir => {
+                out.pop();
+            },
+            component => out.push(component),
+        }
+    }
+    out
+}
+
+fn policies_for(paths: &[PathBuf]) -> Vec<ScanPolicy> {
+    let policies = SCAN_POLICIES.read().unwrap();
+    if policies.is_empty() {
+        return vec![];
+    }
+    let paths = paths.iter().map(|p| normalize_path(p)).collect::<Vec<_>>();
+    policies
+        .iter()
+        .filter(|(prefix, _)| paths.iter().any(|p| p.starts_with(prefix)))
+        .map(|(_, policy)| policy.clone())
+        .collect()
+}
+
+fn mask_expr(name: &PlSmallStr, dtype: &DataType, mask: &ColumnMask) -> Expr {
+    let column = col(name.clone());
+    let masked = match mask {
+        #[cfg(feature = "row_hash")]
+        ColumnMask::Hash { key } => column.hash(key[0], key[1], key[2], key[3]),
+        ColumnMask::Redact(replacement) => when(column.is_not_null())
+            .then(lit(replacement.clone()))
+            .otherwise(lit(NULL).cast(DataType::String)),
+        ColumnMask::Nullify => lit(NULL).cast(dtype.clone()),
+    };
+    masked.alias(name.clone())
+}
+
+/// Wrap the scan at `node` in the row filters and column masks of the policies registered for
+/// its paths.
+pub(super) fn apply_scan_policies(
+    node: Node,
+    ctxt: &mut DslConversionContext,
+) -> PolarsResult<Node> {
+    let IR::Scan { sources, .. } = ctxt.lp_arena.get(node) else {
+        return Ok(node);
+    };
+    let Some(paths) = sources.as_paths() else {
+        return Ok(node);
+    };
+    let policies = policies_for(paths);
+
+    let mut input = node;
+    for policy in policies {
+        if let Some(predicate) = policy.row_filter {
+            let predicate = expand_filter(predicate, input, ctxt.lp_arena, ctxt.opt_flags)?;
+            let predicate = to_expr_ir(predicate, ctxt.expr_arena)?;
+            ctxt.conversion_optimizer
+                .push_scratch(predicate.node(), ctxt.expr_arena);
+            let lp = IR::Filter { input, predicate };
+            input = run_conversion(lp, ctxt, "scan policy filter")?;
+        }
+
+        let schema = ctxt.lp_arena.get(input).schema(ctxt.lp_arena).into_owned();
+        let masks = policy
+            .masks
+            .iter()
+            .filter_map(|(name, mask)| Some(mask_expr(name, schema.get(name)?, mask)))
+            .collect::<Vec<_>>();
+        if masks.is_empty() {
+            continue;
+        }
+        let (exprs, schema) =
+            resolve_with_columns(masks, input, ctxt.lp_arena, ctxt.expr_arena, ctxt.opt_flags)?;
+        ctxt.conversion_optimizer
+            .fill_scratch(&exprs, ctxt.expr_arena);
+        let lp = IR::HStack {
+            input,
+            exprs,
+            schema,
+            options: ProjectionOptions::default(),
+        };
+        input = run_conversion(lp, ctxt, "scan policy mask")?;
+    }
+    Ok(input)
+}
@@ -33,10 +33,11 @@ pub(super) fn parquet_file_info(
     sources: &ScanSources,
     row_index: Option<&RowIndex>,
     #[allow(unused)] cloud_options: Option<&polars_io::cloud::CloudOptions>,
+    options: &ParquetOptions,
 ) -> PolarsResult<(FileInfo, Option<FileMetadataRef>)> {
     use polars_core::error::feature_gated;
 
-    let (reader_schema, num_rows, metadata) = {
+    let (mut reader_schema, num_rows, metadata) = {
         if sources.is_cloud_url() {
             let first_path = &sources.as_paths().unwrap()[0];
             feature_gated!("cloud", {
@@ -66,6 +67,19 @@ pub(super) fn parquet_file_info(
         }
     };
 
+    if let Some(metadata) = &metadata {
+        if options.strings_as_categorical {
+            polars_parquet::read::schema::strings_as_categorical(
+                Arc::make_mut(&mut reader_schema),
+                metadata,
+            );
+        }
+        // The metadata is passed on to the reader of the first file, which renames its columns.
+        if let Some(column_mapping) = &options.column_mapping {
+            column_mapping.apply_to_schema(Arc::make_mut(&mut reader_schema), metadata)?;
+        }
+    }
+
     let schema =
         prepare_output_schema(Schema::from_arrow_schema(reader_schema.as_ref()), row_index);
 
@@ -84,6 +98,7 @@ pub(super) fn ipc_file_info(
     sources: &ScanSources,
     row_index: Option<&RowIndex>,
     cloud_options: Option<&polars_io::cloud::CloudOptions>,
+    #[cfg(feature = "ipc_encryption")] encryption: Option<&polars_io::ipc::IpcEncryption>,
 ) -> PolarsResult<(FileInfo, arrow::io::ipc::read::FileMetadata)> {
     use polars_core::error::feature_gated;
 
@@ -91,30 +106,41 @@ pub(super) fn ipc_file_info(
         polars_bail!(ComputeError: "expected at least 1 source");
     };
 
-    let metadata = match first {
-        ScanSourceRef::Path(path) => {
-            if is_cloud_url(path) {
-                feature_gated!("cloud", {
-                    let uri = path.to_string_lossy();
-                    get_runtime().block_on(async {
-                        polars_io::ipc::IpcReaderAsync::from_uri(&uri, cloud_options)
-                            .await?
-                            .metadata()
-                            .await
-                    })?
-                })
-            } else {
-                arrow::io::ipc::read::read_file_metadata(&mut std::io::BufReader::new(
-                    polars_utils::open_file(path)?,
-                ))?
-            }
-        },
-        ScanSourceRef::File(file) => {
-            arrow::io::ipc::read::read_file_metadata(&mut std::io::BufReader::new(file))?
-        },
-        ScanSourceRef::Buffer(buff) => {
-            arrow::io::ipc::read::read_file_metadata(&mut std::io::Cursor::new(buff))?
-        },
+    #[cfg(feature = "ipc_encryption")]
+    let decrypted = encryption
+        .map(|encryption| first.to_decrypted_ipc_memslice(cloud_options, encryption))
+        .transpose()?;
+    #[cfg(not(feature = "ipc_encryption"))]
+    let decrypted: Option<polars_utils::mmap::MemSlice> = None;
+
+    let metadata = if let Some(memslice) = decrypted {
+        arrow::io::ipc::read::read_file_metadata(&mut std::io::Cursor::new(memslice.as_ref()))?
+    } else {
+        match first {
+            ScanSourceRef::Path(path) => {
+                if is_cloud_url(path) {
+                    feature_gated!("cloud", {
+                        let uri = path.to_string_lossy();
+                        get_runtime().block_on(async {
+                            polars_io::ipc::IpcReaderAsync::from_uri(&uri, cloud_options)
+                                .await?
+                                .metadata()
+                                .await
+                        })?
+                    })
+                } else {
+                    arrow::io::ipc::read::read_file_metadata(&mut std::io::BufReader::new(
+                        polars_utils::open_file(path)?,
+                    ))?
+                }
+            },
+            ScanSourceRef::File(file) => {
+                arrow::io::ipc::read::read_file_metadata(&mut std::io::BufReader::new(file))?
+            },
+            ScanSourceRef::Buffer(buff) => {
+                arrow::io::ipc::read::read_file_metadata(&mut std::io::Cursor::new(buff))?
+            },
+        }
     };
 
     let file_info = FileInfo::new(
@@ -0,0 +1,114 @@
+use super::*;
+
+/// An `is_in` of `lhs` against the single column of a subquery.
+struct IsInSubquery {
+    lhs: Expr,
+    subquery: Arc<DslPlan>,
+    column: PlSmallStr,
+    nulls_equal: bool,
+    negated: bool,
+}
+
+fn split_conjunction(expr: &Expr, out: &mut Vec<Expr>) {
+    match expr {
+        Expr::BinaryExpr {
+            left,
+            op: Operator::And | Operator::LogicalAnd,
+            right,
+        } => {
+            split_conjunction(left, out);
+            split_conjunction(right, out);
+        },
+        // A row is kept by a filter on `all_horizontal` if all inputs are true.
+        Expr::Function {
+            input,
+            function: FunctionExpr::Boolean(BooleanFunction::AllHorizontal),
+            ..
+        } => {
+            for e in input {
+                split_conjunction(e, out);
+            }
+        },
+        e => out.push(e.clone()),
+    }
+}
+
+fn as_is_in_subquery(expr: &Expr, negated: bool) -> Option<IsInSubquery> {
+    match expr {
+        Expr::Function {
+            input,
+            function: FunctionExpr::Boolean(BooleanFunction::Not),
+            ..
+        } if !negated && input.len() == 1 => as_is_in_subquery(&input[0], true),
+        Expr::Function {
+            input,
+            function: FunctionExpr::Boolean(BooleanFunction::IsIn { nulls_equal }),
+            ..
+        } => match input.as_slice() {
+            [lhs, Expr::SubPlan(subquery, names)] if names.len() == 1 => Some(IsInSubquery {
+                lhs: lhs.clone(),
+                subquery: Arc::clone(subquery),
+                column: names[0].as_str().into(),
+                nulls_equal: *nulls_equal,
+                negated,
+            }),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Rewrite a filter of which the predicate has `is_in` against subqueries in its top-level
+/// conjunction into semi-joins, or anti-joins if the `is_in` is negated, with the subqueries.
+///
+/// Returns `None` if there is nothing to rewrite.
+pub(super) fn rewrite_is_in_subqueries(input: &Arc<DslPlan>, predicate: &Expr) -> Option<DslPlan> {
+    let mut conjuncts = vec![];
+    split_conjunction(predicate, &mut conjuncts);
+    if !conjuncts
+        .iter()
+        .any(|e| as_is_in_subquery(e, false).is_some())
+    {
+        return None;
+    }
+
+    let mut lp = input.clone();
+    let mut remaining = vec![];
+    for conjunct in conjuncts {
+        let Some(is_in) = as_is_in_subquery(&conjunct, false) else {
+            remaining.push(conjunct);
+            continue;
+        };
+
+        let how = if is_in.negated {
+            // A null doesn't match any value, but `is_in` is null for it, which filters the row.
+            if !is_in.nulls_equal {
+                remaining.push(is_in.lhs.clone().is_not_null());
+            }
+            JoinType::Anti
+        } else {
+            JoinType::Semi
+        };
+        let mut args = JoinArgs::new(how);
+        args.nulls_equal = is_in.nulls_equal;
+        lp = Arc::new(DslPlan::Join {
+            input_left: lp,
+            input_right: is_in.subquery,
+            left_on: vec![is_in.lhs],
+            right_on: vec![Expr::Column(is_in.column)],
+            predicates: vec![],
+            options: Arc::new(JoinOptions {
+                args,
+                ..Default::default()
+            }),
+        });
+    }
+
+    Some(match remaining.into_iter().reduce(|acc, e| acc.and(e)) {
+        Some(predicate) => DslPlan::Filter {
+            input: lp,
+            predicate,
+        },
+        None => Arc::unwrap_or_clone(lp),
+    })
+}
@@ -47,6 +47,10 @@ fn check_namespace(function: &FunctionExpr, first_dtype: &DataType) -> PolarsRes
         FunctionExpr::BinaryExpr(_) => {
             polars_ensure!(first_dtype == &DataType::Binary, InvalidOperation: "expected Binary type, got: {}", first_dtype)
         },
+        #[cfg(feature = "ip")]
+        FunctionExpr::IpExpr(_) => {
+            polars_ensure!(first_dtype == &DataType::String, InvalidOperation: "expected String type, got: {}", first_dtype)
+        },
         #[cfg(feature = "temporal")]
         FunctionExpr::TemporalExpr(_) => {
             polars_ensure!(first_dtype.is_temporal(), InvalidOperation: "expected Date(time)/Duration type, got: {}", first_dtype)
@@ -47,8 +47,19 @@ pub fn count_rows(
             FileScan::Csv { options } => count_all_rows_csv(sources, options),
             #[cfg(feature = "parquet")]
             FileScan::Parquet { .. } => count_rows_parquet(sources, cloud_options),
+            #[cfg(feature = "ipc_encryption")]
+            FileScan::Ipc {
+                encryption: Some(encryption),
+                ..
+            } => sources
+                .iter()
+                .map(|source| {
+                    let memslice = source.to_decrypted_ipc_memslice(cloud_options, encryption)?;
+                    count_rows_ipc_sync(&mut std::io::Cursor::new(memslice)).map(|v| v as usize)
+                })
+                .sum::<PolarsResult<usize>>(),
             #[cfg(feature = "ipc")]
-            FileScan::Ipc { options, metadata } => count_rows_ipc(
+            FileScan::Ipc { metadata, .. } => count_rows_ipc(
                 sources,
                 #[cfg(feature = "cloud")]
                 cloud_options,
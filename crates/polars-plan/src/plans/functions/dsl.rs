@@ -32,6 +32,8 @@ pub enum DslFunction {
     Explode {
         columns: Vec<Selector>,
         allow_empty: bool,
+        #[cfg_attr(feature = "serde", serde(default))]
+        position: Option<PlSmallStr>,
     },
     #[cfg(feature = "pivot")]
     Unpivot {
@@ -43,10 +45,22 @@ pub enum DslFunction {
         strict: bool,
     },
     Unnest(Vec<Selector>),
+    SetSortedBy {
+        columns: Vec<PlSmallStr>,
+        descending: Vec<bool>,
+    },
+    SortWithinPartitions {
+        partition_by: Vec<PlSmallStr>,
+        by: Vec<PlSmallStr>,
+        sort_options: SortMultipleOptions,
+    },
     Stats(StatsFunction),
     /// FillValue
     FillNan(Expr),
     Drop(DropFunction),
+    /// Fix the seed of all unseeded random expressions in the input.
+    #[cfg(feature = "random")]
+    RandomSeed(u64),
     // Function that is already converted to IR.
     #[cfg_attr(feature = "serde", serde(skip))]
     FunctionIR(FunctionIR),
@@ -143,6 +157,47 @@ impl DslFunction {
                 validate_columns_in_input(columns.as_ref(), input_schema, "unnest")?;
                 FunctionIR::Unnest { columns }
             },
+            DslFunction::SetSortedBy {
+                columns,
+                descending,
+            } => {
+                validate_columns_in_input(columns.iter(), input_schema, "set_sorted_by")?;
+                polars_ensure!(
+                    !columns.is_empty() && descending.len() == columns.len(),
+                    InvalidOperation: "set_sorted_by expects a `descending` flag for every column, \
+                    got {} columns and {} flags",
+                    columns.len(), descending.len()
+                );
+                FunctionIR::SetSortedBy {
+                    columns: columns.into(),
+                    descending: descending.into(),
+                }
+            },
+            DslFunction::SortWithinPartitions {
+                partition_by,
+                by,
+                sort_options,
+            } => {
+                validate_columns_in_input(
+                    partition_by.iter(),
+                    input_schema,
+                    "sort_within_partitions",
+                )?;
+                validate_columns_in_input(by.iter(), input_schema, "sort_within_partitions")?;
+                polars_ensure!(
+                    !partition_by.is_empty(),
+                    InvalidOperation: "sort_within_partitions needs a column to partition by"
+                );
+                polars_ensure!(
+                    !by.is_empty(),
+                    InvalidOperation: "sort_within_partitions needs a column to sort by"
+                );
+                FunctionIR::SortWithinPartitions {
+                    partition_by: partition_by.into(),
+                    by: by.into(),
+                    sort_options,
+                }
+            },
             #[cfg(feature = "python")]
             DslFunction::OpaquePython(inner) => FunctionIR::OpaquePython(inner),
             DslFunction::Stats(_)
@@ -152,6 +207,8 @@ impl DslFunction {
                 // We should not reach this.
                 panic!("impl error")
             },
+            #[cfg(feature = "random")]
+            DslFunction::RandomSeed(_) => panic!("impl error"),
         };
         Ok(function)
     }
@@ -35,6 +35,19 @@ pub enum FunctionIR {
         #[cfg_attr(feature = "ir_serde", serde(skip))]
         schema: CachedSchema,
     },
+    /// Declares that the input is sorted by `columns`, lexicographically. The data is not
+    /// changed.
+    SetSortedBy {
+        columns: Arc<[PlSmallStr]>,
+        descending: Arc<[bool]>,
+    },
+    /// Sorts the rows by `by` within every run of equal values in `partition_by`, or within
+    /// every chunk if there are no partition columns.
+    SortWithinPartitions {
+        partition_by: Arc<[PlSmallStr]>,
+        by: Arc<[PlSmallStr]>,
+        sort_options: SortMultipleOptions,
+    },
     #[cfg(feature = "python")]
     OpaquePython(OpaquePythonUdf),
 
@@ -59,6 +72,8 @@ pub enum FunctionIR {
     },
     Explode {
         columns: Arc<[PlSmallStr]>,
+        /// Name of the column with the position of the values in their list.
+        position: Option<PlSmallStr>,
         #[cfg_attr(feature = "ir_serde", serde(skip))]
         schema: CachedSchema,
     },
@@ -116,10 +131,45 @@ impl PartialEq for FunctionIR {
                     ..
                 },
             ) => existing_l == existing_r && new_l == new_r,
-            (Explode { columns: l, .. }, Explode { columns: r, .. }) => l == r,
+            (
+                Explode {
+                    columns: l,
+                    position: position_l,
+                    ..
+                },
+                Explode {
+                    columns: r,
+                    position: position_r,
+                    ..
+                },
+            ) => l == r && position_l == position_r,
             #[cfg(feature = "pivot")]
             (Unpivot { args: l, .. }, Unpivot { args: r, .. }) => l == r,
             (RowIndex { name: l, .. }, RowIndex { name: r, .. }) => l == r,
+            (
+                SetSortedBy {
+                    columns: columns_l,
+                    descending: descending_l,
+                },
+                SetSortedBy {
+                    columns: columns_r,
+                    descending: descending_r,
+                },
+            ) => columns_l == columns_r && descending_l == descending_r,
+            (
+                SortWithinPartitions {
+                    partition_by: partition_by_l,
+                    by: by_l,
+                    sort_options: sort_options_l,
+                },
+                SortWithinPartitions {
+                    partition_by: partition_by_r,
+                    by: by_r,
+                    sort_options: sort_options_r,
+                },
+            ) => {
+                partition_by_l == partition_by_r && by_l == by_r && sort_options_l == sort_options_r
+            },
             _ => false,
         }
     }
@@ -155,7 +205,14 @@ impl Hash for FunctionIR {
                 existing.hash(state);
                 new.hash(state);
             },
-            FunctionIR::Explode { columns, schema: _ } => columns.hash(state),
+            FunctionIR::Explode {
+                columns,
+                position,
+                schema: _,
+            } => {
+                columns.hash(state);
+                position.hash(state);
+            },
             #[cfg(feature = "pivot")]
             FunctionIR::Unpivot { args, schema: _ } => args.hash(state),
             FunctionIR::RowIndex {
@@ -166,6 +223,22 @@ impl Hash for FunctionIR {
                 name.hash(state);
                 offset.hash(state);
             },
+            FunctionIR::SetSortedBy {
+                columns,
+                descending,
+            } => {
+                columns.hash(state);
+                descending.hash(state);
+            },
+            FunctionIR::SortWithinPartitions {
+                partition_by,
+                by,
+                sort_options,
+            } => {
+                partition_by.hash(state);
+                by.hash(state);
+                sort_options.hash(state);
+            },
         }
     }
 }
@@ -177,6 +250,9 @@ impl FunctionIR {
         match self {
             Rechunk | Pipeline { .. } => false,
             FastCount { .. } | Unnest { .. } | Rename { .. } | Explode { .. } => true,
+            SetSortedBy { .. } => true,
+            // The runs of the partition columns can cross batches.
+            SortWithinPartitions { .. } => false,
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
             Opaque { streamable, .. } => *streamable,
@@ -205,8 +281,9 @@ impl FunctionIR {
             OpaquePython(OpaquePythonUdf { predicate_pd, .. }) => *predicate_pd,
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
-            Rechunk | Unnest { .. } | Rename { .. } | Explode { .. } => true,
-            RowIndex { .. } | FastCount { .. } => false,
+            Rechunk | Unnest { .. } | Rename { .. } | Explode { .. } | SetSortedBy { .. } => true,
+            // Filtering rows can merge the runs of the partition columns.
+            RowIndex { .. } | FastCount { .. } | SortWithinPartitions { .. } => false,
             Pipeline { .. } => unimplemented!(),
         }
     }
@@ -220,7 +297,7 @@ impl FunctionIR {
             Rechunk | FastCount { .. } | Unnest { .. } | Rename { .. } | Explode { .. } => true,
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
-            RowIndex { .. } => true,
+            RowIndex { .. } | SetSortedBy { .. } | SortWithinPartitions { .. } => true,
             Pipeline { .. } => unimplemented!(),
         }
     }
@@ -230,6 +307,9 @@ impl FunctionIR {
         match self {
             Unnest { columns } => Cow::Borrowed(columns.as_ref()),
             Explode { columns, .. } => Cow::Borrowed(columns.as_ref()),
+            SortWithinPartitions {
+                partition_by, by, ..
+            } => Cow::Owned(partition_by.iter().chain(by.iter()).cloned().collect()),
             _ => Cow::Borrowed(&[]),
         }
     }
@@ -272,7 +352,14 @@ impl FunctionIR {
                 }
             },
             Rename { existing, new, .. } => rename::rename_impl(df, existing, new),
-            Explode { columns, .. } => df.explode(columns.iter().cloned()),
+            Explode {
+                columns, position, ..
+            } => match position {
+                Some(position) => {
+                    df.explode_with_position(columns.iter().cloned(), position.clone(), None)
+                },
+                None => df.explode(columns.iter().cloned()),
+            },
             #[cfg(feature = "pivot")]
             Unpivot { args, .. } => {
                 use polars_ops::pivot::UnpivotDF;
@@ -280,6 +367,32 @@ impl FunctionIR {
                 df.unpivot2(args)
             },
             RowIndex { name, offset, .. } => df.with_row_index(name.clone(), *offset),
+            SetSortedBy {
+                columns,
+                descending,
+            } => {
+                // Only the first column is sorted on its own.
+                if let Some(idx) = columns.first().and_then(|c| df.get_column_index(c)) {
+                    let sorted = if descending[0] {
+                        IsSorted::Descending
+                    } else {
+                        IsSorted::Ascending
+                    };
+                    // SAFETY: the flag doesn't change the length or the name.
+                    unsafe { df.get_columns_mut()[idx].set_sorted_flag(sorted) };
+                    df.clear_schema();
+                }
+                Ok(df)
+            },
+            SortWithinPartitions {
+                partition_by,
+                by,
+                sort_options,
+            } => df.sort_within_partitions(
+                partition_by.iter().cloned(),
+                by.iter().cloned(),
+                sort_options.clone(),
+            ),
         }
     }
 
@@ -313,6 +426,18 @@ impl Display for FunctionIR {
                 let columns = columns.as_ref();
                 fmt_column_delimited(f, columns, "[", "]")
             },
+            SetSortedBy { columns, .. } => {
+                write!(f, "SET SORTED BY:")?;
+                fmt_column_delimited(f, columns.as_ref(), "[", "]")
+            },
+            SortWithinPartitions {
+                partition_by, by, ..
+            } => {
+                write!(f, "SORT WITHIN PARTITIONS:")?;
+                fmt_column_delimited(f, partition_by.as_ref(), "[", "]")?;
+                write!(f, " BY:")?;
+                fmt_column_delimited(f, by.as_ref(), "[", "]")
+            },
             Pipeline { original, .. } => {
                 if let Some(original) = original {
                     let ir_display = original.as_ref().display();
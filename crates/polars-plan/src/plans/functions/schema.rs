@@ -48,7 +48,9 @@ impl FunctionIR {
                 schema.insert_at_index(0, name, IDX_DTYPE)?;
                 Ok(Cow::Owned(Arc::new(schema)))
             },
-            Rechunk => Ok(Cow::Borrowed(input_schema)),
+            Rechunk | SetSortedBy { .. } | SortWithinPartitions { .. } => {
+                Ok(Cow::Borrowed(input_schema))
+            },
             Unnest { columns: _columns } => {
                 #[cfg(feature = "dtype-struct")]
                 {
@@ -94,7 +96,11 @@ impl FunctionIR {
                 input_schema,
                 name.clone(),
             ))),
-            Explode { schema, columns } => explode_schema(schema, input_schema, columns),
+            Explode {
+                schema,
+                columns,
+                position,
+            } => explode_schema(schema, input_schema, columns, position.as_ref()),
             #[cfg(feature = "pivot")]
             Unpivot { schema, args } => unpivot_schema(args, schema, input_schema),
         }
@@ -121,6 +127,7 @@ fn explode_schema<'a>(
     cached_schema: &CachedSchema,
     schema: &'a Schema,
     columns: &[PlSmallStr],
+    position: Option<&PlSmallStr>,
 ) -> PolarsResult<Cow<'a, SchemaRef>> {
     let mut guard = cached_schema.lock().unwrap();
     if let Some(schema) = &*guard {
@@ -143,6 +150,18 @@ fn explode_schema<'a>(
 
         PolarsResult::Ok(())
     })?;
+    if let Some(position) = position {
+        polars_ensure!(
+            !schema.contains(position),
+            Duplicate: "column with name '{}' already exists", position
+        );
+        let index = columns
+            .iter()
+            .filter_map(|name| schema.index_of(name))
+            .min()
+            .unwrap_or(0);
+        schema.insert_at_index(index, position.clone(), IDX_DTYPE)?;
+    }
     let schema = Arc::new(schema);
     *guard = Some(schema.clone());
     Ok(Cow::Owned(schema))
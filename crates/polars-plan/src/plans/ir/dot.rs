@@ -316,6 +316,7 @@ impl<'a> IRDotDisplay<'a> {
                         SinkTypeIR::Memory => "SINK (MEMORY)",
                         SinkTypeIR::File { .. } => "SINK (FILE)",
                         SinkTypeIR::Partition { .. } => "SINK (PARTITION)",
+                        SinkTypeIR::Callback { .. } => "SINK (CALLBACK)",
                     })
                 })?;
             },
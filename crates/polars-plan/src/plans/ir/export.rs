@@ -0,0 +1,142 @@
+use std::fmt::Write;
+
+use polars_utils::slice_enum::Slice;
+
+use super::*;
+
+/// What was pushed down into a scan.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PlanNodePushdown {
+    /// The projected columns, `None` if all columns are read.
+    pub projection: Option<Vec<PlSmallStr>>,
+    pub predicate: Option<String>,
+    pub slice: Option<Slice>,
+}
+
+/// A node of a plan with a stable ID, see [`IRPlanRef::nodes`].
+#[derive(Clone, Debug)]
+pub struct PlanNode {
+    pub id: usize,
+    /// The kind of the node, e.g. `selection` or `parquet`, see [`IR::name`].
+    pub name: &'static str,
+    /// The node as it is shown by [`IRPlanRef::describe`], without its inputs.
+    pub description: String,
+    pub schema: SchemaRef,
+    /// The IDs of the inputs of the node.
+    pub inputs: Vec<usize>,
+    /// Only set for scans.
+    pub pushdown: Option<PlanNodePushdown>,
+}
+
+impl PlanNode {
+    fn new(ir: &IR, plan: IRPlanRef<'_>, id: usize, inputs: Vec<usize>) -> Self {
+        let schema = ir.schema(plan.lp_arena).into_owned();
+        let mut description = String::new();
+        match ir {
+            // The ID of a cache is a memory address, use the stable ID of the node instead.
+            IR::Cache { cache_hits, .. } => {
+                write!(description, "CACHE[id: {id}, cache_hits: {cache_hits}]").unwrap()
+            },
+            _ => write_ir_non_recursive(&mut description, ir, plan.expr_arena, &schema, 0).unwrap(),
+        }
+
+        let pushdown = match ir {
+            IR::Scan {
+                predicate,
+                unified_scan_args,
+                ..
+            } => Some(PlanNodePushdown {
+                projection: unified_scan_args
+                    .projection
+                    .as_ref()
+                    .map(|columns| columns.to_vec()),
+                predicate: predicate
+                    .as_ref()
+                    .map(|e| e.display(plan.expr_arena).to_string()),
+                slice: unified_scan_args.pre_slice.clone(),
+            }),
+            IR::DataFrameScan { output_schema, .. } => Some(PlanNodePushdown {
+                projection: output_schema
+                    .as_ref()
+                    .map(|schema| schema.iter_names_cloned().collect()),
+                ..Default::default()
+            }),
+            _ => None,
+        };
+
+        Self {
+            id,
+            name: ir.name(),
+            description,
+            schema,
+            inputs,
+            pushdown,
+        }
+    }
+}
+
+impl IRPlanRef<'_> {
+    /// Get the nodes of the plan, starting with the root.
+    ///
+    /// The IDs are assigned depth-first from the root, visiting the inputs of a node in order.
+    /// They only depend on the structure of the plan, so the same query gets the same IDs and
+    /// descriptions on every run. A node that is the input of several nodes is returned once.
+    pub fn nodes(self) -> Vec<PlanNode> {
+        let mut ids = PlHashMap::new();
+        let mut order = vec![];
+        let mut stack = vec![self.lp_top];
+        while let Some(node) = stack.pop() {
+            if ids.contains_key(&node) {
+                continue;
+            }
+            ids.insert(node, order.len());
+            order.push(node);
+            let inputs = self.lp_arena.get(node).get_inputs_vec();
+            stack.extend(inputs.into_iter().rev());
+        }
+
+        order
+            .into_iter()
+            .map(|node| {
+                let ir = self.lp_arena.get(node);
+                let inputs = ir.get_inputs_vec().iter().map(|n| ids[n]).collect();
+                PlanNode::new(ir, self, ids[&node], inputs)
+            })
+            .collect()
+    }
+
+    /// Get a [Mermaid](https://mermaid.js.org) flowchart of the plan, with the node IDs of
+    /// [`IRPlanRef::nodes`].
+    pub fn describe_mermaid(self) -> String {
+        let mut out = String::from("flowchart BT\n");
+        let nodes = self.nodes();
+        for node in &nodes {
+            let label = node
+                .description
+                .lines()
+                .map(str::trim)
+                .collect::<Vec<_>>()
+                .join("<br>")
+                .replace('"', "#quot;");
+            writeln!(out, "  n{}[\"{label}\"]", node.id).unwrap();
+        }
+        for node in &nodes {
+            for input in &node.inputs {
+                writeln!(out, "  n{input} --> n{}", node.id).unwrap();
+            }
+        }
+        out
+    }
+}
+
+impl IRPlan {
+    /// See [`IRPlanRef::nodes`].
+    pub fn nodes(&self) -> Vec<PlanNode> {
+        self.as_ref().nodes()
+    }
+
+    /// See [`IRPlanRef::describe_mermaid`].
+    pub fn describe_mermaid(&self) -> String {
+        self.as_ref().describe_mermaid()
+    }
+}
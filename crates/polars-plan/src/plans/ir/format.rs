@@ -482,11 +482,17 @@ impl Display for ExprIRDisplay<'_> {
                     Implode(expr) => write!(f, "{}.implode()", self.with_root(expr)),
                     NUnique(expr) => write!(f, "{}.n_unique()", self.with_root(expr)),
                     Sum(expr) => write!(f, "{}.sum()", self.with_root(expr)),
+                    Product(expr) => write!(f, "{}.product()", self.with_root(expr)),
+                    GeoMean(expr) => write!(f, "{}.geo_mean()", self.with_root(expr)),
+                    HarmonicMean(expr) => write!(f, "{}.harmonic_mean()", self.with_root(expr)),
                     AggGroups(expr) => write!(f, "{}.groups()", self.with_root(expr)),
                     Count(expr, _) => write!(f, "{}.count()", self.with_root(expr)),
                     Var(expr, _) => write!(f, "{}.var()", self.with_root(expr)),
                     Std(expr, _) => write!(f, "{}.std()", self.with_root(expr)),
                     Quantile { expr, .. } => write!(f, "{}.quantile()", self.with_root(expr)),
+                    Udf { input, function } => {
+                        write!(f, "{}.{}()", self.with_root(input), function.name())
+                    },
                 }
             },
             Cast {
@@ -890,6 +896,7 @@ pub fn write_ir_non_recursive(
                 SinkTypeIR::Memory => "SINK (memory)",
                 SinkTypeIR::File { .. } => "SINK (file)",
                 SinkTypeIR::Partition { .. } => "SINK (partition)",
+                SinkTypeIR::Callback { .. } => "SINK (callback)",
             };
             write!(f, "{:indent$}{name}", "")
         },
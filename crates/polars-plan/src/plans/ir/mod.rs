@@ -1,13 +1,16 @@
 mod dot;
+mod export;
 mod format;
 mod inputs;
 mod schema;
+mod snapshot;
 pub(crate) mod tree_format;
 
 use std::borrow::Cow;
 use std::fmt;
 
 pub use dot::{EscapeLabel, IRDotDisplay, PathsDisplay, ScanSourcesDisplay};
+pub use export::{PlanNode, PlanNodePushdown};
 pub use format::{ExprIRDisplay, IRDisplay, write_group_by, write_ir_non_recursive};
 use polars_core::prelude::*;
 use polars_utils::idx_vec::UnitVec;
@@ -39,6 +39,7 @@ impl IR {
                 SinkTypeIR::Memory => "sink (memory)",
                 SinkTypeIR::File { .. } => "sink (file)",
                 SinkTypeIR::Partition { .. } => "sink (partition)",
+                SinkTypeIR::Callback { .. } => "sink (callback)",
             },
             SinkMultiple { .. } => "sink multiple",
             SimpleProjection { .. } => "simple_projection",
@@ -0,0 +1,62 @@
+use std::path::Path;
+
+use super::*;
+
+fn normalize_path(path: &Path, root: Option<&Path>) -> String {
+    let path = root
+        .and_then(|root| path.strip_prefix(root).ok())
+        .unwrap_or(path);
+    path.display().to_string().replace('\\', "/")
+}
+
+impl IRPlanRef<'_> {
+    /// Describe the plan like [`IRPlanRef::describe`], but in a form that only depends on the
+    /// structure of the plan, for comparing against snapshots stored in files.
+    ///
+    /// Scan and cache IDs are replaced by the order in which the nodes are visited from the
+    /// root, and paths of scanned files are made relative to `root` (if given) and use `/` as
+    /// separator.
+    pub fn describe_snapshot(self, root: Option<&Path>) -> String {
+        let mut out = self.describe();
+
+        let mut scan_ids = vec![];
+        let mut cache_ids = vec![];
+        let mut paths = vec![];
+        for (_, ir) in self.lp_arena.iter(self.lp_top) {
+            match ir {
+                IR::Scan { sources, id, .. } => {
+                    let id = id.to_usize();
+                    if !scan_ids.contains(&id) {
+                        scan_ids.push(id);
+                    }
+                    paths.extend(sources.as_paths().into_iter().flatten());
+                },
+                IR::Cache { id, .. } if !cache_ids.contains(id) => cache_ids.push(*id),
+                _ => {},
+            }
+        }
+
+        for (i, id) in scan_ids.into_iter().enumerate() {
+            out = out.replace(&format!("[id: Some({id})]"), &format!("[id: {i}]"));
+        }
+        for (i, id) in cache_ids.into_iter().enumerate() {
+            out = out.replace(&format!("CACHE[id: {id:x},"), &format!("CACHE[id: {i},"));
+        }
+
+        // Replace longer paths first, so that paths that are a prefix of another path don't
+        // break the replacement of the other path.
+        paths.sort_by_key(|path| std::cmp::Reverse(path.as_os_str().len()));
+        paths.dedup();
+        for path in paths {
+            out = out.replace(&path.display().to_string(), &normalize_path(path, root));
+        }
+        out
+    }
+}
+
+impl IRPlan {
+    /// See [`IRPlanRef::describe_snapshot`].
+    pub fn describe_snapshot(&self, root: Option<&Path>) -> String {
+        self.as_ref().describe_snapshot(root)
+    }
+}
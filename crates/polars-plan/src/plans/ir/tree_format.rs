@@ -370,6 +370,7 @@ impl<'a> TreeFmtNode<'a> {
                                 SinkTypeIR::Memory => "SINK (memory)",
                                 SinkTypeIR::File { .. } => "SINK (file)",
                                 SinkTypeIR::Partition { .. } => "SINK (partition)",
+                                SinkTypeIR::Callback { .. } => "SINK (callback)",
                             },
                         ),
                         vec![self.lp_node(None, *input)],
@@ -53,9 +53,13 @@ macro_rules! push_expr {
                     Count(e, _) => $push($c, e),
                     Quantile { expr, .. } => $push($c, expr),
                     Sum(e) => $push($c, e),
+                    Product(e) => $push($c, e),
+                    GeoMean(e) => $push($c, e),
+                    HarmonicMean(e) => $push($c, e),
                     AggGroups(e) => $push($c, e),
                     Std(e, _) => $push($c, e),
                     Var(e, _) => $push($c, e),
+                    Udf { input, .. } => $push($c, input),
                 }
             },
             Ternary {
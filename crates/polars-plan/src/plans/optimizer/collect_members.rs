@@ -33,6 +33,7 @@ pub(super) struct MemberCollector {
     pub(crate) has_distinct: bool,
     pub(crate) has_sort: bool,
     pub(crate) has_group_by: bool,
+    pub(crate) has_set_sorted_by: bool,
     #[cfg(feature = "cse")]
     scans: UniqueScans,
 }
@@ -48,6 +49,7 @@ impl MemberCollector {
             has_distinct: false,
             has_sort: false,
             has_group_by: false,
+            has_set_sorted_by: false,
             #[cfg(feature = "cse")]
             scans: UniqueScans::default(),
         }
@@ -70,6 +72,12 @@ impl MemberCollector {
                 Sort { .. } => {
                     self.has_sort = true;
                 },
+                MapFunction {
+                    function: FunctionIR::SetSortedBy { .. },
+                    ..
+                } => {
+                    self.has_set_sorted_by = true;
+                },
                 Cache { .. } => self.has_cache = true,
                 ExtContext { .. } => self.has_ext_context = true,
                 #[cfg(feature = "cse")]
@@ -0,0 +1,316 @@
+//! Materialization of the expressions marked with [`Expr::cache`].
+use polars_utils::format_pl_smallstr;
+use recursive::recursive;
+
+use super::*;
+use crate::constants::CACHED_EXPR;
+use crate::prelude::visitor::AexprNode;
+
+/// The input of `node` if it is marked to be cached.
+fn cached_input(node: Node, expr_arena: &Arena<AExpr>) -> Option<Node> {
+    match expr_arena.get(node) {
+        AExpr::Function {
+            input,
+            function: FunctionExpr::Cache,
+            ..
+        } => Some(input[0].node()),
+        _ => None,
+    }
+}
+
+fn has_cached(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    expr_arena
+        .iter(node)
+        .any(|(node, _)| cached_input(node, expr_arena).is_some())
+}
+
+/// Rebuild `node` with `f` applied to its inputs, which get whether they are evaluated on the
+/// whole frame. That is the case for all inputs, except for the function of a window, which is
+/// evaluated per group.
+fn map_inputs(
+    node: Node,
+    on_frame: bool,
+    expr_arena: &mut Arena<AExpr>,
+    mut f: impl FnMut(Node, bool, &mut Arena<AExpr>) -> Node,
+) -> Node {
+    let ae = expr_arena.get(node).clone();
+    let mut inputs = vec![];
+    ae.inputs_rev(&mut inputs);
+    inputs.reverse();
+    let is_window = matches!(ae, AExpr::Window { .. });
+    let new_inputs = inputs
+        .iter()
+        .enumerate()
+        .map(|(i, input)| f(*input, on_frame && !(is_window && i == 0), expr_arena))
+        .collect::<Vec<_>>();
+    if new_inputs == inputs {
+        return node;
+    }
+
+    let mut new_ae = ae.clone().replace_inputs(&new_inputs);
+    // The names of function inputs can matter, e.g. as the fields of a struct.
+    if let (
+        AExpr::Function { input: old, .. } | AExpr::AnonymousFunction { input: old, .. },
+        AExpr::Function { input: new, .. } | AExpr::AnonymousFunction { input: new, .. },
+    ) = (&ae, &mut new_ae)
+    {
+        for (old, new) in old.iter().zip(new.iter_mut()) {
+            if old.node() != new.node() {
+                if let Some(name) = old.output_name_inner().get() {
+                    new.set_alias(name.clone());
+                }
+            }
+        }
+    }
+    expr_arena.add(new_ae)
+}
+
+/// Remove the cache markers that can't be materialized before the context: those on
+/// expressions that aren't elementwise and are evaluated per group.
+#[recursive]
+fn strip_groupwise(node: Node, on_frame: bool, expr_arena: &mut Arena<AExpr>) -> Node {
+    if let Some(input) = cached_input(node, expr_arena) {
+        if !on_frame && !is_elementwise_rec(input, expr_arena) {
+            return strip_groupwise(input, on_frame, expr_arena);
+        }
+    }
+    map_inputs(node, on_frame, expr_arena, strip_groupwise)
+}
+
+/// Replace the cached expressions that don't contain other cached expressions by a column,
+/// adding each distinct expression and the name of its column to `hoisted`.
+#[recursive]
+fn hoist_innermost(
+    node: Node,
+    expr_arena: &mut Arena<AExpr>,
+    hoisted: &mut Vec<ExprIR>,
+    count: &mut usize,
+) -> Node {
+    if let Some(input) = cached_input(node, expr_arena) {
+        if !has_cached(input, expr_arena) {
+            let existing = hoisted.iter().find(|e| {
+                AexprNode::new(e.node()).hashable_and_cmp(expr_arena)
+                    == AexprNode::new(input).hashable_and_cmp(expr_arena)
+            });
+            let name = match existing {
+                Some(e) => e.output_name().clone(),
+                None => {
+                    let name = format_pl_smallstr!("{CACHED_EXPR}{count}");
+                    *count += 1;
+                    hoisted.push(ExprIR::new(input, OutputName::Alias(name.clone())));
+                    name
+                },
+            };
+            return expr_arena.add(AExpr::Column(name));
+        }
+    }
+    map_inputs(node, true, expr_arena, |node, _, expr_arena| {
+        hoist_innermost(node, expr_arena, hoisted, count)
+    })
+}
+
+/// Set the root of `e` to the result of `f`, keeping its output name.
+fn rewrite_expr(
+    e: &mut ExprIR,
+    expr_arena: &mut Arena<AExpr>,
+    f: impl FnOnce(Node, &mut Arena<AExpr>) -> Node,
+) {
+    let node = f(e.node(), expr_arena);
+    if node != e.node() {
+        if !e.has_alias() {
+            e.set_alias(e.output_name().clone());
+        }
+        e.set_node(node);
+    }
+}
+
+/// Compute the cached expressions in `exprs` in `with_columns` before `input` is consumed,
+/// replacing them by their columns. Expressions that are nested in cached expressions are
+/// computed in an earlier `with_columns`. Returns the new input.
+fn materialize(
+    mut input: Node,
+    exprs: &mut [(&mut ExprIR, bool)],
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    count: &mut usize,
+) -> Node {
+    for (e, on_frame) in exprs.iter_mut() {
+        rewrite_expr(e, expr_arena, |node, expr_arena| {
+            strip_groupwise(node, *on_frame, expr_arena)
+        });
+    }
+    loop {
+        let mut hoisted = vec![];
+        for (e, _) in exprs.iter_mut() {
+            rewrite_expr(e, expr_arena, |node, expr_arena| {
+                hoist_innermost(node, expr_arena, &mut hoisted, count)
+            });
+        }
+        if hoisted.is_empty() {
+            return input;
+        }
+        let lp = IRBuilder::new(input, expr_arena, lp_arena)
+            .with_columns(
+                hoisted,
+                // The cached columns are temporaries that may have a different length than the
+                // frame, e.g. aggregations, like the columns of common subexpressions.
+                ProjectionOptions {
+                    should_broadcast: false,
+                    ..Default::default()
+                },
+            )
+            .build();
+        input = lp_arena.add(lp);
+    }
+}
+
+/// Compute the expressions marked with [`Expr::cache`] once, before the `select`,
+/// `with_columns`, `group_by`, `filter` or `sort` that contains them, so that all their
+/// occurrences in that context share the result.
+///
+/// Expressions that are evaluated per group, in the function of a window or in the aggregations
+/// of a group-by, are only cached if they are elementwise, as only then computing them on the
+/// whole frame gives the same result.
+pub(in crate::plans::optimizer) fn materialize_cached_exprs(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) {
+    let nodes = (&*lp_arena)
+        .iter(root)
+        .filter(|(_, ir)| match ir {
+            IR::Select { expr, .. }
+            | IR::HStack { exprs: expr, .. }
+            | IR::Sort {
+                by_column: expr, ..
+            } => expr.iter().any(|e| has_cached(e.node(), expr_arena)),
+            IR::Filter { predicate, .. } => has_cached(predicate.node(), expr_arena),
+            IR::GroupBy {
+                keys, aggs, apply, ..
+            } => {
+                apply.is_none()
+                    && keys
+                        .iter()
+                        .chain(aggs)
+                        .any(|e| has_cached(e.node(), expr_arena))
+            },
+            _ => false,
+        })
+        .map(|(node, _)| node)
+        .collect::<Vec<_>>();
+
+    let mut count = 0;
+    for node in nodes {
+        match lp_arena.take(node) {
+            IR::Select {
+                input,
+                mut expr,
+                schema,
+                options,
+            } => {
+                let mut exprs = expr.iter_mut().map(|e| (e, true)).collect::<Vec<_>>();
+                let input = materialize(input, &mut exprs, lp_arena, expr_arena, &mut count);
+                lp_arena.replace(
+                    node,
+                    IR::Select {
+                        input,
+                        expr,
+                        schema,
+                        options,
+                    },
+                );
+            },
+            IR::HStack {
+                input,
+                mut exprs,
+                schema,
+                options,
+            } => {
+                let mut hstack_exprs = exprs.iter_mut().map(|e| (e, true)).collect::<Vec<_>>();
+                let input = materialize(input, &mut hstack_exprs, lp_arena, expr_arena, &mut count);
+                // Drop the cached columns again.
+                let lp = IRBuilder::new(input, expr_arena, lp_arena)
+                    .with_columns(exprs, options)
+                    .build();
+                let input = lp_arena.add(lp);
+                lp_arena.replace(
+                    node,
+                    IR::SimpleProjection {
+                        input,
+                        columns: schema,
+                    },
+                );
+            },
+            IR::Filter {
+                input,
+                mut predicate,
+            } => {
+                let schema = lp_arena.get(input).schema(lp_arena).into_owned();
+                let mut exprs = [(&mut predicate, true)];
+                let input = materialize(input, &mut exprs, lp_arena, expr_arena, &mut count);
+                let input = lp_arena.add(IR::Filter { input, predicate });
+                // Drop the cached columns again.
+                lp_arena.replace(
+                    node,
+                    IR::SimpleProjection {
+                        input,
+                        columns: schema,
+                    },
+                );
+            },
+            IR::Sort {
+                input,
+                mut by_column,
+                slice,
+                sort_options,
+            } => {
+                let schema = lp_arena.get(input).schema(lp_arena).into_owned();
+                let mut exprs = by_column.iter_mut().map(|e| (e, true)).collect::<Vec<_>>();
+                let input = materialize(input, &mut exprs, lp_arena, expr_arena, &mut count);
+                let input = lp_arena.add(IR::Sort {
+                    input,
+                    by_column,
+                    slice,
+                    sort_options,
+                });
+                // Drop the cached columns again.
+                lp_arena.replace(
+                    node,
+                    IR::SimpleProjection {
+                        input,
+                        columns: schema,
+                    },
+                );
+            },
+            IR::GroupBy {
+                input,
+                mut keys,
+                mut aggs,
+                schema,
+                apply,
+                maintain_order,
+                options,
+            } => {
+                let mut exprs = keys
+                    .iter_mut()
+                    .map(|e| (e, true))
+                    .chain(aggs.iter_mut().map(|e| (e, false)))
+                    .collect::<Vec<_>>();
+                let input = materialize(input, &mut exprs, lp_arena, expr_arena, &mut count);
+                lp_arena.replace(
+                    node,
+                    IR::GroupBy {
+                        input,
+                        keys,
+                        aggs,
+                        schema,
+                        apply,
+                        maintain_order,
+                        options,
+                    },
+                );
+            },
+            _ => unreachable!(),
+        }
+    }
+}
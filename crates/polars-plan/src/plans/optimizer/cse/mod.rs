@@ -1,6 +1,8 @@
+mod cached_expr;
 mod cse_expr;
 mod cse_lp;
 
+pub(super) use cached_expr::materialize_cached_exprs;
 pub(super) use cse_expr::CommonSubExprOptimizer;
 pub use cse_expr::NaiveExprMerger;
 pub(super) use cse_lp::{elim_cmn_subplans, prune_unused_caches};
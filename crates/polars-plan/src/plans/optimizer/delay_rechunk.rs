@@ -56,6 +56,7 @@ impl OptimizationRule for DelayRechunk {
                         },
                         Union { options, .. } => {
                             options.rechunk = false;
+                            options.chunk_size = None;
                         },
                         _ => unreachable!(),
                     }
@@ -0,0 +1,33 @@
+use polars_utils::arena::{Arena, Node};
+
+use super::*;
+
+/// Fix the output of the nodes whose output is otherwise allowed to differ between runs:
+///
+/// - group-by and distinct maintain the order of their input,
+/// - distinct keeps the first row of every group instead of an arbitrary one,
+/// - unions concatenate their inputs in order.
+pub(super) fn make_deterministic(root: Node, lp_arena: &mut Arena<IR>) {
+    let nodes = (&*lp_arena)
+        .iter(root)
+        .map(|(node, _)| node)
+        .collect::<Vec<_>>();
+
+    for node in nodes {
+        match lp_arena.get_mut(node) {
+            IR::GroupBy {
+                maintain_order,
+                options,
+                ..
+            } if !options.is_rolling() && !options.is_dynamic() => *maintain_order = true,
+            IR::Distinct { options, .. } => {
+                options.maintain_order = true;
+                if options.keep_strategy == UniqueKeepStrategy::Any {
+                    options.keep_strategy = UniqueKeepStrategy::First;
+                }
+            },
+            IR::Union { options, .. } => options.maintain_order = true,
+            _ => {},
+        }
+    }
+}
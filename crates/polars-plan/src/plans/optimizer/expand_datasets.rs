@@ -124,6 +124,7 @@ impl OptimizationRule for ExpandDatasets {
                                 glob: _,
                                 projection: _projection @ None,
                                 row_index: _row_index @ None,
+                                row_index_per_file: _,
                                 pre_slice: _pre_slice @ None,
                                 cast_columns_policy,
                                 missing_columns_policy,
@@ -4,6 +4,8 @@ use crate::prelude::*;
 
 mod cache_states;
 mod delay_rechunk;
+mod deterministic;
+mod nan_policy;
 
 mod cluster_with_columns;
 mod collapse_and_project;
@@ -20,10 +22,13 @@ pub(crate) use join_utils::ExprOrigin;
 mod expand_datasets;
 mod predicate_pushdown;
 mod projection_pushdown;
+#[cfg(feature = "semi_anti_join")]
+mod semi_join_reduction;
 mod set_order;
 mod simplify_expr;
 mod slice_pushdown_expr;
 mod slice_pushdown_lp;
+mod sortedness;
 mod stack_opt;
 
 use collapse_and_project::SimpleProjectionAndCollapse;
@@ -41,6 +46,7 @@ pub use stack_opt::{OptimizationRule, StackOptimizer};
 
 use self::flatten_union::FlattenUnionRule;
 use self::set_order::set_order_flags;
+use self::sortedness::set_sorted_keys_flags;
 pub use crate::frame::{AllowedOptimizations, OptFlags};
 pub use crate::plans::conversion::type_coercion::TypeCoercionRule;
 use crate::plans::optimizer::count_star::CountStar;
@@ -206,6 +212,15 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
         collapse_joins::optimize(lp_top, lp_arena, expr_arena, opt_flags.new_streaming());
     }
 
+    // Must run after predicate pushdown, as it looks for the filtered dimensions.
+    #[cfg(feature = "semi_anti_join")]
+    if opt_flags.semi_join_reduction() {
+        let members = get_or_init_members!();
+        if members.has_group_by && members.has_joins_or_unions {
+            members.has_cache |= semi_join_reduction::optimize(lp_top, lp_arena, expr_arena);
+        }
+    }
+
     // Make sure its before slice pushdown.
     if opt_flags.fast_projection() {
         rules.push(Box::new(SimpleProjectionAndCollapse::new(
@@ -255,6 +270,23 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
         cluster_with_columns::optimize(lp_top, lp_arena, expr_arena)
     }
 
+    // Must run after `set_order_flags`, which unsets `maintain_order` where it isn't observed.
+    if opt_flags.deterministic() {
+        deterministic::make_deterministic(lp_top, lp_arena);
+    }
+
+    if let Some(policy) = opt_flags.nan_policy() {
+        nan_policy::apply_nan_policy(lp_top, lp_arena, expr_arena, policy)?;
+    }
+
+    // Must run after `set_order_flags`.
+    let members = get_or_init_members!();
+    if (members.has_set_sorted_by || members.has_sort)
+        && (members.has_group_by || members.has_distinct)
+    {
+        set_sorted_keys_flags(lp_top, lp_arena, expr_arena);
+    }
+
     if _cse_plan_changed
         && get_members_opt!()
             .is_some_and(|members| members.has_joins_or_unions && members.has_cache)
@@ -272,6 +304,13 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
         )?;
     }
 
+    // Like the elimination of common subexpressions, this modifies the projections. It also runs
+    // without that optimization, as the expressions to cache are explicitly marked.
+    #[cfg(feature = "cse")]
+    if !get_or_init_members!().has_ext_context {
+        cse::materialize_cached_exprs(lp_top, lp_arena, expr_arena);
+    }
+
     // This one should run (nearly) last as this modifies the projections
     #[cfg(feature = "cse")]
     if comm_subexpr_elim && !get_or_init_members!().has_ext_context {
@@ -0,0 +1,137 @@
+use polars_utils::arena::{Arena, Node};
+
+use super::*;
+
+/// Rewrite the `min`, `max`, `mean`, `sum` and `count` aggregations of floats in the plan to
+/// treat `NaN` according to `policy`:
+///
+/// - [`NanPolicy::Ignore`] drops `NaN` before aggregating, so that it is skipped and not counted
+///   at all,
+/// - [`NanPolicy::AsNull`] turns `NaN` into null before aggregating, so that it is skipped but
+///   counted as null, e.g. by `len`,
+/// - [`NanPolicy::Propagate`] makes `min` and `max` return `NaN` if a group contains one, as
+///   `mean` and `sum` already do.
+///
+/// Under both [`NanPolicy::Ignore`] and [`NanPolicy::AsNull`] a group of only `NaN` aggregates to
+/// null.
+pub(super) fn apply_nan_policy(
+    root: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    policy: NanPolicy,
+) -> PolarsResult<()> {
+    let mut exprs = vec![];
+    let mut aggs = PlHashSet::new();
+
+    for (_, ir) in lp_arena.iter(root) {
+        let Some(schema) = ir.input_schema(lp_arena) else {
+            continue;
+        };
+        exprs.clear();
+        ir.copy_exprs(&mut exprs);
+
+        for e in &exprs {
+            let nodes = (&*expr_arena)
+                .iter(e.node())
+                .filter_map(|(node, ae)| {
+                    let AExpr::Agg(agg) = ae else { return None };
+                    let input = match agg {
+                        IRAggExpr::Min { input, .. }
+                        | IRAggExpr::Max { input, .. }
+                        | IRAggExpr::Mean(input)
+                        | IRAggExpr::Sum(input)
+                        | IRAggExpr::Count(input, _) => *input,
+                        _ => return None,
+                    };
+                    (!aggs.contains(&node)).then_some((node, input))
+                })
+                .collect::<Vec<_>>();
+
+            for (node, input) in nodes {
+                let dtype =
+                    expr_arena
+                        .get(input)
+                        .to_dtype(&schema, Context::Default, expr_arena)?;
+                if dtype.is_float() {
+                    rewrite_agg(node, input, dtype, expr_arena, policy);
+                }
+                aggs.insert(node);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn rewrite_agg(
+    node: Node,
+    input: Node,
+    dtype: DataType,
+    expr_arena: &mut Arena<AExpr>,
+    policy: NanPolicy,
+) {
+    let input = match policy {
+        NanPolicy::Ignore => drop_nan(input, expr_arena),
+        NanPolicy::AsNull => nan_to_null(input, dtype, expr_arena),
+        NanPolicy::Propagate => input,
+    };
+    let propagate = policy == NanPolicy::Propagate;
+
+    let AExpr::Agg(agg) = expr_arena.get_mut(node) else {
+        unreachable!()
+    };
+    match agg {
+        IRAggExpr::Min {
+            input: agg_input,
+            propagate_nans,
+        }
+        | IRAggExpr::Max {
+            input: agg_input,
+            propagate_nans,
+        } => {
+            *agg_input = input;
+            *propagate_nans = propagate;
+        },
+        IRAggExpr::Mean(agg_input) | IRAggExpr::Sum(agg_input) | IRAggExpr::Count(agg_input, _) => {
+            *agg_input = input
+        },
+        _ => unreachable!(),
+    }
+}
+
+/// `input.filter(input.is_not_nan() | input.is_null())`
+fn drop_nan(input: Node, expr_arena: &mut Arena<AExpr>) -> Node {
+    let mut boolean = |function| {
+        let function = FunctionExpr::Boolean(function);
+        let options = function.function_options();
+        expr_arena.add(AExpr::Function {
+            input: vec![ExprIR::from_node(input, expr_arena)],
+            function,
+            options,
+        })
+    };
+    let left = boolean(BooleanFunction::IsNotNan);
+    let right = boolean(BooleanFunction::IsNull);
+    let by = expr_arena.add(AExpr::BinaryExpr {
+        left,
+        op: Operator::Or,
+        right,
+    });
+    expr_arena.add(AExpr::Filter { input, by })
+}
+
+/// `when(input.is_nan()).then(null).otherwise(input)`
+fn nan_to_null(input: Node, dtype: DataType, expr_arena: &mut Arena<AExpr>) -> Node {
+    let function = FunctionExpr::Boolean(BooleanFunction::IsNan);
+    let options = function.function_options();
+    let predicate = expr_arena.add(AExpr::Function {
+        input: vec![ExprIR::from_node(input, expr_arena)],
+        function,
+        options,
+    });
+    let truthy = expr_arena.add(AExpr::Literal(LiteralValue::Scalar(Scalar::null(dtype))));
+    expr_arena.add(AExpr::Ternary {
+        predicate,
+        truthy,
+        falsy: input,
+    })
+}
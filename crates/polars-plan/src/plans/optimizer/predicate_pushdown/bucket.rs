@@ -0,0 +1,189 @@
+use polars_core::prelude::*;
+use polars_io::SerReader;
+use polars_io::cloud::CloudOptions;
+use polars_io::parquet::metadata::FileMetadataRef;
+use polars_io::parquet::read::{FileMetadata, ParquetReader};
+
+use super::keys::predicate_to_key;
+use crate::dsl::functions::{
+    BUCKET_HASH, BUCKET_HASH_VERSION, BUCKETING_METADATA_KEY, bucket_of_row,
+};
+use crate::plans::hive::HivePartitionsDf;
+use crate::prelude::*;
+
+/// The column to which `sink_parquet_bucketed` writes the bucket of a row.
+const BUCKET_COLUMN: &str = "bucket";
+
+/// The key columns and number of buckets of a dataset written with `sink_parquet_bucketed`.
+#[derive(PartialEq)]
+struct BucketingScheme {
+    by: Vec<PlSmallStr>,
+    num_buckets: u32,
+}
+
+/// The bucketing scheme recorded in the metadata of a file, if it was bucketed with the hash
+/// that [`bucket_of_row`] computes.
+fn bucketing_scheme(metadata: &FileMetadata) -> Option<BucketingScheme> {
+    let value = metadata
+        .key_value_metadata()
+        .as_ref()?
+        .iter()
+        .find(|kv| kv.key == BUCKETING_METADATA_KEY)?
+        .value
+        .as_deref()?;
+    let value: serde_json::Value = serde_json::from_str(value).ok()?;
+    if value.get("hash")?.as_str()? != BUCKET_HASH
+        || value.get("hash_version")?.as_u64()? != BUCKET_HASH_VERSION as u64
+    {
+        return None;
+    }
+    let by = value
+        .get("by")?
+        .as_array()?
+        .iter()
+        .map(|name| name.as_str().map(PlSmallStr::from_str))
+        .collect::<Option<Vec<_>>>()?;
+    let num_buckets = u32::try_from(value.get("num_buckets")?.as_u64()?).ok()?;
+    (!by.is_empty() && num_buckets > 0).then_some(BucketingScheme { by, num_buckets })
+}
+
+/// The metadata of all but the first file of `sources`; that of the first file is already part
+/// of the scan.
+fn remaining_file_metadata(
+    sources: &ScanSources,
+    #[allow(unused)] cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<Vec<FileMetadataRef>> {
+    if sources.is_cloud_url() {
+        #[cfg(feature = "cloud")]
+        {
+            use polars_io::pl_async::get_runtime;
+            use polars_io::prelude::ParquetObjectStore;
+
+            let paths = &sources.as_paths().unwrap()[1..];
+            get_runtime().block_in_place_on(futures::future::try_join_all(paths.iter().map(
+                |path| async move {
+                    let mut reader =
+                        ParquetObjectStore::from_uri(&path.to_string_lossy(), cloud_options, None)
+                            .await?;
+                    reader.get_metadata().await.cloned()
+                },
+            )))
+        }
+        #[cfg(not(feature = "cloud"))]
+        polars_bail!(ComputeError: "'cloud' feature is not enabled")
+    } else {
+        sources
+            .iter()
+            .skip(1)
+            .map(|source| {
+                ParquetReader::new(std::io::Cursor::new(source.to_memslice()?))
+                    .get_metadata()
+                    .cloned()
+            })
+            .collect()
+    }
+}
+
+/// The bucketing scheme of a scan, if every file of it was bucketed with the same scheme.
+fn common_bucketing_scheme(
+    sources: &ScanSources,
+    scan_type: &FileScan,
+    cloud_options: Option<&CloudOptions>,
+) -> Option<BucketingScheme> {
+    let FileScan::Parquet {
+        metadata: Some(metadata),
+        ..
+    } = scan_type
+    else {
+        return None;
+    };
+    let scheme = bucketing_scheme(metadata)?;
+    let all_equal = remaining_file_metadata(sources, cloud_options)
+        .ok()?
+        .iter()
+        .all(|metadata| bucketing_scheme(metadata).as_ref() == Some(&scheme));
+    all_equal.then_some(scheme)
+}
+
+/// The value to which a predicate fixes a column, if it is `col == lit` or `lit == col`.
+fn fixed_value<'a>(node: Node, expr_arena: &'a Arena<AExpr>) -> Option<(&'a str, AnyValue<'a>)> {
+    let AExpr::BinaryExpr {
+        left,
+        op: Operator::Eq,
+        right,
+    } = expr_arena.get(node)
+    else {
+        return None;
+    };
+    match (expr_arena.get(*left), expr_arena.get(*right)) {
+        (AExpr::Column(name), AExpr::Literal(lv)) | (AExpr::Literal(lv), AExpr::Column(name)) => {
+            Some((name.as_str(), lv.to_any_value()?))
+        },
+        _ => None,
+    }
+}
+
+/// Add a predicate on the bucket of a scan of a dataset written with `sink_parquet_bucketed`
+/// if the predicates fix all key columns, so that the other buckets are skipped by the
+/// pruning of hive partitions.
+///
+/// This reads the metadata of every file, which is only done once the predicates fix the key
+/// columns: a single file that was not bucketed with the same scheme disables the pruning.
+pub(super) fn add_bucket_predicate(
+    sources: &ScanSources,
+    scan_type: &FileScan,
+    cloud_options: Option<&CloudOptions>,
+    hive_parts: Option<&HivePartitionsDf>,
+    schema: &Schema,
+    acc_predicates: &mut PlHashMap<PlSmallStr, ExprIR>,
+    expr_arena: &mut Arena<AExpr>,
+) {
+    let Some(bucket_dtype) = hive_parts.and_then(|hive| hive.schema().get(BUCKET_COLUMN)) else {
+        return;
+    };
+    let mut fixed = PlHashMap::new();
+    for predicate in acc_predicates.values() {
+        if let Some((name, value)) = fixed_value(predicate.node(), expr_arena) {
+            fixed.insert(name, value);
+        }
+    }
+    if fixed.is_empty() {
+        return;
+    }
+    let Some(scheme) = common_bucketing_scheme(sources, scan_type, cloud_options) else {
+        return;
+    };
+    let Some(keys) = scheme
+        .by
+        .iter()
+        .map(|name| {
+            let value = fixed.get(name.as_str())?;
+            let dtype = schema.get(name)?;
+            let value = Scalar::new(dtype.clone(), value.strict_cast(dtype)?.into_static());
+            Some(value.into_column(name.clone()))
+        })
+        .collect::<Option<Vec<_>>>()
+    else {
+        return;
+    };
+    let Ok(bucket) = bucket_of_row(&keys, scheme.num_buckets) else {
+        return;
+    };
+    let Some(bucket) = AnyValue::UInt32(bucket).strict_cast(bucket_dtype) else {
+        return;
+    };
+
+    let left = expr_arena.add(AExpr::Column(PlSmallStr::from_static(BUCKET_COLUMN)));
+    let right = expr_arena.add(AExpr::Literal(LiteralValue::Scalar(Scalar::new(
+        bucket_dtype.clone(),
+        bucket.into_static(),
+    ))));
+    let node = expr_arena.add(AExpr::BinaryExpr {
+        left,
+        op: Operator::Eq,
+        right,
+    });
+    let predicate = ExprIR::from_node(node, expr_arena);
+    let key = predicate_to_key(node, expr_arena);
+    acc_predicates.entry(key).or_insert(predicate);
+}
@@ -33,7 +33,9 @@ fn should_block_join_specific(
         Function {
             function:
                 FunctionExpr::Boolean(BooleanFunction::IsUnique)
-                | FunctionExpr::Boolean(BooleanFunction::IsDuplicated),
+                | FunctionExpr::Boolean(BooleanFunction::IsDuplicated)
+                | FunctionExpr::Boolean(BooleanFunction::IsDuplicatedBy { .. })
+                | FunctionExpr::OccurrenceCountBy,
             ..
         } => LeftRight(true, true),
         #[cfg(feature = "is_first_distinct")]
@@ -1,3 +1,5 @@
+#[cfg(all(feature = "parquet", feature = "row_hash", feature = "dtype-struct"))]
+mod bucket;
 mod group_by;
 mod join;
 mod keys;
@@ -393,6 +395,16 @@ impl PredicatePushDown<'_> {
                         blocked_names.contains(&name.as_ref())
                     })
                 };
+                #[cfg(all(feature = "parquet", feature = "row_hash", feature = "dtype-struct"))]
+                bucket::add_bucket_predicate(
+                    &sources,
+                    &scan_type,
+                    unified_scan_args.cloud_options.as_ref(),
+                    scan_hive_parts.as_ref(),
+                    &file_info.schema,
+                    &mut acc_predicates,
+                    expr_arena,
+                );
                 let predicate = predicate_at_scan(acc_predicates, predicate.clone(), expr_arena);
 
                 let mut do_optimization = match &*scan_type {
@@ -506,8 +518,12 @@ impl PredicatePushDown<'_> {
                                 false,
                             )
                         },
-                        FunctionIR::Explode { columns, .. } => {
-                            let condition = |name: &PlSmallStr| columns.iter().any(|s| s == name);
+                        FunctionIR::Explode {
+                            columns, position, ..
+                        } => {
+                            let condition = |name: &PlSmallStr| {
+                                columns.iter().any(|s| s == name) || position.as_ref() == Some(name)
+                            };
 
                             // first columns that refer to the exploded columns should be done here
                             let local_predicates = transfer_to_local_by_name(
@@ -41,13 +41,21 @@ pub(super) fn process_functions(
             let lp = IR::MapFunction { input, function };
             Ok(lp)
         },
-        Explode { columns, .. } => {
+        Explode {
+            columns, position, ..
+        } => {
+            if let Some(position) = &position {
+                // The position column is created by the explode.
+                ctx.acc_projections
+                    .retain(|c| column_node_to_name(*c, expr_arena) != position);
+                ctx.projected_names.remove(position);
+            }
             columns
                 .iter()
                 .for_each(|name| add_str_to_accumulated(name.clone(), &mut ctx, expr_arena));
             proj_pd.pushdown_and_assign(input, ctx, lp_arena, expr_arena)?;
             Ok(IRBuilder::new(input, expr_arena, lp_arena)
-                .explode(columns.clone())
+                .explode(columns.clone(), position)
                 .build())
         },
         #[cfg(feature = "pivot")]
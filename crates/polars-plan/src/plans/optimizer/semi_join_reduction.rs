@@ -0,0 +1,160 @@
+//! Optimization that reduces the fact table of an aggregation over a star-schema join with the
+//! filtered dimensions, before it is joined with the other dimensions.
+//!
+//! For example, in `fact.join(d1, on='a').join(d2.filter(..), on='b').group_by(..)`, the fact
+//! table is semi-joined with the filtered `d2` on `b` before it is joined with `d1`. The filtered
+//! dimension is cached, so that it is only computed once.
+
+use std::sync::Arc;
+
+use polars_core::prelude::PlHashSet;
+use polars_ops::frame::{JoinArgs, JoinType};
+use polars_utils::arena::{Arena, Node};
+
+use super::{AExpr, IR, JoinOptions};
+use crate::plans::ArenaLpIter;
+
+/// Whether a dimension only has a subset of its rows.
+fn is_filtered(mut node: Node, lp_arena: &Arena<IR>) -> bool {
+    loop {
+        match lp_arena.get(node) {
+            IR::Filter { .. } => return true,
+            IR::Scan { predicate, .. } => return predicate.is_some(),
+            IR::Select { input, .. }
+            | IR::HStack { input, .. }
+            | IR::SimpleProjection { input, .. }
+            | IR::Cache { input, .. } => node = *input,
+            _ => return false,
+        }
+    }
+}
+
+fn is_inner_equi_join(ir: &IR) -> bool {
+    matches!(ir, IR::Join { options, .. }
+        if options.args.how == JoinType::Inner && options.options.is_none())
+}
+
+/// The inner join that an aggregation is computed over, skipping projections and filters.
+fn aggregated_join(group_by_input: Node, lp_arena: &Arena<IR>) -> Option<Node> {
+    let mut node = group_by_input;
+    loop {
+        match lp_arena.get(node) {
+            IR::Select { input, .. }
+            | IR::HStack { input, .. }
+            | IR::SimpleProjection { input, .. }
+            | IR::Filter { input, .. } => node = *input,
+            ir if is_inner_equi_join(ir) => return Some(node),
+            _ => return None,
+        }
+    }
+}
+
+fn next_cache_id(lp_arena: &Arena<IR>) -> usize {
+    (0..lp_arena.len())
+        .filter_map(|i| match lp_arena.get(Node(i)) {
+            IR::Cache { id, .. } => Some(id.saturating_add(1)),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns whether the plan was changed.
+pub fn optimize(root: Node, lp_arena: &mut Arena<IR>, expr_arena: &Arena<AExpr>) -> bool {
+    let mut group_by_inputs = Vec::new();
+    for (_, ir) in (&*lp_arena).iter(root) {
+        if let IR::GroupBy { input, .. } = ir {
+            group_by_inputs.push(*input);
+        }
+    }
+
+    let mut cache_id = next_cache_id(lp_arena);
+    let mut visited_joins = PlHashSet::new();
+    let mut changed = false;
+    for input in group_by_inputs {
+        let Some(top_join) = aggregated_join(input, lp_arena) else {
+            continue;
+        };
+
+        // The joins of a left-deep join tree over the fact table, from the top down.
+        let mut joins = Vec::new();
+        let mut fact = top_join;
+        while is_inner_equi_join(lp_arena.get(fact)) {
+            joins.push(fact);
+            let IR::Join { input_left, .. } = lp_arena.get(fact) else {
+                unreachable!()
+            };
+            fact = *input_left;
+        }
+        // Aggregations can share (a part of) a join tree, which must only be reduced once.
+        if !joins.iter().all(|join| visited_joins.insert(*join)) {
+            continue;
+        }
+        // The join of the fact table with its first dimension already reduces it.
+        let Some((&bottom_join, reducing_joins)) = joins.split_last() else {
+            continue;
+        };
+
+        let fact_schema = lp_arena.get(fact).schema(lp_arena).into_owned();
+        let mut reduced_fact = fact;
+        for &join in reducing_joins.iter().rev() {
+            let IR::Join {
+                input_right,
+                left_on,
+                right_on,
+                options,
+                ..
+            } = lp_arena.get(join)
+            else {
+                unreachable!()
+            };
+            let keys_from_fact = left_on.iter().all(|e| {
+                matches!(expr_arena.get(e.node()), AExpr::Column(name) if fact_schema.contains(name))
+            });
+            if !keys_from_fact || !is_filtered(*input_right, lp_arena) {
+                continue;
+            }
+
+            let dimension = *input_right;
+            let left_on = left_on.clone();
+            let right_on = right_on.clone();
+            let mut args = JoinArgs::new(JoinType::Semi);
+            args.nulls_equal = options.args.nulls_equal;
+            args.nulls_equal_per_key = options.args.nulls_equal_per_key.clone();
+
+            let cache = IR::Cache {
+                input: dimension,
+                id: cache_id,
+                cache_hits: 1,
+            };
+            cache_id += 1;
+            let semi_join_dimension = lp_arena.add(cache.clone());
+            let join_dimension = lp_arena.add(cache);
+            let IR::Join { input_right, .. } = lp_arena.get_mut(join) else {
+                unreachable!()
+            };
+            *input_right = join_dimension;
+
+            reduced_fact = lp_arena.add(IR::Join {
+                input_left: reduced_fact,
+                input_right: semi_join_dimension,
+                schema: fact_schema.clone(),
+                left_on,
+                right_on,
+                options: Arc::new(JoinOptions {
+                    args,
+                    ..Default::default()
+                }),
+            });
+        }
+
+        if reduced_fact != fact {
+            let IR::Join { input_left, .. } = lp_arena.get_mut(bottom_join) else {
+                unreachable!()
+            };
+            *input_left = reduced_fact;
+            changed = true;
+        }
+    }
+    changed
+}
@@ -16,10 +16,14 @@ fn is_order_dependent_top_level(ae: &AExpr, ctx: Context) -> bool {
             IRAggExpr::Implode(_) => true,
             IRAggExpr::Quantile { .. } => false,
             IRAggExpr::Sum(_) => false,
+            IRAggExpr::Product(_) => false,
+            IRAggExpr::GeoMean(_) => false,
+            IRAggExpr::HarmonicMean(_) => false,
             IRAggExpr::Count(_, _) => false,
             IRAggExpr::Std(_, _) => false,
             IRAggExpr::Var(_, _) => false,
             IRAggExpr::AggGroups(_) => true,
+            IRAggExpr::Udf { .. } => false,
         },
         AExpr::Column(_) => matches!(ctx, Context::Aggregation),
         _ => true,
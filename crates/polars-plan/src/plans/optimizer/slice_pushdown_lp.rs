@@ -241,8 +241,9 @@ impl SlicePushDown {
                 #[cfg(feature = "python")]
                 FileScan::PythonDataset { .. } => true,
 
-                // TODO: This can be `true` after Anonymous scan dispatches to new-streaming.
-                FileScan::Anonymous { .. } => state.offset == 0,
+                FileScan::Anonymous { function, .. } => {
+                    state.offset == 0 || function.allows_offset_pushdown()
+                },
             }  =>  {
                 unified_scan_args.pre_slice = Some(state.to_slice_enum());
 
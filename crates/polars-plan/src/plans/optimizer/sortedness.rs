@@ -0,0 +1,210 @@
+use polars_utils::arena::{Arena, Node};
+use recursive::recursive;
+
+use super::*;
+
+/// The sorted columns, together with whether they are sorted in descending order.
+type SortedBy = Option<Arc<[(PlSmallStr, bool)]>>;
+
+/// The longest prefix of `sorted` of which all columns pass `keep`.
+fn prefix(sorted: SortedBy, keep: impl Fn(&PlSmallStr) -> bool) -> SortedBy {
+    let sorted = sorted?;
+    let len = sorted.iter().take_while(|(name, _)| keep(name)).count();
+    (len > 0).then(|| sorted[..len].into())
+}
+
+fn column_name<'a>(e: &'a ExprIR, expr_arena: &'a Arena<AExpr>) -> Option<&'a PlSmallStr> {
+    match expr_arena.get(e.node()) {
+        AExpr::Column(name) if e.output_name() == name => Some(name),
+        _ => None,
+    }
+}
+
+/// The columns that the output of `node` is known to be sorted by, lexicographically.
+#[recursive]
+fn sorted_by(
+    node: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    cache: &mut PlHashMap<Node, SortedBy>,
+) -> SortedBy {
+    if let Some(sorted) = cache.get(&node) {
+        return sorted.clone();
+    }
+
+    let mut input_sorted = |input: &Node| sorted_by(*input, lp_arena, expr_arena, cache);
+    let sorted = match lp_arena.get(node) {
+        IR::MapFunction {
+            function:
+                FunctionIR::SetSortedBy {
+                    columns,
+                    descending,
+                },
+            ..
+        } => Some(
+            columns
+                .iter()
+                .cloned()
+                .zip(descending.iter().copied())
+                .collect(),
+        ),
+        IR::MapFunction {
+            input,
+            function: FunctionIR::Rechunk,
+        }
+        | IR::Filter { input, .. }
+        | IR::Slice { input, .. }
+        | IR::Cache { input, .. } => input_sorted(input),
+        IR::Sort {
+            by_column,
+            sort_options,
+            ..
+        } => by_column
+            .iter()
+            .enumerate()
+            .map(|(i, e)| {
+                let descending = match sort_options.descending.as_slice() {
+                    [descending] => *descending,
+                    descending => descending[i],
+                };
+                Some((column_name(e, expr_arena)?.clone(), descending))
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(Into::into),
+        IR::SimpleProjection { input, columns } => {
+            prefix(input_sorted(input), |name| columns.contains(name))
+        },
+        IR::Select { input, expr, .. } => prefix(input_sorted(input), |name| {
+            expr.iter()
+                .any(|e| column_name(e, expr_arena) == Some(name))
+        }),
+        IR::HStack { input, exprs, .. } => prefix(input_sorted(input), |name| {
+            exprs.iter().all(|e| e.output_name() != name)
+        }),
+        _ => None,
+    };
+    cache.insert(node, sorted.clone());
+    sorted
+}
+
+/// Whether equal values of `keys` are contiguous in data sorted by `sorted`.
+fn keys_are_sorted(sorted: &[(PlSmallStr, bool)], keys: &[&PlSmallStr]) -> bool {
+    !keys.is_empty()
+        && keys.len() <= sorted.len()
+        && sorted[..keys.len()]
+            .iter()
+            .all(|(name, _)| keys.contains(&name))
+}
+
+/// Whether data sorted by `sorted` is sorted ascending by `on` within every group of `by`,
+/// that is, whether `on` is the first sorted column or directly follows the `by` columns.
+#[cfg(feature = "asof_join")]
+fn sorted_within_groups(sorted: &[(PlSmallStr, bool)], by: &[PlSmallStr], on: &PlSmallStr) -> bool {
+    let is_on = |i: usize| {
+        sorted
+            .get(i)
+            .is_some_and(|(name, descending)| name == on && !descending)
+    };
+    is_on(0) || (is_on(by.len()) && keys_are_sorted(sorted, &by.iter().collect::<Vec<_>>()))
+}
+
+/// Use the declared and known sortedness of the inputs of group-bys and distincts to group by
+/// runs of equal keys instead of hashing, and of asof joins to skip their sortedness check,
+/// which can't be done at all if there are `by` groups.
+///
+/// This must run after `set_order_flags`, as that may remove sorts.
+pub(super) fn set_sorted_keys_flags(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) {
+    let nodes = (&*lp_arena)
+        .iter(root)
+        .filter(|(_, ir)| match ir {
+            IR::GroupBy { .. } | IR::Distinct { .. } => true,
+            #[cfg(feature = "asof_join")]
+            IR::Join { options, .. } => matches!(options.args.how, JoinType::AsOf(_)),
+            _ => false,
+        })
+        .map(|(node, _)| node)
+        .collect::<Vec<_>>();
+
+    let mut cache = PlHashMap::default();
+    for node in nodes {
+        let input = lp_arena.get(node).get_inputs()[0];
+        let Some(sorted) = sorted_by(input, lp_arena, expr_arena, &mut cache) else {
+            continue;
+        };
+        let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
+        #[cfg(feature = "asof_join")]
+        let right_sorted = match lp_arena.get(node) {
+            IR::Join { input_right, .. } => {
+                sorted_by(*input_right, lp_arena, expr_arena, &mut cache)
+            },
+            _ => None,
+        };
+
+        match lp_arena.get_mut(node) {
+            IR::GroupBy {
+                keys,
+                options,
+                apply,
+                ..
+            } => {
+                if apply.is_some() || options.is_rolling() || options.is_dynamic() {
+                    continue;
+                }
+                let Some(keys) = keys
+                    .iter()
+                    .map(|e| match expr_arena.get(e.node()) {
+                        AExpr::Column(name) => Some(name),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>()
+                else {
+                    continue;
+                };
+                if keys_are_sorted(&sorted, &keys) {
+                    Arc::make_mut(options).keys_sorted = true;
+                }
+            },
+            IR::Distinct { options, .. } => {
+                let subset = match &options.subset {
+                    Some(subset) => subset.iter().collect::<Vec<_>>(),
+                    None => input_schema.iter_names().collect(),
+                };
+                options.keys_sorted = keys_are_sorted(&sorted, &subset);
+            },
+            #[cfg(feature = "asof_join")]
+            IR::Join {
+                left_on,
+                right_on,
+                options,
+                ..
+            } => {
+                let (Some(left_on), Some(right_on)) = (
+                    column_name(&left_on[0], expr_arena).cloned(),
+                    column_name(&right_on[0], expr_arena).cloned(),
+                ) else {
+                    continue;
+                };
+                let JoinType::AsOf(asof_options) = &options.args.how else {
+                    unreachable!()
+                };
+                let left_by = asof_options.left_by.as_deref().unwrap_or_default();
+                let right_by = asof_options.right_by.as_deref().unwrap_or_default();
+                let sorted = sorted_within_groups(&sorted, left_by, &left_on)
+                    && right_sorted.is_some_and(|right_sorted| {
+                        sorted_within_groups(&right_sorted, right_by, &right_on)
+                    });
+                if sorted {
+                    let JoinType::AsOf(asof_options) = &mut Arc::make_mut(options).args.how else {
+                        unreachable!()
+                    };
+                    asof_options.check_sortedness = false;
+                }
+            },
+            _ => unreachable!(),
+        }
+    }
+}
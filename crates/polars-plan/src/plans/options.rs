@@ -20,6 +20,8 @@ pub struct DistinctOptionsIR {
     pub keep_strategy: UniqueKeepStrategy,
     /// Take only a slice of the result
     pub slice: Option<(i64, usize)>,
+    /// Equal rows of the subset are contiguous in the input. This is set by the optimizer.
+    pub keys_sorted: bool,
 }
 
 // a boolean that can only be set to `false` safely
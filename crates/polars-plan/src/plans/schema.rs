@@ -332,6 +332,15 @@ pub(crate) fn det_join_schema(
 
             let mut _asof_pre_added_rhs_keys: PlHashSet<PlSmallStr> = PlHashSet::new();
 
+            // The right columns of bracket asof joins hold both matches in a struct.
+            let right_dtype = |dtype: DataType| match &options.args.how {
+                #[cfg(feature = "asof_join")]
+                JoinType::AsOf(asof_options) if asof_options.strategy == AsofStrategy::Bracket => {
+                    asof_bracket_dtype(&dtype)
+                },
+                _ => dtype,
+            };
+
             // Handles coalescing of asof-joins.
             // Asof joins are not equi-joins
             // so the columns that are joined on, may have different
@@ -348,10 +357,11 @@ pub(crate) fn det_join_schema(
                         if schema_left.contains(&field_right.name) {
                             new_schema.with_column(
                                 _join_suffix_name(&field_right.name, options.args.suffix()),
-                                field_right.dtype,
+                                right_dtype(field_right.dtype),
                             );
                         } else {
-                            new_schema.with_column(field_right.name, field_right.dtype);
+                            new_schema
+                                .with_column(field_right.name, right_dtype(field_right.dtype));
                         }
                     }
                 }
@@ -399,13 +409,15 @@ pub(crate) fn det_join_schema(
                     (name.clone(), dtype.clone())
                 };
 
-                new_schema.try_insert(name, dtype).map_err(|e| {
-                    if let Some(column) = suffixed {
-                        join_suffix_duplicate_help_msg(&column)
-                    } else {
-                        e
-                    }
-                })?;
+                new_schema
+                    .try_insert(name, right_dtype(dtype))
+                    .map_err(|e| {
+                        if let Some(column) = suffixed {
+                            join_suffix_duplicate_help_msg(&column)
+                        } else {
+                            e
+                        }
+                    })?;
             }
 
             Ok(Arc::new(new_schema))
@@ -69,9 +69,13 @@ impl TreeWalker for Expr {
                 Count(x, nulls) => Count(am(x, f)?, nulls),
                 Quantile { expr, quantile, method: interpol } => Quantile { expr: am(expr, &mut f)?, quantile: am(quantile, f)?, method: interpol },
                 Sum(x) => Sum(am(x, f)?),
+                Product(x) => Product(am(x, f)?),
+                GeoMean(x) => GeoMean(am(x, f)?),
+                HarmonicMean(x) => HarmonicMean(am(x, f)?),
                 AggGroups(x) => AggGroups(am(x, f)?),
                 Std(x, ddf) => Std(am(x, f)?, ddf),
                 Var(x, ddf) => Var(am(x, f)?, ddf),
+                Udf { input, function } => Udf { input: am(input, f)?, function },
             }),
             Ternary { predicate, truthy, falsy } => Ternary { predicate: am(predicate, &mut f)?, truthy: am(truthy, &mut f)?, falsy: am(falsy, f)? },
             Function { input, function, options } => Function { input: input.into_iter().map(f).collect::<Result<_, _>>()?, function, options },
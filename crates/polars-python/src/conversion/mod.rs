@@ -744,9 +744,10 @@ impl<'py> FromPyObject<'py> for Wrap<AsofStrategy> {
             "backward" => AsofStrategy::Backward,
             "forward" => AsofStrategy::Forward,
             "nearest" => AsofStrategy::Nearest,
+            "bracket" => AsofStrategy::Bracket,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "asof `strategy` must be one of {{'backward', 'forward', 'nearest'}}, got {v}",
+                    "asof `strategy` must be one of {{'backward', 'forward', 'nearest', 'bracket'}}, got {v}",
                 )));
             },
         };
@@ -823,6 +824,21 @@ impl<'py> FromPyObject<'py> for Wrap<StartBy> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<MonthEndPolicy> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "clamp" => MonthEndPolicy::Clamp,
+            "month_end" => MonthEndPolicy::MonthEnd,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`month_end_policy` must be one of {{'clamp', 'month_end'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<ClosedWindow> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -855,6 +871,40 @@ impl<'py> FromPyObject<'py> for Wrap<RoundMode> {
     }
 }
 
+#[cfg(feature = "sql")]
+impl<'py> FromPyObject<'py> for Wrap<polars::sql::ExprDialect> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        use polars::sql::ExprDialect;
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "python" => ExprDialect::Python,
+            "sql" => ExprDialect::Sql,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`dialect` must be one of {{'python', 'sql'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<ModeTiePolicy> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "all" => ModeTiePolicy::All,
+            "smallest" => ModeTiePolicy::Smallest,
+            "first" => ModeTiePolicy::First,
+            "all_as_list" => ModeTiePolicy::AllAsList,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`ties` must be one of {{'all', 'smallest', 'first', 'all_as_list'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "csv")]
 impl<'py> FromPyObject<'py> for Wrap<CsvEncoding> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
@@ -915,9 +965,10 @@ impl<'py> FromPyObject<'py> for Wrap<Label> {
             "left" => Label::Left,
             "right" => Label::Right,
             "datapoint" => Label::DataPoint,
+            "period_end" => Label::PeriodEnd,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "`label` must be one of {{'left', 'right', 'datapoint'}}, got {v}",
+                    "`label` must be one of {{'left', 'right', 'datapoint', 'period_end'}}, got {v}",
                 )));
             },
         };
@@ -1215,6 +1266,38 @@ pub(crate) fn parse_cloud_options(uri: &str, kv: Vec<(String, String)>) -> PyRes
     Ok(out)
 }
 
+impl<'py> FromPyObject<'py> for Wrap<EnumAppendPolicy> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "strict" => EnumAppendPolicy::Strict,
+            "extend" => EnumAppendPolicy::Extend,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`enum_policy` must be one of {{'strict', 'extend'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+#[cfg(feature = "list_sets")]
+impl<'py> FromPyObject<'py> for Wrap<SetNullBehavior> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "equal" => SetNullBehavior::Equal,
+            "distinct" => SetNullBehavior::Distinct,
+            "ignore" => SetNullBehavior::Ignore,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`nulls` must be one of {{'equal', 'distinct', 'ignore'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "list_sets")]
 impl<'py> FromPyObject<'py> for Wrap<SetOperation> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
@@ -191,12 +191,28 @@ impl PyDataFrame {
         Ok(())
     }
 
-    pub fn vstack(&self, py: Python, other: &PyDataFrame) -> PyResult<Self> {
-        py.enter_polars_df(|| self.df.vstack(&other.df))
+    #[pyo3(signature = (other, enum_policy=Wrap(EnumAppendPolicy::Strict)))]
+    pub fn vstack(
+        &self,
+        py: Python,
+        other: &PyDataFrame,
+        enum_policy: Wrap<EnumAppendPolicy>,
+    ) -> PyResult<Self> {
+        py.enter_polars_df(|| {
+            let mut df = self.df.clone();
+            df.vstack_mut_with_policy(&other.df, enum_policy.0)?;
+            Ok(df)
+        })
     }
 
-    pub fn vstack_mut(&mut self, py: Python, other: &PyDataFrame) -> PyResult<()> {
-        py.enter_polars(|| self.df.vstack_mut(&other.df))?;
+    #[pyo3(signature = (other, enum_policy=Wrap(EnumAppendPolicy::Strict)))]
+    pub fn vstack_mut(
+        &mut self,
+        py: Python,
+        other: &PyDataFrame,
+        enum_policy: Wrap<EnumAppendPolicy>,
+    ) -> PyResult<()> {
+        py.enter_polars(|| self.df.vstack_mut_with_policy(&other.df, enum_policy.0))?;
         Ok(())
     }
 
@@ -345,6 +345,12 @@ impl PyDataFrame {
         })
     }
 
+    #[cfg(feature = "ipc")]
+    pub fn write_shared_memory(&mut self, py: Python, name: &str) -> PyResult<String> {
+        let path = py.enter_polars(|| polars::io::ipc::write_shared_memory(&mut self.df, name))?;
+        Ok(path.to_string_lossy().into_owned())
+    }
+
     #[cfg(feature = "avro")]
     #[pyo3(signature = (py_f, compression, name))]
     pub fn write_avro(
@@ -8,6 +8,14 @@ impl PyExpr {
         self.inner.clone().cat().get_categories().into()
     }
 
+    fn cat_extend_categories(&self, categories: Vec<String>) -> Self {
+        self.inner
+            .clone()
+            .cat()
+            .extend_categories(categories)
+            .into()
+    }
+
     fn cat_len_bytes(&self) -> Self {
         self.inner.clone().cat().len_bytes().into()
     }
@@ -3,6 +3,7 @@ use pyo3::prelude::*;
 
 use crate::PyExpr;
 use crate::conversion::Wrap;
+use crate::error::PyPolarsErr;
 
 #[pymethods]
 impl PyExpr {
@@ -28,6 +29,16 @@ impl PyExpr {
         self.inner.clone().dt().offset_by(by.inner).into()
     }
 
+    fn dt_missing_periods(&self, every: &str) -> PyResult<Self> {
+        let every = Duration::try_parse(every).map_err(PyPolarsErr::from)?;
+        Ok(self.inner.clone().dt().missing_periods(every).into())
+    }
+
+    fn dt_gaps(&self, interval: &str) -> PyResult<Self> {
+        let interval = Duration::try_parse(interval).map_err(PyPolarsErr::from)?;
+        Ok(self.inner.clone().dt().gaps(interval).into())
+    }
+
     fn dt_epoch_seconds(&self) -> Self {
         self.inner
             .clone()
@@ -339,6 +339,13 @@ impl PyExpr {
         self.inner.clone().gather(idx.inner).into()
     }
 
+    fn scatter(&self, indices: Self, values: Self) -> Self {
+        self.inner
+            .clone()
+            .scatter(indices.inner, values.inner)
+            .into()
+    }
+
     fn get(&self, idx: Self) -> Self {
         self.inner.clone().get(idx.inner).into()
     }
@@ -377,6 +384,11 @@ impl PyExpr {
         out.into()
     }
 
+    fn shift_by_time(&self, by: Self, period: &str) -> PyResult<Self> {
+        let period = Duration::try_parse(period).map_err(PyPolarsErr::from)?;
+        Ok(self.inner.clone().shift_by_time(by.inner, period).into())
+    }
+
     fn fill_null(&self, expr: Self) -> Self {
         self.inner.clone().fill_null(expr.inner).into()
     }
@@ -696,6 +708,25 @@ impl PyExpr {
         self.inner.clone().product().into()
     }
 
+    fn geo_mean(&self) -> Self {
+        self.inner.clone().geo_mean().into()
+    }
+
+    fn harmonic_mean(&self) -> Self {
+        self.inner.clone().harmonic_mean().into()
+    }
+
+    #[pyo3(signature = (by, start, end))]
+    fn twap(&self, by: PyExpr, start: Option<PyExpr>, end: Option<PyExpr>) -> Self {
+        let expr = self.inner.clone();
+        if start.is_none() && end.is_none() {
+            return expr.twap(by.inner).into();
+        }
+        let bound = |b: Option<PyExpr>| b.map_or_else(|| dsl::lit(Null {}), |b| b.inner);
+        expr.twap_in_window(by.inner, bound(start), bound(end))
+            .into()
+    }
+
     fn shrink_dtype(&self) -> Self {
         self.inner.clone().shrink_dtype().into()
     }
@@ -726,8 +757,11 @@ impl PyExpr {
     fn reinterpret(&self, signed: bool) -> Self {
         self.inner.clone().reinterpret(signed).into()
     }
-    fn mode(&self) -> Self {
-        self.inner.clone().mode().into()
+    fn mode(&self, ties: Wrap<ModeTiePolicy>) -> Self {
+        self.inner.clone().mode_with_ties(ties.0).into()
+    }
+    fn top_k_frequent(&self, k: usize) -> Self {
+        self.inner.clone().top_k_frequent(k).into()
     }
     fn exclude(&self, columns: Vec<String>) -> Self {
         self.inner.clone().exclude(columns).into()
@@ -906,6 +940,10 @@ impl PyExpr {
         self.inner.clone().set_sorted_flag(is_sorted).into()
     }
 
+    fn cache(&self) -> Self {
+        self.inner.clone().cache().into()
+    }
+
     fn replace(&self, old: PyExpr, new: PyExpr) -> Self {
         self.inner.clone().replace(old.inner, new.inner).into()
     }
@@ -207,12 +207,14 @@ impl PyExpr {
         self.inner.clone().list().to_array(width).into()
     }
 
-    #[pyo3(signature = (width_strat, name_gen, upper_bound))]
+    #[pyo3(signature = (width_strat, name_gen, upper_bound, infer_sample_size, width))]
     fn list_to_struct(
         &self,
         width_strat: Wrap<ListToStructWidthStrategy>,
         name_gen: Option<PyObject>,
         upper_bound: usize,
+        infer_sample_size: Option<usize>,
+        width: Option<usize>,
     ) -> PyResult<Self> {
         let name_gen = name_gen.map(|lambda| {
             NameGenerator::from_func(move |idx: usize| {
@@ -229,7 +231,11 @@ impl PyExpr {
             .clone()
             .list()
             .to_struct(ListToStructArgs::InferWidth {
-                infer_field_strategy: width_strat.0,
+                infer_field_strategy: match (width, infer_sample_size) {
+                    (Some(width), _) => ListToStructWidthStrategy::Fixed(width),
+                    (None, Some(n)) => ListToStructWidthStrategy::MaxWidthSample(n),
+                    (None, None) => width_strat.0,
+                },
                 get_index_name: name_gen,
                 max_fields: upper_bound,
             })
@@ -251,6 +257,28 @@ impl PyExpr {
             .into())
     }
 
+    #[pyo3(signature = (keys, fields))]
+    fn list_to_struct_with_keys(
+        &self,
+        keys: PyExpr,
+        fields: Option<Bound<'_, PySequence>>,
+    ) -> PyResult<Self> {
+        let fields = fields
+            .map(|names| {
+                names
+                    .try_iter()?
+                    .map(|x| Ok(x?.extract::<Wrap<PlSmallStr>>()?.0))
+                    .collect::<PyResult<Arc<[_]>>>()
+            })
+            .transpose()?;
+        Ok(self
+            .inner
+            .clone()
+            .list()
+            .to_struct_with_keys(keys.inner, fields)
+            .into())
+    }
+
     fn list_n_unique(&self) -> Self {
         self.inner.clone().list().n_unique().into()
     }
@@ -266,14 +294,23 @@ impl PyExpr {
     }
 
     #[cfg(feature = "list_sets")]
-    fn list_set_operation(&self, other: PyExpr, operation: Wrap<SetOperation>) -> Self {
-        let e = self.inner.clone().list();
-        match operation.0 {
-            SetOperation::Intersection => e.set_intersection(other.inner),
-            SetOperation::Difference => e.set_difference(other.inner),
-            SetOperation::Union => e.union(other.inner),
-            SetOperation::SymmetricDifference => e.set_symmetric_difference(other.inner),
-        }
-        .into()
+    fn list_set_operation(
+        &self,
+        other: PyExpr,
+        operation: Wrap<SetOperation>,
+        multiset: bool,
+        maintain_order: bool,
+        nulls: Wrap<SetNullBehavior>,
+    ) -> Self {
+        let options = SetOperationOptions {
+            multiset,
+            maintain_order,
+            nulls: nulls.0,
+        };
+        self.inner
+            .clone()
+            .list()
+            .set_operation_with_options(other.inner, operation.0, options)
+            .into()
     }
 }
@@ -344,6 +344,28 @@ impl PyExpr {
         self.inner.clone().rolling_skew(options).into()
     }
 
+    #[pyo3(signature = (name, window_size, min_periods, center))]
+    fn rolling_map_registered(
+        &self,
+        name: &str,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            weights: None,
+            min_periods,
+            center,
+            fn_params: None,
+        };
+        self.inner
+            .clone()
+            .rolling_map_registered(name, options)
+            .into()
+    }
+
     #[pyo3(signature = (window_size, fisher, bias, min_periods, center))]
     fn rolling_kurtosis(
         &self,
@@ -22,6 +22,11 @@ impl PyExpr {
         self.inner.clone().struct_().rename_fields(names).into()
     }
 
+    #[cfg(feature = "dtype-array")]
+    fn struct_to_array(&self) -> Self {
+        self.inner.clone().struct_().to_array().into()
+    }
+
     #[cfg(feature = "json")]
     fn struct_json_encode(&self) -> Self {
         self.inner.clone().struct_().json_encode().into()
@@ -28,6 +28,20 @@ pub fn read_ipc_schema(py: Python, py_f: PyObject) -> PyResult<Bound<PyDict>> {
     Ok(dict)
 }
 
+#[cfg(feature = "ipc")]
+#[pyfunction]
+pub fn shared_memory_path(name: &str) -> PyResult<String> {
+    let path = polars::io::ipc::shared_memory_path(name).map_err(PyPolarsErr::from)?;
+    Ok(path.to_string_lossy().into_owned())
+}
+
+#[cfg(feature = "ipc")]
+#[pyfunction]
+pub fn remove_shared_memory(name: &str) -> PyResult<()> {
+    polars::io::ipc::remove_shared_memory(name).map_err(PyPolarsErr::from)?;
+    Ok(())
+}
+
 #[cfg(feature = "parquet")]
 #[pyfunction]
 pub fn read_parquet_schema(py: Python, py_f: PyObject) -> PyResult<Bound<PyDict>> {
@@ -88,6 +88,37 @@ pub fn arg_where(condition: PyExpr) -> PyExpr {
     dsl::arg_where(condition.inner).into()
 }
 
+#[pyfunction]
+pub fn is_duplicated_by(exprs: Vec<PyExpr>, keep: Wrap<UniqueKeepStrategy>) -> PyResult<PyExpr> {
+    let exprs = exprs.to_exprs();
+    let e = dsl::is_duplicated_by(exprs, keep.0).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
+
+#[pyfunction]
+pub fn bucket_id(by: Vec<PyExpr>, num_buckets: u32) -> PyResult<PyExpr> {
+    let by = by.to_exprs();
+    let e = dsl::bucket_id(by, num_buckets).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
+
+#[pyfunction]
+pub fn bucketing_metadata(by: Vec<String>, num_buckets: u32) -> String {
+    let by = by.into_iter().map(PlSmallStr::from).collect::<Vec<_>>();
+    dsl::bucketing_metadata(&by, num_buckets)
+}
+
+#[pyfunction]
+pub fn encode_rows(
+    exprs: Vec<PyExpr>,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+) -> PyResult<PyExpr> {
+    let exprs = exprs.to_exprs();
+    let e = dsl::encode_rows(exprs, descending, nulls_last).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
+
 #[pyfunction]
 pub fn as_struct(exprs: Vec<PyExpr>) -> PyResult<PyExpr> {
     let exprs = exprs.to_exprs();
@@ -174,6 +205,20 @@ pub fn cols(names: Vec<String>) -> PyExpr {
     dsl::cols(names).into()
 }
 
+#[pyfunction]
+pub fn align_frames(
+    lfs: Vec<PyLazyFrame>,
+    on: Vec<String>,
+    how: Wrap<JoinType>,
+    descending: Vec<bool>,
+) -> PyResult<Vec<PyLazyFrame>> {
+    let lfs = lfs.into_iter().map(|lf| lf.ldf).collect::<Vec<_>>();
+    let on = on.into_iter().map(PlSmallStr::from).collect::<Vec<_>>();
+    let aligned =
+        dsl::functions::align_frames(&lfs, &on, how.0, descending).map_err(PyPolarsErr::from)?;
+    Ok(aligned.into_iter().map(PyLazyFrame::from).collect())
+}
+
 #[pyfunction]
 pub fn concat_lf(
     seq: &Bound<'_, PyAny>,
@@ -355,6 +400,18 @@ pub fn concat_lf_horizontal(lfs: &Bound<'_, PyAny>, parallel: bool) -> PyResult<
     Ok(lf.into())
 }
 
+#[pyfunction]
+#[pyo3(signature = (rows, names=None))]
+pub fn values(rows: Vec<Vec<PyExpr>>, names: Option<Vec<String>>) -> PyResult<PyLazyFrame> {
+    let rows = rows
+        .into_iter()
+        .map(|row| row.to_exprs())
+        .collect::<Vec<_>>();
+    let names = names.map(|names| names.into_iter().map(PlSmallStr::from).collect::<Vec<_>>());
+    let lf = dsl::functions::values(&rows, names.as_deref()).map_err(PyPolarsErr::from)?;
+    Ok(lf.into())
+}
+
 #[pyfunction]
 pub fn concat_expr(e: Vec<PyExpr>, rechunk: bool) -> PyResult<PyExpr> {
     let e = e.to_exprs();
@@ -575,3 +632,10 @@ pub fn sql_expr(sql: &str) -> PyResult<PyExpr> {
     let expr = polars::sql::sql_expr(sql).map_err(PyPolarsErr::from)?;
     Ok(expr.into())
 }
+
+#[pyfunction]
+#[cfg(feature = "sql")]
+pub fn parse_expr(expr: &str, dialect: Wrap<polars::sql::ExprDialect>) -> PyResult<PyExpr> {
+    let expr = polars::sql::parse_expr(expr, dialect.0).map_err(PyPolarsErr::from)?;
+    Ok(expr.into())
+}
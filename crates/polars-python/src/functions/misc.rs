@@ -1,8 +1,14 @@
+use std::sync::Arc;
+
+use polars::prelude::{
+    ExtensionTypeDefinition, PolarsError, PolarsResult, RollingAggregator,
+    RollingAggregatorFactory, Series,
+};
 use polars_plan::prelude::*;
 use pyo3::prelude::*;
 
 use crate::PyExpr;
-use crate::conversion::Wrap;
+use crate::conversion::{Wrap, get_series, to_series};
 use crate::expr::ToExprs;
 use crate::prelude::DataType;
 
@@ -63,6 +69,150 @@ pub fn register_plugin_function(
     .into())
 }
 
+/// A [`RollingAggregator`] implemented by a Python object with the methods `add`, `remove` and
+/// `evaluate`.
+struct PyRollingAggregator(PyObject);
+
+fn rolling_aggregator_err(method: &str, err: PyErr) -> PolarsError {
+    PolarsError::ComputeError(
+        format!("Python rolling aggregator failed in '{method}': {err}").into(),
+    )
+}
+
+impl RollingAggregator for PyRollingAggregator {
+    fn add(&mut self, value: f64) -> PolarsResult<()> {
+        Python::with_gil(|py| self.0.call_method1(py, "add", (value,)))
+            .map_err(|e| rolling_aggregator_err("add", e))?;
+        Ok(())
+    }
+
+    fn remove(&mut self, value: f64) -> PolarsResult<()> {
+        Python::with_gil(|py| self.0.call_method1(py, "remove", (value,)))
+            .map_err(|e| rolling_aggregator_err("remove", e))?;
+        Ok(())
+    }
+
+    fn evaluate(&self) -> PolarsResult<Option<f64>> {
+        Python::with_gil(|py| self.0.call_method0(py, "evaluate")?.extract(py))
+            .map_err(|e| rolling_aggregator_err("evaluate", e))
+    }
+}
+
+#[pyfunction]
+pub fn register_rolling_aggregator(name: &str, factory: PyObject) {
+    let factory: RollingAggregatorFactory = Arc::new(move || {
+        let aggregator = Python::with_gil(|py| factory.call0(py))
+            .map_err(|e| rolling_aggregator_err("factory", e))?;
+        Ok(Box::new(PyRollingAggregator(aggregator)))
+    });
+    polars::prelude::register_rolling_aggregator(name, factory)
+}
+
+#[pyfunction]
+pub fn unregister_rolling_aggregator(name: &str) -> bool {
+    polars::prelude::unregister_rolling_aggregator(name)
+}
+
+/// An [`ExtensionTypeDefinition`] implemented by a Python object with the attribute
+/// `storage_dtype` and optionally the methods `parse`, `format`, `validate` and `can_cast_to`.
+struct PyExtensionType {
+    definition: PyObject,
+    storage_dtype: DataType,
+    has_parse: bool,
+    has_format: bool,
+    has_validate: bool,
+    has_can_cast_to: bool,
+}
+
+fn extension_type_err(method: &str, err: PyErr) -> PolarsError {
+    PolarsError::ComputeError(format!("Python extension type failed in '{method}': {err}").into())
+}
+
+impl PyExtensionType {
+    fn call_with_series(
+        &self,
+        method: &str,
+        s: &Series,
+        metadata: Option<&str>,
+    ) -> PolarsResult<PyObject> {
+        Python::with_gil(|py| {
+            let s = to_series(py, s.clone().into())?;
+            self.definition.call_method1(py, method, (s, metadata))
+        })
+        .map_err(|e| extension_type_err(method, e))
+    }
+
+    fn transform(&self, method: &str, s: &Series, metadata: Option<&str>) -> PolarsResult<Series> {
+        let out = self.call_with_series(method, s, metadata)?;
+        Python::with_gil(|py| get_series(out.bind(py))).map_err(|e| extension_type_err(method, e))
+    }
+}
+
+impl ExtensionTypeDefinition for PyExtensionType {
+    fn storage_dtype(&self) -> DataType {
+        self.storage_dtype.clone()
+    }
+
+    fn parse(&self, s: &Series, metadata: Option<&str>) -> PolarsResult<Series> {
+        if !self.has_parse {
+            return s.strict_cast(&self.storage_dtype);
+        }
+        self.transform("parse", s, metadata)
+    }
+
+    fn format(&self, s: &Series, metadata: Option<&str>) -> PolarsResult<Series> {
+        if !self.has_format {
+            return s.cast(&DataType::String);
+        }
+        self.transform("format", s, metadata)
+    }
+
+    fn validate(&self, s: &Series, metadata: Option<&str>) -> PolarsResult<()> {
+        if self.has_validate {
+            self.call_with_series("validate", s, metadata)?;
+        }
+        Ok(())
+    }
+
+    fn can_cast_to(&self, dtype: &DataType) -> bool {
+        if !self.has_can_cast_to {
+            return true;
+        }
+        // A failing check doesn't allow the cast.
+        Python::with_gil(|py| {
+            self.definition
+                .call_method1(py, "can_cast_to", (&Wrap(dtype.clone()),))?
+                .extract::<bool>(py)
+        })
+        .unwrap_or(false)
+    }
+}
+
+#[pyfunction]
+pub fn register_extension_type(name: &str, definition: Bound<'_, PyAny>) -> PyResult<()> {
+    let storage_dtype = definition
+        .getattr("storage_dtype")?
+        .extract::<Wrap<DataType>>()?
+        .0;
+    let definition = PyExtensionType {
+        storage_dtype,
+        has_parse: definition.hasattr("parse")?,
+        has_format: definition.hasattr("format")?,
+        has_validate: definition.hasattr("validate")?,
+        has_can_cast_to: definition.hasattr("can_cast_to")?,
+        definition: definition.unbind(),
+    };
+    polars::prelude::register_extension_type(name, Some(Arc::new(definition)));
+    Ok(())
+}
+
+#[pyfunction]
+pub fn unregister_extension_type(name: &str) -> bool {
+    let registered = polars::prelude::get_extension_type(name).is_some();
+    polars::prelude::register_extension_type(name, None);
+    registered
+}
+
 #[pyfunction]
 pub fn __register_startup_deps() {
     #[cfg(feature = "object")]
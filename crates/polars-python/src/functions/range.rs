@@ -6,7 +6,7 @@ use pyo3::prelude::*;
 use crate::error::PyPolarsErr;
 use crate::prelude::*;
 use crate::utils::EnterPolarsExt;
-use crate::{PyExpr, PySeries};
+use crate::{PyExpr, PyLazyFrame, PySeries};
 
 #[pyfunction]
 pub fn int_range(start: PyExpr, end: PyExpr, step: i64, dtype: Wrap<DataType>) -> PyExpr {
@@ -41,6 +41,19 @@ pub fn eager_int_range(
     })
 }
 
+#[pyfunction]
+pub fn scan_int_range(
+    start: i64,
+    end: i64,
+    step: i64,
+    dtype: Wrap<DataType>,
+    name: &str,
+) -> PyResult<PyLazyFrame> {
+    let lf = LazyFrame::scan_int_range(name.into(), start, end, step, dtype.0)
+        .map_err(PyPolarsErr::from)?;
+    Ok(lf.into())
+}
+
 #[pyfunction]
 pub fn int_ranges(
     start: PyExpr,
@@ -79,6 +92,20 @@ pub fn date_range(
     Ok(dsl::date_range(start, end, interval, closed).into())
 }
 
+#[pyfunction]
+pub fn scan_date_range(
+    start: i32,
+    end: i32,
+    interval: &str,
+    closed: Wrap<ClosedWindow>,
+    name: &str,
+) -> PyResult<PyLazyFrame> {
+    let interval = Duration::try_parse(interval).map_err(PyPolarsErr::from)?;
+    let lf = LazyFrame::scan_date_range(name.into(), start, end, interval, closed.0)
+        .map_err(PyPolarsErr::from)?;
+    Ok(lf.into())
+}
+
 #[pyfunction]
 pub fn date_ranges(
     start: PyExpr,
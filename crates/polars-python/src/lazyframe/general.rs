@@ -303,7 +303,7 @@ impl PyLazyFrame {
         source, sources, n_rows, cache, parallel, rechunk, row_index, low_memory, cloud_options,
         credential_provider, use_statistics, hive_partitioning, schema, hive_schema,
         try_parse_hive_dates, retries, glob, include_file_paths, allow_missing_columns,
-        cast_options,
+        cast_options, strings_as_categorical, column_mapping,
     ))]
     fn new_from_parquet(
         source: Option<PyObject>,
@@ -326,8 +326,11 @@ impl PyLazyFrame {
         include_file_paths: Option<String>,
         allow_missing_columns: bool,
         cast_options: Wrap<CastColumnsPolicy>,
+        strings_as_categorical: bool,
+        column_mapping: Option<PyObject>,
     ) -> PyResult<Self> {
         use cloud::credential_provider::PlCredentialProvider;
+        use polars_io::prelude::{ColumnRenameFn, ParquetColumnMapping};
         use polars_utils::slice_enum::Slice;
 
         use crate::utils::to_py_err;
@@ -339,6 +342,38 @@ impl PyLazyFrame {
             parallel,
             low_memory,
             use_statistics,
+            strings_as_categorical,
+            column_mapping: column_mapping
+                .map(|mapping| {
+                    Python::with_gil(|py| {
+                        let mapping = mapping.bind(py);
+                        PyResult::Ok(if let Ok(field_ids) = mapping.downcast::<PyDict>() {
+                            ParquetColumnMapping::FieldIds(
+                                field_ids
+                                    .iter()
+                                    .map(|(name, id)| {
+                                        Ok((name.extract::<String>()?.into(), id.extract()?))
+                                    })
+                                    .collect::<PyResult<_>>()?,
+                            )
+                        } else {
+                            let function = mapping.clone().unbind();
+                            ParquetColumnMapping::Rename(ColumnRenameFn(Arc::new(move |name| {
+                                Python::with_gil(|py| {
+                                    let out = function.call1(py, (name,))?;
+                                    PyResult::Ok(out.extract::<String>(py)?.into())
+                                })
+                                .map_err(|e| {
+                                    polars_err!(
+                                        ComputeError:
+                                        "Python function in 'column_mapping' produced an error: {e}."
+                                    )
+                                })
+                            })))
+                        })
+                    })
+                })
+                .transpose()?,
         };
 
         let sources = sources.0;
@@ -394,6 +429,7 @@ impl PyLazyFrame {
             glob,
             projection: None,
             row_index,
+            row_index_per_file: false,
             pre_slice: n_rows.map(|len| Slice::Positive { offset: 0, len }),
             cast_columns_policy: cast_options.0,
             missing_columns_policy: if allow_missing_columns {
@@ -457,6 +493,8 @@ impl PyLazyFrame {
             cloud_options: None,
             hive_options,
             include_file_paths: include_file_paths.map(|x| x.into()),
+            #[cfg(feature = "ipc_encryption")]
+            encryption: None,
         };
 
         let sources = sources.0;
@@ -751,6 +789,19 @@ impl PyLazyFrame {
         })
     }
 
+    fn collect_chunked(
+        &self,
+        py: Python,
+        engine: Wrap<Engine>,
+        n_rows: usize,
+    ) -> PyResult<PyDataFrame> {
+        py.enter_polars_df(|| {
+            self.ldf
+                .clone()
+                .collect_chunked_with_engine(n_rows, engine.0)
+        })
+    }
+
     #[pyo3(signature = (engine, lambda))]
     fn collect_with_callback(
         &self,
@@ -855,7 +906,7 @@ impl PyLazyFrame {
     #[cfg(all(feature = "streaming", feature = "ipc"))]
     #[pyo3(signature = (
         target, compression, compat_level, cloud_options, credential_provider, retries,
-        sink_options
+        sink_options, stream=false, flush_each_batch=false
     ))]
     fn sink_ipc(
         &self,
@@ -867,10 +918,14 @@ impl PyLazyFrame {
         credential_provider: Option<PyObject>,
         retries: usize,
         sink_options: Wrap<SinkOptions>,
+        stream: bool,
+        flush_each_batch: bool,
     ) -> PyResult<PyLazyFrame> {
         let options = IpcWriterOptions {
             compression: compression.0,
             compat_level: compat_level.0,
+            stream,
+            flush_each_batch,
             ..Default::default()
         };
 
@@ -1063,6 +1118,11 @@ impl PyLazyFrame {
         py.enter_polars_df(|| ldf.fetch(n_rows))
     }
 
+    fn stats(&self, py: Python) -> PyResult<PyDataFrame> {
+        let ldf = self.ldf.clone();
+        py.enter_polars_df(|| ldf.stats())
+    }
+
     fn filter(&mut self, predicate: PyExpr) -> Self {
         let ldf = self.ldf.clone();
         ldf.filter(predicate.inner).into()
@@ -1125,42 +1185,70 @@ impl PyLazyFrame {
         Ok(PyLazyGroupBy { lgb: Some(lazy_gb) })
     }
 
+    #[pyo3(signature = (
+        index_column, every, period, offset, label, include_boundaries, closed, group_by,
+        start_by, month_end_policy, origin, label_offset
+    ))]
     fn group_by_dynamic(
         &mut self,
         index_column: PyExpr,
         every: &str,
-        period: &str,
+        period: Option<&str>,
         offset: &str,
         label: Wrap<Label>,
         include_boundaries: bool,
         closed: Wrap<ClosedWindow>,
         group_by: Vec<PyExpr>,
         start_by: Wrap<StartBy>,
+        month_end_policy: Option<Wrap<MonthEndPolicy>>,
+        origin: Option<i64>,
+        label_offset: Option<&str>,
     ) -> PyResult<PyLazyGroupBy> {
         let closed_window = closed.0;
         let group_by = group_by
             .into_iter()
             .map(|pyexpr| pyexpr.inner)
             .collect::<Vec<_>>();
+        let mut options = DynamicGroupOptions {
+            offset: Duration::try_parse(offset).map_err(PyPolarsErr::from)?,
+            label: label.0,
+            include_boundaries,
+            closed_window,
+            start_by: start_by.0,
+            month_end_policy: month_end_policy.map(|p| p.0),
+            origin,
+            label_offset: label_offset
+                .map(Duration::try_parse)
+                .transpose()
+                .map_err(PyPolarsErr::from)?,
+            ..Default::default()
+        }
+        .with_frequency(every)
+        .map_err(PyPolarsErr::from)?;
+        if let Some(period) = period {
+            options.period = Duration::try_parse(period).map_err(PyPolarsErr::from)?;
+        }
         let ldf = self.ldf.clone();
-        let lazy_gb = ldf.group_by_dynamic(
-            index_column.inner,
-            group_by,
-            DynamicGroupOptions {
-                every: Duration::try_parse(every).map_err(PyPolarsErr::from)?,
-                period: Duration::try_parse(period).map_err(PyPolarsErr::from)?,
-                offset: Duration::try_parse(offset).map_err(PyPolarsErr::from)?,
-                label: label.0,
-                include_boundaries,
-                closed_window,
-                start_by: start_by.0,
-                ..Default::default()
-            },
-        );
+        let lazy_gb = ldf.group_by_dynamic(index_column.inner, group_by, options);
 
         Ok(PyLazyGroupBy { lgb: Some(lazy_gb) })
     }
 
+    fn upsample(
+        &self,
+        py: Python,
+        by: Vec<String>,
+        time_column: &str,
+        every: &str,
+        maintain_order: bool,
+    ) -> PyResult<Self> {
+        let every = Duration::try_parse(every).map_err(PyPolarsErr::from)?;
+        let by = by.into_iter().map(PlSmallStr::from).collect();
+        let ldf = self.ldf.clone();
+        py.enter_polars(|| ldf.upsample(by, time_column, every, maintain_order))
+            .map(Into::into)
+    }
+
     fn with_context(&self, contexts: Vec<Self>) -> Self {
         let contexts = contexts.into_iter().map(|ldf| ldf.ldf).collect::<Vec<_>>();
         self.ldf.clone().with_context(contexts).into()
@@ -1216,7 +1304,7 @@ impl PyLazyFrame {
             .into())
     }
 
-    #[pyo3(signature = (other, left_on, right_on, allow_parallel, force_parallel, nulls_equal, how, suffix, validate, maintain_order, coalesce=None))]
+    #[pyo3(signature = (other, left_on, right_on, allow_parallel, force_parallel, nulls_equal, how, suffix, validate, maintain_order, coalesce=None, nulls_equal_per_key=None))]
     fn join(
         &self,
         other: Self,
@@ -1230,6 +1318,7 @@ impl PyLazyFrame {
         validate: Wrap<JoinValidation>,
         maintain_order: Wrap<MaintainOrderJoin>,
         coalesce: Option<bool>,
+        nulls_equal_per_key: Option<Vec<bool>>,
     ) -> PyResult<Self> {
         let coalesce = match coalesce {
             None => JoinCoalesce::JoinSpecific,
@@ -1255,6 +1344,7 @@ impl PyLazyFrame {
             .allow_parallel(allow_parallel)
             .force_parallel(force_parallel)
             .join_nulls(nulls_equal)
+            .join_nulls_per_key(nulls_equal_per_key)
             .how(how.0)
             .suffix(suffix)
             .validate(validate.0)
@@ -1264,6 +1354,16 @@ impl PyLazyFrame {
             .into())
     }
 
+    #[cfg(feature = "is_in")]
+    fn into_subquery(&self) -> PyResult<PyExpr> {
+        let e = self
+            .ldf
+            .clone()
+            .into_subquery()
+            .map_err(PyPolarsErr::from)?;
+        Ok(e.into())
+    }
+
     fn join_where(&self, other: Self, predicates: Vec<PyExpr>, suffix: String) -> PyResult<Self> {
         let ldf = self.ldf.clone();
         let other = other.ldf;
@@ -1388,6 +1488,20 @@ impl PyLazyFrame {
         .into()
     }
 
+    #[pyo3(signature = (subset, sort, normalize, name))]
+    fn value_counts(
+        &self,
+        subset: Option<Vec<PyExpr>>,
+        sort: bool,
+        normalize: bool,
+        name: Option<&str>,
+    ) -> Self {
+        let ldf = self.ldf.clone();
+        let subset = subset.map(|e| e.to_exprs());
+        ldf.value_counts(subset, sort, normalize, name.map(PlSmallStr::from_str))
+            .into()
+    }
+
     #[pyo3(signature = (subset=None))]
     fn drop_nans(&self, subset: Option<Vec<PyExpr>>) -> Self {
         let ldf = self.ldf.clone();
@@ -1402,6 +1516,12 @@ impl PyLazyFrame {
         ldf.drop_nulls(subset).into()
     }
 
+    fn duplicates(&self, subset: Option<Vec<PyExpr>>, name: &str) -> Self {
+        let ldf = self.ldf.clone();
+        let subset = subset.map(|e| e.to_exprs());
+        ldf.duplicates(subset, name.into()).into()
+    }
+
     #[pyo3(signature = (offset, len=None))]
     fn slice(&self, offset: i64, len: Option<IdxSize>) -> Self {
         let ldf = self.ldf.clone();
@@ -1439,6 +1559,24 @@ impl PyLazyFrame {
         ldf.with_row_index(name, offset).into()
     }
 
+    fn with_row_index_per_file(&self, name: &str, offset: Option<IdxSize>) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.with_row_index_per_file(name, offset).into()
+    }
+
+    fn with_row_hash(&self, name: &str, by: Vec<PyExpr>, seed: u64) -> PyResult<Self> {
+        let ldf = self.ldf.clone();
+        let out = ldf
+            .with_row_hash(name, by.to_exprs(), seed)
+            .map_err(PyPolarsErr::from)?;
+        Ok(out.into())
+    }
+
+    fn with_random_seed(&self, seed: u64) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.with_random_seed(seed).into()
+    }
+
     #[pyo3(signature = (lambda, predicate_pushdown, projection_pushdown, slice_pushdown, streamable, schema, validate_output))]
     fn map_batches(
         &self,
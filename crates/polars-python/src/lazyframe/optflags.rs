@@ -58,6 +58,8 @@ flag_getter_setters! {
     (COLLAPSE_JOINS, get_collapse_joins, set_collapse_joins, clear=true)
     (CHECK_ORDER_OBSERVE, get_check_order_observe, set_check_order_observe, clear=true)
     (FAST_PROJECTION, get_fast_projection, set_fast_projection, clear=true)
+    (SEMI_JOIN_REDUCTION, get_semi_join_reduction, set_semi_join_reduction, clear=true)
+    (DETERMINISTIC, get_deterministic, set_deterministic, clear=false)
 
     (EAGER, get_eager, set_eager, clear=true)
     (STREAMING, get_old_streaming, set_old_streaming, clear=true)
@@ -150,11 +150,18 @@ impl<'py> FromPyObject<'py> for Wrap<SinkOptions> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = ob.extract::<pyo3::Bound<'_, PyDict>>()?;
 
-        if parsed.len() != 3 {
+        // `batch_size` is optional.
+        let batch_size = PyDictMethods::get_item(&parsed, "batch_size")?;
+        if parsed.len() != 3 + usize::from(batch_size.is_some()) {
             return Err(PyValueError::new_err(
-                "`sink_options` must be a dictionary with the exactly 3 field.",
+                "`sink_options` must be a dictionary with exactly the fields `sync_on_close`, \
+                 `maintain_order` and `mkdir`, and optionally `batch_size`",
             ));
         }
+        let batch_size = match batch_size {
+            None => None,
+            Some(batch_size) => batch_size.extract::<Option<usize>>()?,
+        };
 
         let sync_on_close =
             PyDictMethods::get_item(&parsed, "sync_on_close")?.ok_or_else(|| {
@@ -176,6 +183,7 @@ impl<'py> FromPyObject<'py> for Wrap<SinkOptions> {
             sync_on_close,
             maintain_order,
             mkdir,
+            batch_size,
         }))
     }
 }
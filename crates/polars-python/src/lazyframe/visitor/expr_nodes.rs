@@ -198,6 +198,7 @@ pub enum PyBooleanFunction {
     IsLastDistinct,
     IsUnique,
     IsDuplicated,
+    IsDuplicatedBy,
     IsBetween,
     IsIn,
     AllHorizontal,
@@ -250,6 +251,8 @@ pub enum PyTemporalFunction {
     TimeStamp,
     Truncate,
     OffsetBy,
+    MissingPeriods,
+    Gaps,
     MonthStart,
     MonthEnd,
     BaseUtcOffset,
@@ -689,6 +692,21 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 arguments: vec![n.0],
                 options: py.None(),
             },
+            IRAggExpr::Product(n) => Agg {
+                name: "product".into_py_any(py)?,
+                arguments: vec![n.0],
+                options: py.None(),
+            },
+            IRAggExpr::GeoMean(n) => Agg {
+                name: "geo_mean".into_py_any(py)?,
+                arguments: vec![n.0],
+                options: py.None(),
+            },
+            IRAggExpr::HarmonicMean(n) => Agg {
+                name: "harmonic_mean".into_py_any(py)?,
+                arguments: vec![n.0],
+                options: py.None(),
+            },
             IRAggExpr::Count(n, include_null) => Agg {
                 name: "count".into_py_any(py)?,
                 arguments: vec![n.0],
@@ -709,6 +727,11 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 arguments: vec![n.0],
                 options: py.None(),
             },
+            IRAggExpr::Udf { input, function } => Agg {
+                name: function.name().into_py_any(py)?,
+                arguments: vec![input.0],
+                options: py.None(),
+            },
         }
         .into_py_any(py),
         AExpr::Ternary {
@@ -739,6 +762,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Categorical(_) => {
                     return Err(PyNotImplementedError::new_err("categorical expr"));
                 },
+                FunctionExpr::IpExpr(_) => {
+                    return Err(PyNotImplementedError::new_err("ip expr"));
+                },
                 FunctionExpr::ListExpr(_) => {
                     return Err(PyNotImplementedError::new_err("list expr"));
                 },
@@ -768,6 +794,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::Contains { literal, strict } => {
                         (PyStringFunction::Contains, literal, strict).into_py_any(py)
                     },
+                    StringFunction::ContainsWithEngine { .. } => {
+                        return Err(PyNotImplementedError::new_err("contains with regex engine"));
+                    },
                     StringFunction::CountMatches(literal) => {
                         (PyStringFunction::CountMatches, literal).into_py_any(py)
                     },
@@ -816,7 +845,40 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                         },
                     )
                         .into_py_any(py),
+                    StringFunction::Casefold => {
+                        return Err(PyNotImplementedError::new_err("casefold"));
+                    },
+                    StringFunction::CollationKey => {
+                        return Err(PyNotImplementedError::new_err("collation_key"));
+                    },
+                    StringFunction::LowercaseLocale(_) => {
+                        return Err(PyNotImplementedError::new_err("to_lowercase_locale"));
+                    },
+                    StringFunction::UppercaseLocale(_) => {
+                        return Err(PyNotImplementedError::new_err("to_uppercase_locale"));
+                    },
                     StringFunction::Reverse => (PyStringFunction::Reverse,).into_py_any(py),
+                    StringFunction::Levenshtein => {
+                        return Err(PyNotImplementedError::new_err("levenshtein"));
+                    },
+                    StringFunction::JaroWinkler => {
+                        return Err(PyNotImplementedError::new_err("jaro_winkler"));
+                    },
+                    StringFunction::NgramSimilarity(_) => {
+                        return Err(PyNotImplementedError::new_err("ngram_similarity"));
+                    },
+                    StringFunction::Tokenize(_) => {
+                        return Err(PyNotImplementedError::new_err("tokenize"));
+                    },
+                    StringFunction::Ngrams(_) => {
+                        return Err(PyNotImplementedError::new_err("ngrams"));
+                    },
+                    StringFunction::Shingle(_) => {
+                        return Err(PyNotImplementedError::new_err("shingle"));
+                    },
+                    StringFunction::ToUuid(_) => {
+                        return Err(PyNotImplementedError::new_err("to_uuid"));
+                    },
                     StringFunction::PadStart { length, fill_char } => {
                         (PyStringFunction::PadStart, length, fill_char).into_py_any(py)
                     },
@@ -838,6 +900,20 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::Base64Decode(strict) => {
                         (PyStringFunction::Base64Decode, strict).into_py_any(py)
                     },
+                    StringFunction::Base32Encode => {
+                        return Err(PyNotImplementedError::new_err("base32_encode"));
+                    },
+                    #[cfg(feature = "binary_encoding")]
+                    StringFunction::Base32Decode(_) => {
+                        return Err(PyNotImplementedError::new_err("base32_decode"));
+                    },
+                    StringFunction::Base85Encode => {
+                        return Err(PyNotImplementedError::new_err("base85_encode"));
+                    },
+                    #[cfg(feature = "binary_encoding")]
+                    StringFunction::Base85Decode(_) => {
+                        return Err(PyNotImplementedError::new_err("base85_decode"));
+                    },
                     StringFunction::StartsWith => (PyStringFunction::StartsWith,).into_py_any(py),
                     StringFunction::StripChars => (PyStringFunction::StripChars,).into_py_any(py),
                     StringFunction::StripCharsStart => {
@@ -863,6 +939,13 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::Split(inclusive) => {
                         (PyStringFunction::Split, inclusive).into_py_any(py)
                     },
+                    StringFunction::SplitMax(_) => {
+                        return Err(PyNotImplementedError::new_err("split with maxsplit"));
+                    },
+                    #[cfg(feature = "regex")]
+                    StringFunction::SplitRegex(_) => {
+                        return Err(PyNotImplementedError::new_err("split_regex"));
+                    },
                     StringFunction::ToDecimal(inference_length) => {
                         (PyStringFunction::ToDecimal, inference_length).into_py_any(py)
                     },
@@ -886,6 +969,14 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::FindMany { .. } => {
                         return Err(PyNotImplementedError::new_err("find_many"));
                     },
+                    #[cfg(feature = "find_many")]
+                    StringFunction::ReplaceManyRegex => {
+                        return Err(PyNotImplementedError::new_err("replace_many_regex"));
+                    },
+                    #[cfg(feature = "find_many")]
+                    StringFunction::ExtractFirstMatch => {
+                        return Err(PyNotImplementedError::new_err("extract_first_match"));
+                    },
                     #[cfg(feature = "regex")]
                     StringFunction::EscapeRegex => (PyStringFunction::EscapeRegex,).into_py_any(py),
                 },
@@ -965,6 +1056,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     },
                     TemporalFunction::Truncate => (PyTemporalFunction::Truncate,).into_py_any(py),
                     TemporalFunction::OffsetBy => (PyTemporalFunction::OffsetBy,).into_py_any(py),
+                    TemporalFunction::MissingPeriods(every) => {
+                        (PyTemporalFunction::MissingPeriods, Wrap(*every)).into_py_any(py)
+                    },
+                    TemporalFunction::Gaps(interval) => {
+                        (PyTemporalFunction::Gaps, Wrap(*interval)).into_py_any(py)
+                    },
                     TemporalFunction::MonthStart => {
                         (PyTemporalFunction::MonthStart,).into_py_any(py)
                     },
@@ -1020,6 +1117,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     BooleanFunction::IsDuplicated => {
                         (PyBooleanFunction::IsDuplicated,).into_py_any(py)
                     },
+                    BooleanFunction::IsDuplicatedBy { keep } => {
+                        (PyBooleanFunction::IsDuplicatedBy, Into::<&str>::into(keep))
+                            .into_py_any(py)
+                    },
                     BooleanFunction::IsBetween { closed } => {
                         (PyBooleanFunction::IsBetween, Into::<&str>::into(closed)).into_py_any(py)
                     },
@@ -1051,6 +1152,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Hash(seed, seed_1, seed_2, seed_3) => {
                     ("hash", seed, seed_1, seed_2, seed_3).into_py_any(py)
                 },
+                FunctionExpr::BucketId(num_buckets) => ("bucket_id", num_buckets).into_py_any(py),
                 FunctionExpr::ArgWhere => ("argwhere",).into_py_any(py),
                 #[cfg(feature = "index_of")]
                 FunctionExpr::IndexOf => ("index_of",).into_py_any(py),
@@ -1121,16 +1223,22 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 },
                 FunctionExpr::ShiftAndFill => ("shift_and_fill",).into_py_any(py),
                 FunctionExpr::Shift => ("shift",).into_py_any(py),
+                FunctionExpr::ShiftByTime(period) => {
+                    ("shift_by_time", Wrap(*period)).into_py_any(py)
+                },
                 FunctionExpr::DropNans => ("drop_nans",).into_py_any(py),
                 FunctionExpr::DropNulls => ("drop_nulls",).into_py_any(py),
-                FunctionExpr::Mode => ("mode",).into_py_any(py),
+                FunctionExpr::Mode(ties) => ("mode", Into::<&str>::into(ties)).into_py_any(py),
+                FunctionExpr::TopKFrequent(k) => ("top_k_frequent", k).into_py_any(py),
                 FunctionExpr::Skew(bias) => ("skew", bias).into_py_any(py),
                 FunctionExpr::Kurtosis(fisher, bias) => ("kurtosis", fisher, bias).into_py_any(py),
+                FunctionExpr::Twap => ("twap",).into_py_any(py),
                 FunctionExpr::Reshape(_) => return Err(PyNotImplementedError::new_err("reshape")),
                 #[cfg(feature = "repeat_by")]
                 FunctionExpr::RepeatBy => ("repeat_by",).into_py_any(py),
                 FunctionExpr::ArgUnique => ("arg_unique",).into_py_any(py),
                 FunctionExpr::Repeat => ("repeat",).into_py_any(py),
+                FunctionExpr::Scatter => ("scatter",).into_py_any(py),
                 FunctionExpr::Rank {
                     options: _,
                     seed: _,
@@ -1154,8 +1262,13 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     normalize,
                 } => ("value_counts", sort, parallel, name.as_str(), normalize).into_py_any(py),
                 FunctionExpr::UniqueCounts => ("unique_counts",).into_py_any(py),
+                FunctionExpr::OccurrenceCountBy => ("occurrence_count_by",).into_py_any(py),
                 FunctionExpr::ApproxNUnique => ("approx_n_unique",).into_py_any(py),
                 FunctionExpr::Coalesce => ("coalesce",).into_py_any(py),
+                FunctionExpr::RowEncode {
+                    descending,
+                    nulls_last,
+                } => ("encode_rows", descending, nulls_last).into_py_any(py),
                 FunctionExpr::ShrinkType => ("shrink_dtype",).into_py_any(py),
                 FunctionExpr::Diff(null_behaviour) => (
                     "diff",
@@ -1214,6 +1327,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Random { .. } => {
                     return Err(PyNotImplementedError::new_err("random"));
                 },
+                FunctionExpr::Uuid(_) => {
+                    return Err(PyNotImplementedError::new_err("uuid"));
+                },
+                FunctionExpr::Cache => ("cache",).into_py_any(py),
                 FunctionExpr::SetSortedFlag(sorted) => (
                     "set_sorted",
                     match sorted {
@@ -1253,6 +1370,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::FillNullWithStrategy(_) => {
                     return Err(PyNotImplementedError::new_err("fill null with strategy"));
                 },
+                FunctionExpr::FillNullBy { forward, limit } => {
+                    ("fill_null_by", forward, Wrap(*limit)).into_py_any(py)
+                },
                 FunctionExpr::GatherEvery { n, offset } => {
                     ("gather_every", offset, n).into_py_any(py)
                 },
@@ -587,9 +587,12 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                     *swapping,
                 )
                     .into_py_any(py)?,
-                FunctionIR::Explode { columns, schema: _ } => (
+                FunctionIR::Explode {
+                    columns, position, ..
+                } => (
                     "explode",
                     columns.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+                    position.as_ref().map(|s| s.to_string()),
                 )
                     .into_py_any(py)?,
                 #[cfg(feature = "pivot")]
@@ -610,6 +613,33 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                     schema: _,
                     offset,
                 } => ("row_index", name.to_string(), offset.unwrap_or(0)).into_py_any(py)?,
+                FunctionIR::SetSortedBy {
+                    columns,
+                    descending,
+                } => (
+                    "set_sorted_by",
+                    columns.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+                    descending.to_vec(),
+                )
+                    .into_py_any(py)?,
+                FunctionIR::SortWithinPartitions {
+                    partition_by,
+                    by,
+                    sort_options,
+                } => (
+                    "sort_within_partitions",
+                    partition_by
+                        .iter()
+                        .map(|s| s.to_string())
+                        .collect::<Vec<_>>(),
+                    by.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+                    (
+                        sort_options.maintain_order,
+                        sort_options.nulls_last.clone(),
+                        sort_options.descending.clone(),
+                    ),
+                )
+                    .into_py_any(py)?,
                 FunctionIR::FastCount {
                     sources,
                     scan_type,
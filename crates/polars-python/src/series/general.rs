@@ -194,9 +194,24 @@ impl PySeries {
         self.series.n_chunks()
     }
 
-    fn append(&mut self, other: &PySeries) -> PyResult<()> {
+    #[pyo3(signature = (name, metadata=None))]
+    fn to_extension(&self, py: Python, name: &str, metadata: Option<&str>) -> PyResult<Self> {
+        py.enter_polars_series(|| self.series.to_extension(name, metadata))
+    }
+
+    fn to_storage(&self) -> Self {
+        self.series.to_storage().into()
+    }
+
+    fn extension_type(&self) -> Option<(String, Option<String>)> {
+        let (name, metadata) = self.series.extension_type()?;
+        Some((name.to_string(), metadata.map(|m| m.to_string())))
+    }
+
+    #[pyo3(signature = (other, enum_policy=Wrap(EnumAppendPolicy::Strict)))]
+    fn append(&mut self, other: &PySeries, enum_policy: Wrap<EnumAppendPolicy>) -> PyResult<()> {
         self.series
-            .append(&other.series)
+            .append_with_policy(&other.series, enum_policy.0)
             .map_err(PyPolarsErr::from)?;
         Ok(())
     }
@@ -1,2 +1,2 @@
 pub mod schema;
-pub use schema::Schema;
+pub use schema::{Schema, SchemaDiff};
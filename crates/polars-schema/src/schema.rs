@@ -343,6 +343,55 @@ impl<D> Schema<D> {
     }
 }
 
+impl<D: PartialEq + Clone> Schema<D> {
+    /// The changes that turn this schema into `other`, by column name.
+    ///
+    /// The columns are listed in the order of the schema they are in, the retyped columns in the
+    /// order of `other`. A change in the order of the columns is not a difference.
+    pub fn diff(&self, other: &Self) -> SchemaDiff<D> {
+        let added = other
+            .iter()
+            .filter(|(name, _)| !self.contains(name))
+            .map(|(name, dtype)| (name.clone(), dtype.clone()))
+            .collect();
+        let removed = self
+            .iter()
+            .filter(|(name, _)| !other.contains(name))
+            .map(|(name, dtype)| (name.clone(), dtype.clone()))
+            .collect();
+        let retyped = other
+            .iter()
+            .filter_map(|(name, dtype)| {
+                let old = self.get(name)?;
+                (old != dtype).then(|| (name.clone(), old.clone(), dtype.clone()))
+            })
+            .collect();
+        SchemaDiff {
+            added,
+            removed,
+            retyped,
+        }
+    }
+}
+
+/// The difference between two schemas, see [`Schema::diff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SchemaDiff<D> {
+    /// The columns that are only in the new schema.
+    pub added: Vec<(PlSmallStr, D)>,
+    /// The columns that are only in the old schema.
+    pub removed: Vec<(PlSmallStr, D)>,
+    /// The columns with a different type, as `(name, old, new)`.
+    pub retyped: Vec<(PlSmallStr, D, D)>,
+}
+
+impl<D> SchemaDiff<D> {
+    /// Whether the schemas have the same columns with the same types.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.retyped.is_empty()
+    }
+}
+
 impl<D> Schema<D>
 where
     D: Clone + Default,
@@ -1,7 +1,6 @@
 use std::cell::RefCell;
 use std::ops::Deref;
 
-use polars_core::frame::row::Row;
 use polars_core::prelude::*;
 use polars_lazy::prelude::*;
 use polars_ops::frame::JoinCoalesce;
@@ -321,11 +320,11 @@ impl SQLContext {
 
             #[cfg(feature = "semi_anti_join")]
             SetExpr::SetOperation {
-                op: SetOperator::Intersect | SetOperator::Except,
+                op: op @ (SetOperator::Intersect | SetOperator::Except),
                 set_quantifier,
                 left,
                 right,
-            } => self.process_except_intersect(left, right, set_quantifier, query),
+            } => self.process_except_intersect(op, left, right, set_quantifier, query),
 
             SetExpr::Values(Values {
                 explicit_row: _,
@@ -355,44 +354,47 @@ impl SQLContext {
     #[cfg(feature = "semi_anti_join")]
     fn process_except_intersect(
         &mut self,
+        op: &SetOperator,
         left: &SetExpr,
         right: &SetExpr,
         quantifier: &SetQuantifier,
         query: &Query,
     ) -> PolarsResult<LazyFrame> {
-        let (join_type, op_name) = match *query.body {
-            SetExpr::SetOperation {
-                op: SetOperator::Except,
-                ..
-            } => (JoinType::Anti, "EXCEPT"),
-            _ => (JoinType::Semi, "INTERSECT"),
+        let op_name = match op {
+            SetOperator::Except => "EXCEPT",
+            _ => "INTERSECT",
         };
         let mut lf = self.process_query(left, query)?;
         let mut rf = self.process_query(right, query)?;
-        let join = lf
-            .clone()
-            .join_builder()
-            .with(rf.clone())
-            .how(join_type)
-            .join_nulls(true);
 
-        let lf_schema = self.get_frame_schema(&mut lf)?;
-        let lf_cols: Vec<_> = lf_schema.iter_names().map(|nm| col(nm.clone())).collect();
-        let joined_tbl = match quantifier {
-            SetQuantifier::ByName => join.on(lf_cols).finish(),
-            SetQuantifier::Distinct | SetQuantifier::None => {
+        let rf = match quantifier {
+            SetQuantifier::ByName | SetQuantifier::DistinctByName | SetQuantifier::AllByName => rf,
+            SetQuantifier::Distinct | SetQuantifier::None | SetQuantifier::All => {
+                // Match the columns by position.
+                let lf_schema = self.get_frame_schema(&mut lf)?;
                 let rf_schema = self.get_frame_schema(&mut rf)?;
-                let rf_cols: Vec<_> = rf_schema.iter_names().map(|nm| col(nm.clone())).collect();
-                if lf_cols.len() != rf_cols.len() {
+                if lf_schema.len() != rf_schema.len() {
                     polars_bail!(SQLInterface: "{} requires equal number of columns in each table (use '{} BY NAME' to combine mismatched tables)", op_name, op_name)
                 }
-                join.left_on(lf_cols).right_on(rf_cols).finish()
+                let rf_cols: Vec<_> = rf_schema
+                    .iter_names()
+                    .zip(lf_schema.iter_names())
+                    .map(|(r, l)| col(r.clone()).alias(l.clone()))
+                    .collect();
+                rf.select(rf_cols)
             },
+            #[allow(unreachable_patterns)]
             _ => {
                 polars_bail!(SQLInterface: "'{} {}' is not supported", op_name, quantifier.to_string())
             },
         };
-        Ok(joined_tbl.unique(None, UniqueKeepStrategy::Any))
+        let keep_duplicates = matches!(quantifier, SetQuantifier::All | SetQuantifier::AllByName);
+        match (op, keep_duplicates) {
+            (SetOperator::Except, false) => lf.except(rf, None),
+            (SetOperator::Except, true) => lf.except_all(rf, None),
+            (_, false) => lf.intersect(rf, None),
+            (_, true) => lf.intersect_all(rf, None),
+        }
     }
 
     fn process_union(
@@ -440,22 +442,24 @@ impl SQLContext {
     }
 
     fn process_values(&mut self, values: &[Vec<SQLExpr>]) -> PolarsResult<LazyFrame> {
-        let frame_rows: Vec<Row> = values.iter().map(|row| {
-            let row_data: Result<Vec<_>, _> = row.iter().map(|expr| {
-                let expr = parse_sql_expr(expr, self, None)?;
-                match expr {
-                    Expr::Literal(value) => {
-                        value.to_any_value()
-                            .ok_or_else(|| polars_err!(SQLInterface: "invalid literal value: {:?}", value))
-                            .map(|av| av.into_static())
-                    },
-                    _ => polars_bail!(SQLInterface: "VALUES clause expects literals; found {}", expr),
-                }
-            }).collect();
-            row_data.map(Row::new)
-        }).collect::<Result<_, _>>()?;
+        let rows = values
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|expr| {
+                        let expr = parse_sql_expr(expr, self, None)?;
+                        polars_ensure!(
+                            !has_expr(&expr, |e| matches!(e, Expr::Column(_) | Expr::Wildcard)),
+                            SQLInterface: "VALUES clause expects constant expressions; found {}", expr
+                        );
+                        Ok(expr)
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
 
-        Ok(DataFrame::from_rows(frame_rows.as_ref())?.lazy())
+        polars_lazy::dsl::values(&rows, None)
+            .map_err(|err| polars_err!(SQLInterface: "invalid VALUES clause: {}", err))
     }
 
     // EXPLAIN SELECT * FROM DF
@@ -856,6 +860,7 @@ impl SQLContext {
                                 suffix: None,
                                 slice: None,
                                 nulls_equal: false,
+                                nulls_equal_per_key: None,
                                 coalesce: Default::default(),
                                 maintain_order: polars_ops::frame::MaintainOrderJoin::Left,
                             },
@@ -1027,7 +1032,10 @@ impl SQLContext {
         constraint: &JoinConstraint,
         join_type: JoinType,
     ) -> PolarsResult<LazyFrame> {
-        let (left_on, right_on) = process_join_constraint(constraint, tbl_left, tbl_right)?;
+        let (left_on, right_on, nulls_equal) =
+            process_join_constraint(constraint, tbl_left, tbl_right)?;
+        // Only set when a key is compared with `IS NOT DISTINCT FROM` or `<=>`.
+        let nulls_equal = nulls_equal.iter().any(|&eq| eq).then_some(nulls_equal);
 
         let joined = tbl_left
             .frame
@@ -1036,6 +1044,7 @@ impl SQLContext {
             .with(tbl_right.frame.clone())
             .left_on(left_on)
             .right_on(right_on)
+            .join_nulls_per_key(nulls_equal)
             .how(join_type)
             .suffix(format!(":{}", tbl_right.name))
             .coalesce(JoinCoalesce::KeepColumns)
@@ -1594,32 +1603,55 @@ fn is_regex_colname(nm: &str) -> bool {
     nm.starts_with('^') && nm.ends_with('$')
 }
 
+/// Join keys of the left and right table, and whether nulls are equal for every key.
+type JoinKeys = (Vec<Expr>, Vec<Expr>, Vec<bool>);
+
+fn process_join_key(
+    left: &SQLExpr,
+    right: &SQLExpr,
+    nulls_equal: bool,
+    tbl_left: &TableInfo,
+    tbl_right: &TableInfo,
+) -> PolarsResult<JoinKeys> {
+    match (left, right) {
+        (SQLExpr::CompoundIdentifier(left), SQLExpr::CompoundIdentifier(right)) => {
+            let (left_on, right_on) =
+                collect_compound_identifiers(left, right, &tbl_left.name, &tbl_right.name)?;
+            Ok((left_on, right_on, vec![nulls_equal]))
+        },
+        _ => {
+            polars_bail!(SQLInterface: "only equi-join constraints (on identifiers) are currently supported; found lhs={:?}, rhs={:?}", left, right)
+        },
+    }
+}
+
 fn process_join_on(
     expression: &sqlparser::ast::Expr,
     tbl_left: &TableInfo,
     tbl_right: &TableInfo,
-) -> PolarsResult<(Vec<Expr>, Vec<Expr>)> {
+) -> PolarsResult<JoinKeys> {
     match expression {
         SQLExpr::BinaryOp { left, op, right } => match op {
             BinaryOperator::And => {
-                let (mut left_i, mut right_i) = process_join_on(left, tbl_left, tbl_right)?;
-                let (mut left_j, mut right_j) = process_join_on(right, tbl_left, tbl_right)?;
+                let (mut left_i, mut right_i, mut nulls_equal_i) =
+                    process_join_on(left, tbl_left, tbl_right)?;
+                let (mut left_j, mut right_j, mut nulls_equal_j) =
+                    process_join_on(right, tbl_left, tbl_right)?;
                 left_i.append(&mut left_j);
                 right_i.append(&mut right_j);
-                Ok((left_i, right_i))
-            },
-            BinaryOperator::Eq => match (left.as_ref(), right.as_ref()) {
-                (SQLExpr::CompoundIdentifier(left), SQLExpr::CompoundIdentifier(right)) => {
-                    collect_compound_identifiers(left, right, &tbl_left.name, &tbl_right.name)
-                },
-                _ => {
-                    polars_bail!(SQLInterface: "only equi-join constraints (on identifiers) are currently supported; found lhs={:?}, rhs={:?}", left, right)
-                },
+                nulls_equal_i.append(&mut nulls_equal_j);
+                Ok((left_i, right_i, nulls_equal_i))
             },
+            BinaryOperator::Eq => process_join_key(left, right, false, tbl_left, tbl_right),
+            // Null-safe equality, "x <=> y".
+            BinaryOperator::Spaceship => process_join_key(left, right, true, tbl_left, tbl_right),
             _ => {
                 polars_bail!(SQLInterface: "only equi-join constraints (combined with 'AND') are currently supported; found op = '{:?}'", op)
             },
         },
+        SQLExpr::IsNotDistinctFrom(left, right) => {
+            process_join_key(left, right, true, tbl_left, tbl_right)
+        },
         SQLExpr::Nested(expr) => process_join_on(expr, tbl_left, tbl_right),
         _ => {
             polars_bail!(SQLInterface: "only equi-join constraints are currently supported; found expression = {:?}", expression)
@@ -1631,14 +1663,15 @@ fn process_join_constraint(
     constraint: &JoinConstraint,
     tbl_left: &TableInfo,
     tbl_right: &TableInfo,
-) -> PolarsResult<(Vec<Expr>, Vec<Expr>)> {
+) -> PolarsResult<JoinKeys> {
     match constraint {
-        JoinConstraint::On(expr @ SQLExpr::BinaryOp { .. }) => {
-            process_join_on(expr, tbl_left, tbl_right)
-        },
+        JoinConstraint::On(
+            expr @ (SQLExpr::BinaryOp { .. } | SQLExpr::IsNotDistinctFrom(..) | SQLExpr::Nested(_)),
+        ) => process_join_on(expr, tbl_left, tbl_right),
         JoinConstraint::Using(idents) if !idents.is_empty() => {
             let using: Vec<Expr> = idents.iter().map(|id| col(id.value.as_str())).collect();
-            Ok((using.clone(), using))
+            let nulls_equal = vec![false; using.len()];
+            Ok((using.clone(), using, nulls_equal))
         },
         JoinConstraint::Natural => {
             let left_names = tbl_left.schema.iter_names().collect::<PlHashSet<_>>();
@@ -1650,7 +1683,8 @@ fn process_join_constraint(
             if on.is_empty() {
                 polars_bail!(SQLInterface: "no common columns found for NATURAL JOIN")
             }
-            Ok((on.clone(), on))
+            let nulls_equal = vec![false; on.len()];
+            Ok((on.clone(), on, nulls_equal))
         },
         _ => polars_bail!(SQLInterface: "unsupported SQL join constraint:\n{:?}", constraint),
     }
@@ -0,0 +1,897 @@
+//! Parsing of expressions that are written in the syntax of the Python API, e.g.
+//! `col('a') * 2 + col('b').log()`, see [`parse_expr`].
+//!
+//! Only a subset of the API is supported: the operators, the functions `col`, `lit`, `len`,
+//! `all`, `when` and `coalesce`, and the common methods of expressions and of their `str` and
+//! `dt` namespaces. Names may be prefixed with `pl.`, so that expressions can be copied from
+//! Python code.
+use std::collections::VecDeque;
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_ops::series::RoundMode;
+use polars_plan::dsl::{ChainedThen, ChainedWhen, Operator, Then, When};
+use polars_plan::plans::DynLiteralValue;
+use recursive::recursive;
+
+use crate::sql_expr;
+
+/// The syntax of a textual expression, see [`parse_expr`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ExprDialect {
+    /// The syntax of the Python API, e.g. `col('a') * 2 + col('b').log()`.
+    #[default]
+    Python,
+    /// A SQL expression, e.g. `a * 2 + LN(b)`, as parsed by [`sql_expr`].
+    Sql,
+}
+
+/// Parse a textual expression into an [`Expr`], e.g. to define computed columns in a
+/// configuration file.
+///
+/// ```rust
+/// # use polars_sql::{ExprDialect, parse_expr};
+/// # use polars_core::prelude::*;
+/// # use polars_lazy::prelude::*;
+/// # fn main() {
+///
+/// let df = df! {
+///    "a" =>  [1, 2, 3],
+///    "b" =>  [4.0, 5.0, 6.0],
+/// }
+/// .unwrap();
+/// let expr = parse_expr("col('a') * 2 + col('b').log()", ExprDialect::Python).unwrap();
+/// df.lazy().select(vec![expr]).collect().unwrap();
+/// # }
+/// ```
+pub fn parse_expr<S: AsRef<str>>(s: S, dialect: ExprDialect) -> PolarsResult<Expr> {
+    let s = s.as_ref();
+    match dialect {
+        ExprDialect::Python => {
+            let mut parser = ExprParser {
+                tokens: tokenize(s)?,
+                pos: 0,
+                depth: 0,
+            };
+            let value = parser.parse_comparison()?;
+            if parser.peek() != &Token::End {
+                return Err(parser.error("unexpected token"));
+            }
+            value.into_expr()
+        },
+        ExprDialect::Sql => sql_expr(s),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Int(i128),
+    Float(f64),
+    Str(String),
+    Punct(&'static str),
+    End,
+}
+
+// Longer punctuation first, so that e.g. `**` isn't read as two `*`.
+const PUNCTUATION: &[&str] = &[
+    "**", "//", "==", "!=", "<=", ">=", "<", ">", "+", "-", "*", "/", "%", "&", "|", "^", "~", "(",
+    ")", "[", "]", ",", ".", "=",
+];
+
+fn syntax_error(msg: &str, pos: usize) -> PolarsError {
+    polars_err!(SQLSyntax: "cannot parse expression: {} at position {}", msg, pos)
+}
+
+/// Split `s` into tokens with their byte positions.
+fn tokenize(s: &str) -> PolarsResult<Vec<(usize, Token)>> {
+    let bytes = s.as_bytes();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        let start = i;
+        if c.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let token = if c.is_ascii_digit() {
+            let is_digit = |i: usize| {
+                bytes
+                    .get(i)
+                    .is_some_and(|c| c.is_ascii_digit() || *c == b'_')
+            };
+            while is_digit(i) {
+                i += 1;
+            }
+            let mut is_float = false;
+            // A `.` that isn't followed by a digit is a method call on an integer.
+            if bytes.get(i) == Some(&b'.') && bytes.get(i + 1).is_some_and(u8::is_ascii_digit) {
+                is_float = true;
+                i += 1;
+                while is_digit(i) {
+                    i += 1;
+                }
+            }
+            if matches!(bytes.get(i), Some(b'e' | b'E')) {
+                is_float = true;
+                i += 1;
+                if matches!(bytes.get(i), Some(b'+' | b'-')) {
+                    i += 1;
+                }
+                while is_digit(i) {
+                    i += 1;
+                }
+            }
+            let number = s[start..i].replace('_', "");
+            if is_float {
+                Token::Float(
+                    number
+                        .parse()
+                        .map_err(|_| syntax_error("invalid number", start))?,
+                )
+            } else {
+                Token::Int(
+                    number
+                        .parse()
+                        .map_err(|_| syntax_error("invalid number", start))?,
+                )
+            }
+        } else if c == b'\'' || c == b'"' {
+            let mut out = String::new();
+            let mut chars = s[i + 1..].char_indices();
+            loop {
+                let Some((j, ch)) = chars.next() else {
+                    return Err(syntax_error("unterminated string", start));
+                };
+                match ch {
+                    '\\' => {
+                        let Some((_, escaped)) = chars.next() else {
+                            return Err(syntax_error("unterminated string", start));
+                        };
+                        out.push(match escaped {
+                            'n' => '\n',
+                            't' => '\t',
+                            'r' => '\r',
+                            '0' => '\0',
+                            other => other,
+                        });
+                    },
+                    ch if ch == c as char => {
+                        i += 1 + j + 1;
+                        break;
+                    },
+                    ch => out.push(ch),
+                }
+            }
+            Token::Str(out)
+        } else if c.is_ascii_alphabetic() || c == b'_' {
+            while bytes
+                .get(i)
+                .is_some_and(|c| c.is_ascii_alphanumeric() || *c == b'_')
+            {
+                i += 1;
+            }
+            Token::Ident(s[start..i].to_string())
+        } else {
+            let Some(punct) = PUNCTUATION.iter().find(|p| s[i..].starts_with(**p)) else {
+                return Err(syntax_error("unexpected character", start));
+            };
+            i += punct.len();
+            Token::Punct(*punct)
+        };
+        tokens.push((start, token));
+    }
+    tokens.push((s.len(), Token::End));
+    Ok(tokens)
+}
+
+/// The value of a parsed (sub)expression. Literals are kept as they are until they are used as
+/// an expression, so that they can be passed as the parameters of functions.
+enum Value {
+    Expr(Expr),
+    Int(i128),
+    Float(f64),
+    Str(String),
+    Bool(bool),
+    Null,
+    List(Vec<Value>),
+    DataType(DataType),
+    When(When),
+    Then(Then),
+    ChainedWhen(ChainedWhen),
+    ChainedThen(ChainedThen),
+}
+
+fn null() -> Expr {
+    lit(LiteralValue::untyped_null())
+}
+
+impl Value {
+    fn kind(&self) -> &'static str {
+        match self {
+            Value::Expr(_) => "an expression",
+            Value::Int(_) => "an integer",
+            Value::Float(_) => "a float",
+            Value::Str(_) => "a string",
+            Value::Bool(_) => "a boolean",
+            Value::Null => "None",
+            Value::List(_) => "a list",
+            Value::DataType(_) => "a data type",
+            Value::When(_) | Value::ChainedWhen(_) => "a `when` without `then`",
+            Value::Then(_) | Value::ChainedThen(_) => "a `when-then`",
+        }
+    }
+
+    /// Like [`Value::into_expr`], but a string is a column name instead of a literal, as in the
+    /// arguments of e.g. `over` and `then` in Python.
+    fn into_expr_or_col(self) -> PolarsResult<Expr> {
+        match self {
+            Value::Str(name) => Ok(col(name.as_str())),
+            v => v.into_expr(),
+        }
+    }
+
+    fn into_expr(self) -> PolarsResult<Expr> {
+        Ok(match self {
+            Value::Expr(e) => e,
+            Value::Int(v) => Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(v))),
+            Value::Float(v) => Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Float(v))),
+            Value::Str(v) => lit(v.as_str()),
+            Value::Bool(v) => lit(v),
+            Value::Null => null(),
+            Value::List(values) => {
+                let values = values
+                    .into_iter()
+                    .map(|v| {
+                        Ok(match v {
+                            Value::Int(v) => AnyValue::Int64(i64::try_from(v).map_err(
+                                |_| polars_err!(SQLSyntax: "integer {} is out of range", v),
+                            )?),
+                            Value::Float(v) => AnyValue::Float64(v),
+                            Value::Str(v) => AnyValue::StringOwned(v.into()),
+                            Value::Bool(v) => AnyValue::Boolean(v),
+                            Value::Null => AnyValue::Null,
+                            v => polars_bail!(
+                                SQLSyntax: "lists may only contain literals, got {}", v.kind()
+                            ),
+                        })
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let s = Series::from_any_values(PlSmallStr::EMPTY, &values, false)?;
+                lit(s.implode()?.into_series())
+            },
+            Value::Then(then) => then.otherwise(null()),
+            Value::ChainedThen(then) => then.otherwise(null()),
+            v @ (Value::DataType(_) | Value::When(_) | Value::ChainedWhen(_)) => {
+                polars_bail!(SQLSyntax: "expected an expression, got {}", v.kind())
+            },
+        })
+    }
+}
+
+fn parse_dtype(name: &str) -> Option<DataType> {
+    Some(match name {
+        "Boolean" => DataType::Boolean,
+        "Int8" => DataType::Int8,
+        "Int16" => DataType::Int16,
+        "Int32" => DataType::Int32,
+        "Int64" => DataType::Int64,
+        "UInt8" => DataType::UInt8,
+        "UInt16" => DataType::UInt16,
+        "UInt32" => DataType::UInt32,
+        "UInt64" => DataType::UInt64,
+        "Float32" => DataType::Float32,
+        "Float64" => DataType::Float64,
+        "String" | "Utf8" => DataType::String,
+        "Binary" => DataType::Binary,
+        "Date" => DataType::Date,
+        "Datetime" => DataType::Datetime(TimeUnit::Microseconds, None),
+        "Duration" => DataType::Duration(TimeUnit::Microseconds),
+        "Time" => DataType::Time,
+        "Null" => DataType::Null,
+        _ => return None,
+    })
+}
+
+/// The arguments of a call of `function`.
+struct Args {
+    function: String,
+    positional: VecDeque<Value>,
+    keyword: Vec<(String, Value)>,
+}
+
+impl Args {
+    /// Take the next argument, by position or else by `name`.
+    fn get(&mut self, name: &str) -> Option<Value> {
+        if let Some(v) = self.positional.pop_front() {
+            return Some(v);
+        }
+        let idx = self.keyword.iter().position(|(n, _)| n == name)?;
+        Some(self.keyword.remove(idx).1)
+    }
+
+    fn required(&mut self, name: &str) -> PolarsResult<Value> {
+        self.get(name).ok_or_else(
+            || polars_err!(SQLSyntax: "'{}' is missing argument '{}'", self.function, name),
+        )
+    }
+
+    fn invalid(&self, name: &str, expected: &str, got: &Value) -> PolarsError {
+        polars_err!(
+            SQLSyntax: "argument '{}' of '{}' should be {}, got {}",
+            name, self.function, expected, got.kind()
+        )
+    }
+
+    fn expr(&mut self, name: &str) -> PolarsResult<Expr> {
+        self.required(name)?.into_expr()
+    }
+
+    /// An expression where a string is a column name.
+    fn expr_or_col(&mut self, name: &str) -> PolarsResult<Expr> {
+        self.required(name)?.into_expr_or_col()
+    }
+
+    /// An expression that defaults to null.
+    fn opt_expr(&mut self, name: &str) -> PolarsResult<Expr> {
+        self.get(name).map_or(Ok(null()), Value::into_expr)
+    }
+
+    fn int(&mut self, name: &str, default: i64) -> PolarsResult<i64> {
+        match self.get(name) {
+            None => Ok(default),
+            Some(Value::Int(v)) => i64::try_from(v)
+                .map_err(|_| polars_err!(SQLSyntax: "integer {} is out of range", v)),
+            Some(v) => Err(self.invalid(name, "an integer", &v)),
+        }
+    }
+
+    fn uint<T: TryFrom<i64>>(&mut self, name: &str, default: i64) -> PolarsResult<T> {
+        let v = self.int(name, default)?;
+        T::try_from(v).map_err(
+            |_| polars_err!(SQLSyntax: "argument '{}' of '{}' is out of range", name, self.function),
+        )
+    }
+
+    fn float(&mut self, name: &str, default: f64) -> PolarsResult<f64> {
+        match self.get(name) {
+            None => Ok(default),
+            Some(Value::Int(v)) => Ok(v as f64),
+            Some(Value::Float(v)) => Ok(v),
+            Some(v) => Err(self.invalid(name, "a number", &v)),
+        }
+    }
+
+    fn bool(&mut self, name: &str, default: bool) -> PolarsResult<bool> {
+        match self.get(name) {
+            None => Ok(default),
+            Some(Value::Bool(v)) => Ok(v),
+            Some(v) => Err(self.invalid(name, "a boolean", &v)),
+        }
+    }
+
+    fn string(&mut self, name: &str) -> PolarsResult<String> {
+        match self.required(name)? {
+            Value::Str(v) => Ok(v),
+            v => Err(self.invalid(name, "a string", &v)),
+        }
+    }
+
+    fn dtype(&mut self, name: &str) -> PolarsResult<DataType> {
+        match self.required(name)? {
+            Value::DataType(dtype) => Ok(dtype),
+            Value::Str(v) => {
+                parse_dtype(&v).ok_or_else(|| polars_err!(SQLSyntax: "unknown data type '{}'", v))
+            },
+            v => Err(self.invalid(name, "a data type", &v)),
+        }
+    }
+
+    /// All remaining positional arguments.
+    fn rest(&mut self) -> Vec<Value> {
+        self.positional.drain(..).collect()
+    }
+
+    /// All remaining positional arguments, where strings are column names.
+    fn rest_exprs(&mut self) -> PolarsResult<Vec<Expr>> {
+        self.rest()
+            .into_iter()
+            .map(Value::into_expr_or_col)
+            .collect()
+    }
+
+    fn finish(self) -> PolarsResult<()> {
+        polars_ensure!(
+            self.positional.is_empty() && self.keyword.is_empty(),
+            SQLSyntax: "too many arguments for '{}'", self.function
+        );
+        Ok(())
+    }
+}
+
+fn call_function(name: &str, mut args: Args) -> PolarsResult<Value> {
+    let out = match name {
+        "col" => {
+            let names = args
+                .rest()
+                .into_iter()
+                .map(|v| match v {
+                    Value::Str(name) => Ok(name),
+                    v => Err(args.invalid("name", "a string", &v)),
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            match names.as_slice() {
+                [] => polars_bail!(SQLSyntax: "'col' is missing argument 'name'"),
+                [name] => col(name.as_str()),
+                _ => cols(names),
+            }
+        },
+        "lit" => {
+            let value = args.expr("value")?;
+            match args.get("dtype") {
+                None => value,
+                Some(Value::DataType(dtype)) => value.cast(dtype),
+                Some(v) => return Err(args.invalid("dtype", "a data type", &v)),
+            }
+        },
+        "len" => len(),
+        "all" => all(),
+        "when" => {
+            // Multiple conditions are combined with `&`, like in Python.
+            let conditions = args.rest_exprs()?;
+            let Some(condition) = conditions.into_iter().reduce(|a, b| a.and(b)) else {
+                polars_bail!(SQLSyntax: "'when' is missing argument 'predicate'")
+            };
+            args.finish()?;
+            return Ok(Value::When(when(condition)));
+        },
+        "coalesce" => coalesce(&args.rest_exprs()?),
+        _ => polars_bail!(SQLInterface: "unknown function '{}'", name),
+    };
+    args.finish()?;
+    Ok(Value::Expr(out))
+}
+
+fn call_method(receiver: Value, name: &str, mut args: Args) -> PolarsResult<Value> {
+    let e = match (receiver, name) {
+        (Value::When(w), "then") => {
+            let out = Value::Then(w.then(args.expr_or_col("statement")?));
+            args.finish()?;
+            return Ok(out);
+        },
+        (Value::ChainedWhen(w), "then") => {
+            let out = Value::ChainedThen(w.then(args.expr_or_col("statement")?));
+            args.finish()?;
+            return Ok(out);
+        },
+        (Value::Then(t), "when") => {
+            let out = Value::ChainedWhen(t.when(args.expr_or_col("predicate")?));
+            args.finish()?;
+            return Ok(out);
+        },
+        (Value::ChainedThen(t), "when") => {
+            let out = Value::ChainedWhen(t.when(args.expr_or_col("predicate")?));
+            args.finish()?;
+            return Ok(out);
+        },
+        (Value::Then(t), "otherwise") => {
+            let out = t.otherwise(args.expr_or_col("statement")?);
+            args.finish()?;
+            return Ok(Value::Expr(out));
+        },
+        (Value::ChainedThen(t), "otherwise") => {
+            let out = t.otherwise(args.expr_or_col("statement")?);
+            args.finish()?;
+            return Ok(Value::Expr(out));
+        },
+        (receiver, _) => receiver.into_expr()?,
+    };
+
+    let out = match name {
+        "alias" => e.alias(args.string("name")?),
+        "cast" => {
+            let dtype = args.dtype("dtype")?;
+            if args.bool("strict", true)? {
+                e.strict_cast(dtype)
+            } else {
+                e.cast(dtype)
+            }
+        },
+        "abs" => e.abs(),
+        "sqrt" => e.sqrt(),
+        "exp" => e.exp(),
+        "log" => e.log(args.float("base", std::f64::consts::E)?),
+        "log10" => e.log(10.0),
+        "log1p" => e.log1p(),
+        "sign" => e.sign(),
+        "floor" => e.floor(),
+        "ceil" => e.ceil(),
+        "round" => e.round(args.uint("decimals", 0)?, RoundMode::default()),
+        "pow" => e.pow(args.expr_or_col("exponent")?),
+        "clip" => {
+            let lower = args.get("lower_bound");
+            let upper = args.get("upper_bound");
+            match (lower, upper) {
+                (Some(lower), Some(upper)) => {
+                    e.clip(lower.into_expr_or_col()?, upper.into_expr_or_col()?)
+                },
+                (Some(lower), None) => e.clip_min(lower.into_expr_or_col()?),
+                (None, Some(upper)) => e.clip_max(upper.into_expr_or_col()?),
+                (None, None) => e,
+            }
+        },
+        "sum" => e.sum(),
+        "mean" => e.mean(),
+        "median" => e.median(),
+        "min" => e.min(),
+        "max" => e.max(),
+        "std" => e.std(args.uint("ddof", 1)?),
+        "var" => e.var(args.uint("ddof", 1)?),
+        "count" => e.count(),
+        "len" => e.len(),
+        "n_unique" => e.n_unique(),
+        "first" => e.first(),
+        "last" => e.last(),
+        "head" => e.head(Some(args.uint("n", 10)?)),
+        "tail" => e.tail(Some(args.uint("n", 10)?)),
+        "cum_sum" => e.cum_sum(args.bool("reverse", false)?),
+        "shift" => e.shift(args.get("n").map_or(Ok(lit(1)), Value::into_expr)?),
+        "reverse" => e.reverse(),
+        "unique" => e.unique(),
+        "sort" => {
+            let descending = args.bool("descending", false)?;
+            let nulls_last = args.bool("nulls_last", false)?;
+            e.sort(
+                SortOptions::default()
+                    .with_order_descending(descending)
+                    .with_nulls_last(nulls_last),
+            )
+        },
+        "over" => {
+            let partition_by = args.rest_exprs()?;
+            polars_ensure!(
+                !partition_by.is_empty(),
+                SQLSyntax: "'over' is missing argument 'partition_by'"
+            );
+            e.over(partition_by)
+        },
+        "is_null" => e.is_null(),
+        "is_not_null" => e.is_not_null(),
+        "is_nan" => e.is_nan(),
+        "is_not_nan" => e.is_not_nan(),
+        "is_in" => e.is_in(args.expr("other")?, args.bool("nulls_equal", false)?),
+        "not_" => e.not(),
+        "fill_null" => e.fill_null(args.expr("value")?),
+        "fill_nan" => e.fill_nan(args.expr("value")?),
+        "drop_nulls" => e.drop_nulls(),
+        _ => polars_bail!(SQLInterface: "unknown method '{}'", name),
+    };
+    args.finish()?;
+    Ok(Value::Expr(out))
+}
+
+fn call_namespace_method(
+    e: Expr,
+    namespace: &str,
+    name: &str,
+    mut args: Args,
+) -> PolarsResult<Expr> {
+    let out = match (namespace, name) {
+        ("str", "to_uppercase") => e.str().to_uppercase(),
+        ("str", "to_lowercase") => e.str().to_lowercase(),
+        ("str", "len_chars") => e.str().len_chars(),
+        ("str", "len_bytes") => e.str().len_bytes(),
+        ("str", "contains") => {
+            let pattern = args.expr("pattern")?;
+            if args.bool("literal", false)? {
+                e.str().contains_literal(pattern)
+            } else {
+                e.str().contains(pattern, args.bool("strict", true)?)
+            }
+        },
+        ("str", "starts_with") => e.str().starts_with(args.expr("prefix")?),
+        ("str", "ends_with") => e.str().ends_with(args.expr("suffix")?),
+        ("str", "strip_chars") => e.str().strip_chars(args.opt_expr("characters")?),
+        ("str", "slice") => {
+            let offset = args.expr("offset")?;
+            e.str().slice(offset, args.opt_expr("length")?)
+        },
+        ("str", "replace") | ("str", "replace_all") => {
+            let pattern = args.expr("pattern")?;
+            let value = args.expr("value")?;
+            let literal = args.bool("literal", false)?;
+            if name == "replace" {
+                e.str().replace(pattern, value, literal)
+            } else {
+                e.str().replace_all(pattern, value, literal)
+            }
+        },
+        ("dt", "year") => e.dt().year(),
+        ("dt", "month") => e.dt().month(),
+        ("dt", "day") => e.dt().day(),
+        ("dt", "weekday") => e.dt().weekday(),
+        ("dt", "hour") => e.dt().hour(),
+        ("dt", "minute") => e.dt().minute(),
+        ("dt", "second") => e.dt().second(),
+        _ => polars_bail!(SQLInterface: "unknown method '{}.{}'", namespace, name),
+    };
+    args.finish()?;
+    Ok(out)
+}
+
+fn binary(left: Value, op: Operator, right: Value) -> PolarsResult<Value> {
+    Ok(Value::Expr(binary_expr(
+        left.into_expr()?,
+        op,
+        right.into_expr()?,
+    )))
+}
+
+/// The maximum nesting of parentheses, calls and unary operators, which bounds the depth of the
+/// parsed expression.
+const MAX_DEPTH: usize = 256;
+
+/// A recursive descent parser with the operator precedence of Python.
+struct ExprParser {
+    tokens: Vec<(usize, Token)>,
+    pos: usize,
+    /// The current nesting, see [`MAX_DEPTH`].
+    depth: usize,
+}
+
+impl ExprParser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos].1
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.tokens[self.pos].1.clone();
+        if token != Token::End {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn error(&self, msg: &str) -> PolarsError {
+        syntax_error(msg, self.tokens[self.pos].0)
+    }
+
+    fn eat(&mut self, punct: &str) -> bool {
+        if matches!(self.peek(), Token::Punct(p) if *p == punct) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, punct: &str) -> PolarsResult<()> {
+        if self.eat(punct) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{punct}'")))
+        }
+    }
+
+    fn ident(&mut self) -> PolarsResult<String> {
+        match self.peek().clone() {
+            Token::Ident(name) => {
+                self.pos += 1;
+                Ok(name)
+            },
+            _ => Err(self.error("expected a name")),
+        }
+    }
+
+    /// Parse the binary operators `ops` of one precedence level, which are left-associative.
+    fn parse_binary(
+        &mut self,
+        ops: &[(&str, Operator)],
+        operand: fn(&mut Self) -> PolarsResult<Value>,
+    ) -> PolarsResult<Value> {
+        let mut left = operand(self)?;
+        'outer: loop {
+            for (punct, op) in ops {
+                if self.eat(punct) {
+                    let right = operand(self)?;
+                    left = binary(left, *op, right)?;
+                    continue 'outer;
+                }
+            }
+            return Ok(left);
+        }
+    }
+
+    fn parse_comparison(&mut self) -> PolarsResult<Value> {
+        const OPS: &[(&str, Operator)] = &[
+            ("==", Operator::Eq),
+            ("!=", Operator::NotEq),
+            ("<=", Operator::LtEq),
+            (">=", Operator::GtEq),
+            ("<", Operator::Lt),
+            (">", Operator::Gt),
+        ];
+        let left = self.parse_or()?;
+        for (punct, op) in OPS {
+            if self.eat(punct) {
+                let right = self.parse_or()?;
+                if OPS.iter().any(|(p, _)| self.peek() == &Token::Punct(*p)) {
+                    return Err(self.error("chained comparisons are not supported"));
+                }
+                return binary(left, *op, right);
+            }
+        }
+        Ok(left)
+    }
+
+    fn parse_or(&mut self) -> PolarsResult<Value> {
+        self.parse_binary(&[("|", Operator::Or)], Self::parse_xor)
+    }
+
+    fn parse_xor(&mut self) -> PolarsResult<Value> {
+        self.parse_binary(&[("^", Operator::Xor)], Self::parse_and)
+    }
+
+    fn parse_and(&mut self) -> PolarsResult<Value> {
+        self.parse_binary(&[("&", Operator::And)], Self::parse_additive)
+    }
+
+    fn parse_additive(&mut self) -> PolarsResult<Value> {
+        self.parse_binary(
+            &[("+", Operator::Plus), ("-", Operator::Minus)],
+            Self::parse_multiplicative,
+        )
+    }
+
+    fn parse_multiplicative(&mut self) -> PolarsResult<Value> {
+        self.parse_binary(
+            &[
+                ("*", Operator::Multiply),
+                ("//", Operator::FloorDivide),
+                ("/", Operator::TrueDivide),
+                ("%", Operator::Modulus),
+            ],
+            Self::parse_unary,
+        )
+    }
+
+    /// Every level of nesting passes through here, so this is where the depth is limited.
+    #[recursive]
+    fn parse_unary(&mut self) -> PolarsResult<Value> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error("expression is nested too deeply"));
+        }
+        self.depth += 1;
+        let out = self.parse_unary_inner();
+        self.depth -= 1;
+        out
+    }
+
+    fn parse_unary_inner(&mut self) -> PolarsResult<Value> {
+        if self.eat("-") {
+            return Ok(match self.parse_unary()? {
+                Value::Int(v) => Value::Int(-v),
+                Value::Float(v) => Value::Float(-v),
+                v => Value::Expr(-v.into_expr()?),
+            });
+        }
+        if self.eat("+") {
+            return self.parse_unary();
+        }
+        if self.eat("~") {
+            return Ok(Value::Expr(self.parse_unary()?.into_expr()?.not()));
+        }
+        self.parse_power()
+    }
+
+    fn parse_power(&mut self) -> PolarsResult<Value> {
+        let base = self.parse_postfix()?;
+        if self.eat("**") {
+            // The exponent binds tighter on the left than a unary operator, e.g. `2 ** -1`.
+            let exponent = self.parse_unary()?;
+            return Ok(Value::Expr(base.into_expr()?.pow(exponent.into_expr()?)));
+        }
+        Ok(base)
+    }
+
+    fn parse_postfix(&mut self) -> PolarsResult<Value> {
+        let mut value = self.parse_primary()?;
+        while self.eat(".") {
+            let name = self.ident()?;
+            if matches!(name.as_str(), "str" | "dt") {
+                self.expect(".")?;
+                let method = self.ident()?;
+                self.expect("(")?;
+                let args = self.parse_args(format!("{name}.{method}"))?;
+                let e = value.into_expr()?;
+                value = Value::Expr(call_namespace_method(e, &name, &method, args)?);
+            } else {
+                self.expect("(")?;
+                let args = self.parse_args(name.clone())?;
+                value = call_method(value, &name, args)?;
+            }
+        }
+        Ok(value)
+    }
+
+    /// Parse the arguments of a call after the opening parenthesis.
+    fn parse_args(&mut self, function: String) -> PolarsResult<Args> {
+        let mut args = Args {
+            function,
+            positional: VecDeque::new(),
+            keyword: vec![],
+        };
+        while !self.eat(")") {
+            let is_keyword = matches!(self.peek(), Token::Ident(_))
+                && self.tokens[self.pos + 1].1 == Token::Punct("=");
+            if is_keyword {
+                let name = self.ident()?;
+                self.expect("=")?;
+                args.keyword.push((name, self.parse_comparison()?));
+            } else if !args.keyword.is_empty() {
+                return Err(self.error("positional argument follows keyword argument"));
+            } else {
+                args.positional.push_back(self.parse_comparison()?);
+            }
+            if !self.eat(",") {
+                self.expect(")")?;
+                break;
+            }
+        }
+        Ok(args)
+    }
+
+    fn parse_primary(&mut self) -> PolarsResult<Value> {
+        let is_operand = match self.peek() {
+            Token::End => false,
+            Token::Punct(p) => matches!(*p, "(" | "["),
+            _ => true,
+        };
+        if !is_operand {
+            return Err(self.error("expected an expression"));
+        }
+        match self.advance() {
+            Token::Int(v) => Ok(Value::Int(v)),
+            Token::Float(v) => Ok(Value::Float(v)),
+            Token::Str(v) => Ok(Value::Str(v)),
+            Token::Punct("(") => {
+                let value = self.parse_comparison()?;
+                self.expect(")")?;
+                Ok(value)
+            },
+            Token::Punct("[") => {
+                let mut values = vec![];
+                while !self.eat("]") {
+                    values.push(self.parse_comparison()?);
+                    if !self.eat(",") {
+                        self.expect("]")?;
+                        break;
+                    }
+                }
+                Ok(Value::List(values))
+            },
+            Token::Ident(mut name) => {
+                if name == "pl" && self.eat(".") {
+                    name = self.ident()?;
+                }
+                match name.as_str() {
+                    "True" => return Ok(Value::Bool(true)),
+                    "False" => return Ok(Value::Bool(false)),
+                    "None" => return Ok(Value::Null),
+                    _ => {},
+                }
+                if self.eat("(") {
+                    let args = self.parse_args(name.clone())?;
+                    return call_function(&name, args);
+                }
+                match parse_dtype(&name) {
+                    Some(dtype) => Ok(Value::DataType(dtype)),
+                    None => Err(polars_err!(SQLInterface: "unknown name '{}'", name)),
+                }
+            },
+            _ => unreachable!(),
+        }
+    }
+}
@@ -2,6 +2,7 @@
 //! This crate provides a SQL interface for Polars DataFrames
 #![deny(missing_docs)]
 mod context;
+mod expr_parser;
 pub mod function_registry;
 mod functions;
 pub mod keywords;
@@ -10,4 +11,5 @@ mod table_functions;
 mod types;
 
 pub use context::SQLContext;
+pub use expr_parser::{ExprDialect, parse_expr};
 pub use sql_expr::sql_expr;
@@ -0,0 +1,130 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_df() -> DataFrame {
+    df! {
+        "a" => [1i64, 2, 3, 4],
+        "b" => [1.0, 2.5, 4.0, 10.0],
+        "c" => ["x", "yy", "x", "zzz"],
+    }
+    .unwrap()
+}
+
+fn assert_parsed_eq(s: &str, expected: Expr) {
+    let df = create_df();
+    let parsed = parse_expr(s, ExprDialect::Python).unwrap();
+    let out = df.clone().lazy().select([parsed]).collect().unwrap();
+    let expected = df.lazy().select([expected]).collect().unwrap();
+    assert!(out.equals_missing(&expected), "{s}:\n{out}\n{expected}");
+}
+
+#[test]
+fn test_parse_expr_operators() {
+    assert_parsed_eq(
+        "col('a') * 2 + col('b').log()",
+        col("a") * lit(2i64) + col("b").log(std::f64::consts::E),
+    );
+    assert_parsed_eq("pl.col('a') - -1", col("a") - lit(-1i64));
+    assert_parsed_eq(
+        "col('a') / 2",
+        binary_expr(col("a"), Operator::TrueDivide, lit(2i64)),
+    );
+    assert_parsed_eq(
+        "col('a') // 2 % 2",
+        col("a").floor_div(lit(2i64)) % lit(2i64),
+    );
+    assert_parsed_eq("-col('a') ** 2", -col("a").pow(lit(2i64)));
+    // Like in Python, the comparisons bind looser than `&`, so they need parentheses.
+    assert_parsed_eq(
+        "(col('a') > 1) & ~(col('c') == \"x\")",
+        col("a").gt(lit(1i64)).and(col("c").eq(lit("x")).not()),
+    );
+    let values = Series::new("".into(), [1i64, 3]).implode().unwrap();
+    assert_parsed_eq(
+        "col('a').is_in([1, 3])",
+        col("a").is_in(lit(values.into_series()), false),
+    );
+}
+
+#[test]
+fn test_parse_expr_methods() {
+    assert_parsed_eq(
+        "col('b').round(0).cast(pl.Int64).alias('rounded')",
+        col("b")
+            .round(0, Default::default())
+            .strict_cast(DataType::Int64)
+            .alias("rounded"),
+    );
+    assert_parsed_eq(
+        "col('c').str.to_uppercase().str.len_chars()",
+        col("c").str().to_uppercase().str().len_chars(),
+    );
+    assert_parsed_eq("col('a').sum().over('c')", col("a").sum().over([col("c")]));
+    assert_parsed_eq(
+        "col('a').sort(descending=True, nulls_last=True)",
+        col("a").sort(
+            SortOptions::default()
+                .with_order_descending(true)
+                .with_nulls_last(true),
+        ),
+    );
+    assert_parsed_eq(
+        "when(col('a') > 2).then(lit('big')).when(col('a') > 1).then('c').otherwise(None)",
+        when(col("a").gt(lit(2i64)))
+            .then(lit("big"))
+            .when(col("a").gt(lit(1i64)))
+            .then(col("c"))
+            .otherwise(lit(LiteralValue::untyped_null())),
+    );
+    assert_parsed_eq("len()", len());
+    // Like in Python, strings are column names in the bounds of `clip`.
+    assert_parsed_eq(
+        "col('b').clip('a', upper_bound=5)",
+        col("b").clip(col("a"), lit(5i64)),
+    );
+}
+
+#[test]
+fn test_parse_expr_errors() {
+    for s in [
+        "col('a' + 1",
+        "col('a').round('x')",
+        "col('a').alias()",
+        "col('a').alias('x', 'y')",
+        "1 < col('a') < 3",
+        "col('a') $ 1",
+        "'unterminated",
+        "when(col('a') > 1)",
+    ] {
+        let err = parse_expr(s, ExprDialect::Python).unwrap_err();
+        assert!(matches!(err, PolarsError::SQLSyntax(_)), "{s}: {err}");
+    }
+    for s in ["col('a').unknown()", "unknown(1)", "col('a').str.unknown()"] {
+        let err = parse_expr(s, ExprDialect::Python).unwrap_err();
+        assert!(matches!(err, PolarsError::SQLInterface(_)), "{s}: {err}");
+    }
+}
+
+#[test]
+fn test_parse_expr_nested_too_deeply() {
+    for s in [
+        format!("{}col('a'){}", "(".repeat(100_000), ")".repeat(100_000)),
+        format!("{}col('a')", "-".repeat(100_000)),
+    ] {
+        let err = parse_expr(s, ExprDialect::Python).unwrap_err();
+        assert!(err.to_string().contains("nested too deeply"), "{err}");
+    }
+    assert_parsed_eq(
+        &format!("{}col('a'){}", "(".repeat(100), ")".repeat(100)),
+        col("a"),
+    );
+}
+
+#[test]
+fn test_parse_expr_sql() {
+    let df = create_df();
+    let expr = parse_expr("a * 2 + LN(b) AS c", ExprDialect::Sql).unwrap();
+    let out = df.lazy().select([expr]).collect().unwrap();
+    assert_eq!(out.get_column_names(), ["c"]);
+}
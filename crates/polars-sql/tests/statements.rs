@@ -525,6 +525,49 @@ fn test_join_on_different_keys() {
     );
 }
 
+#[test]
+fn test_join_null_safe_keys() {
+    let df1 = df! {
+        "a" => [Some(1), None, Some(2), None],
+        "b" => [Some("x"), Some("y"), None, Some("z")],
+    }
+    .unwrap();
+    let df2 = df! {
+        "a" => [Some(1), None, Some(2), None],
+        "b" => [Some("x"), Some("y"), None, Some("w")],
+        "c" => [1, 2, 3, 4],
+    }
+    .unwrap();
+
+    let mut ctx = SQLContext::new();
+    ctx.register("df1", df1.lazy());
+    ctx.register("df2", df2.lazy());
+
+    let expected = df! {
+        "a" => [Some(1), None],
+        "b" => ["x", "y"],
+        "c" => [1, 2],
+    }
+    .unwrap();
+    for op in ["<=>", "IS NOT DISTINCT FROM"] {
+        let sql = format!(
+            r#"
+            SELECT df1.a, df1.b, df2.c
+            FROM df1
+            INNER JOIN df2 ON df1.a {op} df2.a AND df1.b = df2.b
+            ORDER BY c
+        "#
+        );
+        let actual = ctx.execute(&sql).unwrap().collect().unwrap();
+        assert!(
+            actual.equals_missing(&expected),
+            "({op}) expected = {:?}\nactual={:?}",
+            expected,
+            actual
+        );
+    }
+}
+
 #[test]
 fn test_join_multi_consecutive() {
     let df1 = df! { "a" => [1, 2, 3], "b" => [4, 8, 6] }.unwrap();
@@ -10,12 +10,13 @@ use std::marker::PhantomData;
 use std::panic::{AssertUnwindSafe, Location};
 use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, LazyLock, OnceLock, Weak};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crossbeam_deque::{Injector, Steal, Stealer, Worker as WorkQueue};
 use crossbeam_utils::CachePadded;
 use park_group::ParkGroup;
 use parking_lot::Mutex;
+use polars_core::frame::DataFrame;
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
 use slotmap::SlotMap;
@@ -55,6 +56,159 @@ pub fn clear_task_wait_statistics() {
     NS_SPENT_BLOCKED.lock().clear()
 }
 
+thread_local!(
+    /// The node that tasks spawned from this thread are attributed to.
+    static TLS_PROFILE_NODE: Cell<Option<Arc<NodeUsage>>> = const { Cell::new(None) };
+    /// Whether the task currently running on this thread is profiled.
+    static TLS_TASK_PROFILED: Cell<bool> = const { Cell::new(false) };
+    /// Rows produced by the task currently running on this thread.
+    static TLS_TASK_ROWS: Cell<u64> = const { Cell::new(0) };
+    /// Estimated bytes produced by the task currently running on this thread.
+    static TLS_TASK_BYTES: Cell<u64> = const { Cell::new(0) };
+    /// Bytes spilled by the task currently running on this thread.
+    static TLS_TASK_SPILLED_BYTES: Cell<u64> = const { Cell::new(0) };
+);
+
+/// Resources used by all tasks attributed to a single node of a query.
+///
+/// Every task holds the usage of the node it belongs to, so that a poll only
+/// has to update a few atomics and concurrent queries don't share any state.
+#[derive(Debug)]
+pub struct NodeUsage {
+    epoch: Instant,
+    first_poll_ns: AtomicU64,
+    last_poll_ns: AtomicU64,
+    busy_ns: AtomicU64,
+    running: AtomicU64,
+    peak_running: AtomicU64,
+    rows: AtomicU64,
+    peak_bytes: AtomicU64,
+    spilled_bytes: AtomicU64,
+}
+
+impl Default for NodeUsage {
+    fn default() -> Self {
+        Self {
+            epoch: Instant::now(),
+            first_poll_ns: AtomicU64::new(u64::MAX),
+            last_poll_ns: AtomicU64::new(0),
+            busy_ns: AtomicU64::new(0),
+            running: AtomicU64::new(0),
+            peak_running: AtomicU64::new(0),
+            rows: AtomicU64::new(0),
+            peak_bytes: AtomicU64::new(0),
+            spilled_bytes: AtomicU64::new(0),
+        }
+    }
+}
+
+impl NodeUsage {
+    fn start_poll(&self) {
+        let running = self.running.fetch_add(1, Ordering::Relaxed) + 1;
+        self.peak_running.fetch_max(running, Ordering::Relaxed);
+    }
+
+    fn record_poll(&self, start: Instant, end: Instant, rows: u64, bytes: u64, spilled_bytes: u64) {
+        let since_epoch = |t: Instant| t.saturating_duration_since(self.epoch).as_nanos() as u64;
+        let (start, end) = (since_epoch(start), since_epoch(end));
+        self.running.fetch_sub(1, Ordering::Relaxed);
+        self.first_poll_ns.fetch_min(start, Ordering::Relaxed);
+        self.last_poll_ns.fetch_max(end, Ordering::Relaxed);
+        self.busy_ns.fetch_add(end - start, Ordering::Relaxed);
+        self.rows.fetch_add(rows, Ordering::Relaxed);
+        self.peak_bytes.fetch_max(bytes, Ordering::Relaxed);
+        self.spilled_bytes
+            .fetch_add(spilled_bytes, Ordering::Relaxed);
+    }
+
+    /// Start of the first poll of any task of this node, `None` if none was polled.
+    pub fn first_poll(&self) -> Option<Instant> {
+        let ns = self.first_poll_ns.load(Ordering::Relaxed);
+        (ns != u64::MAX).then(|| self.epoch + Duration::from_nanos(ns))
+    }
+
+    /// End of the last poll of any task of this node, `None` if none was polled.
+    pub fn last_poll(&self) -> Option<Instant> {
+        self.first_poll()?;
+        let ns = self.last_poll_ns.load(Ordering::Relaxed);
+        Some(self.epoch + Duration::from_nanos(ns))
+    }
+
+    /// Total time the tasks of this node were being polled.
+    pub fn busy(&self) -> Duration {
+        Duration::from_nanos(self.busy_ns.load(Ordering::Relaxed))
+    }
+
+    /// The largest number of tasks of this node that were polled at the same time.
+    pub fn peak_running(&self) -> u64 {
+        self.peak_running.load(Ordering::Relaxed)
+    }
+
+    pub fn rows(&self) -> u64 {
+        self.rows.load(Ordering::Relaxed)
+    }
+
+    /// The largest estimated size of the morsels a single poll of a task of this node produced.
+    pub fn peak_bytes(&self) -> u64 {
+        self.peak_bytes.load(Ordering::Relaxed)
+    }
+
+    pub fn spilled_bytes(&self) -> u64 {
+        self.spilled_bytes.load(Ordering::Relaxed)
+    }
+}
+
+/// Attributes all tasks spawned within `f`, and the tasks they spawn in turn, to `node`.
+pub fn with_profile_node<R>(node: Option<Arc<NodeUsage>>, f: impl FnOnce() -> R) -> R {
+    let prev = TLS_PROFILE_NODE.replace(node);
+    let out = f();
+    TLS_PROFILE_NODE.set(prev);
+    out
+}
+
+fn current_profile_node() -> Option<Arc<NodeUsage>> {
+    let node = TLS_PROFILE_NODE.take();
+    let out = node.clone();
+    TLS_PROFILE_NODE.set(node);
+    out
+}
+
+/// Records that the running task produced `df`.
+pub fn record_output(df: &DataFrame) {
+    TLS_TASK_ROWS.set(TLS_TASK_ROWS.get().wrapping_add(df.height() as u64));
+    // Estimating the size walks all the buffers, so only do it when it's reported.
+    if TLS_TASK_PROFILED.get() {
+        let bytes = df.estimated_size() as u64;
+        TLS_TASK_BYTES.set(TLS_TASK_BYTES.get().wrapping_add(bytes));
+    }
+}
+
+/// Records that the running task spilled `bytes` bytes to disk.
+pub fn record_spilled_bytes(bytes: u64) {
+    TLS_TASK_SPILLED_BYTES.set(TLS_TASK_SPILLED_BYTES.get().wrapping_add(bytes));
+}
+
+fn run_profiled(task: ReadyTask, node: Arc<NodeUsage>) {
+    TLS_TASK_ROWS.set(0);
+    TLS_TASK_BYTES.set(0);
+    TLS_TASK_SPILLED_BYTES.set(0);
+    let prev = TLS_PROFILE_NODE.replace(Some(node.clone()));
+    let was_profiled = TLS_TASK_PROFILED.replace(true);
+    node.start_poll();
+    let start = Instant::now();
+    task.run();
+    let end = Instant::now();
+    TLS_TASK_PROFILED.set(was_profiled);
+    TLS_PROFILE_NODE.set(prev);
+    node.record_poll(
+        start,
+        end,
+        TLS_TASK_ROWS.take(),
+        TLS_TASK_BYTES.take(),
+        TLS_TASK_SPILLED_BYTES.take(),
+    );
+}
+
 slotmap::new_key_type! {
     struct TaskKey;
 }
@@ -78,6 +232,7 @@ struct TaskMetadata {
     priority: TaskPriority,
     freshly_spawned: AtomicBool,
     scoped: Option<ScopedTaskMetadata>,
+    profile_node: Option<Arc<NodeUsage>>,
 }
 
 impl Drop for TaskMetadata {
@@ -256,7 +411,11 @@ impl Executor {
                     }
                 }
                 worker.recruit_next();
-                task.run();
+                if let Some(node) = task.metadata().profile_node.clone() {
+                    run_profiled(task, node);
+                } else {
+                    task.run();
+                }
             }
         }
     }
@@ -355,6 +514,7 @@ impl<'scope> TaskScope<'scope, '_> {
                             task_key,
                             completed_tasks: Arc::downgrade(&self.completed_tasks),
                         }),
+                        profile_node: current_profile_node(),
                     },
                 )
             };
@@ -410,6 +570,7 @@ where
             priority,
             freshly_spawned: AtomicBool::new(true),
             scoped: None,
+            profile_node: current_profile_node(),
         },
     );
     runnable.schedule();
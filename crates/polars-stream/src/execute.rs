@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use polars_core::POOL;
 use polars_core::frame::DataFrame;
 use polars_error::PolarsResult;
@@ -16,6 +18,9 @@ pub struct StreamingExecutionState {
 
     // The ExecutionState passed to any non-streaming operations.
     pub in_memory_exec_state: ExecutionState,
+
+    // The resources used by each node, if the query is profiled.
+    pub node_usage: Option<SecondaryMap<GraphNodeKey, Arc<async_executor::NodeUsage>>>,
 }
 
 impl Default for StreamingExecutionState {
@@ -23,6 +28,7 @@ impl Default for StreamingExecutionState {
         Self {
             num_pipelines: POOL.current_num_threads(),
             in_memory_exec_state: ExecutionState::default(),
+            node_usage: None,
         }
     }
 }
@@ -183,13 +189,19 @@ fn run_subgraph(
             }
 
             // Spawn a task per pipeline.
-            node.compute.spawn(
-                scope,
-                &mut recv_ports[..],
-                &mut send_ports[..],
-                state,
-                &mut join_handles,
-            );
+            let node_usage = state
+                .node_usage
+                .as_ref()
+                .and_then(|usage| usage.get(node_key).cloned());
+            async_executor::with_profile_node(node_usage, || {
+                node.compute.spawn(
+                    scope,
+                    &mut recv_ports[..],
+                    &mut send_ports[..],
+                    state,
+                    &mut join_handles,
+                )
+            });
 
             // Ensure the ports were consumed.
             assert!(recv_ports.iter().all(|p| p.is_none()));
@@ -259,6 +271,7 @@ fn run_subgraph(
 
 pub fn execute_graph(
     graph: &mut Graph,
+    node_usage: Option<SecondaryMap<GraphNodeKey, Arc<async_executor::NodeUsage>>>,
 ) -> PolarsResult<SparseSecondaryMap<GraphNodeKey, DataFrame>> {
     // Get the number of threads from the rayon thread-pool as that respects our config.
     let num_pipelines = POOL.current_num_threads();
@@ -267,6 +280,7 @@ pub fn execute_graph(
     let state = StreamingExecutionState {
         num_pipelines,
         in_memory_exec_state: ExecutionState::default(),
+        node_usage,
     };
 
     // Ensure everything is properly connected.
@@ -4,6 +4,7 @@ use std::sync::{Arc, OnceLock};
 
 use polars_core::frame::DataFrame;
 
+use crate::async_executor;
 use crate::async_primitives::wait_group::WaitToken;
 
 static IDEAL_MORSEL_SIZE: OnceLock<usize> = OnceLock::new();
@@ -90,6 +91,7 @@ pub struct Morsel {
 
 impl Morsel {
     pub fn new(df: DataFrame, seq: MorselSeq, source_token: SourceToken) -> Self {
+        async_executor::record_output(&df);
         Self {
             df,
             seq,
@@ -126,6 +128,7 @@ impl Morsel {
     #[allow(unused)]
     pub fn map<F: FnOnce(DataFrame) -> DataFrame>(mut self, f: F) -> Self {
         self.df = f(self.df);
+        async_executor::record_output(&self.df);
         self
     }
 
@@ -134,6 +137,7 @@ impl Morsel {
         f: F,
     ) -> Result<Self, E> {
         self.df = f(self.df)?;
+        async_executor::record_output(&self.df);
         Ok(self)
     }
 
@@ -143,6 +147,7 @@ impl Morsel {
         F: Future<Output = Result<DataFrame, E>>,
     {
         self.df = f(self.df).await?;
+        async_executor::record_output(&self.df);
         Ok(self)
     }
 
@@ -0,0 +1,107 @@
+use polars_core::prelude::*;
+use polars_ops::series::{EWMOptions, EwmMethod, EwmState};
+use polars_utils::pl_str::PlSmallStr;
+
+use super::compute_node_prelude::*;
+use super::partitioned::for_each_group_stable;
+
+/// Computes an exponentially-weighted moving statistic of a single column,
+/// carrying the state from one morsel to the next. If `partition_by` is not
+/// empty, the statistic is computed per group, as in `ewm_mean().over(..)`,
+/// with a separate state for every group.
+pub struct EwmNode {
+    column: PlSmallStr,
+    partition_by: Vec<PlSmallStr>,
+    method: EwmMethod,
+    options: EWMOptions,
+    dtype: DataType,
+    states: PlHashMap<Vec<AnyValue<'static>>, EwmState>,
+}
+
+impl EwmNode {
+    pub fn new(
+        column: PlSmallStr,
+        partition_by: Vec<PlSmallStr>,
+        method: EwmMethod,
+        options: EWMOptions,
+        dtype: &DataType,
+    ) -> PolarsResult<Self> {
+        // Validate the options up front instead of on the first morsel.
+        EwmState::new(method, options, dtype)?;
+        Ok(Self {
+            column,
+            partition_by,
+            method,
+            options,
+            dtype: dtype.clone(),
+            states: PlHashMap::default(),
+        })
+    }
+
+    fn state(&mut self, key: Vec<AnyValue<'static>>) -> PolarsResult<&mut EwmState> {
+        if !self.states.contains_key(&key) {
+            let state = EwmState::new(self.method, self.options, &self.dtype)?;
+            self.states.insert(key.clone(), state);
+        }
+        Ok(self.states.get_mut(&key).unwrap())
+    }
+
+    fn update(&mut self, df: DataFrame) -> PolarsResult<DataFrame> {
+        let values = df.column(&self.column)?.as_materialized_series();
+        let out = if df.height() == 0 {
+            EwmState::new(self.method, self.options, &self.dtype)?.update(values)?
+        } else if self.partition_by.is_empty() {
+            self.state(Vec::new())?.update(values)?
+        } else {
+            // Update the state of every group with its values.
+            let partition_by = self.partition_by.clone();
+            for_each_group_stable(&df, &partition_by, |key, idx| {
+                let group_values = unsafe { values.take_slice_unchecked(idx) };
+                self.state(key)?.update(&group_values)
+            })?
+        };
+        DataFrame::new(vec![out.with_name(self.column.clone()).into_column()])
+    }
+}
+
+impl ComputeNode for EwmNode {
+    fn name(&self) -> &str {
+        "ewm"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+        recv.swap_with_slice(send);
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        let mut recv = recv_ports[0].take().unwrap().serial();
+        let mut send = send_ports[0].take().unwrap().serial();
+
+        // The moving statistic depends on all previous values, so we must be serial.
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            while let Ok(morsel) = recv.recv().await {
+                let morsel = morsel.try_map(|df| self.update(df))?;
+                if send.send(morsel).await.is_err() {
+                    break;
+                }
+            }
+
+            Ok(())
+        }));
+    }
+}
@@ -0,0 +1,256 @@
+use std::sync::Arc;
+
+use polars_core::POOL;
+use polars_core::prelude::{Column, PlRandomState, SortMultipleOptions, UniqueKeepStrategy};
+use polars_core::scalar::Scalar;
+use polars_core::schema::Schema;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::unique_column_name;
+use rayon::prelude::*;
+
+use super::compute_node_prelude::*;
+use crate::async_primitives::connector::Receiver;
+use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::nodes::spill::{LocalPartitions, SpillingPartitioner, env_memory_budget};
+
+/// Memory budget (in bytes) for the buffered state of a distinct in the
+/// streaming engine. If set, unordered distincts are executed by the
+/// [`ExternalDistinctNode`], which spills to disk once the budget is exceeded.
+/// Defaults to the budget of the query, see
+/// [`polars_core::config::with_query_memory_budget`].
+pub fn distinct_memory_budget() -> PolarsResult<Option<usize>> {
+    Ok(env_memory_budget("POLARS_DISTINCT_MEMORY_BUDGET")?
+        .or_else(polars_core::config::query_memory_budget))
+}
+
+// We use more partitions than pipelines so that a single partition comfortably
+// fits in memory when it is deduplicated at the end.
+const PARTITIONS_PER_PIPELINE: usize = 16;
+
+struct DistinctShared {
+    keep: UniqueKeepStrategy,
+    // The temporary column with the sequence id of the morsel every row came from.
+    seq_column: PlSmallStr,
+    partitions: SpillingPartitioner,
+}
+
+impl DistinctShared {
+    /// Deduplicate the rows of a single partition, keeping the sequence column.
+    fn dedup_partition(&self, df: DataFrame) -> PolarsResult<DataFrame> {
+        let df = match self.keep {
+            // The order in which the rows were received decides which one we keep.
+            UniqueKeepStrategy::First | UniqueKeepStrategy::Last => df.sort(
+                [self.seq_column.clone()],
+                SortMultipleOptions::default().with_maintain_order(true),
+            )?,
+            UniqueKeepStrategy::Any | UniqueKeepStrategy::None => df,
+        };
+        let key_names = self.partitions.key_names().to_vec();
+        df.unique_impl(false, Some(key_names), self.keep, None)
+    }
+
+    fn prepare_spill(&self, df: DataFrame) -> PolarsResult<DataFrame> {
+        // Keeping only the rows that have a unique key can't be decided
+        // before all rows are seen.
+        if self.keep == UniqueKeepStrategy::None {
+            Ok(df)
+        } else {
+            self.dedup_partition(df)
+        }
+    }
+}
+
+struct DistinctSinkState {
+    shared: DistinctShared,
+    locals: Vec<LocalPartitions>,
+}
+
+impl DistinctSinkState {
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        receivers: Vec<Receiver<Morsel>>,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        for (mut recv, local) in receivers.into_iter().zip(&mut self.locals) {
+            let shared = &self.shared;
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                while let Ok(morsel) = recv.recv().await {
+                    let seq = morsel.seq().to_u64();
+                    let mut df = morsel.into_df();
+                    let height = df.height();
+                    let seq =
+                        Column::new_scalar(shared.seq_column.clone(), Scalar::from(seq), height);
+                    df.with_column(seq)?;
+                    shared
+                        .partitions
+                        .insert(local, df, |df| shared.prepare_spill(df))?;
+                }
+                Ok(())
+            }));
+        }
+    }
+
+    /// Deduplicates every partition independently, the spilled rows of a
+    /// partition are only loaded when that partition is processed.
+    fn finalize(&mut self, output_schema: &Schema) -> PolarsResult<DataFrame> {
+        let shared = &self.shared;
+        let partitions = shared.partitions.take_partitions(&mut self.locals);
+
+        let dfs = POOL.install(|| {
+            partitions
+                .into_par_iter()
+                .map(|(mut dfs, files)| {
+                    for file in files {
+                        dfs.push(file.load()?);
+                    }
+                    if dfs.is_empty() {
+                        return Ok(None);
+                    }
+                    let df = accumulate_dataframes_vertical_unchecked(dfs);
+                    let mut df = shared.dedup_partition(df)?;
+                    df.drop_in_place(shared.seq_column.as_str())?;
+                    Ok(Some(df))
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+
+        let dfs = dfs.into_iter().flatten().collect::<Vec<_>>();
+        if dfs.is_empty() {
+            Ok(DataFrame::empty_with_schema(output_schema))
+        } else {
+            Ok(accumulate_dataframes_vertical_unchecked(dfs))
+        }
+    }
+}
+
+enum ExternalDistinctState {
+    Sink(DistinctSinkState),
+    Source(InMemorySourceNode),
+    Done,
+}
+
+/// A distinct that partitions its input by the hash of the keys and spills
+/// those partitions to disk when its buffered state exceeds a memory budget.
+/// When the input is done every partition is deduplicated independently, so
+/// only a single partition of the input has to fit in memory at once.
+pub struct ExternalDistinctNode {
+    state: ExternalDistinctState,
+    output_schema: Arc<Schema>,
+}
+
+impl ExternalDistinctNode {
+    pub fn new(
+        key_names: Vec<PlSmallStr>,
+        keep: UniqueKeepStrategy,
+        output_schema: Arc<Schema>,
+        memory_budget: usize,
+        random_state: PlRandomState,
+        num_pipelines: usize,
+    ) -> Self {
+        let partitions = SpillingPartitioner::new(
+            "external distinct",
+            "distinct",
+            key_names,
+            random_state,
+            num_pipelines * PARTITIONS_PER_PIPELINE,
+            memory_budget,
+        );
+        let locals = (0..num_pipelines).map(|_| partitions.new_local()).collect();
+        let shared = DistinctShared {
+            keep,
+            seq_column: unique_column_name(),
+            partitions,
+        };
+        Self {
+            state: ExternalDistinctState::Sink(DistinctSinkState { shared, locals }),
+            output_schema,
+        }
+    }
+}
+
+impl ComputeNode for ExternalDistinctNode {
+    fn name(&self) -> &str {
+        "external-distinct"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // State transitions.
+        match &mut self.state {
+            // If the output doesn't want any more data, transition to being done.
+            _ if send[0] == PortState::Done => {
+                self.state = ExternalDistinctState::Done;
+            },
+            // Input is done, transition to being a source.
+            ExternalDistinctState::Sink(sink) if matches!(recv[0], PortState::Done) => {
+                let df = sink.finalize(&self.output_schema)?;
+                let source = InMemorySourceNode::new(Arc::new(df), MorselSeq::new(0));
+                self.state = ExternalDistinctState::Source(source);
+            },
+            // Defer to source node implementation.
+            ExternalDistinctState::Source(src) => {
+                src.update_state(&mut [], send, state)?;
+                if send[0] == PortState::Done {
+                    self.state = ExternalDistinctState::Done;
+                }
+            },
+            // Nothing to change.
+            ExternalDistinctState::Done | ExternalDistinctState::Sink(_) => {},
+        }
+
+        // Communicate our state.
+        match &self.state {
+            ExternalDistinctState::Sink { .. } => {
+                send[0] = PortState::Blocked;
+                recv[0] = PortState::Ready;
+            },
+            ExternalDistinctState::Source(..) => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            ExternalDistinctState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, ExternalDistinctState::Sink(_))
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(send_ports.len() == 1 && recv_ports.len() == 1);
+        match &mut self.state {
+            ExternalDistinctState::Sink(sink) => {
+                assert!(send_ports[0].is_none());
+                sink.spawn(
+                    scope,
+                    recv_ports[0].take().unwrap().parallel(),
+                    join_handles,
+                )
+            },
+            ExternalDistinctState::Source(source) => {
+                assert!(recv_ports[0].is_none());
+                source.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
+            ExternalDistinctState::Done => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,354 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use polars_core::POOL;
+use polars_core::config::verbose;
+use polars_core::prelude::{DataType, Float64Chunked, IntoColumn, NewChunkedArray, QuantileMethod};
+use polars_core::schema::Schema;
+use polars_error::polars_ensure;
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::total_ord::TotalOrd;
+use rayon::prelude::*;
+
+use super::compute_node_prelude::*;
+use crate::async_primitives::connector::Receiver;
+use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::nodes::spill::{PartitionSpiller, env_memory_budget};
+
+/// Memory budget (in bytes) for the values buffered by a median or quantile
+/// in the streaming engine. If set, these aggregations are executed exactly by
+/// the [`ExternalQuantileNode`], which spills sorted runs to disk once the
+/// budget is exceeded. Defaults to the budget of the query, see
+/// [`polars_core::config::with_query_memory_budget`].
+pub fn quantile_memory_budget() -> PolarsResult<Option<usize>> {
+    Ok(env_memory_budget("POLARS_QUANTILE_MEMORY_BUDGET")?
+        .or_else(polars_core::config::query_memory_budget))
+}
+
+const VALUE_COLUMN: PlSmallStr = PlSmallStr::from_static("__POLARS_QUANTILE_VALUE");
+
+/// The value of rank `k` (0-based) in the union of the ascending `runs`.
+///
+/// Every step picks the middle of the largest remaining range as pivot and
+/// counts the values below and equal to it in all runs with a binary search,
+/// after which at least half of that range is discarded. The runs are never
+/// copied, so they can be memory-mapped files.
+fn select_from_sorted_runs(runs: &[&[f64]], mut k: usize) -> f64 {
+    let mut lo = vec![0; runs.len()];
+    let mut hi = runs.iter().map(|run| run.len()).collect::<Vec<_>>();
+    let mut lt = vec![0; runs.len()];
+    let mut le = vec![0; runs.len()];
+    loop {
+        let (pivot_run, _) = (0..runs.len())
+            .map(|i| (i, hi[i] - lo[i]))
+            .max_by_key(|(_, len)| *len)
+            .unwrap();
+        let pivot = runs[pivot_run][(lo[pivot_run] + hi[pivot_run]) / 2];
+
+        let (mut num_lt, mut num_le) = (0, 0);
+        for (i, run) in runs.iter().enumerate() {
+            let range = &run[lo[i]..hi[i]];
+            lt[i] = lo[i] + range.partition_point(|v| v.tot_lt(&pivot));
+            le[i] = lo[i] + range.partition_point(|v| v.tot_le(&pivot));
+            num_lt += lt[i] - lo[i];
+            num_le += le[i] - lo[i];
+        }
+
+        if k < num_lt {
+            hi.copy_from_slice(&lt);
+        } else if k < num_le {
+            return pivot;
+        } else {
+            k -= num_le;
+            lo.copy_from_slice(&le);
+        }
+    }
+}
+
+/// The quantile of the union of the ascending `runs`, with the same
+/// interpolation as the in-memory engine.
+fn quantile_from_sorted_runs(
+    runs: &[&[f64]],
+    quantile: f64,
+    method: QuantileMethod,
+) -> Option<f64> {
+    let n = runs.iter().map(|run| run.len()).sum::<usize>();
+    if n == 0 {
+        return None;
+    }
+    let float_idx = (n - 1) as f64 * quantile;
+    let idx = match method {
+        QuantileMethod::Nearest => float_idx.round() as usize,
+        QuantileMethod::Lower | QuantileMethod::Midpoint | QuantileMethod::Linear => {
+            float_idx as usize
+        },
+        QuantileMethod::Higher => float_idx.ceil() as usize,
+        QuantileMethod::Equiprobable => ((n as f64 * quantile).ceil() - 1.0).max(0.0) as usize,
+    };
+    let idx = idx.min(n - 1);
+    let lower = select_from_sorted_runs(runs, idx);
+    let top_idx = (float_idx.ceil() as usize).min(n - 1);
+    let out = match method {
+        QuantileMethod::Linear | QuantileMethod::Midpoint if top_idx != idx => {
+            let upper = select_from_sorted_runs(runs, top_idx);
+            if lower == upper {
+                lower
+            } else if method == QuantileMethod::Linear {
+                lower + (float_idx - idx as f64) * (upper - lower)
+            } else {
+                (lower + upper) / 2.0
+            }
+        },
+        _ => lower,
+    };
+    Some(out)
+}
+
+struct LocalQuantileSinkState {
+    // The non-null values that are not yet spilled.
+    buffered: Vec<f64>,
+}
+
+struct QuantileShared {
+    memory_budget: usize,
+    buffered_bytes: AtomicUsize,
+    spiller: PartitionSpiller,
+}
+
+impl QuantileShared {
+    fn spill_local(&self, local: &mut LocalQuantileSinkState) -> PolarsResult<()> {
+        let mut values = core::mem::take(&mut local.buffered);
+        if values.is_empty() {
+            return Ok(());
+        }
+        let bytes = values.len() * size_of::<f64>();
+        values.sort_unstable_by(TotalOrd::tot_cmp);
+        let run = Float64Chunked::from_vec(VALUE_COLUMN, values);
+        let mut df = DataFrame::new(vec![run.into_column()])?;
+        self.spiller.spill(0, &mut df)?;
+
+        if verbose() {
+            eprintln!("external quantile: spilled a sorted run of {bytes} bytes to disk");
+        }
+        self.buffered_bytes.fetch_sub(bytes, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+struct QuantileSinkState {
+    shared: QuantileShared,
+    locals: Vec<LocalQuantileSinkState>,
+    quantile: f64,
+    method: QuantileMethod,
+}
+
+impl QuantileSinkState {
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        receivers: Vec<Receiver<Morsel>>,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        for (mut recv, local) in receivers.into_iter().zip(&mut self.locals) {
+            let shared = &self.shared;
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                while let Ok(morsel) = recv.recv().await {
+                    let df = morsel.into_df();
+                    let values = df.get_columns()[0].cast(&DataType::Float64)?;
+                    let values = values.f64()?;
+                    let prev_len = local.buffered.len();
+                    for arr in values.downcast_iter() {
+                        if arr.null_count() == 0 {
+                            local.buffered.extend_from_slice(arr.values());
+                        } else {
+                            local.buffered.extend(arr.iter().flatten());
+                        }
+                    }
+
+                    let added_bytes = (local.buffered.len() - prev_len) * size_of::<f64>();
+                    let prev = shared
+                        .buffered_bytes
+                        .fetch_add(added_bytes, Ordering::Relaxed);
+                    if prev + added_bytes > shared.memory_budget {
+                        shared.spill_local(local)?;
+                    }
+                }
+                Ok(())
+            }));
+        }
+    }
+
+    /// Sorts the values that are still in memory into runs and selects the
+    /// quantile across those and the spilled runs, which are memory-mapped.
+    fn finalize(&mut self, output_schema: &Schema) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            (0.0..=1.0).contains(&self.quantile),
+            ComputeError: "quantile should be between 0.0 and 1.0",
+        );
+
+        let mut runs = self
+            .locals
+            .iter_mut()
+            .map(|local| core::mem::take(&mut local.buffered))
+            .collect::<Vec<_>>();
+        POOL.install(|| {
+            runs.par_iter_mut()
+                .for_each(|run| run.sort_unstable_by(TotalOrd::tot_cmp))
+        });
+
+        let spilled = self
+            .shared
+            .spiller
+            .take_files()
+            .into_iter()
+            .flatten()
+            .map(|file| {
+                let df = file.load()?;
+                Ok(df.get_columns()[0].f64()?.rechunk().into_owned())
+            })
+            .collect::<PolarsResult<Vec<Float64Chunked>>>()?;
+
+        let mut slices = runs.iter().map(|run| run.as_slice()).collect::<Vec<_>>();
+        for run in &spilled {
+            slices.push(run.cont_slice()?);
+        }
+        slices.retain(|run| !run.is_empty());
+        let out = quantile_from_sorted_runs(&slices, self.quantile, self.method);
+
+        let (name, dtype) = output_schema.get_at_index(0).unwrap();
+        let out = Float64Chunked::from_slice_options(name.clone(), &[out])
+            .into_column()
+            .cast(dtype)?;
+        DataFrame::new(vec![out])
+    }
+}
+
+enum ExternalQuantileState {
+    Sink(QuantileSinkState),
+    Source(InMemorySourceNode),
+    Done,
+}
+
+/// An exact median or quantile of a single column that spills sorted runs of
+/// its input to disk when the buffered values exceed a memory budget. When the
+/// input is done the quantile is selected across all runs without merging
+/// them, so the column never has to fit in memory at once.
+pub struct ExternalQuantileNode {
+    state: ExternalQuantileState,
+    output_schema: Arc<Schema>,
+}
+
+impl ExternalQuantileNode {
+    pub fn new(
+        quantile: f64,
+        method: QuantileMethod,
+        output_schema: Arc<Schema>,
+        memory_budget: usize,
+        num_pipelines: usize,
+    ) -> Self {
+        let locals = (0..num_pipelines)
+            .map(|_| LocalQuantileSinkState {
+                buffered: Vec::new(),
+            })
+            .collect();
+        let shared = QuantileShared {
+            memory_budget,
+            buffered_bytes: AtomicUsize::new(0),
+            spiller: PartitionSpiller::new("quantile", 1),
+        };
+        Self {
+            state: ExternalQuantileState::Sink(QuantileSinkState {
+                shared,
+                locals,
+                quantile,
+                method,
+            }),
+            output_schema,
+        }
+    }
+}
+
+impl ComputeNode for ExternalQuantileNode {
+    fn name(&self) -> &str {
+        "external-quantile"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // State transitions.
+        match &mut self.state {
+            // If the output doesn't want any more data, transition to being done.
+            _ if send[0] == PortState::Done => {
+                self.state = ExternalQuantileState::Done;
+            },
+            // Input is done, transition to being a source.
+            ExternalQuantileState::Sink(sink) if matches!(recv[0], PortState::Done) => {
+                let df = sink.finalize(&self.output_schema)?;
+                let source = InMemorySourceNode::new(Arc::new(df), MorselSeq::new(0));
+                self.state = ExternalQuantileState::Source(source);
+            },
+            // Defer to source node implementation.
+            ExternalQuantileState::Source(src) => {
+                src.update_state(&mut [], send, state)?;
+                if send[0] == PortState::Done {
+                    self.state = ExternalQuantileState::Done;
+                }
+            },
+            // Nothing to change.
+            ExternalQuantileState::Done | ExternalQuantileState::Sink(_) => {},
+        }
+
+        // Communicate our state.
+        match &self.state {
+            ExternalQuantileState::Sink { .. } => {
+                send[0] = PortState::Blocked;
+                recv[0] = PortState::Ready;
+            },
+            ExternalQuantileState::Source(..) => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            ExternalQuantileState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, ExternalQuantileState::Sink(_))
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(send_ports.len() == 1 && recv_ports.len() == 1);
+        match &mut self.state {
+            ExternalQuantileState::Sink(sink) => {
+                assert!(send_ports[0].is_none());
+                sink.spawn(
+                    scope,
+                    recv_ports[0].take().unwrap().parallel(),
+                    join_handles,
+                )
+            },
+            ExternalQuantileState::Source(source) => {
+                assert!(recv_ports[0].is_none());
+                source.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
+            ExternalQuantileState::Done => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use polars_core::prelude::*;
+use polars_ops::series::fill_null_by;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::compute_node_prelude::*;
+use super::partitioned::for_each_group_stable;
+
+/// Fills the null values of a single column forward, with the last non-null
+/// value if it lies at most `limit` away in the `by` column, carrying that
+/// value from one morsel to the next. If `partition_by` is not empty, the
+/// values are filled per group, as in `fill_null_by(..).over(..)`.
+pub struct ForwardFillByNode {
+    column: PlSmallStr,
+    by: PlSmallStr,
+    partition_by: Vec<PlSmallStr>,
+    limit: i64,
+    /// The last non-null value of every group, together with its `by` value.
+    last_valid: PlHashMap<Vec<AnyValue<'static>>, (Series, Series)>,
+}
+
+impl ForwardFillByNode {
+    pub fn new(
+        column: PlSmallStr,
+        by: PlSmallStr,
+        partition_by: Vec<PlSmallStr>,
+        limit: i64,
+    ) -> Self {
+        Self {
+            column,
+            by,
+            partition_by,
+            limit,
+            last_valid: PlHashMap::default(),
+        }
+    }
+
+    fn fill(
+        &mut self,
+        key: Vec<AnyValue<'static>>,
+        values: &Series,
+        by: &Series,
+    ) -> PolarsResult<Series> {
+        // Prepend the last non-null value of the previous morsels, so that it
+        // can fill the leading nulls of this one.
+        let (values, by, offset) = match self.last_valid.get(&key) {
+            Some((last_value, last_by)) => {
+                let mut carried_values = last_value.clone();
+                carried_values.append(values)?;
+                let mut carried_by = last_by.clone();
+                carried_by.append(by)?;
+                (carried_values, carried_by, 1usize)
+            },
+            None => (values.clone(), by.clone(), 0usize),
+        };
+        let out = fill_null_by(&values, &by, true, self.limit)?;
+
+        if let Some(i) = values.is_not_null().into_no_null_iter().rposition(|v| v) {
+            let last_valid = (values.slice(i as i64, 1), by.slice(i as i64, 1));
+            self.last_valid.insert(key, last_valid);
+        }
+        Ok(out.slice(offset as i64, out.len() - offset))
+    }
+
+    fn update(&mut self, df: DataFrame) -> PolarsResult<DataFrame> {
+        let values = df.column(&self.column)?.as_materialized_series();
+        let by = df.column(&self.by)?.as_materialized_series();
+        let out = if df.height() == 0 || self.partition_by.is_empty() {
+            self.fill(Vec::new(), values, by)?
+        } else {
+            // Fill the values of every group separately.
+            let partition_by = self.partition_by.clone();
+            for_each_group_stable(&df, &partition_by, |key, idx| {
+                let group_values = unsafe { values.take_slice_unchecked(idx) };
+                let group_by = unsafe { by.take_slice_unchecked(idx) };
+                self.fill(key, &group_values, &group_by)
+            })?
+        };
+        DataFrame::new(vec![out.with_name(self.column.clone()).into_column()])
+    }
+}
+
+impl ComputeNode for ForwardFillByNode {
+    fn name(&self) -> &str {
+        "forward-fill-by"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+        recv.swap_with_slice(send);
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        let mut recv = recv_ports[0].take().unwrap().serial();
+        let mut send = send_ports[0].take().unwrap().serial();
+
+        // The filled values depend on all previous values, so we must be serial.
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            while let Ok(morsel) = recv.recv().await {
+                let morsel = morsel.try_map(|df| self.update(df))?;
+                if send.send(morsel).await.is_err() {
+                    break;
+                }
+            }
+
+            Ok(())
+        }));
+    }
+}
@@ -0,0 +1,303 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use parking_lot::Mutex;
+use polars_core::prelude::PlRandomState;
+use polars_core::schema::Schema;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_plan::plans::DataFrameUdf;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::compute_node_prelude::*;
+use crate::async_primitives::connector::Receiver;
+use crate::async_primitives::wait_group::WaitGroup;
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::nodes::spill::{LocalPartitions, SpillFile, SpillingPartitioner, env_memory_budget};
+
+const DEFAULT_MEMORY_BUDGET: usize = 1 << 30;
+
+/// Memory budget (in bytes) for the buffered rows of a group-by apply in the
+/// streaming engine, once exceeded the rows are spilled to disk.
+pub fn group_by_apply_memory_budget() -> PolarsResult<usize> {
+    Ok(env_memory_budget("POLARS_GROUP_BY_APPLY_MEMORY_BUDGET")?.unwrap_or(DEFAULT_MEMORY_BUDGET))
+}
+
+// We use more partitions than pipelines so that a single partition comfortably
+// fits in memory when its groups are applied at the end.
+const PARTITIONS_PER_PIPELINE: usize = 16;
+
+struct ApplyShared {
+    function: Arc<dyn DataFrameUdf>,
+    partitions: SpillingPartitioner,
+}
+
+impl ApplyShared {
+    /// Apply the function to every group of a partition. All rows of a key
+    /// are in the same partition, so its groups are complete.
+    fn apply_partition(
+        &self,
+        mut dfs: Vec<DataFrame>,
+        files: Vec<SpillFile>,
+    ) -> PolarsResult<DataFrame> {
+        for file in files {
+            dfs.push(file.load()?);
+        }
+        let mut df = accumulate_dataframes_vertical_unchecked(dfs);
+        df.as_single_chunk_par();
+        df.group_by(self.partitions.key_names().iter().cloned())?
+            .apply(|group| self.function.call_udf(group))
+    }
+}
+
+struct ApplySinkState {
+    shared: ApplyShared,
+    locals: Vec<LocalPartitions>,
+}
+
+impl ApplySinkState {
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        receivers: Vec<Receiver<Morsel>>,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        for (mut recv, local) in receivers.into_iter().zip(&mut self.locals) {
+            let shared = &self.shared;
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                while let Ok(morsel) = recv.recv().await {
+                    shared.partitions.insert(local, morsel.into_df(), Ok)?;
+                }
+                Ok(())
+            }));
+        }
+    }
+
+    fn into_source(mut self, output_schema: &Schema) -> ApplySourceState {
+        let mut partitions: VecDeque<_> = self
+            .shared
+            .partitions
+            .take_partitions(&mut self.locals)
+            .into_iter()
+            .filter(|(dfs, files)| !dfs.is_empty() || !files.is_empty())
+            .map(|(dfs, files)| PendingPartition::Grouped { dfs, files })
+            .collect();
+        if partitions.is_empty() {
+            partitions.push_back(PendingPartition::Applied(DataFrame::empty_with_schema(
+                output_schema,
+            )));
+        }
+
+        ApplySourceState {
+            shared: self.shared,
+            partitions: Mutex::new(partitions),
+            seq: AtomicU64::new(0),
+        }
+    }
+}
+
+enum PendingPartition {
+    /// The rows of a partition of which the groups aren't applied yet.
+    Grouped {
+        dfs: Vec<DataFrame>,
+        files: Vec<SpillFile>,
+    },
+    /// The part of an applied partition that is not yet sent.
+    Applied(DataFrame),
+}
+
+struct ApplySourceState {
+    shared: ApplyShared,
+    partitions: Mutex<VecDeque<PendingPartition>>,
+    seq: AtomicU64,
+}
+
+impl ApplySourceState {
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        send_ports: &mut [Option<SendPort<'_>>],
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        let senders = send_ports[0].take().unwrap().parallel();
+        let source_token = SourceToken::new();
+        for mut send in senders {
+            let slf = &*self;
+            let source_token = source_token.clone();
+            join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
+                let wait_group = WaitGroup::default();
+                loop {
+                    // Only the partitions that are being sent are in memory at once.
+                    let Some(pending) = slf.partitions.lock().pop_front() else {
+                        break;
+                    };
+                    let mut df = match pending {
+                        PendingPartition::Grouped { dfs, files } => {
+                            slf.shared.apply_partition(dfs, files)?
+                        },
+                        PendingPartition::Applied(df) => df,
+                    };
+
+                    loop {
+                        let (morsel_df, rest) = df.split_at(get_ideal_morsel_size() as i64);
+                        let seq = MorselSeq::new(slf.seq.fetch_add(1, Ordering::Relaxed));
+                        let mut morsel = Morsel::new(morsel_df, seq, source_token.clone());
+                        morsel.set_consume_token(wait_group.token());
+                        if send.send(morsel).await.is_err() {
+                            return Ok(());
+                        }
+                        wait_group.wait().await;
+
+                        if rest.height() == 0 {
+                            break;
+                        }
+                        if source_token.stop_requested() {
+                            let rest = PendingPartition::Applied(rest);
+                            slf.partitions.lock().push_front(rest);
+                            return Ok(());
+                        }
+                        df = rest;
+                    }
+
+                    if source_token.stop_requested() {
+                        break;
+                    }
+                }
+
+                Ok(())
+            }));
+        }
+    }
+}
+
+enum GroupByApplyState {
+    Sink(ApplySinkState),
+    Source(ApplySourceState),
+    Done,
+}
+
+/// A group-by that applies a function to the rows of every group.
+///
+/// The input is partitioned by the hash of the keys, spilling those partitions
+/// to disk when the buffered rows exceed a memory budget. When the input is
+/// done the partitions are grouped and applied one by one as they are sent, so
+/// only a few partitions of the input have to fit in memory at once.
+pub struct GroupByApplyNode {
+    state: GroupByApplyState,
+    output_schema: Arc<Schema>,
+}
+
+impl GroupByApplyNode {
+    pub fn new(
+        key_names: Vec<PlSmallStr>,
+        function: Arc<dyn DataFrameUdf>,
+        output_schema: Arc<Schema>,
+        memory_budget: usize,
+        random_state: PlRandomState,
+        num_pipelines: usize,
+    ) -> Self {
+        let partitions = SpillingPartitioner::new(
+            "group-by apply",
+            "group_by_apply",
+            key_names,
+            random_state,
+            num_pipelines * PARTITIONS_PER_PIPELINE,
+            memory_budget,
+        );
+        let locals = (0..num_pipelines).map(|_| partitions.new_local()).collect();
+        let shared = ApplyShared {
+            function,
+            partitions,
+        };
+        Self {
+            state: GroupByApplyState::Sink(ApplySinkState { shared, locals }),
+            output_schema,
+        }
+    }
+}
+
+impl ComputeNode for GroupByApplyNode {
+    fn name(&self) -> &str {
+        "group-by-apply"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // State transitions.
+        match &mut self.state {
+            // If the output doesn't want any more data, transition to being done.
+            _ if send[0] == PortState::Done => {
+                self.state = GroupByApplyState::Done;
+            },
+            // Input is done, transition to being a source.
+            GroupByApplyState::Sink(_) if matches!(recv[0], PortState::Done) => {
+                let GroupByApplyState::Sink(sink) =
+                    core::mem::replace(&mut self.state, GroupByApplyState::Done)
+                else {
+                    unreachable!()
+                };
+                self.state = GroupByApplyState::Source(sink.into_source(&self.output_schema));
+            },
+            // All partitions are sent.
+            GroupByApplyState::Source(src) if src.partitions.lock().is_empty() => {
+                self.state = GroupByApplyState::Done;
+            },
+            // Nothing to change.
+            GroupByApplyState::Done | GroupByApplyState::Sink(_) | GroupByApplyState::Source(_) => {
+            },
+        }
+
+        // Communicate our state.
+        match &self.state {
+            GroupByApplyState::Sink { .. } => {
+                send[0] = PortState::Blocked;
+                recv[0] = PortState::Ready;
+            },
+            GroupByApplyState::Source(..) => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            GroupByApplyState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, GroupByApplyState::Sink(_))
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(send_ports.len() == 1 && recv_ports.len() == 1);
+        match &mut self.state {
+            GroupByApplyState::Sink(sink) => {
+                assert!(send_ports[0].is_none());
+                sink.spawn(
+                    scope,
+                    recv_ports[0].take().unwrap().parallel(),
+                    join_handles,
+                )
+            },
+            GroupByApplyState::Source(source) => {
+                assert!(recv_ports[0].is_none());
+                source.spawn(scope, send_ports, join_handles);
+            },
+            GroupByApplyState::Done => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use std::num::NonZeroUsize;
+
+use polars_core::frame::DataFrame;
+use polars_core::schema::SchemaRef;
+use polars_error::PolarsResult;
+use polars_plan::dsl::SinkCallback;
+
+use super::{SinkInputPort, SinkNode};
+use crate::async_executor::spawn;
+use crate::async_primitives::connector::Receiver;
+use crate::execute::StreamingExecutionState;
+use crate::nodes::io_sinks::phase::PhaseOutcome;
+use crate::nodes::{JoinHandle, TaskPriority};
+
+/// Passes the batches of the result of a query to a function while the query runs.
+pub struct CallbackSinkNode {
+    function: SinkCallback,
+    schema: SchemaRef,
+    maintain_order: bool,
+    chunk_size: Option<NonZeroUsize>,
+}
+
+impl CallbackSinkNode {
+    pub fn new(
+        function: SinkCallback,
+        schema: SchemaRef,
+        maintain_order: bool,
+        chunk_size: Option<NonZeroUsize>,
+    ) -> Self {
+        Self {
+            function,
+            schema,
+            maintain_order,
+            chunk_size,
+        }
+    }
+}
+
+/// Call `function` on a blocking thread, as it may block to apply back-pressure.
+async fn call(function: &SinkCallback, df: DataFrame) -> PolarsResult<bool> {
+    let function = function.clone();
+    polars_io::pl_async::get_runtime()
+        .spawn_blocking(move || function(df))
+        .await
+        .unwrap()
+}
+
+impl SinkNode for CallbackSinkNode {
+    fn name(&self) -> &str {
+        "callback-sink"
+    }
+
+    fn is_sink_input_parallel(&self) -> bool {
+        false
+    }
+
+    fn do_maintain_order(&self) -> bool {
+        self.maintain_order
+    }
+
+    fn spawn_sink(
+        &mut self,
+        mut recv_port_rx: Receiver<(PhaseOutcome, SinkInputPort)>,
+        _state: &StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        let function = self.function.clone();
+        let schema = self.schema.clone();
+        let chunk_size = self.chunk_size;
+
+        join_handles.push(spawn(TaskPriority::High, async move {
+            let mut buffer = DataFrame::empty_with_schema(schema.as_ref());
+            let mut stopped = false;
+
+            while let Ok((outcome, rx)) = recv_port_rx.recv().await {
+                let mut rx = rx.serial();
+                while let Ok(morsel) = rx.recv().await {
+                    let (df, _, source_token, consume_token) = morsel.into_inner();
+                    // Drain the morsels that were already underway when the function asked to
+                    // stop.
+                    if stopped {
+                        continue;
+                    }
+
+                    let Some(chunk_size) = chunk_size else {
+                        stopped = call(&function, df).await?;
+                        if stopped {
+                            source_token.stop();
+                        }
+                        continue;
+                    };
+
+                    // @NOTE: This also performs schema validation.
+                    buffer.vstack_mut(&df)?;
+                    while buffer.height() >= chunk_size.get() && !stopped {
+                        let batch;
+                        (batch, buffer) = buffer.split_at(chunk_size.get() as i64);
+                        stopped = call(&function, batch).await?;
+                    }
+                    if stopped {
+                        source_token.stop();
+                    }
+                    // Keep the consume_token until the function is done with the data to apply
+                    // its back-pressure to the query.
+                    drop(consume_token);
+                }
+
+                outcome.stopped();
+            }
+
+            if !stopped && !buffer.is_empty() {
+                call(&function, buffer).await?;
+            }
+            Ok(())
+        }));
+    }
+}
@@ -1,18 +1,20 @@
 use std::cmp::Reverse;
 use std::io::BufWriter;
+use std::sync::Arc;
 
 use polars_core::schema::{SchemaExt, SchemaRef};
 use polars_core::utils::arrow;
 use polars_core::utils::arrow::array::Array;
+use polars_core::utils::arrow::datatypes::Metadata;
 use polars_core::utils::arrow::io::ipc::write::{
     DictionaryTracker, EncodedData, WriteOptions, commit_encoded_arrays, default_ipc_fields,
     encode_array, encode_new_dictionaries,
 };
 use polars_error::PolarsResult;
-use polars_io::SerWriter;
 use polars_io::cloud::CloudOptions;
-use polars_io::ipc::{IpcWriter, IpcWriterOptions};
+use polars_io::ipc::IpcWriterOptions;
 use polars_plan::dsl::{SinkOptions, SinkTarget};
+use polars_utils::pl_str::PlSmallStr;
 use polars_utils::priority::Priority;
 
 use super::{
@@ -80,13 +82,20 @@ impl SinkNode for IpcSinkNode {
         let (mut lin_rx, lin_txs) =
             Linearizer::new(state.num_pipelines, *DEFAULT_SINK_LINEARIZER_BUFFER_SIZE);
         // Collect task -> IO task
-        let (mut io_tx, mut io_rx) = connector::<(Vec<EncodedData>, EncodedData)>();
+        let (mut io_tx, mut io_rx) = connector::<(
+            Vec<(PlSmallStr, Arc<Metadata>)>,
+            Vec<EncodedData>,
+            EncodedData,
+        )>();
 
         let options = WriteOptions {
             compression: self.write_options.compression.map(Into::into),
         };
 
-        let chunk_size = self.write_options.chunk_size;
+        let chunk_size = self
+            .sink_options
+            .batch_size
+            .unwrap_or(self.write_options.chunk_size as usize);
 
         let ipc_fields = self
             .input_schema
@@ -99,7 +108,7 @@ impl SinkNode for IpcSinkNode {
         join_handles.push(buffer_and_distribute_columns_task(
             recv_port_rx,
             dist_tx,
-            chunk_size as usize,
+            chunk_size,
             self.input_schema.clone(),
         ));
 
@@ -111,9 +120,10 @@ impl SinkNode for IpcSinkNode {
                 .into_iter()
                 .zip(lin_txs)
                 .map(|(mut dist_rx, mut lin_tx)| {
-                    let write_options = self.write_options;
+                    let compat_level = self.write_options.compat_level;
                     spawn(TaskPriority::High, async move {
                         while let Ok((seq, col_idx, column)) = dist_rx.recv().await {
+                            let metadata = column.field().metadata().cloned();
                             let mut variadic_buffer_counts = Vec::new();
                             let mut buffers = Vec::new();
                             let mut arrow_data = Vec::new();
@@ -127,7 +137,7 @@ impl SinkNode for IpcSinkNode {
                             //
                             // This also properly sets the inner types of the record batches, which is
                             // important for dictionary and nested type encoding.
-                            let array = column.rechunk_to_arrow(write_options.compat_level);
+                            let array = column.rechunk_to_arrow(compat_level);
 
                             // Encode array.
                             encode_array(
@@ -145,6 +155,7 @@ impl SinkNode for IpcSinkNode {
                                 Reverse(seq),
                                 (
                                     col_idx,
+                                    metadata,
                                     array,
                                     variadic_buffer_counts,
                                     buffers,
@@ -174,6 +185,7 @@ impl SinkNode for IpcSinkNode {
             };
 
             struct CurrentColumn {
+                metadata: Option<Arc<Metadata>>,
                 array: Box<dyn Array>,
                 variadic_buffer_counts: Vec<i64>,
                 buffers: Vec<arrow::io::ipc::format::ipc::Buffer>,
@@ -189,6 +201,9 @@ impl SinkNode for IpcSinkNode {
                 encoded_dictionaries: Vec<EncodedData>,
             }
 
+            // The schema is only written once, so we only need the column metadata of the first
+            // record batch.
+            let mut is_first_batch = true;
             let mut current = Current {
                 seq: 0,
                 height: 0,
@@ -200,7 +215,7 @@ impl SinkNode for IpcSinkNode {
             // Linearize from all the Encoder tasks.
             while let Some(Priority(
                 Reverse(seq),
-                (i, array, variadic_buffer_counts, buffers, arrow_data, nodes, offset),
+                (i, metadata, array, variadic_buffer_counts, buffers, arrow_data, nodes, offset),
             )) = lin_rx.get().await
             {
                 if current.num_columns_seen == 0 {
@@ -212,6 +227,7 @@ impl SinkNode for IpcSinkNode {
                 debug_assert_eq!(current.height, array.len());
                 debug_assert!(current.columns[i].is_none());
                 current.columns[i] = Some(CurrentColumn {
+                    metadata,
                     array,
                     variadic_buffer_counts,
                     buffers,
@@ -229,10 +245,18 @@ impl SinkNode for IpcSinkNode {
                     let mut arrow_data = Vec::new();
                     let mut nodes = Vec::new();
                     let mut offset = 0;
+                    let mut column_metadata = Vec::new();
 
                     for (i, column) in current.columns.iter_mut().enumerate() {
                         let column = column.take().unwrap();
 
+                        if is_first_batch {
+                            if let Some(metadata) = column.metadata {
+                                let name = input_schema.get_at_index(i).unwrap().0.clone();
+                                column_metadata.push((name, metadata));
+                            }
+                        }
+
                         // @Optimize: It would be nice to do this on the Encode Tasks, but it is
                         // difficult to centralize the dictionary tracker like that.
                         //
@@ -273,8 +297,10 @@ impl SinkNode for IpcSinkNode {
                         &mut encoded_data,
                     );
 
+                    is_first_batch = false;
                     if io_tx
                         .send((
+                            column_metadata,
                             std::mem::take(&mut current.encoded_dictionaries),
                             encoded_data,
                         ))
@@ -295,7 +321,7 @@ impl SinkNode for IpcSinkNode {
         // Task that will actually do write to the target file.
         let target = self.target.clone();
         let sink_options = self.sink_options.clone();
-        let write_options = self.write_options;
+        let write_options = self.write_options.clone();
         let cloud_options = self.cloud_options.clone();
         let input_schema = self.input_schema.clone();
         let io_task = polars_io::pl_async::get_runtime().spawn(async move {
@@ -303,15 +329,23 @@ impl SinkNode for IpcSinkNode {
                 .open_into_writeable_async(&sink_options, cloud_options.as_ref())
                 .await?;
             let writer = BufWriter::new(&mut *file);
-            let mut writer = IpcWriter::new(writer)
-                .with_compression(write_options.compression)
-                .with_parallel(false)
-                .batched(&input_schema)?;
+            let writer = write_options.to_writer(writer).with_parallel(false);
+            let mut writer = if write_options.stream {
+                writer.batched_stream(&input_schema)?
+            } else {
+                writer.batched(&input_schema)?
+            };
 
-            while let Ok((dicts, record_batch)) = io_rx.recv().await {
+            while let Ok((column_metadata, dicts, record_batch)) = io_rx.recv().await {
+                for (name, metadata) in &column_metadata {
+                    writer.add_field_metadata(name, metadata);
+                }
                 // @TODO: At the moment this is a sync write, this is not ideal because we can only
                 // have so many blocking threads in the tokio threadpool.
                 writer.write_encoded(dicts.as_slice(), &record_batch)?;
+                if write_options.flush_each_batch {
+                    writer.flush()?;
+                }
             }
 
             writer.finish()?;
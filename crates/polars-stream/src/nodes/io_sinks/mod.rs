@@ -20,6 +20,7 @@ use crate::nodes::TaskPriority;
 mod phase;
 use phase::PhaseOutcome;
 
+pub mod callback;
 #[cfg(feature = "csv")]
 pub mod csv;
 #[cfg(feature = "ipc")]
@@ -1,9 +1,10 @@
 use std::cmp::Reverse;
 use std::io::BufWriter;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
 use polars_core::prelude::{ArrowSchema, CompatLevel};
 use polars_core::schema::SchemaRef;
+use polars_core::utils::arrow::datatypes::Metadata;
 use polars_error::PolarsResult;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::write::BatchedWriter;
@@ -16,6 +17,7 @@ use polars_parquet::write::{
     WriteOptions, array_to_columns, to_parquet_schema,
 };
 use polars_plan::dsl::{SinkOptions, SinkTarget};
+use polars_utils::pl_str::PlSmallStr;
 use polars_utils::priority::Priority;
 
 use super::{
@@ -99,7 +101,8 @@ impl SinkNode for ParquetSinkNode {
         let (mut lin_rx, lin_txs) =
             Linearizer::new(state.num_pipelines, *DEFAULT_SINK_LINEARIZER_BUFFER_SIZE);
         // Collect task -> IO task
-        let (mut io_tx, mut io_rx) = connector::<Vec<Vec<CompressedPage>>>();
+        let (mut io_tx, mut io_rx) =
+            connector::<(Vec<(PlSmallStr, Arc<Metadata>)>, Vec<Vec<CompressedPage>>)>();
 
         let write_options = &self.write_options;
 
@@ -114,8 +117,9 @@ impl SinkNode for ParquetSinkNode {
         join_handles.push(buffer_and_distribute_columns_task(
             recv_port_rx,
             dist_tx,
-            write_options
-                .row_group_size
+            self.sink_options
+                .batch_size
+                .or(write_options.row_group_size)
                 .unwrap_or(DEFAULT_ROW_GROUP_SIZE),
             self.input_schema.clone(),
         ));
@@ -135,6 +139,7 @@ impl SinkNode for ParquetSinkNode {
                         while let Ok((rg_idx, col_idx, column)) = dist_rx.recv().await {
                             let type_ = &parquet_schema.fields()[col_idx];
                             let column_options = &column_options[col_idx];
+                            let metadata = column.field().metadata().cloned();
 
                             let array = column.as_materialized_series().rechunk();
                             let array = array.to_arrow(0, CompatLevel::newest());
@@ -169,7 +174,10 @@ impl SinkNode for ParquetSinkNode {
                                 .collect::<ParquetResult<Vec<_>>>()?;
 
                             if lin_tx
-                                .insert(Priority(Reverse(rg_idx), (col_idx, compressed_pages)))
+                                .insert(Priority(
+                                    Reverse(rg_idx),
+                                    (col_idx, metadata, compressed_pages),
+                                ))
                                 .await
                                 .is_err()
                             {
@@ -192,16 +200,23 @@ impl SinkNode for ParquetSinkNode {
                 seq: usize,
                 num_columns_seen: usize,
                 columns: Vec<Option<Vec<Vec<CompressedPage>>>>,
+                column_metadata: Vec<(PlSmallStr, Arc<Metadata>)>,
             }
 
+            // The arrow schema is only written once, so we only need the column metadata of the
+            // first row group.
+            let mut is_first_row_group = true;
             let mut current = Current {
                 seq: 0,
                 num_columns_seen: 0,
                 columns: (0..input_schema.len()).map(|_| None).collect(),
+                column_metadata: Vec::new(),
             };
 
             // Linearize from all the Encoder tasks.
-            while let Some(Priority(Reverse(seq), (i, compressed_pages))) = lin_rx.get().await {
+            while let Some(Priority(Reverse(seq), (i, metadata, compressed_pages))) =
+                lin_rx.get().await
+            {
                 if current.num_columns_seen == 0 {
                     current.seq = seq;
                 }
@@ -209,6 +224,10 @@ impl SinkNode for ParquetSinkNode {
                 debug_assert_eq!(current.seq, seq);
                 debug_assert!(current.columns[i].is_none());
                 current.columns[i] = Some(compressed_pages);
+                if let Some(metadata) = metadata.filter(|_| is_first_row_group) {
+                    let name = input_schema.get_at_index(i).unwrap().0.clone();
+                    current.column_metadata.push((name, metadata));
+                }
                 current.num_columns_seen += 1;
 
                 if current.num_columns_seen == input_schema.len() {
@@ -220,7 +239,13 @@ impl SinkNode for ParquetSinkNode {
                         current_row_group.extend(column.take().unwrap());
                     }
 
-                    if io_tx.send(current_row_group).await.is_err() {
+                    is_first_row_group = false;
+                    let column_metadata = std::mem::take(&mut current.column_metadata);
+                    if io_tx
+                        .send((column_metadata, current_row_group))
+                        .await
+                        .is_err()
+                    {
                         return Ok(());
                     }
                     current.num_columns_seen = 0;
@@ -269,7 +294,10 @@ impl SinkNode for ParquetSinkNode {
             );
 
             let num_parquet_columns = writer.parquet_schema().leaves().len();
-            while let Ok(current_row_group) = io_rx.recv().await {
+            while let Ok((column_metadata, current_row_group)) = io_rx.recv().await {
+                for (name, metadata) in &column_metadata {
+                    writer.add_field_metadata(name, metadata);
+                }
                 // @TODO: At the moment this is a sync write, this is not ideal because we can only
                 // have so many blocking threads in the tokio threadpool.
                 assert_eq!(current_row_group.len(), num_parquet_columns);
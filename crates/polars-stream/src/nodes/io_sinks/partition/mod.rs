@@ -40,7 +40,7 @@ pub fn get_create_new_fn(
                 input_schema,
                 target,
                 sink_options.clone(),
-                ipc_writer_options,
+                ipc_writer_options.clone(),
                 cloud_options.clone(),
             )) as Box<dyn SinkNode + Send + Sync>;
             Ok(sink)
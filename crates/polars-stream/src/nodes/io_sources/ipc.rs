@@ -49,6 +49,8 @@ pub mod builder {
     pub struct IpcReaderBuilder {
         #[expect(unused)]
         pub first_metadata: Option<Arc<FileMetadata>>,
+        #[cfg(feature = "ipc_encryption")]
+        pub encryption: Option<polars_io::ipc::IpcEncryption>,
     }
 
     #[cfg(feature = "ipc")]
@@ -86,6 +88,8 @@ pub mod builder {
                 scan_source,
                 cloud_options,
                 metadata,
+                #[cfg(feature = "ipc_encryption")]
+                encryption: self.encryption.clone(),
                 verbose,
                 init_data: None,
             };
@@ -105,6 +109,8 @@ struct IpcFileReader {
     scan_source: ScanSource,
     cloud_options: Option<Arc<CloudOptions>>,
     metadata: Option<Arc<FileMetadata>>,
+    #[cfg(feature = "ipc_encryption")]
+    encryption: Option<polars_io::ipc::IpcEncryption>,
     verbose: bool,
 
     init_data: Option<InitializedState>,
@@ -152,6 +158,23 @@ impl FileReader for IpcFileReader {
             return Ok(());
         }
 
+        #[cfg(feature = "ipc_encryption")]
+        if let Some(encryption) = &self.encryption {
+            let memslice = self
+                .scan_source
+                .as_scan_source_ref()
+                .to_decrypted_ipc_memslice(self.cloud_options.as_deref(), encryption)?;
+            let file_metadata = Arc::new(read_file_metadata(&mut std::io::Cursor::new(
+                memslice.as_ref(),
+            ))?);
+            self.init_data = Some(InitializedState {
+                memslice,
+                file_metadata,
+                n_rows_in_file: None,
+            });
+            return Ok(());
+        }
+
         // check_latest: IR resolution does not download IPC.
         // TODO: Streaming reads
         if let ScanSourceRef::Path(p) = self.scan_source.as_scan_source_ref() {
@@ -68,6 +68,7 @@ impl ApplyExtraOps {
                 extra_ops:
                     ExtraOperations {
                         row_index,
+                        row_index_per_file: _,
                         pre_slice,
                         cast_columns_policy,
                         missing_columns_policy,
@@ -21,6 +21,7 @@ use polars_utils::slice_enum::Slice;
 pub struct ExtraOperations {
     // Note: These fields are ordered according to when they (should be) applied.
     pub row_index: Option<RowIndex>,
+    pub row_index_per_file: bool,
     pub pre_slice: Option<Slice>,
     pub cast_columns_policy: CastColumnsPolicy,
     pub missing_columns_policy: MissingColumnsPolicy,
@@ -29,8 +30,10 @@ pub struct ExtraOperations {
 }
 
 impl ExtraOperations {
+    /// Whether the files must track the row position across the files before them. A row index
+    /// per file does not need this.
     pub fn has_row_index_or_slice(&self) -> bool {
-        self.row_index.is_some() || self.pre_slice.is_some()
+        (self.row_index.is_some() && !self.row_index_per_file) || self.pre_slice.is_some()
     }
 }
 
@@ -101,8 +101,6 @@ impl MultiScanTaskInitializer {
                     return Ok(());
                 }
 
-                let predicate = predicate.cloned();
-
                 self.init_and_run(bridge_recv_port_tx, skip_files_mask, predicate)
                     .await?
                     .await?;
@@ -16,7 +16,12 @@ impl MultiScanTaskInitializer {
     /// `(skip_files_mask, scan_predicate)`
     ///
     /// TODO: Move logic here, rename to `evaluate_on_constant_columns`.
-    pub fn initialize_predicate(&self) -> PolarsResult<(Option<Bitmap>, Option<&ScanIOPredicate>)> {
+    pub fn initialize_predicate(&self) -> PolarsResult<(Option<Bitmap>, Option<ScanIOPredicate>)> {
+        let (skip_files_mask, predicate) = self.initialize_scan_predicate()?;
+        Ok((skip_files_mask, self.add_runtime_filters(predicate)))
+    }
+
+    fn initialize_scan_predicate(&self) -> PolarsResult<(Option<Bitmap>, Option<ScanIOPredicate>)> {
         if let Some(predicate) = &self.config.predicate {
             if let Some(hive_parts) = self.config.hive_parts.as_ref() {
                 let (skip_files_mask, need_pred_for_inner_readers) = scan_predicate_to_mask(
@@ -29,12 +34,31 @@ impl MultiScanTaskInitializer {
 
                 return Ok((
                     skip_files_mask,
-                    need_pred_for_inner_readers.then_some(predicate),
+                    need_pred_for_inner_readers.then(|| predicate.clone()),
                 ));
             }
         }
 
-        Ok((None, self.config.predicate.as_ref()))
+        Ok((None, self.config.predicate.clone()))
+    }
+
+    /// Add the runtime filters of the joins that this scan can be the probe side of, they prune
+    /// rows from the moment the join sets them.
+    fn add_runtime_filters(
+        &self,
+        mut predicate: Option<ScanIOPredicate>,
+    ) -> Option<ScanIOPredicate> {
+        for slot in &self.config.runtime_filters {
+            if self.config.verbose {
+                eprintln!(
+                    "[MultiScanTaskInitializer]: attaching runtime filter on column '{}'",
+                    slot.column()
+                );
+            }
+            predicate =
+                Some(slot.apply_to_scan_predicate(predicate, &self.config.full_file_schema));
+        }
+        predicate
     }
 }
 
@@ -178,7 +178,8 @@ async fn resolve_negative_slice(config: &MultiFileReaderConfig) -> PolarsResult<
 
     let mut row_index = config.row_index.clone();
 
-    if let Some(row_index) = row_index.as_mut() {
+    // A row index per file does not depend on the rows of the skipped files.
+    if let Some(row_index) = row_index.as_mut().filter(|_| !config.row_index_per_file) {
         if verbose {
             eprintln!("resolve_negative_slice(): continuing scan to resolve row index");
         }
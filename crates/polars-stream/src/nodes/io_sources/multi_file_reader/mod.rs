@@ -13,7 +13,7 @@ use initialization::MultiScanTaskInitializer;
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_io::cloud::CloudOptions;
-use polars_io::predicates::ScanIOPredicate;
+use polars_io::predicates::{RuntimeFilterSlot, ScanIOPredicate};
 use polars_io::{RowIndex, pl_async};
 use polars_plan::dsl::{CastColumnsPolicy, ExtraColumnsPolicy, MissingColumnsPolicy, ScanSources};
 use polars_plan::plans::hive::HivePartitionsDf;
@@ -45,8 +45,14 @@ pub struct MultiFileReaderConfig {
     pub full_file_schema: SchemaRef,
 
     pub row_index: Option<RowIndex>,
+    /// Restart the row index at its offset for every file, so that files don't need to wait for
+    /// the row counts of the files before them.
+    pub row_index_per_file: bool,
     pub pre_slice: Option<Slice>,
     pub predicate: Option<ScanIOPredicate>,
+    /// Filters on file columns that are set by joins of which the scan is the probe side, they
+    /// are added to the predicate and prune rows once they are set.
+    pub runtime_filters: Vec<Arc<RuntimeFilterSlot>>,
 
     pub hive_parts: Option<Arc<HivePartitionsDf>>,
     pub include_file_paths: Option<PlSmallStr>,
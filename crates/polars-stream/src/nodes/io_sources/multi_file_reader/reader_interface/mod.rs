@@ -2,6 +2,8 @@
 
 pub mod builder;
 pub mod capabilities;
+#[cfg(feature = "cloud")]
+pub mod offload;
 pub mod output;
 
 use async_trait::async_trait;
@@ -0,0 +1,170 @@
+//! Reads the result of a scan that was offloaded to the object store, see
+//! [`ScanOffload`](polars_io::cloud::offload::ScanOffload).
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use polars_core::frame::DataFrame;
+use polars_core::schema::SchemaRef;
+use polars_error::PolarsResult;
+use polars_io::cloud::CloudOptions;
+use polars_io::cloud::offload::{OffloadRequest, get_scan_offload};
+use polars_io::path_utils::is_cloud_url;
+use polars_io::pl_async;
+use polars_io::predicates::{ScanIOPredicate, SpecializedColumnPredicateExpr};
+use polars_plan::dsl::ScanSource;
+use polars_utils::IdxSize;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::output::{FileReaderOutputRecv, FileReaderOutputSend};
+use super::{BeginReadArgs, FileReader, FileReaderCallbacks};
+use crate::async_executor::{JoinHandle, TaskPriority, spawn};
+use crate::morsel::{Morsel, MorselSeq, SourceToken};
+
+/// Offer the read of `scan_source` to the offload registered for its URL scheme.
+///
+/// This is done before the file reader is initialized, so that an offloaded read makes no
+/// requests for e.g. metadata. The read must not have a row index or slice, as the offload may
+/// leave out rows. The `predicate` must be applied to the result afterwards.
+pub async fn try_offload_read(
+    scan_source: &ScanSource,
+    format: &str,
+    cloud_options: Option<Arc<CloudOptions>>,
+    projected_schema: &SchemaRef,
+    predicate: Option<&ScanIOPredicate>,
+    verbose: bool,
+) -> PolarsResult<Option<OffloadedFileReader>> {
+    let ScanSource::Path(path) = scan_source else {
+        return Ok(None);
+    };
+    let Some(url) = path.to_str().filter(|_| is_cloud_url(path)) else {
+        return Ok(None);
+    };
+    let Some(offload) = get_scan_offload(url) else {
+        return Ok(None);
+    };
+
+    let columns = projected_schema.iter_names_cloned().collect::<Vec<_>>();
+    let equalities = predicate
+        .iter()
+        .flat_map(|p| p.column_predicates.predicates.iter())
+        .filter(|(name, _)| projected_schema.contains(name))
+        .filter_map(|(name, (_, specialized))| match specialized {
+            Some(SpecializedColumnPredicateExpr::Eq(value)) => Some((name.clone(), value.clone())),
+            _ => None,
+        })
+        .collect();
+    let request = OffloadRequest {
+        url: url.into(),
+        format: format.into(),
+        cloud_options,
+        columns,
+        equalities,
+    };
+
+    if verbose {
+        eprintln!(
+            "[MultiScan]: offering read of {} to the '{}' offload",
+            url,
+            offload.name()
+        );
+    }
+
+    let offload_name = PlSmallStr::from_str(offload.name());
+    let df = pl_async::get_runtime()
+        .spawn(async move { offload.offload(request).await })
+        .await
+        .unwrap()?;
+
+    if verbose && df.is_none() {
+        eprintln!("[MultiScan]: '{}' offload declined", offload_name);
+    }
+
+    Ok(df.map(|df| OffloadedFileReader {
+        offload_name,
+        schema: df.schema().clone(),
+        df: Some(df),
+        verbose,
+    }))
+}
+
+/// Reads the result of an offloaded scan instead of the file.
+pub struct OffloadedFileReader {
+    offload_name: PlSmallStr,
+    schema: SchemaRef,
+    df: Option<DataFrame>,
+    verbose: bool,
+}
+
+#[async_trait]
+impl FileReader for OffloadedFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let BeginReadArgs {
+            projected_schema,
+            row_index: None,
+            pre_slice: None,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+            ..
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        let df = self
+            .df
+            .take()
+            .expect("OffloadedFileReader called more than once");
+        // Columns that the result lacks are handled like columns that are missing from the file.
+        let df = df.select(
+            projected_schema
+                .iter_names()
+                .filter(|name| df.schema().contains(name))
+                .cloned(),
+        )?;
+
+        if let Some(mut file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.try_send(df.schema().clone());
+        }
+        let height = IdxSize::try_from(df.height()).unwrap_or(IdxSize::MAX);
+        for mut tx in [n_rows_in_file_tx, row_position_on_end_tx]
+            .into_iter()
+            .flatten()
+        {
+            _ = tx.try_send(height);
+        }
+
+        if self.verbose {
+            eprintln!(
+                "[OffloadedFileReader]: '{}' offload returned {} rows",
+                self.offload_name,
+                df.height()
+            );
+        }
+
+        let (mut morsel_sender, morsel_rx) = FileReaderOutputSend::new_serial();
+        let handle = spawn(TaskPriority::Low, async move {
+            _ = morsel_sender
+                .send_morsel(Morsel::new(df, MorselSeq::default(), SourceToken::new()))
+                .await;
+            Ok(())
+        });
+
+        Ok((morsel_rx, handle))
+    }
+
+    async fn file_schema(&mut self) -> PolarsResult<SchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
@@ -30,6 +30,8 @@ use crate::nodes::io_sources::multi_file_reader::initialization::slice::{
 };
 use crate::nodes::io_sources::multi_file_reader::post_apply_pipeline::PostApplyPool;
 use crate::nodes::io_sources::multi_file_reader::reader_interface::capabilities::ReaderCapabilities;
+#[cfg(feature = "cloud")]
+use crate::nodes::io_sources::multi_file_reader::reader_interface::offload::try_offload_read;
 use crate::nodes::io_sources::multi_file_reader::reader_interface::output::FileReaderOutputRecv;
 use crate::nodes::io_sources::multi_file_reader::reader_interface::{
     BeginReadArgs, FileReader, FileReaderCallbacks,
@@ -47,8 +49,8 @@ impl MultiScanTaskInitializer {
         let reader_capabilities = self.config.file_reader_builder.reader_capabilities();
 
         // Row index should only be pushed if we have a predicate or negative slice as there is a
-        // serial synchronization cost.
-        if self.config.row_index.is_some() {
+        // serial synchronization cost. A row index per file has no such cost.
+        if self.config.row_index.is_some() && !self.config.row_index_per_file {
             debug_assert!(
                 self.config.predicate.is_some()
                     || matches!(self.config.pre_slice, Some(Slice::Negative { .. }))
@@ -98,6 +100,7 @@ impl MultiScanTaskInitializer {
 
         let extra_ops = ExtraOperations {
             row_index,
+            row_index_per_file: self.config.row_index_per_file,
             pre_slice,
             cast_columns_policy,
             missing_columns_policy,
@@ -132,6 +135,8 @@ impl MultiScanTaskInitializer {
         // allowing for e.g. concurrent Parquet metadata fetch.
         let readers_init_iter = {
             let skip_files_mask = skip_files_mask.clone();
+            #[cfg(feature = "cloud")]
+            let offload_predicate = extra_ops.predicate.clone();
 
             // If a negative slice was initialized, the length of the initialized readers will be the exact
             // stopping position.
@@ -159,12 +164,16 @@ impl MultiScanTaskInitializer {
                     let file_reader_builder = config.file_reader_builder.clone();
                     let sources = config.sources.clone();
                     let skip_files_mask = skip_files_mask.clone();
+                    #[cfg(feature = "cloud")]
+                    let config = config.clone();
+                    #[cfg(feature = "cloud")]
+                    let offload_predicate = offload_predicate.clone();
 
                     let maybe_initialized = initialized_readers.pop_front();
                     let scan_source = sources.get(scan_source_idx).unwrap().into_owned();
 
                     AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
-                        let (scan_source, reader, n_rows_in_file) = async {
+                        let (scan_source, reader, n_rows_in_file, offloaded) = async {
                             if verbose {
                                 eprintln!("[MultiScan]: Initialize source {}", scan_source_idx);
                             }
@@ -176,9 +185,33 @@ impl MultiScanTaskInitializer {
                                     scan_source,
                                     reader,
                                     Some(n_rows_in_file),
+                                    false,
                                 ));
                             }
 
+                            let skipped = !has_row_index_or_slice
+                                && skip_files_mask.is_some_and(|x| x.get_bit(scan_source_idx));
+
+                            // Reads without a row index or slice can be offloaded to the object
+                            // store. This is offered before the reader is initialized, so that an
+                            // offloaded read does not fetch e.g. metadata.
+                            #[cfg(feature = "cloud")]
+                            if !has_row_index_or_slice && !skipped {
+                                if let Some(offloaded) = try_offload_read(
+                                    &scan_source,
+                                    config.file_reader_builder.reader_name(),
+                                    cloud_options.clone(),
+                                    &config.projected_file_schema,
+                                    offload_predicate.as_ref(),
+                                    verbose,
+                                )
+                                .await?
+                                {
+                                    let reader: Box<dyn FileReader> = Box::new(offloaded);
+                                    return Ok((scan_source, reader, None, true));
+                                }
+                            }
+
                             let mut reader = file_reader_builder.build_file_reader(
                                 scan_source.clone(),
                                 cloud_options,
@@ -188,18 +221,22 @@ impl MultiScanTaskInitializer {
                             // Skip initialization if this file is filtered, this can save some cloud calls / metadata deserialization.
                             // Downstream must also check against `skip_files_mask` and avoid calling any functions on this reader
                             // if it is filtered out.
-                            if !has_row_index_or_slice
-                                && skip_files_mask.is_some_and(|x| x.get_bit(scan_source_idx))
-                            {
-                                return Ok((scan_source, reader, None));
+                            if skipped {
+                                return Ok((scan_source, reader, None, false));
                             }
 
                             reader.initialize().await?;
-                            PolarsResult::Ok((scan_source, reader, None))
+                            PolarsResult::Ok((scan_source, reader, None, false))
                         }
                         .await?;
 
-                        Ok((scan_source_idx, scan_source, reader, n_rows_in_file))
+                        Ok((
+                            scan_source_idx,
+                            scan_source,
+                            reader,
+                            n_rows_in_file,
+                            offloaded,
+                        ))
                     }))
                 })
                 .buffered(
@@ -270,9 +307,18 @@ impl MultiScanTaskInitializer {
 /// Starts readers, potentially multiple at the same time if it can.
 struct ReaderStarter {
     reader_capabilities: ReaderCapabilities,
+    /// Yields `(scan_source_idx, scan_source, reader, n_rows_in_file, offloaded)`.
     #[expect(clippy::type_complexity)]
-    readers_init_iter:
-        BoxStream<'static, PolarsResult<(usize, ScanSource, Box<dyn FileReader>, Option<IdxSize>)>>,
+    readers_init_iter: BoxStream<
+        'static,
+        PolarsResult<(
+            usize,
+            ScanSource,
+            Box<dyn FileReader>,
+            Option<IdxSize>,
+            bool,
+        )>,
+    >,
     n_sources: usize,
     started_reader_tx: tokio::sync::mpsc::Sender<(
         AbortOnDropHandle<PolarsResult<StartedReaderState>>,
@@ -336,7 +382,7 @@ impl ReaderStarter {
                 break;
             }
 
-            let Some((scan_source_idx, scan_source, mut reader, opt_n_rows_in_file)) =
+            let Some((scan_source_idx, scan_source, mut reader, opt_n_rows_in_file, offloaded)) =
                 readers_init_iter.next().await.transpose()?
             else {
                 if verbose {
@@ -376,7 +422,9 @@ impl ReaderStarter {
             }
 
             let row_index_this_file = extra_ops.row_index.clone().map(|mut ri| {
-                ri.offset = ri.offset.saturating_add(current_row_position);
+                if !extra_ops.row_index_per_file {
+                    ri.offset = ri.offset.saturating_add(current_row_position);
+                }
                 ri
             });
 
@@ -420,6 +468,14 @@ impl ReaderStarter {
 
             let mut extra_ops_post = extra_ops_this_file;
 
+            // An offloaded read only returns the result of the offload, which may contain more
+            // rows than the predicate selects, so everything is applied afterwards.
+            let reader_capabilities = if offloaded {
+                ReaderCapabilities::empty()
+            } else {
+                reader_capabilities
+            };
+
             let row_index = if reader_capabilities.contains(ReaderCapabilities::ROW_INDEX) {
                 extra_ops_post.row_index.take()
             } else {
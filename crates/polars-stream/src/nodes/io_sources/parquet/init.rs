@@ -284,15 +284,22 @@ impl ParquetReadImpl {
 
         // Decode loop (spawns decodes on the computational executor).
         let (decode_send, mut decode_recv) = tokio::sync::mpsc::channel(self.config.num_pipelines);
+        let max_rows_per_decode = self.config.max_rows_per_decode;
         let decode_task = AbortOnDropHandle(io_runtime.spawn(async move {
             while let Some(prefetch) = prefetch_recv.recv().await {
                 let row_group_data = prefetch.await.unwrap()?;
-                let row_group_decoder = row_group_decoder.clone();
-                let decode_fut = async_executor::spawn(TaskPriority::High, async move {
-                    row_group_decoder.row_group_data_to_df(row_group_data).await
-                });
-                if decode_send.send(decode_fut).await.is_err() {
-                    break;
+                // Large row groups are decoded in multiple parts, so that they don't serialize
+                // the pipelines.
+                for row_group_data in
+                    row_group_decoder.split_row_group(row_group_data, max_rows_per_decode)
+                {
+                    let row_group_decoder = row_group_decoder.clone();
+                    let decode_fut = async_executor::spawn(TaskPriority::High, async move {
+                        row_group_decoder.row_group_data_to_df(row_group_data).await
+                    });
+                    if decode_send.send(decode_fut).await.is_err() {
+                        return PolarsResult::Ok(());
+                    }
                 }
             }
             PolarsResult::Ok(())
@@ -10,7 +10,7 @@ use polars_io::predicates::ScanIOPredicate;
 use polars_io::prelude::{FileMetadata, ParquetOptions};
 use polars_io::utils::byte_source::{DynByteSource, DynByteSourceBuilder, MemSliceByteSource};
 use polars_io::{RowIndex, pl_async};
-use polars_parquet::read::schema::infer_schema_with_options;
+use polars_parquet::read::schema::{infer_schema_with_options, strings_as_categorical};
 use polars_plan::dsl::{CastColumnsPolicy, ScanSource};
 use polars_utils::IdxSize;
 use polars_utils::mem::prefetch::get_memory_prefetch_func;
@@ -80,7 +80,7 @@ impl FileReader for ParquetFileReader {
 
         let mut byte_source = Arc::new(byte_source);
 
-        let file_metadata = if let Some(v) = self.metadata.clone() {
+        let mut file_metadata = if let Some(v) = self.metadata.clone() {
             v
         } else {
             let (metadata_bytes, opt_full_bytes) = {
@@ -104,7 +104,17 @@ impl FileReader for ParquetFileReader {
             )?)
         };
 
-        let file_schema = Arc::new(infer_schema_with_options(&file_metadata, &None)?);
+        let mut file_schema = infer_schema_with_options(&file_metadata, &None)?;
+        if self.config.strings_as_categorical {
+            strings_as_categorical(&mut file_schema, &file_metadata);
+        }
+        if let Some(column_mapping) = &self.config.column_mapping {
+            let renames = column_mapping.apply_to_schema(&mut file_schema, &file_metadata)?;
+            if !renames.is_empty() {
+                Arc::make_mut(&mut file_metadata).rename_root_columns(&renames);
+            }
+        }
+        let file_schema = Arc::new(file_schema);
 
         self.init_data = Some(InitializedState {
             file_metadata,
@@ -198,6 +208,12 @@ impl FileReader for ParquetFileReader {
             .map(|x| x.parse::<usize>().expect("integer").max(1))
             .unwrap_or(16_777_216);
 
+        // Row groups with more rows than this are decoded in parallel in row ranges of at most
+        // this many rows.
+        let max_rows_per_decode = std::env::var("POLARS_PARQUET_MAX_ROWS_PER_DECODE")
+            .map(|x| x.parse::<usize>().expect("integer").max(1))
+            .unwrap_or(4_194_304);
+
         let projected_arrow_schema: ArrowSchemaRef = Arc::new(
             projected_schema
                 .iter_names()
@@ -259,6 +275,7 @@ impl FileReader for ParquetFileReader {
                 num_pipelines,
                 row_group_prefetch_size,
                 min_values_per_thread,
+                max_rows_per_decode,
             },
             verbose,
             schema: file_schema.clone(),
@@ -346,6 +363,9 @@ struct Config {
     /// Minimum number of values for a parallel spawned task to process to amortize
     /// parallelism overhead.
     min_values_per_thread: usize,
+    /// Maximum number of rows of a row group to decode in a single task. Larger row groups are
+    /// split into row ranges that are decoded in parallel.
+    max_rows_per_decode: usize,
 }
 
 impl ParquetReadImpl {
@@ -16,6 +16,7 @@ use polars_utils::pl_str::PlSmallStr;
 use crate::utils::task_handles_ext;
 
 /// Represents byte-data that can be transformed into a DataFrame after some computation.
+#[derive(Clone)]
 pub(super) struct RowGroupData {
     pub(super) fetched_bytes: FetchedBytes,
     pub(super) row_offset: usize,
@@ -167,6 +168,7 @@ impl RowGroupDataFetcher {
     }
 }
 
+#[derive(Clone)]
 pub(super) enum FetchedBytes {
     MemSlice { mem_slice: MemSlice, offset: usize },
     BytesMap(PlHashMap<usize, MemSlice>),
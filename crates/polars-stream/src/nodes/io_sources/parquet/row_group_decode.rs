@@ -37,6 +37,38 @@ pub(super) struct RowGroupDecoder {
 }
 
 impl RowGroupDecoder {
+    /// Splits the rows of a row group into ranges of at most `max_rows` rows that can be decoded
+    /// in parallel. Pages outside of a range are skipped without being decompressed.
+    ///
+    /// Row groups that are decoded with a pre-filter are not split, as pre-filtering is not
+    /// supported for slices.
+    pub(super) fn split_row_group(
+        &self,
+        row_group_data: RowGroupData,
+        max_rows: usize,
+    ) -> Vec<RowGroupData> {
+        let (offset, len) = row_group_data
+            .slice
+            .unwrap_or((0, row_group_data.row_group_metadata.num_rows()));
+
+        if len <= max_rows
+            || (self.use_prefiltered.is_some() && !self.predicate_arrow_field_indices.is_empty())
+        {
+            return vec![row_group_data];
+        }
+
+        let n_parts = len.div_ceil(max_rows);
+        let rows_per_part = len.div_ceil(n_parts);
+
+        (offset..offset + len)
+            .step_by(rows_per_part)
+            .map(|part_offset| RowGroupData {
+                slice: Some((part_offset, rows_per_part.min(offset + len - part_offset))),
+                ..row_group_data.clone()
+            })
+            .collect()
+    }
+
     pub(super) async fn row_group_data_to_df(
         &self,
         mut row_group_data: RowGroupData,
@@ -11,6 +11,7 @@ use polars_core::{POOL, config};
 use polars_expr::hash_keys::HashKeys;
 use polars_expr::idx_table::{IdxTable, new_idx_table};
 use polars_io::pl_async::get_runtime;
+use polars_io::predicates::{RuntimeFilterBuilder, RuntimeFilterSlot};
 use polars_ops::frame::{JoinArgs, JoinType, MaintainOrderJoin};
 use polars_ops::series::coalesce_columns;
 use polars_utils::cardinality_sketch::CardinalitySketch;
@@ -45,10 +46,19 @@ struct EquiJoinParams {
     left_payload_schema: Arc<Schema>,
     right_payload_schema: Arc<Schema>,
     args: JoinArgs,
+    nulls_equal: Vec<bool>,
     random_state: PlRandomState,
+    /// Filters on the key of the left and right side, only the one of the probe side is set once
+    /// the build side is collected.
+    runtime_filters: [Option<Arc<RuntimeFilterSlot>>; 2],
 }
 
 impl EquiJoinParams {
+    fn probe_runtime_filter(&self) -> Option<&Arc<RuntimeFilterSlot>> {
+        let probe_idx = self.left_is_build? as usize;
+        self.runtime_filters[probe_idx].as_ref()
+    }
+
     /// Should we emit unmatched rows from the build side?
     fn emit_unmatched_build(&self) -> bool {
         if self.left_is_build.unwrap() {
@@ -160,10 +170,10 @@ async fn select_keys(
         key_columns.push(selector.evaluate(df, state).await?.into_column());
     }
     let keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
-    Ok(HashKeys::from_df(
+    Ok(HashKeys::from_df_per_key(
         &keys,
         params.random_state,
-        params.args.nulls_equal,
+        &params.nulls_equal,
         false,
     ))
 }
@@ -399,6 +409,9 @@ struct LocalBuilder {
     // let stop = morsel_idxs_offsets[(i + 1) * num_partitions + p];
     morsel_idxs_values_per_p: Vec<Vec<IdxSize>>,
     morsel_idxs_offsets_per_p: Vec<usize>,
+
+    // The keys seen by this builder for the runtime filter, if any.
+    runtime_filter: RuntimeFilterBuilder,
 }
 
 struct BuildState {
@@ -418,6 +431,7 @@ impl BuildState {
                 sketch_per_p: vec![CardinalitySketch::default(); num_partitions],
                 morsel_idxs_values_per_p: vec![Vec::new(); num_partitions],
                 morsel_idxs_offsets_per_p: vec![0; num_partitions],
+                runtime_filter: RuntimeFilterBuilder::default(),
             })
             .collect();
         Self {
@@ -456,6 +470,13 @@ impl BuildState {
             let mut payload = select_payload(morsel.df().clone(), payload_selector);
             payload.rechunk_mut();
 
+            if params.probe_runtime_filter().is_some() {
+                let keys = key_selectors[0]
+                    .evaluate(morsel.df(), &state.in_memory_exec_state)
+                    .await?;
+                local.runtime_filter.update(keys.as_materialized_series())?;
+            }
+
             hash_keys.gen_idxs_per_partition(
                 &partitioner,
                 &mut local.morsel_idxs_values_per_p,
@@ -471,6 +492,14 @@ impl BuildState {
         Ok(())
     }
 
+    fn set_runtime_filter(&mut self, slot: &RuntimeFilterSlot) -> PolarsResult<()> {
+        let mut builder = RuntimeFilterBuilder::default();
+        for local in &mut self.local_builders {
+            builder.merge(core::mem::take(&mut local.runtime_filter))?;
+        }
+        slot.set(builder)
+    }
+
     fn finalize_ordered(&mut self, params: &EquiJoinParams, table: &dyn IdxTable) -> ProbeState {
         let track_unmatchable = params.emit_unmatched_build();
         let payload_schema = if params.left_is_build.unwrap() {
@@ -1157,6 +1186,7 @@ impl EquiJoinNode {
         left_key_selectors: Vec<StreamExpr>,
         right_key_selectors: Vec<StreamExpr>,
         args: JoinArgs,
+        runtime_filters: [Option<Arc<RuntimeFilterSlot>>; 2],
         num_pipelines: usize,
     ) -> PolarsResult<Self> {
         let left_is_build = match args.maintain_order {
@@ -1202,6 +1232,7 @@ impl EquiJoinNode {
             EquiJoinState::Sample(SampleState::default())
         };
 
+        let nulls_equal = args.nulls_equal_for_keys(left_key_selectors.len())?;
         let left_payload_schema = Arc::new(select_schema(&left_input_schema, &left_payload_select));
         let right_payload_schema =
             Arc::new(select_schema(&right_input_schema, &right_payload_select));
@@ -1220,7 +1251,9 @@ impl EquiJoinNode {
                 left_payload_schema,
                 right_payload_schema,
                 args,
+                nulls_equal,
                 random_state: PlRandomState::default(),
+                runtime_filters,
             },
             table: new_idx_table(unique_key_schema),
         })
@@ -1264,6 +1297,9 @@ impl ComputeNode for EquiJoinNode {
         // If we are building and the build input is done, transition to probing.
         if let EquiJoinState::Build(build_state) = &mut self.state {
             if recv[build_idx] == PortState::Done {
+                if let Some(slot) = self.params.probe_runtime_filter() {
+                    build_state.set_runtime_filter(slot)?;
+                }
                 let probe_state = if self.params.preserve_order_build {
                     build_state.finalize_ordered(&self.params, &*self.table)
                 } else {
@@ -1278,6 +1314,15 @@ impl ComputeNode for EquiJoinNode {
         if let EquiJoinState::Probe(probe_state) = &mut self.state {
             let samples_consumed = probe_state.sampled_probe_morsels.is_empty();
             if samples_consumed && recv[probe_idx] == PortState::Done {
+                if let Some(slot) = self.params.probe_runtime_filter() {
+                    if config::verbose() {
+                        eprintln!(
+                            "runtime filter on column '{}' pruned {} rows",
+                            slot.column(),
+                            slot.pruned_rows()
+                        );
+                    }
+                }
                 if self.params.emit_unmatched_build() {
                     if self.params.preserve_order_build {
                         let unmatched = probe_state.ordered_unmatched(&self.params);
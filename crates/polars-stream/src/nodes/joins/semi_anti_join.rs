@@ -6,6 +6,7 @@ use polars_core::prelude::*;
 use polars_core::schema::Schema;
 use polars_expr::groups::{Grouper, new_hash_grouper};
 use polars_expr::hash_keys::HashKeys;
+use polars_io::predicates::{RuntimeFilterBuilder, RuntimeFilterSlot};
 use polars_ops::frame::{JoinArgs, JoinType};
 use polars_utils::IdxSize;
 use polars_utils::cardinality_sketch::CardinalitySketch;
@@ -29,10 +30,10 @@ async fn select_keys(
         key_columns.push(selector.evaluate(df, state).await?.into_column());
     }
     let keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
-    Ok(HashKeys::from_df(
+    Ok(HashKeys::from_df_per_key(
         &keys,
         params.random_state,
-        params.nulls_equal,
+        &params.nulls_equal_per_key,
         false,
     ))
 }
@@ -42,9 +43,12 @@ struct SemiAntiJoinParams {
     left_key_selectors: Vec<StreamExpr>,
     right_key_selectors: Vec<StreamExpr>,
     nulls_equal: bool,
+    nulls_equal_per_key: Vec<bool>,
     is_anti: bool,
     return_bool: bool,
     random_state: PlRandomState,
+    /// Filter on the key of the probe side to set once the build side is collected.
+    runtime_filter: Option<Arc<RuntimeFilterSlot>>,
 }
 
 pub struct SemiAntiJoinNode {
@@ -60,12 +64,14 @@ impl SemiAntiJoinNode {
         right_key_selectors: Vec<StreamExpr>,
         args: JoinArgs,
         return_bool: bool,
+        runtime_filter: Option<Arc<RuntimeFilterSlot>>,
         num_pipelines: usize,
     ) -> PolarsResult<Self> {
         let left_is_build = false;
         let is_anti = args.how == JoinType::Anti;
 
         let state = SemiAntiJoinState::Build(BuildState::new(num_pipelines, num_pipelines));
+        let nulls_equal_per_key = args.nulls_equal_for_keys(left_key_selectors.len())?;
 
         Ok(Self {
             state,
@@ -74,9 +80,11 @@ impl SemiAntiJoinNode {
                 left_key_selectors,
                 right_key_selectors,
                 random_state: PlRandomState::default(),
-                nulls_equal: args.nulls_equal,
+                nulls_equal: nulls_equal_per_key.iter().all(|&eq| eq),
+                nulls_equal_per_key,
                 return_bool,
                 is_anti,
+                runtime_filter,
             },
             grouper: new_hash_grouper(unique_key_schema),
         })
@@ -103,6 +111,9 @@ struct LocalBuilder {
     // let stop = key_idxs_offsets[(i + 1) * num_partitions + p];
     key_idxs_values_per_p: Vec<Vec<IdxSize>>,
     key_idxs_offsets_per_p: Vec<usize>,
+
+    // The keys seen by this builder for the runtime filter, if any.
+    runtime_filter: RuntimeFilterBuilder,
 }
 
 struct BuildState {
@@ -117,6 +128,7 @@ impl BuildState {
                 sketch_per_p: vec![CardinalitySketch::default(); num_partitions],
                 key_idxs_values_per_p: vec![Vec::new(); num_partitions],
                 key_idxs_offsets_per_p: vec![0; num_partitions],
+                runtime_filter: RuntimeFilterBuilder::default(),
             })
             .collect();
         Self { local_builders }
@@ -144,6 +156,13 @@ impl BuildState {
             )
             .await?;
 
+            if params.runtime_filter.is_some() {
+                let keys = key_selectors[0]
+                    .evaluate(morsel.df(), &state.in_memory_exec_state)
+                    .await?;
+                local.runtime_filter.update(keys.as_materialized_series())?;
+            }
+
             hash_keys.gen_idxs_per_partition(
                 &partitioner,
                 &mut local.key_idxs_values_per_p,
@@ -159,6 +178,14 @@ impl BuildState {
         Ok(())
     }
 
+    fn set_runtime_filter(&mut self, slot: &RuntimeFilterSlot) -> PolarsResult<()> {
+        let mut builder = RuntimeFilterBuilder::default();
+        for local in &mut self.local_builders {
+            builder.merge(core::mem::take(&mut local.runtime_filter))?;
+        }
+        slot.set(builder)
+    }
+
     fn finalize(&mut self, grouper: &dyn Grouper) -> ProbeState {
         // To reduce maximum memory usage we want to drop the original keys
         // as soon as they're processed, so we move into Arcs. The drops might
@@ -365,6 +392,9 @@ impl ComputeNode for SemiAntiJoinNode {
         // If we are building and the build input is done, transition to probing.
         if let SemiAntiJoinState::Build(build_state) = &mut self.state {
             if recv[build_idx] == PortState::Done {
+                if let Some(slot) = &self.params.runtime_filter {
+                    build_state.set_runtime_filter(slot)?;
+                }
                 let probe_state = build_state.finalize(&*self.grouper);
                 self.state = SemiAntiJoinState::Probe(probe_state);
             }
@@ -373,6 +403,15 @@ impl ComputeNode for SemiAntiJoinNode {
         // If we are probing and the probe input is done, we're done.
         if let SemiAntiJoinState::Probe(_) = &mut self.state {
             if recv[probe_idx] == PortState::Done {
+                if let Some(slot) = &self.params.runtime_filter {
+                    if polars_core::config::verbose() {
+                        eprintln!(
+                            "runtime filter on column '{}' pruned {} rows",
+                            slot.column(),
+                            slot.pruned_rows()
+                        );
+                    }
+                }
                 self.state = SemiAntiJoinState::Done;
             }
         }
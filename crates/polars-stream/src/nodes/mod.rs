@@ -1,5 +1,15 @@
+#[cfg(feature = "ewma")]
+pub mod ewm;
+#[cfg(feature = "ipc")]
+pub mod external_distinct;
+#[cfg(feature = "ipc")]
+pub mod external_quantile;
+#[cfg(feature = "fill_null_by")]
+pub mod fill_null_by;
 pub mod filter;
 pub mod group_by;
+#[cfg(feature = "ipc")]
+pub mod group_by_apply;
 pub mod in_memory_map;
 pub mod in_memory_sink;
 pub mod in_memory_source;
@@ -13,9 +23,14 @@ pub mod merge_sorted;
 pub mod multiplexer;
 pub mod negative_slice;
 pub mod ordered_union;
+#[cfg(any(feature = "ewma", feature = "fill_null_by"))]
+pub mod partitioned;
 pub mod reduce;
 pub mod select;
 pub mod simple_projection;
+pub mod sorted_distinct;
+#[cfg(feature = "ipc")]
+pub mod spill;
 pub mod streaming_slice;
 pub mod with_row_index;
 pub mod zip;
@@ -0,0 +1,46 @@
+use polars_core::prelude::*;
+use polars_utils::pl_str::PlSmallStr;
+
+/// Calls `f` with the key and the row indices of every group of `partition_by`
+/// in `df`, in order of first occurrence, and scatters the series it returns
+/// back to the rows they belong to.
+///
+/// `f` must return a series with one value for every row index of its group.
+pub fn for_each_group_stable<F>(
+    df: &DataFrame,
+    partition_by: &[PlSmallStr],
+    mut f: F,
+) -> PolarsResult<Series>
+where
+    F: FnMut(Vec<AnyValue<'static>>, &[IdxSize]) -> PolarsResult<Series>,
+{
+    let groups = df.group_by_stable(partition_by.iter().cloned())?;
+    let keys = groups.keys();
+    let groups = (**groups.get_groups()).clone().into_idx();
+
+    let mut outputs = Vec::with_capacity(groups.len());
+    let mut order = Vec::with_capacity(df.height());
+    for (i, (_, idx)) in groups.iter().enumerate() {
+        let key = keys
+            .iter()
+            .map(|c| c.get(i).map(|v| v.into_static()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let out = f(key, idx)?;
+        debug_assert_eq!(out.len(), idx.len());
+        outputs.push(out);
+        order.extend_from_slice(idx);
+    }
+
+    let mut inverse = vec![0 as IdxSize; order.len()];
+    for (i, row) in order.into_iter().enumerate() {
+        inverse[row as usize] = i as IdxSize;
+    }
+    let mut outputs = outputs.into_iter();
+    let mut concatenated = outputs.next().unwrap();
+    for s in outputs {
+        concatenated.append(&s)?;
+    }
+    // SAFETY: every row of `df` is in exactly one group, so `inverse` is a
+    // permutation of the rows of `concatenated`.
+    Ok(unsafe { concatenated.take_slice_unchecked(&inverse) })
+}
@@ -0,0 +1,98 @@
+use polars_core::prelude::*;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::compute_node_prelude::*;
+
+/// Keeps the first row of every run of equal `keys`, for input that is known
+/// to be sorted by them, so that all duplicates are neighbours. The last key
+/// of a morsel is carried to the next one, as a run may span both.
+pub struct SortedDistinctNode {
+    keys: Vec<PlSmallStr>,
+    /// The keys of the last row seen.
+    last_key: Option<DataFrame>,
+}
+
+impl SortedDistinctNode {
+    pub fn new(keys: Vec<PlSmallStr>) -> Self {
+        Self {
+            keys,
+            last_key: None,
+        }
+    }
+
+    fn update(&mut self, df: DataFrame) -> PolarsResult<DataFrame> {
+        if df.height() == 0 {
+            return Ok(df);
+        }
+
+        // Compare every row with its predecessor, the first row with the last
+        // one of the previous morsel.
+        let mut keys = df.select(self.keys.iter().cloned())?;
+        let carried = match self.last_key.take() {
+            Some(mut last_key) => {
+                last_key.vstack_mut(&keys)?;
+                keys = last_key;
+                true
+            },
+            None => false,
+        };
+        let n = keys.height();
+        let mut changed = BooleanChunked::full(PlSmallStr::EMPTY, false, n - 1);
+        for column in keys.get_columns() {
+            let s = column.as_materialized_series();
+            changed = &changed | &s.slice(1, n - 1).not_equal_missing(&s.slice(0, n - 1))?;
+        }
+        let mask = if carried {
+            changed
+        } else {
+            std::iter::once(true)
+                .chain(changed.into_no_null_iter())
+                .collect::<BooleanChunked>()
+        };
+
+        self.last_key = Some(keys.slice(-1, 1));
+        df.filter(&mask)
+    }
+}
+
+impl ComputeNode for SortedDistinctNode {
+    fn name(&self) -> &str {
+        "sorted-distinct"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+        recv.swap_with_slice(send);
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        let mut recv = recv_ports[0].take().unwrap().serial();
+        let mut send = send_ports[0].take().unwrap().serial();
+
+        // Whether a row is kept depends on the row before it, so we must be serial.
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            while let Ok(morsel) = recv.recv().await {
+                let morsel = morsel.try_map(|df| self.update(df))?;
+                if send.send(morsel).await.is_err() {
+                    break;
+                }
+            }
+
+            Ok(())
+        }));
+    }
+}
@@ -0,0 +1,292 @@
+use std::fs::File;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use parking_lot::Mutex;
+use polars_core::config::verbose;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::PlRandomState;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_error::{PolarsResult, polars_err};
+use polars_expr::hash_keys::HashKeys;
+#[cfg(feature = "ipc_encryption")]
+use polars_io::ipc::{IpcEncryption, spill_encryption};
+use polars_io::ipc::{IpcReader, IpcWriterOptions};
+use polars_io::path_utils::POLARS_TEMP_DIR_BASE_PATH;
+use polars_io::{SerReader, SerWriter};
+use polars_utils::IdxSize;
+use polars_utils::hashing::HashPartitioner;
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::async_executor;
+
+/// Reads a memory budget in bytes from the environment variable `var`, if it is set.
+pub fn env_memory_budget(var: &str) -> PolarsResult<Option<usize>> {
+    let Ok(budget) = std::env::var(var) else {
+        return Ok(None);
+    };
+    budget.trim().parse().map(Some).map_err(|_| {
+        polars_err!(InvalidOperation: "invalid value for {}: '{}', expected a number of bytes", var, budget)
+    })
+}
+
+/// A spill directory that is removed when dropped.
+struct SpillDir(PathBuf);
+
+impl SpillDir {
+    fn try_new(kind: &str) -> PolarsResult<Self> {
+        let path = POLARS_TEMP_DIR_BASE_PATH.join(format!(
+            "{kind}/{}-{:016x}",
+            std::process::id(),
+            rand::random::<u64>()
+        ));
+        std::fs::create_dir_all(&path)?;
+        Ok(Self(path))
+    }
+}
+
+impl Drop for SpillDir {
+    fn drop(&mut self) {
+        // This can fail if the directory is already cleaned up, that is fine.
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+struct SpillState {
+    dir: Option<SpillDir>,
+    // The encryption is fixed when the first file is spilled, so that all
+    // files can be read back with the same key.
+    options: IpcWriterOptions,
+    num_files: usize,
+    files_per_p: Vec<Vec<PathBuf>>,
+}
+
+/// A DataFrame that was spilled to disk.
+pub struct SpillFile {
+    path: PathBuf,
+    #[cfg(feature = "ipc_encryption")]
+    encryption: Option<IpcEncryption>,
+}
+
+impl SpillFile {
+    /// Read the DataFrame back and remove the file.
+    pub fn load(self) -> PolarsResult<DataFrame> {
+        #[cfg(feature = "ipc_encryption")]
+        if let Some(encryption) = &self.encryption {
+            let df = IpcReader::new_encrypted(File::open(&self.path)?, encryption)?.finish()?;
+            std::fs::remove_file(&self.path)?;
+            return Ok(df);
+        }
+        let df = IpcReader::new(File::open(&self.path)?)
+            .memory_mapped(None)
+            .finish()?;
+        std::fs::remove_file(&self.path)?;
+        Ok(df)
+    }
+}
+
+/// Spills the partitions of a partitioned sink to IPC files in a temporary
+/// directory, which is created on the first spill and removed on drop.
+pub struct PartitionSpiller {
+    kind: &'static str,
+    state: Mutex<SpillState>,
+}
+
+impl PartitionSpiller {
+    pub fn new(kind: &'static str, num_partitions: usize) -> Self {
+        Self {
+            kind,
+            state: Mutex::new(SpillState {
+                dir: None,
+                options: IpcWriterOptions::default(),
+                num_files: 0,
+                files_per_p: vec![Vec::new(); num_partitions],
+            }),
+        }
+    }
+
+    /// Write `df` to a new file of partition `p`.
+    pub fn spill(&self, p: usize, df: &mut DataFrame) -> PolarsResult<()> {
+        let (path, options) = {
+            let mut state = self.state.lock();
+            if state.dir.is_none() {
+                #[cfg(feature = "ipc_encryption")]
+                {
+                    state.options.encryption = spill_encryption();
+                }
+                state.dir = Some(SpillDir::try_new(self.kind)?);
+            }
+            state.num_files += 1;
+            let path = state
+                .dir
+                .as_ref()
+                .unwrap()
+                .0
+                .join(format!("{p}-{}.ipc", state.num_files));
+            (path, state.options.clone())
+        };
+        let mut file = File::create(&path)?;
+        options.to_writer(&mut file).finish(df)?;
+        async_executor::record_spilled_bytes(file.metadata()?.len());
+        self.state.lock().files_per_p[p].push(path);
+        Ok(())
+    }
+
+    /// Take the files spilled so far, per partition.
+    pub fn take_files(&self) -> Vec<Vec<SpillFile>> {
+        let mut state = self.state.lock();
+        let num_partitions = state.files_per_p.len();
+        let files_per_p =
+            core::mem::replace(&mut state.files_per_p, vec![Vec::new(); num_partitions]);
+        files_per_p
+            .into_iter()
+            .map(|files| {
+                files
+                    .into_iter()
+                    .map(|path| SpillFile {
+                        path,
+                        #[cfg(feature = "ipc_encryption")]
+                        encryption: state.options.encryption.clone(),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// The rows a single pipeline buffered per partition that are not spilled yet.
+pub struct LocalPartitions {
+    buffered_per_p: Vec<Vec<DataFrame>>,
+    buffered_bytes: usize,
+    idxs_per_p: Vec<Vec<IdxSize>>,
+}
+
+/// Partitions rows by the hash of their keys, so that all rows of a key end up
+/// in the same partition, and spills the partitions of a pipeline to disk once
+/// all pipelines together buffer more than a memory budget.
+pub struct SpillingPartitioner {
+    name: &'static str,
+    key_names: Vec<PlSmallStr>,
+    random_state: PlRandomState,
+    partitioner: HashPartitioner,
+    memory_budget: usize,
+    buffered_bytes: AtomicUsize,
+    spiller: PartitionSpiller,
+}
+
+impl SpillingPartitioner {
+    /// `name` is used in the verbose logs, `kind` names the spill directory.
+    pub fn new(
+        name: &'static str,
+        kind: &'static str,
+        key_names: Vec<PlSmallStr>,
+        random_state: PlRandomState,
+        num_partitions: usize,
+        memory_budget: usize,
+    ) -> Self {
+        Self {
+            name,
+            key_names,
+            random_state,
+            partitioner: HashPartitioner::new(num_partitions, 0),
+            memory_budget,
+            buffered_bytes: AtomicUsize::new(0),
+            spiller: PartitionSpiller::new(kind, num_partitions),
+        }
+    }
+
+    pub fn key_names(&self) -> &[PlSmallStr] {
+        &self.key_names
+    }
+
+    pub fn new_local(&self) -> LocalPartitions {
+        let num_partitions = self.partitioner.num_partitions();
+        LocalPartitions {
+            buffered_per_p: vec![Vec::new(); num_partitions],
+            buffered_bytes: 0,
+            idxs_per_p: vec![Vec::new(); num_partitions],
+        }
+    }
+
+    /// Partition the rows of `df` into `local`. If that exceeds the memory
+    /// budget, every buffered partition of `local` is passed through `prepare`
+    /// and spilled.
+    pub fn insert(
+        &self,
+        local: &mut LocalPartitions,
+        mut df: DataFrame,
+        prepare: impl Fn(DataFrame) -> PolarsResult<DataFrame>,
+    ) -> PolarsResult<()> {
+        let keys = df.select(self.key_names.iter().cloned())?;
+        let hash_keys = HashKeys::from_df(&keys, self.random_state, true, false);
+        df.rechunk_mut(); // For gathers.
+
+        for idxs in &mut local.idxs_per_p {
+            idxs.clear();
+        }
+        hash_keys.gen_idxs_per_partition(&self.partitioner, &mut local.idxs_per_p, &mut [], true);
+
+        let mut added_bytes = 0;
+        for (idxs, buffered) in local.idxs_per_p.iter().zip(&mut local.buffered_per_p) {
+            if idxs.is_empty() {
+                continue;
+            }
+            // SAFETY: the indices are in-bounds as they come from the hash keys of this frame.
+            let p_df = unsafe { df.take_slice_unchecked_impl(idxs, false) };
+            added_bytes += p_df.estimated_size();
+            buffered.push(p_df);
+        }
+        local.buffered_bytes += added_bytes;
+
+        let prev = self
+            .buffered_bytes
+            .fetch_add(added_bytes, Ordering::Relaxed);
+        if prev + added_bytes > self.memory_budget {
+            self.spill_local(local, prepare)?;
+        }
+        Ok(())
+    }
+
+    fn spill_local(
+        &self,
+        local: &mut LocalPartitions,
+        prepare: impl Fn(DataFrame) -> PolarsResult<DataFrame>,
+    ) -> PolarsResult<()> {
+        for (p, buffered) in local.buffered_per_p.iter_mut().enumerate() {
+            if buffered.is_empty() {
+                continue;
+            }
+            let df = accumulate_dataframes_vertical_unchecked(core::mem::take(buffered));
+            self.spiller.spill(p, &mut prepare(df)?)?;
+        }
+
+        if verbose() {
+            eprintln!(
+                "{}: spilled {} bytes to disk",
+                self.name, local.buffered_bytes
+            );
+        }
+        self.buffered_bytes
+            .fetch_sub(local.buffered_bytes, Ordering::Relaxed);
+        local.buffered_bytes = 0;
+        Ok(())
+    }
+
+    /// Take the rows buffered by `locals` and the files spilled so far, per
+    /// partition.
+    pub fn take_partitions(
+        &self,
+        locals: &mut [LocalPartitions],
+    ) -> Vec<(Vec<DataFrame>, Vec<SpillFile>)> {
+        let mut buffered_per_p = vec![Vec::new(); self.partitioner.num_partitions()];
+        for local in locals {
+            for (p, buffered) in local.buffered_per_p.iter_mut().enumerate() {
+                buffered_per_p[p].append(buffered);
+            }
+        }
+        buffered_per_p
+            .into_iter()
+            .zip(self.spiller.take_files())
+            .collect()
+    }
+}
@@ -38,6 +38,10 @@ impl NodeStyle {
             | K::Multiplexer { .. } => Self::MemoryIntensive,
             #[cfg(feature = "merge_sorted")]
             K::MergeSorted { .. } => Self::MemoryIntensive,
+            #[cfg(feature = "ipc")]
+            K::ExternalDistinct { .. } | K::ExternalQuantile { .. } | K::GroupByApply { .. } => {
+                Self::MemoryIntensive
+            },
             _ => Self::Generic,
         }
     }
@@ -203,6 +207,38 @@ fn visualize_plan_rec(
             format!("with-row-index\\nname: {name}\\noffset: {offset:?}"),
             from_ref(input),
         ),
+        #[cfg(feature = "ewma")]
+        PhysNodeKind::Ewm {
+            input,
+            column,
+            partition_by,
+            method,
+            options,
+        } => {
+            let mut label = format!("ewm\\n{method:?}({column})\\nalpha: {}", options.alpha);
+            if !partition_by.is_empty() {
+                write!(label, "\\nover: [{}]", partition_by.join(", ")).unwrap();
+            }
+            (label, from_ref(input))
+        },
+        #[cfg(feature = "fill_null_by")]
+        PhysNodeKind::ForwardFillBy {
+            input,
+            column,
+            by,
+            partition_by,
+            limit,
+        } => {
+            let mut label = format!("forward-fill-by\\n{column} by {by}\\nlimit: {limit}");
+            if !partition_by.is_empty() {
+                write!(label, "\\nover: [{}]", partition_by.join(", ")).unwrap();
+            }
+            (label, from_ref(input))
+        },
+        PhysNodeKind::SortedDistinct { input, keys } => (
+            format!("sorted-distinct\\nkeys: {}", keys.join(", ")),
+            from_ref(input),
+        ),
         PhysNodeKind::InputIndependentSelect { selectors } => (
             format!(
                 "input-independent-select\\n{}",
@@ -245,6 +281,7 @@ fn visualize_plan_rec(
             from_ref(input),
         ),
         PhysNodeKind::InMemorySink { input } => ("in-memory-sink".to_string(), from_ref(input)),
+        PhysNodeKind::CallbackSink { input, .. } => ("callback-sink".to_string(), from_ref(input)),
         PhysNodeKind::FileSink {
             input, file_type, ..
         } => match file_type {
@@ -332,6 +369,7 @@ fn visualize_plan_rec(
             projected_file_schema,
             output_schema,
             row_index,
+            row_index_per_file,
             pre_slice,
             predicate,
             hive_parts,
@@ -360,6 +398,10 @@ fn visualize_plan_rec(
 
             if let Some(ri) = row_index {
                 write!(f, "\nrow index: name: {}, offset: {:?}", ri.name, ri.offset).unwrap();
+
+                if *row_index_per_file {
+                    write!(f, " (per file)").unwrap();
+                }
             }
 
             if let Some(col_name) = include_file_paths {
@@ -403,6 +445,44 @@ fn visualize_plan_rec(
             ),
             from_ref(input),
         ),
+        #[cfg(feature = "ipc")]
+        PhysNodeKind::ExternalDistinct {
+            input,
+            keys,
+            keep,
+            memory_budget,
+        } => (
+            format!(
+                "external-distinct\\nkeys: {}\\nkeep: {keep:?}\\nmemory budget: {memory_budget}",
+                keys.join(", ")
+            ),
+            from_ref(input),
+        ),
+        #[cfg(feature = "ipc")]
+        PhysNodeKind::ExternalQuantile {
+            input,
+            quantile,
+            method,
+            memory_budget,
+        } => (
+            format!(
+                "external-quantile\\nquantile: {quantile}\\nmethod: {method:?}\\nmemory budget: {memory_budget}"
+            ),
+            from_ref(input),
+        ),
+        #[cfg(feature = "ipc")]
+        PhysNodeKind::GroupByApply {
+            input,
+            keys,
+            memory_budget,
+            ..
+        } => (
+            format!(
+                "group-by-apply\\nkeys: {}\\nmemory budget: {memory_budget}",
+                keys.join(", ")
+            ),
+            from_ref(input),
+        ),
         PhysNodeKind::InMemoryJoin {
             input_left,
             input_right,
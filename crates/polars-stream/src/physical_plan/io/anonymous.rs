@@ -0,0 +1,49 @@
+use std::sync::{Arc, Mutex};
+
+use polars_core::config;
+use polars_plan::plans::{AnonymousScan, AnonymousScanArgs, AnonymousScanBatches};
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::execute::StreamingExecutionState;
+use crate::morsel::get_ideal_morsel_size;
+use crate::nodes::io_sources::batch::GetBatchFn;
+use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
+
+/// Reads an [`AnonymousScan`] through [`AnonymousScan::scan_batches`]. The batches are only
+/// requested once the reader is polled.
+pub fn anonymous_scan_to_reader_builder(
+    reader_name: &str,
+    function: Arc<dyn AnonymousScan>,
+    args: AnonymousScanArgs,
+) -> Arc<dyn FileReaderBuilder> {
+    let name = PlSmallStr::from_str(reader_name);
+    let output_schema = args.schema.clone();
+    let batches: Mutex<(Option<AnonymousScanArgs>, Option<AnonymousScanBatches>)> =
+        Mutex::new((Some(args), None));
+
+    let get_batch_fn = Box::new(move |_state: &StreamingExecutionState| {
+        let mut guard = batches.lock().unwrap();
+        let (args, batches) = &mut *guard;
+
+        if let Some(args) = args.take() {
+            *batches = Some(function.scan_batches(args, get_ideal_morsel_size())?);
+        }
+
+        batches.as_mut().unwrap().next().transpose()
+    }) as GetBatchFn;
+
+    use crate::nodes::io_sources::batch::builder::BatchFnReaderBuilder;
+    use crate::nodes::io_sources::batch::{BatchFnReader, GetBatchState};
+
+    let reader = BatchFnReader {
+        name: name.clone(),
+        output_schema: Some(output_schema),
+        get_batch_state: Some(GetBatchState::from(get_batch_fn)),
+        verbose: config::verbose(),
+    };
+
+    Arc::new(BatchFnReaderBuilder {
+        name,
+        reader: std::sync::Mutex::new(Some(reader)),
+    }) as Arc<dyn FileReaderBuilder>
+}
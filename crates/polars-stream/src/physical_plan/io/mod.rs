@@ -1,2 +1,3 @@
+pub mod anonymous;
 #[cfg(feature = "python")]
 pub mod python_dataset;
@@ -638,6 +638,111 @@ fn lower_exprs_with_ctx(
                 transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(tmp_name)));
             },
 
+            #[cfg(feature = "ewma")]
+            AExpr::Function {
+                input: ref inner_exprs,
+                function:
+                    ref function @ (FunctionExpr::EwmMean { .. }
+                    | FunctionExpr::EwmStd { .. }
+                    | FunctionExpr::EwmVar { .. }),
+                options: _,
+            } => {
+                let (node_key, out_name) =
+                    lower_ewm(input, &function, inner_exprs[0].node(), &[], ctx)?;
+                input_streams.insert(PhysStream::first(node_key));
+                transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
+            },
+
+            // The ewm node keeps a state per group, which makes `ewm_*().over()`
+            // streamable as long as the rows of each group stay in order.
+            #[cfg(feature = "ewma")]
+            AExpr::Window {
+                function,
+                ref partition_by,
+                order_by: None,
+                options: WindowType::Over(WindowMapping::GroupsToRows),
+            } if matches!(
+                ctx.expr_arena.get(function),
+                AExpr::Function {
+                    function: FunctionExpr::EwmMean { .. }
+                        | FunctionExpr::EwmStd { .. }
+                        | FunctionExpr::EwmVar { .. },
+                    ..
+                }
+            ) =>
+            {
+                let AExpr::Function {
+                    input: inner_exprs,
+                    function: ewm_function,
+                    ..
+                } = ctx.expr_arena.get(function).clone()
+                else {
+                    unreachable!()
+                };
+                let (node_key, out_name) = lower_ewm(
+                    input,
+                    &ewm_function,
+                    inner_exprs[0].node(),
+                    partition_by,
+                    ctx,
+                )?;
+                input_streams.insert(PhysStream::first(node_key));
+                transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
+            },
+
+            #[cfg(feature = "fill_null_by")]
+            AExpr::Function {
+                input: ref inner_exprs,
+                function: ref function @ FunctionExpr::FillNullBy { forward: true, .. },
+                options: _,
+            } => {
+                let (node_key, out_name) = lower_forward_fill_by(
+                    input,
+                    function,
+                    inner_exprs[0].node(),
+                    inner_exprs[1].node(),
+                    &[],
+                    ctx,
+                )?;
+                input_streams.insert(PhysStream::first(node_key));
+                transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
+            },
+
+            // Like the ewm node, the forward fill node keeps a state per group.
+            #[cfg(feature = "fill_null_by")]
+            AExpr::Window {
+                function,
+                ref partition_by,
+                order_by: None,
+                options: WindowType::Over(WindowMapping::GroupsToRows),
+            } if matches!(
+                ctx.expr_arena.get(function),
+                AExpr::Function {
+                    function: FunctionExpr::FillNullBy { forward: true, .. },
+                    ..
+                }
+            ) =>
+            {
+                let AExpr::Function {
+                    input: inner_exprs,
+                    function: fill_function,
+                    ..
+                } = ctx.expr_arena.get(function).clone()
+                else {
+                    unreachable!()
+                };
+                let (node_key, out_name) = lower_forward_fill_by(
+                    input,
+                    &fill_function,
+                    inner_exprs[0].node(),
+                    inner_exprs[1].node(),
+                    partition_by,
+                    ctx,
+                )?;
+                input_streams.insert(PhysStream::first(node_key));
+                transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
+            },
+
             #[cfg(feature = "is_in")]
             AExpr::Function {
                 input: ref inner_exprs,
@@ -876,10 +981,17 @@ fn lower_exprs_with_ctx(
                 | IRAggExpr::First(ref mut inner)
                 | IRAggExpr::Last(ref mut inner)
                 | IRAggExpr::Sum(ref mut inner)
+                | IRAggExpr::Product(ref mut inner)
+                | IRAggExpr::GeoMean(ref mut inner)
+                | IRAggExpr::HarmonicMean(ref mut inner)
                 | IRAggExpr::Mean(ref mut inner)
                 | IRAggExpr::Var(ref mut inner, _ /* ddof */)
                 | IRAggExpr::Std(ref mut inner, _ /* ddof */)
-                | IRAggExpr::Count(ref mut inner, _ /* count_nulls */) => {
+                | IRAggExpr::Count(ref mut inner, _ /* count_nulls */)
+                | IRAggExpr::Udf {
+                    input: ref mut inner,
+                    ..
+                } => {
                     let (trans_input, trans_exprs) = lower_exprs_with_ctx(input, &[*inner], ctx)?;
                     *inner = trans_exprs[0];
 
@@ -936,6 +1048,14 @@ fn lower_exprs_with_ctx(
                 | IRAggExpr::Quantile { .. }
                 | IRAggExpr::AggGroups(_) => {
                     let out_name = unique_column_name();
+                    #[cfg(feature = "ipc")]
+                    if let Some(stream) =
+                        try_build_external_quantile_stream(input, expr, out_name.clone(), ctx)?
+                    {
+                        input_streams.insert(stream);
+                        transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
+                        continue;
+                    }
                     fallback_subset.push(ExprIR::new(expr, OutputName::Alias(out_name.clone())));
                     transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
                 },
@@ -1016,6 +1136,65 @@ pub fn compute_output_schema(
     Ok(Arc::new(output_schema))
 }
 
+/// Lowers a median, or a quantile with a literal quantile, of a numeric column
+/// to an exact [`PhysNodeKind::ExternalQuantile`] if a memory budget is set for
+/// it, instead of falling back to the in-memory engine.
+#[cfg(feature = "ipc")]
+fn try_build_external_quantile_stream(
+    input: PhysStream,
+    expr: ExprNodeKey,
+    out_name: PlSmallStr,
+    ctx: &mut LowerExprContext,
+) -> PolarsResult<Option<PhysStream>> {
+    let Some(memory_budget) = crate::nodes::external_quantile::quantile_memory_budget()? else {
+        return Ok(None);
+    };
+    let (inner, quantile, method) = match ctx.expr_arena.get(expr) {
+        AExpr::Agg(IRAggExpr::Median(inner)) => {
+            (*inner, 0.5, polars_core::prelude::QuantileMethod::Linear)
+        },
+        AExpr::Agg(IRAggExpr::Quantile {
+            expr: inner,
+            quantile,
+            method,
+        }) => {
+            let AExpr::Literal(lit) = ctx.expr_arena.get(*quantile) else {
+                return Ok(None);
+            };
+            let Some(quantile) = lit.to_any_value().and_then(|av| av.extract::<f64>()) else {
+                return Ok(None);
+            };
+            (*inner, quantile, *method)
+        },
+        _ => return Ok(None),
+    };
+
+    let inner_expr = ExprIR::new(inner, OutputName::Alias(out_name.clone()));
+    let inner_schema = schema_for_select(input, &[inner_expr.clone()], ctx)?;
+    if !inner_schema
+        .get_at_index(0)
+        .unwrap()
+        .1
+        .is_primitive_numeric()
+    {
+        return Ok(None);
+    }
+    let output_schema = schema_for_select(
+        input,
+        &[ExprIR::new(expr, OutputName::Alias(out_name))],
+        ctx,
+    )?;
+    let select_stream = build_select_stream_with_ctx(input, &[inner_expr], ctx)?;
+    let kind = PhysNodeKind::ExternalQuantile {
+        input: select_stream,
+        quantile,
+        method,
+        memory_budget,
+    };
+    let node_key = ctx.phys_sm.insert(PhysNode::new(output_schema, kind));
+    Ok(Some(PhysStream::first(node_key)))
+}
+
 /// Computes the schema that selecting the given expressions on the input node
 /// would result in.
 fn schema_for_select(
@@ -1027,6 +1206,114 @@ fn schema_for_select(
     compute_output_schema(input_schema, exprs, ctx.expr_arena)
 }
 
+/// Lowers an ewm function of `value`, optionally over the groups of
+/// `partition_by`, to an ewm node. Returns the node and the name of its single
+/// output column.
+#[cfg(feature = "ewma")]
+fn lower_ewm(
+    input: PhysStream,
+    function: &FunctionExpr,
+    value: Node,
+    partition_by: &[Node],
+    ctx: &mut LowerExprContext,
+) -> PolarsResult<(PhysNodeKey, PlSmallStr)> {
+    use polars_ops::series::EwmMethod;
+
+    let (method, ewm_options) = match function {
+        FunctionExpr::EwmMean { options } => (EwmMethod::Mean, *options),
+        FunctionExpr::EwmStd { options } => (EwmMethod::Std, *options),
+        FunctionExpr::EwmVar { options } => (EwmMethod::Var, *options),
+        _ => unreachable!(),
+    };
+
+    // Select the input and the group keys into columns, which the ewm node
+    // then replaces by the moving statistic morsel by morsel.
+    let out_name = unique_column_name();
+    let key_names = partition_by
+        .iter()
+        .map(|_| unique_column_name())
+        .collect_vec();
+    let nodes = std::iter::once(value)
+        .chain(partition_by.iter().copied())
+        .collect_vec();
+    let (trans_input, trans_exprs) = lower_exprs_with_ctx(input, &nodes, ctx)?;
+    let selectors = std::iter::once(&out_name)
+        .chain(key_names.iter())
+        .zip(trans_exprs)
+        .map(|(name, expr)| ExprIR::new(expr, OutputName::Alias(name.clone())))
+        .collect_vec();
+    let select_stream = build_select_stream_with_ctx(trans_input, &selectors, ctx)?;
+    let in_dtype = ctx.phys_sm[select_stream.node]
+        .output_schema
+        .try_get(&out_name)?;
+    let out_dtype = match in_dtype {
+        DataType::Float32 => DataType::Float32,
+        _ => DataType::Float64,
+    };
+    let output_schema = Schema::from_iter([(out_name.clone(), out_dtype)]);
+    let node_kind = PhysNodeKind::Ewm {
+        input: select_stream,
+        column: out_name.clone(),
+        partition_by: key_names,
+        method,
+        options: ewm_options,
+    };
+    let node_key = ctx
+        .phys_sm
+        .insert(PhysNode::new(Arc::new(output_schema), node_kind));
+    Ok((node_key, out_name))
+}
+
+/// Lowers a forward `fill_null_by` of `value` by `by`, optionally over the
+/// groups of `partition_by`, to a forward fill node. Returns the node and the
+/// name of its single output column.
+#[cfg(feature = "fill_null_by")]
+fn lower_forward_fill_by(
+    input: PhysStream,
+    function: &FunctionExpr,
+    value: Node,
+    by: Node,
+    partition_by: &[Node],
+    ctx: &mut LowerExprContext,
+) -> PolarsResult<(PhysNodeKey, PlSmallStr)> {
+    let FunctionExpr::FillNullBy { limit, .. } = function else {
+        unreachable!()
+    };
+    let out_name = unique_column_name();
+    let by_name = unique_column_name();
+    let key_names = partition_by
+        .iter()
+        .map(|_| unique_column_name())
+        .collect_vec();
+    let nodes = [value, by]
+        .into_iter()
+        .chain(partition_by.iter().copied())
+        .collect_vec();
+    let (trans_input, trans_exprs) = lower_exprs_with_ctx(input, &nodes, ctx)?;
+    let selectors = [&out_name, &by_name]
+        .into_iter()
+        .chain(key_names.iter())
+        .zip(trans_exprs)
+        .map(|(name, expr)| ExprIR::new(expr, OutputName::Alias(name.clone())))
+        .collect_vec();
+    let select_stream = build_select_stream_with_ctx(trans_input, &selectors, ctx)?;
+    let select_schema = &ctx.phys_sm[select_stream.node].output_schema;
+    let limit = fill_null_by_limit(*limit, select_schema.try_get(&by_name)?)?;
+    let out_dtype = select_schema.try_get(&out_name)?.clone();
+    let output_schema = Schema::from_iter([(out_name.clone(), out_dtype)]);
+    let node_kind = PhysNodeKind::ForwardFillBy {
+        input: select_stream,
+        column: out_name.clone(),
+        by: by_name,
+        partition_by: key_names,
+        limit,
+    };
+    let node_key = ctx
+        .phys_sm
+        .insert(PhysNode::new(Arc::new(output_schema), node_kind));
+    Ok((node_key, out_name))
+}
+
 fn build_select_stream_with_ctx(
     input: PhysStream,
     exprs: &[ExprIR],
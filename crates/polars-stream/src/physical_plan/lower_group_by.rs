@@ -72,6 +72,56 @@ fn build_group_by_fallback(
     Ok(PhysStream::first(phys_sm.insert(group_by_node)))
 }
 
+/// Tries to build a group-by that applies `function` to every group, which
+/// only supports unordered group-bys on plain columns.
+#[cfg(feature = "ipc")]
+#[allow(clippy::too_many_arguments)]
+fn try_build_group_by_apply(
+    input: PhysStream,
+    keys: &[ExprIR],
+    output_schema: Arc<Schema>,
+    maintain_order: bool,
+    options: &GroupbyOptions,
+    function: Arc<dyn DataFrameUdf>,
+    expr_arena: &Arena<AExpr>,
+    phys_sm: &mut SlotMap<PhysNodeKey, PhysNode>,
+) -> PolarsResult<Option<PhysStream>> {
+    if maintain_order || keys.is_empty() {
+        return Ok(None);
+    }
+
+    #[cfg(feature = "dynamic_group_by")]
+    if options.dynamic.is_some() || options.rolling.is_some() {
+        return Ok(None);
+    }
+
+    let key_names = keys
+        .iter()
+        .map(|key| match expr_arena.get(key.node()) {
+            AExpr::Column(name) if name == key.output_name() => Some(name.clone()),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>();
+    let Some(key_names) = key_names else {
+        return Ok(None);
+    };
+
+    let apply_node = PhysNode {
+        output_schema,
+        kind: PhysNodeKind::GroupByApply {
+            input,
+            keys: key_names,
+            function,
+            memory_budget: crate::nodes::group_by_apply::group_by_apply_memory_budget()?,
+        },
+    };
+    let mut stream = PhysStream::first(phys_sm.insert(apply_node));
+    if let Some((offset, length)) = options.slice {
+        stream = build_slice_stream(stream, offset, length, phys_sm);
+    }
+    Ok(Some(stream))
+}
+
 /// Tries to lower an expression as a 'elementwise scalar agg expression'.
 ///
 /// Such an expression is defined as the elementwise combination of scalar
@@ -204,9 +254,13 @@ fn try_lower_elementwise_scalar_agg_expr(
                 | IRAggExpr::Last(input)
                 | IRAggExpr::Mean(input)
                 | IRAggExpr::Sum(input)
+                | IRAggExpr::Product(input)
+                | IRAggExpr::GeoMean(input)
+                | IRAggExpr::HarmonicMean(input)
                 | IRAggExpr::Var(input, ..)
                 | IRAggExpr::Std(input, ..)
-                | IRAggExpr::Count(input, ..) => {
+                | IRAggExpr::Count(input, ..)
+                | IRAggExpr::Udf { input, .. } => {
                     if is_input_independent(*input, expr_arena, expr_cache) {
                         // TODO: we could simply return expr here, but we first need an is_scalar function, because if
                         // it is not a scalar we need to return expr.implode().
@@ -396,6 +450,22 @@ pub fn build_group_by_stream(
     expr_cache: &mut ExprCache,
     ctx: StreamingLowerIRContext,
 ) -> PolarsResult<PhysStream> {
+    #[cfg(feature = "ipc")]
+    if let Some(function) = &apply {
+        if let Some(stream) = try_build_group_by_apply(
+            input,
+            keys,
+            output_schema.clone(),
+            maintain_order,
+            &options,
+            function.clone(),
+            expr_arena,
+            phys_sm,
+        )? {
+            return Ok(stream);
+        }
+    }
+
     let streaming = try_build_streaming_group_by(
         input,
         keys,
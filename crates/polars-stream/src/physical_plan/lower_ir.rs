@@ -1,3 +1,5 @@
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use parking_lot::Mutex;
@@ -9,11 +11,13 @@ use polars_error::{PolarsResult, polars_bail};
 use polars_expr::state::ExecutionState;
 use polars_mem_engine::create_physical_plan;
 use polars_plan::dsl::{
-    ExtraColumnsPolicy, FileScan, FileSinkType, PartitionSinkTypeIR, PartitionVariantIR, SinkTypeIR,
+    CallbackSinkType, ExtraColumnsPolicy, FileScan, FileSinkType, PartitionSinkTypeIR,
+    PartitionVariantIR, ScanSources, SinkTypeIR,
 };
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
 use polars_plan::plans::{
-    AExpr, Context, FunctionIR, IR, IRAggExpr, LiteralValue, write_ir_non_recursive,
+    AExpr, AnonymousScanArgs, Context, FunctionIR, IR, IRAggExpr, LiteralValue,
+    write_ir_non_recursive,
 };
 use polars_plan::prelude::GroupbyOptions;
 use polars_utils::arena::{Arena, Node};
@@ -249,6 +253,21 @@ pub fn lower_ir(
                 let phys_input = lower_ir!(*input)?;
                 PhysNodeKind::InMemorySink { input: phys_input }
             },
+            SinkTypeIR::Callback(CallbackSinkType {
+                function,
+                sink_options,
+            }) => {
+                let function = function.clone();
+                let maintain_order = sink_options.maintain_order;
+                let chunk_size = sink_options.batch_size.and_then(NonZeroUsize::new);
+                let phys_input = lower_ir!(*input)?;
+                PhysNodeKind::CallbackSink {
+                    input: phys_input,
+                    function,
+                    maintain_order,
+                    chunk_size,
+                }
+            },
             SinkTypeIR::File(FileSinkType {
                 target,
                 sink_options,
@@ -465,19 +484,24 @@ pub fn lower_ir(
 
         v @ IR::Scan { .. } => {
             let IR::Scan {
-                sources: scan_sources,
+                sources: mut scan_sources,
                 file_info,
                 mut hive_parts,
                 output_schema: _,
                 scan_type,
                 predicate,
-                unified_scan_args,
+                mut unified_scan_args,
                 id: _,
             } = v.clone()
             else {
                 unreachable!();
             };
 
+            if matches!(&*scan_type, FileScan::Anonymous { .. }) {
+                // Give multiscan a single scan source. (It doesn't actually read from this).
+                scan_sources = ScanSources::Paths(Arc::from([PathBuf::from("anonymous-scan-0")]));
+            }
+
             if scan_sources.is_empty()
                 || unified_scan_args
                     .pre_slice
@@ -509,9 +533,13 @@ pub fn lower_ir(
                     #[cfg(feature = "ipc")]
                     FileScan::Ipc {
                         options: polars_io::ipc::IpcScanOptions {},
+                        #[cfg(feature = "ipc_encryption")]
+                        encryption,
                         metadata: first_metadata,
                     } => Arc::new(crate::nodes::io_sources::ipc::builder::IpcReaderBuilder {
                         first_metadata: first_metadata.clone(),
+                        #[cfg(feature = "ipc_encryption")]
+                        encryption: encryption.clone(),
                     }) as Arc<dyn FileReaderBuilder>,
 
                     #[cfg(feature = "csv")]
@@ -545,7 +573,28 @@ pub fn lower_ir(
                         )
                     },
 
-                    FileScan::Anonymous { .. } => todo!("unimplemented: AnonymousScan"),
+                    FileScan::Anonymous { function, options } => {
+                        // The slice is handled by the scan itself.
+                        let (offset, n_rows) = match unified_scan_args.pre_slice.take() {
+                            Some(Slice::Positive { offset, len }) => (offset, Some(len)),
+                            Some(Slice::Negative { .. }) => unreachable!(),
+                            None => (0, None),
+                        };
+                        let args = AnonymousScanArgs {
+                            offset,
+                            n_rows,
+                            with_columns: None,
+                            schema: file_info.schema.clone(),
+                            output_schema: None,
+                            predicate: predicate.as_ref().map(|p| p.to_expr(expr_arena)),
+                        };
+
+                        crate::physical_plan::io::anonymous::anonymous_scan_to_reader_builder(
+                            options.fmt_str,
+                            function.clone(),
+                            args,
+                        )
+                    },
                 };
 
                 {
@@ -590,6 +639,7 @@ pub fn lower_ir(
                         projected_file_schema,
                         output_schema: output_schema.clone(),
                         row_index: None,
+                        row_index_per_file: unified_scan_args.row_index_per_file,
                         pre_slice: None,
                         predicate: None,
                         hive_parts,
@@ -625,8 +675,11 @@ pub fn lower_ir(
                     *pre_slice_to_multiscan = pre_slice_post.take();
 
                     // * If a predicate was pushed then we always push row index
+                    // * A row index per file can only be added by the multiscan, but it doesn't
+                    //   need a serial synchronization step.
                     if predicate_to_multiscan.is_some()
                         || matches!(pre_slice, Some(Slice::Negative { .. }))
+                        || unified_scan_args.row_index_per_file
                     {
                         *row_index_to_multiscan = row_index_post.take();
                     }
@@ -984,6 +1037,52 @@ pub fn lower_ir(
             } else {
                 all_col_names.clone()
             };
+
+            // If the input is sorted by the keys, duplicates are neighbours, so
+            // we can drop them while streaming without keeping any state but
+            // the last key.
+            if options.keys_sorted
+                && matches!(
+                    options.keep_strategy,
+                    UniqueKeepStrategy::First | UniqueKeepStrategy::Any
+                )
+            {
+                let distinct_node = PhysNode {
+                    output_schema,
+                    kind: PhysNodeKind::SortedDistinct {
+                        input: phys_input,
+                        keys: key_names,
+                    },
+                };
+                let mut stream = PhysStream::first(phys_sm.insert(distinct_node));
+                if let Some((offset, length)) = options.slice {
+                    stream = build_slice_stream(stream, offset, length, phys_sm);
+                }
+                return Ok(stream);
+            }
+
+            // With a memory budget we use a distinct that spills its state to
+            // disk, keeping the input order isn't supported by it.
+            #[cfg(feature = "ipc")]
+            if let Some(memory_budget) = crate::nodes::external_distinct::distinct_memory_budget()?
+            {
+                if !options.maintain_order {
+                    let distinct_node = PhysNode {
+                        output_schema,
+                        kind: PhysNodeKind::ExternalDistinct {
+                            input: phys_input,
+                            keys: key_names,
+                            keep: options.keep_strategy,
+                            memory_budget,
+                        },
+                    };
+                    let mut stream = PhysStream::first(phys_sm.insert(distinct_node));
+                    if let Some((offset, length)) = options.slice {
+                        stream = build_slice_stream(stream, offset, length, phys_sm);
+                    }
+                    return Ok(stream);
+                }
+            }
             let key_name_set: PlHashSet<_> = key_names.iter().cloned().collect();
 
             let mut group_by_output_schema = Schema::with_capacity(all_col_names.len() + 1);
@@ -1,8 +1,9 @@
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use polars_core::frame::DataFrame;
-use polars_core::prelude::{IdxSize, InitHashMaps, PlHashMap, SortMultipleOptions};
+use polars_core::frame::{DataFrame, UniqueKeepStrategy};
+use polars_core::prelude::{IdxSize, InitHashMaps, PlHashMap, QuantileMethod, SortMultipleOptions};
 use polars_core::schema::{Schema, SchemaRef};
 use polars_error::PolarsResult;
 use polars_io::RowIndex;
@@ -10,7 +11,7 @@ use polars_io::cloud::CloudOptions;
 use polars_ops::frame::JoinArgs;
 use polars_plan::dsl::{
     CastColumnsPolicy, JoinTypeOptionsIR, MissingColumnsPolicy, PartitionTargetCallback,
-    PartitionVariantIR, ScanSources, SinkOptions, SinkTarget,
+    PartitionVariantIR, ScanSources, SinkCallback, SinkOptions, SinkTarget,
 };
 use polars_plan::plans::hive::HivePartitionsDf;
 use polars_plan::plans::{AExpr, DataFrameUdf, IR};
@@ -107,6 +108,34 @@ pub enum PhysNodeKind {
         selectors: Vec<ExprIR>,
     },
 
+    /// Replaces `column` with its exponentially-weighted moving statistic,
+    /// per group of the `partition_by` columns if there are any.
+    #[cfg(feature = "ewma")]
+    Ewm {
+        input: PhysStream,
+        column: PlSmallStr,
+        partition_by: Vec<PlSmallStr>,
+        method: polars_ops::series::EwmMethod,
+        options: polars_ops::series::EWMOptions,
+    },
+
+    /// Replaces `column` with its nulls filled forward within `limit` of the
+    /// `by` column, per group of the `partition_by` columns if there are any.
+    #[cfg(feature = "fill_null_by")]
+    ForwardFillBy {
+        input: PhysStream,
+        column: PlSmallStr,
+        by: PlSmallStr,
+        partition_by: Vec<PlSmallStr>,
+        limit: i64,
+    },
+
+    /// Keeps the first row of every run of equal `keys`, for input sorted by them.
+    SortedDistinct {
+        input: PhysStream,
+        keys: Vec<PlSmallStr>,
+    },
+
     Reduce {
         input: PhysStream,
         exprs: Vec<ExprIR>,
@@ -156,6 +185,13 @@ pub enum PhysNodeKind {
         cloud_options: Option<CloudOptions>,
     },
 
+    CallbackSink {
+        input: PhysStream,
+        function: SinkCallback,
+        maintain_order: bool,
+        chunk_size: Option<NonZeroUsize>,
+    },
+
     SinkMultiple {
         sinks: Vec<PhysNodeKey>,
     },
@@ -212,6 +248,8 @@ pub enum PhysNodeKind {
         output_schema: SchemaRef,
 
         row_index: Option<RowIndex>,
+        /// Restart the row index at its offset for every file.
+        row_index_per_file: bool,
         pre_slice: Option<Slice>,
         predicate: Option<ExprIR>,
 
@@ -237,6 +275,37 @@ pub enum PhysNodeKind {
         aggs: Vec<ExprIR>,
     },
 
+    /// An unordered distinct on the `keys` columns that spills its state to
+    /// disk once it exceeds `memory_budget` bytes.
+    #[cfg(feature = "ipc")]
+    ExternalDistinct {
+        input: PhysStream,
+        keys: Vec<PlSmallStr>,
+        keep: UniqueKeepStrategy,
+        memory_budget: usize,
+    },
+
+    /// An exact quantile of the single column of `input` that spills sorted
+    /// runs of the column to disk once they exceed `memory_budget` bytes.
+    #[cfg(feature = "ipc")]
+    ExternalQuantile {
+        input: PhysStream,
+        quantile: f64,
+        method: QuantileMethod,
+        memory_budget: usize,
+    },
+
+    /// A group-by on the `keys` columns that applies `function` to the rows
+    /// of every group, spilling the rows to disk once they exceed
+    /// `memory_budget` bytes.
+    #[cfg(feature = "ipc")]
+    GroupByApply {
+        input: PhysStream,
+        keys: Vec<PlSmallStr>,
+        function: Arc<dyn DataFrameUdf>,
+        memory_budget: usize,
+    },
+
     EquiJoin {
         input_left: PhysStream,
         input_right: PhysStream,
@@ -314,6 +383,7 @@ fn visit_node_inputs_mut(
             | PhysNodeKind::InMemorySink { input }
             | PhysNodeKind::FileSink { input, .. }
             | PhysNodeKind::PartitionSink { input, .. }
+            | PhysNodeKind::CallbackSink { input, .. }
             | PhysNodeKind::InMemoryMap { input, .. }
             | PhysNodeKind::Map { input, .. }
             | PhysNodeKind::Sort { input, .. }
@@ -323,6 +393,31 @@ fn visit_node_inputs_mut(
                 visit(input);
             },
 
+            #[cfg(feature = "ewma")]
+            PhysNodeKind::Ewm { input, .. } => {
+                rec!(input.node);
+                visit(input);
+            },
+
+            #[cfg(feature = "fill_null_by")]
+            PhysNodeKind::ForwardFillBy { input, .. } => {
+                rec!(input.node);
+                visit(input);
+            },
+
+            PhysNodeKind::SortedDistinct { input, .. } => {
+                rec!(input.node);
+                visit(input);
+            },
+
+            #[cfg(feature = "ipc")]
+            PhysNodeKind::ExternalDistinct { input, .. }
+            | PhysNodeKind::ExternalQuantile { input, .. }
+            | PhysNodeKind::GroupByApply { input, .. } => {
+                rec!(input.node);
+                visit(input);
+            },
+
             PhysNodeKind::InMemoryJoin {
                 input_left,
                 input_right,
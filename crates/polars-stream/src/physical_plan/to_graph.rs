@@ -3,7 +3,7 @@ use std::sync::Arc;
 use std::sync::atomic::AtomicUsize;
 
 use parking_lot::Mutex;
-use polars_core::prelude::PlRandomState;
+use polars_core::prelude::{DataType, PlRandomState};
 use polars_core::schema::Schema;
 use polars_core::{POOL, config};
 use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
@@ -11,7 +11,9 @@ use polars_expr::groups::new_hash_grouper;
 use polars_expr::planner::{ExpressionConversionState, create_physical_expr};
 use polars_expr::reduce::into_reduction;
 use polars_expr::state::ExecutionState;
+use polars_io::predicates::RuntimeFilterSlot;
 use polars_mem_engine::{create_physical_plan, create_scan_predicate};
+use polars_ops::frame::{JoinArgs, JoinType, MaintainOrderJoin};
 use polars_plan::dsl::{JoinOptions, PartitionVariantIR, ScanSources};
 use polars_plan::plans::expr_ir::ExprIR;
 use polars_plan::plans::{AExpr, ArenaExprIter, Context, IR};
@@ -23,7 +25,7 @@ use polars_utils::pl_str::PlSmallStr;
 use recursive::recursive;
 use slotmap::{SecondaryMap, SlotMap};
 
-use super::{PhysNode, PhysNodeKey, PhysNodeKind};
+use super::{PhysNode, PhysNodeKey, PhysNodeKind, PhysStream};
 use crate::execute::StreamingExecutionState;
 use crate::expression::StreamExpr;
 use crate::graph::{Graph, GraphNodeKey};
@@ -68,6 +70,56 @@ struct GraphConversionContext<'a> {
     phys_to_graph: SecondaryMap<PhysNodeKey, GraphNodeKey>,
     expr_conversion_state: ExpressionConversionState,
     num_pipelines: usize,
+    /// Runtime filters to push into the scans, set by the joins they are the probe side of.
+    runtime_filters: SecondaryMap<PhysNodeKey, Vec<Arc<RuntimeFilterSlot>>>,
+}
+
+/// Create a runtime filter on the key of a side of a join that can be its probe side, if it reads
+/// the key column directly from a scan. The join must drop the probe rows that have no match, and
+/// only sets the filter if the side ends up being the probe side.
+fn create_runtime_filter(
+    probe: PhysStream,
+    probe_on: &[ExprIR],
+    args: &JoinArgs,
+    ctx: &mut GraphConversionContext<'_>,
+) -> Option<Arc<RuntimeFilterSlot>> {
+    let nulls_equal = args.nulls_equal_for_keys(probe_on.len()).ok()?;
+    let ([key], [false]) = (probe_on, nulls_equal.as_slice()) else {
+        return None;
+    };
+    let AExpr::Column(name) = ctx.expr_arena.get(key.node()) else {
+        return None;
+    };
+    let dtype = ctx.phys_sm[probe.node].output_schema.get(name)?;
+    if !(dtype.is_integer()
+        || dtype.is_temporal()
+        || matches!(dtype, DataType::String | DataType::Binary))
+    {
+        return None;
+    }
+
+    let mut stream = probe;
+    let scan_key = loop {
+        match &ctx.phys_sm[stream.node].kind {
+            PhysNodeKind::Filter { input, .. } => stream = *input,
+            PhysNodeKind::SimpleProjection { input, columns } if columns.contains(name) => {
+                stream = *input
+            },
+            PhysNodeKind::MultiScan {
+                projected_file_schema,
+                pre_slice: None,
+                ..
+            } if projected_file_schema.get(name) == Some(dtype) => break stream.node,
+            _ => return None,
+        }
+    };
+
+    let slot = Arc::new(RuntimeFilterSlot::new(name.clone(), dtype.clone()));
+    ctx.runtime_filters
+        .entry(scan_key)?
+        .or_default()
+        .push(slot.clone());
+    Some(slot)
 }
 
 pub fn physical_plan_to_graph(
@@ -84,6 +136,7 @@ pub fn physical_plan_to_graph(
         phys_to_graph: SecondaryMap::with_capacity(phys_sm.len()),
         expr_conversion_state: ExpressionConversionState::new(false),
         num_pipelines,
+        runtime_filters: SecondaryMap::new(),
     };
 
     to_graph_rec(root, &mut ctx)?;
@@ -185,6 +238,59 @@ fn to_graph_rec<'a>(
             )
         },
 
+        #[cfg(feature = "ewma")]
+        Ewm {
+            input,
+            column,
+            partition_by,
+            method,
+            options,
+        } => {
+            let dtype = ctx.phys_sm[input.node]
+                .output_schema
+                .try_get(column)?
+                .clone();
+            let input_key = to_graph_rec(input.node, ctx)?;
+            ctx.graph.add_node(
+                nodes::ewm::EwmNode::new(
+                    column.clone(),
+                    partition_by.clone(),
+                    *method,
+                    *options,
+                    &dtype,
+                )?,
+                [(input_key, input.port)],
+            )
+        },
+
+        #[cfg(feature = "fill_null_by")]
+        ForwardFillBy {
+            input,
+            column,
+            by,
+            partition_by,
+            limit,
+        } => {
+            let input_key = to_graph_rec(input.node, ctx)?;
+            ctx.graph.add_node(
+                nodes::fill_null_by::ForwardFillByNode::new(
+                    column.clone(),
+                    by.clone(),
+                    partition_by.clone(),
+                    *limit,
+                ),
+                [(input_key, input.port)],
+            )
+        },
+
+        SortedDistinct { input, keys } => {
+            let input_key = to_graph_rec(input.node, ctx)?;
+            ctx.graph.add_node(
+                nodes::sorted_distinct::SortedDistinctNode::new(keys.clone()),
+                [(input_key, input.port)],
+            )
+        },
+
         InputIndependentSelect { selectors } => {
             let empty_schema = Default::default();
             let phys_selectors = selectors
@@ -240,6 +346,25 @@ fn to_graph_rec<'a>(
             )
         },
 
+        CallbackSink {
+            input,
+            function,
+            maintain_order,
+            chunk_size,
+        } => {
+            let input_schema = ctx.phys_sm[input.node].output_schema.clone();
+            let input_key = to_graph_rec(input.node, ctx)?;
+            ctx.graph.add_node(
+                SinkComputeNode::from(nodes::io_sinks::callback::CallbackSinkNode::new(
+                    function.clone(),
+                    input_schema,
+                    *maintain_order,
+                    *chunk_size,
+                )),
+                [(input_key, input.port)],
+            )
+        },
+
         FileSink {
             target,
             sink_options,
@@ -258,7 +383,7 @@ fn to_graph_rec<'a>(
                         input_schema,
                         target.clone(),
                         sink_options,
-                        *ipc_writer_options,
+                        ipc_writer_options.clone(),
                         cloud_options.clone(),
                     )),
                     [(input_key, input.port)],
@@ -481,6 +606,7 @@ fn to_graph_rec<'a>(
             projected_file_schema,
             output_schema,
             row_index,
+            row_index_per_file,
             pre_slice,
             predicate,
             hive_parts,
@@ -491,6 +617,10 @@ fn to_graph_rec<'a>(
             file_schema,
         } => {
             let hive_parts = hive_parts.clone();
+            let runtime_filters = ctx
+                .runtime_filters
+                .remove(phys_node_key)
+                .unwrap_or_default();
 
             let predicate = predicate
                 .as_ref()
@@ -518,6 +648,7 @@ fn to_graph_rec<'a>(
             let full_file_schema = file_schema.clone();
 
             let row_index = row_index.clone();
+            let row_index_per_file = *row_index_per_file;
             let pre_slice = pre_slice.clone();
             let hive_parts = hive_parts.map(Arc::new);
             let include_file_paths = include_file_paths.clone();
@@ -537,8 +668,10 @@ fn to_graph_rec<'a>(
                         projected_file_schema,
                         full_file_schema,
                         row_index,
+                        row_index_per_file,
                         pre_slice,
                         predicate,
+                        runtime_filters,
                         hive_parts,
                         include_file_paths,
                         missing_columns_policy,
@@ -594,6 +727,68 @@ fn to_graph_rec<'a>(
             )
         },
 
+        #[cfg(feature = "ipc")]
+        ExternalDistinct {
+            input,
+            keys,
+            keep,
+            memory_budget,
+        } => {
+            let input_key = to_graph_rec(input.node, ctx)?;
+            ctx.graph.add_node(
+                nodes::external_distinct::ExternalDistinctNode::new(
+                    keys.clone(),
+                    *keep,
+                    node.output_schema.clone(),
+                    *memory_budget,
+                    PlRandomState::default(),
+                    ctx.num_pipelines,
+                ),
+                [(input_key, input.port)],
+            )
+        },
+
+        #[cfg(feature = "ipc")]
+        ExternalQuantile {
+            input,
+            quantile,
+            method,
+            memory_budget,
+        } => {
+            let input_key = to_graph_rec(input.node, ctx)?;
+            ctx.graph.add_node(
+                nodes::external_quantile::ExternalQuantileNode::new(
+                    *quantile,
+                    *method,
+                    node.output_schema.clone(),
+                    *memory_budget,
+                    ctx.num_pipelines,
+                ),
+                [(input_key, input.port)],
+            )
+        },
+
+        #[cfg(feature = "ipc")]
+        GroupByApply {
+            input,
+            keys,
+            function,
+            memory_budget,
+        } => {
+            let input_key = to_graph_rec(input.node, ctx)?;
+            ctx.graph.add_node(
+                nodes::group_by_apply::GroupByApplyNode::new(
+                    keys.clone(),
+                    function.clone(),
+                    node.output_schema.clone(),
+                    *memory_budget,
+                    PlRandomState::default(),
+                    ctx.num_pipelines,
+                ),
+                [(input_key, input.port)],
+            )
+        },
+
         InMemoryJoin {
             input_left,
             input_right,
@@ -671,6 +866,26 @@ fn to_graph_rec<'a>(
             output_bool: _,
         } => {
             let args = args.clone();
+            // Which sides can be the probe side of a join that drops its unmatched rows. The
+            // equi-join only chooses its build side at runtime if the order isn't maintained.
+            let (left_can_probe, right_can_probe) = match node.kind {
+                EquiJoin { .. } if args.how == JoinType::Inner => match args.maintain_order {
+                    MaintainOrderJoin::None => (true, true),
+                    MaintainOrderJoin::Left | MaintainOrderJoin::LeftRight => (true, false),
+                    MaintainOrderJoin::Right | MaintainOrderJoin::RightLeft => (false, true),
+                },
+                #[cfg(feature = "semi_anti_join")]
+                SemiAntiJoin { output_bool, .. } => {
+                    (args.how == JoinType::Semi && !output_bool, false)
+                },
+                _ => (false, false),
+            };
+            let left_runtime_filter = left_can_probe
+                .then(|| create_runtime_filter(*input_left, left_on, &args, ctx))
+                .flatten();
+            let right_runtime_filter = right_can_probe
+                .then(|| create_runtime_filter(*input_right, right_on, &args, ctx))
+                .flatten();
             let left_input_key = to_graph_rec(input_left.node, ctx)?;
             let right_input_key = to_graph_rec(input_right.node, ctx)?;
             let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
@@ -727,6 +942,7 @@ fn to_graph_rec<'a>(
                         right_key_selectors,
                         args,
                         output_bool,
+                        left_runtime_filter,
                         ctx.num_pipelines,
                     )?,
                     [
@@ -744,6 +960,7 @@ fn to_graph_rec<'a>(
                         left_key_selectors,
                         right_key_selectors,
                         args,
+                        [left_runtime_filter, right_runtime_filter],
                         ctx.num_pipelines,
                     )?,
                     [
@@ -957,6 +1174,7 @@ fn to_graph_rec<'a>(
             let projected_file_schema = output_schema.clone();
             let full_file_schema = output_schema.clone();
             let row_index = None;
+            let row_index_per_file = false;
             let pre_slice = None;
             let predicate = None;
             let hive_parts = None;
@@ -976,8 +1194,10 @@ fn to_graph_rec<'a>(
                         projected_file_schema,
                         full_file_schema,
                         row_index,
+                        row_index_per_file,
                         pre_slice,
                         predicate,
+                        runtime_filters: Vec::new(),
                         hive_parts,
                         include_file_paths,
                         missing_columns_policy,
@@ -1,5 +1,6 @@
 #![allow(unused)] // TODO: remove me
 use std::cmp::Reverse;
+use std::time::Instant;
 
 use polars_core::POOL;
 use polars_core::prelude::*;
@@ -10,6 +11,7 @@ use polars_plan::prelude::expr_ir::ExprIR;
 use polars_utils::arena::{Arena, Node};
 use slotmap::{SecondaryMap, SlotMap};
 
+use crate::async_executor::NodeUsage;
 use crate::graph::{Graph, GraphNodeKey};
 use crate::physical_plan::{PhysNode, PhysNodeKey, PhysNodeKind, StreamingLowerIRContext};
 
@@ -108,6 +110,64 @@ impl StreamingQuery {
     }
 
     pub fn execute(self) -> PolarsResult<QueryResult> {
+        #[cfg(feature = "tracing")]
+        return self.execute_traced();
+        #[cfg(not(feature = "tracing"))]
+        self.execute_with_usage(None)
+    }
+
+    /// Executes the query with a `tracing` span per node that records the estimated and actual
+    /// number of rows the node produced, how long its tasks were busy, and the time between the
+    /// first and the last poll of its tasks.
+    #[cfg(feature = "tracing")]
+    fn execute_traced(self) -> PolarsResult<QueryResult> {
+        use tracing::field::Empty;
+
+        let mut estimated_rows: SecondaryMap<GraphNodeKey, usize> = SecondaryMap::new();
+        for (phys_key, graph_key) in self.phys_to_graph.iter() {
+            if let Some(rows) = estimate_rows(self.phys_sm[phys_key].kind()) {
+                estimated_rows.insert(*graph_key, rows);
+            }
+        }
+        let node_usage: SecondaryMap<GraphNodeKey, Arc<NodeUsage>> = self
+            .graph
+            .nodes
+            .keys()
+            .map(|key| (key, Arc::default()))
+            .collect();
+        let spans = self
+            .graph
+            .nodes
+            .iter()
+            .map(|(key, node)| {
+                let span = tracing::info_span!(
+                    "polars.stream.execute",
+                    node = node.compute.name(),
+                    estimated_rows = estimated_rows.get(key).copied(),
+                    rows = Empty,
+                    busy_us = Empty,
+                    duration_us = Empty,
+                );
+                (key, span)
+            })
+            .collect::<Vec<_>>();
+
+        let result = self.execute_with_usage(Some(node_usage.clone()));
+        for (key, span) in spans {
+            let usage = &node_usage[key];
+            span.record("rows", usage.rows());
+            span.record("busy_us", usage.busy().as_micros() as u64);
+            if let Some((start, end)) = usage.first_poll().zip(usage.last_poll()) {
+                span.record("duration_us", end.duration_since(start).as_micros() as u64);
+            }
+        }
+        result
+    }
+
+    fn execute_with_usage(
+        self,
+        node_usage: Option<SecondaryMap<GraphNodeKey, Arc<NodeUsage>>>,
+    ) -> PolarsResult<QueryResult> {
         let StreamingQuery {
             top_ir,
             mut graph,
@@ -117,7 +177,7 @@ impl StreamingQuery {
         } = self;
 
         crate::async_executor::clear_task_wait_statistics();
-        let mut results = crate::execute::execute_graph(&mut graph)?;
+        let mut results = crate::execute::execute_graph(&mut graph, node_usage)?;
 
         if std::env::var("POLARS_TRACK_WAIT_STATS").as_deref() == Ok("1") {
             let mut stats = crate::async_executor::get_task_wait_statistics();
@@ -153,6 +213,61 @@ impl StreamingQuery {
             )),
         }
     }
+
+    /// Executes the query while measuring the resources used by each node.
+    ///
+    /// Nodes that never ran a task are left out of the profile.
+    pub fn execute_profiled(self) -> PolarsResult<(QueryResult, Vec<NodeProfile>)> {
+        let node_usage: SecondaryMap<GraphNodeKey, Arc<NodeUsage>> = self
+            .graph
+            .nodes
+            .keys()
+            .map(|key| (key, Arc::default()))
+            .collect();
+        let names: Vec<(GraphNodeKey, String)> = self
+            .graph
+            .nodes
+            .iter()
+            .map(|(key, node)| (key, node.compute.name().to_string()))
+            .collect();
+
+        let result = self.execute_with_usage(Some(node_usage.clone()))?;
+
+        let profile = names
+            .into_iter()
+            .filter_map(|(key, name)| {
+                let usage = &node_usage[key];
+                let (start, end) = usage.first_poll().zip(usage.last_poll())?;
+                let wall = end.duration_since(start).as_secs_f64();
+                // Only count the capacity the node actually occupied, so nodes of queries running
+                // at the same time don't affect each other's utilization.
+                let capacity = wall * usage.peak_running() as f64;
+                let thread_utilization =
+                    (capacity > 0.0).then(|| (usage.busy().as_secs_f64() / capacity).min(1.0));
+                Some(NodeProfile {
+                    name,
+                    start,
+                    end,
+                    rows: usage.rows(),
+                    peak_memory: usage.peak_bytes(),
+                    spilled_bytes: usage.spilled_bytes(),
+                    thread_utilization,
+                })
+            })
+            .collect();
+        Ok((result, profile))
+    }
+}
+
+/// Estimate the number of rows a node produces from what is known before execution.
+#[cfg(feature = "tracing")]
+fn estimate_rows(kind: &PhysNodeKind) -> Option<usize> {
+    match kind {
+        PhysNodeKind::InMemorySource { df } => Some(df.height()),
+        PhysNodeKind::StreamingSlice { length, .. }
+        | PhysNodeKind::NegativeSlice { length, .. } => Some(*length),
+        _ => None,
+    }
 }
 
 pub enum QueryResult {
@@ -161,6 +276,23 @@ pub enum QueryResult {
     Multiple(Vec<DataFrame>),
 }
 
+/// Resources used by a single node of a profiled streaming query.
+pub struct NodeProfile {
+    pub name: String,
+    /// Start of the first poll of any of the node's tasks.
+    pub start: Instant,
+    /// End of the last poll of any of the node's tasks.
+    pub end: Instant,
+    /// Number of rows in the morsels produced by the node.
+    pub rows: u64,
+    /// Largest estimated size of the morsels produced by a single poll of one of its tasks.
+    pub peak_memory: u64,
+    pub spilled_bytes: u64,
+    /// Fraction of the time between `start` and `end` the node's tasks were busy, relative to
+    /// the largest number of its tasks that ran at the same time.
+    pub thread_utilization: Option<f64>,
+}
+
 impl QueryResult {
     pub fn unwrap_single(self) -> DataFrame {
         use QueryResult::*;
@@ -0,0 +1,319 @@
+use polars_core::prelude::*;
+
+use super::utils::{assert_series_values_equal, categorical_dtype_to_string_dtype};
+
+/// Configuration options for comparing DataFrame equality.
+///
+/// Controls which aspects of the frames are checked and the tolerance for floating point
+/// comparisons.
+pub struct DataFrameEqualOptions {
+    /// Whether to check that the rows appear in the same order.
+    pub check_row_order: bool,
+    /// Whether to check that the columns appear in the same order.
+    pub check_column_order: bool,
+    /// Whether to check that the data types match.
+    pub check_dtypes: bool,
+    /// Whether to check for exact equality (true) or approximate equality (false) for floating point values.
+    pub check_exact: bool,
+    /// Relative tolerance for approximate equality of floating point values.
+    pub rtol: f64,
+    /// Absolute tolerance for approximate equality of floating point values.
+    pub atol: f64,
+    /// Whether to compare categorical values as strings.
+    pub categorical_as_str: bool,
+}
+
+impl Default for DataFrameEqualOptions {
+    /// Creates a new `DataFrameEqualOptions` with default settings.
+    ///
+    /// Default configuration:
+    /// - Checks row order, column order and data types
+    /// - Uses exact equality comparisons
+    /// - Sets relative tolerance to 1e-5 and absolute tolerance to 1e-8 for floating point comparisons
+    /// - Does not convert categorical values to strings for comparison
+    fn default() -> Self {
+        Self {
+            check_row_order: true,
+            check_column_order: true,
+            check_dtypes: true,
+            check_exact: true,
+            rtol: 1e-5,
+            atol: 1e-8,
+            categorical_as_str: false,
+        }
+    }
+}
+
+impl DataFrameEqualOptions {
+    /// Creates a new `DataFrameEqualOptions` with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether to check that rows appear in the same order.
+    pub fn with_check_row_order(mut self, value: bool) -> Self {
+        self.check_row_order = value;
+        self
+    }
+
+    /// Sets whether to check that columns appear in the same order.
+    pub fn with_check_column_order(mut self, value: bool) -> Self {
+        self.check_column_order = value;
+        self
+    }
+
+    /// Sets whether to check that data types match.
+    pub fn with_check_dtypes(mut self, value: bool) -> Self {
+        self.check_dtypes = value;
+        self
+    }
+
+    /// Sets whether to require exact equality for floating point values.
+    pub fn with_check_exact(mut self, value: bool) -> Self {
+        self.check_exact = value;
+        self
+    }
+
+    /// Sets the relative tolerance for approximate equality of floating point values.
+    pub fn with_rtol(mut self, value: f64) -> Self {
+        self.rtol = value;
+        self
+    }
+
+    /// Sets the absolute tolerance for approximate equality of floating point values.
+    pub fn with_atol(mut self, value: f64) -> Self {
+        self.atol = value;
+        self
+    }
+
+    /// Sets whether to compare categorical values as strings.
+    pub fn with_categorical_as_str(mut self, value: bool) -> Self {
+        self.categorical_as_str = value;
+        self
+    }
+}
+
+fn names_not_in(df: &DataFrame, other: &DataFrame) -> Vec<PlSmallStr> {
+    df.get_column_names()
+        .into_iter()
+        .filter(|name| other.column(name.as_str()).is_err())
+        .cloned()
+        .collect()
+}
+
+/// Verifies that two DataFrames are equal according to a set of configurable criteria.
+///
+/// # Order of Checks
+///
+/// 1. Column names, listing the columns that are only in one of the frames
+/// 2. Column order (if `check_column_order` is true)
+/// 3. Data types (if `check_dtypes` is true), listing all mismatching columns
+/// 4. Height
+/// 5. Values of every column. If `check_row_order` is false, both frames are sorted by all
+///    columns first. All columns with mismatching values are reported, each with the
+///    mismatching rows.
+pub fn assert_dataframe_equal(
+    left: &DataFrame,
+    right: &DataFrame,
+    options: DataFrameEqualOptions,
+) -> PolarsResult<()> {
+    let only_left = names_not_in(left, right);
+    let only_right = names_not_in(right, left);
+    if !only_left.is_empty() || !only_right.is_empty() {
+        return Err(polars_err!(
+            assertion_error = "DataFrames",
+            "column names mismatch",
+            format!("{only_left:?} not in right"),
+            format!("{only_right:?} not in left")
+        ));
+    }
+
+    if options.check_column_order && left.get_column_names() != right.get_column_names() {
+        return Err(polars_err!(
+            assertion_error = "DataFrames",
+            "column order mismatch",
+            format!("{:?}", left.get_column_names()),
+            format!("{:?}", right.get_column_names())
+        ));
+    }
+
+    if options.check_dtypes {
+        let mismatches = left
+            .get_columns()
+            .iter()
+            .filter_map(|l| {
+                let r = right.column(l.name()).unwrap();
+                let (mut ldt, mut rdt) = (l.dtype().clone(), r.dtype().clone());
+                if options.categorical_as_str {
+                    ldt = categorical_dtype_to_string_dtype(&ldt);
+                    rdt = categorical_dtype_to_string_dtype(&rdt);
+                }
+                (ldt != rdt).then(|| format!("'{}': {} != {}", l.name(), l.dtype(), r.dtype()))
+            })
+            .collect::<Vec<_>>();
+        if !mismatches.is_empty() {
+            return Err(polars_err!(
+                AssertionError: "DataFrames are different (data type mismatch)\n{}",
+                mismatches.join("\n")
+            ));
+        }
+    }
+
+    if left.height() != right.height() {
+        return Err(polars_err!(
+            assertion_error = "DataFrames",
+            "height mismatch",
+            left.height(),
+            right.height()
+        ));
+    }
+
+    let right = right.select(left.get_column_names_owned())?;
+    let (left, right) = if options.check_row_order {
+        (left.clone(), right)
+    } else {
+        let by = left.get_column_names_owned();
+        (
+            left.sort(by.clone(), SortMultipleOptions::default())?,
+            right.sort(by, SortMultipleOptions::default())?,
+        )
+    };
+
+    let mut mismatches = vec![];
+    for (l, r) in left.get_columns().iter().zip(right.get_columns()) {
+        if let Err(e) = assert_series_values_equal(
+            l.as_materialized_series(),
+            r.as_materialized_series(),
+            true,
+            options.check_exact,
+            options.rtol,
+            options.atol,
+            options.categorical_as_str,
+        ) {
+            mismatches.push(format!("column '{}': {}", l.name(), e));
+        }
+    }
+    if !mismatches.is_empty() {
+        return Err(polars_err!(
+            AssertionError: "DataFrames are different (value mismatch in {} column(s))\n{}",
+            mismatches.len(),
+            mismatches.join("\n")
+        ));
+    }
+
+    Ok(())
+}
+
+/// Asserts that two DataFrames are equal according to the specified options.
+///
+/// Unlike comparing with [`DataFrame::equals`], a failed assertion reports which columns
+/// differ and shows the mismatching rows instead of printing both frames in full.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_testing::assert_dataframe_equal;
+/// use polars_testing::asserts::DataFrameEqualOptions;
+///
+/// let df1 = df!["a" => [1, 2], "b" => [1.0, 2.0]].unwrap();
+/// let df2 = df!["b" => [1.0, 2.0 + 1e-9], "a" => [1, 2]].unwrap();
+///
+/// let options = DataFrameEqualOptions::default()
+///     .with_check_column_order(false)
+///     .with_check_exact(false);
+/// assert_dataframe_equal!(&df1, &df2, options);
+/// ```
+///
+/// # Panics
+///
+/// Panics when the frames are not equal according to the specified comparison criteria.
+#[macro_export]
+macro_rules! assert_dataframe_equal {
+    ($left:expr, $right:expr $(, $options:expr)?) => {
+        {
+            #[allow(unused_assignments)]
+            #[allow(unused_mut)]
+            let mut options = $crate::asserts::DataFrameEqualOptions::default();
+            $(options = $options;)?
+
+            match $crate::asserts::assert_dataframe_equal($left, $right, options) {
+                Ok(_) => {},
+                Err(e) => panic!("{}", e),
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use polars_core::prelude::*;
+
+    use crate::asserts::DataFrameEqualOptions;
+
+    #[test]
+    fn test_dataframe_equal() {
+        let df1 = df!["a" => [1, 2, 3], "b" => ["x", "y", "z"]].unwrap();
+        let df2 = df!["a" => [3, 1, 2], "b" => ["z", "x", "y"]].unwrap();
+
+        assert_dataframe_equal!(&df1, &df1);
+        let options = DataFrameEqualOptions::default().with_check_row_order(false);
+        assert_dataframe_equal!(&df1, &df2, options);
+    }
+
+    #[test]
+    #[should_panic(expected = "column names mismatch")]
+    fn test_dataframe_column_names_mismatch() {
+        let df1 = df!["a" => [1], "b" => [1]].unwrap();
+        let df2 = df!["a" => [1], "c" => [1]].unwrap();
+
+        assert_dataframe_equal!(&df1, &df2);
+    }
+
+    #[test]
+    #[should_panic(expected = "column order mismatch")]
+    fn test_dataframe_column_order_mismatch() {
+        let df1 = df!["a" => [1], "b" => [1]].unwrap();
+        let df2 = df!["b" => [1], "a" => [1]].unwrap();
+
+        assert_dataframe_equal!(&df1, &df2);
+    }
+
+    #[test]
+    #[should_panic(expected = "'a': i32 != i64")]
+    fn test_dataframe_dtype_mismatch() {
+        let df1 = df!["a" => [1i32]].unwrap();
+        let df2 = df!["a" => [1i64]].unwrap();
+
+        assert_dataframe_equal!(&df1, &df2);
+    }
+
+    #[test]
+    #[should_panic(expected = "height mismatch")]
+    fn test_dataframe_height_mismatch() {
+        let df1 = df!["a" => [1, 2]].unwrap();
+        let df2 = df!["a" => [1]].unwrap();
+
+        assert_dataframe_equal!(&df1, &df2);
+    }
+
+    #[test]
+    fn test_dataframe_value_mismatch_report() {
+        let df1 = df!["a" => [1, 2, 3], "b" => [1.0, 2.0, 3.0], "c" => ["x", "y", "z"]].unwrap();
+        let df2 = df!["a" => [1, 5, 3], "b" => [1.0, 2.0, 3.5], "c" => ["x", "y", "z"]].unwrap();
+
+        let err = crate::asserts::assert_dataframe_equal(&df1, &df2, Default::default())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("value mismatch in 2 column(s)"));
+        assert!(err.contains("column 'a'") && err.contains("column 'b'"));
+        assert!(!err.contains("column 'c'"));
+        assert!(err.contains("1 value(s) differ"));
+
+        let options = DataFrameEqualOptions::default()
+            .with_check_exact(false)
+            .with_atol(1.0);
+        let df2 = df!["a" => [1, 2, 3], "b" => [1.0, 2.5, 3.5], "c" => ["x", "y", "z"]].unwrap();
+        assert_dataframe_equal!(&df1, &df2, options);
+    }
+}
@@ -1,4 +1,10 @@
+pub mod frame;
+#[cfg(feature = "lazy")]
+pub mod plan;
 pub mod series;
 mod utils;
 
+pub use frame::{DataFrameEqualOptions, assert_dataframe_equal};
+#[cfg(feature = "lazy")]
+pub use plan::{UPDATE_SNAPSHOTS_ENV, assert_plan_snapshot};
 pub use utils::{SeriesEqualOptions, assert_series_equal};
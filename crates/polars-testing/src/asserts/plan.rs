@@ -0,0 +1,109 @@
+use std::path::Path;
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+
+/// Set this environment variable to `1` to write the current plans to the snapshot files instead
+/// of comparing against them.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "POLARS_UPDATE_SNAPSHOTS";
+
+/// Assert that the optimized plan of `lf` equals the snapshot stored in the file at `snapshot`.
+///
+/// The plan is rendered with [`LazyFrame::describe_optimized_plan_snapshot`] with paths
+/// relative to `root`. If the snapshot file does not exist, or [`UPDATE_SNAPSHOTS_ENV`] is set
+/// to `1`, the plan is written to the file instead. Commit the snapshot files so that changes to
+/// the optimized plans, e.g. a filter that is no longer pushed down into a scan, show up as a
+/// failing assertion.
+pub fn assert_plan_snapshot(
+    lf: &LazyFrame,
+    snapshot: impl AsRef<Path>,
+    root: Option<&Path>,
+) -> PolarsResult<()> {
+    let snapshot = snapshot.as_ref();
+    let plan = lf.describe_optimized_plan_snapshot(root)?;
+
+    let update = std::env::var(UPDATE_SNAPSHOTS_ENV).as_deref() == Ok("1");
+    if update || !snapshot.exists() {
+        if let Some(parent) = snapshot.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(snapshot, format!("{plan}\n"))?;
+        return Ok(());
+    }
+
+    let expected = std::fs::read_to_string(snapshot)?;
+    // Allow the snapshot to be edited with editors that change the line endings.
+    let expected = expected.trim_end().replace("\r\n", "\n");
+    if expected != plan {
+        return Err(polars_err!(
+            assertion_error = "Plans",
+            format!(
+                "optimized plan does not match snapshot '{}', set {}=1 to update it",
+                snapshot.display(),
+                UPDATE_SNAPSHOTS_ENV
+            ),
+            plan,
+            expected
+        ));
+    }
+    Ok(())
+}
+
+/// Asserts that the optimized plan of a [`LazyFrame`] equals the snapshot stored in a file.
+///
+/// Relative paths of snapshots are resolved against the directory of the crate's manifest.
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_lazy::prelude::*;
+/// use polars_testing::assert_plan_snapshot;
+///
+/// let lf = df!["a" => [1, 2, 3]].unwrap().lazy().filter(col("a").gt(lit(1)));
+/// assert_plan_snapshot!(&lf, "tests/snapshots/filter.txt");
+/// ```
+#[macro_export]
+macro_rules! assert_plan_snapshot {
+    ($lf:expr, $snapshot:expr $(, $root:expr)?) => {
+        {
+            #[allow(unused_assignments)]
+            #[allow(unused_mut)]
+            let mut root: Option<&std::path::Path> = None;
+            $(root = Some(std::path::Path::new($root));)?
+
+            let snapshot = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($snapshot);
+            match $crate::asserts::assert_plan_snapshot($lf, snapshot, root) {
+                Ok(_) => {},
+                Err(e) => panic!("{}", e),
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_snapshot() -> PolarsResult<()> {
+        let dir = std::env::temp_dir().join(format!("polars-plan-snapshot-{}", std::process::id()));
+        let snapshot = dir.join("filter.txt");
+        let df = df!["a" => [1, 2, 3], "b" => [1, 2, 3]]?;
+
+        let lf = df
+            .clone()
+            .lazy()
+            .select([col("a")])
+            .filter(col("a").gt(lit(1)));
+        assert_plan_snapshot(&lf, &snapshot, None)?;
+        assert_plan_snapshot(&lf, &snapshot, None)?;
+
+        let lf = df.lazy().filter(col("a").gt(lit(2)));
+        let err = assert_plan_snapshot(&lf, &snapshot, None).unwrap_err();
+        assert!(err.to_string().contains("does not match snapshot"));
+
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
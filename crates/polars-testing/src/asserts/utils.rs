@@ -169,6 +169,40 @@ pub fn comparing_nested_floats(left: &DataType, right: &DataType) -> bool {
     left_has_floats && right_has_floats
 }
 
+/// Maximum number of mismatching rows that are shown in an assertion error.
+const MAX_DIFF_ROWS: usize = 10;
+
+/// Builds an assertion error that lists the mismatching values next to their row index,
+/// instead of printing both Series in full.
+fn value_mismatch_error(detail: &str, rows: &IdxCa, left: &Series, right: &Series) -> PolarsError {
+    let n = rows.len();
+    let shown = n.min(MAX_DIFF_ROWS);
+    let diff = DataFrame::new(vec![
+        rows.head(Some(shown)).with_name("row".into()).into_column(),
+        left.head(Some(shown))
+            .with_name("left".into())
+            .into_column(),
+        right
+            .head(Some(shown))
+            .with_name("right".into())
+            .into_column(),
+    ]);
+    let diff = match diff {
+        Ok(diff) => diff.to_string(),
+        Err(e) => e.to_string(),
+    };
+    polars_err!(
+        AssertionError: "Series are different ({})\n{} value(s) differ, first {} shown:\n{}",
+        detail, n, shown, diff
+    )
+}
+
+/// Row indices of the `true` values of `mask`.
+fn mask_to_rows(mask: &BooleanChunked) -> PolarsResult<IdxCa> {
+    let rows = IdxCa::from_vec("row".into(), (0..mask.len() as IdxSize).collect());
+    rows.filter(mask)
+}
+
 /// Ensures that null values in two Series match exactly and returns an error if any mismatches are found.
 pub fn assert_series_null_values_match(left: &Series, right: &Series) -> PolarsResult<()> {
     let null_value_mismatch = left.is_null().not_equal(&right.is_null());
@@ -266,12 +300,13 @@ pub fn assert_series_values_within_tolerance(
         let exceeded_indices = within_tolerance.not();
         let problematic_left = left_unequal.filter(&exceeded_indices)?;
         let problematic_right = right_unequal.filter(&exceeded_indices)?;
+        let rows = mask_to_rows(unequal)?.filter(&exceeded_indices)?;
 
-        Err(polars_err!(
-            assertion_error = "Series",
+        Err(value_mismatch_error(
             "values not within tolerance",
-            problematic_left,
-            problematic_right
+            &rows,
+            &problematic_left,
+            &problematic_right,
         ))
     }
 }
@@ -368,11 +403,11 @@ pub fn assert_series_values_equal(
     }
 
     if check_exact || !left.dtype().is_float() || !right.dtype().is_float() {
-        return Err(polars_err!(
-            assertion_error = "Series",
+        return Err(value_mismatch_error(
             "exact value mismatch",
-            left,
-            right
+            &mask_to_rows(&unequal)?,
+            &left.filter(&unequal)?,
+            &right.filter(&unequal)?,
         ));
     }
 
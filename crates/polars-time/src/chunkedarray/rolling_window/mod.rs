@@ -1,4 +1,6 @@
 mod dispatch;
+#[cfg(feature = "rolling_window")]
+mod registered;
 #[cfg(feature = "rolling_window_by")]
 mod rolling_kernels;
 
@@ -7,6 +9,8 @@ pub use dispatch::*;
 use polars_compute::rolling;
 use polars_compute::rolling::RollingFnParams;
 use polars_core::prelude::*;
+#[cfg(feature = "rolling_window")]
+pub use registered::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -0,0 +1,238 @@
+//! Rolling aggregations with kernels registered by the user, see [`register_rolling_aggregator`].
+use std::sync::{Arc, RwLock};
+
+use polars_core::prelude::*;
+
+/// A rolling statistic that is updated as values enter and leave the window, instead of being
+/// computed from all values in the window for every row.
+///
+/// Values leave the window in the order in which they entered it. Nulls never enter the window.
+pub trait RollingAggregator {
+    /// Add a value that enters the window.
+    fn add(&mut self, value: f64) -> PolarsResult<()>;
+
+    /// Remove a value that leaves the window, which is the oldest value in the window.
+    fn remove(&mut self, value: f64) -> PolarsResult<()>;
+
+    /// The statistic of the values in the window, or `None` if it is undefined.
+    fn evaluate(&self) -> PolarsResult<Option<f64>>;
+}
+
+/// Creates the [`RollingAggregator`] that is slid over a column.
+pub type RollingAggregatorFactory =
+    Arc<dyn Fn() -> PolarsResult<Box<dyn RollingAggregator>> + Send + Sync>;
+
+static ROLLING_AGGREGATORS: RwLock<Vec<(PlSmallStr, RollingAggregatorFactory)>> =
+    RwLock::new(Vec::new());
+
+/// Register the rolling aggregator with the given `name`, replacing the aggregator that was
+/// registered under that name.
+///
+/// The registry is global to the process: use names that are unique to your library, and remove
+/// the aggregator with [`unregister_rolling_aggregator`] once it is no longer used. The
+/// aggregator can be used with [`rolling_map_registered`].
+pub fn register_rolling_aggregator(name: &str, factory: RollingAggregatorFactory) {
+    let mut aggregators = ROLLING_AGGREGATORS.write().unwrap();
+    let replaced = aggregators
+        .iter()
+        .position(|(n, _)| n == name)
+        .map(|i| aggregators.remove(i));
+    aggregators.push((name.into(), factory));
+    // Dropping the replaced aggregator may run user code, so do it after unlocking.
+    drop(aggregators);
+    drop(replaced);
+}
+
+/// Remove the rolling aggregator registered as `name`. Returns whether there was one.
+pub fn unregister_rolling_aggregator(name: &str) -> bool {
+    take_rolling_aggregator(name).is_some()
+}
+
+/// Remove the rolling aggregator registered as `name` from the registry and return it, so that
+/// it is dropped after the registry is unlocked, as dropping it may run user code.
+fn take_rolling_aggregator(name: &str) -> Option<RollingAggregatorFactory> {
+    let mut aggregators = ROLLING_AGGREGATORS.write().unwrap();
+    let i = aggregators.iter().position(|(n, _)| n == name)?;
+    Some(aggregators.remove(i).1)
+}
+
+fn get_rolling_aggregator(name: &str) -> PolarsResult<RollingAggregatorFactory> {
+    let aggregators = ROLLING_AGGREGATORS.read().unwrap();
+    aggregators
+        .iter()
+        .find(|(n, _)| n == name)
+        .map(|(_, factory)| factory.clone())
+        .ok_or_else(|| polars_err!(ComputeError: "no rolling aggregator registered as '{}'", name))
+}
+
+/// Apply the rolling aggregator registered as `name` over fixed windows of `s`, which is cast
+/// to `Float64`.
+///
+/// Every value enters and leaves the aggregator once, so the aggregation is linear in the
+/// length of `s` if the aggregator updates in constant time. Weights are not supported.
+pub fn rolling_map_registered(
+    s: &Series,
+    name: &str,
+    options: &RollingOptionsFixedWindow,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        options.weights.is_none(),
+        InvalidOperation: "registered rolling aggregators don't support weights"
+    );
+    polars_ensure!(options.min_periods <= options.window_size, InvalidOperation: "`min_periods` should be <= `window_size`");
+    polars_ensure!(options.window_size > 0, InvalidOperation: "`window_size` should be > 0");
+    let mut aggregator = get_rolling_aggregator(name)?()?;
+
+    let s = s.cast(&DataType::Float64)?;
+    let ca = s.f64()?.rechunk();
+    let arr = ca.downcast_as_array();
+    let len = arr.len();
+
+    // The window of row `i` is `i - before..i + after`.
+    let (before, after) = if options.center {
+        let after = options.window_size.div_ceil(2);
+        (options.window_size - after, after)
+    } else {
+        (options.window_size - 1, 1)
+    };
+    let (mut start, mut end, mut count) = (0, 0, 0);
+    let mut out = Vec::with_capacity(len);
+    for i in 0..len {
+        while end < std::cmp::min(len, i + after) {
+            if let Some(v) = arr.get(end) {
+                aggregator.add(v)?;
+                count += 1;
+            }
+            end += 1;
+        }
+        while start < i.saturating_sub(before) {
+            if let Some(v) = arr.get(start) {
+                aggregator.remove(v)?;
+                count -= 1;
+            }
+            start += 1;
+        }
+        out.push(if count >= options.min_periods {
+            aggregator.evaluate()?
+        } else {
+            None
+        });
+    }
+    Ok(Float64Chunked::from_iter(out)
+        .with_name(s.name().clone())
+        .into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A rolling sum with Kahan compensation.
+    #[derive(Default)]
+    struct KahanSum {
+        sum: f64,
+        compensation: f64,
+    }
+
+    impl KahanSum {
+        fn update(&mut self, value: f64) {
+            let y = value - self.compensation;
+            let t = self.sum + y;
+            self.compensation = (t - self.sum) - y;
+            self.sum = t;
+        }
+    }
+
+    impl RollingAggregator for KahanSum {
+        fn add(&mut self, value: f64) -> PolarsResult<()> {
+            self.update(value);
+            Ok(())
+        }
+
+        fn remove(&mut self, value: f64) -> PolarsResult<()> {
+            self.update(-value);
+            Ok(())
+        }
+
+        fn evaluate(&self) -> PolarsResult<Option<f64>> {
+            Ok(Some(self.sum))
+        }
+    }
+
+    /// Fails once the window holds more than one value.
+    #[derive(Default)]
+    struct AtMostOne(usize);
+
+    impl RollingAggregator for AtMostOne {
+        fn add(&mut self, _value: f64) -> PolarsResult<()> {
+            self.0 += 1;
+            polars_ensure!(self.0 <= 1, ComputeError: "more than one value");
+            Ok(())
+        }
+
+        fn remove(&mut self, _value: f64) -> PolarsResult<()> {
+            self.0 -= 1;
+            Ok(())
+        }
+
+        fn evaluate(&self) -> PolarsResult<Option<f64>> {
+            Ok(None)
+        }
+    }
+
+    #[test]
+    fn test_rolling_map_registered() -> PolarsResult<()> {
+        register_rolling_aggregator(
+            "test_kahan_sum",
+            Arc::new(|| Ok(Box::new(KahanSum::default()))),
+        );
+        let s = Series::new("a".into(), [Some(1i32), Some(2), None, Some(4), Some(5)]);
+        let options = RollingOptionsFixedWindow {
+            window_size: 2,
+            min_periods: 1,
+            ..Default::default()
+        };
+        let out = rolling_map_registered(&s, "test_kahan_sum", &options)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            [Some(1.0), Some(3.0), Some(2.0), Some(4.0), Some(9.0)]
+        );
+
+        let options = RollingOptionsFixedWindow {
+            window_size: 3,
+            min_periods: 2,
+            center: true,
+            ..Default::default()
+        };
+        let out = rolling_map_registered(&s, "test_kahan_sum", &options)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            [Some(3.0), Some(3.0), Some(6.0), Some(9.0), Some(9.0)]
+        );
+
+        assert!(unregister_rolling_aggregator("test_kahan_sum"));
+        assert!(!unregister_rolling_aggregator("test_kahan_sum"));
+        assert!(rolling_map_registered(&s, "test_kahan_sum", &options).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_rolling_map_registered_error() {
+        register_rolling_aggregator(
+            "test_at_most_one",
+            Arc::new(|| Ok(Box::new(AtMostOne::default()))),
+        );
+        let s = Series::new("a".into(), [1.0, 2.0]);
+        let options = RollingOptionsFixedWindow {
+            window_size: 1,
+            ..Default::default()
+        };
+        assert!(rolling_map_registered(&s, "test_at_most_one", &options).is_ok());
+        let options = RollingOptionsFixedWindow {
+            window_size: 2,
+            ..Default::default()
+        };
+        assert!(rolling_map_registered(&s, "test_at_most_one", &options).is_err());
+        unregister_rolling_aggregator("test_at_most_one");
+    }
+}
@@ -0,0 +1,207 @@
+use arrow::legacy::time_zone::Tz;
+#[cfg(feature = "timezones")]
+use polars_core::datatypes::time_zone::parse_time_zone;
+use polars_core::prelude::*;
+
+use crate::Duration;
+
+type AddFn = fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>;
+
+/// The distinct non-null timestamps of `datetime` in ascending order.
+fn sorted_timestamps(datetime: &DatetimeChunked) -> Vec<i64> {
+    let mut values = datetime.phys.iter().flatten().collect::<Vec<_>>();
+    values.sort_unstable();
+    values.dedup();
+    values
+}
+
+fn add_fn(tu: TimeUnit) -> AddFn {
+    match tu {
+        TimeUnit::Nanoseconds => Duration::add_ns,
+        TimeUnit::Microseconds => Duration::add_us,
+        TimeUnit::Milliseconds => Duration::add_ms,
+    }
+}
+
+fn check_interval(interval: &Duration, name: &str) -> PolarsResult<()> {
+    polars_ensure!(
+        !interval.is_zero() && !interval.negative() && !interval.parsed_int,
+        InvalidOperation: "`{}` must be a positive duration, got {}", name, interval
+    );
+    Ok(())
+}
+
+fn missing_timestamps(
+    values: &[i64],
+    every: &Duration,
+    add: AddFn,
+    tz: Option<&Tz>,
+) -> PolarsResult<Vec<i64>> {
+    let (Some(&first), Some(&last)) = (values.first(), values.last()) else {
+        return Ok(vec![]);
+    };
+    let mut out = vec![];
+    let mut values = values.iter().peekable();
+    let mut t = first;
+    while t <= last {
+        while values.next_if(|&&v| v < t).is_some() {}
+        if values.next_if_eq(&&t).is_none() {
+            out.push(t);
+        }
+        t = add(every, t, tz)?;
+    }
+    Ok(out)
+}
+
+fn gap_bounds(
+    values: &[i64],
+    interval: &Duration,
+    add: AddFn,
+    tz: Option<&Tz>,
+) -> PolarsResult<(Vec<i64>, Vec<i64>)> {
+    let mut starts = vec![];
+    let mut ends = vec![];
+    for w in values.windows(2) {
+        if w[1] > add(interval, w[0], tz)? {
+            starts.push(w[0]);
+            ends.push(w[1]);
+        }
+    }
+    Ok((starts, ends))
+}
+
+/// Apply `f` to the sorted timestamps of a `Date` or `Datetime` Series and convert the result
+/// with `to_series`, which gets the dtype of the Series. Dates are handled as datetimes in
+/// milliseconds.
+fn with_timestamps<T>(
+    s: &Series,
+    interval: &Duration,
+    f: impl Fn(&[i64], AddFn, Option<&Tz>) -> PolarsResult<T>,
+    to_series: impl Fn(T, &DataType) -> PolarsResult<Series>,
+) -> PolarsResult<Series> {
+    match s.dtype() {
+        DataType::Date => {
+            polars_ensure!(
+                interval.is_full_days(),
+                InvalidOperation: "expected a whole number of days for a Date column, got {}", interval
+            );
+            let datetime = s.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+            let values = sorted_timestamps(datetime.datetime()?);
+            to_series(f(&values, add_fn(TimeUnit::Milliseconds), None)?, s.dtype())
+        },
+        DataType::Datetime(tu, tz) => {
+            let values = sorted_timestamps(s.datetime()?);
+            let out = match tz {
+                #[cfg(feature = "timezones")]
+                Some(tz) => f(&values, add_fn(*tu), Some(&parse_time_zone(tz)?))?,
+                _ => f(&values, add_fn(*tu), None)?,
+            };
+            to_series(out, s.dtype())
+        },
+        dt => polars_bail!(
+            InvalidOperation: "expected Date or Datetime, got {}", dt
+        ),
+    }
+}
+
+/// Timestamps in the time unit of `dtype`, or in milliseconds for dates, as a Series of `dtype`.
+fn timestamps_to_series(
+    name: PlSmallStr,
+    values: Vec<i64>,
+    dtype: &DataType,
+) -> PolarsResult<Series> {
+    let out = Int64Chunked::from_vec(name, values);
+    let mut out = match dtype {
+        DataType::Date => out
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?
+            .cast(dtype)?,
+        _ => out.cast(dtype)?,
+    };
+    out.set_sorted_flag(IsSorted::Ascending);
+    Ok(out)
+}
+
+/// The timestamps that are missing from `s` on the grid that starts at its earliest value and
+/// steps by `every` up to its latest value.
+///
+/// The values of `s` don't have to be sorted, nulls are ignored and values that are not on the
+/// grid don't fill a period. The result is sorted.
+pub fn impl_missing_periods(s: &Series, every: &Duration) -> PolarsResult<Series> {
+    check_interval(every, "every")?;
+    with_timestamps(
+        s,
+        every,
+        |values, add, tz| missing_timestamps(values, every, add, tz),
+        |values, dtype| timestamps_to_series(s.name().clone(), values, dtype),
+    )
+}
+
+/// The gaps between consecutive values of `s` that are further apart than `interval`, as a
+/// struct with the fields `start` and `end`, the values before and after each gap.
+///
+/// The values of `s` don't have to be sorted and nulls are ignored. The gaps are sorted.
+pub fn impl_gaps(s: &Series, interval: &Duration) -> PolarsResult<Series> {
+    check_interval(interval, "interval")?;
+    with_timestamps(
+        s,
+        interval,
+        |values, add, tz| gap_bounds(values, interval, add, tz),
+        |(starts, ends), dtype| {
+            let fields = [
+                timestamps_to_series(PlSmallStr::from_static("start"), starts, dtype)?,
+                timestamps_to_series(PlSmallStr::from_static("end"), ends, dtype)?,
+            ];
+            let len = fields[0].len();
+            Ok(StructChunked::from_series(s.name().clone(), len, fields.iter())?.into_series())
+        },
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_missing_periods_and_gaps() -> PolarsResult<()> {
+        let hour = 3_600_000i64;
+        let s = Series::new(
+            "t".into(),
+            [
+                Some(5 * hour),
+                Some(0),
+                None,
+                Some(hour),
+                Some(2 * hour),
+                Some(6 * hour),
+            ],
+        )
+        .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+
+        let out = impl_missing_periods(&s, &Duration::parse("1h"))?;
+        assert_eq!(out.dtype(), s.dtype());
+        assert_eq!(
+            Vec::from(out.to_physical_repr().i64()?),
+            [Some(3 * hour), Some(4 * hour)]
+        );
+
+        let out = impl_gaps(&s, &Duration::parse("1h"))?;
+        let fields = out.struct_()?.fields_as_series();
+        assert_eq!(
+            Vec::from(fields[0].to_physical_repr().i64()?),
+            [Some(2 * hour)]
+        );
+        assert_eq!(
+            Vec::from(fields[1].to_physical_repr().i64()?),
+            [Some(5 * hour)]
+        );
+
+        let dates = Series::new("d".into(), [0i32, 3, 1]).cast(&DataType::Date)?;
+        let out = impl_missing_periods(&dates, &Duration::parse("1d"))?;
+        assert_eq!(out.dtype(), &DataType::Date);
+        assert_eq!(Vec::from(out.to_physical_repr().i32()?), [Some(2)]);
+
+        assert!(impl_missing_periods(&s, &Duration::parse("0h")).is_err());
+        assert!(impl_missing_periods(&dates, &Duration::parse("1h")).is_err());
+        Ok(())
+    }
+}
@@ -12,10 +12,74 @@ use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
+use crate::windows::calendar::NS_DAY;
 
 #[repr(transparent)]
 struct Wrap<T>(pub T);
 
+fn parse_tz(tz: &Option<TimeZone>) -> Option<Tz> {
+    match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => tz.parse::<Tz>().ok(),
+        _ => None,
+    }
+}
+
+/// Convert the `origin` of the options to an offset from the window boundaries
+/// that `every` truncates to.
+fn origin_offset(
+    options: &DynamicGroupOptions,
+    origin: i64,
+    tu: TimeUnit,
+    tz: &Option<TimeZone>,
+) -> PolarsResult<Duration> {
+    let tz = parse_tz(tz);
+    let (origin, truncated, ns_factor) = match tu {
+        TimeUnit::Nanoseconds => (origin, options.every.truncate_ns(origin, tz.as_ref())?, 1),
+        TimeUnit::Microseconds => {
+            let origin = origin / 1_000;
+            (
+                origin,
+                options.every.truncate_us(origin, tz.as_ref())?,
+                1_000,
+            )
+        },
+        TimeUnit::Milliseconds => {
+            let origin = origin / 1_000_000;
+            (
+                origin,
+                options.every.truncate_ms(origin, tz.as_ref())?,
+                1_000_000,
+            )
+        },
+    };
+    Ok(Duration::from_nsecs((origin - truncated) * ns_factor))
+}
+
+/// Map a time onto an axis that only counts business days, Monday to Friday, so that windows of
+/// whole days on it span business days. Weekend times are moved to the last instant of the
+/// Friday before them, `day` is the length of a day in the unit of `t`.
+fn to_business_time(t: i64, day: i64) -> i64 {
+    let mut days = t.div_euclid(day);
+    let mut time_of_day = t.rem_euclid(day);
+    // The epoch is a Thursday, count from the Monday before it.
+    let weekday = (days + 3).rem_euclid(7);
+    if weekday >= 5 {
+        days -= weekday - 4;
+        time_of_day = day - 1;
+    }
+    let days_since_monday = days + 3;
+    let business_days = days_since_monday.div_euclid(7) * 5 + days_since_monday.rem_euclid(7);
+    business_days * day + time_of_day
+}
+
+/// Inverse of [`to_business_time`].
+fn from_business_time(t: i64, day: i64) -> i64 {
+    let business_days = t.div_euclid(day);
+    let days = business_days.div_euclid(5) * 7 + business_days.rem_euclid(5) - 3;
+    days * day + t.rem_euclid(day)
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DynamicGroupOptions {
@@ -33,6 +97,26 @@ pub struct DynamicGroupOptions {
     pub include_boundaries: bool,
     pub closed_window: ClosedWindow,
     pub start_by: StartBy,
+    /// Align the window boundaries to this point in time instead of to the
+    /// epoch, e.g. a timestamp at 09:30 with `every="1h"` starts windows at
+    /// half past the hour. Expressed in nanoseconds since the epoch, or as an
+    /// index value for integer index columns. Takes precedence over `offset`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub origin: Option<i64>,
+    /// How calendar windows are laid out when the first window starts on a
+    /// day that doesn't exist in every month. If `None`, every window starts
+    /// `every` after the start of the previous one.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub month_end_policy: Option<MonthEndPolicy>,
+    /// Only count business days, Monday to Friday, in `every`, `period` and
+    /// `offset`, which must then be whole days. Weekend rows belong to the
+    /// window of the Friday before them.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub business_days: bool,
+    /// Shift the label of every window by this duration, e.g. `-1h` with
+    /// `Label::Right` labels hourly windows by the hour they start in.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub label_offset: Option<Duration>,
 }
 
 impl Default for DynamicGroupOptions {
@@ -46,10 +130,59 @@ impl Default for DynamicGroupOptions {
             include_boundaries: false,
             closed_window: ClosedWindow::Left,
             start_by: Default::default(),
+            origin: None,
+            month_end_policy: None,
+            business_days: false,
+            label_offset: None,
         }
     }
 }
 
+impl DynamicGroupOptions {
+    /// Set `every` and `period` from a resampling frequency.
+    ///
+    /// Next to the regular duration strings (see [`Duration::parse`]) this
+    /// accepts period-end aliases that label each window by its last calendar
+    /// day:
+    ///
+    /// * `"{n}mo_end"`: month end
+    /// * `"{n}q_end"`: quarter end
+    /// * `"{n}y_end"`: year end
+    ///
+    /// and business-day aliases, `"{n}bd"`, of which the windows span `n`
+    /// business days, see [`DynamicGroupOptions::business_days`].
+    pub fn with_frequency(mut self, frequency: &str) -> PolarsResult<Self> {
+        if let Some(n) = frequency.strip_suffix("bd") {
+            let n = if n.is_empty() {
+                1
+            } else {
+                n.parse::<i64>().ok().filter(|n| *n > 0).ok_or_else(
+                    || polars_err!(InvalidOperation: "invalid business-day frequency '{}'", frequency),
+                )?
+            };
+            self.every = Duration::from_days(n);
+            self.period = self.every;
+            self.business_days = true;
+            return Ok(self);
+        }
+        let (every, label) = match frequency.strip_suffix("_end") {
+            Some(every) => {
+                let every = Duration::try_parse(every)?;
+                polars_ensure!(
+                    every.months_only(),
+                    InvalidOperation: "period-end frequency '{}' must be a whole number of months, quarters or years", frequency
+                );
+                (every, Label::PeriodEnd)
+            },
+            None => (Duration::try_parse(frequency)?, self.label),
+        };
+        self.every = every;
+        self.period = every;
+        self.label = label;
+        Ok(self)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RollingGroupOptions {
@@ -274,9 +407,48 @@ impl Wrap<&DataFrame> {
         // A requirement for the index so we can set this such that downstream code has this info.
         dt.set_sorted_flag(IsSorted::Ascending);
 
-        let w = Window::new(options.every, options.period, options.offset);
         let dt = dt.datetime().unwrap();
         let tz = dt.time_zone();
+        let day = match tu {
+            TimeUnit::Nanoseconds => NS_DAY,
+            TimeUnit::Microseconds => NS_DAY / 1_000,
+            TimeUnit::Milliseconds => NS_DAY / 1_000_000,
+        };
+        if options.business_days {
+            polars_ensure!(
+                tz.is_none(),
+                InvalidOperation: "business-day windows are not supported for time zone aware index columns"
+            );
+            let whole_days = |d: &Duration| d.months() == 0 && d.weeks() == 0 && d.is_full_days();
+            polars_ensure!(
+                whole_days(&options.every) && whole_days(&options.period) && whole_days(&options.offset),
+                InvalidOperation: "business-day windows require 'every', 'period' and 'offset' in whole days"
+            );
+            polars_ensure!(
+                options.start_by.weekday().is_none(),
+                InvalidOperation: "business-day windows cannot start by a weekday"
+            );
+        }
+        let offset = match options.origin {
+            Some(origin) if options.business_days => {
+                origin_offset(options, to_business_time(origin, NS_DAY), tu, tz)?
+            },
+            Some(origin) => origin_offset(options, origin, tu, tz)?,
+            None => options.offset,
+        };
+        let business_ts = options.business_days.then(|| {
+            dt.downcast_iter()
+                .next()
+                .unwrap()
+                .values()
+                .iter()
+                .map(|t| to_business_time(*t, day))
+                .collect_vec()
+        });
+        let mut w = Window::new(options.every, options.period, offset);
+        if let Some(policy) = options.month_end_policy {
+            w = w.with_month_end_policy(policy);
+        }
 
         let mut lower_bound = None;
         let mut upper_bound = None;
@@ -290,7 +462,7 @@ impl Wrap<&DataFrame> {
         }
         if options.label == Label::Left {
             include_lower_bound = true;
-        } else if options.label == Label::Right {
+        } else if matches!(options.label, Label::Right | Label::PeriodEnd) {
             include_upper_bound = true;
         }
 
@@ -307,9 +479,9 @@ impl Wrap<&DataFrame> {
                 _ => unreachable!(),
             };
 
+        let vals = dt.downcast_iter().next().unwrap();
+        let ts = business_ts.as_deref().unwrap_or(vals.values().as_slice());
         let groups = if group_by.is_none() {
-            let vals = dt.downcast_iter().next().unwrap();
-            let ts = vals.values().as_slice();
             let (groups, lower, upper) = group_by_windows(
                 w,
                 ts,
@@ -326,9 +498,6 @@ impl Wrap<&DataFrame> {
                 rolling: false,
             })
         } else {
-            let vals = dt.downcast_iter().next().unwrap();
-            let ts = vals.values().as_slice();
-
             let groups = group_by.as_ref().unwrap();
 
             let iter = groups.par_iter().map(|[start, len]| {
@@ -383,6 +552,43 @@ impl Wrap<&DataFrame> {
         let dt = unsafe { dt.clone().into_series().agg_first(&groups) };
         let mut dt = dt.datetime().unwrap().as_ref().clone();
 
+        let parsed_tz = parse_tz(tz);
+        let add = match tu {
+            TimeUnit::Nanoseconds => Duration::add_ns,
+            TimeUnit::Microseconds => Duration::add_us,
+            TimeUnit::Milliseconds => Duration::add_ms,
+        };
+        // The period end is the last day of a calendar window, and the upper
+        // bound itself for windows with a time component.
+        let period_end = if options.label == Label::PeriodEnd {
+            let upper = upper_bound.as_ref().unwrap();
+            Some(if options.business_days {
+                upper
+                    .iter()
+                    .map(|t| from_business_time(t - day, day))
+                    .collect_vec()
+            } else if options.every.is_full_days() {
+                let one_day_back = -Duration::from_days(1);
+                upper
+                    .iter()
+                    .map(|t| add(&one_day_back, *t, parsed_tz.as_ref()))
+                    .collect::<PolarsResult<Vec<_>>>()?
+            } else {
+                upper.clone()
+            })
+        } else {
+            None
+        };
+        if options.business_days {
+            for t in lower_bound
+                .iter_mut()
+                .chain(upper_bound.iter_mut())
+                .flatten()
+            {
+                *t = from_business_time(*t, day);
+            }
+        }
+
         let lower =
             lower_bound.map(|lower| Int64Chunked::new_vec(PlSmallStr::from_static(LB_NAME), lower));
         let upper =
@@ -400,6 +606,22 @@ impl Wrap<&DataFrame> {
                 upper.set_sorted_flag(IsSorted::Ascending)
             }
             dt = upper.with_name(dt.name().clone());
+        } else if let Some(period_end) = period_end {
+            let mut period_end = Int64Chunked::new_vec(dt.name().clone(), period_end);
+            if group_by.is_none() {
+                period_end.set_sorted_flag(IsSorted::Ascending)
+            }
+            dt = period_end;
+        }
+        if let Some(label_offset) = &options.label_offset {
+            let sorted = dt.is_sorted_flag();
+            let mut shifted = dt
+                .into_no_null_iter()
+                .map(|t| add(label_offset, t, parsed_tz.as_ref()))
+                .collect::<PolarsResult<Vec<_>>>()
+                .map(|v| Int64Chunked::new_vec(dt.name().clone(), v))?;
+            shifted.set_sorted_flag(sorted);
+            dt = shifted;
         }
 
         let mut bounds = vec![];
@@ -5,6 +5,8 @@ pub mod chunkedarray;
 mod date_range;
 #[cfg(feature = "timezones")]
 mod dst_offset;
+#[cfg(feature = "temporal_gaps")]
+mod gaps;
 mod group_by;
 #[cfg(feature = "month_end")]
 mod month_end;
@@ -17,6 +19,8 @@ pub mod prelude;
 pub mod replace;
 mod round;
 pub mod series;
+#[cfg(feature = "shift_by_time")]
+mod shift_by_time;
 mod truncate;
 mod upsample;
 mod utils;
@@ -27,6 +31,8 @@ pub use base_utc_offset::*;
 pub use date_range::*;
 #[cfg(feature = "timezones")]
 pub use dst_offset::*;
+#[cfg(feature = "temporal_gaps")]
+pub use gaps::*;
 #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
 pub use group_by::dynamic::*;
 #[cfg(feature = "month_end")]
@@ -38,6 +44,8 @@ pub use offset_by::*;
 #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
 pub use replace::*;
 pub use round::*;
+#[cfg(feature = "shift_by_time")]
+pub use shift_by_time::*;
 #[cfg(feature = "dtype-date")]
 pub use truncate::*;
 pub use upsample::*;
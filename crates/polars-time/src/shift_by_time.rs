@@ -0,0 +1,97 @@
+use arrow::legacy::time_zone::Tz;
+use polars_core::prelude::*;
+
+use crate::Duration;
+
+fn source_rows(
+    by: &Int64Chunked,
+    period: &Duration,
+    tu: TimeUnit,
+    tz: Option<&Tz>,
+) -> PolarsResult<IdxCa> {
+    let add = match tu {
+        TimeUnit::Nanoseconds => Duration::add_ns,
+        TimeUnit::Microseconds => Duration::add_us,
+        TimeUnit::Milliseconds => Duration::add_ms,
+    };
+    let back = -*period;
+
+    let mut rows = PlHashMap::with_capacity(by.len());
+    for (i, t) in by.iter().enumerate() {
+        if let Some(t) = t {
+            polars_ensure!(
+                rows.insert(t, i as IdxSize).is_none(),
+                InvalidOperation: "`shift_by_time` expects unique values in `by`, consider \
+                using `over` to shift within groups"
+            );
+        }
+    }
+    by.iter()
+        .map(|t| match t {
+            Some(t) => Ok(rows.get(&add(&back, t, tz)?).copied()),
+            None => Ok(None),
+        })
+        .collect()
+}
+
+/// Shift the values of `s` by the time `period` according to `by`: every row gets the value of
+/// the row whose `by` value is exactly `period` earlier, or null if there is no such row.
+///
+/// A negative `period` shifts the other way. The values of `by` must be unique, but don't have
+/// to be sorted.
+pub fn impl_shift_by_time(s: &Series, by: &Series, period: &Duration) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.len() == by.len(),
+        length_mismatch = "shift_by_time",
+        s.len(),
+        by.len()
+    );
+    polars_ensure!(
+        !period.parsed_int,
+        InvalidOperation: "`period` may not be a parsed integer (i.e. use '2d', not '2i')"
+    );
+    let idx = match by.dtype() {
+        DataType::Date => {
+            polars_ensure!(
+                period.is_full_days(),
+                InvalidOperation: "expected a whole number of days for a Date column, got {}", period
+            );
+            let by = by.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+            source_rows(&by.datetime()?.phys, period, TimeUnit::Milliseconds, None)?
+        },
+        DataType::Datetime(tu, tz) => {
+            let by = &by.datetime()?.phys;
+            match tz {
+                #[cfg(feature = "timezones")]
+                Some(tz) => source_rows(by, period, *tu, tz.parse::<Tz>().ok().as_ref())?,
+                _ => source_rows(by, period, *tu, None)?,
+            }
+        },
+        dt => polars_bail!(
+            InvalidOperation: "expected `by` to be Date or Datetime, got {}", dt
+        ),
+    };
+    // SAFETY: the indices are rows of `by`, which has the length of `s`.
+    Ok(unsafe { s.take_unchecked(&idx) })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_shift_by_time() -> PolarsResult<()> {
+        let by = Series::new("t".into(), [3i32, 0, 1, 5]).cast(&DataType::Date)?;
+        let s = Series::new("a".into(), [30i32, 0, 10, 50]);
+
+        let out = impl_shift_by_time(&s, &by, &Duration::parse("1d"))?;
+        assert_eq!(Vec::from(out.i32()?), [None, None, Some(0), None]);
+        let out = impl_shift_by_time(&s, &by, &Duration::parse("-2d"))?;
+        assert_eq!(Vec::from(out.i32()?), [Some(50), None, Some(30), None]);
+
+        let by = Series::new("t".into(), [0i32, 0]).cast(&DataType::Date)?;
+        let s = Series::new("a".into(), [1i32, 2]);
+        assert!(impl_shift_by_time(&s, &by, &Duration::parse("1d")).is_err());
+        Ok(())
+    }
+}
@@ -22,6 +22,7 @@ use serde::{Deserialize, Serialize};
 use super::calendar::{
     NS_DAY, NS_HOUR, NS_MICROSECOND, NS_MILLISECOND, NS_MINUTE, NS_SECOND, NS_WEEK,
 };
+use super::group_by::MonthEndPolicy;
 #[cfg(feature = "timezones")]
 use crate::utils::{localize_datetime_opt, try_localize_datetime, unlocalize_datetime};
 use crate::windows::calendar::{DAYS_PER_MONTH, is_leap_year};
@@ -409,6 +410,19 @@ impl Duration {
         }
     }
 
+    /// Sum of two durations of the same sign.
+    pub(crate) fn add_same_sign(&self, other: &Duration) -> Self {
+        debug_assert_eq!(self.negative, other.negative);
+        Self {
+            months: self.months + other.months,
+            weeks: self.weeks + other.weeks,
+            days: self.days + other.days,
+            nsecs: self.nsecs + other.nsecs,
+            negative: self.negative,
+            parsed_int: self.parsed_int && other.parsed_int,
+        }
+    }
+
     /// `true` if zero duration.
     pub fn is_zero(&self) -> bool {
         self.months == 0 && self.weeks == 0 && self.days == 0 && self.nsecs == 0
@@ -490,7 +504,12 @@ impl Duration {
     }
 
     #[doc(hidden)]
-    fn add_month(ts: NaiveDateTime, n_months: i64, negative: bool) -> NaiveDateTime {
+    fn add_month(
+        ts: NaiveDateTime,
+        n_months: i64,
+        negative: bool,
+        policy: MonthEndPolicy,
+    ) -> NaiveDateTime {
         let mut months = n_months;
         if negative {
             months = -months;
@@ -521,6 +540,11 @@ impl Duration {
 
         if day > last_day_of_month {
             day = last_day_of_month
+        } else if policy == MonthEndPolicy::MonthEnd
+            && ts.day()
+                == DAYS_PER_MONTH[is_leap_year(ts.year()) as usize][ts.month0() as usize] as u32
+        {
+            day = last_day_of_month
         }
 
         // Retrieve the original time and construct a data
@@ -898,7 +922,7 @@ impl Duration {
                 },
                 _ => timestamp_to_datetime(t),
             };
-            let dt = Self::add_month(ts, d.months, d.negative);
+            let dt = Self::add_month(ts, d.months, d.negative, MonthEndPolicy::Clamp);
             t = match tz {
                 #[cfg(feature = "timezones")]
                 // for UTC, use fastpath below (same as naive)
@@ -995,6 +1019,108 @@ impl Duration {
         let nsecs = if d.negative { -d.nsecs } else { d.nsecs };
         Ok(new_t? + nsecs / 1_000_000)
     }
+
+    /// Add the duration with calendar arithmetic on the local time of `tz`.
+    ///
+    /// Different from [`Duration::add_ns`], the months, weeks and days are added at once and local
+    /// times that don't resolve to a single instant because of DST don't raise: ambiguous times
+    /// resolve to the earliest instant, and non-existent times are shifted forward by the length
+    /// of the gap.
+    fn add_calendar_impl<F, G, J>(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        policy: MonthEndPolicy,
+        nsecs_to_unit: F,
+        timestamp_to_datetime: G,
+        datetime_to_timestamp: J,
+    ) -> PolarsResult<i64>
+    where
+        F: Fn(i64) -> i64,
+        G: Fn(i64) -> NaiveDateTime,
+        J: Fn(NaiveDateTime) -> i64,
+    {
+        let d = self;
+        let mut dt = match tz {
+            #[cfg(feature = "timezones")]
+            Some(tz) if tz != &chrono_tz::UTC => unlocalize_datetime(timestamp_to_datetime(t), tz),
+            _ => timestamp_to_datetime(t),
+        };
+        if d.months > 0 {
+            dt = Self::add_month(dt, d.months, d.negative, policy);
+        }
+        let days = d.weeks * 7 + d.days;
+        dt += chrono::TimeDelta::days(if d.negative { -days } else { days });
+
+        let t = match tz {
+            #[cfg(feature = "timezones")]
+            Some(tz) if tz != &chrono_tz::UTC => {
+                match try_localize_datetime(dt, tz, Ambiguous::Earliest, NonExistent::Null)? {
+                    Some(dt) => datetime_to_timestamp(dt),
+                    None => {
+                        // Use the offset from before the transition.
+                        use chrono::{Offset, TimeZone as _};
+                        let offset = tz
+                            .offset_from_utc_datetime(&(dt - chrono::TimeDelta::days(1)))
+                            .fix()
+                            .local_minus_utc();
+                        datetime_to_timestamp(dt - chrono::TimeDelta::seconds(offset as i64))
+                    },
+                }
+            },
+            _ => datetime_to_timestamp(dt),
+        };
+        let nsecs = if d.negative { -d.nsecs } else { d.nsecs };
+        Ok(t + nsecs_to_unit(nsecs))
+    }
+
+    pub fn add_calendar_ns(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        policy: MonthEndPolicy,
+    ) -> PolarsResult<i64> {
+        self.add_calendar_impl(
+            t,
+            tz,
+            policy,
+            |nsecs| nsecs,
+            timestamp_ns_to_datetime,
+            datetime_to_timestamp_ns,
+        )
+    }
+
+    pub fn add_calendar_us(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        policy: MonthEndPolicy,
+    ) -> PolarsResult<i64> {
+        self.add_calendar_impl(
+            t,
+            tz,
+            policy,
+            |nsecs| nsecs / 1000,
+            timestamp_us_to_datetime,
+            datetime_to_timestamp_us,
+        )
+    }
+
+    pub fn add_calendar_ms(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        policy: MonthEndPolicy,
+    ) -> PolarsResult<i64> {
+        self.add_calendar_impl(
+            t,
+            tz,
+            policy,
+            |nsecs| nsecs / 1_000_000,
+            timestamp_ms_to_datetime,
+            datetime_to_timestamp_ms,
+        )
+    }
 }
 
 impl Mul<i64> for Duration {
@@ -28,6 +28,10 @@ pub enum Label {
     Left,
     Right,
     DataPoint,
+    /// The last calendar day of the window, e.g. month-end dates for monthly
+    /// windows. Windows with a time component, e.g. `every="1h"`, have no last
+    /// day and are labelled by their upper bound.
+    PeriodEnd,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoStaticStr)]
@@ -67,6 +71,24 @@ impl StartBy {
     }
 }
 
+/// How calendar windows (`every` in months, quarters or years) are laid out when the first
+/// window starts on a day that doesn't exist in every month, e.g. on the 31st.
+///
+/// Without a policy, every window starts `every` after the start of the previous one, so a day
+/// clamped to the end of a short month carries over to the windows after it, e.g. windows
+/// starting on Jan 31 continue with Feb 29 and Mar 29.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[strum(serialize_all = "snake_case")]
+pub enum MonthEndPolicy {
+    /// Keep the day of the month of the first window, clamped to the last day of short months.
+    /// E.g. windows starting on Jan 31 continue with Feb 29, Mar 31 and Apr 30.
+    Clamp,
+    /// Like `Clamp`, but if the first window starts on the last day of its month, every window
+    /// starts on the last day of its month. E.g. Feb 29 continues with Mar 31 and Apr 30.
+    MonthEnd,
+}
+
 #[allow(clippy::too_many_arguments)]
 fn update_groups_and_bounds(
     bounds_iter: BoundsIter<'_>,
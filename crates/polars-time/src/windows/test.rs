@@ -926,3 +926,96 @@ fn test_group_by_windows_offsets_3776() {
     );
     assert_eq!(groups, [[0, 1], [1, 1], [2, 1]]);
 }
+
+#[test]
+fn test_group_by_windows_month_end_policy() {
+    let ts = |(m, d)| {
+        NaiveDate::from_ymd_opt(2024, m, d)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_nanos_opt()
+            .unwrap()
+    };
+    let group_by = |dates: &[(u32, u32)], policy: Option<MonthEndPolicy>| {
+        let time = dates.iter().copied().map(ts).collect::<Vec<_>>();
+        let mut window = Window::new(
+            Duration::parse("1mo"),
+            Duration::parse("1mo"),
+            Duration::parse("0ns"),
+        );
+        if let Some(policy) = policy {
+            window = window.with_month_end_policy(policy);
+        }
+        let (groups, lower, _) = group_by_windows(
+            window,
+            &time,
+            ClosedWindow::Left,
+            TimeUnit::Nanoseconds,
+            &None,
+            true,
+            false,
+            StartBy::DataPoint,
+        );
+        (groups, lower)
+    };
+
+    // Without a policy, the day clamped to the end of February carries over.
+    let dates = [(1, 31), (2, 29), (3, 30), (3, 31), (4, 30)];
+    let (groups, lower) = group_by(&dates, None);
+    assert_eq!(groups, [[0, 1], [1, 1], [2, 2], [4, 1]]);
+    assert_eq!(lower, [(1, 31), (2, 29), (3, 29), (4, 29)].map(ts));
+
+    // The day of the first window is kept after a short month.
+    let (groups, lower) = group_by(&dates, Some(MonthEndPolicy::Clamp));
+    assert_eq!(groups, [[0, 1], [1, 2], [3, 1], [4, 1]]);
+    assert_eq!(lower, [(1, 31), (2, 29), (3, 31), (4, 30)].map(ts));
+
+    let dates = [(2, 29), (3, 30), (3, 31), (4, 29), (4, 30)];
+    let (groups, lower) = group_by(&dates, Some(MonthEndPolicy::Clamp));
+    assert_eq!(groups, [[0, 1], [1, 2], [3, 2]]);
+    assert_eq!(lower, [(2, 29), (3, 29), (4, 29)].map(ts));
+
+    // Windows starting at a month end stay at month ends.
+    let (groups, lower) = group_by(&dates, Some(MonthEndPolicy::MonthEnd));
+    assert_eq!(groups, [[0, 2], [2, 2], [4, 1]]);
+    assert_eq!(lower, [(2, 29), (3, 31), (4, 30)].map(ts));
+}
+
+#[test]
+#[cfg(feature = "timezones")]
+fn test_group_by_windows_month_into_dst_gap() {
+    // 2024-02-10 02:30 EST and 2024-03-10 04:00 EDT, one month later 02:30 doesn't exist.
+    let utc = |m, d, h, min| {
+        NaiveDate::from_ymd_opt(2024, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_nanos_opt()
+            .unwrap()
+    };
+    let time = [utc(2, 10, 7, 30), utc(3, 10, 8, 0)];
+    let window = Window::new(
+        Duration::parse("1mo"),
+        Duration::parse("1mo"),
+        Duration::parse("0ns"),
+    )
+    .with_month_end_policy(MonthEndPolicy::Clamp);
+    let tz = TimeZone::opt_try_new(Some("America/New_York")).unwrap();
+    let (groups, lower, upper) = group_by_windows(
+        window,
+        &time,
+        ClosedWindow::Left,
+        TimeUnit::Nanoseconds,
+        &tz,
+        true,
+        true,
+        StartBy::DataPoint,
+    );
+    assert_eq!(groups, [[0, 1], [1, 1]]);
+    // The second window starts at 03:30 EDT, shifted forward by the length of the gap.
+    assert_eq!(lower, [utc(2, 10, 7, 30), utc(3, 10, 7, 30)]);
+    assert_eq!(upper, [utc(3, 10, 7, 30), utc(4, 10, 6, 30)]);
+}
@@ -48,6 +48,7 @@ pub struct Window {
     every: Duration,
     period: Duration,
     pub offset: Duration,
+    month_end_policy: Option<MonthEndPolicy>,
 }
 
 impl Window {
@@ -57,9 +58,51 @@ impl Window {
             every,
             period,
             offset,
+            month_end_policy: None,
         }
     }
 
+    /// Set how calendar windows are laid out when the first window starts on a day that doesn't
+    /// exist in every month.
+    pub fn with_month_end_policy(mut self, month_end_policy: MonthEndPolicy) -> Self {
+        self.month_end_policy = Some(month_end_policy);
+        self
+    }
+
+    /// Whether `every` has calendar months, of which the length depends on the month, and they
+    /// are laid out by a [`MonthEndPolicy`].
+    fn is_calendar(&self) -> bool {
+        self.month_end_policy.is_some() && self.every.months() > 0
+    }
+
+    /// The bounds of the `n`-th window after the window starting at `first`.
+    ///
+    /// Calendar windows are computed from the local time of `first` rather than from the start of
+    /// the previous window, so that a day clamped to the end of a short month doesn't carry over
+    /// to the windows after it.
+    fn nth_calendar_bounds(
+        &self,
+        first: i64,
+        n: i64,
+        tu: TimeUnit,
+        tz: Option<&Tz>,
+    ) -> PolarsResult<Bounds> {
+        let add = match tu {
+            TimeUnit::Nanoseconds => Duration::add_calendar_ns,
+            TimeUnit::Microseconds => Duration::add_calendar_us,
+            TimeUnit::Milliseconds => Duration::add_calendar_ms,
+        };
+        let policy = self.month_end_policy.unwrap_or(MonthEndPolicy::Clamp);
+        let every = self.every * n;
+        let start = add(&every, first, tz, policy)?;
+        let stop = if self.period.negative {
+            add(&self.period, start, tz, policy)?
+        } else {
+            add(&every.add_same_sign(&self.period), first, tz, policy)?
+        };
+        Ok(Bounds::new(start, stop))
+    }
+
     /// Truncate the given ns timestamp by the window boundary.
     pub fn truncate_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
         self.every.truncate_ns(t, tz)
@@ -188,6 +231,10 @@ pub struct BoundsIter<'a> {
     boundary: Bounds,
     // boundary per window iterator
     bi: Bounds,
+    // start of the first window and the number of windows after it, calendar windows are
+    // computed from these
+    first_start: i64,
+    n: i64,
     tu: TimeUnit,
     tz: Option<&'a Tz>,
 }
@@ -201,6 +248,8 @@ impl<'a> BoundsIter<'a> {
         start_by: StartBy,
     ) -> PolarsResult<Self> {
         let bi = match start_by {
+            // The stop of calendar windows is set below.
+            StartBy::DataPoint if window.is_calendar() => boundary,
             StartBy::DataPoint => {
                 let mut boundary = boundary;
                 let offset_fn = match tu {
@@ -306,10 +355,17 @@ impl<'a> BoundsIter<'a> {
                 }
             },
         };
+        let bi = if window.is_calendar() {
+            window.nth_calendar_bounds(bi.start, 0, tu, tz)?
+        } else {
+            bi
+        };
         Ok(Self {
             window,
             boundary,
             bi,
+            first_start: bi.start,
+            n: 0,
             tu,
             tz,
         })
@@ -322,6 +378,14 @@ impl Iterator for BoundsIter<'_> {
     fn next(&mut self) -> Option<Self::Item> {
         if self.bi.start < self.boundary.stop {
             let out = self.bi;
+            if self.window.is_calendar() {
+                self.n += 1;
+                self.bi = self
+                    .window
+                    .nth_calendar_bounds(self.first_start, self.n, self.tu, self.tz)
+                    .unwrap();
+                return Some(out);
+            }
             match self.tu {
                 // TODO: find some way to propagate error instead of unwrapping?
                 // Issue is that `next` needs to return `Option`.
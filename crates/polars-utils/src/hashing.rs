@@ -177,3 +177,52 @@ impl<T: DirtyHash> DirtyHash for Option<T> {
         self.as_ref().map(|s| s.dirty_hash()).unwrap_or(0)
     }
 }
+
+/// The 32-bit x86 variant of MurmurHash3, the hash with which Hive, Spark and Iceberg assign rows
+/// to buckets.
+///
+/// Unlike the other hashes in this module its output is specified, and must never change.
+pub fn murmur3_32(bytes: &[u8], seed: u32) -> u32 {
+    const C1: u32 = 0xcc9e_2d51;
+    const C2: u32 = 0x1b87_3593;
+
+    let mix = |k: u32| k.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+
+    let mut h = seed;
+    let mut chunks = bytes.chunks_exact(4);
+    for chunk in &mut chunks {
+        h ^= mix(u32::from_le_bytes(chunk.try_into().unwrap()));
+        h = h.rotate_left(13).wrapping_mul(5).wrapping_add(0xe654_6b64);
+    }
+
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        let mut k = 0u32;
+        for (i, b) in tail.iter().enumerate() {
+            k |= (*b as u32) << (8 * i);
+        }
+        h ^= mix(k);
+    }
+
+    h ^= bytes.len() as u32;
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^ (h >> 16)
+}
+
+#[cfg(test)]
+mod test {
+    use super::murmur3_32;
+
+    #[test]
+    fn test_murmur3_32() {
+        assert_eq!(murmur3_32(b"", 0), 0);
+        assert_eq!(murmur3_32(b"", 1), 0x514e_28b7);
+        assert_eq!(murmur3_32(b"hello", 0), 0x248b_fa47);
+        // The reference values of the Iceberg specification.
+        assert_eq!(murmur3_32(&34i64.to_le_bytes(), 0), 2017239379);
+        assert_eq!(murmur3_32(b"iceberg", 0), 1210000089);
+    }
+}
@@ -1,4 +1,5 @@
 use std::cell::RefCell;
+use std::sync::{LazyLock, Mutex};
 
 use regex::{Regex, RegexBuilder};
 
@@ -14,10 +15,45 @@ fn get_size_limit() -> Option<usize> {
     )
 }
 
+fn get_global_cache_size() -> usize {
+    std::env::var("POLARS_REGEX_CACHE_SIZE")
+        .ok()
+        .filter(|l| !l.is_empty())
+        .map(|l| l.parse().expect("invalid POLARS_REGEX_CACHE_SIZE"))
+        .unwrap_or(512)
+}
+
 // Regex compilation is really heavy, and the resulting regexes can be large as
 // well, so we should have a good caching scheme.
 //
-// TODO: add larger global cache which has time-based flush.
+// Every thread has a small cache that it can use without synchronization. On
+// a miss it falls back to a larger cache shared by all threads, so that
+// expressions with a pattern per row (e.g. `str.contains(col("pattern"))`)
+// compile every distinct pattern once, instead of once per thread.
+static GLOBAL_REGEX_CACHE: LazyLock<Option<Mutex<LruCache<String, Regex>>>> = LazyLock::new(|| {
+    let size = get_global_cache_size();
+    (size > 0).then(|| Mutex::new(LruCache::with_capacity(size)))
+});
+
+fn global_get(re: &str) -> Option<Regex> {
+    let cache = GLOBAL_REGEX_CACHE.as_ref()?;
+    cache.lock().unwrap().get(re).cloned()
+}
+
+fn global_insert(re: &str, regex: &Regex) {
+    if let Some(cache) = GLOBAL_REGEX_CACHE.as_ref() {
+        cache.lock().unwrap().insert(re.to_string(), regex.clone());
+    }
+}
+
+/// Remove all compiled regular expressions from the cache shared between
+/// threads.
+pub fn clear_global_regex_cache() {
+    if let Some(cache) = GLOBAL_REGEX_CACHE.as_ref() {
+        let mut cache = cache.lock().unwrap();
+        while cache.pop_lru().is_some() {}
+    }
+}
 
 /// A cache for compiled regular expressions.
 pub struct RegexCache {
@@ -35,6 +71,10 @@ impl RegexCache {
 
     pub fn compile(&mut self, re: &str) -> Result<&Regex, regex::Error> {
         let r = self.cache.try_get_or_insert_with(re, |re| {
+            if let Some(regex) = global_get(re) {
+                return Ok(regex);
+            }
+
             // We do this little loop to only check POLARS_REGEX_SIZE_LIMIT when
             // a regex fails to compile due to the size limit.
             loop {
@@ -51,7 +91,12 @@ impl RegexCache {
                         }
                         break err;
                     },
-                    r => break r,
+                    r => {
+                        if let Ok(regex) = &r {
+                            global_insert(re, regex);
+                        }
+                        break r;
+                    },
                 };
             }
         });
@@ -0,0 +1,10 @@
+//! Declarative validation of the columns of a [`LazyFrame`](polars_lazy::frame::LazyFrame).
+//!
+//! A [`Validator`] holds a list of checks per column. [`Validator::validate`] runs all of
+//! them in a single lazy query and returns a [`ValidationReport`] with the number of
+//! violating rows and a sample of them for every failed check.
+mod report;
+mod validator;
+
+pub use report::{ValidationReport, Violation};
+pub use validator::{Check, Validator};
@@ -0,0 +1,55 @@
+use std::fmt::{Display, Formatter};
+
+use polars_core::prelude::*;
+
+/// A check that failed.
+#[derive(Clone, Debug)]
+pub struct Violation {
+    pub column: PlSmallStr,
+    /// Description of the failed check.
+    pub check: String,
+    /// Number of rows that violate the check. Checks on the schema, e.g. a data type
+    /// mismatch, count all rows.
+    pub count: IdxSize,
+    /// Up to `sample_size` violating rows, with their row index and the value of the column.
+    /// `None` for checks on the schema.
+    pub sample: Option<DataFrame>,
+}
+
+/// The result of [`Validator::validate`](crate::Validator::validate).
+#[derive(Clone, Debug)]
+pub struct ValidationReport {
+    pub n_rows: IdxSize,
+    pub violations: Vec<Violation>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Returns an error that lists all violations if there are any.
+    pub fn ensure_valid(&self) -> PolarsResult<()> {
+        polars_ensure!(self.is_valid(), ComputeError: "validation failed:\n{}", self);
+        Ok(())
+    }
+}
+
+impl Display for ValidationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_valid() {
+            return write!(f, "all checks passed on {} rows", self.n_rows);
+        }
+        for (i, v) in self.violations.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(
+                f,
+                "column '{}': {} failed for {} of {} rows",
+                v.column, v.check, v.count, self.n_rows
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,327 @@
+use std::fmt::{Display, Formatter};
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+
+use crate::report::{ValidationReport, Violation};
+
+const ROW_INDEX: &str = "__polars_validate_row";
+const LEN: &str = "__polars_validate_len";
+
+/// A check on the values of a column.
+#[derive(Clone)]
+pub enum Check {
+    /// The column has this data type.
+    Dtype(DataType),
+    /// The column has no null values.
+    NotNull,
+    /// The values lie between `min` and `max`, both inclusive. Nulls are not checked.
+    Range {
+        min: Option<Expr>,
+        max: Option<Expr>,
+    },
+    /// The values match this regex. Nulls are not checked.
+    Pattern(PlSmallStr),
+    /// The non-null values are unique.
+    Unique,
+    /// The non-null values occur in `column` of `other`, like a foreign key.
+    References {
+        other: LazyFrame,
+        column: PlSmallStr,
+    },
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Check::Dtype(dtype) => write!(f, "dtype {dtype}"),
+            Check::NotNull => write!(f, "not null"),
+            Check::Range { min, max } => {
+                write!(f, "range [")?;
+                if let Some(min) = min {
+                    write!(f, "{min}")?;
+                }
+                write!(f, ", ")?;
+                if let Some(max) = max {
+                    write!(f, "{max}")?;
+                }
+                write!(f, "]")
+            },
+            Check::Pattern(pattern) => write!(f, "pattern '{pattern}'"),
+            Check::Unique => write!(f, "unique"),
+            Check::References { column, .. } => write!(f, "references '{column}'"),
+        }
+    }
+}
+
+/// A set of checks that are run on a [`LazyFrame`] with [`Validator::validate`].
+///
+/// # Example
+///
+/// ```rust
+/// use polars_core::prelude::*;
+/// use polars_lazy::prelude::*;
+/// use polars_validate::{Check, Validator};
+///
+/// fn example(lf: LazyFrame) -> PolarsResult<()> {
+///     Validator::new()
+///         .check("id", Check::Unique)
+///         .check("age", Check::Range { min: Some(lit(0)), max: Some(lit(150)) })
+///         .check("email", Check::Pattern("^[^@]+@[^@]+$".into()))
+///         .validate(lf)?
+///         .ensure_valid()
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Validator {
+    checks: Vec<(PlSmallStr, Check)>,
+    sample_size: usize,
+}
+
+impl Default for Validator {
+    fn default() -> Self {
+        Self {
+            checks: vec![],
+            sample_size: 10,
+        }
+    }
+}
+
+impl Validator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a check on `column`.
+    pub fn check(mut self, column: impl Into<PlSmallStr>, check: Check) -> Self {
+        self.checks.push((column.into(), check));
+        self
+    }
+
+    /// Set the maximum number of violating rows that are sampled per check. Defaults to 10.
+    pub fn with_sample_size(mut self, sample_size: usize) -> Self {
+        self.sample_size = sample_size;
+        self
+    }
+
+    /// Run all checks on `lf`.
+    ///
+    /// Checks on the schema are resolved without reading any data. All other checks are
+    /// evaluated in a single query, so sources are scanned once.
+    pub fn validate(&self, mut lf: LazyFrame) -> PolarsResult<ValidationReport> {
+        let schema = lf.collect_schema()?;
+        let mut lf = lf.with_row_index(ROW_INDEX, None);
+
+        let mut schema_violations = vec![];
+        let mut flags = vec![];
+        for (i, (name, check)) in self.checks.iter().enumerate() {
+            let Some(dtype) = schema.get(name) else {
+                schema_violations.push((name, "column exists".to_string()));
+                continue;
+            };
+            let column = col(name.clone());
+            let flag = match check {
+                Check::Dtype(expected) => {
+                    if dtype != expected {
+                        schema_violations.push((name, format!("{check} (found {dtype})")));
+                    }
+                    continue;
+                },
+                Check::NotNull => column.is_null(),
+                Check::Range { min, max } => {
+                    let mut flag = lit(false);
+                    if let Some(min) = min {
+                        flag = flag.or(column.clone().lt(min.clone()));
+                    }
+                    if let Some(max) = max {
+                        flag = flag.or(column.gt(max.clone()));
+                    }
+                    flag
+                },
+                Check::Pattern(pattern) => {
+                    if !dtype.is_string() {
+                        schema_violations.push((name, format!("{check} (found {dtype})")));
+                        continue;
+                    }
+                    column.str().contains(lit(pattern.as_str()), true).not()
+                },
+                Check::Unique => column.clone().is_not_null().and(column.is_duplicated()),
+                Check::References { other, column: key } => {
+                    let right_key = format!("__polars_validate_key_{i}");
+                    let found = format!("__polars_validate_found_{i}");
+                    let right = other
+                        .clone()
+                        .select([
+                            col(key.clone()).alias(right_key.as_str()),
+                            lit(true).alias(found.as_str()),
+                        ])
+                        .unique(Some(vec![right_key.clone()]), UniqueKeepStrategy::Any);
+                    lf = lf.join(
+                        right,
+                        [column.clone()],
+                        [col(right_key)],
+                        JoinArgs::new(JoinType::Left),
+                    );
+                    column.is_not_null().and(col(found).is_null())
+                },
+            };
+            flags.push((i, flag.alias(format!("__polars_validate_flag_{i}"))));
+        }
+
+        let n = Some(self.sample_size);
+        let mut aggs = vec![len().alias(LEN)];
+        for (i, _) in &flags {
+            let flag = col(format!("__polars_validate_flag_{i}"));
+            let name = &self.checks[*i].0;
+            aggs.extend([
+                flag.clone().sum().alias(format!("count_{i}")),
+                col(ROW_INDEX)
+                    .filter(flag.clone())
+                    .head(n)
+                    .implode()
+                    .alias(format!("rows_{i}")),
+                col(name.clone())
+                    .filter(flag)
+                    .head(n)
+                    .implode()
+                    .alias(format!("values_{i}")),
+            ]);
+        }
+        let out = lf
+            .with_columns(
+                flags
+                    .iter()
+                    .map(|(_, flag)| flag.clone())
+                    .collect::<Vec<_>>(),
+            )
+            .select(aggs)
+            .collect()?;
+
+        let get_idx = |name: &str| -> PolarsResult<IdxSize> {
+            let s = out.column(name)?.cast(&IDX_DTYPE)?;
+            Ok(s.as_materialized_series().idx()?.get(0).unwrap_or(0))
+        };
+        let n_rows = get_idx(LEN)?;
+
+        let mut violations = schema_violations
+            .into_iter()
+            .map(|(name, check)| Violation {
+                column: name.clone(),
+                check,
+                count: n_rows,
+                sample: None,
+            })
+            .collect::<Vec<_>>();
+        for (i, _) in &flags {
+            let count = get_idx(&format!("count_{i}"))?;
+            if count == 0 {
+                continue;
+            }
+            let (name, check) = &self.checks[*i];
+            let sample_column = |prefix: &str, name: PlSmallStr| -> PolarsResult<Column> {
+                let s = out
+                    .column(&format!("{prefix}_{i}"))?
+                    .as_materialized_series()
+                    .list()?
+                    .get_as_series(0)
+                    .unwrap();
+                Ok(s.with_name(name).into_column())
+            };
+            let sample = DataFrame::new(vec![
+                sample_column("rows", PlSmallStr::from_static("row_index"))?,
+                sample_column("values", name.clone())?,
+            ])?
+            .sort(["row_index"], Default::default())?;
+            violations.push(Violation {
+                column: name.clone(),
+                check: check.to_string(),
+                count,
+                sample: Some(sample),
+            });
+        }
+
+        Ok(ValidationReport { n_rows, violations })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_validate() -> PolarsResult<()> {
+        let users = df![
+            "id" => [1, 2, 2, 4],
+            "age" => [Some(30), Some(-1), None, Some(200)],
+            "email" => [Some("a@x.com"), Some("bogus"), None, Some("d@x.com")],
+            "country" => [Some("nl"), Some("de"), Some("xx"), None],
+        ]?;
+        let countries = df!["code" => ["nl", "de", "nl"]]?;
+
+        let report = Validator::new()
+            .check("id", Check::Unique)
+            .check("id", Check::Dtype(DataType::Int64))
+            .check("age", Check::NotNull)
+            .check(
+                "age",
+                Check::Range {
+                    min: Some(lit(0)),
+                    max: Some(lit(150)),
+                },
+            )
+            .check("email", Check::Pattern("^[^@]+@[^@]+$".into()))
+            .check(
+                "country",
+                Check::References {
+                    other: countries.lazy(),
+                    column: "code".into(),
+                },
+            )
+            .check("missing", Check::NotNull)
+            .with_sample_size(1)
+            .validate(users.clone().lazy())?;
+
+        assert_eq!(report.n_rows, 4);
+        let summary = report
+            .violations
+            .iter()
+            .map(|v| (v.column.as_str(), v.count))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            [
+                ("id", 4),
+                ("missing", 4),
+                ("id", 2),
+                ("age", 1),
+                ("age", 2),
+                ("email", 1),
+                ("country", 1),
+            ]
+        );
+        let sample = report.violations[6].sample.as_ref().unwrap();
+        assert_eq!(
+            sample
+                .column("row_index")?
+                .as_materialized_series()
+                .idx()?
+                .get(0),
+            Some(2)
+        );
+        assert_eq!(
+            sample
+                .column("country")?
+                .as_materialized_series()
+                .str()?
+                .get(0),
+            Some("xx")
+        );
+        assert!(report.ensure_valid().is_err());
+
+        let report = Validator::new()
+            .check("id", Check::NotNull)
+            .validate(users.lazy())?;
+        assert!(report.is_valid());
+        Ok(())
+    }
+}
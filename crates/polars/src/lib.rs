@@ -177,9 +177,14 @@
 //! * `lazy` - Lazy API
 //!     - `regex` - Use regexes in [column selection]
 //!     - `dot_diagram` - Create dot diagrams from lazy logical plans.
+//!     - `describe` - Summary statistics of the columns of a [`LazyFrame`](crate::prelude::LazyFrame).
 //! * `sql` - Pass SQL queries to Polars.
+//! * `validate` - Declarative validation of the columns of a [`LazyFrame`](crate::prelude::LazyFrame).
+//! * `datagen` - Lazily generated synthetic tables, including TPC-H and TPC-DS tables, for benchmarks and tests.
 //! * `streaming` - Process datasets larger than RAM.
 //! * `random` - Generate arrays with randomly sampled values
+//! * `proptest` - [proptest](https://crates.io/crates/proptest) strategies that generate [`Series`],
+//!   [`DataFrame`]s, expressions and lazy plans for property-based testing.
 //! * `ndarray`- Convert from [`DataFrame`] to [ndarray](https://docs.rs/ndarray/)
 //! * `temporal` - Conversions between [Chrono](https://docs.rs/chrono/) and Polars for temporal data types
 //! * `timezones` - Activate timezone support.
@@ -196,6 +201,8 @@
 //!       Polars will be a bit slower with this feature activated as many data structures
 //!       are less cache efficient.
 //!     - `cse` - Activate common subplan elimination optimization
+//!     - `tracing` - Emit [tracing](https://crates.io/crates/tracing) spans with the estimated and actual
+//!       rows and the duration of every plan node when executing a query.
 //! * IO related:
 //!     - `serde` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!       Can be used for JSON and more serde supported serialization formats.
@@ -204,6 +211,7 @@
 //!     - `parquet` - Read Apache Parquet format
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
+//!     - `ipc_encryption` - AES-GCM encryption of written IPC files and of spilled files
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
 //!       Supported compressions:
 //!          - gzip
@@ -266,6 +274,9 @@
 //!     - `arg_where` - Get indices where condition holds.
 //!     - `search_sorted` - Find indices where elements should be inserted to maintain order.
 //!     - `offset_by` - Add an offset to dates that take months and leap years into account.
+//!     - `temporal_gaps` - Find missing periods and gaps in dates and datetimes.
+//!     - `shift_by_time` - Shift values by a period of time rather than a number of rows.
+//!     - `upsample` - Upsample a `LazyFrame` at a regular frequency.
 //!     - `trigonometry` - Trigonometric functions.
 //!     - `sign` - Compute the element-wise sign of a [`Series`].
 //!     - `propagate_nans` - NaN propagating min/max aggregations.
@@ -417,19 +428,22 @@ pub mod docs;
 pub mod prelude;
 #[cfg(feature = "sql")]
 pub mod sql;
-
 pub use polars_core::{
     apply_method_all_arrow_series, chunked_array, datatypes, df, error, frame, functions, series,
     testing,
 };
 #[cfg(feature = "dtype-categorical")]
 pub use polars_core::{enable_string_cache, using_string_cache};
+#[cfg(feature = "datagen")]
+pub use polars_datagen as datagen;
 #[cfg(feature = "polars-io")]
 pub use polars_io as io;
 #[cfg(feature = "lazy")]
 pub use polars_lazy as lazy;
 #[cfg(feature = "temporal")]
 pub use polars_time as time;
+#[cfg(feature = "validate")]
+pub use polars_validate as validate;
 
 /// Polars crate version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -1,6 +1,6 @@
 use chrono::NaiveDate;
 use polars::prelude::*;
-use polars_ops::pivot::{PivotAgg, pivot, pivot_stable};
+use polars_ops::pivot::{PivotAgg, pivot, pivot_multiple, pivot_stable};
 
 #[test]
 #[cfg(feature = "dtype-date")]
@@ -270,3 +270,48 @@ fn test_pivot_datetime() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_pivot_multiple() -> PolarsResult<()> {
+    let df = df![
+        "index" => ["a", "a", "b", "b"],
+        "on" => ["x", "y", "x", "x"],
+        "v1" => [1, 2, 3, 4],
+        "v2" => [10, 20, 30, 40],
+    ]?;
+
+    let aggs = [("sum".into(), PivotAgg::Sum), ("max".into(), PivotAgg::Max)];
+    let out = pivot_multiple(
+        &df,
+        ["on"],
+        Some(["index"]),
+        Some(["v1", "v2"]),
+        &aggs,
+        false,
+        None,
+    )?;
+    let expected = df![
+        "index" => ["a", "b"],
+        "v1_sum_x" => [Some(1), Some(7)],
+        "v1_sum_y" => [Some(2), None],
+        "v1_max_x" => [Some(1), Some(4)],
+        "v1_max_y" => [Some(2), None],
+        "v2_sum_x" => [Some(10), Some(70)],
+        "v2_sum_y" => [Some(20), None],
+        "v2_max_x" => [Some(10), Some(40)],
+        "v2_max_y" => [Some(20), None],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = pivot_multiple(
+        &df,
+        ["on"],
+        Some(["index"]),
+        Some(["v1"]),
+        &aggs[..1],
+        false,
+        Some("{on}:{agg}({value})"),
+    )?;
+    assert_eq!(out.get_column_names(), &["index", "x:sum(v1)", "y:sum(v1)"]);
+    Ok(())
+}
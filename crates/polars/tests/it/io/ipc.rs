@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::io::{Cursor, Seek, SeekFrom};
 
 use polars::prelude::*;
@@ -46,6 +47,110 @@ fn write_and_read_ipc() {
     assert!(df.equals(&df_read));
 }
 
+#[test]
+fn write_and_read_ipc_field_metadata() {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let md = Arc::new(BTreeMap::from([("unit".into(), "celsius".into())]));
+    let mut df = create_df();
+    df.apply("temp", |s| {
+        s.as_materialized_series()
+            .clone()
+            .with_metadata(Some(md.clone()))
+    })
+    .unwrap();
+
+    IpcWriter::new(&mut buf).finish(&mut df).unwrap();
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf).finish().unwrap();
+    let temp = df_read.column("temp").unwrap().as_materialized_series();
+    assert_eq!(temp.metadata(), Some(md));
+    assert_eq!(df_read.column("days").unwrap().field().metadata(), None);
+}
+
+#[test]
+fn write_and_read_ipc_field_metadata_batched() -> PolarsResult<()> {
+    let md = Arc::new(BTreeMap::from([("unit".into(), "celsius".into())]));
+    let mut df = create_df();
+    df.apply("temp", |s| {
+        s.as_materialized_series()
+            .clone()
+            .with_metadata(Some(md.clone()))
+    })?;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut writer = IpcWriter::new(&mut buf).batched(df.schema())?;
+    writer.write_batch(&df)?;
+    writer.write_batch(&df)?;
+    writer.finish()?;
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf).finish()?;
+    assert_eq!(df_read.column("temp")?.field().metadata(), Some(&md));
+
+    #[cfg(feature = "lazy")]
+    {
+        let path = std::env::temp_dir().join("write_and_read_ipc_field_metadata_batched.ipc");
+        df.lazy()
+            .sink_ipc(
+                SinkTarget::Path(Arc::new(path.clone())),
+                IpcWriterOptions::default(),
+                None,
+                SinkOptions::default(),
+            )?
+            .collect_with_engine(Engine::Streaming)?;
+
+        let df_read = IpcReader::new(polars_utils::open_file(&path)?).finish()?;
+        std::fs::remove_file(&path)?;
+        assert_eq!(df_read.column("temp")?.field().metadata(), Some(&md));
+        assert_eq!(df_read.column("days")?.field().metadata(), None);
+    }
+    Ok(())
+}
+
+#[test]
+fn write_and_read_ipc_extension_type() {
+    struct Percentage;
+
+    impl ExtensionTypeDefinition for Percentage {
+        fn storage_dtype(&self) -> DataType {
+            DataType::Float64
+        }
+
+        fn format(&self, s: &Series, _metadata: Option<&str>) -> PolarsResult<Series> {
+            let out: StringChunked = s
+                .f64()?
+                .iter()
+                .map(|v| v.map(|v| format!("{}%", v * 100.0)))
+                .collect();
+            Ok(out.with_name(s.name().clone()).into_series())
+        }
+    }
+    register_extension_type("test_percentage", Some(Arc::new(Percentage)));
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let share = Series::new("share".into(), [0.25f64, 0.5])
+        .to_extension("test_percentage", None)
+        .unwrap();
+    let mut df = DataFrame::new(vec![share.into()]).unwrap();
+
+    IpcWriter::new(&mut buf).finish(&mut df).unwrap();
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf).finish().unwrap();
+    let share = df_read.column("share").unwrap().as_materialized_series();
+    assert_eq!(
+        share.extension_type(),
+        Some(("test_percentage".into(), None))
+    );
+    let formatted = share.cast(&DataType::String).unwrap();
+    assert_eq!(
+        Vec::from(formatted.str().unwrap()),
+        [Some("25%"), Some("50%")]
+    );
+    register_extension_type("test_percentage", None);
+}
+
 #[test]
 fn test_read_ipc_with_projection() {
     let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
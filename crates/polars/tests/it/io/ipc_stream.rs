@@ -156,4 +156,44 @@ mod test {
         let actual = IpcStreamReader::new(reader).finish().unwrap();
         assert_df_eq!(df(), actual);
     }
+
+    #[test]
+    #[cfg(all(feature = "lazy", feature = "async"))]
+    fn sink_ipc_stream_into_pipe() {
+        use polars::prelude::{Engine, IntoLazy, SinkOptions, SinkTarget};
+        use polars_io::utils::file::AsyncWriteAdapter;
+        use tokio::io::AsyncReadExt;
+
+        // The buffer of the pipe is small, so that the sink has to wait for the reader.
+        let (writer, mut reader) = tokio::io::duplex(64);
+        let reader = std::thread::spawn(move || {
+            let rt = tokio::runtime::Builder::new_current_thread()
+                .build()
+                .unwrap();
+            rt.block_on(async move {
+                let mut buf = vec![];
+                reader.read_to_end(&mut buf).await.unwrap();
+                buf
+            })
+        });
+
+        let df = create_df();
+        let target = SinkTarget::from_writeable(Box::new(AsyncWriteAdapter::new(writer)));
+        df.clone()
+            .lazy()
+            .sink_ipc_stream(
+                target,
+                IpcWriterOptions::default(),
+                None,
+                SinkOptions::default(),
+            )
+            .unwrap()
+            .collect_with_engine(Engine::Streaming)
+            .unwrap();
+
+        let actual = IpcStreamReader::new(Cursor::new(reader.join().unwrap()))
+            .finish()
+            .unwrap();
+        assert_df_eq!(actual, df);
+    }
 }
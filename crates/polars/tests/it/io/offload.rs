@@ -0,0 +1,65 @@
+use std::sync::Mutex;
+
+use polars::io::cloud::offload::{OffloadRequest, ScanOffload, register_scan_offload};
+use polars::prelude::*;
+
+/// Answers the requests for `url` with `df`, which differs from the contents of the file.
+struct MockOffload {
+    url: String,
+    df: DataFrame,
+    requests: Mutex<Vec<OffloadRequest>>,
+}
+
+#[async_trait::async_trait]
+impl ScanOffload for MockOffload {
+    fn name(&self) -> &str {
+        "mock"
+    }
+
+    async fn offload(&self, request: OffloadRequest) -> PolarsResult<Option<DataFrame>> {
+        if request.url != self.url.as_str() {
+            return Ok(None);
+        }
+        self.requests.lock().unwrap().push(request);
+        Ok(Some(self.df.clone()))
+    }
+}
+
+#[test]
+fn test_scan_offload() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("test_scan_offload.parquet");
+    let mut df = df!(
+        "a" => [1, 2, 3],
+        "b" => ["p", "q", "r"],
+        "c" => [1.0, 2.0, 3.0],
+    )?;
+    ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+    let url = format!("file://{}", path.to_str().unwrap());
+
+    let offload = Arc::new(MockOffload {
+        url: url.clone(),
+        df: df!(
+            "a" => [2, 1, 2],
+            "b" => ["x", "y", "z"],
+        )?,
+        requests: Mutex::default(),
+    });
+    register_scan_offload("file", Some(offload.clone()));
+
+    let out = LazyFrame::scan_parquet(&url, ScanArgsParquet::default())?
+        .filter(col("a").eq(lit(2)))
+        .select([col("b")])
+        .collect_with_engine(Engine::Streaming);
+    register_scan_offload("file", None);
+    std::fs::remove_file(&path)?;
+
+    // The offload answered the read, and the predicate was applied to its result.
+    assert_eq!(out?, df!("b" => ["x", "z"])?);
+    let requests = offload.requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].format, "parquet");
+    let mut columns = requests[0].columns.clone();
+    columns.sort();
+    assert_eq!(columns, ["a", "b"]);
+    Ok(())
+}
@@ -206,3 +206,76 @@ fn test_vstack_empty_3220() -> PolarsResult<()> {
     assert!(stacked.equals(&read_df));
     Ok(())
 }
+
+#[test]
+fn test_field_metadata_batched_and_sink() -> PolarsResult<()> {
+    let md = Arc::new(std::collections::BTreeMap::from([(
+        "unit".into(),
+        "celsius".into(),
+    )]));
+    let mut df = crate::io::create_df();
+    df.apply("temp", |s| {
+        s.as_materialized_series()
+            .clone()
+            .with_metadata(Some(md.clone()))
+    })?;
+
+    let mut buf = Cursor::new(Vec::new());
+    let mut writer = ParquetWriter::new(&mut buf).batched(df.schema())?;
+    writer.write_batch(&df)?;
+    writer.finish()?;
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert_eq!(read_df.column("temp")?.field().metadata(), Some(&md));
+
+    #[cfg(feature = "lazy")]
+    {
+        let path = std::env::temp_dir().join("test_field_metadata_batched_and_sink.parquet");
+        df.lazy()
+            .sink_parquet(
+                SinkTarget::Path(Arc::new(path.clone())),
+                ParquetWriteOptions::default(),
+                None,
+                SinkOptions::default(),
+            )?
+            .collect_with_engine(Engine::Streaming)?;
+
+        let read_df = ParquetReader::new(polars_utils::open_file(&path)?).finish()?;
+        std::fs::remove_file(&path)?;
+        assert_eq!(read_df.column("temp")?.field().metadata(), Some(&md));
+        assert_eq!(read_df.column("days")?.field().metadata(), None);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_extension_type_roundtrip() -> PolarsResult<()> {
+    struct Probability;
+
+    impl ExtensionTypeDefinition for Probability {
+        fn storage_dtype(&self) -> DataType {
+            DataType::Float64
+        }
+
+        fn can_cast_to(&self, dtype: &DataType) -> bool {
+            dtype.is_float() || dtype.is_string()
+        }
+    }
+    register_extension_type("test_parquet_probability", Some(Arc::new(Probability)));
+
+    let p = Series::new("p".into(), [0.25f64, 0.5])
+        .to_extension("test_parquet_probability", Some("binomial"))?;
+    let mut df = DataFrame::new(vec![p.into()])?;
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+    let p = read_df.column("p")?.as_materialized_series();
+    assert_eq!(
+        p.extension_type(),
+        Some(("test_parquet_probability".into(), Some("binomial".into())))
+    );
+    // The cast rules of the extension type apply to the values that were read.
+    assert!(p.cast(&DataType::Int64).is_err());
+    register_extension_type("test_parquet_probability", None);
+    Ok(())
+}
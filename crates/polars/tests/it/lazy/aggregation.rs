@@ -35,3 +35,136 @@ fn test_lazy_agg() {
     let min = new.column("min").unwrap();
     assert_eq!(min, &Column::new("min".into(), [0.1f64, 0.01, 0.1]));
 }
+
+#[test]
+fn test_agg_nan_policy() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 2, 2],
+        "x" => [1.0, f64::NAN, f64::NAN, f64::NAN],
+    ]?;
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            col("x")
+                .max_with_nan_policy(NanPolicy::Propagate)
+                .alias("max_propagate"),
+            col("x")
+                .mean_with_nan_policy(NanPolicy::Ignore)
+                .alias("mean_ignore"),
+            col("x")
+                .min_with_nan_policy(NanPolicy::AsNull)
+                .alias("min_as_null"),
+        ])
+        .collect()?;
+
+    let max_propagate = out.column("max_propagate")?.f64()?;
+    assert!(max_propagate.get(0).unwrap().is_nan());
+    let mean_ignore = out.column("mean_ignore")?.f64()?;
+    assert_eq!(mean_ignore.get(0), Some(1.0));
+    assert_eq!(mean_ignore.get(1), None);
+    let min_as_null = out.column("min_as_null")?.f64()?;
+    assert_eq!(min_as_null.get(0), Some(1.0));
+    assert_eq!(min_as_null.get(1), None);
+    Ok(())
+}
+
+#[test]
+fn test_nan_policy_ignore_vs_as_null() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 2, 2],
+        "x" => [Some(1.0), Some(f64::NAN), None, Some(f64::NAN)],
+    ]?;
+
+    // `Ignore` drops NaN, `AsNull` counts it as null.
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            col("x")
+                .with_nan_policy(NanPolicy::Ignore)
+                .null_count()
+                .alias("null_count_ignore"),
+            col("x")
+                .with_nan_policy(NanPolicy::AsNull)
+                .null_count()
+                .alias("null_count_as_null"),
+            col("x")
+                .with_nan_policy(NanPolicy::Ignore)
+                .len()
+                .alias("len_ignore"),
+            col("x")
+                .with_nan_policy(NanPolicy::AsNull)
+                .len()
+                .alias("len_as_null"),
+        ])
+        .collect()?;
+    let column =
+        |name| -> PolarsResult<Vec<Option<IdxSize>>> { Ok(Vec::from(out.column(name)?.idx()?)) };
+    assert_eq!(column("null_count_ignore")?, [Some(0), Some(1)]);
+    assert_eq!(column("null_count_as_null")?, [Some(1), Some(2)]);
+    assert_eq!(column("len_ignore")?, [Some(1), Some(1)]);
+    assert_eq!(column("len_as_null")?, [Some(2), Some(2)]);
+
+    let query = |policy| {
+        df.clone()
+            .lazy()
+            .group_by_stable([col("g")])
+            .agg([col("x").len().alias("len"), col("x").count().alias("count")])
+            .with_nan_policy(Some(policy))
+            .collect()
+    };
+    let out = query(NanPolicy::Ignore)?;
+    assert_eq!(Vec::from(out.column("len")?.idx()?), [Some(1), Some(1)]);
+    assert_eq!(Vec::from(out.column("count")?.idx()?), [Some(1), Some(0)]);
+    let out = query(NanPolicy::AsNull)?;
+    assert_eq!(Vec::from(out.column("len")?.idx()?), [Some(2), Some(2)]);
+    assert_eq!(Vec::from(out.column("count")?.idx()?), [Some(1), Some(0)]);
+    Ok(())
+}
+
+#[test]
+fn test_query_nan_policy() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 2, 2],
+        "x" => [1.0, f64::NAN, f64::NAN, f64::NAN],
+    ]?;
+    let query = |policy| {
+        df.clone()
+            .lazy()
+            .group_by_stable([col("g")])
+            .agg([
+                col("x").min().alias("min"),
+                col("x").max().alias("max"),
+                col("x").mean().alias("mean"),
+                col("x").sum().alias("sum"),
+            ])
+            .with_nan_policy(policy)
+            .collect()
+    };
+
+    let out = query(Some(NanPolicy::Ignore))?;
+    for name in ["min", "max", "mean", "sum"] {
+        assert_eq!(out.column(name)?.f64()?.get(0), Some(1.0), "{name}");
+    }
+    // A group of only NaN has no values to aggregate, and the sum of no values is zero.
+    for name in ["min", "max", "mean"] {
+        assert_eq!(out.column(name)?.f64()?.get(1), None, "{name}");
+    }
+    assert_eq!(out.column("sum")?.f64()?.get(1), Some(0.0));
+
+    let out = query(Some(NanPolicy::Propagate))?;
+    for name in ["min", "max", "mean", "sum"] {
+        let agg = out.column(name)?.f64()?;
+        assert!(agg.get(0).unwrap().is_nan(), "{name}");
+        assert!(agg.get(1).unwrap().is_nan(), "{name}");
+    }
+
+    // Without a policy `min` and `max` skip NaN.
+    let out = query(None)?;
+    assert_eq!(out.column("min")?.f64()?.get(0), Some(1.0));
+    assert!(out.column("mean")?.f64()?.get(0).unwrap().is_nan());
+    Ok(())
+}
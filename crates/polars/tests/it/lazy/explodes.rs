@@ -18,3 +18,42 @@ fn test_explode_row_numbers() -> PolarsResult<()> {
     assert_eq!(df.shape(), (8, 2));
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "strings", feature = "regex"))]
+fn test_split_regex_and_maxsplit() -> PolarsResult<()> {
+    let df = df![
+        "text" => ["one  two\tthree four", "uno"]
+    ]?
+    .lazy()
+    .select([
+        col("text")
+            .str()
+            .split_regex(lit(r"\s+"), None)
+            .alias("tokens"),
+        col("text")
+            .str()
+            .split_regex(lit(r"\s+"), Some(1))
+            .alias("head"),
+        col("text").str().split_max(lit(" "), 1).alias("literal"),
+    ])
+    .explode([col("tokens")])
+    .collect()?;
+
+    let tokens = df.column("tokens")?.str()?;
+    assert_eq!(
+        tokens.into_no_null_iter().collect::<Vec<_>>(),
+        ["one", "two", "three", "four", "uno"]
+    );
+    let head = df.column("head")?.list()?.get_as_series(0).unwrap();
+    assert_eq!(
+        head.str()?.into_no_null_iter().collect::<Vec<_>>(),
+        ["one", "two\tthree four"]
+    );
+    let literal = df.column("literal")?.list()?.get_as_series(0).unwrap();
+    assert_eq!(
+        literal.str()?.into_no_null_iter().collect::<Vec<_>>(),
+        ["one", " two\tthree four"]
+    );
+    Ok(())
+}
@@ -24,3 +24,34 @@ fn test_format_str() {
 
     assert!(out.equals_missing(&expected));
 }
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_uuid_roundtrip() -> PolarsResult<()> {
+    let df = df![
+        "a" => ["67e55044-10b1-426f-9247-bb680e5fe0c8", "00000000-0000-0000-0000-000000000000", "x"]
+    ]?;
+
+    let out = df
+        .lazy()
+        .with_columns([
+            col("a").str().to_uuid(false).alias("bin"),
+            uuid_v7(len()).alias("generated"),
+        ])
+        .with_column(col("bin").binary().uuid_to_string(true).alias("str"))
+        .collect()?;
+
+    assert_eq!(out.column("generated")?.len(), 3);
+    assert_eq!(out.column("generated")?.n_unique()?, 3);
+    assert_eq!(out.column("generated")?.dtype(), &DataType::String);
+    let expected = Column::new(
+        "str".into(),
+        [
+            Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+            Some("00000000-0000-0000-0000-000000000000"),
+            None,
+        ],
+    );
+    assert!(out.column("str")?.equals_missing(&expected));
+    Ok(())
+}
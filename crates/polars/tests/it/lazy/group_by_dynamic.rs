@@ -61,3 +61,179 @@ fn test_group_by_dynamic_week_bounds() -> PolarsResult<()> {
     assert_eq!(a.get(1)?, AnyValue::Int32(6));
     Ok(())
 }
+
+#[test]
+#[cfg(all(
+    feature = "temporal",
+    feature = "dtype-date",
+    feature = "dynamic_group_by"
+))]
+fn test_group_by_dynamic_period_end_and_origin() -> PolarsResult<()> {
+    let start = NaiveDate::from_ymd_opt(2024, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let stop = NaiveDate::from_ymd_opt(2024, 4, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let range = polars_time::date_range(
+        "dt".into(),
+        start,
+        stop,
+        Duration::parse("1d"),
+        ClosedWindow::Left,
+        TimeUnit::Milliseconds,
+        None,
+    )?
+    .into_series();
+
+    let a = Int32Chunked::full("a".into(), 1, range.len());
+    let df = df![
+        "dt" => range,
+        "a" => a
+    ]?;
+
+    let options = DynamicGroupOptions {
+        offset: Duration::parse("0d"),
+        closed_window: ClosedWindow::Left,
+        ..Default::default()
+    }
+    .with_frequency("1mo_end")?;
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_dynamic(col("dt"), [], options)
+        .agg([col("a").sum()])
+        .collect()?;
+    let dt = out.column("dt")?.cast(&DataType::String)?;
+    let dt = dt.str()?.into_no_null_iter().collect::<Vec<_>>();
+    assert_eq!(
+        dt,
+        [
+            "2024-01-31 00:00:00.000",
+            "2024-02-29 00:00:00.000",
+            "2024-03-31 00:00:00.000"
+        ]
+    );
+    let a = out
+        .column("a")?
+        .i32()?
+        .into_no_null_iter()
+        .collect::<Vec<_>>();
+    assert_eq!(a, [31, 29, 31]);
+
+    // Align daily windows to 09:30.
+    let origin = start.and_utc().timestamp_nanos_opt().unwrap()
+        + 9 * 3_600_000_000_000
+        + 30 * 60_000_000_000;
+    let out = df
+        .lazy()
+        .group_by_dynamic(
+            col("dt"),
+            [],
+            DynamicGroupOptions {
+                every: Duration::parse("1d"),
+                period: Duration::parse("1d"),
+                offset: Duration::parse("0d"),
+                closed_window: ClosedWindow::Left,
+                origin: Some(origin),
+                ..Default::default()
+            },
+        )
+        .agg([col("a").sum()])
+        .collect()?;
+    let dt = out.column("dt")?.cast(&DataType::String)?;
+    assert_eq!(dt.str()?.get(0), Some("2023-12-31 09:30:00.000"));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "temporal",
+    feature = "dtype-date",
+    feature = "dynamic_group_by"
+))]
+fn test_group_by_dynamic_business_days_and_label_offset() -> PolarsResult<()> {
+    // Friday 2024-01-05 to Tuesday 2024-01-09.
+    let start = NaiveDate::from_ymd_opt(2024, 1, 5)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let stop = NaiveDate::from_ymd_opt(2024, 1, 10)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let range = polars_time::date_range(
+        "dt".into(),
+        start,
+        stop,
+        Duration::parse("12h"),
+        ClosedWindow::Left,
+        TimeUnit::Milliseconds,
+        None,
+    )?
+    .into_series();
+
+    let a = Int32Chunked::full("a".into(), 1, range.len());
+    let df = df![
+        "dt" => range,
+        "a" => a
+    ]?;
+
+    let group_by = |options: DynamicGroupOptions| -> PolarsResult<(Vec<String>, Vec<i32>)> {
+        let out = df
+            .clone()
+            .lazy()
+            .group_by_dynamic(col("dt"), [], options)
+            .agg([col("a").sum()])
+            .collect()?;
+        let dt = out.column("dt")?.cast(&DataType::String)?;
+        let dt = dt
+            .str()?
+            .into_no_null_iter()
+            .map(|s| s.to_string())
+            .collect();
+        let a = out.column("a")?.i32()?.into_no_null_iter().collect();
+        Ok((dt, a))
+    };
+
+    // The weekend belongs to the window of the Friday before it.
+    let (dt, a) = group_by(DynamicGroupOptions::default().with_frequency("1bd")?)?;
+    assert_eq!(
+        dt,
+        [
+            "2024-01-05 00:00:00.000",
+            "2024-01-08 00:00:00.000",
+            "2024-01-09 00:00:00.000"
+        ]
+    );
+    assert_eq!(a, [6, 2, 2]);
+
+    let (dt, _) = group_by(DynamicGroupOptions {
+        label: Label::Right,
+        ..DynamicGroupOptions::default().with_frequency("1bd")?
+    })?;
+    assert_eq!(dt[0], "2024-01-08 00:00:00.000");
+
+    // Windows with a time component are labelled by their upper bound.
+    let (dt, a) = group_by(DynamicGroupOptions {
+        label: Label::PeriodEnd,
+        ..DynamicGroupOptions::default().with_frequency("1d")?
+    })?;
+    assert_eq!(dt[0], "2024-01-05 00:00:00.000");
+    assert_eq!(a[0], 2);
+    let (dt, _) = group_by(DynamicGroupOptions {
+        label: Label::PeriodEnd,
+        ..DynamicGroupOptions::default().with_frequency("12h")?
+    })?;
+    assert_eq!(dt[0], "2024-01-05 12:00:00.000");
+
+    let (dt, _) = group_by(DynamicGroupOptions {
+        label: Label::Right,
+        label_offset: Some(Duration::parse("-1h")),
+        ..DynamicGroupOptions::default().with_frequency("1d")?
+    })?;
+    assert_eq!(dt[0], "2024-01-05 23:00:00.000");
+    Ok(())
+}
@@ -117,6 +117,7 @@ from polars.functions import (
     datetime_ranges,
     duration,
     element,
+    encode_rows,
     escape_regex,
     exclude,
     explain_all,
@@ -128,6 +129,7 @@ from polars.functions import (
     groups,
     head,
     implode,
+    is_duplicated_by,
     int_range,
     int_ranges,
     last,
@@ -147,11 +149,14 @@ from polars.functions import (
     n_unique,
     nth,
     ones,
+    parse_expr,
     quantile,
     reduce,
     repeat,
     rolling_corr,
     rolling_cov,
+    scan_date_range,
+    scan_int_range,
     select,
     set_random_seed,
     sql_expr,
@@ -163,6 +168,7 @@ from polars.functions import (
     time,
     time_range,
     time_ranges,
+    values,
     var,
     when,
     zeros,
@@ -195,6 +201,7 @@ from polars.io import (
     read_parquet,
     read_parquet_metadata,
     read_parquet_schema,
+    remove_shared_memory,
     scan_csv,
     scan_delta,
     scan_iceberg,
@@ -202,6 +209,7 @@ from polars.io import (
     scan_ndjson,
     scan_parquet,
     scan_pyarrow_dataset,
+    scan_shared_memory,
 )
 from polars.io.cloud import (
     CredentialProvider,
@@ -305,6 +313,7 @@ __all__ = [
     "read_parquet",
     "read_parquet_metadata",
     "read_parquet_schema",
+    "remove_shared_memory",
     "scan_csv",
     "scan_delta",
     "scan_iceberg",
@@ -312,6 +321,7 @@ __all__ = [
     "scan_ndjson",
     "scan_parquet",
     "scan_pyarrow_dataset",
+    "scan_shared_memory",
     "Catalog",
     # polars.io.cloud
     "CredentialProvider",
@@ -341,6 +351,8 @@ __all__ = [
     "element",
     "ones",
     "repeat",
+    "scan_date_range",
+    "scan_int_range",
     "time_range",
     "time_ranges",
     "zeros",
@@ -381,6 +393,7 @@ __all__ = [
     "date",
     "datetime",
     "duration",
+    "encode_rows",
     "exclude",
     "explain_all",
     "field",
@@ -391,6 +404,7 @@ __all__ = [
     "groups",
     "head",
     "implode",
+    "is_duplicated_by",
     "int_range",
     "int_ranges",
     "last",
@@ -412,6 +426,7 @@ __all__ = [
     "struct",
     "tail",
     "time",
+    "values",
     "var",
     # polars.functions.len
     "len",
@@ -438,6 +453,7 @@ __all__ = [
     "SQLContext",
     "sql",
     "sql_expr",
+    "parse_expr",
     "CompatLevel",
     # optimization
     "QueryOptFlags",
@@ -113,10 +113,11 @@ FloatFmt: TypeAlias = Literal["full", "mixed"]
 IndexOrder: TypeAlias = Literal["c", "fortran"]
 IpcCompression: TypeAlias = Literal["uncompressed", "lz4", "zstd"]
 JoinValidation: TypeAlias = Literal["m:m", "m:1", "1:m", "1:1"]
-Label: TypeAlias = Literal["left", "right", "datapoint"]
+Label: TypeAlias = Literal["left", "right", "datapoint", "period_end"]
 MaintainOrderJoin: TypeAlias = Literal[
     "none", "left", "right", "left_right", "right_left"
 ]
+MonthEndPolicy: TypeAlias = Literal["clamp", "month_end"]
 NonExistent: TypeAlias = Literal["raise", "null"]
 NullBehavior: TypeAlias = Literal["ignore", "drop"]
 ParallelStrategy: TypeAlias = Literal[
@@ -128,6 +129,7 @@ ParquetCompression: TypeAlias = Literal[
 PivotAgg: TypeAlias = Literal[
     "min", "max", "first", "last", "sum", "mean", "median", "len"
 ]
+ModeTiePolicy: TypeAlias = Literal["all", "smallest", "first", "all_as_list"]
 QuantileMethod: TypeAlias = Literal[
     "nearest", "higher", "lower", "midpoint", "linear", "equiprobable"
 ]
@@ -167,12 +169,16 @@ UnstackDirection: TypeAlias = Literal["vertical", "horizontal"]
 MapElementsStrategy: TypeAlias = Literal["thread_local", "threading"]
 
 # The following have a Rust enum equivalent with a different name
-AsofJoinStrategy: TypeAlias = Literal["backward", "forward", "nearest"]  # AsofStrategy
+AsofJoinStrategy: TypeAlias = Literal[
+    "backward", "forward", "nearest", "bracket"
+]  # AsofStrategy
 ClosedInterval: TypeAlias = Literal["left", "right", "both", "none"]  # ClosedWindow
+EnumPolicy: TypeAlias = Literal["strict", "extend"]  # EnumAppendPolicy
 InterpolationMethod: TypeAlias = Literal["linear", "nearest"]
 JoinStrategy: TypeAlias = Literal[
     "inner", "left", "right", "full", "semi", "anti", "cross", "outer"
 ]  # JoinType
+ListSetNulls: TypeAlias = Literal["equal", "distinct", "ignore"]
 ListToStructWidthStrategy: TypeAlias = Literal["first_non_null", "max_width"]
 
 # The following have no equivalent on the Rust side
@@ -378,6 +384,7 @@ __all__ = [
     "DeprecationType",
     "Endianness",
     "EngineType",
+    "EnumPolicy",
     "EpochTimeUnit",
     "ExcelSpreadsheetEngine",
     "ExplainFormat",
@@ -396,9 +403,11 @@ __all__ = [
     "JoinStrategy",
     "JoinValidation",
     "Label",
+    "ListSetNulls",
     "ListToStructWidthStrategy",
     "MaintainOrderJoin",
     "MapElementsStrategy",
+    "MonthEndPolicy",
     "MultiColSelector",
     "MultiIndexSelector",
     "MultiNameSelector",
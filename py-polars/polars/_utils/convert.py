@@ -171,6 +171,13 @@ def _localize_datetime(dt: datetime, time_zone: str) -> datetime:
     try:
         tz = ZoneInfo(time_zone)
     except ZoneInfoNotFoundError:
+        if not time_zone.startswith(("+", "-")):
+            msg = (
+                f"time zone {time_zone!r} not found in the time zone database"
+                "\n\nHint: if the system has no time zone database, install the"
+                " bundled one with `pip install 'polars[timezone]'`."
+            )
+            raise ZoneInfoNotFoundError(msg) from None
         # try fixed offset, which is not supported by ZoneInfo
         tz = _parse_fixed_tz_offset(time_zone)
 
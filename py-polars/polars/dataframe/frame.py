@@ -118,7 +118,7 @@ with contextlib.suppress(ImportError):  # Module not available when building doc
 if TYPE_CHECKING:
     import sys
     from collections.abc import Collection, Iterator, Mapping
-    from datetime import timedelta
+    from datetime import date, datetime, timedelta
     from io import IOBase
     from typing import Literal
 
@@ -145,6 +145,7 @@ if TYPE_CHECKING:
         CsvQuoteStyle,
         DbWriteEngine,
         EngineType,
+        EnumPolicy,
         FillNullStrategy,
         FrameInitTypes,
         IndexOrder,
@@ -155,6 +156,7 @@ if TYPE_CHECKING:
         JoinValidation,
         Label,
         MaintainOrderJoin,
+        MonthEndPolicy,
         MultiColSelector,
         MultiIndexSelector,
         OneOrMoreDataTypes,
@@ -3826,6 +3828,47 @@ class DataFrame:
         self._df.write_ipc_stream(file, compression, compat_level)
         return file if return_bytes else None  # type: ignore[return-value]
 
+    @unstable()
+    def write_shared_memory(self, name: str) -> str:
+        """
+        Write to a named shared memory object that other processes can scan.
+
+        The frame is written as an uncompressed Arrow IPC file, which processes on the
+        same machine read with :func:`scan_shared_memory` by memory mapping it, without
+        the data touching a disk. This is only supported on Linux.
+
+        An existing object with the same name is replaced atomically, processes that
+        are still reading it keep seeing the previous frame. The object lives until
+        it is removed with :func:`remove_shared_memory`.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        name
+            Name of the shared memory object. It must not be empty, start with `.`
+            or contain `/`.
+
+        Returns
+        -------
+        str
+            The path of the shared memory object.
+
+        See Also
+        --------
+        scan_shared_memory
+        remove_shared_memory
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"foo": [1, 2, 3], "bar": ["a", "b", "c"]})
+        >>> df.write_shared_memory("frame")  # doctest: +SKIP
+        '/dev/shm/frame'
+        """
+        return self._df.write_shared_memory(name)
+
     def write_parquet(
         self,
         file: str | Path | IO[bytes],
@@ -6332,6 +6375,59 @@ class DataFrame:
             self.lazy().drop_nulls(subset).collect(optimizations=QueryOptFlags._eager())
         )
 
+    @unstable()
+    def duplicates(
+        self,
+        subset: ColumnNameOrSelector | Collection[ColumnNameOrSelector] | None = None,
+        *,
+        name: str = "count",
+    ) -> DataFrame:
+        """
+        Keep only the rows that occur more than once, with their number of occurrences.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The number of occurrences is added in a column named `name`. The original
+        order of the remaining rows is preserved.
+
+        Parameters
+        ----------
+        subset
+            Column name(s) or selector(s) to compare the rows by. By default all
+            columns are compared.
+        name
+            Name of the column with the number of occurrences. Raises a
+            `DuplicateError` if a column with this name already exists.
+
+        See Also
+        --------
+        polars.is_duplicated_by
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 1, 1], "b": ["x", "y", "x", "z"]})
+        >>> df.duplicates("a")
+        shape: (3, 3)
+        ┌─────┬─────┬───────┐
+        │ a   ┆ b   ┆ count │
+        │ --- ┆ --- ┆ ---   │
+        │ i64 ┆ str ┆ u32   │
+        ╞═════╪═════╪═══════╡
+        │ 1   ┆ x   ┆ 3     │
+        │ 1   ┆ x   ┆ 3     │
+        │ 1   ┆ z   ┆ 3     │
+        └─────┴─────┴───────┘
+        """
+        from polars.lazyframe.opt_flags import QueryOptFlags
+
+        return (
+            self.lazy()
+            .duplicates(subset, name=name)
+            .collect(optimizations=QueryOptFlags._eager())
+        )
+
     def pipe(
         self,
         function: Callable[Concatenate[DataFrame, P], T],
@@ -6831,6 +6927,9 @@ class DataFrame:
         label: Label = "left",
         group_by: IntoExpr | Iterable[IntoExpr] | None = None,
         start_by: StartBy = "window",
+        month_end_policy: MonthEndPolicy | None = None,
+        origin: datetime | date | int | None = None,
+        label_offset: str | timedelta | None = None,
     ) -> DynamicGroupBy:
         """
         Group based on a time value (or index value of type Int32, Int64).
@@ -6866,7 +6965,12 @@ class DataFrame:
             {Int32, Int64}. Note that Int32 gets temporarily cast to Int64, so if
             performance matters use an Int64 column.
         every
-            interval of the window
+            interval of the window. Besides durations, this accepts the aliases
+
+            - `'{n}mo_end'`, `'{n}q_end'`, `'{n}y_end'`: calendar windows of `n`
+              months, quarters or years, labelled by their last day.
+            - `'{n}bd'`: windows of `n` business days, Monday to Friday. Rows on a
+              weekend belong to the window of the Friday before them.
         period
             length of the window, if None it will equal 'every'
         offset
@@ -6878,7 +6982,7 @@ class DataFrame:
             parallelize
         closed : {'left', 'right', 'both', 'none'}
             Define which sides of the temporal interval are closed (inclusive).
-        label : {'left', 'right', 'datapoint'}
+        label : {'left', 'right', 'datapoint', 'period_end'}
             Define which label to use for the window:
 
             - 'left': lower boundary of the window
@@ -6886,6 +6990,9 @@ class DataFrame:
             - 'datapoint': the first value of the index column in the given window.
               If you don't need the label to be at one of the boundaries, choose this
               option for maximum performance
+            - 'period_end': the last calendar day of the window, e.g. month-end dates
+              for monthly windows. Windows with a time component are labelled by
+              their upper boundary.
         group_by
             Also group by this column/these columns
         start_by : {'window', 'datapoint', 'monday', 'tuesday', 'wednesday', 'thursday', 'friday', 'saturday', 'sunday'}
@@ -6904,6 +7011,25 @@ class DataFrame:
 
               The resulting window is then shifted back until the earliest datapoint
               is in or in front of it.
+        month_end_policy : {None, 'clamp', 'month_end'}
+            How calendar windows (`every` in months, quarters or years) are laid out
+            when the first window starts on a day that doesn't exist in every month.
+
+            * None: Every window starts `every` after the start of the previous one,
+              so a day clamped to the end of a short month carries over, e.g. windows
+              starting on Jan 31 continue with Feb 29 and Mar 29.
+            * 'clamp': Keep the day of the month of the first window, clamped to the
+              last day of short months, e.g. Jan 31 continues with Feb 29 and Mar 31.
+            * 'month_end': Like 'clamp', but if the first window starts on the last
+              day of its month, every window starts on the last day of its month.
+        origin
+            Align the window boundaries to this point in time instead of to the
+            epoch, e.g. `datetime(2020, 1, 1, 9, 30)` with `every="1h"` starts the
+            windows at half past the hour. Pass an integer for integer index
+            columns. Takes precedence over `offset`.
+        label_offset
+            Shift the label of every window by this duration, e.g. `"-1h"` with
+            `label="right"`.
 
         Returns
         -------
@@ -7136,6 +7262,9 @@ class DataFrame:
             closed=closed,
             group_by=group_by,
             start_by=start_by,
+            month_end_policy=month_end_policy,
+            origin=origin,
+            label_offset=label_offset,
         )
 
     @deprecate_renamed_parameter("by", "group_by", version="0.20.14")
@@ -7280,6 +7409,11 @@ class DataFrame:
             is nearest to the left's key. String keys are not currently supported for a
             nearest search.
 
+          - A "bracket" search selects both the "backward" and the "forward" match,
+            e.g. to interpolate between the surrounding rows. Every column of the right
+            DataFrame becomes a struct with the fields `backward` and `forward`. Use
+            `coalesce=False` to keep the keys of both matches.
+
         The default is "backward".
 
         Parameters
@@ -7299,7 +7433,7 @@ class DataFrame:
             Join on these columns before doing asof join
         by_right
             Join on these columns before doing asof join
-        strategy : {'backward', 'forward', 'nearest'}
+        strategy : {'backward', 'forward', 'nearest', 'bracket'}
             Join strategy.
         suffix
             Suffix to append to columns with a duplicate name.
@@ -7606,7 +7740,7 @@ class DataFrame:
         right_on: str | Expr | Sequence[str | Expr] | None = None,
         suffix: str = "_right",
         validate: JoinValidation = "m:m",
-        nulls_equal: bool = False,
+        nulls_equal: bool | Sequence[bool] = False,
         coalesce: bool | None = None,
         maintain_order: MaintainOrderJoin | None = None,
     ) -> DataFrame:
@@ -7672,6 +7806,8 @@ class DataFrame:
 
         nulls_equal
             Join on null values. By default null values will never produce matches.
+            Pass a sequence of booleans, one per join key, to only let null values
+            match on some of the keys.
         coalesce
             Coalescing behavior (merging of join columns).
 
@@ -8073,7 +8209,13 @@ class DataFrame:
         else:
             return self._from_pydf(self._df.hstack([s._s for s in columns]))
 
-    def vstack(self, other: DataFrame, *, in_place: bool = False) -> DataFrame:
+    def vstack(
+        self,
+        other: DataFrame,
+        *,
+        in_place: bool = False,
+        enum_policy: EnumPolicy = "strict",
+    ) -> DataFrame:
         """
         Grow this DataFrame vertically by stacking a DataFrame to it.
 
@@ -8083,6 +8225,17 @@ class DataFrame:
             DataFrame to stack.
         in_place
             Modify in place.
+        enum_policy : {'strict', 'extend'}
+            What to do if an `Enum` column of `other` holds categories that the
+            column of this DataFrame lacks.
+
+            - 'strict': raise an error.
+            - 'extend': add the missing categories after the existing ones. The
+              existing values are kept as is, only the appended values are remapped.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         See Also
         --------
@@ -8119,17 +8272,17 @@ class DataFrame:
         """
         if in_place:
             try:
-                self._df.vstack_mut(other._df)
+                self._df.vstack_mut(other._df, enum_policy)
             except RuntimeError as exc:
                 if str(exc) == "Already mutably borrowed":
-                    self._df.vstack_mut(other._df.clone())
+                    self._df.vstack_mut(other._df.clone(), enum_policy)
                     return self
                 else:
                     raise
             else:
                 return self
 
-        return self._from_pydf(self._df.vstack(other._df))
+        return self._from_pydf(self._df.vstack(other._df, enum_policy))
 
     def extend(self, other: DataFrame) -> DataFrame:
         """
@@ -10763,6 +10916,88 @@ class DataFrame:
         """
         return self._from_pydf(self._df.null_count())
 
+    @unstable()
+    def value_counts(
+        self,
+        subset: ColumnNameOrSelector | Collection[ColumnNameOrSelector] | None = None,
+        *,
+        sort: bool = False,
+        normalize: bool = False,
+        name: str | None = None,
+    ) -> DataFrame:
+        """
+        Count the occurrences of the unique combinations of values in the columns.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        subset
+            Column name(s) or selector(s) whose unique combinations are counted. If set
+            to `None` (default), use all columns.
+        sort
+            Sort the output by count in descending order.
+            If set to `False` (default), the order of the output is random.
+        normalize
+            Give the relative frequencies of the unique combinations instead of their
+            counts.
+        name
+            Give the resulting count column a specific name;
+            if `normalize` is True defaults to "proportion",
+            otherwise defaults to "count".
+
+        Returns
+        -------
+        DataFrame
+            The unique combinations of the `subset` columns and their count.
+
+        See Also
+        --------
+        polars.Series.value_counts
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "color": ["red", "blue", "red", "green", "blue", "blue"],
+        ...         "size": ["S", "M", "S", "S", "M", "L"],
+        ...     }
+        ... )
+        >>> df.value_counts("color", sort=True)
+        shape: (3, 2)
+        ┌───────┬───────┐
+        │ color ┆ count │
+        │ ---   ┆ ---   │
+        │ str   ┆ u32   │
+        ╞═══════╪═══════╡
+        │ blue  ┆ 3     │
+        │ red   ┆ 2     │
+        │ green ┆ 1     │
+        └───────┴───────┘
+
+        Count the combinations of multiple columns, or give relative frequencies.
+
+        >>> df.value_counts(["color", "size"])  # doctest: +IGNORE_RESULT
+        >>> df.value_counts("size", sort=True, normalize=True)
+        shape: (3, 2)
+        ┌──────┬────────────┐
+        │ size ┆ proportion │
+        │ ---  ┆ ---        │
+        │ str  ┆ f64        │
+        ╞══════╪════════════╡
+        │ S    ┆ 0.5        │
+        │ M    ┆ 0.333333   │
+        │ L    ┆ 0.166667   │
+        └──────┴────────────┘
+        """
+        return (
+            self.lazy()
+            .value_counts(subset, sort=sort, normalize=normalize, name=name)
+            .collect(optimizations=QueryOptFlags._eager())
+        )
+
     def sample(
         self,
         n: int | Series | None = None,
@@ -9,13 +9,14 @@ from polars._utils.deprecation import deprecated
 if TYPE_CHECKING:
     import sys
     from collections.abc import Iterable
-    from datetime import timedelta
+    from datetime import date, datetime, timedelta
 
     from polars import DataFrame
     from polars._typing import (
         ClosedInterval,
         IntoExpr,
         Label,
+        MonthEndPolicy,
         QuantileMethod,
         SchemaDict,
         StartBy,
@@ -929,6 +930,9 @@ class DynamicGroupBy:
         label: Label,
         group_by: IntoExpr | Iterable[IntoExpr] | None,
         start_by: StartBy,
+        month_end_policy: MonthEndPolicy | None,
+        origin: datetime | date | int | None,
+        label_offset: str | timedelta | None,
     ) -> None:
         every = parse_as_duration_string(every)
         period = parse_as_duration_string(period)
@@ -944,6 +948,9 @@ class DynamicGroupBy:
         self.closed = closed
         self.group_by = group_by
         self.start_by = start_by
+        self.month_end_policy = month_end_policy
+        self.origin = origin
+        self.label_offset = label_offset
 
     def __iter__(self) -> Self:
         from polars.lazyframe.opt_flags import QueryOptFlags
@@ -961,6 +968,9 @@ class DynamicGroupBy:
                 closed=self.closed,
                 group_by=self.group_by,
                 start_by=self.start_by,
+                month_end_policy=self.month_end_policy,
+                origin=self.origin,
+                label_offset=self.label_offset,
             )
             .agg(F.first().agg_groups().alias(temp_col))
             .collect(optimizations=QueryOptFlags.none())
@@ -1014,6 +1024,9 @@ class DynamicGroupBy:
                 closed=self.closed,
                 group_by=self.group_by,
                 start_by=self.start_by,
+                month_end_policy=self.month_end_policy,
+                origin=self.origin,
+                label_offset=self.label_offset,
             )
             .agg(*aggs, **named_aggs)
             .collect(optimizations=QueryOptFlags.none())
@@ -1061,6 +1074,9 @@ class DynamicGroupBy:
                 closed=self.closed,
                 group_by=self.group_by,
                 start_by=self.start_by,
+                month_end_policy=self.month_end_policy,
+                origin=self.origin,
+                label_offset=self.label_offset,
             )
             .map_groups(function, schema)
             .collect(optimizations=QueryOptFlags.none())
@@ -2,10 +2,13 @@ from __future__ import annotations
 
 from typing import TYPE_CHECKING
 
+from polars._utils.unstable import unstable
 from polars._utils.various import qualified_type_name
 from polars._utils.wrap import wrap_expr
 
 if TYPE_CHECKING:
+    from collections.abc import Iterable
+
     from polars import Expr
 
 
@@ -40,6 +43,33 @@ class ExprCatNameSpace:
         """
         return wrap_expr(self._pyexpr.cat_get_categories())
 
+    @unstable()
+    def extend_categories(self, categories: Iterable[str]) -> Expr:
+        """
+        Add categories to an `Enum`.
+
+        The new categories are placed after the existing ones, and categories that
+        are already present are ignored. The values themselves are not touched.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        categories
+            The categories to add.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"dir": ["north", "south"]}, schema={"dir": pl.Enum(["north", "south"])}
+        ... )
+        >>> df.select(pl.col("dir").cat.extend_categories(["east", "north"])).schema
+        Schema({'dir': Enum(categories=['north', 'south', 'east'])})
+        """
+        return wrap_expr(self._pyexpr.cat_extend_categories(list(categories)))
+
     def len_bytes(self) -> Expr:
         """
         Return the byte-length of the string representation of each value.
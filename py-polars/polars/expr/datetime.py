@@ -2404,6 +2404,139 @@ class ExprDateTimeNameSpace:
         by = parse_into_expression(by, str_as_lit=True)
         return wrap_expr(self._pyexpr.dt_offset_by(by))
 
+    @unstable()
+    def missing_periods(self, every: str | dt.timedelta) -> Expr:
+        """
+        Get the timestamps that are missing from a regular grid.
+
+        The grid starts at the earliest value and steps by `every` up to the latest
+        value. The missing timestamps are returned in ascending order, so this
+        changes the length of the column. In a group-by context, this returns the
+        missing timestamps of every group as a list.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        every
+            Interval of the grid, e.g. "1h" or "1d". See :meth:`offset_by` for
+            the string language. For dates, this must be a whole number of days.
+
+        Returns
+        -------
+        Expr
+            Expression of the same data type.
+
+        Notes
+        -----
+        The values don't have to be sorted and nulls are ignored. Values that
+        are not on the grid are ignored as well.
+
+        See Also
+        --------
+        gaps
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "sensor": ["a", "a", "a", "b", "b"],
+        ...         "time": [
+        ...             datetime(2024, 1, 1, 0),
+        ...             datetime(2024, 1, 1, 1),
+        ...             datetime(2024, 1, 1, 4),
+        ...             datetime(2024, 1, 1, 0),
+        ...             datetime(2024, 1, 1, 2),
+        ...         ],
+        ...     }
+        ... )
+        >>> df.group_by("sensor", maintain_order=True).agg(
+        ...     pl.col("time").dt.missing_periods("1h")
+        ... ).explode("time")
+        shape: (3, 2)
+        ┌────────┬─────────────────────┐
+        │ sensor ┆ time                │
+        │ ---    ┆ ---                 │
+        │ str    ┆ datetime[μs]        │
+        ╞════════╪═════════════════════╡
+        │ a      ┆ 2024-01-01 02:00:00 │
+        │ a      ┆ 2024-01-01 03:00:00 │
+        │ b      ┆ 2024-01-01 01:00:00 │
+        └────────┴─────────────────────┘
+        """
+        if isinstance(every, dt.timedelta):
+            every = parse_as_duration_string(every)
+        return wrap_expr(self._pyexpr.dt_missing_periods(every))
+
+    @unstable()
+    def gaps(self, interval: str | dt.timedelta) -> Expr:
+        """
+        Get the gaps between consecutive values that are further apart than expected.
+
+        Every gap is a struct with the fields `start` and `end`, the values before
+        and after the gap. The gaps are returned in ascending order, so this changes
+        the length of the column. In a group-by context, this returns the gaps of
+        every group as a list.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        interval
+            The expected interval between consecutive values, e.g. "1h" or "1d".
+            See :meth:`offset_by` for the string language. For dates, this must be
+            a whole number of days.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct`.
+
+        Notes
+        -----
+        The values don't have to be sorted and nulls are ignored.
+
+        See Also
+        --------
+        missing_periods
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "sensor": ["a", "a", "a", "b", "b"],
+        ...         "time": [
+        ...             datetime(2024, 1, 1, 0),
+        ...             datetime(2024, 1, 1, 1),
+        ...             datetime(2024, 1, 1, 4),
+        ...             datetime(2024, 1, 1, 0),
+        ...             datetime(2024, 1, 1, 2),
+        ...         ],
+        ...     }
+        ... )
+        >>> df.group_by("sensor", maintain_order=True).agg(
+        ...     pl.col("time").dt.gaps("1h")
+        ... ).explode("time").unnest("time")
+        shape: (2, 3)
+        ┌────────┬─────────────────────┬─────────────────────┐
+        │ sensor ┆ start               ┆ end                 │
+        │ ---    ┆ ---                 ┆ ---                 │
+        │ str    ┆ datetime[μs]        ┆ datetime[μs]        │
+        ╞════════╪═════════════════════╪═════════════════════╡
+        │ a      ┆ 2024-01-01 01:00:00 ┆ 2024-01-01 04:00:00 │
+        │ b      ┆ 2024-01-01 00:00:00 ┆ 2024-01-01 02:00:00 │
+        └────────┴─────────────────────┴─────────────────────┘
+        """
+        if isinstance(interval, dt.timedelta):
+            interval = parse_as_duration_string(interval)
+        return wrap_expr(self._pyexpr.dt_gaps(interval))
+
     def month_start(self) -> Expr:
         """
         Roll backward to the first day of the month.
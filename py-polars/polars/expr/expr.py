@@ -75,6 +75,7 @@ if TYPE_CHECKING:
         IntoExpr,
         IntoExprColumn,
         MapElementsStrategy,
+        ModeTiePolicy,
         NullBehavior,
         NumericLiteral,
         PolarsDataType,
@@ -1762,12 +1763,25 @@ class Expr:
         other = parse_into_expression(other)
         return self._from_pyexpr(self._pyexpr.dot(other))
 
-    def mode(self) -> Expr:
+    def mode(self, ties: ModeTiePolicy = "all") -> Expr:
         """
         Compute the most occurring value(s).
 
         Can return multiple Values.
 
+        Parameters
+        ----------
+        ties : {'all', 'smallest', 'first', 'all_as_list'}
+            How to resolve ties between values that occur equally often.
+
+            - 'all': return all of them, in no particular order.
+            - 'smallest': return the smallest of them.
+            - 'first': return the one that occurs first.
+            - 'all_as_list': return all of them in ascending order, as a single list.
+
+            All policies except 'all' return a single value, so they can be used as
+            an aggregation in a group-by.
+
         Examples
         --------
         >>> df = pl.DataFrame(
@@ -1785,8 +1799,46 @@ class Expr:
         ╞═════╪═════╡
         │ 1   ┆ 1   │
         └─────┴─────┘
+
+        Resolve ties by taking the smallest value.
+
+        >>> df.select(pl.all().mode(ties="smallest"))
+        shape: (1, 2)
+        ┌─────┬─────┐
+        │ a   ┆ b   │
+        │ --- ┆ --- │
+        │ i64 ┆ i64 │
+        ╞═════╪═════╡
+        │ 1   ┆ 1   │
+        └─────┴─────┘
+        """
+        return self._from_pyexpr(self._pyexpr.mode(ties))
+
+    @unstable()
+    def top_k_frequent(self, k: int) -> Expr:
+        """
+        Get the `k` most occurring values together with their counts.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The result is a single list of structs with the fields `<name>` and `count`,
+        ordered by descending count. Values that occur equally often are ordered by
+        their first occurrence.
+
+        Parameters
+        ----------
+        k
+            Number of values to return.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["x", "y", "y", "z", "z", "z"]})
+        >>> df.select(pl.col("a").top_k_frequent(2)).to_series().to_list()
+        [[{'a': 'z', 'count': 3}, {'a': 'y', 'count': 2}]]
         """
-        return self._from_pyexpr(self._pyexpr.mode())
+        return self._from_pyexpr(self._pyexpr.top_k_frequent(k))
 
     def cast(
         self,
@@ -2682,6 +2734,95 @@ class Expr:
         index_lit = parse_into_expression(index)
         return self._from_pyexpr(self._pyexpr.get(index_lit))
 
+    @unstable()
+    def scatter(
+        self,
+        indices: Sequence[int] | IntoExpr | Series | np.ndarray[Any, Any],
+        values: IntoExpr,
+    ) -> Expr:
+        """
+        Replace the values at the given indices.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        This is the inverse of :meth:`gather`. Unlike :meth:`Series.scatter`, it does
+        not modify any data in place and can be used lazily and per group, in which
+        case the indices are relative to the group.
+
+        Parameters
+        ----------
+        indices
+            Integer indices, where negative indices count from the end, or a boolean
+            mask with the length of this expression. If an index occurs more than
+            once, the last value is used.
+        values
+            A single value, or a value for every index. Given a mask, this may also
+            have the length of this expression, in which case the values at the
+            masked positions are used.
+
+        See Also
+        --------
+        Series.scatter
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"group": ["a", "a", "b", "b"], "x": [1, 2, 3, 4]})
+        >>> df.select(pl.col("x").scatter([0, -1], [10, 40]))
+        shape: (4, 1)
+        ┌─────┐
+        │ x   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 10  │
+        │ 2   │
+        │ 3   │
+        │ 40  │
+        └─────┘
+
+        Set the first value of every group.
+
+        >>> df.with_columns(pl.col("x").scatter(0, 0).over("group"))
+        shape: (4, 2)
+        ┌───────┬─────┐
+        │ group ┆ x   │
+        │ ---   ┆ --- │
+        │ str   ┆ i64 │
+        ╞═══════╪═════╡
+        │ a     ┆ 0   │
+        │ a     ┆ 2   │
+        │ b     ┆ 0   │
+        │ b     ┆ 4   │
+        └───────┴─────┘
+
+        Use a mask to replace values by a condition.
+
+        >>> df.select(pl.col("x").scatter(pl.col("x") > 2, -pl.col("x")))
+        shape: (4, 1)
+        ┌─────┐
+        │ x   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 1   │
+        │ 2   │
+        │ -3  │
+        │ -4  │
+        └─────┘
+        """
+        if (isinstance(indices, Sequence) and not isinstance(indices, str)) or (
+            _check_for_numpy(indices) and isinstance(indices, np.ndarray)
+        ):
+            indices_pyexpr = F.lit(pl.Series("", indices, dtype=Int64))._pyexpr
+        else:
+            indices_pyexpr = parse_into_expression(indices)  # type: ignore[arg-type]
+        values_pyexpr = parse_into_expression(
+            values, str_as_lit=True, list_as_series=True
+        )
+        return self._from_pyexpr(self._pyexpr.scatter(indices_pyexpr, values_pyexpr))
+
     def shift(
         self, n: int | IntoExprColumn = 1, *, fill_value: IntoExpr | None = None
     ) -> Expr:
@@ -2758,6 +2899,70 @@ class Expr:
         n = parse_into_expression(n)
         return self._from_pyexpr(self._pyexpr.shift(n, fill_value))
 
+    @unstable()
+    def shift_by_time(self, by: IntoExpr, period: str | timedelta) -> Expr:
+        """
+        Shift values by a period of time rather than a number of indices.
+
+        Every row gets the value of the row whose `by` value is exactly `period`
+        earlier, or null if there is no such row. Unlike :meth:`shift`, this doesn't
+        depend on the rows being sorted or evenly spaced in time.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        by
+            Column of data type :class:`Date` or :class:`Datetime` with the time of
+            every row. Its values must be unique; use :meth:`over` to shift within
+            groups.
+        period
+            The period to shift forward, e.g. "1h" or "1mo". See
+            :meth:`Expr.dt.offset_by` for the string language. If a negative period
+            is passed, values are shifted in the opposite direction instead.
+
+        See Also
+        --------
+        shift
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "id": ["a", "a", "a", "b", "b"],
+        ...         "time": [
+        ...             datetime(2024, 1, 1, 0),
+        ...             datetime(2024, 1, 1, 1),
+        ...             datetime(2024, 1, 1, 3),
+        ...             datetime(2024, 1, 1, 0),
+        ...             datetime(2024, 1, 1, 1),
+        ...         ],
+        ...         "value": [1, 2, 3, 10, 20],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     prev=pl.col("value").shift_by_time("time", "1h").over("id")
+        ... )
+        shape: (5, 4)
+        ┌─────┬─────────────────────┬───────┬──────┐
+        │ id  ┆ time                ┆ value ┆ prev │
+        │ --- ┆ ---                 ┆ ---   ┆ ---  │
+        │ str ┆ datetime[μs]        ┆ i64   ┆ i64  │
+        ╞═════╪═════════════════════╪═══════╪══════╡
+        │ a   ┆ 2024-01-01 00:00:00 ┆ 1     ┆ null │
+        │ a   ┆ 2024-01-01 01:00:00 ┆ 2     ┆ 1    │
+        │ a   ┆ 2024-01-01 03:00:00 ┆ 3     ┆ null │
+        │ b   ┆ 2024-01-01 00:00:00 ┆ 10    ┆ null │
+        │ b   ┆ 2024-01-01 01:00:00 ┆ 20    ┆ 10   │
+        └─────┴─────────────────────┴───────┴──────┘
+        """
+        by = parse_into_expression(by)
+        period = parse_as_duration_string(period)
+        return self._from_pyexpr(self._pyexpr.shift_by_time(by, period))
+
     def fill_null(
         self,
         value: Any | Expr | None = None,
@@ -3202,6 +3407,129 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.product())
 
+    @unstable()
+    def geo_mean(self) -> Expr:
+        """
+        Compute the geometric mean of an expression.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The geometric mean is computed as the exponent of the mean of the natural
+        logarithms, so it doesn't overflow like the root of the product would.
+        Null values are ignored and negative values result in NaN.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 4, None, 16]})
+        >>> df.select(pl.col("a").geo_mean())
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 4.0 │
+        └─────┘
+        """
+        return self._from_pyexpr(self._pyexpr.geo_mean())
+
+    @unstable()
+    def harmonic_mean(self) -> Expr:
+        """
+        Compute the harmonic mean of an expression.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Null values are ignored, a value of zero results in zero.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1.0, None, 4.0, 4.0]})
+        >>> df.select(pl.col("a").harmonic_mean())
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 2.0 │
+        └─────┘
+        """
+        return self._from_pyexpr(self._pyexpr.harmonic_mean())
+
+    @unstable()
+    def twap(
+        self,
+        by: IntoExpr,
+        *,
+        start: IntoExpr | None = None,
+        end: IntoExpr | None = None,
+    ) -> Expr:
+        """
+        Compute the time-weighted average (TWAP) of an expression.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Every value is weighted by the time until the next observation, which makes
+        this the mean of a series that holds each value until the next one arrives.
+        The last observation has no next observation, so it has no weight, unless
+        all observations have the same time, in which case its value is returned.
+        Observations where the value or the time is null are ignored.
+
+        Use it in `group_by`, `rolling` or `over` to compute the time-weighted
+        average per window or group.
+
+        Parameters
+        ----------
+        by
+            The times of the observations, sorted in ascending order. Must be of
+            numeric, Date, Datetime or Duration type.
+        start
+            Start of the window to average over. The last observation at or before
+            `start` holds its value from `start` on, and earlier observations are
+            ignored. If None, the window starts at the first observation.
+        end
+            End of the window to average over. The last observation before `end`
+            holds its value until `end`, and later observations are ignored. If
+            None, the window ends at the last observation.
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "time": [
+        ...             datetime(2025, 1, 1, 9, 0),
+        ...             datetime(2025, 1, 1, 9, 30),
+        ...             datetime(2025, 1, 1, 9, 45),
+        ...             datetime(2025, 1, 1, 10, 0),
+        ...         ],
+        ...         "price": [10.0, 12.0, 11.0, 13.0],
+        ...     }
+        ... )
+        >>> df.select(pl.col("price").twap("time"))
+        shape: (1, 1)
+        ┌───────┐
+        │ price │
+        │ ---   │
+        │ f64   │
+        ╞═══════╡
+        │ 10.75 │
+        └───────┘
+        """
+        by_pyexpr = parse_into_expression(by)
+        start_pyexpr = None if start is None else parse_into_expression(start)
+        end_pyexpr = None if end is None else parse_into_expression(end)
+        return self._from_pyexpr(
+            self._pyexpr.twap(by_pyexpr, start_pyexpr, end_pyexpr)
+        )
+
     def n_unique(self) -> Expr:
         """
         Count unique values.
@@ -5801,7 +6129,7 @@ class Expr:
 
     def is_in(
         self,
-        other: Expr | Collection[Any] | Series,
+        other: Expr | Collection[Any] | Series | LazyFrame,
         *,
         nulls_equal: bool = False,
     ) -> Expr:
@@ -5812,6 +6140,11 @@ class Expr:
         ----------
         other
             Series or sequence of primitive type.
+
+            A LazyFrame with a single column can be given as a subquery in a filter.
+            The filter is then executed as a semi-join with the LazyFrame (an
+            anti-join if the `is_in` is negated), without collecting its values
+            first. This is only supported in the top-level conjunction of a filter.
         nulls_equal : bool, default False
             If True, treat null as a distinct value. Null values will not propagate.
 
@@ -5837,6 +6170,10 @@ class Expr:
         │ [9, 10]   ┆ 3                ┆ false    │
         └───────────┴──────────────────┴──────────┘
         """
+        if isinstance(other, pl.LazyFrame):
+            return self._from_pyexpr(
+                self._pyexpr.is_in(other._ldf.into_subquery(), nulls_equal)
+            )
         if isinstance(other, Collection) and not isinstance(other, (str, pl.Series)):
             other = list(other)  # eg: set, frozenset, etc
 
@@ -8553,6 +8890,47 @@ class Expr:
             )
         )
 
+    @unstable()
+    def rolling_map_registered(
+        self,
+        name: str,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Apply a registered rolling aggregator over a rolling window.
+
+        The aggregator is updated as values enter and leave the window, see
+        :func:`polars.plugins.register_rolling_aggregator`. The values are cast to
+        :class:`Float64`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        name
+            The name the aggregator was registered as.
+        window_size
+            The length of the window in number of elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        See Also
+        --------
+        polars.plugins.register_rolling_aggregator
+        rolling_map
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_map_registered(name, window_size, min_samples, center)
+        )
+
     def abs(self) -> Expr:
         """
         Compute absolute values.
@@ -10200,8 +10578,10 @@ class Expr:
 
         Warnings
         --------
-        This can be really slow as it can have `O(n^2)` complexity. Don't use this
-        for operations that visit all elements.
+        Expressions that aggregate every window to a single value (e.g. `sum`, `mean`,
+        `min` or `max`, combined with elementwise expressions) run in a single pass.
+        Other expressions are evaluated once per window, which can be really slow as
+        it has `O(n^2)` complexity.
 
         Examples
         --------
@@ -10230,6 +10610,44 @@ class Expr:
             self._pyexpr.cumulative_eval(expr._pyexpr, min_samples, parallel)
         )
 
+    @unstable()
+    def cache(self) -> Expr:
+        """
+        Compute this expression once per frame and share the result within a context.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        All occurrences of a cached expression in the same `select`, `with_columns`,
+        `group_by`, `filter` or `sort` are computed once. Unlike the automatic
+        elimination of common subexpressions, this also applies to the branches of
+        `when/then/otherwise` and to elementwise expressions in window functions and
+        aggregations, which are then computed on the whole frame before grouping.
+        Cached expressions that aren't elementwise are only shared where they are
+        evaluated on the whole frame.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "x"]})
+        >>> expensive = (pl.col("a") * 10).cache()
+        >>> df.select(
+        ...     pl.when(pl.col("b") == "x").then(expensive).otherwise(-expensive),
+        ...     expensive.sum().over("b").alias("sum"),
+        ... )
+        shape: (3, 2)
+        ┌─────┬─────┐
+        │ a   ┆ sum │
+        │ --- ┆ --- │
+        │ i64 ┆ i64 │
+        ╞═════╪═════╡
+        │ 10  ┆ 40  │
+        │ -20 ┆ 20  │
+        │ 30  ┆ 40  │
+        └─────┴─────┘
+        """
+        return self._from_pyexpr(self._pyexpr.cache())
+
     def set_sorted(self, *, descending: bool = False) -> Expr:
         """
         Flags the expression as 'sorted'.
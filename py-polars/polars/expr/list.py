@@ -18,6 +18,7 @@ if TYPE_CHECKING:
     from polars._typing import (
         IntoExpr,
         IntoExprColumn,
+        ListSetNulls,
         ListToStructWidthStrategy,
         NullBehavior,
     )
@@ -1097,6 +1098,9 @@ class ExprListNameSpace:
         fields: Sequence[str] | Callable[[int], str] | None = None,
         upper_bound: int = 0,
         *,
+        keys: IntoExpr | Sequence[str] | None = None,
+        infer_sample_size: int | None = None,
+        width: int | None = None,
         _eager: bool = False,
     ) -> Expr:
         """
@@ -1125,6 +1129,18 @@ class ExprListNameSpace:
             When operating on a `DataFrame`, the schema does not need to be
             tracked or pre-determined, as the result will be eagerly evaluated,
             so you can leave this parameter unset.
+        keys
+            Lists of keys that give the field of the value at the same position, or
+            a single list of keys for all rows, e.g. the header of a JSON array.
+            The fields are the keys in the order in which they first occur, unless
+            `fields` is given as a list of names, in which case all other keys are
+            ignored. Missing keys result in null values.
+        infer_sample_size
+            Only look at the first `infer_sample_size` sublists to determine the
+            number of fields with the "max_width" strategy.
+        width
+            Use exactly this many fields instead of inferring the number from the
+            data. Unlike `upper_bound`, this makes the schema and the result agree.
 
         Notes
         -----
@@ -1184,19 +1200,44 @@ class ExprListNameSpace:
         ...     named=True
         ... )
         [{'n': {'one': 0, 'two': 1}}, {'n': {'one': 2, 'two': 3}}]
+
+        Take the field names from a list of keys:
+
+        >>> df = pl.DataFrame({"n": [[0, 1], [2, 3]], "k": [["a", "b"], ["b", "c"]]})
+        >>> df.select(
+        ...     pl.col("n").list.to_struct(keys="k", fields=["a", "b", "c"])
+        ... ).rows(named=True)
+        [{'n': {'a': 0, 'b': 1, 'c': None}}, {'n': {'a': None, 'b': 2, 'c': 3}}]
         """
+        if keys is not None:
+            if fields is not None and not isinstance(fields, Sequence):
+                msg = "`fields` must be a list of names when `keys` is given"
+                raise TypeError(msg)
+            if fields is None and not _eager:
+                msg = (
+                    "`to_struct()` with `keys` should be passed a list of field names to "
+                    "avoid query errors in subsequent operations"
+                )
+                warnings.warn(msg, stacklevel=find_stacklevel())
+            keys_pyexpr = parse_into_expression(keys)  # type: ignore[arg-type]
+            return wrap_expr(self._pyexpr.list_to_struct_with_keys(keys_pyexpr, fields))
         if isinstance(fields, Sequence):
             pyexpr = self._pyexpr.list_to_struct_fixed_width(fields)
             return wrap_expr(pyexpr)
         else:
-            if not _eager:
+            if infer_sample_size is not None and n_field_strategy != "max_width":
+                msg = '`infer_sample_size` requires `n_field_strategy="max_width"`'
+                raise ValueError(msg)
+            if not _eager and width is None:
                 msg = (
                     "`to_struct()` should be passed a list of field names to avoid "
                     "query errors in subsequent operations (e.g. <struct operation> "
                     "not supported for dtype Unknown)"
                 )
                 warnings.warn(msg, stacklevel=find_stacklevel())
-            pyexpr = self._pyexpr.list_to_struct(n_field_strategy, fields, upper_bound)
+            pyexpr = self._pyexpr.list_to_struct(
+                n_field_strategy, fields, upper_bound, infer_sample_size, width
+            )
             return wrap_expr(pyexpr)
 
     def eval(self, expr: Expr, *, parallel: bool = False) -> Expr:
@@ -1234,7 +1275,14 @@ class ExprListNameSpace:
         """
         return wrap_expr(self._pyexpr.list_eval(expr._pyexpr, parallel))
 
-    def set_union(self, other: IntoExpr | Collection[Any]) -> Expr:
+    def set_union(
+        self,
+        other: IntoExpr | Collection[Any],
+        *,
+        multiset: bool = False,
+        maintain_order: bool = False,
+        nulls: ListSetNulls = "equal",
+    ) -> Expr:
         """
         Compute the SET UNION between the elements in this list and the elements of `other`.
 
@@ -1242,6 +1290,18 @@ class ExprListNameSpace:
         ----------
         other
             Right hand side of the set operation.
+        multiset
+            Keep duplicate elements, so that an element that occurs `m` times in this
+            list and `n` times in `other` occurs `max(m, n)` times in the result.
+        maintain_order
+            Guarantee that the elements keep their order, with the elements of this
+            list first. Multiset operations always keep the order.
+        nulls : {'equal', 'distinct', 'ignore'}
+            How to treat null elements.
+
+            - 'equal': null elements are equal to each other.
+            - 'distinct': null elements are never equal to each other.
+            - 'ignore': null elements are removed from both lists first.
 
         Examples
         --------
@@ -1272,9 +1332,20 @@ class ExprListNameSpace:
             other = F.lit(other)._pyexpr
         else:
             other = parse_into_expression(other)
-        return wrap_expr(self._pyexpr.list_set_operation(other, "union"))
+        return wrap_expr(
+            self._pyexpr.list_set_operation(
+                other, "union", multiset, maintain_order, nulls
+            )
+        )
 
-    def set_difference(self, other: IntoExpr | Collection[Any]) -> Expr:
+    def set_difference(
+        self,
+        other: IntoExpr | Collection[Any],
+        *,
+        multiset: bool = False,
+        maintain_order: bool = False,
+        nulls: ListSetNulls = "equal",
+    ) -> Expr:
         """
         Compute the SET DIFFERENCE between the elements in this list and the elements of `other`.
 
@@ -1282,6 +1353,18 @@ class ExprListNameSpace:
         ----------
         other
             Right hand side of the set operation.
+        multiset
+            Keep duplicate elements, so that an element that occurs `m` times in this
+            list and `n` times in `other` occurs `m - n` (if positive) times in the result.
+        maintain_order
+            Guarantee that the elements keep their order, with the elements of this
+            list first. Multiset operations always keep the order.
+        nulls : {'equal', 'distinct', 'ignore'}
+            How to treat null elements.
+
+            - 'equal': null elements are equal to each other.
+            - 'distinct': null elements are never equal to each other.
+            - 'ignore': null elements are removed from both lists first.
 
         Examples
         --------
@@ -1314,9 +1397,20 @@ class ExprListNameSpace:
             other = F.lit(other)._pyexpr
         else:
             other = parse_into_expression(other)
-        return wrap_expr(self._pyexpr.list_set_operation(other, "difference"))
+        return wrap_expr(
+            self._pyexpr.list_set_operation(
+                other, "difference", multiset, maintain_order, nulls
+            )
+        )
 
-    def set_intersection(self, other: IntoExpr | Collection[Any]) -> Expr:
+    def set_intersection(
+        self,
+        other: IntoExpr | Collection[Any],
+        *,
+        multiset: bool = False,
+        maintain_order: bool = False,
+        nulls: ListSetNulls = "equal",
+    ) -> Expr:
         """
         Compute the SET INTERSECTION between the elements in this list and the elements of `other`.
 
@@ -1324,6 +1418,18 @@ class ExprListNameSpace:
         ----------
         other
             Right hand side of the set operation.
+        multiset
+            Keep duplicate elements, so that an element that occurs `m` times in this
+            list and `n` times in `other` occurs `min(m, n)` times in the result.
+        maintain_order
+            Guarantee that the elements keep their order, with the elements of this
+            list first. Multiset operations always keep the order.
+        nulls : {'equal', 'distinct', 'ignore'}
+            How to treat null elements.
+
+            - 'equal': null elements are equal to each other.
+            - 'distinct': null elements are never equal to each other.
+            - 'ignore': null elements are removed from both lists first.
 
         Examples
         --------
@@ -1352,9 +1458,20 @@ class ExprListNameSpace:
             other = F.lit(other)._pyexpr
         else:
             other = parse_into_expression(other)
-        return wrap_expr(self._pyexpr.list_set_operation(other, "intersection"))
+        return wrap_expr(
+            self._pyexpr.list_set_operation(
+                other, "intersection", multiset, maintain_order, nulls
+            )
+        )
 
-    def set_symmetric_difference(self, other: IntoExpr | Collection[Any]) -> Expr:
+    def set_symmetric_difference(
+        self,
+        other: IntoExpr | Collection[Any],
+        *,
+        multiset: bool = False,
+        maintain_order: bool = False,
+        nulls: ListSetNulls = "equal",
+    ) -> Expr:
         """
         Compute the SET SYMMETRIC DIFFERENCE between the elements in this list and the elements of `other`.
 
@@ -1362,6 +1479,18 @@ class ExprListNameSpace:
         ----------
         other
             Right hand side of the set operation.
+        multiset
+            Keep duplicate elements, so that an element that occurs `m` times in this
+            list and `n` times in `other` occurs `abs(m - n)` times in the result.
+        maintain_order
+            Guarantee that the elements keep their order, with the elements of this
+            list first. Multiset operations always keep the order.
+        nulls : {'equal', 'distinct', 'ignore'}
+            How to treat null elements.
+
+            - 'equal': null elements are equal to each other.
+            - 'distinct': null elements are never equal to each other.
+            - 'ignore': null elements are removed from both lists first.
 
         Examples
         --------
@@ -1390,4 +1519,8 @@ class ExprListNameSpace:
             other = F.lit(other)._pyexpr
         else:
             other = parse_into_expression(other)
-        return wrap_expr(self._pyexpr.list_set_operation(other, "symmetric_difference"))
+        return wrap_expr(
+            self._pyexpr.list_set_operation(
+                other, "symmetric_difference", multiset, maintain_order, nulls
+            )
+        )
@@ -4,6 +4,7 @@ import os
 from typing import TYPE_CHECKING
 
 from polars._utils.parse import parse_into_list_of_expressions
+from polars._utils.unstable import unstable
 from polars._utils.various import qualified_type_name
 from polars._utils.wrap import wrap_expr
 
@@ -253,6 +254,37 @@ class ExprStructNameSpace:
         """
         return wrap_expr(self._pyexpr.struct_rename_fields(names))
 
+    @unstable()
+    def to_array(self) -> Expr:
+        """
+        Convert this struct to an array with one element per field.
+
+        All fields must have the same data type. The elements follow the order of the
+        fields and a null struct becomes a null array. This is the inverse of
+        :meth:`Expr.arr.to_struct`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"xyz": [{"x": 1, "y": 2, "z": 3}, {"x": 4, "y": 5, "z": 6}]}
+        ... )
+        >>> df.select(pl.col("xyz").struct.to_array())
+        shape: (2, 1)
+        ┌───────────────┐
+        │ xyz           │
+        │ ---           │
+        │ array[i64, 3] │
+        ╞═══════════════╡
+        │ [1, 2, 3]     │
+        │ [4, 5, 6]     │
+        └───────────────┘
+        """
+        return wrap_expr(self._pyexpr.struct_to_array())
+
     def json_encode(self) -> Expr:
         """
         Convert this struct to a string column with json values.
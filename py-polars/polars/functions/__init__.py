@@ -44,6 +44,7 @@ from polars.functions.lazy import (
     cum_fold,
     cum_reduce,
     element,
+    encode_rows,
     exclude,
     explain_all,
     field,
@@ -53,6 +54,7 @@ from polars.functions.lazy import (
     groups,
     head,
     implode,
+    is_duplicated_by,
     last,
     map_batches,
     map_groups,
@@ -60,6 +62,7 @@ from polars.functions.lazy import (
     median,
     n_unique,
     nth,
+    parse_expr,
     quantile,
     reduce,
     rolling_corr,
@@ -68,6 +71,7 @@ from polars.functions.lazy import (
     sql_expr,
     std,
     tail,
+    values,
     var,
 )
 from polars.functions.len import len
@@ -83,6 +87,8 @@ from polars.functions.range import (
     int_ranges,
     linear_space,
     linear_spaces,
+    scan_date_range,
+    scan_int_range,
     time_range,
     time_ranges,
 )
@@ -115,6 +121,8 @@ __all__ = [
     "element",
     "ones",
     "repeat",
+    "scan_date_range",
+    "scan_int_range",
     "time_range",
     "time_ranges",
     "zeros",
@@ -140,6 +148,7 @@ __all__ = [
     "date",  # named date_, see import above
     "datetime",  # named datetime_, see import above
     "duration",
+    "encode_rows",
     "exclude",
     "explain_all",
     "field",
@@ -150,6 +159,7 @@ __all__ = [
     "groups",
     "head",
     "implode",
+    "is_duplicated_by",
     "int_range",
     "int_ranges",
     "last",
@@ -173,12 +183,14 @@ __all__ = [
     "struct",
     "tail",
     "time",
+    "values",
     "var",
     # polars.functions.len
     "len",
     # polars.functions.whenthen
     "when",
     "sql_expr",
+    "parse_expr",
     # polars.functions.escape_regex
     "escape_regex",
 ]
@@ -9,6 +9,7 @@ from typing import TYPE_CHECKING, get_args
 import polars._reexport as pl
 from polars import functions as F
 from polars._typing import ConcatMethod
+from polars._utils.unstable import issue_unstable_warning
 from polars._utils.various import ordered_unique, qualified_type_name
 from polars._utils.wrap import wrap_df, wrap_expr, wrap_ldf, wrap_s
 from polars.exceptions import InvalidOperationError
@@ -317,6 +318,26 @@ def _alignment_join(
     # collect eagerly when hitting a large enough number of frames
     post_align_collect = len(idx_frames) >= 250
 
+    # the keys of the result are determined once from all frames, after which
+    # every frame is left-joined onto them (this gives the same rows as joining
+    # the frames to each other with `how`, also for duplicate keys)
+    frame_keys = [lf.select(align_on).unique() for _, lf in idx_frames]
+    if how == "left":
+        keys = frame_keys[0]
+    elif how == "inner":
+        count = "__POLARS_ALIGN_COUNT"
+        keys = (
+            F.concat(frame_keys)
+            .group_by(align_on)
+            .agg(F.len().alias(count))
+            .filter(F.col(count) == len(frame_keys))
+            .drop(count)
+        )
+    elif how == "full":
+        keys = F.concat(frame_keys).unique()
+    else:
+        keys = None
+
     def join_func(
         idx_x: tuple[int, LazyFrame],
         idx_y: tuple[int, LazyFrame],
@@ -324,16 +345,18 @@ def _alignment_join(
         (_, x), (y_idx, y) = idx_x, idx_y
         return y_idx, x.join(
             y,
-            how=how,
+            how=how if keys is None else "left",
             on=align_on,
             suffix=f":{y_idx}",
             nulls_equal=True,
             coalesce=True,
-            maintain_order="right_left",
+            maintain_order="right_left" if keys is None else "left",
         )
 
     from polars.lazyframe import QueryOptFlags
 
+    if keys is not None:
+        idx_frames = ((-1, keys), *idx_frames)
     joined = reduce(join_func, idx_frames)[1].sort(by=align_on, descending=descending)
     if post_align_collect:
         joined = joined.collect(optimizations=QueryOptFlags.none()).lazy()
@@ -346,6 +369,7 @@ def align_frames(
     how: JoinStrategy = "full",
     select: str | Expr | Sequence[str | Expr] | None = None,
     descending: bool | Sequence[bool] = False,
+    unique_keys: bool = False,
 ) -> list[FrameType]:
     r"""
     Align a sequence of frames using common values from one or more columns as a key.
@@ -380,6 +404,17 @@ def align_frames(
         By default the row alignment values are determined using a full outer join
         strategy across all frames; if you know that the first frame contains all
         required keys, you can set `how="left"` for a large performance increase.
+    unique_keys
+        Declare that the key values are unique within every frame (an error is
+        raised if they are not). Every frame is then joined to the shared keys on
+        its own, instead of all frames being joined into a single master frame,
+        which is much faster for many frames.
+        Only `how="full"`, `"left"` and `"inner"` are supported, and `on` must
+        be given as column names.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
     Examples
     --------
@@ -498,25 +533,43 @@ def align_frames(
     on = [on] if (isinstance(on, str) or not isinstance(on, Sequence)) else on
     align_on = [(c.meta.output_name() if isinstance(c, pl.Expr) else c) for c in on]
 
-    # create aligned master frame (this is the most expensive part; after
-    # we just select out the columns representing the component frames)
     idx_frames = [(idx, frame.lazy()) for idx, frame in enumerate(frames)]  # type: ignore[union-attr]
-    alignment_frame = _alignment_join(
-        *idx_frames, align_on=align_on, how=how, descending=descending
-    )
-
-    # select-out aligned components from the master frame
-    aligned_cols = set(alignment_frame.collect_schema())
-    aligned_frames = []
-    for idx, lf in idx_frames:
-        sfx = f":{idx}"
-        df_cols = [
-            F.col(f"{c}{sfx}").alias(c) if f"{c}{sfx}" in aligned_cols else F.col(c)
-            for c in lf.collect_schema()
+    if unique_keys:
+        issue_unstable_warning(
+            "`unique_keys` in `align_frames` is considered unstable."
+        )
+        if not all(isinstance(c, str) for c in on):
+            msg = "`on` must be given as column names if `unique_keys` is set"
+            raise TypeError(msg)
+        if isinstance(descending, bool):
+            descending = [descending] * len(align_on)
+        aligned_frames = [
+            wrap_ldf(ldf)
+            for ldf in plr.align_frames(
+                [lf._ldf for _, lf in idx_frames], align_on, how, list(descending)
+            )
         ]
-        f = alignment_frame.select(*df_cols)
-        if select is not None:
-            f = f.select(select)
-        aligned_frames.append(f)
+    else:
+        # create aligned master frame (this is the most expensive part; after
+        # we just select out the columns representing the component frames)
+        alignment_frame = _alignment_join(
+            *idx_frames, align_on=align_on, how=how, descending=descending
+        )
+
+        # select-out aligned components from the master frame
+        aligned_cols = set(alignment_frame.collect_schema())
+        aligned_frames = []
+        for idx, lf in idx_frames:
+            sfx = f":{idx}"
+            df_cols = [
+                F.col(f"{c}{sfx}").alias(c)
+                if f"{c}{sfx}" in aligned_cols
+                else F.col(c)
+                for c in lf.collect_schema()
+            ]
+            aligned_frames.append(alignment_frame.select(*df_cols))
+
+    if select is not None:
+        aligned_frames = [f.select(select) for f in aligned_frames]
 
     return F.collect_all(aligned_frames) if eager else aligned_frames  # type: ignore[return-value]
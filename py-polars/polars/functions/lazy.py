@@ -19,7 +19,7 @@ from polars._utils.parse import (
 )
 from polars._utils.unstable import issue_unstable_warning, unstable
 from polars._utils.various import extend_bool, qualified_type_name
-from polars._utils.wrap import wrap_df, wrap_expr
+from polars._utils.wrap import wrap_df, wrap_expr, wrap_ldf
 from polars.datatypes import DTYPE_TEMPORAL_UNITS, Date, Datetime, Int64
 from polars.lazyframe.opt_flags import (
     DEFAULT_QUERY_OPT_FLAGS,
@@ -42,6 +42,7 @@ if TYPE_CHECKING:
         IntoExpr,
         PolarsDataType,
         QuantileMethod,
+        UniqueKeepStrategy,
     )
     from polars.lazyframe.opt_flags import (
         QueryOptFlags,
@@ -2243,6 +2244,168 @@ def coalesce(
         return wrap_expr(plr.coalesce(exprs))
 
 
+@unstable()
+def is_duplicated_by(
+    exprs: IntoExpr | Iterable[IntoExpr],
+    *more_exprs: IntoExpr,
+    keep: UniqueKeepStrategy = "first",
+) -> Expr:
+    """
+    Mark the rows of which the values of the given columns occur more than once.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    The rows are compared in a single hashed pass over the given columns.
+
+    Parameters
+    ----------
+    exprs
+        Columns to compare the rows by. Accepts expression input. Strings are
+        parsed as column names.
+    *more_exprs
+        Additional columns to compare the rows by, specified as positional
+        arguments.
+    keep : {'first', 'last', 'any', 'none'}
+        Which occurrence of a duplicated row is not marked.
+
+        * 'first' : Don't mark the first occurrence.
+        * 'last' : Don't mark the last occurrence.
+        * 'any' : Same as 'first'.
+        * 'none' : Mark all occurrences.
+
+    See Also
+    --------
+    LazyFrame.duplicates
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"a": [1, 2, 1, 1], "b": ["x", "y", "x", "z"]})
+    >>> df.with_columns(
+    ...     first=pl.is_duplicated_by("a", "b"),
+    ...     none=pl.is_duplicated_by("a", "b", keep="none"),
+    ... )
+    shape: (4, 4)
+    ┌─────┬─────┬───────┬───────┐
+    │ a   ┆ b   ┆ first ┆ none  │
+    │ --- ┆ --- ┆ ---   ┆ ---   │
+    │ i64 ┆ str ┆ bool  ┆ bool  │
+    ╞═════╪═════╪═══════╪═══════╡
+    │ 1   ┆ x   ┆ false ┆ true  │
+    │ 2   ┆ y   ┆ false ┆ false │
+    │ 1   ┆ x   ┆ true  ┆ true  │
+    │ 1   ┆ z   ┆ false ┆ false │
+    └─────┴─────┴───────┴───────┘
+    """
+    exprs = parse_into_list_of_expressions(exprs, *more_exprs)
+    return wrap_expr(plr.is_duplicated_by(exprs, keep))
+
+
+@unstable()
+def encode_rows(
+    exprs: IntoExpr | Iterable[IntoExpr],
+    *more_exprs: IntoExpr,
+    descending: bool | Sequence[bool] = False,
+    nulls_last: bool | Sequence[bool] = False,
+) -> Expr:
+    """
+    Encode the rows of the given columns into a single order-preserving binary key.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Comparing the keys byte-wise gives the same order as sorting by the given
+    columns, so the keys can be used to build custom sort keys, range partitions or
+    to merge sorted data outside of Polars. This is the same encoding the engine
+    uses for multi-key sorts; the exact bytes are not stable across Polars versions.
+
+    Parameters
+    ----------
+    exprs
+        Columns to encode. Accepts expression input. Strings are parsed as column
+        names.
+    *more_exprs
+        Additional columns to encode, specified as positional arguments.
+    descending
+        Encode in descending order. Can be specified per column by passing a
+        sequence of booleans.
+    nulls_last
+        Place null values last. Can be specified per column by passing a sequence
+        of booleans.
+
+    See Also
+    --------
+    arg_sort_by
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"a": [2, None, 1, 2], "b": ["x", "y", "z", "w"]})
+    >>> df.sort(pl.encode_rows("a", "b", descending=[True, False], nulls_last=True))
+    shape: (4, 2)
+    ┌──────┬─────┐
+    │ a    ┆ b   │
+    │ ---  ┆ --- │
+    │ i64  ┆ str │
+    ╞══════╪═════╡
+    │ 2    ┆ w   │
+    │ 2    ┆ x   │
+    │ 1    ┆ z   │
+    │ null ┆ y   │
+    └──────┴─────┘
+    """
+    exprs = parse_into_list_of_expressions(exprs, *more_exprs)
+    descending = extend_bool(descending, len(exprs), "descending", "exprs")
+    nulls_last = extend_bool(nulls_last, len(exprs), "nulls_last", "exprs")
+    return wrap_expr(plr.encode_rows(exprs, descending, nulls_last))
+
+
+@unstable()
+def values(
+    rows: Iterable[Sequence[IntoExpr]],
+    *,
+    names: Sequence[str] | None = None,
+) -> LazyFrame:
+    """
+    Create a LazyFrame from a small inline table of literal values.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Every column gets the supertype of its values. The values are evaluated once
+    when the frame is created, so the table is scanned like any in-memory frame.
+
+    Parameters
+    ----------
+    rows
+        The rows of the table, every row has a value for every column. Accepts
+        expression input, strings are parsed as literals.
+    names
+        The column names. Defaults to `column_0`, `column_1`, etc.
+
+    Examples
+    --------
+    >>> pl.values([("a", 1.5), ("b", None), ("c", 2.0)], names=["key", "weight"]).collect()
+    shape: (3, 2)
+    ┌─────┬────────┐
+    │ key ┆ weight │
+    │ --- ┆ ---    │
+    │ str ┆ f64    │
+    ╞═════╪════════╡
+    │ a   ┆ 1.5    │
+    │ b   ┆ null   │
+    │ c   ┆ 2.0    │
+    └─────┴────────┘
+    """
+    pyrows = [
+        [parse_into_expression(value, str_as_lit=True) for value in row]
+        for row in rows
+    ]
+    return wrap_ldf(plr.values(pyrows, None if names is None else list(names)))
+
+
 @overload
 def from_epoch(column: str | Expr, time_unit: EpochTimeUnit = ...) -> Expr: ...
 
@@ -2456,3 +2619,45 @@ def sql_expr(sql: str | Sequence[str]) -> Expr | list[Expr]:
         return wrap_expr(plr.sql_expr(sql))
     else:
         return [wrap_expr(plr.sql_expr(q)) for q in sql]
+
+
+@unstable()
+def parse_expr(expr: str, *, dialect: Literal["python", "sql"] = "python") -> Expr:
+    """
+    Parse a textual expression into a Polars expression.
+
+    This allows defining expressions in configuration files, without executing
+    Python code.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    expr
+        The expression.
+    dialect : {'python', 'sql'}
+        The syntax of the expression.
+
+        - *'python'*: the syntax of the Python API, e.g.
+          `col('a') * 2 + col('b').log()`. The operators, the functions `col`,
+          `lit`, `len`, `all`, `when` and `coalesce`, and the common methods of
+          expressions and of their `str` and `dt` namespaces are supported.
+        - *'sql'*: a SQL expression, as parsed by :func:`sql_expr`.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"a": [1, 2], "b": [3.0, 4.0]})
+    >>> df.select(pl.parse_expr("(col('a') * 2 + col('b')).alias('c')"))
+    shape: (2, 1)
+    ┌─────┐
+    │ c   │
+    │ --- │
+    │ f64 │
+    ╞═════╡
+    │ 5.0 │
+    │ 8.0 │
+    └─────┘
+    """
+    return wrap_expr(plr.parse_expr(expr, dialect))
@@ -1,6 +1,15 @@
-from polars.functions.range.date_range import date_range, date_ranges
+from polars.functions.range.date_range import (
+    date_range,
+    date_ranges,
+    scan_date_range,
+)
 from polars.functions.range.datetime_range import datetime_range, datetime_ranges
-from polars.functions.range.int_range import arange, int_range, int_ranges
+from polars.functions.range.int_range import (
+    arange,
+    int_range,
+    int_ranges,
+    scan_int_range,
+)
 from polars.functions.range.linear_space import linear_space, linear_spaces
 from polars.functions.range.time_range import time_range, time_ranges
 
@@ -14,6 +23,8 @@ __all__ = [
     "int_ranges",
     "linear_space",
     "linear_spaces",
+    "scan_date_range",
+    "scan_int_range",
     "time_range",
     "time_ranges",
 ]
@@ -1,21 +1,24 @@
 from __future__ import annotations
 
 import contextlib
+from datetime import datetime
 from typing import TYPE_CHECKING, overload
 
 from polars import functions as F
+from polars._utils.convert import date_to_int
 from polars._utils.parse import parse_into_expression
-from polars._utils.wrap import wrap_expr
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr, wrap_ldf
 from polars.functions.range._utils import parse_interval_argument
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
     import polars.polars as plr
 
 if TYPE_CHECKING:
-    from datetime import date, datetime, timedelta
+    from datetime import date, timedelta
     from typing import Literal
 
-    from polars import Expr, Series
+    from polars import Expr, LazyFrame, Series
     from polars._typing import ClosedInterval, IntoExprColumn
 
 
@@ -183,6 +186,73 @@ def date_range(
     return result
 
 
+@unstable()
+def scan_date_range(
+    start: date | datetime,
+    end: date | datetime,
+    interval: str | timedelta = "1d",
+    *,
+    closed: ClosedInterval = "both",
+    name: str = "date",
+) -> LazyFrame:
+    """
+    Lazily generate a date range as a single-column LazyFrame.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Unlike :func:`date_range`, the range is a source of the query: slices and
+    filters on the column limit which dates are generated, and the streaming
+    engine produces the range in batches.
+
+    Parameters
+    ----------
+    start
+        Lower bound of the date range.
+    end
+        Upper bound of the date range.
+    interval
+        Interval of the range periods, specified as a Python `timedelta` object
+        or using the Polars duration string language (see :func:`date_range`).
+        Must consist of full days.
+    closed : {'both', 'left', 'right', 'none'}
+        Define which sides of the range are closed (inclusive).
+    name
+        Name of the column.
+
+    See Also
+    --------
+    date_range : Generate a date range as an expression.
+
+    Examples
+    --------
+    >>> from datetime import date
+    >>> lf = pl.scan_date_range(date(2000, 1, 1), date(2999, 12, 31), "1mo")
+    >>> lf.filter(pl.col("date") < date(2000, 4, 1)).collect()
+    shape: (3, 1)
+    ┌────────────┐
+    │ date       │
+    │ ---        │
+    │ date       │
+    ╞════════════╡
+    │ 2000-01-01 │
+    │ 2000-02-01 │
+    │ 2000-03-01 │
+    └────────────┘
+    """
+    if isinstance(start, datetime):
+        start = start.date()
+    if isinstance(end, datetime):
+        end = end.date()
+    interval = parse_interval_argument(interval)
+    return wrap_ldf(
+        plr.scan_date_range(
+            date_to_int(start), date_to_int(end), interval, closed, name
+        )
+    )
+
+
 @overload
 def date_ranges(
     start: date | datetime | IntoExprColumn,
@@ -5,7 +5,8 @@ from typing import TYPE_CHECKING, overload
 
 from polars import functions as F
 from polars._utils.parse import parse_into_expression
-from polars._utils.wrap import wrap_expr, wrap_s
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr, wrap_ldf, wrap_s
 from polars.datatypes import Int64
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -14,7 +15,7 @@ with contextlib.suppress(ImportError):  # Module not available when building doc
 if TYPE_CHECKING:
     from typing import Literal
 
-    from polars import Expr, Series
+    from polars import Expr, LazyFrame, Series
     from polars._typing import IntoExprColumn, PolarsIntegerType
 
 
@@ -227,6 +228,66 @@ def int_range(
     return result
 
 
+@unstable()
+def scan_int_range(
+    start: int = 0,
+    end: int | None = None,
+    step: int = 1,
+    *,
+    dtype: PolarsIntegerType = Int64,
+    name: str = "int_range",
+) -> LazyFrame:
+    """
+    Lazily generate a range of integers as a single-column LazyFrame.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Unlike :func:`int_range`, the range is a source of the query: slices and
+    filters on the column limit which values are generated, and the streaming
+    engine produces the range in batches. Very large ranges therefore never
+    have to be materialized in full.
+
+    Parameters
+    ----------
+    start
+        Start of the range (inclusive). Defaults to 0.
+    end
+        End of the range (exclusive). If set to `None` (default),
+        the value of `start` is used and `start` is set to `0`.
+    step
+        Step size of the range.
+    dtype
+        Data type of the range.
+    name
+        Name of the column.
+
+    See Also
+    --------
+    int_range : Generate a range of integers as an expression.
+
+    Examples
+    --------
+    >>> lf = pl.scan_int_range(10**18, name="i")
+    >>> lf.filter(pl.col("i").is_between(10, 12)).collect()
+    shape: (3, 1)
+    ┌─────┐
+    │ i   │
+    │ --- │
+    │ i64 │
+    ╞═════╡
+    │ 10  │
+    │ 11  │
+    │ 12  │
+    └─────┘
+    """
+    if end is None:
+        end = start
+        start = 0
+    return wrap_ldf(plr.scan_int_range(start, end, step, dtype, name))
+
+
 @overload
 def int_ranges(
     start: int | IntoExprColumn = ...,
@@ -7,7 +7,14 @@ from polars.io.csv import read_csv, read_csv_batched, scan_csv, read_csv_from_zi
 from polars.io.database import read_database, read_database_uri
 from polars.io.delta import read_delta, scan_delta
 from polars.io.iceberg import scan_iceberg
-from polars.io.ipc import read_ipc, read_ipc_schema, read_ipc_stream, scan_ipc
+from polars.io.ipc import (
+    read_ipc,
+    read_ipc_schema,
+    read_ipc_stream,
+    remove_shared_memory,
+    scan_ipc,
+    scan_shared_memory,
+)
 from polars.io.json import read_json
 from polars.io.ndjson import read_ndjson, scan_ndjson
 from polars.io.parquet import (
@@ -55,6 +62,7 @@ __all__ = {
     "read_parquet",
     "read_parquet_metadata",
     "read_parquet_schema",
+    "remove_shared_memory",
     "scan_csv",
     "scan_delta",
     "scan_iceberg",
@@ -62,4 +70,5 @@ __all__ = {
     "scan_ndjson",
     "scan_parquet",
     "scan_pyarrow_dataset",
+    "scan_shared_memory",
 }
@@ -1,8 +1,17 @@
-from polars.io.ipc.functions import read_ipc, read_ipc_schema, read_ipc_stream, scan_ipc
+from polars.io.ipc.functions import (
+    read_ipc,
+    read_ipc_schema,
+    read_ipc_stream,
+    remove_shared_memory,
+    scan_ipc,
+    scan_shared_memory,
+)
 
 __all__ = [
     "read_ipc",
     "read_ipc_schema",
     "read_ipc_stream",
+    "remove_shared_memory",
     "scan_ipc",
+    "scan_shared_memory",
 ]
@@ -8,6 +8,7 @@ from typing import IO, TYPE_CHECKING, Any, Literal
 import polars._reexport as pl
 import polars.functions as F
 from polars._utils.deprecation import deprecate_renamed_parameter
+from polars._utils.unstable import unstable
 from polars._utils.various import (
     is_path_or_str_sequence,
     is_str_sequence,
@@ -29,6 +30,8 @@ from polars.io.cloud.credential_provider._builder import (
 with contextlib.suppress(ImportError):  # Module not available when building docs
     from polars.polars import PyDataFrame, PyLazyFrame
     from polars.polars import read_ipc_schema as _read_ipc_schema
+    from polars.polars import remove_shared_memory as _remove_shared_memory
+    from polars.polars import shared_memory_path as _shared_memory_path
 
 if TYPE_CHECKING:
     from collections.abc import Sequence
@@ -512,3 +515,53 @@ def scan_ipc(
         include_file_paths=include_file_paths,
     )
     return wrap_ldf(pylf)
+
+
+@unstable()
+def scan_shared_memory(name: str) -> LazyFrame:
+    """
+    Lazily read from a named shared memory object written by another process.
+
+    The object is written with :meth:`DataFrame.write_shared_memory` and memory mapped
+    by the scan, so the pages the other process wrote are read without copying them
+    through a file on disk. This is only supported on Linux.
+
+    .. warning::
+        This functionality is currently considered **unstable**. It may be
+        changed at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    name
+        Name of the shared memory object.
+
+    See Also
+    --------
+    DataFrame.write_shared_memory
+    remove_shared_memory
+
+    Examples
+    --------
+    >>> pl.scan_shared_memory("frame").filter(pl.col("foo") > 1)  # doctest: +SKIP
+    """
+    return scan_ipc(_shared_memory_path(name))
+
+
+@unstable()
+def remove_shared_memory(name: str) -> None:
+    """
+    Remove a named shared memory object.
+
+    The object was written by :meth:`DataFrame.write_shared_memory`. Its memory is freed
+    once no process reads it anymore.
+
+    .. warning::
+        This functionality is currently considered **unstable**. It may be
+        changed at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    name
+        Name of the shared memory object.
+    """
+    _remove_shared_memory(name)
@@ -31,6 +31,7 @@ with contextlib.suppress(ImportError):
     from polars.polars import read_parquet_schema as _read_parquet_schema
 
 if TYPE_CHECKING:
+    from collections.abc import Callable
     from typing import Literal
 
     from polars import DataFrame, DataType, LazyFrame
@@ -66,6 +67,7 @@ def read_parquet(
     memory_map: bool = True,
     include_file_paths: str | None = None,
     allow_missing_columns: bool = False,
+    strings_as_categorical: bool = False,
 ) -> DataFrame:
     """
     Read into a DataFrame from a parquet file.
@@ -174,6 +176,14 @@ def read_parquet(
         raise an error. However, if `allow_missing_columns` is set to
         `True`, a full-NULL column is returned instead of erroring for the files
         that do not contain the column.
+    strings_as_categorical
+        Read the string columns as `Categorical`. Columns that are dictionary
+        encoded in the file use the dictionary of the file as categories, which
+        avoids materializing the strings before categorizing them.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
     Returns
     -------
@@ -200,6 +210,12 @@ def read_parquet(
         msg = "the `hive_schema` parameter of `read_parquet` is considered unstable."
         issue_unstable_warning(msg)
 
+    if strings_as_categorical:
+        msg = (
+            "the `strings_as_categorical` parameter of `read_parquet` is considered unstable."
+        )
+        issue_unstable_warning(msg)
+
     # Dispatch to pyarrow if requested
     if use_pyarrow:
         if n_rows is not None:
@@ -211,6 +227,9 @@ def read_parquet(
         if schema is not None:
             msg = "`schema` cannot be used with `use_pyarrow=True`"
             raise ValueError(msg)
+        if strings_as_categorical:
+            msg = "`strings_as_categorical` cannot be used with `use_pyarrow=True`"
+            raise ValueError(msg)
         if hive_schema is not None:
             msg = (
                 "cannot use `hive_partitions` with `use_pyarrow=True`"
@@ -247,6 +266,7 @@ def read_parquet(
         glob=glob,
         include_file_paths=include_file_paths,
         allow_missing_columns=allow_missing_columns,
+        strings_as_categorical=strings_as_categorical,
     )
 
     if columns is not None:
@@ -386,6 +406,8 @@ def scan_parquet(
     include_file_paths: str | None = None,
     allow_missing_columns: bool = False,
     cast_options: ScanCastOptions | None = None,
+    strings_as_categorical: bool = False,
+    column_mapping: dict[str, int] | Callable[[str], str] | None = None,
 ) -> LazyFrame:
     """
     Lazily read from a local or cloud-hosted parquet file (or files).
@@ -479,12 +501,16 @@ def scan_parquet(
         Provide a function that can be called to provide cloud storage
         credentials. The function is expected to return a dictionary of
         credential keys along with an optional credential expiry time.
+        For HTTP(S) sources, the dictionary holds a `bearer_token` that is sent
+        in the `Authorization` header.
 
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
     retries
-        Number of retries if accessing a cloud instance fails.
+        Number of retries if accessing a cloud instance fails. This also limits
+        how often the download of a byte range is resumed after the connection
+        breaks.
     include_file_paths
         Include the path of the source file(s) as a column with this name.
     allow_missing_columns
@@ -500,6 +526,26 @@ def scan_parquet(
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
+    strings_as_categorical
+        Read the string columns as `Categorical`. Columns that are dictionary
+        encoded in the file use the dictionary of the file as categories, which
+        avoids materializing the strings before categorizing them.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    column_mapping
+        Map the top-level columns of every file to the columns of the scan, which
+        allows scanning files whose columns were renamed as one table.
+
+        * A dictionary from column names to Parquet field IDs gives the columns
+          with these field IDs these names. Other columns keep their names.
+        * A function is called with the name of every column in a file and
+          returns its name in the scan.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
     See Also
     --------
@@ -522,6 +568,14 @@ def scan_parquet(
     ...     "aws_region": "us-east-1",
     ... }
     >>> pl.scan_parquet(source, storage_options=storage_options)  # doctest: +SKIP
+
+    Scan files whose column names carry a varying prefix as one table.
+
+    >>> import re
+    >>> pl.scan_parquet(
+    ...     "path/to/*.parquet",
+    ...     column_mapping=lambda name: re.sub(r"^v[0-9]+_", "", name),
+    ... )  # doctest: +SKIP
     """
     if schema is not None:
         msg = "the `schema` parameter of `scan_parquet` is considered unstable."
@@ -535,6 +589,16 @@ def scan_parquet(
         msg = "The `cast_options` parameter of `scan_parquet` is considered unstable."
         issue_unstable_warning(msg)
 
+    if strings_as_categorical:
+        msg = (
+            "the `strings_as_categorical` parameter of `scan_parquet` is considered unstable."
+        )
+        issue_unstable_warning(msg)
+
+    if column_mapping is not None:
+        msg = "the `column_mapping` parameter of `scan_parquet` is considered unstable."
+        issue_unstable_warning(msg)
+
     if isinstance(source, (str, Path)):
         source = normalize_filepath(source, check_not_directory=False)
     elif is_path_or_str_sequence(source):
@@ -567,6 +631,8 @@ def scan_parquet(
         include_file_paths=include_file_paths,
         allow_missing_columns=allow_missing_columns,
         cast_options=cast_options,
+        strings_as_categorical=strings_as_categorical,
+        column_mapping=column_mapping,
     )
 
 
@@ -592,6 +658,8 @@ def _scan_parquet_impl(
     include_file_paths: str | None = None,
     allow_missing_columns: bool = False,
     cast_options: ScanCastOptions | None = None,
+    strings_as_categorical: bool = False,
+    column_mapping: dict[str, int] | Callable[[str], str] | None = None,
 ) -> LazyFrame:
     if isinstance(source, list):
         sources = source
@@ -626,5 +694,7 @@ def _scan_parquet_impl(
         include_file_paths=include_file_paths,
         allow_missing_columns=allow_missing_columns,
         cast_options=cast_options,
+        strings_as_categorical=strings_as_categorical,
+        column_mapping=column_mapping,
     )
     return wrap_ldf(pylf)
@@ -27,7 +27,11 @@ from polars._typing import (
     PartitioningScheme,
 )
 from polars._utils.async_ import _AioDataFrameResult, _GeventDataFrameResult
-from polars._utils.convert import negate_duration_string, parse_as_duration_string
+from polars._utils.convert import (
+    datetime_to_int,
+    negate_duration_string,
+    parse_as_duration_string,
+)
 from polars._utils.deprecation import (
     deprecate_renamed_parameter,
     deprecate_streaming_parameter,
@@ -101,6 +105,7 @@ from polars.schema import Schema
 from polars.selectors import by_dtype, expand_selector
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars.polars as plr
     from polars.polars import PyLazyFrame, get_engine_affinity
 
 if TYPE_CHECKING:
@@ -131,6 +136,7 @@ if TYPE_CHECKING:
         JoinValidation,
         Label,
         MaintainOrderJoin,
+        MonthEndPolicy,
         Orientation,
         ParquetMetadata,
         PlanStage,
@@ -1838,7 +1844,19 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         containing the materialized DataFrame and a DataFrame that
         contains profiling information of each node that is executed.
 
-        The units of the timings are microseconds.
+        The units of the timings are microseconds. Next to the timings, the
+        profiling information contains per node:
+
+        - `rows`: the number of rows the node produced.
+        - `peak_memory`: the largest estimated size in bytes of the data the
+          node produced at once. For the in-memory engine this is the size of
+          the node's output, for the streaming engine that of its largest batch.
+        - `spilled_bytes`: the number of bytes the node spilled to disk. This
+          is null for nodes that can't spill.
+        - `thread_utilization`: the fraction of its threads the node kept busy
+          while it ran. Low values point to memory- or IO-bound nodes.
+
+        Statistics an engine can't measure are null.
 
         Parameters
         ----------
@@ -1952,16 +1970,16 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
          │ b   ┆ 11  ┆ 10  │
          │ c   ┆ 6   ┆ 1   │
          └─────┴─────┴─────┘,
-         shape: (3, 3)
-         ┌─────────────────────────┬───────┬──────┐
-         │ node                    ┆ start ┆ end  │
-         │ ---                     ┆ ---   ┆ ---  │
-         │ str                     ┆ u64   ┆ u64  │
-         ╞═════════════════════════╪═══════╪══════╡
-         │ optimization            ┆ 0     ┆ 5    │
-         │ group_by_partitioned(a) ┆ 5     ┆ 470  │
-         │ sort(a)                 ┆ 475   ┆ 1964 │
-         └─────────────────────────┴───────┴──────┘)
+         shape: (3, 7)
+         ┌─────────────────────────┬───────┬──────┬──────┬─────────────┬───────────────┬────────────────────┐
+         │ node                    ┆ start ┆ end  ┆ rows ┆ peak_memory ┆ spilled_bytes ┆ thread_utilization │
+         │ ---                     ┆ ---   ┆ ---  ┆ ---  ┆ ---         ┆ ---           ┆ ---                │
+         │ str                     ┆ u64   ┆ u64  ┆ u64  ┆ u64         ┆ u64           ┆ f64                │
+         ╞═════════════════════════╪═══════╪══════╪══════╪═════════════╪═══════════════╪════════════════════╡
+         │ optimization            ┆ 0     ┆ 5    ┆ null ┆ null        ┆ null          ┆ null               │
+         │ group_by_partitioned(a) ┆ 5     ┆ 470  ┆ 3    ┆ 51          ┆ null          ┆ null               │
+         │ sort(a)                 ┆ 475   ┆ 1964 ┆ 3    ┆ 51          ┆ null          ┆ null               │
+         └─────────────────────────┴───────┴──────┴──────┴─────────────┴───────────────┴────────────────────┘)
         """
         for k in _kwargs:
             if k not in (  # except "private" kwargs
@@ -1971,9 +1989,11 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 raise TypeError(error_msg)
         engine = _select_engine(engine)
 
-        optimizations = optimizations.__copy__()
-        optimizations._pyoptflags.old_streaming = engine == "old-streaming"  # type: ignore[comparison-overlap]
-        ldf = self._ldf.with_optimizations(optimizations._pyoptflags)
+        # Don't leak the engine selection into the flags the caller passed in.
+        opt_flags = optimizations.__copy__()
+        opt_flags._pyoptflags.old_streaming = engine == "old-streaming"  # type: ignore[comparison-overlap]
+        opt_flags._pyoptflags.streaming = engine == "streaming"
+        ldf = self._ldf.with_optimizations(opt_flags._pyoptflags)
 
         callback = _gpu_engine_callback(
             engine,
@@ -2298,6 +2318,53 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         callback = _kwargs.get("post_opt_callback", callback)
         return wrap_df(ldf.collect(engine, callback))
 
+    @unstable()
+    def collect_chunked(
+        self,
+        n_rows: int,
+        *,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> DataFrame:
+        """
+        Materialize this LazyFrame into a DataFrame with chunks of `n_rows` rows.
+
+        Every chunk except the last one has exactly `n_rows` rows. The chunks are
+        cut by the sink while the query runs, as with the `batch_size` of the
+        `sink_*` methods, instead of rechunking the collected result. This is useful
+        for consumers that require bounded batch sizes, such as Arrow Flight, as
+        every chunk becomes one record batch when the result is exported with
+        :meth:`DataFrame.to_arrow`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        n_rows
+            The number of rows per chunk.
+        engine
+            Select the engine used to process the query, see :meth:`collect`.
+        optimizations
+            The optimization passes done during query optimization.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": range(5)})
+        >>> df = lf.collect_chunked(2)
+        >>> [len(batch) for batch in df.to_arrow().to_batches()]
+        [2, 2, 1]
+        """
+        if n_rows <= 0:
+            msg = f"`n_rows` must be positive, got {n_rows}"
+            raise ValueError(msg)
+        engine = _select_engine(engine)
+        if isinstance(engine, GPUEngine):
+            engine = "gpu"
+        ldf = self._ldf.with_optimizations(optimizations._pyoptflags)
+        return wrap_df(ldf.collect_chunked(engine, n_rows))
+
     @overload
     def collect_async(
         self,
@@ -2451,6 +2518,41 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         """
         return Schema(self._ldf.collect_schema(), check_dtypes=False)
 
+    @unstable()
+    def stats(self) -> DataFrame:
+        """
+        Get the number of rows and the null count, minimum and maximum of every column.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The statistics are read from the metadata of the scanned files where
+        possible, without reading any data. This works for local Parquet files that
+        have statistics and for the row count of local IPC files, as long as the
+        query only scans and selects columns of them. The statistics that are not
+        known from the metadata are computed in a single pass over the data.
+
+        The result has a single row with a `row_count` column and a struct column
+        with the fields `null_count`, `min` and `max` for every column. The minimum
+        and maximum are only computed for numeric, boolean, temporal and string
+        columns and are null for other columns.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, None, 3], "b": ["x", "y", "z"]})
+        >>> lf.stats()
+        shape: (1, 3)
+        ┌───────────┬───────────┬─────────────┐
+        │ row_count ┆ a         ┆ b           │
+        │ ---       ┆ ---       ┆ ---         │
+        │ u32       ┆ struct[3] ┆ struct[3]   │
+        ╞═══════════╪═══════════╪═════════════╡
+        │ 3         ┆ {1,1,3}   ┆ {0,"x","z"} │
+        └───────────┴───────────┴─────────────┘
+        """
+        return wrap_df(self._ldf.stats())
+
     @overload
     def sink_parquet(
         self,
@@ -2776,6 +2878,110 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             return None
         return LazyFrame._from_pyldf(ldf)
 
+    @unstable()
+    def sink_parquet_bucketed(
+        self,
+        path: str | Path,
+        by: str | Sequence[str],
+        num_buckets: int,
+        *,
+        compression: str = "zstd",
+        compression_level: int | None = None,
+        statistics: bool | str | dict[str, bool] = True,
+        row_group_size: int | None = None,
+        maintain_order: bool = True,
+        storage_options: dict[str, Any] | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        retries: int = 2,
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> None:
+        """
+        Evaluate the query in streaming mode and write it to Parquet files per bucket.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Every row is assigned to one of `num_buckets` buckets by the 32-bit MurmurHash3
+        of the key columns that Hive and Iceberg use, so rows with equal keys are always
+        in the same bucket, regardless of the Polars version. The files of bucket `i`
+        are written to `{path}/bucket={i}/`, without the bucket column, and the
+        bucketing scheme and hash are recorded under the `polars.bucketing` key of the
+        file-level Parquet metadata.
+
+        Datasets that are bucketed by the same keys into the same number of buckets
+        can be joined bucket by bucket, and scans with `hive_partitioning` can skip
+        all buckets but one when filtering on the `bucket` column.
+
+        Parameters
+        ----------
+        path
+            Directory to which the buckets should be written.
+        by
+            Names of the key columns to bucket by.
+        num_buckets
+            Number of buckets.
+        compression : {'lz4', 'uncompressed', 'snappy', 'gzip', 'lzo', 'brotli', 'zstd'}
+            Compression of the files, see :meth:`sink_parquet`.
+        compression_level
+            The level of compression to use, see :meth:`sink_parquet`.
+        statistics
+            Write statistics to the parquet headers, see :meth:`sink_parquet`.
+        row_group_size
+            Size of the row groups in number of rows.
+        maintain_order
+            Maintain the order in which data is processed within every bucket.
+        storage_options
+            Options that indicate how to connect to a cloud provider, see
+            :meth:`sink_parquet`.
+        credential_provider
+            Provide a function that can be called to provide cloud storage
+            credentials, see :meth:`sink_parquet`.
+        retries
+            Number of retries if accessing a cloud instance fails.
+        sync_on_close: { None, 'data', 'all' }
+            Sync to disk when before closing a file, see :meth:`sink_parquet`.
+        mkdir: bool
+            Recursively create all the directories in the path.
+        engine
+            Select the engine used to process the query, optional.
+        optimizations
+            The optimization passes done during query optimization.
+
+        Examples
+        --------
+        >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
+        >>> lf.sink_parquet_bucketed("out/", by="id", num_buckets=16)  # doctest: +SKIP
+        """
+        from polars.io.partition import PartitionByKey
+
+        by = [by] if isinstance(by, str) else list(by)
+        bucket = wrap_expr(
+            plr.bucket_id([F.col(name)._pyexpr for name in by], num_buckets)
+        )
+        scheme = plr.bucketing_metadata(by, num_buckets)
+        self.sink_parquet(
+            PartitionByKey(path, by={"bucket": bucket}, include_key=False),
+            compression=compression,
+            compression_level=compression_level,
+            statistics=statistics,
+            row_group_size=row_group_size,
+            maintain_order=maintain_order,
+            storage_options=storage_options,
+            credential_provider=credential_provider,
+            retries=retries,
+            sync_on_close=sync_on_close,
+            metadata={"polars.bucketing": scheme},
+            mkdir=mkdir,
+            engine=engine,
+            optimizations=optimizations,
+        )
+
     @overload
     def sink_ipc(
         self,
@@ -2783,6 +2989,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         *,
         compression: IpcCompression | None = "zstd",
         compat_level: CompatLevel | None = None,
+        batch_size: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -2803,6 +3010,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         *,
         compression: IpcCompression | None = "zstd",
         compat_level: CompatLevel | None = None,
+        batch_size: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -2822,6 +3030,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         *,
         compression: IpcCompression | None = "uncompressed",
         compat_level: CompatLevel | None = None,
+        batch_size: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -2849,6 +3058,13 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         compat_level
             Use a specific compatibility level
             when exporting Polars' internal data structures.
+        batch_size
+            The number of rows of every record batch that is written, except
+            for the last one.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -2953,6 +3169,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             "sync_on_close": sync_on_close or "none",
             "maintain_order": maintain_order,
             "mkdir": mkdir,
+            "batch_size": batch_size,
         }
 
         if compat_level is None:
@@ -2980,6 +3197,96 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             return None
         return LazyFrame._from_pyldf(ldf)
 
+    @unstable()
+    def sink_ipc_stream(
+        self,
+        path: str | Path | IO[bytes],
+        *,
+        compression: IpcCompression | None = "uncompressed",
+        compat_level: CompatLevel | None = None,
+        batch_size: int | None = None,
+        flush_each_batch: bool = False,
+        maintain_order: bool = True,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> None:
+        """
+        Evaluate the query in streaming mode and write it in the Arrow IPC stream format.
+
+        Unlike the IPC file format, the stream format needs no footer or seeking, so
+        the result can be written to any writable binary object, such as a pipe or a
+        network socket (e.g. `socket.makefile("wb")`), and read by the other side
+        while the query is still running.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        path
+            File path or writable binary object to which the stream should be
+            written.
+        compression : {'uncompressed', 'lz4', 'zstd'}
+            Choose "zstd" for good compression performance.
+            Choose "lz4" for fast compression/decompression.
+        compat_level
+            Use a specific compatibility level
+            when exporting Polars' internal data structures.
+        batch_size
+            The number of rows of every record batch that is written, except
+            for the last one.
+        flush_each_batch
+            Flush the writer after every record batch, so that the reader receives
+            each batch as soon as it is written instead of when the writer's buffer
+            is full.
+        maintain_order
+            Maintain the order in which data is processed.
+            Setting this to `False` will be slightly faster.
+        engine
+            Select the engine used to process the query, optional.
+            At the moment, if set to `"auto"` (default), the query is run
+            using the polars streaming engine.
+        optimizations
+            The optimization passes done during query optimization.
+
+        Examples
+        --------
+        >>> import socket
+        >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
+        >>> with socket.create_connection(("localhost", 9000)) as sock:  # doctest: +SKIP
+        ...     with sock.makefile("wb") as f:
+        ...         lf.sink_ipc_stream(f, flush_each_batch=True)
+        """
+        engine = _select_engine(engine)
+
+        if compat_level is None:
+            compat_level = True  # type: ignore[assignment]
+        elif isinstance(compat_level, CompatLevel):
+            compat_level = compat_level._version  # type: ignore[attr-defined]
+
+        if compression is None:
+            compression = "uncompressed"
+
+        ldf = self._ldf.sink_ipc(
+            target=_to_sink_target(path),
+            compression=compression,
+            compat_level=compat_level,
+            cloud_options=None,
+            credential_provider=None,
+            retries=0,
+            sink_options={
+                "sync_on_close": "none",
+                "maintain_order": maintain_order,
+                "mkdir": False,
+                "batch_size": batch_size,
+            },
+            stream=True,
+            flush_each_batch=flush_each_batch,
+        )
+        ldf = ldf.with_optimizations(optimizations._pyoptflags)
+        LazyFrame._from_pyldf(ldf).collect(engine=engine)
+
     @overload
     def sink_csv(
         self,
@@ -4640,6 +4947,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         label: Label = "left",
         group_by: IntoExpr | Iterable[IntoExpr] | None = None,
         start_by: StartBy = "window",
+        month_end_policy: MonthEndPolicy | None = None,
+        origin: datetime | date | int | None = None,
+        label_offset: str | timedelta | None = None,
     ) -> LazyGroupBy:
         """
         Group based on a time value (or index value of type Int32, Int64).
@@ -4675,7 +4985,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             {Int32, Int64}. Note that Int32 gets temporarily cast to Int64, so if
             performance matters use an Int64 column.
         every
-            interval of the window
+            interval of the window. Besides durations, this accepts the aliases
+
+            - `'{n}mo_end'`, `'{n}q_end'`, `'{n}y_end'`: calendar windows of `n`
+              months, quarters or years, labelled by their last day.
+            - `'{n}bd'`: windows of `n` business days, Monday to Friday. Rows on a
+              weekend belong to the window of the Friday before them.
         period
             length of the window, if None it will equal 'every'
         offset
@@ -4687,7 +5002,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             parallelize
         closed : {'left', 'right', 'both', 'none'}
             Define which sides of the temporal interval are closed (inclusive).
-        label : {'left', 'right', 'datapoint'}
+        label : {'left', 'right', 'datapoint', 'period_end'}
             Define which label to use for the window:
 
             - 'left': lower boundary of the window
@@ -4695,6 +5010,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             - 'datapoint': the first value of the index column in the given window.
               If you don't need the label to be at one of the boundaries, choose this
               option for maximum performance
+            - 'period_end': the last calendar day of the window, e.g. month-end dates
+              for monthly windows. Windows with a time component are labelled by
+              their upper boundary.
         group_by
             Also group by this column/these columns
         start_by : {'window', 'datapoint', 'monday', 'tuesday', 'wednesday', 'thursday', 'friday', 'saturday', 'sunday'}
@@ -4713,6 +5031,25 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
               The resulting window is then shifted back until the earliest datapoint
               is in or in front of it.
+        month_end_policy : {None, 'clamp', 'month_end'}
+            How calendar windows (`every` in months, quarters or years) are laid out
+            when the first window starts on a day that doesn't exist in every month.
+
+            * None: Every window starts `every` after the start of the previous one,
+              so a day clamped to the end of a short month carries over, e.g. windows
+              starting on Jan 31 continue with Feb 29 and Mar 29.
+            * 'clamp': Keep the day of the month of the first window, clamped to the
+              last day of short months, e.g. Jan 31 continues with Feb 29 and Mar 31.
+            * 'month_end': Like 'clamp', but if the first window starts on the last
+              day of its month, every window starts on the last day of its month.
+        origin
+            Align the window boundaries to this point in time instead of to the
+            epoch, e.g. `datetime(2020, 1, 1, 9, 30)` with `every="1h"` starts the
+            windows at half past the hour. Pass an integer for integer index
+            columns. Takes precedence over `offset`.
+        label_offset
+            Shift the label of every window by this duration, e.g. `"-1h"` with
+            `label="right"`.
 
         Returns
         -------
@@ -4942,12 +5279,15 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         if offset is None:
             offset = "0ns"
 
-        if period is None:
-            period = every
-
         period = parse_as_duration_string(period)
         offset = parse_as_duration_string(offset)
         every = parse_as_duration_string(every)
+        label_offset = parse_as_duration_string(label_offset)
+
+        if isinstance(origin, datetime):
+            origin = datetime_to_int(origin, "ns")
+        elif isinstance(origin, date):
+            origin = datetime_to_int(datetime.combine(origin, time()), "ns")
 
         pyexprs_by = (
             parse_into_list_of_expressions(group_by) if group_by is not None else []
@@ -4962,9 +5302,102 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             closed,
             pyexprs_by,
             start_by,
+            month_end_policy,
+            origin,
+            label_offset,
         )
         return LazyGroupBy(lgb)
 
+    @unstable()
+    def upsample(
+        self,
+        time_column: str,
+        *,
+        every: str | timedelta,
+        group_by: str | Sequence[str] | None = None,
+        maintain_order: bool = False,
+    ) -> LazyFrame:
+        """
+        Upsample a LazyFrame at a regular frequency.
+
+        For every group, a row is added for each timestamp from the earliest to the
+        latest value of `time_column`, in steps of `every`. The added rows have the
+        keys of their group and nulls in the other columns, which can be filled with
+        e.g. :meth:`Expr.forward_fill` or :meth:`Expr.interpolate`.
+
+        This doesn't stream: the input is collected and upsampled in memory with
+        :meth:`DataFrame.upsample`, one group at a time.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        time_column
+            Time column will be used to determine a date_range. It doesn't have to be
+            sorted.
+        every
+            Interval will start 'every' duration. See :meth:`DataFrame.upsample` for
+            the string language.
+        group_by
+            First group by these columns and then upsample for every group.
+        maintain_order
+            Keep the groups in the order of their first occurrence. This is slower.
+
+        Returns
+        -------
+        LazyFrame
+            Result will be sorted by `time_column` within each group.
+
+        See Also
+        --------
+        DataFrame.upsample
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "time": [
+        ...             datetime(2021, 2, 1),
+        ...             datetime(2021, 4, 1),
+        ...             datetime(2021, 5, 1),
+        ...             datetime(2021, 6, 1),
+        ...         ],
+        ...         "groups": ["A", "B", "A", "B"],
+        ...         "values": [0, 1, 2, 3],
+        ...     }
+        ... )
+        >>> lf.upsample(
+        ...     time_column="time", every="1mo", group_by="groups", maintain_order=True
+        ... ).with_columns(pl.col("values").forward_fill()).collect()
+        shape: (7, 3)
+        ┌─────────────────────┬────────┬────────┐
+        │ time                ┆ groups ┆ values │
+        │ ---                 ┆ ---    ┆ ---    │
+        │ datetime[μs]        ┆ str    ┆ i64    │
+        ╞═════════════════════╪════════╪════════╡
+        │ 2021-02-01 00:00:00 ┆ A      ┆ 0      │
+        │ 2021-03-01 00:00:00 ┆ A      ┆ 0      │
+        │ 2021-04-01 00:00:00 ┆ A      ┆ 0      │
+        │ 2021-05-01 00:00:00 ┆ A      ┆ 2      │
+        │ 2021-04-01 00:00:00 ┆ B      ┆ 1      │
+        │ 2021-05-01 00:00:00 ┆ B      ┆ 1      │
+        │ 2021-06-01 00:00:00 ┆ B      ┆ 3      │
+        └─────────────────────┴────────┴────────┘
+        """
+        if group_by is None:
+            group_by = []
+        if isinstance(group_by, str):
+            group_by = [group_by]
+
+        every = parse_as_duration_string(every)
+
+        return self._from_pyldf(
+            self._ldf.upsample(group_by, time_column, every, maintain_order)
+        )
+
     def join_asof(
         self,
         other: LazyFrame,
@@ -5005,6 +5438,11 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             is nearest to the left's key. String keys are not currently supported for a
             nearest search.
 
+          - A "bracket" search selects both the "backward" and the "forward" match,
+            e.g. to interpolate between the surrounding rows. Every column of the right
+            DataFrame becomes a struct with the fields `backward` and `forward`. Use
+            `coalesce=False` to keep the keys of both matches.
+
         The default is "backward".
 
         Parameters
@@ -5024,7 +5462,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             Join on these columns before doing asof join.
         by_right
             Join on these columns before doing asof join.
-        strategy : {'backward', 'forward', 'nearest'}
+        strategy : {'backward', 'forward', 'nearest', 'bracket'}
             Join strategy.
         suffix
             Suffix to append to columns with a duplicate name.
@@ -5349,7 +5787,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         right_on: str | Expr | Sequence[str | Expr] | None = None,
         suffix: str = "_right",
         validate: JoinValidation = "m:m",
-        nulls_equal: bool = False,
+        nulls_equal: bool | Sequence[bool] = False,
         coalesce: bool | None = None,
         maintain_order: MaintainOrderJoin | None = None,
         allow_parallel: bool = True,
@@ -5416,6 +5854,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 This is currently not supported by the streaming engine.
         nulls_equal
             Join on null values. By default null values will never produce matches.
+            Pass a sequence of booleans, one per join key, to only let null values
+            match on some of the keys.
         coalesce
             Coalescing behavior (merging of join columns).
 
@@ -5558,6 +5998,11 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         if maintain_order is None:
             maintain_order = "none"
 
+        nulls_equal_per_key = None
+        if not isinstance(nulls_equal, bool):
+            nulls_equal_per_key = list(nulls_equal)
+            nulls_equal = all(nulls_equal_per_key)
+
         uses_on = on is not None
         uses_left_on = left_on is not None
         uses_right_on = right_on is not None
@@ -5625,6 +6070,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 validate,
                 maintain_order,
                 coalesce,
+                nulls_equal_per_key,
             )
         )
 
@@ -6453,6 +6899,36 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         """
         return self.select(F.all().approx_n_unique())
 
+    @unstable()
+    def with_random_seed(self, seed: int) -> LazyFrame:
+        """
+        Fix the seed of all random expressions in the query.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Every random expression (such as :meth:`Expr.shuffle` or :meth:`Expr.sample`)
+        in the query so far that was not given an explicit `seed` gets a seed
+        derived from `seed`. The result is then identical across runs, regardless
+        of the number of threads or the engine used to execute the query.
+
+        Parameters
+        ----------
+        seed
+            Seed from which the seeds of the individual random expressions are
+            derived.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"g": [1, 1, 2, 2], "a": [1, 2, 3, 4]})
+        >>> q = lf.group_by("g", maintain_order=True).agg(pl.col("a").shuffle())
+        >>> q = q.with_random_seed(0)
+        >>> q.collect().equals(q.collect())
+        True
+        """
+        return self._from_pyldf(self._ldf.with_random_seed(seed))
+
     def with_row_index(self, name: str = "index", offset: int = 0) -> LazyFrame:
         """
         Add a row index as the first column in the LazyFrame.
@@ -6530,6 +7006,99 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             msg = f"`offset` input for `with_row_index` cannot be {issue}, got {offset}"
             raise ValueError(msg) from None
 
+    def with_row_index_per_file(
+        self, name: str = "index", offset: int = 0
+    ) -> LazyFrame:
+        """
+        Add a row index that restarts for every scanned file as the first column.
+
+        Unlike :meth:`with_row_index`, the index of a row doesn't depend on the number
+        of rows in the files before it, so the files can be read in parallel without
+        any synchronization between them. Together with a column of file paths (see
+        `include_file_paths` of the scan functions) this identifies every row.
+
+        If this LazyFrame isn't a scan of files, this is the same as
+        :meth:`with_row_index`.
+
+        Parameters
+        ----------
+        name
+            Name of the index column.
+        offset
+            Start the index of every file at this offset. Cannot be negative.
+
+        See Also
+        --------
+        with_row_index
+        with_row_hash
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 3, 5]})
+        >>> lf.with_row_index_per_file("i", offset=1).collect()
+        shape: (3, 2)
+        ┌─────┬─────┐
+        │ i   ┆ a   │
+        │ --- ┆ --- │
+        │ u32 ┆ i64 │
+        ╞═════╪═════╡
+        │ 1   ┆ 1   │
+        │ 2   ┆ 3   │
+        │ 3   ┆ 5   │
+        └─────┴─────┘
+        """
+        try:
+            return self._from_pyldf(self._ldf.with_row_index_per_file(name, offset))
+        except OverflowError:
+            issue = "negative" if offset < 0 else "greater than the maximum index value"
+            msg = (
+                "`offset` input for `with_row_index_per_file` cannot be"
+                f" {issue}, got {offset}"
+            )
+            raise ValueError(msg) from None
+
+    def with_row_hash(
+        self,
+        name: str,
+        *by: IntoExpr | Iterable[IntoExpr],
+        seed: int = 0,
+    ) -> LazyFrame:
+        """
+        Add a column with a 64-bit hash of the values of `by` in every row.
+
+        Unlike a row index, the id of a row only depends on its values, so it can be
+        computed without processing the rows in order, and is the same no matter in
+        which file or partition the row is read. Rows with equal values of `by` get
+        equal ids.
+
+        Parameters
+        ----------
+        name
+            Name of the hash column.
+        *by
+            The columns to hash. Accepts expression input. Strings are parsed as
+            column names.
+        seed
+            Random seed of the hash.
+
+        Warnings
+        --------
+        The hash is not guaranteed to be stable across Polars versions.
+
+        See Also
+        --------
+        with_row_index_per_file
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 2, 1], "b": ["x", "y", "x"]})
+        >>> ids = lf.with_row_hash("id", "a", "b").collect()["id"]
+        >>> ids[0] == ids[2], ids[0] == ids[1]
+        (True, False)
+        """
+        pyexprs = parse_into_list_of_expressions(*by)
+        return self._from_pyldf(self._ldf.with_row_hash(name, pyexprs, seed))
+
     @deprecated(
         "`LazyFrame.with_row_count` is deprecated; use `LazyFrame.with_row_index` instead."
         " Note that the default column name has changed from 'row_nr' to 'index'."
@@ -7026,6 +7595,88 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         """
         return self._from_pyldf(self._ldf.null_count())
 
+    @unstable()
+    def value_counts(
+        self,
+        subset: ColumnNameOrSelector | Collection[ColumnNameOrSelector] | None = None,
+        *,
+        sort: bool = False,
+        normalize: bool = False,
+        name: str | None = None,
+    ) -> LazyFrame:
+        """
+        Count the occurrences of the unique combinations of values in the columns.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        This is a group-by on the `subset` columns and runs in the streaming engine.
+
+        Parameters
+        ----------
+        subset
+            Column name(s) or selector(s) whose unique combinations are counted. If set
+            to `None` (default), use all columns.
+        sort
+            Sort the output by count in descending order.
+            If set to `False` (default), the order of the output is random.
+        normalize
+            Give the relative frequencies of the unique combinations instead of their
+            counts.
+        name
+            Give the resulting count column a specific name;
+            if `normalize` is True defaults to "proportion",
+            otherwise defaults to "count".
+
+        Returns
+        -------
+        LazyFrame
+            The unique combinations of the `subset` columns and their count.
+
+        See Also
+        --------
+        polars.Series.value_counts
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "color": ["red", "blue", "red", "green", "blue", "blue"],
+        ...         "size": ["S", "M", "S", "S", "M", "L"],
+        ...     }
+        ... )
+        >>> lf.value_counts("color", sort=True).collect()
+        shape: (3, 2)
+        ┌───────┬───────┐
+        │ color ┆ count │
+        │ ---   ┆ ---   │
+        │ str   ┆ u32   │
+        ╞═══════╪═══════╡
+        │ blue  ┆ 3     │
+        │ red   ┆ 2     │
+        │ green ┆ 1     │
+        └───────┴───────┘
+
+        Count the combinations of multiple columns, or give relative frequencies.
+
+        >>> lf.value_counts(["color", "size"]).collect()  # doctest: +IGNORE_RESULT
+        >>> lf.value_counts("size", sort=True, normalize=True).collect()
+        shape: (3, 2)
+        ┌──────┬────────────┐
+        │ size ┆ proportion │
+        │ ---  ┆ ---        │
+        │ str  ┆ f64        │
+        ╞══════╪════════════╡
+        │ S    ┆ 0.5        │
+        │ M    ┆ 0.333333   │
+        │ L    ┆ 0.166667   │
+        └──────┴────────────┘
+        """
+        if subset is not None:
+            subset = parse_into_list_of_expressions(subset)
+        return self._from_pyldf(self._ldf.value_counts(subset, sort, normalize, name))
+
     def quantile(
         self,
         quantile: float | Expr,
@@ -7366,6 +8017,65 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             subset = parse_into_list_of_expressions(subset)
         return self._from_pyldf(self._ldf.drop_nulls(subset))
 
+    @unstable()
+    def duplicates(
+        self,
+        subset: ColumnNameOrSelector | Collection[ColumnNameOrSelector] | None = None,
+        *,
+        name: str = "count",
+    ) -> LazyFrame:
+        """
+        Keep only the rows that occur more than once, with their number of occurrences.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The number of occurrences is added in a column named `name`. The original
+        order of the remaining rows is preserved.
+
+        Parameters
+        ----------
+        subset
+            Column name(s) or selector(s) to compare the rows by. By default all
+            columns are compared.
+        name
+            Name of the column with the number of occurrences. Raises a
+            `DuplicateError` if a column with this name already exists.
+
+        See Also
+        --------
+        polars.is_duplicated_by
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 2, 1, 1], "b": ["x", "y", "x", "z"]})
+        >>> lf.duplicates().collect()
+        shape: (2, 3)
+        ┌─────┬─────┬───────┐
+        │ a   ┆ b   ┆ count │
+        │ --- ┆ --- ┆ ---   │
+        │ i64 ┆ str ┆ u32   │
+        ╞═════╪═════╪═══════╡
+        │ 1   ┆ x   ┆ 2     │
+        │ 1   ┆ x   ┆ 2     │
+        └─────┴─────┴───────┘
+        >>> lf.duplicates("a").collect()
+        shape: (3, 3)
+        ┌─────┬─────┬───────┐
+        │ a   ┆ b   ┆ count │
+        │ --- ┆ --- ┆ ---   │
+        │ i64 ┆ str ┆ u32   │
+        ╞═════╪═════╪═══════╡
+        │ 1   ┆ x   ┆ 3     │
+        │ 1   ┆ x   ┆ 3     │
+        │ 1   ┆ z   ┆ 3     │
+        └─────┴─────┴───────┘
+        """
+        if subset is not None:
+            subset = parse_into_list_of_expressions(subset)
+        return self._from_pyldf(self._ldf.duplicates(subset, name))
+
     def unpivot(
         self,
         on: ColumnNameOrSelector | Sequence[ColumnNameOrSelector] | None = None,
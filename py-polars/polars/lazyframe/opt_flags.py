@@ -43,6 +43,8 @@ class QueryOptFlags:
         collapse_joins: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
+        semi_join_reduction: None | bool = None,
+        deterministic: None | bool = None,
     ) -> None:
         self._pyoptflags = PyOptFlags.default()
         self.update(
@@ -56,6 +58,8 @@ class QueryOptFlags:
             collapse_joins=collapse_joins,
             check_order_observe=check_order_observe,
             fast_projection=fast_projection,
+            semi_join_reduction=semi_join_reduction,
+            deterministic=deterministic,
         )
 
     @classmethod
@@ -77,6 +81,8 @@ class QueryOptFlags:
         collapse_joins: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
+        semi_join_reduction: None | bool = None,
+        deterministic: None | bool = None,
     ) -> QueryOptFlags:
         """Create new empty set off optimizations."""
         optflags = QueryOptFlags()
@@ -92,6 +98,8 @@ class QueryOptFlags:
             collapse_joins=collapse_joins,
             check_order_observe=check_order_observe,
             fast_projection=fast_projection,
+            semi_join_reduction=semi_join_reduction,
+            deterministic=deterministic,
         )
 
     def update(
@@ -107,6 +115,8 @@ class QueryOptFlags:
         collapse_joins: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
+        semi_join_reduction: None | bool = None,
+        deterministic: None | bool = None,
     ) -> QueryOptFlags:
         """Update the current optimization flags."""
         if predicate_pushdown is not None:
@@ -129,6 +139,10 @@ class QueryOptFlags:
             self.check_order_observe = check_order_observe
         if fast_projection is not None:
             self.fast_projection = fast_projection
+        if semi_join_reduction is not None:
+            self.semi_join_reduction = semi_join_reduction
+        if deterministic is not None:
+            self.deterministic = deterministic
 
         return self
 
@@ -241,6 +255,35 @@ class QueryOptFlags:
     def fast_projection(self, value: bool) -> None:
         self._pyoptflags.fast_projection = value
 
+    @property
+    def semi_join_reduction(self) -> bool:
+        """
+        Semi-join the fact table of an aggregation over joins with the filtered dimensions.
+
+        This is off by default, as the extra join only pays off if the filters on the
+        dimensions are selective.
+        """
+        return self._pyoptflags.semi_join_reduction
+
+    @semi_join_reduction.setter
+    def semi_join_reduction(self, value: bool) -> None:
+        self._pyoptflags.semi_join_reduction = value
+
+    @property
+    def deterministic(self) -> bool:
+        """
+        Force deterministic results where the engine may vary them between runs.
+
+        Group-by, unique and concatenation maintain the order of their input, and
+        unique with `keep="any"` keeps the first row. This trades performance for
+        reproducibility.
+        """
+        return self._pyoptflags.deterministic
+
+    @deterministic.setter
+    def deterministic(self, value: bool) -> None:
+        self._pyoptflags.deterministic = value
+
     def __str__(self) -> str:
         return f"""
 QueryOptFlags {{
@@ -257,6 +300,8 @@ QueryOptFlags {{
     collapse_joins: {self.collapse_joins}
     check_order_observe: {self.check_order_observe}
     fast_projection: {self.fast_projection}
+    semi_join_reduction: {self.semi_join_reduction}
+    deterministic: {self.deterministic}
 
     eager: {self._pyoptflags.eager}
     old_streaming: {self._pyoptflags.old_streaming}
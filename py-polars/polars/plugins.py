@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import atexit
 import contextlib
 import sys
 from functools import lru_cache
@@ -7,18 +8,40 @@ from pathlib import Path
 from typing import TYPE_CHECKING, Any
 
 from polars._utils.parse import parse_into_list_of_expressions
+from polars._utils.unstable import unstable
 from polars._utils.wrap import wrap_expr
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
     import polars.polars as plr
 
 if TYPE_CHECKING:
-    from collections.abc import Iterable
+    from collections.abc import Callable, Iterable
 
     from polars import Expr
     from polars._typing import IntoExpr
 
-__all__ = ["register_plugin_function"]
+__all__ = [
+    "register_extension_type",
+    "register_plugin_function",
+    "register_rolling_aggregator",
+    "unregister_extension_type",
+    "unregister_rolling_aggregator",
+]
+
+# The registries are global to the process, so the objects registered from this
+# interpreter are removed when it exits instead of being kept alive by them.
+_REGISTERED_ROLLING_AGGREGATORS: set[str] = set()
+_REGISTERED_EXTENSION_TYPES: set[str] = set()
+
+
+@atexit.register
+def _unregister_all() -> None:
+    for name in _REGISTERED_ROLLING_AGGREGATORS:
+        plr.unregister_rolling_aggregator(name)
+    _REGISTERED_ROLLING_AGGREGATORS.clear()
+    for name in _REGISTERED_EXTENSION_TYPES:
+        plr.unregister_extension_type(name)
+    _REGISTERED_EXTENSION_TYPES.clear()
 
 
 def register_plugin_function(
@@ -108,6 +131,217 @@ def register_plugin_function(
     )
 
 
+@unstable()
+def register_rolling_aggregator(name: str, factory: Callable[[], Any]) -> None:
+    """
+    Register a rolling aggregator to use with :meth:`Expr.rolling_map_registered`.
+
+    A rolling aggregator keeps the state of a statistic of the values in the window,
+    and is updated as values enter and leave the window. Unlike
+    :meth:`Expr.rolling_map`, it never has to scan the whole window.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    name
+        The name to register the aggregator as. An aggregator that was registered
+        under the same name is replaced. The registry is shared by the whole
+        process, so use a name that is unique to your library, and remove the
+        aggregator with :func:`unregister_rolling_aggregator` when it is no longer
+        needed. It is removed when the interpreter exits at the latest.
+    factory
+        Function without arguments that creates a new aggregator for every column
+        it is applied to. The aggregator must
+        have the methods `add(value)` and `remove(value)`, called with the float
+        values that enter and leave the window, and `evaluate()`, which returns the
+        statistic as a float or `None`. Values leave the window in the order in
+        which they entered it, and nulls never enter it.
+
+    Warnings
+    --------
+    The methods of the aggregator are called from Python for every value, which is
+    much slower than the specialized rolling functions such as
+    :func:`Expr.rolling_sum`. Aggregators implemented in Rust can be registered
+    with `register_rolling_aggregator` in the `polars-time` crate.
+
+    Examples
+    --------
+    A rolling sum with Kahan compensation, which keeps the rounding errors of the
+    values that entered and left the window from accumulating:
+
+    >>> class KahanSum:
+    ...     def __init__(self) -> None:
+    ...         self.sum = 0.0
+    ...         self.compensation = 0.0
+    ...
+    ...     def _update(self, value: float) -> None:
+    ...         y = value - self.compensation
+    ...         t = self.sum + y
+    ...         self.compensation = (t - self.sum) - y
+    ...         self.sum = t
+    ...
+    ...     def add(self, value: float) -> None:
+    ...         self._update(value)
+    ...
+    ...     def remove(self, value: float) -> None:
+    ...         self._update(-value)
+    ...
+    ...     def evaluate(self) -> float | None:
+    ...         return self.sum
+    >>> pl.plugins.register_rolling_aggregator("kahan_sum", KahanSum)
+    >>> df = pl.DataFrame({"a": [1.0, 2.0, 3.0, 4.0, 5.0]})
+    >>> df.select(pl.col("a").rolling_map_registered("kahan_sum", window_size=3))
+    shape: (5, 1)
+    ┌──────┐
+    │ a    │
+    │ ---  │
+    │ f64  │
+    ╞══════╡
+    │ null │
+    │ null │
+    │ 6.0  │
+    │ 9.0  │
+    │ 12.0 │
+    └──────┘
+    """
+    plr.register_rolling_aggregator(name, factory)
+    _REGISTERED_ROLLING_AGGREGATORS.add(name)
+
+
+@unstable()
+def unregister_rolling_aggregator(name: str) -> bool:
+    """
+    Remove a rolling aggregator registered with :func:`register_rolling_aggregator`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    name
+        The name the aggregator was registered as.
+
+    Returns
+    -------
+    bool
+        Whether an aggregator was registered under `name`.
+
+    Examples
+    --------
+    >>> pl.plugins.register_rolling_aggregator("unused", lambda: None)
+    >>> pl.plugins.unregister_rolling_aggregator("unused")
+    True
+    >>> pl.plugins.unregister_rolling_aggregator("unused")
+    False
+    """
+    _REGISTERED_ROLLING_AGGREGATORS.discard(name)
+    return plr.unregister_rolling_aggregator(name)
+
+
+@unstable()
+def register_extension_type(name: str, definition: Any) -> None:
+    """
+    Register a logical extension type to use with :meth:`Series.to_extension`.
+
+    An extension type is layered over a physical storage type, e.g. a "currency" over
+    `Int64` cents. A Series of an extension type is a Series of the storage type that
+    has the name of the extension type in its field metadata, so it keeps its type
+    through operations that keep the values, such as sorting and filtering, and is
+    written to and read from Parquet and IPC files. Operations that compute new
+    values, such as arithmetic and aggregations, give values of the storage type and
+    warn that they dropped the extension type.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    name
+        The name to register the extension type as. A type that was registered under
+        the same name is replaced. The registry is shared by the whole process, so
+        use a name that is unique to your library. The type is removed when the
+        interpreter exits at the latest.
+    definition
+        Object with the attribute `storage_dtype`, the data type the values are
+        stored as, and optionally the methods below. They are called with the Series
+        of storage values and the parameters of the extension type as a string, or
+        `None`.
+
+        - `parse(s, metadata)` converts a String Series to the storage type. By
+          default the values are cast.
+        - `format(s, metadata)` converts the values to a String Series when they are
+          cast to `String`. By default the values are cast.
+        - `validate(s, metadata)` raises if the values are invalid for the type.
+        - `can_cast_to(dtype)` returns whether the values may be cast to `dtype`.
+          By default all casts are allowed.
+
+    Examples
+    --------
+    >>> class Currency:
+    ...     storage_dtype = pl.Int64
+    ...
+    ...     def format(self, s: pl.Series, metadata: str | None) -> pl.Series:
+    ...         units = (s // 100).cast(pl.String)
+    ...         cents = (s % 100).cast(pl.String).str.zfill(2)
+    ...         return ((metadata or "$") + units + "." + cents).alias(s.name)
+    ...
+    ...     def can_cast_to(self, dtype: pl.DataType) -> bool:
+    ...         return not dtype.is_float()
+    >>> pl.plugins.register_extension_type("currency", Currency())
+    >>> s = pl.Series("price", [150, 2005]).to_extension("currency", "€")
+    >>> s.cast(pl.String)
+    shape: (2,)
+    Series: 'price' [str]
+    [
+        "€1.50"
+        "€20.05"
+    ]
+    """
+    plr.register_extension_type(name, definition)
+    _REGISTERED_EXTENSION_TYPES.add(name)
+
+
+@unstable()
+def unregister_extension_type(name: str) -> bool:
+    """
+    Remove an extension type registered with :func:`register_extension_type`.
+
+    Series of the extension type keep its name in their metadata, and are cast like
+    their storage type until it is registered again.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    name
+        The name the extension type was registered as.
+
+    Returns
+    -------
+    bool
+        Whether an extension type was registered under `name`.
+
+    Examples
+    --------
+    >>> class Unused:
+    ...     storage_dtype = pl.Float64
+    >>> pl.plugins.register_extension_type("unused", Unused())
+    >>> pl.plugins.unregister_extension_type("unused")
+    True
+    >>> pl.plugins.unregister_extension_type("unused")
+    False
+    """
+    _REGISTERED_EXTENSION_TYPES.discard(name)
+    return plr.unregister_extension_type(name)
+
+
 def _serialize_kwargs(kwargs: dict[str, Any] | None) -> bytes:
     """Serialize the function's keyword arguments."""
     if not kwargs:
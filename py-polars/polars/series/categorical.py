@@ -7,6 +7,8 @@ from polars._utils.wrap import wrap_s
 from polars.series.utils import expr_dispatch
 
 if TYPE_CHECKING:
+    from collections.abc import Iterable
+
     from polars import Series
     from polars.polars import PySeries
 
@@ -37,6 +39,30 @@ class CatNameSpace:
         ]
         """
 
+    @unstable()
+    def extend_categories(self, categories: Iterable[str]) -> Series:
+        """
+        Add categories to an `Enum`.
+
+        The new categories are placed after the existing ones, and categories that
+        are already present are ignored. The values themselves are not touched.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        categories
+            The categories to add.
+
+        Examples
+        --------
+        >>> s = pl.Series(["north", "south"], dtype=pl.Enum(["north", "south"]))
+        >>> s.cat.extend_categories(["east"]).dtype
+        Enum(categories=['north', 'south', 'east'])
+        """
+
     def is_local(self) -> bool:
         """
         Return whether or not the column is a local categorical.
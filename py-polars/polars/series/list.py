@@ -14,6 +14,7 @@ if TYPE_CHECKING:
     from polars._typing import (
         IntoExpr,
         IntoExprColumn,
+        ListSetNulls,
         ListToStructWidthStrategy,
         NullBehavior,
     )
@@ -858,6 +859,10 @@ class ListNameSpace:
         self,
         n_field_strategy: ListToStructWidthStrategy = "first_non_null",
         fields: Callable[[int], str] | Sequence[str] | None = None,
+        *,
+        keys: Series | Sequence[str] | None = None,
+        infer_sample_size: int | None = None,
+        width: int | None = None,
     ) -> Series:
         """
         Convert the series of type `List` to a series of type `Struct`.
@@ -875,6 +880,18 @@ class ListNameSpace:
             a list of field names can be given, which will be assigned by index.
             Otherwise, to dynamically assign field names, a custom function can be
             used; if neither are set, fields will be `field_0, field_1 .. field_n`.
+        keys
+            Lists of keys that give the field of the value at the same position, or
+            a single list of keys for all rows, e.g. the header of a JSON array.
+            The fields are the keys in the order in which they first occur, unless
+            `fields` is given as a list of names, in which case all other keys are
+            ignored. Missing keys result in null values.
+        infer_sample_size
+            Only look at the first `infer_sample_size` sublists to determine the
+            number of fields with the "max_width" strategy.
+        width
+            Use exactly this many fields instead of inferring the number from the
+            data.
 
         Examples
         --------
@@ -921,6 +938,9 @@ class ListNameSpace:
                     n_field_strategy,
                     fields,
                     upper_bound=0,
+                    keys=keys,
+                    infer_sample_size=infer_sample_size,
+                    width=width,
                     _eager=True,
                 )
             )
@@ -956,7 +976,14 @@ class ListNameSpace:
         ]
         """
 
-    def set_union(self, other: Series | Collection[Any]) -> Series:
+    def set_union(
+        self,
+        other: Series | Collection[Any],
+        *,
+        multiset: bool = False,
+        maintain_order: bool = False,
+        nulls: ListSetNulls = "equal",
+    ) -> Series:
         """
         Compute the SET UNION between the elements in this list and the elements of `other`.
 
@@ -964,6 +991,18 @@ class ListNameSpace:
         ----------
         other
             Right hand side of the set operation.
+        multiset
+            Keep duplicate elements, so that an element that occurs `m` times in this
+            list and `n` times in `other` occurs `max(m, n)` times in the result.
+        maintain_order
+            Guarantee that the elements keep their order, with the elements of this
+            list first. Multiset operations always keep the order.
+        nulls : {'equal', 'distinct', 'ignore'}
+            How to treat null elements.
+
+            - 'equal': null elements are equal to each other.
+            - 'distinct': null elements are never equal to each other.
+            - 'ignore': null elements are removed from both lists first.
 
         Examples
         --------
@@ -980,7 +1019,14 @@ class ListNameSpace:
         ]
         """  # noqa: W505
 
-    def set_difference(self, other: Series | Collection[Any]) -> Series:
+    def set_difference(
+        self,
+        other: Series | Collection[Any],
+        *,
+        multiset: bool = False,
+        maintain_order: bool = False,
+        nulls: ListSetNulls = "equal",
+    ) -> Series:
         """
         Compute the SET DIFFERENCE between the elements in this list and the elements of `other`.
 
@@ -988,6 +1034,18 @@ class ListNameSpace:
         ----------
         other
             Right hand side of the set operation.
+        multiset
+            Keep duplicate elements, so that an element that occurs `m` times in this
+            list and `n` times in `other` occurs `m - n` (if positive) times in the result.
+        maintain_order
+            Guarantee that the elements keep their order, with the elements of this
+            list first. Multiset operations always keep the order.
+        nulls : {'equal', 'distinct', 'ignore'}
+            How to treat null elements.
+
+            - 'equal': null elements are equal to each other.
+            - 'distinct': null elements are never equal to each other.
+            - 'ignore': null elements are removed from both lists first.
 
         See Also
         --------
@@ -1008,7 +1066,14 @@ class ListNameSpace:
         ]
         """  # noqa: W505
 
-    def set_intersection(self, other: Series | Collection[Any]) -> Series:
+    def set_intersection(
+        self,
+        other: Series | Collection[Any],
+        *,
+        multiset: bool = False,
+        maintain_order: bool = False,
+        nulls: ListSetNulls = "equal",
+    ) -> Series:
         """
         Compute the SET INTERSECTION between the elements in this list and the elements of `other`.
 
@@ -1016,6 +1081,18 @@ class ListNameSpace:
         ----------
         other
             Right hand side of the set operation.
+        multiset
+            Keep duplicate elements, so that an element that occurs `m` times in this
+            list and `n` times in `other` occurs `min(m, n)` times in the result.
+        maintain_order
+            Guarantee that the elements keep their order, with the elements of this
+            list first. Multiset operations always keep the order.
+        nulls : {'equal', 'distinct', 'ignore'}
+            How to treat null elements.
+
+            - 'equal': null elements are equal to each other.
+            - 'distinct': null elements are never equal to each other.
+            - 'ignore': null elements are removed from both lists first.
 
         Examples
         --------
@@ -1030,9 +1107,27 @@ class ListNameSpace:
                 [null, 3]
                 [6]
         ]
+
+        Keep duplicates, and don't match null elements.
+
+        >>> a = pl.Series([[1, 1, 2, None]])
+        >>> b = pl.Series([[1, 1, 1, None]])
+        >>> a.list.set_intersection(b, multiset=True, nulls="distinct")
+        shape: (1,)
+        Series: '' [list[i64]]
+        [
+                [1, 1]
+        ]
         """  # noqa: W505
 
-    def set_symmetric_difference(self, other: Series | Collection[Any]) -> Series:
+    def set_symmetric_difference(
+        self,
+        other: Series | Collection[Any],
+        *,
+        multiset: bool = False,
+        maintain_order: bool = False,
+        nulls: ListSetNulls = "equal",
+    ) -> Series:
         """
         Compute the SET SYMMETRIC DIFFERENCE between the elements in this list and the elements of `other`.
 
@@ -1040,6 +1135,18 @@ class ListNameSpace:
         ----------
         other
             Right hand side of the set operation.
+        multiset
+            Keep duplicate elements, so that an element that occurs `m` times in this
+            list and `n` times in `other` occurs `abs(m - n)` times in the result.
+        maintain_order
+            Guarantee that the elements keep their order, with the elements of this
+            list first. Multiset operations always keep the order.
+        nulls : {'equal', 'distinct', 'ignore'}
+            How to treat null elements.
+
+            - 'equal': null elements are equal to each other.
+            - 'distinct': null elements are never equal to each other.
+            - 'ignore': null elements are removed from both lists first.
 
         Examples
         --------
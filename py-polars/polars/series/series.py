@@ -125,10 +125,12 @@ if TYPE_CHECKING:
         BufferInfo,
         ClosedInterval,
         ComparisonOperator,
+        EnumPolicy,
         FillNullStrategy,
         InterpolationMethod,
         IntoExpr,
         IntoExprColumn,
+        ModeTiePolicy,
         MultiIndexSelector,
         NonNestedLiteral,
         NullBehavior,
@@ -2748,8 +2750,10 @@ class Series:
 
         Warnings
         --------
-        This can be really slow as it can have `O(n^2)` complexity. Don't use this
-        for operations that visit all elements.
+        Expressions that aggregate every window to a single value (e.g. `sum`, `mean`,
+        `min` or `max`, combined with elementwise expressions) run in a single pass.
+        Other expressions are evaluated once per window, which can be really slow as
+        it has `O(n^2)` complexity.
 
         Examples
         --------
@@ -3006,7 +3010,7 @@ class Series:
         """
         return self._from_pyseries(self._s.slice(offset=offset, length=length))
 
-    def append(self, other: Series) -> Self:
+    def append(self, other: Series, *, enum_policy: EnumPolicy = "strict") -> Self:
         """
         Append a Series to this one.
 
@@ -3016,6 +3020,17 @@ class Series:
         ----------
         other
             Series to append.
+        enum_policy : {'strict', 'extend'}
+            What to do if both Series are of type `Enum` and `other` holds
+            categories this Series lacks.
+
+            - 'strict': raise an error.
+            - 'extend': add the missing categories after the existing ones. The
+              existing values are kept as is, only the appended values are remapped.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Warnings
         --------
@@ -3047,10 +3062,10 @@ class Series:
         2
         """
         try:
-            self._s.append(other._s)
+            self._s.append(other._s, enum_policy)
         except RuntimeError as exc:
             if str(exc) == "Already mutably borrowed":
-                self._s.append(other._s.clone())
+                self._s.append(other._s.clone(), enum_policy)
             else:
                 raise
         return self
@@ -5228,12 +5243,22 @@ class Series:
             raise ShapeError(msg)
         return self._s.dot(other._s)
 
-    def mode(self) -> Series:
+    def mode(self, ties: ModeTiePolicy = "all") -> Series:
         """
         Compute the most occurring value(s).
 
         Can return multiple Values.
 
+        Parameters
+        ----------
+        ties : {'all', 'smallest', 'first', 'all_as_list'}
+            How to resolve ties between values that occur equally often.
+
+            - 'all': return all of them, in no particular order.
+            - 'smallest': return the smallest of them.
+            - 'first': return the one that occurs first.
+            - 'all_as_list': return all of them in ascending order, as a single list.
+
         Examples
         --------
         >>> s = pl.Series("a", [1, 2, 2, 3])
@@ -6063,6 +6088,44 @@ class Series:
         ]
         """
 
+    @unstable()
+    def rolling_map_registered(
+        self,
+        name: str,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Apply a registered rolling aggregator over a rolling window.
+
+        The aggregator is updated as values enter and leave the window, see
+        :func:`polars.plugins.register_rolling_aggregator`. The values are cast to
+        :class:`Float64`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        name
+            The name the aggregator was registered as.
+        window_size
+            The length of the window in number of elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        See Also
+        --------
+        polars.plugins.register_rolling_aggregator
+        rolling_map
+        """
+
     @unstable()
     @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
     def rolling_median(
@@ -7599,6 +7662,100 @@ class Series:
         """
         return self._from_pyseries(self._s.set_sorted_flag(descending))
 
+    @unstable()
+    def to_extension(self, name: str, metadata: str | None = None) -> Series:
+        """
+        Convert to an extension type registered with `register_extension_type`.
+
+        A String Series is parsed by the extension type, other Series are strictly cast
+        to its storage type. The values are then validated by the extension type.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        name
+            The name the extension type was registered as.
+        metadata
+            The parameters of the extension type, e.g. a currency code.
+
+        See Also
+        --------
+        to_storage
+        extension_type
+
+        Examples
+        --------
+        >>> class Probability:
+        ...     storage_dtype = pl.Float64
+        ...
+        ...     def validate(self, s: pl.Series, metadata: str | None) -> None:
+        ...         if not s.is_between(0, 1).all():
+        ...             msg = "probabilities should be between 0 and 1"
+        ...             raise ValueError(msg)
+        >>> pl.plugins.register_extension_type("probability", Probability())
+        >>> s = pl.Series("p", [0.25, 0.5]).to_extension("probability")
+        >>> s.extension_type()
+        ('probability', None)
+        """
+        return self._from_pyseries(self._s.to_extension(name, metadata))
+
+    @unstable()
+    def to_storage(self) -> Series:
+        """
+        Get the values of a Series of an extension type without the extension type.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        See Also
+        --------
+        to_extension
+
+        Examples
+        --------
+        >>> class Probability:
+        ...     storage_dtype = pl.Float64
+        >>> pl.plugins.register_extension_type("probability", Probability())
+        >>> s = pl.Series("p", [0.25, 0.5]).to_extension("probability")
+        >>> s.to_storage().extension_type() is None
+        True
+        """
+        return self._from_pyseries(self._s.to_storage())
+
+    @unstable()
+    def extension_type(self) -> tuple[str, str | None] | None:
+        """
+        Get the name and the parameters of the extension type of this Series.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Returns
+        -------
+        tuple or None
+            The name and the parameters of the extension type, or `None` if the
+            Series has no extension type.
+
+        See Also
+        --------
+        to_extension
+
+        Examples
+        --------
+        >>> class Currency:
+        ...     storage_dtype = pl.Int64
+        >>> pl.plugins.register_extension_type("currency", Currency())
+        >>> s = pl.Series("price", [150, 2005]).to_extension("currency", "EUR")
+        >>> s.extension_type()
+        ('currency', 'EUR')
+        """
+        return self._s.extension_type()
+
     def new_from_index(self, index: int, length: int) -> Self:
         """
         Create a new Series filled with values from the given index.
@@ -3,6 +3,7 @@ from __future__ import annotations
 import sys
 from typing import TYPE_CHECKING
 
+from polars._utils.unstable import unstable
 from polars._utils.various import (
     BUILDING_SPHINX_DOCS,
     qualified_type_name,
@@ -137,6 +138,31 @@ class StructNameSpace:
         """
         return wrap_df(self._s.struct_unnest())
 
+    @unstable()
+    def to_array(self) -> Series:
+        """
+        Convert this struct to an array with one element per field.
+
+        All fields must have the same data type. The elements follow the order of the
+        fields and a null struct becomes a null array. This is the inverse of
+        :meth:`Series.arr.to_struct`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> s = pl.Series("xy", [{"x": 1.0, "y": 2.0}, None])
+        >>> s.struct.to_array()
+        shape: (2,)
+        Series: 'xy' [array[f64, 2]]
+        [
+            [1.0, 2.0]
+            null
+        ]
+        """
+
     def json_encode(self) -> Series:
         """
         Convert this struct to a string column with json values.
@@ -125,6 +125,8 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::int_ranges))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::scan_int_range))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::linear_space))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::linear_spaces))
@@ -133,6 +135,8 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::date_ranges))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::scan_date_range))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::datetime_range))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::datetime_ranges))
@@ -169,6 +173,16 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::coalesce))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::is_duplicated_by))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::encode_rows))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::align_frames))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::bucket_id))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::bucketing_metadata))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::field)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::col)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::collect_all))
@@ -200,6 +214,7 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::concat_lf_diagonal))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::values)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::concat_lf_horizontal))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::dtype_cols))
@@ -235,11 +250,20 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     #[cfg(feature = "sql")]
     m.add_wrapped(wrap_pyfunction!(functions::sql_expr))
         .unwrap();
+    #[cfg(feature = "sql")]
+    m.add_wrapped(wrap_pyfunction!(functions::parse_expr))
+        .unwrap();
 
     // Functions - I/O
     #[cfg(feature = "ipc")]
     m.add_wrapped(wrap_pyfunction!(functions::read_ipc_schema))
         .unwrap();
+    #[cfg(feature = "ipc")]
+    m.add_wrapped(wrap_pyfunction!(functions::shared_memory_path))
+        .unwrap();
+    #[cfg(feature = "ipc")]
+    m.add_wrapped(wrap_pyfunction!(functions::remove_shared_memory))
+        .unwrap();
     #[cfg(feature = "parquet")]
     m.add_wrapped(wrap_pyfunction!(functions::read_parquet_schema))
         .unwrap();
@@ -410,6 +434,14 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     #[cfg(feature = "ffi_plugin")]
     m.add_wrapped(wrap_pyfunction!(functions::register_plugin_function))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::register_rolling_aggregator))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::unregister_rolling_aggregator))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::register_extension_type))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::unregister_extension_type))
+        .unwrap();
 
     // Capsules
     m.add("_allocator", create_allocator_capsule(py)?)?;
@@ -13,7 +13,7 @@ from polars.testing import assert_frame_equal
 if TYPE_CHECKING:
     from datetime import timezone
 
-    from polars._typing import FillNullStrategy, PolarsIntegerType
+    from polars._typing import EngineType, FillNullStrategy, PolarsIntegerType
 
 
 @pytest.mark.parametrize(
@@ -281,3 +281,47 @@ def test_upsample_sorted_only_within_group_but_no_group_by_provided() -> None:
         match=r"argument in operation 'upsample' is not sorted, please sort the 'expr/series/column' first",
     ):
         df.upsample(time_column="time", every="1mo")
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+@pytest.mark.parametrize("time_zone", [None, "Europe/Warsaw"])
+def test_upsample_lazy(engine: EngineType, time_zone: str | None) -> None:
+    df = pl.DataFrame(
+        {
+            "time": [
+                datetime(2021, 6, 1),
+                datetime(2021, 2, 1),
+                datetime(2021, 4, 1),
+                datetime(2021, 5, 1),
+                datetime(2021, 1, 1),
+            ],
+            "admin": ["Netherlands", "Åland", "Netherlands", "Åland", None],
+            "test2": [5, 0, 1, 3, 4],
+        }
+    ).with_columns(pl.col("time").dt.replace_time_zone(time_zone))
+
+    result = (
+        df.lazy()
+        .upsample(
+            time_column="time", every="1mo", group_by="admin", maintain_order=True
+        )
+        .with_columns(pl.col("test2").interpolate().over("admin"))
+        .collect(engine=engine)
+    )
+    expected = pl.DataFrame(
+        {
+            "time": [
+                datetime(2021, 4, 1),
+                datetime(2021, 5, 1),
+                datetime(2021, 6, 1),
+                datetime(2021, 2, 1),
+                datetime(2021, 3, 1),
+                datetime(2021, 4, 1),
+                datetime(2021, 5, 1),
+                datetime(2021, 1, 1),
+            ],
+            "admin": ["Netherlands"] * 3 + ["Åland"] * 4 + [None],
+            "test2": [1.0, 3.0, 5.0, 0.0, 1.0, 2.0, 3.0, 4.0],
+        }
+    ).with_columns(pl.col("time").dt.replace_time_zone(time_zone))
+    assert_frame_equal(result, expected)
@@ -723,3 +723,46 @@ def test_read_enum_from_csv() -> None:
     read = pl.read_csv(f, schema=schema)
     assert read.schema == schema
     assert_frame_equal(df.cast(schema), read)  # type: ignore[arg-type]
+
+
+def test_enum_extend_categories() -> None:
+    dtype = pl.Enum(["north", "south"])
+    s = pl.Series("dir", ["south", None, "north"], dtype=dtype)
+
+    out = s.cat.extend_categories(["east", "north", "east"])
+    assert out.dtype == pl.Enum(["north", "south", "east"])
+    assert out.to_physical().to_list() == s.to_physical().to_list()
+    assert out.to_list() == ["south", None, "north"]
+
+    lf = s.to_frame().lazy().select(pl.col("dir").cat.extend_categories(["west"]))
+    expected = pl.Enum(["north", "south", "west"])
+    assert lf.collect_schema() == {"dir": expected}
+    assert lf.collect().schema == {"dir": expected}
+
+    with pytest.raises(InvalidOperationError, match="Enum"):
+        pl.Series(["a"], dtype=pl.Categorical).cat.extend_categories(["b"])
+
+
+def test_enum_append_policy() -> None:
+    left = pl.Series("dir", ["north", "south"], dtype=pl.Enum(["north", "south"]))
+    right = pl.Series("dir", ["east", None, "south"], dtype=pl.Enum(["east", "south"]))
+
+    with pytest.raises(SchemaError):
+        left.clone().append(right)
+
+    out = left.clone().append(right, enum_policy="extend")
+    assert out.dtype == pl.Enum(["north", "south", "east"])
+    assert out.to_list() == ["north", "south", "east", None, "south"]
+    assert out.to_physical().to_list() == [0, 1, 2, None, 1]
+
+    df = left.to_frame().with_columns(n=pl.int_range(2))
+    other = right.to_frame().with_columns(n=pl.int_range(3))
+    out_df = df.vstack(other, enum_policy="extend")
+    assert out_df.schema == {"dir": pl.Enum(["north", "south", "east"]), "n": pl.Int64}
+    assert out_df["dir"].to_list() == out.to_list()
+
+    df.vstack(other, in_place=True, enum_policy="extend")
+    assert_frame_equal(df, out_df)
+
+    with pytest.raises(ValueError, match="enum_policy"):
+        left.clone().append(right, enum_policy="grow")  # type: ignore[arg-type]
@@ -11,7 +11,7 @@ from polars.exceptions import ComputeError, InvalidOperationError
 from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
-    from polars._typing import ClosedInterval
+    from polars._typing import ClosedInterval, EngineType
 
 
 def test_date_range() -> None:
@@ -319,3 +319,40 @@ def test_date_range_with_subclass_18470_18447() -> None:
     )
     expected = pl.Series("literal", [datetime(2020, 1, 1), datetime(2020, 1, 2)])
     assert_series_equal(result, expected)
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+@pytest.mark.parametrize("interval", ["1d", "3d", "1w", "1mo", "1y2mo5d"])
+@pytest.mark.parametrize("closed", ["both", "left", "right", "none"])
+def test_scan_date_range(
+    interval: str, closed: ClosedInterval, engine: EngineType
+) -> None:
+    start, end = date(2020, 1, 31), date(2024, 3, 1)
+    lf = pl.scan_date_range(start, end, interval, closed=closed)
+    expected = pl.date_range(start, end, interval, closed=closed, eager=True)
+    expected = expected.alias("date")
+
+    assert_series_equal(lf.collect(engine=engine).to_series(), expected)
+    assert_series_equal(
+        lf.slice(2, 3).collect(engine=engine).to_series(), expected.slice(2, 3)
+    )
+
+    lower, upper = date(2021, 2, 28), date(2023, 6, 1)
+    mask = (expected >= lower) & (expected < upper)
+    assert_series_equal(
+        lf.filter(pl.col("date") >= lower, pl.col("date") < upper)
+        .collect(engine=engine)
+        .to_series(),
+        expected.filter(mask),
+    )
+
+
+def test_scan_date_range_does_not_materialize() -> None:
+    lf = pl.scan_date_range(date(1, 1, 1), date(9999, 12, 31), "1d")
+    out = lf.filter(pl.col("date") == date(2000, 2, 29)).collect()
+    assert out["date"].to_list() == [date(2000, 2, 29)]
+
+
+def test_scan_date_range_invalid_interval() -> None:
+    with pytest.raises(ComputeError, match="must consist of full days"):
+        pl.scan_date_range(date(2020, 1, 1), date(2020, 2, 1), "12h")
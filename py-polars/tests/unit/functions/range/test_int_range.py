@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from typing import Any
+from typing import TYPE_CHECKING, Any
 
 import pytest
 
@@ -8,6 +8,9 @@ import polars as pl
 from polars.exceptions import ComputeError, InvalidOperationError
 from polars.testing import assert_frame_equal, assert_series_equal
 
+if TYPE_CHECKING:
+    from polars._typing import EngineType
+
 
 def test_int_range() -> None:
     result = pl.int_range(0, 3)
@@ -278,3 +281,64 @@ def test_int_ranges_non_int_dtype() -> None:
         ComputeError, match="non-integer `dtype` passed to `int_ranges`: String"
     ):
         pl.int_ranges(0, 3, dtype=pl.String, eager=True)  # type: ignore[arg-type]
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+@pytest.mark.parametrize(
+    ("start", "end", "step", "dtype"),
+    [
+        (0, 100, 1, pl.Int64),
+        (5, 97, 7, pl.Int32),
+        (100, -3, -4, pl.Int16),
+        (0, 255, 3, pl.UInt8),
+        (3, 3, 1, pl.Int64),
+    ],
+)
+def test_scan_int_range(
+    start: int, end: int, step: int, dtype: pl.DataType, engine: EngineType
+) -> None:
+    lf = pl.scan_int_range(start, end, step, dtype=dtype, name="i")
+    expected = pl.int_range(start, end, step, dtype=dtype, eager=True).alias("i")
+
+    assert_series_equal(lf.collect(engine=engine).to_series(), expected)
+    assert_series_equal(
+        lf.slice(3, 4).collect(engine=engine).to_series(), expected.slice(3, 4)
+    )
+    assert_series_equal(
+        lf.filter((pl.col("i") > 10) & (pl.col("i") <= 50))
+        .collect(engine=engine)
+        .to_series(),
+        expected.filter((expected > 10) & (expected <= 50)),
+    )
+    mask = expected.is_between(20, 30, closed="left") & (expected % 2 == 0)
+    assert_series_equal(
+        lf.filter(pl.col("i").is_between(20, 30, closed="left"), pl.col("i") % 2 == 0)
+        .collect(engine=engine)
+        .to_series(),
+        expected.filter(mask),
+    )
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_scan_int_range_does_not_materialize(engine: EngineType) -> None:
+    lf = pl.scan_int_range(10**18, name="i")
+
+    out = lf.filter(pl.col("i") < 3).collect(engine=engine)
+    assert out["i"].to_list() == [0, 1, 2]
+
+    out = lf.slice(10**17, 2).collect(engine=engine)
+    assert out["i"].to_list() == [10**17, 10**17 + 1]
+
+    out = lf.filter(pl.col("i").is_between(10**15, 10**15 + 1)).collect(engine=engine)
+    assert out["i"].to_list() == [10**15, 10**15 + 1]
+
+    assert lf.head(3).collect(engine=engine)["i"].to_list() == [0, 1, 2]
+
+
+def test_scan_int_range_errors() -> None:
+    with pytest.raises(InvalidOperationError, match="step must not be zero"):
+        pl.scan_int_range(0, 10, 0)
+    with pytest.raises(InvalidOperationError, match="must fit in"):
+        pl.scan_int_range(0, 1000, dtype=pl.Int8)
+    with pytest.raises(ComputeError, match="non-integer"):
+        pl.scan_int_range(0, 10, dtype=pl.Float64)  # type: ignore[arg-type]
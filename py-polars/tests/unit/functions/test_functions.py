@@ -1,16 +1,17 @@
 from __future__ import annotations
 
+from datetime import date
 from typing import TYPE_CHECKING, Any
 
 import numpy as np
 import pytest
 
 import polars as pl
-from polars.exceptions import DuplicateError, InvalidOperationError
+from polars.exceptions import ComputeError, DuplicateError, InvalidOperationError
 from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
-    from polars._typing import ConcatMethod
+    from polars._typing import ConcatMethod, JoinStrategy
 
 
 def test_concat_align() -> None:
@@ -462,6 +463,40 @@ def test_align_frames_duplicate_key() -> None:
     ]
 
 
+@pytest.mark.parametrize("how", ["full", "left", "inner"])
+def test_align_frames_unique_keys(how: JoinStrategy) -> None:
+    df1 = pl.DataFrame({"k": [3, 1, None], "x": [30, 10, 0]})
+    df2 = pl.DataFrame({"y": ["b", "c"], "k": [2, 3]})
+    df3 = pl.DataFrame({"k": [1, 3], "z": [1.5, 3.5]})
+
+    result = pl.align_frames(df1, df2, df3, on="k", how=how, unique_keys=True)
+    expected = pl.align_frames(df1, df2, df3, on="k", how=how)
+    for res, exp in zip(result, expected):
+        assert_frame_equal(res, exp)
+
+
+def test_align_frames_unique_keys_lazy() -> None:
+    df1 = pl.DataFrame({"k": [3, 1, None], "x": [30, 10, 0]})
+    df2 = pl.DataFrame({"y": ["b", "c"], "k": [2, 3]})
+    lf1, lf2 = pl.align_frames(
+        df1.lazy(), df2.lazy(), on="k", descending=True, unique_keys=True
+    )
+    assert lf1.collect().rows() == [(None, 0), (3, 30), (2, None), (1, 10)]
+    assert lf2.collect().rows() == [(None, None), ("c", 3), ("b", 2), (None, 1)]
+
+    dup = pl.DataFrame({"k": [1, 1], "w": [0, 0]})
+    with pytest.raises(ComputeError, match="1:1 validation"):
+        pl.align_frames(df1, dup, on="k", unique_keys=True)
+
+
+def test_align_frames_inner_duplicate_keys() -> None:
+    df1 = pl.DataFrame({"k": [1, 2, 1], "x": [1, 2, 3]})
+    df2 = pl.DataFrame({"k": [3, 1], "y": [4, 5]})
+    af1, af2 = pl.align_frames(df1, df2, on="k", how="inner")
+    assert sorted(af1.rows()) == [(1, 1), (1, 3)]
+    assert af2.rows() == [(1, 5), (1, 5)]
+
+
 def test_align_frames_single_row_20445() -> None:
     left = pl.DataFrame({"a": [1], "b": [2]})
     right = pl.DataFrame({"a": [1], "c": [3]})
@@ -664,3 +699,28 @@ def test_escape_regex() -> None:
         match="escape_regex function supports only `str` type, got `int`",
     ):
         pl.escape_regex(3)  # type: ignore[arg-type]
+
+
+def test_values() -> None:
+    lf = pl.values(
+        [("a", 1, pl.date(2020, 1, 1)), ("b", 2.5, None)],
+        names=["key", "weight", "dt"],
+    )
+    assert isinstance(lf, pl.LazyFrame)
+    assert lf.explain().startswith("DF ")
+    assert lf.collect_schema() == pl.Schema(
+        {"key": pl.String, "weight": pl.Float64, "dt": pl.Date}
+    )
+    expected = pl.DataFrame(
+        {
+            "key": ["a", "b"],
+            "weight": [1.0, 2.5],
+            "dt": [date(2020, 1, 1), None],
+        }
+    )
+    assert_frame_equal(lf.collect(), expected)
+
+    assert pl.values([(1, "x")]).collect_schema().names() == ["column_0", "column_1"]
+
+    with pytest.raises(pl.exceptions.ShapeError):
+        pl.values([(1, 2), (3,)])
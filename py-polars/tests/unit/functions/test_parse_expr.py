@@ -0,0 +1,90 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import SQLInterfaceError, SQLSyntaxError
+from polars.testing import assert_frame_equal
+
+
+@pytest.fixture
+def df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "a": [1, 2, 3, 4],
+            "b": [1.0, 2.5, 4.0, 10.0],
+            "c": ["x", "yy", "x", "zzz"],
+        }
+    )
+
+
+@pytest.mark.parametrize(
+    ("text", "expected"),
+    [
+        ("col('a') * 2 + col('b').log()", pl.col("a") * 2 + pl.col("b").log()),
+        ("pl.col('a') // 2 % 2", pl.col("a") // 2 % 2),
+        ("-pl.col('a') ** 2", -(pl.col("a") ** 2)),
+        (
+            "(col('a') > 1) & ~(col('c') == 'x')",
+            (pl.col("a") > 1) & ~(pl.col("c") == "x"),
+        ),
+        ("col('a').is_in([1, 3])", pl.col("a").is_in([1, 3])),
+        ("col('b').round(0).cast(pl.Int64)", pl.col("b").round(0).cast(pl.Int64)),
+        (
+            "col('c').str.len_chars().alias('n')",
+            pl.col("c").str.len_chars().alias("n"),
+        ),
+        ("col('a').sum().over('c')", pl.col("a").sum().over("c")),
+        ("col('b').clip('a', 5)", pl.col("b").clip("a", 5)),
+        (
+            "col('a').sort(descending=True)",
+            pl.col("a").sort(descending=True),
+        ),
+        (
+            "when(col('a') > 2).then(lit('big')).when(col('a') > 1).then('c')",
+            pl.when(pl.col("a") > 2)
+            .then(pl.lit("big"))
+            .when(pl.col("a") > 1)
+            .then("c"),
+        ),
+    ],
+)
+def test_parse_expr(df: pl.DataFrame, text: str, expected: pl.Expr) -> None:
+    assert_frame_equal(df.select(pl.parse_expr(text)), df.select(expected))
+
+
+def test_parse_expr_sql(df: pl.DataFrame) -> None:
+    assert_frame_equal(
+        df.select(pl.parse_expr("a * 2 + LN(b) AS d", dialect="sql")),
+        df.select((pl.col("a") * 2 + pl.col("b").log()).alias("d")),
+    )
+
+
+@pytest.mark.parametrize(
+    "text",
+    [
+        "col('a' + 1",
+        "col('a').round('x')",
+        "col('a').alias('x', 'y')",
+        "1 < col('a') < 3",
+        "when(col('a') > 1)",
+    ],
+)
+def test_parse_expr_invalid(text: str) -> None:
+    with pytest.raises(SQLSyntaxError):
+        pl.parse_expr(text)
+
+
+def test_parse_expr_unknown_method() -> None:
+    with pytest.raises(SQLInterfaceError, match="unknown method 'unknown'"):
+        pl.parse_expr("col('a').unknown()")
+
+
+def test_parse_expr_nested_too_deeply() -> None:
+    with pytest.raises(SQLSyntaxError, match="nested too deeply"):
+        pl.parse_expr("(" * 100_000 + "col('a')" + ")" * 100_000)
+
+
+def test_parse_expr_invalid_dialect() -> None:
+    with pytest.raises(ValueError, match="`dialect` must be one of"):
+        pl.parse_expr("col('a')", dialect="js")  # type: ignore[arg-type]
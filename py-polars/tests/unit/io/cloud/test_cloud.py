@@ -1,11 +1,19 @@
 from __future__ import annotations
 
 import contextlib
+import http.server
+import io
+import re
+import threading
+import time
+from email.utils import formatdate
 from functools import partial
+from typing import Any
 
 import pytest
 
 import polars as pl
+from polars.testing import assert_frame_equal
 
 
 @pytest.mark.slow
@@ -54,3 +62,133 @@ def test_scan_err_rebuild_store_19933() -> None:
     # Note: We get called 2 times per attempt
     if call_count != 4:
         raise AssertionError(call_count)
+
+
+def test_scan_parquet_http_range_requests() -> None:
+    df = pl.DataFrame({"a": range(10_000), "b": ["x", "y"] * 5_000})
+    f = io.BytesIO()
+    df.write_parquet(f, row_group_size=1_000)
+    data = f.getvalue()
+
+    ranges: list[str | None] = []
+    broke_connection = False
+
+    class Handler(http.server.BaseHTTPRequestHandler):
+        def _send_headers(self, status: int, length: int) -> bool:
+            if self.headers["Authorization"] != "Bearer secret":
+                self.send_error(401)
+                return False
+            self.send_response(status)
+            self.send_header("Content-Length", str(length))
+            self.send_header("ETag", '"v1"')
+            self.send_header("Last-Modified", formatdate(0, usegmt=True))
+            return True
+
+        def do_HEAD(self) -> None:
+            if self._send_headers(200, len(data)):
+                self.end_headers()
+
+        def do_GET(self) -> None:
+            nonlocal broke_connection
+
+            ranges.append(self.headers["Range"])
+            m = re.fullmatch(r"bytes=(\d+)-(\d+)", self.headers["Range"] or "")
+            assert m is not None
+            start, end = int(m[1]), min(int(m[2]) + 1, len(data))
+            body = data[start:end]
+
+            if not self._send_headers(206, len(body)):
+                return
+            self.send_header("Content-Range", f"bytes {start}-{end - 1}/{len(data)}")
+            self.end_headers()
+
+            if not broke_connection and len(body) > 1:
+                # Cut off the body, the download has to resume after it.
+                broke_connection = True
+                self.wfile.write(body[: len(body) // 2])
+                self.close_connection = True
+                return
+            self.wfile.write(body)
+
+        def log_message(self, *args: Any) -> None:
+            pass
+
+    server = http.server.ThreadingHTTPServer(("127.0.0.1", 0), Handler)
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    url = f"http://127.0.0.1:{server.server_address[1]}/data.parquet"
+
+    try:
+        q = pl.scan_parquet(
+            url, credential_provider=lambda: ({"bearer_token": "secret"}, None)
+        )
+        assert_frame_equal(q.collect(), df)
+        assert_frame_equal(
+            q.filter(pl.col("a") >= 9_500).select("b").collect(),
+            df.filter(pl.col("a") >= 9_500).select("b"),
+        )
+        assert broke_connection
+        assert all(r is not None for r in ranges)
+
+        q = pl.scan_parquet(
+            url, credential_provider=lambda: ({"bearer_token": "wrong"}, None)
+        )
+        with pytest.raises(IOError):
+            q.collect()
+    finally:
+        server.shutdown()
+        server.server_close()
+
+
+def test_scan_parquet_http_bearer_token_refresh() -> None:
+    df = pl.DataFrame({"a": range(10_000)})
+    f = io.BytesIO()
+    df.write_parquet(f, row_group_size=1_000)
+    data = f.getvalue()
+
+    issued: list[str] = []
+    received: list[str] = []
+
+    def credential_provider() -> tuple[dict[str, str], int]:
+        issued.append(f"token-{len(issued)}")
+        # Expires within the request time buffer, so it is fetched again per request.
+        return {"bearer_token": issued[-1]}, int(time.time()) + 1
+
+    class Handler(http.server.BaseHTTPRequestHandler):
+        def _send_headers(self, status: int, length: int) -> bool:
+            token = (self.headers["Authorization"] or "").removeprefix("Bearer ")
+            if token not in issued:
+                self.send_error(401)
+                return False
+            received.append(token)
+            self.send_response(status)
+            self.send_header("Content-Length", str(length))
+            return True
+
+        def do_HEAD(self) -> None:
+            if self._send_headers(200, len(data)):
+                self.end_headers()
+
+        def do_GET(self) -> None:
+            m = re.fullmatch(r"bytes=(\d+)-(\d+)", self.headers["Range"] or "")
+            assert m is not None
+            start, end = int(m[1]), min(int(m[2]) + 1, len(data))
+            if self._send_headers(206, end - start):
+                content_range = f"bytes {start}-{end - 1}/{len(data)}"
+                self.send_header("Content-Range", content_range)
+                self.end_headers()
+                self.wfile.write(data[start:end])
+
+        def log_message(self, *args: Any) -> None:
+            pass
+
+    server = http.server.ThreadingHTTPServer(("127.0.0.1", 0), Handler)
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    url = f"http://127.0.0.1:{server.server_address[1]}/data.parquet"
+
+    try:
+        q = pl.scan_parquet(url, credential_provider=credential_provider)
+        assert_frame_equal(q.collect(), df)
+        assert len(set(received)) > 1
+    finally:
+        server.shutdown()
+        server.server_close()
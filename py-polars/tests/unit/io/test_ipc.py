@@ -1,6 +1,8 @@
 from __future__ import annotations
 
 import io
+import os
+import sys
 from decimal import Decimal
 from typing import TYPE_CHECKING, Any, no_type_check
 
@@ -469,3 +471,54 @@ def test_roundtrip_empty_str_list_21163() -> None:
     bytes = df.serialize()
     deserialized = pl.DataFrame.deserialize(io.BytesIO(bytes))
     assert_frame_equal(df, deserialized)
+
+
+@pytest.mark.skipif(sys.platform != "linux", reason="Shared memory requires Linux")
+def test_shared_memory_roundtrip() -> None:
+    name = f"polars-test-{os.getpid()}"
+    df = pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+
+    path = df.write_shared_memory(name)
+    try:
+        q = pl.scan_shared_memory(name)
+        assert_frame_equal(q.collect(), df)
+        assert_frame_equal(
+            q.filter(pl.col("a") > 1).select("b").collect(), df[1:, ["b"]]
+        )
+
+        # Replacing the frame doesn't affect a reader that is still open.
+        before = pl.read_ipc(path, memory_map=True)
+        df.head(1).write_shared_memory(name)
+        assert_frame_equal(pl.scan_shared_memory(name).collect(), df.head(1))
+        assert_frame_equal(before, df)
+    finally:
+        pl.remove_shared_memory(name)
+    assert not os.path.exists(path)
+
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        df.write_shared_memory("a/b")
+
+
+@pytest.mark.parametrize("flush_each_batch", [False, True])
+def test_sink_ipc_stream(flush_each_batch: bool) -> None:
+    df = pl.DataFrame({"a": range(10_000), "b": ["x", "y"] * 5_000})
+
+    f = io.BytesIO()
+    df.lazy().sink_ipc_stream(f, compression="zstd", flush_each_batch=flush_each_batch)
+    f.seek(0)
+    assert_frame_equal(pl.read_ipc_stream(f), df)
+
+
+def test_sink_ipc_batch_size(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": range(10)})
+
+    path = tmp_path / "data.arrow"
+    df.lazy().sink_ipc(path, batch_size=4)
+    assert pl.read_ipc(path, rechunk=False).n_chunks() == 3
+
+    f = io.BytesIO()
+    df.lazy().sink_ipc_stream(f, batch_size=3)
+    f.seek(0)
+    out = pl.read_ipc_stream(f, rechunk=False)
+    assert_frame_equal(out, df)
+    assert out.n_chunks() == 4
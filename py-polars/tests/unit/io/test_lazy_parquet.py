@@ -1019,3 +1019,55 @@ print("OK", end="", file=sys.stderr)
 
     for logs in out_str.split("QUERY-FENCE"):
         ensure_caches_dropped(logs)
+
+
+@pytest.mark.write_disk
+def test_scan_parquet_stats(tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [None, None, 3, 1, 7, None],
+            "b": ["y", "x", None, "z", "w", "v"],
+            "c": [[1], [2, 3], None, [], [4], [5]],
+        }
+    )
+    df.head(2).write_parquet(tmp_path / "0.parquet", row_group_size=1)
+    df.tail(4).write_parquet(tmp_path / "1.parquet", row_group_size=2)
+
+    lf = pl.scan_parquet(tmp_path / "*.parquet")
+    expected = df.lazy().stats()
+    assert expected.row(0) == (
+        6,
+        {"null_count": 3, "min": 1, "max": 7},
+        {"null_count": 1, "min": "v", "max": "z"},
+        {"null_count": 1, "min": None, "max": None},
+    )
+    assert_frame_equal(lf.stats(), expected)
+    assert_frame_equal(lf.select("b").stats(), expected.select("row_count", "b"))
+
+    filtered = lf.filter(pl.col("a") > 1).stats()
+    assert filtered.row(0)[:2] == (2, {"null_count": 0, "min": 3, "max": 7})
+
+
+@pytest.mark.write_disk
+def test_scan_parquet_split_large_row_groups(monkeypatch: Any, tmp_path: Path) -> None:
+    monkeypatch.setenv("POLARS_PARQUET_MAX_ROWS_PER_DECODE", "7")
+    path = tmp_path / "data.parquet"
+    df = pl.DataFrame(
+        {
+            "a": range(100),
+            "b": [None if i % 3 == 0 else str(i % 5) for i in range(100)],
+            "c": [[i] * (i % 4) for i in range(100)],
+        }
+    )
+    df.write_parquet(path, row_group_size=60, data_page_size=64)
+
+    lf = pl.scan_parquet(path)
+    assert_frame_equal(lf.collect(engine="streaming"), df)
+    assert_frame_equal(
+        lf.with_row_index().slice(13, 71).collect(engine="streaming"),
+        df.with_row_index().slice(13, 71),
+    )
+    assert_frame_equal(
+        lf.filter(pl.col("a") % 2 == 0).collect(engine="streaming"),
+        df.filter(pl.col("a") % 2 == 0),
+    )
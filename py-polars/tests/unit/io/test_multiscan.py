@@ -671,3 +671,44 @@ def test_row_index_filter_22612(scan: Any, write: Any) -> None:
             .collect(),
             df.with_row_index().slice(end - 2, 3),
         )
+
+
+@pytest.mark.write_disk
+def test_multiscan_row_index_per_file(tmp_path: Path) -> None:
+    a = pl.DataFrame({"col": [5, 10, 1996]})
+    b = pl.DataFrame({"col": [42, 13]})
+
+    a.write_parquet(tmp_path / "a.parquet")
+    b.write_parquet(tmp_path / "b.parquet")
+
+    lf = pl.scan_parquet(tmp_path / "*.parquet", include_file_paths="f")
+
+    out = lf.with_row_index_per_file("i", offset=1).collect()
+    assert out.columns == ["i", "col", "f"]
+    assert_frame_equal(
+        out.select("i", "col"),
+        pl.DataFrame(
+            [
+                pl.Series("i", [1, 2, 3, 1, 2], get_index_type()),
+                pl.Series("col", [5, 10, 1996, 42, 13]),
+            ]
+        ),
+    )
+    assert out.select(pl.struct("f", "i").is_unique().all()).item()
+
+    with pytest.raises(ValueError, match="cannot be negative"):
+        lf.with_row_index_per_file("i", offset=-1)
+
+
+def test_with_row_hash() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 1, 1], "b": ["x", "y", "x", "z"]})
+
+    ids = lf.with_row_hash("id", "a", "b").collect()["id"]
+    assert ids.dtype == pl.UInt64
+    assert ids[0] == ids[2]
+    assert ids.n_unique() == 3
+
+    ids_a = lf.with_row_hash("id", pl.col("a")).collect()["id"]
+    assert ids_a[0] == ids_a[2] == ids_a[3]
+
+    assert (lf.with_row_hash("id", "a", "b", seed=1).collect()["id"] != ids).any()
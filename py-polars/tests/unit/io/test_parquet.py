@@ -3329,3 +3329,78 @@ def test_field_overwrites_metadata() -> None:
     assert schema[2].metadata[b"struct"] == b"true"
     assert schema[2].type.fields[0].metadata[b"md"] == b"yes"
     assert schema[2].type.fields[1].metadata[b"md2"] == b"Yes!"
+
+
+@pytest.mark.parametrize("use_pyarrow", [True, False])
+def test_read_parquet_strings_as_categorical(use_pyarrow: bool, tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "dict": ["a", "b", None, "a", "c", "b"] * 10,
+            "plain": ["x", "y", "z", None, "y", "x"] * 10,
+            "int": list(range(60)),
+        }
+    )
+    path = tmp_path / "strings.parquet"
+    if use_pyarrow:
+        pq.write_table(df.to_arrow(), path, row_group_size=25, use_dictionary=["dict"])
+    else:
+        df.write_parquet(path, row_group_size=25)
+
+    lf = pl.scan_parquet(path, strings_as_categorical=True)
+    assert lf.collect_schema() == pl.Schema(
+        {"dict": pl.Categorical, "plain": pl.Categorical, "int": pl.Int64}
+    )
+
+    out = pl.read_parquet(path, strings_as_categorical=True)
+    assert out.schema == lf.collect_schema()
+    assert_frame_equal(out.with_columns(pl.col(pl.Categorical).cast(pl.String)), df)
+
+
+@pytest.mark.write_disk
+def test_scan_parquet_column_mapping(tmp_path: Path) -> None:
+    old = pl.DataFrame({"key": [1, 2], "val": ["a", "b"], "extra": [0, 0]})
+    new = pl.DataFrame({"value": ["c", "d", "e"], "id": [3, 4, 5]})
+    old.lazy().sink_parquet(
+        tmp_path / "0.parquet",
+        field_overwrites={
+            "key": ParquetFieldOverwrites(field_id=1),
+            "val": ParquetFieldOverwrites(field_id=2),
+            "extra": ParquetFieldOverwrites(field_id=3),
+        },
+    )
+    new.lazy().sink_parquet(
+        tmp_path / "1.parquet",
+        field_overwrites={
+            "value": ParquetFieldOverwrites(field_id=2),
+            "id": ParquetFieldOverwrites(field_id=1),
+        },
+    )
+
+    lf = pl.scan_parquet(
+        tmp_path / "*.parquet",
+        column_mapping={"id": 1, "value": 2},
+        allow_missing_columns=True,
+    )
+    expected = pl.DataFrame(
+        {
+            "id": [1, 2, 3, 4, 5],
+            "value": ["a", "b", "c", "d", "e"],
+            "extra": [0, 0, None, None, None],
+        }
+    )
+    assert_frame_equal(lf.collect(), expected)
+    assert_frame_equal(
+        lf.filter(pl.col("id") > 1).select("value").collect(),
+        expected.filter(pl.col("id") > 1).select("value"),
+    )
+
+    renames = {"key": "id", "val": "value"}
+    lf = pl.scan_parquet(
+        tmp_path / "*.parquet",
+        column_mapping=lambda name: renames.get(name, name),
+        allow_missing_columns=True,
+    )
+    assert_frame_equal(lf.collect(), expected)
+
+    with pytest.raises(pl.exceptions.DuplicateError):
+        pl.scan_parquet(tmp_path / "0.parquet", column_mapping={"key": 2}).collect()
@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import io
+import json
 from typing import TYPE_CHECKING, Any, TypedDict
 
 import pytest
@@ -373,3 +374,103 @@ def test_partition_to_memory(tmp_path: Path, io_type: IOType) -> None:
     for i, (_, value) in enumerate(output_files.items()):
         value.seek(0)
         assert_frame_equal(io_type["scan"](value).collect(), df.slice(i, 1))
+
+
+@pytest.mark.write_disk
+def test_sink_parquet_bucketed(tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "k": [i % 13 for i in range(100)],
+            "s": [str(i % 3) for i in range(100)],
+            "v": range(100),
+        }
+    )
+
+    df.lazy().sink_parquet_bucketed(
+        tmp_path, by=["k", "s"], num_buckets=4, sync_on_close="data"
+    )
+
+    files = sorted(tmp_path.rglob("*.parquet"))
+    assert {f.parent.name for f in files} <= {f"bucket={i}" for i in range(4)}
+    for f in files:
+        assert json.loads(pl.read_parquet_metadata(f)["polars.bucketing"]) == {
+            "by": ["k", "s"],
+            "num_buckets": 4,
+            "hash": "murmur3_x86_32",
+            "hash_version": 1,
+        }
+        assert pl.read_parquet_schema(f).keys() == {"k", "s", "v"}
+
+    out = pl.scan_parquet(tmp_path, hive_partitioning=True).collect()
+    assert_frame_equal(out.drop("bucket").sort("v"), df)
+
+    # Equal keys are in the same bucket.
+    n_buckets = out.group_by("k", "s").agg(pl.col("bucket").n_unique())
+    assert (n_buckets["bucket"] == 1).all()
+
+    # The bucket of a row is deterministic.
+    df.lazy().sink_parquet_bucketed(
+        tmp_path / "again", by=["k", "s"], num_buckets=4, sync_on_close="data"
+    )
+    again = pl.scan_parquet(tmp_path / "again", hive_partitioning=True).collect()
+    assert_frame_equal(again.sort("v"), out.sort("v"))
+
+
+@pytest.mark.write_disk
+def test_scan_parquet_bucket_pruning(tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "k": [i % 13 for i in range(100)],
+            "s": [str(i % 3) for i in range(100)],
+            "v": range(100),
+        }
+    )
+    df.lazy().sink_parquet_bucketed(
+        tmp_path, by=["k", "s"], num_buckets=8, sync_on_close="data"
+    )
+    lf = pl.scan_parquet(tmp_path, hive_partitioning=True)
+
+    q = lf.filter(pl.col("k") == 5, pl.col("s") == "2").select("k", "s", "v")
+    assert 'col("bucket")' in q.explain()
+    assert_frame_equal(
+        q.collect().sort("v"),
+        df.filter(pl.col("k") == 5, pl.col("s") == "2"),
+    )
+
+    # All key columns must be fixed to prune buckets.
+    q = lf.filter(pl.col("k") == 5).select("k", "s", "v")
+    assert 'col("bucket")' not in q.explain()
+    assert_frame_equal(q.collect().sort("v"), df.filter(pl.col("k") == 5))
+
+
+@pytest.mark.write_disk
+def test_scan_parquet_bucket_pruning_float_keys(tmp_path: Path) -> None:
+    df = pl.DataFrame({"k": [0.0, -0.0, 1.5, 2.5, -1.5], "v": range(5)})
+    df.lazy().sink_parquet_bucketed(
+        tmp_path, by="k", num_buckets=4, sync_on_close="data"
+    )
+    lf = pl.scan_parquet(tmp_path, hive_partitioning=True)
+
+    q = lf.filter(pl.col("k") == -0.0).select("k", "v")
+    assert 'col("bucket")' in q.explain()
+    assert q.collect()["v"].sort().to_list() == [0, 1]
+
+
+@pytest.mark.write_disk
+def test_scan_parquet_bucket_pruning_requires_all_files(tmp_path: Path) -> None:
+    df = pl.DataFrame({"k": range(20), "v": range(20)})
+    df.lazy().sink_parquet_bucketed(
+        tmp_path, by="k", num_buckets=4, sync_on_close="data"
+    )
+    # A file without the bucketing metadata, in a bucket its keys do not belong to.
+    stray = pl.DataFrame({"k": range(20), "v": range(20, 40)})
+    for i in range(4):
+        if not any((tmp_path / f"bucket={i}").glob("*.parquet")):
+            continue
+        stray.write_parquet(tmp_path / f"bucket={i}" / "zzz-stray.parquet")
+        break
+
+    lf = pl.scan_parquet(tmp_path, hive_partitioning=True)
+    q = lf.filter(pl.col("k") == 3).select("k", "v")
+    assert 'col("bucket")' not in q.explain()
+    assert q.collect()["v"].sort().to_list() == [3, 23]
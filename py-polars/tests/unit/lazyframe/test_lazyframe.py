@@ -24,7 +24,7 @@ from tests.unit.conftest import FLOAT_DTYPES, NUMERIC_DTYPES
 if TYPE_CHECKING:
     from _pytest.capture import CaptureFixture
 
-    from polars._typing import PolarsDataType
+    from polars._typing import EngineType, PolarsDataType
 
 
 def test_init_signature_match() -> None:
@@ -1491,3 +1491,14 @@ def test_unique_length_multiple_columns() -> None:
         }
     )
     assert lf.unique().select(pl.len()).collect().item() == 4
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_collect_chunked(engine: EngineType) -> None:
+    lf = pl.concat([pl.LazyFrame({"a": range(i, i + 3)}) for i in range(0, 9, 3)])
+    df = lf.collect_chunked(4, engine=engine)
+    assert_frame_equal(df, lf.collect())
+    assert [len(b) for b in df.to_arrow().to_batches()] == [4, 4, 1]
+
+    with pytest.raises(ValueError):
+        lf.collect_chunked(0)
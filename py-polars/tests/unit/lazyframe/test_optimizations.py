@@ -1,10 +1,16 @@
+from __future__ import annotations
+
 import itertools
+from typing import TYPE_CHECKING
 
 import pytest
 
 import polars as pl
 from polars.testing import assert_frame_equal
 
+if TYPE_CHECKING:
+    from polars._typing import EngineType
+
 
 def test_is_null_followed_by_all() -> None:
     lf = pl.LazyFrame({"group": [0, 0, 0, 1], "val": [6, 0, None, None]})
@@ -388,3 +394,59 @@ def test_order_observe_group_by() -> None:
 
     plan = q.explain()
     assert "AGGREGATE[maintain_order: true]" in plan
+
+
+def test_semi_join_reduction_star_schema() -> None:
+    fact = pl.LazyFrame(
+        {
+            "a": [1, 2, 3, 1, 2, 3],
+            "b": [10, 10, 20, 20, 30, 30],
+            "v": [1, 2, 3, 4, 5, 6],
+        }
+    )
+    d1 = pl.LazyFrame({"a": [1, 2, 3], "x": ["p", "q", "r"]})
+    d2 = pl.LazyFrame({"b": [10, 20, 30], "y": ["s", "t", "s"]})
+
+    q = (
+        fact.join(d1, on="a")
+        .join(d2.filter(pl.col("y") == "s"), on="b")
+        .group_by("x")
+        .agg(pl.col("v").sum())
+        .sort("x")
+    )
+
+    reduce = pl.QueryOptFlags(semi_join_reduction=True)
+    assert not pl.QueryOptFlags().semi_join_reduction
+    assert "SEMI JOIN" not in q.explain()
+    assert "SEMI JOIN" in q.explain(optimizations=reduce)
+
+    expected = pl.DataFrame({"x": ["p", "q", "r"], "v": [1, 7, 6]})
+    assert_frame_equal(q.collect(), expected)
+    assert_frame_equal(q.collect(optimizations=reduce), expected)
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_deterministic_mode(engine: EngineType) -> None:
+    lf = pl.LazyFrame({"a": [5, 3, 5, 1, 3, 4] * 1000, "b": range(6000)})
+    opts = pl.QueryOptFlags(deterministic=True)
+
+    q = lf.group_by("a").agg(pl.col("b").first())
+    expected = pl.DataFrame({"a": [5, 3, 1, 4], "b": [0, 1, 3, 5]})
+    for _ in range(3):
+        out = q.collect(engine=engine, optimizations=opts)
+        assert_frame_equal(out, expected)
+
+    q = lf.unique("a", keep="any")
+    for _ in range(3):
+        out = q.collect(engine=engine, optimizations=opts)
+        assert_frame_equal(out, expected)
+
+    q = pl.concat([lf.head(2), lf.tail(2)], parallel=True)
+    out = q.collect(engine=engine, optimizations=opts)
+    assert out["b"].to_list() == [0, 1, 5998, 5999]
+
+
+def test_deterministic_flag() -> None:
+    assert not pl.QueryOptFlags().deterministic
+    assert pl.QueryOptFlags(deterministic=True).deterministic
+    assert pl.QueryOptFlags.none(deterministic=True).deterministic
@@ -776,3 +776,107 @@ def test_empty_agg_22005() -> None:
         .select(pl.col("a").sum())
     )
     assert_frame_equal(out.collect(), pl.DataFrame({"a": 0}))
+
+
+def test_geo_mean_harmonic_mean() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 2, 2, 3],
+            "a": [1.0, 4.0, 16.0, 1e200, 1e200, None],
+            "b": [1.0, 4.0, 4.0, 2.0, 2.0, None],
+        }
+    )
+
+    q = (
+        df.lazy()
+        .group_by("g")
+        .agg(pl.col("a").geo_mean(), pl.col("b").harmonic_mean())
+        .sort("g")
+    )
+    expected = pl.DataFrame(
+        {"g": [1, 2, 3], "a": [4.0, 1e200, None], "b": [2.0, 2.0, None]}
+    )
+    assert_frame_equal(q.collect(), expected, rtol=1e-10)
+    assert_frame_equal(q.collect(engine="streaming"), expected, rtol=1e-10)
+
+    out = df.select(
+        pl.col("b").geo_mean().alias("geo"),
+        pl.col("b").harmonic_mean().over("g").alias("harmonic"),
+    )
+    assert out["geo"].item() == pytest.approx(64**0.2)
+    assert out["harmonic"].to_list()[:5] == pytest.approx([2.0, 2.0, 2.0, 2.0, 2.0])
+
+
+def test_geo_mean_harmonic_mean_dtype() -> None:
+    df = pl.DataFrame({"a": [1.0, 2.0]}, schema={"a": pl.Float32})
+    q = df.lazy().select(pl.col("a").geo_mean(), h=pl.col("a").harmonic_mean())
+    assert q.collect_schema() == {"a": pl.Float32, "h": pl.Float32}
+    assert q.collect().schema == {"a": pl.Float32, "h": pl.Float32}
+
+
+def test_twap() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "b", "b", "a"],
+            "time": [
+                datetime(2025, 1, 1, 9, 0),
+                datetime(2025, 1, 1, 9, 30),
+                datetime(2025, 1, 1, 9, 45),
+                datetime(2025, 1, 1, 9, 50),
+                datetime(2025, 1, 1, 9, 55),
+                datetime(2025, 1, 1, 10, 0),
+            ],
+            "price": [10.0, 12.0, None, 1.0, 2.0, 13.0],
+        }
+    )
+    out = df.group_by("g", maintain_order=True).agg(pl.col("price").twap("time"))
+    # The null price is ignored, so 12.0 lasts from 9:30 until 10:00.
+    expected = pl.DataFrame({"g": ["a", "b"], "price": [11.0, 1.0]})
+    assert_frame_equal(out, expected)
+
+    out = df.select(pl.col("price").twap("time").over("g"))
+    assert out["price"].to_list() == [11.0, 11.0, 11.0, 1.0, 1.0, 11.0]
+
+    # A single observation, or observations at the same time, give the last value.
+    assert df.head(1).select(pl.col("price").twap("time")).item() == 10.0
+    q = pl.LazyFrame({"v": [1.0, 3.0], "t": [1, 1]}, schema_overrides={"v": pl.Float32})
+    assert q.select(pl.col("v").twap("t")).collect().item() == 3.0
+    assert q.select(pl.col("v").twap("t")).collect_schema() == {"v": pl.Float32}
+
+    with pytest.raises(InvalidOperationError, match="sorted"):
+        df.select(pl.col("price").twap(pl.col("time").reverse()))
+
+
+def test_twap_window() -> None:
+    df = pl.DataFrame(
+        {
+            "time": [
+                datetime(2025, 1, 1, 9, 0),
+                datetime(2025, 1, 1, 9, 30),
+                datetime(2025, 1, 1, 10, 0),
+                datetime(2025, 1, 1, 10, 30),
+            ],
+            "price": [10.0, 12.0, 13.0, 14.0],
+        }
+    )
+    # 10.0 is carried in from before the window, 13.0 holds until its end.
+    out = df.select(
+        pl.col("price").twap(
+            "time", start=datetime(2025, 1, 1, 9, 15), end=datetime(2025, 1, 1, 10, 15)
+        )
+    )
+    assert out.item() == 11.75
+
+    # Without a start the window starts at the first observation.
+    out = df.select(pl.col("price").twap("time", end=datetime(2025, 1, 1, 10, 15)))
+    assert out.item() == pytest.approx((10.0 * 30 + 12.0 * 30 + 13.0 * 15) / 75)
+
+    out = df.select(pl.col("price").twap("time", start=datetime(2025, 1, 1, 9, 45)))
+    assert out.item() == pytest.approx((12.0 * 15 + 13.0 * 30) / 45)
+
+    with pytest.raises(InvalidOperationError, match="window"):
+        df.select(
+            pl.col("price").twap(
+                "time", start=datetime(2025, 1, 1, 11), end=datetime(2025, 1, 1, 10)
+            )
+        )
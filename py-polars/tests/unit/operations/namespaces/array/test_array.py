@@ -455,6 +455,39 @@ def test_array_to_struct() -> None:
     ).sum().collect().columns == ["field_0", "field_1", "field_2"]
 
 
+def test_array_to_struct_custom_names_schema() -> None:
+    lf = pl.LazyFrame({"a": [[1, 2]]}, schema={"a": pl.Array(pl.Int64, 2)})
+    q = lf.select(pl.col("a").arr.to_struct(fields=lambda idx: f"s{idx}"))
+    expected = pl.Struct({"s0": pl.Int64, "s1": pl.Int64})
+    assert q.collect_schema() == {"a": expected}
+    assert q.collect().schema == {"a": expected}
+
+
+def test_array_struct_roundtrip() -> None:
+    df = pl.DataFrame(
+        {"reading": [[1.0, 2.0, 3.0], None, [4.0, None, 6.0]]},
+        schema={"reading": pl.Array(pl.Float64, 3)},
+    )
+    out = df.lazy().select(
+        pl.col("reading").arr.to_struct(fields=["x", "y", "z"]).struct.to_array()
+    )
+    assert out.collect_schema() == {"reading": pl.Array(pl.Float64, 3)}
+    assert out.collect()["reading"].to_list() == [
+        [1.0, 2.0, 3.0],
+        [None, None, None],
+        [4.0, None, 6.0],
+    ]
+
+    s = pl.Series("s", [{"x": 1, "y": 2}, None, {"x": None, "y": 4}])
+    assert_series_equal(
+        s.struct.to_array(),
+        pl.Series("s", [[1, 2], None, [None, 4]], dtype=pl.Array(pl.Int64, 2)),
+    )
+
+    with pytest.raises(InvalidOperationError, match="same dtype"):
+        pl.Series([{"x": 1, "y": "a"}]).struct.to_array()
+
+
 def test_array_shift() -> None:
     df = pl.DataFrame(
         {"a": [[1, 2, 3], None, [4, 5, 6], [7, 8, 9]], "n": [None, 1, 1, -2]},
@@ -704,6 +704,61 @@ def test_select_from_list_to_struct_11143() -> None:
     assert df.equals(pl.DataFrame({"a": [1.0, 1.5]}))
 
 
+def test_list_to_struct_width() -> None:
+    df = pl.DataFrame({"n": [[0, 1], [0, 1, 2], [0, 1, 2, 3]]})
+
+    q = df.lazy().select(pl.col("n").list.to_struct(width=3).struct.unnest())
+    expected = pl.DataFrame(
+        {"field_0": [0, 0, 0], "field_1": [1, 1, 1], "field_2": [None, 2, 2]}
+    )
+    assert q.collect_schema() == expected.schema
+    assert_frame_equal(q.collect(), expected)
+
+    out = df.select(
+        pl.col("n").list.to_struct("max_width", infer_sample_size=2, _eager=True)
+    )
+    assert out.schema["n"] == pl.Struct(
+        {"field_0": pl.Int64, "field_1": pl.Int64, "field_2": pl.Int64}
+    )
+
+    with pytest.raises(ValueError, match="infer_sample_size"):
+        pl.col("n").list.to_struct(infer_sample_size=2, _eager=True)
+
+
+def test_list_to_struct_keys() -> None:
+    df = pl.DataFrame(
+        {
+            "values": [[1, 2], [3, 4, 5], None, [6]],
+            "keys": [["a", "b"], ["c", "a", "a"], ["a"], [None]],
+        }
+    )
+
+    out = df.select(pl.col("values").list.to_struct(keys="keys", _eager=True))
+    assert out.to_series().to_list() == [
+        {"a": 1, "b": 2, "c": None},
+        {"a": 5, "b": None, "c": 3},
+        {"a": None, "b": None, "c": None},
+        {"a": None, "b": None, "c": None},
+    ]
+
+    q = df.lazy().select(
+        pl.col("values").list.to_struct(keys="keys", fields=["c", "a"]).struct.unnest()
+    )
+    expected = pl.DataFrame({"c": [None, 3, None, None], "a": [1, 5, None, None]})
+    assert q.collect_schema() == expected.schema
+    assert_frame_equal(q.collect(), expected)
+
+    # A single list of keys is used for all rows.
+    s = pl.Series("rows", [["1", "x"], ["2", "y"]])
+    assert s.list.to_struct(keys=["id", "name"]).to_list() == [
+        {"id": "1", "name": "x"},
+        {"id": "2", "name": "y"},
+    ]
+
+    with pytest.raises(pl.exceptions.ShapeError, match="has 1 values but 2 keys"):
+        pl.Series([[1]]).list.to_struct(keys=["a", "b"])
+
+
 def test_list_arr_get_8810() -> None:
     assert pl.DataFrame(pl.Series("a", [None], pl.List(pl.Int64))).select(
         pl.col("a").list.get(0, null_on_oob=True)
@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import pytest
+
 import polars as pl
 from polars.testing import assert_frame_equal
 
@@ -231,3 +233,69 @@ def test_broadcast_sliced() -> None:
     expected = pl.DataFrame({"a": [[2], [3, 4]]})
 
     assert_frame_equal(out, expected)
+
+
+def test_list_set_operations_multiset() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[3, 1, 1, 2, None, None], [5, 4]],
+            "b": [[1, 2, 2, None, 6], [4, 4]],
+        }
+    )
+    a = pl.col("a").list
+    out = df.select(
+        intersection=a.set_intersection("b", multiset=True),
+        union=a.set_union("b", multiset=True),
+        difference=a.set_difference("b", multiset=True),
+        symmetric_difference=a.set_symmetric_difference("b", multiset=True),
+    )
+    expected = pl.DataFrame(
+        {
+            "intersection": [[1, 2, None], [4]],
+            "union": [[3, 1, 1, 2, None, None, 2, 6], [5, 4, 4]],
+            "difference": [[3, 1, None], [5]],
+            "symmetric_difference": [[3, 1, None, 2, 6], [5, 4]],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    s = pl.Series([["b", "a", "a"]])
+    assert s.list.set_difference(pl.Series([["a"]]), multiset=True).to_list() == [
+        ["b", "a"]
+    ]
+
+
+def test_list_set_operations_nulls() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[3, 1, 1, 2, None, None], [5, 4]],
+            "b": [[1, 2, 2, None, 6], [4, 4]],
+        }
+    )
+    a = pl.col("a").list
+    out = df.select(
+        intersection=a.set_intersection("b", nulls="distinct"),
+        union=a.set_union("b", nulls="distinct"),
+        difference=a.set_difference("b", nulls="distinct"),
+        symmetric_difference=a.set_symmetric_difference("b", nulls="distinct"),
+        ignored=a.set_difference("b", nulls="ignore"),
+    )
+    expected = pl.DataFrame(
+        {
+            "intersection": [[1, 2], [4]],
+            "union": [[3, 1, 2, None, None, None, 6], [5, 4]],
+            "difference": [[3, None, None], [5]],
+            "symmetric_difference": [[3, None, None, None, 6], [5]],
+            "ignored": [[3], [5]],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    with pytest.raises(ValueError, match="`nulls` must be one of"):
+        a.set_union("b", nulls="first")  # type: ignore[arg-type]
+
+
+def test_list_set_difference_maintain_order() -> None:
+    s = pl.Series([[5, 1, 4, 2, 3, 4]])
+    out = s.list.set_difference(pl.Series([[1]]), maintain_order=True)
+    assert out.to_list() == [[5, 4, 2, 3]]
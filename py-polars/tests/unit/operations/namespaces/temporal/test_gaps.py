@@ -0,0 +1,102 @@
+from __future__ import annotations
+
+from datetime import date, datetime, timedelta
+from zoneinfo import ZoneInfo
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_missing_periods() -> None:
+    s = pl.Series(
+        "t",
+        [
+            datetime(2024, 1, 1, 5),
+            datetime(2024, 1, 1, 0),
+            None,
+            datetime(2024, 1, 1, 1),
+            datetime(2024, 1, 1, 2, 30),
+        ],
+    )
+    result = s.to_frame().select(pl.col("t").dt.missing_periods("1h")).to_series()
+    expected = pl.Series(
+        "t",
+        [datetime(2024, 1, 1, h) for h in (2, 3, 4)],
+    )
+    assert_series_equal(result, expected)
+
+    result = s.to_frame().select(pl.col("t").dt.missing_periods(timedelta(hours=2)))
+    assert result["t"].to_list() == [datetime(2024, 1, 1, 2), datetime(2024, 1, 1, 4)]
+
+
+def test_missing_periods_date_and_time_zone() -> None:
+    s = pl.Series("d", [date(2024, 1, 4), date(2024, 1, 1), date(2024, 1, 2)])
+    result = s.to_frame().select(pl.col("d").dt.missing_periods("1d"))
+    assert result["d"].to_list() == [date(2024, 1, 3)]
+
+    # Calendar days keep the time of day across the change to daylight saving time.
+    tz = "Europe/Amsterdam"
+    s = pl.Series([datetime(2024, 3, 30), datetime(2024, 4, 1)]).dt.replace_time_zone(
+        tz
+    )
+    result = s.to_frame().select(pl.first().dt.missing_periods("1d")).to_series()
+    assert result.dtype == pl.Datetime("us", tz)
+    assert result.to_list() == [datetime(2024, 3, 31, tzinfo=ZoneInfo(tz))]
+
+
+def test_gaps_group_by() -> None:
+    df = pl.DataFrame(
+        {
+            "sensor": ["a", "b", "a", "a", "b", "c"],
+            "time": [
+                datetime(2024, 1, 1, 0),
+                datetime(2024, 1, 1, 0),
+                datetime(2024, 1, 1, 4),
+                datetime(2024, 1, 1, 1),
+                datetime(2024, 1, 1, 1),
+                datetime(2024, 1, 1, 0),
+            ],
+        }
+    )
+    result = df.group_by("sensor", maintain_order=True).agg(
+        pl.col("time").dt.gaps("1h").alias("gaps"),
+        pl.col("time").dt.missing_periods("1h").alias("missing"),
+    )
+    assert result.schema["gaps"] == pl.List(
+        pl.Struct({"start": pl.Datetime("us"), "end": pl.Datetime("us")})
+    )
+    expected = pl.DataFrame(
+        {
+            "sensor": ["a", "b", "c"],
+            "gaps": [
+                [{"start": datetime(2024, 1, 1, 1), "end": datetime(2024, 1, 1, 4)}],
+                [],
+                [],
+            ],
+            "missing": [
+                [datetime(2024, 1, 1, 2), datetime(2024, 1, 1, 3)],
+                [],
+                [],
+            ],
+        },
+        schema=result.schema,
+    )
+    assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize("interval", ["0h", "-1h", "1i"])
+def test_gaps_invalid_interval(interval: str) -> None:
+    df = pl.DataFrame({"t": [datetime(2024, 1, 1)]})
+    with pytest.raises(InvalidOperationError):
+        df.select(pl.col("t").dt.gaps(interval))
+    with pytest.raises(InvalidOperationError):
+        df.select(pl.col("t").dt.missing_periods(interval))
+
+
+def test_missing_periods_date_requires_whole_days() -> None:
+    df = pl.DataFrame({"d": [date(2024, 1, 1), date(2024, 1, 3)]})
+    with pytest.raises(InvalidOperationError, match="whole number of days"):
+        df.select(pl.col("d").dt.missing_periods("12h"))
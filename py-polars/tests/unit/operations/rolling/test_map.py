@@ -1,15 +1,20 @@
 from __future__ import annotations
 
+import gc
+import weakref
 from typing import TYPE_CHECKING
 
 import numpy as np
 import pytest
 
 import polars as pl
+from polars.exceptions import ComputeError
 from polars.testing import assert_series_equal
 from tests.unit.conftest import INTEGER_DTYPES
 
 if TYPE_CHECKING:
+    from collections.abc import Iterator
+
     from polars._typing import PolarsDataType
 
 
@@ -134,3 +139,84 @@ def test_rolling_map_rolling_std() -> None:
 
     expected = s.rolling_std(window_size=4, min_samples=3, center=False)
     assert_series_equal(result, expected)
+
+
+class _RollingSum:
+    def __init__(self) -> None:
+        self.sum = 0.0
+
+    def add(self, value: float) -> None:
+        self.sum += value
+
+    def remove(self, value: float) -> None:
+        self.sum -= value
+
+    def evaluate(self) -> float | None:
+        return self.sum
+
+
+@pytest.fixture
+def rolling_sum() -> Iterator[str]:
+    pl.plugins.register_rolling_aggregator("test_sum", _RollingSum)
+    yield "test_sum"
+    pl.plugins.unregister_rolling_aggregator("test_sum")
+
+
+@pytest.mark.parametrize("center", [False, True])
+@pytest.mark.parametrize("min_samples", [None, 1, 2])
+def test_rolling_map_registered(
+    rolling_sum: str, center: bool, min_samples: int | None
+) -> None:
+    s = pl.Series("a", [1, 4, None, 2, 9, 3])
+    result = s.rolling_map_registered(
+        rolling_sum, window_size=3, min_samples=min_samples, center=center
+    )
+    expected = s.cast(pl.Float64).rolling_sum(
+        window_size=3, min_samples=min_samples, center=center
+    )
+    assert_series_equal(result, expected)
+
+
+def test_rolling_map_registered_unknown_name() -> None:
+    pl.plugins.register_rolling_aggregator("test_removed", _RollingSum)
+    assert pl.plugins.unregister_rolling_aggregator("test_removed")
+    assert not pl.plugins.unregister_rolling_aggregator("test_removed")
+
+    df = pl.DataFrame({"a": [1.0, 2.0]})
+    with pytest.raises(ComputeError, match="no rolling aggregator registered"):
+        df.select(pl.col("a").rolling_map_registered("test_removed", window_size=2))
+
+
+def test_unregister_rolling_aggregator_releases_factory() -> None:
+    class Factory:
+        def __call__(self) -> _RollingSum:
+            return _RollingSum()
+
+    factory = Factory()
+    ref = weakref.ref(factory)
+    pl.plugins.register_rolling_aggregator("test_released", factory)
+    del factory
+    gc.collect()
+    assert ref() is not None
+
+    assert pl.plugins.unregister_rolling_aggregator("test_released")
+    gc.collect()
+    assert ref() is None
+
+
+class _FailingSum(_RollingSum):
+    def add(self, value: float) -> None:
+        if value > 2:
+            msg = "value too large"
+            raise ValueError(msg)
+        super().add(value)
+
+
+def test_rolling_map_registered_error() -> None:
+    pl.plugins.register_rolling_aggregator("test_failing_sum", _FailingSum)
+    try:
+        s = pl.Series("a", [1.0, 2.0, 3.0])
+        with pytest.raises(ComputeError, match="value too large"):
+            s.rolling_map_registered("test_failing_sum", window_size=2)
+    finally:
+        pl.plugins.unregister_rolling_aggregator("test_failing_sum")
@@ -84,6 +84,21 @@ def test_rolling_kernels_and_rolling(
     assert_frame_equal(out1, out2)
 
 
+def test_rolling_quantile_multiple_chunks(example_df: pl.DataFrame) -> None:
+    df = example_df.with_columns(
+        pl.when(pl.col("values") != 2).then(pl.col("values").cast(pl.Float64))
+    )
+    chunked = pl.concat([df.slice(0, 2), df.slice(2)], rechunk=False)
+    assert chunked.n_chunks() == 2
+
+    aggs = [
+        pl.col("values").quantile(0.2).alias("quantile"),
+        pl.col("values").median().alias("median"),
+    ]
+    expected = df.rolling("dt", period="3d").agg(aggs)
+    assert_frame_equal(chunked.rolling("dt", period="3d").agg(aggs), expected)
+
+
 @pytest.mark.parametrize(
     ("offset", "closed", "expected_values"),
     [
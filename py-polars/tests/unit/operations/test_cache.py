@@ -0,0 +1,114 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from polars._typing import EngineType
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_cache_select_with_columns(engine: EngineType) -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3, 4], "b": ["x", "y", "x", "y"]})
+    cached = (pl.col("a") * 10).cache()
+
+    out = lf.select(
+        pl.when(pl.col("b") == "x").then(cached).otherwise(-cached),
+        cached.alias("c"),
+        (cached + 1).alias("d"),
+    ).collect(engine=engine)
+    expected = pl.DataFrame(
+        {"a": [10, -20, 30, -40], "c": [10, 20, 30, 40], "d": [11, 21, 31, 41]}
+    )
+    assert_frame_equal(out, expected)
+
+    out = lf.with_columns(
+        cached.alias("c"), cached.sum().alias("s"), (cached > 15).cache().alias("gt")
+    ).collect(engine=engine)
+    expected = pl.DataFrame(
+        {
+            "a": [1, 2, 3, 4],
+            "b": ["x", "y", "x", "y"],
+            "c": [10, 20, 30, 40],
+            "s": [100, 100, 100, 100],
+            "gt": [False, True, True, True],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_cache_window_and_group_by(engine: EngineType) -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3, 4], "b": ["x", "y", "x", "y"]})
+    cached = (pl.col("a") * 10).cache()
+
+    out = lf.select(
+        cached.sum().over("b").alias("sum"),
+        (cached - cached.mean()).over("b").alias("centered"),
+        # Not elementwise, so it is computed per group.
+        pl.col("a").cum_sum().cache().over("b").alias("cum_sum"),
+    ).collect(engine=engine)
+    expected = pl.DataFrame(
+        {
+            "sum": [40, 60, 40, 60],
+            "centered": [-10.0, -10.0, 10.0, 10.0],
+            "cum_sum": [1, 2, 4, 6],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    out = (
+        lf.group_by("b", maintain_order=True)
+        .agg(
+            cached.sum().alias("sum"),
+            cached.max().alias("max"),
+            pl.col("a").cum_sum().cache().last().alias("cum_sum"),
+        )
+        .collect(engine=engine)
+    )
+    expected = pl.DataFrame(
+        {"b": ["x", "y"], "sum": [40, 60], "max": [30, 40], "cum_sum": [4, 6]}
+    )
+    assert_frame_equal(out, expected)
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_cache_filter_and_sort(engine: EngineType) -> None:
+    lf = pl.LazyFrame({"a": [3, 1, 4, 2], "b": ["x", "y", "x", "y"]})
+    cached = (pl.col("a") * 10).cache()
+
+    q = lf.filter(pl.when(pl.col("b") == "x").then(cached > 35).otherwise(cached > 5))
+    assert "__POLARS_CACHED_EXPR_" in q.explain()
+    expected = pl.DataFrame({"a": [1, 4, 2], "b": ["y", "x", "y"]})
+    assert_frame_equal(q.collect(engine=engine), expected)
+
+    q = lf.sort(pl.when(pl.col("b") == "x").then(cached).otherwise(-cached))
+    assert "__POLARS_CACHED_EXPR_" in q.explain()
+    expected = pl.DataFrame({"a": [2, 1, 3, 4], "b": ["y", "y", "x", "x"]})
+    assert_frame_equal(q.collect(engine=engine), expected)
+
+
+def test_cache_computed_once() -> None:
+    calls = 0
+
+    def times_ten(s: pl.Series) -> pl.Series:
+        nonlocal calls
+        calls += 1
+        return s * 10
+
+    lf = pl.LazyFrame({"a": [1, 2, 3], "b": [True, False, True]})
+    cached = pl.col("a").map_batches(times_ten, return_dtype=pl.Int64).cache()
+    q = lf.select(
+        pl.when("b").then(cached).otherwise(cached + 1),
+        (cached * 2).alias("c"),
+    )
+    assert "__POLARS_CACHED_EXPR_" in q.explain()
+
+    out = q.collect(optimizations=pl.QueryOptFlags(comm_subexpr_elim=False))
+    assert calls == 1
+    expected = pl.DataFrame({"a": [10, 21, 30], "c": [20, 40, 60]})
+    assert_frame_equal(out, expected)
@@ -12,7 +12,7 @@ from polars.exceptions import ComputeError, InvalidOperationError
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
-    from polars._typing import Label, StartBy
+    from polars._typing import Label, MonthEndPolicy, StartBy
 
 
 @pytest.mark.parametrize(
@@ -1082,3 +1082,117 @@ def test_group_by_dynamic_single_row_22585() -> None:
         {"group": ["x"], "date": [date(2025, 1, 1)], "len": [1]}
     ).with_columns(pl.col("len").cast(pl.UInt32))
     assert_frame_equal(expected, out)
+
+
+@pytest.mark.parametrize(
+    ("month_end_policy", "dates", "expected_dates", "expected_len"),
+    [
+        (
+            None,
+            [(1, 31), (2, 29), (3, 30), (3, 31), (4, 30)],
+            [(1, 31), (2, 29), (3, 29), (4, 29)],
+            [1, 1, 2, 1],
+        ),
+        (
+            "clamp",
+            [(1, 31), (2, 29), (3, 30), (3, 31), (4, 30)],
+            [(1, 31), (2, 29), (3, 31), (4, 30)],
+            [1, 2, 1, 1],
+        ),
+        (
+            "month_end",
+            [(2, 29), (3, 30), (3, 31), (4, 29), (4, 30)],
+            [(2, 29), (3, 31), (4, 30)],
+            [2, 2, 1],
+        ),
+    ],
+)
+def test_group_by_dynamic_month_end_policy(
+    month_end_policy: MonthEndPolicy | None,
+    dates: list[tuple[int, int]],
+    expected_dates: list[tuple[int, int]],
+    expected_len: list[int],
+) -> None:
+    df = pl.DataFrame({"date": [date(2024, m, d) for m, d in dates]})
+    out = df.group_by_dynamic(
+        "date", every="1mo", start_by="datapoint", month_end_policy=month_end_policy
+    ).agg(pl.len())
+    expected = pl.DataFrame(
+        {
+            "date": [date(2024, m, d) for m, d in expected_dates],
+            "len": pl.Series(expected_len, dtype=pl.UInt32),
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    out = (
+        df.lazy()
+        .group_by_dynamic(
+            "date",
+            every="1mo",
+            start_by="datapoint",
+            month_end_policy=month_end_policy,
+        )
+        .agg(pl.len())
+        .collect()
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_group_by_dynamic_business_days() -> None:
+    # Friday 2024-01-05 to Tuesday 2024-01-09.
+    df = pl.DataFrame(
+        {"date": pl.date_range(date(2024, 1, 5), date(2024, 1, 9), eager=True)}
+    )
+    out = df.group_by_dynamic("date", every="1bd").agg(pl.len())
+    expected = pl.DataFrame(
+        {
+            "date": [date(2024, 1, 5), date(2024, 1, 8), date(2024, 1, 9)],
+            "len": pl.Series([3, 1, 1], dtype=pl.UInt32),
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    with pytest.raises(InvalidOperationError, match="business-day frequency"):
+        df.group_by_dynamic("date", every="-1bd").agg(pl.len())
+
+
+def test_group_by_dynamic_period_end_origin_and_label_offset() -> None:
+    df = pl.DataFrame(
+        {
+            "dt": pl.datetime_range(
+                datetime(2024, 1, 1), datetime(2024, 1, 1, 2, 30), "30m", eager=True
+            )
+        }
+    )
+    hours = [datetime(2024, 1, 1, h) for h in range(3)]
+
+    out = df.group_by_dynamic("dt", every="1h", label="period_end").agg(pl.len())
+    assert out["dt"].to_list() == [datetime(2024, 1, 1, h) for h in range(1, 4)]
+
+    out = df.group_by_dynamic(
+        "dt", every="1h", label="right", label_offset="-1h"
+    ).agg(pl.len())
+    assert out["dt"].to_list() == hours
+
+    out = (
+        df.lazy()
+        .group_by_dynamic("dt", every="1h", origin=datetime(2020, 1, 1, 0, 30))
+        .agg(pl.len())
+        .collect()
+    )
+    expected = pl.DataFrame(
+        {
+            "dt": [
+                datetime(2023, 12, 31, 23, 30),
+                datetime(2024, 1, 1, 0, 30),
+                datetime(2024, 1, 1, 1, 30),
+                datetime(2024, 1, 1, 2, 30),
+            ],
+            "len": pl.Series([1, 2, 2, 1], dtype=pl.UInt32),
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    out = df.group_by_dynamic("dt", every="1h", origin=date(2020, 1, 1)).agg(pl.len())
+    assert out["dt"].to_list() == hours
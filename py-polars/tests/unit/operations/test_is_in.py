@@ -737,3 +737,35 @@ def test_null_propagate_all_paths_cat(nulls_equal: bool) -> None:
     missing_value = True if nulls_equal else None
     expected = pl.Series([True, False, missing_value])
     assert_series_equal(result, expected)
+
+
+@pytest.mark.parametrize("nulls_equal", [False, True])
+def test_is_in_subquery(nulls_equal: bool) -> None:
+    lf = pl.LazyFrame({"id": [1, 2, None, 4, 5], "v": [10, 20, 30, 40, 50]})
+    lookup = pl.LazyFrame({"key": [1, 4, None, 6], "flag": [True, True, True, False]})
+    keys = lookup.filter("flag").select("key")
+
+    q = lf.filter(pl.col("id").is_in(keys, nulls_equal=nulls_equal), pl.col("v") > 10)
+    assert "SEMI JOIN" in q.explain()
+    expected_in = [30, 40] if nulls_equal else [40]
+    assert q.collect()["v"].sort().to_list() == expected_in
+
+    q = lf.filter(~pl.col("id").is_in(keys, nulls_equal=nulls_equal))
+    assert "ANTI JOIN" in q.explain()
+    assert q.collect()["v"].sort().to_list() == [20, 50]
+
+    # Same result as collecting the values up front.
+    values = keys.collect().to_series()
+    assert_frame_equal(
+        lf.filter(~pl.col("id").is_in(values, nulls_equal=nulls_equal)).collect(),
+        q.collect(),
+        check_row_order=False,
+    )
+
+
+def test_is_in_subquery_invalid() -> None:
+    lf = pl.LazyFrame({"id": [1, 2]})
+    with pytest.raises(InvalidOperationError, match="exactly one column"):
+        lf.filter(pl.col("id").is_in(pl.LazyFrame({"a": [1], "b": [2]})))
+    with pytest.raises(InvalidOperationError, match="not allowed"):
+        lf.select(pl.col("id").is_in(pl.LazyFrame({"a": [1]}))).collect()
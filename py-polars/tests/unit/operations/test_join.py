@@ -980,6 +980,29 @@ def test_join_4_columns_with_validity() -> None:
     )
 
 
+def test_join_nulls_equal_per_key() -> None:
+    lhs = pl.DataFrame({"a": [1, None, 2, None], "b": ["x", "y", None, "z"]})
+    rhs = pl.DataFrame(
+        {"a": [1, None, 2, None], "b": ["x", "y", None, "w"], "c": [1, 2, 3, 4]}
+    )
+
+    out = lhs.join(rhs, on=["a", "b"], nulls_equal=[True, False]).sort("c")
+    expected = pl.DataFrame({"a": [1, None], "b": ["x", "y"], "c": [1, 2]})
+    assert_frame_equal(out, expected)
+
+    out = lhs.join(rhs, on=["a", "b"], nulls_equal=[False, True]).sort("c")
+    expected = pl.DataFrame({"a": [1, 2], "b": ["x", None], "c": [1, 3]})
+    assert_frame_equal(out, expected)
+
+    out = lhs.join(
+        rhs, on=["a", "b"], how="left", nulls_equal=[True, False], maintain_order="left"
+    )
+    assert out["c"].to_list() == [1, 2, None, None]
+
+    with pytest.raises(InvalidOperationError, match="nulls_equal"):
+        lhs.join(rhs, on=["a", "b"], nulls_equal=[True])
+
+
 @pytest.mark.release
 def test_cross_join() -> None:
     # triggers > 100 rows implementation
@@ -1327,3 +1327,39 @@ def test_join_asof_large_int_21276(
         }
     )
     assert_frame_equal(result, expected)
+
+
+def test_join_asof_bracket() -> None:
+    df = pl.DataFrame({"t": [0, 1, 3, 6], "g": ["a", "a", "b", "a"]})
+    obs = pl.DataFrame({"t": [1, 2, 5], "g": ["a", "b", "a"], "value": [1.0, 2.0, 5.0]})
+
+    out = df.join_asof(obs, on="t", strategy="bracket", coalesce=False)
+    expected = df.with_columns(
+        t_right=pl.struct(
+            backward=pl.Series([None, 1, 2, 5]), forward=pl.Series([1, 1, 5, None])
+        ),
+        g_right=pl.struct(
+            backward=pl.Series([None, "a", "b", "a"]),
+            forward=pl.Series(["a", "a", "a", None]),
+        ),
+        value=pl.struct(
+            backward=pl.Series([None, 1.0, 2.0, 5.0]),
+            forward=pl.Series([1.0, 1.0, 5.0, None]),
+        ),
+    )
+    assert_frame_equal(out, expected)
+    assert out.schema == df.lazy().join_asof(
+        obs.lazy(), on="t", strategy="bracket", coalesce=False
+    ).collect_schema()
+
+    out = df.join_asof(obs, on="t", by="g", strategy="bracket", tolerance=1)
+    expected = df.with_columns(
+        value=pl.struct(
+            backward=pl.Series([None, 1.0, 2.0, 5.0]),
+            forward=pl.Series([1.0, 1.0, None, None]),
+        ),
+    )
+    assert_frame_equal(out, expected)
+    assert out.schema == df.lazy().join_asof(
+        obs.lazy(), on="t", by="g", strategy="bracket", tolerance=1
+    ).collect_schema()
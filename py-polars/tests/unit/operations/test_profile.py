@@ -1,3 +1,5 @@
+from concurrent.futures import ThreadPoolExecutor
+
 import polars as pl
 
 
@@ -16,7 +18,15 @@ def test_profile_columns() -> None:
     # │ group_by(a)  ┆ 69    ┆ 342 │
     # └──────────────┴───────┴─────┘
     assert len(profiling_info) == 2
-    assert profiling_info[1].columns == ["node", "start", "end"]
+    assert profiling_info[1].columns == [
+        "node",
+        "start",
+        "end",
+        "rows",
+        "peak_memory",
+        "spilled_bytes",
+        "thread_utilization",
+    ]
 
 
 def test_profile_with_cse() -> None:
@@ -29,4 +39,52 @@ def test_profile_with_cse() -> None:
         pl.when(x.is_null())
         .then(None)
         .otherwise(pl.when(y == 0).then(None).otherwise(x + y))
-    ).profile(optimizations=pl.QueryOptFlags(comm_subexpr_elim=True))[1].shape == (2, 3)
+    ).profile(optimizations=pl.QueryOptFlags(comm_subexpr_elim=True))[1].shape == (2, 7)
+
+
+def test_profile_node_statistics() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 1, 3], "b": [1.0, 2.0, 3.0, 4.0]})
+    _, profile = lf.group_by("a").agg(pl.col("b").sum()).sort("a").profile()
+
+    optimization = profile.row(0, named=True)
+    assert optimization["node"] == "optimization"
+    assert optimization["rows"] is None
+
+    sort = profile.filter(pl.col("node").str.starts_with("sort")).row(0, named=True)
+    assert sort["rows"] == 3
+    assert sort["peak_memory"] > 0
+    assert sort["spilled_bytes"] is None
+    assert sort["thread_utilization"] is None
+
+
+def test_profile_streaming_node_statistics() -> None:
+    lf = pl.LazyFrame({"a": range(100)})
+    df, profile = lf.filter(pl.col("a") < 10).profile(engine="streaming")
+
+    assert df.height == 10
+    assert profile["node"][0] == "optimization"
+    nodes = profile.slice(1)
+    assert nodes.height > 0
+    assert (nodes["spilled_bytes"] == 0).all()
+    assert (nodes["peak_memory"] >= 0).all()
+    assert nodes["thread_utilization"].is_between(0.0, 1.0).all()
+    assert nodes["rows"].max() >= 10
+
+
+def test_profile_does_not_mutate_optimizations() -> None:
+    opts = pl.QueryOptFlags()
+    streaming = opts._pyoptflags.streaming
+    pl.LazyFrame({"a": [1, 2, 3]}).profile(engine="streaming", optimizations=opts)
+    assert opts._pyoptflags.streaming == streaming
+
+
+def test_profile_streaming_concurrent_queries() -> None:
+    def profile_rows(n: int) -> int | None:
+        lf = pl.LazyFrame({"a": range(n)})
+        _, profile = lf.filter(pl.col("a") >= 0).profile(engine="streaming")
+        return profile["rows"].max()  # type: ignore[return-value]
+
+    with ThreadPoolExecutor(max_workers=2) as pool:
+        rows = list(pool.map(profile_rows, [1_000, 5_000] * 4))
+
+    assert rows == [1_000, 5_000] * 4
@@ -1,11 +1,16 @@
 from __future__ import annotations
 
+from typing import TYPE_CHECKING
+
 import pytest
 
 import polars as pl
 from polars.exceptions import ShapeError
 from polars.testing import assert_frame_equal, assert_series_equal
 
+if TYPE_CHECKING:
+    from polars._typing import EngineType
+
 
 def test_shuffle_group_by_reseed() -> None:
     def unique_shuffle_groups(n: int, seed: int | None) -> int:
@@ -146,3 +151,34 @@ def test_sample_16232() -> None:
     assert df.select(pl.col("b").list.sample(n=pl.col("a"), seed=0)).to_dict(
         as_series=False
     ) == {"b": [[], [], [1]]}
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_with_random_seed_reproducible(engine: EngineType) -> None:
+    df = pl.DataFrame({"g": [1, 2, 3] * 100, "a": range(300)})
+    q = (
+        df.lazy()
+        .with_columns(b=pl.col("a").shuffle(), c=pl.col("a").sample(fraction=1.0))
+        .group_by("g", maintain_order=True)
+        .agg(pl.col("b").shuffle(), pl.col("c").first())
+        .with_random_seed(42)
+    )
+    expected = q.collect(engine=engine)
+    for _ in range(3):
+        assert_frame_equal(q.collect(engine=engine), expected)
+
+    # Every random expression gets its own seed.
+    out = df.lazy().select(x=pl.col("a").shuffle(), y=pl.col("a").shuffle())
+    res = out.with_random_seed(42).collect()
+    assert not res["x"].equals(res["y"])
+
+
+def test_with_random_seed_keeps_explicit_seed() -> None:
+    df = pl.DataFrame({"a": range(100)})
+    expected = df.select(pl.col("a").shuffle(seed=1))
+    out = df.lazy().select(pl.col("a").shuffle(seed=1)).with_random_seed(42)
+    assert_frame_equal(out.collect(), expected)
+
+    a = df.lazy().select(pl.col("a").shuffle()).with_random_seed(1).collect()
+    b = df.lazy().select(pl.col("a").shuffle()).with_random_seed(2).collect()
+    assert not a.equals(b)
@@ -0,0 +1,56 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ShapeError
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from polars._typing import EngineType
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_scatter_expr(engine: EngineType) -> None:
+    lf = pl.LazyFrame({"g": [1, 1, 2, 2, 2], "x": [1, 2, 3, 4, 5]})
+
+    out = lf.select(
+        idx=pl.col("x").scatter([-1, 0, 0], [10, 20, 30]),
+        mask=pl.col("x").scatter(pl.col("x") > 3, None),
+        aligned=pl.col("x").scatter(pl.col("x") > 3, -pl.col("x")),
+    ).collect(engine=engine)
+    expected = pl.DataFrame(
+        {
+            "idx": [30, 2, 3, 4, 10],
+            "mask": [1, 2, 3, None, None],
+            "aligned": [1, 2, 3, -4, -5],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_scatter_expr_per_group(engine: EngineType) -> None:
+    lf = pl.LazyFrame({"g": [1, 1, 2, 2, 2], "x": [1, 2, 3, 4, 5]})
+
+    out = lf.with_columns(pl.col("x").scatter(-1, 0).over("g")).collect(engine=engine)
+    assert out["x"].to_list() == [1, 0, 3, 4, 0]
+
+    out = (
+        lf.group_by("g", maintain_order=True)
+        .agg(pl.col("x").scatter(0, pl.col("x").max()))
+        .collect(engine=engine)
+    )
+    assert out.to_dict(as_series=False) == {"g": [1, 2], "x": [[2, 2], [5, 4, 5]]}
+
+
+def test_scatter_expr_errors() -> None:
+    df = pl.DataFrame({"x": [1, 2, 3]})
+    with pytest.raises(pl.exceptions.OutOfBoundsError):
+        df.select(pl.col("x").scatter(3, 0))
+    with pytest.raises(ShapeError):
+        df.select(pl.col("x").scatter([0, 1], [1, 2, 3]))
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        df.select(pl.col("x").scatter(0, "a"))
@@ -1,11 +1,11 @@
 from __future__ import annotations
 
-from datetime import date
+from datetime import date, datetime, timedelta
 
 import pytest
 
 import polars as pl
-from polars.exceptions import ComputeError
+from polars.exceptions import ComputeError, InvalidOperationError
 from polars.testing import assert_frame_equal, assert_series_equal
 
 
@@ -178,3 +178,39 @@ def test_shift_fill_value_nonscalar() -> None:
         match="'fill_value' must be scalar value",
     ):
         df.select(pl.col("a").shift(1, fill_value=pl.col("b")))
+
+
+def test_shift_by_time() -> None:
+    df = pl.DataFrame(
+        {
+            "id": ["a", "b", "a", "a", "b"],
+            "time": [
+                datetime(2024, 1, 1, 3),
+                datetime(2024, 1, 1, 0),
+                datetime(2024, 1, 1, 1),
+                datetime(2024, 1, 1, 0),
+                datetime(2024, 1, 1, 1),
+            ],
+            "value": [3, 10, 1, 0, 11],
+        }
+    )
+    result = df.select(
+        prev=pl.col("value").shift_by_time("time", "1h").over("id"),
+        next=pl.col("value").shift_by_time("time", timedelta(hours=-2)).over("id"),
+    )
+    expected = pl.DataFrame(
+        {"prev": [None, None, 0, None, 10], "next": [None, None, 3, None, None]}
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_shift_by_time_date_and_errors() -> None:
+    df = pl.DataFrame({"date": [date(2024, 2, 15), date(2024, 1, 15)], "value": [2, 1]})
+    result = df.select(pl.col("value").shift_by_time("date", "1mo"))
+    assert result["value"].to_list() == [1, None]
+
+    with pytest.raises(InvalidOperationError, match="whole number of days"):
+        df.select(pl.col("value").shift_by_time("date", "12h"))
+    year = pl.col("date").dt.truncate("1y")
+    with pytest.raises(InvalidOperationError, match="unique"):
+        df.select(pl.col("value").shift_by_time(year, "1d"))
@@ -11,7 +11,7 @@ from polars.testing import assert_frame_equal, assert_series_equal
 from polars.testing.parametric import dataframes, series
 
 if TYPE_CHECKING:
-    from polars._typing import PolarsDataType
+    from polars._typing import EngineType, PolarsDataType
 
 
 @given(
@@ -198,6 +198,30 @@ def test_sort_by_exprs() -> None:
     assert out.to_list() == [1, -1, 2, -2]
 
 
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_sort_by_exprs_nulls_last_per_key(engine: EngineType) -> None:
+    lf = pl.LazyFrame(
+        {"a": [1, None, -2, 2, None, -1], "b": ["x", "y", "z", "w", "v", "u"]}
+    )
+    out = lf.sort(
+        pl.col("a").abs(),
+        pl.col("a") < 0,
+        descending=[False, True],
+        nulls_last=[True, False],
+        maintain_order=True,
+    ).collect(engine=engine)
+    expected = pl.DataFrame(
+        {"a": [-1, 1, -2, 2, None, None], "b": ["u", "x", "z", "w", "y", "v"]}
+    )
+    assert_frame_equal(out, expected)
+
+    # Keys of a single value don't change the order.
+    out = lf.sort(pl.col("a").max(), "b").collect(engine=engine)
+    assert_frame_equal(out, lf.sort("b").collect())
+    out = lf.sort(pl.lit(1), maintain_order=True).collect(engine=engine)
+    assert_frame_equal(out, lf.collect())
+
+
 @pytest.mark.parametrize(
     ("sort_function", "expected"),
     [
@@ -1,11 +1,16 @@
 from __future__ import annotations
 
+from typing import TYPE_CHECKING
+
 import pytest
 
 import polars as pl
 from polars.exceptions import DuplicateError
 from polars.testing import assert_frame_equal
 
+if TYPE_CHECKING:
+    from polars._typing import EngineType
+
 
 def test_value_counts() -> None:
     s = pl.Series("a", [1, 2, 2, 3])
@@ -85,5 +90,87 @@ def test_value_counts_duplicate_name() -> None:
     assert result.to_dict(as_series=False) == {"literal": [1], "a": [3]}
 
 
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_frame_value_counts(engine: EngineType) -> None:
+    lf = pl.LazyFrame(
+        {
+            "a": ["x", "y", "x", "x", "y", None],
+            "b": [1, 1, 1, 2, 1, 1],
+        }
+    )
+    result = lf.value_counts("a", sort=True).collect(engine=engine)
+    expected = pl.DataFrame(
+        {"a": ["x", "y", None], "count": [3, 2, 1]},
+        schema_overrides={"count": pl.UInt32},
+    )
+    assert_frame_equal(result, expected)
+
+    result = (
+        lf.value_counts(normalize=True, name="p")
+        .sort("a", "b", nulls_last=True)
+        .collect(engine=engine)
+    )
+    expected = pl.DataFrame(
+        {
+            "a": ["x", "x", "y", None],
+            "b": [1, 2, 1, 1],
+            "p": [2 / 6, 1 / 6, 2 / 6, 1 / 6],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    df = lf.collect()
+    assert_frame_equal(
+        df.value_counts(["a", "b"]).sort("a", "b"),
+        df.group_by("a", "b").len(name="count").sort("a", "b"),
+    )
+
+
+def test_frame_value_counts_duplicate_name() -> None:
+    df = pl.DataFrame({"count": [1, 0, 1]})
+    with pytest.raises(DuplicateError):
+        df.value_counts()
+    result = df.value_counts(name="n", sort=True)
+    expected = pl.DataFrame(
+        {"count": [1, 0], "n": [2, 1]}, schema_overrides={"n": pl.UInt32}
+    )
+    assert_frame_equal(result, expected)
+
+
 def test_count() -> None:
     assert pl.Series([None, 1, None, 2, 3]).count() == 3
+
+
+def test_top_k_frequent() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 1, 2, 2, 2],
+            "a": ["x", "y", "y", "x", "z", "w", "w"],
+        }
+    )
+    assert df.select(pl.col("a").top_k_frequent(2)).item().to_list() == [
+        {"a": "x", "count": 2},
+        {"a": "y", "count": 2},
+    ]
+
+    out = (
+        df.lazy()
+        .group_by("g")
+        .agg(pl.col("a").top_k_frequent(1))
+        .sort("g")
+        .collect(engine="streaming")
+    )
+    expected = pl.DataFrame(
+        {"g": [1, 2], "a": [[{"a": "x", "count": 2}], [{"a": "w", "count": 2}]]},
+        schema={
+            "g": pl.Int64,
+            "a": pl.List(pl.Struct({"a": pl.String, "count": pl.UInt32})),
+        },
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_top_k_frequent_duplicate_name() -> None:
+    s = pl.Series("count", [1, 1, 2])
+    with pytest.raises(DuplicateError, match="duplicate field names"):
+        s.to_frame().select(pl.col("count").top_k_frequent(1))
@@ -1,5 +1,7 @@
+import pytest
+
 import polars as pl
-from polars.testing import assert_series_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 
 
 def test_is_unique_series() -> None:
@@ -85,3 +87,50 @@ def test_is_duplicated_null() -> None:
     s = pl.Series([None, None, None])
     expected = pl.Series([True, True, True], dtype=pl.Boolean)
     assert_series_equal(s.is_duplicated(), expected)
+
+
+def test_is_duplicated_by() -> None:
+    df = pl.DataFrame({"a": [1, 2, 1, 3, 1, 2], "b": ["x", "y", "z", "x", "x", "y"]})
+    out = df.select(
+        first=pl.is_duplicated_by("a"),
+        last=pl.is_duplicated_by("a", keep="last"),
+        none=pl.is_duplicated_by(pl.col("a"), "b", keep="none"),
+        all=pl.is_duplicated_by(pl.all(), keep="none"),
+    )
+    assert out.to_dict(as_series=False) == {
+        "first": [False, False, True, False, True, True],
+        "last": [True, True, True, False, False, False],
+        "none": [True, True, False, False, True, True],
+        "all": [True, True, False, False, True, True],
+    }
+
+    # null keys compare equal
+    df = pl.DataFrame({"a": [None, None, 1]})
+    assert df.select(pl.is_duplicated_by("a", keep="none")).to_series().to_list() == [
+        True,
+        True,
+        False,
+    ]
+
+
+def test_duplicates() -> None:
+    df = pl.DataFrame({"a": [1, 2, 1, 3, 1, 2], "b": ["x", "y", "z", "x", "x", "y"]})
+    expected = pl.DataFrame(
+        {"a": [1, 2, 1, 2], "b": ["x", "y", "x", "y"], "count": [2, 2, 2, 2]},
+        schema_overrides={"count": pl.get_index_type()},
+    )
+    assert_frame_equal(df.duplicates(), expected)
+    assert_frame_equal(df.lazy().duplicates().collect(), expected)
+
+    out = df.duplicates("a")
+    assert out["a"].to_list() == [1, 2, 1, 1, 2]
+    assert out["count"].to_list() == [3, 2, 3, 3, 2]
+
+    assert df.duplicates("b").height == 5
+    assert df.head(0).duplicates().height == 0
+
+    out = df.duplicates("a", name="n")
+    assert out.columns == ["a", "b", "n"]
+    assert out["n"].to_list() == [3, 2, 3, 3, 2]
+    with pytest.raises(pl.exceptions.DuplicateError):
+        df.duplicates("a", name="b")
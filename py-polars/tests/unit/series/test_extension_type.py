@@ -0,0 +1,95 @@
+from __future__ import annotations
+
+import gc
+import weakref
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_series_equal
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+
+class _Currency:
+    storage_dtype = pl.Int64
+
+    def parse(self, s: pl.Series, metadata: str | None) -> pl.Series:
+        amount = s.str.strip_prefix(metadata or "$").cast(pl.Float64)
+        return (amount * 100).round().cast(pl.Int64)
+
+    def format(self, s: pl.Series, metadata: str | None) -> pl.Series:
+        units = (s // 100).cast(pl.String)
+        cents = (s % 100).cast(pl.String).str.zfill(2)
+        return ((metadata or "$") + units + "." + cents).alias(s.name)
+
+    def validate(self, s: pl.Series, metadata: str | None) -> None:
+        if (s < 0).any():
+            msg = "amounts should not be negative"
+            raise ValueError(msg)
+
+    def can_cast_to(self, dtype: pl.DataType) -> bool:
+        return not dtype.is_float()
+
+
+@pytest.fixture
+def currency() -> Iterator[str]:
+    pl.plugins.register_extension_type("test_currency", _Currency())
+    yield "test_currency"
+    pl.plugins.unregister_extension_type("test_currency")
+
+
+def test_extension_type(currency: str) -> None:
+    s = pl.Series("price", ["€1.50", "€20.05"]).to_extension(currency, "€")
+    assert s.dtype == pl.Int64
+    assert s.extension_type() == (currency, "€")
+    assert_series_equal(s.to_storage(), pl.Series("price", [150, 2005]))
+
+    # The type is kept by operations that keep the values.
+    result = s.sort(descending=True)
+    assert result.extension_type() == (currency, "€")
+    assert result.cast(pl.Int64).extension_type() == (currency, "€")
+    assert result.cast(pl.String).to_list() == ["€20.05", "€1.50"]
+
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        s.cast(pl.Float64)
+    with pytest.raises(pl.exceptions.ComputeError, match="negative"):
+        pl.Series("price", [-1]).to_extension(currency)
+
+
+def test_extension_type_dropped_warns(currency: str) -> None:
+    s = pl.Series("price", [150, 2005]).to_extension(currency)
+
+    with pytest.warns(UserWarning, match="drops the extension type 'test_currency'"):
+        result = s + s
+    assert result.extension_type() is None
+    assert_series_equal(result, pl.Series("price", [300, 4010]))
+
+    with pytest.warns(UserWarning, match="drops the extension type 'test_currency'"):
+        assert s.sum() == 2155
+
+
+def test_extension_type_unregistered(currency: str) -> None:
+    s = pl.Series("price", [150]).to_extension(currency)
+    assert pl.plugins.unregister_extension_type(currency)
+
+    # Unregistered types keep their name, and are cast like their storage type.
+    assert s.extension_type() == (currency, None)
+    assert s.cast(pl.Float64).to_list() == [150.0]
+    with pytest.raises(pl.exceptions.ComputeError, match="no extension type"):
+        pl.Series([1]).to_extension(currency)
+
+
+def test_unregister_extension_type_releases_definition() -> None:
+    definition = _Currency()
+    ref = weakref.ref(definition)
+    pl.plugins.register_extension_type("test_released", definition)
+    del definition
+    gc.collect()
+    assert ref() is not None
+
+    assert pl.plugins.unregister_extension_type("test_released")
+    gc.collect()
+    assert ref() is None
@@ -1015,6 +1015,34 @@ def test_mode() -> None:
     assert set(pl.int_range(0, 3, eager=True).mode().to_list()) == {0, 1, 2}
 
 
+def test_mode_ties() -> None:
+    s = pl.Series("a", [3, 1, None, 3, 1, None])
+    assert s.mode(ties="smallest").to_list() == [1]
+    assert s.mode(ties="first").to_list() == [3]
+    assert s.mode(ties="all_as_list").to_list() == [[None, 1, 3]]
+    assert pl.Series("a", [], dtype=pl.Int64).mode(ties="smallest").to_list() == [None]
+
+    df = pl.DataFrame({"g": [1, 1, 1, 2, 2, 2], "a": [2, 1, 2, 5, 4, 4]})
+    out = (
+        df.lazy()
+        .group_by("g")
+        .agg(
+            pl.col("a").mode(ties="smallest").alias("smallest"),
+            pl.col("a").mode(ties="all_as_list").alias("all"),
+        )
+        .sort("g")
+        .collect(engine="streaming")
+    )
+    assert out.to_dict(as_series=False) == {
+        "g": [1, 2],
+        "smallest": [2, 4],
+        "all": [[2], [4]],
+    }
+
+    with pytest.raises(ValueError, match="`ties` must be one of"):
+        s.mode(ties="largest")  # type: ignore[arg-type]
+
+
 def test_diff() -> None:
     s = pl.Series("a", [1, 2, 3, 2, 2, 3, 0])
     expected = pl.Series("a", [1, 1, -1, 0, 1, -3])
@@ -1856,6 +1884,32 @@ def test_cumulative_eval() -> None:
     assert_series_equal(s.cumulative_eval(expr3), expected3)
 
 
+def test_cumulative_eval_aggregations() -> None:
+    s = pl.Series("values", [1, None, 3, 4, None, 6])
+
+    assert s.cumulative_eval(pl.element().sum()).to_list() == [1, 1, 4, 8, 8, 14]
+    assert s.cumulative_eval(pl.element().max() * 2).to_list() == [2, 2, 6, 8, 8, 12]
+    assert s.cumulative_eval(pl.len()).to_list() == [1, 2, 3, 4, 5, 6]
+    assert s.cumulative_eval(pl.element().mean(), min_samples=3).to_list() == [
+        None,
+        None,
+        None,
+        8 / 3,
+        8 / 3,
+        14 / 4,
+    ]
+
+    # Not an aggregation, falls back to evaluating every window.
+    assert s.cumulative_eval(pl.element().unique().len()).to_list() == [
+        1,
+        2,
+        3,
+        4,
+        4,
+        5,
+    ]
+
+
 def test_reverse() -> None:
     s = pl.Series("values", [1, 2, 3, 4, 5])
     assert s.reverse().to_list() == [5, 4, 3, 2, 1]
@@ -327,6 +327,28 @@ def test_values_clause_table_registration() -> None:
             assert res.to_dict(as_series=False) == {"x": [-1], "y": [1]}
 
 
+def test_values_clause_expressions() -> None:
+    res = pl.sql(
+        """
+        SELECT * FROM (
+          VALUES
+            (1, 'a', DATE '2020-01-01'),
+            (2 * 3, NULL, CAST('2021-06-30' AS DATE)),
+            (-1.5, 'c', NULL)
+        ) AS tbl(x, y, z)
+        """,
+        eager=True,
+    )
+    assert res.to_dict(as_series=False) == {
+        "x": [1.0, 6.0, -1.5],
+        "y": ["a", None, "c"],
+        "z": [date(2020, 1, 1), date(2021, 6, 30), None],
+    }
+
+    with pytest.raises(SQLInterfaceError, match="expects constant expressions"):
+        pl.sql("SELECT * FROM (VALUES (1, x)) AS tbl(a, b)", eager=True)
+
+
 def test_read_csv(tmp_path: Path) -> None:
     # check empty string vs null, parsing of dates, etc
     df = pl.DataFrame(
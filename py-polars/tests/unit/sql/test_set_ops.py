@@ -70,23 +70,23 @@ def test_except_intersect_by_name() -> None:
 
 
 @pytest.mark.parametrize(
-    ("op", "op_subtype"),
+    ("op", "op_subtype", "expected"),
     [
-        ("EXCEPT", "ALL"),
-        ("EXCEPT", "ALL BY NAME"),
-        ("INTERSECT", "ALL"),
-        ("INTERSECT", "ALL BY NAME"),
+        ("EXCEPT", "ALL", [1, 2, 2, 3, None]),
+        ("EXCEPT", "ALL BY NAME", [1, 2, 2, 3, None]),
+        ("INTERSECT", "ALL", [1, 1, 2, None]),
+        ("INTERSECT", "ALL BY NAME", [1, 1, 2, None]),
     ],
 )
-def test_except_intersect_all_unsupported(op: str, op_subtype: str) -> None:
-    df1 = pl.DataFrame({"n": [1, 1, 1, 2, 2, 2, 3]})  # noqa: F841
-    df2 = pl.DataFrame({"n": [1, 1, 2, 2]})  # noqa: F841
+def test_except_intersect_all(
+    op: str, op_subtype: str, expected: list[int | None]
+) -> None:
+    df1 = pl.DataFrame({"n": [1, 1, 1, 2, 2, 2, 3, None, None]})  # noqa: F841
+    df2 = pl.DataFrame({"n": [1, 1, 2, 4, None]})  # noqa: F841
 
-    with pytest.raises(
-        SQLInterfaceError,
-        match=f"'{op} {op_subtype}' is not supported",
-    ):
-        pl.sql(f"SELECT * FROM df1 {op} {op_subtype} SELECT * FROM df2")
+    # duplicates are matched one-to-one, and rows keep the order of the left table
+    res = pl.sql(f"SELECT * FROM df1 {op} {op_subtype} SELECT * FROM df2", eager=True)
+    assert res["n"].to_list() == expected
 
 
 def test_update_statement_error() -> None:
@@ -142,13 +142,6 @@ def test_except_intersect_errors(op: str) -> None:
     df1 = pl.DataFrame({"x": [1, 9, 1, 1], "y": [2, 3, 4, 4], "z": [5, 5, 5, 5]})  # noqa: F841
     df2 = pl.DataFrame({"x": [1, 9, 1], "y": [2, None, 4], "z": [7, 6, 5]})  # noqa: F841
 
-    if op != "UNION":
-        with pytest.raises(
-            SQLInterfaceError,
-            match=f"'{op} ALL' is not supported",
-        ):
-            pl.sql(f"SELECT * FROM df1 {op} ALL SELECT * FROM df2", eager=False)
-
     with pytest.raises(
         SQLInterfaceError,
         match=f"{op} requires equal number of columns in each table",
@@ -411,3 +411,72 @@ def test_streaming_flag_21799() -> None:
         pl.LazyFrame({"a": 1}).collect(streaming=False)  # type: ignore[call-overload]
     with pytest.raises(DeprecationWarning):
         pl.LazyFrame({"a": 1}).collect(streaming=True)  # type: ignore[call-overload]
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize(
+    "method", ["nearest", "higher", "lower", "midpoint", "linear", "equiprobable"]
+)
+def test_streaming_external_quantile(
+    method: Any, tmp_path: Path, monkeypatch: Any, capfd: Any
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_QUANTILE_MEMORY_BUDGET", "0")
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    values = [(i * 7919) % 1000 / 7 for i in range(1000)]
+    df = pl.DataFrame(
+        {
+            "a": [None if i % 10 == 0 else v for i, v in enumerate(values)],
+            "b": pl.Series(values, dtype=pl.Float32),
+            "c": [i % 13 for i in range(1000)],
+        }
+    )
+    q = df.lazy().select(
+        pl.col("a").quantile(0.3, method),
+        pl.col("b").quantile(0.77, method),
+        pl.col("c").quantile(0.5, method).alias("c_q"),
+        pl.col("c").median().alias("c_median"),
+    )
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+    err = capfd.readouterr().err
+    assert "external quantile: spilled" in err
+
+
+def test_streaming_external_quantile_empty(monkeypatch: Any) -> None:
+    monkeypatch.setenv("POLARS_QUANTILE_MEMORY_BUDGET", "1000000")
+    q = pl.LazyFrame({"a": [None, None]}, schema={"a": pl.Int64}).select(
+        pl.col("a").median(), b=pl.col("a").quantile(0.1)
+    )
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+def test_streaming_external_quantile_invalid_budget(monkeypatch: Any) -> None:
+    monkeypatch.setenv("POLARS_QUANTILE_MEMORY_BUDGET", "1GB")
+    q = pl.LazyFrame({"a": [1, 2, 3]}).select(pl.col("a").median())
+    with pytest.raises(
+        pl.exceptions.InvalidOperationError, match="POLARS_QUANTILE_MEMORY_BUDGET"
+    ):
+        q.collect(engine="streaming")
+
+
+def test_streaming_ewm_over() -> None:
+    # Enough rows to span multiple morsels, so the state of every group has to
+    # carry over from one morsel to the next.
+    n = 250_000
+    df = pl.select(i=pl.int_range(n)).select(
+        g=pl.col("i") % 3,
+        h=pl.col("i") % 5 == 0,
+        a=(pl.col("i") * 7919 % 101).cast(pl.Float64),
+    )
+    q = df.lazy().select(
+        pl.col("a").ewm_mean(alpha=0.3),
+        b=pl.col("a").ewm_std(alpha=0.1).over("g"),
+        c=pl.col("a").ewm_var(alpha=0.5, adjust=False).over("g", "h"),
+    )
+    graph = q.show_graph(raw_output=True, plan_stage="physical", engine="streaming")
+    assert graph.count("ewm") == 3
+    assert "over:" in graph
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
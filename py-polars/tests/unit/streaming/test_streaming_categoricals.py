@@ -32,3 +32,33 @@ def test_streaming_cat_14933() -> None:
     result = df1.join(df2, on="a", how="left")
     expected = {"a": [0], "l": [None]}
     assert result.collect(engine="streaming").to_dict(as_series=False) == expected
+
+
+def test_streaming_categorical_key_group_by_join() -> None:
+    with pl.StringCache():
+        lf = pl.LazyFrame(
+            {"key": ["b", "a", "b", None, "c"], "value": [1, 2, 3, 6, 5]},
+            schema_overrides={"key": pl.Categorical},
+        )
+        # Categories that are added to the cache after the schema is resolved.
+        other = pl.LazyFrame({"key": ["c", "d", "a"], "other": [10, 20, 30]}).cast(
+            {"key": pl.Categorical}
+        )
+
+        out = (
+            lf.group_by("key")
+            .agg(pl.col("value").sum())
+            .sort("value")
+            .collect(engine="streaming")
+        )
+        assert out.to_dict(as_series=False) == {
+            "key": ["a", "b", "c", None],
+            "value": [2, 4, 5, 6],
+        }
+
+        joined = lf.join(other, on="key").sort("value").collect(engine="streaming")
+        assert joined.to_dict(as_series=False) == {
+            "key": ["a", "c"],
+            "value": [2, 5],
+            "other": [30, 10],
+        }
@@ -531,3 +531,29 @@ def test_streaming_group_by_all_null_21593() -> None:
 
     out = df.lazy().group_by(pl.all()).min().collect(engine="streaming")
     assert_frame_equal(df, out, check_row_order=False)
+
+
+@pytest.mark.write_disk
+def test_streaming_map_groups_spill(
+    tmp_path: Path, monkeypatch: Any, capfd: Any
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_GROUP_BY_APPLY_MEMORY_BUDGET", "0")
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    lf = pl.LazyFrame(
+        {
+            "a": [i % 13 for i in range(1000)],
+            "b": list(range(1000)),
+        }
+    )
+    q = lf.group_by("a").map_groups(
+        lambda df: df.select(pl.col("a").first(), pl.col("b").sum(), pl.len()),
+        schema={"a": pl.Int64, "b": pl.Int64, "len": pl.UInt32},
+    )
+    expected = q.collect(engine="in-memory").sort("a")
+    assert_frame_equal(q.collect(engine="streaming").sort("a"), expected)
+
+    err = capfd.readouterr().err
+    assert "group-by apply: spilled" in err
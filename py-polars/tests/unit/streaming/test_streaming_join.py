@@ -1,7 +1,8 @@
 from __future__ import annotations
 
+import re
 from datetime import datetime
-from typing import TYPE_CHECKING, Literal
+from typing import TYPE_CHECKING, Any, Literal
 
 import numpy as np
 import pandas as pd
@@ -313,6 +314,33 @@ def test_streaming_outer_join_partial_flush(tmp_path: Path) -> None:
     )
 
 
+@pytest.mark.write_disk
+@pytest.mark.parametrize("how", ["inner", "semi"])
+def test_streaming_join_runtime_filter(
+    how: JoinStrategy, tmp_path: Path, monkeypatch: Any, capfd: Any
+) -> None:
+    path = tmp_path / "probe.parquet"
+    pl.DataFrame({"k": range(1000), "v": range(1000)}).write_parquet(
+        path, row_group_size=100
+    )
+    build = pl.LazyFrame({"k": [5, 250, 251, 999, None], "w": [1, 2, 3, 4, 5]})
+    q = pl.scan_parquet(path).join(build, on="k", how=how)
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    out = q.collect(engine="streaming")
+    err = capfd.readouterr().err
+    assert "attaching runtime filter on column 'k'" in err
+    pruned = re.search(r"runtime filter on column 'k' pruned (\d+) rows", err)
+    assert pruned is not None
+    assert int(pruned.group(1)) > 0
+
+    expected = pl.DataFrame({"k": [5, 250, 251, 999], "v": [5, 250, 251, 999]})
+    if how == "inner":
+        expected = expected.with_columns(w=pl.Series([1, 2, 3, 4]))
+    assert_frame_equal(out, expected, check_row_order=False, check_dtypes=False)
+    assert_frame_equal(out, q.collect(engine="in-memory"), check_row_order=False)
+
+
 def test_flush_join_and_operation_19040() -> None:
     df_A = pl.LazyFrame({"K": [True, False], "A": [1, 1]})
 
@@ -53,3 +53,34 @@ def test_streaming_unique(monkeypatch: Any, capfd: Any) -> None:
     assert_frame_equal(q.collect(engine="old-streaming"), q.collect(engine="in-memory"))  # type: ignore[call-overload]
     (_, err) = capfd.readouterr()
     assert "df -> re-project-sink -> sort_multiple" in err
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("keep", ["first", "last", "any", "none"])
+def test_streaming_external_unique(
+    keep: Any, tmp_path: Path, monkeypatch: Any, capfd: Any
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_DISTINCT_MEMORY_BUDGET", "0")
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    df = pl.DataFrame(
+        {
+            "a": [i % 7 for i in range(1000)],
+            "b": [i % 3 for i in range(1000)],
+            "c": list(range(1000)),
+        }
+    )
+    q = df.lazy().unique(subset=["a", "b"], keep=keep, maintain_order=False)
+    expected = q.collect(engine="in-memory").sort("a", "b")
+    if keep == "any":
+        assert_frame_equal(
+            q.collect(engine="streaming").select("a", "b").sort("a", "b"),
+            expected.select("a", "b"),
+        )
+    else:
+        assert_frame_equal(q.collect(engine="streaming").sort("a", "b"), expected)
+
+    err = capfd.readouterr().err
+    assert "external distinct: spilled" in err
@@ -399,3 +399,33 @@ def test_order_enum() -> None:
     assert_order_series(["a", "b", "c"], ["c", "b", "a"], dtype)
     assert_order_series([None], [None], dtype)
     assert_order_series([None], ["a"], dtype)
+
+
+@pytest.mark.parametrize("descending", [False, True, [True, False]])
+@pytest.mark.parametrize("nulls_last", [False, True, [False, True]])
+def test_encode_rows_sort(
+    descending: bool | list[bool], nulls_last: bool | list[bool]
+) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [2, None, 1, 2, None, 1],
+            "b": ["x", "y", None, "w", "a", "z"],
+        }
+    )
+
+    key = pl.encode_rows("a", "b", descending=descending, nulls_last=nulls_last)
+    assert df.select(key).dtypes == [pl.Binary]
+    assert_frame_equal(
+        df.sort(key),
+        df.sort(["a", "b"], descending=descending, nulls_last=nulls_last),
+    )
+
+
+def test_encode_rows_broadcast_literal() -> None:
+    df = pl.DataFrame({"a": [3, 1, 2]})
+    out = df.select(
+        with_lit=pl.encode_rows("a", pl.lit("x")),
+        key=pl.encode_rows("a"),
+    )
+    assert out["with_lit"].arg_sort().to_list() == [1, 2, 0]
+    assert out["key"].arg_sort().to_list() == [1, 2, 0]